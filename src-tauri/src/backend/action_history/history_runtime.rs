@@ -0,0 +1,159 @@
+// Recent user-initiated actions (pull, push, commit, merge, play and stop),
+// newest first, for the "recent actions" palette. The list is kept in memory
+// and mirrored to `action-history.json` in the app data directory so it
+// survives restarts. Each entry stores the payload the command ran with, which
+// is what `history_repeat` replays.
+
+fn action_history_file(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|error| format!("Failed to resolve app data directory: {error}"))?;
+    fs::create_dir_all(&app_data_dir)
+        .map_err(|error| format!("Failed to create app data directory: {error}"))?;
+    Ok(app_data_dir.join(ACTION_HISTORY_FILE))
+}
+
+/// A missing or unreadable file starts an empty history rather than failing
+/// the action being recorded.
+fn read_action_history_file(path: &Path) -> Vec<ActionHistoryEntry> {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|raw| serde_json::from_str::<Vec<ActionHistoryEntry>>(&raw).ok())
+        .unwrap_or_default()
+}
+
+fn write_action_history_file(path: &Path, entries: &[ActionHistoryEntry]) -> Result<(), String> {
+    let body = serde_json::to_string_pretty(entries)
+        .map_err(|error| format!("Failed to serialize action history: {error}"))?;
+    fs::write(path, format!("{body}\n"))
+        .map_err(|error| format!("Failed to write {}: {error}", path.display()))
+}
+
+/// Git actions target a worktree path; the history shows its directory name.
+fn action_history_worktree(target: &str) -> String {
+    Path::new(target)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| target.to_string())
+}
+
+fn push_action_history_entry(entries: &mut Vec<ActionHistoryEntry>, entry: ActionHistoryEntry) {
+    entries.insert(0, entry);
+    entries.truncate(ACTION_HISTORY_MAX_ENTRIES);
+}
+
+/// Newest first, loading the persisted history on first use.
+fn action_history_entries(app: &AppHandle) -> Vec<ActionHistoryEntry> {
+    let Some(state) = app.try_state::<ActionHistoryState>() else {
+        return Vec::new();
+    };
+    let mut entries = state.lock_entries();
+    entries
+        .get_or_insert_with(|| {
+            action_history_file(app)
+                .map(|path| read_action_history_file(&path))
+                .unwrap_or_default()
+        })
+        .clone()
+}
+
+/// Appends the outcome of `command` to the history. Failures to persist are
+/// logged; the in-memory list is still updated.
+fn record_action_history<P: Serialize, R: CommandResponse>(
+    app: &AppHandle,
+    command: &str,
+    target: &str,
+    payload: &P,
+    response: &R,
+) {
+    let Some(state) = app.try_state::<ActionHistoryState>() else {
+        return;
+    };
+    let payload = match serde_json::to_value(payload) {
+        Ok(payload) => payload,
+        Err(error) => {
+            log_line(&format!(
+                "[action-history] failed to serialize {command} payload: {error}"
+            ));
+            return;
+        }
+    };
+    let entry = ActionHistoryEntry {
+        id: Uuid::new_v4().to_string(),
+        command: command.to_string(),
+        worktree: action_history_worktree(target),
+        ok: response.command_ok(),
+        error: response.command_error().map(str::to_string),
+        recorded_at: now_iso(),
+        payload,
+    };
+
+    let path = action_history_file(app);
+    let mut entries = state.lock_entries();
+    let entries = entries.get_or_insert_with(|| {
+        path.as_deref()
+            .map(read_action_history_file)
+            .unwrap_or_default()
+    });
+    push_action_history_entry(entries, entry);
+    if let Err(error) = path.and_then(|path| write_action_history_file(&path, entries)) {
+        log_line(&format!("[action-history] {error}"));
+    }
+}
+
+struct ActionReplay {
+    ok: bool,
+    error: Option<String>,
+    result: serde_json::Value,
+}
+
+/// Runs a recorded command again with its stored payload. The command records
+/// the repeat as a new history entry itself.
+fn replay_action<P, R>(
+    payload: serde_json::Value,
+    run: impl FnOnce(P) -> R,
+) -> Result<ActionReplay, String>
+where
+    P: serde::de::DeserializeOwned,
+    R: CommandResponse + Serialize,
+{
+    let payload = serde_json::from_value::<P>(payload)
+        .map_err(|error| format!("The recorded payload can no longer be replayed: {error}"))?;
+    let response = run(payload);
+    Ok(ActionReplay {
+        ok: response.command_ok(),
+        error: response.command_error().map(str::to_string),
+        result: serde_json::to_value(&response)
+            .map_err(|error| format!("Failed to serialize the replayed response: {error}"))?,
+    })
+}
+
+#[cfg(test)]
+mod history_runtime_tests {
+    use super::*;
+
+    fn entry(id: &str) -> ActionHistoryEntry {
+        ActionHistoryEntry {
+            id: id.to_string(),
+            command: "git_push".to_string(),
+            worktree: action_history_worktree("/repo/.worktrees/feature"),
+            ok: true,
+            error: None,
+            recorded_at: "2026-01-01T00:00:00Z".to_string(),
+            payload: serde_json::json!({ "path": "/repo/.worktrees/feature" }),
+        }
+    }
+
+    #[test]
+    fn keeps_the_newest_action_history_entries() {
+        let mut entries = Vec::new();
+        for index in 0..ACTION_HISTORY_MAX_ENTRIES + 2 {
+            push_action_history_entry(&mut entries, entry(&index.to_string()));
+        }
+        assert_eq!(entries.len(), ACTION_HISTORY_MAX_ENTRIES);
+        assert_eq!(entries[0].id, (ACTION_HISTORY_MAX_ENTRIES + 1).to_string());
+        assert_eq!(entries[0].worktree, "feature");
+        assert_eq!(action_history_worktree("feature"), "feature");
+    }
+}
@@ -0,0 +1,175 @@
+// API tokens for the MCP server. Each token carries the capabilities it was
+// created with (see `core::capabilities`), and every request is checked
+// against the capabilities of its `Authorization: Bearer` token. While no
+// token exists, requests without one get `Capability::DEFAULT`, so a local
+// assistant keeps working without setup but cannot stop processes or delete
+// state. Once a token exists, every request has to present one.
+
+fn api_tokens_file(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|error| format!("Failed to resolve app data directory: {error}"))?;
+    fs::create_dir_all(&app_data_dir)
+        .map_err(|error| format!("Failed to create app data directory: {error}"))?;
+    Ok(app_data_dir.join(API_TOKENS_FILE))
+}
+
+fn read_api_tokens_file(path: &Path) -> Result<Vec<ApiTokenRecord>, String> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let raw = fs::read_to_string(path)
+        .map_err(|error| format!("Failed to read {}: {error}", path.display()))?;
+    serde_json::from_str::<Vec<ApiTokenRecord>>(&raw)
+        .map_err(|error| format!("Failed to parse {}: {error}", path.display()))
+}
+
+fn write_api_tokens_file(path: &Path, tokens: &[ApiTokenRecord]) -> Result<(), String> {
+    let body = serde_json::to_string_pretty(tokens)
+        .map_err(|error| format!("Failed to serialize API tokens: {error}"))?;
+    fs::write(path, format!("{body}\n"))
+        .map_err(|error| format!("Failed to write {}: {error}", path.display()))
+}
+
+/// Runs `update` on the tokens, loading them on first use, and persists them
+/// when it returns `Ok(true)`. An unreadable file is an error rather than an
+/// empty list, which would let requests without a token through.
+fn with_api_tokens<T>(
+    app: &AppHandle,
+    update: impl FnOnce(&mut Vec<ApiTokenRecord>) -> Result<(T, bool), String>,
+) -> Result<T, String> {
+    let state = app
+        .try_state::<ApiTokensState>()
+        .ok_or_else(|| "API token state is not available.".to_string())?;
+    let path = api_tokens_file(app)?;
+    let mut tokens = state.lock_tokens();
+    if tokens.is_none() {
+        *tokens = Some(read_api_tokens_file(&path)?);
+    }
+    let tokens = tokens.get_or_insert_with(Vec::new);
+    let (value, changed) = update(tokens)?;
+    if changed {
+        write_api_tokens_file(&path, tokens)?;
+    }
+    Ok(value)
+}
+
+fn api_token_summaries(tokens: &[ApiTokenRecord]) -> Vec<ApiTokenSummary> {
+    tokens
+        .iter()
+        .map(|token| ApiTokenSummary {
+            id: token.id.clone(),
+            name: token.name.clone(),
+            capabilities: token.capabilities.clone(),
+            created_at: token.created_at.clone(),
+        })
+        .collect()
+}
+
+fn list_api_tokens(app: &AppHandle) -> Result<Vec<ApiTokenSummary>, String> {
+    with_api_tokens(app, |tokens| Ok((api_token_summaries(tokens), false)))
+}
+
+fn new_api_token_secret() -> String {
+    use rand::Rng;
+    let bytes = rand::thread_rng().gen::<[u8; 32]>();
+    let hex = bytes
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<String>();
+    format!("groove_{hex}")
+}
+
+/// Creates a token and returns the tokens with the new secret.
+fn create_api_token(
+    app: &AppHandle,
+    name: &str,
+    capabilities: Option<&[String]>,
+) -> Result<(Vec<ApiTokenSummary>, String), String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("API token name is required.".to_string());
+    }
+    if name.chars().count() > MAX_API_TOKEN_NAME_CHARS {
+        return Err(format!(
+            "API token name must be at most {MAX_API_TOKEN_NAME_CHARS} characters."
+        ));
+    }
+    let capabilities = match capabilities {
+        Some(capabilities) => capabilities::parse_capabilities(capabilities)?,
+        None => Capability::DEFAULT.to_vec(),
+    };
+    if capabilities.is_empty() {
+        return Err("An API token needs at least one capability.".to_string());
+    }
+
+    let secret = new_api_token_secret();
+    let record = ApiTokenRecord {
+        id: Uuid::new_v4().to_string(),
+        name: name.to_string(),
+        secret_digest: capabilities::api_token_digest(&secret),
+        capabilities: capabilities
+            .iter()
+            .map(|capability| capability.as_str().to_string())
+            .collect(),
+        created_at: now_iso(),
+    };
+    let tokens = with_api_tokens(app, |tokens| {
+        if tokens.len() >= MAX_API_TOKENS {
+            return Err(format!("At most {MAX_API_TOKENS} API tokens can exist."));
+        }
+        tokens.push(record);
+        Ok((api_token_summaries(tokens), true))
+    })?;
+    Ok((tokens, secret))
+}
+
+fn revoke_api_token(app: &AppHandle, id: &str) -> Result<Vec<ApiTokenSummary>, String> {
+    with_api_tokens(app, |tokens| {
+        let before = tokens.len();
+        tokens.retain(|token| token.id != id);
+        if tokens.len() == before {
+            return Err(format!("No API token with id {id}."));
+        }
+        Ok((api_token_summaries(tokens), true))
+    })
+}
+
+/// Capabilities of the caller presenting `bearer`. Capabilities a newer
+/// build wrote and this one does not know are ignored. Until a token exists a
+/// caller without one gets the default capabilities, unless `require_token`
+/// is set: then only a stored token is accepted.
+fn api_token_capabilities(
+    app: &AppHandle,
+    bearer: Option<&str>,
+    require_token: bool,
+) -> Result<Vec<Capability>, String> {
+    with_api_tokens(app, |tokens| {
+        let capabilities = stored_api_token_capabilities(tokens, bearer, require_token)?;
+        Ok((capabilities, false))
+    })
+}
+
+fn stored_api_token_capabilities(
+    tokens: &[ApiTokenRecord],
+    bearer: Option<&str>,
+    require_token: bool,
+) -> Result<Vec<Capability>, String> {
+    match bearer.map(str::trim).filter(|bearer| !bearer.is_empty()) {
+        None if tokens.is_empty() && !require_token => Ok(Capability::DEFAULT.to_vec()),
+        None => Err("An API token is required.".to_string()),
+        Some(bearer) => {
+            let digest = capabilities::api_token_digest(bearer);
+            let token = tokens
+                .iter()
+                .find(|token| token.secret_digest == digest)
+                .ok_or_else(|| "Unknown or revoked API token.".to_string())?;
+            Ok(token
+                .capabilities
+                .iter()
+                .filter_map(|capability| Capability::parse(capability).ok())
+                .collect())
+        }
+    }
+}
@@ -0,0 +1,264 @@
+// Whole-app backups for moving to another machine. `app_backup_create` writes
+// one JSON document holding the `APP_BACKUP_FILES` found in the app data
+// directory plus the `.groove/workspace.json` of every open workspace, which
+// carries worktree notes, tags and testing settings. `app_backup_restore`
+// writes them back, workspace files only into roots that were open or
+// already hold a `.groove` directory; grooves recorded as running on this
+// machine are kept, since the processes behind the backed-up ones do not
+// exist here.
+
+fn app_data_file(app: &AppHandle, file_name: &str) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|error| format!("Failed to resolve app data directory: {error}"))?;
+    fs::create_dir_all(&app_data_dir)
+        .map_err(|error| format!("Failed to create app data directory: {error}"))?;
+    Ok(app_data_dir.join(file_name))
+}
+
+fn read_json_value_file(path: &Path) -> Result<serde_json::Value, String> {
+    let raw = fs::read_to_string(path)
+        .map_err(|error| format!("Failed to read {}: {error}", path.display()))?;
+    serde_json::from_str(&raw)
+        .map_err(|error| format!("Failed to parse {}: {error}", path.display()))
+}
+
+fn build_app_backup_document(app: &AppHandle) -> Result<AppBackupDocument, String> {
+    let mut files = BTreeMap::new();
+    for file_name in APP_BACKUP_FILES {
+        let path = app_data_file(app, file_name)?;
+        if path_is_file(&path) {
+            files.insert(file_name.to_string(), read_json_value_file(&path)?);
+        }
+    }
+
+    let mut workspaces = BTreeMap::new();
+    for record in read_persisted_workspace_state(app)?.open_workspaces {
+        let workspace_json = Path::new(&record.workspace_root)
+            .join(".groove")
+            .join("workspace.json");
+        if path_is_file(&workspace_json) {
+            workspaces.insert(
+                record.workspace_root,
+                read_json_value_file(&workspace_json)?,
+            );
+        }
+    }
+
+    Ok(AppBackupDocument {
+        groove_backup_version: APP_BACKUP_VERSION,
+        created_at: now_iso(),
+        files,
+        workspaces,
+    })
+}
+
+fn parse_app_backup_document(raw: &str) -> Result<AppBackupDocument, String> {
+    let document = serde_json::from_str::<AppBackupDocument>(raw)
+        .map_err(|error| format!("Not a Groove backup: {error}"))?;
+    if document.groove_backup_version != APP_BACKUP_VERSION {
+        return Err(format!(
+            "Unsupported backup version {}; expected {APP_BACKUP_VERSION}.",
+            document.groove_backup_version
+        ));
+    }
+    if let Some(unknown) = document
+        .files
+        .keys()
+        .find(|file_name| !APP_BACKUP_FILES.contains(&file_name.as_str()))
+    {
+        return Err(format!("Backup contains an unknown file: {unknown}."));
+    }
+    Ok(document)
+}
+
+fn run_app_backup_create(
+    app: &AppHandle,
+    payload: &AppBackupCreatePayload,
+) -> Result<AppBackupResponse, String> {
+    let path = resolve_settings_sync_path(&payload.path)?;
+    let document = build_app_backup_document(app)?;
+    let body = serde_json::to_string_pretty(&document)
+        .map_err(|error| format!("Failed to serialize backup: {error}"))?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|error| format!("Failed to create {}: {error}", parent.display()))?;
+    }
+    fs::write(&path, format!("{body}\n"))
+        .map_err(|error| format!("Failed to write {}: {error}", path.display()))?;
+
+    Ok(AppBackupResponse {
+        request_id: String::new(),
+        ok: true,
+        path: Some(path.display().to_string()),
+        files: document.files.into_keys().collect(),
+        workspaces: document.workspaces.into_keys().collect(),
+        skipped_workspaces: Vec::new(),
+        error: None,
+    })
+}
+
+fn restore_worktree_execution_state(
+    app: &AppHandle,
+    value: serde_json::Value,
+) -> Result<(), String> {
+    let mut restored = serde_json::from_value::<PersistedWorktreeExecutionState>(value)
+        .map_err(|error| format!("Backup has an invalid worktree-executions.json: {error}"))?;
+    migrate_workspace_storage_keys(&mut restored);
+    let _guard = lock_worktree_execution_state();
+    let current = read_persisted_worktree_execution_state(app)?;
+    restored.running_by_workspace = current.running_by_workspace;
+    write_persisted_worktree_execution_state(app, &restored)
+}
+
+/// Whether a backed-up `workspace.json` may be written to `root`: a directory
+/// that was open here before the restore or already holds a `.groove`
+/// directory. Any other root a backup names is skipped, so a crafted backup
+/// cannot plant workspace metadata in arbitrary directories.
+fn backup_restore_target_allowed(root: &Path, open_workspace_keys: &HashSet<String>) -> bool {
+    root.is_absolute()
+        && path_is_directory(root)
+        && (path_is_directory(&root.join(".groove"))
+            || open_workspace_keys.contains(&workspace_root_storage_key(root)))
+}
+
+fn run_app_backup_restore(
+    app: &AppHandle,
+    payload: &AppBackupRestorePayload,
+) -> Result<AppBackupResponse, String> {
+    let path = resolve_settings_sync_path(&payload.path)?;
+    if !path_is_file(&path) {
+        return Err(format!("{} does not exist.", path.display()));
+    }
+    let raw = fs::read_to_string(&path)
+        .map_err(|error| format!("Failed to read {}: {error}", path.display()))?;
+    let document = parse_app_backup_document(&raw)?;
+    // Read before `active-workspace.json` is replaced by the backup's.
+    let open_workspace_keys = read_persisted_workspace_state(app)?
+        .open_workspaces
+        .iter()
+        .map(|record| workspace_root_storage_key(Path::new(&record.workspace_root)))
+        .collect::<HashSet<_>>();
+
+    let mut files = Vec::new();
+    for (file_name, value) in document.files {
+        if file_name == "worktree-executions.json" {
+            restore_worktree_execution_state(app, value)?;
+        } else {
+            let target = app_data_file(app, &file_name)?;
+            let body = serde_json::to_string_pretty(&value)
+                .map_err(|error| format!("Failed to serialize {file_name}: {error}"))?;
+            fs::write(&target, format!("{body}\n"))
+                .map_err(|error| format!("Failed to write {}: {error}", target.display()))?;
+        }
+        files.push(file_name);
+    }
+    if files
+        .iter()
+        .any(|file_name| file_name == "global-settings.json")
+    {
+        // Normalizes the restored file and applies it to the in-memory mirrors.
+        let settings = ensure_global_settings(app)?;
+        write_global_settings_file(&global_settings_file(app)?, &settings)?;
+    }
+
+    let mut workspaces = Vec::new();
+    let mut skipped_workspaces = Vec::new();
+    if payload.include_workspaces.unwrap_or(true) {
+        for (workspace_root, value) in document.workspaces {
+            let root = PathBuf::from(&workspace_root);
+            if !backup_restore_target_allowed(&root, &open_workspace_keys) {
+                skipped_workspaces.push(workspace_root);
+                continue;
+            }
+            let workspace_meta =
+                serde_json::from_value::<WorkspaceMeta>(value).map_err(|error| {
+                    format!("Backup has an invalid workspace.json for {workspace_root}: {error}")
+                })?;
+            let groove_dir = root.join(".groove");
+            fs::create_dir_all(&groove_dir)
+                .map_err(|error| format!("Failed to create {}: {error}", groove_dir.display()))?;
+            write_workspace_meta_file(&groove_dir.join("workspace.json"), &workspace_meta)?;
+            invalidate_workspace_caches(app, &root);
+            workspaces.push(workspace_root);
+        }
+    }
+
+    Ok(AppBackupResponse {
+        request_id: String::new(),
+        ok: true,
+        path: Some(path.display().to_string()),
+        files,
+        workspaces,
+        skipped_workspaces,
+        error: None,
+    })
+}
+
+#[cfg(test)]
+mod backup_runtime_tests {
+    use super::*;
+
+    fn backup_json(version: u32, file_name: &str) -> String {
+        serde_json::json!({
+            "grooveBackupVersion": version,
+            "createdAt": "2026-01-01T00:00:00Z",
+            "files": { file_name: {} },
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn parse_app_backup_document_accepts_current_version() {
+        let document =
+            parse_app_backup_document(&backup_json(APP_BACKUP_VERSION, "global-settings.json"))
+                .expect("current version parses");
+        assert!(document.files.contains_key("global-settings.json"));
+        assert!(document.workspaces.is_empty());
+    }
+
+    #[test]
+    fn parse_app_backup_document_refuses_other_versions_and_unknown_files() {
+        let error =
+            parse_app_backup_document(&backup_json(APP_BACKUP_VERSION + 1, "global-settings.json"))
+                .expect_err("newer version is refused");
+        assert!(error.contains("Unsupported backup version"));
+
+        let error = parse_app_backup_document(&backup_json(APP_BACKUP_VERSION, API_TOKENS_FILE))
+            .expect_err("files outside APP_BACKUP_FILES are refused");
+        assert!(error.contains(API_TOKENS_FILE));
+
+        for local_only in [WORKSPACE_TRUST_FILE, CUSTOM_COMMAND_APPROVALS_FILE] {
+            let error = parse_app_backup_document(&backup_json(APP_BACKUP_VERSION, local_only))
+                .expect_err("trust and approvals are never restored");
+            assert!(error.contains(local_only));
+        }
+    }
+
+    #[test]
+    fn workspace_files_are_restored_only_into_open_or_groove_roots() {
+        let base = std::env::temp_dir().join(format!("groove-backup-roots-{}", Uuid::new_v4()));
+        let known = base.join("known");
+        let open = base.join("open");
+        let other = base.join("other");
+        fs::create_dir_all(known.join(".groove")).expect("create known workspace");
+        fs::create_dir_all(&open).expect("create open workspace");
+        fs::create_dir_all(&other).expect("create other directory");
+        let open_workspace_keys = HashSet::from([workspace_root_storage_key(&open)]);
+
+        assert!(backup_restore_target_allowed(&known, &open_workspace_keys));
+        assert!(backup_restore_target_allowed(&open, &open_workspace_keys));
+        assert!(!backup_restore_target_allowed(&other, &open_workspace_keys));
+        assert!(!backup_restore_target_allowed(
+            &base.join("missing"),
+            &open_workspace_keys
+        ));
+        assert!(!backup_restore_target_allowed(
+            Path::new("relative"),
+            &open_workspace_keys
+        ));
+
+        let _ = fs::remove_dir_all(&base);
+    }
+}
@@ -0,0 +1,175 @@
+/// Locks `mutex`, recovering from poisoning instead of surfacing an error.
+///
+/// A poisoned lock means a thread panicked while holding it, so the guarded
+/// value may be half-updated. `recover` gets a chance to repair it (and returns
+/// a short description of what it did) before the poison flag is cleared and
+/// a `StateRecoveredEvent` is emitted.
+fn lock_or_recover<'a, T>(
+    mutex: &'a Mutex<T>,
+    state_name: &str,
+    recover: impl FnOnce(&mut T) -> String,
+) -> MutexGuard<'a, T> {
+    match mutex.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => {
+            let mut guard = poisoned.into_inner();
+            let detail = recover(&mut guard);
+            mutex.clear_poison();
+            report_state_recovered(state_name, &detail);
+            guard
+        }
+    }
+}
+
+/// Poisoned locks recovered since the app started.
+static STATE_LOCK_RECOVERIES: AtomicU64 = AtomicU64::new(0);
+
+fn report_state_recovered(state_name: &str, detail: &str) {
+    STATE_LOCK_RECOVERIES.fetch_add(1, Ordering::Relaxed);
+    log_line(&format!(
+        "[state-recovery] recovered poisoned {state_name} lock: {detail}"
+    ));
+    emit_background_groove_event(&StateRecoveredEvent {
+        state: state_name.to_string(),
+        detail: detail.to_string(),
+        recovered_at: now_iso(),
+    });
+}
+
+/// Live PTYs cannot be rebuilt from disk, so recovery keeps the sessions that
+/// are still running or hibernated, drops the ones whose child already exited,
+/// and rebuilds the per-worktree index from what is left.
+fn recover_groove_terminal_sessions_state(sessions_state: &mut GrooveTerminalSessionsState) -> String {
+    let count_before = sessions_state.sessions_by_id.len();
+    sessions_state
+        .sessions_by_id
+        .retain(|_, session| {
+            session
+                .pty
+                .as_mut()
+                .is_none_or(|pty| !matches!(pty.child.try_wait(), Ok(Some(_))))
+        });
+
+    let mut sessions = sessions_state
+        .sessions_by_id
+        .values()
+        .map(|session| {
+            (
+                session.worktree_key.clone(),
+                session.started_at.clone(),
+                session.session_id.clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+    sessions.sort();
+    sessions_state.session_ids_by_worktree.clear();
+    for (worktree_key, _, session_id) in sessions {
+        sessions_state
+            .session_ids_by_worktree
+            .entry(worktree_key)
+            .or_default()
+            .push(session_id);
+    }
+
+    format!(
+        "kept={} dropped_exited={}",
+        sessions_state.sessions_by_id.len(),
+        count_before - sessions_state.sessions_by_id.len()
+    )
+}
+
+impl GrooveTerminalState {
+    fn lock_sessions(&self) -> MutexGuard<'_, GrooveTerminalSessionsState> {
+        lock_or_recover(
+            &self.inner,
+            "grooveTerminalSessions",
+            recover_groove_terminal_sessions_state,
+        )
+    }
+}
+
+/// Cache entries are derived from files on disk, so recovery empties the cache
+/// and lets the next lookup rebuild it.
+fn recover_by_clearing<K, V>(entries: &mut HashMap<K, V>) -> String {
+    let cleared = entries.len();
+    entries.clear();
+    format!("cleared={cleared}")
+}
+
+impl WorkspaceContextCacheState {
+    fn lock_entries(&self) -> MutexGuard<'_, HashMap<String, WorkspaceContextCacheEntry>> {
+        lock_or_recover(&self.entries, "workspaceContextCache", recover_by_clearing)
+    }
+}
+
+impl TerminalResolutionCacheState {
+    fn lock_entries(&self) -> MutexGuard<'_, HashMap<String, TerminalResolutionCacheEntry>> {
+        lock_or_recover(&self.entries, "terminalResolutionCache", recover_by_clearing)
+    }
+}
+
+impl GrooveListCacheState {
+    fn lock_entries(&self) -> MutexGuard<'_, HashMap<String, GrooveListCacheEntry>> {
+        lock_or_recover(&self.entries, "grooveListCache", recover_by_clearing)
+    }
+
+    fn lock_in_flight(&self) -> MutexGuard<'_, HashMap<String, Arc<GrooveListInFlight>>> {
+        lock_or_recover(&self.in_flight, "grooveListInFlight", recover_by_clearing)
+    }
+
+    fn lock_restored_from_disk(&self) -> MutexGuard<'_, HashSet<String>> {
+        lock_or_recover(&self.restored_from_disk, "grooveListRestoredFromDisk", |keys| {
+            format!("kept={}", keys.len())
+        })
+    }
+}
+
+impl ActionHistoryState {
+    /// Reloaded from `action-history.json` on next use when reset.
+    fn lock_entries(&self) -> MutexGuard<'_, Option<Vec<ActionHistoryEntry>>> {
+        lock_or_recover(&self.entries, "actionHistory", |entries| {
+            *entries = None;
+            "reset=true".to_string()
+        })
+    }
+}
+
+impl ApiTokensState {
+    /// Reloaded from `api-tokens.json` on next use when reset.
+    fn lock_tokens(&self) -> MutexGuard<'_, Option<Vec<ApiTokenRecord>>> {
+        lock_or_recover(&self.tokens, "apiTokens", |tokens| {
+            *tokens = None;
+            "reset=true".to_string()
+        })
+    }
+}
+
+impl UndoState {
+    fn lock_entries(&self) -> MutexGuard<'_, Vec<UndoRecord>> {
+        lock_or_recover(&self.entries, "undoStack", |entries| {
+            let cleared = entries.len();
+            entries.clear();
+            format!("cleared={cleared}")
+        })
+    }
+}
+
+impl CommandRunsState {
+    fn lock_runs(&self) -> MutexGuard<'_, Vec<CommandRun>> {
+        lock_or_recover(&self.runs, "commandRuns", |runs| {
+            let cleared = runs.len();
+            runs.clear();
+            format!("cleared={cleared}")
+        })
+    }
+}
+
+impl GrooveBinStatusState {
+    /// The status is re-evaluated from the environment on demand when unset.
+    fn lock_status(&self) -> MutexGuard<'_, Option<GrooveBinCheckStatus>> {
+        lock_or_recover(&self.status, "grooveBinStatus", |status| {
+            *status = None;
+            "reset=true".to_string()
+        })
+    }
+}
@@ -0,0 +1,193 @@
+// Captured runs of custom commands: output, exit code and duration of the
+// last few runs per worktree, so "did the last deploy succeed" can be answered
+// without running it again. Runs are kept in memory only; the output of each
+// is trimmed to its tail.
+
+/// Keeps at most `COMMAND_RUNS_MAX_PER_WORKTREE` runs for the worktree of
+/// `run`, dropping its oldest ones. Runs of other worktrees are untouched.
+fn push_command_run(runs: &mut Vec<CommandRun>, run: CommandRun) {
+    runs.insert(0, run);
+    let (workspace_root, worktree) = (runs[0].workspace_root.clone(), runs[0].worktree.clone());
+    let mut kept = 0;
+    runs.retain(|run| {
+        if run.workspace_root != workspace_root || run.worktree != worktree {
+            return true;
+        }
+        kept += 1;
+        kept <= COMMAND_RUNS_MAX_PER_WORKTREE
+    });
+}
+
+/// The last `max_chars` characters of `output`, and whether anything was cut.
+fn command_run_output_tail(output: &str, max_chars: usize) -> (String, bool) {
+    let total = output.chars().count();
+    if total <= max_chars {
+        return (output.to_string(), false);
+    }
+    (output.chars().skip(total - max_chars).collect(), true)
+}
+
+fn finish_command_run(run: &mut CommandRun, result: CommandResult, elapsed: Duration) {
+    let (stdout, stdout_truncated) =
+        command_run_output_tail(&result.stdout, COMMAND_RUN_MAX_OUTPUT_CHARS);
+    let (stderr, stderr_truncated) =
+        command_run_output_tail(&result.stderr, COMMAND_RUN_MAX_OUTPUT_CHARS);
+    run.status = if result.error.is_none() && result.exit_code == Some(0) {
+        CommandRunStatus::Succeeded
+    } else {
+        CommandRunStatus::Failed
+    };
+    run.exit_code = result.exit_code;
+    run.stdout = stdout;
+    run.stderr = stderr;
+    run.output_truncated = stdout_truncated || stderr_truncated;
+    run.error = result.error;
+    run.finished_at = Some(now_iso());
+    run.duration_ms = Some(elapsed.as_millis().try_into().unwrap_or(u64::MAX));
+}
+
+/// Runs `command` to completion, reading both pipes on their own threads so a
+/// chatty command cannot fill a pipe and stall. Killed after `timeout`.
+fn run_captured_command(mut command: Command, timeout: Duration) -> CommandResult {
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let mut child = match command.spawn() {
+        Ok(child) => child,
+        Err(error) => {
+            return CommandResult {
+                exit_code: None,
+                stdout: String::new(),
+                stderr: String::new(),
+                error: Some(format!("Failed to run command: {error}")),
+            }
+        }
+    };
+
+    let stdout = child.stdout.take().map(read_pipe_in_background);
+    let stderr = child.stderr.take().map(read_pipe_in_background);
+
+    let started = Instant::now();
+    let (exit_code, error) = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break (status.code(), None),
+            Ok(None) if started.elapsed() >= timeout => {
+                let _ = child.kill();
+                let status = child.wait().ok();
+                break (
+                    status.and_then(|status| status.code()),
+                    Some(format!(
+                        "Command timed out after {} seconds and was terminated.",
+                        timeout.as_secs()
+                    )),
+                );
+            }
+            Ok(None) => thread::sleep(COMMAND_TIMEOUT_POLL_INTERVAL),
+            Err(error) => {
+                break (
+                    None,
+                    Some(format!("Failed while waiting for command: {error}")),
+                )
+            }
+        }
+    };
+
+    let join = |reader: Option<JoinHandle<Vec<u8>>>| {
+        let output = reader
+            .and_then(|reader| reader.join().ok())
+            .unwrap_or_default();
+        String::from_utf8_lossy(&output).to_string()
+    };
+    CommandResult {
+        exit_code,
+        stdout: join(stdout),
+        stderr: join(stderr),
+        error,
+    }
+}
+
+/// Records `run` as running and finishes it on a background thread; the
+/// caller returns without waiting for the command.
+fn start_captured_command_run(app: &AppHandle, run: CommandRun, command: Command) {
+    let Some(state) = app.try_state::<CommandRunsState>() else {
+        return;
+    };
+    let run_id = run.id.clone();
+    push_command_run(&mut state.lock_runs(), run);
+
+    let app = app.clone();
+    thread::spawn(move || {
+        let started = Instant::now();
+        let result = run_captured_command(command, COMMAND_RUN_TIMEOUT);
+        let elapsed = started.elapsed();
+        let Some(state) = app.try_state::<CommandRunsState>() else {
+            return;
+        };
+        let mut runs = state.lock_runs();
+        // Gone when newer runs of the worktree pushed it out meanwhile.
+        if let Some(run) = runs.iter_mut().find(|run| run.id == run_id) {
+            finish_command_run(run, result, elapsed);
+        }
+    });
+}
+
+#[cfg(test)]
+mod runs_runtime_tests {
+    use super::*;
+
+    fn run(id: &str, worktree: &str) -> CommandRun {
+        CommandRun {
+            id: id.to_string(),
+            command_id: "deploy".to_string(),
+            label: "Deploy preview".to_string(),
+            workspace_root: "/repo".to_string(),
+            worktree: worktree.to_string(),
+            command: "./deploy.sh".to_string(),
+            status: CommandRunStatus::Running,
+            exit_code: None,
+            stdout: String::new(),
+            stderr: String::new(),
+            output_truncated: false,
+            error: None,
+            started_at: "2026-01-01T00:00:00Z".to_string(),
+            finished_at: None,
+            duration_ms: None,
+        }
+    }
+
+    #[test]
+    fn bounds_command_runs_per_worktree() {
+        let mut runs = vec![run("other", "docs")];
+        for index in 0..COMMAND_RUNS_MAX_PER_WORKTREE + 2 {
+            push_command_run(&mut runs, run(&index.to_string(), "login"));
+        }
+        let login = runs.iter().filter(|run| run.worktree == "login").count();
+        assert_eq!(login, COMMAND_RUNS_MAX_PER_WORKTREE);
+        assert_eq!(runs[0].id, (COMMAND_RUNS_MAX_PER_WORKTREE + 1).to_string());
+        assert!(runs.iter().any(|run| run.id == "other"));
+    }
+
+    #[test]
+    fn finishes_runs_with_the_output_tail() {
+        let mut finished = run("a", "login");
+        finish_command_run(
+            &mut finished,
+            CommandResult {
+                exit_code: Some(1),
+                stdout: "x".repeat(COMMAND_RUN_MAX_OUTPUT_CHARS + 5),
+                stderr: "failed".to_string(),
+                error: None,
+            },
+            Duration::from_millis(1500),
+        );
+        assert_eq!(finished.status, CommandRunStatus::Failed);
+        assert_eq!(finished.stdout.len(), COMMAND_RUN_MAX_OUTPUT_CHARS);
+        assert!(finished.output_truncated);
+        assert_eq!(finished.duration_ms, Some(1500));
+        assert_eq!(
+            command_run_output_tail("héllo", 3),
+            ("llo".to_string(), true)
+        );
+    }
+}
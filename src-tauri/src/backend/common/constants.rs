@@ -1,14 +1,58 @@
 const MAX_DISCOVERY_DEPTH: usize = 4;
 const MAX_DISCOVERY_DIRECTORIES: usize = 2500;
 const COMMAND_TIMEOUT_POLL_INTERVAL: Duration = Duration::from_millis(50);
-const WORKSPACE_EVENTS_POLL_INTERVAL: Duration = Duration::from_millis(1800);
+const DEFAULT_WORKSPACE_EVENTS_POLL_INTERVAL_MS: u64 = 1800;
+const MIN_WORKSPACE_EVENTS_POLL_INTERVAL_MS: u64 = 500;
+const MAX_WORKSPACE_EVENTS_POLL_INTERVAL_MS: u64 = 60_000;
 const WORKSPACE_EVENTS_MIN_EMIT_INTERVAL: Duration = Duration::from_millis(1200);
 const WORKSPACE_EVENTS_STOP_POLL_INTERVAL: Duration = Duration::from_millis(100);
+const DEFAULT_WORKTREE_WATCH_POLL_INTERVAL_MS: u64 = 400;
+const MIN_WORKTREE_WATCH_POLL_INTERVAL_MS: u64 = 100;
+const MAX_WORKTREE_WATCH_POLL_INTERVAL_MS: u64 = 10_000;
+/// Longest time the watched worktree goes without `git status`; edits to
+/// tracked files do not touch the index, so they only show up this way.
+const WORKTREE_WATCH_GIT_REFRESH_INTERVAL: Duration = Duration::from_millis(1500);
 const GROOVE_LIST_CACHE_TTL: Duration = Duration::from_secs(45);
 const GROOVE_LIST_CACHE_STALE_TTL: Duration = Duration::from_secs(50);
+const GROOVE_LIST_CACHE_FILE: &str = "groove-list-cache.json";
+/// Bump when the persisted rows or their signatures change shape; files with
+/// another version are ignored.
+const GROOVE_LIST_CACHE_FILE_VERSION: u32 = 1;
+const GROOVE_LIST_CACHE_FILE_MAX_WORKSPACES: usize = 32;
+/// Worktrees processed at once by the `groove_bulk_*` commands.
+const GROOVE_BULK_MAX_CONCURRENCY: usize = 4;
+const GROOVE_BULK_MAX_WORKTREES: usize = 100;
+/// What `groove_rm` checks before removing a worktree, each needing to be
+/// acknowledged while the worktree has findings in it.
+const GROOVE_RM_SAFETY_CATEGORIES: [&str; 4] = [
+    "uncommittedChanges",
+    "unpushedCommits",
+    "runningProcesses",
+    "terminalSessions",
+];
+/// Verbs accepted by `worktree_action`.
+const WORKTREE_ACTIONS: [&str; 8] = [
+    "play",
+    "openTerminal",
+    "openEditor",
+    "push",
+    "pull",
+    "stop",
+    "remove",
+    "setTestingTarget",
+];
+/// Editor `worktree_action` opens when no `command` option is given;
+/// `{worktree}` is appended when the command does not mention it.
+const DEFAULT_WORKTREE_EDITOR_COMMAND: &str = "code";
+/// Under `<workspace>/.groove`, one tarball and one metadata file per
+/// archived worktree.
+const GROOVE_ARCHIVES_DIR: &str = "archives";
+/// Bump when the layout of an archive tarball changes.
+const GROOVE_ARCHIVE_VERSION: u32 = 1;
+const GROOVE_ARCHIVE_TAR_TIMEOUT: Duration = Duration::from_secs(300);
 const DEFAULT_WORKTREE_SYMLINK_PATHS: [&str; 3] = [".env", ".env.local", "node_modules"];
-const SUPPORTED_DEFAULT_TERMINALS: [&str; 9] = [
-    "auto", "ghostty", "warp", "kitty", "alacritty", "gnome", "xterm", "none", "custom",
+const SUPPORTED_DEFAULT_TERMINALS: [&str; 10] = [
+    "auto", "ghostty", "warp", "kitty", "alacritty", "gnome", "xterm", "zellij", "none", "custom",
 ];
 const SUPPORTED_THEME_MODES: [&str; 10] = [
     "light",
@@ -27,13 +71,249 @@ const GITIGNORE_REQUIRED_ENTRIES: [&str; 2] = [".groove/", ".worktrees/"];
 const GROOVE_PLAY_COMMAND_SENTINEL: &str = "__groove_terminal__";
 const GROOVE_PLAY_CLAUDE_CODE_COMMAND_SENTINEL: &str = "__groove_terminal_claude__";
 const GROOVE_OPEN_TERMINAL_COMMAND_SENTINEL: &str = "__groove_terminal_open__";
+/// Play or open-terminal command that opens the worktree in a tmux window.
+const GROOVE_TMUX_COMMAND_SENTINEL: &str = "__groove_tmux__";
+/// A play command of `preset:<name>` refers to one of these by name, so the
+/// stored value keeps tracking the preset if its command line changes.
+const PLAY_GROOVE_COMMAND_PRESET_PREFIX: &str = "preset:";
+/// `(name, command)` pairs for `PLAY_GROOVE_COMMAND_PRESET_PREFIX`.
+const PLAY_GROOVE_COMMAND_PRESETS: [(&str, &str); 5] = [
+    ("groove-opencode", GROOVE_PLAY_COMMAND_SENTINEL),
+    ("groove-claude-code", GROOVE_PLAY_CLAUDE_CODE_COMMAND_SENTINEL),
+    (
+        "ghostty-opencode",
+        "ghostty --working-directory={worktree} -e opencode",
+    ),
+    ("kitty-opencode", "kitty --directory {worktree} opencode"),
+    ("tmux-window", GROOVE_TMUX_COMMAND_SENTINEL),
+];
+/// `{name}` placeholders a Play command template may use.
+const PLAY_GROOVE_COMMAND_PLACEHOLDERS: [&str; 4] =
+    ["worktree", "worktree_escaped", "target", "branch"];
+/// Worktree `play_command_preview` resolves for when none is given.
+const PLAY_COMMAND_PREVIEW_SAMPLE_WORKTREE: &str = "feature-preview";
+/// Commit message used when neither the caller nor the workspace commit
+/// template provides one.
+const DEFAULT_COMMIT_MESSAGE: &str = "chore: update files";
+/// Message file handed to `commit-msg` hooks, relative to the git dir.
+const GROOVE_COMMIT_MESSAGE_FILE: &str = "GROOVE_COMMIT_EDITMSG";
+/// Lines of hook output kept in commit responses; a failing linter can print
+/// thousands.
+const GIT_HOOK_OUTPUT_MAX_LINES: usize = 200;
+/// Unpushed commits `git_push_preflight` checks for a DCO sign-off.
+const DCO_PREFLIGHT_MAX_COMMITS: &str = "200";
+/// Commits `gh_pr_prepare` lists in a pull request body.
+const GH_PR_PREPARE_MAX_COMMITS: &str = "100";
+/// Config of the DCO GitHub app; its presence means the repository
+/// enforces sign-offs.
+const DCO_CONFIG_FILE: &str = ".github/dco.yml";
+/// Upper bound for `GitDiffFilePayload.context_lines`.
+const GIT_DIFF_MAX_CONTEXT_LINES: u32 = 1000;
+/// Sides of a conflicted file larger than this are listed without content.
+const GIT_CONFLICT_CONTENT_MAX_BYTES: usize = 1024 * 1024;
+const GIT_LOG_PAGE_DEFAULT_LIMIT: usize = 50;
+const GIT_LOG_PAGE_MAX_LIMIT: usize = 500;
+const WORKTREE_SEARCH_DEFAULT_LIMIT: usize = 20;
+const WORKTREE_SEARCH_MAX_LIMIT: usize = 200;
+/// From this many worktrees on, the scan checks their `.groove` directories
+/// on several threads.
+const WORKSPACE_SCAN_PARALLEL_MIN_WORKTREES: usize = 100;
+const WORKSPACE_WORKTREES_PAGE_DEFAULT_LIMIT: usize = 100;
+const WORKSPACE_WORKTREES_PAGE_MAX_LIMIT: usize = 500;
+/// Items listed per category of `workspace_summary`.
+const WORKSPACE_SUMMARY_TOP_ITEMS: usize = 3;
+const MAX_WORKTREE_NOTE_CHARS: usize = 500;
+const MAX_WORKTREE_TAGS: usize = 16;
+const MAX_WORKTREE_TAG_CHARS: usize = 40;
+const ACTION_HISTORY_FILE: &str = "action-history.json";
+const ACTION_HISTORY_MAX_ENTRIES: usize = 50;
+const UNDO_STACK_MAX_ENTRIES: usize = 20;
+const API_TOKENS_FILE: &str = "api-tokens.json";
+const WORKSPACE_TRUST_FILE: &str = "workspace-trust.json";
+const CUSTOM_COMMAND_APPROVALS_FILE: &str = "custom-command-approvals.json";
+/// Git config for every invocation in a workspace that is not trusted: no
+/// hooks (the path has no hook files on any platform) and no fsmonitor
+/// command.
+const UNTRUSTED_WORKSPACE_GIT_CONFIG: [(&str, &str); 2] =
+    [("core.hooksPath", "/dev/null"), ("core.fsmonitor", "false")];
+const MAX_API_TOKENS: usize = 32;
+const MAX_API_TOKEN_NAME_CHARS: usize = 60;
+const MAX_WORKSPACE_CUSTOM_COMMANDS: usize = 32;
+const MAX_CUSTOM_COMMAND_LABEL_CHARS: usize = 60;
+const COMMAND_RUNS_MAX_PER_WORKTREE: usize = 10;
+/// Per stream; longer output keeps its tail.
+const COMMAND_RUN_MAX_OUTPUT_CHARS: usize = 16_000;
+const COMMAND_RUN_TIMEOUT: Duration = Duration::from_secs(30 * 60);
 /// Pseudo-worktree name for terminal sessions rooted at the workspace root
 /// itself (e.g. the Intelligence page) instead of a `.worktrees/` entry.
 const GROOVE_WORKSPACE_TERMINAL_WORKTREE: &str = "__workspace__";
-const GROOVE_TERMINAL_OUTPUT_EVENT: &str = "groove-terminal-output";
-const GROOVE_TERMINAL_LIFECYCLE_EVENT: &str = "groove-terminal-lifecycle";
 const DEFAULT_GROOVE_TERMINAL_COLS: u16 = 120;
 const DEFAULT_GROOVE_TERMINAL_ROWS: u16 = 34;
 const MIN_GROOVE_TERMINAL_DIMENSION: u16 = 10;
 const MAX_GROOVE_TERMINAL_DIMENSION: u16 = 500;
-const MAX_GROOVE_TERMINAL_SNAPSHOT_BYTES: usize = 256 * 1024;
+/// Default scrollback kept per terminal session.
+const DEFAULT_GROOVE_TERMINAL_SNAPSHOT_BYTES: usize = 256 * 1024;
+const MIN_GROOVE_TERMINAL_SNAPSHOT_BYTES: usize = 16 * 1024;
+const MAX_GROOVE_TERMINAL_SNAPSHOT_BYTES: usize = 4 * 1024 * 1024;
+/// Default scrollback kept across all terminal sessions.
+const DEFAULT_GROOVE_TERMINAL_SNAPSHOT_BUDGET_BYTES: usize = 8 * 1024 * 1024;
+const MAX_GROOVE_TERMINAL_SNAPSHOT_BUDGET_BYTES: usize = 256 * 1024 * 1024;
+const DEFAULT_GROOVE_TERMINAL_SCROLLBACK_FILE_BYTES: usize = 16 * 1024 * 1024;
+const MIN_GROOVE_TERMINAL_SCROLLBACK_FILE_BYTES: usize = 1024 * 1024;
+const MAX_GROOVE_TERMINAL_SCROLLBACK_FILE_BYTES: usize = 64 * 1024 * 1024;
+const GROOVE_TERMINAL_SCROLLBACK_DIR: &str = "terminal-scrollback";
+const DEFAULT_GROOVE_TERMINAL_SCROLLBACK_FETCH_BYTES: usize = 64 * 1024;
+const MAX_GROOVE_TERMINAL_SCROLLBACK_FETCH_BYTES: usize = 1024 * 1024;
+/// Under `<workspace>/.groove`, one directory per worktree.
+const GROOVE_TERMINAL_HISTORY_DIR: &str = "terminal-history";
+/// History files kept per worktree; the oldest go first.
+const MAX_GROOVE_TERMINAL_HISTORY_FILES: usize = 20;
+const DEFAULT_GROOVE_TERMINAL_HISTORY_FETCH_BYTES: usize = 256 * 1024;
+const MAX_GROOVE_TERMINAL_HISTORY_FETCH_BYTES: usize = 4 * 1024 * 1024;
+const DEFAULT_GROOVE_TERMINAL_HIBERNATE_IDLE_MINUTES: u64 = 30;
+const MIN_GROOVE_TERMINAL_HIBERNATE_IDLE_MINUTES: u64 = 1;
+const MAX_GROOVE_TERMINAL_HIBERNATE_IDLE_MINUTES: u64 = 24 * 60;
+/// Disk kept for terminal history per workspace, and for scrollback files.
+const DEFAULT_GROOVE_TERMINAL_STORAGE_QUOTA_BYTES: u64 = 512 * 1024 * 1024;
+const MIN_GROOVE_TERMINAL_STORAGE_QUOTA_BYTES: u64 = 16 * 1024 * 1024;
+const MAX_GROOVE_TERMINAL_STORAGE_QUOTA_BYTES: u64 = 64 * 1024 * 1024 * 1024;
+const DEFAULT_GROOVE_TERMINAL_STORAGE_MAX_AGE_DAYS: u64 = 30;
+const MIN_GROOVE_TERMINAL_STORAGE_MAX_AGE_DAYS: u64 = 1;
+const MAX_GROOVE_TERMINAL_STORAGE_MAX_AGE_DAYS: u64 = 365;
+const GROOVE_TERMINAL_STORAGE_JANITOR_INTERVAL: Duration = Duration::from_secs(10 * 60);
+/// Output chunks (at most 4 KiB each) a terminal reader may queue ahead of
+/// the event flusher before it waits.
+const GROOVE_TERMINAL_OUTPUT_CHANNEL_CAPACITY: usize = 256;
+const GROOVE_TERMINAL_REAPER_INTERVAL: Duration = Duration::from_secs(5);
+/// A workspace worker that has not started a poll pass for this long is
+/// reported as stalled by `app_health`.
+const APP_HEALTH_WORKER_STALL_AFTER: Duration = Duration::from_secs(60);
+const SYSTEM_METRICS_SAMPLE_INTERVAL: Duration = Duration::from_secs(30);
+const BACKEND_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+const DEFAULT_SYSTEM_ALERT_RAM_PERCENT: u8 = 90;
+const DEFAULT_SYSTEM_ALERT_DISK_PERCENT: u8 = 95;
+const MIN_SYSTEM_ALERT_PERCENT: u8 = 50;
+const MAX_SYSTEM_ALERT_PERCENT: u8 = 99;
+const SETTINGS_PROFILES_FILE: &str = "settings-profiles.json";
+const MAX_SETTINGS_PROFILE_NAME_CHARS: usize = 64;
+/// Bump when `ExportedSettingsProfile` changes shape; other versions are
+/// refused on import.
+const SETTINGS_PROFILE_EXPORT_VERSION: u32 = 1;
+/// Upper bound for the day counts of a worktree staleness policy.
+const MAX_WORKTREE_STALENESS_DAYS: u32 = 3650;
+const WORKTREE_STALENESS_ACTIONS: [&str; 3] = ["report", "archive", "prompt"];
+/// Under `<workspace>/.groove`; present while the workspace is frozen.
+const WORKSPACE_FROZEN_STATE_FILE: &str = "frozen.json";
+/// Under `<workspace>/.groove`, holding the tarballs of a frozen workspace.
+const WORKSPACE_FROZEN_ARCHIVE_DIR: &str = "frozen";
+/// Under `<workspace>/.groove`; the merge train `merge_train_resume` picks up.
+const MERGE_TRAIN_STATE_FILE: &str = "merge-train.json";
+/// Merge strategies a merge train accepts, as `gh pr merge` flags.
+const MERGE_TRAIN_STRATEGIES: [&str; 3] = ["merge", "squash", "rebase"];
+/// In the app data directory; maintenance schedules and their last runs.
+const MAINTENANCE_STATE_FILE: &str = "maintenance.json";
+/// Tasks the maintenance scheduler and `maintenance_run_now` run, in the
+/// order they run in when all are due.
+const MAINTENANCE_TASKS: [&str; 4] = [
+    "fetchPrune",
+    "staleBranchReport",
+    "cleanup",
+    "conflictMatrix",
+];
+const MAINTENANCE_SCHEDULER_INTERVAL: Duration = Duration::from_secs(60);
+/// UTC-12 to UTC+14.
+const MIN_MAINTENANCE_UTC_OFFSET_MINUTES: i32 = -12 * 60;
+const MAX_MAINTENANCE_UTC_OFFSET_MINUTES: i32 = 14 * 60;
+const TESTING_ENVIRONMENT_SUPERVISOR_INTERVAL: Duration = Duration::from_secs(1);
+const DEFAULT_TESTING_ENVIRONMENT_MAX_RESTARTS: u32 = 3;
+const MAX_TESTING_ENVIRONMENT_MAX_RESTARTS: u32 = 20;
+const DEFAULT_TESTING_ENVIRONMENT_RESTART_BACKOFF_MS: u64 = 2_000;
+const MIN_TESTING_ENVIRONMENT_RESTART_BACKOFF_MS: u64 = 250;
+const MAX_TESTING_ENVIRONMENT_RESTART_BACKOFF_MS: u64 = 60_000;
+/// Longest wait before a restart however many came before it.
+const MAX_TESTING_ENVIRONMENT_RESTART_DELAY: Duration = Duration::from_secs(5 * 60);
+/// A restarted testing environment that stays up this long starts its
+/// restart count over when it next crashes.
+const TESTING_ENVIRONMENT_STABLE_AFTER: Duration = Duration::from_secs(60);
+/// Crashes kept per testing environment, oldest dropped first.
+const TESTING_ENVIRONMENT_CRASH_HISTORY: usize = 10;
+/// Under `<workspace>/.groove`, one directory per worktree.
+const TESTING_LOGS_DIR: &str = "testing-logs";
+const TESTING_LOG_FILE: &str = "play.log";
+/// Size at which `play.log` is rotated to `play.log.1`.
+const TESTING_LOG_ROTATE_BYTES: u64 = 4 * 1024 * 1024;
+/// Rotated files kept per worktree; the oldest go first.
+const TESTING_LOG_ROTATIONS: usize = 3;
+const DEFAULT_TESTING_LOG_TAIL_LINES: usize = 200;
+const MAX_TESTING_LOG_TAIL_LINES: usize = 5_000;
+const DEFAULT_STATUS_PAGE_PORT: u16 = 4924;
+/// Lowest port the status page may use; the ones below need privileges on
+/// most systems.
+const MIN_STATUS_PAGE_PORT: u16 = 1024;
+/// Seconds between reloads of the HTML status page.
+const STATUS_PAGE_REFRESH_SECONDS: u32 = 10;
+const DEFAULT_COMMAND_RETRY_MAX_ATTEMPTS: u32 = 3;
+const MAX_COMMAND_RETRY_MAX_ATTEMPTS: u32 = 6;
+const DEFAULT_COMMAND_RETRY_BACKOFF_MS: u64 = 1_000;
+const MIN_COMMAND_RETRY_BACKOFF_MS: u64 = 100;
+const DEFAULT_COMMAND_RETRY_MAX_BACKOFF_MS: u64 = 8_000;
+const MAX_COMMAND_RETRY_MAX_BACKOFF_MS: u64 = 60_000;
+/// Directories under `<workspace>/.groove` packed by `workspace_freeze`.
+const WORKSPACE_FREEZE_ARCHIVED_DIRS: [&str; 1] = [GROOVE_TERMINAL_HISTORY_DIR];
+const WORKSPACE_FREEZE_TAR_TIMEOUT: Duration = Duration::from_secs(120);
+const SETTINGS_SYNC_FILE: &str = "settings-sync.json";
+/// File holding the sync document in a gist target.
+const SETTINGS_SYNC_GIST_FILE_NAME: &str = "groove-settings.json";
+const SETTINGS_SYNC_VERSION: u32 = 1;
+const SETTINGS_SYNC_GH_TIMEOUT: Duration = Duration::from_secs(30);
+/// Bump when `AppBackupDocument` changes shape; other versions are refused
+/// by `app_backup_restore`.
+const APP_BACKUP_VERSION: u32 = 1;
+/// App data files bundled by `app_backup_create`. API tokens, workspace trust,
+/// custom command approvals, caches, scrollback and sounds stay on this
+/// machine: trust is granted where the code runs, never carried in a file.
+const APP_BACKUP_FILES: [&str; 7] = [
+    "active-workspace.json",
+    "global-settings.json",
+    "worktree-executions.json",
+    ACTION_HISTORY_FILE,
+    SETTINGS_PROFILES_FILE,
+    SETTINGS_SYNC_FILE,
+    MAINTENANCE_STATE_FILE,
+];
+/// Global settings that stay on this machine: sound library entries name
+/// files in the app data directory, and whether the status page listens is
+/// decided per machine.
+const SETTINGS_SYNC_LOCAL_ONLY_FIELDS: [&str; 3] =
+    ["soundLibrary", "statusPageSettings", "updatedAt"];
+/// Workspace settings carried when workspace preferences are synced, keyed
+/// by the workspace's root name.
+const SETTINGS_SYNC_WORKSPACE_FIELDS: [&str; 8] = [
+    "defaultTerminal",
+    "terminalCustomCommand",
+    "playGrooveCommand",
+    "openTerminalAtWorktreeCommand",
+    "worktreeSymlinkPaths",
+    "commitTemplate",
+    "commitLint",
+    "maxWorktreeCount",
+];
+/// A worktree whose processes use less CPU than this counts as idle when
+/// suggesting grooves to stop.
+const SYSTEM_ALERT_IDLE_CPU_PERCENT: f64 = 5.0;
+/// Descriptor or inotify usage at this share of its limit gets a
+/// remediation hint in diagnostics.
+const FD_USAGE_HINT_PERCENT: f64 = 80.0;
+const COMMAND_TELEMETRY_SLOW_THRESHOLD: Duration = Duration::from_millis(250);
+/// Per stream of a debug transcript; longer output keeps its tail.
+const SUBPROCESS_TRANSCRIPT_MAX_OUTPUT_CHARS: usize = 64_000;
+/// Debug transcripts kept per command; later subprocesses are not recorded.
+const SUBPROCESS_TRANSCRIPTS_MAX_PER_COMMAND: usize = 100;
+/// Environment variables whose name contains one of these are reported in
+/// debug transcripts without their value.
+const SUBPROCESS_TRANSCRIPT_SECRET_ENV_MARKERS: [&str; 4] =
+    ["TOKEN", "SECRET", "PASSWORD", "CREDENTIAL"];
+const GIT_OPERATION_QUEUE_REPORT_INTERVAL: Duration = Duration::from_secs(2);
+const REMOTE_WORKSPACE_COMMAND_TIMEOUT: Duration = Duration::from_secs(30);
+const TMUX_COMMAND_TIMEOUT: Duration = Duration::from_secs(10);
+const ZELLIJ_COMMAND_TIMEOUT: Duration = Duration::from_secs(10);
@@ -1,6 +1,8 @@
 #[derive(Default)]
 struct WorkspaceEventState {
-    worker: Mutex<Option<WorkspaceWorker>>,
+    /// One poll worker per open workspace, by `workspace_root_storage_key`.
+    workers: Mutex<HashMap<String, WorkspaceWorker>>,
+    /// Number of workers started so far.
     worker_generation: Arc<AtomicU64>,
 }
 
@@ -23,6 +25,10 @@ struct TerminalResolutionCacheState {
 struct GrooveListCacheState {
     entries: Mutex<HashMap<String, GrooveListCacheEntry>>,
     in_flight: Mutex<HashMap<String, Arc<GrooveListInFlight>>>,
+    /// Keys whose rows were already restored from `groove-list-cache.json`
+    /// this run, so an invalidated cache is not brought back from disk. Also
+    /// held while that file is rewritten.
+    restored_from_disk: Mutex<HashSet<String>>,
 }
 
 #[derive(Default)]
@@ -30,6 +36,47 @@ struct GrooveBinStatusState {
     status: Mutex<Option<GrooveBinCheckStatus>>,
 }
 
+/// `None` until the persisted history has been read.
+#[derive(Default)]
+struct ActionHistoryState {
+    entries: Mutex<Option<Vec<ActionHistoryEntry>>>,
+}
+
+/// `None` until `api-tokens.json` has been read.
+#[derive(Default)]
+struct ApiTokensState {
+    tokens: Mutex<Option<Vec<ApiTokenRecord>>>,
+}
+
+/// Oldest first; capped at `UNDO_STACK_MAX_ENTRIES`.
+#[derive(Default)]
+struct UndoState {
+    entries: Mutex<Vec<UndoRecord>>,
+}
+
+/// Newest first; capped per worktree at `COMMAND_RUNS_MAX_PER_WORKTREE`.
+#[derive(Default)]
+struct CommandRunsState {
+    runs: Mutex<Vec<CommandRun>>,
+}
+
+/// Where an undo entry writes its previous values back to.
+#[derive(Debug, Clone)]
+enum UndoTarget {
+    Workspace(PathBuf),
+    Worktree(PathBuf, String),
+    GlobalSettings,
+}
+
+#[derive(Debug, Clone)]
+struct UndoRecord {
+    entry: UndoEntry,
+    target: UndoTarget,
+    /// JSON values of the changed fields before the change; null means the
+    /// field was absent.
+    previous: serde_json::Map<String, serde_json::Value>,
+}
+
 #[derive(Default)]
 struct GrooveTerminalState {
     inner: Mutex<GrooveTerminalSessionsState>,
@@ -48,21 +95,88 @@ struct GrooveTerminalSessionState {
     worktree: String,
     worktree_path: String,
     command: String,
+    open_mode: GrooveTerminalOpenMode,
     started_at: String,
     cols: u16,
     rows: u16,
+    /// `None` while the session is hibernated.
+    pty: Option<GrooveTerminalPty>,
+    /// Bumped whenever the PTY is taken away, so the reader thread of the old
+    /// PTY does not close the session when it sees EOF.
+    pty_generation: u64,
+    /// Set for plain local shells, the only sessions that may hibernate.
+    respawn: Option<GrooveTerminalSpawnSpec>,
+    last_input_at: Instant,
+    snapshot: Arc<Mutex<TerminalSnapshotBuffer>>,
+}
+
+struct GrooveTerminalPty {
     child: Box<dyn PtyChild + Send>,
     master: Box<dyn MasterPty + Send>,
     writer: Box<dyn Write + Send>,
-    snapshot: Arc<Mutex<Vec<u8>>>,
+}
+
+type GrooveTerminalReader = Box<dyn Read + Send>;
+
+#[derive(Debug, Clone)]
+struct GrooveTerminalSpawnSpec {
+    program: String,
+    args: Vec<String>,
+    cwd: PathBuf,
+    worktree_path: PathBuf,
+}
+
+/// Recent output of a terminal session, replayed when a view reattaches. Its
+/// length counts towards the global snapshot budget until it is dropped.
+#[derive(Default)]
+struct TerminalSnapshotBuffer {
+    bytes: Vec<u8>,
+    /// Bytes dropped from the front so far, by either limit. This is also the
+    /// output offset of the first byte in `bytes`.
+    trimmed_bytes: u64,
+    scrollback: TerminalScrollback,
+    history: TerminalHistory,
+    last_output_at: Option<Instant>,
+}
+
+/// Where output trimmed from a session snapshot is spilled when scrollback
+/// persistence is on.
+#[derive(Default)]
+struct TerminalScrollback {
+    /// `None` when the app data directory could not be resolved.
+    dir: Option<PathBuf>,
+    session_id: String,
+    file: Option<TerminalScrollbackFile>,
+    /// Recorded scrollback outlives its session.
+    recording: bool,
+}
+
+/// Where all output of a session is appended when terminal history is on, so
+/// it can be restored after the app restarts. Unlike scrollback, the file is
+/// kept when the session closes.
+#[derive(Default)]
+struct TerminalHistory {
+    /// `None` for read-only workspaces, or once the file failed to open.
+    path: Option<PathBuf>,
+    file: Option<TerminalScrollbackFile>,
+}
+
+struct TerminalScrollbackFile {
+    file: fs::File,
+    /// Output offset of the first byte in the file.
+    start_offset: u64,
+    len: u64,
+}
+
+impl Drop for TerminalSnapshotBuffer {
+    fn drop(&mut self) {
+        account_terminal_snapshot_bytes(self.bytes.len(), 0);
+    }
 }
 
 impl Drop for GrooveTerminalState {
     fn drop(&mut self) {
-        let sessions_to_close = match self.inner.lock() {
-            Ok(mut sessions_state) => drain_groove_terminal_sessions(&mut sessions_state, None),
-            Err(_) => Vec::new(),
-        };
+        let sessions_to_close = drain_groove_terminal_sessions(&mut self.lock_sessions(), None);
         close_groove_terminal_sessions_best_effort(sessions_to_close);
     }
 }
@@ -95,6 +209,9 @@ struct GrooveListCacheEntry {
     created_at: Instant,
     response: GrooveListResponse,
     native_cache: Option<GrooveListNativeCache>,
+    /// Set when a worktree changed: the response is no longer served, but
+    /// the native rows of the other worktrees are still reused.
+    invalidated: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -123,21 +240,66 @@ impl GrooveListInFlight {
     }
 }
 
+/// The poll worker of `worktree_watch`; a single worktree is watched at a time.
+#[derive(Default)]
+struct WorktreeWatchState {
+    watcher: Mutex<Option<WorktreeWatcher>>,
+}
+
+struct WorktreeWatcher {
+    worktree_path: PathBuf,
+    stop: Arc<AtomicBool>,
+    handle: JoinHandle<()>,
+}
+
 struct WorkspaceWorker {
     workspace_root: String,
     stop: Arc<AtomicBool>,
     handle: JoinHandle<()>,
+    /// When the poll loop last started a pass.
+    last_poll_at: Arc<Mutex<Instant>>,
 }
 
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct PersistedWorktreeExecutionState {
+    /// Inner maps are keyed by worktree id (see `worktree_state_key`); entries
+    /// written before ids were used are keyed by directory name.
     #[serde(default)]
     last_executed_at_by_workspace: HashMap<String, HashMap<String, String>>,
     #[serde(default)]
     tombstones_by_workspace: HashMap<String, HashMap<String, WorktreeTombstone>>,
     #[serde(default)]
     running_by_workspace: HashMap<String, HashMap<String, RunningGrooveRecord>>,
+    #[serde(default)]
+    session_layouts_by_workspace: HashMap<String, HashMap<String, WorktreeSessionLayout>>,
+}
+
+/// Terminal sessions that were open for a worktree, in the order they were
+/// opened. Entries are added on open and dropped on an explicit close or
+/// restart, so an app shutdown leaves the layout in place for
+/// `worktree_session_layout_restore`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorktreeSessionLayout {
+    worktree: String,
+    #[serde(default)]
+    entries: Vec<WorktreeSessionLayoutEntry>,
+    updated_at: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorktreeSessionLayoutEntry {
+    session_id: String,
+    /// One of the `openMode` values accepted by `groove_terminal_open`.
+    open_mode: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    target: Option<String>,
+    /// True for Play sessions (run locally in the app), which are also
+    /// tracked as running grooves.
+    #[serde(default)]
+    run_local: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -145,6 +307,8 @@ struct PersistedWorktreeExecutionState {
 struct WorktreeTombstone {
     workspace_root: String,
     worktree: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    worktree_id: Option<String>,
     worktree_path: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     branch_name: Option<String>,
@@ -167,6 +331,10 @@ struct RunningGrooveRecord {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pid: Option<u32>,
     started_at: String,
+    /// Start time of `pid` as reported by the OS, checked before the process
+    /// is stopped so a reused PID is not signalled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    process_started_at: Option<String>,
     /// Best-effort liveness annotation set only when returned to the frontend;
     /// never persisted.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -498,6 +666,17 @@ struct PullRequestRecord {
     added_at: String,
 }
 
+/// The GitHub issue a worktree was created for.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct IssueRecord {
+    number: i64,
+    url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    linked_at: String,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 enum WorktreeState {
@@ -574,6 +753,38 @@ struct WorktreeRecord {
     comments: Vec<CommentRecord>,
     #[serde(default)]
     pull_requests: Vec<PullRequestRecord>,
+    /// Branch checked out in this worktree when Groove created it. Needed when
+    /// the directory name is a hashed alias that cannot be reversed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    branch: Option<String>,
+    /// Overrides the workspace `play_groove_command` for this worktree only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    play_groove_command: Option<String>,
+    /// Free-form reminder of what the worktree is for; searchable from the
+    /// quick switcher.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    /// Set when the worktree was created from an issue; PR bodies refer to it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    issue: Option<IssueRecord>,
+    /// Record id of the worktree this one is stacked on. An id rather than a
+    /// name, so renaming the parent keeps the link.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    parent_id: Option<String>,
+}
+
+/// Contents of `<worktree>/.groove/worktree.json`. The id travels with the
+/// directory, so a renamed or moved worktree can be matched back to its
+/// `WorktreeRecord` (and the state keyed by that id).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorktreeIdentity {
+    id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    branch: Option<String>,
+    created_at: String,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -634,6 +845,155 @@ struct WorkspaceMeta {
     /// means unlimited.
     #[serde(default)]
     max_worktree_count: Option<u32>,
+    /// Experimental: when set, worktrees, git and terminal sessions of this
+    /// workspace live on this SSH host instead of the local disk.
+    #[serde(default)]
+    remote: Option<WorkspaceRemote>,
+    /// Overrides applied with `git -c` to every git invocation Groove makes
+    /// for this workspace.
+    #[serde(default)]
+    git_identity: Option<WorkspaceGitIdentity>,
+    /// Message and trailers `git_commit` uses for this workspace.
+    #[serde(default)]
+    commit_template: Option<WorkspaceCommitTemplate>,
+    /// Conventional-commit rules `git_lint_commit_message` checks against;
+    /// `None` leaves messages unchecked.
+    #[serde(default)]
+    commit_lint: Option<WorkspaceCommitLintRules>,
+    /// Commits made through the app get `Signed-off-by` (DCO), and
+    /// `git_push_preflight` checks unpushed commits for it.
+    #[serde(default)]
+    require_sign_off: bool,
+    /// Named commands the worktree view offers as buttons.
+    #[serde(default)]
+    custom_commands: Vec<WorkspaceCustomCommand>,
+    /// Worktree name → absolute path of git worktrees adopted from outside
+    /// `.worktrees/`. Scans and `groove_list` include them like the others.
+    #[serde(default)]
+    adopted_worktrees: HashMap<String, String>,
+    /// Rules `worktree_staleness_report` classifies worktrees by; `None`
+    /// reports nothing as stale.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    staleness_policy: Option<WorktreeStalenessPolicy>,
+}
+
+/// A worktree matching any enabled rule is stale.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+struct WorktreeStalenessPolicy {
+    /// Stale once the newest commit is older than this many days.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    no_commits_days: Option<u32>,
+    /// Stale once the branch is merged into the default branch.
+    branch_merged: bool,
+    /// Stale once the branch has pull requests and none is open.
+    pr_closed: bool,
+    /// Stale once no opencode log was written in the worktree for this many
+    /// days.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    no_opencode_activity_days: Option<u32>,
+    /// "report"; "archive" to forget stale worktrees when the report runs;
+    /// "prompt" to have the report suggest deleting them.
+    action: String,
+}
+
+impl Default for WorktreeStalenessPolicy {
+    fn default() -> Self {
+        Self {
+            no_commits_days: None,
+            branch_merged: false,
+            pr_closed: false,
+            no_opencode_activity_days: None,
+            action: "report".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceRemote {
+    /// `host`, `user@host` or an ssh config alias.
+    destination: String,
+    #[serde(default)]
+    port: Option<u16>,
+    /// Absolute path of the repository root on the remote host.
+    root: String,
+    /// Forward the local ssh agent (`ssh -A`) so git on the host can use the
+    /// user's keys.
+    #[serde(default)]
+    forward_agent: bool,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceGitIdentity {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    user_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    user_email: Option<String>,
+    /// `core.sshCommand`, e.g. `ssh -i ~/.ssh/id_work -o IdentitiesOnly=yes`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ssh_command: Option<String>,
+}
+
+/// `{branch}`, `{ticket}` (e.g. `PROJ-123` taken from the branch name) and
+/// `{worktree}` are expanded in both the message and the trailers.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceCommitTemplate {
+    /// Used when a commit is made without an explicit message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+    /// `Key: value` lines appended to every commit message; ones that need
+    /// `{ticket}` are skipped on branches without a ticket ID.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    trailers: Vec<String>,
+}
+
+/// `{worktree}` (the worktree path) and `{branch}` are expanded in the
+/// template.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceCustomCommand {
+    /// Assigned when the command is first saved.
+    #[serde(default)]
+    id: String,
+    label: String,
+    template: String,
+    #[serde(default = "default_custom_command_run_mode")]
+    run_mode: CustomCommandRunMode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum CustomCommandRunMode {
+    // A new in-app terminal tab, so output and prompts stay visible.
+    Terminal,
+    // A background process with no terminal attached.
+    Detached,
+    // A background process whose output, exit code and duration are kept
+    // for `command_runs_list`.
+    Capture,
+}
+
+fn default_custom_command_run_mode() -> CustomCommandRunMode {
+    CustomCommandRunMode::Terminal
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceCommitLintRules {
+    /// Allowed types; empty means the conventional-commit defaults.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    types: Vec<String>,
+    /// Allowed scopes; empty means any.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    scopes: Vec<String>,
+    #[serde(default)]
+    require_scope: bool,
+    /// Longest allowed first line, in characters; 72 when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_subject_length: Option<u16>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -668,1348 +1028,4070 @@ struct WorkspaceContextResponse {
     rows: Vec<WorkspaceScanRow>,
     #[serde(skip_serializing_if = "Option::is_none")]
     cancelled: Option<bool>,
+    /// Set on a cold-start `workspace_get_active` answer that has the meta but
+    /// no rows yet; the full context follows in a `workspace-context-complete`
+    /// event.
     #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
-}
-
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-struct WorkspaceGitignoreSanityResponse {
-    request_id: String,
-    ok: bool,
+    partial: Option<bool>,
+    /// Set when the workspace root or its `.groove` directory cannot be
+    /// written. The context is read without writing, and settings writes and
+    /// new worktrees are refused up front.
     #[serde(skip_serializing_if = "Option::is_none")]
-    workspace_root: Option<String>,
-    is_applicable: bool,
-    has_groove_entry: bool,
-    has_workspace_entry: bool,
-    #[serde(default)]
-    missing_entries: Vec<String>,
+    read_only: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    patched: Option<bool>,
+    read_only_reason: Option<String>,
+    /// "undecided" for a workspace the user has not trusted or distrusted
+    /// yet, "denied" once distrusted; unset when trusted. Commands from its
+    /// settings and git hooks only run when trusted; see `workspace_set_trust`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    patched_worktree: Option<String>,
+    trust: Option<String>,
+    /// Set when only one page of `rows` was asked for.
     #[serde(skip_serializing_if = "Option::is_none")]
-    play_started: Option<bool>,
+    page: Option<RowsPageInfo>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
 
+/// `active-workspace.json`: the active workspace and every open one. Files
+/// written before several workspaces could be open only have
+/// `workspaceRoot`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PersistedWorkspaceState {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    workspace_root: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    updated_at: Option<String>,
+    #[serde(default)]
+    open_workspaces: Vec<OpenWorkspaceRecord>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OpenWorkspaceRecord {
+    workspace_root: String,
+    opened_at: String,
+    last_active_at: String,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct WorkspaceTermSanityResponse {
+struct OpenWorkspaceSummary {
+    workspace_root: String,
+    /// The root directory's name.
+    root_name: String,
+    opened_at: String,
+    last_active_at: String,
+    active: bool,
+    /// Whether a `workspace_events` worker polls this workspace.
+    watching: bool,
+    /// False once the root is no longer an accessible directory.
+    available: bool,
+}
+
+/// Open workspaces, most recently active first.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceListOpenResponse {
     request_id: String,
     ok: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    term_value: Option<String>,
-    is_usable: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    applied: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    fixed_value: Option<String>,
+    active_workspace_root: Option<String>,
+    workspaces: Vec<OpenWorkspaceSummary>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
 
+/// Stage timings of one `build_workspace_context`, for startup telemetry.
+#[derive(Debug, Clone, Copy, Default)]
+struct WorkspaceContextTimings {
+    remote: Duration,
+    meta: Duration,
+    scan: Duration,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveListPayload {
-    root_name: Option<String>,
+struct WorkspaceSearchWorktreesPayload {
     #[serde(default)]
-    known_worktrees: Vec<String>,
-    workspace_meta: Option<WorkspaceMetaContext>,
-    dir: Option<String>,
+    query: String,
+    #[serde(default)]
+    limit: Option<usize>,
+    #[serde(default)]
+    include_deleted: bool,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveRestorePayload {
-    workspace_root: Option<String>,
-    root_name: Option<String>,
-    #[serde(default)]
-    known_worktrees: Vec<String>,
-    workspace_meta: Option<WorkspaceMetaContext>,
-    worktree: String,
-    action: Option<String>,
-    target: Option<String>,
-    dir: Option<String>,
-    opencode_log_file: Option<String>,
+struct WorktreeSearchMatch {
+    /// `worktree`, `branch`, `tag` or `note`.
+    field: String,
+    text: String,
+    /// Character positions in `text` to highlight.
+    indices: Vec<usize>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveNewPayload {
-    root_name: Option<String>,
-    #[serde(default)]
-    known_worktrees: Vec<String>,
-    workspace_meta: Option<WorkspaceMetaContext>,
+struct WorktreeSearchResult {
+    worktree: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    worktree_id: Option<String>,
     branch: String,
-    base: Option<String>,
-    dir: Option<String>,
+    path: String,
+    status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_executed_at: Option<String>,
+    score: i64,
+    matches: Vec<WorktreeSearchMatch>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveRmPayload {
-    root_name: Option<String>,
-    #[serde(default)]
-    known_worktrees: Vec<String>,
-    workspace_meta: Option<WorkspaceMetaContext>,
-    target: String,
-    worktree: String,
-    dir: Option<String>,
-    force: Option<bool>,
+struct WorkspaceSearchWorktreesResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_root: Option<String>,
+    results: Vec<WorktreeSearchResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveStopPayload {
-    root_name: Option<String>,
+struct WorkspaceListWorktreesPayload {
     #[serde(default)]
-    known_worktrees: Vec<String>,
-    workspace_meta: Option<WorkspaceMetaContext>,
-    worktree: String,
-    instance_id: Option<String>,
-    dir: Option<String>,
+    offset: usize,
+    #[serde(default)]
+    limit: Option<usize>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+/// Asks for one page of worktree rows instead of all of them.
+#[derive(Debug, Clone, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct WorkspaceOpenTerminalPayload {
-    root_name: Option<String>,
+struct RowsPagePayload {
+    /// `nextCursor` of the previous page; unset for the first page.
     #[serde(default)]
-    known_worktrees: Vec<String>,
-    workspace_meta: Option<WorkspaceMetaContext>,
-    worktree: Option<String>,
+    cursor: Option<String>,
+    /// Defaults to 100, at most 500.
+    #[serde(default)]
+    limit: Option<usize>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct WorkspaceEventsPayload {
-    root_name: Option<String>,
-    #[serde(default)]
-    known_worktrees: Vec<String>,
-    workspace_meta: Option<WorkspaceMetaContext>,
+struct RowsPageInfo {
+    /// Rows on every page together, e.g. after a groove list filter.
+    total: usize,
+    /// Unset on the last page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_cursor: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct WorkspaceTerminalSettingsPayload {
-    default_terminal: String,
-    terminal_custom_command: Option<String>,
-    telemetry_enabled: Option<bool>,
-    disable_groove_business: Option<bool>,
-    hide_mascot: Option<bool>,
-    hide_labels: Option<bool>,
-    show_fps: Option<bool>,
+struct WorkspaceListWorktreesResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_root: Option<String>,
+    rows: Vec<WorkspaceScanRow>,
+    /// Rows in the whole workspace, not just this page.
+    total: usize,
+    offset: usize,
+    has_more: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+/// A worktree listed under a `workspace_summary` category.
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct WorkspaceCommandSettingsPayload {
-    play_groove_command: String,
-    open_terminal_at_worktree_command: Option<String>,
+struct WorkspaceSummaryItem {
+    worktree: String,
+    /// What the worktree is listed for, e.g. `claude-code`, `3 changed files`
+    /// or `PR #42`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detail: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    /// When the agent or testing environment started, or the pull request
+    /// was attached.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    since: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct WorkspaceMaxWorktreeCountPayload {
-    #[serde(default)]
-    max_worktree_count: Option<u32>,
+struct WorkspaceSummaryCategory {
+    count: usize,
+    /// At most `WORKSPACE_SUMMARY_TOP_ITEMS`, newest first.
+    top: Vec<WorkspaceSummaryItem>,
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct WorkspaceMaxWorktreeCountResponse {
+struct WorkspaceSummaryResponse {
     request_id: String,
     ok: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     workspace_root: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    workspace_meta: Option<WorkspaceMeta>,
-    /// Worktrees auto-removed to bring the count down to the new limit.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    evicted_worktrees: Vec<String>,
+    total_worktrees: usize,
+    running_agents: WorkspaceSummaryCategory,
+    dirty_worktrees: WorkspaceSummaryCategory,
+    open_prs: WorkspaceSummaryCategory,
+    running_testing_envs: WorkspaceSummaryCategory,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
 
-#[derive(Debug, Clone, Default, Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct WorktreeStorageStatsPayload {
-    /// Disk-size calculation walks every file in each worktree (`du`), which is
-    /// expensive for worktrees with large `node_modules`. Off by default so the
-    /// panel can show counts instantly; opted into on demand.
-    #[serde(default)]
-    include_sizes: bool,
-}
-
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct WorktreeStorageRow {
-    worktree: String,
-    path: String,
-    /// Only meaningful when the response's `sizesIncluded` is true; otherwise 0.
-    bytes: u64,
+struct WorkspaceCustomCommandsListResponse {
+    request_id: String,
+    ok: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    last_executed_at: Option<String>,
+    workspace_root: Option<String>,
+    custom_commands: Vec<WorkspaceCustomCommand>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct WorktreeStorageStatsResponse {
+struct WorkspaceCustomCommandRunResponse {
     request_id: String,
     ok: bool,
-    total_count: usize,
-    total_bytes: u64,
-    /// Whether disk sizes were computed for this response.
-    sizes_included: bool,
+    /// The expanded command line.
     #[serde(skip_serializing_if = "Option::is_none")]
-    max_worktree_count: Option<u32>,
-    worktrees: Vec<WorktreeStorageRow>,
+    command: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    workspace_root: Option<String>,
+    run_mode: Option<CustomCommandRunMode>,
+    /// The terminal the command was started in, for `terminal` commands.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    session: Option<GrooveTerminalSession>,
+    /// The run as started, for `capture` commands; poll `command_runs_list`
+    /// for its outcome.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    run: Option<CommandRun>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct WorkspaceMarkOnboardingPayload {
-    #[serde(default)]
-    symlinks_configured: bool,
-    #[serde(default)]
-    commands_configured: bool,
+enum CommandRunStatus {
+    Running,
+    Succeeded,
+    Failed,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+/// One captured run of a custom command.
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct WorkspaceRootDirectoryPayload {
-    #[serde(default)]
-    root_directory: Option<String>,
+struct CommandRun {
+    id: String,
+    command_id: String,
+    label: String,
+    workspace_root: String,
+    worktree: String,
+    /// The expanded command line.
+    command: String,
+    status: CommandRunStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exit_code: Option<i32>,
+    stdout: String,
+    stderr: String,
+    /// Set when `stdout` or `stderr` were cut to their tail.
+    output_truncated: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    started_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    finished_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration_ms: Option<u64>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct WorkspaceWorktreeSymlinkPathsPayload {
+struct CommandRunsListPayload {
+    /// Only runs in this worktree of the active workspace.
     #[serde(default)]
-    worktree_symlink_paths: Vec<String>,
+    worktree: Option<String>,
+    /// Only runs of this custom command.
+    #[serde(default)]
+    command_id: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct SetWorktreeStatePayload {
-    worktree: String,
-    state: WorktreeState,
+struct CommandRunsListResponse {
+    request_id: String,
+    ok: bool,
+    /// Newest first.
+    runs: Vec<CommandRun>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct OpencodeSettingsUpdatePayload {
-    enabled: bool,
-    #[serde(default)]
-    default_model: Option<String>,
-    #[serde(default)]
-    settings_directory: Option<String>,
+struct CancelRequestPayload {
+    /// Request id of the command to cancel, e.g. from `GitProgressEvent` or
+    /// `commands_in_flight`.
+    request_id: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct OpencodeCopySkillsPayload {
-    global_skills_path: String,
-    workspace_skills_path: String,
-    #[serde(default)]
-    global_to_workspace: Vec<String>,
-    #[serde(default)]
-    workspace_to_global: Vec<String>,
+struct CancelRequestResponse {
+    request_id: String,
+    ok: bool,
+    /// The command that was asked to stop. It still answers its own
+    /// request, with a "was cancelled" error and `cancelled: true` on the
+    /// responses that carry the flag once it noticed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    command: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct WorkspaceBrowseEntriesPayload {
-    relative_path: Option<String>,
+struct InFlightCommandInfo {
+    request_id: String,
+    command: String,
+    elapsed_ms: u64,
+    cancelled: bool,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GlobalSettingsUpdatePayload {
-    telemetry_enabled: Option<bool>,
-    disable_groove_business: Option<bool>,
-    hide_mascot: Option<bool>,
-    hide_labels: Option<bool>,
-    show_fps: Option<bool>,
-    always_show_diagnostics_sidebar: Option<bool>,
-    periodic_rerender_enabled: Option<bool>,
-    theme_mode: Option<String>,
-    keyboard_shortcut_leader: Option<String>,
-    keyboard_leader_bindings: Option<HashMap<String, String>>,
-    opencode_settings: Option<OpencodeSettingsUpdatePayload>,
-    sound_library: Option<Vec<SoundLibraryEntry>>,
-    claude_code_sound_settings: Option<ClaudeCodeSoundSettings>,
-    groove_sound_settings: Option<GrooveSoundSettings>,
+struct CommandsInFlightResponse {
+    request_id: String,
+    ok: bool,
+    /// Oldest first; this request is not listed.
+    commands: Vec<InFlightCommandInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+/// An API token for the MCP server. Only the SHA-256 digest of the secret is
+/// stored; `capabilities` holds `Capability::as_str` values.
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct SoundLibraryRemovePayload {
-    sound_id: String,
+struct ApiTokenRecord {
+    id: String,
+    name: String,
+    secret_digest: String,
+    capabilities: Vec<String>,
+    created_at: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct SoundLibraryReadPayload {
-    file_name: String,
+struct ApiTokenSummary {
+    id: String,
+    name: String,
+    capabilities: Vec<String>,
+    created_at: String,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct SoundLibraryRenamePayload {
-    sound_id: String,
-    new_name: String,
+struct ApiTokenCreatePayload {
+    name: String,
+    /// Defaults to every capability except `destructive`.
+    #[serde(default)]
+    capabilities: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct SoundLibraryGetPathPayload {
-    sound_id: String,
+struct ApiTokenRevokePayload {
+    id: String,
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct SoundLibraryPathResponse {
+struct ApiTokensResponse {
     request_id: String,
     ok: bool,
+    tokens: Vec<ApiTokenSummary>,
+    /// The secret of a token `api_token_create` just made; never shown again.
     #[serde(skip_serializing_if = "Option::is_none")]
-    folder_path: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    file_path: Option<String>,
+    secret: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+/// One recent action. `payload` is what the command was invoked with, so
+/// `history_repeat` can run it again.
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct SoundLibraryReadResponse {
-    request_id: String,
+struct ActionHistoryEntry {
+    id: String,
+    command: String,
+    /// Directory name of the worktree the action targeted.
+    worktree: String,
     ok: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    data: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    recorded_at: String,
+    payload: serde_json::Value,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GitAuthStatusPayload {
-    workspace_root: String,
+struct HistoryListPayload {
+    #[serde(default)]
+    limit: Option<usize>,
+    /// Only actions on this worktree.
+    #[serde(default)]
+    worktree: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GhSwitchPayload {
-    user: String,
+struct HistoryListResponse {
+    request_id: String,
+    ok: bool,
+    entries: Vec<ActionHistoryEntry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GhLogoutPayload {
-    user: String,
+struct HistoryRepeatPayload {
+    id: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GhLoginPayload {
-    token: String,
+struct HistoryRepeatResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    command: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    worktree: Option<String>,
+    /// The replayed command's own response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+/// A change `undo_last` can revert.
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GitPathPayload {
-    path: String,
+struct UndoEntry {
+    id: String,
+    /// Command that made the change.
+    command: String,
+    description: String,
+    /// `workspace`, `worktree` or `globalSettings`.
+    scope: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_root: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    worktree: Option<String>,
+    /// JSON names of the fields that will be restored.
+    fields: Vec<String>,
+    created_at: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GitPullPayload {
-    path: String,
-    #[serde(default)]
-    rebase: bool,
-}
-
-#[derive(Debug, Clone, Deserialize)]
+struct UndoListResponse {
+    request_id: String,
+    ok: bool,
+    /// Newest first; `undo_last` reverts the first one.
+    entries: Vec<UndoEntry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GitPushPayload {
-    path: String,
-    #[serde(default)]
-    set_upstream: bool,
-    #[serde(default)]
-    force_with_lease: bool,
-    #[serde(default)]
-    branch: Option<String>,
+struct UndoLastResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    undone: Option<UndoEntry>,
+    remaining: usize,
+    /// Set when a workspace or worktree change was reverted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_meta: Option<WorkspaceMeta>,
+    /// Set when a global settings change was reverted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    global_settings: Option<GlobalSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GitMergePayload {
-    path: String,
-    target_branch: String,
+struct WorkspaceGitignoreSanityResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_root: Option<String>,
+    is_applicable: bool,
+    has_groove_entry: bool,
+    has_workspace_entry: bool,
     #[serde(default)]
-    ff_only: bool,
+    missing_entries: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    patched: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    patched_worktree: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    play_started: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceTermSanityResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    term_value: Option<String>,
+    is_usable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applied: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fixed_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GitCommitPayload {
-    path: String,
+struct GrooveListPayload {
+    root_name: Option<String>,
     #[serde(default)]
-    message: Option<String>,
+    known_worktrees: Vec<String>,
+    workspace_meta: Option<WorkspaceMetaContext>,
+    dir: Option<String>,
+    /// Attach per-worktree CPU and memory to every row.
+    #[serde(default)]
+    include_resources: bool,
+    /// Attach status badges to every row.
+    #[serde(default)]
+    include_badges: bool,
+    /// Trim and sort the rows before they are sent.
+    #[serde(default)]
+    filter: Option<GrooveListFilter>,
+    /// Send one page of the (filtered) rows.
+    #[serde(default)]
+    page: Option<RowsPagePayload>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+/// Narrows a groove list down; rows must pass every criterion that is set.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GitFilesPayload {
-    path: String,
-    files: Vec<String>,
+struct GrooveListFilter {
+    /// Worktree states to keep; worktrees without a record are `pending`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    status: Vec<WorktreeState>,
+    /// Keep worktrees carrying this tag, in any case.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tag: Option<String>,
+    /// `logState` values to keep, e.g. `latest`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    activity: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sort: Option<GrooveListSort>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveTerminalOpenPayload {
+enum GrooveListSort {
+    /// Most recently played first; never played last.
+    LastActivity,
+    /// Most changed files first, in builds with `native-git`.
+    DiffSize,
+    Name,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveRestorePayload {
+    workspace_root: Option<String>,
     root_name: Option<String>,
     #[serde(default)]
     known_worktrees: Vec<String>,
     workspace_meta: Option<WorkspaceMetaContext>,
     worktree: String,
+    action: Option<String>,
     target: Option<String>,
-    open_mode: Option<String>,
-    cols: Option<u16>,
-    rows: Option<u16>,
-    force_restart: Option<bool>,
-    open_new: Option<bool>,
+    dir: Option<String>,
+    opencode_log_file: Option<String>,
+    /// Emit the sidecar's output as `groove-command-output` events while it
+    /// runs.
+    #[serde(default)]
+    stream: bool,
+    #[serde(default)]
+    debug: Option<bool>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveTerminalWritePayload {
+struct GrooveNewPayload {
     root_name: Option<String>,
     #[serde(default)]
     known_worktrees: Vec<String>,
     workspace_meta: Option<WorkspaceMetaContext>,
-    worktree: String,
-    session_id: Option<String>,
-    input: String,
+    branch: String,
+    base: Option<String>,
+    dir: Option<String>,
+    /// `"branch"` (default), `"hashed"` or `"auto"` — see
+    /// `WorktreeDirNameStrategy`.
+    dir_name_strategy: Option<String>,
+    /// GitHub issue number to start work on. `branch` may then be empty to
+    /// name the branch after the issue, and the issue is linked in the
+    /// worktree record.
+    issue: Option<i64>,
+    /// Worktree to stack the new one on. Its branch is the default `base`,
+    /// and `worktree_restack` and `gh_pr_create` follow the link.
+    #[serde(default)]
+    parent: Option<String>,
+    /// Emit the sidecar's output as `groove-command-output` events while it
+    /// runs.
+    #[serde(default)]
+    stream: bool,
+    #[serde(default)]
+    debug: Option<bool>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveTerminalResizePayload {
+struct GrooveRmPayload {
     root_name: Option<String>,
     #[serde(default)]
     known_worktrees: Vec<String>,
     workspace_meta: Option<WorkspaceMetaContext>,
+    target: String,
     worktree: String,
-    session_id: Option<String>,
-    cols: u16,
-    rows: u16,
+    dir: Option<String>,
+    /// Runs `git worktree remove --force` instead of the sidecar's `rm`.
+    force: Option<bool>,
+    /// Safety categories (see `GROOVE_RM_SAFETY_CATEGORIES`) the caller
+    /// accepts losing; removal is refused while the worktree has findings in
+    /// any other.
+    #[serde(default)]
+    acknowledge: Vec<String>,
+    /// Emit the sidecar's output as `groove-command-output` events while it
+    /// runs.
+    #[serde(default)]
+    stream: bool,
+    #[serde(default)]
+    debug: Option<bool>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveTerminalClosePayload {
+struct GrooveStopPayload {
     root_name: Option<String>,
     #[serde(default)]
     known_worktrees: Vec<String>,
     workspace_meta: Option<WorkspaceMetaContext>,
     worktree: String,
-    session_id: Option<String>,
+    instance_id: Option<String>,
+    dir: Option<String>,
 }
 
+/// Payload of `groove_bulk_rm`, `groove_bulk_stop` and `groove_bulk_restore`.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveTerminalSessionPayload {
+struct GrooveBulkPayload {
     root_name: Option<String>,
     #[serde(default)]
     known_worktrees: Vec<String>,
     workspace_meta: Option<WorkspaceMetaContext>,
-    worktree: String,
-    session_id: Option<String>,
+    worktrees: Vec<String>,
+    dir: Option<String>,
+    /// Only read by `groove_bulk_rm`.
+    force: Option<bool>,
+    /// Only read by `groove_bulk_rm`; applies to every worktree.
+    #[serde(default)]
+    acknowledge: Vec<String>,
+    /// Only read by `groove_bulk_restore`.
+    action: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct RuntimeStateRow {
-    branch: String,
+struct GrooveArchivePayload {
+    root_name: Option<String>,
+    #[serde(default)]
+    known_worktrees: Vec<String>,
+    workspace_meta: Option<WorkspaceMetaContext>,
     worktree: String,
-    log_state: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    log_target: Option<String>,
-}
-
-
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-struct GrooveListResponse {
-    request_id: String,
-    ok: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    workspace_root: Option<String>,
-    rows: HashMap<String, RuntimeStateRow>,
-    stdout: String,
-    stderr: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
-}
-
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-struct GrooveCommandResponse {
-    request_id: String,
-    ok: bool,
-    exit_code: Option<i32>,
-    stdout: String,
-    stderr: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
-}
-
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-struct ExternalUrlOpenResponse {
-    request_id: String,
-    ok: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
-}
-
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-struct WorkspaceOpenDirectoryResponse {
-    request_id: String,
-    ok: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
-}
-
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-struct GrooveStopResponse {
-    request_id: String,
-    ok: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    already_stopped: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pid: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    source: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
+    dir: Option<String>,
+    /// Branch the diff is taken against; defaults to `origin/HEAD`, `main`
+    /// or `master`, whichever exists.
+    base: Option<String>,
+    /// Remove the worktree once archived. Its uncommitted changes and
+    /// unpushed commits are in the archive, so only running processes and
+    /// terminal sessions can still block the removal.
+    #[serde(default)]
+    remove: bool,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveRecoverableListResponse {
-    request_id: String,
-    ok: bool,
-    grooves: Vec<RunningGrooveRecord>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
+struct GrooveArchiveListPayload {
+    root_name: Option<String>,
+    #[serde(default)]
+    known_worktrees: Vec<String>,
+    workspace_meta: Option<WorkspaceMetaContext>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveRecoverableClearPayload {
+struct GrooveUnarchivePayload {
     root_name: Option<String>,
     #[serde(default)]
     known_worktrees: Vec<String>,
     workspace_meta: Option<WorkspaceMetaContext>,
-    /// Worktrees to clear; an empty list clears every running record for the workspace.
+    id: String,
+    /// Branch of the new worktree; defaults to the archived branch.
+    branch: Option<String>,
+    dir: Option<String>,
+    /// Keep the archive after restoring it.
     #[serde(default)]
-    worktrees: Vec<String>,
-}
-
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-struct GrooveRecoverableClearResponse {
-    request_id: String,
-    ok: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
+    keep_archive: bool,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveSummaryPayload {
+struct WorktreeRestackPayload {
     root_name: Option<String>,
     #[serde(default)]
     known_worktrees: Vec<String>,
     workspace_meta: Option<WorkspaceMetaContext>,
-    session_ids: Vec<String>,
+    /// The parent whose children are rebased.
+    worktree: String,
+    dir: Option<String>,
+    /// Also restack the children's own children, parents first.
+    #[serde(default)]
+    recursive: bool,
+    #[serde(default)]
+    autostash: bool,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct DiscoverWorktreeUnitPayload {
+struct WorktreeActionPayload {
     root_name: Option<String>,
     #[serde(default)]
     known_worktrees: Vec<String>,
     workspace_meta: Option<WorkspaceMetaContext>,
     worktree: String,
-    session_id: String,
+    /// One of `WORKTREE_ACTIONS`.
+    action: String,
+    /// Extra fields of the payload of the command the action runs, e.g.
+    /// `rebase` for "pull" or `force` for "remove".
+    #[serde(default)]
+    options: serde_json::Map<String, serde_json::Value>,
+    dir: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct ClaimWorktreeRewardPayload {
-    worktree: String,
-}
-
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-struct ClaimWorktreeRewardResponse {
-    request_id: String,
-    ok: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    unit: Option<WorktreeUnit>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    gold: Option<u64>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
+struct WorkspaceOpenTerminalPayload {
+    root_name: Option<String>,
+    #[serde(default)]
+    known_worktrees: Vec<String>,
+    workspace_meta: Option<WorkspaceMetaContext>,
+    worktree: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct LootWorktreePayload {
-    worktree: String,
+struct WorkspaceEventsPayload {
+    root_name: Option<String>,
+    #[serde(default)]
+    known_worktrees: Vec<String>,
+    workspace_meta: Option<WorkspaceMetaContext>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct LootWorktreeResponse {
-    request_id: String,
-    ok: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    unit: Option<WorktreeUnit>,
-    #[serde(skip_serializing_if = "Vec::is_empty", default)]
-    loot: Vec<WorktreeLootEntry>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    inventory: Option<HashMap<String, u32>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
+struct WorktreeWatchPayload {
+    worktree_path: String,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct DiscoverWorktreeUnitResponse {
-    request_id: String,
-    ok: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    unit: Option<WorktreeUnit>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    level: Option<u8>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    raw_claude_output: Option<String>,
-    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
-    was_new_discovery: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
+struct WorkspaceTerminalSettingsPayload {
+    default_terminal: String,
+    terminal_custom_command: Option<String>,
+    telemetry_enabled: Option<bool>,
+    disable_groove_business: Option<bool>,
+    hide_mascot: Option<bool>,
+    hide_labels: Option<bool>,
+    show_fps: Option<bool>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveSummaryResponse {
-    request_id: String,
-    ok: bool,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
-    summaries: Vec<GrooveSummaryEntry>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    compiled_summary: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
+struct WorkspaceCommandSettingsPayload {
+    play_groove_command: String,
+    open_terminal_at_worktree_command: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveCommentPayload {
-    root_name: Option<String>,
+struct PlayCommandPreviewPayload {
+    /// The template to preview, validated as it would be when saved.
+    play_groove_command: String,
+    /// Worktree of the active workspace to resolve it for; a sample one
+    /// when omitted.
     #[serde(default)]
-    known_worktrees: Vec<String>,
-    workspace_meta: Option<WorkspaceMetaContext>,
-    worktree: String,
-    /// When true, also feed the Claude conversation since the last commit into
-    /// the drafting prompt (the Changes-panel "Draft commit comment" button).
+    worktree: Option<String>,
+    /// What Play passes as the target; the worktree's branch when omitted.
     #[serde(default)]
-    include_session: bool,
+    target: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveCommentResponse {
-    request_id: String,
-    ok: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    comment: Option<CommentRecord>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
+struct WorkspaceMaxWorktreeCountPayload {
+    #[serde(default)]
+    max_worktree_count: Option<u32>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveCommentMarkCommittedPayload {
-    root_name: Option<String>,
+struct WorkspaceStalenessPolicyPayload {
+    /// `None` removes the policy.
     #[serde(default)]
-    known_worktrees: Vec<String>,
-    workspace_meta: Option<WorkspaceMetaContext>,
-    worktree: String,
-    created_at: String,
+    policy: Option<WorktreeStalenessPolicy>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveCommentMarkCommittedResponse {
-    request_id: String,
-    ok: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    comment: Option<CommentRecord>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
+struct WorktreeStalenessReportPayload {
+    /// Classify only; an "archive" policy forgets nothing.
+    #[serde(default)]
+    dry_run: bool,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GroovePrAttachPayload {
-    root_name: Option<String>,
+struct WorkspaceFreezePayload {
+    /// Defaults to true: terminal history is packed into tarballs until thaw.
     #[serde(default)]
-    known_worktrees: Vec<String>,
-    workspace_meta: Option<WorkspaceMetaContext>,
-    worktree: String,
-    url: String,
+    compress_artifacts: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceThawPayload {
+    /// Reopen the recorded terminal sessions and Play targets. Otherwise the
+    /// session layouts are put back for `worktree_session_layout_restore`.
     #[serde(default)]
-    title: Option<String>,
+    restart_sessions: bool,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceConflictMatrixPayload {
+    /// Worktrees to compare; empty compares every worktree on disk.
+    #[serde(default)]
+    worktrees: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MergeTrainStartPayload {
+    /// Worktrees to merge, in merge order.
+    worktrees: Vec<String>,
+    /// Branch the pull requests are merged into; defaults to the repository's
+    /// default branch.
     #[serde(default)]
     base: Option<String>,
+    /// `merge` (default), `squash` or `rebase`, as for `gh_pr_merge`.
     #[serde(default)]
-    head: Option<String>,
+    strategy: Option<String>,
+    /// Leave the worktrees and their remote branches in place once merged.
+    #[serde(default)]
+    keep_worktrees: bool,
+    #[serde(default)]
+    dir: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MergeTrainResumePayload {
+    /// Skip the step the train paused on instead of running it again.
+    #[serde(default)]
+    skip: bool,
 }
 
+/// A schedule change for one maintenance task; unset fields are kept.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GroovePrDetachPayload {
-    root_name: Option<String>,
+struct MaintenanceTaskSchedulePayload {
+    task: String,
     #[serde(default)]
-    known_worktrees: Vec<String>,
-    workspace_meta: Option<WorkspaceMetaContext>,
-    worktree: String,
-    url: String,
+    enabled: Option<bool>,
+    /// Local time of day as "HH:MM".
+    #[serde(default)]
+    at: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GroovePrResponse {
-    request_id: String,
-    ok: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pull_request: Option<PullRequestRecord>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
+struct MaintenanceConfigurePayload {
+    /// Minutes the local time the schedules are given in is ahead of UTC,
+    /// e.g. the negated `Date.getTimezoneOffset()`.
+    #[serde(default)]
+    utc_offset_minutes: Option<i32>,
+    #[serde(default)]
+    tasks: Vec<MaintenanceTaskSchedulePayload>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveSummaryEntry {
-    session_id: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    worktree: Option<String>,
-    ok: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    summary: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
+struct MaintenanceRunNowPayload {
+    /// Tasks to run, in order; every task when empty.
+    #[serde(default)]
+    tasks: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct WorkspaceEventsResponse {
-    request_id: String,
-    ok: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    workspace_root: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
+struct WorkspaceGitIdentityPayload {
+    /// `None` (or all fields blank) removes the overrides.
+    #[serde(default)]
+    git_identity: Option<WorkspaceGitIdentity>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveTerminalSession {
-    session_id: String,
-    workspace_root: String,
-    worktree: String,
-    worktree_path: String,
-    command: String,
-    started_at: String,
-    cols: u16,
-    rows: u16,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    snapshot: Option<String>,
+struct WorkspaceCommitTemplatePayload {
+    /// `None` (or an empty template) removes it.
+    #[serde(default)]
+    commit_template: Option<WorkspaceCommitTemplate>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveTerminalResponse {
-    request_id: String,
-    ok: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    session: Option<GrooveTerminalSession>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
+struct WorkspaceCommitLintPayload {
+    /// `None` turns commit message linting off.
+    #[serde(default)]
+    commit_lint: Option<WorkspaceCommitLintRules>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveTerminalSessionsResponse {
-    request_id: String,
-    ok: bool,
-    sessions: Vec<GrooveTerminalSession>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
+struct WorkspaceRequireSignOffPayload {
+    require_sign_off: bool,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveTerminalOutputEvent {
-    session_id: String,
-    workspace_root: String,
-    worktree: String,
-    chunk: String,
+struct WorkspaceAdoptWorktreePayload {
+    /// An existing `git worktree` of the active workspace's repository.
+    path: String,
+    /// Move it under `.worktrees/` with `git worktree move` instead of
+    /// leaving it where it is.
+    #[serde(default)]
+    relocate: bool,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveTerminalLifecycleEvent {
-    session_id: String,
+struct WorkspaceMigratePayload {
+    /// Report what would be adopted without changing anything.
+    #[serde(default)]
+    dry_run: bool,
+    /// Move adopted worktrees under `.worktrees/` instead of leaving them
+    /// where they are.
+    #[serde(default)]
+    relocate: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceTrustRecord {
     workspace_root: String,
-    worktree: String,
-    kind: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    message: Option<String>,
+    trusted: bool,
+    decided_at: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceTrustPayload {
+    trusted: bool,
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveTerminalActiveWorktreesResponse {
+struct WorkspaceTrustResponse {
     request_id: String,
     ok: bool,
-    worktrees: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_root: Option<String>,
+    trusted: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveTerminalActivityEntry {
-    session_id: String,
-    has_activity: bool,
+struct CustomCommandApprovePayload {
+    /// The command as sent in `custom-command-confirmation`.
+    command: String,
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GrooveTerminalActivityResponse {
+struct CustomCommandApproveResponse {
     request_id: String,
     ok: bool,
-    entries: Vec<GrooveTerminalActivityEntry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_root: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceCustomCommandsPayload {
+    /// Replaces the whole list; commands without an id are new.
+    #[serde(default)]
+    custom_commands: Vec<WorkspaceCustomCommand>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceCustomCommandRunPayload {
+    root_name: Option<String>,
+    #[serde(default)]
+    known_worktrees: Vec<String>,
+    workspace_meta: Option<WorkspaceMetaContext>,
+    worktree: String,
+    id: String,
+    cols: Option<u16>,
+    rows: Option<u16>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceRemotePayload {
+    /// `None` turns the workspace back into a local one.
+    #[serde(default)]
+    remote: Option<WorkspaceRemote>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceRemoteGitStatusPayload {
+    worktree: String,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct WorkspaceTerminalSettingsResponse {
+struct WorkspaceRemoteProbeResponse {
     request_id: String,
     ok: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    workspace_root: Option<String>,
+    destination: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    workspace_meta: Option<WorkspaceMeta>,
+    git_version: Option<String>,
+    root_is_repository: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct SetWorktreeStateResponse {
+struct RemoteWorktreeRow {
+    worktree: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    branch: Option<String>,
+    path: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceRemoteWorktreesResponse {
     request_id: String,
     ok: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    workspace_root: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    worktree: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    record: Option<WorktreeRecord>,
+    destination: Option<String>,
+    worktrees: Vec<RemoteWorktreeRow>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveTmuxKillWindowPayload {
+    root_name: Option<String>,
+    #[serde(default)]
+    known_worktrees: Vec<String>,
+    workspace_meta: Option<WorkspaceMetaContext>,
+    worktree: String,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct WorkspaceBrowseEntry {
+struct GrooveTmuxWindow {
+    index: u32,
     name: String,
     path: String,
-    is_dir: bool,
+    active: bool,
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct WorkspaceBrowseEntriesResponse {
+struct GrooveTmuxWindowsResponse {
     request_id: String,
     ok: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    workspace_root: Option<String>,
-    relative_path: String,
-    entries: Vec<WorkspaceBrowseEntry>,
+    session: Option<String>,
+    /// Whether a tmux client is currently attached to the session.
+    attached: bool,
+    windows: Vec<GrooveTmuxWindow>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct SoundLibraryEntry {
-    pub id: String,
-    pub name: String,
-    pub file_name: String,
+struct GrooveZellijSession {
+    worktree: String,
+    session: String,
+    /// Exited sessions can be resurrected by attaching to them.
+    exited: bool,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct ClaudeCodeHookSoundEntry {
-    #[serde(default)]
-    pub enabled: bool,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub sound_id: Option<String>,
+struct GrooveZellijSessionsResponse {
+    request_id: String,
+    ok: bool,
+    sessions: Vec<GrooveZellijSession>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
 }
 
-impl Default for ClaudeCodeHookSoundEntry {
-    fn default() -> Self {
-        Self {
-            enabled: false,
-            sound_id: None,
-        }
-    }
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceMaxWorktreeCountResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_root: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_meta: Option<WorkspaceMeta>,
+    /// Worktrees auto-removed to bring the count down to the new limit.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    evicted_worktrees: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct ClaudeCodeSoundSettings {
-    #[serde(default)]
-    pub notification: ClaudeCodeHookSoundEntry,
+struct WorktreeStorageStatsPayload {
+    /// Disk-size calculation walks every file in each worktree (`du`), which is
+    /// expensive for worktrees with large `node_modules`. Off by default so the
+    /// panel can show counts instantly; opted into on demand.
     #[serde(default)]
-    pub stop: ClaudeCodeHookSoundEntry,
-}
-
-impl Default for ClaudeCodeSoundSettings {
-    fn default() -> Self {
-        Self {
-            notification: ClaudeCodeHookSoundEntry::default(),
-            stop: ClaudeCodeHookSoundEntry::default(),
-        }
-    }
+    include_sizes: bool,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct GrooveSoundHookEntry {
-    #[serde(default)]
-    pub enabled: bool,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub sound_id: Option<String>,
+struct WorktreeStorageRow {
+    worktree: String,
+    path: String,
+    /// Only meaningful when the response's `sizesIncluded` is true; otherwise 0.
+    bytes: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_executed_at: Option<String>,
 }
 
-impl Default for GrooveSoundHookEntry {
-    fn default() -> Self {
-        Self {
-            enabled: false,
-            sound_id: None,
-        }
-    }
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorktreeStorageStatsResponse {
+    request_id: String,
+    ok: bool,
+    total_count: usize,
+    total_bytes: u64,
+    /// Whether disk sizes were computed for this response.
+    sizes_included: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_worktree_count: Option<u32>,
+    worktrees: Vec<WorktreeStorageRow>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_root: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct GrooveSoundSettings {
-    #[serde(default)]
-    pub play: GrooveSoundHookEntry,
-    #[serde(default)]
-    pub pause: GrooveSoundHookEntry,
+struct WorkspaceMarkOnboardingPayload {
     #[serde(default)]
-    pub summary_start: GrooveSoundHookEntry,
+    symlinks_configured: bool,
     #[serde(default)]
-    pub summary_end: GrooveSoundHookEntry,
+    commands_configured: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceRootDirectoryPayload {
     #[serde(default)]
-    pub emergency: GrooveSoundHookEntry,
+    root_directory: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceWorktreeSymlinkPathsPayload {
     #[serde(default)]
-    pub remove: GrooveSoundHookEntry,
+    worktree_symlink_paths: Vec<String>,
 }
 
-impl Default for GrooveSoundSettings {
-    fn default() -> Self {
-        Self {
-            play: GrooveSoundHookEntry::default(),
-            pause: GrooveSoundHookEntry::default(),
-            summary_start: GrooveSoundHookEntry::default(),
-            summary_end: GrooveSoundHookEntry::default(),
-            emergency: GrooveSoundHookEntry::default(),
-            remove: GrooveSoundHookEntry::default(),
-        }
-    }
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SetWorktreeStatePayload {
+    worktree: String,
+    state: WorktreeState,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GlobalSettings {
-    #[serde(default = "default_true")]
-    telemetry_enabled: bool,
-    #[serde(default, alias = "disableGrooveLoadingSection")]
-    disable_groove_business: bool,
-    #[serde(default)]
-    hide_mascot: bool,
+struct SetWorktreePlayCommandPayload {
+    worktree: String,
+    /// `None` (or blank) clears the override.
     #[serde(default)]
-    hide_labels: bool,
+    play_groove_command: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SetWorktreeLabelsPayload {
+    worktree: String,
+    /// `None` (or blank) clears the note.
     #[serde(default)]
-    show_fps: bool,
+    note: Option<String>,
+    /// Replaces the worktree's tags.
     #[serde(default)]
-    always_show_diagnostics_sidebar: bool,
+    tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OpencodeSettingsUpdatePayload {
+    enabled: bool,
     #[serde(default)]
-    periodic_rerender_enabled: bool,
-    #[serde(default = "default_theme_mode")]
-    theme_mode: String,
-    #[serde(default = "default_keyboard_shortcut_leader")]
-    keyboard_shortcut_leader: String,
-    #[serde(default = "default_keyboard_leader_bindings")]
-    keyboard_leader_bindings: HashMap<String, String>,
-    #[serde(default = "default_opencode_settings")]
-    opencode_settings: OpencodeSettings,
+    default_model: Option<String>,
     #[serde(default)]
-    sound_library: Vec<SoundLibraryEntry>,
+    settings_directory: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OpencodeCopySkillsPayload {
+    global_skills_path: String,
+    workspace_skills_path: String,
     #[serde(default)]
-    claude_code_sound_settings: ClaudeCodeSoundSettings,
+    global_to_workspace: Vec<String>,
     #[serde(default)]
-    groove_sound_settings: GrooveSoundSettings,
+    workspace_to_global: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct OpencodeIntegrationStatusResponse {
-    request_id: String,
-    ok: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    workspace_root: Option<String>,
-    workspace_scope_available: bool,
+struct WorkspaceBrowseEntriesPayload {
+    relative_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GlobalSettingsUpdatePayload {
+    telemetry_enabled: Option<bool>,
+    disable_groove_business: Option<bool>,
+    hide_mascot: Option<bool>,
+    hide_labels: Option<bool>,
+    show_fps: Option<bool>,
+    always_show_diagnostics_sidebar: Option<bool>,
+    periodic_rerender_enabled: Option<bool>,
+    theme_mode: Option<String>,
+    keyboard_shortcut_leader: Option<String>,
+    keyboard_leader_bindings: Option<HashMap<String, String>>,
+    opencode_settings: Option<OpencodeSettingsUpdatePayload>,
+    sound_library: Option<Vec<SoundLibraryEntry>>,
+    claude_code_sound_settings: Option<ClaudeCodeSoundSettings>,
+    groove_sound_settings: Option<GrooveSoundSettings>,
+    terminal_memory_settings: Option<TerminalMemorySettings>,
+    system_alert_settings: Option<SystemAlertSettings>,
+    telemetry_path_redaction: Option<String>,
+    custom_command_policy: Option<CustomCommandPolicy>,
+    /// "auto" clears it.
+    default_terminal: Option<String>,
+    polling_settings: Option<PollingSettings>,
+    testing_environment_restart_policy: Option<TestingEnvironmentRestartPolicy>,
+    status_page_settings: Option<StatusPageSettings>,
+    command_retry_policy: Option<CommandRetryPolicy>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SettingsProfileSavePayload {
+    name: String,
+    /// Captured from the current global settings when absent.
+    overrides: Option<SettingsProfileOverrides>,
+}
+
+/// Payload of `settings_profile_switch`, `settings_profile_delete` and
+/// `settings_profile_export`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SettingsProfileNamePayload {
+    name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SettingsProfileImportPayload {
+    /// A document written by `settings_profile_export`.
+    contents: String,
+    /// Saves the profile under this name instead of the exported one.
+    name: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SettingsSyncConfigurePayload {
+    /// "off", "file" or "gist".
+    kind: String,
+    path: Option<String>,
+    /// An existing gist to sync with; a private one is created on the first
+    /// export when absent.
+    gist_id: Option<String>,
+    include_workspace_prefs: Option<bool>,
+}
+
+/// Payload of `settings_sync_export`, `settings_sync_import` and
+/// `settings_sync_merge`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SettingsSyncRunPayload {
+    /// Export or import even though the other side changed since the last
+    /// sync.
+    force: Option<bool>,
+    /// How a merge settles fields changed on both sides: "local" or
+    /// "remote". Conflicts are reported and nothing is written when absent.
+    prefer: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AppBackupCreatePayload {
+    /// Absolute, or starting with `~/`.
+    path: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AppBackupRestorePayload {
+    path: String,
+    /// Also write each bundled `.groove/workspace.json` back into its
+    /// workspace. Defaults to true.
+    include_workspaces: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SoundLibraryRemovePayload {
+    sound_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SoundLibraryReadPayload {
+    file_name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SoundLibraryRenamePayload {
+    sound_id: String,
+    new_name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SoundLibraryGetPathPayload {
+    sound_id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SoundLibraryPathResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    folder_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SoundLibraryReadResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GitAuthStatusPayload {
+    workspace_root: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GhSwitchPayload {
+    user: String,
+    #[serde(default)]
+    debug: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GhLogoutPayload {
+    user: String,
+    #[serde(default)]
+    debug: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GhLoginPayload {
+    token: String,
+    #[serde(default)]
+    debug: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GitPathPayload {
+    path: String,
+    /// Honored by the commands answering with a `GitCommandResponse`.
+    #[serde(default)]
+    debug: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitPullPayload {
+    path: String,
+    #[serde(default)]
+    rebase: bool,
+    #[serde(default)]
+    debug: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GitFetchBlobsPayload {
+    path: String,
+    /// Ref the worktree is compared against; defaults to its upstream.
+    #[serde(default)]
+    base: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitPushPayload {
+    path: String,
+    #[serde(default)]
+    set_upstream: bool,
+    #[serde(default)]
+    force_with_lease: bool,
+    #[serde(default)]
+    branch: Option<String>,
+    #[serde(default)]
+    debug: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitMergePayload {
+    path: String,
+    target_branch: String,
+    #[serde(default)]
+    ff_only: bool,
+    #[serde(default)]
+    debug: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitCommitPayload {
+    path: String,
+    #[serde(default)]
+    message: Option<String>,
+    /// Skip `pre-commit` and `commit-msg` hooks (`git commit --no-verify`).
+    #[serde(default)]
+    no_verify: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GitLintCommitMessagePayload {
+    path: String,
+    message: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GitFilesPayload {
+    path: String,
+    files: Vec<String>,
+    #[serde(default)]
+    debug: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GitDiffFilePayload {
+    path: String,
+    /// The file to diff, relative to the worktree. For a rename either the
+    /// old or the new path finds it.
+    file_path: String,
+    /// Diff the index against `HEAD` instead of the working tree against
+    /// the index.
+    #[serde(default)]
+    staged: bool,
+    /// Unchanged lines around each change; 3 when omitted.
+    #[serde(default)]
+    context_lines: Option<u32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GitDiffHunksPayload {
+    path: String,
+    /// List the staged hunks (index against `HEAD`) instead of the unstaged
+    /// ones (working tree against the index).
+    #[serde(default)]
+    staged: bool,
+}
+
+/// A hunk returned by `git_diff_hunks`, echoed back to stage or unstage it.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GitHunkSelection {
+    file_path: String,
+    old_start: u32,
+    old_lines: u32,
+    new_start: u32,
+    new_lines: u32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GitHunksPayload {
+    path: String,
+    hunks: Vec<GitHunkSelection>,
+    #[serde(default)]
+    debug: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitRebaseStartPayload {
+    path: String,
+    /// Branch or commit to replay the worktree's branch onto.
+    onto: String,
+    /// Stash local changes before the rebase and reapply them after.
+    #[serde(default)]
+    autostash: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitConflictResolvePayload {
+    path: String,
+    file_path: String,
+    /// `ours`, `theirs` or `base` to take that side as is, `content` to
+    /// write `content`, or `delete` to resolve by removing the file.
+    resolution: String,
+    /// Kept out of the action history.
+    #[serde(default, skip_serializing)]
+    content: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GitLogPayload {
+    path: String,
+    #[serde(default)]
+    offset: usize,
+    #[serde(default)]
+    limit: Option<usize>,
+    /// Branch or other ref to list; the worktree's `HEAD` when omitted.
+    #[serde(default)]
+    branch: Option<String>,
+    /// Matched like `git log --author`, against name and email.
+    #[serde(default)]
+    author: Option<String>,
+    /// Only commits touching this path, relative to the worktree.
+    #[serde(default)]
+    file_path: Option<String>,
+    /// Any date `git log --since`/`--until` accepts, e.g. `2024-05-01` or
+    /// `2 weeks ago`.
+    #[serde(default)]
+    since: Option<String>,
+    #[serde(default)]
+    until: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveTerminalOpenPayload {
+    root_name: Option<String>,
+    #[serde(default)]
+    known_worktrees: Vec<String>,
+    workspace_meta: Option<WorkspaceMetaContext>,
+    worktree: String,
+    target: Option<String>,
+    open_mode: Option<String>,
+    cols: Option<u16>,
+    rows: Option<u16>,
+    force_restart: Option<bool>,
+    open_new: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WorktreeSessionLayoutRestorePayload {
+    root_name: Option<String>,
+    #[serde(default)]
+    known_worktrees: Vec<String>,
+    workspace_meta: Option<WorkspaceMetaContext>,
+    worktree: String,
+    cols: Option<u16>,
+    rows: Option<u16>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveTerminalWritePayload {
+    root_name: Option<String>,
+    #[serde(default)]
+    known_worktrees: Vec<String>,
+    workspace_meta: Option<WorkspaceMetaContext>,
+    worktree: String,
+    session_id: Option<String>,
+    input: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveTerminalResizePayload {
+    root_name: Option<String>,
+    #[serde(default)]
+    known_worktrees: Vec<String>,
+    workspace_meta: Option<WorkspaceMetaContext>,
+    worktree: String,
+    session_id: Option<String>,
+    cols: u16,
+    rows: u16,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveTerminalClosePayload {
+    root_name: Option<String>,
+    #[serde(default)]
+    known_worktrees: Vec<String>,
+    workspace_meta: Option<WorkspaceMetaContext>,
+    worktree: String,
+    session_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveTerminalSessionPayload {
+    root_name: Option<String>,
+    #[serde(default)]
+    known_worktrees: Vec<String>,
+    workspace_meta: Option<WorkspaceMetaContext>,
+    worktree: String,
+    session_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveTerminalScrollbackPayload {
+    session_id: String,
+    /// Output offset to read from; defaults to the chunk just before the
+    /// in-memory snapshot.
+    offset: Option<u64>,
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveTerminalHistoryPayload {
+    root_name: Option<String>,
+    #[serde(default)]
+    known_worktrees: Vec<String>,
+    workspace_meta: Option<WorkspaceMetaContext>,
+    worktree: String,
+    /// Defaults to the most recently written history of the worktree.
+    session_id: Option<String>,
+    max_bytes: Option<usize>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveTerminalAttachPayload {
+    session_id: String,
+    /// `offset` of the last output event the terminal view saw; without it
+    /// only the in-memory snapshot is replayed.
+    offset: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveTerminalScrollbackRecordingPayload {
+    session_id: String,
+    recording: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RuntimeStateRow {
+    branch: String,
+    worktree: String,
+    log_state: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    log_target: Option<String>,
+    /// Only filled in when the list was requested with `includeResources`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resources: Option<WorktreeResourceUsage>,
+    /// Only filled in when the list was requested with `includeBadges`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    badges: Option<Vec<WorktreeBadge>>,
+    /// Worktree this one is stacked on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent: Option<String>,
+    /// Worktrees stacked on this one, by name.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    children: Vec<String>,
+}
+
+/// A status badge of a groove list row, such as `ahead 3` or `PR #42`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorktreeBadge {
+    /// `running`, `failing`, `dirty`, `ahead`, `behind` or `pr`.
+    kind: String,
+    label: String,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorktreeResourceUsage {
+    /// Summed over processes, so it can pass 100 on multi-core machines.
+    cpu_percent: f64,
+    rss_bytes: u64,
+    process_count: usize,
+}
+
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveListResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_root: Option<String>,
+    rows: HashMap<String, RuntimeStateRow>,
+    /// Worktrees in `filter.sort` order, when the list was sorted; the
+    /// page's worktrees in order, when it was paged.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order: Option<Vec<String>>,
+    /// The filter applied to `rows`, as requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<GrooveListFilter>,
+    /// Set when only one page of `rows` was asked for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<RowsPageInfo>,
+    stdout: String,
+    stderr: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cancelled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveCommandResponse {
+    request_id: String,
+    ok: bool,
+    exit_code: Option<i32>,
+    stdout: String,
+    stderr: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    /// Subprocesses the command ran, when it was called with `debug: true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    debug_transcripts: Option<Vec<SubprocessTranscript>>,
+}
+
+/// What removing a worktree would throw away or interrupt.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveRmSafetyScan {
+    /// Paths `git status` lists. `None` when it failed, which blocks removal
+    /// like uncommitted changes do.
+    uncommitted_changes: Option<u32>,
+    /// Commits on `HEAD` that no remote branch contains. `None` when the
+    /// repository has no remotes, where nothing can be pushed.
+    unpushed_commits: Option<u32>,
+    /// Processes working in the worktree, terminal shells excluded.
+    running_pids: Vec<i32>,
+    terminal_sessions: usize,
+    /// Categories with findings, in `GROOVE_RM_SAFETY_CATEGORIES` order.
+    blocking: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveRmResponse {
+    request_id: String,
+    ok: bool,
+    exit_code: Option<i32>,
+    stdout: String,
+    stderr: String,
+    /// Set when removal was refused over unacknowledged categories.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    safety: Option<GrooveRmSafetyScan>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    /// Subprocesses the command ran, when it was called with `debug: true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    debug_transcripts: Option<Vec<SubprocessTranscript>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveRmSafetyResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    safety: Option<GrooveRmSafetyScan>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// What an archive under `.groove/archives` holds, written next to the
+/// tarball as `<id>.json` and inside it as `archive.json`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveArchiveMetadata {
+    version: u32,
+    id: String,
+    worktree: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    branch: Option<String>,
+    base_ref: String,
+    /// Merge base of the worktree and `base_ref`; the diff applies on it.
+    base_commit: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    head_commit: Option<String>,
+    archived_at: String,
+    #[serde(default)]
+    untracked_files: usize,
+    #[serde(default)]
+    has_logs: bool,
+    /// Size of the tarball; 0 inside it.
+    #[serde(default)]
+    bytes: u64,
+}
+
+/// Response of `groove_archive` and `groove_unarchive`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveArchiveResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    archive: Option<GrooveArchiveMetadata>,
+    /// The worktree archived, or the one created by an unarchive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    worktree: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveArchiveListResponse {
+    request_id: String,
+    ok: bool,
+    /// Newest first.
+    archives: Vec<GrooveArchiveMetadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// How rebasing one stacked worktree onto its parent went.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorktreeRestackResult {
+    worktree: String,
+    /// Branch of the parent it was rebased onto.
+    onto: String,
+    ok: bool,
+    /// Set when the rebase stopped on conflicts; it is left in progress for
+    /// `git_rebase_continue` or `git_rebase_abort`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    conflicted_files: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorktreeRestackResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    worktree: Option<String>,
+    /// In rebase order. Children of a worktree that failed are skipped.
+    results: Vec<WorktreeRestackResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorktreeActionResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    action: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    worktree: Option<String>,
+    /// The response of the command the action ran.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorktreePathIssue {
+    kind: String,
+    message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveNewResponse {
+    request_id: String,
+    ok: bool,
+    exit_code: Option<i32>,
+    stdout: String,
+    stderr: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    worktree: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    preflight_issues: Vec<WorktreePathIssue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    /// Subprocesses the command ran, when it was called with `debug: true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    debug_transcripts: Option<Vec<SubprocessTranscript>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExternalUrlOpenResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceOpenDirectoryResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveStopResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    already_stopped: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pid: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveBulkResult {
+    worktree: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// `ok` is false when any worktree failed; `results` has one entry per
+/// requested worktree, in order.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveBulkResponse {
+    request_id: String,
+    ok: bool,
+    results: Vec<GrooveBulkResult>,
+    succeeded: usize,
+    failed: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveRecoverableListResponse {
+    request_id: String,
+    ok: bool,
+    grooves: Vec<RunningGrooveRecord>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveRecoverableClearPayload {
+    root_name: Option<String>,
+    #[serde(default)]
+    known_worktrees: Vec<String>,
+    workspace_meta: Option<WorkspaceMetaContext>,
+    /// Worktrees to clear; an empty list clears every running record for the workspace.
+    #[serde(default)]
+    worktrees: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveRecoverableClearResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveSummaryPayload {
+    root_name: Option<String>,
+    #[serde(default)]
+    known_worktrees: Vec<String>,
+    workspace_meta: Option<WorkspaceMetaContext>,
+    session_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DiscoverWorktreeUnitPayload {
+    root_name: Option<String>,
+    #[serde(default)]
+    known_worktrees: Vec<String>,
+    workspace_meta: Option<WorkspaceMetaContext>,
+    worktree: String,
+    session_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ClaimWorktreeRewardPayload {
+    worktree: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ClaimWorktreeRewardResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit: Option<WorktreeUnit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gold: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LootWorktreePayload {
+    worktree: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LootWorktreeResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit: Option<WorktreeUnit>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    loot: Vec<WorktreeLootEntry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inventory: Option<HashMap<String, u32>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DiscoverWorktreeUnitResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit: Option<WorktreeUnit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    level: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    raw_claude_output: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    was_new_discovery: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveSummaryResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    summaries: Vec<GrooveSummaryEntry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    compiled_summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveCommentPayload {
+    root_name: Option<String>,
+    #[serde(default)]
+    known_worktrees: Vec<String>,
+    workspace_meta: Option<WorkspaceMetaContext>,
+    worktree: String,
+    /// When true, also feed the Claude conversation since the last commit into
+    /// the drafting prompt (the Changes-panel "Draft commit comment" button).
+    #[serde(default)]
+    include_session: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveCommentResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment: Option<CommentRecord>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveCommentMarkCommittedPayload {
+    root_name: Option<String>,
+    #[serde(default)]
+    known_worktrees: Vec<String>,
+    workspace_meta: Option<WorkspaceMetaContext>,
+    worktree: String,
+    created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveCommentMarkCommittedResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment: Option<CommentRecord>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GroovePrAttachPayload {
+    root_name: Option<String>,
+    #[serde(default)]
+    known_worktrees: Vec<String>,
+    workspace_meta: Option<WorkspaceMetaContext>,
+    worktree: String,
+    url: String,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    base: Option<String>,
+    #[serde(default)]
+    head: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GroovePrDetachPayload {
+    root_name: Option<String>,
+    #[serde(default)]
+    known_worktrees: Vec<String>,
+    workspace_meta: Option<WorkspaceMetaContext>,
+    worktree: String,
+    url: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GroovePrResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pull_request: Option<PullRequestRecord>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveSummaryEntry {
+    session_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    worktree: Option<String>,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BackgroundActivityResponse {
+    request_id: String,
+    ok: bool,
+    paused: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    paused_since: Option<String>,
+    /// False when background activity already was in the requested state.
+    changed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceEventsResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_root: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Shared by `worktree_watch` and `worktree_unwatch`; `worktree_path` is the
+/// worktree watched after the call, if any.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorktreeWatchResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    worktree_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveTerminalSession {
+    session_id: String,
+    workspace_root: String,
+    worktree: String,
+    worktree_path: String,
+    command: String,
+    started_at: String,
+    cols: u16,
+    rows: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    snapshot: Option<String>,
+    /// The shell was stopped while idle and starts again on the next write.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    hibernated: bool,
+    /// Process id of the shell; absent while hibernated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pid: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveTerminalResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    session: Option<GrooveTerminalSession>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Offsets count bytes of session output since it started.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveTerminalScrollbackResponse {
+    request_id: String,
+    ok: bool,
+    session_id: String,
+    data: String,
+    /// Offset of the first byte of `data`.
+    offset: u64,
+    /// Oldest offset still available on disk.
+    start_offset: u64,
+    /// Offset where the in-memory snapshot begins.
+    snapshot_offset: u64,
+    /// Whether older scrollback than `data` is available.
+    has_more: bool,
+    recording: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveTerminalHistoryResponse {
+    request_id: String,
+    ok: bool,
+    /// Session the history belongs to; `None` when the worktree has none.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    session_id: Option<String>,
+    /// The last `max_bytes` of its output.
+    data: String,
+    /// Whether older output than `data` was left out.
+    truncated: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// One terminal output file on disk.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TerminalStorageSessionUsage {
+    session_id: String,
+    /// `history`, `scrollback` or `recording`.
+    kind: String,
+    bytes: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    modified_at: Option<String>,
+    /// An open session still writes to it, so it is never cleaned up.
+    live: bool,
+    /// Set for scrollback files of open sessions; closed sessions' scrollback
+    /// is not tied to a worktree anymore.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    worktree: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TerminalStorageWorktreeUsage {
+    worktree: String,
+    bytes: u64,
+    sessions: Vec<TerminalStorageSessionUsage>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TerminalStorageStatsResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_root: Option<String>,
+    quota_bytes: u64,
+    max_age_days: u64,
+    /// Terminal history of the workspace, counted against `quota_bytes`.
+    history_bytes: u64,
+    #[serde(default)]
+    worktrees: Vec<TerminalStorageWorktreeUsage>,
+    /// Scrollback files of all workspaces, counted against `quota_bytes` on
+    /// their own.
+    scrollback_bytes: u64,
+    #[serde(default)]
+    scrollback: Vec<TerminalStorageSessionUsage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Output missed since the payload offset, followed by live
+/// `groove-terminal-output` events; events whose `offset` is at most
+/// `end_offset` are already part of `data`.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveTerminalAttachResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    session: Option<GrooveTerminalSession>,
+    data: String,
+    /// Offset of the first byte of `data`.
+    offset: u64,
+    /// Offset right after the last byte of `data`.
+    end_offset: u64,
+    /// Whether part of the missed output is no longer available, so `data`
+    /// starts later than requested.
+    truncated: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveTerminalSessionsResponse {
+    request_id: String,
+    ok: bool,
+    sessions: Vec<GrooveTerminalSession>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorktreeSessionLayoutRestoreResponse {
+    request_id: String,
+    ok: bool,
+    sessions: Vec<GrooveTerminalSession>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    failures: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveTerminalActiveWorktreesResponse {
+    request_id: String,
+    ok: bool,
+    worktrees: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveTerminalActivityEntry {
+    session_id: String,
+    has_activity: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrooveTerminalActivityResponse {
+    request_id: String,
+    ok: bool,
+    entries: Vec<GrooveTerminalActivityEntry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceTerminalSettingsResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_root: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_meta: Option<WorkspaceMeta>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// What Play would run for a worktree, without running it.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PlayCommandPreviewResponse {
+    request_id: String,
+    ok: bool,
+    /// `command`, or `groove-terminal` and `tmux` for the templates that
+    /// open inside Groove and have no argv.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mode: Option<String>,
+    /// Program first, one entry per argument as the process gets it.
+    argv: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    worktree_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    branch: Option<String>,
+    /// Whether the target was appended because the template has no
+    /// placeholder.
+    target_appended: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SetWorktreeStateResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_root: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    worktree: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    record: Option<WorktreeRecord>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceAdoptWorktreeResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_root: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    worktree: Option<String>,
+    /// Where the worktree lives now; under `.worktrees/` when relocated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    relocated: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    record: Option<WorktreeRecord>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceMigrationEntry {
+    /// Where the worktree was found.
+    path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    branch: Option<String>,
+    /// Name the worktree has (or would have) in Groove.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    worktree: Option<String>,
+    /// `adopted` or `relocated` (`adopt` or `relocate` in a dry run),
+    /// `alreadyManaged`, `skipped` when the directory is gone, or `failed`.
+    action: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceMigrateResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_root: Option<String>,
+    /// `none`, `groove`, `bareRepository`, `siblingDirectory`, `nested` or
+    /// `mixed`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    layout: Option<String>,
+    dry_run: bool,
+    /// One entry per linked worktree of the repository.
+    #[serde(default)]
+    entries: Vec<WorkspaceMigrationEntry>,
+    /// Worktrees adopted by this run.
+    migrated: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceBrowseEntry {
+    name: String,
+    path: String,
+    is_dir: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceBrowseEntriesResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_root: Option<String>,
+    relative_path: String,
+    entries: Vec<WorkspaceBrowseEntry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SoundLibraryEntry {
+    pub id: String,
+    pub name: String,
+    pub file_name: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ClaudeCodeHookSoundEntry {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sound_id: Option<String>,
+}
+
+impl Default for ClaudeCodeHookSoundEntry {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            sound_id: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ClaudeCodeSoundSettings {
+    #[serde(default)]
+    pub notification: ClaudeCodeHookSoundEntry,
+    #[serde(default)]
+    pub stop: ClaudeCodeHookSoundEntry,
+}
+
+impl Default for ClaudeCodeSoundSettings {
+    fn default() -> Self {
+        Self {
+            notification: ClaudeCodeHookSoundEntry::default(),
+            stop: ClaudeCodeHookSoundEntry::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GrooveSoundHookEntry {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sound_id: Option<String>,
+}
+
+impl Default for GrooveSoundHookEntry {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            sound_id: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GrooveSoundSettings {
+    #[serde(default)]
+    pub play: GrooveSoundHookEntry,
+    #[serde(default)]
+    pub pause: GrooveSoundHookEntry,
+    #[serde(default)]
+    pub summary_start: GrooveSoundHookEntry,
+    #[serde(default)]
+    pub summary_end: GrooveSoundHookEntry,
+    #[serde(default)]
+    pub emergency: GrooveSoundHookEntry,
+    #[serde(default)]
+    pub remove: GrooveSoundHookEntry,
+}
+
+impl Default for GrooveSoundSettings {
+    fn default() -> Self {
+        Self {
+            play: GrooveSoundHookEntry::default(),
+            pause: GrooveSoundHookEntry::default(),
+            summary_start: GrooveSoundHookEntry::default(),
+            summary_end: GrooveSoundHookEntry::default(),
+            emergency: GrooveSoundHookEntry::default(),
+            remove: GrooveSoundHookEntry::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GlobalSettings {
+    #[serde(default = "default_true")]
+    telemetry_enabled: bool,
+    #[serde(default, alias = "disableGrooveLoadingSection")]
+    disable_groove_business: bool,
+    #[serde(default)]
+    hide_mascot: bool,
+    #[serde(default)]
+    hide_labels: bool,
+    #[serde(default)]
+    show_fps: bool,
+    #[serde(default)]
+    always_show_diagnostics_sidebar: bool,
+    #[serde(default)]
+    periodic_rerender_enabled: bool,
+    #[serde(default = "default_theme_mode")]
+    theme_mode: String,
+    #[serde(default = "default_keyboard_shortcut_leader")]
+    keyboard_shortcut_leader: String,
+    #[serde(default = "default_keyboard_leader_bindings")]
+    keyboard_leader_bindings: HashMap<String, String>,
+    #[serde(default = "default_opencode_settings")]
+    opencode_settings: OpencodeSettings,
+    #[serde(default)]
+    sound_library: Vec<SoundLibraryEntry>,
+    #[serde(default)]
+    claude_code_sound_settings: ClaudeCodeSoundSettings,
+    #[serde(default)]
+    groove_sound_settings: GrooveSoundSettings,
+    #[serde(default)]
+    terminal_memory_settings: TerminalMemorySettings,
+    #[serde(default)]
+    system_alert_settings: SystemAlertSettings,
+    /// How paths are written in logs: "off", "basename" or "hash".
+    #[serde(default = "default_telemetry_path_redaction")]
+    telemetry_path_redaction: String,
+    #[serde(default)]
+    custom_command_policy: CustomCommandPolicy,
+    /// Terminal opened in workspaces whose default terminal is "auto".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    default_terminal: Option<String>,
+    #[serde(default)]
+    polling_settings: PollingSettings,
+    #[serde(default)]
+    testing_environment_restart_policy: TestingEnvironmentRestartPolicy,
+    #[serde(default)]
+    status_page_settings: StatusPageSettings,
+    #[serde(default)]
+    command_retry_policy: CommandRetryPolicy,
+    /// Stamped by `write_global_settings_file`; settings sync compares it to
+    /// tell whether the settings changed since the last sync.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    updated_at: Option<String>,
+}
+
+/// Pauses between passes of the background poll loops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+struct PollingSettings {
+    /// The events worker of each open workspace.
+    workspace_events_interval_ms: u64,
+    /// The `worktree_watch` worker.
+    worktree_watch_interval_ms: u64,
+}
+
+impl Default for PollingSettings {
+    fn default() -> Self {
+        Self {
+            workspace_events_interval_ms: DEFAULT_WORKSPACE_EVENTS_POLL_INTERVAL_MS,
+            worktree_watch_interval_ms: DEFAULT_WORKTREE_WATCH_POLL_INTERVAL_MS,
+        }
+    }
+}
+
+/// What a settings profile puts over the global settings when switched to;
+/// unset fields are left as they are.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+struct SettingsProfileOverrides {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    telemetry_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    theme_mode: Option<String>,
+    /// "auto" clears the global default terminal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_terminal: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    polling_settings: Option<PollingSettings>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SettingsProfile {
+    name: String,
+    overrides: SettingsProfileOverrides,
+    updated_at: String,
+}
+
+/// `settings-profiles.json` in the app data directory.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+struct PersistedSettingsProfiles {
+    /// The profile last switched to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    active_profile: Option<String>,
+    profiles: Vec<SettingsProfile>,
+}
+
+/// The document `settings_profile_export` produces and
+/// `settings_profile_import` reads, meant to be kept with dotfiles.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExportedSettingsProfile {
+    groove_settings_profile_version: u32,
+    name: String,
+    overrides: SettingsProfileOverrides,
+}
+
+/// Where settings sync reads and writes its document.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+struct SettingsSyncTarget {
+    /// "off", "file" or "gist".
+    kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    /// Set by the first export when not given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gist_id: Option<String>,
+    /// Also sync the active workspace's preferences.
+    include_workspace_prefs: bool,
+}
+
+/// `settings-sync.json` in the app data directory.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+struct PersistedSettingsSync {
+    target: SettingsSyncTarget,
+    /// `updatedAt` of the document as of the last sync.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remote_updated_at: Option<String>,
+    /// `updatedAt` of the global settings right after the last sync.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    local_updated_at: Option<String>,
+    /// The document as of the last sync, the common base of a merge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    base: Option<SettingsSyncDocument>,
+}
+
+/// What settings sync writes to the file or gist.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SettingsSyncDocument {
+    groove_settings_sync_version: u32,
+    updated_at: String,
+    /// Global settings without `SETTINGS_SYNC_LOCAL_ONLY_FIELDS`.
+    settings: serde_json::Map<String, serde_json::Value>,
+    /// `SETTINGS_SYNC_WORKSPACE_FIELDS` of each synced workspace, by root
+    /// name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    workspaces: BTreeMap<String, serde_json::Map<String, serde_json::Value>>,
+}
+
+/// What `app_backup_create` writes.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AppBackupDocument {
+    groove_backup_version: u32,
+    created_at: String,
+    /// Contents of each `APP_BACKUP_FILES` entry that existed, by file name.
+    files: BTreeMap<String, serde_json::Value>,
+    /// `.groove/workspace.json` of each open workspace, by workspace root.
+    #[serde(default)]
+    workspaces: BTreeMap<String, serde_json::Value>,
+}
+
+/// Limits on the play, terminal and custom commands workspaces configure.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+struct CustomCommandPolicy {
+    /// Commands containing any of these as whole words are refused.
+    denied_patterns: Vec<String>,
+    /// When not empty, the only programs commands may start.
+    allowed_programs: Vec<String>,
+    /// Refuse programs given as a relative path or not found on `PATH`.
+    require_resolved_programs: bool,
+    /// What the first run of a command in a trusted workspace needs:
+    /// "allow", "confirm" or "block".
+    trusted_first_run: String,
+    /// The same for workspaces that are not trusted.
+    untrusted_first_run: String,
+}
+
+impl Default for CustomCommandPolicy {
+    fn default() -> Self {
+        Self {
+            denied_patterns: command_policy::DEFAULT_DENIED_COMMAND_PATTERNS
+                .map(String::from)
+                .to_vec(),
+            allowed_programs: Vec::new(),
+            require_resolved_programs: true,
+            trusted_first_run: FirstRunPolicy::Confirm.as_str().to_string(),
+            untrusted_first_run: FirstRunPolicy::Block.as_str().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+struct TerminalMemorySettings {
+    /// Scrollback kept per terminal session.
+    snapshot_max_bytes: usize,
+    /// Scrollback kept across all sessions; the oldest sessions are trimmed
+    /// first once it is exceeded.
+    snapshot_budget_bytes: usize,
+    /// Spill trimmed scrollback to a file per session instead of dropping it.
+    scrollback_persistence_enabled: bool,
+    scrollback_file_max_bytes: usize,
+    /// Append all output of each session to
+    /// `.groove/terminal-history/<worktree>/<session>.log` in its workspace,
+    /// so it can be restored after a restart. Capped like scrollback files.
+    history_persistence_enabled: bool,
+    /// Stop plain shells that sit idle with nothing running in them; they are
+    /// started again in the same directory on the next keystroke.
+    hibernate_idle_shells: bool,
+    hibernate_idle_minutes: u64,
+    /// Disk that terminal history may take per workspace, and scrollback
+    /// files in total; the oldest closed sessions' files go first.
+    storage_quota_bytes: u64,
+    /// History and recorded scrollback older than this are deleted.
+    storage_max_age_days: u64,
+}
+
+impl Default for TerminalMemorySettings {
+    fn default() -> Self {
+        Self {
+            snapshot_max_bytes: DEFAULT_GROOVE_TERMINAL_SNAPSHOT_BYTES,
+            snapshot_budget_bytes: DEFAULT_GROOVE_TERMINAL_SNAPSHOT_BUDGET_BYTES,
+            scrollback_persistence_enabled: false,
+            scrollback_file_max_bytes: DEFAULT_GROOVE_TERMINAL_SCROLLBACK_FILE_BYTES,
+            history_persistence_enabled: false,
+            hibernate_idle_shells: false,
+            hibernate_idle_minutes: DEFAULT_GROOVE_TERMINAL_HIBERNATE_IDLE_MINUTES,
+            storage_quota_bytes: DEFAULT_GROOVE_TERMINAL_STORAGE_QUOTA_BYTES,
+            storage_max_age_days: DEFAULT_GROOVE_TERMINAL_STORAGE_MAX_AGE_DAYS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+struct SystemAlertSettings {
+    enabled: bool,
+    /// Alert once RAM usage goes above this percentage.
+    ram_percent: u8,
+    /// Alert once the disk holding the active workspace is fuller than this.
+    disk_percent: u8,
+    /// Alert whenever any swap is in use.
+    swap_in_use: bool,
+    /// Also show alerts as native desktop notifications.
+    desktop_notifications: bool,
+}
+
+impl Default for SystemAlertSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            ram_percent: DEFAULT_SYSTEM_ALERT_RAM_PERCENT,
+            disk_percent: DEFAULT_SYSTEM_ALERT_DISK_PERCENT,
+            swap_in_use: true,
+            desktop_notifications: false,
+        }
+    }
+}
+
+/// How the supervisor restarts testing environments (Play sessions) that
+/// crash. Restarts are off unless `enabled`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+struct TestingEnvironmentRestartPolicy {
+    enabled: bool,
+    /// Restarts in a row before the supervisor gives up.
+    max_restarts: u32,
+    /// Wait before the first restart, doubled for each one after it.
+    backoff_ms: u64,
+}
+
+impl Default for TestingEnvironmentRestartPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_restarts: DEFAULT_TESTING_ENVIRONMENT_MAX_RESTARTS,
+            backoff_ms: DEFAULT_TESTING_ENVIRONMENT_RESTART_BACKOFF_MS,
+        }
+    }
+}
+
+/// The HTTP status page for monitoring Groove from a browser, script or
+/// status bar widget. Off unless `enabled`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+struct StatusPageSettings {
+    enabled: bool,
+    port: u16,
+    /// Listen on every interface instead of only 127.0.0.1. Requests from
+    /// other machines must then present an API token with `read`.
+    allow_remote: bool,
+}
+
+impl Default for StatusPageSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: DEFAULT_STATUS_PAGE_PORT,
+            allow_remote: false,
+        }
+    }
+}
+
+/// How git and gh runs that failed for a passing reason, like a dropped
+/// connection, are tried again. Only runs that are safe to repeat qualify:
+/// fetches, pulls, pushes and read-only gh calls.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+struct CommandRetryPolicy {
+    /// Tries in total, the first included; 1 turns retries off.
+    max_attempts: u32,
+    /// Wait before the first retry, doubled for each one after it.
+    backoff_ms: u64,
+    max_backoff_ms: u64,
+    /// Failure categories to retry: `network`, `timeout` and `lock`.
+    retry_on: Vec<String>,
+}
+
+impl Default for CommandRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: DEFAULT_COMMAND_RETRY_MAX_ATTEMPTS,
+            backoff_ms: DEFAULT_COMMAND_RETRY_BACKOFF_MS,
+            max_backoff_ms: DEFAULT_COMMAND_RETRY_MAX_BACKOFF_MS,
+            retry_on: [retry::RetryCategory::Network, retry::RetryCategory::Timeout]
+                .map(|category| category.as_str().to_string())
+                .to_vec(),
+        }
+    }
+}
+
+/// One resource checked by the system metrics sampler.
+#[derive(Debug, Clone, Copy)]
+struct SystemAlertReading {
+    kind: &'static str,
+    value_percent: f64,
+    threshold_percent: f64,
+    over: bool,
+    /// Memory that would have to be freed to get back under the threshold.
+    excess_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OpencodeIntegrationStatusResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_root: Option<String>,
+    workspace_scope_available: bool,
     global_scope_available: bool,
     effective_scope: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    workspace_settings: Option<OpencodeSettings>,
+    workspace_settings: Option<OpencodeSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    global_settings: Option<OpencodeSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OpencodeWorkspaceSettingsResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_root: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    settings: Option<OpencodeSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OpencodeGlobalSettingsResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    settings: Option<OpencodeSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OpenCodeStatusResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<OpenCodeStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OpencodeSettingsDirectoryValidationResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resolved_path: Option<String>,
+    directory_exists: bool,
+    opencode_config_exists: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OpencodeSkillsListResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    global_scope: Option<OpencodeSkillScope>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_scope: Option<OpencodeSkillScope>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OpencodeCopySkillsResponse {
+    request_id: String,
+    ok: bool,
+    copied_to_workspace: usize,
+    copied_to_global: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OpenCodeProfileResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    profile: Option<OpenCodeProfile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OpenCodeSyncResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<SyncResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OpenCodeRepairResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<OpenCodeRepairResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OpenCodeRunResponse {
+    request_id: String,
+    ok: bool,
+    result: OpenCodeRunResult,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OpenCodeCancelResponse {
+    request_id: String,
+    ok: bool,
+    result: CancelResult,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GlobalSettingsResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    global_settings: Option<GlobalSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Shared by the `settings_profile_*` commands. `global_settings` is set
+/// after a switch; `exported` by `settings_profile_export`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SettingsProfilesResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    active_profile: Option<String>,
+    profiles: Vec<SettingsProfile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    global_settings: Option<GlobalSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exported: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SettingsSyncResponse {
+    request_id: String,
+    ok: bool,
+    target: SettingsSyncTarget,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_synced_at: Option<String>,
+    /// Fields changed on both sides since the last sync, as `themeMode` or
+    /// `workspace:<root name>.defaultTerminal`.
+    conflicts: Vec<String>,
+    /// Set when the global settings were written.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    global_settings: Option<GlobalSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AppBackupResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    /// App data files written to or restored from the backup.
+    files: Vec<String>,
+    /// Workspace roots whose `workspace.json` was written or restored.
+    workspaces: Vec<String>,
+    /// Bundled workspace roots that do not exist on this machine, or that
+    /// were neither open nor already a Groove workspace.
+    skipped_workspaces: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitProfileStatus {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_email: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitSshStatus {
+    state: String,
+    message: String,
+}
+
+impl GitSshStatus {
+    fn unknown() -> Self {
+        Self {
+            state: "unknown".to_string(),
+            message: "SSH status unavailable".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitAuthStatusResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_root: Option<String>,
+    profile: GitProfileStatus,
+    ssh_status: GitSshStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GhAccount {
+    login: String,
+    active: bool,
+    scopes: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    protocol: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GhAuthStatusResponse {
+    request_id: String,
+    ok: bool,
+    installed: bool,
+    logged_in: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    active_account: Option<String>,
+    accounts: Vec<GhAccount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cancelled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    /// Runs that failed for a passing reason and were tried again.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retries: Option<Vec<CommandRetry>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GhCommandResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cancelled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    /// Subprocesses the command ran, when it was called with `debug: true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    debug_transcripts: Option<Vec<SubprocessTranscript>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GhSshOverviewPayload {
+    #[serde(default)]
+    workspace_root: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GhSshSetIdentityPayload {
+    workspace_root: String,
+    alias: String,
+    #[serde(default)]
+    debug: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GhSshIdentity {
+    alias: String,
+    hostname: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    identity_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    username: Option<String>,
+    auth_state: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GhRemoteOrigin {
+    url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    host: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    repo: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    matched_alias: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GhSshOverviewResponse {
+    request_id: String,
+    ok: bool,
+    config_found: bool,
+    identities: Vec<GhSshIdentity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    origin: Option<GhRemoteOrigin>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cancelled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GhWorktreePayload {
+    worktree_path: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GhIssueListPayload {
+    worktree_path: String,
+    /// `open` (default), `closed` or `all`.
+    state: Option<String>,
+    /// GitHub search query, e.g. `label:bug no:assignee`.
+    search: Option<String>,
+    limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GhIssueSummary {
+    number: i64,
+    title: String,
+    state: String,
+    url: String,
+    labels: Vec<String>,
+    assignees: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    updated_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GhIssueListResponse {
+    request_id: String,
+    ok: bool,
+    issues: Vec<GhIssueSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cancelled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    /// Runs that failed for a passing reason and were tried again.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retries: Option<Vec<CommandRetry>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GhIssueViewPayload {
+    worktree_path: String,
+    number: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GhIssueDetail {
+    number: i64,
+    title: String,
+    state: String,
+    url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<String>,
+    labels: Vec<String>,
+    assignees: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    global_settings: Option<OpencodeSettings>,
+    created_at: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
+    updated_at: Option<String>,
+    comments: Vec<GhPrComment>,
+    /// Branch name `groove_new` uses for this issue when none is given.
+    suggested_branch: String,
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct OpencodeWorkspaceSettingsResponse {
+struct GhIssueViewResponse {
     request_id: String,
     ok: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    workspace_root: Option<String>,
+    issue: Option<GhIssueDetail>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    settings: Option<OpencodeSettings>,
+    cancelled: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    /// Runs that failed for a passing reason and were tried again.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retries: Option<Vec<CommandRetry>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GhPrViewPayload {
+    worktree_path: String,
+    selector: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GhPrPreparePayload {
+    worktree_path: String,
+    base: String,
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct OpencodeGlobalSettingsResponse {
-    request_id: String,
-    ok: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    settings: Option<OpencodeSettings>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
+struct GhPrPrepareCommit {
+    sha: String,
+    author: String,
+    subject: String,
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct OpenCodeStatusResponse {
+struct GhPrPrepareResponse {
     request_id: String,
     ok: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    status: Option<OpenCodeStatus>,
+    title: Option<String>,
+    /// The pull request template, when the repository has one, followed by
+    /// the commit list, the linked issue and the co-authors.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    /// Commits between the base and `HEAD`, newest first.
+    commits: Vec<GhPrPrepareCommit>,
+    co_authors: Vec<String>,
+    /// Relative to the worktree, e.g. `.github/pull_request_template.md`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    template_path: Option<String>,
+    /// Issue the worktree was created from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    issue: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cancelled: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GhPrCreatePayload {
+    worktree_path: String,
+    /// Empty targets the branch of the worktree this one is stacked on.
+    #[serde(default)]
+    base: String,
+    /// Without a title, gh fills title and body from the branch's commits.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    #[serde(default)]
+    draft: bool,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct OpencodeSettingsDirectoryValidationResponse {
+struct GhPrCreateResponse {
     request_id: String,
     ok: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    resolved_path: Option<String>,
-    directory_exists: bool,
-    opencode_config_exists: bool,
+    number: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cancelled: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
 
+/// Edits an existing PR; only the fields that are set change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GhPrUpdatePayload {
+    worktree_path: String,
+    selector: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    /// An empty string clears the description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    base: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    add_labels: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    remove_labels: Vec<String>,
+    /// `true` marks a draft ready for review, `false` converts it back to a draft.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ready: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    debug: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GhPrMergePayload {
+    worktree_path: String,
+    selector: String,
+    /// `merge` (default), `squash` or `rebase`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    strategy: Option<String>,
+    /// Deletes the PR's head branch on GitHub once merged. The local branch is
+    /// left alone: it is usually checked out in a worktree.
+    #[serde(default)]
+    delete_branch: bool,
+    /// Overrides the merge or squash commit title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    subject: Option<String>,
+    /// Overrides the merge or squash commit body.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct OpencodeSkillsListResponse {
+struct GhPrMergeResponse {
     request_id: String,
     ok: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    global_scope: Option<OpencodeSkillScope>,
+    number: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    workspace_scope: Option<OpencodeSkillScope>,
+    merge_commit_sha: Option<String>,
+    branch_deleted: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cancelled: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GhPrCreateWebPayload {
+    worktree_path: String,
+    base: String,
+    #[serde(default)]
+    debug: Option<bool>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct OpencodeCopySkillsResponse {
+struct GhRepoDefaultBranchResponse {
     request_id: String,
     ok: bool,
-    copied_to_workspace: usize,
-    copied_to_global: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cancelled: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    /// Runs that failed for a passing reason and were tried again.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retries: Option<Vec<CommandRetry>>,
+}
+
+/// One PR as returned by `gh pr list --json ...`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GhPrSummary {
+    number: i64,
+    title: String,
+    state: String,
+    url: String,
+    #[serde(default)]
+    is_draft: bool,
+    /// Rolled-up CI status: `success`, `failure` or `pending`; `None` when the
+    /// PR has no checks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    checks_status: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct OpenCodeProfileResponse {
-    request_id: String,
-    ok: bool,
+struct WorktreeStalenessEntry {
+    worktree: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    profile: Option<OpenCodeProfile>,
+    branch: Option<String>,
+    stale: bool,
+    /// Rules the worktree matched: "noCommits", "branchMerged", "prClosed"
+    /// or "noOpencodeActivity".
+    reasons: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
+    last_commit_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_opencode_activity_at: Option<String>,
+    /// "archived" when the policy forgot the worktree, "delete" when it
+    /// asks for the worktree to be removed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    action: Option<String>,
+    /// Why a stale worktree was left alone: "running" or "dirty".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    skipped: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct OpenCodeSyncResponse {
+struct WorktreeStalenessReportResponse {
     request_id: String,
     ok: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    result: Option<SyncResult>,
+    workspace_root: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    policy: Option<WorktreeStalenessPolicy>,
+    worktrees: Vec<WorktreeStalenessEntry>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
 
+/// A directory under `<workspace>/.groove` packed while the workspace is
+/// frozen.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FrozenWorkspaceArchive {
+    /// Directory name relative to `.groove`, e.g. "terminal-history".
+    source: String,
+    /// Tarball path relative to `.groove`.
+    archive: String,
+    #[serde(default)]
+    bytes: u64,
+}
+
+/// What `workspace_freeze` stopped, persisted in `.groove/frozen.json` until
+/// `workspace_thaw`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FrozenWorkspaceState {
+    frozen_at: String,
+    #[serde(default)]
+    running_grooves: Vec<RunningGrooveRecord>,
+    #[serde(default)]
+    session_layouts: Vec<WorktreeSessionLayout>,
+    /// Terminal sessions that were open, hibernated ones included.
+    #[serde(default)]
+    closed_sessions: usize,
+    /// Node and opencode processes stopped after the sessions were closed.
+    #[serde(default)]
+    stopped_pids: Vec<i32>,
+    #[serde(default)]
+    archives: Vec<FrozenWorkspaceArchive>,
+}
+
+/// Response of `workspace_freeze` and `workspace_thaw`.
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct OpenCodeRepairResponse {
+struct WorkspaceFreezeResponse {
     request_id: String,
     ok: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    result: Option<OpenCodeRepairResult>,
+    workspace_root: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    frozen: Option<FrozenWorkspaceState>,
+    /// Sessions and Play targets reopened by a thaw, as `<worktree>: <mode>`.
+    restarted: Vec<String>,
+    /// Steps that failed without stopping the freeze or thaw.
+    failures: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
 
+/// A file two worktrees both changed since their merge base.
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct OpenCodeRunResponse {
-    request_id: String,
-    ok: bool,
-    result: OpenCodeRunResult,
+struct WorktreeConflictFile {
+    path: String,
+    /// The changes overlap or touch, which git reports as a conflict.
+    overlapping: bool,
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct OpenCodeCancelResponse {
-    request_id: String,
-    ok: bool,
-    result: CancelResult,
+struct WorktreeConflictPair {
+    left: String,
+    right: String,
+    /// "conflicting" when a file has overlapping changes, "sameFiles" when
+    /// the worktrees only change the same files, else "clear".
+    risk: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    merge_base: Option<String>,
+    files: Vec<WorktreeConflictFile>,
+    /// Why the pair could not be compared; `risk` is then "unknown".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GlobalSettingsResponse {
+struct WorkspaceConflictMatrixResponse {
     request_id: String,
     ok: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    global_settings: Option<GlobalSettings>,
+    workspace_root: Option<String>,
+    worktrees: Vec<String>,
+    /// One entry per pair of `worktrees`, in order.
+    pairs: Vec<WorktreeConflictPair>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize)]
+/// A worktree in a merge train and the step it is at.
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GitProfileStatus {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    user_name: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    user_email: Option<String>,
+struct MergeTrainEntry {
+    worktree: String,
+    pr_number: i64,
+    /// Next step to run: "rebase", "checks", "merge", "cleanup" or "done".
+    step: String,
 }
 
-#[derive(Debug, Clone, Default, Serialize)]
+/// Why a merge train stopped before its last worktree.
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GitSshStatus {
-    state: String,
+struct MergeTrainPause {
+    worktree: String,
+    step: String,
+    /// "conflicts", "checksFailed", "checksPending" or "failed".
+    reason: String,
     message: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    conflicted_files: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    failed_checks: Vec<String>,
 }
 
-impl GitSshStatus {
-    fn unknown() -> Self {
-        Self {
-            state: "unknown".to_string(),
-            message: "SSH status unavailable".to_string(),
-        }
-    }
+/// A merge train, persisted in `.groove/merge-train.json` so it can be
+/// resumed after a pause or a restart.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MergeTrainState {
+    base: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    strategy: Option<String>,
+    #[serde(default)]
+    keep_worktrees: bool,
+    worktree_dir: String,
+    /// "running", "paused", "completed" or "aborted".
+    status: String,
+    entries: Vec<MergeTrainEntry>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    paused: Option<MergeTrainPause>,
+    started_at: String,
+    updated_at: String,
 }
 
+/// Response of the `merge_train_*` commands.
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GitAuthStatusResponse {
+struct MergeTrainResponse {
     request_id: String,
     ok: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     workspace_root: Option<String>,
-    profile: GitProfileStatus,
-    ssh_status: GitSshStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    train: Option<MergeTrainState>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+/// The outcome of one run of a maintenance task.
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GhAccount {
-    login: String,
-    active: bool,
-    scopes: Vec<String>,
+struct MaintenanceRun {
+    started_at: String,
+    duration_ms: u64,
+    /// "schedule" or "manual".
+    trigger: String,
+    ok: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    workspace_root: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    summary: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Schedule and last run of a maintenance task. A task without `at` only
+/// runs through `maintenance_run_now`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+struct MaintenanceTaskState {
+    enabled: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    protocol: Option<String>,
+    at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_run: Option<MaintenanceRun>,
+}
+
+/// `maintenance.json` in the app data directory.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+struct PersistedMaintenance {
+    utc_offset_minutes: i32,
+    tasks: BTreeMap<String, MaintenanceTaskState>,
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GhAuthStatusResponse {
-    request_id: String,
-    ok: bool,
-    installed: bool,
-    logged_in: bool,
+struct MaintenanceTaskInfo {
+    task: String,
+    enabled: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    active_account: Option<String>,
-    accounts: Vec<GhAccount>,
+    at: Option<String>,
+    /// Next scheduled start, in UTC; unset when the task is not scheduled.
     #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
+    next_run_at: Option<String>,
+    running: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_run: Option<MaintenanceRun>,
 }
 
+/// Response of `maintenance_status`, `maintenance_configure` and
+/// `maintenance_run_now`.
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GhCommandResponse {
+struct MaintenanceResponse {
     request_id: String,
     ok: bool,
+    utc_offset_minutes: i32,
+    tasks: Vec<MaintenanceTaskInfo>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct GhSshOverviewPayload {
-    #[serde(default)]
-    workspace_root: Option<String>,
-}
-
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GhSshSetIdentityPayload {
-    workspace_root: String,
-    alias: String,
+struct TestingEnvironmentCrash {
+    at: String,
+    session_id: String,
+    reason: String,
 }
 
+/// A testing environment the supervisor has seen crash since the app
+/// started.
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GhSshIdentity {
-    alias: String,
-    hostname: String,
+struct TestingEnvironmentInfo {
+    workspace_root: String,
+    worktree: String,
+    /// "running", "restarting", "crashed" or "failed".
+    status: String,
+    /// Restarts since the environment last stayed up.
+    restarts: u32,
+    /// Session of the last restart while it is running.
     #[serde(skip_serializing_if = "Option::is_none")]
-    identity_file: Option<String>,
+    session_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    username: Option<String>,
-    auth_state: String,
+    next_restart_at: Option<String>,
+    /// Most recent last.
+    crashes: Vec<TestingEnvironmentCrash>,
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GhRemoteOrigin {
-    url: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    host: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    owner: Option<String>,
+struct TestingEnvironmentStatusResponse {
+    request_id: String,
+    ok: bool,
+    environments: Vec<TestingEnvironmentInfo>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    repo: Option<String>,
+    error: Option<String>,
+}
+
+/// A groove recorded as running, as shown on the status page.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatusPageRunningGroove {
+    workspace_root: String,
+    worktree: String,
+    command: String,
+    started_at: String,
+}
+
+/// What the status page serves at `/status.json`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatusPageSnapshot {
+    app_version: String,
+    generated_at: String,
+    /// The worst status of any health subsystem.
+    status: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    matched_alias: Option<String>,
+    active_workspace_root: Option<String>,
+    open_workspaces: Vec<String>,
+    running_grooves: Vec<StatusPageRunningGroove>,
+    testing_environments: Vec<TestingEnvironmentInfo>,
+    health: Vec<AppHealthSubsystem>,
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GhSshOverviewResponse {
+struct StatusPageStatusResponse {
     request_id: String,
     ok: bool,
-    config_found: bool,
-    identities: Vec<GhSshIdentity>,
+    settings: StatusPageSettings,
+    listening: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    origin: Option<GhRemoteOrigin>,
+    url: Option<String>,
+    /// Why the page is not listening although enabled, e.g. a port in use.
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GhWorktreePayload {
-    worktree_path: String,
-}
-
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct GhPrViewPayload {
-    worktree_path: String,
-    selector: String,
+struct TestingEnvironmentLogsTailPayload {
+    worktree: String,
+    /// Defaults to `DEFAULT_TESTING_LOG_TAIL_LINES`.
+    lines: Option<usize>,
+    /// Start (true) or stop (false) sending new lines as
+    /// `testing-environment-log` events. Left as is when absent.
+    follow: Option<bool>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct GhPrCreateWebPayload {
-    worktree_path: String,
-    base: String,
+struct TestingEnvironmentLogsClearPayload {
+    worktree: String,
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GhRepoDefaultBranchResponse {
+struct TestingEnvironmentLogsResponse {
     request_id: String,
     ok: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    default_branch: Option<String>,
+    workspace_root: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    worktree: Option<String>,
+    /// Oldest first; empty after a clear.
+    lines: Vec<String>,
+    /// Whether new lines are sent as events.
+    following: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
 
-/// One PR as returned by `gh pr list --json ...`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct GhPrSummary {
-    number: i64,
-    title: String,
-    state: String,
-    url: String,
-    #[serde(default)]
-    is_draft: bool,
-}
-
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct GhPrListResponse {
@@ -2019,7 +5101,12 @@ struct GhPrListResponse {
     branch: Option<String>,
     prs: Vec<GhPrSummary>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    cancelled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    /// Runs that failed for a passing reason and were tried again.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retries: Option<Vec<CommandRetry>>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -2032,6 +5119,65 @@ struct GhPrComment {
     created_at: Option<String>,
 }
 
+/// An inline review conversation on one file of a PR.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GhPrReviewThread {
+    id: String,
+    path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<i64>,
+    is_resolved: bool,
+    /// The diff the thread was left on has since changed.
+    is_outdated: bool,
+    comments: Vec<GhPrComment>,
+}
+
+/// A submitted review; `state` is `APPROVED`, `CHANGES_REQUESTED`,
+/// `COMMENTED` or `DISMISSED`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GhPrReview {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<String>,
+    state: String,
+    body: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    submitted_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GhPrCommentsResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    number: Option<i64>,
+    reviews: Vec<GhPrReview>,
+    threads: Vec<GhPrReviewThread>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cancelled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    /// Runs that failed for a passing reason and were tried again.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retries: Option<Vec<CommandRetry>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GhPrReviewPayload {
+    worktree_path: String,
+    selector: String,
+    /// `approve`, `requestChanges` or `comment`.
+    event: String,
+    /// Required for `requestChanges` and `comment`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    debug: Option<bool>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct GhPrDetail {
@@ -2062,6 +5208,48 @@ struct GhPrDetail {
     comments: Vec<GhPrComment>,
 }
 
+/// A check run, or a commit status from an external CI, on a PR's head.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GhPrCheck {
+    name: String,
+    /// As GitHub reports it: `QUEUED`, `IN_PROGRESS`, `COMPLETED`, `PENDING`...
+    status: String,
+    /// `SUCCESS`, `FAILURE`, `SKIPPED`...; set once the check completed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conclusion: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workflow: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GhPrChecksResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    number: Option<i64>,
+    checks: Vec<GhPrCheck>,
+    /// `success`, `failure` or `pending`; `None` without checks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    checks_status: Option<String>,
+    /// `MERGEABLE`, `CONFLICTING` or `UNKNOWN`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mergeable: Option<String>,
+    /// GitHub's merge state, e.g. `CLEAN`, `BLOCKED`, `BEHIND` or `DIRTY`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    merge_state_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cancelled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    /// Runs that failed for a passing reason and were tried again.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retries: Option<Vec<CommandRetry>>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct GhPrViewResponse {
@@ -2070,7 +5258,12 @@ struct GhPrViewResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pr: Option<GhPrDetail>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    cancelled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    /// Runs that failed for a passing reason and were tried again.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retries: Option<Vec<CommandRetry>>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -2085,8 +5278,65 @@ struct GitStatusResponse {
     deleted: u32,
     untracked: u32,
     dirty: bool,
+    /// Set while a rebase is stopped in the worktree.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rebase: Option<GitRebaseState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_snippet: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitRebaseState {
+    /// Branch being rebased; `None` for a rebase of a detached HEAD.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    branch: Option<String>,
+    /// Commit the branch is replayed onto.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    onto: Option<String>,
+    /// 1-based number of the commit being applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    step: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitRebaseStatusResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rebase: Option<GitRebaseState>,
+    /// Files left unmerged by the step the rebase stopped at.
+    #[serde(default)]
+    conflicted_files: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Result of `git_rebase_start`, `_continue`, `_skip` and `_abort`, with the
+/// rebase state left behind.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitRebaseResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exit_code: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     output_snippet: Option<String>,
+    /// Still set when the rebase stopped again, e.g. on the next conflict.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rebase: Option<GitRebaseState>,
+    #[serde(default)]
+    conflicted_files: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
@@ -2108,45 +5358,168 @@ struct GitCurrentBranchResponse {
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GitListBranchesResponse {
+struct GitListBranchesResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    #[serde(default)]
+    branches: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_snippet: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitAheadBehindResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    ahead: u32,
+    behind: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_snippet: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitFetchBlobsResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    /// `None` when the repository is a full clone and nothing had to be fetched.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    partial_clone_filter: Option<String>,
+    changed_paths: usize,
+    fetched_blobs: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitCommandResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exit_code: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_snippet: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    /// Subprocesses the command ran, when it was called with `debug: true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    debug_transcripts: Option<Vec<SubprocessTranscript>>,
+    /// Runs that failed for a passing reason and were tried again.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retries: Option<Vec<CommandRetry>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitHookRun {
+    hook: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exit_code: Option<i32>,
+    duration_ms: u64,
+    /// The hook's stdout and stderr, limited to the last lines.
+    output: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitCommitResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exit_code: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_snippet: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    /// Hooks and commit together.
+    duration_ms: u64,
+    /// `husky`, `pre-commit` or `lefthook` when one of them manages the hooks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hook_manager: Option<String>,
+    /// Hooks that ran before the commit, in order.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    hooks: Vec<GitHookRun>,
+    /// The hook that rejected the commit, if any; its output is in `hooks`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    failed_hook: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitCommitHooksResponse {
     request_id: String,
     ok: bool,
+    /// Installed hooks that `noVerify` would skip.
+    hooks: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    path: Option<String>,
-    #[serde(default)]
-    branches: Vec<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    output_snippet: Option<String>,
+    hook_manager: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GitAheadBehindResponse {
+struct GitCommitLintIssue {
+    /// commitlint-style rule name, e.g. `type-enum`.
+    rule: String,
+    message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitLintCommitMessageResponse {
     request_id: String,
     ok: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    path: Option<String>,
-    ahead: u32,
-    behind: u32,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    output_snippet: Option<String>,
+    /// False when the workspace has no `commitLint` rules; `issues` is then
+    /// always empty.
+    enabled: bool,
+    issues: Vec<GitCommitLintIssue>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct GitCommandResponse {
+struct GitUnsignedCommit {
+    sha: String,
+    subject: String,
+    /// `name <email>`.
+    author: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitPushPreflightResponse {
     request_id: String,
     ok: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     path: Option<String>,
+    /// Whether every check passed and the push can go ahead.
+    passed: bool,
+    dco_required: bool,
+    /// `workspace` (the `requireSignOff` option) or `repository` (a DCO app
+    /// config in the repository).
     #[serde(skip_serializing_if = "Option::is_none")]
-    exit_code: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    output_snippet: Option<String>,
+    dco_source: Option<String>,
+    checked_commits: usize,
+    unsigned_commits: Vec<GitUnsignedCommit>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
@@ -2189,6 +5562,12 @@ struct GitFileStatesResponse {
 struct GitDiffLine {
     kind: String,
     content: String,
+    /// Line number in the old file; `None` for added lines.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    old_line_number: Option<u32>,
+    /// Line number in the new file; `None` for removed lines.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_line_number: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -2228,6 +5607,128 @@ struct GitDiffResponse {
     error: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitDiffFileResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    staged: bool,
+    /// `None` when the file has no changes on the requested side.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file: Option<GitDiffFile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitDiffHunksResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    staged: bool,
+    #[serde(default)]
+    files: Vec<GitDiffFile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// One side of a conflicted file. `content` is left out for binary and
+/// oversized files, with the reason in `omitted`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitConflictSide {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+    /// `binary` or `tooLarge`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    omitted: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitConflictFile {
+    file_path: String,
+    /// `bothModified`, `bothAdded`, `deletedByUs`, `deletedByThem`,
+    /// `addedByUs`, `addedByThem` or `bothDeleted`.
+    conflict: String,
+    /// Each side is `None` when the file does not exist there.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    base: Option<GitConflictSide>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ours: Option<GitConflictSide>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    theirs: Option<GitConflictSide>,
+    /// The working tree file, conflict markers included.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    working: Option<GitConflictSide>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitConflictsResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    /// `merge`, `rebase`, `cherry-pick` or `revert`; `None` when nothing is
+    /// in progress.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    operation: Option<String>,
+    #[serde(default)]
+    files: Vec<GitConflictFile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitConflictResolveResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    /// Conflicted files left after this one was resolved.
+    remaining_conflicts: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_snippet: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitLogCommit {
+    hash: String,
+    short_hash: String,
+    parent_hashes: Vec<String>,
+    author_name: String,
+    author_email: String,
+    /// Author date in strict ISO 8601, with the author's offset.
+    authored_at: String,
+    subject: String,
+    /// Ref decorations, e.g. `HEAD -> main`, `origin/main`, `tag: v1.0`.
+    refs: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GitLogResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    #[serde(default)]
+    commits: Vec<GitLogCommit>,
+    offset: usize,
+    has_more: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct DiagnosticsStopResponse {
@@ -2256,6 +5757,53 @@ struct DiagnosticsStopAllResponse {
     error: Option<String>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DiagnosticsStopByCategoryPayload {
+    category: String,
+    /// When true, only report the processes that would be stopped.
+    preview: Option<bool>,
+    root_name: Option<String>,
+    #[serde(default)]
+    known_worktrees: Vec<String>,
+    workspace_meta: Option<WorkspaceMetaContext>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DiagnosticsStopTarget {
+    pid: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ppid: Option<i32>,
+    command: String,
+    /// "stopped", "alreadyStopped" or "failed"; absent in preview mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    outcome: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DiagnosticsStopByCategoryResponse {
+    request_id: String,
+    ok: bool,
+    category: String,
+    preview: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_root: Option<String>,
+    /// Processes matched before anything was stopped.
+    targets: Vec<DiagnosticsStopTarget>,
+    /// Targeted processes still running after execution.
+    remaining: Vec<DiagnosticsStopTarget>,
+    attempted: usize,
+    stopped: usize,
+    already_stopped: usize,
+    failed: usize,
+    #[serde(default)]
+    errors: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct DiagnosticsNodeAppRow {
@@ -2330,6 +5878,117 @@ struct DiagnosticsSystemOverviewResponse {
     error: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DiagnosticsTerminalSessionMemory {
+    session_id: String,
+    workspace_root: String,
+    worktree: String,
+    command: String,
+    started_at: String,
+    snapshot_bytes: usize,
+    trimmed_bytes: u64,
+    scrollback_file_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DiagnosticsWorktreeResourceRow {
+    worktree: String,
+    path: String,
+    usage: WorktreeResourceUsage,
+    pids: Vec<i32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DiagnosticsWorktreeResourcesResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_root: Option<String>,
+    /// Heaviest first, by CPU and then memory.
+    rows: Vec<DiagnosticsWorktreeResourceRow>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    warning: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DiagnosticsFdCounts {
+    fds: usize,
+    inotify_instances: usize,
+    inotify_watches: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DiagnosticsFdHolderRow {
+    /// "groove" for the app's own descriptors, named by subsystem, "worktree"
+    /// for processes running in a worktree and "other" for the rest.
+    kind: String,
+    name: String,
+    counts: DiagnosticsFdCounts,
+    pids: Vec<i32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DiagnosticsFdLimits {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    open_files_soft: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    open_files_hard: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inotify_max_user_watches: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inotify_max_user_instances: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system_open_files: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system_max_files: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DiagnosticsFdUsage {
+    /// Descriptors held by the app process itself.
+    groove: DiagnosticsFdCounts,
+    /// Every process the app can inspect, which is those of the same user;
+    /// inotify limits apply to this total.
+    user: DiagnosticsFdCounts,
+    limits: DiagnosticsFdLimits,
+    rows: Vec<DiagnosticsFdHolderRow>,
+    hints: Vec<String>,
+    warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DiagnosticsFdUsageResponse {
+    request_id: String,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    usage: Option<DiagnosticsFdUsage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DiagnosticsTerminalMemoryResponse {
+    request_id: String,
+    ok: bool,
+    /// Largest snapshot first.
+    sessions: Vec<DiagnosticsTerminalSessionMemory>,
+    total_snapshot_bytes: usize,
+    limits: TerminalMemorySettings,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct GrooveBinCheckStatus {
@@ -2344,6 +6003,29 @@ struct GrooveBinCheckStatus {
     effective_binary_source: String,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AppHealthSubsystem {
+    name: String,
+    /// "ok", "warning" or "error".
+    status: String,
+    summary: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AppHealthResponse {
+    request_id: String,
+    ok: bool,
+    /// The worst status of any subsystem.
+    status: String,
+    app_version: String,
+    checked_at: String,
+    subsystems: Vec<AppHealthSubsystem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct GrooveBinStatusResponse {
@@ -2382,6 +6064,15 @@ struct ProcessSnapshotRow {
     command: String,
 }
 
+#[derive(Debug, Clone)]
+struct ProcessResourceRow {
+    pid: i32,
+    ppid: Option<i32>,
+    cpu_percent: f64,
+    rss_bytes: u64,
+    command: String,
+}
+
 #[derive(Debug, Clone)]
 struct CandidateRoot {
     root_path: PathBuf,
@@ -2397,17 +6088,66 @@ struct CommandResult {
     error: Option<String>,
 }
 
+/// One subprocess a command called with `debug: true` ran.
+/// A failed try of a git or gh run that was tried again, so a slow answer
+/// can be explained.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CommandRetry {
+    /// The program and its subcommand, e.g. `git fetch`.
+    command: String,
+    /// 1 for the first try.
+    attempt: u32,
+    /// `network`, `timeout` or `lock`.
+    category: String,
+    error: String,
+    /// Wait before the next try.
+    backoff_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SubprocessTranscript {
+    /// The program followed by its arguments.
+    argv: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cwd: Option<String>,
+    /// Variables set (`Some`) or removed (`None`) on top of the app's own
+    /// environment. Secret-looking values read `"<redacted>"`.
+    env: BTreeMap<String, Option<String>>,
+    duration_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exit_code: Option<i32>,
+    stdout: String,
+    stderr: String,
+    /// Set when `stdout` or `stderr` were cut to their tail.
+    output_truncated: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct SnapshotEntry {
     exists: bool,
     mtime_ms: u128,
 }
 
+/// A path the workspace event worker polls. Per-worktree targets carry the
+/// worktree name so changes can be attributed to it.
+#[derive(Debug, Clone)]
+struct WorkspacePollTarget {
+    path: PathBuf,
+    worktree: Option<String>,
+    is_metadata: bool,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct WorkspaceContextSignature {
     workspace_manifest: SnapshotEntry,
     worktrees_dir: SnapshotEntry,
     worktree_execution_state_file: SnapshotEntry,
+    /// See `workspace_git_marker`.
+    git_marker: SnapshotEntry,
 }
 
 /// Result of registering Groove's embedded MCP server as an HTTP transport in
@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 // ---------------------------------------------------------------------------
@@ -139,6 +139,57 @@ fn run_taskkill(args: &[&str]) -> Result<KillResult, String> {
     })
 }
 
+/// When a process started and the command line it runs, as reported by the
+/// OS. The start time is only compared with earlier readings for the same
+/// PID, so its format differs per platform.
+pub struct ProcessIdentity {
+    pub started_at: String,
+    pub command: String,
+}
+
+/// Read the identity of a running process, or `None` when it is not running
+/// or cannot be inspected.
+pub fn read_process_identity(pid: i32) -> Option<ProcessIdentity> {
+    if pid <= 0 {
+        return None;
+    }
+
+    match Platform::current() {
+        Platform::Linux | Platform::MacOS => {
+            let output = Command::new("ps")
+                .args(["-o", "lstart=,args=", "-p", &pid.to_string()])
+                .env("LC_ALL", "C")
+                .output()
+                .ok()?;
+            if !output.status.success() {
+                return None;
+            }
+            // `lstart` is always five fields, e.g. `Fri Oct 16 09:12:03 2026`.
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let mut tokens = stdout.split_whitespace();
+            let started_at = tokens.by_ref().take(5).collect::<Vec<_>>().join(" ");
+            let command = tokens.collect::<Vec<_>>().join(" ");
+            (!started_at.is_empty()).then_some(ProcessIdentity {
+                started_at,
+                command,
+            })
+        }
+        Platform::Windows => {
+            let query = format!(
+                "$p = Get-CimInstance Win32_Process -Filter \"ProcessId = {pid}\"; if ($p) {{ $p.CreationDate.ToUniversalTime().ToString('o'); $p.CommandLine }}"
+            );
+            let stdout = run_powershell_query(&query).ok()?;
+            let mut lines = stdout.lines();
+            let started_at = lines.next()?.trim().to_string();
+            let command = lines.next().unwrap_or_default().trim().to_string();
+            (!started_at.is_empty()).then_some(ProcessIdentity {
+                started_at,
+                command,
+            })
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // 3. Process snapshot listing
 // ---------------------------------------------------------------------------
@@ -189,6 +240,39 @@ fn list_process_snapshot_windows() -> Result<ProcessSnapshotOutput, String> {
     })
 }
 
+/// Like `list_process_snapshot_raw`, with CPU and resident memory per process.
+/// Unix rows are `pid ppid %cpu rss-kib args`; Windows rows are CSV with the
+/// working set in bytes and no CPU figure.
+pub fn list_process_resources_raw() -> Result<ProcessSnapshotOutput, String> {
+    match Platform::current() {
+        Platform::Windows => {
+            let command = "Get-CimInstance Win32_Process | Select-Object ProcessId,ParentProcessId,WorkingSetSize,CommandLine | ConvertTo-Csv -NoTypeInformation";
+            Ok(ProcessSnapshotOutput {
+                stdout: run_powershell_query(command)?,
+                warning: Some("CPU usage per process is not available on Windows.".to_string()),
+            })
+        }
+        Platform::Linux | Platform::MacOS => {
+            let output = Command::new("ps")
+                .args(["-eo", "pid=,ppid=,pcpu=,rss=,args="])
+                .output()
+                .map_err(|error| format!("Failed to execute ps: {error}"))?;
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+                return Err(if stderr.is_empty() {
+                    "ps failed while listing process resources.".to_string()
+                } else {
+                    format!("ps failed: {stderr}")
+                });
+            }
+            Ok(ProcessSnapshotOutput {
+                stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+                warning: None,
+            })
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // 4. Memory-consuming programs
 // ---------------------------------------------------------------------------
@@ -620,9 +704,17 @@ fn calculate_dir_size_walk(path: &Path) -> u64 {
             if file_type.is_symlink() {
                 continue;
             }
+            // Junctions and other reparse points may lead outside `path` or
+            // back into it; their targets are not counted.
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if is_reparse_point(&metadata) {
+                continue;
+            }
             if file_type.is_dir() {
                 stack.push(entry.path());
-            } else if let Ok(metadata) = entry.metadata() {
+            } else {
                 total = total.saturating_add(metadata.len());
             }
         }
@@ -766,6 +858,29 @@ pub fn create_symlink(source: &Path, destination: &Path) -> Result<(), std::io::
     }
 }
 
+/// Directory junction, which Windows allows without the symlink privilege.
+/// Not available on other platforms.
+pub fn create_directory_junction(source: &Path, destination: &Path) -> Result<(), String> {
+    match Platform::current() {
+        Platform::Windows => {
+            let output = Command::new("cmd")
+                .args(["/C", "mklink", "/J"])
+                .arg(destination)
+                .arg(source)
+                .output()
+                .map_err(|error| format!("Failed to run mklink: {error}"))?;
+            if output.status.success() {
+                Ok(())
+            } else {
+                Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+            }
+        }
+        Platform::Linux | Platform::MacOS => {
+            Err("Directory junctions are only available on Windows.".to_string())
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // 12. Terminfo probe
 // ---------------------------------------------------------------------------
@@ -892,6 +1007,116 @@ pub fn appimage_cleaned_env() -> Vec<(String, Option<String>)> {
     Vec::new()
 }
 
+// ---------------------------------------------------------------------------
+// 15. Desktop notifications
+// ---------------------------------------------------------------------------
+
+pub fn show_desktop_notification(title: &str, body: &str) -> Result<(), String> {
+    let output = match Platform::current() {
+        Platform::Linux => Command::new("notify-send")
+            .args(["--app-name=Groove", title, body])
+            .output(),
+        Platform::MacOS => {
+            let quote = |value: &str| {
+                format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+            };
+            Command::new("osascript")
+                .arg("-e")
+                .arg(format!(
+                    "display notification {} with title {}",
+                    quote(body),
+                    quote(title)
+                ))
+                .output()
+        }
+        Platform::Windows => {
+            let quote = |value: &str| format!("'{}'", value.replace('\'', "''"));
+            let script = format!(
+                "[Windows.UI.Notifications.ToastNotificationManager, Windows.UI.Notifications, ContentType = WindowsRuntime] > $null; \
+                 $xml = [Windows.UI.Notifications.ToastNotificationManager]::GetTemplateContent([Windows.UI.Notifications.ToastTemplateType]::ToastText02); \
+                 $texts = $xml.GetElementsByTagName('text'); \
+                 $texts.Item(0).AppendChild($xml.CreateTextNode({})) > $null; \
+                 $texts.Item(1).AppendChild($xml.CreateTextNode({})) > $null; \
+                 [Windows.UI.Notifications.ToastNotificationManager]::CreateToastNotifier('Groove').Show([Windows.UI.Notifications.ToastNotification]::new($xml))",
+                quote(title),
+                quote(body)
+            );
+            return run_powershell_query(&script).map(|_| ());
+        }
+    }
+    .map_err(|error| format!("Failed to show desktop notification: {error}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(if stderr.is_empty() {
+            "Desktop notification failed.".to_string()
+        } else {
+            format!("Desktop notification failed: {stderr}")
+        });
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// 16. Reparse points (Windows junctions and symlinks)
+// ---------------------------------------------------------------------------
+
+/// Whether `metadata`, read without following links, is a Windows reparse
+/// point such as a junction, a symlink or a mount point. Always false
+/// elsewhere.
+pub fn is_reparse_point(metadata: &std::fs::Metadata) -> bool {
+    #[cfg(windows)]
+    {
+        use std::os::windows::fs::MetadataExt;
+        const FILE_ATTRIBUTE_REPARSE_POINT: u32 = 0x400;
+        metadata.file_attributes() & FILE_ATTRIBUTE_REPARSE_POINT != 0
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = metadata;
+        false
+    }
+}
+
+/// Where the reparse point at `path` leads, or `None` when `path` is not one.
+pub fn reparse_point_target(path: &Path) -> Option<PathBuf> {
+    let metadata = std::fs::symlink_metadata(path).ok()?;
+    if !is_reparse_point(&metadata) {
+        return None;
+    }
+    path.canonicalize().ok().map(strip_verbatim_prefix)
+}
+
+/// `path` with links resolved, except a final component that is a reparse
+/// point: the result names the junction itself rather than where it leads.
+/// The `\\?\` prefix Windows puts on canonical paths is dropped so the
+/// result compares equal to paths that never went through `canonicalize`.
+pub fn canonicalize_entry_path(path: &Path) -> std::io::Result<PathBuf> {
+    let entry_is_reparse_point = std::fs::symlink_metadata(path)
+        .map(|metadata| is_reparse_point(&metadata))
+        .unwrap_or(false);
+    let resolved = match (entry_is_reparse_point, path.parent(), path.file_name()) {
+        (true, Some(parent), Some(name)) => parent.canonicalize()?.join(name),
+        _ => path.canonicalize()?,
+    };
+    Ok(strip_verbatim_prefix(resolved))
+}
+
+fn strip_verbatim_prefix(path: PathBuf) -> PathBuf {
+    if Platform::current() != Platform::Windows {
+        return path;
+    }
+    let rendered = path.to_string_lossy();
+    if let Some(share) = rendered.strip_prefix(r"\\?\UNC\") {
+        PathBuf::from(format!(r"\\{share}"))
+    } else if let Some(local) = rendered.strip_prefix(r"\\?\") {
+        PathBuf::from(local)
+    } else {
+        path
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Shared helpers
 // ---------------------------------------------------------------------------
@@ -1,12 +1,12 @@
 use portable_pty::{native_pty_system, Child as PtyChild, CommandBuilder, MasterPty, PtySize};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs;
-use std::io::{Read, Write};
+use std::io::{Read, Seek, SeekFrom, Write};
 use std::path::{Component, Path, PathBuf};
 use std::process::{Command, Stdio};
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::sync::{Arc, Condvar, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8, AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
 use std::thread::{self, JoinHandle};
 use std::time::{Duration, Instant, UNIX_EPOCH};
 use tauri::{AppHandle, Emitter, Manager, State};
@@ -15,8 +15,25 @@ use time::OffsetDateTime;
 use uuid::Uuid;
 use walkdir::WalkDir;
 
-use crate::diagnostics;
-use crate::git_gh;
-use crate::terminal::{self, GrooveTerminalOpenMode};
-use crate::workspace;
+use crate::command_errors;
+use crate::core::capabilities::{self, Capability};
+use crate::core::command_policy::{self, FirstRunPolicy};
+use crate::core::commit_lint;
+use crate::core::diagnostics;
+use crate::core::fuzzy;
+use crate::core::git;
+use crate::core::redaction::{self, PathRedaction};
+use crate::core::remote;
+use crate::core::retry;
+use crate::core::terminal::{self, GrooveTerminalOpenMode};
+use crate::core::tmux;
+use crate::core::workspace;
+use crate::core::wsl;
+use crate::core::zellij;
+use crate::events::{
+    self, BackendHeartbeatEvent, BackgroundActivityEvent, CustomCommandConfirmationEvent, GitProgressEvent, GitQueueWaitEvent, GrooveCommandCompleteEvent, GrooveCommandOutputEvent, GrooveEvent,
+    GrooveNotificationEvent, GrooveTerminalLifecycleEvent, GrooveTerminalOutputEvent,
+    StateRecoveredEvent, SystemAlertEvent, TestingEnvironmentLogEvent, TestingEnvironmentStatusEvent, WorkspaceChangeEvent, WorkspaceContextCompleteEvent,
+    WorkspaceReadyEvent, WorktreeChangeReason, WorktreeEvictedEvent, WorktreeWatchEvent,
+};
 
@@ -49,15 +49,144 @@ fn resolve_remote_url_with_fallback(repository_root: &Path) -> Option<(String, S
     first_non_empty_line(&remote_result.stdout).map(|url| (remote_name, url))
 }
 
+/// Where `path` lives inside WSL, when the app runs on Windows and `path` is
+/// on a `\\wsl.localhost` share. Git and terminals for such paths run in the
+/// distro through `wsl.exe`; Windows git on the share is slow and trips over
+/// file ownership and line endings.
+fn wsl_location(path: &Path) -> Option<wsl::WslPath> {
+    if !cfg!(windows) {
+        return None;
+    }
+    wsl::parse_wsl_unc_path(&path.to_string_lossy())
+}
+
+/// `git -C <path>`, or the same git run inside the distro for WSL paths,
+/// with the workspace's git identity overrides applied.
+fn git_command_at(path: &Path) -> Command {
+    let config_args = git::config_override_args(&workspace_git_config_for_path(path));
+    match wsl_location(path) {
+        Some(location) => {
+            let mut command = Command::new("wsl.exe");
+            command
+                .args(wsl::wsl_exec_args(
+                    &location.distro,
+                    &location.path,
+                    &["git"],
+                ))
+                .args(config_args);
+            command
+        }
+        None => {
+            let mut command = Command::new("git");
+            command.args(config_args).arg("-C").arg(path);
+            command
+        }
+    }
+}
+
+type WorkspaceGitConfigCache =
+    Mutex<HashMap<PathBuf, (Option<std::time::SystemTime>, Vec<(&'static str, String)>)>>;
+
+static WORKSPACE_GIT_CONFIG: std::sync::OnceLock<WorkspaceGitConfigCache> =
+    std::sync::OnceLock::new();
+
+fn workspace_git_identity_pairs(identity: &WorkspaceGitIdentity) -> Vec<(&'static str, String)> {
+    git::identity_config_pairs(
+        identity.user_name.as_deref(),
+        identity.user_email.as_deref(),
+        identity.ssh_command.as_deref(),
+    )
+}
+
+/// The nearest `.groove/workspace.json` above `path`, i.e. the metadata of
+/// the workspace a worktree belongs to.
+fn workspace_json_for_path(path: &Path) -> Option<PathBuf> {
+    path.ancestors()
+        .map(|ancestor| ancestor.join(".groove").join("workspace.json"))
+        .find(|candidate| candidate.is_file())
+}
+
+/// Git config overrides of the workspace containing `path`. A workspace that
+/// is not trusted gets no `core.sshCommand` from its settings and runs with
+/// `UNTRUSTED_WORKSPACE_GIT_CONFIG`.
+fn workspace_git_config_for_path(path: &Path) -> Vec<(&'static str, String)> {
+    let Some(workspace_json) = workspace_json_for_path(path) else {
+        return Vec::new();
+    };
+    let trusted = workspace_json
+        .parent()
+        .and_then(Path::parent)
+        .is_some_and(workspace_trusted);
+    let pairs = cached_workspace_git_config(&workspace_json);
+    if trusted {
+        return pairs;
+    }
+    pairs
+        .into_iter()
+        .filter(|(key, _)| *key != "core.sshCommand")
+        .chain(
+            UNTRUSTED_WORKSPACE_GIT_CONFIG
+                .iter()
+                .map(|(key, value)| (*key, value.to_string())),
+        )
+        .collect()
+}
+
+/// The git identity overrides of `workspace_json`. Parsed once per
+/// modification, since this runs for every git invocation.
+fn cached_workspace_git_config(workspace_json: &Path) -> Vec<(&'static str, String)> {
+    let modified = fs::metadata(workspace_json)
+        .and_then(|metadata| metadata.modified())
+        .ok();
+
+    let cache = WORKSPACE_GIT_CONFIG.get_or_init(Default::default);
+    if let Some((cached_modified, pairs)) =
+        lock_or_recover(cache, "workspaceGitConfig", recover_by_clearing).get(workspace_json)
+    {
+        if modified.is_some() && *cached_modified == modified {
+            return pairs.clone();
+        }
+    }
+
+    let pairs = fs::read_to_string(workspace_json)
+        .ok()
+        .and_then(|raw| serde_json::from_str::<serde_json::Value>(&raw).ok())
+        .and_then(|value| {
+            serde_json::from_value::<WorkspaceGitIdentity>(value.get("gitIdentity")?.clone()).ok()
+        })
+        .map(|identity| workspace_git_identity_pairs(&identity))
+        .unwrap_or_default();
+    lock_or_recover(cache, "workspaceGitConfig", recover_by_clearing)
+        .insert(workspace_json.to_path_buf(), (modified, pairs.clone()));
+    pairs
+}
+
+/// One field of the `workspace.json` governing `path`, read fresh each call.
+fn workspace_meta_field_for_path<T: serde::de::DeserializeOwned>(
+    path: &Path,
+    field: &str,
+) -> Option<T> {
+    let raw = fs::read_to_string(workspace_json_for_path(path)?).ok()?;
+    let value = serde_json::from_str::<serde_json::Value>(&raw).ok()?;
+    serde_json::from_value(value.get(field)?.clone()).ok()
+}
+
+/// Turns a path printed by git running at `repository` back into one the
+/// app can open: Linux paths from a WSL distro become their UNC share path.
+fn host_path_from_git_output(repository: &Path, path: &str) -> PathBuf {
+    match wsl_location(repository) {
+        Some(location) => PathBuf::from(wsl::wsl_to_windows_unc(&location.distro, path)),
+        None => PathBuf::from(path),
+    }
+}
+
 fn validate_git_worktree_path(path: &str) -> Result<PathBuf, String> {
     let candidate = validate_existing_path(path)?;
     if !path_is_directory(&candidate) {
         return Err("path must point to an existing directory.".to_string());
     }
 
-    let result = Command::new("git")
-        .arg("-C")
-        .arg(&candidate)
+    let result = git_command_at(&candidate)
         .args(["rev-parse", "--is-inside-work-tree"])
         .output();
 
@@ -78,10 +207,210 @@ fn validate_git_worktree_path(path: &str) -> Result<PathBuf, String> {
     }
 }
 
+type GitOperationQueues = Mutex<HashMap<String, Arc<git::GitOperationQueue>>>;
+
+static GIT_OPERATION_QUEUES: std::sync::OnceLock<GitOperationQueues> = std::sync::OnceLock::new();
+
+/// Waits for a turn on the repository's operation queue. Worktrees of one
+/// repository share its `.git` locks, so the queue is keyed by the common git
+/// dir: reads run alongside each other, writes run alone, and fetches and
+/// pushes run one at a time beside both. Long waits are logged and surfaced
+/// as `GitQueueWaitEvent`s with the queue position.
+fn acquire_git_operation(path: &Path, args: &[&str]) -> git::GitOperationPermit {
+    let repository = git::resolve_git_common_dir(path).unwrap_or_else(|| path.to_path_buf());
+    let queue = {
+        let queues = GIT_OPERATION_QUEUES.get_or_init(Default::default);
+        let mut queues = lock_or_recover(queues, "gitOperationQueues", recover_by_clearing);
+        Arc::clone(queues.entry(path_storage_key(&repository)).or_default())
+    };
+
+    let operation = args.first().copied().unwrap_or_default();
+    queue.acquire(
+        git::classify_git_operation(args),
+        GIT_OPERATION_QUEUE_REPORT_INTERVAL,
+        |ahead, waited| {
+            log_line(&format!(
+                "[git-queue] waiting repository={} operation={operation} ahead={ahead} waited_ms={}",
+                repository.display(),
+                waited.as_millis()
+            ));
+            emit_background_groove_event(&GitQueueWaitEvent {
+                repository: repository.display().to_string(),
+                operation: operation.to_string(),
+                ahead,
+                waited_ms: waited.as_millis() as u64,
+            });
+        },
+    )
+}
+
+/// `pending_operations` summed over every repository queue.
+fn pending_git_operations() -> usize {
+    let Some(queues) = GIT_OPERATION_QUEUES.get() else {
+        return 0;
+    };
+    lock_or_recover(queues, "gitOperationQueues", recover_by_clearing)
+        .values()
+        .map(|queue| queue.pending_operations())
+        .sum()
+}
+
+/// Runs a `tokio::process` future to completion from synchronous command
+/// code. On a runtime worker, where async commands run, the worker hands its
+/// other tasks off for the wait; elsewhere the app runtime drives it.
+fn block_on_process<F: std::future::Future>(future: F) -> F::Output {
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) => tokio::task::block_in_place(|| handle.block_on(future)),
+        Err(_) => tauri::async_runtime::block_on(future),
+    }
+}
+
+/// `command` as a `tokio::process::Command` with the same program,
+/// arguments, environment and directory. The child is killed when its
+/// future is dropped, which is how cancellation stops it.
+fn async_command(command: &Command) -> tokio::process::Command {
+    let mut async_command = tokio::process::Command::new(command.get_program());
+    async_command.args(command.get_args()).kill_on_drop(true);
+    for (key, value) in command.get_envs() {
+        match value {
+            Some(value) => async_command.env(key, value),
+            None => async_command.env_remove(key),
+        };
+    }
+    if let Some(dir) = command.get_current_dir() {
+        async_command.current_dir(dir);
+    }
+    async_command
+}
+
+/// `future`, unless `cancelled` is set first. The flag is checked on a
+/// timer, so a child that prints nothing stops as promptly as a chatty one.
+async fn unless_cancelled<F: std::future::Future>(
+    cancelled: Option<&AtomicBool>,
+    future: F,
+) -> Option<F::Output> {
+    let Some(cancelled) = cancelled else {
+        return Some(future.await);
+    };
+    tokio::pin!(future);
+    let mut poll = tokio::time::interval(COMMAND_TIMEOUT_POLL_INTERVAL);
+    loop {
+        tokio::select! {
+            output = &mut future => return Some(output),
+            _ = poll.tick() => {
+                if cancelled.load(Ordering::Relaxed) {
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+/// Reads what is left of a child's `pipe` into `buffer`, so output read
+/// before the child is killed is kept.
+async fn read_child_pipe(pipe: Option<impl tokio::io::AsyncRead + Unpin>, buffer: &mut Vec<u8>) {
+    use tokio::io::AsyncReadExt;
+
+    if let Some(mut pipe) = pipe {
+        let _ = pipe.read_to_end(buffer).await;
+    }
+}
+
+fn cancelled_error() -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::Interrupted, "the command was cancelled")
+}
+
+/// `command.output()` through `tokio::process`, killed with an
+/// `Interrupted` error when `cancelled` is set.
+fn process_output(
+    command: &Command,
+    cancelled: Option<&AtomicBool>,
+) -> std::io::Result<std::process::Output> {
+    let mut command = async_command(command);
+    block_on_process(async move {
+        unless_cancelled(cancelled, command.output())
+            .await
+            .unwrap_or_else(|| Err(cancelled_error()))
+    })
+}
+
+/// Like `process_output` without cancellation, with `stdin_data` written to
+/// the child while its output is read. A child that exits before reading all
+/// of it is left to report that through its exit status.
+fn process_output_with_stdin(
+    command: &Command,
+    stdin_data: &[u8],
+) -> std::io::Result<std::process::Output> {
+    use tokio::io::AsyncWriteExt;
+
+    let mut command = async_command(command);
+    command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    block_on_process(async move {
+        let mut child = command.spawn()?;
+        let stdin = child.stdin.take();
+        let write = async move {
+            if let Some(mut stdin) = stdin {
+                let _ = stdin.write_all(stdin_data).await;
+            }
+        };
+        let ((), output) = tokio::join!(write, child.wait_with_output());
+        output
+    })
+}
+
+/// `command.output()`, except that inside a command that gets cancelled the
+/// child is killed and an `Interrupted` error is returned.
+fn output_unless_cancelled(command: &mut Command) -> std::io::Result<std::process::Output> {
+    process_output(command, current_command_cancellation().as_deref())
+}
+
+fn read_pipe_in_background(mut pipe: impl Read + Send + 'static) -> JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        let _ = pipe.read_to_end(&mut buffer);
+        buffer
+    })
+}
+
+/// Git commands that only read, and network transfers, which take their ref
+/// locks only once the transfer is done, can be killed when their command is
+/// cancelled; writes run to completion so no lock or half-written state is
+/// left behind.
+fn git_output(command: &mut Command, args: &[&str]) -> std::io::Result<std::process::Output> {
+    match git::classify_git_operation(args) {
+        git::GitOperationKind::Read | git::GitOperationKind::Network => {
+            output_unless_cancelled(command)
+        }
+        git::GitOperationKind::Write => process_output(command, None),
+    }
+}
+
+/// `git <subcommand>`, naming a git run in the retries of its command.
+fn git_retry_label(args: &[&str]) -> String {
+    format!("git {}", retry::git_subcommand(args).unwrap_or_default())
+}
+
+/// Fetches and other runs that only talk to a remote are tried again, per
+/// the command retry policy, when they fail for a passing reason.
 fn run_git_command_at_path(path: &Path, args: &[&str]) -> CommandResult {
-    let output = Command::new("git").arg("-C").arg(path).args(args).output();
+    run_with_command_retries(
+        &git_retry_label(args),
+        retry::git_command_is_retryable(args),
+        |_| run_git_command_at_path_once(path, args),
+    )
+}
 
-    match output {
+fn run_git_command_at_path_once(path: &Path, args: &[&str]) -> CommandResult {
+    let _permit = acquire_git_operation(path, args);
+    let mut command = git_command_at(path);
+    command.args(args);
+    let started_at = Instant::now();
+    let output = git_output(&mut command, args);
+
+    let result = match output {
         Ok(output) => CommandResult {
             exit_code: output.status.code(),
             stdout: String::from_utf8_lossy(&output.stdout).to_string(),
@@ -94,13 +423,46 @@ fn run_git_command_at_path(path: &Path, args: &[&str]) -> CommandResult {
             stderr: String::new(),
             error: Some(format!("Failed to execute git: {error}")),
         },
-    }
+    };
+    record_subprocess_transcript(&command, started_at, &result);
+    result
 }
 
 fn run_git_command_at_path_with_args(path: &Path, args: &[String]) -> CommandResult {
-    let output = Command::new("git").arg("-C").arg(path).args(args).output();
+    let borrowed_args = args.iter().map(String::as_str).collect::<Vec<_>>();
+    let _permit = acquire_git_operation(path, &borrowed_args);
+    let mut command = git_command_at(path);
+    command.args(args);
+    let started_at = Instant::now();
+    let output = git_output(&mut command, &borrowed_args);
+
+    let result = match output {
+        Ok(output) => CommandResult {
+            exit_code: output.status.code(),
+            stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+            stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+            error: None,
+        },
+        Err(error) => CommandResult {
+            exit_code: None,
+            stdout: String::new(),
+            stderr: String::new(),
+            error: Some(format!("Failed to execute git: {error}")),
+        },
+    };
+    record_subprocess_transcript(&command, started_at, &result);
+    result
+}
 
-    match output {
+/// Runs git with `stdin_data` piped to it, e.g. a patch for `git apply -`.
+fn run_git_command_at_path_with_stdin(path: &Path, args: &[&str], stdin_data: &str) -> CommandResult {
+    let _permit = acquire_git_operation(path, args);
+    let mut command = git_command_at(path);
+    command.args(args);
+    let started_at = Instant::now();
+    let output = process_output_with_stdin(&command, stdin_data.as_bytes());
+
+    let result = match output {
         Ok(output) => CommandResult {
             exit_code: output.status.code(),
             stdout: String::from_utf8_lossy(&output.stdout).to_string(),
@@ -113,7 +475,226 @@ fn run_git_command_at_path_with_args(path: &Path, args: &[String]) -> CommandRes
             stderr: String::new(),
             error: Some(format!("Failed to execute git: {error}")),
         },
+    };
+    record_subprocess_transcript(&command, started_at, &result);
+    result
+}
+
+/// Runs a network-bound git command (`pull`, `push`, `fetch`) with
+/// `--progress` and streams each new phase/percentage as a `GitProgressEvent`
+/// tagged with `request_id`, so the UI can show how far a slow transfer got.
+/// The full stderr is still captured for the returned `CommandResult`.
+/// Cancelling the command kills the transfer within a poll interval, even
+/// while git prints nothing, and transfers that fail for a passing reason
+/// are tried again per the command retry policy.
+fn run_git_command_with_progress(path: &Path, args: &[&str], request_id: &str) -> CommandResult {
+    run_with_command_retries(
+        &git_retry_label(args),
+        retry::git_command_is_retryable(args),
+        |_| run_git_command_with_progress_once(path, args, request_id),
+    )
+}
+
+fn run_git_command_with_progress_once(
+    path: &Path,
+    args: &[&str],
+    request_id: &str,
+) -> CommandResult {
+    let _permit = acquire_git_operation(path, args);
+    let operation = args.first().copied().unwrap_or_default();
+    let mut command = git_command_at(path);
+    command
+        .args(args.first())
+        .arg("--progress")
+        .args(args.iter().skip(1));
+    let started_at = Instant::now();
+    let cancellation = current_command_cancellation();
+
+    let (mut stdout, mut stderr) = (Vec::new(), Vec::new());
+    let mut async_git = async_command(&command);
+    async_git
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let transfer = async {
+        use tokio::io::AsyncReadExt;
+
+        let mut child = async_git.spawn()?;
+        let stderr_pipe = child.stderr.take();
+        let read_stderr = async {
+            let Some(mut pipe) = stderr_pipe else {
+                return;
+            };
+            let mut reader = git::GitProgressReader::default();
+            let mut last_reported: Option<(String, u8)> = None;
+            let mut chunk = [0_u8; 4096];
+            loop {
+                let read = match pipe.read(&mut chunk).await {
+                    Ok(0) | Err(_) => break,
+                    Ok(read) => read,
+                };
+                stderr.extend_from_slice(&chunk[..read]);
+                for progress in reader.push(&String::from_utf8_lossy(&chunk[..read])) {
+                    let step = (progress.phase.clone(), progress.percent);
+                    if last_reported.as_ref() == Some(&step) {
+                        continue;
+                    }
+                    last_reported = Some(step);
+                    emit_background_groove_event(&GitProgressEvent {
+                        request_id: request_id.to_string(),
+                        path: path.display().to_string(),
+                        operation: operation.to_string(),
+                        phase: progress.phase,
+                        percent: progress.percent,
+                        completed: progress.completed,
+                        total: progress.total,
+                    });
+                }
+            }
+        };
+        tokio::join!(
+            read_child_pipe(child.stdout.take(), &mut stdout),
+            read_stderr
+        );
+        child.wait().await
+    };
+    let outcome = block_on_process(unless_cancelled(cancellation.as_deref(), transfer));
+
+    let stdout = String::from_utf8_lossy(&stdout).to_string();
+    let stderr = String::from_utf8_lossy(&stderr).to_string();
+    let result = match outcome {
+        Some(Ok(status)) => CommandResult {
+            exit_code: status.code(),
+            stdout,
+            stderr,
+            error: None,
+        },
+        Some(Err(error)) => CommandResult {
+            exit_code: None,
+            stdout,
+            stderr,
+            error: Some(format!("Failed to execute git: {error}")),
+        },
+        None => CommandResult {
+            exit_code: None,
+            stdout,
+            stderr,
+            error: Some(format!("git {operation} was cancelled.")),
+        },
+    };
+    record_subprocess_transcript(&command, started_at, &result);
+    result
+}
+
+fn detect_partial_clone(path: &Path) -> Option<git::PartialClone> {
+    let result = run_git_command_at_path(
+        path,
+        &["config", "--get-regexp", r"^remote\..*\.partialclonefilter$"],
+    );
+    if result.error.is_some() || result.exit_code != Some(0) {
+        return None;
+    }
+    git::parse_partial_clone_config(&result.stdout)
+}
+
+/// Paths that differ between the worktree and `merge_base` (when given),
+/// plus its staged and unstaged edits. Renames are not detected because
+/// that would read the very blobs a partial clone is missing.
+fn worktree_changed_paths(path: &Path, merge_base: Option<&str>) -> Result<BTreeSet<String>, String> {
+    let mut changed = BTreeSet::new();
+    if let Some(merge_base) = merge_base {
+        let diff = run_git_command_at_path(
+            path,
+            &["diff", "--name-only", "--no-renames", merge_base, "HEAD"],
+        );
+        if let Some(error) = diff.error {
+            return Err(error);
+        }
+        if diff.exit_code != Some(0) {
+            return Err(first_non_empty_line(&diff.stderr)
+                .unwrap_or_else(|| "git diff failed".to_string()));
+        }
+        changed.extend(
+            diff.stdout
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(str::to_string),
+        );
+    }
+
+    let status = run_git_command_at_path(path, &["status", "--porcelain=v1", "--no-renames"]);
+    if let Some(error) = status.error {
+        return Err(error);
+    }
+    if status.exit_code != Some(0) {
+        return Err(first_non_empty_line(&status.stderr)
+            .unwrap_or_else(|| "git status failed".to_string()));
+    }
+    let (staged, unstaged, _) = parse_git_file_states(&status.stdout);
+    changed.extend(staged);
+    changed.extend(unstaged);
+    Ok(changed)
+}
+
+/// Fetches, in one batch, the blobs of `paths` at `revisions` that a partial
+/// clone has not downloaded yet, so later diffs do not demand-fetch them one
+/// by one. Returns how many blobs were requested.
+fn fetch_missing_blobs(
+    path: &Path,
+    partial_clone: &git::PartialClone,
+    revisions: &[&str],
+    paths: &BTreeSet<String>,
+) -> Result<usize, String> {
+    if paths.is_empty() {
+        return Ok(0);
+    }
+
+    let mut rev_list_args = ["rev-list", "--objects", "--missing=print", "--no-walk"]
+        .into_iter()
+        .chain(revisions.iter().copied())
+        .chain(["--"])
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    rev_list_args.extend(paths.iter().map(|changed| format!(":(literal){changed}")));
+    let listed = run_git_command_at_path_with_args(path, &rev_list_args);
+    if let Some(error) = listed.error {
+        return Err(error);
+    }
+    if listed.exit_code != Some(0) {
+        return Err(first_non_empty_line(&listed.stderr)
+            .unwrap_or_else(|| "git rev-list failed".to_string()));
+    }
+    let missing = git::parse_missing_object_ids(&listed.stdout);
+    if missing.is_empty() {
+        return Ok(0);
+    }
+
+    // Mirrors the fetch git itself issues for promisor objects: no ref
+    // negotiation, no refs or tags written, object ids fed on stdin.
+    let _permit = acquire_git_operation(path, &["fetch"]);
+    let mut command = git_command_at(path);
+    command
+        .args(["-c", "fetch.negotiationAlgorithm=noop", "fetch"])
+        .arg(&partial_clone.remote)
+        .args([
+            "--no-tags",
+            "--no-write-fetch-head",
+            "--recurse-submodules=no",
+            "--filter=blob:none",
+            "--stdin",
+        ])
+        .env("GIT_TERMINAL_PROMPT", "0");
+    let output =
+        process_output_with_stdin(&command, format!("{}\n", missing.join("\n")).as_bytes())
+            .map_err(|error| format!("Failed to execute git: {error}"))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(first_non_empty_line(&stderr).unwrap_or_else(|| {
+            format!("git fetch from promisor remote \"{}\" failed", partial_clone.remote)
+        }));
     }
+    Ok(missing.len())
 }
 
 fn command_output_snippet(result: &CommandResult) -> Option<String> {
@@ -130,20 +711,20 @@ fn command_output_snippet(result: &CommandResult) -> Option<String> {
         })
 }
 
-fn parse_git_porcelain_counts(output: &str) -> git_gh::GitPorcelainCounts {
-    git_gh::parse_git_porcelain_counts(output)
+fn parse_git_porcelain_counts(output: &str) -> git::GitPorcelainCounts {
+    git::parse_git_porcelain_counts(output)
 }
 
 fn parse_git_ahead_behind(status_sb_output: &str) -> (u32, u32) {
-    git_gh::parse_git_ahead_behind(status_sb_output)
+    git::parse_git_ahead_behind(status_sb_output)
 }
 
 fn parse_git_file_states(output: &str) -> (Vec<String>, Vec<String>, Vec<String>) {
-    git_gh::parse_git_file_states(output)
+    git::parse_git_file_states(output)
 }
 
 fn normalize_git_file_list(files: &[String]) -> Result<Vec<String>, String> {
-    git_gh::normalize_git_file_list(files)
+    git::normalize_git_file_list(files)
 }
 
 fn resolve_workspace_root(
@@ -0,0 +1,213 @@
+// Alerts on system resources. A sampler thread started with the app reads
+// RAM, swap and disk usage every `SYSTEM_METRICS_SAMPLE_INTERVAL` and checks
+// them against `SystemAlertSettings`. An alert is emitted as `system-alert`
+// when it starts and again when it clears, optionally as a desktop
+// notification too. Memory alerts suggest idle grooves of the active
+// workspace to stop, picked from the per-worktree resource attribution.
+
+static SYSTEM_ALERTS_ENABLED: AtomicBool = AtomicBool::new(true);
+static SYSTEM_ALERT_RAM_PERCENT: AtomicU8 = AtomicU8::new(DEFAULT_SYSTEM_ALERT_RAM_PERCENT);
+static SYSTEM_ALERT_DISK_PERCENT: AtomicU8 = AtomicU8::new(DEFAULT_SYSTEM_ALERT_DISK_PERCENT);
+static SYSTEM_ALERT_SWAP_IN_USE: AtomicBool = AtomicBool::new(true);
+static SYSTEM_ALERT_DESKTOP_NOTIFICATIONS: AtomicBool = AtomicBool::new(false);
+
+fn set_system_alert_thresholds(settings: &SystemAlertSettings) {
+    SYSTEM_ALERTS_ENABLED.store(settings.enabled, Ordering::Relaxed);
+    SYSTEM_ALERT_RAM_PERCENT.store(settings.ram_percent, Ordering::Relaxed);
+    SYSTEM_ALERT_DISK_PERCENT.store(settings.disk_percent, Ordering::Relaxed);
+    SYSTEM_ALERT_SWAP_IN_USE.store(settings.swap_in_use, Ordering::Relaxed);
+    SYSTEM_ALERT_DESKTOP_NOTIFICATIONS.store(settings.desktop_notifications, Ordering::Relaxed);
+}
+
+fn system_alert_thresholds() -> SystemAlertSettings {
+    SystemAlertSettings {
+        enabled: SYSTEM_ALERTS_ENABLED.load(Ordering::Relaxed),
+        ram_percent: SYSTEM_ALERT_RAM_PERCENT.load(Ordering::Relaxed),
+        disk_percent: SYSTEM_ALERT_DISK_PERCENT.load(Ordering::Relaxed),
+        swap_in_use: SYSTEM_ALERT_SWAP_IN_USE.load(Ordering::Relaxed),
+        desktop_notifications: SYSTEM_ALERT_DESKTOP_NOTIFICATIONS.load(Ordering::Relaxed),
+    }
+}
+
+/// Readings of every resource an enabled threshold applies to. Resources
+/// the platform cannot report are left out.
+fn sample_system_alert_readings(
+    settings: &SystemAlertSettings,
+    disk_target: &Path,
+) -> Vec<SystemAlertReading> {
+    use crate::backend::common::platform_env;
+
+    if !settings.enabled {
+        return Vec::new();
+    }
+    let mut readings = Vec::new();
+    if let Some((total, used, usage_percent)) = platform_env::read_ram_usage() {
+        let threshold_percent = f64::from(settings.ram_percent);
+        let allowed = (total as f64 * threshold_percent / 100.0) as u64;
+        readings.push(SystemAlertReading {
+            kind: "ram",
+            value_percent: usage_percent,
+            threshold_percent,
+            over: usage_percent > threshold_percent,
+            excess_bytes: used.saturating_sub(allowed),
+        });
+    }
+    if let Some((_, _, usage_percent)) = platform_env::read_disk_usage(disk_target) {
+        let threshold_percent = f64::from(settings.disk_percent);
+        readings.push(SystemAlertReading {
+            kind: "disk",
+            value_percent: usage_percent,
+            threshold_percent,
+            over: usage_percent > threshold_percent,
+            excess_bytes: 0,
+        });
+    }
+    if settings.swap_in_use {
+        if let Some((_, used, usage_percent)) = platform_env::read_swap_usage() {
+            readings.push(SystemAlertReading {
+                kind: "swap",
+                value_percent: usage_percent,
+                threshold_percent: 0.0,
+                over: used > 0,
+                excess_bytes: used,
+            });
+        }
+    }
+    readings
+}
+
+fn system_alert_message(reading: &SystemAlertReading, active: bool) -> String {
+    match (reading.kind, active) {
+        ("swap", true) => format!("Swap is in use ({:.0}% of swap).", reading.value_percent),
+        ("swap", false) => "Swap is no longer in use.".to_string(),
+        (kind, true) => format!(
+            "{} usage is at {:.0}%, above the {:.0}% alert threshold.",
+            if kind == "ram" { "RAM" } else { "Disk" },
+            reading.value_percent,
+            reading.threshold_percent
+        ),
+        (kind, false) => format!(
+            "{} usage is back under {:.0}%.",
+            if kind == "ram" { "RAM" } else { "Disk" },
+            reading.threshold_percent
+        ),
+    }
+}
+
+/// Idle grooves of the active workspace that would free `excess_bytes`, and
+/// a sentence suggesting to stop them.
+fn system_alert_stop_suggestion(
+    app: &AppHandle,
+    excess_bytes: u64,
+) -> (Vec<String>, Option<String>) {
+    if excess_bytes == 0 {
+        return (Vec::new(), None);
+    }
+    let Ok((rows, _)) = active_workspace_root_from_state(app)
+        .and_then(|workspace_root| collect_worktree_resources(app, &workspace_root))
+    else {
+        return (Vec::new(), None);
+    };
+    let usage = rows
+        .into_iter()
+        .map(|row| (row.worktree, row.usage.rss_bytes, row.usage.cpu_percent))
+        .collect::<Vec<_>>();
+    let stop_worktrees =
+        diagnostics::idle_worktrees_to_stop(&usage, excess_bytes, SYSTEM_ALERT_IDLE_CPU_PERCENT);
+    if stop_worktrees.is_empty() {
+        return (stop_worktrees, None);
+    }
+
+    let freed_bytes = usage
+        .iter()
+        .filter(|(worktree, _, _)| stop_worktrees.contains(worktree))
+        .map(|(_, rss_bytes, _)| rss_bytes)
+        .sum::<u64>();
+    let suggestion = format!(
+        "Stop {} idle groove{} ({}) to free about {:.1} GB.",
+        stop_worktrees.len(),
+        if stop_worktrees.len() == 1 { "" } else { "s" },
+        stop_worktrees.join(", "),
+        freed_bytes as f64 / (1024.0 * 1024.0 * 1024.0)
+    );
+    (stop_worktrees, Some(suggestion))
+}
+
+fn emit_system_alert(app: &AppHandle, reading: &SystemAlertReading, active: bool) {
+    let (stop_worktrees, suggestion) = if active {
+        system_alert_stop_suggestion(app, reading.excess_bytes)
+    } else {
+        (Vec::new(), None)
+    };
+    let message = system_alert_message(reading, active);
+
+    if active && SYSTEM_ALERT_DESKTOP_NOTIFICATIONS.load(Ordering::Relaxed) {
+        let body = suggestion.as_ref().map_or_else(
+            || message.clone(),
+            |suggestion| format!("{message} {suggestion}"),
+        );
+        if let Err(error) =
+            crate::backend::common::platform_env::show_desktop_notification("Groove", &body)
+        {
+            log_line(&format!("[system-alerts] {error}"));
+        }
+    }
+
+    emit_groove_event(
+        app,
+        &SystemAlertEvent {
+            kind: reading.kind.to_string(),
+            active,
+            value_percent: reading.value_percent,
+            threshold_percent: reading.threshold_percent,
+            message,
+            stop_worktrees,
+            suggestion,
+        },
+    );
+}
+
+fn start_system_metrics_sampler(app: AppHandle) {
+    thread::spawn(move || {
+        let mut active = HashMap::<&'static str, SystemAlertReading>::new();
+        loop {
+            thread::sleep(SYSTEM_METRICS_SAMPLE_INTERVAL);
+            if background_activity_paused() {
+                continue;
+            }
+
+            let disk_target = active_workspace_root_from_state(&app)
+                .ok()
+                .or_else(|| std::env::current_dir().ok())
+                .unwrap_or_else(|| PathBuf::from("/"));
+            let readings = sample_system_alert_readings(&system_alert_thresholds(), &disk_target);
+
+            for reading in readings.iter().filter(|reading| reading.over) {
+                if active.insert(reading.kind, *reading).is_none() {
+                    emit_system_alert(&app, reading, true);
+                }
+            }
+            // Alerts whose resource is back under its threshold, or no longer
+            // checked at all, clear with the latest reading available.
+            let cleared = active
+                .iter()
+                .filter(|(kind, _)| {
+                    !readings
+                        .iter()
+                        .any(|reading| reading.kind == **kind && reading.over)
+                })
+                .map(|(kind, last)| {
+                    readings
+                        .iter()
+                        .find(|reading| reading.kind == *kind)
+                        .copied()
+                        .unwrap_or(*last)
+                })
+                .collect::<Vec<_>>();
+            for reading in cleared {
+                active.remove(reading.kind);
+                emit_system_alert(&app, &reading, false);
+            }
+        }
+    });
+}
@@ -91,6 +91,472 @@ fn list_worktree_node_app_rows() -> Result<(Vec<DiagnosticsNodeAppRow>, Option<S
     Ok((rows, warning))
 }
 
+fn list_process_resource_rows() -> Result<(Vec<ProcessResourceRow>, Option<String>), String> {
+    use crate::backend::common::platform_env::{self, Platform};
+
+    let raw = platform_env::list_process_resources_raw()?;
+
+    let mut rows = Vec::new();
+    match Platform::current() {
+        Platform::Windows => {
+            for line in raw.stdout.lines().map(str::trim).filter(|l| !l.is_empty()) {
+                if line.starts_with("\"ProcessId\"") {
+                    continue;
+                }
+
+                let columns = diagnostics::parse_basic_csv_line(line);
+                if columns.len() < 4 {
+                    continue;
+                }
+
+                let Some(pid) = columns[0].trim().parse::<i32>().ok() else {
+                    continue;
+                };
+                rows.push(ProcessResourceRow {
+                    pid,
+                    ppid: columns[1].trim().parse::<i32>().ok(),
+                    cpu_percent: 0.0,
+                    rss_bytes: columns[2].trim().parse::<u64>().unwrap_or(0),
+                    command: columns[3].trim().to_string(),
+                });
+            }
+        }
+        Platform::Linux | Platform::MacOS => {
+            for raw_line in raw.stdout.lines() {
+                let mut tokens = raw_line.split_whitespace();
+                let (Some(pid_token), Some(ppid_token), Some(cpu_token), Some(rss_token)) =
+                    (tokens.next(), tokens.next(), tokens.next(), tokens.next())
+                else {
+                    continue;
+                };
+                let Some(pid) = pid_token.parse::<i32>().ok() else {
+                    continue;
+                };
+
+                rows.push(ProcessResourceRow {
+                    pid,
+                    ppid: ppid_token.parse::<i32>().ok(),
+                    cpu_percent: cpu_token.parse::<f64>().unwrap_or(0.0),
+                    rss_bytes: rss_token.parse::<u64>().unwrap_or(0) * 1024,
+                    command: tokens.collect::<Vec<_>>().join(" "),
+                });
+            }
+        }
+    }
+
+    Ok((rows, raw.warning))
+}
+
+/// Shell pids of the live terminal sessions of a workspace, by worktree.
+fn groove_terminal_session_pids(app: &AppHandle, workspace_root: &Path) -> Vec<(String, i32)> {
+    let Some(state) = app.try_state::<GrooveTerminalState>() else {
+        return Vec::new();
+    };
+    let workspace_root_key = workspace_root_storage_key(workspace_root);
+    let sessions_state = state.lock_sessions();
+    sessions_state
+        .sessions_by_id
+        .values()
+        .filter(|session| {
+            workspace_root_storage_key(Path::new(&session.workspace_root)) == workspace_root_key
+        })
+        .filter_map(|session| {
+            let pid = session.pty.as_ref()?.child.process_id()?;
+            Some((session.worktree.clone(), pid as i32))
+        })
+        .collect()
+}
+
+/// CPU and resident memory of everything running in each worktree of a
+/// workspace, heaviest first. CPU is the `ps` figure, which on Linux averages
+/// over each process's lifetime rather than sampling the current load.
+fn collect_worktree_resources(
+    app: &AppHandle,
+    workspace_root: &Path,
+) -> Result<(Vec<DiagnosticsWorktreeResourceRow>, Option<String>), String> {
+    let effective_root = ensure_workspace_meta(workspace_root)
+        .map(|(meta, _)| effective_workspace_root(workspace_root, &meta))
+        .unwrap_or_else(|_| workspace_root.to_path_buf());
+    let worktrees_dir = effective_root.join(".worktrees");
+    let worktree_directories = if path_is_directory(&worktrees_dir) {
+        list_worktree_directories(&worktrees_dir)?
+    } else {
+        Vec::new()
+    };
+    let worktree_paths = worktree_directories
+        .into_iter()
+        .filter_map(|path| {
+            let worktree = path.file_name()?.to_string_lossy().to_string();
+            Some((worktree, path.display().to_string()))
+        })
+        .collect::<Vec<_>>();
+
+    // Processes started in a worktree that is a Windows junction may name
+    // the junction's target instead of the worktree path.
+    let match_paths = worktree_paths
+        .iter()
+        .cloned()
+        .chain(worktree_paths.iter().filter_map(|(worktree, path)| {
+            let target =
+                crate::backend::common::platform_env::reparse_point_target(Path::new(path))?;
+            Some((worktree.clone(), target.display().to_string()))
+        }))
+        .collect::<Vec<_>>();
+
+    let seeds = groove_terminal_session_pids(app, workspace_root);
+    let (processes, warning) = list_process_resource_rows()?;
+    let own_pid = std::process::id() as i32;
+    let owners = diagnostics::attribute_processes_to_worktrees(
+        &processes
+            .iter()
+            .filter(|row| row.pid != own_pid)
+            .map(|row| (row.pid, row.ppid, row.command.as_str()))
+            .collect::<Vec<_>>(),
+        &match_paths,
+        &seeds,
+    );
+
+    let mut rows = worktree_paths
+        .into_iter()
+        .map(|(worktree, path)| {
+            (
+                worktree.clone(),
+                DiagnosticsWorktreeResourceRow {
+                    worktree,
+                    path,
+                    usage: WorktreeResourceUsage::default(),
+                    pids: Vec::new(),
+                },
+            )
+        })
+        .collect::<HashMap<_, _>>();
+    for process in &processes {
+        let Some(row) = owners
+            .get(&process.pid)
+            .and_then(|worktree| rows.get_mut(worktree))
+        else {
+            continue;
+        };
+        row.usage.cpu_percent += process.cpu_percent;
+        row.usage.rss_bytes += process.rss_bytes;
+        row.usage.process_count += 1;
+        row.pids.push(process.pid);
+    }
+
+    let mut rows = rows.into_values().collect::<Vec<_>>();
+    for row in &mut rows {
+        row.pids.sort_unstable();
+    }
+    rows.sort_by(|left, right| {
+        right
+            .usage
+            .cpu_percent
+            .total_cmp(&left.usage.cpu_percent)
+            .then(right.usage.rss_bytes.cmp(&left.usage.rss_bytes))
+            .then_with(|| left.worktree.cmp(&right.worktree))
+    });
+    Ok((rows, warning))
+}
+
+/// Fills in `RuntimeStateRow.resources` for a successful groove list. A failed
+/// collection leaves the rows as they were.
+fn attach_worktree_resources(app: &AppHandle, response: &mut GrooveListResponse) {
+    let Some(workspace_root) = response.workspace_root.as_deref().filter(|_| response.ok) else {
+        return;
+    };
+    let resources = match collect_worktree_resources(app, Path::new(workspace_root)) {
+        Ok((rows, _)) => rows,
+        Err(error) => {
+            log_line(&format!(
+                "[groove-list] failed to collect worktree resources: {error}"
+            ));
+            return;
+        }
+    };
+    let usage_by_worktree = resources
+        .into_iter()
+        .map(|row| (row.worktree, row.usage))
+        .collect::<HashMap<_, _>>();
+    for (worktree, row) in response.rows.iter_mut() {
+        row.resources = Some(usage_by_worktree.get(worktree).copied().unwrap_or_default());
+    }
+}
+
+/// Link targets of a process's descriptors, each with the watches it holds
+/// when it is an inotify instance. `None` when the process is gone or
+/// belongs to another user.
+fn read_process_fds(pid: i32) -> Option<Vec<(String, usize)>> {
+    let entries = fs::read_dir(format!("/proc/{pid}/fd")).ok()?;
+    let fds = entries
+        .flatten()
+        .filter_map(|entry| {
+            let target = fs::read_link(entry.path()).ok()?;
+            let target = target.to_string_lossy().to_string();
+            let watches = if target == "anon_inode:inotify" {
+                let fdinfo = format!("/proc/{pid}/fdinfo/{}", entry.file_name().to_string_lossy());
+                fs::read_to_string(fdinfo)
+                    .map(|fdinfo| diagnostics::count_inotify_watches(&fdinfo))
+                    .unwrap_or(0)
+            } else {
+                0
+            };
+            Some((target, watches))
+        })
+        .collect();
+    Some(fds)
+}
+
+impl DiagnosticsFdCounts {
+    fn of(fds: &[(String, usize)]) -> Self {
+        Self {
+            fds: fds.len(),
+            inotify_instances: fds
+                .iter()
+                .filter(|(target, _)| target == "anon_inode:inotify")
+                .count(),
+            inotify_watches: fds.iter().map(|(_, watches)| watches).sum(),
+        }
+    }
+
+    fn add(&mut self, other: DiagnosticsFdCounts) {
+        self.fds += other.fds;
+        self.inotify_instances += other.inotify_instances;
+        self.inotify_watches += other.inotify_watches;
+    }
+}
+
+fn read_fd_limits() -> DiagnosticsFdLimits {
+    let read_u64 = |path: &str| {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+    };
+    let (open_files_soft, open_files_hard) = fs::read_to_string("/proc/self/limits")
+        .ok()
+        .and_then(|limits| diagnostics::parse_open_files_limit(&limits))
+        .unwrap_or((None, None));
+    // `allocated unused max`
+    let file_nr = fs::read_to_string("/proc/sys/fs/file-nr")
+        .map(|file_nr| {
+            file_nr
+                .split_whitespace()
+                .map(|value| value.parse::<u64>().ok())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    DiagnosticsFdLimits {
+        open_files_soft,
+        open_files_hard,
+        inotify_max_user_watches: read_u64("/proc/sys/fs/inotify/max_user_watches"),
+        inotify_max_user_instances: read_u64("/proc/sys/fs/inotify/max_user_instances"),
+        system_open_files: file_nr.first().copied().flatten(),
+        system_max_files: file_nr.get(2).copied().flatten(),
+    }
+}
+
+fn fd_limit_hints(
+    groove: &DiagnosticsFdCounts,
+    user: &DiagnosticsFdCounts,
+    limits: &DiagnosticsFdLimits,
+) -> Vec<String> {
+    let near_limit = |used: usize, limit: Option<u64>| {
+        let limit = limit.filter(|limit| *limit > 0)?;
+        let percent = used as f64 / limit as f64 * 100.0;
+        (percent >= FD_USAGE_HINT_PERCENT).then_some((percent, limit))
+    };
+    let mut hints = Vec::new();
+    if let Some((percent, limit)) =
+        near_limit(user.inotify_watches, limits.inotify_max_user_watches)
+    {
+        hints.push(format!(
+            "inotify watches are at {percent:.0}% of fs.inotify.max_user_watches ({limit}). Stop idle dev servers and watchers, or raise the limit with `sudo sysctl fs.inotify.max_user_watches=524288`."
+        ));
+    }
+    if let Some((percent, limit)) =
+        near_limit(user.inotify_instances, limits.inotify_max_user_instances)
+    {
+        hints.push(format!(
+            "inotify instances are at {percent:.0}% of fs.inotify.max_user_instances ({limit}). Stop idle dev servers and watchers, or raise the limit with `sudo sysctl fs.inotify.max_user_instances=1024`."
+        ));
+    }
+    if let Some((percent, limit)) = near_limit(groove.fds, limits.open_files_soft) {
+        hints.push(format!(
+            "Groove holds {percent:.0}% of its open file limit ({limit}). Close unused terminals, or raise the limit with `ulimit -n` before starting Groove."
+        ));
+    }
+    if let Some((percent, limit)) = limits
+        .system_open_files
+        .and_then(|open_files| near_limit(open_files as usize, limits.system_max_files))
+    {
+        hints.push(format!(
+            "The system file table is {percent:.0}% full (fs.file-max {limit}). Stop processes you no longer need."
+        ));
+    }
+    hints
+}
+
+/// Descriptors and inotify watches held by the app, by subsystem, by the
+/// processes of each worktree of the active workspace, and by everything else
+/// the user runs. Linux only: it reads `/proc`.
+fn collect_fd_usage(app: &AppHandle) -> Result<DiagnosticsFdUsage, String> {
+    use crate::backend::common::platform_env::Platform;
+
+    if Platform::current() != Platform::Linux {
+        return Err(
+            "File descriptor and inotify diagnostics are only available on Linux.".to_string(),
+        );
+    }
+    let fds_by_pid = fs::read_dir("/proc")
+        .map_err(|error| format!("Failed to read /proc: {error}"))?
+        .flatten()
+        .filter_map(|entry| entry.file_name().to_str()?.parse::<i32>().ok())
+        .filter_map(|pid| Some((pid, read_process_fds(pid)?)))
+        .collect::<HashMap<_, _>>();
+    let mut user = DiagnosticsFdCounts::default();
+    for fds in fds_by_pid.values() {
+        user.add(DiagnosticsFdCounts::of(fds));
+    }
+
+    let own_pid = std::process::id() as i32;
+    let own_fds = fds_by_pid.get(&own_pid).cloned().unwrap_or_default();
+    let groove = DiagnosticsFdCounts::of(&own_fds);
+    let scrollback_dir = terminal_scrollback_dir(app)
+        .map(|dir| dir.display().to_string())
+        .unwrap_or_default();
+    let mut fds_by_subsystem = BTreeMap::<&str, Vec<(String, usize)>>::new();
+    for fd in own_fds {
+        fds_by_subsystem
+            .entry(diagnostics::classify_fd_target(&fd.0, &scrollback_dir))
+            .or_default()
+            .push(fd);
+    }
+    let mut rows = fds_by_subsystem
+        .into_iter()
+        .map(|(subsystem, fds)| DiagnosticsFdHolderRow {
+            kind: "groove".to_string(),
+            name: subsystem.to_string(),
+            counts: DiagnosticsFdCounts::of(&fds),
+            pids: vec![own_pid],
+        })
+        .collect::<Vec<_>>();
+
+    let mut warnings = Vec::new();
+    let mut attributed = HashSet::from([own_pid]);
+    let mut worktree_rows = Vec::new();
+    match active_workspace_root_from_state(app)
+        .and_then(|workspace_root| collect_worktree_resources(app, &workspace_root))
+    {
+        Ok((resource_rows, _)) => {
+            for resource_row in resource_rows {
+                let mut counts = DiagnosticsFdCounts::default();
+                for pid in &resource_row.pids {
+                    if let Some(fds) = fds_by_pid.get(pid) {
+                        counts.add(DiagnosticsFdCounts::of(fds));
+                        attributed.insert(*pid);
+                    }
+                }
+                if counts.fds > 0 {
+                    worktree_rows.push(DiagnosticsFdHolderRow {
+                        kind: "worktree".to_string(),
+                        name: resource_row.worktree,
+                        counts,
+                        pids: resource_row.pids,
+                    });
+                }
+            }
+        }
+        Err(error) => warnings.push(format!("Worktree attribution unavailable: {error}")),
+    }
+    worktree_rows.sort_by(|left, right| {
+        right
+            .counts
+            .inotify_watches
+            .cmp(&left.counts.inotify_watches)
+            .then_with(|| right.counts.fds.cmp(&left.counts.fds))
+    });
+    rows.extend(worktree_rows);
+
+    let mut other = DiagnosticsFdCounts::default();
+    let mut other_watchers = Vec::new();
+    for (pid, fds) in fds_by_pid
+        .iter()
+        .filter(|(pid, _)| !attributed.contains(pid))
+    {
+        let counts = DiagnosticsFdCounts::of(fds);
+        if counts.inotify_watches > 0 {
+            other_watchers.push((*pid, counts.inotify_watches));
+        }
+        other.add(counts);
+    }
+    other_watchers.sort_by_key(|(_, watches)| std::cmp::Reverse(*watches));
+    rows.push(DiagnosticsFdHolderRow {
+        kind: "other".to_string(),
+        name: "otherProcesses".to_string(),
+        counts: other,
+        pids: other_watchers.into_iter().map(|(pid, _)| pid).collect(),
+    });
+
+    let limits = read_fd_limits();
+    Ok(DiagnosticsFdUsage {
+        hints: fd_limit_hints(&groove, &user, &limits),
+        groove,
+        user,
+        limits,
+        rows,
+        warnings,
+    })
+}
+
+fn list_stop_category_targets(
+    category: diagnostics::DiagnosticsStopCategory,
+    workspace_root: Option<&Path>,
+) -> Result<Vec<DiagnosticsStopTarget>, String> {
+    use diagnostics::DiagnosticsStopCategory;
+
+    let (snapshot_rows, _warning) = list_process_snapshot_rows()?;
+    let own_pid = std::process::id() as i32;
+    let workspace_root_rendered = workspace_root.map(|root| root.display().to_string());
+    let mut seen = HashSet::new();
+    let mut targets = snapshot_rows
+        .into_iter()
+        .filter(|row| row.pid != own_pid)
+        .filter(|row| {
+            let process_name = row.process_name.as_deref();
+            match category {
+                DiagnosticsStopCategory::Opencode => {
+                    diagnostics::is_opencode_command(process_name, &row.command)
+                }
+                DiagnosticsStopCategory::NodeDevServers => {
+                    is_worktree_node_process(process_name, &row.command)
+                        || (command_mentions_worktrees(&row.command)
+                            && command_matches_turbo_dev(&row.command))
+                }
+                // Only node and opencode processes are matched so editors or
+                // shells that merely have the workspace open are left alone.
+                DiagnosticsStopCategory::Workspace => {
+                    workspace_root_rendered
+                        .as_deref()
+                        .map(|root| diagnostics::command_mentions_workspace_root(&row.command, root))
+                        .unwrap_or(false)
+                        && (is_likely_node_command(process_name, &row.command)
+                            || diagnostics::is_opencode_command(process_name, &row.command))
+                        && !is_next_telemetry_detached_flush_command(&row.command)
+                }
+            }
+        })
+        .filter(|row| seen.insert(row.pid))
+        .map(|row| DiagnosticsStopTarget {
+            pid: row.pid,
+            ppid: row.ppid,
+            command: row.command,
+            outcome: None,
+        })
+        .collect::<Vec<_>>();
+
+    targets.sort_by_key(|target| target.pid);
+    Ok(targets)
+}
+
 fn get_msot_consuming_programs_output() -> Result<String, String> {
     crate::backend::common::platform_env::top_memory_consumers()
 }
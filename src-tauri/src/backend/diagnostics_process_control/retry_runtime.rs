@@ -0,0 +1,247 @@
+// Retries of git and gh runs that failed for a passing reason, following
+// `GlobalSettings.command_retry_policy`. Callers pass whether the run is safe
+// to repeat (see `core::retry`); a failure `retry::classify_failure` puts in
+// one of the policy's `retry_on` categories is then tried again after an
+// exponential backoff. Each retry is logged and recorded for the command
+// running on the thread, and comes back in its response as `retries` so a
+// slow answer explains itself.
+
+/// Retries recorded for the command whose request id is `owner`.
+struct CommandRetryLog {
+    owner: String,
+    retries: Vec<CommandRetry>,
+}
+
+/// Shared with the commands run inside the one that created it, so their
+/// retries show up in the outer command's response.
+type SharedCommandRetries = Arc<Mutex<CommandRetryLog>>;
+
+/// Mirrored from the global settings by `write_global_settings_file`.
+static COMMAND_RETRY_POLICY: std::sync::OnceLock<Mutex<CommandRetryPolicy>> =
+    std::sync::OnceLock::new();
+
+thread_local! {
+    static CURRENT_COMMAND_RETRIES: std::cell::RefCell<Option<SharedCommandRetries>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+fn set_command_retry_policy(policy: &CommandRetryPolicy) {
+    *COMMAND_RETRY_POLICY
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|error| error.into_inner()) = policy.clone();
+}
+
+fn command_retry_policy() -> CommandRetryPolicy {
+    COMMAND_RETRY_POLICY
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+        .clone()
+}
+
+/// Makes a command's retry log the one `record_command_retry` writes to on
+/// this thread until dropped, then restores the previous one.
+struct BoundCommandRetries {
+    previous: Option<SharedCommandRetries>,
+}
+
+impl BoundCommandRetries {
+    fn bind(context: &CommandContext) -> Self {
+        let previous = CURRENT_COMMAND_RETRIES
+            .with(|current| current.replace(Some(Arc::clone(&context.retries))));
+        Self { previous }
+    }
+}
+
+impl Drop for BoundCommandRetries {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT_COMMAND_RETRIES.with(|current| current.replace(previous));
+    }
+}
+
+fn current_command_retries() -> Option<SharedCommandRetries> {
+    CURRENT_COMMAND_RETRIES.with(|current| current.borrow().clone())
+}
+
+fn lock_command_retries(retries: &SharedCommandRetries) -> MutexGuard<'_, CommandRetryLog> {
+    retries.lock().unwrap_or_else(|error| error.into_inner())
+}
+
+fn record_command_retry(retry: CommandRetry) {
+    if let Some(retries) = current_command_retries() {
+        lock_command_retries(&retries).retries.push(retry);
+    }
+}
+
+/// Sleeps for `duration` in short steps. Returns false as soon as the
+/// command running on this thread is cancelled.
+fn sleep_unless_command_cancelled(duration: Duration) -> bool {
+    let started_at = Instant::now();
+    while started_at.elapsed() < duration {
+        if command_cancelled() {
+            return false;
+        }
+        thread::sleep(
+            COMMAND_TIMEOUT_POLL_INTERVAL.min(duration.saturating_sub(started_at.elapsed())),
+        );
+    }
+    !command_cancelled()
+}
+
+/// The category to retry `result` for under `policy`, if any.
+fn command_retry_category(
+    policy: &CommandRetryPolicy,
+    result: &CommandResult,
+) -> Option<retry::RetryCategory> {
+    if result.error.is_none() && result.exit_code == Some(0) {
+        return None;
+    }
+    retry::classify_failure(&result.stderr, result.error.as_deref()).filter(|category| {
+        policy
+            .retry_on
+            .iter()
+            .any(|value| value == category.as_str())
+    })
+}
+
+/// Runs `run` with the try number (1 for the first) until it succeeds, fails
+/// for a reason the retry policy does not cover, runs out of tries or the
+/// command is cancelled. `label` names the run in logs and in the recorded
+/// retries, e.g. `git fetch`. With `retryable` false, `run` runs once.
+fn run_with_command_retries(
+    label: &str,
+    retryable: bool,
+    run: impl FnMut(u32) -> CommandResult,
+) -> CommandResult {
+    run_with_retry_policy(&command_retry_policy(), label, retryable, run)
+}
+
+/// `run_with_command_retries` under `policy` instead of the global one.
+fn run_with_retry_policy(
+    policy: &CommandRetryPolicy,
+    label: &str,
+    retryable: bool,
+    mut run: impl FnMut(u32) -> CommandResult,
+) -> CommandResult {
+    let mut attempt = 1;
+    loop {
+        let result = run(attempt);
+        if !retryable || attempt >= policy.max_attempts || command_cancelled() {
+            return result;
+        }
+        let Some(category) = command_retry_category(policy, &result) else {
+            return result;
+        };
+        let backoff = retry::backoff(
+            Duration::from_millis(policy.backoff_ms),
+            Duration::from_millis(policy.max_backoff_ms),
+            attempt,
+        );
+        let error = result
+            .error
+            .clone()
+            .or_else(|| first_non_empty_line(&result.stderr))
+            .unwrap_or_default();
+        log_line(&format!(
+            "[retry] {label} failed ({}) on try {attempt} of {}, retrying in {}ms: {error}",
+            category.as_str(),
+            policy.max_attempts,
+            backoff.as_millis()
+        ));
+        record_command_retry(CommandRetry {
+            command: label.to_string(),
+            attempt,
+            category: category.as_str().to_string(),
+            error,
+            backoff_ms: backoff.as_millis().try_into().unwrap_or(u64::MAX),
+        });
+        if !sleep_unless_command_cancelled(backoff) {
+            return result;
+        }
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod retry_runtime_tests {
+    use super::*;
+
+    fn failed(stderr: &str) -> CommandResult {
+        CommandResult {
+            exit_code: Some(128),
+            stdout: String::new(),
+            stderr: stderr.to_string(),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn only_failures_in_the_policy_categories_are_retried() {
+        let policy = CommandRetryPolicy::default();
+        assert_eq!(
+            command_retry_category(
+                &policy,
+                &failed("fatal: Could not resolve host: github.com")
+            ),
+            Some(retry::RetryCategory::Network)
+        );
+        assert_eq!(
+            command_retry_category(
+                &policy,
+                &failed("fatal: Unable to create '.git/index.lock'")
+            ),
+            None
+        );
+        assert_eq!(
+            command_retry_category(&policy, &failed("fatal: not a git repository")),
+            None
+        );
+
+        let ok = CommandResult {
+            exit_code: Some(0),
+            stdout: String::new(),
+            stderr: "Could not resolve host: mirror".to_string(),
+            error: None,
+        };
+        assert_eq!(command_retry_category(&policy, &ok), None);
+    }
+
+    #[test]
+    fn retries_are_recorded_on_the_command_that_started_the_log() {
+        let policy = CommandRetryPolicy {
+            max_attempts: 3,
+            backoff_ms: 1,
+            max_backoff_ms: 1,
+            retry_on: vec!["network".to_string()],
+        };
+        let context = CommandContext::new("git_pull");
+        let mut tries = Vec::new();
+        let result = {
+            let _bound = BoundCommandRetries::bind(&context);
+            run_with_retry_policy(&policy, "git pull", true, |attempt| {
+                tries.push(attempt);
+                if attempt < 3 {
+                    failed("fatal: the remote end hung up unexpectedly")
+                } else {
+                    CommandResult {
+                        exit_code: Some(0),
+                        stdout: String::new(),
+                        stderr: String::new(),
+                        error: None,
+                    }
+                }
+            })
+        };
+
+        assert_eq!(result.exit_code, Some(0));
+        assert_eq!(tries, vec![1, 2, 3]);
+        let retries = context.take_command_retries();
+        assert_eq!(retries.len(), 2);
+        assert_eq!(retries[0].command, "git pull");
+        assert_eq!(retries[0].category, "network");
+        assert_eq!(retries[1].attempt, 2);
+        assert!(context.take_command_retries().is_empty());
+    }
+}
@@ -0,0 +1,186 @@
+// Raw subprocess transcripts for commands called with `debug: true`, so a
+// git, gh or sidecar run that behaves differently than in the user's shell can
+// be compared without adding log lines. The command turns recording on through
+// its `CommandContext`; each subprocess then started on the command's thread
+// is recorded with its argv, cwd, the environment it got on top of the app's,
+// duration and output, and the transcripts come back as `debugTranscripts`.
+
+/// Transcripts recorded for the command whose request id is `owner`.
+struct SubprocessTranscriptCapture {
+    owner: String,
+    transcripts: Vec<SubprocessTranscript>,
+}
+
+/// Shared with the commands run inside the recording one, so their
+/// subprocesses land in its transcripts too.
+type SharedSubprocessTranscripts = Arc<Mutex<Option<SubprocessTranscriptCapture>>>;
+
+thread_local! {
+    static CURRENT_SUBPROCESS_TRANSCRIPTS: std::cell::RefCell<Option<SharedSubprocessTranscripts>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// Makes a command's transcripts the ones `record_subprocess_transcript`
+/// writes to on this thread until dropped, then restores the previous ones.
+struct BoundSubprocessTranscripts {
+    previous: Option<SharedSubprocessTranscripts>,
+}
+
+impl BoundSubprocessTranscripts {
+    fn bind(context: &CommandContext) -> Self {
+        let previous = CURRENT_SUBPROCESS_TRANSCRIPTS
+            .with(|current| current.replace(Some(Arc::clone(&context.transcripts))));
+        Self { previous }
+    }
+}
+
+impl Drop for BoundSubprocessTranscripts {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT_SUBPROCESS_TRANSCRIPTS.with(|current| current.replace(previous));
+    }
+}
+
+fn current_subprocess_transcripts() -> Option<SharedSubprocessTranscripts> {
+    CURRENT_SUBPROCESS_TRANSCRIPTS.with(|current| current.borrow().clone())
+}
+
+fn lock_subprocess_transcripts(
+    transcripts: &SharedSubprocessTranscripts,
+) -> MutexGuard<'_, Option<SubprocessTranscriptCapture>> {
+    transcripts
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+}
+
+fn subprocess_transcript_env_value(name: &str, value: &str) -> String {
+    let upper = name.to_ascii_uppercase();
+    if SUBPROCESS_TRANSCRIPT_SECRET_ENV_MARKERS
+        .iter()
+        .any(|marker| upper.contains(marker))
+    {
+        "<redacted>".to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+fn subprocess_transcript(
+    command: &Command,
+    elapsed: Duration,
+    result: &CommandResult,
+) -> SubprocessTranscript {
+    let argv = std::iter::once(command.get_program())
+        .chain(command.get_args())
+        .map(|part| part.to_string_lossy().to_string())
+        .collect();
+    let env = command
+        .get_envs()
+        .map(|(name, value)| {
+            let name = name.to_string_lossy().to_string();
+            let value =
+                value.map(|value| subprocess_transcript_env_value(&name, &value.to_string_lossy()));
+            (name, value)
+        })
+        .collect();
+    let (stdout, stdout_truncated) =
+        command_run_output_tail(&result.stdout, SUBPROCESS_TRANSCRIPT_MAX_OUTPUT_CHARS);
+    let (stderr, stderr_truncated) =
+        command_run_output_tail(&result.stderr, SUBPROCESS_TRANSCRIPT_MAX_OUTPUT_CHARS);
+    SubprocessTranscript {
+        argv,
+        cwd: command
+            .get_current_dir()
+            .map(|cwd| cwd.display().to_string()),
+        env,
+        duration_ms: elapsed.as_millis().try_into().unwrap_or(u64::MAX),
+        exit_code: result.exit_code,
+        stdout,
+        stderr,
+        output_truncated: stdout_truncated || stderr_truncated,
+        error: result.error.clone(),
+    }
+}
+
+/// Adds `command`'s run to the transcripts of the command running on this
+/// thread, when that one records them.
+fn record_subprocess_transcript(command: &Command, started_at: Instant, result: &CommandResult) {
+    let Some(transcripts) = current_subprocess_transcripts() else {
+        return;
+    };
+    let mut capture = lock_subprocess_transcripts(&transcripts);
+    let Some(capture) = capture.as_mut() else {
+        return;
+    };
+    if capture.transcripts.len() < SUBPROCESS_TRANSCRIPTS_MAX_PER_COMMAND {
+        capture
+            .transcripts
+            .push(subprocess_transcript(command, started_at.elapsed(), result));
+    }
+}
+
+#[cfg(test)]
+mod transcript_runtime_tests {
+    use super::*;
+
+    fn command_result(stdout: &str) -> CommandResult {
+        CommandResult {
+            exit_code: Some(0),
+            stdout: stdout.to_string(),
+            stderr: String::new(),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn transcript_lists_argv_cwd_and_env_deltas_with_secrets_redacted() {
+        let mut command = Command::new("gh");
+        command
+            .args(["pr", "view", "12"])
+            .current_dir("/repo")
+            .env("GH_TOKEN", "ghp_secret")
+            .env("GIT_CONFIG_KEY_0", "user.name")
+            .env_remove("PAGER");
+
+        let transcript =
+            subprocess_transcript(&command, Duration::from_millis(42), &command_result("ok\n"));
+
+        assert_eq!(transcript.argv, vec!["gh", "pr", "view", "12"]);
+        assert_eq!(transcript.cwd.as_deref(), Some("/repo"));
+        assert_eq!(transcript.duration_ms, 42);
+        assert_eq!(
+            transcript.env.get("GH_TOKEN"),
+            Some(&Some("<redacted>".to_string()))
+        );
+        assert_eq!(
+            transcript.env.get("GIT_CONFIG_KEY_0"),
+            Some(&Some("user.name".to_string()))
+        );
+        assert_eq!(transcript.env.get("PAGER"), Some(&None));
+        assert_eq!(transcript.stdout, "ok\n");
+        assert!(!transcript.output_truncated);
+    }
+
+    #[test]
+    fn only_the_command_that_asked_for_transcripts_records_them() {
+        let mut command = Command::new("git");
+        command.arg("status");
+        let started_at = Instant::now();
+
+        record_subprocess_transcript(&command, started_at, &command_result(""));
+
+        let context = CommandContext::new("git_add");
+        {
+            let _bound = BoundSubprocessTranscripts::bind(&context);
+            record_subprocess_transcript(&command, started_at, &command_result(""));
+            context.capture_subprocess_transcripts(Some(true));
+            record_subprocess_transcript(&command, started_at, &command_result("clean\n"));
+        }
+        record_subprocess_transcript(&command, started_at, &command_result(""));
+
+        let transcripts = context.take_subprocess_transcripts().expect("recorded");
+        assert_eq!(transcripts.len(), 1);
+        assert_eq!(transcripts[0].stdout, "clean\n");
+        assert!(context.take_subprocess_transcripts().is_none());
+    }
+}
@@ -328,7 +328,10 @@ fn doctrine_generate_report_blocking(
             .unwrap_or_else(|| record.created_at.clone());
 
         candidates.push(DoctrineWorktreeCase {
-            branch: branch_guess_from_worktree_name(worktree_name),
+            branch: record
+                .branch
+                .clone()
+                .unwrap_or_else(|| branch_guess_from_worktree_name(worktree_name)),
             prompts: doctrine_window_prompts(&extract.prompts),
             date,
             summary: doctrine_choose_summary(record, &extract.claude_summaries),
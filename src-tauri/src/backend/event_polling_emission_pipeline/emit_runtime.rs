@@ -0,0 +1,20 @@
+/// Emits `event` under its registered name with the schema version attached.
+fn emit_groove_event<E: GrooveEvent>(app: &AppHandle, event: &E) {
+    let _ = app.emit(E::NAME, events::versioned(event));
+}
+
+/// Set during setup so work running off the command threads (lock recovery,
+/// the git operation queue) can still reach the frontend.
+static BACKGROUND_EVENT_APP_HANDLE: std::sync::OnceLock<AppHandle> = std::sync::OnceLock::new();
+
+fn install_background_event_app_handle(app: &AppHandle) {
+    let _ = BACKGROUND_EVENT_APP_HANDLE.set(app.clone());
+}
+
+/// Like `emit_groove_event`, for callers without an `AppHandle`. Dropped
+/// silently before setup has run.
+fn emit_background_groove_event<E: GrooveEvent>(event: &E) {
+    if let Some(app) = BACKGROUND_EVENT_APP_HANDLE.get() {
+        emit_groove_event(app, event);
+    }
+}
@@ -0,0 +1,73 @@
+// App-wide pause of background activity, toggled by `background_pause` and
+// `background_resume`. The periodic loops (workspace worker, worktree watch,
+// system metrics sampler, terminal reaper and hibernation, terminal storage
+// janitor, backend heartbeat) keep their threads but skip every pass while paused, and carry
+// on from their last state on resume. Terminals, commands started by the
+// user and the MCP server keep running.
+
+static BACKGROUND_ACTIVITY_PAUSED: AtomicBool = AtomicBool::new(false);
+/// When the current pause started; `None` while background work runs.
+static BACKGROUND_PAUSED_SINCE: Mutex<Option<String>> = Mutex::new(None);
+
+fn background_activity_paused() -> bool {
+    BACKGROUND_ACTIVITY_PAUSED.load(Ordering::Relaxed)
+}
+
+fn background_paused_since() -> Option<String> {
+    BACKGROUND_PAUSED_SINCE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
+
+/// Pauses or resumes background work. Returns whether the state changed;
+/// the `background-activity` event is only sent when it did.
+fn set_background_activity_paused(app: &AppHandle, paused: bool) -> bool {
+    let changed_at = {
+        let mut paused_since = BACKGROUND_PAUSED_SINCE
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if paused_since.is_some() == paused {
+            return false;
+        }
+        let now = now_iso();
+        *paused_since = paused.then(|| now.clone());
+        BACKGROUND_ACTIVITY_PAUSED.store(paused, Ordering::Relaxed);
+        now
+    };
+
+    log_line(&format!(
+        "[background] {}",
+        if paused { "paused" } else { "resumed" }
+    ));
+    emit_groove_event(
+        app,
+        &BackgroundActivityEvent {
+            paused,
+            changed_at,
+        },
+    );
+    true
+}
+
+// Poll intervals of the workspace events workers and the worktree watch,
+// mirrored from `GlobalSettings.polling_settings` whenever that file is
+// written. Workers pick up a change on their next pass.
+
+static WORKSPACE_EVENTS_POLL_INTERVAL_MS: AtomicU64 =
+    AtomicU64::new(DEFAULT_WORKSPACE_EVENTS_POLL_INTERVAL_MS);
+static WORKTREE_WATCH_POLL_INTERVAL_MS: AtomicU64 =
+    AtomicU64::new(DEFAULT_WORKTREE_WATCH_POLL_INTERVAL_MS);
+
+fn set_polling_intervals(settings: &PollingSettings) {
+    WORKSPACE_EVENTS_POLL_INTERVAL_MS.store(settings.workspace_events_interval_ms, Ordering::Relaxed);
+    WORKTREE_WATCH_POLL_INTERVAL_MS.store(settings.worktree_watch_interval_ms, Ordering::Relaxed);
+}
+
+fn workspace_events_poll_interval() -> Duration {
+    Duration::from_millis(WORKSPACE_EVENTS_POLL_INTERVAL_MS.load(Ordering::Relaxed))
+}
+
+fn worktree_watch_poll_interval() -> Duration {
+    Duration::from_millis(WORKTREE_WATCH_POLL_INTERVAL_MS.load(Ordering::Relaxed))
+}
@@ -0,0 +1,316 @@
+// Focused poll worker behind `worktree_watch`, for the groove open in the
+// detail panel. It polls one worktree faster than the workspace worker polls
+// the whole list: git status runs when the index or HEAD changes and at
+// least every `WORKTREE_WATCH_GIT_REFRESH_INTERVAL`, and the log and process
+// signals are read on every pass. Only the fields that changed are emitted.
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct WorktreeWatchSnapshot {
+    modified: u32,
+    added: u32,
+    deleted: u32,
+    untracked: u32,
+    ahead: u32,
+    behind: u32,
+    git_error: Option<String>,
+    log_state: String,
+    latest_log_mtime_ms: u64,
+    terminal_sessions: usize,
+    command_runs_running: usize,
+}
+
+impl WorktreeWatchSnapshot {
+    fn dirty(&self) -> bool {
+        self.modified > 0 || self.added > 0 || self.deleted > 0 || self.untracked > 0
+    }
+}
+
+/// The event for `next`, with only the fields that differ from `previous`
+/// (every field when there is no previous snapshot). `None` when nothing
+/// changed.
+fn worktree_watch_delta(
+    worktree_path: &str,
+    sequence: u64,
+    previous: Option<&WorktreeWatchSnapshot>,
+    next: &WorktreeWatchSnapshot,
+) -> Option<WorktreeWatchEvent> {
+    if previous == Some(next) {
+        return None;
+    }
+    fn changed<T: PartialEq + Clone>(
+        previous: Option<&WorktreeWatchSnapshot>,
+        next: &WorktreeWatchSnapshot,
+        field: impl Fn(&WorktreeWatchSnapshot) -> T,
+    ) -> Option<T> {
+        let value = field(next);
+        (previous.map(&field).as_ref() != Some(&value)).then_some(value)
+    }
+
+    Some(WorktreeWatchEvent {
+        worktree_path: worktree_path.to_string(),
+        sequence,
+        modified: changed(previous, next, |snapshot| snapshot.modified),
+        added: changed(previous, next, |snapshot| snapshot.added),
+        deleted: changed(previous, next, |snapshot| snapshot.deleted),
+        untracked: changed(previous, next, |snapshot| snapshot.untracked),
+        dirty: changed(previous, next, WorktreeWatchSnapshot::dirty),
+        ahead: changed(previous, next, |snapshot| snapshot.ahead),
+        behind: changed(previous, next, |snapshot| snapshot.behind),
+        log_state: changed(previous, next, |snapshot| snapshot.log_state.clone()),
+        latest_log_mtime_ms: changed(previous, next, |snapshot| snapshot.latest_log_mtime_ms),
+        terminal_sessions: changed(previous, next, |snapshot| snapshot.terminal_sessions),
+        command_runs_running: changed(previous, next, |snapshot| snapshot.command_runs_running),
+        error: next.git_error.clone(),
+        removed: None,
+    })
+}
+
+/// Status counts and ahead/behind of the worktree, from libgit2 when it can
+/// read the repository and otherwise from one `git status -b` run.
+fn read_worktree_watch_git(
+    worktree_path: &Path,
+) -> Result<(git::GitPorcelainCounts, u32, u32), String> {
+    if let (Some(status), Some((ahead, behind))) = (
+        native_git::porcelain_status(worktree_path),
+        native_git::ahead_behind(worktree_path),
+    ) {
+        return Ok((parse_git_porcelain_counts(&status), ahead, behind));
+    }
+
+    let result = run_git_command_at_path(worktree_path, &["status", "--porcelain=v1", "-b"]);
+    if let Some(error) = result.error {
+        return Err(error);
+    }
+    if result.exit_code != Some(0) {
+        return Err(first_non_empty_line(&result.stderr)
+            .or_else(|| first_non_empty_line(&result.stdout))
+            .unwrap_or_else(|| "git status failed".to_string()));
+    }
+    let (ahead, behind) = parse_git_ahead_behind(&result.stdout);
+    Ok((parse_git_porcelain_counts(&result.stdout), ahead, behind))
+}
+
+/// Terminal sessions open in the worktree and custom command runs still
+/// running in it.
+fn count_worktree_watch_processes(app: &AppHandle, worktree_path: &Path) -> (usize, usize) {
+    let terminal_sessions = app
+        .try_state::<GrooveTerminalState>()
+        .map(|state| {
+            state
+                .lock_sessions()
+                .sessions_by_id
+                .values()
+                .filter(|session| Path::new(&session.worktree_path) == worktree_path)
+                .count()
+        })
+        .unwrap_or(0);
+    let command_runs_running = app
+        .try_state::<CommandRunsState>()
+        .map(|state| {
+            state
+                .lock_runs()
+                .iter()
+                .filter(|run| {
+                    run.status == CommandRunStatus::Running
+                        && Path::new(&run.workspace_root)
+                            .join(".worktrees")
+                            .join(&run.worktree)
+                            == worktree_path
+                })
+                .count()
+        })
+        .unwrap_or(0);
+    (terminal_sessions, command_runs_running)
+}
+
+/// Index and HEAD of the worktree's git dir, whose changes trigger a git
+/// status run before the refresh interval is up.
+fn worktree_watch_git_targets(worktree_path: &Path) -> Vec<PathBuf> {
+    worktree_git_dir(worktree_path)
+        .map(|git_dir| vec![git_dir.join("index"), git_dir.join("HEAD")])
+        .unwrap_or_default()
+}
+
+fn run_worktree_watch(app: AppHandle, worktree_path: PathBuf, stop: Arc<AtomicBool>) {
+    let worktree_path_display = worktree_path.display().to_string();
+    let git_targets = worktree_watch_git_targets(&worktree_path);
+    let mut git_snapshots = Vec::<SnapshotEntry>::new();
+    let mut last_git_refresh_at: Option<Instant> = None;
+    let mut current = WorktreeWatchSnapshot::default();
+    let mut previous: Option<WorktreeWatchSnapshot> = None;
+    let mut sequence: u64 = 0;
+
+    while !stop.load(Ordering::Relaxed) {
+        if background_activity_paused() {
+            thread::sleep(WORKSPACE_EVENTS_STOP_POLL_INTERVAL);
+            continue;
+        }
+
+        if !path_is_directory(&worktree_path) {
+            emit_groove_event(
+                &app,
+                &WorktreeWatchEvent {
+                    worktree_path: worktree_path_display.clone(),
+                    sequence,
+                    modified: None,
+                    added: None,
+                    deleted: None,
+                    untracked: None,
+                    dirty: None,
+                    ahead: None,
+                    behind: None,
+                    log_state: None,
+                    latest_log_mtime_ms: None,
+                    terminal_sessions: None,
+                    command_runs_running: None,
+                    error: None,
+                    removed: Some(true),
+                },
+            );
+            log_line(&format!(
+                "[worktree-watch] stopped worktree={worktree_path_display} reason=removed"
+            ));
+            return;
+        }
+
+        let next_git_snapshots = git_targets
+            .iter()
+            .map(|path| snapshot_entry(path))
+            .collect::<Vec<_>>();
+        let git_due = last_git_refresh_at
+            .map(|at| at.elapsed() >= WORKTREE_WATCH_GIT_REFRESH_INTERVAL)
+            .unwrap_or(true);
+        if git_due || next_git_snapshots != git_snapshots {
+            match read_worktree_watch_git(&worktree_path) {
+                Ok((counts, ahead, behind)) => {
+                    current.modified = counts.modified;
+                    current.added = counts.added;
+                    current.deleted = counts.deleted;
+                    current.untracked = counts.untracked;
+                    current.ahead = ahead;
+                    current.behind = behind;
+                    current.git_error = None;
+                }
+                Err(error) => current.git_error = Some(error),
+            }
+            last_git_refresh_at = Some(Instant::now());
+            // Taken after the run: `git status` may refresh the index itself.
+            git_snapshots = git_targets
+                .iter()
+                .map(|path| snapshot_entry(path))
+                .collect();
+        }
+
+        let log_signals = collect_native_log_signals(&worktree_path);
+        current.log_state = log_signals.log_state;
+        current.latest_log_mtime_ms =
+            u64::try_from(log_signals.latest_log_mtime_ms).unwrap_or(u64::MAX);
+        (current.terminal_sessions, current.command_runs_running) =
+            count_worktree_watch_processes(&app, &worktree_path);
+
+        if let Some(event) = worktree_watch_delta(
+            &worktree_path_display,
+            sequence,
+            previous.as_ref(),
+            &current,
+        ) {
+            emit_groove_event(&app, &event);
+            sequence += 1;
+            previous = Some(current.clone());
+        }
+
+        let mut slept = Duration::ZERO;
+        while slept < worktree_watch_poll_interval() && !stop.load(Ordering::Relaxed) {
+            thread::sleep(WORKSPACE_EVENTS_STOP_POLL_INTERVAL);
+            slept += WORKSPACE_EVENTS_STOP_POLL_INTERVAL;
+        }
+    }
+}
+
+/// Starts watching `worktree_path`, stopping the watch of any other
+/// worktree. Watching the worktree already watched keeps its worker.
+fn start_worktree_watch(app: &AppHandle, worktree_path: PathBuf) -> Result<(), String> {
+    let state = app.state::<WorktreeWatchState>();
+    let mut watcher = state
+        .watcher
+        .lock()
+        .map_err(|error| format!("Failed to acquire worktree watch lock: {error}"))?;
+    if let Some(existing) = watcher.as_ref() {
+        if existing.worktree_path == worktree_path && !existing.handle.is_finished() {
+            return Ok(());
+        }
+    }
+    if let Some(existing) = watcher.take() {
+        existing.stop.store(true, Ordering::Relaxed);
+    }
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let stop_signal = stop.clone();
+    let app_handle = app.clone();
+    let worktree_path_clone = worktree_path.clone();
+    let handle = thread::spawn(move || {
+        run_worktree_watch(app_handle, worktree_path_clone, stop_signal);
+    });
+    log_line(&format!(
+        "[worktree-watch] started worktree={}",
+        worktree_path.display()
+    ));
+    *watcher = Some(WorktreeWatcher {
+        worktree_path,
+        stop,
+        handle,
+    });
+    Ok(())
+}
+
+/// Stops the current watch, if any.
+fn stop_worktree_watch(app: &AppHandle) -> Result<(), String> {
+    let state = app.state::<WorktreeWatchState>();
+    let mut watcher = state
+        .watcher
+        .lock()
+        .map_err(|error| format!("Failed to acquire worktree watch lock: {error}"))?;
+    if let Some(existing) = watcher.take() {
+        existing.stop.store(true, Ordering::Relaxed);
+        log_line(&format!(
+            "[worktree-watch] stopped worktree={} reason=unwatch",
+            existing.worktree_path.display()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod watch_runtime_tests {
+    use super::*;
+
+    #[test]
+    fn worktree_watch_delta_only_carries_changed_fields() {
+        let first = WorktreeWatchSnapshot {
+            modified: 2,
+            log_state: "none".to_string(),
+            ..WorktreeWatchSnapshot::default()
+        };
+        let full = worktree_watch_delta("/repo/.worktrees/a", 0, None, &first).unwrap();
+        assert_eq!(full.modified, Some(2));
+        assert_eq!(full.untracked, Some(0));
+        assert_eq!(full.dirty, Some(true));
+        assert_eq!(full.log_state.as_deref(), Some("none"));
+
+        assert!(worktree_watch_delta("/repo/.worktrees/a", 1, Some(&first), &first).is_none());
+
+        let next = WorktreeWatchSnapshot {
+            modified: 0,
+            ahead: 1,
+            ..first.clone()
+        };
+        let delta = worktree_watch_delta("/repo/.worktrees/a", 1, Some(&first), &next).unwrap();
+        assert_eq!(delta.sequence, 1);
+        assert_eq!(delta.modified, Some(0));
+        assert_eq!(delta.dirty, Some(false));
+        assert_eq!(delta.ahead, Some(1));
+        assert_eq!(delta.behind, None);
+        assert_eq!(delta.log_state, None);
+        assert_eq!(delta.terminal_sessions, None);
+    }
+}
@@ -0,0 +1,63 @@
+#[tauri::command]
+fn api_tokens_list(app: AppHandle) -> ApiTokensResponse {
+    handle_command("api_tokens_list", |context| match list_api_tokens(&app) {
+        Ok(tokens) => ApiTokensResponse {
+            request_id: context.request_id(),
+            ok: true,
+            tokens,
+            secret: None,
+            error: None,
+        },
+        Err(error) => ApiTokensResponse {
+            request_id: context.request_id(),
+            ok: false,
+            tokens: Vec::new(),
+            secret: None,
+            error: Some(error),
+        },
+    })
+}
+
+#[tauri::command]
+fn api_token_create(app: AppHandle, payload: ApiTokenCreatePayload) -> ApiTokensResponse {
+    handle_command("api_token_create", |context| {
+        match create_api_token(&app, &payload.name, payload.capabilities.as_deref()) {
+            Ok((tokens, secret)) => ApiTokensResponse {
+                request_id: context.request_id(),
+                ok: true,
+                tokens,
+                secret: Some(secret),
+                error: None,
+            },
+            Err(error) => ApiTokensResponse {
+                request_id: context.request_id(),
+                ok: false,
+                tokens: Vec::new(),
+                secret: None,
+                error: Some(error),
+            },
+        }
+    })
+}
+
+#[tauri::command]
+fn api_token_revoke(app: AppHandle, payload: ApiTokenRevokePayload) -> ApiTokensResponse {
+    handle_command("api_token_revoke", |context| {
+        match revoke_api_token(&app, payload.id.trim()) {
+            Ok(tokens) => ApiTokensResponse {
+                request_id: context.request_id(),
+                ok: true,
+                tokens,
+                secret: None,
+                error: None,
+            },
+            Err(error) => ApiTokensResponse {
+                request_id: context.request_id(),
+                ok: false,
+                tokens: Vec::new(),
+                secret: None,
+                error: Some(error),
+            },
+        }
+    })
+}
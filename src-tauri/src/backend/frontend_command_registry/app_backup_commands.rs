@@ -0,0 +1,43 @@
+fn app_backup_error_response(request_id: String, error: String) -> AppBackupResponse {
+    AppBackupResponse {
+        request_id,
+        ok: false,
+        path: None,
+        files: Vec::new(),
+        workspaces: Vec::new(),
+        skipped_workspaces: Vec::new(),
+        error: Some(error),
+    }
+}
+
+#[tauri::command]
+async fn app_backup_create(app: AppHandle, payload: AppBackupCreatePayload) -> AppBackupResponse {
+    handle_blocking_command(
+        "app_backup_create",
+        move |context| match run_app_backup_create(&app, &payload) {
+            Ok(response) => AppBackupResponse {
+                request_id: context.request_id(),
+                ..response
+            },
+            Err(error) => app_backup_error_response(context.request_id(), error),
+        },
+        app_backup_error_response,
+    )
+    .await
+}
+
+#[tauri::command]
+async fn app_backup_restore(app: AppHandle, payload: AppBackupRestorePayload) -> AppBackupResponse {
+    handle_blocking_command(
+        "app_backup_restore",
+        move |context| match run_app_backup_restore(&app, &payload) {
+            Ok(response) => AppBackupResponse {
+                request_id: context.request_id(),
+                ..response
+            },
+            Err(error) => app_backup_error_response(context.request_id(), error),
+        },
+        app_backup_error_response,
+    )
+    .await
+}
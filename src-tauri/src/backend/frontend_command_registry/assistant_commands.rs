@@ -82,27 +82,20 @@ fn probe_groove_mcp_server(port: u16) -> bool {
 /// the UI thread because `claude mcp add` may block for a moment.
 #[tauri::command]
 async fn assistant_connect_transport() -> AssistantConnectResponse {
-    let request_id = request_id();
-    let fallback_request_id = request_id.clone();
-
-    match tauri::async_runtime::spawn_blocking(move || {
-        assistant_connect_transport_blocking(request_id)
-    })
-    .await
-    {
-        Ok(response) => response,
-        Err(error) => AssistantConnectResponse {
-            request_id: fallback_request_id,
+    handle_blocking_command(
+        "assistant_connect_transport",
+        move |context| assistant_connect_transport_blocking(context.request_id()),
+        |request_id, error| AssistantConnectResponse {
+            request_id,
             ok: false,
             already_connected: false,
             endpoint: groove_mcp_endpoint(),
             scope: "user".to_string(),
             message: None,
-            error: Some(format!(
-                "Failed to run assistant connect worker thread: {error}"
-            )),
+            error: Some(error),
         },
-    }
+    )
+    .await
 }
 
 fn assistant_connect_transport_blocking(request_id: String) -> AssistantConnectResponse {
@@ -210,28 +203,21 @@ fn assistant_connect_transport_blocking(request_id: String) -> AssistantConnectR
 /// Runs off the UI thread — `claude mcp list` live-pings every server.
 #[tauri::command]
 async fn assistant_validate_mcp() -> AssistantValidateResponse {
-    let request_id = request_id();
-    let fallback_request_id = request_id.clone();
-
-    match tauri::async_runtime::spawn_blocking(move || {
-        assistant_validate_mcp_blocking(request_id)
-    })
-    .await
-    {
-        Ok(response) => response,
-        Err(error) => AssistantValidateResponse {
-            request_id: fallback_request_id,
+    handle_blocking_command(
+        "assistant_validate_mcp",
+        move |context| assistant_validate_mcp_blocking(context.request_id()),
+        |request_id, error| AssistantValidateResponse {
+            request_id,
             ok: false,
             server_running: false,
             registered_in_claude: false,
             claude_connection_ok: false,
             endpoint: groove_mcp_endpoint(),
             details: None,
-            error: Some(format!(
-                "Failed to run assistant validate worker thread: {error}"
-            )),
+            error: Some(error),
         },
-    }
+    )
+    .await
 }
 
 fn assistant_validate_mcp_blocking(request_id: String) -> AssistantValidateResponse {
@@ -322,29 +308,35 @@ fn assistant_rules_list_response(
 
 #[tauri::command]
 fn assistant_rules_list(app: AppHandle) -> AssistantRulesListResponse {
-    assistant_rules_list_response(&app, request_id(), None)
+    handle_command("assistant_rules_list", |context| {
+        assistant_rules_list_response(&app, context.request_id(), None)
+    })
 }
 
 #[tauri::command]
 fn assistant_rule_add(app: AppHandle, scope: String, text: String) -> AssistantRulesListResponse {
-    let request_id = request_id();
-    let workspace_root = resolve_workspace_root(&app, &None, None, &[], &None).ok();
-    let outcome = assistant_rules_scope_path(&app, scope.trim(), workspace_root.as_deref())
-        .and_then(|path| assistant_rules_add(&path, &text).map(|_| ()));
-    match outcome {
-        Ok(()) => assistant_rules_list_response(&app, request_id, None),
-        Err(error) => assistant_rules_list_response(&app, request_id, Some(error)),
-    }
+    handle_command("assistant_rule_add", |context| {
+        let request_id = context.request_id();
+        let workspace_root = resolve_workspace_root(&app, &None, None, &[], &None).ok();
+        let outcome = assistant_rules_scope_path(&app, scope.trim(), workspace_root.as_deref())
+            .and_then(|path| assistant_rules_add(&path, &text).map(|_| ()));
+        match outcome {
+            Ok(()) => assistant_rules_list_response(&app, request_id, None),
+            Err(error) => assistant_rules_list_response(&app, request_id, Some(error)),
+        }
+    })
 }
 
 #[tauri::command]
 fn assistant_rule_remove(app: AppHandle, scope: String, id: String) -> AssistantRulesListResponse {
-    let request_id = request_id();
-    let workspace_root = resolve_workspace_root(&app, &None, None, &[], &None).ok();
-    let outcome = assistant_rules_scope_path(&app, scope.trim(), workspace_root.as_deref())
-        .and_then(|path| assistant_rules_remove(&path, id.trim()).map(|_| ()));
-    match outcome {
-        Ok(()) => assistant_rules_list_response(&app, request_id, None),
-        Err(error) => assistant_rules_list_response(&app, request_id, Some(error)),
-    }
+    handle_command("assistant_rule_remove", |context| {
+        let request_id = context.request_id();
+        let workspace_root = resolve_workspace_root(&app, &None, None, &[], &None).ok();
+        let outcome = assistant_rules_scope_path(&app, scope.trim(), workspace_root.as_deref())
+            .and_then(|path| assistant_rules_remove(&path, id.trim()).map(|_| ()));
+        match outcome {
+            Ok(()) => assistant_rules_list_response(&app, request_id, None),
+            Err(error) => assistant_rules_list_response(&app, request_id, Some(error)),
+        }
+    })
 }
@@ -0,0 +1,50 @@
+// Both commands run off the main thread so they are answered while a
+// synchronous command still holds it.
+
+#[tauri::command(async)]
+fn cancel_request(payload: CancelRequestPayload) -> CancelRequestResponse {
+    handle_command("cancel_request", |context| {
+        let request_id = context.request_id();
+        let target = payload.request_id.trim();
+        if target.is_empty() {
+            return CancelRequestResponse {
+                request_id,
+                ok: false,
+                command: None,
+                error: Some("requestId is required.".to_string()),
+            };
+        }
+
+        match cancel_command(target) {
+            Some(command) => CancelRequestResponse {
+                request_id,
+                ok: true,
+                command: Some(command.to_string()),
+                error: None,
+            },
+            None => CancelRequestResponse {
+                request_id,
+                ok: false,
+                command: None,
+                error: Some(format!("No command with request id {target} is in flight.")),
+            },
+        }
+    })
+}
+
+#[tauri::command(async)]
+fn commands_in_flight() -> CommandsInFlightResponse {
+    handle_command("commands_in_flight", |context| {
+        let request_id = context.request_id();
+        let commands = list_commands_in_flight()
+            .into_iter()
+            .filter(|command| command.request_id != request_id)
+            .collect();
+        CommandsInFlightResponse {
+            request_id,
+            ok: true,
+            commands,
+            error: None,
+        }
+    })
+}
@@ -1,26 +1,41 @@
 pub(crate) fn run() {
     tauri::Builder::default()
         .manage(WorkspaceEventState::default())
+        .manage(WorktreeWatchState::default())
         .manage(WorkspaceContextCacheState::default())
         .manage(TerminalResolutionCacheState::default())
         .manage(GrooveListCacheState::default())
         .manage(GrooveBinStatusState::default())
         .manage(GrooveTerminalState::default())
+        .manage(ActionHistoryState::default())
+        .manage(ApiTokensState::default())
+        .manage(UndoState::default())
+        .manage(CommandRunsState::default())
         .setup(|app| {
             let status = evaluate_groove_bin_check_status(&app.handle());
             if status.has_issue {
-                eprintln!(
+                log_line(&format!(
                     "[startup-warning] GROOVE_BIN is invalid and may break groove command execution: {}",
                     status.configured_path.as_deref().unwrap_or("<unset>")
-                );
+                ));
             }
 
             let state = app.state::<GrooveBinStatusState>();
-            if let Ok(mut stored) = state.status.lock() {
-                *stored = Some(status);
-            }
+            *state.lock_status() = Some(status);
 
-            let _ = ensure_global_settings(&app.handle());
+            if let Ok(settings) = ensure_global_settings(app.handle()) {
+                set_command_telemetry_enabled(settings.telemetry_enabled);
+                set_terminal_memory_limits(&settings.terminal_memory_settings);
+                set_system_alert_thresholds(&settings.system_alert_settings);
+                set_telemetry_path_redaction(&settings.telemetry_path_redaction);
+                set_custom_command_policy(&settings.custom_command_policy);
+                set_default_terminal_override(settings.default_terminal.as_deref());
+                set_polling_intervals(&settings.polling_settings);
+                set_status_page_settings(&settings.status_page_settings);
+                set_command_retry_policy(&settings.command_retry_policy);
+            }
+            load_workspace_trust(app.handle());
+            remove_stale_terminal_scrollback_files(app.handle());
 
             // Ensure TERM is set so PTY sessions render correctly.
             // GUI-launched apps (AppImage, desktop entry) often lack TERM.
@@ -33,7 +48,15 @@ pub(crate) fn run() {
                 std::env::set_var("TERM", "xterm-256color");
             }
 
+            install_background_event_app_handle(app.handle());
             start_groove_mcp_server(app.handle().clone());
+            start_groove_terminal_reaper(app.handle().clone());
+            start_terminal_storage_janitor(app.handle().clone());
+            start_system_metrics_sampler(app.handle().clone());
+            start_maintenance_scheduler(app.handle().clone());
+            start_testing_environment_supervisor(app.handle().clone());
+            start_status_page_server(app.handle().clone());
+            start_backend_heartbeat(app.handle().clone());
 
             Ok(())
         })
@@ -42,12 +65,26 @@ pub(crate) fn run() {
             workspace_open,
             workspace_get_active,
             workspace_clear_active,
+            workspace_list_open,
+            workspace_switch,
+            workspace_close,
             workspace_term_sanity_check,
             workspace_term_sanity_apply,
             workspace_gitignore_sanity_check,
             workspace_gitignore_sanity_apply,
             global_settings_get,
             global_settings_update,
+            settings_profile_list,
+            settings_profile_save,
+            settings_profile_delete,
+            settings_profile_switch,
+            settings_profile_export,
+            settings_profile_import,
+            settings_sync_status,
+            settings_sync_configure,
+            settings_sync_export,
+            settings_sync_import,
+            settings_sync_merge,
             sound_library_read,
             sound_library_import,
             sound_library_remove,
@@ -56,22 +93,70 @@ pub(crate) fn run() {
             sound_library_open_directory,
             workspace_update_terminal_settings,
             workspace_update_commands_settings,
+            play_command_preview,
             workspace_update_max_worktree_count,
+            workspace_update_staleness_policy,
+            workspace_freeze,
+            workspace_thaw,
+            workspace_conflict_matrix,
+            workspace_update_remote,
+            workspace_update_git_identity,
+            workspace_update_commit_template,
+            workspace_update_commit_lint,
+            workspace_update_require_sign_off,
+            workspace_set_trust,
+            custom_command_approve,
+            workspace_custom_commands_list,
+            workspace_update_custom_commands,
+            workspace_remote_probe,
+            workspace_remote_list_worktrees,
+            workspace_remote_git_status,
             workspace_update_root_directory,
             workspace_mark_onboarding_configured,
             workspace_update_worktree_symlink_paths,
             workspace_set_worktree_state,
+            workspace_adopt_worktree,
+            workspace_migrate,
+            workspace_set_worktree_play_command,
+            workspace_set_worktree_labels,
+            workspace_search_worktrees,
+            workspace_list_worktrees,
+            workspace_summary,
             workspace_claim_worktree_reward,
             workspace_loot_worktree,
             workspace_list_symlink_entries,
             workspace_open_terminal,
+            workspace_custom_commands_run,
+            command_runs_list,
+            cancel_request,
+            commands_in_flight,
+            maintenance_status,
+            maintenance_configure,
+            maintenance_run_now,
+            testing_environment_status,
+            testing_environment_logs_tail,
+            testing_environment_logs_clear,
+            status_page_status,
+            app_backup_create,
+            app_backup_restore,
             workspace_open_workspace_terminal,
+            groove_tmux_list_windows,
+            groove_tmux_kill_window,
+            groove_zellij_list_sessions,
+            groove_zellij_attach_session,
+            groove_zellij_kill_session,
             workspace_open_directory,
             groove_terminal_open,
+            worktree_session_layout_restore,
             groove_terminal_write,
             groove_terminal_resize,
             groove_terminal_close,
             groove_terminal_get_session,
+            groove_terminal_fetch_scrollback,
+            groove_terminal_attach,
+            groove_terminal_set_scrollback_recording,
+            groove_terminal_history,
+            terminal_storage_stats,
             groove_terminal_list_sessions,
             groove_terminal_check_activity,
             groove_terminal_active_worktrees,
@@ -82,8 +167,16 @@ pub(crate) fn run() {
             git_ahead_behind,
             git_pull,
             git_push,
+            git_fetch_blobs_for_worktree,
             git_merge,
             git_merge_abort,
+            git_conflicts_list,
+            git_conflict_resolve,
+            git_rebase_status,
+            git_rebase_start,
+            git_rebase_continue,
+            git_rebase_skip,
+            git_rebase_abort,
             git_has_staged_changes,
             git_merge_in_progress,
             git_has_upstream,
@@ -91,8 +184,16 @@ pub(crate) fn run() {
             git_diff,
             git_stage_files,
             git_unstage_files,
+            git_diff_file,
+            git_diff_hunks,
+            git_log,
+            git_stage_hunk,
+            git_unstage_hunk,
             git_add,
             git_commit,
+            git_commit_hooks,
+            git_lint_commit_message,
+            git_push_preflight,
             gh_auth_status,
             gh_auth_login,
             gh_auth_switch,
@@ -102,13 +203,36 @@ pub(crate) fn run() {
             gh_repo_default_branch,
             gh_pr_list,
             gh_pr_view,
+            gh_pr_checks,
+            gh_pr_merge,
+            gh_pr_comments,
+            gh_pr_review,
+            gh_pr_prepare,
+            gh_pr_create,
             gh_pr_create_web,
+            gh_pr_update,
+            gh_issue_list,
+            gh_issue_view,
             open_external_url,
             groove_list,
             groove_new,
             groove_restore,
             groove_rm,
+            groove_rm_safety_check,
             groove_stop,
+            groove_bulk_rm,
+            groove_bulk_stop,
+            groove_bulk_restore,
+            worktree_staleness_report,
+            worktree_action,
+            groove_archive,
+            groove_archive_list,
+            groove_unarchive,
+            worktree_restack,
+            merge_train_start,
+            merge_train_resume,
+            merge_train_status,
+            merge_train_abort,
             groove_recoverable_list,
             groove_recoverable_clear,
             worktree_storage_stats,
@@ -120,13 +244,29 @@ pub(crate) fn run() {
             groove_discover_worktree_unit,
             groove_bin_status,
             groove_bin_repair,
+            app_health,
+            history_list,
+            api_tokens_list,
+            api_token_create,
+            api_token_revoke,
+            history_repeat,
+            undo_list,
+            undo_last,
             diagnostics_stop_process,
             diagnostics_kill_all_node_instances,
             diagnostics_list_worktree_node_apps,
             diagnostics_clean_all_dev_servers,
+            diagnostics_stop_by_category,
             diagnostics_get_msot_consuming_programs,
             diagnostics_get_system_overview,
+            diagnostics_terminal_memory,
+            diagnostics_worktree_resources,
+            diagnostics_fd_usage,
             workspace_events,
+            worktree_watch,
+            worktree_unwatch,
+            background_pause,
+            background_resume,
             opencode_integration_status,
             opencode_update_workspace_settings,
             opencode_update_global_settings,
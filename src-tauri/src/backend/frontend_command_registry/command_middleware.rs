@@ -0,0 +1,649 @@
+// Shared wrapper for `#[tauri::command]` handlers. Every command body runs
+// inside `handle_command` (or `handle_blocking_command` for the async ones that
+// offload to a worker thread), which hands it a `CommandContext` carrying the
+// request id, times the call and reports failures and slow calls as
+// `[command-telemetry]` lines, with the failure message mapped to a
+// `CommandErrorCode` for that log only: responses still carry just `error`,
+// and each command validates its own payload. A new command only has to
+// build its response; the bookkeeping is done here.
+//
+// Every command in flight can be cancelled by its request id through
+// `cancel_request`. Cancellation is cooperative: a body checks
+// `CommandContext::is_cancelled`, and the process helpers check
+// `command_cancelled` on the thread the body runs on and kill the child
+// they are waiting on. Responses listed in
+// `impl_cancellable_command_response!` then come back with `cancelled: true`.
+
+/// Mirrors `GlobalSettings.telemetry_enabled` so the per-call check does not
+/// re-read `global-settings.json`. Refreshed whenever that file is written.
+static COMMAND_TELEMETRY_ENABLED: AtomicBool = AtomicBool::new(true);
+
+fn set_command_telemetry_enabled(enabled: bool) {
+    COMMAND_TELEMETRY_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Mirrors `GlobalSettings.telemetry_path_redaction` as an index into
+/// `PathRedaction::ALL`, read by `log_line` for every log line.
+static TELEMETRY_PATH_REDACTION: AtomicU8 = AtomicU8::new(PathRedaction::Off as u8);
+
+fn set_telemetry_path_redaction(value: &str) {
+    let mode = PathRedaction::parse(value).unwrap_or(PathRedaction::Off);
+    TELEMETRY_PATH_REDACTION.store(mode as u8, Ordering::Relaxed);
+}
+
+fn telemetry_path_redaction() -> PathRedaction {
+    PathRedaction::ALL
+        .get(usize::from(
+            TELEMETRY_PATH_REDACTION.load(Ordering::Relaxed),
+        ))
+        .copied()
+        .unwrap_or(PathRedaction::Off)
+}
+
+/// Read access to the fields every command response shares.
+trait CommandResponse {
+    fn command_request_id(&self) -> &str;
+    fn command_ok(&self) -> bool;
+    fn command_error(&self) -> Option<&str>;
+    /// Sets `cancelled` on the responses that carry it.
+    fn mark_cancelled(&mut self) {}
+    /// Sets `debug_transcripts` on the responses that carry it.
+    fn attach_debug_transcripts(&mut self, _transcripts: Vec<SubprocessTranscript>) {}
+    /// Sets `retries` on the responses that carry it.
+    fn attach_command_retries(&mut self, _retries: Vec<CommandRetry>) {}
+}
+
+macro_rules! command_response_accessors {
+    () => {
+        fn command_request_id(&self) -> &str {
+            &self.request_id
+        }
+
+        fn command_ok(&self) -> bool {
+            self.ok
+        }
+
+        fn command_error(&self) -> Option<&str> {
+            self.error.as_deref()
+        }
+    };
+}
+
+macro_rules! impl_command_response {
+    ($($response:ty),* $(,)?) => {
+        $(
+            impl CommandResponse for $response {
+                command_response_accessors!();
+            }
+        )*
+    };
+}
+
+macro_rules! debug_transcripts_accessor {
+    () => {
+        fn attach_debug_transcripts(&mut self, transcripts: Vec<SubprocessTranscript>) {
+            self.debug_transcripts = Some(transcripts);
+        }
+    };
+}
+
+macro_rules! command_retries_accessor {
+    () => {
+        fn attach_command_retries(&mut self, retries: Vec<CommandRetry>) {
+            self.retries = Some(retries);
+        }
+    };
+}
+
+/// For responses with a `cancelled: Option<bool>` field, which is set when
+/// the command was cancelled while it ran.
+macro_rules! impl_cancellable_command_response {
+    ($($response:ty),* $(,)?) => {
+        $(
+            impl CommandResponse for $response {
+                command_response_accessors!();
+
+                fn mark_cancelled(&mut self) {
+                    self.cancelled = Some(true);
+                }
+            }
+        )*
+    };
+}
+
+/// For cancellable responses that also have a `retries` field, which lists
+/// the network-bound runs that failed and were tried again.
+macro_rules! impl_retrying_command_response {
+    ($($response:ty),* $(,)?) => {
+        $(
+            impl CommandResponse for $response {
+                command_response_accessors!();
+                command_retries_accessor!();
+
+                fn mark_cancelled(&mut self) {
+                    self.cancelled = Some(true);
+                }
+            }
+        )*
+    };
+}
+
+/// For responses with a `debug_transcripts` field, which is set when the
+/// command was called with `debug: true`.
+macro_rules! impl_debuggable_command_response {
+    ($($response:ty),* $(,)?) => {
+        $(
+            impl CommandResponse for $response {
+                command_response_accessors!();
+                debug_transcripts_accessor!();
+            }
+        )*
+    };
+}
+
+impl_command_response!(
+    ApiTokensResponse,
+    AppBackupResponse,
+    AppHealthResponse,
+    AssistantConnectResponse,
+    AssistantRulesListResponse,
+    AssistantValidateResponse,
+    BackgroundActivityResponse,
+    CancelRequestResponse,
+    ClaimWorktreeRewardResponse,
+    CommandRunsListResponse,
+    CommandsInFlightResponse,
+    CustomCommandApproveResponse,
+    DiagnosticsFdUsageResponse,
+    DiagnosticsMostConsumingProgramsResponse,
+    DiagnosticsNodeAppsResponse,
+    DiagnosticsStopAllResponse,
+    DiagnosticsStopByCategoryResponse,
+    DiagnosticsStopResponse,
+    DiagnosticsSystemOverviewResponse,
+    DiagnosticsTerminalMemoryResponse,
+    DiagnosticsWorktreeResourcesResponse,
+    DiscoverWorktreeUnitResponse,
+    DoctrineListResponse,
+    DoctrineReportResponse,
+    DoctrineResultResponse,
+    DoctrineSetActiveResponse,
+    ExternalUrlOpenResponse,
+    GitAheadBehindResponse,
+    GitAuthStatusResponse,
+    GitBooleanResponse,
+    GitCommitHooksResponse,
+    GitCommitResponse,
+    GitConflictResolveResponse,
+    GitConflictsResponse,
+    GitCurrentBranchResponse,
+    GitDiffResponse,
+    GitDiffFileResponse,
+    GitDiffHunksResponse,
+    GitLogResponse,
+    GitFetchBlobsResponse,
+    GitFileStatesResponse,
+    GitLintCommitMessageResponse,
+    GitListBranchesResponse,
+    GitPushPreflightResponse,
+    GitRebaseResponse,
+    GitRebaseStatusResponse,
+    GitStatusResponse,
+    GlobalSettingsResponse,
+    GrooveArchiveListResponse,
+    GrooveArchiveResponse,
+    GrooveBinRepairResponse,
+    GrooveBinStatusResponse,
+    GrooveCommentMarkCommittedResponse,
+    GrooveCommentResponse,
+    GroovePrResponse,
+    GrooveRecoverableClearResponse,
+    GrooveRecoverableListResponse,
+    GrooveRmSafetyResponse,
+    GrooveStopResponse,
+    GrooveBulkResponse,
+    GrooveSummaryResponse,
+    GrooveTerminalActiveWorktreesResponse,
+    GrooveTerminalActivityResponse,
+    GrooveTerminalAttachResponse,
+    GrooveTerminalHistoryResponse,
+    GrooveTerminalResponse,
+    GrooveTerminalScrollbackResponse,
+    GrooveTerminalSessionsResponse,
+    LootWorktreeResponse,
+    MaintenanceResponse,
+    OpenCodeProfileResponse,
+    OpenCodeRepairResponse,
+    OpenCodeStatusResponse,
+    OpenCodeSyncResponse,
+    OpencodeCopySkillsResponse,
+    OpencodeGlobalSettingsResponse,
+    OpencodeIntegrationStatusResponse,
+    OpencodeSettingsDirectoryValidationResponse,
+    OpencodeSkillsListResponse,
+    OpencodeWorkspaceSettingsResponse,
+    PlayCommandPreviewResponse,
+    SetWorktreeStateResponse,
+    SettingsProfilesResponse,
+    SettingsSyncResponse,
+    SoundLibraryPathResponse,
+    SoundLibraryReadResponse,
+    StatusPageStatusResponse,
+    TerminalStorageStatsResponse,
+    TestingEnvironmentLogsResponse,
+    TestingEnvironmentStatusResponse,
+    UndoLastResponse,
+    UndoListResponse,
+    WorkspaceAdoptWorktreeResponse,
+    WorkspaceBrowseEntriesResponse,
+    WorkspaceCustomCommandRunResponse,
+    WorkspaceCustomCommandsListResponse,
+    WorkspaceEventsResponse,
+    WorkspaceFreezeResponse,
+    WorkspaceConflictMatrixResponse,
+    WorktreeWatchResponse,
+    WorkspaceGitignoreSanityResponse,
+    WorkspaceMaxWorktreeCountResponse,
+    WorkspaceMigrateResponse,
+    WorkspaceOpenDirectoryResponse,
+    WorkspaceRemoteProbeResponse,
+    WorkspaceRemoteWorktreesResponse,
+    WorkspaceSearchWorktreesResponse,
+    WorkspaceListWorktreesResponse,
+    WorkspaceSummaryResponse,
+    WorkspaceListOpenResponse,
+    GrooveTmuxWindowsResponse,
+    GrooveZellijSessionsResponse,
+    HistoryListResponse,
+    HistoryRepeatResponse,
+    WorkspaceTermSanityResponse,
+    WorkspaceTerminalSettingsResponse,
+    WorkspaceTrustResponse,
+    WorktreeActionResponse,
+    WorktreeRestackResponse,
+    MergeTrainResponse,
+    WorktreeSessionLayoutRestoreResponse,
+    WorktreeStalenessReportResponse,
+    WorktreeStorageStatsResponse,
+);
+
+impl_cancellable_command_response!(
+    GhPrCreateResponse,
+    GhPrMergeResponse,
+    GhPrPrepareResponse,
+    GhSshOverviewResponse,
+    GrooveListResponse,
+    WorkspaceContextResponse,
+);
+
+impl_retrying_command_response!(
+    GhAuthStatusResponse,
+    GhIssueListResponse,
+    GhIssueViewResponse,
+    GhPrChecksResponse,
+    GhPrCommentsResponse,
+    GhPrListResponse,
+    GhPrViewResponse,
+    GhRepoDefaultBranchResponse,
+);
+
+impl_debuggable_command_response!(GrooveCommandResponse, GrooveNewResponse, GrooveRmResponse);
+
+impl CommandResponse for GitCommandResponse {
+    command_response_accessors!();
+    debug_transcripts_accessor!();
+    command_retries_accessor!();
+}
+
+impl CommandResponse for GhCommandResponse {
+    command_response_accessors!();
+    debug_transcripts_accessor!();
+
+    fn mark_cancelled(&mut self) {
+        self.cancelled = Some(true);
+    }
+}
+
+// The OpenCode run/cancel responses report failures inside `result`.
+impl CommandResponse for OpenCodeRunResponse {
+    fn command_request_id(&self) -> &str {
+        &self.request_id
+    }
+
+    fn command_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn command_error(&self) -> Option<&str> {
+        self.result
+            .error
+            .as_ref()
+            .map(|error| error.message.as_str())
+    }
+}
+
+impl CommandResponse for OpenCodeCancelResponse {
+    fn command_request_id(&self) -> &str {
+        &self.request_id
+    }
+
+    fn command_ok(&self) -> bool {
+        self.ok
+    }
+
+    fn command_error(&self) -> Option<&str> {
+        self.result
+            .error
+            .as_ref()
+            .map(|error| error.message.as_str())
+    }
+}
+
+#[derive(Clone)]
+struct CommandContext {
+    command: &'static str,
+    request_id: String,
+    started_at: Instant,
+    /// Shared with the command this one runs inside of, if any, so that
+    /// cancelling the outer command also cancels the inner one.
+    cancelled: Arc<AtomicBool>,
+    /// Shared with the command this one runs inside of, if any, so that a
+    /// recording outer command also gets the inner one's subprocesses.
+    transcripts: SharedSubprocessTranscripts,
+    /// Shared with the command this one runs inside of, if any, which then
+    /// returns the retries of both.
+    retries: SharedCommandRetries,
+}
+
+impl CommandContext {
+    fn new(command: &'static str) -> Self {
+        let request_id = request_id();
+        let retries = current_command_retries().unwrap_or_else(|| {
+            Arc::new(Mutex::new(CommandRetryLog {
+                owner: request_id.clone(),
+                retries: Vec::new(),
+            }))
+        });
+        Self {
+            command,
+            request_id,
+            started_at: Instant::now(),
+            cancelled: current_command_cancellation().unwrap_or_default(),
+            transcripts: current_subprocess_transcripts().unwrap_or_default(),
+            retries,
+        }
+    }
+
+    fn request_id(&self) -> String {
+        self.request_id.clone()
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Records the subprocesses the command runs from here on when `debug`
+    /// is set, for the response's `debug_transcripts`. Does nothing inside a
+    /// command that already records them; that one keeps them.
+    fn capture_subprocess_transcripts(&self, debug: Option<bool>) {
+        if debug != Some(true) {
+            return;
+        }
+        let mut capture = lock_subprocess_transcripts(&self.transcripts);
+        if capture.is_none() {
+            *capture = Some(SubprocessTranscriptCapture {
+                owner: self.request_id.clone(),
+                transcripts: Vec::new(),
+            });
+        }
+    }
+
+    /// The transcripts recorded since this command turned recording on.
+    fn take_subprocess_transcripts(&self) -> Option<Vec<SubprocessTranscript>> {
+        let mut capture = lock_subprocess_transcripts(&self.transcripts);
+        if capture
+            .as_ref()
+            .is_some_and(|capture| capture.owner == self.request_id)
+        {
+            return capture.take().map(|capture| capture.transcripts);
+        }
+        None
+    }
+
+    /// The retries recorded so far, when this command created the log.
+    fn take_command_retries(&self) -> Vec<CommandRetry> {
+        let mut log = lock_command_retries(&self.retries);
+        if log.owner != self.request_id {
+            return Vec::new();
+        }
+        std::mem::take(&mut log.retries)
+    }
+
+    fn finish<R: CommandResponse>(self, mut response: R) -> R {
+        if self.is_cancelled() {
+            response.mark_cancelled();
+        }
+        if let Some(transcripts) = self.take_subprocess_transcripts() {
+            response.attach_debug_transcripts(transcripts);
+        }
+        let retries = self.take_command_retries();
+        if !retries.is_empty() {
+            response.attach_command_retries(retries);
+        }
+        record_command_telemetry(
+            self.command,
+            response.command_request_id(),
+            response.command_ok(),
+            response.command_error(),
+            self.started_at.elapsed(),
+        );
+        response
+    }
+}
+
+fn record_command_telemetry(
+    command: &str,
+    request_id: &str,
+    ok: bool,
+    error: Option<&str>,
+    elapsed: Duration,
+) {
+    if !COMMAND_TELEMETRY_ENABLED.load(Ordering::Relaxed)
+        || (ok && elapsed < COMMAND_TELEMETRY_SLOW_THRESHOLD)
+    {
+        return;
+    }
+    let error_code = match (ok, error) {
+        (true, _) => "none",
+        (false, Some(error)) => command_errors::classify_command_error(error).as_str(),
+        (false, None) => command_errors::CommandErrorCode::Internal.as_str(),
+    };
+    log_line(&format!(
+        "[command-telemetry] command={command} request_id={request_id} ok={ok} elapsed_ms={} error_code={error_code}",
+        elapsed.as_millis()
+    ));
+}
+
+/// Gate for commands reached through an external surface such as the MCP
+/// server rather than the desktop UI. Denials are reported like failed
+/// commands.
+fn authorize_remote_command(
+    granted: &[Capability],
+    command: &str,
+    required: Capability,
+) -> Result<(), String> {
+    capabilities::require_capability(granted, command, required).inspect_err(|error| {
+        record_command_telemetry(command, &request_id(), false, Some(error), Duration::ZERO)
+    })
+}
+
+/// Commands currently running, reported by the backend heartbeat.
+static COMMANDS_IN_FLIGHT: AtomicU64 = AtomicU64::new(0);
+
+/// Counts a command as in flight until dropped, also when its body panics.
+struct InFlightCommand;
+
+impl InFlightCommand {
+    fn start() -> Self {
+        COMMANDS_IN_FLIGHT.fetch_add(1, Ordering::Relaxed);
+        Self
+    }
+}
+
+impl Drop for InFlightCommand {
+    fn drop(&mut self) {
+        COMMANDS_IN_FLIGHT.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Runs a synchronous command body with a fresh `CommandContext`.
+fn handle_command<R: CommandResponse>(
+    command: &'static str,
+    handler: impl FnOnce(&CommandContext) -> R,
+) -> R {
+    let _in_flight = InFlightCommand::start();
+    let context = CommandContext::new(command);
+    let _cancellable = CancellableCommand::register(&context);
+    let response = {
+        let _bound = BoundCommandCancellation::bind(&context);
+        let _transcripts = BoundSubprocessTranscripts::bind(&context);
+        let _retries = BoundCommandRetries::bind(&context);
+        handler(&context)
+    };
+    context.finish(response)
+}
+
+/// Runs `handler` on the blocking pool so the async command does not stall
+/// the UI thread. `on_worker_failure` builds the response when the worker
+/// thread itself fails (it receives the request id and the error message).
+async fn handle_blocking_command<R>(
+    command: &'static str,
+    handler: impl FnOnce(&CommandContext) -> R + Send + 'static,
+    on_worker_failure: impl FnOnce(String, String) -> R,
+) -> R
+where
+    R: CommandResponse + Send + 'static,
+{
+    let _in_flight = InFlightCommand::start();
+    let context = CommandContext::new(command);
+    let _cancellable = CancellableCommand::register(&context);
+    let worker_context = context.clone();
+    let response = match tauri::async_runtime::spawn_blocking(move || {
+        let _bound = BoundCommandCancellation::bind(&worker_context);
+        let _transcripts = BoundSubprocessTranscripts::bind(&worker_context);
+        let _retries = BoundCommandRetries::bind(&worker_context);
+        handler(&worker_context)
+    })
+    .await
+    {
+        Ok(response) => response,
+        Err(error) => on_worker_failure(
+            context.request_id(),
+            format!("Failed to run {command} worker thread: {error}"),
+        ),
+    };
+    context.finish(response)
+}
+
+/// A command that can be cancelled through `cancel_request`.
+struct CancellableEntry {
+    command: &'static str,
+    started_at: Instant,
+    cancelled: Arc<AtomicBool>,
+}
+
+type CommandCancellations = Mutex<HashMap<String, CancellableEntry>>;
+
+static COMMAND_CANCELLATIONS: std::sync::OnceLock<CommandCancellations> =
+    std::sync::OnceLock::new();
+
+fn command_cancellations() -> MutexGuard<'static, HashMap<String, CancellableEntry>> {
+    let cancellations = COMMAND_CANCELLATIONS.get_or_init(Default::default);
+    lock_or_recover(cancellations, "commandCancellations", recover_by_clearing)
+}
+
+/// Keeps a command cancellable by its request id until dropped.
+struct CancellableCommand {
+    request_id: String,
+}
+
+impl CancellableCommand {
+    fn register(context: &CommandContext) -> Self {
+        command_cancellations().insert(
+            context.request_id.clone(),
+            CancellableEntry {
+                command: context.command,
+                started_at: context.started_at,
+                cancelled: Arc::clone(&context.cancelled),
+            },
+        );
+        Self {
+            request_id: context.request_id.clone(),
+        }
+    }
+}
+
+impl Drop for CancellableCommand {
+    fn drop(&mut self) {
+        command_cancellations().remove(&self.request_id);
+    }
+}
+
+/// Flags the command with `request_id` as cancelled. Returns the command's
+/// name, or `None` when no such command is in flight.
+fn cancel_command(request_id: &str) -> Option<&'static str> {
+    let cancellations = command_cancellations();
+    let entry = cancellations.get(request_id)?;
+    entry.cancelled.store(true, Ordering::Relaxed);
+    Some(entry.command)
+}
+
+/// Commands in flight, oldest first.
+fn list_commands_in_flight() -> Vec<InFlightCommandInfo> {
+    let mut commands = command_cancellations()
+        .iter()
+        .map(|(request_id, entry)| InFlightCommandInfo {
+            request_id: request_id.clone(),
+            command: entry.command.to_string(),
+            elapsed_ms: entry.started_at.elapsed().as_millis() as u64,
+            cancelled: entry.cancelled.load(Ordering::Relaxed),
+        })
+        .collect::<Vec<_>>();
+    commands.sort_by_key(|command| std::cmp::Reverse(command.elapsed_ms));
+    commands
+}
+
+thread_local! {
+    static CURRENT_COMMAND_CANCELLATION: std::cell::RefCell<Option<Arc<AtomicBool>>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// Makes a command's cancellation flag the one `command_cancelled` reads on
+/// this thread until dropped, then restores the previous one.
+struct BoundCommandCancellation {
+    previous: Option<Arc<AtomicBool>>,
+}
+
+impl BoundCommandCancellation {
+    fn bind(context: &CommandContext) -> Self {
+        let previous = CURRENT_COMMAND_CANCELLATION
+            .with(|current| current.replace(Some(Arc::clone(&context.cancelled))));
+        Self { previous }
+    }
+}
+
+impl Drop for BoundCommandCancellation {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT_COMMAND_CANCELLATION.with(|current| current.replace(previous));
+    }
+}
+
+fn current_command_cancellation() -> Option<Arc<AtomicBool>> {
+    CURRENT_COMMAND_CANCELLATION.with(|current| current.borrow().clone())
+}
+
+/// Whether the command running on this thread was cancelled.
+fn command_cancelled() -> bool {
+    current_command_cancellation().is_some_and(|cancelled| cancelled.load(Ordering::Relaxed))
+}
@@ -1,268 +1,537 @@
 #[tauri::command]
 fn diagnostics_stop_process(pid: i32) -> DiagnosticsStopResponse {
-    let request_id = request_id();
-    if pid <= 0 {
-        return DiagnosticsStopResponse {
-            request_id,
-            ok: false,
-            pid: None,
-            already_stopped: None,
-            error: Some("pid must be a positive integer.".to_string()),
+    handle_command("diagnostics_stop_process", |context| {
+        let request_id = context.request_id();
+        if pid <= 0 {
+            return DiagnosticsStopResponse {
+                request_id,
+                ok: false,
+                pid: None,
+                already_stopped: None,
+                error: Some("pid must be a positive integer.".to_string()),
+            };
+        }
+
+        match stop_process_by_pid(pid) {
+            Ok((already_stopped, stopped_pid)) => DiagnosticsStopResponse {
+                request_id,
+                ok: true,
+                pid: Some(stopped_pid),
+                already_stopped: Some(already_stopped),
+                error: None,
+            },
+            Err(error) => DiagnosticsStopResponse {
+                request_id,
+                ok: false,
+                pid: Some(pid),
+                already_stopped: None,
+                error: Some(error),
+            },
+        }
+    })
+}
+
+#[tauri::command]
+fn diagnostics_kill_all_node_instances() -> DiagnosticsStopAllResponse {
+    handle_command("diagnostics_kill_all_node_instances", |context| {
+        let request_id = context.request_id();
+
+        let (snapshot_rows, _warning) = match list_process_snapshot_rows() {
+            Ok(value) => value,
+            Err(error) => {
+                return DiagnosticsStopAllResponse {
+                    request_id,
+                    ok: false,
+                    attempted: 0,
+                    stopped: 0,
+                    already_stopped: 0,
+                    failed: 0,
+                    errors: Vec::new(),
+                    error: Some(error),
+                }
+            }
         };
-    }
 
-    match stop_process_by_pid(pid) {
-        Ok((already_stopped, stopped_pid)) => DiagnosticsStopResponse {
-            request_id,
-            ok: true,
-            pid: Some(stopped_pid),
-            already_stopped: Some(already_stopped),
-            error: None,
-        },
-        Err(error) => DiagnosticsStopResponse {
+        let unique_pids = snapshot_rows
+            .into_iter()
+            .filter(|row| is_likely_node_command(row.process_name.as_deref(), &row.command))
+            .map(|row| row.pid)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        let (stopped, already_stopped, failed, errors) = stop_pid_set(&unique_pids);
+        let has_errors = !errors.is_empty();
+
+        DiagnosticsStopAllResponse {
             request_id,
-            ok: false,
-            pid: Some(pid),
-            already_stopped: None,
-            error: Some(error),
-        },
-    }
+            ok: failed == 0,
+            attempted: unique_pids.len(),
+            stopped,
+            already_stopped,
+            failed,
+            errors,
+            error: if has_errors {
+                Some(format!("Failed to stop {} Node process(es).", failed))
+            } else {
+                None
+            },
+        }
+    })
 }
 
 #[tauri::command]
-fn diagnostics_kill_all_node_instances() -> DiagnosticsStopAllResponse {
-    let request_id = request_id();
+fn diagnostics_list_worktree_node_apps(app: AppHandle) -> DiagnosticsNodeAppsResponse {
+    handle_command("diagnostics_list_worktree_node_apps", |context| {
+        let started_at = Instant::now();
+        let request_id = context.request_id();
+        let telemetry_enabled = telemetry_enabled_for_app(&app);
 
-    let (snapshot_rows, _warning) = match list_process_snapshot_rows() {
-        Ok(value) => value,
-        Err(error) => {
-            return DiagnosticsStopAllResponse {
+        let response = match list_worktree_node_app_rows() {
+            Ok((rows, warning)) => DiagnosticsNodeAppsResponse {
+                request_id,
+                ok: true,
+                rows,
+                warning,
+                error: None,
+            },
+            Err(error) => DiagnosticsNodeAppsResponse {
                 request_id,
                 ok: false,
-                attempted: 0,
-                stopped: 0,
-                already_stopped: 0,
-                failed: 0,
-                errors: Vec::new(),
+                rows: Vec::new(),
+                warning: None,
                 error: Some(error),
-            }
-        }
-    };
-
-    let unique_pids = snapshot_rows
-        .into_iter()
-        .filter(|row| is_likely_node_command(row.process_name.as_deref(), &row.command))
-        .map(|row| row.pid)
-        .collect::<HashSet<_>>()
-        .into_iter()
-        .collect::<Vec<_>>();
-
-    let (stopped, already_stopped, failed, errors) = stop_pid_set(&unique_pids);
-    let has_errors = !errors.is_empty();
-
-    DiagnosticsStopAllResponse {
-        request_id,
-        ok: failed == 0,
-        attempted: unique_pids.len(),
-        stopped,
-        already_stopped,
-        failed,
-        errors,
-        error: if has_errors {
-            Some(format!(
-                "Failed to stop {} Node process(es).",
-                failed
-            ))
-        } else {
-            None
-        },
-    }
+            },
+        };
+
+        let details = format!(
+            "outcome={} rows={} has_warning={}",
+            if response.ok { "ok" } else { "error" },
+            response.rows.len(),
+            response.warning.is_some(),
+        );
+        log_backend_timing(
+            telemetry_enabled,
+            "diagnostics.list_worktree_node_apps",
+            started_at.elapsed(),
+            details.as_str(),
+        );
+        response
+    })
 }
 
 #[tauri::command]
-fn diagnostics_list_worktree_node_apps(app: AppHandle) -> DiagnosticsNodeAppsResponse {
-    let started_at = Instant::now();
-    let request_id = request_id();
-    let telemetry_enabled = telemetry_enabled_for_app(&app);
+fn diagnostics_clean_all_dev_servers(app: AppHandle) -> DiagnosticsStopAllResponse {
+    handle_command("diagnostics_clean_all_dev_servers", |context| {
+        let started_at = Instant::now();
+        let request_id = context.request_id();
+        let telemetry_enabled = telemetry_enabled_for_app(&app);
+        let (snapshot_rows, _warning) = match list_process_snapshot_rows() {
+            Ok(value) => value,
+            Err(error) => {
+                let response = DiagnosticsStopAllResponse {
+                    request_id,
+                    ok: false,
+                    attempted: 0,
+                    stopped: 0,
+                    already_stopped: 0,
+                    failed: 0,
+                    errors: Vec::new(),
+                    error: Some(error),
+                };
+                log_backend_timing(
+                    telemetry_enabled,
+                    "diagnostics.clean_all_dev_servers",
+                    started_at.elapsed(),
+                    "outcome=error attempted=0 stopped=0 already_stopped=0 failed=0",
+                );
+                return response;
+            }
+        };
 
-    let response = match list_worktree_node_app_rows() {
-        Ok((rows, warning)) => DiagnosticsNodeAppsResponse {
-            request_id,
-            ok: true,
-            rows,
-            warning,
-            error: None,
-        },
-        Err(error) => DiagnosticsNodeAppsResponse {
+        let pids = snapshot_rows
+            .into_iter()
+            .filter(|row| {
+                is_worktree_node_process(row.process_name.as_deref(), &row.command)
+                    || (command_mentions_worktrees(&row.command)
+                        && command_matches_turbo_dev(&row.command))
+            })
+            .map(|row| row.pid)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        let (stopped, already_stopped, failed, errors) = stop_pid_set(&pids);
+
+        let response = DiagnosticsStopAllResponse {
             request_id,
-            ok: false,
-            rows: Vec::new(),
-            warning: None,
-            error: Some(error),
-        },
-    };
-
-    let details = format!(
-        "outcome={} rows={} has_warning={}",
-        if response.ok { "ok" } else { "error" },
-        response.rows.len(),
-        response.warning.is_some(),
-    );
-    log_backend_timing(
-        telemetry_enabled,
-        "diagnostics.list_worktree_node_apps",
-        started_at.elapsed(),
-        details.as_str(),
-    );
-    response
+            ok: failed == 0,
+            attempted: pids.len(),
+            stopped,
+            already_stopped,
+            failed,
+            errors,
+            error: if failed == 0 {
+                None
+            } else {
+                Some(format!(
+                    "Failed to clean all target processes: {} process(es).",
+                    failed
+                ))
+            },
+        };
+
+        let details = format!(
+            "outcome={} attempted={} stopped={} already_stopped={} failed={}",
+            if response.ok { "ok" } else { "error" },
+            response.attempted,
+            response.stopped,
+            response.already_stopped,
+            response.failed,
+        );
+        log_backend_timing(
+            telemetry_enabled,
+            "diagnostics.clean_all_dev_servers",
+            started_at.elapsed(),
+            details.as_str(),
+        );
+        response
+    })
 }
 
 #[tauri::command]
-fn diagnostics_clean_all_dev_servers(app: AppHandle) -> DiagnosticsStopAllResponse {
-    let started_at = Instant::now();
-    let request_id = request_id();
-    let telemetry_enabled = telemetry_enabled_for_app(&app);
-    let (snapshot_rows, _warning) = match list_process_snapshot_rows() {
-        Ok(value) => value,
-        Err(error) => {
-            let response = DiagnosticsStopAllResponse {
-                request_id,
-                ok: false,
-                attempted: 0,
-                stopped: 0,
-                already_stopped: 0,
-                failed: 0,
-                errors: Vec::new(),
-                error: Some(error),
-            };
-            log_backend_timing(
-                telemetry_enabled,
-                "diagnostics.clean_all_dev_servers",
-                started_at.elapsed(),
-                "outcome=error attempted=0 stopped=0 already_stopped=0 failed=0",
-            );
-            return response;
-        }
-    };
-
-    let pids = snapshot_rows
-        .into_iter()
-        .filter(|row| {
-            is_worktree_node_process(row.process_name.as_deref(), &row.command)
-                || (command_mentions_worktrees(&row.command)
-                    && command_matches_turbo_dev(&row.command))
-        })
-        .map(|row| row.pid)
-        .collect::<HashSet<_>>()
-        .into_iter()
-        .collect::<Vec<_>>();
-
-    let (stopped, already_stopped, failed, errors) = stop_pid_set(&pids);
-
-    let response = DiagnosticsStopAllResponse {
-        request_id,
-        ok: failed == 0,
-        attempted: pids.len(),
-        stopped,
-        already_stopped,
-        failed,
-        errors,
-        error: if failed == 0 {
-            None
+fn diagnostics_stop_by_category(
+    app: AppHandle,
+    payload: DiagnosticsStopByCategoryPayload,
+) -> DiagnosticsStopByCategoryResponse {
+    handle_command("diagnostics_stop_by_category", |context| {
+        let started_at = Instant::now();
+        let request_id = context.request_id();
+        let telemetry_enabled = telemetry_enabled_for_app(&app);
+        let preview = payload.preview.unwrap_or(false);
+
+        let category = match diagnostics::parse_diagnostics_stop_category(&payload.category) {
+            Ok(value) => value,
+            Err(error) => {
+                return DiagnosticsStopByCategoryResponse {
+                    request_id,
+                    ok: false,
+                    category: payload.category.trim().to_string(),
+                    preview,
+                    workspace_root: None,
+                    targets: Vec::new(),
+                    remaining: Vec::new(),
+                    attempted: 0,
+                    stopped: 0,
+                    already_stopped: 0,
+                    failed: 0,
+                    errors: Vec::new(),
+                    error: Some(error),
+                };
+            }
+        };
+
+        let workspace_root = if category == diagnostics::DiagnosticsStopCategory::Workspace {
+            match resolve_workspace_root(
+                &app,
+                &payload.root_name,
+                None,
+                &payload.known_worktrees,
+                &payload.workspace_meta,
+            ) {
+                Ok(value) => Some(value),
+                Err(error) => {
+                    return DiagnosticsStopByCategoryResponse {
+                        request_id,
+                        ok: false,
+                        category: category.as_str().to_string(),
+                        preview,
+                        workspace_root: None,
+                        targets: Vec::new(),
+                        remaining: Vec::new(),
+                        attempted: 0,
+                        stopped: 0,
+                        already_stopped: 0,
+                        failed: 0,
+                        errors: Vec::new(),
+                        error: Some(error),
+                    };
+                }
+            }
         } else {
-            Some(format!(
-                "Failed to clean all target processes: {} process(es).",
-                failed
-            ))
-        },
-    };
-
-    let details = format!(
-        "outcome={} attempted={} stopped={} already_stopped={} failed={}",
-        if response.ok { "ok" } else { "error" },
-        response.attempted,
-        response.stopped,
-        response.already_stopped,
-        response.failed,
-    );
-    log_backend_timing(
-        telemetry_enabled,
-        "diagnostics.clean_all_dev_servers",
-        started_at.elapsed(),
-        details.as_str(),
-    );
-    response
+            None
+        };
+        let workspace_root_rendered = workspace_root
+            .as_ref()
+            .map(|root| root.display().to_string());
+
+        let mut targets = match list_stop_category_targets(category, workspace_root.as_deref()) {
+            Ok(value) => value,
+            Err(error) => {
+                return DiagnosticsStopByCategoryResponse {
+                    request_id,
+                    ok: false,
+                    category: category.as_str().to_string(),
+                    preview,
+                    workspace_root: workspace_root_rendered,
+                    targets: Vec::new(),
+                    remaining: Vec::new(),
+                    attempted: 0,
+                    stopped: 0,
+                    already_stopped: 0,
+                    failed: 0,
+                    errors: Vec::new(),
+                    error: Some(error),
+                };
+            }
+        };
+
+        let mut stopped = 0usize;
+        let mut already_stopped = 0usize;
+        let mut failed = 0usize;
+        let mut errors = Vec::new();
+        if !preview {
+            for target in &mut targets {
+                let outcome = match stop_process_by_pid(target.pid) {
+                    Ok((true, _)) => {
+                        already_stopped += 1;
+                        "alreadyStopped"
+                    }
+                    Ok((false, _)) => {
+                        stopped += 1;
+                        "stopped"
+                    }
+                    Err(error) => {
+                        failed += 1;
+                        errors.push(format!("PID {}: {error}", target.pid));
+                        "failed"
+                    }
+                };
+                target.outcome = Some(outcome.to_string());
+            }
+        }
+
+        let remaining = targets
+            .iter()
+            .filter(|target| is_process_running(target.pid))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let response = DiagnosticsStopByCategoryResponse {
+            request_id,
+            ok: failed == 0,
+            category: category.as_str().to_string(),
+            preview,
+            workspace_root: workspace_root_rendered,
+            attempted: if preview { 0 } else { targets.len() },
+            targets,
+            remaining,
+            stopped,
+            already_stopped,
+            failed,
+            errors,
+            error: if failed == 0 {
+                None
+            } else {
+                Some(format!(
+                    "Failed to stop {} {} process(es).",
+                    failed,
+                    category.as_str()
+                ))
+            },
+        };
+
+        let details = format!(
+            "outcome={} category={} preview={} targets={} stopped={} already_stopped={} failed={} remaining={}",
+            if response.ok { "ok" } else { "error" },
+            response.category,
+            response.preview,
+            response.targets.len(),
+            response.stopped,
+            response.already_stopped,
+            response.failed,
+            response.remaining.len(),
+        );
+        log_backend_timing(
+            telemetry_enabled,
+            "diagnostics.stop_by_category",
+            started_at.elapsed(),
+            details.as_str(),
+        );
+        response
+    })
 }
 
 #[tauri::command]
 fn diagnostics_get_msot_consuming_programs(
     app: AppHandle,
 ) -> DiagnosticsMostConsumingProgramsResponse {
-    let started_at = Instant::now();
-    let request_id = request_id();
-    let telemetry_enabled = telemetry_enabled_for_app(&app);
+    handle_command("diagnostics_get_msot_consuming_programs", |context| {
+        let started_at = Instant::now();
+        let request_id = context.request_id();
+        let telemetry_enabled = telemetry_enabled_for_app(&app);
+
+        let response = match get_msot_consuming_programs_output() {
+            Ok(output) => DiagnosticsMostConsumingProgramsResponse {
+                request_id,
+                ok: true,
+                output,
+                error: None,
+            },
+            Err(error) => DiagnosticsMostConsumingProgramsResponse {
+                request_id,
+                ok: false,
+                output: String::new(),
+                error: Some(error),
+            },
+        };
 
-    let response = match get_msot_consuming_programs_output() {
-        Ok(output) => DiagnosticsMostConsumingProgramsResponse {
+        let details = format!(
+            "outcome={} output_len={}",
+            if response.ok { "ok" } else { "error" },
+            response.output.len(),
+        );
+        log_backend_timing(
+            telemetry_enabled,
+            "diagnostics.get_msot_consuming_programs",
+            started_at.elapsed(),
+            details.as_str(),
+        );
+        response
+    })
+}
+
+#[tauri::command(async)]
+fn diagnostics_get_system_overview(app: AppHandle) -> DiagnosticsSystemOverviewResponse {
+    handle_command("diagnostics_get_system_overview", |context| {
+        let started_at = Instant::now();
+        let request_id = context.request_id();
+        let telemetry_enabled = telemetry_enabled_for_app(&app);
+
+        let overview = collect_system_overview();
+        let response = DiagnosticsSystemOverviewResponse {
             request_id,
             ok: true,
-            output,
+            overview: Some(overview),
             error: None,
-        },
-        Err(error) => DiagnosticsMostConsumingProgramsResponse {
-            request_id,
-            ok: false,
-            output: String::new(),
-            error: Some(error),
-        },
-    };
-
-    let details = format!(
-        "outcome={} output_len={}",
-        if response.ok { "ok" } else { "error" },
-        response.output.len(),
-    );
-    log_backend_timing(
-        telemetry_enabled,
-        "diagnostics.get_msot_consuming_programs",
-        started_at.elapsed(),
-        details.as_str(),
-    );
-    response
+        };
+
+        let details = if let Some(overview) = response.overview.as_ref() {
+            format!(
+                "outcome=ok cpu={} ram={} swap={} disk={} platform={}",
+                overview.cpu_usage_percent.is_some(),
+                overview.ram_usage_percent.is_some(),
+                overview.swap_usage_percent.is_some(),
+                overview.disk_usage_percent.is_some(),
+                overview.platform,
+            )
+        } else {
+            "outcome=ok overview=false".to_string()
+        };
+        log_backend_timing(
+            telemetry_enabled,
+            "diagnostics.get_system_overview",
+            started_at.elapsed(),
+            details.as_str(),
+        );
+
+        response
+    })
 }
 
 #[tauri::command(async)]
-fn diagnostics_get_system_overview(app: AppHandle) -> DiagnosticsSystemOverviewResponse {
-    let started_at = Instant::now();
-    let request_id = request_id();
-    let telemetry_enabled = telemetry_enabled_for_app(&app);
-
-    let overview = collect_system_overview();
-    let response = DiagnosticsSystemOverviewResponse {
-        request_id,
-        ok: true,
-        overview: Some(overview),
-        error: None,
-    };
-
-    let details = if let Some(overview) = response.overview.as_ref() {
-        format!(
-            "outcome=ok cpu={} ram={} swap={} disk={} platform={}",
-            overview.cpu_usage_percent.is_some(),
-            overview.ram_usage_percent.is_some(),
-            overview.swap_usage_percent.is_some(),
-            overview.disk_usage_percent.is_some(),
-            overview.platform,
-        )
-    } else {
-        "outcome=ok overview=false".to_string()
-    };
-    log_backend_timing(
-        telemetry_enabled,
-        "diagnostics.get_system_overview",
-        started_at.elapsed(),
-        details.as_str(),
-    );
-
-    response
+fn diagnostics_worktree_resources(app: AppHandle) -> DiagnosticsWorktreeResourcesResponse {
+    handle_command("diagnostics_worktree_resources", |context| {
+        let started_at = Instant::now();
+        let request_id = context.request_id();
+        let telemetry_enabled = telemetry_enabled_for_app(&app);
+
+        let response = match active_workspace_root_from_state(&app).and_then(|workspace_root| {
+            collect_worktree_resources(&app, &workspace_root)
+                .map(|(rows, warning)| (workspace_root, rows, warning))
+        }) {
+            Ok((workspace_root, rows, warning)) => DiagnosticsWorktreeResourcesResponse {
+                request_id,
+                ok: true,
+                workspace_root: Some(workspace_root.display().to_string()),
+                rows,
+                warning,
+                error: None,
+            },
+            Err(error) => DiagnosticsWorktreeResourcesResponse {
+                request_id,
+                ok: false,
+                workspace_root: None,
+                rows: Vec::new(),
+                warning: None,
+                error: Some(error),
+            },
+        };
+
+        let details = format!(
+            "outcome={} rows={} has_warning={}",
+            if response.ok { "ok" } else { "error" },
+            response.rows.len(),
+            response.warning.is_some(),
+        );
+        log_backend_timing(
+            telemetry_enabled,
+            "diagnostics.worktree_resources",
+            started_at.elapsed(),
+            details.as_str(),
+        );
+        response
+    })
+}
+
+#[tauri::command(async)]
+fn diagnostics_fd_usage(app: AppHandle) -> DiagnosticsFdUsageResponse {
+    handle_command("diagnostics_fd_usage", |context| {
+        let started_at = Instant::now();
+        let telemetry_enabled = telemetry_enabled_for_app(&app);
+        let response = match collect_fd_usage(&app) {
+            Ok(usage) => DiagnosticsFdUsageResponse {
+                request_id: context.request_id(),
+                ok: true,
+                usage: Some(usage),
+                error: None,
+            },
+            Err(error) => DiagnosticsFdUsageResponse {
+                request_id: context.request_id(),
+                ok: false,
+                usage: None,
+                error: Some(error),
+            },
+        };
+
+        let details = format!(
+            "outcome={} hints={}",
+            if response.ok { "ok" } else { "error" },
+            response.usage.as_ref().map_or(0, |usage| usage.hints.len()),
+        );
+        log_backend_timing(
+            telemetry_enabled,
+            "diagnostics.fd_usage",
+            started_at.elapsed(),
+            details.as_str(),
+        );
+        response
+    })
+}
+
+#[tauri::command]
+fn diagnostics_terminal_memory(
+    state: State<GrooveTerminalState>,
+) -> DiagnosticsTerminalMemoryResponse {
+    handle_command("diagnostics_terminal_memory", |context| {
+        let sessions = terminal_session_memory_rows(&state.lock_sessions());
+        DiagnosticsTerminalMemoryResponse {
+            request_id: context.request_id(),
+            ok: true,
+            total_snapshot_bytes: sessions.iter().map(|session| session.snapshot_bytes).sum(),
+            sessions,
+            limits: terminal_memory_limits(),
+            error: None,
+        }
+    })
 }
@@ -4,84 +4,94 @@ fn workspace_events(
     state: State<WorkspaceEventState>,
     payload: WorkspaceEventsPayload,
 ) -> WorkspaceEventsResponse {
-    let request_id = request_id();
-
-    let known_worktrees = match validate_known_worktrees(&payload.known_worktrees) {
-        Ok(known_worktrees) => known_worktrees,
-        Err(error) => {
-            return WorkspaceEventsResponse {
-                request_id,
-                ok: false,
-                workspace_root: None,
-                error: Some(error),
+    handle_command("workspace_events", |context| {
+        let request_id = context.request_id();
+
+        let known_worktrees = match validate_known_worktrees(&payload.known_worktrees) {
+            Ok(known_worktrees) => known_worktrees,
+            Err(error) => {
+                return WorkspaceEventsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    error: Some(error),
+                }
             }
-        }
-    };
-
-    let workspace_root = match resolve_workspace_root(
-        &app,
-        &payload.root_name,
-        None,
-        &known_worktrees,
-        &payload.workspace_meta,
-    ) {
-        Ok(root) => root,
-        Err(error) => {
-            return WorkspaceEventsResponse {
-                request_id,
-                ok: false,
-                workspace_root: None,
-                error: Some(error),
+        };
+
+        let workspace_root = match resolve_workspace_root(
+            &app,
+            &payload.root_name,
+            None,
+            &known_worktrees,
+            &payload.workspace_meta,
+        ) {
+            Ok(root) => root,
+            Err(error) => {
+                return WorkspaceEventsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    error: Some(error),
+                }
             }
-        }
-    };
+        };
 
-    let events_effective_root = ensure_workspace_meta(&workspace_root)
-        .map(|(meta, _)| effective_workspace_root(&workspace_root, &meta))
-        .unwrap_or_else(|_| workspace_root.clone());
+        let events_effective_root = ensure_workspace_meta(&workspace_root)
+            .map(|(meta, _)| effective_workspace_root(&workspace_root, &meta))
+            .unwrap_or_else(|_| workspace_root.clone());
 
-    let poll_targets = {
-        let mut targets = vec![
-            events_effective_root.join(".worktrees"),
-            workspace_root.join(".groove"),
-            workspace_root.join(".groove").join("workspace.json"),
-        ];
-
-        for worktree in &known_worktrees {
-            targets.push(
-                events_effective_root
-                    .join(".worktrees")
-                    .join(worktree)
-                    .join(".groove"),
-            );
-            targets.push(
-                events_effective_root
+        let poll_targets = {
+            let workspace_target = |path: PathBuf| WorkspacePollTarget {
+                path,
+                worktree: None,
+                is_metadata: false,
+            };
+            let mut targets = vec![
+                workspace_target(events_effective_root.join(".worktrees")),
+                workspace_target(workspace_root.join(".groove")),
+                workspace_target(workspace_root.join(".groove").join("workspace.json")),
+            ];
+
+            for worktree in &known_worktrees {
+                let groove_dir = events_effective_root
                     .join(".worktrees")
                     .join(worktree)
-                    .join(".groove")
-                    .join("workspace.json"),
-            );
-        }
-
-        targets
-    };
+                    .join(".groove");
+                targets.push(WorkspacePollTarget {
+                    path: groove_dir.join("workspace.json"),
+                    worktree: Some(worktree.clone()),
+                    is_metadata: true,
+                });
+                targets.push(WorkspacePollTarget {
+                    path: groove_dir,
+                    worktree: Some(worktree.clone()),
+                    is_metadata: false,
+                });
+            }
 
-    let mut worker = match state.worker.lock() {
-        Ok(worker) => worker,
-        Err(error) => {
-            return WorkspaceEventsResponse {
-                request_id,
-                ok: false,
-                workspace_root: Some(workspace_root.display().to_string()),
-                error: Some(format!("Failed to acquire workspace event lock: {error}")),
-            };
-        }
-    };
+            targets
+        };
+
+        let mut workers = match state.workers.lock() {
+            Ok(workers) => workers,
+            Err(error) => {
+                return WorkspaceEventsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: Some(workspace_root.display().to_string()),
+                    error: Some(format!("Failed to acquire workspace event lock: {error}")),
+                };
+            }
+        };
 
-    let workspace_root_display = workspace_root.display().to_string();
+        let workspace_root_display = workspace_root.display().to_string();
 
-    if let Some(existing) = worker.as_ref() {
-        if existing.workspace_root == workspace_root_display && !existing.handle.is_finished() {
+        // Workers of the other open workspaces keep running, so switching
+        // back to one does not start its polling over.
+        let worker_key = workspace_root_storage_key(&workspace_root);
+        workers.retain(|_, worker| !worker.handle.is_finished());
+        if workers.contains_key(&worker_key) {
             return WorkspaceEventsResponse {
                 request_id,
                 ok: true,
@@ -89,168 +99,291 @@ fn workspace_events(
                 error: None,
             };
         }
-    }
-
-    let worker_generation = state.worker_generation.clone();
-    let generation = worker_generation.fetch_add(1, Ordering::Relaxed) + 1;
-
-    if let Some(previous) = worker.take() {
-        previous.stop.store(true, Ordering::Relaxed);
-    }
-
-    let stop = Arc::new(AtomicBool::new(false));
-    let stop_signal = stop.clone();
-    let app_handle = app.clone();
-    let request_id_clone = request_id.clone();
-    let workspace_root_clone = workspace_root.clone();
-    let known_worktrees_clone = known_worktrees.clone();
-    let worker_generation_clone = worker_generation.clone();
-
-    let handle = thread::spawn(move || {
-        if worker_generation_clone.load(Ordering::Relaxed) != generation {
-            return;
-        }
-
-        let mut snapshots = HashMap::<PathBuf, SnapshotEntry>::new();
-        for target in &poll_targets {
-            snapshots.insert(target.clone(), snapshot_entry(target));
-        }
 
-        let workspace_root_display = workspace_root_clone.display().to_string();
-        let mut runtime_pids_by_worktree =
-            snapshot_runtime_pids_by_worktree(&workspace_root_clone, &known_worktrees_clone);
-
-        let _ = app_handle.emit(
-            "workspace-ready",
-            serde_json::json!({
-                "requestId": request_id_clone,
-                "workspaceRoot": workspace_root_clone,
-                "kind": "filesystem"
-            }),
-        );
+        state.worker_generation.fetch_add(1, Ordering::Relaxed);
 
-        let mut index: u64 = 0;
-        let mut pending_sources = HashSet::<String>::new();
-        let mut pending_runtime_sources = HashSet::<String>::new();
-        let mut last_emit_at = Instant::now()
-            .checked_sub(WORKSPACE_EVENTS_MIN_EMIT_INTERVAL)
-            .unwrap_or_else(Instant::now);
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_signal = stop.clone();
+        let app_handle = app.clone();
+        let request_id_clone = request_id.clone();
+        let workspace_root_clone = workspace_root.clone();
+        let known_worktrees_clone = known_worktrees.clone();
+        let last_poll_at = Arc::new(Mutex::new(Instant::now()));
+        let last_poll_at_clone = last_poll_at.clone();
 
-        while !stop_signal.load(Ordering::Relaxed)
-            && worker_generation_clone.load(Ordering::Relaxed) == generation
-        {
+        let handle = thread::spawn(move || {
+            let mut snapshots = HashMap::<PathBuf, SnapshotEntry>::new();
             for target in &poll_targets {
-                let next = snapshot_entry(target);
-                let previous = snapshots.get(target).cloned().unwrap_or(SnapshotEntry {
-                    exists: false,
-                    mtime_ms: 0,
-                });
-
-                if previous.exists != next.exists || previous.mtime_ms != next.mtime_ms {
-                    snapshots.insert(target.clone(), next);
-                    let source = target
-                        .strip_prefix(&workspace_root_clone)
-                        .map(|value| value.display().to_string())
-                        .unwrap_or_else(|_| target.display().to_string());
-                    pending_sources.insert(source);
-                }
+                snapshots.insert(target.path.clone(), snapshot_entry(&target.path));
             }
 
-            let next_runtime_pids_by_worktree =
+            let workspace_root_display = workspace_root_clone.display().to_string();
+            let mut runtime_pids_by_worktree =
                 snapshot_runtime_pids_by_worktree(&workspace_root_clone, &known_worktrees_clone);
-            for worktree in &known_worktrees_clone {
-                let previous_pid = runtime_pids_by_worktree
-                    .get(worktree)
-                    .copied()
-                    .unwrap_or(None);
-                let next_pid = next_runtime_pids_by_worktree
-                    .get(worktree)
-                    .copied()
-                    .unwrap_or(None);
-
-                if previous_pid != next_pid {
-                    pending_runtime_sources.insert(format!(".worktrees/{worktree}"));
+
+            emit_groove_event(
+                &app_handle,
+                &WorkspaceReadyEvent {
+                    request_id: request_id_clone.clone(),
+                    workspace_root: Some(workspace_root_clone.display().to_string()),
+                    kind: "filesystem".to_string(),
+                },
+            );
+
+            let mut index: u64 = 0;
+            let mut pending_sources = HashSet::<String>::new();
+            let mut pending_runtime_sources = HashSet::<String>::new();
+            let mut pending_changes = WorktreeChangeSet::default();
+            let mut pending_runtime_changes = WorktreeChangeSet::default();
+            let mut last_emit_at = Instant::now()
+                .checked_sub(WORKSPACE_EVENTS_MIN_EMIT_INTERVAL)
+                .unwrap_or_else(Instant::now);
+
+            while !stop_signal.load(Ordering::Relaxed) {
+                // Snapshots are kept, so the first pass after a resume
+                // reports what changed during the pause.
+                if background_activity_paused() {
+                    thread::sleep(WORKSPACE_EVENTS_STOP_POLL_INTERVAL);
+                    continue;
                 }
-            }
-            runtime_pids_by_worktree = next_runtime_pids_by_worktree;
-
-            poll_and_emit_notifications(&app_handle, &workspace_root_clone, &workspace_root_display);
-
-            if !pending_runtime_sources.is_empty()
-                && last_emit_at.elapsed() >= WORKSPACE_EVENTS_MIN_EMIT_INTERVAL
-            {
-                index += 1;
-                let mut sources = pending_runtime_sources.drain().collect::<Vec<_>>();
-                sources.sort();
-                let source_count = sources.len();
-
-                invalidate_groove_list_cache_for_workspace(&app_handle, &workspace_root_clone);
-                let _ = app_handle.emit(
-                    "workspace-change",
-                    serde_json::json!({
-                        "index": index,
-                        "source": sources.first().cloned().unwrap_or_default(),
-                        "sources": sources,
-                        "sourceCount": source_count,
-                        "workspaceRoot": workspace_root_display,
-                        "kind": "runtime"
-                    }),
+                if let Ok(mut last_poll_at) = last_poll_at_clone.lock() {
+                    *last_poll_at = Instant::now();
+                }
+                for target in &poll_targets {
+                    let next = snapshot_entry(&target.path);
+                    let previous = snapshots.get(&target.path).cloned().unwrap_or(SnapshotEntry {
+                        exists: false,
+                        mtime_ms: 0,
+                    });
+
+                    if previous.exists != next.exists || previous.mtime_ms != next.mtime_ms {
+                        if let (Some(worktree), Some(reason)) = (
+                            target.worktree.as_ref(),
+                            WorktreeChangeReason::from_snapshots(
+                                target.is_metadata,
+                                (previous.exists, previous.mtime_ms),
+                                (next.exists, next.mtime_ms),
+                            ),
+                        ) {
+                            pending_changes.record(worktree, reason);
+                        }
+                        snapshots.insert(target.path.clone(), next);
+                        let source = target
+                            .path
+                            .strip_prefix(&workspace_root_clone)
+                            .map(|value| value.display().to_string())
+                            .unwrap_or_else(|_| target.path.display().to_string());
+                        pending_sources.insert(source);
+                    }
+                }
+
+                let next_runtime_pids_by_worktree = snapshot_runtime_pids_by_worktree(
+                    &workspace_root_clone,
+                    &known_worktrees_clone,
                 );
-                last_emit_at = Instant::now();
-            }
+                for worktree in &known_worktrees_clone {
+                    let previous_pid = runtime_pids_by_worktree
+                        .get(worktree)
+                        .copied()
+                        .unwrap_or(None);
+                    let next_pid = next_runtime_pids_by_worktree
+                        .get(worktree)
+                        .copied()
+                        .unwrap_or(None);
+
+                    if let Some(reason) =
+                        WorktreeChangeReason::from_runtime_pids(previous_pid, next_pid)
+                    {
+                        pending_runtime_sources.insert(format!(".worktrees/{worktree}"));
+                        pending_runtime_changes.record(worktree, reason);
+                    }
+                }
+                runtime_pids_by_worktree = next_runtime_pids_by_worktree;
 
-            if !pending_sources.is_empty()
-                && last_emit_at.elapsed() >= WORKSPACE_EVENTS_MIN_EMIT_INTERVAL
-            {
-                index += 1;
-                let mut sources = pending_sources.drain().collect::<Vec<_>>();
-                sources.sort();
-                let source_count = sources.len();
-
-                let _ = app_handle.emit(
-                    "workspace-change",
-                    serde_json::json!({
-                        "index": index,
-                        "source": sources.first().cloned().unwrap_or_default(),
-                        "sources": sources,
-                        "sourceCount": source_count,
-                        "workspaceRoot": workspace_root_display,
-                        "kind": "filesystem"
-                    }),
+                poll_and_emit_notifications(
+                    &app_handle,
+                    &workspace_root_clone,
+                    &workspace_root_display,
                 );
-                last_emit_at = Instant::now();
-            }
 
-            let sleep_started = Instant::now();
-            while sleep_started.elapsed() < WORKSPACE_EVENTS_POLL_INTERVAL {
-                if stop_signal.load(Ordering::Relaxed)
-                    || worker_generation_clone.load(Ordering::Relaxed) != generation
+                if !pending_runtime_sources.is_empty()
+                    && last_emit_at.elapsed() >= WORKSPACE_EVENTS_MIN_EMIT_INTERVAL
+                {
+                    index += 1;
+                    let mut sources = pending_runtime_sources.drain().collect::<Vec<_>>();
+                    sources.sort();
+                    let source_count = sources.len();
+
+                    let changes = pending_runtime_changes.drain();
+                    for worktree in changes.keys() {
+                        invalidate_worktree_caches(&app_handle, &workspace_root_clone, worktree);
+                    }
+                    emit_groove_event(
+                        &app_handle,
+                        &WorkspaceChangeEvent {
+                            index,
+                            source: sources.first().cloned().unwrap_or_default(),
+                            sources,
+                            source_count,
+                            workspace_root: workspace_root_display.clone(),
+                            kind: "runtime".to_string(),
+                            changes,
+                        },
+                    );
+                    last_emit_at = Instant::now();
+                }
+
+                if !pending_sources.is_empty()
+                    && last_emit_at.elapsed() >= WORKSPACE_EVENTS_MIN_EMIT_INTERVAL
                 {
-                    break;
+                    index += 1;
+                    let mut sources = pending_sources.drain().collect::<Vec<_>>();
+                    sources.sort();
+                    let source_count = sources.len();
+
+                    emit_groove_event(
+                        &app_handle,
+                        &WorkspaceChangeEvent {
+                            index,
+                            source: sources.first().cloned().unwrap_or_default(),
+                            sources,
+                            source_count,
+                            workspace_root: workspace_root_display.clone(),
+                            kind: "filesystem".to_string(),
+                            changes: pending_changes.drain(),
+                        },
+                    );
+                    last_emit_at = Instant::now();
+                }
+
+                let sleep_started = Instant::now();
+                while sleep_started.elapsed() < workspace_events_poll_interval() {
+                    if stop_signal.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    thread::sleep(WORKSPACE_EVENTS_STOP_POLL_INTERVAL);
                 }
-                thread::sleep(WORKSPACE_EVENTS_STOP_POLL_INTERVAL);
             }
+
+            log_line(&format!(
+                "[workspace-events] worker for {workspace_root_display} stopped; exiting poll loop"
+            ));
+        });
+
+        workers.insert(
+            worker_key,
+            WorkspaceWorker {
+                workspace_root: workspace_root_display.clone(),
+                stop,
+                handle,
+                last_poll_at,
+            },
+        );
+
+        WorkspaceEventsResponse {
+            request_id,
+            ok: true,
+            workspace_root: Some(workspace_root_display),
+            error: None,
         }
+    })
+}
 
-        if worker_generation_clone.load(Ordering::Relaxed) != generation {
-            eprintln!("[workspace-events] worker superseded; exiting poll loop");
+/// Stops the events worker of `workspace_root`, if one runs.
+fn stop_workspace_event_worker(app: &AppHandle, workspace_root: &Path) {
+    let Some(state) = app.try_state::<WorkspaceEventState>() else {
+        return;
+    };
+    let Ok(mut workers) = state.workers.lock() else {
+        return;
+    };
+    if let Some(worker) = workers.remove(&workspace_root_storage_key(workspace_root)) {
+        worker.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+#[tauri::command]
+fn worktree_watch(app: AppHandle, payload: WorktreeWatchPayload) -> WorktreeWatchResponse {
+    handle_command("worktree_watch", |context| {
+        let request_id = context.request_id();
+        let worktree_path = match validate_git_worktree_path(&payload.worktree_path) {
+            Ok(path) => path,
+            Err(error) => {
+                return WorktreeWatchResponse {
+                    request_id,
+                    ok: false,
+                    worktree_path: None,
+                    error: Some(error),
+                }
+            }
+        };
+
+        let worktree_path_display = worktree_path.display().to_string();
+        match start_worktree_watch(&app, worktree_path) {
+            Ok(()) => WorktreeWatchResponse {
+                request_id,
+                ok: true,
+                worktree_path: Some(worktree_path_display),
+                error: None,
+            },
+            Err(error) => WorktreeWatchResponse {
+                request_id,
+                ok: false,
+                worktree_path: None,
+                error: Some(error),
+            },
         }
-    });
+    })
+}
 
-    *worker = Some(WorkspaceWorker {
-        workspace_root: workspace_root_display.clone(),
-        stop,
-        handle,
-    });
+#[tauri::command]
+fn worktree_unwatch(app: AppHandle) -> WorktreeWatchResponse {
+    handle_command("worktree_unwatch", |context| {
+        let request_id = context.request_id();
+        match stop_worktree_watch(&app) {
+            Ok(()) => WorktreeWatchResponse {
+                request_id,
+                ok: true,
+                worktree_path: None,
+                error: None,
+            },
+            Err(error) => WorktreeWatchResponse {
+                request_id,
+                ok: false,
+                worktree_path: None,
+                error: Some(error),
+            },
+        }
+    })
+}
 
-    WorkspaceEventsResponse {
+fn background_activity_response(request_id: String, changed: bool) -> BackgroundActivityResponse {
+    BackgroundActivityResponse {
         request_id,
         ok: true,
-        workspace_root: Some(workspace_root_display),
+        paused: background_activity_paused(),
+        paused_since: background_paused_since(),
+        changed,
         error: None,
     }
 }
 
+#[tauri::command]
+fn background_pause(app: AppHandle) -> BackgroundActivityResponse {
+    handle_command("background_pause", |context| {
+        let changed = set_background_activity_paused(&app, true);
+        background_activity_response(context.request_id(), changed)
+    })
+}
+
+#[tauri::command]
+fn background_resume(app: AppHandle) -> BackgroundActivityResponse {
+    handle_command("background_resume", |context| {
+        let changed = set_background_activity_paused(&app, false);
+        background_activity_response(context.request_id(), changed)
+    })
+}
+
 fn poll_and_emit_notifications(
     app_handle: &AppHandle,
     workspace_root: &Path,
@@ -300,12 +433,12 @@ fn poll_and_emit_notifications(
 
         if file_age_ok {
             if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&content) {
-                let _ = app_handle.emit(
-                    "groove-notification",
-                    serde_json::json!({
-                        "workspaceRoot": workspace_root_display,
-                        "notification": parsed
-                    }),
+                emit_groove_event(
+                    app_handle,
+                    &GrooveNotificationEvent {
+                        workspace_root: workspace_root_display.to_string(),
+                        notification: parsed,
+                    },
                 );
             }
         }
@@ -314,3 +447,25 @@ fn poll_and_emit_notifications(
     }
 }
 
+
+/// Per-worktree change reasons accumulated between two emitted events.
+#[derive(Default)]
+struct WorktreeChangeSet {
+    reasons_by_worktree: BTreeMap<String, BTreeSet<WorktreeChangeReason>>,
+}
+
+impl WorktreeChangeSet {
+    fn record(&mut self, worktree: &str, reason: WorktreeChangeReason) {
+        self.reasons_by_worktree
+            .entry(worktree.to_string())
+            .or_default()
+            .insert(reason);
+    }
+
+    fn drain(&mut self) -> BTreeMap<String, Vec<WorktreeChangeReason>> {
+        std::mem::take(&mut self.reasons_by_worktree)
+            .into_iter()
+            .map(|(worktree, reasons)| (worktree, reasons.into_iter().collect()))
+            .collect()
+    }
+}
@@ -1,454 +1,546 @@
-#[tauri::command]
+#[tauri::command(async)]
 fn git_auth_status(payload: GitAuthStatusPayload) -> GitAuthStatusResponse {
-    let request_id = request_id();
-    let workspace_root = match validate_workspace_root_path(&payload.workspace_root) {
-        Ok(root) => root,
-        Err(error) => {
-            return GitAuthStatusResponse {
-                request_id,
-                ok: false,
-                workspace_root: None,
-                profile: GitProfileStatus::default(),
-                ssh_status: GitSshStatus::unknown(),
-                error: Some(error),
+    handle_command("git_auth_status", |context| {
+        let request_id = context.request_id();
+        let workspace_root = match validate_workspace_root_path(&payload.workspace_root) {
+            Ok(root) => root,
+            Err(error) => {
+                return GitAuthStatusResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    profile: GitProfileStatus::default(),
+                    ssh_status: GitSshStatus::unknown(),
+                    error: Some(error),
+                }
             }
-        }
-    };
-
-    let mut profile = GitProfileStatus::default();
-    let mut ssh_status = GitSshStatus::unknown();
+        };
 
-    let user_name_result =
-        run_capture_command(&workspace_root, "git", &["config", "--get", "user.name"]);
-    if user_name_result.error.is_none() && user_name_result.exit_code == Some(0) {
-        profile.user_name = first_non_empty_line(&user_name_result.stdout);
-    }
+        let mut profile = GitProfileStatus::default();
+        let mut ssh_status = GitSshStatus::unknown();
 
-    let user_email_result =
-        run_capture_command(&workspace_root, "git", &["config", "--get", "user.email"]);
-    if user_email_result.error.is_none() && user_email_result.exit_code == Some(0) {
-        profile.user_email = first_non_empty_line(&user_email_result.stdout);
-    }
+        let user_name_result =
+            run_capture_command(&workspace_root, "git", &["config", "--get", "user.name"]);
+        if user_name_result.error.is_none() && user_name_result.exit_code == Some(0) {
+            profile.user_name = first_non_empty_line(&user_name_result.stdout);
+        }
 
-    let ssh_test_result = run_capture_command_timeout(
-        &workspace_root,
-        "ssh",
-        &[
-            "-T",
-            "-o",
-            "BatchMode=yes",
-            "-o",
-            "ConnectTimeout=5",
-            "-o",
-            "StrictHostKeyChecking=no",
-            "-o",
-            "UserKnownHostsFile=/dev/null",
-            "git@github.com",
-        ],
-        Duration::from_secs(8),
-    );
-    let combined_output = format!("{}\n{}", ssh_test_result.stdout, ssh_test_result.stderr);
-    let combined_lower = combined_output.to_lowercase();
+        let user_email_result =
+            run_capture_command(&workspace_root, "git", &["config", "--get", "user.email"]);
+        if user_email_result.error.is_none() && user_email_result.exit_code == Some(0) {
+            profile.user_email = first_non_empty_line(&user_email_result.stdout);
+        }
 
-    if combined_lower.contains("successfully authenticated") {
-        ssh_status.state = "authenticated".to_string();
-        ssh_status.message = "Authenticated with GitHub over SSH".to_string();
-    } else if combined_lower.contains("permission denied")
-        || combined_lower.contains("publickey")
-        || combined_lower.contains("authentication failed")
-    {
-        ssh_status.state = "unauthenticated".to_string();
-        ssh_status.message = "SSH authentication failed".to_string();
-    } else if combined_lower.contains("connection timed out")
-        || combined_lower.contains("operation timed out")
-        || ssh_test_result
-            .error
-            .as_ref()
-            .map(|value| value.to_lowercase().contains("timed out"))
-            .unwrap_or(false)
-    {
-        ssh_status.state = "unreachable".to_string();
-        ssh_status.message = "GitHub SSH check timed out".to_string();
-    } else if combined_lower.contains("could not resolve hostname")
-        || combined_lower.contains("temporary failure in name resolution")
-        || combined_lower.contains("name or service not known")
-        || combined_lower.contains("network is unreachable")
-        || combined_lower.contains("no route to host")
-        || combined_lower.contains("connection refused")
-        || combined_lower.contains("connection closed")
-        || combined_lower.contains("connection reset")
-    {
-        ssh_status.state = "unreachable".to_string();
-        ssh_status.message = "GitHub SSH endpoint unreachable".to_string();
-    } else if let Some(error) = ssh_test_result.error {
-        let lower_error = error.to_lowercase();
-        if lower_error.contains("no such file or directory") {
-            ssh_status.state = "unavailable".to_string();
-            ssh_status.message = "OpenSSH is not installed".to_string();
+        let ssh_test_result = run_with_command_retries("ssh -T", true, |attempt| {
+            run_capture_command_timeout(
+                &workspace_root,
+                "ssh",
+                &[
+                    "-T",
+                    "-o",
+                    "BatchMode=yes",
+                    "-o",
+                    "ConnectTimeout=5",
+                    "-o",
+                    "StrictHostKeyChecking=no",
+                    "-o",
+                    "UserKnownHostsFile=/dev/null",
+                    "git@github.com",
+                ],
+                retry::progressive_timeout(Duration::from_secs(8), attempt),
+            )
+        });
+        let combined_output = format!("{}\n{}", ssh_test_result.stdout, ssh_test_result.stderr);
+        let combined_lower = combined_output.to_lowercase();
+
+        if combined_lower.contains("successfully authenticated") {
+            ssh_status.state = "authenticated".to_string();
+            ssh_status.message = "Authenticated with GitHub over SSH".to_string();
+        } else if combined_lower.contains("permission denied")
+            || combined_lower.contains("publickey")
+            || combined_lower.contains("authentication failed")
+        {
+            ssh_status.state = "unauthenticated".to_string();
+            ssh_status.message = "SSH authentication failed".to_string();
+        } else if combined_lower.contains("connection timed out")
+            || combined_lower.contains("operation timed out")
+            || ssh_test_result
+                .error
+                .as_ref()
+                .map(|value| value.to_lowercase().contains("timed out"))
+                .unwrap_or(false)
+        {
+            ssh_status.state = "unreachable".to_string();
+            ssh_status.message = "GitHub SSH check timed out".to_string();
+        } else if combined_lower.contains("could not resolve hostname")
+            || combined_lower.contains("temporary failure in name resolution")
+            || combined_lower.contains("name or service not known")
+            || combined_lower.contains("network is unreachable")
+            || combined_lower.contains("no route to host")
+            || combined_lower.contains("connection refused")
+            || combined_lower.contains("connection closed")
+            || combined_lower.contains("connection reset")
+        {
+            ssh_status.state = "unreachable".to_string();
+            ssh_status.message = "GitHub SSH endpoint unreachable".to_string();
+        } else if let Some(error) = ssh_test_result.error {
+            let lower_error = error.to_lowercase();
+            if lower_error.contains("no such file or directory") {
+                ssh_status.state = "unavailable".to_string();
+                ssh_status.message = "OpenSSH is not installed".to_string();
+            } else {
+                ssh_status.state = "unknown".to_string();
+                ssh_status.message = "SSH check unavailable".to_string();
+            }
         } else {
             ssh_status.state = "unknown".to_string();
-            ssh_status.message = "SSH check unavailable".to_string();
+            ssh_status.message = "SSH status unavailable".to_string();
         }
-    } else {
-        ssh_status.state = "unknown".to_string();
-        ssh_status.message = "SSH status unavailable".to_string();
-    }
 
-    GitAuthStatusResponse {
-        request_id,
-        ok: true,
-        workspace_root: Some(workspace_root.display().to_string()),
-        profile,
-        ssh_status,
-        error: None,
-    }
+        GitAuthStatusResponse {
+            request_id,
+            ok: true,
+            workspace_root: Some(workspace_root.display().to_string()),
+            profile,
+            ssh_status,
+            error: None,
+        }
+    })
 }
 
-#[tauri::command]
+#[tauri::command(async)]
 fn git_status(payload: GitPathPayload) -> GitStatusResponse {
-    let request_id = request_id();
-    let worktree_path = match validate_git_worktree_path(&payload.path) {
-        Ok(path) => path,
-        Err(error) => {
+    handle_command("git_status", |context| {
+        let request_id = context.request_id();
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => {
+                return GitStatusResponse {
+                    request_id,
+                    ok: false,
+                    path: None,
+                    modified: 0,
+                    added: 0,
+                    deleted: 0,
+                    untracked: 0,
+                    dirty: false,
+                    rebase: None,
+                    output_snippet: None,
+                    error: Some(error),
+                }
+            }
+        };
+
+        let result = native_git::porcelain_status(&worktree_path)
+            .map(native_git_result)
+            .unwrap_or_else(|| {
+                run_git_command_at_path(&worktree_path, &["status", "--porcelain=v1"])
+            });
+        if let Some(error) = result.error.clone() {
             return GitStatusResponse {
                 request_id,
                 ok: false,
-                path: None,
+                path: Some(worktree_path.display().to_string()),
                 modified: 0,
                 added: 0,
                 deleted: 0,
                 untracked: 0,
                 dirty: false,
+                rebase: None,
                 output_snippet: None,
                 error: Some(error),
-            }
+            };
+        }
+        if result.exit_code != Some(0) {
+            return GitStatusResponse {
+                request_id,
+                ok: false,
+                path: Some(worktree_path.display().to_string()),
+                modified: 0,
+                added: 0,
+                deleted: 0,
+                untracked: 0,
+                dirty: false,
+                rebase: None,
+                output_snippet: command_output_snippet(&result),
+                error: Some(
+                    first_non_empty_line(&result.stderr)
+                        .or_else(|| first_non_empty_line(&result.stdout))
+                        .unwrap_or_else(|| "git status failed".to_string()),
+                ),
+            };
         }
-    };
 
-    let result = run_git_command_at_path(&worktree_path, &["status", "--porcelain=v1"]);
-    if let Some(error) = result.error.clone() {
-        return GitStatusResponse {
-            request_id,
-            ok: false,
-            path: Some(worktree_path.display().to_string()),
-            modified: 0,
-            added: 0,
-            deleted: 0,
-            untracked: 0,
-            dirty: false,
-            output_snippet: None,
-            error: Some(error),
-        };
-    }
-    if result.exit_code != Some(0) {
-        return GitStatusResponse {
+        let counts = parse_git_porcelain_counts(&result.stdout);
+        GitStatusResponse {
             request_id,
-            ok: false,
+            ok: true,
             path: Some(worktree_path.display().to_string()),
-            modified: 0,
-            added: 0,
-            deleted: 0,
-            untracked: 0,
-            dirty: false,
+            modified: counts.modified,
+            added: counts.added,
+            deleted: counts.deleted,
+            untracked: counts.untracked,
+            dirty: counts.dirty(),
+            rebase: rebase_state_at(&worktree_path),
             output_snippet: command_output_snippet(&result),
-            error: Some(
-                first_non_empty_line(&result.stderr)
-                    .or_else(|| first_non_empty_line(&result.stdout))
-                    .unwrap_or_else(|| "git status failed".to_string()),
-            ),
-        };
-    }
-
-    let counts = parse_git_porcelain_counts(&result.stdout);
-    GitStatusResponse {
-        request_id,
-        ok: true,
-        path: Some(worktree_path.display().to_string()),
-        modified: counts.modified,
-        added: counts.added,
-        deleted: counts.deleted,
-        untracked: counts.untracked,
-        dirty: counts.dirty(),
-        output_snippet: command_output_snippet(&result),
-        error: None,
-    }
+            error: None,
+        }
+    })
 }
 
-#[tauri::command]
+#[tauri::command(async)]
 fn git_current_branch(payload: GitPathPayload) -> GitCurrentBranchResponse {
-    let request_id = request_id();
-    let worktree_path = match validate_git_worktree_path(&payload.path) {
-        Ok(path) => path,
-        Err(error) => {
+    handle_command("git_current_branch", |context| {
+        let request_id = context.request_id();
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => {
+                return GitCurrentBranchResponse {
+                    request_id,
+                    ok: false,
+                    path: None,
+                    branch: None,
+                    output_snippet: None,
+                    error: Some(error),
+                }
+            }
+        };
+
+        let result = native_git::current_branch(&worktree_path)
+            .map(|branch| native_git_result(branch.unwrap_or_default()))
+            .unwrap_or_else(|| {
+                run_git_command_at_path(&worktree_path, &["branch", "--show-current"])
+            });
+        if let Some(error) = result.error {
             return GitCurrentBranchResponse {
                 request_id,
                 ok: false,
-                path: None,
+                path: Some(worktree_path.display().to_string()),
                 branch: None,
                 output_snippet: None,
                 error: Some(error),
-            }
+            };
+        }
+        if result.exit_code != Some(0) {
+            return GitCurrentBranchResponse {
+                request_id,
+                ok: false,
+                path: Some(worktree_path.display().to_string()),
+                branch: None,
+                output_snippet: command_output_snippet(&result),
+                error: Some(
+                    first_non_empty_line(&result.stderr)
+                        .or_else(|| first_non_empty_line(&result.stdout))
+                        .unwrap_or_else(|| "git branch --show-current failed".to_string()),
+                ),
+            };
         }
-    };
 
-    let result = run_git_command_at_path(&worktree_path, &["branch", "--show-current"]);
-    if let Some(error) = result.error {
-        return GitCurrentBranchResponse {
-            request_id,
-            ok: false,
-            path: Some(worktree_path.display().to_string()),
-            branch: None,
-            output_snippet: None,
-            error: Some(error),
-        };
-    }
-    if result.exit_code != Some(0) {
-        return GitCurrentBranchResponse {
+        GitCurrentBranchResponse {
             request_id,
-            ok: false,
+            ok: true,
             path: Some(worktree_path.display().to_string()),
-            branch: None,
+            branch: first_non_empty_line(&result.stdout),
             output_snippet: command_output_snippet(&result),
-            error: Some(
-                first_non_empty_line(&result.stderr)
-                    .or_else(|| first_non_empty_line(&result.stdout))
-                    .unwrap_or_else(|| "git branch --show-current failed".to_string()),
-            ),
-        };
-    }
-
-    GitCurrentBranchResponse {
-        request_id,
-        ok: true,
-        path: Some(worktree_path.display().to_string()),
-        branch: first_non_empty_line(&result.stdout),
-        output_snippet: command_output_snippet(&result),
-        error: None,
-    }
+            error: None,
+        }
+    })
 }
 
-#[tauri::command]
+#[tauri::command(async)]
 fn git_list_branches(payload: GitPathPayload) -> GitListBranchesResponse {
-    let request_id = request_id();
-    let worktree_path = match validate_git_worktree_path(&payload.path) {
-        Ok(path) => path,
-        Err(error) => {
+    handle_command("git_list_branches", |context| {
+        let request_id = context.request_id();
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => {
+                return GitListBranchesResponse {
+                    request_id,
+                    ok: false,
+                    path: None,
+                    branches: Vec::new(),
+                    output_snippet: None,
+                    error: Some(error),
+                }
+            }
+        };
+
+        let result = native_git::list_branches(&worktree_path)
+            .map(|branches| native_git_result(branches.join("\n")))
+            .unwrap_or_else(|| {
+                run_git_command_at_path(&worktree_path, &["branch", "--format=%(refname:short)"])
+            });
+        if let Some(error) = result.error.clone() {
             return GitListBranchesResponse {
                 request_id,
                 ok: false,
-                path: None,
+                path: Some(worktree_path.display().to_string()),
                 branches: Vec::new(),
-                output_snippet: None,
+                output_snippet: command_output_snippet(&result),
                 error: Some(error),
-            }
+            };
+        }
+        if result.exit_code != Some(0) {
+            return GitListBranchesResponse {
+                request_id,
+                ok: false,
+                path: Some(worktree_path.display().to_string()),
+                branches: Vec::new(),
+                output_snippet: command_output_snippet(&result),
+                error: Some(
+                    first_non_empty_line(&result.stderr)
+                        .or_else(|| first_non_empty_line(&result.stdout))
+                        .unwrap_or_else(|| "git branch --format failed".to_string()),
+                ),
+            };
         }
-    };
 
-    let result = run_git_command_at_path(&worktree_path, &["branch", "--format=%(refname:short)"]);
-    if let Some(error) = result.error.clone() {
-        return GitListBranchesResponse {
-            request_id,
-            ok: false,
-            path: Some(worktree_path.display().to_string()),
-            branches: Vec::new(),
-            output_snippet: command_output_snippet(&result),
-            error: Some(error),
-        };
-    }
-    if result.exit_code != Some(0) {
-        return GitListBranchesResponse {
+        let branches = result
+            .stdout
+            .lines()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+
+        GitListBranchesResponse {
             request_id,
-            ok: false,
+            ok: true,
             path: Some(worktree_path.display().to_string()),
-            branches: Vec::new(),
+            branches,
             output_snippet: command_output_snippet(&result),
-            error: Some(
-                first_non_empty_line(&result.stderr)
-                    .or_else(|| first_non_empty_line(&result.stdout))
-                    .unwrap_or_else(|| "git branch --format failed".to_string()),
-            ),
-        };
-    }
-
-    let branches = result
-        .stdout
-        .lines()
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-        .map(str::to_string)
-        .collect::<Vec<_>>();
-
-    GitListBranchesResponse {
-        request_id,
-        ok: true,
-        path: Some(worktree_path.display().to_string()),
-        branches,
-        output_snippet: command_output_snippet(&result),
-        error: None,
-    }
+            error: None,
+        }
+    })
 }
 
-#[tauri::command]
+#[tauri::command(async)]
 fn git_ahead_behind(payload: GitPathPayload) -> GitAheadBehindResponse {
-    let request_id = request_id();
-    let worktree_path = match validate_git_worktree_path(&payload.path) {
-        Ok(path) => path,
-        Err(error) => {
+    handle_command("git_ahead_behind", |context| {
+        let request_id = context.request_id();
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => {
+                return GitAheadBehindResponse {
+                    request_id,
+                    ok: false,
+                    path: None,
+                    ahead: 0,
+                    behind: 0,
+                    output_snippet: None,
+                    error: Some(error),
+                }
+            }
+        };
+
+        if let Some((ahead, behind)) = native_git::ahead_behind(&worktree_path) {
+            return GitAheadBehindResponse {
+                request_id,
+                ok: true,
+                path: Some(worktree_path.display().to_string()),
+                ahead,
+                behind,
+                output_snippet: None,
+                error: None,
+            };
+        }
+
+        let result = run_git_command_at_path(&worktree_path, &["status", "-sb"]);
+        if let Some(error) = result.error {
             return GitAheadBehindResponse {
                 request_id,
                 ok: false,
-                path: None,
+                path: Some(worktree_path.display().to_string()),
                 ahead: 0,
                 behind: 0,
                 output_snippet: None,
                 error: Some(error),
-            }
+            };
+        }
+        if result.exit_code != Some(0) {
+            return GitAheadBehindResponse {
+                request_id,
+                ok: false,
+                path: Some(worktree_path.display().to_string()),
+                ahead: 0,
+                behind: 0,
+                output_snippet: command_output_snippet(&result),
+                error: Some(
+                    first_non_empty_line(&result.stderr)
+                        .or_else(|| first_non_empty_line(&result.stdout))
+                        .unwrap_or_else(|| "git status -sb failed".to_string()),
+                ),
+            };
         }
-    };
 
-    let result = run_git_command_at_path(&worktree_path, &["status", "-sb"]);
-    if let Some(error) = result.error {
-        return GitAheadBehindResponse {
-            request_id,
-            ok: false,
-            path: Some(worktree_path.display().to_string()),
-            ahead: 0,
-            behind: 0,
-            output_snippet: None,
-            error: Some(error),
-        };
-    }
-    if result.exit_code != Some(0) {
-        return GitAheadBehindResponse {
+        let (ahead, behind) = parse_git_ahead_behind(&result.stdout);
+        GitAheadBehindResponse {
             request_id,
-            ok: false,
+            ok: true,
             path: Some(worktree_path.display().to_string()),
-            ahead: 0,
-            behind: 0,
+            ahead,
+            behind,
             output_snippet: command_output_snippet(&result),
-            error: Some(
-                first_non_empty_line(&result.stderr)
-                    .or_else(|| first_non_empty_line(&result.stdout))
-                    .unwrap_or_else(|| "git status -sb failed".to_string()),
-            ),
-        };
-    }
-
-    let (ahead, behind) = parse_git_ahead_behind(&result.stdout);
-    GitAheadBehindResponse {
-        request_id,
-        ok: true,
-        path: Some(worktree_path.display().to_string()),
-        ahead,
-        behind,
-        output_snippet: command_output_snippet(&result),
-        error: None,
-    }
+            error: None,
+        }
+    })
 }
 
-#[tauri::command]
-fn git_pull(payload: GitPullPayload) -> GitCommandResponse {
-    let request_id = request_id();
-    let worktree_path = match validate_git_worktree_path(&payload.path) {
-        Ok(path) => path,
-        Err(error) => {
+#[tauri::command(async)]
+fn git_pull(app: AppHandle, payload: GitPullPayload) -> GitCommandResponse {
+    let response = handle_command("git_pull", |context| {
+        context.capture_subprocess_transcripts(payload.debug);
+        let request_id = context.request_id();
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => {
+                return GitCommandResponse {
+                    request_id,
+                    ok: false,
+                    path: None,
+                    exit_code: None,
+                    output_snippet: None,
+                    error: Some(error),
+                    debug_transcripts: None,
+                    retries: None,
+                }
+            }
+        };
+
+        let args = if payload.rebase {
+            vec!["pull", "--rebase"]
+        } else {
+            vec!["pull"]
+        };
+        let result = run_git_command_with_progress(&worktree_path, &args, &request_id);
+        if let Some(error) = result.error.clone() {
             return GitCommandResponse {
                 request_id,
                 ok: false,
-                path: None,
-                exit_code: None,
-                output_snippet: None,
+                path: Some(worktree_path.display().to_string()),
+                exit_code: result.exit_code,
+                output_snippet: command_output_snippet(&result),
                 error: Some(error),
-            }
+                debug_transcripts: None,
+                retries: None,
+            };
         }
-    };
 
-    let args = if payload.rebase {
-        vec!["pull", "--rebase"]
-    } else {
-        vec!["pull"]
-    };
-    let result = run_git_command_at_path(&worktree_path, &args);
-    if let Some(error) = result.error.clone() {
-        return GitCommandResponse {
+        let ok = result.exit_code == Some(0);
+        GitCommandResponse {
             request_id,
-            ok: false,
+            ok,
             path: Some(worktree_path.display().to_string()),
             exit_code: result.exit_code,
             output_snippet: command_output_snippet(&result),
-            error: Some(error),
-        };
-    }
-
-    let ok = result.exit_code == Some(0);
-    GitCommandResponse {
-        request_id,
-        ok,
-        path: Some(worktree_path.display().to_string()),
-        exit_code: result.exit_code,
-        output_snippet: command_output_snippet(&result),
-        error: if ok {
-            None
-        } else {
-            Some(
-                first_non_empty_line(&result.stderr)
-                    .or_else(|| first_non_empty_line(&result.stdout))
-                    .unwrap_or_else(|| "git pull failed".to_string()),
-            )
-        },
-    }
+            error: if ok {
+                None
+            } else {
+                Some(
+                    first_non_empty_line(&result.stderr)
+                        .or_else(|| first_non_empty_line(&result.stdout))
+                        .unwrap_or_else(|| "git pull failed".to_string()),
+                )
+            },
+            debug_transcripts: None,
+            retries: None,
+        }
+    });
+    record_action_history(&app, "git_pull", &payload.path, &payload, &response);
+    response
 }
 
-#[tauri::command]
-fn git_push(payload: GitPushPayload) -> GitCommandResponse {
-    let request_id = request_id();
-    let worktree_path = match validate_git_worktree_path(&payload.path) {
-        Ok(path) => path,
-        Err(error) => {
-            return GitCommandResponse {
-                request_id,
-                ok: false,
-                path: None,
-                exit_code: None,
-                output_snippet: None,
-                error: Some(error),
+#[tauri::command(async)]
+fn git_push(app: AppHandle, payload: GitPushPayload) -> GitCommandResponse {
+    let response = handle_command("git_push", |context| {
+        context.capture_subprocess_transcripts(payload.debug);
+        let request_id = context.request_id();
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => {
+                return GitCommandResponse {
+                    request_id,
+                    ok: false,
+                    path: None,
+                    exit_code: None,
+                    output_snippet: None,
+                    error: Some(error),
+                    debug_transcripts: None,
+                    retries: None,
+                }
             }
+        };
+
+        let mut args = vec!["push"];
+        if payload.force_with_lease {
+            args.push("--force-with-lease");
         }
-    };
 
-    let mut args = vec!["push"];
-    if payload.force_with_lease {
-        args.push("--force-with-lease");
-    }
+        if payload.set_upstream {
+            let branch = payload
+                .branch
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(|value| value.to_string())
+                .or_else(|| {
+                    let current_branch =
+                        run_git_command_at_path(&worktree_path, &["branch", "--show-current"]);
+                    first_non_empty_line(&current_branch.stdout)
+                });
 
-    if payload.set_upstream {
-        let branch = payload
-            .branch
-            .as_deref()
-            .map(str::trim)
-            .filter(|value| !value.is_empty())
-            .map(|value| value.to_string())
-            .or_else(|| {
-                let current_branch =
-                    run_git_command_at_path(&worktree_path, &["branch", "--show-current"]);
-                first_non_empty_line(&current_branch.stdout)
-            });
+            let Some(branch) = branch else {
+                return GitCommandResponse {
+                    request_id,
+                    ok: false,
+                    path: Some(worktree_path.display().to_string()),
+                    exit_code: None,
+                    output_snippet: None,
+                    error: Some("branch is required when setUpstream is enabled.".to_string()),
+                    debug_transcripts: None,
+                    retries: None,
+                };
+            };
+
+            args.extend(["-u", "origin"]);
+            args.push(branch.as_str());
+
+            let result = run_git_command_with_progress(&worktree_path, &args, &request_id);
+            if let Some(error) = result.error.clone() {
+                return GitCommandResponse {
+                    request_id,
+                    ok: false,
+                    path: Some(worktree_path.display().to_string()),
+                    exit_code: result.exit_code,
+                    output_snippet: command_output_snippet(&result),
+                    error: Some(error),
+                    debug_transcripts: None,
+                    retries: None,
+                };
+            }
 
-        let Some(branch) = branch else {
+            let ok = result.exit_code == Some(0);
             return GitCommandResponse {
                 request_id,
-                ok: false,
+                ok,
                 path: Some(worktree_path.display().to_string()),
-                exit_code: None,
-                output_snippet: None,
-                error: Some("branch is required when setUpstream is enabled.".to_string()),
+                exit_code: result.exit_code,
+                output_snippet: command_output_snippet(&result),
+                error: if ok {
+                    None
+                } else {
+                    Some(
+                        first_non_empty_line(&result.stderr)
+                            .or_else(|| first_non_empty_line(&result.stdout))
+                            .unwrap_or_else(|| "git push failed".to_string()),
+                    )
+                },
+                debug_transcripts: None,
+                retries: None,
             };
-        };
-
-        args.extend(["-u", "origin"]);
-        args.push(branch.as_str());
+        }
 
-        let result = run_git_command_at_path(&worktree_path, &args);
+        let result = run_git_command_with_progress(&worktree_path, &args, &request_id);
         if let Some(error) = result.error.clone() {
             return GitCommandResponse {
                 request_id,
@@ -457,11 +549,13 @@ fn git_push(payload: GitPushPayload) -> GitCommandResponse {
                 exit_code: result.exit_code,
                 output_snippet: command_output_snippet(&result),
                 error: Some(error),
+                debug_transcripts: None,
+                retries: None,
             };
         }
 
         let ok = result.exit_code == Some(0);
-        return GitCommandResponse {
+        GitCommandResponse {
             request_id,
             ok,
             path: Some(worktree_path.display().to_string()),
@@ -476,616 +570,1815 @@ fn git_push(payload: GitPushPayload) -> GitCommandResponse {
                         .unwrap_or_else(|| "git push failed".to_string()),
                 )
             },
+            debug_transcripts: None,
+            retries: None,
+        }
+    });
+    record_action_history(&app, "git_push", &payload.path, &payload, &response);
+    response
+}
+
+#[tauri::command(async)]
+fn git_fetch_blobs_for_worktree(payload: GitFetchBlobsPayload) -> GitFetchBlobsResponse {
+    handle_command("git_fetch_blobs_for_worktree", |context| {
+        let request_id = context.request_id();
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => {
+                return GitFetchBlobsResponse {
+                    request_id,
+                    ok: false,
+                    path: None,
+                    partial_clone_filter: None,
+                    changed_paths: 0,
+                    fetched_blobs: 0,
+                    error: Some(error),
+                }
+            }
         };
-    }
+        let path = Some(worktree_path.display().to_string());
 
-    let result = run_git_command_at_path(&worktree_path, &args);
-    if let Some(error) = result.error.clone() {
-        return GitCommandResponse {
-            request_id,
-            ok: false,
-            path: Some(worktree_path.display().to_string()),
-            exit_code: result.exit_code,
-            output_snippet: command_output_snippet(&result),
-            error: Some(error),
+        let Some(partial_clone) = detect_partial_clone(&worktree_path) else {
+            return GitFetchBlobsResponse {
+                request_id,
+                ok: true,
+                path,
+                partial_clone_filter: None,
+                changed_paths: 0,
+                fetched_blobs: 0,
+                error: None,
+            };
         };
-    }
 
-    let ok = result.exit_code == Some(0);
-    GitCommandResponse {
-        request_id,
-        ok,
-        path: Some(worktree_path.display().to_string()),
-        exit_code: result.exit_code,
-        output_snippet: command_output_snippet(&result),
-        error: if ok {
-            None
+        let explicit_base = payload
+            .base
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty());
+        let base = explicit_base.unwrap_or("@{upstream}");
+        let merge_base_result = run_git_command_at_path(&worktree_path, &["merge-base", base, "HEAD"]);
+        let merge_base = if merge_base_result.error.is_none() && merge_base_result.exit_code == Some(0) {
+            first_non_empty_line(&merge_base_result.stdout)
         } else {
-            Some(
-                first_non_empty_line(&result.stderr)
-                    .or_else(|| first_non_empty_line(&result.stdout))
-                    .unwrap_or_else(|| "git push failed".to_string()),
-            )
-        },
-    }
+            None
+        };
+        if explicit_base.is_some() && merge_base.is_none() {
+            return GitFetchBlobsResponse {
+                request_id,
+                ok: false,
+                path,
+                partial_clone_filter: Some(partial_clone.filter),
+                changed_paths: 0,
+                fetched_blobs: 0,
+                error: Some(format!("Could not find a merge base between \"{base}\" and HEAD.")),
+            };
+        }
+
+        let result = worktree_changed_paths(&worktree_path, merge_base.as_deref()).and_then(|paths| {
+            let mut revisions = vec!["HEAD"];
+            revisions.extend(merge_base.as_deref());
+            fetch_missing_blobs(&worktree_path, &partial_clone, &revisions, &paths)
+                .map(|fetched| (paths.len(), fetched))
+        });
+        match result {
+            Ok((changed_paths, fetched_blobs)) => GitFetchBlobsResponse {
+                request_id,
+                ok: true,
+                path,
+                partial_clone_filter: Some(partial_clone.filter),
+                changed_paths,
+                fetched_blobs,
+                error: None,
+            },
+            Err(error) => GitFetchBlobsResponse {
+                request_id,
+                ok: false,
+                path,
+                partial_clone_filter: Some(partial_clone.filter),
+                changed_paths: 0,
+                fetched_blobs: 0,
+                error: Some(error),
+            },
+        }
+    })
 }
 
-#[tauri::command]
-fn git_merge(payload: GitMergePayload) -> GitCommandResponse {
-    let request_id = request_id();
-    let worktree_path = match validate_git_worktree_path(&payload.path) {
-        Ok(path) => path,
-        Err(error) => {
+#[tauri::command(async)]
+fn git_merge(app: AppHandle, payload: GitMergePayload) -> GitCommandResponse {
+    let response = handle_command("git_merge", |context| {
+        context.capture_subprocess_transcripts(payload.debug);
+        let request_id = context.request_id();
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => {
+                return GitCommandResponse {
+                    request_id,
+                    ok: false,
+                    path: None,
+                    exit_code: None,
+                    output_snippet: None,
+                    error: Some(error),
+                    debug_transcripts: None,
+                    retries: None,
+                }
+            }
+        };
+
+        let target_branch = payload.target_branch.trim();
+        if target_branch.is_empty() {
             return GitCommandResponse {
                 request_id,
                 ok: false,
-                path: None,
+                path: Some(worktree_path.display().to_string()),
                 exit_code: None,
                 output_snippet: None,
-                error: Some(error),
-            }
+                error: Some("targetBranch must be a non-empty string.".to_string()),
+                debug_transcripts: None,
+                retries: None,
+            };
         }
-    };
 
-    let target_branch = payload.target_branch.trim();
-    if target_branch.is_empty() {
-        return GitCommandResponse {
-            request_id,
-            ok: false,
-            path: Some(worktree_path.display().to_string()),
-            exit_code: None,
-            output_snippet: None,
-            error: Some("targetBranch must be a non-empty string.".to_string()),
+        let result = if payload.ff_only {
+            run_git_command_at_path(&worktree_path, &["merge", "--ff-only", target_branch])
+        } else {
+            run_git_command_at_path(&worktree_path, &["merge", target_branch])
         };
-    }
 
-    let result = if payload.ff_only {
-        run_git_command_at_path(&worktree_path, &["merge", "--ff-only", target_branch])
-    } else {
-        run_git_command_at_path(&worktree_path, &["merge", target_branch])
-    };
+        if let Some(error) = result.error.clone() {
+            return GitCommandResponse {
+                request_id,
+                ok: false,
+                path: Some(worktree_path.display().to_string()),
+                exit_code: result.exit_code,
+                output_snippet: command_output_snippet(&result),
+                error: Some(error),
+                debug_transcripts: None,
+                retries: None,
+            };
+        }
 
-    if let Some(error) = result.error.clone() {
-        return GitCommandResponse {
+        let ok = result.exit_code == Some(0);
+        GitCommandResponse {
             request_id,
-            ok: false,
+            ok,
             path: Some(worktree_path.display().to_string()),
             exit_code: result.exit_code,
             output_snippet: command_output_snippet(&result),
-            error: Some(error),
-        };
-    }
-
-    let ok = result.exit_code == Some(0);
-    GitCommandResponse {
-        request_id,
-        ok,
-        path: Some(worktree_path.display().to_string()),
-        exit_code: result.exit_code,
-        output_snippet: command_output_snippet(&result),
-        error: if ok {
-            None
-        } else {
-            Some(
-                first_non_empty_line(&result.stderr)
-                    .or_else(|| first_non_empty_line(&result.stdout))
-                    .unwrap_or_else(|| "git merge failed".to_string()),
-            )
-        },
-    }
+            error: if ok {
+                None
+            } else {
+                Some(
+                    first_non_empty_line(&result.stderr)
+                        .or_else(|| first_non_empty_line(&result.stdout))
+                        .unwrap_or_else(|| "git merge failed".to_string()),
+                )
+            },
+            debug_transcripts: None,
+            retries: None,
+        }
+    });
+    record_action_history(&app, "git_merge", &payload.path, &payload, &response);
+    response
 }
 
-#[tauri::command]
+#[tauri::command(async)]
 fn git_merge_abort(payload: GitPathPayload) -> GitCommandResponse {
-    let request_id = request_id();
-    let worktree_path = match validate_git_worktree_path(&payload.path) {
-        Ok(path) => path,
-        Err(error) => {
+    handle_command("git_merge_abort", |context| {
+        context.capture_subprocess_transcripts(payload.debug);
+        let request_id = context.request_id();
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => {
+                return GitCommandResponse {
+                    request_id,
+                    ok: false,
+                    path: None,
+                    exit_code: None,
+                    output_snippet: None,
+                    error: Some(error),
+                    debug_transcripts: None,
+                    retries: None,
+                }
+            }
+        };
+
+        // Conflicts can also come from a rebase, cherry-pick or revert, which
+        // `git merge --abort` refuses to undo.
+        let operation = conflict_operation_at(&worktree_path).unwrap_or("merge");
+        let result = run_git_command_at_path(&worktree_path, &[operation, "--abort"]);
+        if let Some(error) = result.error.clone() {
             return GitCommandResponse {
                 request_id,
                 ok: false,
-                path: None,
-                exit_code: None,
-                output_snippet: None,
+                path: Some(worktree_path.display().to_string()),
+                exit_code: result.exit_code,
+                output_snippet: command_output_snippet(&result),
                 error: Some(error),
-            }
+                debug_transcripts: None,
+                retries: None,
+            };
         }
-    };
 
-    let result = run_git_command_at_path(&worktree_path, &["merge", "--abort"]);
-    if let Some(error) = result.error.clone() {
-        return GitCommandResponse {
+        let ok = result.exit_code == Some(0);
+        GitCommandResponse {
             request_id,
-            ok: false,
+            ok,
             path: Some(worktree_path.display().to_string()),
             exit_code: result.exit_code,
             output_snippet: command_output_snippet(&result),
-            error: Some(error),
-        };
+            error: if ok {
+                None
+            } else {
+                Some(
+                    first_non_empty_line(&result.stderr)
+                        .or_else(|| first_non_empty_line(&result.stdout))
+                        .unwrap_or_else(|| format!("git {operation} --abort failed")),
+                )
+            },
+            debug_transcripts: None,
+            retries: None,
+        }
+    })
+}
+
+/// Conflicted paths of the worktree, from `git ls-files --unmerged`.
+fn list_unmerged_files(worktree_path: &Path) -> Result<Vec<git::UnmergedFile>, String> {
+    let result = run_git_command_at_path(worktree_path, &["ls-files", "--unmerged", "-z"]);
+    if let Some(error) = result.error.clone() {
+        return Err(error);
     }
+    if result.exit_code != Some(0) {
+        return Err(first_non_empty_line(&result.stderr)
+            .unwrap_or_else(|| "git ls-files --unmerged failed".to_string()));
+    }
+    Ok(git::parse_unmerged_files(&result.stdout))
+}
 
-    let ok = result.exit_code == Some(0);
-    GitCommandResponse {
-        request_id,
-        ok,
-        path: Some(worktree_path.display().to_string()),
-        exit_code: result.exit_code,
-        output_snippet: command_output_snippet(&result),
-        error: if ok {
-            None
-        } else {
-            Some(
-                first_non_empty_line(&result.stderr)
-                    .or_else(|| first_non_empty_line(&result.stdout))
-                    .unwrap_or_else(|| "git merge --abort failed".to_string()),
-            )
+/// The worktree's own git dir. WSL worktrees ask git, since their `.git`
+/// file names a path inside the distro.
+fn worktree_git_dir(worktree_path: &Path) -> Option<PathBuf> {
+    if wsl_location(worktree_path).is_none() {
+        return git::resolve_git_dir(worktree_path);
+    }
+    let (_, head) = git_path_at(worktree_path, "HEAD").ok()?;
+    head.parent().map(Path::to_path_buf)
+}
+
+/// The merge, rebase, cherry-pick or revert in progress in the worktree.
+fn conflict_operation_at(worktree_path: &Path) -> Option<&'static str> {
+    git::conflict_operation(&worktree_git_dir(worktree_path)?)
+}
+
+fn rebase_state_at(worktree_path: &Path) -> Option<GitRebaseState> {
+    let progress = git::read_rebase_progress(&worktree_git_dir(worktree_path)?)?;
+    Some(GitRebaseState {
+        branch: progress.branch,
+        onto: progress.onto,
+        step: progress.step,
+        total: progress.total,
+    })
+}
+
+fn conflict_side(bytes: Vec<u8>) -> GitConflictSide {
+    let omitted = if bytes.len() > GIT_CONFLICT_CONTENT_MAX_BYTES {
+        Some("tooLarge")
+    } else if bytes.contains(&0) {
+        Some("binary")
+    } else {
+        None
+    };
+    match omitted {
+        Some(reason) => GitConflictSide {
+            content: None,
+            omitted: Some(reason.to_string()),
+        },
+        None => GitConflictSide {
+            content: Some(String::from_utf8_lossy(&bytes).to_string()),
+            omitted: None,
         },
     }
 }
 
-#[tauri::command]
-fn git_has_staged_changes(payload: GitPathPayload) -> GitBooleanResponse {
-    let request_id = request_id();
-    let worktree_path = match validate_git_worktree_path(&payload.path) {
-        Ok(path) => path,
-        Err(error) => {
-            return GitBooleanResponse {
-                request_id,
-                ok: false,
-                path: None,
-                value: false,
-                output_snippet: None,
-                error: Some(error),
-            }
-        }
+/// Raw content of a blob; `git cat-file` output is not always text.
+fn git_blob_bytes(worktree_path: &Path, object: &str) -> Option<Vec<u8>> {
+    let _permit = acquire_git_operation(worktree_path, &["cat-file"]);
+    let output = git_command_at(worktree_path)
+        .args(["cat-file", "blob", object])
+        .output()
+        .ok()?;
+    output.status.success().then_some(output.stdout)
+}
+
+fn conflict_file(worktree_path: &Path, file: &git::UnmergedFile) -> GitConflictFile {
+    let side = |object: &Option<String>| {
+        object
+            .as_deref()
+            .and_then(|object| git_blob_bytes(worktree_path, object))
+            .map(conflict_side)
     };
+    GitConflictFile {
+        file_path: file.path.clone(),
+        conflict: file.conflict_kind().to_string(),
+        base: side(&file.base),
+        ours: side(&file.ours),
+        theirs: side(&file.theirs),
+        working: fs::read(worktree_path.join(&file.path))
+            .ok()
+            .map(conflict_side),
+    }
+}
 
-    let result = run_git_command_at_path(&worktree_path, &["diff", "--cached", "--name-only"]);
-    if let Some(error) = result.error {
-        return GitBooleanResponse {
-            request_id,
+#[tauri::command(async)]
+fn git_conflicts_list(payload: GitPathPayload) -> GitConflictsResponse {
+    handle_command("git_conflicts_list", |context| {
+        let request_id = context.request_id();
+        let failed = |path: Option<String>, error: String| GitConflictsResponse {
+            request_id: request_id.clone(),
             ok: false,
-            path: Some(worktree_path.display().to_string()),
-            value: false,
-            output_snippet: None,
+            path,
+            operation: None,
+            files: Vec::new(),
             error: Some(error),
         };
-    }
-    if result.exit_code != Some(0) {
-        return GitBooleanResponse {
-            request_id,
-            ok: false,
-            path: Some(worktree_path.display().to_string()),
-            value: false,
-            output_snippet: command_output_snippet(&result),
-            error: Some(
-                first_non_empty_line(&result.stderr)
-                    .or_else(|| first_non_empty_line(&result.stdout))
-                    .unwrap_or_else(|| "git diff --cached --name-only failed".to_string()),
-            ),
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => return failed(None, error),
         };
-    }
+        let display_path = worktree_path.display().to_string();
 
-    GitBooleanResponse {
-        request_id,
-        ok: true,
-        path: Some(worktree_path.display().to_string()),
-        value: result.stdout.lines().any(|line| !line.trim().is_empty()),
-        output_snippet: command_output_snippet(&result),
-        error: None,
-    }
+        let unmerged = match list_unmerged_files(&worktree_path) {
+            Ok(unmerged) => unmerged,
+            Err(error) => return failed(Some(display_path), error),
+        };
+        let files = unmerged
+            .iter()
+            .map(|file| conflict_file(&worktree_path, file))
+            .collect();
+
+        GitConflictsResponse {
+            request_id: request_id.clone(),
+            ok: true,
+            path: Some(display_path),
+            operation: conflict_operation_at(&worktree_path).map(str::to_string),
+            files,
+            error: None,
+        }
+    })
 }
 
-#[tauri::command]
-fn git_merge_in_progress(payload: GitPathPayload) -> GitBooleanResponse {
-    let request_id = request_id();
-    let worktree_path = match validate_git_worktree_path(&payload.path) {
-        Ok(path) => path,
-        Err(error) => {
-            return GitBooleanResponse {
-                request_id,
+/// Resolves one conflicted file and stages the result. Only paths git
+/// reports as unmerged are touched.
+#[tauri::command(async)]
+fn git_conflict_resolve(
+    app: AppHandle,
+    payload: GitConflictResolvePayload,
+) -> GitConflictResolveResponse {
+    let response = handle_command("git_conflict_resolve", |context| {
+        let request_id = context.request_id();
+        let failed = |path: Option<String>, output_snippet: Option<String>, error: String| {
+            GitConflictResolveResponse {
+                request_id: request_id.clone(),
                 ok: false,
-                path: None,
-                value: false,
-                output_snippet: None,
+                path,
+                remaining_conflicts: 0,
+                output_snippet,
                 error: Some(error),
             }
-        }
-    };
+        };
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => return failed(None, None, error),
+        };
+        let display_path = worktree_path.display().to_string();
 
-    let result = run_git_command_at_path(
-        &worktree_path,
-        &["rev-parse", "-q", "--verify", "MERGE_HEAD"],
-    );
-    if let Some(error) = result.error {
-        return GitBooleanResponse {
-            request_id,
-            ok: false,
-            path: Some(worktree_path.display().to_string()),
-            value: false,
-            output_snippet: None,
-            error: Some(error),
+        let unmerged = match list_unmerged_files(&worktree_path) {
+            Ok(unmerged) => unmerged,
+            Err(error) => return failed(Some(display_path), None, error),
+        };
+        let file_path = payload.file_path.trim();
+        let Some(file) = unmerged.iter().find(|file| file.path == file_path) else {
+            return failed(
+                Some(display_path),
+                None,
+                format!("{file_path} has no unresolved conflict."),
+            );
         };
-    }
 
-    if result.exit_code == Some(0) {
-        return GitBooleanResponse {
-            request_id,
-            ok: true,
-            path: Some(worktree_path.display().to_string()),
-            value: true,
-            output_snippet: command_output_snippet(&result),
-            error: None,
+        let remove = ["rm", "-q", "--", file_path];
+        let add = ["add", "--", file_path];
+        let steps: Vec<Vec<&str>> = match payload.resolution.as_str() {
+            "ours" | "theirs" | "base" => {
+                let (stage, object) = match payload.resolution.as_str() {
+                    "ours" => ("--stage=2", &file.ours),
+                    "theirs" => ("--stage=3", &file.theirs),
+                    _ => ("--stage=1", &file.base),
+                };
+                // A side without the file resolves to deleting it.
+                if object.is_none() {
+                    vec![remove.to_vec()]
+                } else {
+                    vec![
+                        vec!["checkout-index", "-f", stage, "--", file_path],
+                        add.to_vec(),
+                    ]
+                }
+            }
+            "content" => {
+                let Some(content) = payload.content.as_deref() else {
+                    return failed(
+                        Some(display_path),
+                        None,
+                        "content is required when resolution is content.".to_string(),
+                    );
+                };
+                if let Err(error) = fs::write(worktree_path.join(file_path), content) {
+                    return failed(
+                        Some(display_path),
+                        None,
+                        format!("Failed to write {file_path}: {error}"),
+                    );
+                }
+                vec![add.to_vec()]
+            }
+            "delete" => vec![remove.to_vec()],
+            other => {
+                return failed(
+                    Some(display_path),
+                    None,
+                    format!(
+                        "Unsupported resolution {other:?}; expected ours, theirs, base, content or delete."
+                    ),
+                )
+            }
         };
-    }
 
-    if result.exit_code == Some(1) {
-        return GitBooleanResponse {
-            request_id,
+        let mut output_snippet = None;
+        for args in &steps {
+            let result = run_git_command_at_path(&worktree_path, args);
+            output_snippet = command_output_snippet(&result);
+            if let Some(error) = result.error.clone() {
+                return failed(Some(display_path), output_snippet, error);
+            }
+            if result.exit_code != Some(0) {
+                return failed(
+                    Some(display_path),
+                    output_snippet,
+                    first_non_empty_line(&result.stderr)
+                        .or_else(|| first_non_empty_line(&result.stdout))
+                        .unwrap_or_else(|| format!("git {} failed", args[0])),
+                );
+            }
+        }
+
+        let remaining_conflicts = list_unmerged_files(&worktree_path)
+            .map(|files| files.len())
+            .unwrap_or_default();
+        GitConflictResolveResponse {
+            request_id: request_id.clone(),
             ok: true,
-            path: Some(worktree_path.display().to_string()),
-            value: false,
-            output_snippet: command_output_snippet(&result),
+            path: Some(display_path),
+            remaining_conflicts,
+            output_snippet,
             error: None,
+        }
+    });
+    record_action_history(&app, "git_conflict_resolve", &payload.path, &payload, &response);
+    response
+}
+
+#[tauri::command(async)]
+fn git_rebase_status(payload: GitPathPayload) -> GitRebaseStatusResponse {
+    handle_command("git_rebase_status", |context| {
+        let request_id = context.request_id();
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => {
+                return GitRebaseStatusResponse {
+                    request_id,
+                    ok: false,
+                    path: None,
+                    rebase: None,
+                    conflicted_files: Vec::new(),
+                    error: Some(error),
+                }
+            }
         };
-    }
 
-    GitBooleanResponse {
-        request_id,
+        let rebase = rebase_state_at(&worktree_path);
+        let conflicted_files = if rebase.is_some() {
+            list_unmerged_files(&worktree_path)
+        } else {
+            Ok(Vec::new())
+        };
+        match conflicted_files {
+            Ok(files) => GitRebaseStatusResponse {
+                request_id,
+                ok: true,
+                path: Some(worktree_path.display().to_string()),
+                rebase,
+                conflicted_files: files.into_iter().map(|file| file.path).collect(),
+                error: None,
+            },
+            Err(error) => GitRebaseStatusResponse {
+                request_id,
+                ok: false,
+                path: Some(worktree_path.display().to_string()),
+                rebase,
+                conflicted_files: Vec::new(),
+                error: Some(error),
+            },
+        }
+    })
+}
+
+#[tauri::command(async)]
+fn git_rebase_start(app: AppHandle, payload: GitRebaseStartPayload) -> GitRebaseResponse {
+    let response = handle_command("git_rebase_start", |context| {
+        let onto = payload.onto.trim();
+        if !is_valid_branch_token(onto) {
+            return GitRebaseResponse {
+                request_id: context.request_id(),
+                ok: false,
+                path: None,
+                exit_code: None,
+                output_snippet: None,
+                rebase: None,
+                conflicted_files: Vec::new(),
+                error: Some("onto must be a branch name or commit.".to_string()),
+            };
+        }
+        let mut args = vec!["rebase"];
+        if payload.autostash {
+            args.push("--autostash");
+        }
+        args.push(onto);
+        run_rebase_step(context.request_id(), &payload.path, false, &args)
+    });
+    record_action_history(&app, "git_rebase_start", &payload.path, &payload, &response);
+    response
+}
+
+#[tauri::command(async)]
+fn git_rebase_continue(payload: GitPathPayload) -> GitRebaseResponse {
+    handle_command("git_rebase_continue", |context| {
+        run_rebase_step(context.request_id(), &payload.path, true, &["rebase", "--continue"])
+    })
+}
+
+#[tauri::command(async)]
+fn git_rebase_skip(payload: GitPathPayload) -> GitRebaseResponse {
+    handle_command("git_rebase_skip", |context| {
+        run_rebase_step(context.request_id(), &payload.path, true, &["rebase", "--skip"])
+    })
+}
+
+#[tauri::command(async)]
+fn git_rebase_abort(payload: GitPathPayload) -> GitRebaseResponse {
+    handle_command("git_rebase_abort", |context| {
+        run_rebase_step(context.request_id(), &payload.path, true, &["rebase", "--abort"])
+    })
+}
+
+/// Runs one rebase command and reports where the rebase stands afterwards.
+/// `core.editor=true` keeps `--continue` from waiting on a commit message
+/// editor; the message git prepared is used as is.
+fn run_rebase_step(
+    request_id: String,
+    path: &str,
+    requires_rebase: bool,
+    args: &[&str],
+) -> GitRebaseResponse {
+    let failed = |path: Option<String>, error: String| GitRebaseResponse {
+        request_id: request_id.clone(),
         ok: false,
-        path: Some(worktree_path.display().to_string()),
-        value: false,
-        output_snippet: command_output_snippet(&result),
-        error: Some(
+        path,
+        exit_code: None,
+        output_snippet: None,
+        rebase: None,
+        conflicted_files: Vec::new(),
+        error: Some(error),
+    };
+    let worktree_path = match validate_git_worktree_path(path) {
+        Ok(path) => path,
+        Err(error) => return failed(None, error),
+    };
+    let display_path = worktree_path.display().to_string();
+
+    let in_progress = rebase_state_at(&worktree_path).is_some();
+    if requires_rebase && !in_progress {
+        return failed(Some(display_path), "No rebase in progress.".to_string());
+    }
+    if !requires_rebase && in_progress {
+        return failed(
+            Some(display_path),
+            "A rebase is already in progress; continue, skip or abort it first.".to_string(),
+        );
+    }
+
+    let mut git_args = vec!["-c", "core.editor=true"];
+    git_args.extend_from_slice(args);
+    let result = run_git_command_at_path(&worktree_path, &git_args);
+    if let Some(error) = result.error.clone() {
+        return failed(Some(display_path), error);
+    }
+
+    let rebase = rebase_state_at(&worktree_path);
+    let conflicted_files = if rebase.is_some() {
+        list_unmerged_files(&worktree_path)
+            .map(|files| files.into_iter().map(|file| file.path).collect())
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    let ok = result.exit_code == Some(0);
+    let error = if ok {
+        None
+    } else if !conflicted_files.is_empty() {
+        Some(format!(
+            "Rebase stopped with conflicts in {} file(s).",
+            conflicted_files.len()
+        ))
+    } else {
+        Some(
             first_non_empty_line(&result.stderr)
                 .or_else(|| first_non_empty_line(&result.stdout))
-                .unwrap_or_else(|| "git rev-parse -q --verify MERGE_HEAD failed".to_string()),
-        ),
+                .unwrap_or_else(|| format!("git {} failed", args.join(" "))),
+        )
+    };
+    GitRebaseResponse {
+        request_id: request_id.clone(),
+        ok,
+        path: Some(display_path),
+        exit_code: result.exit_code,
+        output_snippet: command_output_snippet(&result),
+        rebase,
+        conflicted_files,
+        error,
     }
 }
 
-#[tauri::command]
-fn git_has_upstream(payload: GitPathPayload) -> GitBooleanResponse {
-    let request_id = request_id();
-    let worktree_path = match validate_git_worktree_path(&payload.path) {
-        Ok(path) => path,
-        Err(error) => {
+#[tauri::command(async)]
+fn git_has_staged_changes(payload: GitPathPayload) -> GitBooleanResponse {
+    handle_command("git_has_staged_changes", |context| {
+        let request_id = context.request_id();
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => {
+                return GitBooleanResponse {
+                    request_id,
+                    ok: false,
+                    path: None,
+                    value: false,
+                    output_snippet: None,
+                    error: Some(error),
+                }
+            }
+        };
+
+        let result = run_git_command_at_path(&worktree_path, &["diff", "--cached", "--name-only"]);
+        if let Some(error) = result.error {
             return GitBooleanResponse {
                 request_id,
                 ok: false,
-                path: None,
+                path: Some(worktree_path.display().to_string()),
                 value: false,
                 output_snippet: None,
                 error: Some(error),
-            }
+            };
+        }
+        if result.exit_code != Some(0) {
+            return GitBooleanResponse {
+                request_id,
+                ok: false,
+                path: Some(worktree_path.display().to_string()),
+                value: false,
+                output_snippet: command_output_snippet(&result),
+                error: Some(
+                    first_non_empty_line(&result.stderr)
+                        .or_else(|| first_non_empty_line(&result.stdout))
+                        .unwrap_or_else(|| "git diff --cached --name-only failed".to_string()),
+                ),
+            };
         }
-    };
-
-    let result = run_git_command_at_path(
-        &worktree_path,
-        &[
-            "rev-parse",
-            "--abbrev-ref",
-            "--symbolic-full-name",
-            "@{upstream}",
-        ],
-    );
-    if let Some(error) = result.error {
-        return GitBooleanResponse {
-            request_id,
-            ok: false,
-            path: Some(worktree_path.display().to_string()),
-            value: false,
-            output_snippet: None,
-            error: Some(error),
-        };
-    }
 
-    if result.exit_code == Some(0) {
-        return GitBooleanResponse {
+        GitBooleanResponse {
             request_id,
             ok: true,
             path: Some(worktree_path.display().to_string()),
-            value: true,
+            value: result.stdout.lines().any(|line| !line.trim().is_empty()),
             output_snippet: command_output_snippet(&result),
             error: None,
-        };
-    }
-
-    GitBooleanResponse {
-        request_id,
-        ok: true,
-        path: Some(worktree_path.display().to_string()),
-        value: false,
-        output_snippet: command_output_snippet(&result),
-        error: None,
-    }
+        }
+    })
 }
 
-#[tauri::command]
-fn git_list_file_states(payload: GitPathPayload) -> GitFileStatesResponse {
-    let request_id = request_id();
-    let worktree_path = match validate_git_worktree_path(&payload.path) {
-        Ok(path) => path,
-        Err(error) => {
-            return GitFileStatesResponse {
+#[tauri::command(async)]
+fn git_merge_in_progress(payload: GitPathPayload) -> GitBooleanResponse {
+    handle_command("git_merge_in_progress", |context| {
+        let request_id = context.request_id();
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => {
+                return GitBooleanResponse {
+                    request_id,
+                    ok: false,
+                    path: None,
+                    value: false,
+                    output_snippet: None,
+                    error: Some(error),
+                }
+            }
+        };
+
+        let result = run_git_command_at_path(
+            &worktree_path,
+            &["rev-parse", "-q", "--verify", "MERGE_HEAD"],
+        );
+        if let Some(error) = result.error {
+            return GitBooleanResponse {
                 request_id,
                 ok: false,
-                path: None,
-                staged: Vec::new(),
-                unstaged: Vec::new(),
-                untracked: Vec::new(),
+                path: Some(worktree_path.display().to_string()),
+                value: false,
                 output_snippet: None,
                 error: Some(error),
-            }
+            };
         }
-    };
 
-    let result = run_git_command_at_path(&worktree_path, &["status", "--porcelain=v1"]);
-    if let Some(error) = result.error.clone() {
-        return GitFileStatesResponse {
-            request_id,
-            ok: false,
-            path: Some(worktree_path.display().to_string()),
-            staged: Vec::new(),
-            unstaged: Vec::new(),
-            untracked: Vec::new(),
-            output_snippet: command_output_snippet(&result),
-            error: Some(error),
-        };
-    }
-    if result.exit_code != Some(0) {
-        return GitFileStatesResponse {
+        if result.exit_code == Some(0) {
+            return GitBooleanResponse {
+                request_id,
+                ok: true,
+                path: Some(worktree_path.display().to_string()),
+                value: true,
+                output_snippet: command_output_snippet(&result),
+                error: None,
+            };
+        }
+
+        if result.exit_code == Some(1) {
+            return GitBooleanResponse {
+                request_id,
+                ok: true,
+                path: Some(worktree_path.display().to_string()),
+                value: false,
+                output_snippet: command_output_snippet(&result),
+                error: None,
+            };
+        }
+
+        GitBooleanResponse {
             request_id,
             ok: false,
             path: Some(worktree_path.display().to_string()),
-            staged: Vec::new(),
-            unstaged: Vec::new(),
-            untracked: Vec::new(),
+            value: false,
             output_snippet: command_output_snippet(&result),
             error: Some(
                 first_non_empty_line(&result.stderr)
                     .or_else(|| first_non_empty_line(&result.stdout))
-                    .unwrap_or_else(|| "git status --porcelain=v1 failed".to_string()),
+                    .unwrap_or_else(|| "git rev-parse -q --verify MERGE_HEAD failed".to_string()),
             ),
-        };
-    }
-
-    let (staged, unstaged, untracked) = parse_git_file_states(&result.stdout);
-    GitFileStatesResponse {
-        request_id,
-        ok: true,
-        path: Some(worktree_path.display().to_string()),
-        staged,
-        unstaged,
-        untracked,
-        output_snippet: command_output_snippet(&result),
-        error: None,
-    }
+        }
+    })
 }
 
-#[tauri::command]
-fn git_stage_files(payload: GitFilesPayload) -> GitCommandResponse {
-    let request_id = request_id();
-    let worktree_path = match validate_git_worktree_path(&payload.path) {
-        Ok(path) => path,
-        Err(error) => {
-            return GitCommandResponse {
+#[tauri::command(async)]
+fn git_has_upstream(payload: GitPathPayload) -> GitBooleanResponse {
+    handle_command("git_has_upstream", |context| {
+        let request_id = context.request_id();
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => {
+                return GitBooleanResponse {
+                    request_id,
+                    ok: false,
+                    path: None,
+                    value: false,
+                    output_snippet: None,
+                    error: Some(error),
+                }
+            }
+        };
+
+        let result = run_git_command_at_path(
+            &worktree_path,
+            &[
+                "rev-parse",
+                "--abbrev-ref",
+                "--symbolic-full-name",
+                "@{upstream}",
+            ],
+        );
+        if let Some(error) = result.error {
+            return GitBooleanResponse {
                 request_id,
                 ok: false,
-                path: None,
-                exit_code: None,
+                path: Some(worktree_path.display().to_string()),
+                value: false,
                 output_snippet: None,
                 error: Some(error),
-            }
+            };
         }
-    };
-    let files = match normalize_git_file_list(&payload.files) {
-        Ok(files) => files,
-        Err(error) => {
-            return GitCommandResponse {
+
+        if result.exit_code == Some(0) {
+            return GitBooleanResponse {
                 request_id,
-                ok: false,
+                ok: true,
                 path: Some(worktree_path.display().to_string()),
-                exit_code: None,
-                output_snippet: None,
-                error: Some(error),
-            }
+                value: true,
+                output_snippet: command_output_snippet(&result),
+                error: None,
+            };
         }
-    };
 
-    let mut args = vec!["add".to_string(), "--".to_string()];
-    args.extend(files);
-    let result = run_git_command_at_path_with_args(&worktree_path, &args);
-    if let Some(error) = result.error.clone() {
-        return GitCommandResponse {
+        GitBooleanResponse {
             request_id,
-            ok: false,
+            ok: true,
             path: Some(worktree_path.display().to_string()),
-            exit_code: result.exit_code,
+            value: false,
             output_snippet: command_output_snippet(&result),
-            error: Some(error),
-        };
-    }
-
-    let ok = result.exit_code == Some(0);
-    GitCommandResponse {
-        request_id,
-        ok,
-        path: Some(worktree_path.display().to_string()),
-        exit_code: result.exit_code,
-        output_snippet: command_output_snippet(&result),
-        error: if ok {
-            None
-        } else {
-            Some(
-                first_non_empty_line(&result.stderr)
-                    .or_else(|| first_non_empty_line(&result.stdout))
-                    .unwrap_or_else(|| "git add -- failed".to_string()),
-            )
-        },
-    }
+            error: None,
+        }
+    })
 }
 
-#[tauri::command]
-fn git_unstage_files(payload: GitFilesPayload) -> GitCommandResponse {
-    let request_id = request_id();
-    let worktree_path = match validate_git_worktree_path(&payload.path) {
-        Ok(path) => path,
-        Err(error) => {
-            return GitCommandResponse {
+#[tauri::command(async)]
+fn git_list_file_states(payload: GitPathPayload) -> GitFileStatesResponse {
+    handle_command("git_list_file_states", |context| {
+        let request_id = context.request_id();
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => {
+                return GitFileStatesResponse {
+                    request_id,
+                    ok: false,
+                    path: None,
+                    staged: Vec::new(),
+                    unstaged: Vec::new(),
+                    untracked: Vec::new(),
+                    output_snippet: None,
+                    error: Some(error),
+                }
+            }
+        };
+
+        let result = native_git::porcelain_status(&worktree_path)
+            .map(native_git_result)
+            .unwrap_or_else(|| {
+                run_git_command_at_path(&worktree_path, &["status", "--porcelain=v1"])
+            });
+        if let Some(error) = result.error.clone() {
+            return GitFileStatesResponse {
                 request_id,
                 ok: false,
-                path: None,
-                exit_code: None,
-                output_snippet: None,
+                path: Some(worktree_path.display().to_string()),
+                staged: Vec::new(),
+                unstaged: Vec::new(),
+                untracked: Vec::new(),
+                output_snippet: command_output_snippet(&result),
                 error: Some(error),
-            }
+            };
         }
-    };
-    let files = match normalize_git_file_list(&payload.files) {
-        Ok(files) => files,
-        Err(error) => {
-            return GitCommandResponse {
+        if result.exit_code != Some(0) {
+            return GitFileStatesResponse {
                 request_id,
                 ok: false,
                 path: Some(worktree_path.display().to_string()),
-                exit_code: None,
-                output_snippet: None,
-                error: Some(error),
-            }
+                staged: Vec::new(),
+                unstaged: Vec::new(),
+                untracked: Vec::new(),
+                output_snippet: command_output_snippet(&result),
+                error: Some(
+                    first_non_empty_line(&result.stderr)
+                        .or_else(|| first_non_empty_line(&result.stdout))
+                        .unwrap_or_else(|| "git status --porcelain=v1 failed".to_string()),
+                ),
+            };
         }
-    };
 
-    let mut args = vec![
-        "restore".to_string(),
-        "--staged".to_string(),
-        "--".to_string(),
-    ];
-    args.extend(files);
-    let result = run_git_command_at_path_with_args(&worktree_path, &args);
-    if let Some(error) = result.error.clone() {
-        return GitCommandResponse {
+        let (staged, unstaged, untracked) = parse_git_file_states(&result.stdout);
+        GitFileStatesResponse {
             request_id,
-            ok: false,
+            ok: true,
             path: Some(worktree_path.display().to_string()),
-            exit_code: result.exit_code,
+            staged,
+            unstaged,
+            untracked,
             output_snippet: command_output_snippet(&result),
-            error: Some(error),
-        };
-    }
-
-    let ok = result.exit_code == Some(0);
-    GitCommandResponse {
-        request_id,
-        ok,
-        path: Some(worktree_path.display().to_string()),
-        exit_code: result.exit_code,
-        output_snippet: command_output_snippet(&result),
-        error: if ok {
-            None
-        } else {
-            Some(
-                first_non_empty_line(&result.stderr)
-                    .or_else(|| first_non_empty_line(&result.stdout))
-                    .unwrap_or_else(|| "git restore --staged -- failed".to_string()),
-            )
-        },
-    }
+            error: None,
+        }
+    })
 }
 
-#[tauri::command]
-fn git_add(payload: GitPathPayload) -> GitCommandResponse {
-    let request_id = request_id();
-    let worktree_path = match validate_git_worktree_path(&payload.path) {
-        Ok(path) => path,
-        Err(error) => {
+#[tauri::command(async)]
+fn git_stage_files(payload: GitFilesPayload) -> GitCommandResponse {
+    handle_command("git_stage_files", |context| {
+        context.capture_subprocess_transcripts(payload.debug);
+        let request_id = context.request_id();
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => {
+                return GitCommandResponse {
+                    request_id,
+                    ok: false,
+                    path: None,
+                    exit_code: None,
+                    output_snippet: None,
+                    error: Some(error),
+                    debug_transcripts: None,
+                    retries: None,
+                }
+            }
+        };
+        let files = match normalize_git_file_list(&payload.files) {
+            Ok(files) => files,
+            Err(error) => {
+                return GitCommandResponse {
+                    request_id,
+                    ok: false,
+                    path: Some(worktree_path.display().to_string()),
+                    exit_code: None,
+                    output_snippet: None,
+                    error: Some(error),
+                    debug_transcripts: None,
+                    retries: None,
+                }
+            }
+        };
+
+        let mut args = vec!["add".to_string(), "--".to_string()];
+        args.extend(files);
+        let result = run_git_command_at_path_with_args(&worktree_path, &args);
+        if let Some(error) = result.error.clone() {
             return GitCommandResponse {
                 request_id,
                 ok: false,
-                path: None,
-                exit_code: None,
-                output_snippet: None,
+                path: Some(worktree_path.display().to_string()),
+                exit_code: result.exit_code,
+                output_snippet: command_output_snippet(&result),
                 error: Some(error),
-            }
+                debug_transcripts: None,
+                retries: None,
+            };
         }
-    };
 
-    let result = run_git_command_at_path(&worktree_path, &["add", "-A"]);
-    if let Some(error) = result.error.clone() {
-        return GitCommandResponse {
+        let ok = result.exit_code == Some(0);
+        GitCommandResponse {
             request_id,
-            ok: false,
+            ok,
             path: Some(worktree_path.display().to_string()),
             exit_code: result.exit_code,
             output_snippet: command_output_snippet(&result),
-            error: Some(error),
-        };
-    }
-
-    let ok = result.exit_code == Some(0);
-    GitCommandResponse {
-        request_id,
-        ok,
-        path: Some(worktree_path.display().to_string()),
-        exit_code: result.exit_code,
-        output_snippet: command_output_snippet(&result),
-        error: if ok {
-            None
-        } else {
-            Some(
-                first_non_empty_line(&result.stderr)
-                    .or_else(|| first_non_empty_line(&result.stdout))
-                    .unwrap_or_else(|| "git add -A failed".to_string()),
-            )
-        },
-    }
+            error: if ok {
+                None
+            } else {
+                Some(
+                    first_non_empty_line(&result.stderr)
+                        .or_else(|| first_non_empty_line(&result.stdout))
+                        .unwrap_or_else(|| "git add -- failed".to_string()),
+                )
+            },
+            debug_transcripts: None,
+            retries: None,
+        }
+    })
 }
 
-#[tauri::command]
-fn git_commit(payload: GitCommitPayload) -> GitCommandResponse {
-    let request_id = request_id();
-    let worktree_path = match validate_git_worktree_path(&payload.path) {
-        Ok(path) => path,
-        Err(error) => {
+#[tauri::command(async)]
+fn git_unstage_files(payload: GitFilesPayload) -> GitCommandResponse {
+    handle_command("git_unstage_files", |context| {
+        context.capture_subprocess_transcripts(payload.debug);
+        let request_id = context.request_id();
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => {
+                return GitCommandResponse {
+                    request_id,
+                    ok: false,
+                    path: None,
+                    exit_code: None,
+                    output_snippet: None,
+                    error: Some(error),
+                    debug_transcripts: None,
+                    retries: None,
+                }
+            }
+        };
+        let files = match normalize_git_file_list(&payload.files) {
+            Ok(files) => files,
+            Err(error) => {
+                return GitCommandResponse {
+                    request_id,
+                    ok: false,
+                    path: Some(worktree_path.display().to_string()),
+                    exit_code: None,
+                    output_snippet: None,
+                    error: Some(error),
+                    debug_transcripts: None,
+                    retries: None,
+                }
+            }
+        };
+
+        let mut args = vec![
+            "restore".to_string(),
+            "--staged".to_string(),
+            "--".to_string(),
+        ];
+        args.extend(files);
+        let result = run_git_command_at_path_with_args(&worktree_path, &args);
+        if let Some(error) = result.error.clone() {
             return GitCommandResponse {
                 request_id,
                 ok: false,
-                path: None,
-                exit_code: None,
-                output_snippet: None,
+                path: Some(worktree_path.display().to_string()),
+                exit_code: result.exit_code,
+                output_snippet: command_output_snippet(&result),
                 error: Some(error),
-            }
+                debug_transcripts: None,
+                retries: None,
+            };
         }
-    };
-
-    let message = payload
-        .message
-        .as_deref()
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-        .unwrap_or("chore: update files");
 
-    let result = run_git_command_at_path(&worktree_path, &["commit", "-m", message]);
-    if let Some(error) = result.error.clone() {
-        return GitCommandResponse {
+        let ok = result.exit_code == Some(0);
+        GitCommandResponse {
             request_id,
-            ok: false,
+            ok,
             path: Some(worktree_path.display().to_string()),
             exit_code: result.exit_code,
             output_snippet: command_output_snippet(&result),
+            error: if ok {
+                None
+            } else {
+                Some(
+                    first_non_empty_line(&result.stderr)
+                        .or_else(|| first_non_empty_line(&result.stdout))
+                        .unwrap_or_else(|| "git restore --staged -- failed".to_string()),
+                )
+            },
+            debug_transcripts: None,
+            retries: None,
+        }
+    })
+}
+
+#[tauri::command(async)]
+fn git_diff_file(payload: GitDiffFilePayload) -> GitDiffFileResponse {
+    handle_command("git_diff_file", |context| {
+        let request_id = context.request_id();
+        let staged = payload.staged;
+        let failed = |path: Option<String>, error: String| GitDiffFileResponse {
+            request_id: request_id.clone(),
+            ok: false,
+            path,
+            staged,
+            file: None,
             error: Some(error),
         };
-    }
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => return failed(None, error),
+        };
+        let path = Some(worktree_path.display().to_string());
+        let file_path = match normalize_git_file_list(std::slice::from_ref(&payload.file_path)) {
+            Ok(mut files) => files.remove(0),
+            Err(error) => return failed(path, error),
+        };
 
-    let ok = result.exit_code == Some(0);
-    GitCommandResponse {
-        request_id,
-        ok,
-        path: Some(worktree_path.display().to_string()),
+        let mut base_args = vec![
+            "diff".to_string(),
+            "--no-color".to_string(),
+            "--no-ext-diff".to_string(),
+            "--find-renames".to_string(),
+        ];
+        if staged {
+            base_args.push("--cached".to_string());
+        }
+
+        // Renames are only detected when both paths are in the pathspec, so
+        // look for one across the whole diff first; names only, no content.
+        let mut name_status_args = base_args.clone();
+        name_status_args.extend(["--name-status".to_string(), "-z".to_string()]);
+        let name_status = run_git_command_at_path_with_args(&worktree_path, &name_status_args);
+        if let Some(error) = name_status.error.clone() {
+            return failed(path, error);
+        }
+        if name_status.exit_code != Some(0) {
+            return failed(
+                path,
+                first_non_empty_line(&name_status.stderr)
+                    .unwrap_or_else(|| "git diff --name-status failed".to_string()),
+            );
+        }
+        let pathspecs = match git::find_rename_in_name_status(&name_status.stdout, &file_path) {
+            Some((old_path, new_path)) => vec![old_path, new_path],
+            None => vec![file_path.clone()],
+        };
+
+        let context_lines = payload
+            .context_lines
+            .unwrap_or(3)
+            .min(GIT_DIFF_MAX_CONTEXT_LINES);
+        let mut diff_args = base_args;
+        diff_args.extend([
+            "--src-prefix=a/".to_string(),
+            "--dst-prefix=b/".to_string(),
+            format!("--unified={context_lines}"),
+            "--".to_string(),
+        ]);
+        diff_args.extend(pathspecs);
+        let diff_result = run_git_command_at_path_with_args(&worktree_path, &diff_args);
+        if let Some(error) = diff_result.error.clone() {
+            return failed(path, error);
+        }
+        if diff_result.exit_code != Some(0) {
+            return failed(
+                path,
+                first_non_empty_line(&diff_result.stderr)
+                    .unwrap_or_else(|| "git diff failed".to_string()),
+            );
+        }
+
+        let mut file = parse_unified_diff(&diff_result.stdout)
+            .into_iter()
+            .find(|file| {
+                file.file_path == file_path || file.old_path.as_deref() == Some(file_path.as_str())
+            });
+
+        // Untracked files only have an unstaged side, diffed like `git_diff`
+        // does against an empty file.
+        if file.is_none() && !staged {
+            let untracked = run_git_command_at_path(
+                &worktree_path,
+                &["ls-files", "--others", "--exclude-standard", "--", &file_path],
+            );
+            if untracked.exit_code == Some(0) && !untracked.stdout.trim().is_empty() {
+                let no_index_args = [
+                    "diff".to_string(),
+                    "--no-color".to_string(),
+                    "--no-ext-diff".to_string(),
+                    "--no-index".to_string(),
+                    format!("--unified={context_lines}"),
+                    "/dev/null".to_string(),
+                    file_path.clone(),
+                ];
+                let untracked_diff =
+                    run_git_command_at_path_with_args(&worktree_path, &no_index_args);
+                let mut untracked_file = parse_unified_diff(&untracked_diff.stdout)
+                    .into_iter()
+                    .next()
+                    .unwrap_or_else(|| GitDiffFile {
+                        file_path: file_path.clone(),
+                        old_path: None,
+                        status: "untracked".to_string(),
+                        additions: 0,
+                        deletions: 0,
+                        binary: false,
+                        hunks: Vec::new(),
+                    });
+                untracked_file.file_path = file_path.clone();
+                untracked_file.old_path = None;
+                untracked_file.status = "untracked".to_string();
+                file = Some(untracked_file);
+            }
+        }
+
+        GitDiffFileResponse {
+            request_id: request_id.clone(),
+            ok: true,
+            path,
+            staged,
+            file,
+            error: None,
+        }
+    })
+}
+
+/// `git diff` arguments shared by hunk listing and hunk staging, so the
+/// hunks a client picked are found again byte for byte. Prefixes are pinned
+/// because `diff.noprefix` would otherwise break `git apply`.
+fn hunk_diff_args(staged: bool) -> Vec<&'static str> {
+    let mut args = vec![
+        "diff",
+        "--no-color",
+        "--no-ext-diff",
+        "--src-prefix=a/",
+        "--dst-prefix=b/",
+        "--unified=0",
+    ];
+    if staged {
+        args.push("--cached");
+    }
+    args
+}
+
+#[tauri::command(async)]
+fn git_diff_hunks(payload: GitDiffHunksPayload) -> GitDiffHunksResponse {
+    handle_command("git_diff_hunks", |context| {
+        let request_id = context.request_id();
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => {
+                return GitDiffHunksResponse {
+                    request_id,
+                    ok: false,
+                    path: None,
+                    staged: payload.staged,
+                    files: Vec::new(),
+                    error: Some(error),
+                }
+            }
+        };
+
+        let result = run_git_command_at_path(&worktree_path, &hunk_diff_args(payload.staged));
+        let error = result.error.clone().or_else(|| {
+            (result.exit_code != Some(0)).then(|| {
+                first_non_empty_line(&result.stderr)
+                    .unwrap_or_else(|| "git diff --unified=0 failed".to_string())
+            })
+        });
+        if let Some(error) = error {
+            return GitDiffHunksResponse {
+                request_id,
+                ok: false,
+                path: Some(worktree_path.display().to_string()),
+                staged: payload.staged,
+                files: Vec::new(),
+                error: Some(error),
+            };
+        }
+
+        GitDiffHunksResponse {
+            request_id,
+            ok: true,
+            path: Some(worktree_path.display().to_string()),
+            staged: payload.staged,
+            files: parse_unified_diff(&result.stdout),
+            error: None,
+        }
+    })
+}
+
+/// Re-reads the diff the hunks were picked from and applies just those hunks
+/// to the index: forward for unstaged hunks, reversed for staged ones.
+fn apply_selected_hunks(
+    command: &'static str,
+    payload: GitHunksPayload,
+    unstage: bool,
+) -> GitCommandResponse {
+    handle_command(command, |context| {
+        context.capture_subprocess_transcripts(payload.debug);
+        let request_id = context.request_id();
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => {
+                return GitCommandResponse {
+                    request_id,
+                    ok: false,
+                    path: None,
+                    exit_code: None,
+                    output_snippet: None,
+                    error: Some(error),
+                    debug_transcripts: None,
+                    retries: None,
+                }
+            }
+        };
+        let failed = |result: Option<&CommandResult>, error: String| GitCommandResponse {
+            request_id: request_id.clone(),
+            ok: false,
+            path: Some(worktree_path.display().to_string()),
+            exit_code: result.and_then(|result| result.exit_code),
+            output_snippet: result.and_then(command_output_snippet),
+            error: Some(error),
+            debug_transcripts: None,
+            retries: None,
+        };
+
+        let diff_result = run_git_command_at_path(&worktree_path, &hunk_diff_args(unstage));
+        if let Some(error) = diff_result.error.clone() {
+            return failed(Some(&diff_result), error);
+        }
+        if diff_result.exit_code != Some(0) {
+            return failed(
+                Some(&diff_result),
+                first_non_empty_line(&diff_result.stderr)
+                    .unwrap_or_else(|| "git diff --unified=0 failed".to_string()),
+            );
+        }
+
+        let selected = payload
+            .hunks
+            .iter()
+            .map(|hunk| git::DiffHunkRef {
+                file_path: hunk.file_path.clone(),
+                old_start: hunk.old_start,
+                old_lines: hunk.old_lines,
+                new_start: hunk.new_start,
+                new_lines: hunk.new_lines,
+            })
+            .collect::<Vec<_>>();
+        let patch = match git::select_diff_hunks(&diff_result.stdout, &selected) {
+            Ok(patch) => patch,
+            Err(error) => return failed(None, error),
+        };
+
+        let mut apply_args = vec!["apply", "--cached", "--unidiff-zero", "--whitespace=nowarn"];
+        if unstage {
+            apply_args.push("--reverse");
+        }
+        apply_args.push("-");
+        let result = run_git_command_at_path_with_stdin(&worktree_path, &apply_args, &patch);
+        if let Some(error) = result.error.clone() {
+            return failed(Some(&result), error);
+        }
+
+        let ok = result.exit_code == Some(0);
+        GitCommandResponse {
+            request_id,
+            ok,
+            path: Some(worktree_path.display().to_string()),
+            exit_code: result.exit_code,
+            output_snippet: command_output_snippet(&result),
+            error: if ok {
+                None
+            } else {
+                Some(
+                    first_non_empty_line(&result.stderr)
+                        .or_else(|| first_non_empty_line(&result.stdout))
+                        .unwrap_or_else(|| "git apply --cached failed".to_string()),
+                )
+            },
+            debug_transcripts: None,
+            retries: None,
+        }
+    })
+}
+
+#[tauri::command(async)]
+fn git_stage_hunk(payload: GitHunksPayload) -> GitCommandResponse {
+    apply_selected_hunks("git_stage_hunk", payload, false)
+}
+
+#[tauri::command(async)]
+fn git_unstage_hunk(payload: GitHunksPayload) -> GitCommandResponse {
+    apply_selected_hunks("git_unstage_hunk", payload, true)
+}
+
+#[tauri::command(async)]
+fn git_add(payload: GitPathPayload) -> GitCommandResponse {
+    handle_command("git_add", |context| {
+        context.capture_subprocess_transcripts(payload.debug);
+        let request_id = context.request_id();
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => {
+                return GitCommandResponse {
+                    request_id,
+                    ok: false,
+                    path: None,
+                    exit_code: None,
+                    output_snippet: None,
+                    error: Some(error),
+                    debug_transcripts: None,
+                    retries: None,
+                }
+            }
+        };
+
+        let result = run_git_command_at_path(&worktree_path, &["add", "-A"]);
+        if let Some(error) = result.error.clone() {
+            return GitCommandResponse {
+                request_id,
+                ok: false,
+                path: Some(worktree_path.display().to_string()),
+                exit_code: result.exit_code,
+                output_snippet: command_output_snippet(&result),
+                error: Some(error),
+                debug_transcripts: None,
+                retries: None,
+            };
+        }
+
+        let ok = result.exit_code == Some(0);
+        GitCommandResponse {
+            request_id,
+            ok,
+            path: Some(worktree_path.display().to_string()),
+            exit_code: result.exit_code,
+            output_snippet: command_output_snippet(&result),
+            error: if ok {
+                None
+            } else {
+                Some(
+                    first_non_empty_line(&result.stderr)
+                        .or_else(|| first_non_empty_line(&result.stdout))
+                        .unwrap_or_else(|| "git add -A failed".to_string()),
+                )
+            },
+            debug_transcripts: None,
+            retries: None,
+        }
+    })
+}
+
+/// `git rev-parse --git-path <name>`: the path as git prints it (for git and
+/// hook arguments) and where the app can reach it.
+fn git_path_at(worktree_path: &Path, name: &str) -> Result<(String, PathBuf), String> {
+    let result = run_git_command_at_path(worktree_path, &["rev-parse", "--git-path", name]);
+    if let Some(error) = result.error.clone() {
+        return Err(error);
+    }
+    let git_path = first_non_empty_line(&result.stdout)
+        .filter(|_| result.exit_code == Some(0))
+        .ok_or_else(|| {
+            first_non_empty_line(&result.stderr)
+                .unwrap_or_else(|| "git rev-parse --git-path failed".to_string())
+        })?;
+    let host_path = if Path::new(&git_path).is_relative() {
+        worktree_path.join(&git_path)
+    } else {
+        host_path_from_git_output(worktree_path, &git_path)
+    };
+    Ok((git_path, host_path))
+}
+
+/// Installed hooks that `git commit --no-verify` would skip, and the tool
+/// that manages them. `core.hooksPath` is honored through `--git-path`.
+fn installed_commit_hooks(
+    worktree_path: &Path,
+) -> Result<(Vec<&'static str>, Option<&'static str>), String> {
+    let (_, hooks_dir) = git_path_at(worktree_path, "hooks")?;
+    let hooks = git::VERIFY_COMMIT_HOOKS
+        .into_iter()
+        .filter(|hook| is_attempt_ready_executable(&hooks_dir.join(hook)))
+        .collect();
+    Ok((hooks, git::detect_hook_manager(worktree_path, &hooks_dir)))
+}
+
+fn hook_output_tail(result: &CommandResult) -> String {
+    let output = [result.stdout.trim_end(), result.stderr.trim_end()]
+        .into_iter()
+        .filter(|stream| !stream.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let lines = output.lines().collect::<Vec<_>>();
+    lines[lines.len().saturating_sub(GIT_HOOK_OUTPUT_MAX_LINES)..].join("\n")
+}
+
+/// Runs `hook` through `git hook run`, or `None` when git is too old for it.
+fn run_commit_hook(worktree_path: &Path, hook: &str, hook_args: &[&str]) -> Option<GitHookRun> {
+    let started = Instant::now();
+    let mut args = vec!["hook", "run", "--ignore-missing", hook];
+    if !hook_args.is_empty() {
+        args.push("--");
+        args.extend_from_slice(hook_args);
+    }
+    let result = run_git_command_at_path(worktree_path, &args);
+    if git::is_hook_run_unsupported(&result.stderr) {
+        return None;
+    }
+
+    Some(GitHookRun {
+        hook: hook.to_string(),
+        ok: result.error.is_none() && result.exit_code == Some(0),
         exit_code: result.exit_code,
-        output_snippet: command_output_snippet(&result),
-        error: if ok {
+        duration_ms: started.elapsed().as_millis() as u64,
+        output: result
+            .error
+            .clone()
+            .unwrap_or_else(|| hook_output_tail(&result)),
+    })
+}
+
+enum CommitHooksOutcome {
+    /// git cannot run hooks on its own (before 2.36); `git commit` runs them.
+    Unsupported,
+    Rejected(Vec<GitHookRun>),
+    /// Every hook passed; commit with `--no-verify` and this message, which a
+    /// `commit-msg` hook may have rewritten.
+    Passed(Vec<GitHookRun>, String),
+}
+
+/// Runs the commit hooks ahead of `git commit` so each one is timed and its
+/// output captured separately.
+fn run_commit_verify_hooks(
+    worktree_path: &Path,
+    hooks: &[&str],
+    message: &str,
+) -> Result<CommitHooksOutcome, String> {
+    let mut runs = Vec::new();
+    let mut message = message.to_string();
+    for hook in hooks {
+        let run = if *hook == "commit-msg" {
+            let (git_path, host_path) = git_path_at(worktree_path, GROOVE_COMMIT_MESSAGE_FILE)?;
+            fs::write(&host_path, &message).map_err(|error| {
+                format!("Failed to write the message for the commit-msg hook: {error}")
+            })?;
+            let run = run_commit_hook(worktree_path, hook, &[&git_path]);
+            if let Ok(edited) = fs::read_to_string(&host_path) {
+                message = edited;
+            }
+            let _ = fs::remove_file(&host_path);
+            run
+        } else {
+            run_commit_hook(worktree_path, hook, &[])
+        };
+
+        let Some(run) = run else {
+            return Ok(CommitHooksOutcome::Unsupported);
+        };
+        let ok = run.ok;
+        runs.push(run);
+        if !ok {
+            return Ok(CommitHooksOutcome::Rejected(runs));
+        }
+    }
+    Ok(CommitHooksOutcome::Passed(runs, message))
+}
+
+#[tauri::command(async)]
+fn git_lint_commit_message(payload: GitLintCommitMessagePayload) -> GitLintCommitMessageResponse {
+    handle_command("git_lint_commit_message", |context| {
+        let request_id = context.request_id();
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => {
+                return GitLintCommitMessageResponse {
+                    request_id,
+                    ok: false,
+                    enabled: false,
+                    issues: Vec::new(),
+                    error: Some(error),
+                }
+            }
+        };
+
+        let rules =
+            workspace_meta_field_for_path::<WorkspaceCommitLintRules>(&worktree_path, "commitLint");
+        GitLintCommitMessageResponse {
+            request_id,
+            ok: true,
+            enabled: rules.is_some(),
+            issues: rules
+                .map(|rules| lint_commit_message_with_rules(&payload.message, &rules))
+                .unwrap_or_default(),
+            error: None,
+        }
+    })
+}
+
+fn workspace_requires_sign_off(worktree_path: &Path) -> bool {
+    workspace_meta_field_for_path::<bool>(worktree_path, "requireSignOff").unwrap_or(false)
+}
+
+/// Where DCO enforcement for `worktree_path` comes from, if anywhere.
+fn dco_requirement_source(worktree_path: &Path) -> Option<&'static str> {
+    if workspace_requires_sign_off(worktree_path) {
+        Some("workspace")
+    } else if worktree_path.join(DCO_CONFIG_FILE).is_file() {
+        Some("repository")
+    } else {
+        None
+    }
+}
+
+/// Commits `git push` would send: those after the upstream, or every commit
+/// no remote has yet when the branch has no upstream.
+fn unpushed_dco_commits(worktree_path: &Path) -> Result<Vec<git::DcoCommit>, String> {
+    let has_upstream = run_git_command_at_path(
+        worktree_path,
+        &["rev-parse", "--verify", "--quiet", "@{upstream}"],
+    )
+    .exit_code
+        == Some(0);
+    let mut args = vec![
+        "log",
+        "--no-merges",
+        "-n",
+        DCO_PREFLIGHT_MAX_COMMITS,
+        git::DCO_LOG_FORMAT,
+    ];
+    if has_upstream {
+        args.push("@{upstream}..HEAD");
+    } else {
+        args.extend(["HEAD", "--not", "--remotes"]);
+    }
+
+    let result = run_git_command_at_path(worktree_path, &args);
+    if let Some(error) = result.error.clone() {
+        return Err(error);
+    }
+    if result.exit_code != Some(0) {
+        return Err(
+            first_non_empty_line(&result.stderr).unwrap_or_else(|| "git log failed".to_string())
+        );
+    }
+    Ok(git::parse_dco_log(&result.stdout))
+}
+
+#[tauri::command(async)]
+fn git_push_preflight(payload: GitPathPayload) -> GitPushPreflightResponse {
+    handle_command("git_push_preflight", |context| {
+        let request_id = context.request_id();
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => {
+                return GitPushPreflightResponse {
+                    request_id,
+                    ok: false,
+                    path: None,
+                    passed: false,
+                    dco_required: false,
+                    dco_source: None,
+                    checked_commits: 0,
+                    unsigned_commits: Vec::new(),
+                    error: Some(error),
+                }
+            }
+        };
+        let path = Some(worktree_path.display().to_string());
+
+        let Some(dco_source) = dco_requirement_source(&worktree_path) else {
+            return GitPushPreflightResponse {
+                request_id,
+                ok: true,
+                path,
+                passed: true,
+                dco_required: false,
+                dco_source: None,
+                checked_commits: 0,
+                unsigned_commits: Vec::new(),
+                error: None,
+            };
+        };
+
+        match unpushed_dco_commits(&worktree_path) {
+            Ok(commits) => {
+                let checked_commits = commits.len();
+                let unsigned_commits = commits
+                    .into_iter()
+                    .filter(|commit| !commit.signed_off)
+                    .map(|commit| GitUnsignedCommit {
+                        sha: commit.sha,
+                        subject: commit.subject,
+                        author: commit.author,
+                    })
+                    .collect::<Vec<_>>();
+                GitPushPreflightResponse {
+                    request_id,
+                    ok: true,
+                    path,
+                    passed: unsigned_commits.is_empty(),
+                    dco_required: true,
+                    dco_source: Some(dco_source.to_string()),
+                    checked_commits,
+                    unsigned_commits,
+                    error: None,
+                }
+            }
+            Err(error) => GitPushPreflightResponse {
+                request_id,
+                ok: false,
+                path,
+                passed: false,
+                dco_required: true,
+                dco_source: Some(dco_source.to_string()),
+                checked_commits: 0,
+                unsigned_commits: Vec::new(),
+                error: Some(error),
+            },
+        }
+    })
+}
+
+#[tauri::command(async)]
+fn git_commit_hooks(payload: GitPathPayload) -> GitCommitHooksResponse {
+    handle_command("git_commit_hooks", |context| {
+        let request_id = context.request_id();
+        let detected = validate_git_worktree_path(&payload.path)
+            .and_then(|worktree_path| installed_commit_hooks(&worktree_path));
+        match detected {
+            Ok((hooks, hook_manager)) => GitCommitHooksResponse {
+                request_id,
+                ok: true,
+                hooks: hooks.into_iter().map(str::to_string).collect(),
+                hook_manager: hook_manager.map(str::to_string),
+                error: None,
+            },
+            Err(error) => GitCommitHooksResponse {
+                request_id,
+                ok: false,
+                hooks: Vec::new(),
+                hook_manager: None,
+                error: Some(error),
+            },
+        }
+    })
+}
+
+#[tauri::command(async)]
+fn git_commit(app: AppHandle, payload: GitCommitPayload) -> GitCommitResponse {
+    let response = handle_command("git_commit", |context| {
+        let request_id = context.request_id();
+        let started = Instant::now();
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => {
+                return GitCommitResponse {
+                    request_id,
+                    ok: false,
+                    path: None,
+                    exit_code: None,
+                    output_snippet: None,
+                    error: Some(error),
+                    duration_ms: started.elapsed().as_millis() as u64,
+                    hook_manager: None,
+                    hooks: Vec::new(),
+                    failed_hook: None,
+                }
+            }
+        };
+
+        let commit_template = workspace_meta_field_for_path::<WorkspaceCommitTemplate>(
+            &worktree_path,
+            "commitTemplate",
+        );
+        let branch = commit_template
+            .as_ref()
+            .and_then(|_| current_branch_at(&worktree_path));
+        let worktree = worktree_path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let message = render_commit_message(
+            commit_template.as_ref(),
+            payload.message.as_deref(),
+            branch.as_deref(),
+            &worktree,
+        );
+
+        // Detection failures leave the hooks to `git commit` itself.
+        let (installed_hooks, hook_manager) =
+            installed_commit_hooks(&worktree_path).unwrap_or_default();
+        let hook_manager = hook_manager.map(str::to_string);
+        let commit_response =
+            |ok: bool,
+             result: Option<&CommandResult>,
+             error: Option<String>,
+             hooks: Vec<GitHookRun>,
+             failed_hook: Option<String>| GitCommitResponse {
+                request_id: request_id.clone(),
+                ok,
+                path: Some(worktree_path.display().to_string()),
+                exit_code: result.and_then(|result| result.exit_code),
+                output_snippet: result.and_then(command_output_snippet),
+                error,
+                duration_ms: started.elapsed().as_millis() as u64,
+                hook_manager: hook_manager.clone(),
+                hooks,
+                failed_hook,
+            };
+
+        let (hooks, message, skip_hooks) = if payload.no_verify || installed_hooks.is_empty() {
+            (Vec::new(), message, payload.no_verify)
+        } else {
+            match run_commit_verify_hooks(&worktree_path, &installed_hooks, &message) {
+                Ok(CommitHooksOutcome::Unsupported) => (Vec::new(), message, false),
+                Ok(CommitHooksOutcome::Passed(hooks, message)) => (hooks, message, true),
+                Ok(CommitHooksOutcome::Rejected(hooks)) => {
+                    let failed = hooks.last().map(|run| run.hook.clone()).unwrap_or_default();
+                    let detail = hooks
+                        .last()
+                        .and_then(|run| first_non_empty_line(&run.output))
+                        .map(|line| format!(": {line}"))
+                        .unwrap_or_default();
+                    return commit_response(
+                        false,
+                        None,
+                        Some(format!("{failed} hook rejected the commit{detail}")),
+                        hooks,
+                        Some(failed),
+                    );
+                }
+                Err(error) => return commit_response(false, None, Some(error), Vec::new(), None),
+            }
+        };
+
+        let mut args = vec!["commit", "-m", message.as_str()];
+        if skip_hooks {
+            args.push("--no-verify");
+        }
+        if workspace_requires_sign_off(&worktree_path) {
+            args.push("--signoff");
+        }
+        let result = run_git_command_at_path(&worktree_path, &args);
+        if let Some(error) = result.error.clone() {
+            return commit_response(false, Some(&result), Some(error), hooks, None);
+        }
+
+        let ok = result.exit_code == Some(0);
+        let error = if ok {
             None
         } else {
             Some(
@@ -1093,18 +2386,23 @@ fn git_commit(payload: GitCommitPayload) -> GitCommandResponse {
                     .or_else(|| first_non_empty_line(&result.stdout))
                     .unwrap_or_else(|| "git commit failed".to_string()),
             )
-        },
-    }
+        };
+        commit_response(ok, Some(&result), error, hooks, None)
+    });
+    record_action_history(&app, "git_commit", &payload.path, &payload, &response);
+    response
 }
 
+/// Parses `git diff` output into files and hunks. Header lines (modes,
+/// renames, binary markers) are only read before a file's first hunk, so a
+/// removed line that starts with `--` is not taken for a `---` header.
 fn parse_unified_diff(diff_text: &str) -> Vec<GitDiffFile> {
     let mut files: Vec<GitDiffFile> = Vec::new();
     let mut current_file: Option<GitDiffFile> = None;
     let mut current_hunk: Option<GitDiffHunk> = None;
-    let mut pending_old_path: Option<String> = None;
-    let mut pending_new_path: Option<String> = None;
-    let mut pending_status: Option<String> = None;
-    let mut pending_binary = false;
+    // Next old/new line numbers inside the current hunk.
+    let mut old_line = 0u32;
+    let mut new_line = 0u32;
 
     let flush_hunk = |file: &mut GitDiffFile, hunk: &mut Option<GitDiffHunk>| {
         if let Some(h) = hunk.take() {
@@ -1142,62 +2440,42 @@ fn parse_unified_diff(diff_text: &str) -> Vec<GitDiffFile> {
                 binary: false,
                 hunks: Vec::new(),
             });
-            pending_old_path = None;
-            pending_new_path = None;
-            pending_status = None;
-            pending_binary = false;
             continue;
         }
 
-        if line.starts_with("new file mode") {
-            pending_status = Some("added".to_string());
+        let Some(file) = current_file.as_mut() else {
             continue;
-        }
-        if line.starts_with("deleted file mode") {
-            pending_status = Some("deleted".to_string());
-            continue;
-        }
-        if line.starts_with("rename from ") {
-            pending_old_path = Some(line.trim_start_matches("rename from ").to_string());
-            pending_status = Some("renamed".to_string());
-            continue;
-        }
-        if line.starts_with("rename to ") {
-            pending_new_path = Some(line.trim_start_matches("rename to ").to_string());
-            continue;
-        }
-        if line.starts_with("Binary files ") || line.starts_with("GIT binary patch") {
-            pending_binary = true;
-            continue;
-        }
-        if line.starts_with("--- ") {
-            if let Some(file) = current_file.as_mut() {
-                if let Some(status) = pending_status.take() {
-                    file.status = status;
-                }
-                if let Some(old_path) = pending_old_path.take() {
-                    file.old_path = Some(old_path);
-                }
-                if pending_binary {
-                    file.binary = true;
-                    pending_binary = false;
-                }
+        };
+
+        if current_hunk.is_none() && file.hunks.is_empty() {
+            if line.starts_with("new file mode") {
+                file.status = "added".to_string();
+                continue;
             }
-            continue;
-        }
-        if line.starts_with("+++ ") {
-            if let Some(file) = current_file.as_mut() {
-                if let Some(new_path) = pending_new_path.take() {
-                    file.file_path = new_path;
-                }
+            if line.starts_with("deleted file mode") {
+                file.status = "deleted".to_string();
+                continue;
+            }
+            if let Some(old_path) = line.strip_prefix("rename from ") {
+                file.old_path = Some(old_path.to_string());
+                file.status = "renamed".to_string();
+                continue;
+            }
+            if let Some(new_path) = line.strip_prefix("rename to ") {
+                file.file_path = new_path.to_string();
+                continue;
+            }
+            if line.starts_with("Binary files ") || line.starts_with("GIT binary patch") {
+                file.binary = true;
+                continue;
+            }
+            if line.starts_with("--- ") || line.starts_with("+++ ") {
+                continue;
             }
-            continue;
         }
 
         if let Some(rest) = line.strip_prefix("@@") {
-            if let Some(file) = current_file.as_mut() {
-                flush_hunk(file, &mut current_hunk);
-            }
+            flush_hunk(file, &mut current_hunk);
             let end = rest.find("@@").unwrap_or(rest.len());
             let header_inner = &rest[..end];
             let header = format!("@@{}@@", header_inner);
@@ -1216,6 +2494,8 @@ fn parse_unified_diff(diff_text: &str) -> Vec<GitDiffFile> {
                     new_lines = lines;
                 }
             }
+            old_line = old_start;
+            new_line = new_start;
             current_hunk = Some(GitDiffHunk {
                 header,
                 old_start,
@@ -1227,32 +2507,32 @@ fn parse_unified_diff(diff_text: &str) -> Vec<GitDiffFile> {
             continue;
         }
 
-        if current_hunk.is_some() {
-            let (kind, content) = if let Some(rest) = line.strip_prefix('+') {
-                ("add", rest.to_string())
+        let Some(hunk) = current_hunk.as_mut() else {
+            continue;
+        };
+        let (kind, content, old_line_number, new_line_number) =
+            if let Some(rest) = line.strip_prefix('+') {
+                new_line += 1;
+                file.additions += 1;
+                ("add", rest, None, Some(new_line - 1))
             } else if let Some(rest) = line.strip_prefix('-') {
-                ("remove", rest.to_string())
+                old_line += 1;
+                file.deletions += 1;
+                ("remove", rest, Some(old_line - 1), None)
             } else if let Some(rest) = line.strip_prefix(' ') {
-                ("context", rest.to_string())
-            } else if line.starts_with('\\') {
-                continue;
+                old_line += 1;
+                new_line += 1;
+                ("context", rest, Some(old_line - 1), Some(new_line - 1))
             } else {
+                // `\ No newline at end of file` and the trailing empty line.
                 continue;
             };
-            if let Some(file) = current_file.as_mut() {
-                if kind == "add" {
-                    file.additions += 1;
-                } else if kind == "remove" {
-                    file.deletions += 1;
-                }
-            }
-            if let Some(hunk) = current_hunk.as_mut() {
-                hunk.lines.push(GitDiffLine {
-                    kind: kind.to_string(),
-                    content,
-                });
-            }
-        }
+        hunk.lines.push(GitDiffLine {
+            kind: kind.to_string(),
+            content: content.to_string(),
+            old_line_number,
+            new_line_number,
+        });
     }
 
     flush_file(&mut files, &mut current_file, &mut current_hunk);
@@ -1270,130 +2550,257 @@ fn parse_hunk_range(value: &str) -> (u32, u32) {
     }
 }
 
-#[tauri::command]
+#[tauri::command(async)]
 fn git_diff(payload: GitPathPayload) -> GitDiffResponse {
-    let request_id = request_id();
-    let worktree_path = match validate_git_worktree_path(&payload.path) {
-        Ok(path) => path,
-        Err(error) => {
+    handle_command("git_diff", |context| {
+        let request_id = context.request_id();
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => {
+                return GitDiffResponse {
+                    request_id,
+                    ok: false,
+                    path: None,
+                    files: Vec::new(),
+                    error: Some(error),
+                }
+            }
+        };
+
+        let diff_result = run_git_command_at_path(
+            &worktree_path,
+            &["diff", "HEAD", "--no-color", "--unified=3"],
+        );
+
+        if let Some(error) = diff_result.error.clone() {
             return GitDiffResponse {
                 request_id,
                 ok: false,
-                path: None,
+                path: Some(worktree_path.display().to_string()),
                 files: Vec::new(),
                 error: Some(error),
-            }
+            };
         }
-    };
 
-    let diff_result = run_git_command_at_path(
-        &worktree_path,
-        &["diff", "HEAD", "--no-color", "--unified=3"],
-    );
+        let mut files = if diff_result.exit_code == Some(0) || diff_result.exit_code == Some(1) {
+            parse_unified_diff(&diff_result.stdout)
+        } else {
+            Vec::new()
+        };
+
+        let untracked_result = run_git_command_at_path(
+            &worktree_path,
+            &["ls-files", "--others", "--exclude-standard"],
+        );
+        if untracked_result.error.is_none() && untracked_result.exit_code == Some(0) {
+            for line in untracked_result.stdout.lines() {
+                let trimmed = line.trim();
+                if trimmed.is_empty() {
+                    continue;
+                }
+                let untracked_diff = run_git_command_at_path(
+                    &worktree_path,
+                    &[
+                        "diff",
+                        "--no-color",
+                        "--no-index",
+                        "--unified=3",
+                        "/dev/null",
+                        trimmed,
+                    ],
+                );
+                let parsed = parse_unified_diff(&untracked_diff.stdout);
+                if let Some(mut file) = parsed.into_iter().next() {
+                    file.file_path = trimmed.to_string();
+                    file.old_path = None;
+                    file.status = "untracked".to_string();
+                    files.push(file);
+                } else {
+                    files.push(GitDiffFile {
+                        file_path: trimmed.to_string(),
+                        old_path: None,
+                        status: "untracked".to_string(),
+                        additions: 0,
+                        deletions: 0,
+                        binary: false,
+                        hunks: Vec::new(),
+                    });
+                }
+            }
+        }
 
-    if let Some(error) = diff_result.error.clone() {
-        return GitDiffResponse {
+        GitDiffResponse {
             request_id,
-            ok: false,
+            ok: true,
             path: Some(worktree_path.display().to_string()),
-            files: Vec::new(),
-            error: Some(error),
-        };
-    }
+            files,
+            error: None,
+        }
+    })
+}
 
-    let mut files = if diff_result.exit_code == Some(0) || diff_result.exit_code == Some(1) {
-        parse_unified_diff(&diff_result.stdout)
-    } else {
-        Vec::new()
+/// Filters are passed as `--opt=value` so a value starting with `-` cannot be
+/// read as another option; the ref must look like a branch name.
+fn git_log_args(payload: &GitLogPayload, limit: usize) -> Result<Vec<String>, String> {
+    let mut args = vec![
+        "log".to_string(),
+        "--no-color".to_string(),
+        "--decorate=short".to_string(),
+        git::HISTORY_LOG_FORMAT.to_string(),
+        format!("--skip={}", payload.offset),
+        // One extra commit tells whether another page follows.
+        format!("--max-count={}", limit + 1),
+    ];
+    let filter = |value: &Option<String>| {
+        value
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
     };
-
-    let untracked_result = run_git_command_at_path(
-        &worktree_path,
-        &["ls-files", "--others", "--exclude-standard"],
-    );
-    if untracked_result.error.is_none() && untracked_result.exit_code == Some(0) {
-        for line in untracked_result.stdout.lines() {
-            let trimmed = line.trim();
-            if trimmed.is_empty() {
-                continue;
-            }
-            let untracked_diff = run_git_command_at_path(
-                &worktree_path,
-                &[
-                    "diff",
-                    "--no-color",
-                    "--no-index",
-                    "--unified=3",
-                    "/dev/null",
-                    trimmed,
-                ],
-            );
-            let parsed = parse_unified_diff(&untracked_diff.stdout);
-            if let Some(mut file) = parsed.into_iter().next() {
-                file.file_path = trimmed.to_string();
-                file.old_path = None;
-                file.status = "untracked".to_string();
-                files.push(file);
-            } else {
-                files.push(GitDiffFile {
-                    file_path: trimmed.to_string(),
-                    old_path: None,
-                    status: "untracked".to_string(),
-                    additions: 0,
-                    deletions: 0,
-                    binary: false,
-                    hunks: Vec::new(),
-                });
-            }
+    if let Some(author) = filter(&payload.author) {
+        args.push(format!("--author={author}"));
+    }
+    if let Some(since) = filter(&payload.since) {
+        args.push(format!("--since={since}"));
+    }
+    if let Some(until) = filter(&payload.until) {
+        args.push(format!("--until={until}"));
+    }
+    if let Some(branch) = filter(&payload.branch) {
+        if !is_valid_branch_token(&branch) {
+            return Err(format!("Invalid branch: {branch}"));
         }
+        args.push(branch);
     }
-
-    GitDiffResponse {
-        request_id,
-        ok: true,
-        path: Some(worktree_path.display().to_string()),
-        files,
-        error: None,
+    if let Some(file_path) = filter(&payload.file_path) {
+        if file_path.contains('\0') {
+            return Err("filePath cannot contain null bytes.".to_string());
+        }
+        args.push("--".to_string());
+        args.push(file_path);
     }
+    Ok(args)
 }
 
-#[tauri::command]
-fn open_external_url(url: String) -> ExternalUrlOpenResponse {
-    let request_id = request_id();
-    let trimmed_url = url.trim();
-
-    if trimmed_url.is_empty() {
-        return ExternalUrlOpenResponse {
-            request_id,
+#[tauri::command(async)]
+fn git_log(payload: GitLogPayload) -> GitLogResponse {
+    handle_command("git_log", |context| {
+        let request_id = context.request_id();
+        let offset = payload.offset;
+        let failed = |path: Option<String>, error: String| GitLogResponse {
+            request_id: request_id.clone(),
             ok: false,
-            error: Some("URL must not be empty.".to_string()),
+            path,
+            commits: Vec::new(),
+            offset,
+            has_more: false,
+            error: Some(error),
         };
-    }
-
-    if !trimmed_url.starts_with("http://") && !trimmed_url.starts_with("https://") {
-        return ExternalUrlOpenResponse {
-            request_id,
-            ok: false,
-            error: Some("URL must start with http:// or https://.".to_string()),
+        let worktree_path = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => path,
+            Err(error) => return failed(None, error),
         };
-    }
+        let path = Some(worktree_path.display().to_string());
+
+        let limit = payload
+            .limit
+            .unwrap_or(GIT_LOG_PAGE_DEFAULT_LIMIT)
+            .clamp(1, GIT_LOG_PAGE_MAX_LIMIT);
+        let args = match git_log_args(&payload, limit) {
+            Ok(args) => args,
+            Err(error) => return failed(path, error),
+        };
+        let result = run_git_command_at_path_with_args(&worktree_path, &args);
+        if let Some(error) = result.error.clone() {
+            return failed(path, error);
+        }
+        if result.exit_code != Some(0) {
+            // A branch without commits yet has no history to show.
+            if result.stderr.contains("does not have any commits yet") {
+                return GitLogResponse {
+                    request_id: request_id.clone(),
+                    ok: true,
+                    path,
+                    commits: Vec::new(),
+                    offset,
+                    has_more: false,
+                    error: None,
+                };
+            }
+            return failed(
+                path,
+                first_non_empty_line(&result.stderr)
+                    .unwrap_or_else(|| "git log failed".to_string()),
+            );
+        }
 
-    match open_url_in_default_browser(trimmed_url) {
-        Ok(()) => ExternalUrlOpenResponse {
-            request_id,
+        let mut commits = git::parse_history_log(&result.stdout);
+        let has_more = commits.len() > limit;
+        commits.truncate(limit);
+        GitLogResponse {
+            request_id: request_id.clone(),
             ok: true,
+            path,
+            commits: commits
+                .into_iter()
+                .map(|commit| GitLogCommit {
+                    short_hash: commit.hash.chars().take(7).collect(),
+                    hash: commit.hash,
+                    parent_hashes: commit.parents,
+                    author_name: commit.author_name,
+                    author_email: commit.author_email,
+                    authored_at: commit.authored_at,
+                    subject: commit.subject,
+                    refs: commit.refs,
+                })
+                .collect(),
+            offset,
+            has_more,
             error: None,
-        },
-        Err(error) => ExternalUrlOpenResponse {
-            request_id,
-            ok: false,
-            error: Some(error),
-        },
-    }
+        }
+    })
+}
+
+#[tauri::command(async)]
+fn open_external_url(url: String) -> ExternalUrlOpenResponse {
+    handle_command("open_external_url", |context| {
+        let request_id = context.request_id();
+        let trimmed_url = url.trim();
+
+        if trimmed_url.is_empty() {
+            return ExternalUrlOpenResponse {
+                request_id,
+                ok: false,
+                error: Some("URL must not be empty.".to_string()),
+            };
+        }
+
+        if !trimmed_url.starts_with("http://") && !trimmed_url.starts_with("https://") {
+            return ExternalUrlOpenResponse {
+                request_id,
+                ok: false,
+                error: Some("URL must start with http:// or https://.".to_string()),
+            };
+        }
+
+        match open_url_in_default_browser(trimmed_url) {
+            Ok(()) => ExternalUrlOpenResponse {
+                request_id,
+                ok: true,
+                error: None,
+            },
+            Err(error) => ExternalUrlOpenResponse {
+                request_id,
+                ok: false,
+                error: Some(error),
+            },
+        }
+    })
 }
 
 fn run_gh(args: &[&str]) -> CommandResult {
-    run_capture_command(&std::env::temp_dir(), "gh", args)
+    run_gh_in(&std::env::temp_dir(), args)
 }
 
 fn run_gh_with_stdin(args: &[&str], stdin_data: &str) -> CommandResult {
@@ -1405,24 +2812,16 @@ fn run_gh_with_stdin(args: &[&str], stdin_data: &str) -> CommandResult {
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
 
-    let mut child = match command.spawn() {
-        Ok(child) => child,
-        Err(error) => {
-            return CommandResult {
-                exit_code: None,
-                stdout: String::new(),
-                stderr: String::new(),
-                error: Some(format!("Failed to execute gh: {error}")),
-            }
+    let started_at = Instant::now();
+    let output = command.spawn().and_then(|mut child| {
+        if let Some(mut stdin) = child.stdin.take() {
+            let _ = stdin.write_all(stdin_data.as_bytes());
+            // Dropping `stdin` here closes the pipe so gh can finish reading.
         }
-    };
-
-    if let Some(mut stdin) = child.stdin.take() {
-        let _ = stdin.write_all(stdin_data.as_bytes());
-        // Dropping `stdin` here closes the pipe so gh can finish reading.
-    }
+        child.wait_with_output()
+    });
 
-    match child.wait_with_output() {
+    let result = match output {
         Ok(output) => CommandResult {
             exit_code: output.status.code(),
             stdout: String::from_utf8_lossy(&output.stdout).to_string(),
@@ -1435,7 +2834,9 @@ fn run_gh_with_stdin(args: &[&str], stdin_data: &str) -> CommandResult {
             stderr: String::new(),
             error: Some(format!("Failed to execute gh: {error}")),
         },
-    }
+    };
+    record_subprocess_transcript(&command, started_at, &result);
+    result
 }
 
 /// GitHub logins are ASCII alphanumeric plus hyphens and never start with a
@@ -1512,7 +2913,9 @@ fn gh_failure_response(request_id: String, result: CommandResult) -> GhCommandRe
         return GhCommandResponse {
             request_id,
             ok: false,
+            cancelled: None,
             error: Some(message),
+            debug_transcripts: None,
         };
     }
 
@@ -1520,7 +2923,9 @@ fn gh_failure_response(request_id: String, result: CommandResult) -> GhCommandRe
         return GhCommandResponse {
             request_id,
             ok: true,
+            cancelled: None,
             error: None,
+            debug_transcripts: None,
         };
     }
 
@@ -1531,7 +2936,9 @@ fn gh_failure_response(request_id: String, result: CommandResult) -> GhCommandRe
     GhCommandResponse {
         request_id,
         ok: false,
+        cancelled: None,
         error: Some(detail),
+        debug_transcripts: None,
     }
 }
 
@@ -1540,21 +2947,22 @@ fn gh_failure_response(request_id: String, result: CommandResult) -> GhCommandRe
 // would block the UI thread. This mirrors `assistant_validate_mcp` and friends.
 #[tauri::command]
 async fn gh_auth_status() -> GhAuthStatusResponse {
-    let request_id = request_id();
-    let fallback_request_id = request_id.clone();
-
-    match tauri::async_runtime::spawn_blocking(move || gh_auth_status_blocking(request_id)).await {
-        Ok(response) => response,
-        Err(error) => GhAuthStatusResponse {
-            request_id: fallback_request_id,
+    handle_blocking_command(
+        "gh_auth_status",
+        move |context| gh_auth_status_blocking(context.request_id()),
+        |request_id, error| GhAuthStatusResponse {
+            request_id,
             ok: false,
             installed: false,
             logged_in: false,
             active_account: None,
             accounts: Vec::new(),
-            error: Some(format!("Failed to run gh auth status worker thread: {error}")),
+            cancelled: None,
+            error: Some(error),
+            retries: None,
         },
-    }
+    )
+    .await
 }
 
 fn gh_auth_status_blocking(request_id: String) -> GhAuthStatusResponse {
@@ -1567,7 +2975,9 @@ fn gh_auth_status_blocking(request_id: String) -> GhAuthStatusResponse {
             logged_in: false,
             active_account: None,
             accounts: Vec::new(),
+            cancelled: None,
             error: None,
+            retries: None,
         };
     }
 
@@ -1586,25 +2996,29 @@ fn gh_auth_status_blocking(request_id: String) -> GhAuthStatusResponse {
         logged_in: !accounts.is_empty(),
         active_account,
         accounts,
+        cancelled: None,
         error: None,
+        retries: None,
     }
 }
 
 #[tauri::command]
 async fn gh_auth_login(payload: GhLoginPayload) -> GhCommandResponse {
-    let request_id = request_id();
-    let fallback_request_id = request_id.clone();
-
-    match tauri::async_runtime::spawn_blocking(move || gh_auth_login_blocking(request_id, payload))
-        .await
-    {
-        Ok(response) => response,
-        Err(error) => GhCommandResponse {
-            request_id: fallback_request_id,
+    handle_blocking_command(
+        "gh_auth_login",
+        move |context| {
+            context.capture_subprocess_transcripts(payload.debug);
+            gh_auth_login_blocking(context.request_id(), payload)
+        },
+        |request_id, error| GhCommandResponse {
+            request_id,
             ok: false,
-            error: Some(format!("Failed to run gh auth login worker thread: {error}")),
+            cancelled: None,
+            error: Some(error),
+            debug_transcripts: None,
         },
-    }
+    )
+    .await
 }
 
 fn gh_auth_login_blocking(request_id: String, payload: GhLoginPayload) -> GhCommandResponse {
@@ -1614,7 +3028,9 @@ fn gh_auth_login_blocking(request_id: String, payload: GhLoginPayload) -> GhComm
         return GhCommandResponse {
             request_id,
             ok: false,
+            cancelled: None,
             error: Some("A GitHub token is required.".to_string()),
+            debug_transcripts: None,
         };
     }
 
@@ -1625,38 +3041,48 @@ fn gh_auth_login_blocking(request_id: String, payload: GhLoginPayload) -> GhComm
     gh_failure_response(request_id, result)
 }
 
-#[tauri::command]
+#[tauri::command(async)]
 fn gh_auth_switch(payload: GhSwitchPayload) -> GhCommandResponse {
-    let request_id = request_id();
-    let user = payload.user.trim();
+    handle_command("gh_auth_switch", |context| {
+        context.capture_subprocess_transcripts(payload.debug);
+        let request_id = context.request_id();
+        let user = payload.user.trim();
 
-    if !is_valid_gh_login(user) {
-        return GhCommandResponse {
-            request_id,
-            ok: false,
-            error: Some("A valid GitHub account login is required.".to_string()),
-        };
-    }
+        if !is_valid_gh_login(user) {
+            return GhCommandResponse {
+                request_id,
+                ok: false,
+                cancelled: None,
+                error: Some("A valid GitHub account login is required.".to_string()),
+                debug_transcripts: None,
+            };
+        }
 
-    let result = run_gh(&["auth", "switch", "--hostname", "github.com", "--user", user]);
-    gh_failure_response(request_id, result)
+        let result = run_gh(&["auth", "switch", "--hostname", "github.com", "--user", user]);
+        gh_failure_response(request_id, result)
+    })
 }
 
-#[tauri::command]
+#[tauri::command(async)]
 fn gh_auth_logout(payload: GhLogoutPayload) -> GhCommandResponse {
-    let request_id = request_id();
-    let user = payload.user.trim();
+    handle_command("gh_auth_logout", |context| {
+        context.capture_subprocess_transcripts(payload.debug);
+        let request_id = context.request_id();
+        let user = payload.user.trim();
 
-    if !is_valid_gh_login(user) {
-        return GhCommandResponse {
-            request_id,
-            ok: false,
-            error: Some("A valid GitHub account login is required.".to_string()),
-        };
-    }
+        if !is_valid_gh_login(user) {
+            return GhCommandResponse {
+                request_id,
+                ok: false,
+                cancelled: None,
+                error: Some("A valid GitHub account login is required.".to_string()),
+                debug_transcripts: None,
+            };
+        }
 
-    let result = run_gh(&["auth", "logout", "--hostname", "github.com", "--user", user]);
-    gh_failure_response(request_id, result)
+        let result = run_gh(&["auth", "logout", "--hostname", "github.com", "--user", user]);
+        gh_failure_response(request_id, result)
+    })
 }
 
 /// SSH host aliases are conservative: ASCII alphanumerics plus `-`, `_`, `.`,
@@ -1795,21 +3221,23 @@ fn owner_repo_from_path(path: &str) -> (Option<String>, Option<String>) {
 /// answers `ssh -T` with "Hi <user>! ..." on a non-zero exit (no shell access).
 fn ssh_identity_probe(alias: &str) -> (Option<String>, String) {
     let target = format!("git@{alias}");
-    let result = run_capture_command_timeout(
-        &std::env::temp_dir(),
-        "ssh",
-        &[
-            "-T",
-            "-o",
-            "BatchMode=yes",
-            "-o",
-            "ConnectTimeout=6",
-            "-o",
-            "StrictHostKeyChecking=accept-new",
-            &target,
-        ],
-        Duration::from_secs(8),
-    );
+    let result = run_with_command_retries("ssh -T", true, |attempt| {
+        run_capture_command_timeout(
+            &std::env::temp_dir(),
+            "ssh",
+            &[
+                "-T",
+                "-o",
+                "BatchMode=yes",
+                "-o",
+                "ConnectTimeout=6",
+                "-o",
+                "StrictHostKeyChecking=accept-new",
+                &target,
+            ],
+            retry::progressive_timeout(Duration::from_secs(8), attempt),
+        )
+    });
 
     let combined = format!("{}\n{}", result.stdout, result.stderr);
     let combined_lower = combined.to_lowercase();
@@ -1853,22 +3281,20 @@ fn ssh_identity_probe(alias: &str) -> (Option<String>, String) {
 // in parallel so the wall-clock cost is one timeout, not one per alias.
 #[tauri::command]
 async fn gh_ssh_overview(payload: GhSshOverviewPayload) -> GhSshOverviewResponse {
-    let request_id = request_id();
-    let fallback_request_id = request_id.clone();
-
-    match tauri::async_runtime::spawn_blocking(move || gh_ssh_overview_blocking(request_id, payload))
-        .await
-    {
-        Ok(response) => response,
-        Err(error) => GhSshOverviewResponse {
-            request_id: fallback_request_id,
+    handle_blocking_command(
+        "gh_ssh_overview",
+        move |context| gh_ssh_overview_blocking(context.request_id(), payload),
+        |request_id, error| GhSshOverviewResponse {
+            request_id,
             ok: false,
             config_found: false,
             identities: Vec::new(),
             origin: None,
-            error: Some(format!("Failed to run gh ssh overview worker thread: {error}")),
+            cancelled: None,
+            error: Some(error),
         },
-    }
+    )
+    .await
 }
 
 fn gh_ssh_overview_blocking(
@@ -1943,84 +3369,100 @@ fn gh_ssh_overview_blocking(
         config_found,
         identities,
         origin,
+        cancelled: None,
         error: None,
     }
 }
 
-#[tauri::command]
+#[tauri::command(async)]
 fn gh_ssh_set_identity(payload: GhSshSetIdentityPayload) -> GhCommandResponse {
-    let request_id = request_id();
-
-    let alias = payload.alias.trim();
-    if !is_valid_ssh_host_alias(alias) {
-        return GhCommandResponse {
-            request_id,
-            ok: false,
-            error: Some("A valid SSH host alias is required.".to_string()),
-        };
-    }
+    handle_command("gh_ssh_set_identity", |context| {
+        context.capture_subprocess_transcripts(payload.debug);
+        let request_id = context.request_id();
 
-    let workspace_root = match validate_workspace_root_path(&payload.workspace_root) {
-        Ok(root) => root,
-        Err(error) => {
+        let alias = payload.alias.trim();
+        if !is_valid_ssh_host_alias(alias) {
             return GhCommandResponse {
                 request_id,
                 ok: false,
-                error: Some(error),
-            }
+                cancelled: None,
+                error: Some("A valid SSH host alias is required.".to_string()),
+                debug_transcripts: None,
+            };
         }
-    };
-
-    // Only allow aliases the user actually has configured for github.com.
-    let config_known = dirs_home()
-        .map(|home| home.join(".ssh").join("config"))
-        .filter(|path| path.exists())
-        .and_then(|path| std::fs::read_to_string(path).ok())
-        .map(|content| parse_ssh_config_github_hosts(&content))
-        .map(|hosts| hosts.iter().any(|(host_alias, _, _)| host_alias == alias))
-        .unwrap_or(false);
 
-    if !config_known {
-        return GhCommandResponse {
-            request_id,
-            ok: false,
-            error: Some(format!(
-                "\"{alias}\" is not a github.com host alias in ~/.ssh/config."
-            )),
+        let workspace_root = match validate_workspace_root_path(&payload.workspace_root) {
+            Ok(root) => root,
+            Err(error) => {
+                return GhCommandResponse {
+                    request_id,
+                    ok: false,
+                    cancelled: None,
+                    error: Some(error),
+                    debug_transcripts: None,
+                }
+            }
         };
-    }
 
-    let Some((remote_name, url)) = resolve_remote_url_with_fallback(&workspace_root) else {
-        return GhCommandResponse {
-            request_id,
-            ok: false,
-            error: Some("This repository has no remote to update.".to_string()),
-        };
-    };
+        // Only allow aliases the user actually has configured for github.com.
+        let config_known = dirs_home()
+            .map(|home| home.join(".ssh").join("config"))
+            .filter(|path| path.exists())
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .map(|content| parse_ssh_config_github_hosts(&content))
+            .map(|hosts| hosts.iter().any(|(host_alias, _, _)| host_alias == alias))
+            .unwrap_or(false);
 
-    let Some((_, path)) = split_remote_url(&url) else {
-        return GhCommandResponse {
-            request_id,
-            ok: false,
-            error: Some(format!("Could not parse the current remote URL: {url}")),
-        };
-    };
+        if !config_known {
+            return GhCommandResponse {
+                request_id,
+                ok: false,
+                cancelled: None,
+                error: Some(format!(
+                    "\"{alias}\" is not a github.com host alias in ~/.ssh/config."
+                )),
+                debug_transcripts: None,
+            };
+        }
 
-    if path.is_empty() {
-        return GhCommandResponse {
-            request_id,
-            ok: false,
-            error: Some("The current remote URL has no repository path.".to_string()),
+        let Some((remote_name, url)) = resolve_remote_url_with_fallback(&workspace_root) else {
+            return GhCommandResponse {
+                request_id,
+                ok: false,
+                cancelled: None,
+                error: Some("This repository has no remote to update.".to_string()),
+                debug_transcripts: None,
+            };
         };
-    }
 
-    let new_url = format!("git@{alias}:{path}");
-    let result = run_capture_command(
-        &workspace_root,
-        "git",
-        &["remote", "set-url", &remote_name, &new_url],
-    );
-    gh_failure_response(request_id, result)
+        let Some((_, path)) = split_remote_url(&url) else {
+            return GhCommandResponse {
+                request_id,
+                ok: false,
+                cancelled: None,
+                error: Some(format!("Could not parse the current remote URL: {url}")),
+                debug_transcripts: None,
+            };
+        };
+
+        if path.is_empty() {
+            return GhCommandResponse {
+                request_id,
+                ok: false,
+                cancelled: None,
+                error: Some("The current remote URL has no repository path.".to_string()),
+                debug_transcripts: None,
+            };
+        }
+
+        let new_url = format!("git@{alias}:{path}");
+        let result = run_capture_command(
+            &workspace_root,
+            "git",
+            &["remote", "set-url", &remote_name, &new_url],
+        );
+        gh_failure_response(request_id, result)
+    })
 }
 
 // ---- GitHub pull-request commands -----------------------------------------
@@ -2029,8 +3471,13 @@ fn gh_ssh_set_identity(payload: GhSshSetIdentityPayload) -> GhCommandResponse {
 // `--json` output. Like the auth commands they run `async` + `spawn_blocking`
 // (network I/O must stay off the UI thread).
 
+/// Read-only calls are tried again, per the command retry policy, when they
+/// fail for a passing reason such as a dropped connection.
 fn run_gh_in(cwd: &Path, args: &[&str]) -> CommandResult {
-    run_capture_command(cwd, "gh", args)
+    let label = format!("gh {}", args[..args.len().min(2)].join(" "));
+    run_with_command_retries(&label, retry::gh_command_is_retryable(args), |_| {
+        run_capture_command(cwd, "gh", args)
+    })
 }
 
 /// Branch names accept `/._-` and alphanumerics; rejecting a leading `-` keeps
@@ -2082,6 +3529,19 @@ struct GhPrCommentRaw {
     created_at: Option<String>,
 }
 
+impl GhPrCommentRaw {
+    fn into_comment(self) -> GhPrComment {
+        GhPrComment {
+            author: self
+                .author
+                .map(|author| author.login)
+                .filter(|login| !login.is_empty()),
+            body: self.body,
+            created_at: normalize_optional(self.created_at),
+        }
+    }
+}
+
 #[derive(serde::Deserialize)]
 struct GhLabelRaw {
     #[serde(default)]
@@ -2123,28 +3583,266 @@ struct GhPrViewRaw {
     comments: Vec<GhPrCommentRaw>,
 }
 
+#[derive(serde::Deserialize)]
+struct GhNodesRaw<T> {
+    #[serde(default = "Vec::new")]
+    nodes: Vec<T>,
+}
+
+#[derive(serde::Deserialize)]
+struct GhReviewThreadRaw {
+    id: String,
+    #[serde(default)]
+    path: String,
+    #[serde(default)]
+    line: Option<i64>,
+    #[serde(default, rename = "isResolved")]
+    is_resolved: bool,
+    #[serde(default, rename = "isOutdated")]
+    is_outdated: bool,
+    comments: GhNodesRaw<GhPrCommentRaw>,
+}
+
+#[derive(serde::Deserialize)]
+struct GhReviewRaw {
+    #[serde(default)]
+    author: Option<GhAuthorRaw>,
+    #[serde(default)]
+    state: String,
+    #[serde(default)]
+    body: String,
+    #[serde(default, rename = "submittedAt")]
+    submitted_at: Option<String>,
+}
+
+#[derive(serde::Deserialize)]
+struct GhPrReviewsRaw {
+    #[serde(rename = "reviewThreads")]
+    review_threads: GhNodesRaw<GhReviewThreadRaw>,
+    reviews: GhNodesRaw<GhReviewRaw>,
+}
+
+/// Review threads are only exposed through GraphQL; `gh pr view --json`
+/// has the reviews but not their inline comments.
+const GH_PR_REVIEWS_QUERY: &str = "query($owner: String!, $name: String!, $number: Int!) {
+  repository(owner: $owner, name: $name) {
+    pullRequest(number: $number) {
+      reviewThreads(first: 100) {
+        nodes {
+          id path line isResolved isOutdated
+          comments(first: 100) { nodes { author { login } body createdAt } }
+        }
+      }
+      reviews(last: 100) { nodes { author { login } state body submittedAt } }
+    }
+  }
+}";
+
+/// Split `https://<host>/<owner>/<repo>/pull/<n>` into `(owner, repo, n)`.
+fn parse_pr_url(url: &str) -> Option<(String, String, i64)> {
+    let (repo_url, _) = url.split_once("/pull/")?;
+    let (_, path) = split_remote_url(repo_url)?;
+    let (owner, repo) = owner_repo_from_path(&path);
+    Some((owner?, repo?, parse_pr_number_from_url(url)?))
+}
+
+#[derive(serde::Deserialize)]
+struct GhIssueRaw {
+    number: i64,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    state: String,
+    #[serde(default)]
+    url: String,
+    #[serde(default)]
+    body: Option<String>,
+    #[serde(default)]
+    author: Option<GhAuthorRaw>,
+    #[serde(default)]
+    labels: Vec<GhLabelRaw>,
+    #[serde(default)]
+    assignees: Vec<GhAuthorRaw>,
+    #[serde(default, rename = "createdAt")]
+    created_at: Option<String>,
+    #[serde(default, rename = "updatedAt")]
+    updated_at: Option<String>,
+    #[serde(default)]
+    comments: Vec<GhPrCommentRaw>,
+}
+
+impl GhIssueRaw {
+    fn label_names(&self) -> Vec<String> {
+        self.labels
+            .iter()
+            .map(|label| label.name.clone())
+            .filter(|name| !name.is_empty())
+            .collect()
+    }
+
+    fn assignee_logins(&self) -> Vec<String> {
+        self.assignees
+            .iter()
+            .map(|assignee| assignee.login.clone())
+            .filter(|login| !login.is_empty())
+            .collect()
+    }
+}
+
+/// Runs `gh issue view` for issue `number` of the repository at `cwd`.
+fn fetch_gh_issue(cwd: &Path, number: i64, fields: &str) -> Result<GhIssueRaw, String> {
+    if number <= 0 {
+        return Err("Issue number must be a positive integer.".to_string());
+    }
+    let number = number.to_string();
+    let result = run_gh_in(cwd, &["issue", "view", &number, "--json", fields]);
+    if let Some(error) = result.error {
+        return Err(if error.contains("Failed to execute gh") {
+            "GitHub CLI (gh) is not installed or not on PATH.".to_string()
+        } else {
+            error
+        });
+    }
+    if result.exit_code != Some(0) {
+        return Err(first_non_empty_line(&result.stderr)
+            .unwrap_or_else(|| "gh issue view failed.".to_string()));
+    }
+    serde_json::from_str::<GhIssueRaw>(&result.stdout)
+        .map_err(|error| format!("Could not parse gh issue view output: {error}"))
+}
+
+/// One `statusCheckRollup` entry: a `CheckRun` (GitHub Actions and apps) or a
+/// `StatusContext` (commit statuses from external CI).
+#[derive(serde::Deserialize)]
+struct GhCheckRaw {
+    #[serde(default, rename = "__typename")]
+    kind: String,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    context: Option<String>,
+    #[serde(default)]
+    status: Option<String>,
+    #[serde(default)]
+    conclusion: Option<String>,
+    #[serde(default)]
+    state: Option<String>,
+    #[serde(default, rename = "detailsUrl")]
+    details_url: Option<String>,
+    #[serde(default, rename = "targetUrl")]
+    target_url: Option<String>,
+    #[serde(default, rename = "workflowName")]
+    workflow_name: Option<String>,
+}
+
+#[derive(serde::Deserialize)]
+struct GhPrListItemRaw {
+    #[serde(flatten)]
+    summary: GhPrSummary,
+    #[serde(default, rename = "statusCheckRollup")]
+    status_check_rollup: Vec<GhCheckRaw>,
+}
+
+#[derive(serde::Deserialize)]
+struct GhPrMergedRaw {
+    number: i64,
+    #[serde(default, rename = "mergeCommit")]
+    merge_commit: Option<GhCommitRaw>,
+    #[serde(default, rename = "headRefName")]
+    head_ref_name: String,
+    #[serde(default, rename = "isCrossRepository")]
+    is_cross_repository: bool,
+}
+
+#[derive(serde::Deserialize)]
+struct GhCommitRaw {
+    oid: String,
+}
+
+#[derive(serde::Deserialize)]
+struct GhPrChecksRaw {
+    number: i64,
+    #[serde(default, rename = "statusCheckRollup")]
+    status_check_rollup: Vec<GhCheckRaw>,
+    #[serde(default)]
+    mergeable: Option<String>,
+    #[serde(default, rename = "mergeStateStatus")]
+    merge_state_status: Option<String>,
+}
+
+impl GhCheckRaw {
+    fn into_check(self) -> GhPrCheck {
+        if self.kind == "StatusContext" {
+            let state = self.state.unwrap_or_default();
+            let pending = matches!(state.as_str(), "PENDING" | "EXPECTED" | "");
+            return GhPrCheck {
+                name: self.context.unwrap_or_default(),
+                status: if pending { "PENDING" } else { "COMPLETED" }.to_string(),
+                conclusion: (!pending).then_some(state),
+                details_url: normalize_optional(self.target_url),
+                workflow: None,
+            };
+        }
+        GhPrCheck {
+            name: self.name.unwrap_or_default(),
+            status: self.status.unwrap_or_default(),
+            conclusion: normalize_optional(self.conclusion),
+            details_url: normalize_optional(self.details_url),
+            workflow: normalize_optional(self.workflow_name),
+        }
+    }
+}
+
+fn gh_check_failed(check: &GhPrCheck) -> bool {
+    matches!(
+        check.conclusion.as_deref(),
+        Some(
+            "FAILURE" | "ERROR" | "TIMED_OUT" | "CANCELLED" | "ACTION_REQUIRED" | "STARTUP_FAILURE"
+        )
+    )
+}
+
+/// Rolls checks up the way GitHub's PR badge does: any failed check fails the
+/// PR, otherwise any unfinished one keeps it pending.
+fn checks_status(checks: &[GhPrCheck]) -> Option<&'static str> {
+    if checks.is_empty() {
+        return None;
+    }
+    if checks.iter().any(gh_check_failed) {
+        Some("failure")
+    } else if checks.iter().any(|check| check.status != "COMPLETED") {
+        Some("pending")
+    } else {
+        Some("success")
+    }
+}
+
+/// A PR number or a github.com pull-request URL, as `gh pr view` accepts.
+fn is_valid_pr_selector(selector: &str) -> bool {
+    let is_number = !selector.is_empty() && selector.chars().all(|c| c.is_ascii_digit());
+    let is_pr_url = selector.starts_with("https://") && selector.contains("/pull/");
+    is_number || is_pr_url
+}
+
 fn normalize_optional(value: Option<String>) -> Option<String> {
     value.filter(|text| !text.trim().is_empty())
 }
 
 #[tauri::command]
 async fn gh_repo_default_branch(payload: GhWorktreePayload) -> GhRepoDefaultBranchResponse {
-    let request_id = request_id();
-    let fallback_request_id = request_id.clone();
-
-    match tauri::async_runtime::spawn_blocking(move || {
-        gh_repo_default_branch_blocking(request_id, payload)
-    })
-    .await
-    {
-        Ok(response) => response,
-        Err(error) => GhRepoDefaultBranchResponse {
-            request_id: fallback_request_id,
+    handle_blocking_command(
+        "gh_repo_default_branch",
+        move |context| gh_repo_default_branch_blocking(context.request_id(), payload),
+        |request_id, error| GhRepoDefaultBranchResponse {
+            request_id,
             ok: false,
             default_branch: None,
-            error: Some(format!("Failed to run gh repo default-branch worker thread: {error}")),
+            cancelled: None,
+            error: Some(error),
+            retries: None,
         },
-    }
+    )
+    .await
 }
 
 fn gh_repo_default_branch_blocking(
@@ -2158,7 +3856,9 @@ fn gh_repo_default_branch_blocking(
                 request_id,
                 ok: false,
                 default_branch: None,
+                cancelled: None,
                 error: Some(error),
+                retries: None,
             }
         }
     };
@@ -2171,276 +3871,1341 @@ fn gh_repo_default_branch_blocking(
                     request_id,
                     ok: true,
                     default_branch: Some(branch),
+                    cancelled: None,
                     error: None,
+                    retries: None,
                 };
             }
         }
     }
 
-    // Fallback: parse the local symbolic ref for origin/HEAD.
-    let symbolic = run_git_command_at_path(
-        &worktree_path,
-        &["symbolic-ref", "refs/remotes/origin/HEAD"],
-    );
-    let default_branch = first_non_empty_line(&symbolic.stdout)
-        .and_then(|line| line.rsplit('/').next().map(|name| name.to_string()));
+    // Fallback: parse the local symbolic ref for origin/HEAD.
+    let symbolic = run_git_command_at_path(
+        &worktree_path,
+        &["symbolic-ref", "refs/remotes/origin/HEAD"],
+    );
+    let default_branch = first_non_empty_line(&symbolic.stdout)
+        .and_then(|line| line.rsplit('/').next().map(|name| name.to_string()));
+
+    GhRepoDefaultBranchResponse {
+        request_id,
+        ok: true,
+        default_branch,
+        cancelled: None,
+        error: None,
+        retries: None,
+    }
+}
+
+#[tauri::command]
+async fn gh_pr_list(payload: GhWorktreePayload) -> GhPrListResponse {
+    handle_blocking_command(
+        "gh_pr_list",
+        move |context| gh_pr_list_blocking(context.request_id(), payload),
+        |request_id, error| GhPrListResponse {
+            request_id,
+            ok: false,
+            branch: None,
+            prs: Vec::new(),
+            cancelled: None,
+            error: Some(error),
+            retries: None,
+        },
+    )
+    .await
+}
+
+fn gh_pr_list_blocking(request_id: String, payload: GhWorktreePayload) -> GhPrListResponse {
+    let worktree_path = match validate_git_worktree_path(&payload.worktree_path) {
+        Ok(path) => path,
+        Err(error) => {
+            return GhPrListResponse {
+                request_id,
+                ok: false,
+                branch: None,
+                prs: Vec::new(),
+                cancelled: None,
+                error: Some(error),
+                retries: None,
+            }
+        }
+    };
+
+    let Some(branch) = current_branch_at(&worktree_path) else {
+        return GhPrListResponse {
+            request_id,
+            ok: false,
+            branch: None,
+            prs: Vec::new(),
+            cancelled: None,
+            error: Some("Could not determine the current branch.".to_string()),
+            retries: None,
+        };
+    };
+
+    if let Some(provider) = pr_provider_for(&worktree_path) {
+        let listed = provider.list(&worktree_path, &branch);
+        return GhPrListResponse {
+            request_id,
+            ok: listed.is_ok(),
+            branch: Some(branch),
+            cancelled: None,
+            error: listed.as_ref().err().cloned(),
+            prs: listed.unwrap_or_default(),
+            retries: None,
+        };
+    }
+
+    let result = run_gh_in(
+        &worktree_path,
+        &[
+            "pr",
+            "list",
+            "--head",
+            &branch,
+            "--state",
+            "all",
+            "--json",
+            "number,title,state,url,isDraft,statusCheckRollup",
+        ],
+    );
+
+    if let Some(error) = result.error {
+        return GhPrListResponse {
+            request_id,
+            ok: false,
+            branch: Some(branch),
+            prs: Vec::new(),
+            cancelled: None,
+            error: Some(if error.contains("Failed to execute gh") {
+                "GitHub CLI (gh) is not installed or not on PATH.".to_string()
+            } else {
+                error
+            }),
+            retries: None,
+        };
+    }
+
+    if result.exit_code != Some(0) {
+        return GhPrListResponse {
+            request_id,
+            ok: false,
+            branch: Some(branch),
+            prs: Vec::new(),
+            cancelled: None,
+            error: Some(
+                first_non_empty_line(&result.stderr)
+                    .unwrap_or_else(|| "gh pr list failed.".to_string()),
+            ),
+            retries: None,
+        };
+    }
+
+    let prs = serde_json::from_str::<Vec<GhPrListItemRaw>>(&result.stdout)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|item| {
+            let checks = item
+                .status_check_rollup
+                .into_iter()
+                .map(GhCheckRaw::into_check)
+                .collect::<Vec<_>>();
+            GhPrSummary {
+                checks_status: checks_status(&checks).map(str::to_string),
+                ..item.summary
+            }
+        })
+        .collect();
+    GhPrListResponse {
+        request_id,
+        ok: true,
+        branch: Some(branch),
+        prs,
+        cancelled: None,
+        error: None,
+        retries: None,
+    }
+}
+
+#[tauri::command]
+async fn gh_pr_view(payload: GhPrViewPayload) -> GhPrViewResponse {
+    handle_blocking_command(
+        "gh_pr_view",
+        move |context| gh_pr_view_blocking(context.request_id(), payload),
+        |request_id, error| GhPrViewResponse {
+            request_id,
+            ok: false,
+            pr: None,
+            cancelled: None,
+            error: Some(error),
+            retries: None,
+        },
+    )
+    .await
+}
+
+fn gh_pr_view_blocking(request_id: String, payload: GhPrViewPayload) -> GhPrViewResponse {
+    let worktree_path = match validate_git_worktree_path(&payload.worktree_path) {
+        Ok(path) => path,
+        Err(error) => {
+            return GhPrViewResponse {
+                request_id,
+                ok: false,
+                pr: None,
+                cancelled: None,
+                error: Some(error),
+                retries: None,
+            }
+        }
+    };
+
+    let selector = payload.selector.trim();
+    if !is_valid_pr_selector(selector) {
+        return GhPrViewResponse {
+            request_id,
+            ok: false,
+            pr: None,
+            cancelled: None,
+            error: Some("Selector must be a PR number or a github.com pull-request URL.".to_string()),
+            retries: None,
+        };
+    }
+
+    let result = run_gh_in(
+        &worktree_path,
+        &[
+            "pr",
+            "view",
+            selector,
+            "--json",
+            "number,title,state,url,isDraft,baseRefName,headRefName,reviewDecision,body,author,labels,additions,deletions,createdAt,updatedAt,comments",
+        ],
+    );
+
+    if let Some(error) = result.error {
+        return GhPrViewResponse {
+            request_id,
+            ok: false,
+            pr: None,
+            cancelled: None,
+            error: Some(if error.contains("Failed to execute gh") {
+                "GitHub CLI (gh) is not installed or not on PATH.".to_string()
+            } else {
+                error
+            }),
+            retries: None,
+        };
+    }
+
+    if result.exit_code != Some(0) {
+        return GhPrViewResponse {
+            request_id,
+            ok: false,
+            pr: None,
+            cancelled: None,
+            error: Some(
+                first_non_empty_line(&result.stderr)
+                    .unwrap_or_else(|| "gh pr view failed.".to_string()),
+            ),
+            retries: None,
+        };
+    }
+
+    let parsed = match serde_json::from_str::<GhPrViewRaw>(&result.stdout) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            return GhPrViewResponse {
+                request_id,
+                ok: false,
+                pr: None,
+                cancelled: None,
+                error: Some(format!("Could not parse gh pr view output: {error}")),
+                retries: None,
+            }
+        }
+    };
+
+    let comments = parsed
+        .comments
+        .into_iter()
+        .map(GhPrCommentRaw::into_comment)
+        .collect();
+
+    GhPrViewResponse {
+        request_id,
+        ok: true,
+        pr: Some(GhPrDetail {
+            number: parsed.number,
+            title: parsed.title,
+            state: parsed.state,
+            url: parsed.url,
+            is_draft: parsed.is_draft,
+            base_ref_name: normalize_optional(parsed.base_ref_name),
+            head_ref_name: normalize_optional(parsed.head_ref_name),
+            review_decision: normalize_optional(parsed.review_decision),
+            body: normalize_optional(parsed.body),
+            author: parsed
+                .author
+                .map(|author| author.login)
+                .filter(|login| !login.is_empty()),
+            labels: parsed
+                .labels
+                .into_iter()
+                .map(|label| label.name)
+                .filter(|name| !name.is_empty())
+                .collect(),
+            additions: parsed.additions,
+            deletions: parsed.deletions,
+            created_at: normalize_optional(parsed.created_at),
+            updated_at: normalize_optional(parsed.updated_at),
+            comments,
+        }),
+        cancelled: None,
+        error: None,
+        retries: None,
+    }
+}
+
+#[tauri::command]
+async fn gh_pr_checks(payload: GhPrViewPayload) -> GhPrChecksResponse {
+    handle_blocking_command(
+        "gh_pr_checks",
+        move |context| gh_pr_checks_blocking(context.request_id(), payload),
+        |request_id, error| GhPrChecksResponse {
+            request_id,
+            ok: false,
+            number: None,
+            checks: Vec::new(),
+            checks_status: None,
+            mergeable: None,
+            merge_state_status: None,
+            cancelled: None,
+            error: Some(error),
+            retries: None,
+        },
+    )
+    .await
+}
+
+fn gh_pr_checks_blocking(request_id: String, payload: GhPrViewPayload) -> GhPrChecksResponse {
+    let failed = |error: String| GhPrChecksResponse {
+        request_id: request_id.clone(),
+        ok: false,
+        number: None,
+        checks: Vec::new(),
+        checks_status: None,
+        mergeable: None,
+        merge_state_status: None,
+        cancelled: None,
+        error: Some(error),
+        retries: None,
+    };
+    let worktree_path = match validate_git_worktree_path(&payload.worktree_path) {
+        Ok(path) => path,
+        Err(error) => return failed(error),
+    };
+
+    let selector = payload.selector.trim();
+    if let Some(provider) = pr_provider_for(&worktree_path) {
+        return match provider.checks(&worktree_path, selector) {
+            Ok(found) => GhPrChecksResponse {
+                request_id: request_id.clone(),
+                ok: true,
+                number: Some(found.number),
+                checks_status: checks_status(&found.checks).map(str::to_string),
+                checks: found.checks,
+                mergeable: found.mergeable,
+                merge_state_status: found.merge_state_status,
+                cancelled: None,
+                error: None,
+                retries: None,
+            },
+            Err(error) => failed(error),
+        };
+    }
+    if !is_valid_pr_selector(selector) {
+        return failed(
+            "Selector must be a PR number or a github.com pull-request URL.".to_string(),
+        );
+    }
+
+    // `gh pr view` rather than `gh pr checks`: the latter exits non-zero
+    // whenever a check failed or is still running.
+    let result = run_gh_in(
+        &worktree_path,
+        &[
+            "pr",
+            "view",
+            selector,
+            "--json",
+            "number,statusCheckRollup,mergeable,mergeStateStatus",
+        ],
+    );
+    if let Some(error) = result.error {
+        return failed(if error.contains("Failed to execute gh") {
+            "GitHub CLI (gh) is not installed or not on PATH.".to_string()
+        } else {
+            error
+        });
+    }
+    if result.exit_code != Some(0) {
+        return failed(
+            first_non_empty_line(&result.stderr)
+                .unwrap_or_else(|| "gh pr view failed.".to_string()),
+        );
+    }
+
+    let parsed = match serde_json::from_str::<GhPrChecksRaw>(&result.stdout) {
+        Ok(parsed) => parsed,
+        Err(error) => return failed(format!("Could not parse gh pr view output: {error}")),
+    };
+    let checks = parsed
+        .status_check_rollup
+        .into_iter()
+        .map(GhCheckRaw::into_check)
+        .collect::<Vec<_>>();
+    GhPrChecksResponse {
+        request_id: request_id.clone(),
+        ok: true,
+        number: Some(parsed.number),
+        checks_status: checks_status(&checks).map(str::to_string),
+        checks,
+        mergeable: normalize_optional(parsed.mergeable),
+        merge_state_status: normalize_optional(parsed.merge_state_status),
+        cancelled: None,
+        error: None,
+        retries: None,
+    }
+}
+
+#[tauri::command]
+async fn gh_pr_merge(app: AppHandle, payload: GhPrMergePayload) -> GhPrMergeResponse {
+    let recorded = payload.clone();
+    let response = handle_blocking_command(
+        "gh_pr_merge",
+        move |context| gh_pr_merge_blocking(context.request_id(), payload),
+        |request_id, error| GhPrMergeResponse {
+            request_id,
+            ok: false,
+            number: None,
+            merge_commit_sha: None,
+            branch_deleted: false,
+            cancelled: None,
+            error: Some(error),
+        },
+    )
+    .await;
+    record_action_history(
+        &app,
+        "gh_pr_merge",
+        &recorded.worktree_path,
+        &recorded,
+        &response,
+    );
+    response
+}
+
+fn gh_pr_merge_blocking(request_id: String, payload: GhPrMergePayload) -> GhPrMergeResponse {
+    let failed = |error: String| GhPrMergeResponse {
+        request_id: request_id.clone(),
+        ok: false,
+        number: None,
+        merge_commit_sha: None,
+        branch_deleted: false,
+        cancelled: None,
+        error: Some(error),
+    };
+    let worktree_path = match validate_git_worktree_path(&payload.worktree_path) {
+        Ok(path) => path,
+        Err(error) => return failed(error),
+    };
+
+    let selector = payload.selector.trim();
+    if !is_valid_pr_selector(selector) {
+        return failed(
+            "Selector must be a PR number or a github.com pull-request URL.".to_string(),
+        );
+    }
+
+    let strategy = match payload.strategy.as_deref().map(str::trim) {
+        None | Some("") | Some("merge") => "--merge",
+        Some("squash") => "--squash",
+        Some("rebase") => "--rebase",
+        Some(other) => {
+            return failed(format!(
+                "Unknown merge strategy \"{other}\"; expected merge, squash or rebase."
+            ))
+        }
+    };
+    let subject = normalize_optional(payload.subject);
+    let body = normalize_optional(payload.body);
+    if strategy == "--rebase" && (subject.is_some() || body.is_some()) {
+        return failed(
+            "A rebase merge keeps the original commits; subject and body do not apply.".to_string(),
+        );
+    }
+
+    // `--delete-branch` is left to the GitHub API below: gh would also delete
+    // the local branch and switch this worktree to the default branch, which
+    // fails whenever that branch is checked out in another worktree.
+    let mut args = vec!["pr", "merge", selector, strategy];
+    if let Some(subject) = subject.as_deref() {
+        args.extend(["--subject", subject]);
+    }
+    if let Some(body) = body.as_deref() {
+        args.extend(["--body", body]);
+    }
+    let result = run_gh_in(&worktree_path, &args);
+    if result.error.is_some() || result.exit_code != Some(0) {
+        let failure = gh_failure_response(request_id.clone(), result);
+        return failed(
+            failure
+                .error
+                .unwrap_or_else(|| "gh pr merge failed.".to_string()),
+        );
+    }
+
+    let merged = run_gh_in(
+        &worktree_path,
+        &[
+            "pr",
+            "view",
+            selector,
+            "--json",
+            "number,mergeCommit,headRefName,isCrossRepository",
+        ],
+    );
+    let merged = (merged.exit_code == Some(0))
+        .then(|| serde_json::from_str::<GhPrMergedRaw>(&merged.stdout).ok())
+        .flatten();
+    let Some(merged) = merged else {
+        // The merge went through; only the follow-up lookup failed.
+        return GhPrMergeResponse {
+            request_id,
+            ok: true,
+            number: None,
+            merge_commit_sha: None,
+            branch_deleted: false,
+            cancelled: None,
+            error: None,
+        };
+    };
+
+    let mut error = None;
+    let mut branch_deleted = false;
+    if payload.delete_branch {
+        if merged.is_cross_repository {
+            error = Some("The head branch lives in a fork and was not deleted.".to_string());
+        } else if is_valid_branch_token(&merged.head_ref_name) {
+            let endpoint = format!(
+                "repos/{{owner}}/{{repo}}/git/refs/heads/{}",
+                merged.head_ref_name
+            );
+            let deleted = run_gh_in(&worktree_path, &["api", "-X", "DELETE", &endpoint]);
+            branch_deleted = deleted.error.is_none() && deleted.exit_code == Some(0);
+            if !branch_deleted {
+                error = Some(format!(
+                    "Merged, but deleting the head branch failed: {}",
+                    first_non_empty_line(&deleted.stderr)
+                        .or(deleted.error)
+                        .unwrap_or_else(|| "gh api failed.".to_string())
+                ));
+            }
+        }
+    }
+
+    GhPrMergeResponse {
+        request_id,
+        ok: true,
+        number: Some(merged.number),
+        merge_commit_sha: merged.merge_commit.map(|commit| commit.oid),
+        branch_deleted,
+        cancelled: None,
+        error,
+    }
+}
+
+#[tauri::command]
+async fn gh_pr_comments(payload: GhPrViewPayload) -> GhPrCommentsResponse {
+    handle_blocking_command(
+        "gh_pr_comments",
+        move |context| gh_pr_comments_blocking(context.request_id(), payload),
+        |request_id, error| GhPrCommentsResponse {
+            request_id,
+            ok: false,
+            number: None,
+            reviews: Vec::new(),
+            threads: Vec::new(),
+            cancelled: None,
+            error: Some(error),
+            retries: None,
+        },
+    )
+    .await
+}
+
+fn gh_pr_comments_blocking(request_id: String, payload: GhPrViewPayload) -> GhPrCommentsResponse {
+    let failed = |error: String| GhPrCommentsResponse {
+        request_id: request_id.clone(),
+        ok: false,
+        number: None,
+        reviews: Vec::new(),
+        threads: Vec::new(),
+        cancelled: None,
+        error: Some(error),
+        retries: None,
+    };
+    let worktree_path = match validate_git_worktree_path(&payload.worktree_path) {
+        Ok(path) => path,
+        Err(error) => return failed(error),
+    };
+
+    let selector = payload.selector.trim();
+    if !is_valid_pr_selector(selector) {
+        return failed(
+            "Selector must be a PR number or a github.com pull-request URL.".to_string(),
+        );
+    }
+
+    // Resolve the selector through gh first so a bare number is looked up in
+    // the repository gh picks for this worktree.
+    let located = run_gh_in(
+        &worktree_path,
+        &["pr", "view", selector, "--json", "url", "-q", ".url"],
+    );
+    let failure = |result: CommandResult, fallback: &str| {
+        gh_failure_response(request_id.clone(), result)
+            .error
+            .unwrap_or_else(|| fallback.to_string())
+    };
+    if located.error.is_some() || located.exit_code != Some(0) {
+        return failed(failure(located, "gh pr view failed."));
+    }
+    let Some((owner, repo, number)) = parse_pr_url(located.stdout.trim()) else {
+        return failed(format!(
+            "Could not parse PR URL \"{}\".",
+            located.stdout.trim()
+        ));
+    };
+
+    let query = format!("query={GH_PR_REVIEWS_QUERY}");
+    let owner = format!("owner={owner}");
+    let repo = format!("name={repo}");
+    let number_field = format!("number={number}");
+    let result = run_gh_in(
+        &worktree_path,
+        &[
+            "api",
+            "graphql",
+            "-f",
+            &query,
+            "-f",
+            &owner,
+            "-f",
+            &repo,
+            "-F",
+            &number_field,
+            "-q",
+            ".data.repository.pullRequest",
+        ],
+    );
+    if result.error.is_some() || result.exit_code != Some(0) {
+        return failed(failure(result, "gh api graphql failed."));
+    }
+    let parsed = match serde_json::from_str::<GhPrReviewsRaw>(&result.stdout) {
+        Ok(parsed) => parsed,
+        Err(error) => return failed(format!("Could not parse review threads: {error}")),
+    };
+
+    let threads = parsed
+        .review_threads
+        .nodes
+        .into_iter()
+        .map(|thread| GhPrReviewThread {
+            id: thread.id,
+            path: thread.path,
+            line: thread.line,
+            is_resolved: thread.is_resolved,
+            is_outdated: thread.is_outdated,
+            comments: thread
+                .comments
+                .nodes
+                .into_iter()
+                .map(GhPrCommentRaw::into_comment)
+                .collect(),
+        })
+        .collect();
+    let reviews = parsed
+        .reviews
+        .nodes
+        .into_iter()
+        .map(|review| GhPrReview {
+            author: review
+                .author
+                .map(|author| author.login)
+                .filter(|login| !login.is_empty()),
+            state: review.state,
+            body: review.body,
+            submitted_at: normalize_optional(review.submitted_at),
+        })
+        .collect();
+
+    GhPrCommentsResponse {
+        request_id: request_id.clone(),
+        ok: true,
+        number: Some(number),
+        reviews,
+        threads,
+        cancelled: None,
+        error: None,
+        retries: None,
+    }
+}
+
+#[tauri::command]
+async fn gh_pr_review(app: AppHandle, payload: GhPrReviewPayload) -> GhCommandResponse {
+    let recorded = payload.clone();
+    let response = handle_blocking_command(
+        "gh_pr_review",
+        move |context| {
+            context.capture_subprocess_transcripts(payload.debug);
+            gh_pr_review_blocking(context.request_id(), payload)
+        },
+        |request_id, error| GhCommandResponse {
+            request_id,
+            ok: false,
+            cancelled: None,
+            error: Some(error),
+            debug_transcripts: None,
+        },
+    )
+    .await;
+    record_action_history(
+        &app,
+        "gh_pr_review",
+        &recorded.worktree_path,
+        &recorded,
+        &response,
+    );
+    response
+}
+
+fn gh_pr_review_blocking(request_id: String, payload: GhPrReviewPayload) -> GhCommandResponse {
+    let failed = |error: &str| GhCommandResponse {
+        request_id: request_id.clone(),
+        ok: false,
+        cancelled: None,
+        error: Some(error.to_string()),
+        debug_transcripts: None,
+    };
+    let worktree_path = match validate_git_worktree_path(&payload.worktree_path) {
+        Ok(path) => path,
+        Err(error) => return failed(&error),
+    };
+
+    let selector = payload.selector.trim();
+    if !is_valid_pr_selector(selector) {
+        return failed("Selector must be a PR number or a github.com pull-request URL.");
+    }
+
+    let flag = match payload.event.trim() {
+        "approve" => "--approve",
+        "requestChanges" => "--request-changes",
+        "comment" => "--comment",
+        _ => return failed("event must be approve, requestChanges or comment."),
+    };
+    let body = normalize_optional(payload.body);
+    if flag != "--approve" && body.is_none() {
+        return failed("A review body is required to comment or request changes.");
+    }
+
+    let mut args = vec!["pr", "review", selector, flag];
+    if let Some(body) = body.as_deref() {
+        args.extend(["--body", body]);
+    }
+    let result = run_gh_in(&worktree_path, &args);
+    gh_failure_response(request_id, result)
+}
+
+#[tauri::command]
+async fn gh_pr_prepare(app: AppHandle, payload: GhPrPreparePayload) -> GhPrPrepareResponse {
+    handle_blocking_command(
+        "gh_pr_prepare",
+        move |context| gh_pr_prepare_blocking(&app, context.request_id(), payload),
+        gh_pr_prepare_failure,
+    )
+    .await
+}
+
+fn gh_pr_prepare_failure(request_id: String, error: String) -> GhPrPrepareResponse {
+    GhPrPrepareResponse {
+        request_id,
+        ok: false,
+        title: None,
+        body: None,
+        commits: Vec::new(),
+        co_authors: Vec::new(),
+        template_path: None,
+        issue: None,
+        cancelled: None,
+        error: Some(error),
+    }
+}
+
+fn gh_pr_prepare_blocking(
+    app: &AppHandle,
+    request_id: String,
+    payload: GhPrPreparePayload,
+) -> GhPrPrepareResponse {
+    let worktree_path = match validate_git_worktree_path(&payload.worktree_path) {
+        Ok(path) => path,
+        Err(error) => return gh_pr_prepare_failure(request_id, error),
+    };
+    let base = payload.base.trim();
+    if !is_valid_branch_token(base) {
+        return gh_pr_prepare_failure(request_id, format!("Invalid base branch: {base}"));
+    }
+
+    // The remote-tracking branch is what the pull request is compared with.
+    let remote_base = format!("origin/{base}");
+    let has_remote_base = run_git_command_at_path(
+        &worktree_path,
+        &["rev-parse", "--verify", "--quiet", remote_base.as_str()],
+    )
+    .exit_code
+        == Some(0);
+    let base_ref = if has_remote_base {
+        remote_base
+    } else {
+        base.to_string()
+    };
+    let range = format!("{base_ref}..HEAD");
+    let result = run_git_command_at_path(
+        &worktree_path,
+        &[
+            "log",
+            "--no-merges",
+            "-n",
+            GH_PR_PREPARE_MAX_COMMITS,
+            git::DCO_LOG_FORMAT,
+            range.as_str(),
+        ],
+    );
+    if let Some(error) = result.error.clone() {
+        return gh_pr_prepare_failure(request_id, error);
+    }
+    if result.exit_code != Some(0) {
+        return gh_pr_prepare_failure(
+            request_id,
+            first_non_empty_line(&result.stderr).unwrap_or_else(|| "git log failed".to_string()),
+        );
+    }
+    let commits = git::parse_pr_commits(&result.stdout);
+
+    let own_email = first_non_empty_line(
+        &run_git_command_at_path(&worktree_path, &["config", "user.email"]).stdout,
+    );
+    let co_authors = git::pr_co_authors(&commits, own_email.as_deref());
+    let issue = worktree_issue_record(app, &worktree_path);
+    let branch = native_git::current_branch(&worktree_path)
+        .flatten()
+        .or_else(|| {
+            first_non_empty_line(
+                &run_git_command_at_path(&worktree_path, &["branch", "--show-current"]).stdout,
+            )
+        })
+        .unwrap_or_default();
+    let title = git::pr_title_from_commits(
+        &commits,
+        &branch,
+        issue.as_ref().and_then(|issue| issue.title.as_deref()),
+    );
+    let generated = git::pr_body_from_commits(
+        &commits,
+        &co_authors,
+        issue.as_ref().map(|issue| issue.number),
+    );
+    let template = find_pr_template(&worktree_path);
+    let body = match &template {
+        Some((_, template)) => format!("{}\n\n{generated}", template.trim_end()),
+        None => generated,
+    };
+
+    GhPrPrepareResponse {
+        request_id,
+        ok: true,
+        title: Some(title),
+        body: Some(body),
+        commits: commits
+            .into_iter()
+            .map(|commit| GhPrPrepareCommit {
+                sha: commit.sha,
+                author: commit.author,
+                subject: commit.subject,
+            })
+            .collect(),
+        co_authors,
+        template_path: template.map(|(path, _)| path),
+        issue: issue.map(|issue| issue.number),
+        cancelled: None,
+        error: None,
+    }
+}
+
+/// The pull request template under `.github`: `pull_request_template.md` in
+/// any case, else the first Markdown file of `PULL_REQUEST_TEMPLATE/`.
+/// Returns its path relative to the worktree and its contents.
+fn find_pr_template(worktree_path: &Path) -> Option<(String, String)> {
+    let github_dir = worktree_path.join(".github");
+    let entries = fs::read_dir(&github_dir)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .collect::<Vec<_>>();
+    let relative = if let Some(file) = entries
+        .iter()
+        .find(|name| name.eq_ignore_ascii_case("pull_request_template.md"))
+    {
+        format!(".github/{file}")
+    } else {
+        let dir = entries
+            .iter()
+            .find(|name| name.eq_ignore_ascii_case("pull_request_template"))?;
+        let mut templates = fs::read_dir(github_dir.join(dir))
+            .ok()?
+            .flatten()
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .filter(|name| name.to_ascii_lowercase().ends_with(".md"))
+            .collect::<Vec<_>>();
+        templates.sort();
+        format!(".github/{dir}/{}", templates.first()?)
+    };
+    let contents = fs::read_to_string(worktree_path.join(&relative)).ok()?;
+    Some((relative, contents))
+}
+
+/// The issue a worktree of the active workspace was created from.
+fn worktree_issue_record(app: &AppHandle, worktree_path: &Path) -> Option<IssueRecord> {
+    let (_, workspace_meta) = active_workspace_meta(app).ok()?;
+    let worktree = worktree_record_name_for_path(&workspace_meta, worktree_path)?;
+    workspace_meta
+        .worktree_records
+        .get(&worktree)?
+        .issue
+        .clone()
+}
+
+/// The name of the record of the worktree at `worktree_path`, matched by the
+/// id in its `.groove/worktree.json`, else by directory name.
+fn worktree_record_name_for_path(
+    workspace_meta: &WorkspaceMeta,
+    worktree_path: &Path,
+) -> Option<String> {
+    let identity_id = read_worktree_identity(worktree_path).map(|identity| identity.id);
+    let worktree = worktree_path.file_name()?.to_string_lossy().to_string();
+    workspace_meta
+        .worktree_records
+        .iter()
+        .find(|(name, record)| match &identity_id {
+            Some(id) => record.id == *id,
+            None => **name == worktree,
+        })
+        .map(|(name, _)| name.clone())
+}
+
+#[tauri::command]
+async fn gh_pr_create(app: AppHandle, mut payload: GhPrCreatePayload) -> GhPrCreateResponse {
+    if payload.base.trim().is_empty() {
+        if let Some(branch) = stacked_parent_branch(&app, Path::new(&payload.worktree_path)) {
+            payload.base = branch;
+        }
+    }
+    let recorded = payload.clone();
+    let response = handle_blocking_command(
+        "gh_pr_create",
+        move |context| gh_pr_create_blocking(context.request_id(), payload),
+        |request_id, error| GhPrCreateResponse {
+            request_id,
+            ok: false,
+            number: None,
+            url: None,
+            cancelled: None,
+            error: Some(error),
+        },
+    )
+    .await;
+    record_action_history(
+        &app,
+        "gh_pr_create",
+        &recorded.worktree_path,
+        &recorded,
+        &response,
+    );
+    response
+}
+
+fn gh_pr_create_blocking(request_id: String, payload: GhPrCreatePayload) -> GhPrCreateResponse {
+    let failed = |error: String| GhPrCreateResponse {
+        request_id: request_id.clone(),
+        ok: false,
+        number: None,
+        url: None,
+        cancelled: None,
+        error: Some(error),
+    };
+    let base = payload.base.trim();
+    if !is_valid_branch_token(base) {
+        return failed("A valid base branch is required.".to_string());
+    }
+    let worktree_path = match validate_git_worktree_path(&payload.worktree_path) {
+        Ok(path) => path,
+        Err(error) => return failed(error),
+    };
+
+    if let Some(provider) = pr_provider_for(&worktree_path) {
+        let Some(branch) = current_branch_at(&worktree_path) else {
+            return failed("Could not determine the current branch.".to_string());
+        };
+        return match provider.create(&worktree_path, &branch, &payload) {
+            Ok((number, url)) => GhPrCreateResponse {
+                request_id: request_id.clone(),
+                ok: true,
+                number,
+                url,
+                cancelled: None,
+                error: None,
+            },
+            Err(error) => failed(error),
+        };
+    }
+
+    let title = normalize_optional(payload.title);
+    let body = payload.body.unwrap_or_default();
+    let mut args = vec!["pr", "create", "--base", base];
+    match title.as_deref() {
+        Some(title) => args.extend(["--title", title, "--body", body.as_str()]),
+        None => args.push("--fill"),
+    }
+    if payload.draft {
+        args.push("--draft");
+    }
+    let result = run_gh_in(&worktree_path, &args);
+    if result.error.is_some() || result.exit_code != Some(0) {
+        let failure = gh_failure_response(request_id.clone(), result);
+        return failed(
+            failure
+                .error
+                .unwrap_or_else(|| "gh pr create failed.".to_string()),
+        );
+    }
 
-    GhRepoDefaultBranchResponse {
-        request_id,
+    // gh prints the new PR's URL as the last line of stdout.
+    let url = result
+        .stdout
+        .lines()
+        .rev()
+        .map(str::trim)
+        .find(|line| line.contains("/pull/"))
+        .map(str::to_string);
+    GhPrCreateResponse {
+        request_id: request_id.clone(),
         ok: true,
-        default_branch,
+        number: url.as_deref().and_then(parse_pr_number_from_url),
+        url,
+        cancelled: None,
         error: None,
     }
 }
 
 #[tauri::command]
-async fn gh_pr_list(payload: GhWorktreePayload) -> GhPrListResponse {
-    let request_id = request_id();
-    let fallback_request_id = request_id.clone();
-
-    match tauri::async_runtime::spawn_blocking(move || gh_pr_list_blocking(request_id, payload))
-        .await
-    {
-        Ok(response) => response,
-        Err(error) => GhPrListResponse {
-            request_id: fallback_request_id,
+async fn gh_pr_update(app: AppHandle, payload: GhPrUpdatePayload) -> GhCommandResponse {
+    let recorded = payload.clone();
+    let response = handle_blocking_command(
+        "gh_pr_update",
+        move |context| {
+            context.capture_subprocess_transcripts(payload.debug);
+            gh_pr_update_blocking(context.request_id(), payload)
+        },
+        |request_id, error| GhCommandResponse {
+            request_id,
             ok: false,
-            branch: None,
-            prs: Vec::new(),
-            error: Some(format!("Failed to run gh pr list worker thread: {error}")),
+            cancelled: None,
+            error: Some(error),
+            debug_transcripts: None,
         },
-    }
+    )
+    .await;
+    record_action_history(
+        &app,
+        "gh_pr_update",
+        &recorded.worktree_path,
+        &recorded,
+        &response,
+    );
+    response
 }
 
-fn gh_pr_list_blocking(request_id: String, payload: GhWorktreePayload) -> GhPrListResponse {
+fn gh_pr_update_blocking(request_id: String, payload: GhPrUpdatePayload) -> GhCommandResponse {
+    let failed = |error: String| GhCommandResponse {
+        request_id: request_id.clone(),
+        ok: false,
+        cancelled: None,
+        error: Some(error),
+        debug_transcripts: None,
+    };
     let worktree_path = match validate_git_worktree_path(&payload.worktree_path) {
         Ok(path) => path,
-        Err(error) => {
-            return GhPrListResponse {
-                request_id,
-                ok: false,
-                branch: None,
-                prs: Vec::new(),
-                error: Some(error),
-            }
-        }
-    };
-
-    let Some(branch) = current_branch_at(&worktree_path) else {
-        return GhPrListResponse {
-            request_id,
-            ok: false,
-            branch: None,
-            prs: Vec::new(),
-            error: Some("Could not determine the current branch.".to_string()),
-        };
+        Err(error) => return failed(error),
     };
 
-    let result = run_gh_in(
-        &worktree_path,
-        &[
-            "pr",
-            "list",
-            "--head",
-            &branch,
-            "--state",
-            "all",
-            "--json",
-            "number,title,state,url,isDraft",
-        ],
-    );
+    let selector = payload.selector.trim();
+    if !is_valid_pr_selector(selector) {
+        return failed(
+            "Selector must be a PR number or a github.com pull-request URL.".to_string(),
+        );
+    }
 
-    if let Some(error) = result.error {
-        return GhPrListResponse {
-            request_id,
-            ok: false,
-            branch: Some(branch),
-            prs: Vec::new(),
-            error: Some(if error.contains("Failed to execute gh") {
-                "GitHub CLI (gh) is not installed or not on PATH.".to_string()
-            } else {
-                error
-            }),
-        };
+    let title = normalize_optional(payload.title);
+    let base = payload
+        .base
+        .as_deref()
+        .map(str::trim)
+        .filter(|base| !base.is_empty());
+    if base.is_some_and(|base| !is_valid_branch_token(base)) {
+        return failed("A valid base branch is required.".to_string());
+    }
+    let invalid_label = payload
+        .add_labels
+        .iter()
+        .chain(&payload.remove_labels)
+        .map(|label| label.trim())
+        .any(|label| label.is_empty() || label.starts_with('-'));
+    if invalid_label {
+        return failed("Labels must be non-empty and may not start with '-'.".to_string());
     }
 
-    if result.exit_code != Some(0) {
-        return GhPrListResponse {
-            request_id,
-            ok: false,
-            branch: Some(branch),
-            prs: Vec::new(),
-            error: Some(
-                first_non_empty_line(&result.stderr)
-                    .unwrap_or_else(|| "gh pr list failed.".to_string()),
-            ),
-        };
+    let mut args = vec!["pr", "edit", selector];
+    if let Some(title) = title.as_deref() {
+        args.extend(["--title", title]);
+    }
+    if let Some(body) = payload.body.as_deref() {
+        args.extend(["--body", body]);
+    }
+    if let Some(base) = base {
+        args.extend(["--base", base]);
+    }
+    for label in &payload.add_labels {
+        args.extend(["--add-label", label.trim()]);
+    }
+    for label in &payload.remove_labels {
+        args.extend(["--remove-label", label.trim()]);
+    }
+    if args.len() > 3 {
+        let result = run_gh_in(&worktree_path, &args);
+        if result.error.is_some() || result.exit_code != Some(0) {
+            return gh_failure_response(request_id.clone(), result);
+        }
     }
 
-    let prs = serde_json::from_str::<Vec<GhPrSummary>>(&result.stdout).unwrap_or_default();
-    GhPrListResponse {
-        request_id,
-        ok: true,
-        branch: Some(branch),
-        prs,
-        error: None,
+    // Draft state is not editable through `gh pr edit`.
+    match payload.ready {
+        Some(true) => gh_failure_response(
+            request_id.clone(),
+            run_gh_in(&worktree_path, &["pr", "ready", selector]),
+        ),
+        Some(false) => gh_failure_response(
+            request_id.clone(),
+            run_gh_in(&worktree_path, &["pr", "ready", "--undo", selector]),
+        ),
+        None => GhCommandResponse {
+            request_id: request_id.clone(),
+            ok: true,
+            cancelled: None,
+            error: None,
+            debug_transcripts: None,
+        },
     }
 }
 
 #[tauri::command]
-async fn gh_pr_view(payload: GhPrViewPayload) -> GhPrViewResponse {
-    let request_id = request_id();
-    let fallback_request_id = request_id.clone();
-
-    match tauri::async_runtime::spawn_blocking(move || gh_pr_view_blocking(request_id, payload))
-        .await
-    {
-        Ok(response) => response,
-        Err(error) => GhPrViewResponse {
-            request_id: fallback_request_id,
+async fn gh_issue_list(payload: GhIssueListPayload) -> GhIssueListResponse {
+    handle_blocking_command(
+        "gh_issue_list",
+        move |context| gh_issue_list_blocking(context.request_id(), payload),
+        |request_id, error| GhIssueListResponse {
+            request_id,
             ok: false,
-            pr: None,
-            error: Some(format!("Failed to run gh pr view worker thread: {error}")),
+            issues: Vec::new(),
+            cancelled: None,
+            error: Some(error),
+            retries: None,
         },
-    }
+    )
+    .await
 }
 
-fn gh_pr_view_blocking(request_id: String, payload: GhPrViewPayload) -> GhPrViewResponse {
+fn gh_issue_list_blocking(request_id: String, payload: GhIssueListPayload) -> GhIssueListResponse {
+    let failed = |error: String| GhIssueListResponse {
+        request_id: request_id.clone(),
+        ok: false,
+        issues: Vec::new(),
+        cancelled: None,
+        error: Some(error),
+        retries: None,
+    };
     let worktree_path = match validate_git_worktree_path(&payload.worktree_path) {
         Ok(path) => path,
-        Err(error) => {
-            return GhPrViewResponse {
-                request_id,
-                ok: false,
-                pr: None,
-                error: Some(error),
-            }
-        }
+        Err(error) => return failed(error),
     };
 
-    let selector = payload.selector.trim();
-    let is_number = !selector.is_empty() && selector.chars().all(|c| c.is_ascii_digit());
-    let is_pr_url = selector.starts_with("https://") && selector.contains("/pull/");
-    if !is_number && !is_pr_url {
-        return GhPrViewResponse {
-            request_id,
-            ok: false,
-            pr: None,
-            error: Some("Selector must be a PR number or a github.com pull-request URL.".to_string()),
-        };
+    let state = match payload.state.as_deref().map(str::trim) {
+        None | Some("") => "open",
+        Some(state @ ("open" | "closed" | "all")) => state,
+        Some(other) => {
+            return failed(format!(
+                "Unknown issue state \"{other}\"; expected open, closed or all."
+            ))
+        }
+    };
+    let limit = payload.limit.unwrap_or(30).clamp(1, 200).to_string();
+    let search = normalize_optional(payload.search);
+    let mut args = vec![
+        "issue",
+        "list",
+        "--state",
+        state,
+        "--limit",
+        limit.as_str(),
+        "--json",
+        "number,title,state,url,labels,assignees,updatedAt",
+    ];
+    if let Some(search) = search.as_deref() {
+        args.extend(["--search", search]);
+    }
+    let result = run_gh_in(&worktree_path, &args);
+    if result.error.is_some() || result.exit_code != Some(0) {
+        let failure = gh_failure_response(request_id.clone(), result);
+        return failed(
+            failure
+                .error
+                .unwrap_or_else(|| "gh issue list failed.".to_string()),
+        );
     }
 
-    let result = run_gh_in(
-        &worktree_path,
-        &[
-            "pr",
-            "view",
-            selector,
-            "--json",
-            "number,title,state,url,isDraft,baseRefName,headRefName,reviewDecision,body,author,labels,additions,deletions,createdAt,updatedAt,comments",
-        ],
-    );
-
-    if let Some(error) = result.error {
-        return GhPrViewResponse {
-            request_id,
-            ok: false,
-            pr: None,
-            error: Some(if error.contains("Failed to execute gh") {
-                "GitHub CLI (gh) is not installed or not on PATH.".to_string()
-            } else {
-                error
-            }),
-        };
+    let issues = match serde_json::from_str::<Vec<GhIssueRaw>>(&result.stdout) {
+        Ok(issues) => issues,
+        Err(error) => return failed(format!("Could not parse gh issue list output: {error}")),
+    };
+    GhIssueListResponse {
+        request_id: request_id.clone(),
+        ok: true,
+        issues: issues
+            .into_iter()
+            .map(|issue| GhIssueSummary {
+                labels: issue.label_names(),
+                assignees: issue.assignee_logins(),
+                number: issue.number,
+                title: issue.title,
+                state: issue.state,
+                url: issue.url,
+                updated_at: normalize_optional(issue.updated_at),
+            })
+            .collect(),
+        cancelled: None,
+        error: None,
+        retries: None,
     }
+}
 
-    if result.exit_code != Some(0) {
-        return GhPrViewResponse {
+#[tauri::command]
+async fn gh_issue_view(payload: GhIssueViewPayload) -> GhIssueViewResponse {
+    handle_blocking_command(
+        "gh_issue_view",
+        move |context| gh_issue_view_blocking(context.request_id(), payload),
+        |request_id, error| GhIssueViewResponse {
             request_id,
             ok: false,
-            pr: None,
-            error: Some(
-                first_non_empty_line(&result.stderr)
-                    .unwrap_or_else(|| "gh pr view failed.".to_string()),
-            ),
-        };
-    }
+            issue: None,
+            cancelled: None,
+            error: Some(error),
+            retries: None,
+        },
+    )
+    .await
+}
 
-    let parsed = match serde_json::from_str::<GhPrViewRaw>(&result.stdout) {
-        Ok(parsed) => parsed,
+fn gh_issue_view_blocking(request_id: String, payload: GhIssueViewPayload) -> GhIssueViewResponse {
+    let issue = validate_git_worktree_path(&payload.worktree_path).and_then(|worktree_path| {
+        fetch_gh_issue(
+            &worktree_path,
+            payload.number,
+            "number,title,state,url,body,author,labels,assignees,createdAt,updatedAt,comments",
+        )
+    });
+    let issue = match issue {
+        Ok(issue) => issue,
         Err(error) => {
-            return GhPrViewResponse {
+            return GhIssueViewResponse {
                 request_id,
                 ok: false,
-                pr: None,
-                error: Some(format!("Could not parse gh pr view output: {error}")),
+                issue: None,
+                cancelled: None,
+                error: Some(error),
+                retries: None,
             }
         }
     };
 
-    let comments = parsed
-        .comments
-        .into_iter()
-        .map(|comment| GhPrComment {
-            author: comment
-                .author
-                .map(|author| author.login)
-                .filter(|login| !login.is_empty()),
-            body: comment.body,
-            created_at: normalize_optional(comment.created_at),
-        })
-        .collect();
-
-    GhPrViewResponse {
+    GhIssueViewResponse {
         request_id,
         ok: true,
-        pr: Some(GhPrDetail {
-            number: parsed.number,
-            title: parsed.title,
-            state: parsed.state,
-            url: parsed.url,
-            is_draft: parsed.is_draft,
-            base_ref_name: normalize_optional(parsed.base_ref_name),
-            head_ref_name: normalize_optional(parsed.head_ref_name),
-            review_decision: normalize_optional(parsed.review_decision),
-            body: normalize_optional(parsed.body),
-            author: parsed
+        issue: Some(GhIssueDetail {
+            labels: issue.label_names(),
+            assignees: issue.assignee_logins(),
+            suggested_branch: git::branch_name_for_issue(
+                u64::try_from(issue.number).unwrap_or_default(),
+                &issue.title,
+            ),
+            number: issue.number,
+            title: issue.title,
+            state: issue.state,
+            url: issue.url,
+            body: normalize_optional(issue.body),
+            author: issue
                 .author
                 .map(|author| author.login)
                 .filter(|login| !login.is_empty()),
-            labels: parsed
-                .labels
+            created_at: normalize_optional(issue.created_at),
+            updated_at: normalize_optional(issue.updated_at),
+            comments: issue
+                .comments
                 .into_iter()
-                .map(|label| label.name)
-                .filter(|name| !name.is_empty())
+                .map(GhPrCommentRaw::into_comment)
                 .collect(),
-            additions: parsed.additions,
-            deletions: parsed.deletions,
-            created_at: normalize_optional(parsed.created_at),
-            updated_at: normalize_optional(parsed.updated_at),
-            comments,
         }),
+        cancelled: None,
         error: None,
+        retries: None,
     }
 }
 
 #[tauri::command]
 async fn gh_pr_create_web(payload: GhPrCreateWebPayload) -> GhCommandResponse {
-    let request_id = request_id();
-    let fallback_request_id = request_id.clone();
-
-    match tauri::async_runtime::spawn_blocking(move || {
-        gh_pr_create_web_blocking(request_id, payload)
-    })
-    .await
-    {
-        Ok(response) => response,
-        Err(error) => GhCommandResponse {
-            request_id: fallback_request_id,
+    handle_blocking_command(
+        "gh_pr_create_web",
+        move |context| {
+            context.capture_subprocess_transcripts(payload.debug);
+            gh_pr_create_web_blocking(context.request_id(), payload)
+        },
+        |request_id, error| GhCommandResponse {
+            request_id,
             ok: false,
-            error: Some(format!("Failed to run gh pr create worker thread: {error}")),
+            cancelled: None,
+            error: Some(error),
+            debug_transcripts: None,
         },
-    }
+    )
+    .await
 }
 
 fn gh_pr_create_web_blocking(request_id: String, payload: GhPrCreateWebPayload) -> GhCommandResponse {
@@ -2449,7 +5214,9 @@ fn gh_pr_create_web_blocking(request_id: String, payload: GhPrCreateWebPayload)
         return GhCommandResponse {
             request_id,
             ok: false,
+            cancelled: None,
             error: Some("A valid base branch is required.".to_string()),
+            debug_transcripts: None,
         };
     }
 
@@ -2459,7 +5226,9 @@ fn gh_pr_create_web_blocking(request_id: String, payload: GhPrCreateWebPayload)
             return GhCommandResponse {
                 request_id,
                 ok: false,
+                cancelled: None,
                 error: Some(error),
+                debug_transcripts: None,
             }
         }
     };
@@ -2474,9 +5243,9 @@ fn gh_pr_create_web_blocking(request_id: String, payload: GhPrCreateWebPayload)
 #[cfg(test)]
 mod gh_auth_status_tests {
     use super::{
-        is_valid_branch_token, is_valid_gh_login, is_valid_ssh_host_alias, owner_repo_from_path,
-        parse_gh_auth_status, parse_pr_number_from_url, parse_ssh_config_github_hosts,
-        split_remote_url,
+        checks_status, is_valid_branch_token, is_valid_gh_login, is_valid_ssh_host_alias,
+        owner_repo_from_path, parse_gh_auth_status, parse_pr_number_from_url, parse_pr_url,
+        parse_ssh_config_github_hosts, split_remote_url, GhCheckRaw,
     };
 
     #[test]
@@ -2597,6 +5366,15 @@ mod gh_auth_status_tests {
         );
     }
 
+    #[test]
+    fn parses_owner_repo_and_number_from_pull_url() {
+        assert_eq!(
+            parse_pr_url("https://github.com/octocat/hello-world/pull/42"),
+            Some(("octocat".to_string(), "hello-world".to_string(), 42))
+        );
+        assert_eq!(parse_pr_url("https://github.com/octocat/hello-world"), None);
+    }
+
     #[test]
     fn validates_base_branch_tokens() {
         assert!(is_valid_branch_token("main"));
@@ -2606,5 +5384,87 @@ mod gh_auth_status_tests {
         assert!(!is_valid_branch_token("has space"));
         assert!(!is_valid_branch_token(""));
     }
+
+    #[test]
+    fn rolls_up_check_runs_and_commit_statuses() {
+        let rollup = |json: &str| {
+            let raw = serde_json::from_str::<Vec<GhCheckRaw>>(json).unwrap();
+            let checks = raw.into_iter().map(GhCheckRaw::into_check).collect::<Vec<_>>();
+            checks_status(&checks)
+        };
+
+        assert_eq!(rollup("[]"), None);
+        assert_eq!(
+            rollup(r#"[{"__typename":"CheckRun","name":"test","status":"COMPLETED","conclusion":"SUCCESS"},
+                {"__typename":"StatusContext","context":"ci/jenkins","state":"SUCCESS"}]"#),
+            Some("success")
+        );
+        assert_eq!(
+            rollup(r#"[{"__typename":"CheckRun","name":"lint","status":"IN_PROGRESS","conclusion":""},
+                {"__typename":"CheckRun","name":"test","status":"COMPLETED","conclusion":"SUCCESS"}]"#),
+            Some("pending")
+        );
+        assert_eq!(
+            rollup(r#"[{"__typename":"CheckRun","name":"lint","status":"IN_PROGRESS","conclusion":""},
+                {"__typename":"StatusContext","context":"ci/jenkins","state":"FAILURE"}]"#),
+            Some("failure")
+        );
+    }
 }
 
+
+#[cfg(test)]
+mod git_diff_tests {
+    use super::parse_unified_diff;
+
+    #[test]
+    fn numbers_lines_on_both_sides() {
+        let diff = "diff --git a/src/a.rs b/src/a.rs
+index 1111111..2222222 100644
+--- a/src/a.rs
++++ b/src/a.rs
+@@ -4,3 +4,3 @@ fn main() {
+ keep
+--- removed dashes
++new
+ tail
+";
+        let files = parse_unified_diff(diff);
+        assert_eq!(files.len(), 1);
+        let lines = &files[0].hunks[0].lines;
+        let numbered = lines
+            .iter()
+            .map(|line| (line.kind.as_str(), line.old_line_number, line.new_line_number))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            numbered,
+            [
+                ("context", Some(4), Some(4)),
+                ("remove", Some(5), None),
+                ("add", None, Some(5)),
+                ("context", Some(6), Some(6)),
+            ]
+        );
+        assert_eq!(lines[1].content, "-- removed dashes");
+        assert_eq!((files[0].additions, files[0].deletions), (1, 1));
+    }
+
+    #[test]
+    fn detects_binary_files_and_pure_renames() {
+        let diff = "diff --git a/logo.png b/logo.png
+index 3333333..4444444 100644
+Binary files a/logo.png and b/logo.png differ
+diff --git a/old.txt b/new.txt
+similarity index 100%
+rename from old.txt
+rename to new.txt
+";
+        let files = parse_unified_diff(diff);
+        assert_eq!(files.len(), 2);
+        assert!(files[0].binary);
+        assert!(files[0].hunks.is_empty());
+        assert_eq!(files[1].status, "renamed");
+        assert_eq!(files[1].file_path, "new.txt");
+        assert_eq!(files[1].old_path.as_deref(), Some("old.txt"));
+    }
+}
@@ -1,24 +1,43 @@
 #[tauri::command]
 async fn groove_list(app: AppHandle, payload: GrooveListPayload) -> GrooveListResponse {
-    let request_id = request_id();
-    let fallback_request_id = request_id.clone();
-
-    match tauri::async_runtime::spawn_blocking(move || {
-        groove_list_blocking(app, payload, request_id)
-    })
-    .await
-    {
-        Ok(response) => response,
-        Err(error) => GrooveListResponse {
-            request_id: fallback_request_id,
+    handle_blocking_command(
+        "groove_list",
+        move |context| {
+            let include_resources = payload.include_resources;
+            let badges_dir = payload.include_badges.then(|| payload.dir.clone());
+            let filter = payload.filter.clone().map(|filter| (filter, payload.dir.clone()));
+            let page = payload.page.clone();
+            let mut response = groove_list_blocking(app.clone(), payload, context.request_id());
+            attach_worktree_stack(&mut response);
+            if let Some((filter, dir)) = filter {
+                apply_groove_list_filter(&app, &mut response, filter, dir.as_deref());
+            }
+            if let Some(page) = page {
+                paginate_groove_list(&mut response, &page);
+            }
+            if include_resources {
+                attach_worktree_resources(&app, &mut response);
+            }
+            if let Some(dir) = badges_dir {
+                attach_worktree_badges(&app, &mut response, dir.as_deref());
+            }
+            response
+        },
+        |request_id, error| GrooveListResponse {
+            request_id,
             ok: false,
             workspace_root: None,
             rows: HashMap::new(),
+            order: None,
+            filter: None,
+            page: None,
             stdout: String::new(),
             stderr: String::new(),
-            error: Some(format!("Failed to run groove list worker thread: {error}")),
+            cancelled: None,
+            error: Some(error),
         },
-    }
+    )
+    .await
 }
 
 fn groove_list_blocking(
@@ -38,8 +57,12 @@ fn groove_list_blocking(
                 ok: false,
                 workspace_root: None,
                 rows: HashMap::new(),
+                order: None,
+                filter: None,
+                page: None,
                 stdout: String::new(),
                 stderr: String::new(),
+                cancelled: None,
                 error: Some(error),
             }
         }
@@ -53,8 +76,12 @@ fn groove_list_blocking(
                 ok: false,
                 workspace_root: None,
                 rows: HashMap::new(),
+                order: None,
+                filter: None,
+                page: None,
                 stdout: String::new(),
                 stderr: String::new(),
+                cancelled: None,
                 error: Some(error),
             }
         }
@@ -73,21 +100,25 @@ fn groove_list_blocking(
         Err(error) => {
             let resolve_elapsed = total_started_at.elapsed();
             if telemetry_enabled {
-                eprintln!(
+                log_line(&format!(
                     "[startup-telemetry] event=groove_list resolve_ms={} exec_ms={} parse_ms={} total_ms={} outcome=resolve-error collector=none fallback_used=false",
                     resolve_elapsed.as_millis(),
                     exec_elapsed.as_millis(),
                     parse_elapsed.as_millis(),
                     total_started_at.elapsed().as_millis(),
-                );
+                ));
             }
             return GrooveListResponse {
                 request_id,
                 ok: false,
                 workspace_root: None,
                 rows: HashMap::new(),
+                order: None,
+                filter: None,
+                page: None,
                 stdout: String::new(),
                 stderr: String::new(),
+                cancelled: None,
                 error: Some(error),
             };
         }
@@ -108,47 +139,46 @@ fn groove_list_blocking(
     let mut stale_response: Option<GrooveListResponse> = None;
     let mut previous_native_cache: Option<GrooveListNativeCache> = None;
     if let Some(cache_state) = app.try_state::<GrooveListCacheState>() {
-        if let Ok(mut entries) = cache_state.entries.lock() {
-            if let Some(cached) = entries.get(&cache_key) {
-                previous_native_cache = cached.native_cache.clone();
-                let cache_age = cached.created_at.elapsed();
-                if cache_age <= GROOVE_LIST_CACHE_TTL {
-                    let mut response = cached.response.clone();
-                    response.request_id = request_id;
-                    if telemetry_enabled {
-                        eprintln!(
-                            "[startup-telemetry] event=groove_list resolve_ms={} exec_ms={} parse_ms={} total_ms={} cache_hit=true collector=cache fallback_used=false",
-                            resolve_elapsed.as_millis(),
-                            exec_elapsed.as_millis(),
-                            parse_elapsed.as_millis(),
-                            total_started_at.elapsed().as_millis(),
-                        );
-                    }
-                    return response;
+        let mut entries = cache_state.lock_entries();
+        if let Some(cached) = entries.get(&cache_key) {
+            previous_native_cache = cached.native_cache.clone();
+            let cache_age = cached.created_at.elapsed();
+            // An invalidated entry is only kept for its native rows.
+            if !cached.invalidated && cache_age <= GROOVE_LIST_CACHE_TTL {
+                let mut response = cached.response.clone();
+                response.request_id = request_id;
+                if telemetry_enabled {
+                    log_line(&format!(
+                        "[startup-telemetry] event=groove_list resolve_ms={} exec_ms={} parse_ms={} total_ms={} cache_hit=true collector=cache fallback_used=false",
+                        resolve_elapsed.as_millis(),
+                        exec_elapsed.as_millis(),
+                        parse_elapsed.as_millis(),
+                        total_started_at.elapsed().as_millis(),
+                    ));
                 }
+                return response;
+            }
 
-                if cache_age <= GROOVE_LIST_CACHE_STALE_TTL {
-                    stale_response = Some(cached.response.clone());
-                } else {
-                    entries.remove(&cache_key);
-                }
-            } else {
+            if cache_age > GROOVE_LIST_CACHE_STALE_TTL {
                 entries.remove(&cache_key);
+            } else if !cached.invalidated {
+                stale_response = Some(cached.response.clone());
             }
+        } else {
+            entries.remove(&cache_key);
         }
     }
 
     let mut wait_cell: Option<Arc<GrooveListInFlight>> = None;
     let mut leader_cell: Option<Arc<GrooveListInFlight>> = None;
     if let Some(cache_state) = app.try_state::<GrooveListCacheState>() {
-        if let Ok(mut in_flight) = cache_state.in_flight.lock() {
-            if let Some(existing) = in_flight.get(&cache_key) {
-                wait_cell = Some(existing.clone());
-            } else {
-                let cell = Arc::new(GrooveListInFlight::new());
-                in_flight.insert(cache_key.clone(), cell.clone());
-                leader_cell = Some(cell);
-            }
+        let mut in_flight = cache_state.lock_in_flight();
+        if let Some(existing) = in_flight.get(&cache_key) {
+            wait_cell = Some(existing.clone());
+        } else {
+            let cell = Arc::new(GrooveListInFlight::new());
+            in_flight.insert(cache_key.clone(), cell.clone());
+            leader_cell = Some(cell);
         }
     }
 
@@ -156,13 +186,13 @@ fn groove_list_blocking(
         if let Some(mut response) = stale_response {
             response.request_id = request_id;
             if telemetry_enabled {
-                eprintln!(
+                log_line(&format!(
                     "[startup-telemetry] event=groove_list resolve_ms={} exec_ms={} parse_ms={} total_ms={} stale_while_refresh=true collector=cache fallback_used=false",
                     resolve_elapsed.as_millis(),
                     exec_elapsed.as_millis(),
                     parse_elapsed.as_millis(),
                     total_started_at.elapsed().as_millis(),
-                );
+                ));
             }
             return response;
         }
@@ -175,8 +205,12 @@ fn groove_list_blocking(
                     ok: false,
                     workspace_root: Some(workspace_root.display().to_string()),
                     rows: HashMap::new(),
+                    order: None,
+                    filter: None,
+                    page: None,
                     stdout: String::new(),
                     stderr: String::new(),
+                    cancelled: None,
                     error: Some("Failed to wait for in-flight groove list request.".to_string()),
                 };
             }
@@ -191,8 +225,12 @@ fn groove_list_blocking(
                         ok: false,
                         workspace_root: Some(workspace_root.display().to_string()),
                         rows: HashMap::new(),
+                        order: None,
+                        filter: None,
+                        page: None,
                         stdout: String::new(),
                         stderr: String::new(),
+                        cancelled: None,
                         error: Some(
                             "Failed while waiting for in-flight groove list result.".to_string(),
                         ),
@@ -206,19 +244,23 @@ fn groove_list_blocking(
             ok: false,
             workspace_root: Some(workspace_root.display().to_string()),
             rows: HashMap::new(),
+            order: None,
+            filter: None,
+            page: None,
             stdout: String::new(),
             stderr: String::new(),
+            cancelled: None,
             error: Some("In-flight groove list request returned no response.".to_string()),
         });
         response.request_id = request_id;
         if telemetry_enabled {
-            eprintln!(
+            log_line(&format!(
                 "[startup-telemetry] event=groove_list resolve_ms={} exec_ms={} parse_ms={} total_ms={} deduped=true collector=cache fallback_used=false",
                 resolve_elapsed.as_millis(),
                 exec_elapsed.as_millis(),
                 parse_elapsed.as_millis(),
                 total_started_at.elapsed().as_millis(),
-            );
+            ));
         }
         return response;
     }
@@ -229,9 +271,21 @@ fn groove_list_blocking(
     let mut native_reused_worktrees = 0usize;
     let mut native_recomputed_worktrees = 0usize;
     let mut cache_native: Option<GrooveListNativeCache> = None;
+    let persisted_cache_key = groove_list_persisted_cache_key(&list_effective_root, &dir);
+    let mut native_cache_source = if previous_native_cache.is_some() {
+        "memory"
+    } else {
+        "none"
+    };
 
     let mut response = if groove_list_native_enabled() {
         let native_started_at = Instant::now();
+        if previous_native_cache.is_none() {
+            previous_native_cache = load_persisted_groove_list_cache(&app, &persisted_cache_key);
+            if previous_native_cache.is_some() {
+                native_cache_source = "disk";
+            }
+        }
         match collect_groove_list_rows_native(
             &list_effective_root,
             &known_worktrees,
@@ -243,14 +297,25 @@ fn groove_list_blocking(
                 collector = "native".to_string();
                 native_reused_worktrees = native.reused_worktrees;
                 native_recomputed_worktrees = native.recomputed_worktrees;
+                let cache_changed = native_recomputed_worktrees > 0
+                    || previous_native_cache.as_ref().map_or(0, |cache| {
+                        cache.rows_by_worktree.len()
+                    }) != native.cache.rows_by_worktree.len();
+                if cache_changed {
+                    persist_groove_list_cache(&app, &persisted_cache_key, &native.cache);
+                }
                 cache_native = Some(native.cache);
                 GrooveListResponse {
                     request_id,
                     ok: true,
                     workspace_root: Some(workspace_root.display().to_string()),
                     rows: native.rows,
+                    order: None,
+                    filter: None,
+                    page: None,
                     stdout: String::new(),
                     stderr: native.warning.unwrap_or_default(),
+                    cancelled: None,
                     error: None,
                 }
             }
@@ -269,8 +334,12 @@ fn groove_list_blocking(
                         ok: false,
                         workspace_root: Some(workspace_root.display().to_string()),
                         rows: HashMap::new(),
+                        order: None,
+                        filter: None,
+                        page: None,
                         stdout: result.stdout,
                         stderr: result.stderr,
+                        cancelled: None,
                         error: result
                             .error
                             .or_else(|| Some("groove list failed.".to_string())),
@@ -281,8 +350,12 @@ fn groove_list_blocking(
                         ok: true,
                         workspace_root: Some(workspace_root.display().to_string()),
                         rows,
+                        order: None,
+                        filter: None,
+                        page: None,
                         stdout: result.stdout,
                         stderr: result.stderr,
+                        cancelled: None,
                         error: None,
                     }
                 }
@@ -301,8 +374,12 @@ fn groove_list_blocking(
                 ok: false,
                 workspace_root: Some(workspace_root.display().to_string()),
                 rows: HashMap::new(),
+                order: None,
+                filter: None,
+                page: None,
                 stdout: result.stdout,
                 stderr: result.stderr,
+                cancelled: None,
                 error: result
                     .error
                     .or_else(|| Some("groove list failed.".to_string())),
@@ -313,13 +390,23 @@ fn groove_list_blocking(
                 ok: true,
                 workspace_root: Some(workspace_root.display().to_string()),
                 rows,
+                order: None,
+                filter: None,
+                page: None,
                 stdout: result.stdout,
                 stderr: result.stderr,
+                cancelled: None,
                 error: None,
             }
         }
     };
 
+    let native_restored_worktrees = if native_cache_source == "disk" {
+        native_reused_worktrees
+    } else {
+        0
+    };
+
     let terminal_integration = if response.ok {
         inject_groove_terminal_sessions_into_runtime_rows(&app, &workspace_root, &mut response.rows)
     } else {
@@ -342,15 +429,13 @@ fn groove_list_blocking(
                     *guard = Some(response.clone());
                     cell.cvar.notify_all();
                 }
-                if let Ok(mut in_flight) = cache_state.in_flight.lock() {
-                    in_flight.remove(&cache_key);
-                }
+                cache_state.lock_in_flight().remove(&cache_key);
             }
         }
 
         if telemetry_enabled {
-            eprintln!(
-                "[startup-telemetry] event=groove_list resolve_ms={} exec_ms={} parse_ms={} total_ms={} outcome=exec-error collector={} fallback_used={} native_error={} native_reused_worktrees={} native_recomputed_worktrees={} terminal_sessions={} terminal_workspace_sessions={} terminal_injected_worktrees={} terminal_integration_error={}",
+            log_line(&format!(
+                "[startup-telemetry] event=groove_list resolve_ms={} exec_ms={} parse_ms={} total_ms={} outcome=exec-error collector={} fallback_used={} native_error={} native_cache_source={} native_restored_worktrees={} native_reused_worktrees={} native_recomputed_worktrees={} terminal_sessions={} terminal_workspace_sessions={} terminal_injected_worktrees={} terminal_integration_error={}",
                 resolve_elapsed.as_millis(),
                 exec_elapsed.as_millis(),
                 parse_elapsed.as_millis(),
@@ -358,43 +443,42 @@ fn groove_list_blocking(
                 collector,
                 fallback_used,
                 native_error.is_some(),
+                native_cache_source,
+                native_restored_worktrees,
                 native_reused_worktrees,
                 native_recomputed_worktrees,
                 terminal_integration.session_count,
                 terminal_integration.workspace_session_count,
                 injected_worktrees,
                 terminal_integration.integration_error.is_some(),
-            );
+            ));
         }
 
         return response;
     }
 
     if let Some(cache_state) = app.try_state::<GrooveListCacheState>() {
-        if let Ok(mut entries) = cache_state.entries.lock() {
-            entries.insert(
-                cache_key.clone(),
-                GrooveListCacheEntry {
-                    created_at: Instant::now(),
-                    response: response.clone(),
-                    native_cache: cache_native,
-                },
-            );
-        }
+        cache_state.lock_entries().insert(
+            cache_key.clone(),
+            GrooveListCacheEntry {
+                created_at: Instant::now(),
+                response: response.clone(),
+                native_cache: cache_native,
+                invalidated: false,
+            },
+        );
         if let Some(cell) = leader_cell {
             if let Ok(mut guard) = cell.response.lock() {
                 *guard = Some(response.clone());
                 cell.cvar.notify_all();
             }
-            if let Ok(mut in_flight) = cache_state.in_flight.lock() {
-                in_flight.remove(&cache_key);
-            }
+            cache_state.lock_in_flight().remove(&cache_key);
         }
     }
 
     if telemetry_enabled {
-        eprintln!(
-            "[startup-telemetry] event=groove_list resolve_ms={} exec_ms={} parse_ms={} total_ms={} outcome=ok collector={} fallback_used={} native_error={} native_reused_worktrees={} native_recomputed_worktrees={} terminal_sessions={} terminal_workspace_sessions={} terminal_injected_worktrees={} terminal_integration_error={}",
+        log_line(&format!(
+            "[startup-telemetry] event=groove_list resolve_ms={} exec_ms={} parse_ms={} total_ms={} outcome=ok collector={} fallback_used={} native_error={} native_cache_source={} native_restored_worktrees={} native_reused_worktrees={} native_recomputed_worktrees={} terminal_sessions={} terminal_workspace_sessions={} terminal_injected_worktrees={} terminal_integration_error={}",
             resolve_elapsed.as_millis(),
             exec_elapsed.as_millis(),
             parse_elapsed.as_millis(),
@@ -402,13 +486,15 @@ fn groove_list_blocking(
             collector,
             fallback_used,
             native_error.is_some(),
+            native_cache_source,
+            native_restored_worktrees,
             native_reused_worktrees,
             native_recomputed_worktrees,
             terminal_integration.session_count,
             terminal_integration.workspace_session_count,
             injected_worktrees,
             terminal_integration.integration_error.is_some(),
-        );
+        ));
     }
 
     response
@@ -420,131 +506,124 @@ fn groove_restore(
     terminal_state: State<GrooveTerminalState>,
     payload: GrooveRestorePayload,
 ) -> GrooveCommandResponse {
-    let request_id = request_id();
-    let telemetry_enabled = telemetry_enabled_for_app(&app);
-
-    if payload.worktree.trim().is_empty() {
-        return GrooveCommandResponse {
-            request_id,
-            ok: false,
-            exit_code: None,
-            stdout: String::new(),
-            stderr: String::new(),
-            error: Some("worktree is required and must be a non-empty string.".to_string()),
-        };
-    }
-
-    let worktree = payload.worktree.trim().to_string();
-    if !is_safe_path_token(&worktree) {
-        return GrooveCommandResponse {
-            request_id,
-            ok: false,
-            exit_code: None,
-            stdout: String::new(),
-            stderr: String::new(),
-            error: Some("worktree contains unsafe characters or path segments.".to_string()),
-        };
-    }
-
-    let action = payload
-        .action
-        .as_deref()
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-        .unwrap_or("restore")
-        .to_string();
-    if action != "restore" && action != "go" {
-        return GrooveCommandResponse {
-            request_id,
-            ok: false,
-            exit_code: None,
-            stdout: String::new(),
-            stderr: String::new(),
-            error: Some("action must be either 'restore' or 'go' when provided.".to_string()),
-        };
-    }
+    let response = handle_command("groove_restore", |context| {
+        context.capture_subprocess_transcripts(payload.debug);
+        let request_id = context.request_id();
+        let telemetry_enabled = telemetry_enabled_for_app(&app);
 
-    let target = if action == "go" {
-        let Some(target) = payload
-            .target
-            .as_deref()
-            .map(str::trim)
-            .filter(|v| !v.is_empty())
-        else {
+        if payload.worktree.trim().is_empty() {
             return GrooveCommandResponse {
                 request_id,
                 ok: false,
                 exit_code: None,
                 stdout: String::new(),
                 stderr: String::new(),
-                error: Some(
-                    "target is required and must be a non-empty string when action is 'go'."
-                        .to_string(),
-                ),
+                error: Some("worktree is required and must be a non-empty string.".to_string()),
+                debug_transcripts: None,
             };
-        };
+        }
 
-        if !is_safe_path_token(target) {
+        let worktree = payload.worktree.trim().to_string();
+        if !is_safe_path_token(&worktree) {
             return GrooveCommandResponse {
                 request_id,
                 ok: false,
                 exit_code: None,
                 stdout: String::new(),
                 stderr: String::new(),
-                error: Some("target contains unsafe characters or path segments.".to_string()),
+                error: Some("worktree contains unsafe characters or path segments.".to_string()),
+                debug_transcripts: None,
             };
         }
 
-        Some(target.to_string())
-    } else {
-        None
-    };
-
-    log_play_telemetry(
-        telemetry_enabled,
-        "groove_restore.start",
-        format!(
-            "request_id={} action={} worktree={} target={} root_name_present={} known_worktrees={}",
-            request_id,
-            action,
-            worktree,
-            target.as_deref().unwrap_or("<none>"),
-            payload.root_name.is_some(),
-            payload.known_worktrees.len()
-        )
-        .as_str(),
-    );
-
-    let known_worktrees = match validate_known_worktrees(&payload.known_worktrees) {
-        Ok(known_worktrees) => known_worktrees,
-        Err(error) => {
+        let action = payload
+            .action
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .unwrap_or("restore")
+            .to_string();
+        if action != "restore" && action != "go" {
             return GrooveCommandResponse {
                 request_id,
                 ok: false,
                 exit_code: None,
                 stdout: String::new(),
                 stderr: String::new(),
-                error: Some(error),
-            }
+                error: Some("action must be either 'restore' or 'go' when provided.".to_string()),
+                debug_transcripts: None,
+            };
         }
-    };
 
-    let dir = match validate_optional_relative_path(&payload.dir, "dir") {
-        Ok(value) => value,
-        Err(error) => {
-            return GrooveCommandResponse {
+        let target = if action == "go" {
+            let Some(target) = payload
+                .target
+                .as_deref()
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+            else {
+                return GrooveCommandResponse {
+                    request_id,
+                    ok: false,
+                    exit_code: None,
+                    stdout: String::new(),
+                    stderr: String::new(),
+                    error: Some(
+                        "target is required and must be a non-empty string when action is 'go'."
+                            .to_string(),
+                    ),
+                    debug_transcripts: None,
+                };
+            };
+
+            if !is_safe_path_token(target) {
+                return GrooveCommandResponse {
+                    request_id,
+                    ok: false,
+                    exit_code: None,
+                    stdout: String::new(),
+                    stderr: String::new(),
+                    error: Some("target contains unsafe characters or path segments.".to_string()),
+                    debug_transcripts: None,
+                };
+            }
+
+            Some(target.to_string())
+        } else {
+            None
+        };
+
+        log_play_telemetry(
+            telemetry_enabled,
+            "groove_restore.start",
+            format!(
+                "request_id={} action={} worktree={} target={} root_name_present={} known_worktrees={}",
                 request_id,
-                ok: false,
-                exit_code: None,
-                stdout: String::new(),
-                stderr: String::new(),
-                error: Some(error),
+                action,
+                worktree,
+                target.as_deref().unwrap_or("<none>"),
+                payload.root_name.is_some(),
+                payload.known_worktrees.len()
+            )
+            .as_str(),
+        );
+
+        let known_worktrees = match validate_known_worktrees(&payload.known_worktrees) {
+            Ok(known_worktrees) => known_worktrees,
+            Err(error) => {
+                return GrooveCommandResponse {
+                    request_id,
+                    ok: false,
+                    exit_code: None,
+                    stdout: String::new(),
+                    stderr: String::new(),
+                    error: Some(error),
+                    debug_transcripts: None,
+                }
             }
-        }
-    };
+        };
 
-    let log_file =
-        match validate_optional_relative_path(&payload.opencode_log_file, "opencodeLogFile") {
+        let dir = match validate_optional_relative_path(&payload.dir, "dir") {
             Ok(value) => value,
             Err(error) => {
                 return GrooveCommandResponse {
@@ -554,590 +633,735 @@ fn groove_restore(
                     stdout: String::new(),
                     stderr: String::new(),
                     error: Some(error),
+                    debug_transcripts: None,
                 }
             }
         };
 
-    let mut root_resolution_attempts = Vec::new();
-    let explicit_workspace_root = payload
-        .workspace_root
-        .as_deref()
-        .map(str::trim)
-        .filter(|value| !value.is_empty());
+        let log_file =
+            match validate_optional_relative_path(&payload.opencode_log_file, "opencodeLogFile") {
+                Ok(value) => value,
+                Err(error) => {
+                    return GrooveCommandResponse {
+                        request_id,
+                        ok: false,
+                        exit_code: None,
+                        stdout: String::new(),
+                        stderr: String::new(),
+                        error: Some(error),
+                        debug_transcripts: None,
+                    }
+                }
+            };
 
-    let (workspace_root, root_fallback_used) = if let Some(workspace_root_hint) = explicit_workspace_root
-    {
-        match validate_workspace_root_path(workspace_root_hint) {
-            Ok(root) => {
-                root_resolution_attempts.push(format!(
-                    "payload.workspaceRoot={} (validated)",
-                    root.display()
-                ));
-                (root, false)
-            }
-            Err(error) => {
-                root_resolution_attempts.push(format!(
-                    "payload.workspaceRoot={} (invalid: {error})",
-                    workspace_root_hint
-                ));
-                let primary_result = resolve_workspace_root(
-                    &app,
-                    &payload.root_name,
-                    Some(&worktree),
-                    &known_worktrees,
-                    &payload.workspace_meta,
-                );
+        let mut root_resolution_attempts = Vec::new();
+        let explicit_workspace_root = payload
+            .workspace_root
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty());
 
-                match primary_result {
-                    Ok(root) => {
-                        root_resolution_attempts.push(
-                            "active-workspace/rootName with required worktree (resolved)".to_string(),
-                        );
-                        (root, false)
-                    }
-                    Err(primary_error) => {
-                        root_resolution_attempts.push(format!(
-                            "active-workspace/rootName with required worktree (failed: {primary_error})"
-                        ));
-                        log_play_telemetry(
-                            telemetry_enabled,
-                            "groove_restore.resolve_root_primary_failed",
-                            format!(
-                                "request_id={} worktree={} error={primary_error}",
-                                request_id, worktree
-                            )
-                            .as_str(),
-                        );
+        let (workspace_root, root_fallback_used) = if let Some(workspace_root_hint) =
+            explicit_workspace_root
+        {
+            match validate_workspace_root_path(workspace_root_hint) {
+                Ok(root) => {
+                    root_resolution_attempts.push(format!(
+                        "payload.workspaceRoot={} (validated)",
+                        root.display()
+                    ));
+                    (root, false)
+                }
+                Err(error) => {
+                    root_resolution_attempts.push(format!(
+                        "payload.workspaceRoot={} (invalid: {error})",
+                        workspace_root_hint
+                    ));
+                    let primary_result = resolve_workspace_root(
+                        &app,
+                        &payload.root_name,
+                        Some(&worktree),
+                        &known_worktrees,
+                        &payload.workspace_meta,
+                    );
 
-                        match resolve_workspace_root(
-                            &app,
-                            &payload.root_name,
-                            None,
-                            &known_worktrees,
-                            &payload.workspace_meta,
-                        ) {
-                            Ok(root) => {
-                                root_resolution_attempts.push(
-                                    "active-workspace/rootName without required worktree (resolved)"
-                                        .to_string(),
-                                );
-                                (root, true)
-                            }
-                            Err(secondary_error) => {
-                                root_resolution_attempts.push(format!(
-                                    "active-workspace/rootName without required worktree (failed: {secondary_error})"
-                                ));
-                                let combined_error = format!(
-                                    "Could not resolve workspace root for Play Groove. Attempted {}.",
-                                    root_resolution_attempts.join("; ")
-                                );
-                                log_play_telemetry(
-                                    telemetry_enabled,
-                                    "groove_restore.resolve_root_failed",
-                                    format!(
-                                        "request_id={} worktree={} error={combined_error}",
-                                        request_id, worktree
-                                    )
-                                    .as_str(),
-                                );
-                                return GrooveCommandResponse {
-                                    request_id,
-                                    ok: false,
-                                    exit_code: None,
-                                    stdout: String::new(),
-                                    stderr: String::new(),
-                                    error: Some(combined_error),
-                                };
+                    match primary_result {
+                        Ok(root) => {
+                            root_resolution_attempts.push(
+                                "active-workspace/rootName with required worktree (resolved)"
+                                    .to_string(),
+                            );
+                            (root, false)
+                        }
+                        Err(primary_error) => {
+                            root_resolution_attempts.push(format!(
+                                "active-workspace/rootName with required worktree (failed: {primary_error})"
+                            ));
+                            log_play_telemetry(
+                                telemetry_enabled,
+                                "groove_restore.resolve_root_primary_failed",
+                                format!(
+                                    "request_id={} worktree={} error={primary_error}",
+                                    request_id, worktree
+                                )
+                                .as_str(),
+                            );
+
+                            match resolve_workspace_root(
+                                &app,
+                                &payload.root_name,
+                                None,
+                                &known_worktrees,
+                                &payload.workspace_meta,
+                            ) {
+                                Ok(root) => {
+                                    root_resolution_attempts.push(
+                                        "active-workspace/rootName without required worktree (resolved)"
+                                            .to_string(),
+                                    );
+                                    (root, true)
+                                }
+                                Err(secondary_error) => {
+                                    root_resolution_attempts.push(format!(
+                                        "active-workspace/rootName without required worktree (failed: {secondary_error})"
+                                    ));
+                                    let combined_error = format!(
+                                        "Could not resolve workspace root for Play Groove. Attempted {}.",
+                                        root_resolution_attempts.join("; ")
+                                    );
+                                    log_play_telemetry(
+                                        telemetry_enabled,
+                                        "groove_restore.resolve_root_failed",
+                                        format!(
+                                            "request_id={} worktree={} error={combined_error}",
+                                            request_id, worktree
+                                        )
+                                        .as_str(),
+                                    );
+                                    return GrooveCommandResponse {
+                                        request_id,
+                                        ok: false,
+                                        exit_code: None,
+                                        stdout: String::new(),
+                                        stderr: String::new(),
+                                        error: Some(combined_error),
+                                        debug_transcripts: None,
+                                    };
+                                }
                             }
                         }
                     }
                 }
             }
-        }
-    } else {
-        root_resolution_attempts
-            .push("payload.workspaceRoot (not provided)".to_string());
-        let primary_result = resolve_workspace_root(
-            &app,
-            &payload.root_name,
-            Some(&worktree),
-            &known_worktrees,
-            &payload.workspace_meta,
-        );
+        } else {
+            root_resolution_attempts.push("payload.workspaceRoot (not provided)".to_string());
+            let primary_result = resolve_workspace_root(
+                &app,
+                &payload.root_name,
+                Some(&worktree),
+                &known_worktrees,
+                &payload.workspace_meta,
+            );
 
-        match primary_result {
-            Ok(root) => {
-                root_resolution_attempts
-                    .push("active-workspace/rootName with required worktree (resolved)".to_string());
-                (root, false)
-            }
-            Err(primary_error) => {
-                root_resolution_attempts.push(format!(
-                    "active-workspace/rootName with required worktree (failed: {primary_error})"
-                ));
-                log_play_telemetry(
-                    telemetry_enabled,
-                    "groove_restore.resolve_root_primary_failed",
-                    format!("request_id={} worktree={} error={primary_error}", request_id, worktree)
+            match primary_result {
+                Ok(root) => {
+                    root_resolution_attempts.push(
+                        "active-workspace/rootName with required worktree (resolved)".to_string(),
+                    );
+                    (root, false)
+                }
+                Err(primary_error) => {
+                    root_resolution_attempts.push(format!(
+                        "active-workspace/rootName with required worktree (failed: {primary_error})"
+                    ));
+                    log_play_telemetry(
+                        telemetry_enabled,
+                        "groove_restore.resolve_root_primary_failed",
+                        format!(
+                            "request_id={} worktree={} error={primary_error}",
+                            request_id, worktree
+                        )
                         .as_str(),
-                );
-                match resolve_workspace_root(
-                    &app,
-                    &payload.root_name,
-                    None,
-                    &known_worktrees,
-                    &payload.workspace_meta,
-                ) {
-                    Ok(root) => {
-                        root_resolution_attempts.push(
-                            "active-workspace/rootName without required worktree (resolved)"
-                                .to_string(),
-                        );
-                        (root, true)
-                    }
-                    Err(secondary_error) => {
-                        root_resolution_attempts.push(format!(
-                            "active-workspace/rootName without required worktree (failed: {secondary_error})"
-                        ));
-                        let combined_error = format!(
-                            "Could not resolve workspace root for Play Groove. Attempted {}.",
-                            root_resolution_attempts.join("; ")
-                        );
-                        log_play_telemetry(
-                            telemetry_enabled,
-                            "groove_restore.resolve_root_failed",
-                            format!("request_id={} worktree={} error={combined_error}", request_id, worktree)
+                    );
+                    match resolve_workspace_root(
+                        &app,
+                        &payload.root_name,
+                        None,
+                        &known_worktrees,
+                        &payload.workspace_meta,
+                    ) {
+                        Ok(root) => {
+                            root_resolution_attempts.push(
+                                "active-workspace/rootName without required worktree (resolved)"
+                                    .to_string(),
+                            );
+                            (root, true)
+                        }
+                        Err(secondary_error) => {
+                            root_resolution_attempts.push(format!(
+                                "active-workspace/rootName without required worktree (failed: {secondary_error})"
+                            ));
+                            let combined_error = format!(
+                                "Could not resolve workspace root for Play Groove. Attempted {}.",
+                                root_resolution_attempts.join("; ")
+                            );
+                            log_play_telemetry(
+                                telemetry_enabled,
+                                "groove_restore.resolve_root_failed",
+                                format!(
+                                    "request_id={} worktree={} error={combined_error}",
+                                    request_id, worktree
+                                )
                                 .as_str(),
-                        );
-                        return GrooveCommandResponse {
-                            request_id,
-                            ok: false,
-                            exit_code: None,
-                            stdout: String::new(),
-                            stderr: String::new(),
-                            error: Some(combined_error),
-                        };
+                            );
+                            return GrooveCommandResponse {
+                                request_id,
+                                ok: false,
+                                exit_code: None,
+                                stdout: String::new(),
+                                stderr: String::new(),
+                                error: Some(combined_error),
+                                debug_transcripts: None,
+                            };
+                        }
                     }
                 }
             }
-        }
-    };
-    let workspace_root_rendered = workspace_root.display().to_string();
-    log_play_telemetry(
-        telemetry_enabled,
-        "groove_restore.resolve_root_ok",
-        format!(
-            "request_id={} workspace_root={} fallback_used={}",
-            request_id, workspace_root_rendered, root_fallback_used
-        )
-        .as_str(),
-    );
+        };
+        let workspace_root_rendered = workspace_root.display().to_string();
+        log_play_telemetry(
+            telemetry_enabled,
+            "groove_restore.resolve_root_ok",
+            format!(
+                "request_id={} workspace_root={} fallback_used={}",
+                request_id, workspace_root_rendered, root_fallback_used
+            )
+            .as_str(),
+        );
 
-    let tombstone = match read_worktree_tombstone(&app, &workspace_root, &worktree) {
-        Ok(value) => value,
-        Err(error) => {
-            return GrooveCommandResponse {
-                request_id,
-                ok: false,
-                exit_code: None,
-                stdout: String::new(),
-                stderr: String::new(),
-                error: Some(error),
+        let tombstone = match read_worktree_tombstone(&app, &workspace_root, &worktree) {
+            Ok(value) => value,
+            Err(error) => {
+                return GrooveCommandResponse {
+                    request_id,
+                    ok: false,
+                    exit_code: None,
+                    stdout: String::new(),
+                    stderr: String::new(),
+                    error: Some(error),
+                    debug_transcripts: None,
+                }
             }
-        }
-    };
+        };
 
-    let inferred_worktree_dir = if dir.is_none() {
-        tombstone.as_ref().and_then(|value| {
-            let tombstone_path = Path::new(&value.worktree_path);
-            tombstone_path
-                .parent()
-                .and_then(|parent| parent.strip_prefix(&workspace_root).ok())
-                .and_then(|relative| {
-                    let rendered = relative.display().to_string();
-                    if rendered.is_empty() {
-                        None
-                    } else {
-                        Some(rendered)
-                    }
-                })
-        })
-    } else {
-        None
-    };
+        let inferred_worktree_dir = if dir.is_none() {
+            tombstone.as_ref().and_then(|value| {
+                let tombstone_path = Path::new(&value.worktree_path);
+                tombstone_path
+                    .parent()
+                    .and_then(|parent| parent.strip_prefix(&workspace_root).ok())
+                    .and_then(|relative| {
+                        let rendered = relative.display().to_string();
+                        if rendered.is_empty() {
+                            None
+                        } else {
+                            Some(rendered)
+                        }
+                    })
+            })
+        } else {
+            None
+        };
 
-    let worktree_dir = dir
-        .clone()
-        .or(inferred_worktree_dir)
-        .unwrap_or_else(|| ".worktrees".to_string());
-    let effective_root = ensure_workspace_meta(&workspace_root)
-        .map(|(meta, _)| effective_workspace_root(&workspace_root, &meta))
-        .unwrap_or_else(|_| workspace_root.clone());
-    let worktree_candidates = worktree_path_token_candidates(&worktree);
-    let mut expected_worktree_path = resolve_worktree_path_for_candidates(
-        &effective_root,
-        &worktree_dir,
-        &worktree_candidates,
-    )
-    .unwrap_or_else(|| effective_root.join(&worktree_dir).join(&worktree_candidates[0]));
-    log_play_telemetry(
-        telemetry_enabled,
-        "groove_restore.resolve_worktree",
-        format!(
-            "request_id={} workspace_root={} worktree_dir={} expected_worktree_path={}",
-            request_id,
-            workspace_root_rendered,
-            worktree_dir,
-            expected_worktree_path.display()
+        let worktree_dir = dir
+            .clone()
+            .or(inferred_worktree_dir)
+            .unwrap_or_else(|| ".worktrees".to_string());
+        let effective_root = ensure_workspace_meta(&workspace_root)
+            .map(|(meta, _)| effective_workspace_root(&workspace_root, &meta))
+            .unwrap_or_else(|_| workspace_root.clone());
+        let worktree_records = read_worktree_records(&workspace_root);
+        let mut worktree_candidates = worktree_path_token_candidates(&worktree);
+        if let Some(mapped) = worktree_for_branch_from_records(&worktree_records, &worktree) {
+            if !worktree_candidates.contains(&mapped) {
+                worktree_candidates.push(mapped);
+            }
+        }
+        let mut expected_worktree_path = resolve_worktree_path_for_candidates(
+            &effective_root,
+            &worktree_dir,
+            &worktree_candidates,
         )
-        .as_str(),
-    );
-    if !path_is_directory(&expected_worktree_path) {
-        let recreate_branch = tombstone
-            .as_ref()
-            .and_then(|value| value.branch_name.as_deref())
-            .map(str::trim)
-            .filter(|value| !value.is_empty())
-            .map(|value| value.to_string())
-            .unwrap_or_else(|| branch_guess_from_worktree_name(&worktree));
-
+        .unwrap_or_else(|| {
+            effective_root
+                .join(&worktree_dir)
+                .join(&worktree_candidates[0])
+        });
         log_play_telemetry(
             telemetry_enabled,
-            "groove_restore.recreate_missing_worktree",
+            "groove_restore.resolve_worktree",
             format!(
-                "request_id={} worktree={} recreate_branch={} worktree_dir={}",
-                request_id, worktree, recreate_branch, worktree_dir
+                "request_id={} workspace_root={} worktree_dir={} expected_worktree_path={}",
+                request_id,
+                workspace_root_rendered,
+                worktree_dir,
+                expected_worktree_path.display()
             )
             .as_str(),
         );
+        if !path_is_directory(&expected_worktree_path) {
+            let recreate_branch = tombstone
+                .as_ref()
+                .and_then(|value| value.branch_name.as_deref())
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(|value| value.to_string())
+                .unwrap_or_else(|| worktree_branch_from_records(&worktree_records, &worktree));
 
-        let mut create_args = vec!["create".to_string(), recreate_branch];
-        if worktree_dir != ".worktrees" {
-            create_args.push("--dir".to_string());
-            create_args.push(worktree_dir.clone());
-        }
-
-        let recreate_result = run_command(&groove_binary_path(&app), &create_args, &effective_root);
-        if recreate_result.exit_code != Some(0) || recreate_result.error.is_some() {
             log_play_telemetry(
                 telemetry_enabled,
-                "groove_restore.recreate_failed",
+                "groove_restore.recreate_missing_worktree",
                 format!(
-                    "request_id={} worktree={} exit_code={:?} error={}",
-                    request_id,
-                    worktree,
-                    recreate_result.exit_code,
-                    recreate_result
-                        .error
-                        .as_deref()
-                        .unwrap_or("Failed to recreate missing worktree before restore.")
+                    "request_id={} worktree={} recreate_branch={} worktree_dir={}",
+                    request_id, worktree, recreate_branch, worktree_dir
                 )
                 .as_str(),
             );
-            return GrooveCommandResponse {
-                request_id,
-                ok: false,
-                exit_code: recreate_result.exit_code,
-                stdout: recreate_result.stdout,
-                stderr: recreate_result.stderr,
-                error: recreate_result.error.or_else(|| {
-                    Some("Failed to recreate missing worktree before restore.".to_string())
-                }),
-            };
-        }
 
-        expected_worktree_path = resolve_worktree_path_for_candidates(
-            &effective_root,
-            &worktree_dir,
-            &worktree_candidates,
-        )
-        .unwrap_or(expected_worktree_path);
+            let recreate_dir_name = worktree_candidates
+                .last()
+                .cloned()
+                .unwrap_or_else(|| worktree.clone());
+            let mut create_args = vec!["create".to_string(), recreate_branch.clone()];
+            if worktree_dir != ".worktrees" {
+                create_args.push("--dir".to_string());
+                create_args.push(worktree_dir.clone());
+            }
+            if recreate_dir_name != workspace::worktree_dir_name_for_branch(&recreate_branch) {
+                create_args.push("--name".to_string());
+                create_args.push(recreate_dir_name);
+            }
 
-        if !path_is_directory(&expected_worktree_path) {
-            return GrooveCommandResponse {
-                request_id,
-                ok: false,
-                exit_code: recreate_result.exit_code,
-                stdout: recreate_result.stdout,
-                stderr: recreate_result.stderr,
-                error: Some(format!(
-                    "Worktree directory is still missing after recreation at \"{}\".",
-                    expected_worktree_path.display()
-                )),
-            };
+            let recreate_result =
+                run_command(&groove_binary_path(&app), &create_args, &effective_root);
+            if recreate_result.exit_code != Some(0) || recreate_result.error.is_some() {
+                log_play_telemetry(
+                    telemetry_enabled,
+                    "groove_restore.recreate_failed",
+                    format!(
+                        "request_id={} worktree={} exit_code={:?} error={}",
+                        request_id,
+                        worktree,
+                        recreate_result.exit_code,
+                        recreate_result
+                            .error
+                            .as_deref()
+                            .unwrap_or("Failed to recreate missing worktree before restore.")
+                    )
+                    .as_str(),
+                );
+                return GrooveCommandResponse {
+                    request_id,
+                    ok: false,
+                    exit_code: recreate_result.exit_code,
+                    stdout: recreate_result.stdout,
+                    stderr: recreate_result.stderr,
+                    error: recreate_result.error.or_else(|| {
+                        Some("Failed to recreate missing worktree before restore.".to_string())
+                    }),
+                    debug_transcripts: None,
+                };
+            }
+
+            expected_worktree_path = resolve_worktree_path_for_candidates(
+                &effective_root,
+                &worktree_dir,
+                &worktree_candidates,
+            )
+            .unwrap_or(expected_worktree_path);
+
+            if !path_is_directory(&expected_worktree_path) {
+                return GrooveCommandResponse {
+                    request_id,
+                    ok: false,
+                    exit_code: recreate_result.exit_code,
+                    stdout: recreate_result.stdout,
+                    stderr: recreate_result.stderr,
+                    error: Some(format!(
+                        "Worktree directory is still missing after recreation at \"{}\".",
+                        expected_worktree_path.display()
+                    )),
+                    debug_transcripts: None,
+                };
+            }
         }
-    }
 
-    let mut ensure_errors = Vec::new();
-    let ensured_worktree_path = worktree_candidates
-        .iter()
-        .find_map(|candidate| match ensure_worktree_in_dir(&effective_root, candidate, &worktree_dir)
-        {
-            Ok(path) => Some(path),
-            Err(error) => {
-                ensure_errors.push(error);
-                None
+        let mut ensure_errors = Vec::new();
+        let ensured_worktree_path = worktree_candidates.iter().find_map(|candidate| {
+            match ensure_worktree_in_dir(&effective_root, candidate, &worktree_dir) {
+                Ok(path) => Some(path),
+                Err(error) => {
+                    ensure_errors.push(error);
+                    None
+                }
             }
         });
-    let Some(ensured_worktree_path) = ensured_worktree_path else {
-        return GrooveCommandResponse {
-            request_id,
-            ok: false,
-            exit_code: None,
-            stdout: String::new(),
-            stderr: String::new(),
-            error: Some(ensure_errors.join(" ")),
+        let Some(ensured_worktree_path) = ensured_worktree_path else {
+            return GrooveCommandResponse {
+                request_id,
+                ok: false,
+                exit_code: None,
+                stdout: String::new(),
+                stderr: String::new(),
+                error: Some(ensure_errors.join(" ")),
+                debug_transcripts: None,
+            };
         };
-    };
-    expected_worktree_path = ensured_worktree_path;
+        expected_worktree_path = ensured_worktree_path;
 
-    ensure_claude_hooks(&expected_worktree_path, &worktree);
+        ensure_claude_hooks(&expected_worktree_path, &worktree);
 
-    let mut result = if action == "go" {
-        let play_groove_command = play_groove_command_for_workspace(&workspace_root);
-        let command_template = play_groove_command.trim();
-        let play_target = target.clone().unwrap_or_default();
-        log_play_telemetry(
-            telemetry_enabled,
-            "groove_restore.go_mode",
-            format!(
-                "request_id={} workspace_root={} worktree={} target={} mode={}",
-                request_id,
-                workspace_root_rendered,
-                worktree,
-                play_target,
-                if is_groove_terminal_play_command(command_template) {
-                    "sentinel"
+        let mut result = if action == "go" {
+            let play_groove_command = play_groove_command_for_worktree(&workspace_root, &worktree);
+            let command_template = play_groove_command.trim();
+            let play_target = target.clone().unwrap_or_default();
+            log_play_telemetry(
+                telemetry_enabled,
+                "groove_restore.go_mode",
+                format!(
+                    "request_id={} workspace_root={} worktree={} target={} mode={}",
+                    request_id,
+                    workspace_root_rendered,
+                    worktree,
+                    play_target,
+                    if is_groove_terminal_play_command(command_template) {
+                        "sentinel"
+                    } else if is_groove_tmux_command(command_template) {
+                        "tmux"
+                    } else {
+                        "custom"
+                    }
+                )
+                .as_str(),
+            );
+            if is_groove_terminal_play_command(command_template) {
+                let terminal_open_mode = if is_groove_terminal_claude_code_command(command_template)
+                {
+                    GrooveTerminalOpenMode::ClaudeCode
                 } else {
-                    "custom"
-                }
-            )
-            .as_str(),
-        );
-        if is_groove_terminal_play_command(command_template) {
-            let terminal_open_mode = if is_groove_terminal_claude_code_command(command_template) {
-                GrooveTerminalOpenMode::ClaudeCode
-            } else {
-                GrooveTerminalOpenMode::Opencode
-            };
-            match open_groove_terminal_session(
-                &app,
-                &terminal_state,
-                &workspace_root,
-                &worktree,
-                &expected_worktree_path,
-                terminal_open_mode,
-                Some(play_target.as_str()),
-                None,
-                None,
-                false,
-                true,
-                true,
-            ) {
-                Ok(session) => {
-                    if is_groove_terminal_claude_code_command(command_template) {
-                        mark_claude_session_started(&workspace_root, &worktree);
+                    GrooveTerminalOpenMode::Opencode
+                };
+                match open_groove_terminal_session(
+                    &app,
+                    &terminal_state,
+                    &workspace_root,
+                    &worktree,
+                    &expected_worktree_path,
+                    terminal_open_mode,
+                    Some(play_target.as_str()),
+                    None,
+                    None,
+                    false,
+                    true,
+                    true,
+                ) {
+                    Ok(session) => {
+                        if is_groove_terminal_claude_code_command(command_template) {
+                            mark_claude_session_started(&workspace_root, &worktree);
+                        }
+                        log_play_telemetry(
+                            telemetry_enabled,
+                            "groove_restore.go_terminal_session_ok",
+                            format!(
+                                "request_id={} worktree={} session_id={} command={} cwd={}",
+                                request_id,
+                                worktree,
+                                session.session_id,
+                                session.command,
+                                session.worktree_path
+                            )
+                            .as_str(),
+                        );
+                        CommandResult {
+                            exit_code: Some(0),
+                            stdout: format!(
+                                "Started Groove terminal session {} using: {}",
+                                session.session_id, session.command
+                            ),
+                            stderr: String::new(),
+                            error: None,
+                        }
                     }
-                    log_play_telemetry(
-                        telemetry_enabled,
-                        "groove_restore.go_terminal_session_ok",
-                        format!(
-                            "request_id={} worktree={} session_id={} command={} cwd={}",
-                            request_id,
-                            worktree,
-                            session.session_id,
-                            session.command,
-                            session.worktree_path
-                        )
-                        .as_str(),
-                    );
-                    CommandResult {
-                        exit_code: Some(0),
-                        stdout: format!(
-                            "Started Groove terminal session {} using: {}",
-                            session.session_id, session.command
-                        ),
-                        stderr: String::new(),
-                        error: None,
+                    Err(error) => {
+                        log_play_telemetry(
+                            telemetry_enabled,
+                            "groove_restore.go_terminal_session_failed",
+                            format!(
+                                "request_id={} worktree={} error={error}",
+                                request_id, worktree
+                            )
+                            .as_str(),
+                        );
+                        CommandResult {
+                            exit_code: None,
+                            stdout: String::new(),
+                            stderr: String::new(),
+                            error: Some(error),
+                        }
                     }
                 }
-                Err(error) => {
-                    log_play_telemetry(
-                        telemetry_enabled,
-                        "groove_restore.go_terminal_session_failed",
-                        format!("request_id={} worktree={} error={error}", request_id, worktree)
+            } else if is_groove_tmux_command(command_template) {
+                let opened = ensure_workspace_meta(&workspace_root).and_then(|(meta, _)| {
+                    open_tmux_worktree_window(
+                        &meta,
+                        &expected_worktree_path,
+                        &[resolve_opencode_bin()],
+                    )
+                });
+                match opened {
+                    Ok(window) => {
+                        log_play_telemetry(
+                            telemetry_enabled,
+                            "groove_restore.go_tmux_ok",
+                            format!(
+                                "request_id={} worktree={} window={window}",
+                                request_id, worktree
+                            )
                             .as_str(),
-                    );
-                    CommandResult {
-                        exit_code: None,
-                        stdout: String::new(),
-                        stderr: String::new(),
-                        error: Some(error),
+                        );
+                        CommandResult {
+                            exit_code: Some(0),
+                            stdout: format!("Started opencode in {window}"),
+                            stderr: String::new(),
+                            error: None,
+                        }
                     }
-                }
-            }
-        } else {
-            let (program, command_args) = match resolve_play_groove_command(
-                command_template,
-                &play_target,
-                &expected_worktree_path,
-            ) {
-                Ok(value) => value,
-                Err(error) => {
-                    log_play_telemetry(
-                        telemetry_enabled,
-                        "groove_restore.go_command_resolve_failed",
-                        format!("request_id={} worktree={} error={error}", request_id, worktree)
+                    Err(error) => {
+                        log_play_telemetry(
+                            telemetry_enabled,
+                            "groove_restore.go_tmux_failed",
+                            format!(
+                                "request_id={} worktree={} error={error}",
+                                request_id, worktree
+                            )
                             .as_str(),
-                    );
-                    return GrooveCommandResponse {
-                        request_id,
-                        ok: false,
-                        exit_code: None,
-                        stdout: String::new(),
-                        stderr: String::new(),
-                        error: Some(error),
-                    };
+                        );
+                        CommandResult {
+                            exit_code: None,
+                            stdout: String::new(),
+                            stderr: String::new(),
+                            error: Some(error),
+                        }
+                    }
                 }
-            };
+            } else {
+                let resolved = authorize_workspace_command(
+                    &app,
+                    &workspace_root,
+                    command_template,
+                    "its play command",
+                )
+                .and_then(|()| {
+                    resolve_play_groove_command(
+                        command_template,
+                        &play_target,
+                        resolve_branch_from_worktree(&expected_worktree_path).as_deref(),
+                        &expected_worktree_path,
+                    )
+                });
+                let (program, command_args, _) = match resolved {
+                    Ok(value) => value,
+                    Err(error) => {
+                        log_play_telemetry(
+                            telemetry_enabled,
+                            "groove_restore.go_command_resolve_failed",
+                            format!(
+                                "request_id={} worktree={} error={error}",
+                                request_id, worktree
+                            )
+                            .as_str(),
+                        );
+                        return GrooveCommandResponse {
+                            request_id,
+                            ok: false,
+                            exit_code: None,
+                            stdout: String::new(),
+                            stderr: String::new(),
+                            error: Some(error),
+                            debug_transcripts: None,
+                        };
+                    }
+                };
 
-            match spawn_terminal_process(
-                &program,
-                &command_args,
-                &expected_worktree_path,
-                &expected_worktree_path,
-            ) {
-                Ok(()) => {
-                    log_play_telemetry(
-                        telemetry_enabled,
-                        "groove_restore.go_custom_command_ok",
-                        format!("request_id={} worktree={} program={}", request_id, worktree, program)
+                match spawn_testing_environment_process(
+                    &workspace_root,
+                    &worktree,
+                    &program,
+                    &command_args,
+                    &expected_worktree_path,
+                ) {
+                    Ok(()) => {
+                        log_play_telemetry(
+                            telemetry_enabled,
+                            "groove_restore.go_custom_command_ok",
+                            format!(
+                                "request_id={} worktree={} program={}",
+                                request_id, worktree, program
+                            )
                             .as_str(),
-                    );
-                    CommandResult {
-                        exit_code: Some(0),
-                        stdout: std::iter::once(program.as_str())
-                            .chain(command_args.iter().map(|value| value.as_str()))
-                            .collect::<Vec<_>>()
-                            .join(" "),
-                        stderr: String::new(),
-                        error: None,
+                        );
+                        CommandResult {
+                            exit_code: Some(0),
+                            stdout: std::iter::once(program.as_str())
+                                .chain(command_args.iter().map(|value| value.as_str()))
+                                .collect::<Vec<_>>()
+                                .join(" "),
+                            stderr: String::new(),
+                            error: None,
+                        }
                     }
-                }
-                Err(error) => {
-                    log_play_telemetry(
-                        telemetry_enabled,
-                        "groove_restore.go_custom_command_failed",
-                        format!(
-                            "request_id={} worktree={} program={} error={error}",
-                            request_id, worktree, program
-                        )
-                        .as_str(),
-                    );
-                    CommandResult {
-                        exit_code: None,
-                        stdout: String::new(),
-                        stderr: String::new(),
-                        error: Some(format!(
-                            "Failed to launch Play Groove command {program}: {error}"
-                        )),
+                    Err(error) => {
+                        log_play_telemetry(
+                            telemetry_enabled,
+                            "groove_restore.go_custom_command_failed",
+                            format!(
+                                "request_id={} worktree={} program={} error={error}",
+                                request_id, worktree, program
+                            )
+                            .as_str(),
+                        );
+                        CommandResult {
+                            exit_code: None,
+                            stdout: String::new(),
+                            stderr: String::new(),
+                            error: Some(format!(
+                                "Failed to launch Play Groove command {program}: {error}"
+                            )),
+                        }
                     }
                 }
             }
-        }
-    } else {
-        let mut args = vec!["restore".to_string(), worktree.clone()];
-        if let Some(dir) = dir {
-            args.push("--dir".to_string());
-            args.push(dir);
-        }
-        if let Some(log_file) = log_file {
-            args.push("--opencode-log-file".to_string());
-            args.push(log_file);
-        }
-        run_command(&groove_binary_path(&app), &args, &effective_root)
-    };
-    let ok = result.exit_code == Some(0) && result.error.is_none();
-    if ok {
-        let stamped_worktree = expected_worktree_path
-            .file_name()
-            .and_then(|value| value.to_str())
-            .unwrap_or_else(|| payload.worktree.trim());
-        if let Err(error) =
-            record_worktree_last_executed_at(&app, &workspace_root, stamped_worktree)
-        {
-            log_play_telemetry(
-                telemetry_enabled,
-                "groove_restore.record_last_executed_failed",
-                format!("request_id={} worktree={} error={error}", request_id, worktree).as_str(),
-            );
-            return GrooveCommandResponse {
-                request_id,
-                ok: false,
-                exit_code: result.exit_code,
-                stdout: result.stdout,
-                stderr: result.stderr,
-                error: Some(error),
-            };
-        }
+        } else {
+            let mut args = vec!["restore".to_string(), worktree.clone()];
+            if let Some(dir) = dir {
+                args.push("--dir".to_string());
+                args.push(dir);
+            }
+            if let Some(log_file) = log_file {
+                args.push("--opencode-log-file".to_string());
+                args.push(log_file);
+            }
+            let stream = payload.stream.then_some(CommandOutputStream {
+                command: "groove_restore",
+                request_id: &request_id,
+            });
+            run_command_with_output_stream(
+                &groove_binary_path(&app),
+                &args,
+                &effective_root,
+                &[],
+                stream.as_ref(),
+            )
+        };
+        let ok = result.exit_code == Some(0) && result.error.is_none();
+        if ok {
+            let stamped_worktree = expected_worktree_path
+                .file_name()
+                .and_then(|value| value.to_str())
+                .unwrap_or_else(|| payload.worktree.trim());
+            if let Err(error) =
+                record_worktree_last_executed_at(&app, &workspace_root, stamped_worktree)
+            {
+                log_play_telemetry(
+                    telemetry_enabled,
+                    "groove_restore.record_last_executed_failed",
+                    format!(
+                        "request_id={} worktree={} error={error}",
+                        request_id, worktree
+                    )
+                    .as_str(),
+                );
+                return GrooveCommandResponse {
+                    request_id,
+                    ok: false,
+                    exit_code: result.exit_code,
+                    stdout: result.stdout,
+                    stderr: result.stderr,
+                    error: Some(error),
+                    debug_transcripts: None,
+                };
+            }
 
-        if let Err(error) = clear_worktree_tombstone(&app, &workspace_root, &worktree) {
-            log_play_telemetry(
-                telemetry_enabled,
-                "groove_restore.clear_tombstone_failed",
-                format!("request_id={} worktree={} error={error}", request_id, worktree).as_str(),
-            );
-            return GrooveCommandResponse {
-                request_id,
-                ok: false,
-                exit_code: result.exit_code,
-                stdout: result.stdout,
-                stderr: result.stderr,
-                error: Some(error),
-            };
-        }
+            if let Err(error) = clear_worktree_tombstone(&app, &workspace_root, &worktree) {
+                log_play_telemetry(
+                    telemetry_enabled,
+                    "groove_restore.clear_tombstone_failed",
+                    format!(
+                        "request_id={} worktree={} error={error}",
+                        request_id, worktree
+                    )
+                    .as_str(),
+                );
+                return GrooveCommandResponse {
+                    request_id,
+                    ok: false,
+                    exit_code: result.exit_code,
+                    stdout: result.stdout,
+                    stderr: result.stderr,
+                    error: Some(error),
+                    debug_transcripts: None,
+                };
+            }
 
-        if action == "restore" {
-            let symlink_warnings =
-                apply_configured_worktree_symlinks(&workspace_root, &expected_worktree_path);
-            if !symlink_warnings.is_empty() {
-                if !result.stderr.trim().is_empty() {
-                    result.stderr.push('\n');
+            if action == "restore" {
+                let symlink_warnings =
+                    apply_configured_worktree_symlinks(&workspace_root, &expected_worktree_path);
+                if !symlink_warnings.is_empty() {
+                    if !result.stderr.trim().is_empty() {
+                        result.stderr.push('\n');
+                    }
+                    result
+                        .stderr
+                        .push_str(&format!("Warning: {}", symlink_warnings.join("; ")));
                 }
-                result.stderr.push_str(&format!(
-                    "Warning: {}",
-                    symlink_warnings.join("; ")
-                ));
             }
+
+            invalidate_workspace_caches(&app, &workspace_root);
         }
 
-        invalidate_workspace_context_cache(&app, &workspace_root);
-        invalidate_groove_list_cache_for_workspace(&app, &workspace_root);
-    }
+        log_play_telemetry(
+            telemetry_enabled,
+            "groove_restore.result",
+            format!(
+                "request_id={} action={} worktree={} ok={} exit_code={:?} error={}",
+                request_id,
+                action,
+                worktree,
+                ok,
+                result.exit_code,
+                result.error.as_deref().unwrap_or("<none>")
+            )
+            .as_str(),
+        );
 
-    log_play_telemetry(
-        telemetry_enabled,
-        "groove_restore.result",
-        format!(
-            "request_id={} action={} worktree={} ok={} exit_code={:?} error={}",
+        GrooveCommandResponse {
             request_id,
-            action,
-            worktree,
             ok,
-            result.exit_code,
-            result.error.as_deref().unwrap_or("<none>")
-        )
-        .as_str(),
+            exit_code: result.exit_code,
+            stdout: result.stdout,
+            stderr: result.stderr,
+            error: result.error,
+            debug_transcripts: None,
+        }
+    });
+    record_action_history(
+        &app,
+        "groove_restore",
+        &payload.worktree,
+        &payload,
+        &response,
     );
-
-    GrooveCommandResponse {
-        request_id,
-        ok,
-        exit_code: result.exit_code,
-        stdout: result.stdout,
-        stderr: result.stderr,
-        error: result.error,
-    }
+    response
 }
 
 fn worktree_path_token_candidates(worktree: &str) -> Vec<String> {
@@ -1172,23 +1396,18 @@ fn resolve_worktree_path_for_candidates(
 
 #[tauri::command]
 async fn groove_summary(app: AppHandle, payload: GrooveSummaryPayload) -> GrooveSummaryResponse {
-    let request_id = request_id();
-    let fallback_request_id = request_id.clone();
-
-    match tauri::async_runtime::spawn_blocking(move || {
-        groove_summary_blocking(app, payload, request_id)
-    })
-    .await
-    {
-        Ok(response) => response,
-        Err(error) => GrooveSummaryResponse {
-            request_id: fallback_request_id,
+    handle_blocking_command(
+        "groove_summary",
+        move |context| groove_summary_blocking(app, payload, context.request_id()),
+        |request_id, error| GrooveSummaryResponse {
+            request_id,
             ok: false,
             summaries: Vec::new(),
             compiled_summary: None,
-            error: Some(format!("Failed to run groove summary worker thread: {error}")),
+            error: Some(error),
         },
-    }
+    )
+    .await
 }
 
 /// Parse "RESUMEN: <one_liner>\n---\n<content>" format. Falls back gracefully.
@@ -1285,11 +1504,15 @@ fn groove_summary_blocking(
         let resolved_session_id =
             resolve_existing_claude_session_id(&cwd, session_id).unwrap_or_else(|| session_id.clone());
 
-        eprintln!(
+        log_line(&format!(
             "[groove-summary] running claude --resume {} (stored={}) -p '...' --output-format text",
             resolved_session_id, session_id
-        );
-        eprintln!("[groove-summary] claude_bin={} cwd={}", claude_bin, cwd.display());
+        ));
+        log_line(&format!(
+            "[groove-summary] claude_bin={} cwd={}",
+            claude_bin,
+            cwd.display()
+        ));
 
         let output = Command::new(&claude_bin)
             .args([
@@ -1308,7 +1531,11 @@ fn groove_summary_blocking(
         match output {
             Ok(out) if out.status.success() => {
                 let summary_text = String::from_utf8_lossy(&out.stdout).trim().to_string();
-                eprintln!("[groove-summary] session {} ok, summary_len={}", session_id, summary_text.len());
+                log_line(&format!(
+                    "[groove-summary] session {} ok, summary_len={}",
+                    session_id,
+                    summary_text.len()
+                ));
                 summaries.push(GrooveSummaryEntry {
                     session_id: session_id.clone(),
                     worktree,
@@ -1320,7 +1547,13 @@ fn groove_summary_blocking(
             Ok(out) => {
                 let stdout = String::from_utf8_lossy(&out.stdout).trim().to_string();
                 let stderr = String::from_utf8_lossy(&out.stderr).trim().to_string();
-                eprintln!("[groove-summary] session {} failed, exit={:?} stdout={} stderr={}", session_id, out.status.code(), stdout, stderr);
+                log_line(&format!(
+                    "[groove-summary] session {} failed, exit={:?} stdout={} stderr={}",
+                    session_id,
+                    out.status.code(),
+                    stdout,
+                    stderr
+                ));
                 summaries.push(GrooveSummaryEntry {
                     session_id: session_id.clone(),
                     worktree,
@@ -1334,7 +1567,10 @@ fn groove_summary_blocking(
                 });
             }
             Err(error) => {
-                eprintln!("[groove-summary] session {} exec error: {}", session_id, error);
+                log_line(&format!(
+                    "[groove-summary] session {} exec error: {}",
+                    session_id, error
+                ));
                 summaries.push(GrooveSummaryEntry {
                     session_id: session_id.clone(),
                     worktree,
@@ -1456,22 +1692,17 @@ fn groove_summary_blocking(
 
 #[tauri::command]
 async fn groove_comment(app: AppHandle, payload: GrooveCommentPayload) -> GrooveCommentResponse {
-    let request_id = request_id();
-    let fallback_request_id = request_id.clone();
-
-    match tauri::async_runtime::spawn_blocking(move || {
-        groove_comment_blocking(app, payload, request_id)
-    })
-    .await
-    {
-        Ok(response) => response,
-        Err(error) => GrooveCommentResponse {
-            request_id: fallback_request_id,
+    handle_blocking_command(
+        "groove_comment",
+        move |context| groove_comment_blocking(app, payload, context.request_id()),
+        |request_id, error| GrooveCommentResponse {
+            request_id,
             ok: false,
             comment: None,
-            error: Some(format!("Failed to run groove comment worker thread: {error}")),
+            error: Some(error),
         },
-    }
+    )
+    .await
 }
 
 const GROOVE_COMMENT_DIFF_BUDGET: usize = 48 * 1024;
@@ -1701,10 +1932,10 @@ Do not include file lists or trivia.\n\n## Discussion with the assistant since t
     };
 
     let claude_bin = resolve_claude_code_bin();
-    eprintln!(
+    log_line(&format!(
         "[groove-comment] running claude -p ... at {}",
         worktree_path.display()
-    );
+    ));
     let output = Command::new(&claude_bin)
         .args(["-p", &prompt, "--output-format", "text"])
         .current_dir(&worktree_path)
@@ -1780,22 +2011,17 @@ Do not include file lists or trivia.\n\n## Discussion with the assistant since t
 
 #[tauri::command]
 async fn groove_pr_attach(app: AppHandle, payload: GroovePrAttachPayload) -> GroovePrResponse {
-    let request_id = request_id();
-    let fallback_request_id = request_id.clone();
-
-    match tauri::async_runtime::spawn_blocking(move || {
-        groove_pr_attach_blocking(app, payload, request_id)
-    })
-    .await
-    {
-        Ok(response) => response,
-        Err(error) => GroovePrResponse {
-            request_id: fallback_request_id,
+    handle_blocking_command(
+        "groove_pr_attach",
+        move |context| groove_pr_attach_blocking(app, payload, context.request_id()),
+        |request_id, error| GroovePrResponse {
+            request_id,
             ok: false,
             pull_request: None,
-            error: Some(format!("Failed to run groove pr attach worker thread: {error}")),
+            error: Some(error),
         },
-    }
+    )
+    .await
 }
 
 fn groove_pr_attach_blocking(
@@ -1948,22 +2174,17 @@ fn groove_pr_attach_blocking(
 
 #[tauri::command]
 async fn groove_pr_detach(app: AppHandle, payload: GroovePrDetachPayload) -> GroovePrResponse {
-    let request_id = request_id();
-    let fallback_request_id = request_id.clone();
-
-    match tauri::async_runtime::spawn_blocking(move || {
-        groove_pr_detach_blocking(app, payload, request_id)
-    })
-    .await
-    {
-        Ok(response) => response,
-        Err(error) => GroovePrResponse {
-            request_id: fallback_request_id,
+    handle_blocking_command(
+        "groove_pr_detach",
+        move |context| groove_pr_detach_blocking(app, payload, context.request_id()),
+        |request_id, error| GroovePrResponse {
+            request_id,
             ok: false,
             pull_request: None,
-            error: Some(format!("Failed to run groove pr detach worker thread: {error}")),
+            error: Some(error),
         },
-    }
+    )
+    .await
 }
 
 fn groove_pr_detach_blocking(
@@ -2051,24 +2272,17 @@ async fn groove_comment_mark_committed(
     app: AppHandle,
     payload: GrooveCommentMarkCommittedPayload,
 ) -> GrooveCommentMarkCommittedResponse {
-    let request_id = request_id();
-    let fallback_request_id = request_id.clone();
-
-    match tauri::async_runtime::spawn_blocking(move || {
-        groove_comment_mark_committed_blocking(app, payload, request_id)
-    })
-    .await
-    {
-        Ok(response) => response,
-        Err(error) => GrooveCommentMarkCommittedResponse {
-            request_id: fallback_request_id,
+    handle_blocking_command(
+        "groove_comment_mark_committed",
+        move |context| groove_comment_mark_committed_blocking(app, payload, context.request_id()),
+        |request_id, error| GrooveCommentMarkCommittedResponse {
+            request_id,
             ok: false,
             comment: None,
-            error: Some(format!(
-                "Failed to run groove comment mark-committed worker thread: {error}"
-            )),
+            error: Some(error),
         },
-    }
+    )
+    .await
 }
 
 fn groove_comment_mark_committed_blocking(
@@ -2258,292 +2472,564 @@ mod groove_commands_tests {
 
         fs::remove_dir_all(&temp_root).expect("cleanup temp workspace");
     }
-}
 
-#[tauri::command]
-fn groove_new(app: AppHandle, payload: GrooveNewPayload) -> GrooveCommandResponse {
-    let request_id = request_id();
+    #[test]
+    fn preflight_auto_strategy_falls_back_to_hashed_name_on_case_collision() {
+        let temp_root = std::env::temp_dir().join(format!("groove-test-{}", Uuid::new_v4()));
+        let worktrees_dir = temp_root.join(".worktrees");
+        fs::create_dir_all(worktrees_dir.join("Feature_X")).expect("create existing worktree");
 
-    let branch = payload.branch.trim();
-    if branch.is_empty() {
-        return GrooveCommandResponse {
-            request_id,
-            ok: false,
-            exit_code: None,
-            stdout: String::new(),
-            stderr: String::new(),
-            error: Some("branch is required and must be a non-empty string.".to_string()),
+        let branch_only =
+            preflight_new_worktree_dir(&worktrees_dir, "feature/x", WorktreeDirNameStrategy::Branch);
+        assert_eq!(branch_only.dir_name, "feature_x");
+        assert_eq!(branch_only.issues.len(), 1);
+        assert_eq!(branch_only.issues[0].kind, "case-collision");
+
+        let auto =
+            preflight_new_worktree_dir(&worktrees_dir, "feature/x", WorktreeDirNameStrategy::Auto);
+        assert_eq!(auto.dir_name, workspace::hashed_worktree_dir_name("feature/x"));
+        assert!(auto.issues.is_empty());
+
+        fs::remove_dir_all(&temp_root).expect("cleanup temp workspace");
+    }
+
+    #[test]
+    fn removal_is_blocked_until_every_category_with_findings_is_acknowledged() {
+        let mut scan = GrooveRmSafetyScan {
+            uncommitted_changes: None,
+            unpushed_commits: Some(2),
+            terminal_sessions: 1,
+            ..GrooveRmSafetyScan::default()
         };
+        scan.blocking = groove_rm_safety_blocking(&scan);
+        assert_eq!(
+            scan.blocking,
+            vec!["uncommittedChanges", "unpushedCommits", "terminalSessions"]
+        );
+
+        let acknowledge = vec!["unpushedCommits".to_string()];
+        let unacknowledged = unacknowledged_groove_rm_safety(&scan, &acknowledge);
+        assert_eq!(unacknowledged, vec!["uncommittedChanges", "terminalSessions"]);
+        assert_eq!(
+            describe_groove_rm_safety(&scan, &unacknowledged),
+            "changes git status could not read, 1 open terminal session(s)"
+        );
+
+        assert!(validate_groove_rm_acknowledge(&acknowledge).is_ok());
+        assert!(validate_groove_rm_acknowledge(&["everything".to_string()]).is_err());
     }
-    if !is_safe_path_token(branch) {
-        return GrooveCommandResponse {
-            request_id,
-            ok: false,
-            exit_code: None,
-            stdout: String::new(),
-            stderr: String::new(),
-            error: Some("branch contains unsafe characters or path segments.".to_string()),
+
+    #[test]
+    fn a_clean_worktree_without_remotes_needs_no_acknowledgement() {
+        let scan = GrooveRmSafetyScan {
+            uncommitted_changes: Some(0),
+            unpushed_commits: None,
+            ..GrooveRmSafetyScan::default()
         };
+        assert!(groove_rm_safety_blocking(&scan).is_empty());
     }
+}
 
-    let base = match payload
-        .base
-        .as_deref()
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-    {
-        Some(value) => {
-            if !is_safe_path_token(value) {
-                return GrooveCommandResponse {
-                    request_id,
-                    ok: false,
-                    exit_code: None,
-                    stdout: String::new(),
-                    stderr: String::new(),
-                    error: Some("base contains unsafe characters or path segments.".to_string()),
-                };
-            }
-            Some(value.to_string())
-        }
-        None => None,
-    };
+#[tauri::command(async)]
+fn groove_new(app: AppHandle, payload: GrooveNewPayload) -> GrooveNewResponse {
+    handle_command("groove_new", |context| {
+        context.capture_subprocess_transcripts(payload.debug);
+        let request_id = context.request_id();
 
-    let known_worktrees = match validate_known_worktrees(&payload.known_worktrees) {
-        Ok(known_worktrees) => known_worktrees,
-        Err(error) => {
-            return GrooveCommandResponse {
+        let branch = payload.branch.trim();
+        let named_after_issue = branch.is_empty() && payload.issue.is_some();
+        if branch.is_empty() && !named_after_issue {
+            return GrooveNewResponse {
                 request_id,
                 ok: false,
                 exit_code: None,
                 stdout: String::new(),
                 stderr: String::new(),
-                error: Some(error),
-            }
+                worktree: None,
+                preflight_issues: Vec::new(),
+                error: Some("branch is required and must be a non-empty string.".to_string()),
+                debug_transcripts: None,
+            };
         }
-    };
-
-    let dir = match validate_optional_relative_path(&payload.dir, "dir") {
-        Ok(value) => value,
-        Err(error) => {
-            return GrooveCommandResponse {
+        if !named_after_issue && !is_safe_path_token(branch) {
+            return GrooveNewResponse {
                 request_id,
                 ok: false,
                 exit_code: None,
                 stdout: String::new(),
                 stderr: String::new(),
-                error: Some(error),
-            }
+                worktree: None,
+                preflight_issues: Vec::new(),
+                error: Some("branch contains unsafe characters or path segments.".to_string()),
+                debug_transcripts: None,
+            };
         }
-    };
 
-    let workspace_root = match resolve_workspace_root(
-        &app,
-        &payload.root_name,
-        None,
-        &known_worktrees,
-        &payload.workspace_meta,
-    ) {
-        Ok(root) => root,
-        Err(primary_error) => {
-            match read_persisted_active_workspace_root(&app)
-                .ok()
-                .flatten()
-                .and_then(|value| validate_workspace_root_path(&value).ok())
-            {
-                Some(active_root) => active_root,
-                None => {
-                    return GrooveCommandResponse {
+        let base = match payload
+            .base
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+        {
+            Some(value) => {
+                if !is_safe_path_token(value) {
+                    return GrooveNewResponse {
                         request_id,
                         ok: false,
                         exit_code: None,
                         stdout: String::new(),
                         stderr: String::new(),
-                        error: Some(primary_error),
-                    }
+                        worktree: None,
+                        preflight_issues: Vec::new(),
+                        error: Some(
+                            "base contains unsafe characters or path segments.".to_string(),
+                        ),
+                        debug_transcripts: None,
+                    };
                 }
+                Some(value.to_string())
             }
-        }
-    };
-
-    let worktree_dir = dir.clone().unwrap_or_else(|| ".worktrees".to_string());
-    let effective_root = ensure_workspace_meta(&workspace_root)
-        .map(|(meta, _)| effective_workspace_root(&workspace_root, &meta))
-        .unwrap_or_else(|_| workspace_root.clone());
+            None => None,
+        };
 
-    let mut args = vec!["create".to_string(), branch.to_string()];
-    if let Some(base) = base {
-        args.push("--base".to_string());
-        args.push(base);
-    }
-    if let Some(dir) = dir {
-        args.push("--dir".to_string());
-        args.push(dir);
-    }
+        let known_worktrees = match validate_known_worktrees(&payload.known_worktrees) {
+            Ok(known_worktrees) => known_worktrees,
+            Err(error) => {
+                return GrooveNewResponse {
+                    request_id,
+                    ok: false,
+                    exit_code: None,
+                    stdout: String::new(),
+                    stderr: String::new(),
+                    worktree: None,
+                    preflight_issues: Vec::new(),
+                    error: Some(error),
+                    debug_transcripts: None,
+                }
+            }
+        };
 
-    let mut result = run_command(&groove_binary_path(&app), &args, &effective_root);
-    let ok = result.exit_code == Some(0) && result.error.is_none();
-    if ok {
-        let stamped_worktree = branch.replace('/', "_");
-        if let Err(error) = register_worktree_record(&workspace_root, &stamped_worktree).map(|_| ()) {
-            return GrooveCommandResponse {
-                request_id,
-                ok: false,
-                exit_code: result.exit_code,
-                stdout: result.stdout,
-                stderr: result.stderr,
-                error: Some(error),
+        let dir = match validate_optional_relative_path(&payload.dir, "dir") {
+            Ok(value) => value,
+            Err(error) => {
+                return GrooveNewResponse {
+                    request_id,
+                    ok: false,
+                    exit_code: None,
+                    stdout: String::new(),
+                    stderr: String::new(),
+                    worktree: None,
+                    preflight_issues: Vec::new(),
+                    error: Some(error),
+                    debug_transcripts: None,
+                }
+            }
+        };
+
+        let dir_name_strategy =
+            match parse_worktree_dir_name_strategy(payload.dir_name_strategy.as_deref()) {
+                Ok(strategy) => strategy,
+                Err(error) => {
+                    return GrooveNewResponse {
+                        request_id,
+                        ok: false,
+                        exit_code: None,
+                        stdout: String::new(),
+                        stderr: String::new(),
+                        worktree: None,
+                        preflight_issues: Vec::new(),
+                        error: Some(error),
+                        debug_transcripts: None,
+                    }
+                }
             };
-        }
-        if let Err(sync_error) =
-            sync_worktree_records_with_disk(&workspace_root, &effective_root)
-        {
-            if !result.stderr.trim().is_empty() {
-                result.stderr.push('\n');
+
+        let workspace_root = match resolve_workspace_root(
+            &app,
+            &payload.root_name,
+            None,
+            &known_worktrees,
+            &payload.workspace_meta,
+        ) {
+            Ok(root) => root,
+            Err(primary_error) => {
+                match read_persisted_active_workspace_root(&app)
+                    .ok()
+                    .flatten()
+                    .and_then(|value| validate_workspace_root_path(&value).ok())
+                {
+                    Some(active_root) => active_root,
+                    None => {
+                        return GrooveNewResponse {
+                            request_id,
+                            ok: false,
+                            exit_code: None,
+                            stdout: String::new(),
+                            stderr: String::new(),
+                            worktree: None,
+                            preflight_issues: Vec::new(),
+                            error: Some(primary_error),
+                            debug_transcripts: None,
+                        }
+                    }
+                }
             }
-            result.stderr.push_str(&format!(
-                "Warning: failed to sync worktree records with disk: {sync_error}"
-            ));
-        }
-        if let Err(error) =
-            record_worktree_last_executed_at(&app, &workspace_root, &stamped_worktree)
-        {
-            return GrooveCommandResponse {
+        };
+        if let Err(error) = ensure_workspace_writable(&workspace_root) {
+            return GrooveNewResponse {
                 request_id,
                 ok: false,
-                exit_code: result.exit_code,
-                stdout: result.stdout,
-                stderr: result.stderr,
+                exit_code: None,
+                stdout: String::new(),
+                stderr: String::new(),
+                worktree: None,
+                preflight_issues: Vec::new(),
                 error: Some(error),
+                debug_transcripts: None,
+            };
+        }
+
+        let worktree_dir = dir.clone().unwrap_or_else(|| ".worktrees".to_string());
+        let effective_root = ensure_workspace_meta(&workspace_root)
+            .map(|(meta, _)| effective_workspace_root(&workspace_root, &meta))
+            .unwrap_or_else(|_| workspace_root.clone());
+
+        let parent = payload
+            .parent
+            .as_deref()
+            .map(str::trim)
+            .filter(|parent| !parent.is_empty());
+        let parent_base = parent.map(|parent| {
+            stacked_worktree_base(&workspace_root, &effective_root, &worktree_dir, parent)
+        });
+        let base = match parent_base.transpose() {
+            Ok(parent_branch) => base.or(parent_branch),
+            Err(error) => {
+                return GrooveNewResponse {
+                    request_id,
+                    ok: false,
+                    exit_code: None,
+                    stdout: String::new(),
+                    stderr: String::new(),
+                    worktree: None,
+                    preflight_issues: Vec::new(),
+                    error: Some(error),
+                    debug_transcripts: None,
+                }
+            }
+        };
+
+        let issue = match payload.issue {
+            Some(number) => match fetch_gh_issue(&effective_root, number, "number,title,url") {
+                Ok(issue) => Some(issue),
+                Err(error) => {
+                    return GrooveNewResponse {
+                        request_id,
+                        ok: false,
+                        exit_code: None,
+                        stdout: String::new(),
+                        stderr: String::new(),
+                        worktree: None,
+                        preflight_issues: Vec::new(),
+                        error: Some(format!("Could not read issue #{number}: {error}")),
+                        debug_transcripts: None,
+                    }
+                }
+            },
+            None => None,
+        };
+        let issue_branch = issue.as_ref().filter(|_| named_after_issue).map(|issue| {
+            git::branch_name_for_issue(
+                u64::try_from(issue.number).unwrap_or_default(),
+                &issue.title,
+            )
+        });
+        let branch = issue_branch.as_deref().unwrap_or(branch);
+
+        let preflight = preflight_new_worktree_dir(
+            &effective_root.join(&worktree_dir),
+            branch,
+            dir_name_strategy,
+        );
+        if !preflight.issues.is_empty() {
+            let details = preflight
+                .issues
+                .iter()
+                .map(|issue| issue.message.as_str())
+                .collect::<Vec<_>>()
+                .join(" ");
+            let hint = if dir_name_strategy == WorktreeDirNameStrategy::Hashed {
+                ""
+            } else {
+                " Retry with dirNameStrategy \"hashed\" to use a short directory name."
+            };
+            return GrooveNewResponse {
+                request_id,
+                ok: false,
+                exit_code: None,
+                stdout: String::new(),
+                stderr: String::new(),
+                worktree: None,
+                preflight_issues: preflight.issues,
+                error: Some(format!("Worktree preflight failed: {details}{hint}")),
+                debug_transcripts: None,
             };
         }
+        let stamped_worktree = preflight.dir_name;
+
+        let mut args = vec!["create".to_string(), branch.to_string()];
+        if let Some(base) = base {
+            args.push("--base".to_string());
+            args.push(base);
+        }
+        if let Some(dir) = dir {
+            args.push("--dir".to_string());
+            args.push(dir);
+        }
+        // Only pass `--name` when it differs from the sidecar's own derivation so
+        // older sidecars keep working for the default strategy.
+        if stamped_worktree != workspace::worktree_dir_name_for_branch(branch) {
+            args.push("--name".to_string());
+            args.push(stamped_worktree.clone());
+        }
 
-        if let Ok(worktree_path) = ensure_worktree_in_dir(&effective_root, &stamped_worktree, &worktree_dir) {
-            let symlink_warnings = apply_configured_worktree_symlinks(&workspace_root, &worktree_path);
-            if !symlink_warnings.is_empty() {
+        // In a partial clone the checkout demand-fetches blobs from the
+        // promisor remote; fail fast instead of waiting on a credential prompt.
+        let partial_clone = detect_partial_clone(&effective_root);
+        let envs: &[(&str, &str)] = match partial_clone {
+            Some(_) => &[("GIT_TERMINAL_PROMPT", "0")],
+            None => &[],
+        };
+        let stream = payload.stream.then_some(CommandOutputStream {
+            command: "groove_new",
+            request_id: &request_id,
+        });
+        let mut result = run_command_with_output_stream(
+            &groove_binary_path(&app),
+            &args,
+            &effective_root,
+            envs,
+            stream.as_ref(),
+        );
+        let ok = result.exit_code == Some(0) && result.error.is_none();
+        if !ok && result.error.is_none() {
+            if let Some(partial_clone) = partial_clone
+                .as_ref()
+                .filter(|_| git::is_promisor_fetch_failure(&result.stderr))
+            {
+                result.error = Some(format!(
+                    "Checking out the new worktree needs blobs from promisor remote \"{}\" (partial clone filter {}), which could not be reached. Check the connection and credentials for that remote and retry.",
+                    partial_clone.remote, partial_clone.filter
+                ));
+            }
+        }
+        if ok {
+            if let Err(error) =
+                register_worktree_record(&workspace_root, &stamped_worktree, Some(branch))
+                    .map(|_| ())
+            {
+                return GrooveNewResponse {
+                    request_id,
+                    ok: false,
+                    exit_code: result.exit_code,
+                    stdout: result.stdout,
+                    stderr: result.stderr,
+                    worktree: None,
+                    preflight_issues: Vec::new(),
+                    error: Some(error),
+                    debug_transcripts: None,
+                };
+            }
+            if let Some(issue) = issue {
+                let record = IssueRecord {
+                    number: issue.number,
+                    url: issue.url,
+                    title: Some(issue.title).filter(|title| !title.is_empty()),
+                    linked_at: now_iso(),
+                };
+                if let Err(link_error) =
+                    link_worktree_issue(&workspace_root, &stamped_worktree, record)
+                {
+                    if !result.stderr.trim().is_empty() {
+                        result.stderr.push('\n');
+                    }
+                    result.stderr.push_str(&format!(
+                        "Warning: failed to link issue #{}: {link_error}",
+                        issue.number
+                    ));
+                }
+            }
+            if let Some(parent) = parent {
+                if let Err(link_error) =
+                    link_worktree_parent(&workspace_root, &stamped_worktree, parent)
+                {
+                    if !result.stderr.trim().is_empty() {
+                        result.stderr.push('\n');
+                    }
+                    result.stderr.push_str(&format!(
+                        "Warning: failed to stack on {parent}: {link_error}"
+                    ));
+                }
+            }
+            if let Err(sync_error) =
+                sync_worktree_records_with_disk(&workspace_root, &effective_root)
+            {
                 if !result.stderr.trim().is_empty() {
                     result.stderr.push('\n');
                 }
                 result.stderr.push_str(&format!(
-                    "Warning: {}",
-                    symlink_warnings.join("; ")
+                    "Warning: failed to sync worktree records with disk: {sync_error}"
                 ));
             }
+            if let Err(error) =
+                record_worktree_last_executed_at(&app, &workspace_root, &stamped_worktree)
+            {
+                return GrooveNewResponse {
+                    request_id,
+                    ok: false,
+                    exit_code: result.exit_code,
+                    stdout: result.stdout,
+                    stderr: result.stderr,
+                    worktree: None,
+                    preflight_issues: Vec::new(),
+                    error: Some(error),
+                    debug_transcripts: None,
+                };
+            }
 
-            ensure_claude_hooks(&worktree_path, &stamped_worktree);
-        }
+            if let Ok(worktree_path) =
+                ensure_worktree_in_dir(&effective_root, &stamped_worktree, &worktree_dir)
+            {
+                let symlink_warnings =
+                    apply_configured_worktree_symlinks(&workspace_root, &worktree_path);
+                if !symlink_warnings.is_empty() {
+                    if !result.stderr.trim().is_empty() {
+                        result.stderr.push('\n');
+                    }
+                    result
+                        .stderr
+                        .push_str(&format!("Warning: {}", symlink_warnings.join("; ")));
+                }
 
-        // Honor the configured max worktree count by evicting the
-        // least-recently-used worktree(s); emits a "worktree-evicted" event.
-        run_post_create_eviction(&app, &workspace_root, &effective_root);
+                ensure_claude_hooks(&worktree_path, &stamped_worktree);
+            }
 
-        invalidate_workspace_context_cache(&app, &workspace_root);
-        invalidate_groove_list_cache_for_workspace(&app, &workspace_root);
-    }
+            // Honor the configured max worktree count by evicting the
+            // least-recently-used worktree(s); emits a "worktree-evicted" event.
+            run_post_create_eviction(&app, &workspace_root, &effective_root);
 
-    GrooveCommandResponse {
-        request_id,
-        ok,
-        exit_code: result.exit_code,
-        stdout: result.stdout,
-        stderr: result.stderr,
-        error: result.error,
-    }
+            invalidate_workspace_caches(&app, &workspace_root);
+        }
+
+        GrooveNewResponse {
+            request_id,
+            ok,
+            exit_code: result.exit_code,
+            stdout: result.stdout,
+            stderr: result.stderr,
+            worktree: ok.then_some(stamped_worktree),
+            preflight_issues: Vec::new(),
+            error: result.error,
+            debug_transcripts: None,
+        }
+    })
 }
 
-#[tauri::command]
+#[tauri::command(async)]
 fn worktree_storage_stats(
     app: AppHandle,
     payload: WorktreeStorageStatsPayload,
 ) -> WorktreeStorageStatsResponse {
-    let request_id = request_id();
-    let include_sizes = payload.include_sizes;
+    handle_command("worktree_storage_stats", |context| {
+        let request_id = context.request_id();
+        let include_sizes = payload.include_sizes;
 
-    let storage_error = |workspace_root: Option<String>, error: String| {
-        WorktreeStorageStatsResponse {
-            request_id: request_id.clone(),
-            ok: false,
-            total_count: 0,
-            total_bytes: 0,
-            sizes_included: false,
-            max_worktree_count: None,
-            worktrees: Vec::new(),
-            workspace_root,
-            error: Some(error),
-        }
-    };
+        let storage_error =
+            |workspace_root: Option<String>, error: String| WorktreeStorageStatsResponse {
+                request_id: request_id.clone(),
+                ok: false,
+                total_count: 0,
+                total_bytes: 0,
+                sizes_included: false,
+                max_worktree_count: None,
+                worktrees: Vec::new(),
+                workspace_root,
+                error: Some(error),
+            };
 
-    let persisted_root = match read_persisted_active_workspace_root(&app) {
-        Ok(Some(value)) => value,
-        Ok(None) => return storage_error(None, "No active workspace selected.".to_string()),
-        Err(error) => return storage_error(None, error),
-    };
+        let persisted_root = match read_persisted_active_workspace_root(&app) {
+            Ok(Some(value)) => value,
+            Ok(None) => return storage_error(None, "No active workspace selected.".to_string()),
+            Err(error) => return storage_error(None, error),
+        };
 
-    let workspace_root = match validate_workspace_root_path(&persisted_root) {
-        Ok(root) => root,
-        Err(error) => return storage_error(Some(persisted_root), error),
-    };
+        let workspace_root = match validate_workspace_root_path(&persisted_root) {
+            Ok(root) => root,
+            Err(error) => return storage_error(Some(persisted_root), error),
+        };
 
-    let (workspace_meta, _) = match ensure_workspace_meta(&workspace_root) {
-        Ok(result) => result,
-        Err(error) => {
-            return storage_error(Some(workspace_root.display().to_string()), error);
-        }
-    };
+        let (workspace_meta, _) = match ensure_workspace_meta(&workspace_root) {
+            Ok(result) => result,
+            Err(error) => {
+                return storage_error(Some(workspace_root.display().to_string()), error);
+            }
+        };
 
-    let effective_root = effective_workspace_root(&workspace_root, &workspace_meta);
-    let rows = match scan_workspace_worktrees(
-        &app,
-        &workspace_root,
-        &effective_root,
-        &workspace_meta.worktree_records,
-    ) {
-        Ok((_, rows)) => rows,
-        Err(error) => {
-            return WorktreeStorageStatsResponse {
-                max_worktree_count: workspace_meta.max_worktree_count,
-                ..storage_error(Some(workspace_root.display().to_string()), error)
-            };
-        }
-    };
+        let effective_root = effective_workspace_root(&workspace_root, &workspace_meta);
+        let rows = match scan_workspace_worktrees(
+            &app,
+            &workspace_root,
+            &effective_root,
+            &workspace_meta,
+        ) {
+            Ok((_, rows)) => rows,
+            Err(error) => {
+                return WorktreeStorageStatsResponse {
+                    max_worktree_count: workspace_meta.max_worktree_count,
+                    ..storage_error(Some(workspace_root.display().to_string()), error)
+                };
+            }
+        };
 
-    let mut worktrees = Vec::new();
-    let mut total_bytes: u64 = 0;
-    for row in rows {
-        // Only count worktrees that physically exist on disk (skip tombstones).
-        if row.status == "deleted" {
-            continue;
+        let mut worktrees = Vec::new();
+        let mut total_bytes: u64 = 0;
+        for row in rows {
+            // Only count worktrees that physically exist on disk (skip tombstones).
+            if row.status == "deleted" {
+                continue;
+            }
+            // Sizing walks every file (`du`), which is expensive — only when asked.
+            let bytes = if include_sizes {
+                crate::backend::common::platform_env::calculate_dir_size_bytes(Path::new(&row.path))
+            } else {
+                0
+            };
+            total_bytes = total_bytes.saturating_add(bytes);
+            worktrees.push(WorktreeStorageRow {
+                worktree: row.worktree,
+                path: row.path,
+                bytes,
+                last_executed_at: row.last_executed_at,
+            });
         }
-        // Sizing walks every file (`du`), which is expensive — only when asked.
-        let bytes = if include_sizes {
-            crate::backend::common::platform_env::calculate_dir_size_bytes(Path::new(&row.path))
+        if include_sizes {
+            worktrees.sort_by_key(|worktree| std::cmp::Reverse(worktree.bytes));
         } else {
-            0
-        };
-        total_bytes = total_bytes.saturating_add(bytes);
-        worktrees.push(WorktreeStorageRow {
-            worktree: row.worktree,
-            path: row.path,
-            bytes,
-            last_executed_at: row.last_executed_at,
-        });
-    }
-    if include_sizes {
-        worktrees.sort_by(|left, right| right.bytes.cmp(&left.bytes));
-    } else {
-        worktrees.sort_by(|left, right| left.worktree.cmp(&right.worktree));
-    }
+            worktrees.sort_by(|left, right| left.worktree.cmp(&right.worktree));
+        }
 
-    WorktreeStorageStatsResponse {
-        request_id,
-        ok: true,
-        total_count: worktrees.len(),
-        total_bytes,
-        sizes_included: include_sizes,
-        max_worktree_count: workspace_meta.max_worktree_count,
-        worktrees,
-        workspace_root: Some(workspace_root.display().to_string()),
-        error: None,
-    }
+        WorktreeStorageStatsResponse {
+            request_id,
+            ok: true,
+            total_count: worktrees.len(),
+            total_bytes,
+            sizes_included: include_sizes,
+            max_worktree_count: workspace_meta.max_worktree_count,
+            worktrees,
+            workspace_root: Some(workspace_root.display().to_string()),
+            error: None,
+        }
+    })
 }
 
 /// True when the worktree has uncommitted changes. If git status cannot be
@@ -2556,6 +3042,135 @@ fn worktree_is_dirty(worktree_path: &Path) -> bool {
     parse_git_porcelain_counts(&result.stdout).dirty()
 }
 
+/// What removing `worktree` would lose or interrupt. Only `git status`
+/// failing counts against the worktree; a failed process listing reports no
+/// processes.
+fn scan_worktree_removal_safety(
+    app: &AppHandle,
+    workspace_root: &Path,
+    worktree: &str,
+    worktree_path: &Path,
+) -> GrooveRmSafetyScan {
+    let status = run_git_command_at_path(worktree_path, &["status", "--porcelain=v1"]);
+    let uncommitted_changes = (status.exit_code == Some(0) && status.error.is_none()).then(|| {
+        status
+            .stdout
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .count() as u32
+    });
+
+    let remotes = run_git_command_at_path(worktree_path, &["remote"]);
+    let unpushed_commits = first_non_empty_line(&remotes.stdout).map(|_| {
+        let result = run_git_command_at_path(
+            worktree_path,
+            &["rev-list", "--count", "HEAD", "--not", "--remotes"],
+        );
+        // Fails only when HEAD has no commits, which leaves nothing to push.
+        result.stdout.trim().parse::<u32>().unwrap_or(0)
+    });
+
+    let workspace_key = workspace_root_storage_key(workspace_root);
+    let (terminal_sessions, shell_pids) = app
+        .try_state::<GrooveTerminalState>()
+        .map(|state| {
+            let sessions_state = state.lock_sessions();
+            let sessions = sessions_state
+                .sessions_by_id
+                .values()
+                .filter(|session| {
+                    session.worktree == worktree
+                        && workspace_root_storage_key(Path::new(&session.workspace_root))
+                            == workspace_key
+                })
+                .collect::<Vec<_>>();
+            let shell_pids = sessions
+                .iter()
+                .filter_map(|session| session.pty.as_ref()?.child.process_id())
+                .map(|pid| pid as i32)
+                .collect::<HashSet<_>>();
+            (sessions.len(), shell_pids)
+        })
+        .unwrap_or_default();
+    let running_pids = collect_worktree_resources(app, workspace_root)
+        .map(|(rows, _)| {
+            rows.into_iter()
+                .filter(|row| row.worktree == worktree)
+                .flat_map(|row| row.pids)
+                .filter(|pid| !shell_pids.contains(pid))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut scan = GrooveRmSafetyScan {
+        uncommitted_changes,
+        unpushed_commits,
+        running_pids,
+        terminal_sessions,
+        blocking: Vec::new(),
+    };
+    scan.blocking = groove_rm_safety_blocking(&scan);
+    scan
+}
+
+fn groove_rm_safety_blocking(scan: &GrooveRmSafetyScan) -> Vec<String> {
+    let findings = [
+        scan.uncommitted_changes != Some(0),
+        scan.unpushed_commits.unwrap_or(0) > 0,
+        !scan.running_pids.is_empty(),
+        scan.terminal_sessions > 0,
+    ];
+    GROOVE_RM_SAFETY_CATEGORIES
+        .iter()
+        .zip(findings)
+        .filter(|(_, found)| *found)
+        .map(|(category, _)| category.to_string())
+        .collect()
+}
+
+fn validate_groove_rm_acknowledge(acknowledge: &[String]) -> Result<(), String> {
+    match acknowledge
+        .iter()
+        .find(|category| !GROOVE_RM_SAFETY_CATEGORIES.contains(&category.as_str()))
+    {
+        Some(category) => Err(format!(
+            "acknowledge contains unknown category \"{category}\"; expected one of: {}.",
+            GROOVE_RM_SAFETY_CATEGORIES.join(", ")
+        )),
+        None => Ok(()),
+    }
+}
+
+fn unacknowledged_groove_rm_safety<'a>(
+    scan: &'a GrooveRmSafetyScan,
+    acknowledge: &[String],
+) -> Vec<&'a str> {
+    scan.blocking
+        .iter()
+        .filter(|category| !acknowledge.contains(category))
+        .map(String::as_str)
+        .collect()
+}
+
+fn describe_groove_rm_safety(scan: &GrooveRmSafetyScan, categories: &[&str]) -> String {
+    categories
+        .iter()
+        .map(|category| match *category {
+            "uncommittedChanges" => match scan.uncommitted_changes {
+                Some(count) => format!("{count} uncommitted change(s)"),
+                None => "changes git status could not read".to_string(),
+            },
+            "unpushedCommits" => {
+                format!("{} unpushed commit(s)", scan.unpushed_commits.unwrap_or(0))
+            }
+            "runningProcesses" => format!("{} running process(es)", scan.running_pids.len()),
+            "terminalSessions" => format!("{} open terminal session(s)", scan.terminal_sessions),
+            other => other.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// Force-removes a worktree's folder (reusing the same steps as `groove_rm`'s
 /// force path) and records a tombstone. Used by eviction only.
 fn remove_worktree_for_eviction(
@@ -2604,11 +3219,13 @@ fn evict_worktrees_over_limit(
     };
     let max = max as usize;
 
-    let (_, rows) = scan_workspace_worktrees(
+    let mut execution_state = read_persisted_worktree_execution_state(app)?;
+    let (_, rows) = scan_workspace_worktrees_with_state(
         app,
         workspace_root,
         effective_root,
-        &workspace_meta.worktree_records,
+        &workspace_meta,
+        &mut execution_state,
     )?;
     let mut on_disk: Vec<WorkspaceScanRow> = rows
         .into_iter()
@@ -2635,16 +3252,13 @@ fn evict_worktrees_over_limit(
     on_disk.sort_by(|left, right| sort_key(left).cmp(&sort_key(right)));
 
     // Worktrees considered "in use": persisted running grooves + active PTYs.
-    let mut running: HashSet<String> = read_running_grooves(app, workspace_root)?
+    let mut running: HashSet<String> = running_grooves_in_state(&execution_state, workspace_root)
         .into_iter()
         .map(|record| record.worktree)
         .collect();
     {
         let state = app.state::<GrooveTerminalState>();
-        let active = match state.inner.lock() {
-            Ok(sessions_state) => active_worktrees_for_workspace(&sessions_state, workspace_root),
-            Err(_) => Vec::new(),
-        };
+        let active = active_worktrees_for_workspace(&state.lock_sessions(), workspace_root);
         for worktree in active {
             running.insert(worktree);
         }
@@ -2678,8 +3292,7 @@ fn evict_worktrees_over_limit(
     }
 
     if !evicted.is_empty() {
-        invalidate_workspace_context_cache(app, workspace_root);
-        invalidate_groove_list_cache_for_workspace(app, workspace_root);
+        invalidate_workspace_caches(app, workspace_root);
     }
     Ok(evicted)
 }
@@ -2689,320 +3302,1206 @@ fn evict_worktrees_over_limit(
 fn run_post_create_eviction(app: &AppHandle, workspace_root: &Path, effective_root: &Path) {
     match evict_worktrees_over_limit(app, workspace_root, effective_root) {
         Ok(evicted) if !evicted.is_empty() => {
-            let _ = app.emit(
-                "worktree-evicted",
-                serde_json::json!({
-                    "workspaceRoot": workspace_root.display().to_string(),
-                    "worktrees": evicted,
-                }),
+            emit_groove_event(
+                app,
+                &WorktreeEvictedEvent {
+                    workspace_root: workspace_root.display().to_string(),
+                    worktrees: evicted,
+                },
             );
         }
         _ => {}
     }
 }
 
+/// Runs the scan `groove_rm` refuses removal on, so the frontend can ask for
+/// acknowledgement before trying. `force` and `acknowledge` are ignored.
 #[tauri::command]
-fn groove_rm(
+async fn groove_rm_safety_check(
     app: AppHandle,
     payload: GrooveRmPayload,
-) -> GrooveCommandResponse {
-    let request_id = request_id();
+) -> GrooveRmSafetyResponse {
+    handle_blocking_command(
+        "groove_rm_safety_check",
+        move |context| {
+            let request_id = context.request_id();
+            match resolve_groove_rm_safety_target(&app, &payload) {
+                Ok((workspace_root, worktree, worktree_path)) => GrooveRmSafetyResponse {
+                    request_id,
+                    ok: true,
+                    safety: Some(scan_worktree_removal_safety(
+                        &app,
+                        &workspace_root,
+                        &worktree,
+                        &worktree_path,
+                    )),
+                    error: None,
+                },
+                Err(error) => groove_rm_safety_error_response(request_id, error),
+            }
+        },
+        groove_rm_safety_error_response,
+    )
+    .await
+}
 
-    let target = payload.target.trim();
-    if target.is_empty() {
-        return GrooveCommandResponse {
-            request_id,
-            ok: false,
-            exit_code: None,
-            stdout: String::new(),
-            stderr: String::new(),
-            error: Some("target is required and must be a non-empty string.".to_string()),
-        };
-    }
-    if !is_safe_path_token(target) {
-        return GrooveCommandResponse {
-            request_id,
-            ok: false,
-            exit_code: None,
-            stdout: String::new(),
-            stderr: String::new(),
-            error: Some("target contains unsafe characters or path segments.".to_string()),
-        };
-    }
-
-    let resolution_worktree = if payload.worktree.trim().is_empty() {
-        target.to_string()
+/// Resolves the worktree like `groove_rm` does, without clearing stale
+/// entries for worktrees that are gone.
+fn resolve_groove_rm_safety_target(
+    app: &AppHandle,
+    payload: &GrooveRmPayload,
+) -> Result<(PathBuf, String, PathBuf), String> {
+    let worktree = if payload.worktree.trim().is_empty() {
+        payload.target.as_str()
     } else {
-        payload.worktree.trim().to_string()
+        payload.worktree.as_str()
     };
-    if !is_safe_path_token(&resolution_worktree) {
-        return GrooveCommandResponse {
-            request_id,
-            ok: false,
-            exit_code: None,
-            stdout: String::new(),
-            stderr: String::new(),
-            error: Some("worktree contains unsafe characters or path segments.".to_string()),
-        };
+    resolve_worktree_target(
+        app,
+        &payload.root_name,
+        &payload.known_worktrees,
+        &payload.workspace_meta,
+        worktree,
+        &payload.dir,
+    )
+}
+
+fn groove_rm_safety_error_response(request_id: String, error: String) -> GrooveRmSafetyResponse {
+    GrooveRmSafetyResponse {
+        request_id,
+        ok: false,
+        safety: None,
+        error: Some(error),
     }
+}
 
-    let known_worktrees = match validate_known_worktrees(&payload.known_worktrees) {
-        Ok(known_worktrees) => known_worktrees,
-        Err(error) => {
-            return GrooveCommandResponse {
+#[tauri::command(async)]
+fn groove_rm(app: AppHandle, payload: GrooveRmPayload) -> GrooveRmResponse {
+    handle_command("groove_rm", |context| {
+        context.capture_subprocess_transcripts(payload.debug);
+        let request_id = context.request_id();
+
+        let target = payload.target.trim();
+        if target.is_empty() {
+            return GrooveRmResponse {
                 request_id,
                 ok: false,
                 exit_code: None,
                 stdout: String::new(),
                 stderr: String::new(),
-                error: Some(error),
-            }
+                safety: None,
+                error: Some("target is required and must be a non-empty string.".to_string()),
+                debug_transcripts: None,
+            };
+        }
+        if !is_safe_path_token(target) {
+            return GrooveRmResponse {
+                request_id,
+                ok: false,
+                exit_code: None,
+                stdout: String::new(),
+                stderr: String::new(),
+                safety: None,
+                error: Some("target contains unsafe characters or path segments.".to_string()),
+                debug_transcripts: None,
+            };
         }
-    };
 
-    let dir = match validate_optional_relative_path(&payload.dir, "dir") {
-        Ok(value) => value,
-        Err(error) => {
-            return GrooveCommandResponse {
+        let resolution_worktree = if payload.worktree.trim().is_empty() {
+            target.to_string()
+        } else {
+            payload.worktree.trim().to_string()
+        };
+        if !is_safe_path_token(&resolution_worktree) {
+            return GrooveRmResponse {
                 request_id,
                 ok: false,
                 exit_code: None,
                 stdout: String::new(),
                 stderr: String::new(),
-                error: Some(error),
-            }
+                safety: None,
+                error: Some("worktree contains unsafe characters or path segments.".to_string()),
+                debug_transcripts: None,
+            };
         }
-    };
 
-    let workspace_root = match resolve_workspace_root(
-        &app,
-        &payload.root_name,
-        Some(&resolution_worktree),
-        &known_worktrees,
-        &payload.workspace_meta,
-    ) {
-        Ok(root) => root,
-        Err(primary_error) => {
-            match resolve_workspace_root(
-                &app,
-                &payload.root_name,
-                None,
-                &known_worktrees,
-                &payload.workspace_meta,
-            ) {
-                Ok(root) => root,
-                Err(_) => {
-                    return GrooveCommandResponse {
-                        request_id,
-                        ok: false,
-                        exit_code: None,
-                        stdout: String::new(),
-                        stderr: String::new(),
-                        error: Some(primary_error),
-                    }
+        let known_worktrees = match validate_known_worktrees(&payload.known_worktrees) {
+            Ok(known_worktrees) => known_worktrees,
+            Err(error) => {
+                return GrooveRmResponse {
+                    request_id,
+                    ok: false,
+                    exit_code: None,
+                    stdout: String::new(),
+                    stderr: String::new(),
+                    safety: None,
+                    error: Some(error),
+                    debug_transcripts: None,
                 }
             }
+        };
+
+        if let Err(error) = validate_groove_rm_acknowledge(&payload.acknowledge) {
+            return GrooveRmResponse {
+                request_id,
+                ok: false,
+                exit_code: None,
+                stdout: String::new(),
+                stderr: String::new(),
+                safety: None,
+                error: Some(error),
+                debug_transcripts: None,
+            };
         }
-    };
 
-    let worktree_dir = dir.clone().unwrap_or_else(|| ".worktrees".to_string());
-    let effective_root = ensure_workspace_meta(&workspace_root)
-        .map(|(meta, _)| effective_workspace_root(&workspace_root, &meta))
-        .unwrap_or_else(|_| workspace_root.clone());
-    let target_path =
-        match ensure_worktree_in_dir(&effective_root, &resolution_worktree, &worktree_dir) {
-            Ok(path) => path,
+        let dir = match validate_optional_relative_path(&payload.dir, "dir") {
+            Ok(value) => value,
             Err(error) => {
-                if is_worktree_missing_error_message(&error) {
-                    if let Err(cleanup_error) = clear_stale_worktree_state(
-                        &app,
-                        &workspace_root,
-                        &resolution_worktree,
-                    ) {
-                        return GrooveCommandResponse {
+                return GrooveRmResponse {
+                    request_id,
+                    ok: false,
+                    exit_code: None,
+                    stdout: String::new(),
+                    stderr: String::new(),
+                    safety: None,
+                    error: Some(error),
+                    debug_transcripts: None,
+                }
+            }
+        };
+
+        let workspace_root = match resolve_workspace_root(
+            &app,
+            &payload.root_name,
+            Some(&resolution_worktree),
+            &known_worktrees,
+            &payload.workspace_meta,
+        ) {
+            Ok(root) => root,
+            Err(primary_error) => {
+                match resolve_workspace_root(
+                    &app,
+                    &payload.root_name,
+                    None,
+                    &known_worktrees,
+                    &payload.workspace_meta,
+                ) {
+                    Ok(root) => root,
+                    Err(_) => {
+                        return GrooveRmResponse {
                             request_id,
                             ok: false,
                             exit_code: None,
                             stdout: String::new(),
                             stderr: String::new(),
-                            error: Some(format!(
-                                "{error} Failed to clear stale groove state: {cleanup_error}"
-                            )),
-                        };
+                            safety: None,
+                            error: Some(primary_error),
+                            debug_transcripts: None,
+                        }
                     }
+                }
+            }
+        };
 
-                    return GrooveCommandResponse {
+        let worktree_dir = dir.clone().unwrap_or_else(|| ".worktrees".to_string());
+        let effective_root = ensure_workspace_meta(&workspace_root)
+            .map(|(meta, _)| effective_workspace_root(&workspace_root, &meta))
+            .unwrap_or_else(|_| workspace_root.clone());
+        let target_path =
+            match ensure_worktree_in_dir(&effective_root, &resolution_worktree, &worktree_dir) {
+                Ok(path) => path,
+                Err(error) => {
+                    if is_worktree_missing_error_message(&error) {
+                        if let Err(cleanup_error) =
+                            clear_stale_worktree_state(&app, &workspace_root, &resolution_worktree)
+                        {
+                            return GrooveRmResponse {
+                                request_id,
+                                ok: false,
+                                exit_code: None,
+                                stdout: String::new(),
+                                stderr: String::new(),
+                                safety: None,
+                                error: Some(format!(
+                                    "{error} Failed to clear stale groove state: {cleanup_error}"
+                                )),
+                                debug_transcripts: None,
+                            };
+                        }
+
+                        return GrooveRmResponse {
+                            request_id,
+                            ok: true,
+                            exit_code: Some(0),
+                            stdout: String::new(),
+                            stderr: format!(
+                                "{error} Removed stale groove entry from local app state."
+                            ),
+                            safety: None,
+                            error: None,
+                            debug_transcripts: None,
+                        };
+                    }
+                    return GrooveRmResponse {
                         request_id,
-                        ok: true,
-                        exit_code: Some(0),
+                        ok: false,
+                        exit_code: None,
                         stdout: String::new(),
-                        stderr: format!(
-                            "{error} Removed stale groove entry from local app state."
-                        ),
-                        error: None,
+                        stderr: String::new(),
+                        safety: None,
+                        error: Some(error),
+                        debug_transcripts: None,
                     };
                 }
-                return GrooveCommandResponse {
-                    request_id,
-                    ok: false,
-                    exit_code: None,
-                    stdout: String::new(),
-                    stderr: String::new(),
-                    error: Some(error),
-                }
+            };
+        let branch_name = resolve_branch_from_worktree(&target_path).or_else(|| {
+            read_worktree_records(&workspace_root)
+                .get(&resolution_worktree)
+                .and_then(|record| record.branch.clone())
+        });
+
+        let safety =
+            scan_worktree_removal_safety(&app, &workspace_root, &resolution_worktree, &target_path);
+        let unacknowledged = unacknowledged_groove_rm_safety(&safety, &payload.acknowledge);
+        if !unacknowledged.is_empty() {
+            return GrooveRmResponse {
+                request_id,
+                ok: false,
+                exit_code: None,
+                stdout: String::new(),
+                stderr: String::new(),
+                error: Some(format!(
+                    "Removing \"{resolution_worktree}\" would lose or interrupt {}. Acknowledge {} to remove it anyway.",
+                    describe_groove_rm_safety(&safety, &unacknowledged),
+                    unacknowledged.join(", ")
+                )),
+                safety: Some(safety),
+                debug_transcripts: None,
+            };
+        }
+
+        let force = payload.force.unwrap_or(false);
+        let (binary, args) = if force {
+            (
+                PathBuf::from("git"),
+                vec![
+                    "worktree".to_string(),
+                    "remove".to_string(),
+                    "--force".to_string(),
+                    target_path.display().to_string(),
+                ],
+            )
+        } else {
+            let mut branch_target =
+                resolve_branch_from_worktree(&target_path).unwrap_or_else(|| target.to_string());
+            // The sidecar derives the directory from its argument; hashed worktree
+            // directories don't round-trip from the branch, so name them directly.
+            if workspace::worktree_dir_name_for_branch(&branch_target) != resolution_worktree {
+                branch_target = resolution_worktree.clone();
             }
+            let mut args = vec!["rm".to_string(), branch_target];
+            if let Some(dir) = dir {
+                args.push("--dir".to_string());
+                args.push(dir);
+            }
+
+            (groove_binary_path(&app), args)
         };
-    let branch_name = resolve_branch_from_worktree(&target_path);
 
-    let force = payload.force.unwrap_or(false);
-    let (binary, args) = if force {
-        (
-            PathBuf::from("git"),
-            vec![
-                "worktree".to_string(),
-                "remove".to_string(),
-                "--force".to_string(),
-                target_path.display().to_string(),
-            ],
-        )
-    } else {
-        let branch_target =
-            resolve_branch_from_worktree(&target_path).unwrap_or_else(|| target.to_string());
-        let mut args = vec!["rm".to_string(), branch_target];
-        if let Some(dir) = dir {
-            args.push("--dir".to_string());
-            args.push(dir);
+        let stream = payload.stream.then_some(CommandOutputStream {
+            command: "groove_rm",
+            request_id: &request_id,
+        });
+        let mut result =
+            run_command_with_output_stream(&binary, &args, &effective_root, &[], stream.as_ref());
+        let mut ok = result.exit_code == Some(0) && result.error.is_none();
+        let mut handled_as_stale = false;
+        if !ok
+            && !path_is_directory(&target_path)
+            && (is_worktree_missing_error_message(&result.stderr)
+                || result
+                    .error
+                    .as_deref()
+                    .map(is_worktree_missing_error_message)
+                    .unwrap_or(false))
+        {
+            if let Err(cleanup_error) =
+                clear_stale_worktree_state(&app, &workspace_root, &resolution_worktree)
+            {
+                if !result.stderr.trim().is_empty() {
+                    result.stderr.push('\n');
+                }
+                result.stderr.push_str(&format!(
+                    "Warning: failed to clear stale groove state after missing worktree error: {cleanup_error}"
+                ));
+            } else {
+                ok = true;
+                handled_as_stale = true;
+                result.exit_code = Some(0);
+                result.error = None;
+                if !result.stderr.trim().is_empty() {
+                    result.stderr.push('\n');
+                }
+                result
+                    .stderr
+                    .push_str("Removed stale groove entry from local app state.");
+            }
+        }
+        if ok && !handled_as_stale {
+            if let Err(tombstone_error) = record_worktree_tombstone(
+                &app,
+                &workspace_root,
+                &resolution_worktree,
+                &target_path,
+                branch_name,
+            ) {
+                if !result.stderr.trim().is_empty() {
+                    result.stderr.push('\n');
+                }
+                result.stderr.push_str(&format!(
+                    "Warning: failed to persist worktree tombstone after deletion: {tombstone_error}"
+                ));
+            }
+
+            invalidate_workspace_caches(&app, &workspace_root);
         }
 
-        (groove_binary_path(&app), args)
-    };
+        GrooveRmResponse {
+            request_id,
+            ok,
+            exit_code: result.exit_code,
+            stdout: result.stdout,
+            stderr: result.stderr,
+            safety: None,
+            error: result.error,
+            debug_transcripts: None,
+        }
+    })
+}
 
-    let mut result = run_command(&binary, &args, &effective_root);
-    let mut ok = result.exit_code == Some(0) && result.error.is_none();
-    let mut handled_as_stale = false;
-    if !ok
-        && !path_is_directory(&target_path)
-        && (is_worktree_missing_error_message(&result.stderr)
-            || result
-                .error
-                .as_deref()
-                .map(is_worktree_missing_error_message)
-                .unwrap_or(false))
-    {
-        if let Err(cleanup_error) = clear_stale_worktree_state(
+#[tauri::command(async)]
+fn groove_stop(app: AppHandle, payload: GrooveStopPayload) -> GrooveStopResponse {
+    let response = stop_groove_worktree(&app, &payload);
+    record_action_history(&app, "groove_stop", &payload.worktree, &payload, &response);
+    response
+}
+
+#[tauri::command]
+async fn groove_bulk_rm(app: AppHandle, payload: GrooveBulkPayload) -> GrooveBulkResponse {
+    handle_blocking_command(
+        "groove_bulk_rm",
+        move |context| {
+            groove_bulk_blocking(context.request_id(), &payload, |worktree| {
+                let response = groove_rm(
+                    app.clone(),
+                    GrooveRmPayload {
+                        root_name: payload.root_name.clone(),
+                        known_worktrees: payload.known_worktrees.clone(),
+                        workspace_meta: payload.workspace_meta.clone(),
+                        target: worktree.to_string(),
+                        worktree: worktree.to_string(),
+                        dir: payload.dir.clone(),
+                        force: payload.force,
+                        acknowledge: payload.acknowledge.clone(),
+                        stream: false,
+                        debug: None,
+                    },
+                );
+                groove_bulk_result(worktree, response.ok, response.error, &response.stderr)
+            })
+        },
+        groove_bulk_error_response,
+    )
+    .await
+}
+
+#[tauri::command]
+async fn groove_bulk_stop(app: AppHandle, payload: GrooveBulkPayload) -> GrooveBulkResponse {
+    handle_blocking_command(
+        "groove_bulk_stop",
+        move |context| {
+            groove_bulk_blocking(context.request_id(), &payload, |worktree| {
+                let response = groove_stop(
+                    app.clone(),
+                    GrooveStopPayload {
+                        root_name: payload.root_name.clone(),
+                        known_worktrees: payload.known_worktrees.clone(),
+                        workspace_meta: payload.workspace_meta.clone(),
+                        worktree: worktree.to_string(),
+                        instance_id: None,
+                        dir: payload.dir.clone(),
+                    },
+                );
+                groove_bulk_result(worktree, response.ok, response.error, "")
+            })
+        },
+        groove_bulk_error_response,
+    )
+    .await
+}
+
+#[tauri::command]
+async fn groove_bulk_restore(app: AppHandle, payload: GrooveBulkPayload) -> GrooveBulkResponse {
+    handle_blocking_command(
+        "groove_bulk_restore",
+        move |context| {
+            groove_bulk_blocking(context.request_id(), &payload, |worktree| {
+                let response = groove_restore(
+                    app.clone(),
+                    app.state::<GrooveTerminalState>(),
+                    GrooveRestorePayload {
+                        workspace_root: None,
+                        root_name: payload.root_name.clone(),
+                        known_worktrees: payload.known_worktrees.clone(),
+                        workspace_meta: payload.workspace_meta.clone(),
+                        worktree: worktree.to_string(),
+                        action: payload.action.clone(),
+                        target: None,
+                        dir: payload.dir.clone(),
+                        opencode_log_file: None,
+                        stream: false,
+                        debug: None,
+                    },
+                );
+                groove_bulk_result(worktree, response.ok, response.error, &response.stderr)
+            })
+        },
+        groove_bulk_error_response,
+    )
+    .await
+}
+
+fn groove_bulk_error_response(request_id: String, error: String) -> GrooveBulkResponse {
+    GrooveBulkResponse {
+        request_id,
+        ok: false,
+        results: Vec::new(),
+        succeeded: 0,
+        failed: 0,
+        error: Some(error),
+    }
+}
+
+fn groove_bulk_result(
+    worktree: &str,
+    ok: bool,
+    error: Option<String>,
+    stderr: &str,
+) -> GrooveBulkResult {
+    GrooveBulkResult {
+        worktree: worktree.to_string(),
+        ok,
+        error: (!ok).then(|| {
+            error
+                .or_else(|| first_non_empty_line(stderr))
+                .unwrap_or_else(|| "Operation failed.".to_string())
+        }),
+    }
+}
+
+#[tauri::command]
+async fn worktree_staleness_report(
+    app: AppHandle,
+    payload: WorktreeStalenessReportPayload,
+) -> WorktreeStalenessReportResponse {
+    handle_blocking_command(
+        "worktree_staleness_report",
+        move |context| {
+            let request_id = context.request_id();
+            let workspace_root = match active_workspace_root_from_state(&app) {
+                Ok(root) => root,
+                Err(error) => return worktree_staleness_error_response(request_id, error),
+            };
+            match run_worktree_staleness_report(&app, &workspace_root, payload.dry_run) {
+                Ok((policy, worktrees)) => WorktreeStalenessReportResponse {
+                    request_id,
+                    ok: true,
+                    workspace_root: Some(workspace_root.display().to_string()),
+                    policy,
+                    worktrees,
+                    error: None,
+                },
+                Err(error) => WorktreeStalenessReportResponse {
+                    workspace_root: Some(workspace_root.display().to_string()),
+                    ..worktree_staleness_error_response(request_id, error)
+                },
+            }
+        },
+        worktree_staleness_error_response,
+    )
+    .await
+}
+
+fn worktree_staleness_error_response(
+    request_id: String,
+    error: String,
+) -> WorktreeStalenessReportResponse {
+    WorktreeStalenessReportResponse {
+        request_id,
+        ok: false,
+        workspace_root: None,
+        policy: None,
+        worktrees: Vec::new(),
+        error: Some(error),
+    }
+}
+
+#[tauri::command]
+fn worktree_action(
+    app: AppHandle,
+    terminal_state: State<GrooveTerminalState>,
+    payload: WorktreeActionPayload,
+) -> WorktreeActionResponse {
+    handle_command("worktree_action", |context| {
+        let request_id = context.request_id();
+        let failed = |action: Option<&str>, error: String| WorktreeActionResponse {
+            request_id: request_id.clone(),
+            ok: false,
+            action: action.map(str::to_string),
+            worktree: Some(payload.worktree.trim().to_string()).filter(|value| !value.is_empty()),
+            result: None,
+            error: Some(error),
+        };
+
+        let action = match normalize_worktree_action(&payload.action) {
+            Ok(action) => action,
+            Err(error) => return failed(None, error),
+        };
+        if let Err(error) = validate_worktree_action_options(action, &payload.options) {
+            return failed(Some(action), error);
+        }
+        let (workspace_root, worktree, worktree_path) = match resolve_worktree_target(
             &app,
-            &workspace_root,
-            &resolution_worktree,
+            &payload.root_name,
+            &payload.known_worktrees,
+            &payload.workspace_meta,
+            &payload.worktree,
+            &payload.dir,
         ) {
-            if !result.stderr.trim().is_empty() {
-                result.stderr.push('\n');
+            Ok(value) => value,
+            Err(error) => return failed(Some(action), error),
+        };
+        let capabilities = gather_worktree_action_capabilities(
+            &app,
+            &workspace_root,
+            &worktree,
+            &worktree_path,
+            action,
+        );
+        if let Err(error) = check_worktree_action_capabilities(action, capabilities) {
+            return failed(Some(action), error);
+        }
+
+        let command_payload =
+            worktree_action_command_payload(action, &payload, &worktree, &worktree_path);
+        let outcome = match action {
+            "play" => run_worktree_action(command_payload, |payload| {
+                groove_restore(app.clone(), terminal_state, payload)
+            }),
+            "openTerminal" => run_worktree_action(command_payload, |payload| {
+                workspace_open_terminal(app.clone(), payload)
+            }),
+            "openEditor" => open_worktree_in_editor(
+                &worktree_path,
+                payload
+                    .options
+                    .get("command")
+                    .and_then(serde_json::Value::as_str),
+            )
+            .map(|program| ActionReplay {
+                ok: true,
+                error: None,
+                result: serde_json::json!({ "program": program }),
+            }),
+            "push" => {
+                run_worktree_action(command_payload, |payload| git_push(app.clone(), payload))
             }
-            result.stderr.push_str(&format!(
-                "Warning: failed to clear stale groove state after missing worktree error: {cleanup_error}"
-            ));
-        } else {
-            ok = true;
-            handled_as_stale = true;
-            result.exit_code = Some(0);
-            result.error = None;
-            if !result.stderr.trim().is_empty() {
-                result.stderr.push('\n');
+            "pull" => {
+                run_worktree_action(command_payload, |payload| git_pull(app.clone(), payload))
+            }
+            "stop" => {
+                run_worktree_action(command_payload, |payload| groove_stop(app.clone(), payload))
             }
-            result
-                .stderr
-                .push_str("Removed stale groove entry from local app state.");
+            "remove" => {
+                run_worktree_action(command_payload, |payload| groove_rm(app.clone(), payload))
+            }
+            "setTestingTarget" => run_worktree_action(command_payload, |payload| {
+                workspace_set_worktree_play_command(app.clone(), payload)
+            }),
+            action => Err(format!("{action} has no handler.")),
+        };
+
+        match outcome {
+            Ok(outcome) => WorktreeActionResponse {
+                request_id: request_id.clone(),
+                ok: outcome.ok,
+                action: Some(action.to_string()),
+                worktree: Some(worktree),
+                result: Some(outcome.result),
+                error: outcome.error,
+            },
+            Err(error) => failed(Some(action), error),
         }
+    })
+}
+
+/// Workspace root, trimmed worktree name and worktree path of a command
+/// naming one existing worktree.
+fn resolve_worktree_target(
+    app: &AppHandle,
+    root_name: &Option<String>,
+    known_worktrees: &[String],
+    workspace_meta: &Option<WorkspaceMetaContext>,
+    worktree: &str,
+    dir: &Option<String>,
+) -> Result<(PathBuf, String, PathBuf), String> {
+    let worktree = worktree.trim();
+    if worktree.is_empty() {
+        return Err("worktree is required and must be a non-empty string.".to_string());
+    }
+    if !is_safe_path_token(worktree) {
+        return Err("worktree contains unsafe characters or path segments.".to_string());
     }
-    if ok && !handled_as_stale {
-        if let Err(tombstone_error) = record_worktree_tombstone(
+    let known_worktrees = validate_known_worktrees(known_worktrees)?;
+    let dir = validate_optional_relative_path(dir, "dir")?;
+    let workspace_root = resolve_workspace_root(
+        app,
+        root_name,
+        Some(worktree),
+        &known_worktrees,
+        workspace_meta,
+    )?;
+    let effective_root = ensure_workspace_meta(&workspace_root)
+        .map(|(meta, _)| effective_workspace_root(&workspace_root, &meta))
+        .unwrap_or_else(|_| workspace_root.clone());
+    let worktree_path = ensure_worktree_in_dir(
+        &effective_root,
+        worktree,
+        dir.as_deref().unwrap_or(".worktrees"),
+    )?;
+    Ok((workspace_root, worktree.to_string(), worktree_path))
+}
+
+#[tauri::command]
+async fn groove_archive(app: AppHandle, payload: GrooveArchivePayload) -> GrooveArchiveResponse {
+    handle_blocking_command(
+        "groove_archive",
+        move |context| {
+            let request_id = context.request_id();
+            let (workspace_root, worktree, worktree_path) = match resolve_worktree_target(
+                &app,
+                &payload.root_name,
+                &payload.known_worktrees,
+                &payload.workspace_meta,
+                &payload.worktree,
+                &payload.dir,
+            ) {
+                Ok(value) => value,
+                Err(error) => return groove_archive_error_response(request_id, error),
+            };
+            let base = payload
+                .base
+                .as_deref()
+                .map(str::trim)
+                .filter(|base| !base.is_empty());
+            if base.is_some_and(|base| !is_safe_path_token(base) || base.starts_with('-')) {
+                return groove_archive_error_response(
+                    request_id,
+                    "base contains unsafe characters or path segments.".to_string(),
+                );
+            }
+
+            let archive =
+                match create_groove_archive(&workspace_root, &worktree, &worktree_path, base) {
+                    Ok(archive) => archive,
+                    Err(error) => return groove_archive_error_response(request_id, error),
+                };
+
+            let removal_error = if payload.remove {
+                let response = groove_rm(
+                    app.clone(),
+                    GrooveRmPayload {
+                        root_name: payload.root_name.clone(),
+                        known_worktrees: payload.known_worktrees.clone(),
+                        workspace_meta: payload.workspace_meta.clone(),
+                        target: worktree.clone(),
+                        worktree: worktree.clone(),
+                        dir: payload.dir.clone(),
+                        force: None,
+                        acknowledge: vec![
+                            "uncommittedChanges".to_string(),
+                            "unpushedCommits".to_string(),
+                        ],
+                        stream: false,
+                        debug: None,
+                    },
+                );
+                (!response.ok).then(|| {
+                    format!(
+                        "Archived as {}, but the worktree was not removed: {}",
+                        archive.id,
+                        response
+                            .error
+                            .unwrap_or_else(|| "Operation failed.".to_string())
+                    )
+                })
+            } else {
+                None
+            };
+            invalidate_workspace_caches(&app, &workspace_root);
+
+            GrooveArchiveResponse {
+                request_id,
+                ok: removal_error.is_none(),
+                archive: Some(archive),
+                worktree: Some(worktree),
+                error: removal_error,
+            }
+        },
+        groove_archive_error_response,
+    )
+    .await
+}
+
+#[tauri::command(async)]
+fn groove_archive_list(
+    app: AppHandle,
+    payload: GrooveArchiveListPayload,
+) -> GrooveArchiveListResponse {
+    handle_command("groove_archive_list", |context| {
+        let request_id = context.request_id();
+        let workspace_root = match resolve_archive_workspace_root(
             &app,
-            &workspace_root,
-            &resolution_worktree,
-            &target_path,
-            branch_name,
+            &payload.root_name,
+            &payload.known_worktrees,
+            &payload.workspace_meta,
         ) {
-            if !result.stderr.trim().is_empty() {
-                result.stderr.push('\n');
+            Ok(root) => root,
+            Err(error) => {
+                return GrooveArchiveListResponse {
+                    request_id,
+                    ok: false,
+                    archives: Vec::new(),
+                    error: Some(error),
+                }
             }
-            result.stderr.push_str(&format!(
-                "Warning: failed to persist worktree tombstone after deletion: {tombstone_error}"
-            ));
+        };
+
+        GrooveArchiveListResponse {
+            request_id,
+            ok: true,
+            archives: read_groove_archives(&workspace_root),
+            error: None,
         }
+    })
+}
 
-        invalidate_workspace_context_cache(&app, &workspace_root);
-        invalidate_groove_list_cache_for_workspace(&app, &workspace_root);
+#[tauri::command]
+async fn groove_unarchive(
+    app: AppHandle,
+    payload: GrooveUnarchivePayload,
+) -> GrooveArchiveResponse {
+    handle_blocking_command(
+        "groove_unarchive",
+        move |context| {
+            let request_id = context.request_id();
+            let id = match validate_groove_archive_id(&payload.id) {
+                Ok(id) => id,
+                Err(error) => return groove_archive_error_response(request_id, error),
+            };
+            let workspace_root = match resolve_archive_workspace_root(
+                &app,
+                &payload.root_name,
+                &payload.known_worktrees,
+                &payload.workspace_meta,
+            ) {
+                Ok(root) => root,
+                Err(error) => return groove_archive_error_response(request_id, error),
+            };
+            let archive = match read_groove_archive(&workspace_root, &id) {
+                Ok(archive) => archive,
+                Err(error) => return groove_archive_error_response(request_id, error),
+            };
+
+            let branch = payload
+                .branch
+                .as_deref()
+                .map(str::trim)
+                .filter(|branch| !branch.is_empty())
+                .or(archive.branch.as_deref())
+                .unwrap_or(archive.worktree.as_str())
+                .to_string();
+            let created = groove_new(
+                app.clone(),
+                GrooveNewPayload {
+                    root_name: payload.root_name.clone(),
+                    known_worktrees: payload.known_worktrees.clone(),
+                    workspace_meta: payload.workspace_meta.clone(),
+                    branch,
+                    base: Some(archive.base_commit.clone()),
+                    dir: payload.dir.clone(),
+                    dir_name_strategy: None,
+                    issue: None,
+                    parent: None,
+                    stream: false,
+                    debug: None,
+                },
+            );
+            let Some(worktree) = created.worktree.filter(|_| created.ok) else {
+                return GrooveArchiveResponse {
+                    archive: Some(archive),
+                    ..groove_archive_error_response(
+                        request_id,
+                        created
+                            .error
+                            .or_else(|| first_non_empty_line(&created.stderr))
+                            .unwrap_or_else(|| "Failed to create the worktree.".to_string()),
+                    )
+                };
+            };
+
+            let restored = resolve_worktree_target(
+                &app,
+                &payload.root_name,
+                &payload.known_worktrees,
+                &payload.workspace_meta,
+                &worktree,
+                &payload.dir,
+            )
+            .and_then(|(_, _, worktree_path)| {
+                restore_groove_archive(&workspace_root, &archive, &worktree_path)
+            })
+            .and_then(|_| {
+                if payload.keep_archive {
+                    Ok(())
+                } else {
+                    remove_groove_archive(&workspace_root, &id)
+                }
+            });
+            invalidate_workspace_caches(&app, &workspace_root);
+
+            GrooveArchiveResponse {
+                request_id,
+                ok: restored.is_ok(),
+                archive: Some(archive),
+                error: restored.err().map(|error| {
+                    format!(
+                        "Created worktree {worktree}, but restoring the archive failed: {error}"
+                    )
+                }),
+                worktree: Some(worktree),
+            }
+        },
+        groove_archive_error_response,
+    )
+    .await
+}
+
+fn resolve_archive_workspace_root(
+    app: &AppHandle,
+    root_name: &Option<String>,
+    known_worktrees: &[String],
+    workspace_meta: &Option<WorkspaceMetaContext>,
+) -> Result<PathBuf, String> {
+    let known_worktrees = validate_known_worktrees(known_worktrees)?;
+    resolve_workspace_root(app, root_name, None, &known_worktrees, workspace_meta)
+}
+
+fn groove_archive_error_response(request_id: String, error: String) -> GrooveArchiveResponse {
+    GrooveArchiveResponse {
+        request_id,
+        ok: false,
+        archive: None,
+        worktree: None,
+        error: Some(error),
     }
+}
 
-    GrooveCommandResponse {
+#[tauri::command]
+async fn worktree_restack(
+    app: AppHandle,
+    payload: WorktreeRestackPayload,
+) -> WorktreeRestackResponse {
+    handle_blocking_command(
+        "worktree_restack",
+        move |context| {
+            let request_id = context.request_id();
+            let (workspace_root, worktree, worktree_path) = match resolve_worktree_target(
+                &app,
+                &payload.root_name,
+                &payload.known_worktrees,
+                &payload.workspace_meta,
+                &payload.worktree,
+                &payload.dir,
+            ) {
+                Ok(value) => value,
+                Err(error) => return worktree_restack_error_response(request_id, error),
+            };
+            let Some(worktrees_dir) = worktree_path.parent() else {
+                return worktree_restack_error_response(
+                    request_id,
+                    "Could not resolve the worktrees directory.".to_string(),
+                );
+            };
+
+            let results = match restack_worktree_children(
+                &request_id,
+                &workspace_root,
+                worktrees_dir,
+                &worktree,
+                payload.recursive,
+                payload.autostash,
+            ) {
+                Ok(results) => results,
+                Err(error) => return worktree_restack_error_response(request_id, error),
+            };
+            invalidate_workspace_caches(&app, &workspace_root);
+
+            let failed = results.iter().filter(|result| !result.ok).count();
+            WorktreeRestackResponse {
+                request_id,
+                ok: failed == 0,
+                worktree: Some(worktree),
+                results,
+                error: (failed > 0)
+                    .then(|| format!("{failed} worktree(s) could not be restacked.")),
+            }
+        },
+        worktree_restack_error_response,
+    )
+    .await
+}
+
+fn worktree_restack_error_response(request_id: String, error: String) -> WorktreeRestackResponse {
+    WorktreeRestackResponse {
         request_id,
-        ok,
-        exit_code: result.exit_code,
-        stdout: result.stdout,
-        stderr: result.stderr,
-        error: result.error,
+        ok: false,
+        worktree: None,
+        results: Vec::new(),
+        error: Some(error),
     }
 }
 
 #[tauri::command]
-fn groove_stop(app: AppHandle, payload: GrooveStopPayload) -> GrooveStopResponse {
-    let request_id = request_id();
+async fn merge_train_start(app: AppHandle, payload: MergeTrainStartPayload) -> MergeTrainResponse {
+    handle_blocking_command(
+        "merge_train_start",
+        move |context| {
+            let request_id = context.request_id();
+            let workspace_root = match active_workspace_root_from_state(&app) {
+                Ok(root) => root,
+                Err(error) => return merge_train_error_response(request_id, error),
+            };
+            let started = start_merge_train(&app, &request_id, &workspace_root, payload);
+            merge_train_response(request_id, &workspace_root, started.map(Some))
+        },
+        merge_train_error_response,
+    )
+    .await
+}
 
-    let worktree = payload.worktree.trim();
-    if worktree.is_empty() {
-        return GrooveStopResponse {
+#[tauri::command]
+async fn merge_train_resume(
+    app: AppHandle,
+    payload: MergeTrainResumePayload,
+) -> MergeTrainResponse {
+    handle_blocking_command(
+        "merge_train_resume",
+        move |context| {
+            let request_id = context.request_id();
+            let workspace_root = match active_workspace_root_from_state(&app) {
+                Ok(root) => root,
+                Err(error) => return merge_train_error_response(request_id, error),
+            };
+            let resumed = resume_merge_train(&app, &request_id, &workspace_root, payload.skip);
+            merge_train_response(request_id, &workspace_root, resumed.map(Some))
+        },
+        merge_train_error_response,
+    )
+    .await
+}
+
+#[tauri::command]
+fn merge_train_status(app: AppHandle) -> MergeTrainResponse {
+    handle_command("merge_train_status", |context| {
+        let request_id = context.request_id();
+        match active_workspace_root_from_state(&app) {
+            Ok(workspace_root) => merge_train_response(
+                request_id,
+                &workspace_root,
+                read_merge_train_state(&workspace_root),
+            ),
+            Err(error) => merge_train_error_response(request_id, error),
+        }
+    })
+}
+
+#[tauri::command]
+fn merge_train_abort(app: AppHandle) -> MergeTrainResponse {
+    handle_command("merge_train_abort", |context| {
+        let request_id = context.request_id();
+        match active_workspace_root_from_state(&app) {
+            Ok(workspace_root) => merge_train_response(
+                request_id,
+                &workspace_root,
+                abort_merge_train(&workspace_root).map(Some),
+            ),
+            Err(error) => merge_train_error_response(request_id, error),
+        }
+    })
+}
+
+fn merge_train_response(
+    request_id: String,
+    workspace_root: &Path,
+    train: Result<Option<MergeTrainState>, String>,
+) -> MergeTrainResponse {
+    let workspace_root = Some(workspace_root.display().to_string());
+    match train {
+        Ok(train) => MergeTrainResponse {
             request_id,
-            ok: false,
-            already_stopped: None,
-            pid: None,
-            source: None,
-            error: Some("worktree is required and must be a non-empty string.".to_string()),
-        };
+            ok: true,
+            workspace_root,
+            train,
+            error: None,
+        },
+        Err(error) => MergeTrainResponse {
+            workspace_root,
+            ..merge_train_error_response(request_id, error)
+        },
     }
-    if !is_safe_path_token(worktree) {
-        return GrooveStopResponse {
-            request_id,
-            ok: false,
-            already_stopped: None,
-            pid: None,
-            source: None,
-            error: Some("worktree contains unsafe characters or path segments.".to_string()),
-        };
+}
+
+fn merge_train_error_response(request_id: String, error: String) -> MergeTrainResponse {
+    MergeTrainResponse {
+        request_id,
+        ok: false,
+        workspace_root: None,
+        train: None,
+        error: Some(error),
+    }
+}
+
+/// Trimmed, deduplicated worktrees of a bulk payload, in the order given.
+/// Each one is validated by the single-worktree command it is passed to.
+fn validate_bulk_worktrees(worktrees: &[String]) -> Result<Vec<String>, String> {
+    let mut seen = HashSet::new();
+    let worktrees = worktrees
+        .iter()
+        .map(|worktree| worktree.trim().to_string())
+        .filter(|worktree| !worktree.is_empty() && seen.insert(worktree.clone()))
+        .collect::<Vec<_>>();
+    if worktrees.is_empty() {
+        return Err("worktrees must contain at least one worktree.".to_string());
+    }
+    if worktrees.len() > GROOVE_BULK_MAX_WORKTREES {
+        return Err(format!(
+            "worktrees accepts at most {GROOVE_BULK_MAX_WORKTREES} entries."
+        ));
+    }
+    Ok(worktrees)
+}
+
+/// Runs `operation` on every worktree of `payload`, up to
+/// `GROOVE_BULK_MAX_CONCURRENCY` at a time. One failing worktree does not
+/// stop the others.
+fn groove_bulk_blocking(
+    request_id: String,
+    payload: &GrooveBulkPayload,
+    operation: impl Fn(&str) -> GrooveBulkResult + Sync,
+) -> GrooveBulkResponse {
+    let worktrees = match validate_bulk_worktrees(&payload.worktrees) {
+        Ok(worktrees) => worktrees,
+        Err(error) => return groove_bulk_error_response(request_id, error),
+    };
+
+    let chunk_size = worktrees.len().div_ceil(GROOVE_BULK_MAX_CONCURRENCY);
+    let operation = &operation;
+    let results = thread::scope(|scope| {
+        let handles = worktrees
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|worktree| operation(worktree))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .zip(worktrees.chunks(chunk_size))
+            .flat_map(|(handle, chunk)| {
+                handle.join().unwrap_or_else(|_| {
+                    chunk
+                        .iter()
+                        .map(|worktree| GrooveBulkResult {
+                            worktree: worktree.clone(),
+                            ok: false,
+                            error: Some("The operation stopped unexpectedly.".to_string()),
+                        })
+                        .collect()
+                })
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let succeeded = results.iter().filter(|result| result.ok).count();
+    let failed = results.len() - succeeded;
+    GrooveBulkResponse {
+        request_id,
+        ok: failed == 0,
+        results,
+        succeeded,
+        failed,
+        error: None,
     }
+}
 
-    let known_worktrees = match validate_known_worktrees(&payload.known_worktrees) {
-        Ok(known_worktrees) => known_worktrees,
-        Err(error) => {
+/// `groove_stop` without the action history entry, for stops the user did not
+/// ask for (e.g. MCP `pause_worktree`).
+fn stop_groove_worktree(app: &AppHandle, payload: &GrooveStopPayload) -> GrooveStopResponse {
+    handle_command("groove_stop", |context| {
+        let request_id = context.request_id();
+
+        let worktree = payload.worktree.trim();
+        if worktree.is_empty() {
             return GrooveStopResponse {
                 request_id,
                 ok: false,
                 already_stopped: None,
                 pid: None,
                 source: None,
-                error: Some(error),
-            }
+                error: Some("worktree is required and must be a non-empty string.".to_string()),
+            };
+        }
+        if !is_safe_path_token(worktree) {
+            return GrooveStopResponse {
+                request_id,
+                ok: false,
+                already_stopped: None,
+                pid: None,
+                source: None,
+                error: Some("worktree contains unsafe characters or path segments.".to_string()),
+            };
         }
-    };
 
-    if let Err(error) = validate_optional_relative_path(&payload.dir, "dir") {
-        return GrooveStopResponse {
-            request_id,
-            ok: false,
-            already_stopped: None,
-            pid: None,
-            source: None,
-            error: Some(error),
+        let known_worktrees = match validate_known_worktrees(&payload.known_worktrees) {
+            Ok(known_worktrees) => known_worktrees,
+            Err(error) => {
+                return GrooveStopResponse {
+                    request_id,
+                    ok: false,
+                    already_stopped: None,
+                    pid: None,
+                    source: None,
+                    error: Some(error),
+                }
+            }
         };
-    }
 
-    let workspace_root = match resolve_workspace_root(
-        &app,
-        &payload.root_name,
-        Some(worktree),
-        &known_worktrees,
-        &payload.workspace_meta,
-    ) {
-        Ok(root) => root,
-        Err(error) => {
+        if let Err(error) = validate_optional_relative_path(&payload.dir, "dir") {
             return GrooveStopResponse {
                 request_id,
                 ok: false,
@@ -3010,24 +4509,17 @@ fn groove_stop(app: AppHandle, payload: GrooveStopPayload) -> GrooveStopResponse
                 pid: None,
                 source: None,
                 error: Some(error),
-            }
+            };
         }
-    };
 
-    let mut pid = None;
-    let mut source = None;
-
-    if let Some(instance_id) = payload
-        .instance_id
-        .as_deref()
-        .map(str::trim)
-        .filter(|v| !v.is_empty())
-    {
-        match parse_pid(instance_id) {
-            Ok(parsed) => {
-                pid = Some(parsed);
-                source = Some("request".to_string());
-            }
+        let workspace_root = match resolve_workspace_root(
+            app,
+            &payload.root_name,
+            Some(worktree),
+            &known_worktrees,
+            &payload.workspace_meta,
+        ) {
+            Ok(root) => root,
             Err(error) => {
                 return GrooveStopResponse {
                     request_id,
@@ -3038,152 +4530,178 @@ fn groove_stop(app: AppHandle, payload: GrooveStopPayload) -> GrooveStopResponse
                     error: Some(error),
                 }
             }
+        };
+
+        let mut pid = None;
+        let mut source = None;
+
+        if let Some(instance_id) = payload
+            .instance_id
+            .as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+        {
+            match parse_pid(instance_id) {
+                Ok(parsed) => {
+                    pid = Some(parsed);
+                    source = Some("request".to_string());
+                }
+                Err(error) => {
+                    return GrooveStopResponse {
+                        request_id,
+                        ok: false,
+                        already_stopped: None,
+                        pid: None,
+                        source: None,
+                        error: Some(error),
+                    }
+                }
+            }
         }
-    }
 
-    let Some(pid) = pid else {
-        return GrooveStopResponse {
-            request_id,
-            ok: true,
-            already_stopped: Some(true),
-            pid: None,
-            source: None,
-            error: None,
+        let Some(pid) = pid else {
+            return GrooveStopResponse {
+                request_id,
+                ok: true,
+                already_stopped: Some(true),
+                pid: None,
+                source: None,
+                error: None,
+            };
         };
-    };
 
-    let response = match stop_process_by_pid(pid) {
-        Ok((already_stopped, pid)) => GrooveStopResponse {
-            request_id,
-            ok: true,
-            already_stopped: Some(already_stopped),
-            pid: Some(pid),
-            source,
-            error: None,
-        },
-        Err(error) => GrooveStopResponse {
-            request_id,
-            ok: false,
-            already_stopped: None,
-            pid: Some(pid),
-            source,
-            error: Some(error),
-        },
-    };
+        let recorded = read_running_grooves(app, &workspace_root)
+            .unwrap_or_default()
+            .into_iter()
+            .find(|record| {
+                record.worktree == worktree
+                    && record.pid.and_then(|value| i32::try_from(value).ok()) == Some(pid)
+            });
+        let stopped = match recorded.as_ref() {
+            Some(record) => stop_recorded_groove_process(record, pid),
+            None => stop_process_by_pid(pid),
+        };
+        let response = match stopped {
+            Ok((already_stopped, pid)) => GrooveStopResponse {
+                request_id,
+                ok: true,
+                already_stopped: Some(already_stopped),
+                pid: Some(pid),
+                source,
+                error: None,
+            },
+            Err(error) => GrooveStopResponse {
+                request_id,
+                ok: false,
+                already_stopped: None,
+                pid: Some(pid),
+                source,
+                error: Some(error),
+            },
+        };
 
-    if response.ok {
-        let _ = clear_running_groove(&app, &workspace_root, worktree);
-        invalidate_groove_list_cache_for_workspace(&app, &workspace_root);
-    }
+        if response.ok {
+            let _ = clear_running_groove(app, &workspace_root, worktree);
+            invalidate_worktree_caches(app, &workspace_root, worktree);
+        }
 
-    response
+        response
+    })
 }
 
 /// Lists grooves that were playing when Groove last exited but were never cleanly
 /// stopped (i.e. survivors of an unexpected shutdown), so the frontend can offer
 /// to recover them on the next launch.
-#[tauri::command]
+#[tauri::command(async)]
 fn groove_recoverable_list(
     app: AppHandle,
     payload: WorkspaceEventsPayload,
 ) -> GrooveRecoverableListResponse {
-    let request_id = request_id();
+    handle_command("groove_recoverable_list", |context| {
+        let request_id = context.request_id();
 
-    let known_worktrees = match validate_known_worktrees(&payload.known_worktrees) {
-        Ok(value) => value,
-        Err(error) => {
-            return GrooveRecoverableListResponse {
-                request_id,
-                ok: false,
-                grooves: Vec::new(),
-                error: Some(error),
-            };
-        }
-    };
+        let known_worktrees = match validate_known_worktrees(&payload.known_worktrees) {
+            Ok(value) => value,
+            Err(error) => {
+                return GrooveRecoverableListResponse {
+                    request_id,
+                    ok: false,
+                    grooves: Vec::new(),
+                    error: Some(error),
+                };
+            }
+        };
 
-    let workspace_root = match resolve_workspace_root(
-        &app,
-        &payload.root_name,
-        None,
-        &known_worktrees,
-        &payload.workspace_meta,
-    ) {
-        Ok(root) => root,
-        Err(error) => {
-            return GrooveRecoverableListResponse {
+        let workspace_root = match resolve_workspace_root(
+            &app,
+            &payload.root_name,
+            None,
+            &known_worktrees,
+            &payload.workspace_meta,
+        ) {
+            Ok(root) => root,
+            Err(error) => {
+                return GrooveRecoverableListResponse {
+                    request_id,
+                    ok: false,
+                    grooves: Vec::new(),
+                    error: Some(error),
+                };
+            }
+        };
+
+        match read_running_grooves(&app, &workspace_root) {
+            Ok(mut grooves) => {
+                for groove in grooves.iter_mut() {
+                    groove.still_running = recorded_groove_process_running(groove);
+                }
+                grooves.sort_by(|a, b| a.worktree.cmp(&b.worktree));
+                GrooveRecoverableListResponse {
+                    request_id,
+                    ok: true,
+                    grooves,
+                    error: None,
+                }
+            }
+            Err(error) => GrooveRecoverableListResponse {
                 request_id,
                 ok: false,
                 grooves: Vec::new(),
                 error: Some(error),
-            };
-        }
-    };
-
-    match read_running_grooves(&app, &workspace_root) {
-        Ok(mut grooves) => {
-            for groove in grooves.iter_mut() {
-                groove.still_running = groove
-                    .pid
-                    .and_then(|pid| i32::try_from(pid).ok())
-                    .map(is_process_running);
-            }
-            grooves.sort_by(|a, b| a.worktree.cmp(&b.worktree));
-            GrooveRecoverableListResponse {
-                request_id,
-                ok: true,
-                grooves,
-                error: None,
-            }
+            },
         }
-        Err(error) => GrooveRecoverableListResponse {
-            request_id,
-            ok: false,
-            grooves: Vec::new(),
-            error: Some(error),
-        },
-    }
+    })
 }
 
 /// Removes running-groove records so the recovery prompt does not reappear. An
 /// empty `worktrees` list clears every running record for the workspace.
-#[tauri::command]
+#[tauri::command(async)]
 fn groove_recoverable_clear(
     app: AppHandle,
     payload: GrooveRecoverableClearPayload,
 ) -> GrooveRecoverableClearResponse {
-    let request_id = request_id();
-
-    let known_worktrees = match validate_known_worktrees(&payload.known_worktrees) {
-        Ok(value) => value,
-        Err(error) => {
-            return GrooveRecoverableClearResponse {
-                request_id,
-                ok: false,
-                error: Some(error),
-            };
-        }
-    };
+    handle_command("groove_recoverable_clear", |context| {
+        let request_id = context.request_id();
 
-    let workspace_root = match resolve_workspace_root(
-        &app,
-        &payload.root_name,
-        None,
-        &known_worktrees,
-        &payload.workspace_meta,
-    ) {
-        Ok(root) => root,
-        Err(error) => {
-            return GrooveRecoverableClearResponse {
-                request_id,
-                ok: false,
-                error: Some(error),
-            };
-        }
-    };
+        let known_worktrees = match validate_known_worktrees(&payload.known_worktrees) {
+            Ok(value) => value,
+            Err(error) => {
+                return GrooveRecoverableClearResponse {
+                    request_id,
+                    ok: false,
+                    error: Some(error),
+                };
+            }
+        };
 
-    let targets: Vec<String> = if payload.worktrees.is_empty() {
-        match read_running_grooves(&app, &workspace_root) {
-            Ok(grooves) => grooves.into_iter().map(|groove| groove.worktree).collect(),
+        let workspace_root = match resolve_workspace_root(
+            &app,
+            &payload.root_name,
+            None,
+            &known_worktrees,
+            &payload.workspace_meta,
+        ) {
+            Ok(root) => root,
             Err(error) => {
                 return GrooveRecoverableClearResponse {
                     request_id,
@@ -3191,26 +4709,39 @@ fn groove_recoverable_clear(
                     error: Some(error),
                 };
             }
-        }
-    } else {
-        payload.worktrees.clone()
-    };
+        };
 
-    for worktree in &targets {
-        if let Err(error) = clear_running_groove(&app, &workspace_root, worktree) {
-            return GrooveRecoverableClearResponse {
-                request_id,
-                ok: false,
-                error: Some(error),
-            };
+        let targets: Vec<String> = if payload.worktrees.is_empty() {
+            match read_running_grooves(&app, &workspace_root) {
+                Ok(grooves) => grooves.into_iter().map(|groove| groove.worktree).collect(),
+                Err(error) => {
+                    return GrooveRecoverableClearResponse {
+                        request_id,
+                        ok: false,
+                        error: Some(error),
+                    };
+                }
+            }
+        } else {
+            payload.worktrees.clone()
+        };
+
+        for worktree in &targets {
+            if let Err(error) = clear_running_groove(&app, &workspace_root, worktree) {
+                return GrooveRecoverableClearResponse {
+                    request_id,
+                    ok: false,
+                    error: Some(error),
+                };
+            }
         }
-    }
 
-    GrooveRecoverableClearResponse {
-        request_id,
-        ok: true,
-        error: None,
-    }
+        GrooveRecoverableClearResponse {
+            request_id,
+            ok: true,
+            error: None,
+        }
+    })
 }
 
 const DISCOVER_PROMPT: &str = "Analyze the work done in this Claude session. Rate its difficulty on a scale 1-5 based on the following cheatsheet, then respond with ONLY a single digit (1, 2, 3, 4, or 5). No words, no punctuation, no explanation.\n\nCheatsheet:\n- 1: UI fixes, fixes that look accidentally placed on someone else's PR.\n- 2: Bugs, crashing bugs, backend weird-to-find or missing things.\n- 3: Features, complex backend discovery, many attempts at fixing this bug because of PR reviewers.\n- 4: Complex, weird-to-explain, weird-to-find, non-features, complex backend analysis (few attempts).\n- 5: Same as 4 but with many attempts.\n\nRespond with a single digit only.";
@@ -3220,27 +4751,20 @@ async fn groove_discover_worktree_unit(
     app: AppHandle,
     payload: DiscoverWorktreeUnitPayload,
 ) -> DiscoverWorktreeUnitResponse {
-    let request_id = request_id();
-    let fallback_request_id = request_id.clone();
-
-    match tauri::async_runtime::spawn_blocking(move || {
-        groove_discover_worktree_unit_blocking(app, payload, request_id)
-    })
-    .await
-    {
-        Ok(response) => response,
-        Err(error) => DiscoverWorktreeUnitResponse {
-            request_id: fallback_request_id,
+    handle_blocking_command(
+        "groove_discover_worktree_unit",
+        move |context| groove_discover_worktree_unit_blocking(app, payload, context.request_id()),
+        |request_id, error| DiscoverWorktreeUnitResponse {
+            request_id,
             ok: false,
             unit: None,
             level: None,
             raw_claude_output: None,
             was_new_discovery: false,
-            error: Some(format!(
-                "Failed to run groove discover worker thread: {error}"
-            )),
+            error: Some(error),
         },
-    }
+    )
+    .await
 }
 
 fn groove_discover_worktree_unit_blocking(
@@ -3340,12 +4864,12 @@ fn groove_discover_worktree_unit_blocking(
     };
 
     let claude_bin = resolve_claude_code_bin();
-    eprintln!(
+    log_line(&format!(
         "[groove-discover] running claude --resume {} (stored={}) -p '...' --output-format text in {}",
         resolved_session_id,
         session_id,
         cwd.display(),
-    );
+    ));
 
     let output = Command::new(&claude_bin)
         .args([
@@ -3393,10 +4917,10 @@ fn groove_discover_worktree_unit_blocking(
     };
 
     let level = parse_claude_difficulty(&raw);
-    eprintln!(
+    log_line(&format!(
         "[groove-discover] worktree={} raw={:?} parsed_level={}",
         worktree, raw, level,
-    );
+    ));
 
     // Read the freshest meta, roll the unit at the discovered level, and
     // persist the unit on the worktree's record.
@@ -3437,6 +4961,12 @@ fn groove_discover_worktree_unit_blocking(
             summaries: Vec::new(),
             comments: Vec::new(),
             pull_requests: Vec::new(),
+            branch: None,
+            play_groove_command: None,
+            note: None,
+            tags: Vec::new(),
+            issue: None,
+            parent_id: None,
         });
     record.unit = Some(unit.clone());
     meta.updated_at = now_iso();
@@ -0,0 +1,81 @@
+#[tauri::command]
+fn history_list(app: AppHandle, payload: HistoryListPayload) -> HistoryListResponse {
+    handle_command("history_list", |context| {
+        let worktree = payload
+            .worktree
+            .as_deref()
+            .map(str::trim)
+            .filter(|worktree| !worktree.is_empty());
+        let entries = action_history_entries(&app)
+            .into_iter()
+            .filter(|entry| worktree.is_none_or(|worktree| entry.worktree == worktree))
+            .take(payload.limit.unwrap_or(ACTION_HISTORY_MAX_ENTRIES))
+            .collect();
+
+        HistoryListResponse {
+            request_id: context.request_id(),
+            ok: true,
+            entries,
+            error: None,
+        }
+    })
+}
+
+#[tauri::command]
+fn history_repeat(
+    app: AppHandle,
+    terminal_state: State<GrooveTerminalState>,
+    payload: HistoryRepeatPayload,
+) -> HistoryRepeatResponse {
+    handle_command("history_repeat", |context| {
+        let request_id = context.request_id();
+        let Some(entry) = action_history_entries(&app)
+            .into_iter()
+            .find(|entry| entry.id == payload.id)
+        else {
+            return HistoryRepeatResponse {
+                request_id,
+                ok: false,
+                command: None,
+                worktree: None,
+                result: None,
+                error: Some(format!("No recent action with id {}.", payload.id)),
+            };
+        };
+
+        let replay = match entry.command.as_str() {
+            "git_pull" => replay_action(entry.payload, |payload| git_pull(app.clone(), payload)),
+            "git_push" => replay_action(entry.payload, |payload| git_push(app.clone(), payload)),
+            "git_commit" => {
+                replay_action(entry.payload, |payload| git_commit(app.clone(), payload))
+            }
+            "git_merge" => replay_action(entry.payload, |payload| git_merge(app.clone(), payload)),
+            "groove_restore" => replay_action(entry.payload, |payload| {
+                groove_restore(app.clone(), terminal_state, payload)
+            }),
+            "groove_stop" => {
+                replay_action(entry.payload, |payload| groove_stop(app.clone(), payload))
+            }
+            command => Err(format!("{command} cannot be repeated.")),
+        };
+
+        match replay {
+            Ok(replay) => HistoryRepeatResponse {
+                request_id,
+                ok: replay.ok,
+                command: Some(entry.command),
+                worktree: Some(entry.worktree),
+                result: Some(replay.result),
+                error: replay.error,
+            },
+            Err(error) => HistoryRepeatResponse {
+                request_id,
+                ok: false,
+                command: Some(entry.command),
+                worktree: Some(entry.worktree),
+                result: None,
+                error: Some(error),
+            },
+        }
+    })
+}
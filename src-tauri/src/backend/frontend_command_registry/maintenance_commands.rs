@@ -0,0 +1,89 @@
+/// The maintenance schedules and last runs as they are now; `ok` is false
+/// when `error` is given.
+fn maintenance_response(
+    app: &AppHandle,
+    request_id: String,
+    error: Option<String>,
+) -> MaintenanceResponse {
+    let state = {
+        let _guard = lock_maintenance_state();
+        read_maintenance_state(app)
+    };
+    match state {
+        Ok(state) => MaintenanceResponse {
+            request_id,
+            ok: error.is_none(),
+            utc_offset_minutes: state.utc_offset_minutes,
+            tasks: maintenance_task_infos(&state),
+            error,
+        },
+        Err(read_error) => MaintenanceResponse {
+            request_id,
+            ok: false,
+            utc_offset_minutes: 0,
+            tasks: Vec::new(),
+            error: Some(error.unwrap_or(read_error)),
+        },
+    }
+}
+
+#[tauri::command]
+fn maintenance_status(app: AppHandle) -> MaintenanceResponse {
+    handle_command("maintenance_status", |context| {
+        maintenance_response(&app, context.request_id(), None)
+    })
+}
+
+#[tauri::command]
+fn maintenance_configure(
+    app: AppHandle,
+    payload: MaintenanceConfigurePayload,
+) -> MaintenanceResponse {
+    handle_command("maintenance_configure", |context| {
+        let configured = {
+            let _guard = lock_maintenance_state();
+            read_maintenance_state(&app).and_then(|mut state| {
+                apply_maintenance_configuration(&mut state, &payload)?;
+                write_maintenance_state(&app, &state)
+            })
+        };
+        maintenance_response(&app, context.request_id(), configured.err())
+    })
+}
+
+#[tauri::command]
+async fn maintenance_run_now(
+    app: AppHandle,
+    payload: MaintenanceRunNowPayload,
+) -> MaintenanceResponse {
+    handle_blocking_command(
+        "maintenance_run_now",
+        move |context| {
+            let request_id = context.request_id();
+            let tasks = if payload.tasks.is_empty() {
+                Ok(MAINTENANCE_TASKS.to_vec())
+            } else {
+                payload
+                    .tasks
+                    .iter()
+                    .map(|task| normalize_maintenance_task(task))
+                    .collect::<Result<Vec<_>, _>>()
+            };
+            match tasks {
+                Ok(tasks) => {
+                    run_maintenance_tasks(&app, &tasks, "manual");
+                    maintenance_response(&app, request_id, None)
+                }
+                Err(error) => maintenance_response(&app, request_id, Some(error)),
+            }
+        },
+        |request_id, error| MaintenanceResponse {
+            request_id,
+            ok: false,
+            utc_offset_minutes: 0,
+            tasks: Vec::new(),
+            error: Some(error),
+        },
+    )
+    .await
+}
@@ -1,20 +1,66 @@
 include!("../common/prelude.rs");
 include!("../common/constants.rs");
 include!("../common/dtos.rs");
+include!("../event_polling_emission_pipeline/emit_runtime.rs");
+include!("../event_polling_emission_pipeline/pause_runtime.rs");
+include!("../event_polling_emission_pipeline/watch_runtime.rs");
+include!("../app_state_management/lock_recovery.rs");
+include!("command_middleware.rs");
 include!("../pty_terminal_sessions/session_runtime.rs");
+include!("../pty_terminal_sessions/scrollback_runtime.rs");
+include!("../pty_terminal_sessions/hibernation_runtime.rs");
+include!("../pty_terminal_sessions/storage_runtime.rs");
 include!("../workspace_metadata_settings/loot_tables.rs");
 include!("../workspace_metadata_settings/settings_runtime.rs");
+include!("../workspace_metadata_settings/profiles_runtime.rs");
+include!("../workspace_metadata_settings/sync_runtime.rs");
+include!("../app_state_management/backup_runtime.rs");
+include!("../workspace_trust/trust_runtime.rs");
+include!("../workspace_trust/command_policy_runtime.rs");
 include!("../assistant_rules/rules_runtime.rs");
 include!("../workspace_discovery_context/discovery_runtime.rs");
 include!("../common/process_command.rs");
+include!("../git_github_bridge/native_git_runtime.rs");
+include!("../git_github_bridge/pr_provider_runtime.rs");
 include!("../startup_health_checks_binary_validation/binary_runtime.rs");
+include!("../startup_health_checks_binary_validation/health_runtime.rs");
 include!("../groove_worktree_lifecycle/groove_runtime.rs");
+include!("../groove_worktree_lifecycle/staleness_runtime.rs");
+include!("../groove_worktree_lifecycle/freeze_runtime.rs");
+include!("../groove_worktree_lifecycle/action_runtime.rs");
+include!("../groove_worktree_lifecycle/archive_runtime.rs");
+include!("../groove_worktree_lifecycle/stack_runtime.rs");
+include!("../groove_worktree_lifecycle/conflict_runtime.rs");
+include!("../groove_worktree_lifecycle/merge_train_runtime.rs");
 include!("../diagnostics_process_control/diagnostics_runtime.rs");
+include!("../diagnostics_process_control/alerts_runtime.rs");
+include!("../diagnostics_process_control/transcript_runtime.rs");
+include!("../diagnostics_process_control/retry_runtime.rs");
 include!("../runtime_cache_dedupe/cache_runtime.rs");
+include!("../action_history/history_runtime.rs");
+include!("../api_tokens/tokens_runtime.rs");
+include!("../undo_stack/undo_runtime.rs");
+include!("../command_runs/runs_runtime.rs");
+include!("../maintenance_scheduler/maintenance_runtime.rs");
+include!("../testing_environments/supervisor_runtime.rs");
+include!("../testing_environments/logs_runtime.rs");
+include!("../status_page/status_page_runtime.rs");
+include!("../remote_workspace_ssh/remote_runtime.rs");
+include!("../tmux_terminal_sessions/tmux_runtime.rs");
+include!("../zellij_terminal_sessions/zellij_runtime.rs");
 include!("workspace_commands.rs");
 include!("terminal_commands.rs");
 include!("git_gh_commands.rs");
 include!("groove_commands.rs");
+include!("history_commands.rs");
+include!("api_tokens_commands.rs");
+include!("undo_commands.rs");
+include!("runs_commands.rs");
+include!("cancellation_commands.rs");
+include!("maintenance_commands.rs");
+include!("testing_environment_commands.rs");
+include!("app_backup_commands.rs");
+include!("status_page_commands.rs");
 include!("startup_commands.rs");
 include!("diagnostics_commands.rs");
 include!("events_commands.rs");
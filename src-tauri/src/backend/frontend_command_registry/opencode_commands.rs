@@ -186,62 +186,64 @@ fn build_opencode_skill_scope(scope: &str, root_path: PathBuf) -> OpencodeSkillS
 
 #[tauri::command]
 fn opencode_integration_status(app: AppHandle) -> OpencodeIntegrationStatusResponse {
-    let request_id = request_id();
+    handle_command("opencode_integration_status", |context| {
+        let request_id = context.request_id();
 
-    let global_settings = match ensure_global_settings(&app) {
-        Ok(value) => value,
-        Err(error) => {
-            return OpencodeIntegrationStatusResponse {
-                request_id,
-                ok: false,
-                workspace_root: None,
-                workspace_scope_available: false,
-                global_scope_available: false,
-                effective_scope: "none".to_string(),
-                workspace_settings: None,
-                global_settings: None,
-                error: Some(error),
+        let global_settings = match ensure_global_settings(&app) {
+            Ok(value) => value,
+            Err(error) => {
+                return OpencodeIntegrationStatusResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    workspace_scope_available: false,
+                    global_scope_available: false,
+                    effective_scope: "none".to_string(),
+                    workspace_settings: None,
+                    global_settings: None,
+                    error: Some(error),
+                }
             }
-        }
-    };
+        };
 
-    let mut workspace_root: Option<PathBuf> = None;
-    let mut workspace_settings: Option<OpencodeSettings> = None;
-    let mut workspace_scope_available = false;
+        let mut workspace_root: Option<PathBuf> = None;
+        let mut workspace_settings: Option<OpencodeSettings> = None;
+        let mut workspace_scope_available = false;
 
-    if let Ok(Some(persisted_root)) = read_persisted_active_workspace_root(&app) {
-        if let Ok(root) = validate_workspace_root_path(&persisted_root) {
-            if let Ok((workspace_meta, _)) = ensure_workspace_meta(&root) {
-                workspace_scope_available = root.join(".opencode").is_dir();
-                workspace_settings = Some(workspace_meta.opencode_settings);
-                workspace_root = Some(root);
+        if let Ok(Some(persisted_root)) = read_persisted_active_workspace_root(&app) {
+            if let Ok(root) = validate_workspace_root_path(&persisted_root) {
+                if let Ok((workspace_meta, _)) = ensure_workspace_meta(&root) {
+                    workspace_scope_available = root.join(".opencode").is_dir();
+                    workspace_settings = Some(workspace_meta.opencode_settings);
+                    workspace_root = Some(root);
+                }
             }
         }
-    }
 
-    let global_scope_available = dirs_home()
-        .map(|home| home.join(".config").join("opencode").is_dir())
-        .unwrap_or(false);
+        let global_scope_available = dirs_home()
+            .map(|home| home.join(".config").join("opencode").is_dir())
+            .unwrap_or(false);
 
-    let effective_scope = if workspace_scope_available {
-        "workspace"
-    } else if global_scope_available {
-        "global"
-    } else {
-        "none"
-    };
+        let effective_scope = if workspace_scope_available {
+            "workspace"
+        } else if global_scope_available {
+            "global"
+        } else {
+            "none"
+        };
 
-    OpencodeIntegrationStatusResponse {
-        request_id,
-        ok: true,
-        workspace_root: workspace_root.map(|value| value.display().to_string()),
-        workspace_scope_available,
-        global_scope_available,
-        effective_scope: effective_scope.to_string(),
-        workspace_settings,
-        global_settings: Some(global_settings.opencode_settings),
-        error: None,
-    }
+        OpencodeIntegrationStatusResponse {
+            request_id,
+            ok: true,
+            workspace_root: workspace_root.map(|value| value.display().to_string()),
+            workspace_scope_available,
+            global_scope_available,
+            effective_scope: effective_scope.to_string(),
+            workspace_settings,
+            global_settings: Some(global_settings.opencode_settings),
+            error: None,
+        }
+    })
 }
 
 #[tauri::command]
@@ -249,46 +251,48 @@ fn opencode_update_workspace_settings(
     app: AppHandle,
     payload: OpencodeSettingsUpdatePayload,
 ) -> OpencodeWorkspaceSettingsResponse {
-    let request_id = request_id();
-    let (workspace_root, mut workspace_meta) = match active_workspace_meta(&app) {
-        Ok(value) => value,
-        Err(error) => {
+    handle_command("opencode_update_workspace_settings", |context| {
+        let request_id = context.request_id();
+        let (workspace_root, mut workspace_meta) = match active_workspace_meta(&app) {
+            Ok(value) => value,
+            Err(error) => {
+                return OpencodeWorkspaceSettingsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    settings: None,
+                    error: Some(error),
+                }
+            }
+        };
+
+        workspace_meta.opencode_settings = normalize_opencode_settings(&OpencodeSettings {
+            enabled: payload.enabled,
+            default_model: payload.default_model,
+            settings_directory: payload
+                .settings_directory
+                .unwrap_or_else(|| workspace_meta.opencode_settings.settings_directory.clone()),
+        });
+        workspace_meta.updated_at = now_iso();
+
+        if let Err(error) = persist_workspace_meta_update(&app, &workspace_root, &workspace_meta) {
             return OpencodeWorkspaceSettingsResponse {
                 request_id,
                 ok: false,
-                workspace_root: None,
-                settings: None,
+                workspace_root: Some(workspace_root.display().to_string()),
+                settings: Some(workspace_meta.opencode_settings),
                 error: Some(error),
-            }
+            };
         }
-    };
 
-    workspace_meta.opencode_settings = normalize_opencode_settings(&OpencodeSettings {
-        enabled: payload.enabled,
-        default_model: payload.default_model,
-        settings_directory: payload
-            .settings_directory
-            .unwrap_or_else(|| workspace_meta.opencode_settings.settings_directory.clone()),
-    });
-    workspace_meta.updated_at = now_iso();
-
-    if let Err(error) = persist_workspace_meta_update(&app, &workspace_root, &workspace_meta) {
-        return OpencodeWorkspaceSettingsResponse {
+        OpencodeWorkspaceSettingsResponse {
             request_id,
-            ok: false,
+            ok: true,
             workspace_root: Some(workspace_root.display().to_string()),
             settings: Some(workspace_meta.opencode_settings),
-            error: Some(error),
-        };
-    }
-
-    OpencodeWorkspaceSettingsResponse {
-        request_id,
-        ok: true,
-        workspace_root: Some(workspace_root.display().to_string()),
-        settings: Some(workspace_meta.opencode_settings),
-        error: None,
-    }
+            error: None,
+        }
+    })
 }
 
 #[tauri::command]
@@ -296,54 +300,56 @@ fn opencode_update_global_settings(
     app: AppHandle,
     payload: OpencodeSettingsUpdatePayload,
 ) -> OpencodeGlobalSettingsResponse {
-    let request_id = request_id();
-    let mut global_settings = match ensure_global_settings(&app) {
-        Ok(value) => value,
-        Err(error) => {
-            return OpencodeGlobalSettingsResponse {
-                request_id,
-                ok: false,
-                settings: None,
-                error: Some(error),
+    handle_command("opencode_update_global_settings", |context| {
+        let request_id = context.request_id();
+        let mut global_settings = match ensure_global_settings(&app) {
+            Ok(value) => value,
+            Err(error) => {
+                return OpencodeGlobalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    settings: None,
+                    error: Some(error),
+                }
             }
-        }
-    };
+        };
 
-    global_settings.opencode_settings = normalize_opencode_settings(&OpencodeSettings {
-        enabled: payload.enabled,
-        default_model: payload.default_model,
-        settings_directory: payload
-            .settings_directory
-            .unwrap_or_else(|| global_settings.opencode_settings.settings_directory.clone()),
-    });
-
-    let settings_file = match global_settings_file(&app) {
-        Ok(path) => path,
-        Err(error) => {
+        global_settings.opencode_settings = normalize_opencode_settings(&OpencodeSettings {
+            enabled: payload.enabled,
+            default_model: payload.default_model,
+            settings_directory: payload
+                .settings_directory
+                .unwrap_or_else(|| global_settings.opencode_settings.settings_directory.clone()),
+        });
+
+        let settings_file = match global_settings_file(&app) {
+            Ok(path) => path,
+            Err(error) => {
+                return OpencodeGlobalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    settings: Some(global_settings.opencode_settings),
+                    error: Some(error),
+                }
+            }
+        };
+
+        if let Err(error) = write_global_settings_file(&settings_file, &global_settings) {
             return OpencodeGlobalSettingsResponse {
                 request_id,
                 ok: false,
                 settings: Some(global_settings.opencode_settings),
                 error: Some(error),
-            }
+            };
         }
-    };
 
-    if let Err(error) = write_global_settings_file(&settings_file, &global_settings) {
-        return OpencodeGlobalSettingsResponse {
+        OpencodeGlobalSettingsResponse {
             request_id,
-            ok: false,
+            ok: true,
             settings: Some(global_settings.opencode_settings),
-            error: Some(error),
-        };
-    }
-
-    OpencodeGlobalSettingsResponse {
-        request_id,
-        ok: true,
-        settings: Some(global_settings.opencode_settings),
-        error: None,
-    }
+            error: None,
+        }
+    })
 }
 
 #[tauri::command]
@@ -351,10 +357,13 @@ fn validate_opencode_settings_directory(
     settings_directory: String,
     workspace_root: Option<String>,
 ) -> OpencodeSettingsDirectoryValidationResponse {
-    let request_id = request_id();
+    handle_command("validate_opencode_settings_directory", |context| {
+        let request_id = context.request_id();
 
-    let resolved =
-        match resolve_opencode_settings_directory(&settings_directory, workspace_root.as_deref()) {
+        let resolved = match resolve_opencode_settings_directory(
+            &settings_directory,
+            workspace_root.as_deref(),
+        ) {
             Ok(path) => path,
             Err(error) => {
                 return OpencodeSettingsDirectoryValidationResponse {
@@ -368,42 +377,43 @@ fn validate_opencode_settings_directory(
             }
         };
 
-    let directory_exists = path_is_directory(&resolved);
-    let opencode_config_exists = path_is_file(&resolved.join("opencode.json"));
+        let directory_exists = path_is_directory(&resolved);
+        let opencode_config_exists = path_is_file(&resolved.join("opencode.json"));
 
-    if !directory_exists {
-        return OpencodeSettingsDirectoryValidationResponse {
-            request_id,
-            ok: false,
-            resolved_path: Some(resolved.display().to_string()),
-            directory_exists,
-            opencode_config_exists,
-            error: Some(format!("Directory not found: {}", resolved.display())),
-        };
-    }
+        if !directory_exists {
+            return OpencodeSettingsDirectoryValidationResponse {
+                request_id,
+                ok: false,
+                resolved_path: Some(resolved.display().to_string()),
+                directory_exists,
+                opencode_config_exists,
+                error: Some(format!("Directory not found: {}", resolved.display())),
+            };
+        }
 
-    if !opencode_config_exists {
-        return OpencodeSettingsDirectoryValidationResponse {
+        if !opencode_config_exists {
+            return OpencodeSettingsDirectoryValidationResponse {
+                request_id,
+                ok: false,
+                resolved_path: Some(resolved.display().to_string()),
+                directory_exists,
+                opencode_config_exists,
+                error: Some(format!(
+                    "Missing opencode.json in directory: {}",
+                    resolved.display()
+                )),
+            };
+        }
+
+        OpencodeSettingsDirectoryValidationResponse {
             request_id,
-            ok: false,
+            ok: true,
             resolved_path: Some(resolved.display().to_string()),
             directory_exists,
             opencode_config_exists,
-            error: Some(format!(
-                "Missing opencode.json in directory: {}",
-                resolved.display()
-            )),
-        };
-    }
-
-    OpencodeSettingsDirectoryValidationResponse {
-        request_id,
-        ok: true,
-        resolved_path: Some(resolved.display().to_string()),
-        directory_exists,
-        opencode_config_exists,
-        error: None,
-    }
+            error: None,
+        }
+    })
 }
 
 #[tauri::command]
@@ -412,217 +422,225 @@ fn opencode_list_skills(
     global_skills_path: Option<String>,
     workspace_skills_path: Option<String>,
 ) -> OpencodeSkillsListResponse {
-    let request_id = request_id();
-
-    let global_scope = if let Some(custom_path) = global_skills_path
-        .as_deref()
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-    {
-        match resolve_directory_input(custom_path) {
-            Ok(skills_path) => Some(OpencodeSkillScope {
-                scope: "global".to_string(),
-                root_path: skills_path
-                    .parent()
-                    .map(|path| path.display().to_string())
-                    .unwrap_or_else(|| skills_path.display().to_string()),
-                skills_path: skills_path.display().to_string(),
-                skills_directory_exists: path_is_directory(&skills_path),
-                skills: if path_is_directory(&skills_path) {
-                    collect_opencode_skills(&skills_path)
-                } else {
-                    Vec::new()
-                },
-            }),
-            Err(error) => {
-                return OpencodeSkillsListResponse {
-                    request_id,
-                    ok: false,
-                    global_scope: None,
-                    workspace_scope: None,
-                    error: Some(error),
-                }
-            }
-        }
-    } else {
-        dirs_home().map(|home| {
-            build_opencode_skill_scope("global", home.join(".config").join("opencode"))
-        })
-    };
+    handle_command("opencode_list_skills", |context| {
+        let request_id = context.request_id();
 
-    let workspace_scope = if let Some(custom_path) = workspace_skills_path
-        .as_deref()
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-    {
-        match resolve_directory_input(custom_path) {
-            Ok(skills_path) => Some(OpencodeSkillScope {
-                scope: "workspace".to_string(),
-                root_path: skills_path
-                    .parent()
-                    .map(|path| path.display().to_string())
-                    .unwrap_or_else(|| skills_path.display().to_string()),
-                skills_path: skills_path.display().to_string(),
-                skills_directory_exists: path_is_directory(&skills_path),
-                skills: if path_is_directory(&skills_path) {
-                    collect_opencode_skills(&skills_path)
-                } else {
-                    Vec::new()
-                },
-            }),
-            Err(error) => {
-                return OpencodeSkillsListResponse {
-                    request_id,
-                    ok: false,
-                    global_scope,
-                    workspace_scope: None,
-                    error: Some(error),
+        let global_scope = if let Some(custom_path) = global_skills_path
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+        {
+            match resolve_directory_input(custom_path) {
+                Ok(skills_path) => Some(OpencodeSkillScope {
+                    scope: "global".to_string(),
+                    root_path: skills_path
+                        .parent()
+                        .map(|path| path.display().to_string())
+                        .unwrap_or_else(|| skills_path.display().to_string()),
+                    skills_path: skills_path.display().to_string(),
+                    skills_directory_exists: path_is_directory(&skills_path),
+                    skills: if path_is_directory(&skills_path) {
+                        collect_opencode_skills(&skills_path)
+                    } else {
+                        Vec::new()
+                    },
+                }),
+                Err(error) => {
+                    return OpencodeSkillsListResponse {
+                        request_id,
+                        ok: false,
+                        global_scope: None,
+                        workspace_scope: None,
+                        error: Some(error),
+                    }
                 }
             }
-        }
-    } else {
-        workspace_root
+        } else {
+            dirs_home().map(|home| {
+                build_opencode_skill_scope("global", home.join(".config").join("opencode"))
+            })
+        };
+
+        let workspace_scope = if let Some(custom_path) = workspace_skills_path
             .as_deref()
             .map(str::trim)
             .filter(|value| !value.is_empty())
-            .map(PathBuf::from)
-            .filter(|root| path_is_directory(&root.join(".opencode")))
-            .map(|root| build_opencode_skill_scope("workspace", root.join(".opencode")))
-    };
+        {
+            match resolve_directory_input(custom_path) {
+                Ok(skills_path) => Some(OpencodeSkillScope {
+                    scope: "workspace".to_string(),
+                    root_path: skills_path
+                        .parent()
+                        .map(|path| path.display().to_string())
+                        .unwrap_or_else(|| skills_path.display().to_string()),
+                    skills_path: skills_path.display().to_string(),
+                    skills_directory_exists: path_is_directory(&skills_path),
+                    skills: if path_is_directory(&skills_path) {
+                        collect_opencode_skills(&skills_path)
+                    } else {
+                        Vec::new()
+                    },
+                }),
+                Err(error) => {
+                    return OpencodeSkillsListResponse {
+                        request_id,
+                        ok: false,
+                        global_scope,
+                        workspace_scope: None,
+                        error: Some(error),
+                    }
+                }
+            }
+        } else {
+            workspace_root
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(PathBuf::from)
+                .filter(|root| path_is_directory(&root.join(".opencode")))
+                .map(|root| build_opencode_skill_scope("workspace", root.join(".opencode")))
+        };
 
-    OpencodeSkillsListResponse {
-        request_id,
-        ok: true,
-        global_scope,
-        workspace_scope,
-        error: None,
-    }
+        OpencodeSkillsListResponse {
+            request_id,
+            ok: true,
+            global_scope,
+            workspace_scope,
+            error: None,
+        }
+    })
 }
 
 
 #[tauri::command]
 fn opencode_copy_skills(payload: OpencodeCopySkillsPayload) -> OpencodeCopySkillsResponse {
-    let request_id = request_id();
+    handle_command("opencode_copy_skills", |context| {
+        let request_id = context.request_id();
 
-    let global_skills_path = match resolve_directory_input(&payload.global_skills_path) {
-        Ok(path) => path,
-        Err(error) => {
-            return OpencodeCopySkillsResponse {
-                request_id,
-                ok: false,
-                copied_to_workspace: 0,
-                copied_to_global: 0,
-                error: Some(error),
+        let global_skills_path = match resolve_directory_input(&payload.global_skills_path) {
+            Ok(path) => path,
+            Err(error) => {
+                return OpencodeCopySkillsResponse {
+                    request_id,
+                    ok: false,
+                    copied_to_workspace: 0,
+                    copied_to_global: 0,
+                    error: Some(error),
+                }
             }
-        }
-    };
+        };
 
-    let workspace_skills_path = match resolve_directory_input(&payload.workspace_skills_path) {
-        Ok(path) => path,
-        Err(error) => {
-            return OpencodeCopySkillsResponse {
-                request_id,
-                ok: false,
-                copied_to_workspace: 0,
-                copied_to_global: 0,
-                error: Some(error),
+        let workspace_skills_path = match resolve_directory_input(&payload.workspace_skills_path) {
+            Ok(path) => path,
+            Err(error) => {
+                return OpencodeCopySkillsResponse {
+                    request_id,
+                    ok: false,
+                    copied_to_workspace: 0,
+                    copied_to_global: 0,
+                    error: Some(error),
+                }
             }
-        }
-    };
+        };
 
-    let copied_to_workspace = match copy_skills_by_name(
-        &global_skills_path,
-        &workspace_skills_path,
-        &payload.global_to_workspace,
-    ) {
-        Ok(value) => value,
-        Err(error) => {
-            return OpencodeCopySkillsResponse {
-                request_id,
-                ok: false,
-                copied_to_workspace: 0,
-                copied_to_global: 0,
-                error: Some(error),
+        let copied_to_workspace = match copy_skills_by_name(
+            &global_skills_path,
+            &workspace_skills_path,
+            &payload.global_to_workspace,
+        ) {
+            Ok(value) => value,
+            Err(error) => {
+                return OpencodeCopySkillsResponse {
+                    request_id,
+                    ok: false,
+                    copied_to_workspace: 0,
+                    copied_to_global: 0,
+                    error: Some(error),
+                }
             }
-        }
-    };
+        };
 
-    let copied_to_global = match copy_skills_by_name(
-        &workspace_skills_path,
-        &global_skills_path,
-        &payload.workspace_to_global,
-    ) {
-        Ok(value) => value,
-        Err(error) => {
-            return OpencodeCopySkillsResponse {
-                request_id,
-                ok: false,
-                copied_to_workspace,
-                copied_to_global: 0,
-                error: Some(error),
+        let copied_to_global = match copy_skills_by_name(
+            &workspace_skills_path,
+            &global_skills_path,
+            &payload.workspace_to_global,
+        ) {
+            Ok(value) => value,
+            Err(error) => {
+                return OpencodeCopySkillsResponse {
+                    request_id,
+                    ok: false,
+                    copied_to_workspace,
+                    copied_to_global: 0,
+                    error: Some(error),
+                }
             }
-        }
-    };
+        };
 
-    OpencodeCopySkillsResponse {
-        request_id,
-        ok: true,
-        copied_to_workspace,
-        copied_to_global,
-        error: None,
-    }
+        OpencodeCopySkillsResponse {
+            request_id,
+            ok: true,
+            copied_to_workspace,
+            copied_to_global,
+            error: None,
+        }
+    })
 }
 
 #[tauri::command]
 fn check_opencode_status(worktree_path: String) -> OpenCodeStatusResponse {
-    let request_id = request_id();
-    let worktree_path = PathBuf::from(worktree_path.trim());
+    handle_command("check_opencode_status", |context| {
+        let request_id = context.request_id();
+        let worktree_path = PathBuf::from(worktree_path.trim());
 
-    if !worktree_path.is_absolute() {
-        return OpenCodeStatusResponse {
-            request_id,
-            ok: false,
-            status: None,
-            error: Some("worktreePath must be an absolute path.".to_string()),
-        };
-    }
+        if !worktree_path.is_absolute() {
+            return OpenCodeStatusResponse {
+                request_id,
+                ok: false,
+                status: None,
+                error: Some("worktreePath must be an absolute path.".to_string()),
+            };
+        }
 
-    OpenCodeStatusResponse {
-        request_id,
-        ok: true,
-        status: Some(check_opencode_status_runtime(&worktree_path)),
-        error: None,
-    }
+        OpenCodeStatusResponse {
+            request_id,
+            ok: true,
+            status: Some(check_opencode_status_runtime(&worktree_path)),
+            error: None,
+        }
+    })
 }
 
 #[tauri::command]
 fn get_opencode_profile(worktree_path: String) -> OpenCodeProfileResponse {
-    let request_id = request_id();
-    let worktree_path = PathBuf::from(worktree_path.trim());
+    handle_command("get_opencode_profile", |context| {
+        let request_id = context.request_id();
+        let worktree_path = PathBuf::from(worktree_path.trim());
 
-    if !worktree_path.is_absolute() {
-        return OpenCodeProfileResponse {
-            request_id,
-            ok: false,
-            profile: None,
-            error: Some("worktreePath must be an absolute path.".to_string()),
-        };
-    }
+        if !worktree_path.is_absolute() {
+            return OpenCodeProfileResponse {
+                request_id,
+                ok: false,
+                profile: None,
+                error: Some("worktreePath must be an absolute path.".to_string()),
+            };
+        }
 
-    match read_or_default_opencode_profile(&worktree_path) {
-        Ok(profile) => OpenCodeProfileResponse {
-            request_id,
-            ok: true,
-            profile: Some(profile),
-            error: None,
-        },
-        Err(error) => OpenCodeProfileResponse {
-            request_id,
-            ok: false,
-            profile: None,
-            error: Some(error),
-        },
-    }
+        match read_or_default_opencode_profile(&worktree_path) {
+            Ok(profile) => OpenCodeProfileResponse {
+                request_id,
+                ok: true,
+                profile: Some(profile),
+                error: None,
+            },
+            Err(error) => OpenCodeProfileResponse {
+                request_id,
+                ok: false,
+                profile: None,
+                error: Some(error),
+            },
+        }
+    })
 }
 
 #[tauri::command]
@@ -630,105 +648,111 @@ fn set_opencode_profile(
     worktree_path: String,
     payload: SetOpenCodeProfilePayload,
 ) -> OpenCodeProfileResponse {
-    let request_id = request_id();
-    let worktree_path = PathBuf::from(worktree_path.trim());
+    handle_command("set_opencode_profile", |context| {
+        let request_id = context.request_id();
+        let worktree_path = PathBuf::from(worktree_path.trim());
 
-    if !worktree_path.is_absolute() {
-        return OpenCodeProfileResponse {
-            request_id,
-            ok: false,
-            profile: None,
-            error: Some("worktreePath must be an absolute path.".to_string()),
+        if !worktree_path.is_absolute() {
+            return OpenCodeProfileResponse {
+                request_id,
+                ok: false,
+                profile: None,
+                error: Some("worktreePath must be an absolute path.".to_string()),
+            };
+        }
+
+        let current = match read_or_default_opencode_profile(&worktree_path) {
+            Ok(value) => value,
+            Err(error) => {
+                return OpenCodeProfileResponse {
+                    request_id,
+                    ok: false,
+                    profile: None,
+                    error: Some(error),
+                }
+            }
         };
-    }
 
-    let current = match read_or_default_opencode_profile(&worktree_path) {
-        Ok(value) => value,
-        Err(error) => {
-            return OpenCodeProfileResponse {
+        let next = merge_opencode_profile_patch(&current, &payload.patch);
+        match write_opencode_profile(&worktree_path, &next) {
+            Ok(()) => OpenCodeProfileResponse {
+                request_id,
+                ok: true,
+                profile: Some(next),
+                error: None,
+            },
+            Err(error) => OpenCodeProfileResponse {
                 request_id,
                 ok: false,
                 profile: None,
                 error: Some(error),
-            }
+            },
         }
-    };
-
-    let next = merge_opencode_profile_patch(&current, &payload.patch);
-    match write_opencode_profile(&worktree_path, &next) {
-        Ok(()) => OpenCodeProfileResponse {
-            request_id,
-            ok: true,
-            profile: Some(next),
-            error: None,
-        },
-        Err(error) => OpenCodeProfileResponse {
-            request_id,
-            ok: false,
-            profile: None,
-            error: Some(error),
-        },
-    }
+    })
 }
 
 #[tauri::command]
 fn sync_opencode_config(worktree_path: String) -> OpenCodeSyncResponse {
-    let request_id = request_id();
-    let worktree_path = PathBuf::from(worktree_path.trim());
+    handle_command("sync_opencode_config", |context| {
+        let request_id = context.request_id();
+        let worktree_path = PathBuf::from(worktree_path.trim());
 
-    if !worktree_path.is_absolute() {
-        return OpenCodeSyncResponse {
-            request_id,
-            ok: false,
-            result: None,
-            error: Some("worktreePath must be an absolute path.".to_string()),
-        };
-    }
+        if !worktree_path.is_absolute() {
+            return OpenCodeSyncResponse {
+                request_id,
+                ok: false,
+                result: None,
+                error: Some("worktreePath must be an absolute path.".to_string()),
+            };
+        }
 
-    match sync_opencode_config_runtime(&worktree_path) {
-        Ok(result) => OpenCodeSyncResponse {
-            request_id,
-            ok: result.ok,
-            result: Some(result),
-            error: None,
-        },
-        Err(error) => OpenCodeSyncResponse {
-            request_id,
-            ok: false,
-            result: None,
-            error: Some(format!("{}: {}", error.code, error.message)),
-        },
-    }
+        match sync_opencode_config_runtime(&worktree_path) {
+            Ok(result) => OpenCodeSyncResponse {
+                request_id,
+                ok: result.ok,
+                result: Some(result),
+                error: None,
+            },
+            Err(error) => OpenCodeSyncResponse {
+                request_id,
+                ok: false,
+                result: None,
+                error: Some(format!("{}: {}", error.code, error.message)),
+            },
+        }
+    })
 }
 
 #[tauri::command]
 fn repair_opencode_integration(worktree_path: String) -> OpenCodeRepairResponse {
-    let request_id = request_id();
-    let worktree_path = PathBuf::from(worktree_path.trim());
+    handle_command("repair_opencode_integration", |context| {
+        let request_id = context.request_id();
+        let worktree_path = PathBuf::from(worktree_path.trim());
 
-    if !worktree_path.is_absolute() {
-        return OpenCodeRepairResponse {
-            request_id,
-            ok: false,
-            result: None,
-            error: Some("worktreePath must be an absolute path.".to_string()),
-        };
-    }
+        if !worktree_path.is_absolute() {
+            return OpenCodeRepairResponse {
+                request_id,
+                ok: false,
+                result: None,
+                error: Some("worktreePath must be an absolute path.".to_string()),
+            };
+        }
 
-    match repair_opencode_integration_runtime(&worktree_path) {
-        Ok(result) => OpenCodeRepairResponse {
-            request_id,
-            ok: true,
-            result: Some(result),
-            error: None,
-        },
-        Err(error) => OpenCodeRepairResponse {
-            request_id,
-            ok: false,
-            result: None,
-            error: Some(error),
-        },
-    }
+        match repair_opencode_integration_runtime(&worktree_path) {
+            Ok(result) => OpenCodeRepairResponse {
+                request_id,
+                ok: true,
+                result: Some(result),
+                error: None,
+            },
+            Err(error) => OpenCodeRepairResponse {
+                request_id,
+                ok: false,
+                result: None,
+                error: Some(error),
+            },
+        }
+    })
 }
 
 #[tauri::command]
@@ -736,64 +760,68 @@ fn run_opencode_flow(
     worktree_path: String,
     payload: RunOpenCodeFlowPayload,
 ) -> OpenCodeRunResponse {
-    let request_id = request_id();
-    let worktree_path = PathBuf::from(worktree_path.trim());
+    handle_command("run_opencode_flow", |context| {
+        let request_id = context.request_id();
+        let worktree_path = PathBuf::from(worktree_path.trim());
+
+        let result = if !worktree_path.is_absolute() {
+            OpenCodeRunResult {
+                run_id: Uuid::new_v4().to_string(),
+                phase: payload.phase,
+                status: "blocked".to_string(),
+                exit_code: None,
+                duration_ms: 0,
+                summary: Some("worktreePath must be an absolute path.".to_string()),
+                stdout: String::new(),
+                stderr: String::new(),
+                error: Some(build_opencode_error(
+                    "ProfileInvalid",
+                    "worktreePath must be an absolute path.",
+                    "Pass an absolute worktree path.",
+                    Vec::new(),
+                )),
+            }
+        } else {
+            run_opencode_flow_runtime(&worktree_path, &payload.phase, &payload.args)
+        };
 
-    let result = if !worktree_path.is_absolute() {
-        OpenCodeRunResult {
-            run_id: Uuid::new_v4().to_string(),
-            phase: payload.phase,
-            status: "blocked".to_string(),
-            exit_code: None,
-            duration_ms: 0,
-            summary: Some("worktreePath must be an absolute path.".to_string()),
-            stdout: String::new(),
-            stderr: String::new(),
-            error: Some(build_opencode_error(
-                "ProfileInvalid",
-                "worktreePath must be an absolute path.",
-                "Pass an absolute worktree path.",
-                Vec::new(),
-            )),
+        OpenCodeRunResponse {
+            request_id,
+            ok: matches!(result.status.as_str(), "ok" | "warning"),
+            result,
         }
-    } else {
-        run_opencode_flow_runtime(&worktree_path, &payload.phase, &payload.args)
-    };
-
-    OpenCodeRunResponse {
-        request_id,
-        ok: matches!(result.status.as_str(), "ok" | "warning"),
-        result,
-    }
+    })
 }
 
 #[tauri::command]
 fn cancel_opencode_flow(run_id: String) -> OpenCodeCancelResponse {
-    let request_id = request_id();
-    let trimmed = run_id.trim();
-    let result = CancelResult {
-        run_id: if trimmed.is_empty() {
-            Uuid::new_v4().to_string()
-        } else {
-            trimmed.to_string()
-        },
-        supported: false,
-        cancelled: false,
-        status: "blocked".to_string(),
-        message: "Run cancellation is not yet supported in this phase.".to_string(),
-        error: Some(build_opencode_error(
-            "NotYetSupported",
-            "cancel_opencode_flow is not implemented yet.",
-            "Allow the running phase to finish or restart the process manually.",
-            Vec::new(),
-        )),
-    };
+    handle_command("cancel_opencode_flow", |context| {
+        let request_id = context.request_id();
+        let trimmed = run_id.trim();
+        let result = CancelResult {
+            run_id: if trimmed.is_empty() {
+                Uuid::new_v4().to_string()
+            } else {
+                trimmed.to_string()
+            },
+            supported: false,
+            cancelled: false,
+            status: "blocked".to_string(),
+            message: "Run cancellation is not yet supported in this phase.".to_string(),
+            error: Some(build_opencode_error(
+                "NotYetSupported",
+                "cancel_opencode_flow is not implemented yet.",
+                "Allow the running phase to finish or restart the process manually.",
+                Vec::new(),
+            )),
+        };
 
-    OpenCodeCancelResponse {
-        request_id,
-        ok: false,
-        result,
-    }
+        OpenCodeCancelResponse {
+            request_id,
+            ok: false,
+            result,
+        }
+    })
 }
 
 #[cfg(test)]
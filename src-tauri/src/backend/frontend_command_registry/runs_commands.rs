@@ -0,0 +1,47 @@
+#[tauri::command]
+fn command_runs_list(app: AppHandle, payload: CommandRunsListPayload) -> CommandRunsListResponse {
+    handle_command("command_runs_list", |context| {
+        let request_id = context.request_id();
+        let workspace_root = match active_workspace_root_from_state(&app) {
+            Ok(root) => root.display().to_string(),
+            Err(error) => {
+                return CommandRunsListResponse {
+                    request_id,
+                    ok: false,
+                    runs: Vec::new(),
+                    error: Some(error),
+                }
+            }
+        };
+        let worktree = payload
+            .worktree
+            .as_deref()
+            .map(str::trim)
+            .filter(|worktree| !worktree.is_empty());
+        let runs = app
+            .try_state::<CommandRunsState>()
+            .map(|state| {
+                state
+                    .lock_runs()
+                    .iter()
+                    .filter(|run| run.workspace_root == workspace_root)
+                    .filter(|run| worktree.is_none_or(|worktree| run.worktree == worktree))
+                    .filter(|run| {
+                        payload
+                            .command_id
+                            .as_deref()
+                            .is_none_or(|command_id| run.command_id == command_id)
+                    })
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        CommandRunsListResponse {
+            request_id,
+            ok: true,
+            runs,
+            error: None,
+        }
+    })
+}
@@ -1,73 +1,80 @@
 #[tauri::command]
-fn groove_bin_status(app: AppHandle, state: State<GrooveBinStatusState>) -> GrooveBinStatusResponse {
-    let request_id = request_id();
+fn groove_bin_status(
+    app: AppHandle,
+    state: State<GrooveBinStatusState>,
+) -> GrooveBinStatusResponse {
+    handle_command("groove_bin_status", |context| {
+        let request_id = context.request_id();
 
-    match state.status.lock() {
-        Ok(mut stored) => {
-            let status = stored
-                .clone()
-                .unwrap_or_else(|| evaluate_groove_bin_check_status(&app));
-            *stored = Some(status.clone());
-            GrooveBinStatusResponse {
-                request_id,
-                ok: true,
-                status,
-                error: None,
-            }
+        let mut stored = state.lock_status();
+        let status = stored
+            .clone()
+            .unwrap_or_else(|| evaluate_groove_bin_check_status(&app));
+        *stored = Some(status.clone());
+        GrooveBinStatusResponse {
+            request_id,
+            ok: true,
+            status,
+            error: None,
         }
-        Err(error) => {
-            let status = evaluate_groove_bin_check_status(&app);
-            GrooveBinStatusResponse {
-                request_id,
-                ok: false,
-                status,
-                error: Some(format!("Failed to persist GROOVE_BIN status: {error}")),
-            }
+    })
+}
+
+#[tauri::command(async)]
+fn app_health(app: AppHandle) -> AppHealthResponse {
+    handle_command("app_health", |context| {
+        let subsystems = collect_app_health(&app);
+        let status = subsystems
+            .iter()
+            .map(|subsystem| subsystem.status.as_str())
+            .max_by_key(|status| app_health_status_rank(status))
+            .unwrap_or("ok")
+            .to_string();
+        AppHealthResponse {
+            request_id: context.request_id(),
+            ok: true,
+            status,
+            app_version: app.package_info().version.to_string(),
+            checked_at: now_iso(),
+            subsystems,
+            error: None,
         }
-    }
+    })
 }
 
 #[tauri::command]
-fn groove_bin_repair(app: AppHandle, state: State<GrooveBinStatusState>) -> GrooveBinRepairResponse {
-    let request_id = request_id();
-    let mut changed = false;
-    let mut action = "noop".to_string();
-    let mut cleared_path = None;
+fn groove_bin_repair(
+    app: AppHandle,
+    state: State<GrooveBinStatusState>,
+) -> GrooveBinRepairResponse {
+    handle_command("groove_bin_repair", |context| {
+        let request_id = context.request_id();
+        let mut changed = false;
+        let mut action = "noop".to_string();
+        let mut cleared_path = None;
 
-    let pre_status = evaluate_groove_bin_check_status(&app);
-    if pre_status.has_issue {
-        if let Some(path) = pre_status.configured_path.clone() {
-            std::env::remove_var("GROOVE_BIN");
-            changed = true;
-            action = "cleared-invalid-env".to_string();
-            cleared_path = Some(path);
+        let pre_status = evaluate_groove_bin_check_status(&app);
+        if pre_status.has_issue {
+            if let Some(path) = pre_status.configured_path.clone() {
+                std::env::remove_var("GROOVE_BIN");
+                changed = true;
+                action = "cleared-invalid-env".to_string();
+                cleared_path = Some(path);
+            }
         }
-    }
 
-    let post_status = evaluate_groove_bin_check_status(&app);
+        let post_status = evaluate_groove_bin_check_status(&app);
 
-    match state.status.lock() {
-        Ok(mut stored) => {
-            *stored = Some(post_status.clone());
-            GrooveBinRepairResponse {
-                request_id,
-                ok: true,
-                changed,
-                action,
-                cleared_path,
-                status: post_status,
-                error: None,
-            }
-        }
-        Err(error) => GrooveBinRepairResponse {
+        *state.lock_status() = Some(post_status.clone());
+        GrooveBinRepairResponse {
             request_id,
-            ok: false,
+            ok: true,
             changed,
             action,
             cleared_path,
             status: post_status,
-            error: Some(format!("Failed to persist GROOVE_BIN status after repair: {error}")),
-        },
-    }
+            error: None,
+        }
+    })
 }
 
@@ -0,0 +1,14 @@
+#[tauri::command]
+fn status_page_status() -> StatusPageStatusResponse {
+    handle_command("status_page_status", |context| {
+        let (settings, listening, url, error) = status_page_listener_status();
+        StatusPageStatusResponse {
+            request_id: context.request_id(),
+            ok: error.is_none(),
+            settings,
+            listening,
+            url,
+            error,
+        }
+    })
+}
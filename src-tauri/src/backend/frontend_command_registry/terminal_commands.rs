@@ -3,87 +3,138 @@ fn workspace_open_terminal(
     app: AppHandle,
     payload: WorkspaceOpenTerminalPayload,
 ) -> GrooveCommandResponse {
-    let request_id = request_id();
+    handle_command("workspace_open_terminal", |context| {
+        let request_id = context.request_id();
 
-    let Some(worktree) = payload
-        .worktree
-        .as_deref()
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-    else {
-        return GrooveCommandResponse {
-            request_id,
-            ok: false,
-            exit_code: None,
-            stdout: String::new(),
-            stderr: String::new(),
-            error: Some("worktree is required and must be a non-empty string.".to_string()),
-        };
-    };
-
-    if !is_safe_path_token(worktree) {
-        return GrooveCommandResponse {
-            request_id,
-            ok: false,
-            exit_code: None,
-            stdout: String::new(),
-            stderr: String::new(),
-            error: Some("worktree contains unsafe characters or path segments.".to_string()),
-        };
-    }
-
-    let known_worktrees = match validate_known_worktrees(&payload.known_worktrees) {
-        Ok(known_worktrees) => known_worktrees,
-        Err(error) => {
+        let Some(worktree) = payload
+            .worktree
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+        else {
             return GrooveCommandResponse {
                 request_id,
                 ok: false,
                 exit_code: None,
                 stdout: String::new(),
                 stderr: String::new(),
-                error: Some(error),
-            }
-        }
-    };
+                error: Some("worktree is required and must be a non-empty string.".to_string()),
+                debug_transcripts: None,
+            };
+        };
 
-    let workspace_root = match resolve_workspace_root(
-        &app,
-        &payload.root_name,
-        Some(worktree),
-        &known_worktrees,
-        &payload.workspace_meta,
-    ) {
-        Ok(root) => root,
-        Err(error) => {
+        if !is_safe_path_token(worktree) {
             return GrooveCommandResponse {
                 request_id,
                 ok: false,
                 exit_code: None,
                 stdout: String::new(),
                 stderr: String::new(),
-                error: Some(error),
-            }
+                error: Some("worktree contains unsafe characters or path segments.".to_string()),
+                debug_transcripts: None,
+            };
         }
-    };
 
-    let workspace_meta = match ensure_workspace_meta(&workspace_root) {
-        Ok((meta, _)) => meta,
-        Err(error) => {
-            return GrooveCommandResponse {
-                request_id,
-                ok: false,
-                exit_code: None,
-                stdout: String::new(),
-                stderr: String::new(),
-                error: Some(error),
+        let known_worktrees = match validate_known_worktrees(&payload.known_worktrees) {
+            Ok(known_worktrees) => known_worktrees,
+            Err(error) => {
+                return GrooveCommandResponse {
+                    request_id,
+                    ok: false,
+                    exit_code: None,
+                    stdout: String::new(),
+                    stderr: String::new(),
+                    error: Some(error),
+                    debug_transcripts: None,
+                }
+            }
+        };
+
+        let workspace_root = match resolve_workspace_root(
+            &app,
+            &payload.root_name,
+            Some(worktree),
+            &known_worktrees,
+            &payload.workspace_meta,
+        ) {
+            Ok(root) => root,
+            Err(error) => {
+                return GrooveCommandResponse {
+                    request_id,
+                    ok: false,
+                    exit_code: None,
+                    stdout: String::new(),
+                    stderr: String::new(),
+                    error: Some(error),
+                    debug_transcripts: None,
+                }
+            }
+        };
+
+        let workspace_meta = match ensure_workspace_meta(&workspace_root) {
+            Ok((meta, _)) => meta,
+            Err(error) => {
+                return GrooveCommandResponse {
+                    request_id,
+                    ok: false,
+                    exit_code: None,
+                    stdout: String::new(),
+                    stderr: String::new(),
+                    error: Some(error),
+                    debug_transcripts: None,
+                }
+            }
+        };
+
+        let effective_root = effective_workspace_root(&workspace_root, &workspace_meta);
+        let worktree_path = match ensure_worktree_in_dir(&effective_root, worktree, ".worktrees") {
+            Ok(path) => path,
+            Err(error) => {
+                return GrooveCommandResponse {
+                    request_id,
+                    ok: false,
+                    exit_code: None,
+                    stdout: String::new(),
+                    stderr: String::new(),
+                    error: Some(error),
+                    debug_transcripts: None,
+                }
+            }
+        };
+
+        if let Some(command) = workspace_meta_terminal_command(&workspace_meta) {
+            if let Err(error) =
+                authorize_workspace_command(&app, &workspace_root, command, "its terminal command")
+            {
+                return GrooveCommandResponse {
+                    request_id,
+                    ok: false,
+                    exit_code: None,
+                    stdout: String::new(),
+                    stderr: String::new(),
+                    error: Some(error),
+                    debug_transcripts: None,
+                };
             }
         }
-    };
 
-    let effective_root = effective_workspace_root(&workspace_root, &workspace_meta);
-    let worktree_path = match ensure_worktree_in_dir(&effective_root, worktree, ".worktrees") {
-        Ok(path) => path,
-        Err(error) => {
+        let launched_command =
+            match launch_open_terminal_at_worktree_command(&worktree_path, &workspace_meta) {
+                Ok(command) => command,
+                Err(error) => {
+                    return GrooveCommandResponse {
+                        request_id,
+                        ok: false,
+                        exit_code: None,
+                        stdout: String::new(),
+                        stderr: String::new(),
+                        error: Some(error),
+                        debug_transcripts: None,
+                    }
+                }
+            };
+
+        if let Err(error) = record_worktree_last_executed_at(&app, &workspace_root, worktree) {
             return GrooveCommandResponse {
                 request_id,
                 ok: false,
@@ -91,12 +142,102 @@ fn workspace_open_terminal(
                 stdout: String::new(),
                 stderr: String::new(),
                 error: Some(error),
+                debug_transcripts: None,
+            };
+        }
+
+        GrooveCommandResponse {
+            request_id,
+            ok: true,
+            exit_code: Some(0),
+            stdout: format!("Opened terminal using: {launched_command}"),
+            stderr: String::new(),
+            error: None,
+            debug_transcripts: None,
+        }
+    })
+}
+
+#[tauri::command]
+fn workspace_open_workspace_terminal(
+    app: AppHandle,
+    payload: WorkspaceEventsPayload,
+) -> GrooveCommandResponse {
+    handle_command("workspace_open_workspace_terminal", |context| {
+        let request_id = context.request_id();
+
+        let known_worktrees = match validate_known_worktrees(&payload.known_worktrees) {
+            Ok(known_worktrees) => known_worktrees,
+            Err(error) => {
+                return GrooveCommandResponse {
+                    request_id,
+                    ok: false,
+                    exit_code: None,
+                    stdout: String::new(),
+                    stderr: String::new(),
+                    error: Some(error),
+                    debug_transcripts: None,
+                }
+            }
+        };
+
+        let workspace_root = match resolve_workspace_root(
+            &app,
+            &payload.root_name,
+            None,
+            &known_worktrees,
+            &payload.workspace_meta,
+        ) {
+            Ok(root) => root,
+            Err(error) => {
+                return GrooveCommandResponse {
+                    request_id,
+                    ok: false,
+                    exit_code: None,
+                    stdout: String::new(),
+                    stderr: String::new(),
+                    error: Some(error),
+                    debug_transcripts: None,
+                }
+            }
+        };
+
+        let workspace_meta = match ensure_workspace_meta(&workspace_root) {
+            Ok((meta, _)) => meta,
+            Err(error) => {
+                return GrooveCommandResponse {
+                    request_id,
+                    ok: false,
+                    exit_code: None,
+                    stdout: String::new(),
+                    stderr: String::new(),
+                    error: Some(error),
+                    debug_transcripts: None,
+                }
+            }
+        };
+
+        if let Some(command) = workspace_meta_terminal_command(&workspace_meta) {
+            if let Err(error) =
+                authorize_workspace_command(&app, &workspace_root, command, "its terminal command")
+            {
+                return GrooveCommandResponse {
+                    request_id,
+                    ok: false,
+                    exit_code: None,
+                    stdout: String::new(),
+                    stderr: String::new(),
+                    error: Some(error),
+                    debug_transcripts: None,
+                };
             }
         }
-    };
 
-    let launched_command =
-        match launch_open_terminal_at_worktree_command(&worktree_path, &workspace_meta) {
+        let workspace_terminal_root = effective_workspace_root(&workspace_root, &workspace_meta);
+        let launched_command = match launch_open_terminal_at_worktree_command(
+            &workspace_terminal_root,
+            &workspace_meta,
+        ) {
             Ok(command) => command,
             Err(error) => {
                 return GrooveCommandResponse {
@@ -106,110 +247,430 @@ fn workspace_open_terminal(
                     stdout: String::new(),
                     stderr: String::new(),
                     error: Some(error),
+                    debug_transcripts: None,
                 }
             }
         };
 
-    if let Err(error) = record_worktree_last_executed_at(&app, &workspace_root, worktree) {
-        return GrooveCommandResponse {
+        GrooveCommandResponse {
             request_id,
-            ok: false,
-            exit_code: None,
-            stdout: String::new(),
+            ok: true,
+            exit_code: Some(0),
+            stdout: format!("Opened terminal using: {launched_command}"),
             stderr: String::new(),
+            error: None,
+            debug_transcripts: None,
+        }
+    })
+}
+
+#[tauri::command]
+fn workspace_custom_commands_run(
+    app: AppHandle,
+    state: State<GrooveTerminalState>,
+    payload: WorkspaceCustomCommandRunPayload,
+) -> WorkspaceCustomCommandRunResponse {
+    handle_command("workspace_custom_commands_run", |context| {
+        let request_id = context.request_id();
+        let run_error = |error: String| WorkspaceCustomCommandRunResponse {
+            request_id: request_id.clone(),
+            ok: false,
+            command: None,
+            run_mode: None,
+            session: None,
+            run: None,
             error: Some(error),
         };
-    }
 
-    GrooveCommandResponse {
-        request_id,
-        ok: true,
-        exit_code: Some(0),
-        stdout: format!("Opened terminal using: {launched_command}"),
-        stderr: String::new(),
-        error: None,
-    }
+        let worktree = payload.worktree.trim();
+        if worktree.is_empty() {
+            return run_error("worktree is required and must be a non-empty string.".to_string());
+        }
+
+        let (workspace_root, worktree_path) = match resolve_terminal_worktree_context(
+            &app,
+            &payload.root_name,
+            &payload.known_worktrees,
+            &payload.workspace_meta,
+            worktree,
+        ) {
+            Ok(value) => value,
+            Err(error) => return run_error(error),
+        };
+
+        let workspace_meta = match ensure_workspace_meta(&workspace_root) {
+            Ok((meta, _)) => meta,
+            Err(error) => return run_error(error),
+        };
+        if workspace_meta.remote.is_some() {
+            return run_error(
+                "Custom commands are not supported for remote workspaces yet.".to_string(),
+            );
+        }
+        let Some(custom_command) = workspace_meta
+            .custom_commands
+            .iter()
+            .find(|command| command.id == payload.id)
+        else {
+            return run_error(format!("No custom command with id {}.", payload.id));
+        };
+        if let Err(error) = authorize_workspace_command(
+            &app,
+            &workspace_root,
+            &custom_command.template,
+            "its custom commands",
+        ) {
+            return run_error(error);
+        }
+
+        let branch = resolve_branch_from_worktree(&worktree_path);
+        let (program, args) = match resolve_custom_command(
+            &custom_command.template,
+            &worktree_path,
+            branch.as_deref(),
+        ) {
+            Ok(value) => value,
+            Err(error) => return run_error(error),
+        };
+
+        let rendered_command = std::iter::once(program.as_str())
+            .chain(args.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let (command, session, run) = match custom_command.run_mode {
+            CustomCommandRunMode::Detached => {
+                if let Err(error) =
+                    spawn_terminal_process(&program, &args, &worktree_path, &worktree_path)
+                {
+                    return run_error(format!("Failed to run {program}: {error}"));
+                }
+                (rendered_command, None, None)
+            }
+            CustomCommandRunMode::Capture => {
+                let run = CommandRun {
+                    id: Uuid::new_v4().to_string(),
+                    command_id: custom_command.id.clone(),
+                    label: custom_command.label.clone(),
+                    workspace_root: workspace_root.display().to_string(),
+                    worktree: worktree.to_string(),
+                    command: rendered_command.clone(),
+                    status: CommandRunStatus::Running,
+                    exit_code: None,
+                    stdout: String::new(),
+                    stderr: String::new(),
+                    output_truncated: false,
+                    error: None,
+                    started_at: now_iso(),
+                    finished_at: None,
+                    duration_ms: None,
+                };
+                start_captured_command_run(
+                    &app,
+                    run.clone(),
+                    terminal_process_command(&program, &args, &worktree_path, &worktree_path),
+                );
+                (rendered_command, None, Some(run))
+            }
+            CustomCommandRunMode::Terminal => {
+                // Typed into a fresh shell rather than spawned directly, so the
+                // tab stays open with the output once the command exits.
+                let command = std::iter::once(&program)
+                    .chain(args.iter())
+                    .map(|token| shell_single_quote_escape(token))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let session = match open_groove_terminal_session(
+                    &app,
+                    &state,
+                    &workspace_root,
+                    worktree,
+                    &worktree_path,
+                    GrooveTerminalOpenMode::Plain,
+                    None,
+                    payload.cols,
+                    payload.rows,
+                    false,
+                    true,
+                    false,
+                ) {
+                    Ok(session) => session,
+                    Err(error) => return run_error(error),
+                };
+                if let Err(error) = write_groove_terminal_input(
+                    &app,
+                    &state,
+                    &session.session_id,
+                    &format!("{command}\r"),
+                ) {
+                    return run_error(error);
+                }
+                (command, Some(session), None)
+            }
+        };
+
+        if let Err(error) = record_worktree_last_executed_at(&app, &workspace_root, worktree) {
+            return run_error(error);
+        }
+
+        WorkspaceCustomCommandRunResponse {
+            request_id,
+            ok: true,
+            command: Some(command),
+            run_mode: Some(custom_command.run_mode),
+            session,
+            run,
+            error: None,
+        }
+    })
 }
 
 #[tauri::command]
-fn workspace_open_workspace_terminal(
+fn groove_tmux_list_windows(
     app: AppHandle,
     payload: WorkspaceEventsPayload,
+) -> GrooveTmuxWindowsResponse {
+    handle_command("groove_tmux_list_windows", |context| {
+        let request_id = context.request_id();
+
+        let workspace_meta = match resolve_payload_workspace_meta(
+            &app,
+            &payload.root_name,
+            &payload.known_worktrees,
+            &payload.workspace_meta,
+        ) {
+            Ok(meta) => meta,
+            Err(error) => {
+                return GrooveTmuxWindowsResponse {
+                    request_id,
+                    ok: false,
+                    session: None,
+                    attached: false,
+                    windows: Vec::new(),
+                    error: Some(error),
+                }
+            }
+        };
+
+        let session = tmux_session_for_workspace(&workspace_meta);
+        match list_tmux_session_windows(&session) {
+            Ok(windows) => GrooveTmuxWindowsResponse {
+                request_id,
+                ok: true,
+                attached: windows.iter().any(|window| window.session_attached),
+                windows: windows
+                    .into_iter()
+                    .map(|window| GrooveTmuxWindow {
+                        index: window.index,
+                        name: window.name,
+                        path: window.path,
+                        active: window.active,
+                    })
+                    .collect(),
+                session: Some(session),
+                error: None,
+            },
+            Err(error) => GrooveTmuxWindowsResponse {
+                request_id,
+                ok: false,
+                session: Some(session),
+                attached: false,
+                windows: Vec::new(),
+                error: Some(error),
+            },
+        }
+    })
+}
+
+#[tauri::command]
+fn groove_tmux_kill_window(
+    app: AppHandle,
+    payload: GrooveTmuxKillWindowPayload,
 ) -> GrooveCommandResponse {
-    let request_id = request_id();
+    handle_command("groove_tmux_kill_window", |context| {
+        let request_id = context.request_id();
 
-    let known_worktrees = match validate_known_worktrees(&payload.known_worktrees) {
-        Ok(known_worktrees) => known_worktrees,
-        Err(error) => {
+        let worktree = payload.worktree.trim();
+        if worktree.is_empty() {
             return GrooveCommandResponse {
                 request_id,
                 ok: false,
                 exit_code: None,
                 stdout: String::new(),
                 stderr: String::new(),
-                error: Some(error),
-            }
+                error: Some("worktree must be a non-empty string.".to_string()),
+                debug_transcripts: None,
+            };
         }
-    };
 
-    let workspace_root = match resolve_workspace_root(
-        &app,
-        &payload.root_name,
-        None,
-        &known_worktrees,
-        &payload.workspace_meta,
-    ) {
-        Ok(root) => root,
-        Err(error) => {
-            return GrooveCommandResponse {
+        let killed = resolve_payload_workspace_meta(
+            &app,
+            &payload.root_name,
+            &payload.known_worktrees,
+            &payload.workspace_meta,
+        )
+        .and_then(|workspace_meta| kill_tmux_worktree_window(&workspace_meta, worktree));
+        match killed {
+            Ok(killed) => GrooveCommandResponse {
+                request_id,
+                ok: true,
+                exit_code: Some(0),
+                stdout: if killed {
+                    format!("Killed tmux window for {worktree}.")
+                } else {
+                    format!("No tmux window is open for {worktree}.")
+                },
+                stderr: String::new(),
+                error: None,
+                debug_transcripts: None,
+            },
+            Err(error) => GrooveCommandResponse {
                 request_id,
                 ok: false,
                 exit_code: None,
                 stdout: String::new(),
                 stderr: String::new(),
                 error: Some(error),
-            }
+                debug_transcripts: None,
+            },
         }
-    };
+    })
+}
 
-    let workspace_meta = match ensure_workspace_meta(&workspace_root) {
-        Ok((meta, _)) => meta,
-        Err(error) => {
-            return GrooveCommandResponse {
+#[tauri::command]
+fn groove_zellij_list_sessions(
+    app: AppHandle,
+    payload: WorkspaceEventsPayload,
+) -> GrooveZellijSessionsResponse {
+    handle_command("groove_zellij_list_sessions", |context| {
+        let request_id = context.request_id();
+
+        let sessions = resolve_payload_workspace_meta(
+            &app,
+            &payload.root_name,
+            &payload.known_worktrees,
+            &payload.workspace_meta,
+        )
+        .and_then(|workspace_meta| zellij_sessions_for_workspace(&workspace_meta));
+        match sessions {
+            Ok(sessions) => GrooveZellijSessionsResponse {
+                request_id,
+                ok: true,
+                sessions,
+                error: None,
+            },
+            Err(error) => GrooveZellijSessionsResponse {
+                request_id,
+                ok: false,
+                sessions: Vec::new(),
+                error: Some(error),
+            },
+        }
+    })
+}
+
+/// Starts the worktree's zellij session if needed; the response says how to
+/// attach to it.
+#[tauri::command]
+fn groove_zellij_attach_session(
+    app: AppHandle,
+    payload: WorkspaceOpenTerminalPayload,
+) -> GrooveCommandResponse {
+    handle_command("groove_zellij_attach_session", |context| {
+        let request_id = context.request_id();
+
+        let opened = zellij_payload_worktree(&payload).and_then(|worktree| {
+            let known_worktrees = validate_known_worktrees(&payload.known_worktrees)?;
+            let workspace_root = resolve_workspace_root(
+                &app,
+                &payload.root_name,
+                Some(worktree),
+                &known_worktrees,
+                &payload.workspace_meta,
+            )?;
+            let (workspace_meta, _) = ensure_workspace_meta(&workspace_root)?;
+            let effective_root = effective_workspace_root(&workspace_root, &workspace_meta);
+            let worktree_path = ensure_worktree_in_dir(&effective_root, worktree, ".worktrees")?;
+            open_zellij_worktree_session(&workspace_meta, &worktree_path)
+        });
+        match opened {
+            Ok(message) => GrooveCommandResponse {
+                request_id,
+                ok: true,
+                exit_code: Some(0),
+                stdout: message,
+                stderr: String::new(),
+                error: None,
+                debug_transcripts: None,
+            },
+            Err(error) => GrooveCommandResponse {
                 request_id,
                 ok: false,
                 exit_code: None,
                 stdout: String::new(),
                 stderr: String::new(),
                 error: Some(error),
-            }
+                debug_transcripts: None,
+            },
         }
-    };
+    })
+}
 
-    let workspace_terminal_root = effective_workspace_root(&workspace_root, &workspace_meta);
-    let launched_command =
-        match launch_open_terminal_at_worktree_command(&workspace_terminal_root, &workspace_meta) {
-            Ok(command) => command,
-            Err(error) => {
-                return GrooveCommandResponse {
-                    request_id,
-                    ok: false,
-                    exit_code: None,
-                    stdout: String::new(),
-                    stderr: String::new(),
-                    error: Some(error),
-                }
-            }
-        };
+#[tauri::command]
+fn groove_zellij_kill_session(
+    app: AppHandle,
+    payload: WorkspaceOpenTerminalPayload,
+) -> GrooveCommandResponse {
+    handle_command("groove_zellij_kill_session", |context| {
+        let request_id = context.request_id();
 
-    GrooveCommandResponse {
-        request_id,
-        ok: true,
-        exit_code: Some(0),
-        stdout: format!("Opened terminal using: {launched_command}"),
-        stderr: String::new(),
-        error: None,
+        let killed = zellij_payload_worktree(&payload).and_then(|worktree| {
+            let workspace_meta = resolve_payload_workspace_meta(
+                &app,
+                &payload.root_name,
+                &payload.known_worktrees,
+                &payload.workspace_meta,
+            )?;
+            kill_zellij_worktree_session(&workspace_meta, worktree)
+                .map(|killed| (worktree.to_string(), killed))
+        });
+        match killed {
+            Ok((worktree, killed)) => GrooveCommandResponse {
+                request_id,
+                ok: true,
+                exit_code: Some(0),
+                stdout: if killed {
+                    format!("Killed zellij session for {worktree}.")
+                } else {
+                    format!("No zellij session exists for {worktree}.")
+                },
+                stderr: String::new(),
+                error: None,
+                debug_transcripts: None,
+            },
+            Err(error) => GrooveCommandResponse {
+                request_id,
+                ok: false,
+                exit_code: None,
+                stdout: String::new(),
+                stderr: String::new(),
+                error: Some(error),
+                debug_transcripts: None,
+            },
+        }
+    })
+}
+
+fn zellij_payload_worktree(payload: &WorkspaceOpenTerminalPayload) -> Result<&str, String> {
+    let worktree = payload
+        .worktree
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| "worktree is required and must be a non-empty string.".to_string())?;
+    if !is_safe_path_token(worktree) {
+        return Err("worktree contains unsafe characters or path segments.".to_string());
     }
+    Ok(worktree)
 }
 
 #[tauri::command]
@@ -218,86 +679,216 @@ fn groove_terminal_open(
     state: State<GrooveTerminalState>,
     payload: GrooveTerminalOpenPayload,
 ) -> GrooveTerminalResponse {
-    let request_id = request_id();
-    let worktree = payload.worktree.trim();
-    if worktree.is_empty() {
-        return GrooveTerminalResponse {
-            request_id,
-            ok: false,
-            session: None,
-            error: Some("worktree is required and must be a non-empty string.".to_string()),
-        };
-    }
-
-    let target = match validate_groove_terminal_target(payload.target.as_deref()) {
-        Ok(value) => value,
-        Err(error) => {
+    handle_command("groove_terminal_open", |context| {
+        let request_id = context.request_id();
+        let worktree = payload.worktree.trim();
+        if worktree.is_empty() {
             return GrooveTerminalResponse {
                 request_id,
                 ok: false,
                 session: None,
-                error: Some(error),
+                error: Some("worktree is required and must be a non-empty string.".to_string()),
             };
         }
-    };
 
-    let open_mode = match validate_groove_terminal_open_mode(payload.open_mode.as_deref()) {
-        Ok(value) => value,
-        Err(error) => {
-            return GrooveTerminalResponse {
+        let target = match validate_groove_terminal_target(payload.target.as_deref()) {
+            Ok(value) => value,
+            Err(error) => {
+                return GrooveTerminalResponse {
+                    request_id,
+                    ok: false,
+                    session: None,
+                    error: Some(error),
+                };
+            }
+        };
+
+        let open_mode = match validate_groove_terminal_open_mode(payload.open_mode.as_deref()) {
+            Ok(value) => value,
+            Err(error) => {
+                return GrooveTerminalResponse {
+                    request_id,
+                    ok: false,
+                    session: None,
+                    error: Some(error),
+                };
+            }
+        };
+
+        let (workspace_root, worktree_path) = match resolve_terminal_worktree_context(
+            &app,
+            &payload.root_name,
+            &payload.known_worktrees,
+            &payload.workspace_meta,
+            worktree,
+        ) {
+            Ok(value) => value,
+            Err(error) => {
+                return GrooveTerminalResponse {
+                    request_id,
+                    ok: false,
+                    session: None,
+                    error: Some(error),
+                };
+            }
+        };
+
+        match open_groove_terminal_session(
+            &app,
+            &state,
+            &workspace_root,
+            worktree,
+            &worktree_path,
+            open_mode,
+            target.as_deref(),
+            payload.cols,
+            payload.rows,
+            payload.force_restart.unwrap_or(false),
+            payload.open_new.unwrap_or(false),
+            false,
+        ) {
+            Ok(session) => GrooveTerminalResponse {
+                request_id,
+                ok: true,
+                session: Some(session),
+                error: None,
+            },
+            Err(error) => GrooveTerminalResponse {
                 request_id,
                 ok: false,
                 session: None,
                 error: Some(error),
-            };
+            },
         }
-    };
-
-    let (workspace_root, worktree_path) = match resolve_terminal_worktree_context(
-        &app,
-        &payload.root_name,
-        &payload.known_worktrees,
-        &payload.workspace_meta,
-        worktree,
-    ) {
-        Ok(value) => value,
-        Err(error) => {
-            return GrooveTerminalResponse {
+    })
+}
+
+#[tauri::command]
+fn worktree_session_layout_restore(
+    app: AppHandle,
+    state: State<GrooveTerminalState>,
+    payload: WorktreeSessionLayoutRestorePayload,
+) -> WorktreeSessionLayoutRestoreResponse {
+    handle_command("worktree_session_layout_restore", |context| {
+        let request_id = context.request_id();
+        let worktree = payload.worktree.trim();
+        if worktree.is_empty() {
+            return WorktreeSessionLayoutRestoreResponse {
                 request_id,
                 ok: false,
-                session: None,
-                error: Some(error),
+                sessions: Vec::new(),
+                failures: Vec::new(),
+                error: Some("worktree is required and must be a non-empty string.".to_string()),
             };
         }
-    };
-
-    match open_groove_terminal_session(
-        &app,
-        &state,
-        &workspace_root,
-        worktree,
-        &worktree_path,
-        open_mode,
-        target.as_deref(),
-        payload.cols,
-        payload.rows,
-        payload.force_restart.unwrap_or(false),
-        payload.open_new.unwrap_or(false),
-        false,
-    ) {
-        Ok(session) => GrooveTerminalResponse {
-            request_id,
-            ok: true,
-            session: Some(session),
-            error: None,
-        },
-        Err(error) => GrooveTerminalResponse {
+
+        let (workspace_root, worktree_path) = match resolve_terminal_worktree_context(
+            &app,
+            &payload.root_name,
+            &payload.known_worktrees,
+            &payload.workspace_meta,
+            worktree,
+        ) {
+            Ok(value) => value,
+            Err(error) => {
+                return WorktreeSessionLayoutRestoreResponse {
+                    request_id,
+                    ok: false,
+                    sessions: Vec::new(),
+                    failures: Vec::new(),
+                    error: Some(error),
+                };
+            }
+        };
+
+        let layout = match read_worktree_session_layout(&app, &workspace_root, worktree) {
+            Ok(value) => value.unwrap_or_default(),
+            Err(error) => {
+                return WorktreeSessionLayoutRestoreResponse {
+                    request_id,
+                    ok: false,
+                    sessions: Vec::new(),
+                    failures: Vec::new(),
+                    error: Some(error),
+                };
+            }
+        };
+
+        let mut sessions = Vec::new();
+        let mut failures = Vec::new();
+        let mut stale_session_ids = Vec::new();
+        for entry in layout.entries {
+            // Sessions that are still alive (the app was not restarted) are
+            // returned as-is rather than opened a second time.
+            let live_session = state
+                .lock_sessions()
+                .sessions_by_id
+                .get(&entry.session_id)
+                .map(groove_terminal_session_from_state);
+
+            if let Some(session) = live_session {
+                sessions.push(session);
+                continue;
+            }
+
+            let open_mode = match validate_groove_terminal_open_mode(Some(entry.open_mode.as_str()))
+            {
+                Ok(value) => value,
+                Err(error) => {
+                    stale_session_ids.push(entry.session_id.clone());
+                    failures.push(format!("{}: {error}", entry.open_mode));
+                    continue;
+                }
+            };
+            match open_groove_terminal_session(
+                &app,
+                &state,
+                &workspace_root,
+                worktree,
+                &worktree_path,
+                open_mode,
+                entry.target.as_deref(),
+                payload.cols,
+                payload.rows,
+                false,
+                true,
+                entry.run_local,
+            ) {
+                Ok(session) => {
+                    if entry.run_local && matches!(open_mode, GrooveTerminalOpenMode::ClaudeCode) {
+                        mark_claude_session_started(&workspace_root, worktree);
+                    }
+                    stale_session_ids.push(entry.session_id.clone());
+                    sessions.push(session);
+                }
+                Err(error) => failures.push(format!("{}: {error}", entry.open_mode)),
+            }
+        }
+
+        // Reopened sessions were recorded under their new ids by the open path;
+        // entries that failed to open are kept so a later restore can retry.
+        if !stale_session_ids.is_empty() {
+            let _ = remove_worktree_session_layout_entries(
+                &app,
+                &workspace_root,
+                worktree,
+                &stale_session_ids,
+            );
+        }
+
+        WorktreeSessionLayoutRestoreResponse {
             request_id,
-            ok: false,
-            session: None,
-            error: Some(error),
-        },
-    }
+            ok: failures.is_empty(),
+            sessions,
+            error: (!failures.is_empty()).then(|| {
+                format!(
+                    "Failed to restore {} terminal session(s) for this worktree.",
+                    failures.len()
+                )
+            }),
+            failures,
+        }
+    })
 }
 
 #[tauri::command]
@@ -306,57 +897,67 @@ fn groove_terminal_write(
     state: State<GrooveTerminalState>,
     payload: GrooveTerminalWritePayload,
 ) -> GrooveTerminalResponse {
-    let request_id = request_id();
-    let worktree = payload.worktree.trim();
-    if worktree.is_empty() {
-        return GrooveTerminalResponse {
-            request_id,
-            ok: false,
-            session: None,
-            error: Some("worktree is required and must be a non-empty string.".to_string()),
-        };
-    }
-
-    let (workspace_root, _) = match resolve_terminal_worktree_context(
-        &app,
-        &payload.root_name,
-        &payload.known_worktrees,
-        &payload.workspace_meta,
-        worktree,
-    ) {
-        Ok(value) => value,
-        Err(error) => {
+    handle_command("groove_terminal_write", |context| {
+        let request_id = context.request_id();
+        let worktree = payload.worktree.trim();
+        if worktree.is_empty() {
             return GrooveTerminalResponse {
                 request_id,
                 ok: false,
                 session: None,
-                error: Some(error),
+                error: Some("worktree is required and must be a non-empty string.".to_string()),
             };
         }
-    };
 
-    let worktree_key = groove_terminal_session_key(&workspace_root, worktree);
-    let mut sessions_state = match state.inner.lock() {
-        Ok(value) => value,
-        Err(error) => {
+        let (workspace_root, _) = match resolve_terminal_worktree_context(
+            &app,
+            &payload.root_name,
+            &payload.known_worktrees,
+            &payload.workspace_meta,
+            worktree,
+        ) {
+            Ok(value) => value,
+            Err(error) => {
+                return GrooveTerminalResponse {
+                    request_id,
+                    ok: false,
+                    session: None,
+                    error: Some(error),
+                };
+            }
+        };
+
+        let worktree_key = groove_terminal_session_key(&workspace_root, worktree);
+        let mut sessions_state = state.lock_sessions();
+
+        let session_id = match resolve_terminal_session_id(
+            &sessions_state,
+            &worktree_key,
+            payload.session_id.as_deref(),
+        ) {
+            Ok(value) => value,
+            Err(error) => {
+                return GrooveTerminalResponse {
+                    request_id,
+                    ok: false,
+                    session: None,
+                    error: Some(error),
+                };
+            }
+        };
+
+        let Some(session) = sessions_state.sessions_by_id.get_mut(&session_id) else {
             return GrooveTerminalResponse {
                 request_id,
                 ok: false,
                 session: None,
-                error: Some(format!(
-                    "Failed to acquire Groove terminal state lock: {error}"
-                )),
-            }
-        }
-    };
+                error: Some(
+                    "No active Groove terminal session found for this worktree.".to_string(),
+                ),
+            };
+        };
 
-    let session_id = match resolve_terminal_session_id(
-        &sessions_state,
-        &worktree_key,
-        payload.session_id.as_deref(),
-    ) {
-        Ok(value) => value,
-        Err(error) => {
+        if let Err(error) = write_groove_terminal_session(&app, session, payload.input.as_bytes()) {
             return GrooveTerminalResponse {
                 request_id,
                 ok: false,
@@ -364,34 +965,14 @@ fn groove_terminal_write(
                 error: Some(error),
             };
         }
-    };
 
-    let Some(session) = sessions_state.sessions_by_id.get_mut(&session_id) else {
-        return GrooveTerminalResponse {
+        GrooveTerminalResponse {
             request_id,
-            ok: false,
-            session: None,
-            error: Some("No active Groove terminal session found for this worktree.".to_string()),
-        };
-    };
-
-    if let Err(error) = session.writer.write_all(payload.input.as_bytes()) {
-        return GrooveTerminalResponse {
-            request_id,
-            ok: false,
-            session: None,
-            error: Some(format!(
-                "Failed to write to Groove terminal session: {error}"
-            )),
-        };
-    }
-
-    GrooveTerminalResponse {
-        request_id,
-        ok: true,
-        session: Some(groove_terminal_session_from_state(session)),
-        error: None,
-    }
+            ok: true,
+            session: Some(groove_terminal_session_from_state(session)),
+            error: None,
+        }
+    })
 }
 
 #[tauri::command]
@@ -400,100 +981,95 @@ fn groove_terminal_resize(
     state: State<GrooveTerminalState>,
     payload: GrooveTerminalResizePayload,
 ) -> GrooveTerminalResponse {
-    let request_id = request_id();
-    let worktree = payload.worktree.trim();
-    if worktree.is_empty() {
-        return GrooveTerminalResponse {
-            request_id,
-            ok: false,
-            session: None,
-            error: Some("worktree is required and must be a non-empty string.".to_string()),
+    handle_command("groove_terminal_resize", |context| {
+        let request_id = context.request_id();
+        let worktree = payload.worktree.trim();
+        if worktree.is_empty() {
+            return GrooveTerminalResponse {
+                request_id,
+                ok: false,
+                session: None,
+                error: Some("worktree is required and must be a non-empty string.".to_string()),
+            };
+        }
+
+        let (workspace_root, _) = match resolve_terminal_worktree_context(
+            &app,
+            &payload.root_name,
+            &payload.known_worktrees,
+            &payload.workspace_meta,
+            worktree,
+        ) {
+            Ok(value) => value,
+            Err(error) => {
+                return GrooveTerminalResponse {
+                    request_id,
+                    ok: false,
+                    session: None,
+                    error: Some(error),
+                };
+            }
+        };
+
+        let worktree_key = groove_terminal_session_key(&workspace_root, worktree);
+        let mut sessions_state = state.lock_sessions();
+
+        let session_id = match resolve_terminal_session_id(
+            &sessions_state,
+            &worktree_key,
+            payload.session_id.as_deref(),
+        ) {
+            Ok(value) => value,
+            Err(error) => {
+                return GrooveTerminalResponse {
+                    request_id,
+                    ok: false,
+                    session: None,
+                    error: Some(error),
+                };
+            }
         };
-    }
 
-    let (workspace_root, _) = match resolve_terminal_worktree_context(
-        &app,
-        &payload.root_name,
-        &payload.known_worktrees,
-        &payload.workspace_meta,
-        worktree,
-    ) {
-        Ok(value) => value,
-        Err(error) => {
+        let Some(session) = sessions_state.sessions_by_id.get_mut(&session_id) else {
             return GrooveTerminalResponse {
                 request_id,
                 ok: false,
                 session: None,
-                error: Some(error),
+                error: Some(
+                    "No active Groove terminal session found for this worktree.".to_string(),
+                ),
             };
-        }
-    };
-
-    let worktree_key = groove_terminal_session_key(&workspace_root, worktree);
-    let mut sessions_state = match state.inner.lock() {
-        Ok(value) => value,
-        Err(error) => {
-            return GrooveTerminalResponse {
-                request_id,
-                ok: false,
-                session: None,
-                error: Some(format!(
-                    "Failed to acquire Groove terminal state lock: {error}"
-                )),
-            }
-        }
-    };
+        };
 
-    let session_id = match resolve_terminal_session_id(
-        &sessions_state,
-        &worktree_key,
-        payload.session_id.as_deref(),
-    ) {
-        Ok(value) => value,
-        Err(error) => {
+        let cols = normalize_terminal_dimension(Some(payload.cols), DEFAULT_GROOVE_TERMINAL_COLS);
+        let rows = normalize_terminal_dimension(Some(payload.rows), DEFAULT_GROOVE_TERMINAL_ROWS);
+        // A hibernated session only records the size it resumes at.
+        if let Err(error) = session.pty.as_ref().map_or(Ok(()), |pty| {
+            pty.master.resize(PtySize {
+                rows,
+                cols,
+                pixel_width: 0,
+                pixel_height: 0,
+            })
+        }) {
             return GrooveTerminalResponse {
                 request_id,
                 ok: false,
                 session: None,
-                error: Some(error),
+                error: Some(format!("Failed to resize Groove terminal session: {error}")),
             };
         }
-    };
-
-    let Some(session) = sessions_state.sessions_by_id.get_mut(&session_id) else {
-        return GrooveTerminalResponse {
-            request_id,
-            ok: false,
-            session: None,
-            error: Some("No active Groove terminal session found for this worktree.".to_string()),
-        };
-    };
-
-    let cols = normalize_terminal_dimension(Some(payload.cols), DEFAULT_GROOVE_TERMINAL_COLS);
-    let rows = normalize_terminal_dimension(Some(payload.rows), DEFAULT_GROOVE_TERMINAL_ROWS);
-    if let Err(error) = session.master.resize(PtySize {
-        rows,
-        cols,
-        pixel_width: 0,
-        pixel_height: 0,
-    }) {
-        return GrooveTerminalResponse {
-            request_id,
-            ok: false,
-            session: None,
-            error: Some(format!("Failed to resize Groove terminal session: {error}")),
-        };
-    }
 
-    session.cols = cols;
-    session.rows = rows;
+        session.cols = cols;
+        session.rows = rows;
 
-    GrooveTerminalResponse {
-        request_id,
-        ok: true,
-        session: Some(groove_terminal_session_from_state(session)),
-        error: None,
-    }
+        GrooveTerminalResponse {
+            request_id,
+            ok: true,
+            session: Some(groove_terminal_session_from_state(session)),
+            error: None,
+        }
+    })
 }
 
 #[tauri::command]
@@ -502,65 +1078,28 @@ fn groove_terminal_close(
     state: State<GrooveTerminalState>,
     payload: GrooveTerminalClosePayload,
 ) -> GrooveTerminalResponse {
-    let request_id = request_id();
-    let telemetry_enabled = telemetry_enabled_for_app(&app);
-    let worktree = payload.worktree.trim();
-    if worktree.is_empty() {
-        return GrooveTerminalResponse {
-            request_id,
-            ok: false,
-            session: None,
-            error: Some("worktree is required and must be a non-empty string.".to_string()),
-        };
-    }
-
-    let (workspace_root, _) = match resolve_terminal_worktree_context(
-        &app,
-        &payload.root_name,
-        &payload.known_worktrees,
-        &payload.workspace_meta,
-        worktree,
-    ) {
-        Ok(value) => value,
-        Err(error) => {
+    handle_command("groove_terminal_close", |context| {
+        let request_id = context.request_id();
+        let telemetry_enabled = telemetry_enabled_for_app(&app);
+        let worktree = payload.worktree.trim();
+        if worktree.is_empty() {
             return GrooveTerminalResponse {
                 request_id,
                 ok: false,
                 session: None,
-                error: Some(error),
+                error: Some("worktree is required and must be a non-empty string.".to_string()),
             };
         }
-    };
 
-    let worktree_key = groove_terminal_session_key(&workspace_root, worktree);
-    let mut sessions_state = match state.inner.lock() {
-        Ok(value) => value,
-        Err(error) => {
-            return GrooveTerminalResponse {
-                request_id,
-                ok: false,
-                session: None,
-                error: Some(format!(
-                    "Failed to acquire Groove terminal state lock: {error}"
-                )),
-            }
-        }
-    };
-
-    let session_id = match resolve_terminal_session_id(
-        &sessions_state,
-        &worktree_key,
-        payload.session_id.as_deref(),
-    ) {
-        Ok(value) => value,
-        Err(error) => {
-            if payload
-                .session_id
-                .as_deref()
-                .map(str::trim)
-                .filter(|candidate| !candidate.is_empty())
-                .is_some()
-            {
+        let (workspace_root, _) = match resolve_terminal_worktree_context(
+            &app,
+            &payload.root_name,
+            &payload.known_worktrees,
+            &payload.workspace_meta,
+            worktree,
+        ) {
+            Ok(value) => value,
+            Err(error) => {
                 return GrooveTerminalResponse {
                     request_id,
                     ok: false,
@@ -568,67 +1107,101 @@ fn groove_terminal_close(
                     error: Some(error),
                 };
             }
+        };
+
+        let worktree_key = groove_terminal_session_key(&workspace_root, worktree);
+        let mut sessions_state = state.lock_sessions();
+
+        let session_id = match resolve_terminal_session_id(
+            &sessions_state,
+            &worktree_key,
+            payload.session_id.as_deref(),
+        ) {
+            Ok(value) => value,
+            Err(error) => {
+                if payload
+                    .session_id
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|candidate| !candidate.is_empty())
+                    .is_some()
+                {
+                    return GrooveTerminalResponse {
+                        request_id,
+                        ok: false,
+                        session: None,
+                        error: Some(error),
+                    };
+                }
+                return GrooveTerminalResponse {
+                    request_id,
+                    ok: true,
+                    session: None,
+                    error: None,
+                };
+            }
+        };
+
+        let Some(mut session) = remove_session_by_id(&mut sessions_state, &session_id) else {
             return GrooveTerminalResponse {
                 request_id,
                 ok: true,
                 session: None,
                 error: None,
             };
-        }
-    };
+        };
+        drop(sessions_state);
+
+        let closed_session_id = session.session_id.clone();
+        let workspace_root_rendered = workspace_root.display().to_string();
+        let kill_detail = match session.pty.as_mut().map(|pty| pty.child.kill()) {
+            Some(Ok(())) => "kill=ok".to_string(),
+            Some(Err(error)) => format!("kill_error={error}"),
+            None => "kill=hibernated".to_string(),
+        };
+        let exit_detail = groove_terminal_session_exit_status(&mut session);
+        let close_detail = format!("reason=requested {kill_detail} {exit_detail}");
+        drop(session);
+        let _ = clear_running_groove_if_session_matches(
+            &app,
+            &workspace_root,
+            worktree,
+            &closed_session_id,
+        );
+        let _ = remove_worktree_session_layout_entries(
+            &app,
+            &workspace_root,
+            worktree,
+            std::slice::from_ref(&closed_session_id),
+        );
+        log_play_telemetry(
+            telemetry_enabled,
+            "terminal.session.closed",
+            format!(
+                "workspace_root={} worktree={} session_id={} {}",
+                workspace_root_rendered, worktree, closed_session_id, close_detail
+            )
+            .as_str(),
+        );
+        invalidate_worktree_caches(&app, &workspace_root, worktree);
+        emit_groove_terminal_lifecycle_event(
+            &app,
+            &closed_session_id,
+            &workspace_root_rendered,
+            worktree,
+            "closed",
+            Some(format!(
+                "Terminal session closed by request ({close_detail})."
+            )),
+        );
 
-    let Some(mut session) = remove_session_by_id(&mut sessions_state, &session_id) else {
-        return GrooveTerminalResponse {
+        GrooveTerminalResponse {
             request_id,
             ok: true,
             session: None,
             error: None,
-        };
-    };
-    drop(sessions_state);
-
-    let closed_session_id = session.session_id.clone();
-    let workspace_root_rendered = workspace_root.display().to_string();
-    let kill_detail = match session.child.kill() {
-        Ok(()) => "kill=ok".to_string(),
-        Err(error) => format!("kill_error={error}"),
-    };
-    let exit_detail = collect_groove_terminal_exit_status(session.child.as_mut());
-    let close_detail = format!("reason=requested {kill_detail} {exit_detail}");
-    drop(session);
-    let _ = clear_running_groove_if_session_matches(
-        &app,
-        &workspace_root,
-        worktree,
-        &closed_session_id,
-    );
-    log_play_telemetry(
-        telemetry_enabled,
-        "terminal.session.closed",
-        format!(
-            "workspace_root={} worktree={} session_id={} {}",
-            workspace_root_rendered, worktree, closed_session_id, close_detail
-        )
-        .as_str(),
-    );
-    invalidate_groove_list_cache_for_workspace(&app, &workspace_root);
-    emit_groove_terminal_lifecycle_event(
-        &app,
-        &closed_session_id,
-        &workspace_root_rendered,
-        worktree,
-        "closed",
-        Some(format!(
-            "Terminal session closed by request ({close_detail})."
-        )),
-    );
-
-    GrooveTerminalResponse {
-        request_id,
-        ok: true,
-        session: None,
-        error: None,
-    }
+        }
+    })
 }
 
 #[tauri::command]
@@ -637,67 +1210,322 @@ fn groove_terminal_get_session(
     state: State<GrooveTerminalState>,
     payload: GrooveTerminalSessionPayload,
 ) -> GrooveTerminalResponse {
-    let request_id = request_id();
-    let worktree = payload.worktree.trim();
-    if worktree.is_empty() {
-        return GrooveTerminalResponse {
-            request_id,
-            ok: false,
-            session: None,
-            error: Some("worktree is required and must be a non-empty string.".to_string()),
-        };
-    }
-
-    let (workspace_root, _) = match resolve_terminal_worktree_context(
-        &app,
-        &payload.root_name,
-        &payload.known_worktrees,
-        &payload.workspace_meta,
-        worktree,
-    ) {
-        Ok(value) => value,
-        Err(error) => {
+    handle_command("groove_terminal_get_session", |context| {
+        let request_id = context.request_id();
+        let worktree = payload.worktree.trim();
+        if worktree.is_empty() {
             return GrooveTerminalResponse {
                 request_id,
                 ok: false,
                 session: None,
+                error: Some("worktree is required and must be a non-empty string.".to_string()),
+            };
+        }
+
+        let (workspace_root, _) = match resolve_terminal_worktree_context(
+            &app,
+            &payload.root_name,
+            &payload.known_worktrees,
+            &payload.workspace_meta,
+            worktree,
+        ) {
+            Ok(value) => value,
+            Err(error) => {
+                return GrooveTerminalResponse {
+                    request_id,
+                    ok: false,
+                    session: None,
+                    error: Some(error),
+                };
+            }
+        };
+
+        let worktree_key = groove_terminal_session_key(&workspace_root, worktree);
+        let sessions_state = state.lock_sessions();
+
+        GrooveTerminalResponse {
+            request_id,
+            ok: true,
+            session: {
+                let session_id = resolve_terminal_session_id(
+                    &sessions_state,
+                    &worktree_key,
+                    payload.session_id.as_deref(),
+                )
+                .ok();
+                session_id
+                    .as_deref()
+                    .and_then(|value| sessions_state.sessions_by_id.get(value))
+                    .map(groove_terminal_session_with_snapshot_from_state)
+            },
+            error: None,
+        }
+    })
+}
+
+#[tauri::command]
+fn groove_terminal_fetch_scrollback(
+    state: State<GrooveTerminalState>,
+    payload: GrooveTerminalScrollbackPayload,
+) -> GrooveTerminalScrollbackResponse {
+    handle_command("groove_terminal_fetch_scrollback", |context| {
+        let response = GrooveTerminalScrollbackResponse {
+            request_id: context.request_id(),
+            session_id: payload.session_id.clone(),
+            ..GrooveTerminalScrollbackResponse::default()
+        };
+        let snapshot = match terminal_session_snapshot(&state, &payload.session_id) {
+            Ok(snapshot) => snapshot,
+            Err(error) => {
+                return GrooveTerminalScrollbackResponse {
+                    error: Some(error),
+                    ..response
+                }
+            }
+        };
+        let Ok(mut buffer) = snapshot.lock() else {
+            return GrooveTerminalScrollbackResponse {
+                error: Some("Terminal scrollback is unavailable.".to_string()),
+                ..response
+            };
+        };
+
+        let recording = buffer.scrollback.recording;
+        let snapshot_offset = buffer.trimmed_bytes;
+        match fetch_terminal_scrollback(&mut buffer, payload.offset, payload.limit) {
+            Ok((offset, bytes, start_offset)) => GrooveTerminalScrollbackResponse {
+                ok: true,
+                data: String::from_utf8_lossy(&bytes).to_string(),
+                offset,
+                start_offset,
+                snapshot_offset,
+                has_more: offset > start_offset,
+                recording,
+                ..response
+            },
+            Err(error) => GrooveTerminalScrollbackResponse {
+                snapshot_offset,
+                recording,
+                error: Some(error),
+                ..response
+            },
+        }
+    })
+}
+
+/// Resubscribes a terminal view to a session that kept running while the
+/// webview reloaded, replaying the output it missed.
+#[tauri::command]
+fn groove_terminal_attach(
+    state: State<GrooveTerminalState>,
+    payload: GrooveTerminalAttachPayload,
+) -> GrooveTerminalAttachResponse {
+    handle_command("groove_terminal_attach", |context| {
+        let response = GrooveTerminalAttachResponse {
+            request_id: context.request_id(),
+            ..GrooveTerminalAttachResponse::default()
+        };
+        let (session, snapshot) = {
+            let sessions_state = state.lock_sessions();
+            match sessions_state.sessions_by_id.get(payload.session_id.trim()) {
+                Some(session) => (
+                    groove_terminal_session_from_state(session),
+                    session.snapshot.clone(),
+                ),
+                None => {
+                    return GrooveTerminalAttachResponse {
+                        error: Some(format!(
+                            "No terminal session with id {}.",
+                            payload.session_id
+                        )),
+                        ..response
+                    }
+                }
+            }
+        };
+        let Ok(mut buffer) = snapshot.lock() else {
+            return GrooveTerminalAttachResponse {
+                error: Some("Terminal output is unavailable.".to_string()),
+                ..response
+            };
+        };
+
+        match terminal_attach_replay(&mut buffer, payload.offset) {
+            Ok((offset, bytes)) => GrooveTerminalAttachResponse {
+                ok: true,
+                session: Some(session),
+                data: String::from_utf8_lossy(&bytes).to_string(),
+                offset,
+                end_offset: buffer.end_offset(),
+                truncated: payload.offset.is_some_and(|requested| offset > requested),
+                ..response
+            },
+            Err(error) => GrooveTerminalAttachResponse {
                 error: Some(error),
+                ..response
+            },
+        }
+    })
+}
+
+/// A recorded session keeps its scrollback file after it closes.
+#[tauri::command]
+fn groove_terminal_set_scrollback_recording(
+    state: State<GrooveTerminalState>,
+    payload: GrooveTerminalScrollbackRecordingPayload,
+) -> GrooveTerminalScrollbackResponse {
+    handle_command("groove_terminal_set_scrollback_recording", |context| {
+        let response = GrooveTerminalScrollbackResponse {
+            request_id: context.request_id(),
+            session_id: payload.session_id.clone(),
+            ..GrooveTerminalScrollbackResponse::default()
+        };
+        let snapshot = match terminal_session_snapshot(&state, &payload.session_id) {
+            Ok(snapshot) => snapshot,
+            Err(error) => {
+                return GrooveTerminalScrollbackResponse {
+                    error: Some(error),
+                    ..response
+                }
+            }
+        };
+        let Ok(mut buffer) = snapshot.lock() else {
+            return GrooveTerminalScrollbackResponse {
+                error: Some("Terminal scrollback is unavailable.".to_string()),
+                ..response
             };
+        };
+
+        let result = buffer.scrollback.set_recording(payload.recording);
+        let start_offset = buffer
+            .scrollback
+            .file
+            .as_ref()
+            .map_or(buffer.trimmed_bytes, |file| file.start_offset);
+        GrooveTerminalScrollbackResponse {
+            ok: result.is_ok(),
+            offset: buffer.trimmed_bytes,
+            start_offset,
+            snapshot_offset: buffer.trimmed_bytes,
+            has_more: start_offset < buffer.trimmed_bytes,
+            recording: buffer.scrollback.recording,
+            error: result.err(),
+            ..response
         }
-    };
+    })
+}
 
-    let worktree_key = groove_terminal_session_key(&workspace_root, worktree);
-    let sessions_state = match state.inner.lock() {
-        Ok(value) => value,
-        Err(error) => {
-            return GrooveTerminalResponse {
-                request_id,
-                ok: false,
-                session: None,
-                error: Some(format!(
-                    "Failed to acquire Groove terminal state lock: {error}"
-                )),
+/// Output persisted under `.groove/terminal-history`, for restoring a terminal
+/// view after a restart.
+#[tauri::command]
+fn groove_terminal_history(
+    app: AppHandle,
+    payload: GrooveTerminalHistoryPayload,
+) -> GrooveTerminalHistoryResponse {
+    handle_command("groove_terminal_history", |context| {
+        let response = GrooveTerminalHistoryResponse {
+            request_id: context.request_id(),
+            ..GrooveTerminalHistoryResponse::default()
+        };
+        let worktree = payload.worktree.trim();
+        if worktree.is_empty() {
+            return GrooveTerminalHistoryResponse {
+                error: Some("worktree is required and must be a non-empty string.".to_string()),
+                ..response
+            };
+        }
+
+        let history = resolve_terminal_worktree_context(
+            &app,
+            &payload.root_name,
+            &payload.known_worktrees,
+            &payload.workspace_meta,
+            worktree,
+        )
+        .and_then(|(workspace_root, _)| {
+            terminal_history_dir(&workspace_root, worktree)
+                .ok_or_else(|| format!("Invalid worktree name {worktree}."))
+        })
+        .and_then(|dir| {
+            read_terminal_history(&dir, payload.session_id.as_deref(), payload.max_bytes)
+        });
+        match history {
+            Ok(Some((session_id, bytes, truncated))) => GrooveTerminalHistoryResponse {
+                ok: true,
+                session_id: Some(session_id),
+                data: String::from_utf8_lossy(&bytes).to_string(),
+                truncated,
+                ..response
+            },
+            Ok(None) => GrooveTerminalHistoryResponse {
+                ok: true,
+                ..response
+            },
+            Err(error) => GrooveTerminalHistoryResponse {
+                error: Some(error),
+                ..response
+            },
+        }
+    })
+}
+
+/// Terminal output kept on disk for the active workspace, and scrollback
+/// files of all workspaces, as the storage janitor accounts for them.
+#[tauri::command]
+fn terminal_storage_stats(
+    app: AppHandle,
+    state: State<GrooveTerminalState>,
+) -> TerminalStorageStatsResponse {
+    handle_command("terminal_storage_stats", |context| {
+        let live = live_terminal_storage_paths(&state);
+        let scrollback = terminal_scrollback_dir(&app)
+            .map(|dir| read_terminal_storage_dir(&dir, false))
+            .unwrap_or_default();
+        let mut response = TerminalStorageStatsResponse {
+            request_id: context.request_id(),
+            quota_bytes: TERMINAL_STORAGE_QUOTA_BYTES.load(Ordering::Relaxed),
+            max_age_days: TERMINAL_STORAGE_MAX_AGE_DAYS.load(Ordering::Relaxed),
+            scrollback_bytes: scrollback.iter().map(|file| file.bytes).sum(),
+            scrollback: scrollback
+                .iter()
+                .map(|file| terminal_storage_usage(file, &live))
+                .collect(),
+            ..TerminalStorageStatsResponse::default()
+        };
+
+        let workspace_root = match active_workspace_root_from_state(&app) {
+            Ok(root) => root,
+            Err(error) => {
+                return TerminalStorageStatsResponse {
+                    error: Some(error),
+                    ..response
+                }
             }
+        };
+        for (worktree, files) in terminal_history_storage(&workspace_root) {
+            let bytes = files.iter().map(|file| file.bytes).sum::<u64>();
+            let mut sessions = files
+                .iter()
+                .map(|file| terminal_storage_usage(file, &live))
+                .collect::<Vec<_>>();
+            sessions.sort_by_key(|session| std::cmp::Reverse(session.bytes));
+            response.history_bytes += bytes;
+            response.worktrees.push(TerminalStorageWorktreeUsage {
+                worktree,
+                bytes,
+                sessions,
+            });
         }
-    };
+        response
+            .worktrees
+            .sort_by_key(|worktree| std::cmp::Reverse(worktree.bytes));
 
-    GrooveTerminalResponse {
-        request_id,
-        ok: true,
-        session: {
-            let session_id = resolve_terminal_session_id(
-                &sessions_state,
-                &worktree_key,
-                payload.session_id.as_deref(),
-            )
-            .ok();
-            session_id
-                .as_deref()
-                .and_then(|value| sessions_state.sessions_by_id.get(value))
-                .map(groove_terminal_session_with_snapshot_from_state)
-        },
-        error: None,
-    }
+        TerminalStorageStatsResponse {
+            ok: true,
+            workspace_root: Some(workspace_root.display().to_string()),
+            ..response
+        }
+    })
 }
 
 #[tauri::command]
@@ -706,56 +1534,46 @@ fn groove_terminal_list_sessions(
     state: State<GrooveTerminalState>,
     payload: GrooveTerminalSessionPayload,
 ) -> GrooveTerminalSessionsResponse {
-    let request_id = request_id();
-    let worktree = payload.worktree.trim();
-    if worktree.is_empty() {
-        return GrooveTerminalSessionsResponse {
-            request_id,
-            ok: false,
-            sessions: Vec::new(),
-            error: Some("worktree is required and must be a non-empty string.".to_string()),
-        };
-    }
-
-    let (workspace_root, _) = match resolve_terminal_worktree_context(
-        &app,
-        &payload.root_name,
-        &payload.known_worktrees,
-        &payload.workspace_meta,
-        worktree,
-    ) {
-        Ok(value) => value,
-        Err(error) => {
+    handle_command("groove_terminal_list_sessions", |context| {
+        let request_id = context.request_id();
+        let worktree = payload.worktree.trim();
+        if worktree.is_empty() {
             return GrooveTerminalSessionsResponse {
                 request_id,
                 ok: false,
                 sessions: Vec::new(),
-                error: Some(error),
+                error: Some("worktree is required and must be a non-empty string.".to_string()),
             };
         }
-    };
 
-    let worktree_key = groove_terminal_session_key(&workspace_root, worktree);
-    let sessions_state = match state.inner.lock() {
-        Ok(value) => value,
-        Err(error) => {
-            return GrooveTerminalSessionsResponse {
-                request_id,
-                ok: false,
-                sessions: Vec::new(),
-                error: Some(format!(
-                    "Failed to acquire Groove terminal state lock: {error}"
-                )),
+        let (workspace_root, _) = match resolve_terminal_worktree_context(
+            &app,
+            &payload.root_name,
+            &payload.known_worktrees,
+            &payload.workspace_meta,
+            worktree,
+        ) {
+            Ok(value) => value,
+            Err(error) => {
+                return GrooveTerminalSessionsResponse {
+                    request_id,
+                    ok: false,
+                    sessions: Vec::new(),
+                    error: Some(error),
+                };
             }
-        }
-    };
+        };
 
-    GrooveTerminalSessionsResponse {
-        request_id,
-        ok: true,
-        sessions: sessions_for_worktree(&sessions_state, &worktree_key),
-        error: None,
-    }
+        let worktree_key = groove_terminal_session_key(&workspace_root, worktree);
+        let sessions_state = state.lock_sessions();
+
+        GrooveTerminalSessionsResponse {
+            request_id,
+            ok: true,
+            sessions: sessions_for_worktree(&sessions_state, &worktree_key),
+            error: None,
+        }
+    })
 }
 
 #[tauri::command]
@@ -764,86 +1582,79 @@ fn groove_terminal_check_activity(
     state: State<GrooveTerminalState>,
     payload: GrooveTerminalSessionPayload,
 ) -> GrooveTerminalActivityResponse {
-    let request_id = request_id();
-    let worktree = payload.worktree.trim();
-    if worktree.is_empty() {
-        return GrooveTerminalActivityResponse {
-            request_id,
-            ok: false,
-            entries: Vec::new(),
-            error: Some("worktree is required and must be a non-empty string.".to_string()),
-        };
-    }
-
-    let (workspace_root, _) = match resolve_terminal_worktree_context(
-        &app,
-        &payload.root_name,
-        &payload.known_worktrees,
-        &payload.workspace_meta,
-        worktree,
-    ) {
-        Ok(value) => value,
-        Err(error) => {
+    handle_command("groove_terminal_check_activity", |context| {
+        let request_id = context.request_id();
+        let worktree = payload.worktree.trim();
+        if worktree.is_empty() {
             return GrooveTerminalActivityResponse {
                 request_id,
                 ok: false,
                 entries: Vec::new(),
-                error: Some(error),
+                error: Some("worktree is required and must be a non-empty string.".to_string()),
             };
         }
-    };
 
-    let worktree_key = groove_terminal_session_key(&workspace_root, worktree);
-
-    let session_pids: Vec<(String, Option<u32>)> = {
-        let sessions_state = match state.inner.lock() {
+        let (workspace_root, _) = match resolve_terminal_worktree_context(
+            &app,
+            &payload.root_name,
+            &payload.known_worktrees,
+            &payload.workspace_meta,
+            worktree,
+        ) {
             Ok(value) => value,
             Err(error) => {
                 return GrooveTerminalActivityResponse {
                     request_id,
                     ok: false,
                     entries: Vec::new(),
-                    error: Some(format!(
-                        "Failed to acquire Groove terminal state lock: {error}"
-                    )),
+                    error: Some(error),
                 };
             }
         };
 
-        let session_ids = sessions_state
-            .session_ids_by_worktree
-            .get(&worktree_key)
-            .cloned()
-            .unwrap_or_default();
+        let worktree_key = groove_terminal_session_key(&workspace_root, worktree);
 
-        session_ids
-            .iter()
-            .filter_map(|session_id| {
-                sessions_state
-                    .sessions_by_id
-                    .get(session_id)
-                    .map(|session| (session_id.clone(), session.child.process_id()))
-            })
-            .collect()
-    };
+        let session_pids: Vec<(String, Option<u32>)> = {
+            let sessions_state = state.lock_sessions();
 
-    let entries = session_pids
-        .into_iter()
-        .map(|(session_id, pid)| {
-            let has_activity = pid.map(has_child_processes).unwrap_or(false);
-            GrooveTerminalActivityEntry {
-                session_id,
-                has_activity,
-            }
-        })
-        .collect();
+            let session_ids = sessions_state
+                .session_ids_by_worktree
+                .get(&worktree_key)
+                .cloned()
+                .unwrap_or_default();
 
-    GrooveTerminalActivityResponse {
-        request_id,
-        ok: true,
-        entries,
-        error: None,
-    }
+            session_ids
+                .iter()
+                .filter_map(|session_id| {
+                    sessions_state
+                        .sessions_by_id
+                        .get(session_id)
+                        .map(|session| {
+                            let pid = session.pty.as_ref().and_then(|pty| pty.child.process_id());
+                            (session_id.clone(), pid)
+                        })
+                })
+                .collect()
+        };
+
+        let entries = session_pids
+            .into_iter()
+            .map(|(session_id, pid)| {
+                let has_activity = pid.map(has_child_processes).unwrap_or(false);
+                GrooveTerminalActivityEntry {
+                    session_id,
+                    has_activity,
+                }
+            })
+            .collect();
+
+        GrooveTerminalActivityResponse {
+            request_id,
+            ok: true,
+            entries,
+            error: None,
+        }
+    })
 }
 
 #[tauri::command]
@@ -852,56 +1663,46 @@ fn groove_terminal_active_worktrees(
     state: State<GrooveTerminalState>,
     payload: WorkspaceEventsPayload,
 ) -> GrooveTerminalActiveWorktreesResponse {
-    let request_id = request_id();
+    handle_command("groove_terminal_active_worktrees", |context| {
+        let request_id = context.request_id();
 
-    let known_worktrees = match validate_known_worktrees(&payload.known_worktrees) {
-        Ok(value) => value,
-        Err(error) => {
-            return GrooveTerminalActiveWorktreesResponse {
-                request_id,
-                ok: false,
-                worktrees: Vec::new(),
-                error: Some(error),
-            };
-        }
-    };
-
-    let workspace_root = match resolve_workspace_root(
-        &app,
-        &payload.root_name,
-        None,
-        &known_worktrees,
-        &payload.workspace_meta,
-    ) {
-        Ok(root) => root,
-        Err(error) => {
-            return GrooveTerminalActiveWorktreesResponse {
-                request_id,
-                ok: false,
-                worktrees: Vec::new(),
-                error: Some(error),
-            };
-        }
-    };
+        let known_worktrees = match validate_known_worktrees(&payload.known_worktrees) {
+            Ok(value) => value,
+            Err(error) => {
+                return GrooveTerminalActiveWorktreesResponse {
+                    request_id,
+                    ok: false,
+                    worktrees: Vec::new(),
+                    error: Some(error),
+                };
+            }
+        };
 
-    let sessions_state = match state.inner.lock() {
-        Ok(value) => value,
-        Err(error) => {
-            return GrooveTerminalActiveWorktreesResponse {
-                request_id,
-                ok: false,
-                worktrees: Vec::new(),
-                error: Some(format!(
-                    "Failed to acquire Groove terminal state lock: {error}"
-                )),
-            };
-        }
-    };
+        let workspace_root = match resolve_workspace_root(
+            &app,
+            &payload.root_name,
+            None,
+            &known_worktrees,
+            &payload.workspace_meta,
+        ) {
+            Ok(root) => root,
+            Err(error) => {
+                return GrooveTerminalActiveWorktreesResponse {
+                    request_id,
+                    ok: false,
+                    worktrees: Vec::new(),
+                    error: Some(error),
+                };
+            }
+        };
 
-    GrooveTerminalActiveWorktreesResponse {
-        request_id,
-        ok: true,
-        worktrees: active_worktrees_for_workspace(&sessions_state, &workspace_root),
-        error: None,
-    }
+        let sessions_state = state.lock_sessions();
+
+        GrooveTerminalActiveWorktreesResponse {
+            request_id,
+            ok: true,
+            worktrees: active_worktrees_for_workspace(&sessions_state, &workspace_root),
+            error: None,
+        }
+    })
 }
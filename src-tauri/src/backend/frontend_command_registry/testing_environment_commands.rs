@@ -0,0 +1,112 @@
+#[tauri::command]
+fn testing_environment_status() -> TestingEnvironmentStatusResponse {
+    handle_command("testing_environment_status", |context| {
+        TestingEnvironmentStatusResponse {
+            request_id: context.request_id(),
+            ok: true,
+            environments: testing_environment_infos(),
+            error: None,
+        }
+    })
+}
+
+fn testing_environment_logs_error_response(
+    request_id: String,
+    error: String,
+) -> TestingEnvironmentLogsResponse {
+    TestingEnvironmentLogsResponse {
+        request_id,
+        ok: false,
+        workspace_root: None,
+        worktree: None,
+        lines: Vec::new(),
+        following: false,
+        error: Some(error),
+    }
+}
+
+/// The active workspace root and the validated worktree name.
+fn testing_environment_logs_target(
+    app: &AppHandle,
+    worktree: &str,
+) -> Result<(PathBuf, String), String> {
+    let worktree = worktree.trim();
+    if !is_safe_path_token(worktree) {
+        return Err("worktree contains unsafe characters or path segments.".to_string());
+    }
+    Ok((active_workspace_root_from_state(app)?, worktree.to_string()))
+}
+
+#[tauri::command]
+async fn testing_environment_logs_tail(
+    app: AppHandle,
+    payload: TestingEnvironmentLogsTailPayload,
+) -> TestingEnvironmentLogsResponse {
+    handle_blocking_command(
+        "testing_environment_logs_tail",
+        move |context| {
+            let (workspace_root, worktree) =
+                match testing_environment_logs_target(&app, &payload.worktree) {
+                    Ok(target) => target,
+                    Err(error) => {
+                        return testing_environment_logs_error_response(context.request_id(), error)
+                    }
+                };
+            if let Some(follow) = payload.follow {
+                set_testing_log_follow(&workspace_root, &worktree, follow);
+            }
+            let lines = payload
+                .lines
+                .unwrap_or(DEFAULT_TESTING_LOG_TAIL_LINES)
+                .clamp(1, MAX_TESTING_LOG_TAIL_LINES);
+            let key = testing_environment_key(&workspace_root.display().to_string(), &worktree);
+            TestingEnvironmentLogsResponse {
+                request_id: context.request_id(),
+                ok: true,
+                lines: tail_testing_log(&testing_logs_dir(&workspace_root, &worktree), lines),
+                following: followed_testing_logs().contains(&key),
+                workspace_root: Some(workspace_root.display().to_string()),
+                worktree: Some(worktree),
+                error: None,
+            }
+        },
+        testing_environment_logs_error_response,
+    )
+    .await
+}
+
+#[tauri::command]
+async fn testing_environment_logs_clear(
+    app: AppHandle,
+    payload: TestingEnvironmentLogsClearPayload,
+) -> TestingEnvironmentLogsResponse {
+    handle_blocking_command(
+        "testing_environment_logs_clear",
+        move |context| {
+            let result = testing_environment_logs_target(&app, &payload.worktree).and_then(
+                |(workspace_root, worktree)| {
+                    clear_testing_log(&workspace_root, &worktree)?;
+                    Ok((workspace_root, worktree))
+                },
+            );
+            match result {
+                Ok((workspace_root, worktree)) => {
+                    let key =
+                        testing_environment_key(&workspace_root.display().to_string(), &worktree);
+                    TestingEnvironmentLogsResponse {
+                        request_id: context.request_id(),
+                        ok: true,
+                        lines: Vec::new(),
+                        following: followed_testing_logs().contains(&key),
+                        workspace_root: Some(workspace_root.display().to_string()),
+                        worktree: Some(worktree),
+                        error: None,
+                    }
+                }
+                Err(error) => testing_environment_logs_error_response(context.request_id(), error),
+            }
+        },
+        testing_environment_logs_error_response,
+    )
+    .await
+}
@@ -0,0 +1,80 @@
+#[tauri::command]
+fn undo_list(app: AppHandle) -> UndoListResponse {
+    handle_command("undo_list", |context| {
+        let entries = app
+            .try_state::<UndoState>()
+            .map(|state| {
+                state
+                    .lock_entries()
+                    .iter()
+                    .rev()
+                    .map(|record| record.entry.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        UndoListResponse {
+            request_id: context.request_id(),
+            ok: true,
+            entries,
+            error: None,
+        }
+    })
+}
+
+#[tauri::command]
+fn undo_last(app: AppHandle) -> UndoLastResponse {
+    handle_command("undo_last", |context| {
+        let request_id = context.request_id();
+        let Some(state) = app.try_state::<UndoState>() else {
+            return UndoLastResponse {
+                request_id,
+                ok: false,
+                undone: None,
+                remaining: 0,
+                workspace_meta: None,
+                global_settings: None,
+                error: Some("Undo is not available.".to_string()),
+            };
+        };
+        // Popped before applying: an entry that cannot be applied (its
+        // worktree was removed, say) must not block the ones below it.
+        let (record, remaining) = {
+            let mut entries = state.lock_entries();
+            let record = entries.pop();
+            (record, entries.len())
+        };
+        let Some(record) = record else {
+            return UndoLastResponse {
+                request_id,
+                ok: false,
+                undone: None,
+                remaining,
+                workspace_meta: None,
+                global_settings: None,
+                error: Some("Nothing to undo.".to_string()),
+            };
+        };
+
+        match apply_undo(&app, &record) {
+            Ok((workspace_meta, global_settings)) => UndoLastResponse {
+                request_id,
+                ok: true,
+                undone: Some(record.entry),
+                remaining,
+                workspace_meta,
+                global_settings,
+                error: None,
+            },
+            Err(error) => UndoLastResponse {
+                request_id,
+                ok: false,
+                undone: Some(record.entry),
+                remaining,
+                workspace_meta: None,
+                global_settings: None,
+                error: Some(error),
+            },
+        }
+    })
+}
@@ -1,39 +1,63 @@
 #[tauri::command]
 fn workspace_pick_and_open(app: AppHandle) -> WorkspaceContextResponse {
-    let request_id = request_id();
-    let previous_workspace_root = read_persisted_active_workspace_root(&app).ok().flatten();
-    let picked = rfd::FileDialog::new().pick_folder();
-    let Some(selected) = picked else {
-        return WorkspaceContextResponse {
-            request_id,
-            ok: false,
-            workspace_root: None,
-            repository_remote_url: None,
-            workspace_meta: None,
-            workspace_message: None,
-            has_worktrees_directory: None,
-            rows: Vec::new(),
-            cancelled: Some(true),
-            error: None,
+    handle_command("workspace_pick_and_open", |context| {
+        let request_id = context.request_id();
+        let previous_workspace_root = read_persisted_active_workspace_root(&app).ok().flatten();
+        let picked = rfd::FileDialog::new().pick_folder();
+        let Some(selected) = picked else {
+            return WorkspaceContextResponse {
+                request_id,
+                ok: false,
+                workspace_root: None,
+                repository_remote_url: None,
+                workspace_meta: None,
+                workspace_message: None,
+                has_worktrees_directory: None,
+                rows: Vec::new(),
+                cancelled: Some(true),
+                partial: None,
+                read_only: None,
+                read_only_reason: None,
+                trust: None,
+                page: None,
+                error: None,
+            };
         };
-    };
 
-    let response = build_workspace_context(&app, &selected, request_id.clone(), true);
-    if response.ok {
-        let next_workspace_root = response.workspace_root.as_deref();
-        if previous_workspace_root.as_deref() != next_workspace_root {
-            emit_workspace_ready_event(&app, &request_id, next_workspace_root, "connection");
+        let response = build_workspace_context(&app, &selected, request_id.clone(), true);
+        if response.ok {
+            let next_workspace_root = response.workspace_root.as_deref();
+            if previous_workspace_root.as_deref() != next_workspace_root {
+                emit_workspace_ready_event(&app, &request_id, next_workspace_root, "connection");
+            }
         }
-    }
 
-    response
+        response
+    })
 }
 
 #[tauri::command]
-fn workspace_open(app: AppHandle, workspace_root: String) -> WorkspaceContextResponse {
-    let request_id = request_id();
-    let previous_workspace_root = read_persisted_active_workspace_root(&app).ok().flatten();
-    let root = match validate_workspace_root_path(&workspace_root) {
+fn workspace_open(
+    app: AppHandle,
+    workspace_root: String,
+    page: Option<RowsPagePayload>,
+) -> WorkspaceContextResponse {
+    handle_command("workspace_open", |context| {
+        let mut response = open_workspace(&app, context.request_id(), &workspace_root);
+        paginate_workspace_context(&mut response, page);
+        response
+    })
+}
+
+/// Opens `workspace_root` and makes it the active workspace. Other open
+/// workspaces stay open.
+fn open_workspace(
+    app: &AppHandle,
+    request_id: String,
+    workspace_root: &str,
+) -> WorkspaceContextResponse {
+    let previous_workspace_root = read_persisted_active_workspace_root(app).ok().flatten();
+    let root = match validate_workspace_root_path(workspace_root) {
         Ok(root) => root,
         Err(error) => {
             return WorkspaceContextResponse {
@@ -46,14 +70,19 @@ fn workspace_open(app: AppHandle, workspace_root: String) -> WorkspaceContextRes
                 has_worktrees_directory: None,
                 rows: Vec::new(),
                 cancelled: None,
+                partial: None,
+                read_only: None,
+                read_only_reason: None,
+                trust: None,
+                page: None,
                 error: Some(error),
             }
         }
     };
 
-    if let Some(cached) = try_cached_workspace_context(&app, &root, &request_id) {
+    if let Some(cached) = try_cached_workspace_context(app, &root, &request_id) {
         if previous_workspace_root.as_deref() != cached.workspace_root.as_deref() {
-            if let Err(error) = persist_active_workspace_root(&app, &root) {
+            if let Err(error) = persist_active_workspace_root(app, &root) {
                 return WorkspaceContextResponse {
                     request_id,
                     ok: false,
@@ -64,11 +93,16 @@ fn workspace_open(app: AppHandle, workspace_root: String) -> WorkspaceContextRes
                     has_worktrees_directory: cached.has_worktrees_directory,
                     rows: cached.rows,
                     cancelled: None,
+                    partial: None,
+                    read_only: cached.read_only,
+                    read_only_reason: cached.read_only_reason,
+                    trust: cached.trust,
+                    page: None,
                     error: Some(error),
                 };
             }
             emit_workspace_ready_event(
-                &app,
+                app,
                 &request_id,
                 cached.workspace_root.as_deref(),
                 "connection",
@@ -77,11 +111,11 @@ fn workspace_open(app: AppHandle, workspace_root: String) -> WorkspaceContextRes
         return cached;
     }
 
-    let response = build_workspace_context(&app, &root, request_id.clone(), true);
+    let response = build_workspace_context(app, &root, request_id.clone(), true);
     if response.ok {
         let next_workspace_root = response.workspace_root.as_deref();
         if previous_workspace_root.as_deref() != next_workspace_root {
-            emit_workspace_ready_event(&app, &request_id, next_workspace_root, "connection");
+            emit_workspace_ready_event(app, &request_id, next_workspace_root, "connection");
         }
     }
 
@@ -89,16 +123,76 @@ fn workspace_open(app: AppHandle, workspace_root: String) -> WorkspaceContextRes
 }
 
 #[tauri::command]
-fn workspace_get_active(app: AppHandle) -> WorkspaceContextResponse {
-    let started_at = Instant::now();
-    let request_id = request_id();
-    let mut telemetry_enabled = true;
-    let persisted_root = match read_persisted_active_workspace_root(&app) {
-        Ok(root) => root,
-        Err(error) => {
-            let response = WorkspaceContextResponse {
+fn workspace_get_active(app: AppHandle, page: Option<RowsPagePayload>) -> WorkspaceContextResponse {
+    handle_command("workspace_get_active", |context| {
+        let started_at = Instant::now();
+        let request_id = context.request_id();
+        let mut telemetry_enabled = true;
+        let persisted_root = match read_persisted_active_workspace_root(&app) {
+            Ok(root) => root,
+            Err(error) => {
+                let response = WorkspaceContextResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    repository_remote_url: None,
+                    workspace_meta: None,
+                    workspace_message: None,
+                    has_worktrees_directory: None,
+                    rows: Vec::new(),
+                    cancelled: None,
+                    partial: None,
+                    read_only: None,
+                    read_only_reason: None,
+                    trust: None,
+                    page: None,
+                    error: Some(error),
+                };
+                log_backend_timing(
+                    telemetry_enabled,
+                    "workspace_get_active",
+                    started_at.elapsed(),
+                    "outcome=read-state-error",
+                );
+                return response;
+            }
+        };
+
+        let mut response = if let Some(persisted_root) = persisted_root {
+            match validate_workspace_root_path(&persisted_root) {
+                Ok(root) => {
+                    telemetry_enabled = telemetry_enabled_for_app(&app);
+                    if let Some(cached) = try_cached_workspace_context(&app, &root, &request_id) {
+                        cached
+                    } else {
+                        partial_workspace_context(&app, &root, request_id)
+                    }
+                }
+                Err(error) => {
+                    let _ = clear_persisted_active_workspace_root(&app);
+                    WorkspaceContextResponse {
+                        request_id,
+                        ok: false,
+                        workspace_root: Some(persisted_root),
+                        repository_remote_url: None,
+                        workspace_meta: None,
+                        workspace_message: None,
+                        has_worktrees_directory: None,
+                        rows: Vec::new(),
+                        cancelled: None,
+                        partial: None,
+                        read_only: None,
+                        read_only_reason: None,
+                        trust: None,
+                        page: None,
+                        error: Some(error),
+                    }
+                }
+            }
+        } else {
+            WorkspaceContextResponse {
                 request_id,
-                ok: false,
+                ok: true,
                 workspace_root: None,
                 repository_remote_url: None,
                 workspace_meta: None,
@@ -106,184 +200,323 @@ fn workspace_get_active(app: AppHandle) -> WorkspaceContextResponse {
                 has_worktrees_directory: None,
                 rows: Vec::new(),
                 cancelled: None,
-                error: Some(error),
-            };
-            log_backend_timing(
-                telemetry_enabled,
-                "workspace_get_active",
-                started_at.elapsed(),
-                "outcome=read-state-error",
+                partial: None,
+                read_only: None,
+                read_only_reason: None,
+                trust: None,
+                page: None,
+                error: None,
+            }
+        };
+        paginate_workspace_context(&mut response, page);
+
+        log_backend_timing(
+            telemetry_enabled,
+            "workspace_get_active",
+            started_at.elapsed(),
+            if !response.ok {
+                "outcome=error"
+            } else if response.partial == Some(true) {
+                "outcome=partial"
+            } else {
+                "outcome=ok"
+            },
+        );
+        response
+    })
+}
+
+#[tauri::command]
+fn workspace_clear_active(
+    app: AppHandle,
+    terminal_state: State<GrooveTerminalState>,
+) -> WorkspaceContextResponse {
+    handle_command("workspace_clear_active", |context| {
+        let request_id = context.request_id();
+        let persisted_workspace_root = read_persisted_active_workspace_root(&app).ok().flatten();
+        let had_active_workspace = persisted_workspace_root.is_some();
+
+        if let Some(workspace_root) = persisted_workspace_root.as_deref() {
+            let workspace_root_key = workspace_root_storage_key(Path::new(workspace_root));
+            let sessions_to_close = drain_groove_terminal_sessions(
+                &mut terminal_state.lock_sessions(),
+                Some(workspace_root_key.as_str()),
             );
-            return response;
+            close_groove_terminal_sessions_best_effort(sessions_to_close);
         }
-    };
 
-    let response = if let Some(persisted_root) = persisted_root {
-        match validate_workspace_root_path(&persisted_root) {
-            Ok(root) => {
-                telemetry_enabled = telemetry_enabled_for_app(&app);
-                if let Some(cached) = try_cached_workspace_context(&app, &root, &request_id) {
-                    cached
-                } else {
-                    build_workspace_context(&app, &root, request_id, false)
+        match clear_persisted_active_workspace_root(&app) {
+            Ok(_) => {
+                if let Some(workspace_root) = persisted_workspace_root.as_deref() {
+                    stop_workspace_event_worker(&app, Path::new(workspace_root));
+                    invalidate_workspace_caches(&app, Path::new(workspace_root));
                 }
-            }
-            Err(error) => {
-                let _ = clear_persisted_active_workspace_root(&app);
+                if had_active_workspace {
+                    emit_workspace_ready_event(&app, &request_id, None, "connection");
+                }
+
                 WorkspaceContextResponse {
                     request_id,
-                    ok: false,
-                    workspace_root: Some(persisted_root),
+                    ok: true,
+                    workspace_root: None,
                     repository_remote_url: None,
                     workspace_meta: None,
                     workspace_message: None,
                     has_worktrees_directory: None,
                     rows: Vec::new(),
                     cancelled: None,
-                    error: Some(error),
+                    partial: None,
+                    read_only: None,
+                    read_only_reason: None,
+                    trust: None,
+                    page: None,
+                    error: None,
                 }
             }
+            Err(error) => WorkspaceContextResponse {
+                request_id,
+                ok: false,
+                workspace_root: None,
+                repository_remote_url: None,
+                workspace_meta: None,
+                workspace_message: None,
+                has_worktrees_directory: None,
+                rows: Vec::new(),
+                cancelled: None,
+                partial: None,
+                read_only: None,
+                read_only_reason: None,
+                trust: None,
+                page: None,
+                error: Some(error),
+            },
         }
-    } else {
-        WorkspaceContextResponse {
-            request_id,
-            ok: true,
-            workspace_root: None,
-            repository_remote_url: None,
-            workspace_meta: None,
-            workspace_message: None,
-            has_worktrees_directory: None,
-            rows: Vec::new(),
-            cancelled: None,
-            error: None,
+    })
+}
+
+fn workspace_list_open_response(app: &AppHandle, request_id: String) -> WorkspaceListOpenResponse {
+    let state = match read_persisted_workspace_state(app) {
+        Ok(state) => state,
+        Err(error) => {
+            return WorkspaceListOpenResponse {
+                request_id,
+                ok: false,
+                active_workspace_root: None,
+                workspaces: Vec::new(),
+                error: Some(error),
+            }
         }
     };
+    let watched = app
+        .try_state::<WorkspaceEventState>()
+        .and_then(|event_state| {
+            let workers = event_state.workers.lock().ok()?;
+            Some(
+                workers
+                    .iter()
+                    .filter(|(_, worker)| !worker.handle.is_finished())
+                    .map(|(key, _)| key.clone())
+                    .collect::<HashSet<_>>(),
+            )
+        })
+        .unwrap_or_default();
+    let active_key = state
+        .workspace_root
+        .as_deref()
+        .map(|active| workspace_root_storage_key(Path::new(active)));
+
+    let mut workspaces = state
+        .open_workspaces
+        .into_iter()
+        .map(|record| {
+            let root = Path::new(&record.workspace_root);
+            let key = workspace_root_storage_key(root);
+            OpenWorkspaceSummary {
+                root_name: root
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_else(|| record.workspace_root.clone()),
+                active: active_key.as_deref() == Some(key.as_str()),
+                watching: watched.contains(&key),
+                available: validate_workspace_root_path(&record.workspace_root).is_ok(),
+                workspace_root: record.workspace_root,
+                opened_at: record.opened_at,
+                last_active_at: record.last_active_at,
+            }
+        })
+        .collect::<Vec<_>>();
+    workspaces.sort_by(|left, right| right.last_active_at.cmp(&left.last_active_at));
 
-    log_backend_timing(
-        telemetry_enabled,
-        "workspace_get_active",
-        started_at.elapsed(),
-        if response.ok {
-            "outcome=ok"
-        } else {
-            "outcome=error"
-        },
-    );
-    response
+    WorkspaceListOpenResponse {
+        request_id,
+        ok: true,
+        active_workspace_root: state.workspace_root,
+        workspaces,
+        error: None,
+    }
 }
 
 #[tauri::command]
-fn workspace_clear_active(
+fn workspace_list_open(app: AppHandle) -> WorkspaceListOpenResponse {
+    handle_command("workspace_list_open", |context| {
+        workspace_list_open_response(&app, context.request_id())
+    })
+}
+
+/// Makes an already open workspace the active one. Its cached context and
+/// events worker are reused, so nothing is torn down or rebuilt.
+#[tauri::command]
+fn workspace_switch(
     app: AppHandle,
-    terminal_state: State<GrooveTerminalState>,
+    workspace_root: String,
+    page: Option<RowsPagePayload>,
 ) -> WorkspaceContextResponse {
-    let request_id = request_id();
-    let persisted_workspace_root = read_persisted_active_workspace_root(&app).ok().flatten();
-    let had_active_workspace = persisted_workspace_root.is_some();
-
-    if let Some(workspace_root) = persisted_workspace_root.as_deref() {
-        let workspace_root_key = workspace_root_storage_key(Path::new(workspace_root));
-        let sessions_to_close = match terminal_state.inner.lock() {
-            Ok(mut sessions_state) => drain_groove_terminal_sessions(
-                &mut sessions_state,
-                Some(workspace_root_key.as_str()),
-            ),
-            Err(_) => Vec::new(),
-        };
-        close_groove_terminal_sessions_best_effort(sessions_to_close);
-    }
-
-    match clear_persisted_active_workspace_root(&app) {
-        Ok(_) => {
-            clear_workspace_context_cache(&app);
-            clear_groove_list_cache(&app);
-            if had_active_workspace {
-                emit_workspace_ready_event(&app, &request_id, None, "connection");
-            }
-
-            WorkspaceContextResponse {
+    handle_command("workspace_switch", |context| {
+        let request_id = context.request_id();
+        let key = workspace_root_storage_key(Path::new(workspace_root.trim()));
+        let is_open = read_persisted_workspace_state(&app).is_ok_and(|state| {
+            state
+                .open_workspaces
+                .iter()
+                .any(|record| workspace_root_storage_key(Path::new(&record.workspace_root)) == key)
+        });
+        if !is_open {
+            return WorkspaceContextResponse {
                 request_id,
-                ok: true,
-                workspace_root: None,
+                ok: false,
+                workspace_root: Some(workspace_root),
                 repository_remote_url: None,
                 workspace_meta: None,
                 workspace_message: None,
                 has_worktrees_directory: None,
                 rows: Vec::new(),
                 cancelled: None,
-                error: None,
-            }
+                partial: None,
+                read_only: None,
+                read_only_reason: None,
+                trust: None,
+                page: None,
+                error: Some("That workspace is not open; open it first.".to_string()),
+            };
         }
-        Err(error) => WorkspaceContextResponse {
-            request_id,
+        let mut response = open_workspace(&app, request_id, workspace_root.trim());
+        paginate_workspace_context(&mut response, page);
+        response
+    })
+}
+
+/// Closes one open workspace: its terminal sessions, events worker and
+/// cached state go away. Closing the active workspace activates the most
+/// recently active of the others.
+#[tauri::command]
+fn workspace_close(
+    app: AppHandle,
+    terminal_state: State<GrooveTerminalState>,
+    workspace_root: String,
+) -> WorkspaceListOpenResponse {
+    handle_command("workspace_close", |context| {
+        let request_id = context.request_id();
+        let failed = |error: String| WorkspaceListOpenResponse {
+            request_id: request_id.clone(),
             ok: false,
-            workspace_root: None,
-            repository_remote_url: None,
-            workspace_meta: None,
-            workspace_message: None,
-            has_worktrees_directory: None,
-            rows: Vec::new(),
-            cancelled: None,
+            active_workspace_root: None,
+            workspaces: Vec::new(),
             error: Some(error),
-        },
-    }
+        };
+        let mut state = match read_persisted_workspace_state(&app) {
+            Ok(state) => state,
+            Err(error) => return failed(error),
+        };
+        let previous_active = state.workspace_root.clone();
+        let workspace_root = workspace_root.trim();
+        if !close_open_workspace(&mut state, workspace_root, true, &now_iso()) {
+            return failed("That workspace is not open.".to_string());
+        }
+        if let Err(error) = write_persisted_workspace_state(&app, &state) {
+            return failed(error);
+        }
+
+        let root = Path::new(workspace_root);
+        let workspace_root_key = workspace_root_storage_key(root);
+        let sessions_to_close = drain_groove_terminal_sessions(
+            &mut terminal_state.lock_sessions(),
+            Some(workspace_root_key.as_str()),
+        );
+        close_groove_terminal_sessions_best_effort(sessions_to_close);
+        stop_workspace_event_worker(&app, root);
+        invalidate_workspace_caches(&app, root);
+
+        if previous_active != state.workspace_root {
+            emit_workspace_ready_event(
+                &app,
+                &request_id,
+                state.workspace_root.as_deref(),
+                "connection",
+            );
+        }
+        workspace_list_open_response(&app, request_id.clone())
+    })
 }
 
 #[tauri::command]
 fn workspace_term_sanity_check() -> WorkspaceTermSanityResponse {
-    let request_id = request_id();
-    let term_value = std::env::var("TERM")
-        .ok()
-        .map(|value| value.trim().to_string())
-        .filter(|value| !value.is_empty());
-    let (is_usable, error) = evaluate_term_sanity(term_value.as_deref());
-
-    WorkspaceTermSanityResponse {
-        request_id,
-        ok: true,
-        term_value,
-        is_usable,
-        applied: None,
-        fixed_value: None,
-        error,
-    }
+    handle_command("workspace_term_sanity_check", |context| {
+        let request_id = context.request_id();
+        let term_value = std::env::var("TERM")
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty());
+        let (is_usable, error) = evaluate_term_sanity(term_value.as_deref());
+
+        WorkspaceTermSanityResponse {
+            request_id,
+            ok: true,
+            term_value,
+            is_usable,
+            applied: None,
+            fixed_value: None,
+            error,
+        }
+    })
 }
 
 #[tauri::command]
 fn workspace_term_sanity_apply() -> WorkspaceTermSanityResponse {
-    let request_id = request_id();
-    let current_term = std::env::var("TERM")
-        .ok()
-        .map(|value| value.trim().to_string())
-        .filter(|value| !value.is_empty());
-
-    if let Some(value) = current_term.as_deref() {
-        let (is_usable, _) = evaluate_term_sanity(Some(value));
-        if is_usable {
-            return WorkspaceTermSanityResponse {
-                request_id,
-                ok: true,
-                term_value: Some(value.to_string()),
-                is_usable: true,
-                applied: Some(false),
-                fixed_value: None,
-                error: None,
-            };
+    handle_command("workspace_term_sanity_apply", |context| {
+        let request_id = context.request_id();
+        let current_term = std::env::var("TERM")
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty());
+
+        if let Some(value) = current_term.as_deref() {
+            let (is_usable, _) = evaluate_term_sanity(Some(value));
+            if is_usable {
+                return WorkspaceTermSanityResponse {
+                    request_id,
+                    ok: true,
+                    term_value: Some(value.to_string()),
+                    is_usable: true,
+                    applied: Some(false),
+                    fixed_value: None,
+                    error: None,
+                };
+            }
         }
-    }
 
-    let fixed_value = TERM_SANITY_FALLBACK.to_string();
-    std::env::set_var("TERM", fixed_value.clone());
+        let fixed_value = TERM_SANITY_FALLBACK.to_string();
+        std::env::set_var("TERM", fixed_value.clone());
 
-    WorkspaceTermSanityResponse {
-        request_id,
-        ok: true,
-        term_value: Some(fixed_value.clone()),
-        is_usable: true,
-        applied: Some(true),
-        fixed_value: Some(fixed_value),
-        error: None,
-    }
+        WorkspaceTermSanityResponse {
+            request_id,
+            ok: true,
+            term_value: Some(fixed_value.clone()),
+            is_usable: true,
+            applied: Some(true),
+            fixed_value: Some(fixed_value),
+            error: None,
+        }
+    })
 }
 
 fn is_usable_term_value(value: &str) -> bool {
@@ -316,14 +549,33 @@ const TERM_SANITY_FALLBACK: &str = "xterm-256color";
 
 #[tauri::command]
 fn workspace_gitignore_sanity_check(app: AppHandle) -> WorkspaceGitignoreSanityResponse {
-    let request_id = request_id();
-    let workspace_root = match active_workspace_root_from_state(&app) {
-        Ok(workspace_root) => workspace_root,
-        Err(error) => {
+    handle_command("workspace_gitignore_sanity_check", |context| {
+        let request_id = context.request_id();
+        let workspace_root = match active_workspace_root_from_state(&app) {
+            Ok(workspace_root) => workspace_root,
+            Err(error) => {
+                return WorkspaceGitignoreSanityResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    is_applicable: false,
+                    has_groove_entry: false,
+                    has_workspace_entry: false,
+                    missing_entries: Vec::new(),
+                    patched: None,
+                    patched_worktree: None,
+                    play_started: None,
+                    error: Some(error),
+                }
+            }
+        };
+
+        let gitignore_path = workspace_root.join(".gitignore");
+        if !path_is_file(&gitignore_path) {
             return WorkspaceGitignoreSanityResponse {
                 request_id,
-                ok: false,
-                workspace_root: None,
+                ok: true,
+                workspace_root: Some(workspace_root.display().to_string()),
                 is_applicable: false,
                 has_groove_entry: false,
                 has_workspace_entry: false,
@@ -331,202 +583,205 @@ fn workspace_gitignore_sanity_check(app: AppHandle) -> WorkspaceGitignoreSanityR
                 patched: None,
                 patched_worktree: None,
                 play_started: None,
-                error: Some(error),
-            }
+                error: None,
+            };
         }
-    };
 
-    let gitignore_path = workspace_root.join(".gitignore");
-    if !path_is_file(&gitignore_path) {
-        return WorkspaceGitignoreSanityResponse {
+        let content = match fs::read_to_string(&gitignore_path) {
+            Ok(content) => content,
+            Err(error) => {
+                return WorkspaceGitignoreSanityResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: Some(workspace_root.display().to_string()),
+                    is_applicable: true,
+                    has_groove_entry: false,
+                    has_workspace_entry: false,
+                    missing_entries: Vec::new(),
+                    patched: None,
+                    patched_worktree: None,
+                    play_started: None,
+                    error: Some(format!(
+                        "Failed to read {}: {error}",
+                        gitignore_path.display()
+                    )),
+                }
+            }
+        };
+
+        let (has_groove_entry, has_workspace_entry, _, missing_entries) =
+            collect_gitignore_sanity(&content);
+
+        WorkspaceGitignoreSanityResponse {
             request_id,
             ok: true,
             workspace_root: Some(workspace_root.display().to_string()),
-            is_applicable: false,
-            has_groove_entry: false,
-            has_workspace_entry: false,
-            missing_entries: Vec::new(),
+            is_applicable: true,
+            has_groove_entry,
+            has_workspace_entry,
+            missing_entries,
             patched: None,
             patched_worktree: None,
             play_started: None,
             error: None,
+        }
+    })
+}
+
+#[tauri::command]
+fn workspace_gitignore_sanity_apply(
+    app: AppHandle,
+    terminal_state: State<GrooveTerminalState>,
+) -> WorkspaceGitignoreSanityResponse {
+    handle_command("workspace_gitignore_sanity_apply", |context| {
+        let request_id = context.request_id();
+        let workspace_root = match active_workspace_root_from_state(&app) {
+            Ok(workspace_root) => workspace_root,
+            Err(error) => {
+                return WorkspaceGitignoreSanityResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    is_applicable: false,
+                    has_groove_entry: false,
+                    has_workspace_entry: false,
+                    missing_entries: Vec::new(),
+                    patched: Some(false),
+                    patched_worktree: None,
+                    play_started: Some(false),
+                    error: Some(error),
+                }
+            }
         };
-    }
 
-    let content = match fs::read_to_string(&gitignore_path) {
-        Ok(content) => content,
-        Err(error) => {
+        let gitignore_path = workspace_root.join(".gitignore");
+        if !path_is_file(&gitignore_path) {
             return WorkspaceGitignoreSanityResponse {
                 request_id,
-                ok: false,
+                ok: true,
                 workspace_root: Some(workspace_root.display().to_string()),
-                is_applicable: true,
+                is_applicable: false,
                 has_groove_entry: false,
                 has_workspace_entry: false,
                 missing_entries: Vec::new(),
-                patched: None,
+                patched: Some(false),
                 patched_worktree: None,
-                play_started: None,
-                error: Some(format!(
-                    "Failed to read {}: {error}",
-                    gitignore_path.display()
-                )),
-            }
+                play_started: Some(false),
+                error: None,
+            };
         }
-    };
-
-    let (has_groove_entry, has_workspace_entry, _, missing_entries) =
-        collect_gitignore_sanity(&content);
-
-    WorkspaceGitignoreSanityResponse {
-        request_id,
-        ok: true,
-        workspace_root: Some(workspace_root.display().to_string()),
-        is_applicable: true,
-        has_groove_entry,
-        has_workspace_entry,
-        missing_entries,
-        patched: None,
-        patched_worktree: None,
-        play_started: None,
-        error: None,
-    }
-}
 
-#[tauri::command]
-fn workspace_gitignore_sanity_apply(
-    app: AppHandle,
-    terminal_state: State<GrooveTerminalState>,
-) -> WorkspaceGitignoreSanityResponse {
-    let request_id = request_id();
-    let workspace_root = match active_workspace_root_from_state(&app) {
-        Ok(workspace_root) => workspace_root,
-        Err(error) => {
-            return WorkspaceGitignoreSanityResponse {
-                request_id,
-                ok: false,
-                workspace_root: None,
-                is_applicable: false,
-                has_groove_entry: false,
-                has_workspace_entry: false,
-                missing_entries: Vec::new(),
-                patched: Some(false),
-                patched_worktree: None,
-                play_started: Some(false),
-                error: Some(error),
+        let content = match fs::read_to_string(&gitignore_path) {
+            Ok(content) => content,
+            Err(error) => {
+                return WorkspaceGitignoreSanityResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: Some(workspace_root.display().to_string()),
+                    is_applicable: true,
+                    has_groove_entry: false,
+                    has_workspace_entry: false,
+                    missing_entries: Vec::new(),
+                    patched: Some(false),
+                    patched_worktree: None,
+                    play_started: Some(false),
+                    error: Some(format!(
+                        "Failed to read {}: {error}",
+                        gitignore_path.display()
+                    )),
+                }
             }
-        }
-    };
-
-    let gitignore_path = workspace_root.join(".gitignore");
-    if !path_is_file(&gitignore_path) {
-        return WorkspaceGitignoreSanityResponse {
-            request_id,
-            ok: true,
-            workspace_root: Some(workspace_root.display().to_string()),
-            is_applicable: false,
-            has_groove_entry: false,
-            has_workspace_entry: false,
-            missing_entries: Vec::new(),
-            patched: Some(false),
-            patched_worktree: None,
-            play_started: Some(false),
-            error: None,
         };
-    }
 
-    let content = match fs::read_to_string(&gitignore_path) {
-        Ok(content) => content,
-        Err(error) => {
+        let (has_groove_entry, has_workspace_entry, has_groove_comment, missing_entries) =
+            collect_gitignore_sanity(&content);
+
+        if missing_entries.is_empty() {
             return WorkspaceGitignoreSanityResponse {
                 request_id,
-                ok: false,
+                ok: true,
                 workspace_root: Some(workspace_root.display().to_string()),
                 is_applicable: true,
-                has_groove_entry: false,
-                has_workspace_entry: false,
-                missing_entries: Vec::new(),
+                has_groove_entry,
+                has_workspace_entry,
+                missing_entries,
                 patched: Some(false),
                 patched_worktree: None,
                 play_started: Some(false),
-                error: Some(format!(
-                    "Failed to read {}: {error}",
-                    gitignore_path.display()
-                )),
-            }
+                error: None,
+            };
         }
-    };
 
-    let (has_groove_entry, has_workspace_entry, has_groove_comment, missing_entries) =
-        collect_gitignore_sanity(&content);
+        let newline = newline_for_content(&content);
+        let mut prefix_lines = Vec::new();
+        if !has_groove_comment {
+            prefix_lines.push(GITIGNORE_GROOVE_COMMENT.to_string());
+        }
+        prefix_lines.extend(missing_entries.iter().cloned());
 
-    if missing_entries.is_empty() {
-        return WorkspaceGitignoreSanityResponse {
-            request_id,
-            ok: true,
-            workspace_root: Some(workspace_root.display().to_string()),
-            is_applicable: true,
-            has_groove_entry,
-            has_workspace_entry,
-            missing_entries,
-            patched: Some(false),
-            patched_worktree: None,
-            play_started: Some(false),
-            error: None,
-        };
-    }
+        let mut next_content = prefix_lines.join(newline);
+        if content.is_empty() {
+            next_content.push_str(newline);
+        } else {
+            next_content.push_str(newline);
+            next_content.push_str(newline);
+            next_content.push_str(&content);
+        }
 
-    let newline = newline_for_content(&content);
-    let mut prefix_lines = Vec::new();
-    if !has_groove_comment {
-        prefix_lines.push(GITIGNORE_GROOVE_COMMENT.to_string());
-    }
-    prefix_lines.extend(missing_entries.iter().cloned());
+        let version = env!("CARGO_PKG_VERSION");
+        let random_suffix = (Uuid::new_v4().as_u128() % 1000) as u16;
+        let patch_worktree_branch = format!("groove/patch.{version}-{random_suffix:03}");
+        let patch_worktree = patch_worktree_branch.replace('/', "_");
 
-    let mut next_content = prefix_lines.join(newline);
-    if content.is_empty() {
-        next_content.push_str(newline);
-    } else {
-        next_content.push_str(newline);
-        next_content.push_str(newline);
-        next_content.push_str(&content);
-    }
+        let patch_effective_root = ensure_workspace_meta(&workspace_root)
+            .map(|(meta, _)| effective_workspace_root(&workspace_root, &meta))
+            .unwrap_or_else(|_| workspace_root.clone());
 
-    let version = env!("CARGO_PKG_VERSION");
-    let random_suffix = (Uuid::new_v4().as_u128() % 1000) as u16;
-    let patch_worktree_branch = format!("groove/patch.{version}-{random_suffix:03}");
-    let patch_worktree = patch_worktree_branch.replace('/', "_");
+        let create_result = run_command(
+            &groove_binary_path(&app),
+            &["create".to_string(), patch_worktree_branch],
+            &patch_effective_root,
+        );
+        if create_result.exit_code != Some(0) || create_result.error.is_some() {
+            return WorkspaceGitignoreSanityResponse {
+                request_id,
+                ok: false,
+                workspace_root: Some(workspace_root.display().to_string()),
+                is_applicable: true,
+                has_groove_entry,
+                has_workspace_entry,
+                missing_entries,
+                patched: Some(false),
+                patched_worktree: Some(patch_worktree),
+                play_started: Some(false),
+                error: create_result.error.or_else(|| {
+                    Some("Failed to create patch worktree for .gitignore sanity apply.".to_string())
+                }),
+            };
+        }
 
-    let patch_effective_root = ensure_workspace_meta(&workspace_root)
-        .map(|(meta, _)| effective_workspace_root(&workspace_root, &meta))
-        .unwrap_or_else(|_| workspace_root.clone());
-
-    let create_result = run_command(
-        &groove_binary_path(&app),
-        &["create".to_string(), patch_worktree_branch],
-        &patch_effective_root,
-    );
-    if create_result.exit_code != Some(0) || create_result.error.is_some() {
-        return WorkspaceGitignoreSanityResponse {
-            request_id,
-            ok: false,
-            workspace_root: Some(workspace_root.display().to_string()),
-            is_applicable: true,
-            has_groove_entry,
-            has_workspace_entry,
-            missing_entries,
-            patched: Some(false),
-            patched_worktree: Some(patch_worktree),
-            play_started: Some(false),
-            error: create_result.error.or_else(|| {
-                Some("Failed to create patch worktree for .gitignore sanity apply.".to_string())
-            }),
-        };
-    }
+        let patch_worktree_path =
+            match resolve_patch_worktree_path(&patch_effective_root, &patch_worktree) {
+                Ok(path) => path,
+                Err(error) => {
+                    return WorkspaceGitignoreSanityResponse {
+                        request_id,
+                        ok: false,
+                        workspace_root: Some(workspace_root.display().to_string()),
+                        is_applicable: true,
+                        has_groove_entry,
+                        has_workspace_entry,
+                        missing_entries,
+                        patched: Some(false),
+                        patched_worktree: Some(patch_worktree),
+                        play_started: Some(false),
+                        error: Some(error),
+                    }
+                }
+            };
 
-    let patch_worktree_path = match resolve_patch_worktree_path(&patch_effective_root, &patch_worktree) {
-        Ok(path) => path,
-        Err(error) => {
+        let worktree_gitignore_path = patch_worktree_path.join(".gitignore");
+        if let Err(error) = fs::write(&worktree_gitignore_path, next_content) {
             return WorkspaceGitignoreSanityResponse {
                 request_id,
                 ok: false,
@@ -538,50 +793,51 @@ fn workspace_gitignore_sanity_apply(
                 patched: Some(false),
                 patched_worktree: Some(patch_worktree),
                 play_started: Some(false),
-                error: Some(error),
-            }
+                error: Some(format!(
+                    "Failed to write {}: {error}",
+                    worktree_gitignore_path.display()
+                )),
+            };
         }
-    };
 
-    let worktree_gitignore_path = patch_worktree_path.join(".gitignore");
-    if let Err(error) = fs::write(&worktree_gitignore_path, next_content) {
-        return WorkspaceGitignoreSanityResponse {
-            request_id,
-            ok: false,
-            workspace_root: Some(workspace_root.display().to_string()),
-            is_applicable: true,
-            has_groove_entry,
-            has_workspace_entry,
-            missing_entries,
-            patched: Some(false),
-            patched_worktree: Some(patch_worktree),
-            play_started: Some(false),
-            error: Some(format!(
-                "Failed to write {}: {error}",
-                worktree_gitignore_path.display()
-            )),
-        };
-    }
+        let play_result = groove_restore(
+            app,
+            terminal_state,
+            GrooveRestorePayload {
+                workspace_root: Some(workspace_root.display().to_string()),
+                root_name: None,
+                known_worktrees: vec![patch_worktree.clone()],
+                workspace_meta: None,
+                worktree: patch_worktree.clone(),
+                action: Some("go".to_string()),
+                target: Some(patch_worktree.clone()),
+                dir: None,
+                opencode_log_file: None,
+                stream: false,
+                debug: None,
+            },
+        );
+        if !play_result.ok {
+            return WorkspaceGitignoreSanityResponse {
+                request_id,
+                ok: false,
+                workspace_root: Some(workspace_root.display().to_string()),
+                is_applicable: true,
+                has_groove_entry: true,
+                has_workspace_entry: true,
+                missing_entries: Vec::new(),
+                patched: Some(true),
+                patched_worktree: Some(patch_worktree),
+                play_started: Some(false),
+                error: Some(play_result.error.unwrap_or_else(|| {
+                    "Failed to launch Play Groove for patch worktree.".to_string()
+                })),
+            };
+        }
 
-    let play_result = groove_restore(
-        app,
-        terminal_state,
-        GrooveRestorePayload {
-            workspace_root: Some(workspace_root.display().to_string()),
-            root_name: None,
-            known_worktrees: vec![patch_worktree.clone()],
-            workspace_meta: None,
-            worktree: patch_worktree.clone(),
-            action: Some("go".to_string()),
-            target: Some(patch_worktree.clone()),
-            dir: None,
-            opencode_log_file: None,
-        },
-    );
-    if !play_result.ok {
-        return WorkspaceGitignoreSanityResponse {
+        WorkspaceGitignoreSanityResponse {
             request_id,
-            ok: false,
+            ok: true,
             workspace_root: Some(workspace_root.display().to_string()),
             is_applicable: true,
             has_groove_entry: true,
@@ -589,28 +845,10 @@ fn workspace_gitignore_sanity_apply(
             missing_entries: Vec::new(),
             patched: Some(true),
             patched_worktree: Some(patch_worktree),
-            play_started: Some(false),
-            error: Some(
-                play_result.error.unwrap_or_else(|| {
-                    "Failed to launch Play Groove for patch worktree.".to_string()
-                }),
-            ),
-        };
-    }
-
-    WorkspaceGitignoreSanityResponse {
-        request_id,
-        ok: true,
-        workspace_root: Some(workspace_root.display().to_string()),
-        is_applicable: true,
-        has_groove_entry: true,
-        has_workspace_entry: true,
-        missing_entries: Vec::new(),
-        patched: Some(true),
-        patched_worktree: Some(patch_worktree),
-        play_started: Some(true),
-        error: None,
-    }
+            play_started: Some(true),
+            error: None,
+        }
+    })
 }
 
 fn resolve_patch_worktree_path(
@@ -721,6 +959,31 @@ mod workspace_commands_tests {
             vec!["groove_patch.1".to_string(), "groove/patch.1".to_string()]
         );
     }
+
+    #[test]
+    fn workspace_summary_category_keeps_newest_items() {
+        let item = |worktree: &str, since: Option<&str>| WorkspaceSummaryItem {
+            worktree: worktree.to_string(),
+            detail: None,
+            url: None,
+            since: since.map(str::to_string),
+        };
+        let category = workspace_summary_category(vec![
+            item("untimed", None),
+            item("old", Some("2026-01-01T00:00:00Z")),
+            item("new", Some("2026-03-01T00:00:00Z")),
+            item("middle", Some("2026-02-01T00:00:00Z")),
+        ]);
+        assert_eq!(category.count, 4);
+        assert_eq!(
+            category
+                .top
+                .iter()
+                .map(|item| item.worktree.as_str())
+                .collect::<Vec<_>>(),
+            vec!["new", "middle", "old"]
+        );
+    }
 }
 
 fn list_git_worktrees_by_branch(
@@ -741,54 +1004,31 @@ fn list_git_worktrees_by_branch(
         return Err(details.join("; "));
     }
 
-    let mut entries = Vec::new();
-    let mut current_branch = None;
-    let mut current_path = None;
-
-    for raw_line in result.stdout.lines().chain(std::iter::once("")) {
-        let line = raw_line.trim();
-        if line.is_empty() {
-            if let Some(path) = current_path.take() {
-                entries.push((current_branch.take(), path));
-            }
-            continue;
-        }
-
-        if let Some(value) = line.strip_prefix("worktree ") {
-            current_path = Some(PathBuf::from(value.trim()));
-            continue;
-        }
-
-        if let Some(value) = line.strip_prefix("branch ") {
-            let normalized = value
-                .trim()
-                .strip_prefix("refs/heads/")
-                .unwrap_or(value.trim())
-                .to_string();
-            current_branch = Some(normalized);
-        }
-    }
-
-    Ok(entries)
+    Ok(git::parse_worktree_list_porcelain(&result.stdout)
+        .into_iter()
+        .map(|(branch, path)| (branch, host_path_from_git_output(workspace_root, &path)))
+        .collect())
 }
 
 #[tauri::command]
 fn global_settings_get(app: AppHandle) -> GlobalSettingsResponse {
-    let request_id = request_id();
-    match ensure_global_settings(&app) {
-        Ok(global_settings) => GlobalSettingsResponse {
-            request_id,
-            ok: true,
-            global_settings: Some(global_settings),
-            error: None,
-        },
-        Err(error) => GlobalSettingsResponse {
-            request_id,
-            ok: false,
-            global_settings: Some(default_global_settings()),
-            error: Some(error),
-        },
-    }
+    handle_command("global_settings_get", |context| {
+        let request_id = context.request_id();
+        match ensure_global_settings(&app) {
+            Ok(global_settings) => GlobalSettingsResponse {
+                request_id,
+                ok: true,
+                global_settings: Some(global_settings),
+                error: None,
+            },
+            Err(error) => GlobalSettingsResponse {
+                request_id,
+                ok: false,
+                global_settings: Some(default_global_settings()),
+                error: Some(error),
+            },
+        }
+    })
 }
 
 #[tauri::command]
@@ -796,301 +1036,706 @@ fn global_settings_update(
     app: AppHandle,
     payload: GlobalSettingsUpdatePayload,
 ) -> GlobalSettingsResponse {
-    let request_id = request_id();
-    let mut global_settings = match ensure_global_settings(&app) {
-        Ok(value) => value,
-        Err(error) => {
-            return GlobalSettingsResponse {
-                request_id,
-                ok: false,
-                global_settings: Some(default_global_settings()),
-                error: Some(error),
-            }
-        }
-    };
-
-    if let Some(telemetry_enabled) = payload.telemetry_enabled {
-        global_settings.telemetry_enabled = telemetry_enabled;
-    }
-    if let Some(disable_groove_business) = payload.disable_groove_business {
-        global_settings.disable_groove_business = disable_groove_business;
-    }
-    if let Some(hide_mascot) = payload.hide_mascot {
-        global_settings.hide_mascot = hide_mascot;
-    }
-    if let Some(hide_labels) = payload.hide_labels {
-        global_settings.hide_labels = hide_labels;
-    }
-    if let Some(show_fps) = payload.show_fps {
-        global_settings.show_fps = show_fps;
-    }
-    if let Some(always_show_diagnostics_sidebar) = payload.always_show_diagnostics_sidebar {
-        global_settings.always_show_diagnostics_sidebar = always_show_diagnostics_sidebar;
-    }
-    if let Some(periodic_rerender_enabled) = payload.periodic_rerender_enabled {
-        global_settings.periodic_rerender_enabled = periodic_rerender_enabled;
-    }
-    if let Some(theme_mode) = payload.theme_mode.as_deref() {
-        match normalize_theme_mode(theme_mode) {
-            Ok(value) => {
-                global_settings.theme_mode = value;
-            }
+    handle_command("global_settings_update", |context| {
+        let request_id = context.request_id();
+        let mut global_settings = match ensure_global_settings(&app) {
+            Ok(value) => value,
             Err(error) => {
                 return GlobalSettingsResponse {
                     request_id,
                     ok: false,
-                    global_settings: Some(global_settings),
+                    global_settings: Some(default_global_settings()),
                     error: Some(error),
                 }
             }
+        };
+
+        let previous_settings = global_settings.clone();
+        if let Some(telemetry_enabled) = payload.telemetry_enabled {
+            global_settings.telemetry_enabled = telemetry_enabled;
         }
-    }
-    if let Some(keyboard_shortcut_leader) = payload.keyboard_shortcut_leader.as_deref() {
-        global_settings.keyboard_shortcut_leader = normalize_shortcut_key(
-            keyboard_shortcut_leader,
-            &default_keyboard_shortcut_leader(),
-        );
-    }
-    if let Some(keyboard_leader_bindings) = payload.keyboard_leader_bindings.as_ref() {
-        global_settings.keyboard_leader_bindings =
-            normalize_keyboard_leader_bindings(keyboard_leader_bindings);
-    }
-    if let Some(opencode_settings) = payload.opencode_settings.as_ref() {
-        global_settings.opencode_settings = normalize_opencode_settings(&OpencodeSettings {
-            enabled: opencode_settings.enabled,
-            default_model: opencode_settings.default_model.clone(),
-            settings_directory: opencode_settings
-                .settings_directory
-                .clone()
-                .unwrap_or_else(|| global_settings.opencode_settings.settings_directory.clone()),
-        });
-    }
-    if let Some(sound_library) = payload.sound_library {
-        for entry in &sound_library {
-            if let Err(reason) = validate_sound_file_name(&entry.file_name) {
-                return GlobalSettingsResponse {
-                    request_id,
-                    ok: false,
-                    global_settings: Some(global_settings),
-                    error: Some(format!(
-                        "Invalid sound library entry \"{}\": {reason}",
-                        entry.id
-                    )),
-                };
+        if let Some(disable_groove_business) = payload.disable_groove_business {
+            global_settings.disable_groove_business = disable_groove_business;
+        }
+        if let Some(hide_mascot) = payload.hide_mascot {
+            global_settings.hide_mascot = hide_mascot;
+        }
+        if let Some(hide_labels) = payload.hide_labels {
+            global_settings.hide_labels = hide_labels;
+        }
+        if let Some(show_fps) = payload.show_fps {
+            global_settings.show_fps = show_fps;
+        }
+        if let Some(always_show_diagnostics_sidebar) = payload.always_show_diagnostics_sidebar {
+            global_settings.always_show_diagnostics_sidebar = always_show_diagnostics_sidebar;
+        }
+        if let Some(periodic_rerender_enabled) = payload.periodic_rerender_enabled {
+            global_settings.periodic_rerender_enabled = periodic_rerender_enabled;
+        }
+        if let Some(theme_mode) = payload.theme_mode.as_deref() {
+            match normalize_theme_mode(theme_mode) {
+                Ok(value) => {
+                    global_settings.theme_mode = value;
+                }
+                Err(error) => {
+                    return GlobalSettingsResponse {
+                        request_id,
+                        ok: false,
+                        global_settings: Some(global_settings),
+                        error: Some(error),
+                    }
+                }
             }
         }
-        global_settings.sound_library = sound_library;
-    }
-    if let Some(claude_code_sound_settings) = payload.claude_code_sound_settings {
-        global_settings.claude_code_sound_settings = claude_code_sound_settings;
-    }
-    if let Some(groove_sound_settings) = payload.groove_sound_settings {
-        global_settings.groove_sound_settings = groove_sound_settings;
-    }
-    let settings_file = match global_settings_file(&app) {
-        Ok(path) => path,
-        Err(error) => {
-            return GlobalSettingsResponse {
-                request_id,
-                ok: false,
-                global_settings: Some(global_settings),
-                error: Some(error),
+        if let Some(keyboard_shortcut_leader) = payload.keyboard_shortcut_leader.as_deref() {
+            global_settings.keyboard_shortcut_leader = normalize_shortcut_key(
+                keyboard_shortcut_leader,
+                &default_keyboard_shortcut_leader(),
+            );
+        }
+        if let Some(keyboard_leader_bindings) = payload.keyboard_leader_bindings.as_ref() {
+            global_settings.keyboard_leader_bindings =
+                normalize_keyboard_leader_bindings(keyboard_leader_bindings);
+        }
+        if let Some(opencode_settings) = payload.opencode_settings.as_ref() {
+            global_settings.opencode_settings = normalize_opencode_settings(&OpencodeSettings {
+                enabled: opencode_settings.enabled,
+                default_model: opencode_settings.default_model.clone(),
+                settings_directory: opencode_settings.settings_directory.clone().unwrap_or_else(
+                    || global_settings.opencode_settings.settings_directory.clone(),
+                ),
+            });
+        }
+        if let Some(sound_library) = payload.sound_library {
+            for entry in &sound_library {
+                if let Err(reason) = validate_sound_file_name(&entry.file_name) {
+                    return GlobalSettingsResponse {
+                        request_id,
+                        ok: false,
+                        global_settings: Some(global_settings),
+                        error: Some(format!(
+                            "Invalid sound library entry \"{}\": {reason}",
+                            entry.id
+                        )),
+                    };
+                }
             }
+            global_settings.sound_library = sound_library;
         }
-    };
-
-    if let Err(error) = write_global_settings_file(&settings_file, &global_settings) {
-        return GlobalSettingsResponse {
-            request_id,
-            ok: false,
-            global_settings: Some(global_settings),
-            error: Some(error),
+        if let Some(claude_code_sound_settings) = payload.claude_code_sound_settings {
+            global_settings.claude_code_sound_settings = claude_code_sound_settings;
+        }
+        if let Some(groove_sound_settings) = payload.groove_sound_settings {
+            global_settings.groove_sound_settings = groove_sound_settings;
+        }
+        if let Some(terminal_memory_settings) = payload.terminal_memory_settings {
+            global_settings.terminal_memory_settings =
+                normalize_terminal_memory_settings(terminal_memory_settings);
+        }
+        if let Some(system_alert_settings) = payload.system_alert_settings {
+            global_settings.system_alert_settings =
+                normalize_system_alert_settings(system_alert_settings);
+        }
+        if let Some(telemetry_path_redaction) = payload.telemetry_path_redaction.as_deref() {
+            match PathRedaction::parse(telemetry_path_redaction) {
+                Ok(mode) => {
+                    global_settings.telemetry_path_redaction = mode.as_str().to_string();
+                }
+                Err(error) => {
+                    return GlobalSettingsResponse {
+                        request_id,
+                        ok: false,
+                        global_settings: Some(global_settings),
+                        error: Some(error),
+                    }
+                }
+            }
+        }
+        if let Some(custom_command_policy) = payload.custom_command_policy {
+            match normalize_custom_command_policy(custom_command_policy) {
+                Ok(policy) => global_settings.custom_command_policy = policy,
+                Err(error) => {
+                    return GlobalSettingsResponse {
+                        request_id,
+                        ok: false,
+                        global_settings: Some(global_settings),
+                        error: Some(error),
+                    }
+                }
+            }
+        }
+        if let Some(default_terminal) = payload.default_terminal.as_deref() {
+            match normalize_default_terminal_override(default_terminal) {
+                Ok(value) => global_settings.default_terminal = value,
+                Err(error) => {
+                    return GlobalSettingsResponse {
+                        request_id,
+                        ok: false,
+                        global_settings: Some(global_settings),
+                        error: Some(error),
+                    }
+                }
+            }
+        }
+        if let Some(polling_settings) = payload.polling_settings {
+            global_settings.polling_settings = normalize_polling_settings(polling_settings);
+        }
+        if let Some(restart_policy) = payload.testing_environment_restart_policy {
+            global_settings.testing_environment_restart_policy =
+                normalize_testing_environment_restart_policy(restart_policy);
+        }
+        if let Some(status_page_settings) = payload.status_page_settings {
+            global_settings.status_page_settings =
+                normalize_status_page_settings(status_page_settings);
+        }
+        if let Some(retry_policy) = payload.command_retry_policy {
+            global_settings.command_retry_policy = normalize_command_retry_policy(retry_policy);
+        }
+        let settings_file = match global_settings_file(&app) {
+            Ok(path) => path,
+            Err(error) => {
+                return GlobalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    global_settings: Some(global_settings),
+                    error: Some(error),
+                }
+            }
         };
-    }
 
-    GlobalSettingsResponse {
+        if let Err(error) = write_global_settings_file(&settings_file, &global_settings) {
+            return GlobalSettingsResponse {
+                request_id,
+                ok: false,
+                global_settings: Some(global_settings),
+                error: Some(error),
+            };
+        }
+        push_undo_entry(
+            &app,
+            UndoTarget::GlobalSettings,
+            "global_settings_update",
+            "Revert app settings".to_string(),
+            changed_fields(&previous_settings, &global_settings),
+        );
+
+        GlobalSettingsResponse {
+            request_id,
+            ok: true,
+            global_settings: Some(global_settings),
+            error: None,
+        }
+    })
+}
+
+fn settings_profiles_ok_response(
+    request_id: String,
+    profiles: PersistedSettingsProfiles,
+    global_settings: Option<GlobalSettings>,
+    exported: Option<String>,
+) -> SettingsProfilesResponse {
+    SettingsProfilesResponse {
         request_id,
         ok: true,
-        global_settings: Some(global_settings),
+        active_profile: profiles.active_profile,
+        profiles: profiles.profiles,
+        global_settings,
+        exported,
         error: None,
     }
 }
 
+fn settings_profiles_error_response(request_id: String, error: String) -> SettingsProfilesResponse {
+    SettingsProfilesResponse {
+        request_id,
+        ok: false,
+        active_profile: None,
+        profiles: Vec::new(),
+        global_settings: None,
+        exported: None,
+        error: Some(error),
+    }
+}
+
 #[tauri::command]
-fn sound_library_read(app: AppHandle, payload: SoundLibraryReadPayload) -> SoundLibraryReadResponse {
-    let request_id = request_id();
+fn settings_profile_list(app: AppHandle) -> SettingsProfilesResponse {
+    handle_command("settings_profile_list", |context| {
+        let request_id = context.request_id();
+        match read_settings_profiles(&app) {
+            Ok(profiles) => settings_profiles_ok_response(request_id, profiles, None, None),
+            Err(error) => settings_profiles_error_response(request_id, error),
+        }
+    })
+}
 
-    if let Err(reason) = validate_sound_file_name(&payload.file_name) {
-        return SoundLibraryReadResponse {
-            request_id,
-            ok: false,
-            data: None,
-            error: Some(reason),
-        };
+#[tauri::command]
+fn settings_profile_save(
+    app: AppHandle,
+    payload: SettingsProfileSavePayload,
+) -> SettingsProfilesResponse {
+    handle_command("settings_profile_save", |context| {
+        let request_id = context.request_id();
+        let result = (|| {
+            let name = normalize_settings_profile_name(&payload.name)?;
+            let overrides = match payload.overrides {
+                Some(overrides) => normalize_settings_profile_overrides(overrides)?,
+                None => capture_settings_profile_overrides(&ensure_global_settings(&app)?),
+            };
+            let mut profiles = read_settings_profiles(&app)?;
+            upsert_settings_profile(
+                &mut profiles,
+                SettingsProfile {
+                    name,
+                    overrides,
+                    updated_at: now_iso(),
+                },
+            );
+            write_settings_profiles(&app, &profiles)?;
+            Ok::<_, String>(profiles)
+        })();
+        match result {
+            Ok(profiles) => settings_profiles_ok_response(request_id, profiles, None, None),
+            Err(error) => settings_profiles_error_response(request_id, error),
+        }
+    })
+}
+
+#[tauri::command]
+fn settings_profile_delete(
+    app: AppHandle,
+    payload: SettingsProfileNamePayload,
+) -> SettingsProfilesResponse {
+    handle_command("settings_profile_delete", |context| {
+        let request_id = context.request_id();
+        let result = (|| {
+            let mut profiles = read_settings_profiles(&app)?;
+            let count = profiles.profiles.len();
+            profiles
+                .profiles
+                .retain(|profile| !profile.name.eq_ignore_ascii_case(payload.name.trim()));
+            if profiles.profiles.len() == count {
+                return Err(format!(
+                    "Settings profile \"{}\" not found.",
+                    payload.name.trim()
+                ));
+            }
+            if profiles
+                .active_profile
+                .as_deref()
+                .is_some_and(|active| active.eq_ignore_ascii_case(payload.name.trim()))
+            {
+                profiles.active_profile = None;
+            }
+            write_settings_profiles(&app, &profiles)?;
+            Ok::<_, String>(profiles)
+        })();
+        match result {
+            Ok(profiles) => settings_profiles_ok_response(request_id, profiles, None, None),
+            Err(error) => settings_profiles_error_response(request_id, error),
+        }
+    })
+}
+
+/// Applies the profile's overrides to the global settings and marks it
+/// active. Undoable like `global_settings_update`.
+#[tauri::command]
+fn settings_profile_switch(
+    app: AppHandle,
+    payload: SettingsProfileNamePayload,
+) -> SettingsProfilesResponse {
+    handle_command("settings_profile_switch", |context| {
+        let request_id = context.request_id();
+        let result = (|| {
+            let mut profiles = read_settings_profiles(&app)?;
+            let profile = find_settings_profile(&profiles, payload.name.trim())
+                .cloned()
+                .ok_or_else(|| {
+                    format!("Settings profile \"{}\" not found.", payload.name.trim())
+                })?;
+            let overrides = normalize_settings_profile_overrides(profile.overrides)?;
+
+            let previous_settings = ensure_global_settings(&app)?;
+            let mut global_settings = previous_settings.clone();
+            apply_settings_profile_overrides(&mut global_settings, &overrides);
+            write_global_settings_file(&global_settings_file(&app)?, &global_settings)?;
+            push_undo_entry(
+                &app,
+                UndoTarget::GlobalSettings,
+                "settings_profile_switch",
+                format!("Switch back from settings profile \"{}\"", profile.name),
+                changed_fields(&previous_settings, &global_settings),
+            );
+
+            profiles.active_profile = Some(profile.name);
+            write_settings_profiles(&app, &profiles)?;
+            Ok::<_, String>((profiles, global_settings))
+        })();
+        match result {
+            Ok((profiles, global_settings)) => {
+                settings_profiles_ok_response(request_id, profiles, Some(global_settings), None)
+            }
+            Err(error) => settings_profiles_error_response(request_id, error),
+        }
+    })
+}
+
+/// The profile as a standalone JSON document, for `settings_profile_import`
+/// on another machine.
+#[tauri::command]
+fn settings_profile_export(
+    app: AppHandle,
+    payload: SettingsProfileNamePayload,
+) -> SettingsProfilesResponse {
+    handle_command("settings_profile_export", |context| {
+        let request_id = context.request_id();
+        let result = (|| {
+            let profiles = read_settings_profiles(&app)?;
+            let exported = find_settings_profile(&profiles, payload.name.trim())
+                .ok_or_else(|| format!("Settings profile \"{}\" not found.", payload.name.trim()))
+                .and_then(export_settings_profile)?;
+            Ok::<_, String>((profiles, exported))
+        })();
+        match result {
+            Ok((profiles, exported)) => {
+                settings_profiles_ok_response(request_id, profiles, None, Some(exported))
+            }
+            Err(error) => settings_profiles_error_response(request_id, error),
+        }
+    })
+}
+
+/// Saves a profile from an exported document, replacing the profile with
+/// the same name.
+#[tauri::command]
+fn settings_profile_import(
+    app: AppHandle,
+    payload: SettingsProfileImportPayload,
+) -> SettingsProfilesResponse {
+    handle_command("settings_profile_import", |context| {
+        let request_id = context.request_id();
+        let result = (|| {
+            let profile =
+                parse_exported_settings_profile(&payload.contents, payload.name.as_deref())?;
+            let mut profiles = read_settings_profiles(&app)?;
+            upsert_settings_profile(&mut profiles, profile);
+            write_settings_profiles(&app, &profiles)?;
+            Ok::<_, String>(profiles)
+        })();
+        match result {
+            Ok(profiles) => settings_profiles_ok_response(request_id, profiles, None, None),
+            Err(error) => settings_profiles_error_response(request_id, error),
+        }
+    })
+}
+
+fn settings_sync_response(
+    request_id: String,
+    state: PersistedSettingsSync,
+    outcome: SettingsSyncOutcome,
+) -> SettingsSyncResponse {
+    SettingsSyncResponse {
+        request_id,
+        ok: outcome.error.is_none(),
+        target: state.target,
+        last_synced_at: state.remote_updated_at,
+        conflicts: outcome.conflicts,
+        global_settings: outcome.global_settings,
+        error: outcome.error,
     }
+}
 
-    let app_data_dir = match app.path().app_data_dir() {
-        Ok(dir) => dir,
-        Err(error) => {
+fn settings_sync_error_response(request_id: String, error: String) -> SettingsSyncResponse {
+    SettingsSyncResponse {
+        request_id,
+        ok: false,
+        target: SettingsSyncTarget::default(),
+        last_synced_at: None,
+        conflicts: Vec::new(),
+        global_settings: None,
+        error: Some(error),
+    }
+}
+
+#[tauri::command]
+fn settings_sync_status(app: AppHandle) -> SettingsSyncResponse {
+    handle_command("settings_sync_status", |context| {
+        let request_id = context.request_id();
+        match read_settings_sync(&app) {
+            Ok(state) => settings_sync_response(request_id, state, SettingsSyncOutcome::default()),
+            Err(error) => settings_sync_error_response(request_id, error),
+        }
+    })
+}
+
+/// Chooses where settings are synced. Choosing another file or gist forgets
+/// the last sync, so the first export or import there is not checked for
+/// conflicts.
+#[tauri::command]
+fn settings_sync_configure(
+    app: AppHandle,
+    payload: SettingsSyncConfigurePayload,
+) -> SettingsSyncResponse {
+    handle_command("settings_sync_configure", |context| {
+        let request_id = context.request_id();
+        let result = (|| {
+            let mut state = read_settings_sync(&app)?;
+            let target = normalize_settings_sync_target(&payload, &state.target)?;
+            if target.kind != state.target.kind
+                || target.path != state.target.path
+                || target.gist_id != state.target.gist_id
+            {
+                state = PersistedSettingsSync::default();
+            }
+            state.target = target;
+            write_settings_sync(&app, &state)?;
+            Ok::<_, String>(state)
+        })();
+        match result {
+            Ok(state) => settings_sync_response(request_id, state, SettingsSyncOutcome::default()),
+            Err(error) => settings_sync_error_response(request_id, error),
+        }
+    })
+}
+
+#[tauri::command]
+async fn settings_sync_export(
+    app: AppHandle,
+    payload: SettingsSyncRunPayload,
+) -> SettingsSyncResponse {
+    handle_blocking_command(
+        "settings_sync_export",
+        move |context| match run_settings_sync_export(&app, &payload) {
+            Ok((state, outcome)) => settings_sync_response(context.request_id(), state, outcome),
+            Err(error) => settings_sync_error_response(context.request_id(), error),
+        },
+        settings_sync_error_response,
+    )
+    .await
+}
+
+#[tauri::command]
+async fn settings_sync_import(
+    app: AppHandle,
+    payload: SettingsSyncRunPayload,
+) -> SettingsSyncResponse {
+    handle_blocking_command(
+        "settings_sync_import",
+        move |context| match run_settings_sync_import(&app, &payload) {
+            Ok((state, outcome)) => settings_sync_response(context.request_id(), state, outcome),
+            Err(error) => settings_sync_error_response(context.request_id(), error),
+        },
+        settings_sync_error_response,
+    )
+    .await
+}
+
+#[tauri::command]
+async fn settings_sync_merge(
+    app: AppHandle,
+    payload: SettingsSyncRunPayload,
+) -> SettingsSyncResponse {
+    handle_blocking_command(
+        "settings_sync_merge",
+        move |context| match run_settings_sync_merge(&app, &payload) {
+            Ok((state, outcome)) => settings_sync_response(context.request_id(), state, outcome),
+            Err(error) => settings_sync_error_response(context.request_id(), error),
+        },
+        settings_sync_error_response,
+    )
+    .await
+}
+
+#[tauri::command]
+fn sound_library_read(
+    app: AppHandle,
+    payload: SoundLibraryReadPayload,
+) -> SoundLibraryReadResponse {
+    handle_command("sound_library_read", |context| {
+        let request_id = context.request_id();
+
+        if let Err(reason) = validate_sound_file_name(&payload.file_name) {
             return SoundLibraryReadResponse {
                 request_id,
                 ok: false,
                 data: None,
-                error: Some(format!("Failed to resolve app data directory: {error}")),
+                error: Some(reason),
             };
         }
-    };
 
-    let sound_file = app_data_dir.join("sounds").join(&payload.file_name);
-    if !sound_file.is_file() {
-        return SoundLibraryReadResponse {
-            request_id,
-            ok: false,
-            data: None,
-            error: Some(format!(
-                "Sound file not found: {} (resolved path: {})",
-                payload.file_name,
-                sound_file.display()
-            )),
+        let app_data_dir = match app.path().app_data_dir() {
+            Ok(dir) => dir,
+            Err(error) => {
+                return SoundLibraryReadResponse {
+                    request_id,
+                    ok: false,
+                    data: None,
+                    error: Some(format!("Failed to resolve app data directory: {error}")),
+                };
+            }
         };
-    }
 
-    match fs::read(&sound_file) {
-        Ok(bytes) => {
-            use base64::Engine;
-            let encoded = base64::engine::general_purpose::STANDARD.encode(&bytes);
-            SoundLibraryReadResponse {
+        let sound_file = app_data_dir.join("sounds").join(&payload.file_name);
+        if !sound_file.is_file() {
+            return SoundLibraryReadResponse {
                 request_id,
-                ok: true,
-                data: Some(encoded),
-                error: None,
+                ok: false,
+                data: None,
+                error: Some(format!(
+                    "Sound file not found: {} (resolved path: {})",
+                    payload.file_name,
+                    sound_file.display()
+                )),
+            };
+        }
+
+        match fs::read(&sound_file) {
+            Ok(bytes) => {
+                use base64::Engine;
+                let encoded = base64::engine::general_purpose::STANDARD.encode(&bytes);
+                SoundLibraryReadResponse {
+                    request_id,
+                    ok: true,
+                    data: Some(encoded),
+                    error: None,
+                }
             }
+            Err(error) => SoundLibraryReadResponse {
+                request_id,
+                ok: false,
+                data: None,
+                error: Some(format!("Failed to read sound file: {error}")),
+            },
         }
-        Err(error) => SoundLibraryReadResponse {
-            request_id,
-            ok: false,
-            data: None,
-            error: Some(format!("Failed to read sound file: {error}")),
-        },
-    }
+    })
 }
 
 #[tauri::command]
 fn sound_library_import(app: AppHandle) -> GlobalSettingsResponse {
-    let request_id = request_id();
-
-    let picked = rfd::FileDialog::new()
-        .set_title("Import sound file")
-        .add_filter(
-            "Audio files",
-            &["mp3", "wav", "ogg", "flac", "m4a", "aac", "webm"],
-        )
-        .pick_files();
+    handle_command("sound_library_import", |context| {
+        let request_id = context.request_id();
+
+        let picked = rfd::FileDialog::new()
+            .set_title("Import sound file")
+            .add_filter(
+                "Audio files",
+                &["mp3", "wav", "ogg", "flac", "m4a", "aac", "webm"],
+            )
+            .pick_files();
+
+        let files = match picked {
+            Some(files) if !files.is_empty() => files,
+            _ => {
+                return match ensure_global_settings(&app) {
+                    Ok(global_settings) => GlobalSettingsResponse {
+                        request_id,
+                        ok: true,
+                        global_settings: Some(global_settings),
+                        error: None,
+                    },
+                    Err(error) => GlobalSettingsResponse {
+                        request_id,
+                        ok: false,
+                        global_settings: None,
+                        error: Some(error),
+                    },
+                };
+            }
+        };
 
-    let files = match picked {
-        Some(files) if !files.is_empty() => files,
-        _ => {
-            return match ensure_global_settings(&app) {
-                Ok(global_settings) => GlobalSettingsResponse {
-                    request_id,
-                    ok: true,
-                    global_settings: Some(global_settings),
-                    error: None,
-                },
-                Err(error) => GlobalSettingsResponse {
+        let app_data_dir = match app.path().app_data_dir() {
+            Ok(dir) => dir,
+            Err(error) => {
+                return GlobalSettingsResponse {
                     request_id,
                     ok: false,
                     global_settings: None,
-                    error: Some(error),
-                },
-            };
-        }
-    };
+                    error: Some(format!("Failed to resolve app data directory: {error}")),
+                };
+            }
+        };
 
-    let app_data_dir = match app.path().app_data_dir() {
-        Ok(dir) => dir,
-        Err(error) => {
+        let sounds_dir = app_data_dir.join("sounds");
+        if let Err(error) = fs::create_dir_all(&sounds_dir) {
             return GlobalSettingsResponse {
                 request_id,
                 ok: false,
                 global_settings: None,
-                error: Some(format!("Failed to resolve app data directory: {error}")),
+                error: Some(format!("Failed to create sounds directory: {error}")),
             };
         }
-    };
 
-    let sounds_dir = app_data_dir.join("sounds");
-    if let Err(error) = fs::create_dir_all(&sounds_dir) {
-        return GlobalSettingsResponse {
-            request_id,
-            ok: false,
-            global_settings: None,
-            error: Some(format!("Failed to create sounds directory: {error}")),
+        let mut global_settings = match ensure_global_settings(&app) {
+            Ok(value) => value,
+            Err(error) => {
+                return GlobalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    global_settings: None,
+                    error: Some(error),
+                };
+            }
         };
-    }
 
-    let mut global_settings = match ensure_global_settings(&app) {
-        Ok(value) => value,
-        Err(error) => {
-            return GlobalSettingsResponse {
-                request_id,
-                ok: false,
-                global_settings: None,
-                error: Some(error),
+        let mut errors: Vec<String> = Vec::new();
+
+        for file_path in &files {
+            let original_name = file_path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or("sound");
+            let extension = file_path
+                .extension()
+                .and_then(|e| e.to_str())
+                .unwrap_or("mp3");
+
+            let file_size = match fs::metadata(file_path) {
+                Ok(meta) => meta.len(),
+                Err(error) => {
+                    errors.push(format!("Cannot read {original_name}: {error}"));
+                    continue;
+                }
             };
-        }
-    };
-
-    let mut errors: Vec<String> = Vec::new();
 
-    for file_path in &files {
-        let original_name = file_path
-            .file_name()
-            .and_then(|n| n.to_str())
-            .unwrap_or("sound");
-        let extension = file_path
-            .extension()
-            .and_then(|e| e.to_str())
-            .unwrap_or("mp3");
-
-        let file_size = match fs::metadata(file_path) {
-            Ok(meta) => meta.len(),
-            Err(error) => {
-                errors.push(format!("Cannot read {original_name}: {error}"));
+            if file_size > 10 * 1024 * 1024 {
+                errors.push(format!("{original_name}: file exceeds 10 MB limit"));
                 continue;
             }
-        };
 
-        if file_size > 10 * 1024 * 1024 {
-            errors.push(format!("{original_name}: file exceeds 10 MB limit"));
-            continue;
-        }
+            let id = uuid::Uuid::new_v4().to_string();
+            let dest_file_name = format!("{id}.{extension}");
+            let dest_path = sounds_dir.join(&dest_file_name);
+
+            if let Err(error) = fs::copy(file_path, &dest_path) {
+                errors.push(format!("Failed to copy {original_name}: {error}"));
+                continue;
+            }
 
-        let id = uuid::Uuid::new_v4().to_string();
-        let dest_file_name = format!("{id}.{extension}");
-        let dest_path = sounds_dir.join(&dest_file_name);
+            let display_name = original_name
+                .rsplit_once('.')
+                .map(|(name, _)| name)
+                .unwrap_or(original_name)
+                .to_string();
 
-        if let Err(error) = fs::copy(file_path, &dest_path) {
-            errors.push(format!("Failed to copy {original_name}: {error}"));
-            continue;
+            global_settings.sound_library.push(SoundLibraryEntry {
+                id,
+                name: display_name,
+                file_name: dest_file_name,
+            });
         }
 
-        let display_name = original_name
-            .rsplit_once('.')
-            .map(|(name, _)| name)
-            .unwrap_or(original_name)
-            .to_string();
-
-        global_settings.sound_library.push(SoundLibraryEntry {
-            id,
-            name: display_name,
-            file_name: dest_file_name,
-        });
-    }
+        let settings_file = match global_settings_file(&app) {
+            Ok(path) => path,
+            Err(error) => {
+                return GlobalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    global_settings: Some(global_settings),
+                    error: Some(error),
+                };
+            }
+        };
 
-    let settings_file = match global_settings_file(&app) {
-        Ok(path) => path,
-        Err(error) => {
+        if let Err(error) = write_global_settings_file(&settings_file, &global_settings) {
             return GlobalSettingsResponse {
                 request_id,
                 ok: false,
@@ -1098,91 +1743,96 @@ fn sound_library_import(app: AppHandle) -> GlobalSettingsResponse {
                 error: Some(error),
             };
         }
-    };
 
-    if let Err(error) = write_global_settings_file(&settings_file, &global_settings) {
-        return GlobalSettingsResponse {
+        let error = if errors.is_empty() {
+            None
+        } else {
+            Some(errors.join("; "))
+        };
+
+        GlobalSettingsResponse {
             request_id,
-            ok: false,
+            ok: true,
             global_settings: Some(global_settings),
-            error: Some(error),
-        };
-    }
-
-    let error = if errors.is_empty() {
-        None
-    } else {
-        Some(errors.join("; "))
-    };
-
-    GlobalSettingsResponse {
-        request_id,
-        ok: true,
-        global_settings: Some(global_settings),
-        error,
-    }
-}
+            error,
+        }
+    })
+}
 
 #[tauri::command]
-fn sound_library_remove(app: AppHandle, payload: SoundLibraryRemovePayload) -> GlobalSettingsResponse {
-    let request_id = request_id();
-
-    let mut global_settings = match ensure_global_settings(&app) {
-        Ok(value) => value,
-        Err(error) => {
-            return GlobalSettingsResponse {
-                request_id,
-                ok: false,
-                global_settings: None,
-                error: Some(error),
-            };
-        }
-    };
-
-    let removed_entry = global_settings
-        .sound_library
-        .iter()
-        .find(|entry| entry.id == payload.sound_id)
-        .cloned();
+fn sound_library_remove(
+    app: AppHandle,
+    payload: SoundLibraryRemovePayload,
+) -> GlobalSettingsResponse {
+    handle_command("sound_library_remove", |context| {
+        let request_id = context.request_id();
 
-    global_settings
-        .sound_library
-        .retain(|entry| entry.id != payload.sound_id);
+        let mut global_settings = match ensure_global_settings(&app) {
+            Ok(value) => value,
+            Err(error) => {
+                return GlobalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    global_settings: None,
+                    error: Some(error),
+                };
+            }
+        };
 
-    if let Some(entry) = &removed_entry {
-        if validate_sound_file_name(&entry.file_name).is_ok() {
-            if let Ok(app_data_dir) = app.path().app_data_dir() {
-                let sound_file = app_data_dir.join("sounds").join(&entry.file_name);
-                let _ = fs::remove_file(sound_file);
+        let removed_entry = global_settings
+            .sound_library
+            .iter()
+            .find(|entry| entry.id == payload.sound_id)
+            .cloned();
+
+        global_settings
+            .sound_library
+            .retain(|entry| entry.id != payload.sound_id);
+
+        if let Some(entry) = &removed_entry {
+            if validate_sound_file_name(&entry.file_name).is_ok() {
+                if let Ok(app_data_dir) = app.path().app_data_dir() {
+                    let sound_file = app_data_dir.join("sounds").join(&entry.file_name);
+                    let _ = fs::remove_file(sound_file);
+                }
             }
         }
-    }
 
-    let hook_settings = &mut global_settings.claude_code_sound_settings;
-    if hook_settings.notification.sound_id.as_deref() == Some(&payload.sound_id) {
-        hook_settings.notification.sound_id = None;
-    }
-    if hook_settings.stop.sound_id.as_deref() == Some(&payload.sound_id) {
-        hook_settings.stop.sound_id = None;
-    }
+        let hook_settings = &mut global_settings.claude_code_sound_settings;
+        if hook_settings.notification.sound_id.as_deref() == Some(&payload.sound_id) {
+            hook_settings.notification.sound_id = None;
+        }
+        if hook_settings.stop.sound_id.as_deref() == Some(&payload.sound_id) {
+            hook_settings.stop.sound_id = None;
+        }
 
-    let groove_hooks = &mut global_settings.groove_sound_settings;
-    for entry in [
-        &mut groove_hooks.play,
-        &mut groove_hooks.pause,
-        &mut groove_hooks.summary_start,
-        &mut groove_hooks.summary_end,
-        &mut groove_hooks.emergency,
-        &mut groove_hooks.remove,
-    ] {
-        if entry.sound_id.as_deref() == Some(&payload.sound_id) {
-            entry.sound_id = None;
+        let groove_hooks = &mut global_settings.groove_sound_settings;
+        for entry in [
+            &mut groove_hooks.play,
+            &mut groove_hooks.pause,
+            &mut groove_hooks.summary_start,
+            &mut groove_hooks.summary_end,
+            &mut groove_hooks.emergency,
+            &mut groove_hooks.remove,
+        ] {
+            if entry.sound_id.as_deref() == Some(&payload.sound_id) {
+                entry.sound_id = None;
+            }
         }
-    }
 
-    let settings_file = match global_settings_file(&app) {
-        Ok(path) => path,
-        Err(error) => {
+        let settings_file = match global_settings_file(&app) {
+            Ok(path) => path,
+            Err(error) => {
+                return GlobalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    global_settings: Some(global_settings),
+                    error: Some(error),
+                };
+            }
+        };
+
+        if let Err(error) = write_global_settings_file(&settings_file, &global_settings) {
             return GlobalSettingsResponse {
                 request_id,
                 ok: false,
@@ -1190,140 +1840,141 @@ fn sound_library_remove(app: AppHandle, payload: SoundLibraryRemovePayload) -> G
                 error: Some(error),
             };
         }
-    };
 
-    if let Err(error) = write_global_settings_file(&settings_file, &global_settings) {
-        return GlobalSettingsResponse {
+        GlobalSettingsResponse {
             request_id,
-            ok: false,
+            ok: true,
             global_settings: Some(global_settings),
-            error: Some(error),
-        };
-    }
-
-    GlobalSettingsResponse {
-        request_id,
-        ok: true,
-        global_settings: Some(global_settings),
-        error: None,
-    }
+            error: None,
+        }
+    })
 }
 
 #[tauri::command]
-fn sound_library_rename(app: AppHandle, payload: SoundLibraryRenamePayload) -> GlobalSettingsResponse {
-    let request_id = request_id();
-
-    let new_name = payload.new_name.trim().to_string();
-    if new_name.is_empty() {
-        return GlobalSettingsResponse {
-            request_id,
-            ok: false,
-            global_settings: None,
-            error: Some("Name must not be empty.".to_string()),
-        };
-    }
-
-    let mut global_settings = match ensure_global_settings(&app) {
-        Ok(value) => value,
-        Err(error) => {
-            return GlobalSettingsResponse {
-                request_id,
-                ok: false,
-                global_settings: None,
-                error: Some(error),
-            };
-        }
-    };
+fn sound_library_rename(
+    app: AppHandle,
+    payload: SoundLibraryRenamePayload,
+) -> GlobalSettingsResponse {
+    handle_command("sound_library_rename", |context| {
+        let request_id = context.request_id();
 
-    let app_data_dir = match app.path().app_data_dir() {
-        Ok(dir) => dir,
-        Err(error) => {
+        let new_name = payload.new_name.trim().to_string();
+        if new_name.is_empty() {
             return GlobalSettingsResponse {
                 request_id,
                 ok: false,
                 global_settings: None,
-                error: Some(format!("Failed to resolve app data directory: {error}")),
+                error: Some("Name must not be empty.".to_string()),
             };
         }
-    };
-    let sounds_dir = app_data_dir.join("sounds");
 
-    let entry = global_settings
-        .sound_library
-        .iter_mut()
-        .find(|e| e.id == payload.sound_id);
+        let mut global_settings = match ensure_global_settings(&app) {
+            Ok(value) => value,
+            Err(error) => {
+                return GlobalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    global_settings: None,
+                    error: Some(error),
+                };
+            }
+        };
 
-    match entry {
-        Some(e) => {
-            if let Err(reason) = validate_sound_file_name(&e.file_name) {
+        let app_data_dir = match app.path().app_data_dir() {
+            Ok(dir) => dir,
+            Err(error) => {
                 return GlobalSettingsResponse {
                     request_id,
                     ok: false,
                     global_settings: None,
-                    error: Some(format!(
-                        "Stored sound file name is unsafe: {reason}"
-                    )),
+                    error: Some(format!("Failed to resolve app data directory: {error}")),
                 };
             }
+        };
+        let sounds_dir = app_data_dir.join("sounds");
 
-            let extension = e
-                .file_name
-                .rsplit_once('.')
-                .map(|(_, ext)| ext.to_string())
-                .unwrap_or_else(|| "wav".to_string());
+        let entry = global_settings
+            .sound_library
+            .iter_mut()
+            .find(|e| e.id == payload.sound_id);
 
-            let base_name = match sanitize_sound_file_name(&new_name, &e.id) {
-                Ok(name) => name,
-                Err(reason) => {
+        match entry {
+            Some(e) => {
+                if let Err(reason) = validate_sound_file_name(&e.file_name) {
                     return GlobalSettingsResponse {
                         request_id,
                         ok: false,
-                        global_settings: Some(global_settings),
-                        error: Some(reason),
+                        global_settings: None,
+                        error: Some(format!("Stored sound file name is unsafe: {reason}")),
                     };
                 }
-            };
 
-            // Build new file name, handling conflicts
-            let mut new_file_name = format!("{base_name}.{extension}");
-            let mut counter = 1u32;
-            while sounds_dir.join(&new_file_name).exists()
-                && new_file_name != e.file_name
-            {
-                new_file_name = format!("{base_name} ({counter}).{extension}");
-                counter += 1;
-            }
+                let extension = e
+                    .file_name
+                    .rsplit_once('.')
+                    .map(|(_, ext)| ext.to_string())
+                    .unwrap_or_else(|| "wav".to_string());
+
+                let base_name = match sanitize_sound_file_name(&new_name, &e.id) {
+                    Ok(name) => name,
+                    Err(reason) => {
+                        return GlobalSettingsResponse {
+                            request_id,
+                            ok: false,
+                            global_settings: Some(global_settings),
+                            error: Some(reason),
+                        };
+                    }
+                };
 
-            // Rename physical file
-            let old_path = sounds_dir.join(&e.file_name);
-            let new_path = sounds_dir.join(&new_file_name);
-            if old_path.is_file() && new_file_name != e.file_name {
-                if let Err(error) = fs::rename(&old_path, &new_path) {
-                    return GlobalSettingsResponse {
-                        request_id,
-                        ok: false,
-                        global_settings: Some(global_settings),
-                        error: Some(format!("Failed to rename sound file: {error}")),
-                    };
+                // Build new file name, handling conflicts
+                let mut new_file_name = format!("{base_name}.{extension}");
+                let mut counter = 1u32;
+                while sounds_dir.join(&new_file_name).exists() && new_file_name != e.file_name {
+                    new_file_name = format!("{base_name} ({counter}).{extension}");
+                    counter += 1;
                 }
-            }
 
-            e.name = new_name;
-            e.file_name = new_file_name;
-        }
-        None => {
-            return GlobalSettingsResponse {
-                request_id,
-                ok: false,
-                global_settings: Some(global_settings),
-                error: Some("Sound not found in library.".to_string()),
-            };
+                // Rename physical file
+                let old_path = sounds_dir.join(&e.file_name);
+                let new_path = sounds_dir.join(&new_file_name);
+                if old_path.is_file() && new_file_name != e.file_name {
+                    if let Err(error) = fs::rename(&old_path, &new_path) {
+                        return GlobalSettingsResponse {
+                            request_id,
+                            ok: false,
+                            global_settings: Some(global_settings),
+                            error: Some(format!("Failed to rename sound file: {error}")),
+                        };
+                    }
+                }
+
+                e.name = new_name;
+                e.file_name = new_file_name;
+            }
+            None => {
+                return GlobalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    global_settings: Some(global_settings),
+                    error: Some("Sound not found in library.".to_string()),
+                };
+            }
         }
-    }
 
-    let settings_file = match global_settings_file(&app) {
-        Ok(path) => path,
-        Err(error) => {
+        let settings_file = match global_settings_file(&app) {
+            Ok(path) => path,
+            Err(error) => {
+                return GlobalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    global_settings: Some(global_settings),
+                    error: Some(error),
+                };
+            }
+        };
+
+        if let Err(error) = write_global_settings_file(&settings_file, &global_settings) {
             return GlobalSettingsResponse {
                 request_id,
                 ok: false,
@@ -1331,291 +1982,301 @@ fn sound_library_rename(app: AppHandle, payload: SoundLibraryRenamePayload) -> G
                 error: Some(error),
             };
         }
-    };
 
-    if let Err(error) = write_global_settings_file(&settings_file, &global_settings) {
-        return GlobalSettingsResponse {
+        GlobalSettingsResponse {
             request_id,
-            ok: false,
+            ok: true,
             global_settings: Some(global_settings),
-            error: Some(error),
+            error: None,
+        }
+    })
+}
+
+#[tauri::command]
+fn sound_library_get_path(
+    app: AppHandle,
+    payload: SoundLibraryGetPathPayload,
+) -> SoundLibraryPathResponse {
+    handle_command("sound_library_get_path", |context| {
+        let request_id = context.request_id();
+
+        let global_settings = match ensure_global_settings(&app) {
+            Ok(value) => value,
+            Err(error) => {
+                return SoundLibraryPathResponse {
+                    request_id,
+                    ok: false,
+                    folder_path: None,
+                    file_path: None,
+                    error: Some(error),
+                };
+            }
         };
-    }
 
-    GlobalSettingsResponse {
-        request_id,
-        ok: true,
-        global_settings: Some(global_settings),
-        error: None,
-    }
+        let entry = global_settings
+            .sound_library
+            .iter()
+            .find(|e| e.id == payload.sound_id);
+
+        let entry = match entry {
+            Some(e) => e,
+            None => {
+                return SoundLibraryPathResponse {
+                    request_id,
+                    ok: false,
+                    folder_path: None,
+                    file_path: None,
+                    error: Some("Sound not found in library.".to_string()),
+                };
+            }
+        };
+
+        let app_data_dir = match app.path().app_data_dir() {
+            Ok(dir) => dir,
+            Err(error) => {
+                return SoundLibraryPathResponse {
+                    request_id,
+                    ok: false,
+                    folder_path: None,
+                    file_path: None,
+                    error: Some(format!("Failed to resolve app data directory: {error}")),
+                };
+            }
+        };
+
+        let sounds_dir = app_data_dir.join("sounds");
+        let sound_file = sounds_dir.join(&entry.file_name);
+
+        SoundLibraryPathResponse {
+            request_id,
+            ok: true,
+            folder_path: Some(sounds_dir.to_string_lossy().to_string()),
+            file_path: Some(sound_file.to_string_lossy().to_string()),
+            error: None,
+        }
+    })
 }
 
 #[tauri::command]
-fn sound_library_get_path(app: AppHandle, payload: SoundLibraryGetPathPayload) -> SoundLibraryPathResponse {
-    let request_id = request_id();
+fn sound_library_open_directory(app: AppHandle) -> SoundLibraryPathResponse {
+    handle_command("sound_library_open_directory", |context| {
+        let request_id = context.request_id();
 
-    let global_settings = match ensure_global_settings(&app) {
-        Ok(value) => value,
-        Err(error) => {
+        let app_data_dir = match app.path().app_data_dir() {
+            Ok(dir) => dir,
+            Err(error) => {
+                return SoundLibraryPathResponse {
+                    request_id,
+                    ok: false,
+                    folder_path: None,
+                    file_path: None,
+                    error: Some(format!("Failed to resolve app data directory: {error}")),
+                };
+            }
+        };
+
+        let sounds_dir = app_data_dir.join("sounds");
+        if let Err(error) = fs::create_dir_all(&sounds_dir) {
             return SoundLibraryPathResponse {
                 request_id,
                 ok: false,
                 folder_path: None,
                 file_path: None,
-                error: Some(error),
+                error: Some(format!("Failed to create sounds directory: {error}")),
             };
         }
-    };
 
-    let entry = global_settings
-        .sound_library
-        .iter()
-        .find(|e| e.id == payload.sound_id);
+        let dir_str = sounds_dir.to_string_lossy().to_string();
+        let _ = std::process::Command::new("xdg-open")
+            .arg(&dir_str)
+            .spawn()
+            .or_else(|_| std::process::Command::new("open").arg(&dir_str).spawn());
 
-    let entry = match entry {
-        Some(e) => e,
-        None => {
-            return SoundLibraryPathResponse {
+        SoundLibraryPathResponse {
+            request_id,
+            ok: true,
+            folder_path: Some(dir_str),
+            file_path: None,
+            error: None,
+        }
+    })
+}
+
+#[tauri::command]
+fn workspace_open_directory(path: String) -> WorkspaceOpenDirectoryResponse {
+    handle_command("workspace_open_directory", |context| {
+        let request_id = context.request_id();
+        let trimmed = path.trim();
+
+        if trimmed.is_empty() {
+            return WorkspaceOpenDirectoryResponse {
                 request_id,
                 ok: false,
-                folder_path: None,
-                file_path: None,
-                error: Some("Sound not found in library.".to_string()),
+                error: Some("Directory path must not be empty.".to_string()),
             };
         }
-    };
 
-    let app_data_dir = match app.path().app_data_dir() {
-        Ok(dir) => dir,
-        Err(error) => {
-            return SoundLibraryPathResponse {
+        let dir = std::path::Path::new(trimmed);
+        if !dir.is_dir() {
+            return WorkspaceOpenDirectoryResponse {
                 request_id,
                 ok: false,
-                folder_path: None,
-                file_path: None,
-                error: Some(format!("Failed to resolve app data directory: {error}")),
+                error: Some("Directory does not exist.".to_string()),
             };
         }
-    };
-
-    let sounds_dir = app_data_dir.join("sounds");
-    let sound_file = sounds_dir.join(&entry.file_name);
 
-    SoundLibraryPathResponse {
-        request_id,
-        ok: true,
-        folder_path: Some(sounds_dir.to_string_lossy().to_string()),
-        file_path: Some(sound_file.to_string_lossy().to_string()),
-        error: None,
-    }
+        match crate::backend::common::platform_env::open_path_in_file_manager(dir) {
+            Ok(()) => WorkspaceOpenDirectoryResponse {
+                request_id,
+                ok: true,
+                error: None,
+            },
+            Err(error) => WorkspaceOpenDirectoryResponse {
+                request_id,
+                ok: false,
+                error: Some(error),
+            },
+        }
+    })
 }
 
 #[tauri::command]
-fn sound_library_open_directory(app: AppHandle) -> SoundLibraryPathResponse {
-    let request_id = request_id();
+fn workspace_update_terminal_settings(
+    app: AppHandle,
+    payload: WorkspaceTerminalSettingsPayload,
+) -> WorkspaceTerminalSettingsResponse {
+    handle_command("workspace_update_terminal_settings", |context| {
+        let request_id = context.request_id();
 
-    let app_data_dir = match app.path().app_data_dir() {
-        Ok(dir) => dir,
-        Err(error) => {
-            return SoundLibraryPathResponse {
+        let default_terminal = match normalize_default_terminal(&payload.default_terminal) {
+            Ok(value) => value,
+            Err(error) => {
+                return WorkspaceTerminalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    workspace_meta: None,
+                    error: Some(error),
+                }
+            }
+        };
+
+        let terminal_custom_command = payload
+            .terminal_custom_command
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(|value| value.to_string());
+
+        if default_terminal == "custom" && terminal_custom_command.is_none() {
+            return WorkspaceTerminalSettingsResponse {
                 request_id,
                 ok: false,
-                folder_path: None,
-                file_path: None,
-                error: Some(format!("Failed to resolve app data directory: {error}")),
+                workspace_root: None,
+                workspace_meta: None,
+                error: Some(
+                    "terminalCustomCommand is required when defaultTerminal is set to custom."
+                        .to_string(),
+                ),
             };
         }
-    };
-
-    let sounds_dir = app_data_dir.join("sounds");
-    if let Err(error) = fs::create_dir_all(&sounds_dir) {
-        return SoundLibraryPathResponse {
-            request_id,
-            ok: false,
-            folder_path: None,
-            file_path: None,
-            error: Some(format!("Failed to create sounds directory: {error}")),
-        };
-    }
 
-    let dir_str = sounds_dir.to_string_lossy().to_string();
-    let _ = std::process::Command::new("xdg-open")
-        .arg(&dir_str)
-        .spawn()
-        .or_else(|_| std::process::Command::new("open").arg(&dir_str).spawn());
-
-    SoundLibraryPathResponse {
-        request_id,
-        ok: true,
-        folder_path: Some(dir_str),
-        file_path: None,
-        error: None,
-    }
-}
-
-#[tauri::command]
-fn workspace_open_directory(path: String) -> WorkspaceOpenDirectoryResponse {
-    let request_id = request_id();
-    let trimmed = path.trim();
-
-    if trimmed.is_empty() {
-        return WorkspaceOpenDirectoryResponse {
-            request_id,
-            ok: false,
-            error: Some("Directory path must not be empty.".to_string()),
+        let persisted_root = match read_persisted_active_workspace_root(&app) {
+            Ok(Some(value)) => value,
+            Ok(None) => {
+                return WorkspaceTerminalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    workspace_meta: None,
+                    error: Some("No active workspace selected.".to_string()),
+                }
+            }
+            Err(error) => {
+                return WorkspaceTerminalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    workspace_meta: None,
+                    error: Some(error),
+                }
+            }
         };
-    }
 
-    let dir = std::path::Path::new(trimmed);
-    if !dir.is_dir() {
-        return WorkspaceOpenDirectoryResponse {
-            request_id,
-            ok: false,
-            error: Some("Directory does not exist.".to_string()),
+        let workspace_root = match validate_workspace_root_path(&persisted_root) {
+            Ok(root) => root,
+            Err(error) => {
+                return WorkspaceTerminalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: Some(persisted_root),
+                    workspace_meta: None,
+                    error: Some(error),
+                }
+            }
         };
-    }
-
-    match crate::backend::common::platform_env::open_path_in_file_manager(dir) {
-        Ok(()) => WorkspaceOpenDirectoryResponse {
-            request_id,
-            ok: true,
-            error: None,
-        },
-        Err(error) => WorkspaceOpenDirectoryResponse {
-            request_id,
-            ok: false,
-            error: Some(error),
-        },
-    }
-}
-
-#[tauri::command]
-fn workspace_update_terminal_settings(
-    app: AppHandle,
-    payload: WorkspaceTerminalSettingsPayload,
-) -> WorkspaceTerminalSettingsResponse {
-    let request_id = request_id();
 
-    let default_terminal = match normalize_default_terminal(&payload.default_terminal) {
-        Ok(value) => value,
-        Err(error) => {
-            return WorkspaceTerminalSettingsResponse {
-                request_id,
-                ok: false,
-                workspace_root: None,
-                workspace_meta: None,
-                error: Some(error),
+        let (mut workspace_meta, _) = match ensure_workspace_meta(&workspace_root) {
+            Ok(result) => result,
+            Err(error) => {
+                return WorkspaceTerminalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: Some(workspace_root.display().to_string()),
+                    workspace_meta: None,
+                    error: Some(error),
+                }
             }
-        }
-    };
-
-    let terminal_custom_command = payload
-        .terminal_custom_command
-        .as_deref()
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-        .map(|value| value.to_string());
-
-    if default_terminal == "custom" && terminal_custom_command.is_none() {
-        return WorkspaceTerminalSettingsResponse {
-            request_id,
-            ok: false,
-            workspace_root: None,
-            workspace_meta: None,
-            error: Some(
-                "terminalCustomCommand is required when defaultTerminal is set to custom."
-                    .to_string(),
-            ),
         };
-    }
 
-    let persisted_root = match read_persisted_active_workspace_root(&app) {
-        Ok(Some(value)) => value,
-        Ok(None) => {
-            return WorkspaceTerminalSettingsResponse {
-                request_id,
-                ok: false,
-                workspace_root: None,
-                workspace_meta: None,
-                error: Some("No active workspace selected.".to_string()),
-            }
+        let previous_meta = workspace_meta.clone();
+        workspace_meta.default_terminal = default_terminal;
+        workspace_meta.terminal_custom_command = terminal_custom_command;
+        if let Some(telemetry_enabled) = payload.telemetry_enabled {
+            workspace_meta.telemetry_enabled = telemetry_enabled;
         }
-        Err(error) => {
-            return WorkspaceTerminalSettingsResponse {
-                request_id,
-                ok: false,
-                workspace_root: None,
-                workspace_meta: None,
-                error: Some(error),
-            }
+        if let Some(disable_groove_business) = payload.disable_groove_business {
+            workspace_meta.disable_groove_business = disable_groove_business;
         }
-    };
-
-    let workspace_root = match validate_workspace_root_path(&persisted_root) {
-        Ok(root) => root,
-        Err(error) => {
-            return WorkspaceTerminalSettingsResponse {
-                request_id,
-                ok: false,
-                workspace_root: Some(persisted_root),
-                workspace_meta: None,
-                error: Some(error),
-            }
+        if let Some(hide_mascot) = payload.hide_mascot {
+            workspace_meta.hide_mascot = hide_mascot;
         }
-    };
+        if let Some(hide_labels) = payload.hide_labels {
+            workspace_meta.hide_labels = hide_labels;
+        }
+        if let Some(show_fps) = payload.show_fps {
+            workspace_meta.show_fps = show_fps;
+        }
+        workspace_meta.updated_at = now_iso();
 
-    let (mut workspace_meta, _) = match ensure_workspace_meta(&workspace_root) {
-        Ok(result) => result,
-        Err(error) => {
+        let workspace_json = workspace_root.join(".groove").join("workspace.json");
+        if let Err(error) = write_workspace_meta_file(&workspace_json, &workspace_meta) {
             return WorkspaceTerminalSettingsResponse {
                 request_id,
                 ok: false,
                 workspace_root: Some(workspace_root.display().to_string()),
                 workspace_meta: None,
                 error: Some(error),
-            }
+            };
         }
-    };
 
-    workspace_meta.default_terminal = default_terminal;
-    workspace_meta.terminal_custom_command = terminal_custom_command;
-    if let Some(telemetry_enabled) = payload.telemetry_enabled {
-        workspace_meta.telemetry_enabled = telemetry_enabled;
-    }
-    if let Some(disable_groove_business) = payload.disable_groove_business {
-        workspace_meta.disable_groove_business = disable_groove_business;
-    }
-    if let Some(hide_mascot) = payload.hide_mascot {
-        workspace_meta.hide_mascot = hide_mascot;
-    }
-    if let Some(hide_labels) = payload.hide_labels {
-        workspace_meta.hide_labels = hide_labels;
-    }
-    if let Some(show_fps) = payload.show_fps {
-        workspace_meta.show_fps = show_fps;
-    }
-    workspace_meta.updated_at = now_iso();
+        invalidate_workspace_caches(&app, &workspace_root);
+        push_undo_entry(
+            &app,
+            UndoTarget::Workspace(workspace_root.clone()),
+            "workspace_update_terminal_settings",
+            "Revert terminal settings".to_string(),
+            changed_fields(&previous_meta, &workspace_meta),
+        );
 
-    let workspace_json = workspace_root.join(".groove").join("workspace.json");
-    if let Err(error) = write_workspace_meta_file(&workspace_json, &workspace_meta) {
-        return WorkspaceTerminalSettingsResponse {
+        WorkspaceTerminalSettingsResponse {
             request_id,
-            ok: false,
+            ok: true,
             workspace_root: Some(workspace_root.display().to_string()),
-            workspace_meta: None,
-            error: Some(error),
-        };
-    }
-
-    invalidate_workspace_context_cache(&app, &workspace_root);
-
-    WorkspaceTerminalSettingsResponse {
-        request_id,
-        ok: true,
-        workspace_root: Some(workspace_root.display().to_string()),
-        workspace_meta: Some(workspace_meta),
-        error: None,
-    }
+            workspace_meta: Some(workspace_meta),
+            error: None,
+        }
+    })
 }
 
 #[tauri::command]
@@ -1623,11 +2284,36 @@ fn workspace_update_root_directory(
     app: AppHandle,
     payload: WorkspaceRootDirectoryPayload,
 ) -> WorkspaceTerminalSettingsResponse {
-    let request_id = request_id();
+    handle_command("workspace_update_root_directory", |context| {
+        let request_id = context.request_id();
+
+        let normalized_root_directory = match payload.root_directory.as_deref() {
+            Some(value) => match validate_root_directory_value(value) {
+                Ok(normalized) => normalized,
+                Err(error) => {
+                    return WorkspaceTerminalSettingsResponse {
+                        request_id,
+                        ok: false,
+                        workspace_root: None,
+                        workspace_meta: None,
+                        error: Some(error),
+                    }
+                }
+            },
+            None => None,
+        };
 
-    let normalized_root_directory = match payload.root_directory.as_deref() {
-        Some(value) => match validate_root_directory_value(value) {
-            Ok(normalized) => normalized,
+        let persisted_root = match read_persisted_active_workspace_root(&app) {
+            Ok(Some(value)) => value,
+            Ok(None) => {
+                return WorkspaceTerminalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    workspace_meta: None,
+                    error: Some("No active workspace selected.".to_string()),
+                }
+            }
             Err(error) => {
                 return WorkspaceTerminalSettingsResponse {
                     request_id,
@@ -1637,98 +2323,74 @@ fn workspace_update_root_directory(
                     error: Some(error),
                 }
             }
-        },
-        None => None,
-    };
+        };
 
-    let persisted_root = match read_persisted_active_workspace_root(&app) {
-        Ok(Some(value)) => value,
-        Ok(None) => {
-            return WorkspaceTerminalSettingsResponse {
-                request_id,
-                ok: false,
-                workspace_root: None,
-                workspace_meta: None,
-                error: Some("No active workspace selected.".to_string()),
+        let workspace_root = match validate_workspace_root_path(&persisted_root) {
+            Ok(root) => root,
+            Err(error) => {
+                return WorkspaceTerminalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: Some(persisted_root),
+                    workspace_meta: None,
+                    error: Some(error),
+                }
             }
-        }
-        Err(error) => {
-            return WorkspaceTerminalSettingsResponse {
-                request_id,
-                ok: false,
-                workspace_root: None,
-                workspace_meta: None,
-                error: Some(error),
+        };
+
+        if let Some(ref relative) = normalized_root_directory {
+            let candidate = workspace_root.join(relative);
+            if !path_is_directory(&candidate) {
+                return WorkspaceTerminalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: Some(workspace_root.display().to_string()),
+                    workspace_meta: None,
+                    error: Some(format!(
+                        "rootDirectory \"{}\" does not exist under the workspace root.",
+                        relative
+                    )),
+                };
             }
         }
-    };
 
-    let workspace_root = match validate_workspace_root_path(&persisted_root) {
-        Ok(root) => root,
-        Err(error) => {
-            return WorkspaceTerminalSettingsResponse {
-                request_id,
-                ok: false,
-                workspace_root: Some(persisted_root),
-                workspace_meta: None,
-                error: Some(error),
+        let (mut workspace_meta, _) = match ensure_workspace_meta(&workspace_root) {
+            Ok(result) => result,
+            Err(error) => {
+                return WorkspaceTerminalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: Some(workspace_root.display().to_string()),
+                    workspace_meta: None,
+                    error: Some(error),
+                }
             }
-        }
-    };
+        };
 
-    if let Some(ref relative) = normalized_root_directory {
-        let candidate = workspace_root.join(relative);
-        if !path_is_directory(&candidate) {
-            return WorkspaceTerminalSettingsResponse {
-                request_id,
-                ok: false,
-                workspace_root: Some(workspace_root.display().to_string()),
-                workspace_meta: None,
-                error: Some(format!(
-                    "rootDirectory \"{}\" does not exist under the workspace root.",
-                    relative
-                )),
-            };
-        }
-    }
+        workspace_meta.root_directory = normalized_root_directory;
+        workspace_meta.updated_at = now_iso();
 
-    let (mut workspace_meta, _) = match ensure_workspace_meta(&workspace_root) {
-        Ok(result) => result,
-        Err(error) => {
+        let workspace_json = workspace_root.join(".groove").join("workspace.json");
+        if let Err(error) = write_workspace_meta_file(&workspace_json, &workspace_meta) {
             return WorkspaceTerminalSettingsResponse {
                 request_id,
                 ok: false,
                 workspace_root: Some(workspace_root.display().to_string()),
                 workspace_meta: None,
                 error: Some(error),
-            }
+            };
         }
-    };
 
-    workspace_meta.root_directory = normalized_root_directory;
-    workspace_meta.updated_at = now_iso();
+        invalidate_workspace_caches(&app, &workspace_root);
 
-    let workspace_json = workspace_root.join(".groove").join("workspace.json");
-    if let Err(error) = write_workspace_meta_file(&workspace_json, &workspace_meta) {
-        return WorkspaceTerminalSettingsResponse {
+        WorkspaceTerminalSettingsResponse {
             request_id,
-            ok: false,
+            ok: true,
             workspace_root: Some(workspace_root.display().to_string()),
-            workspace_meta: None,
-            error: Some(error),
-        };
-    }
-
-    invalidate_workspace_context_cache(&app, &workspace_root);
-    invalidate_groove_list_cache_for_workspace(&app, &workspace_root);
-
-    WorkspaceTerminalSettingsResponse {
-        request_id,
-        ok: true,
-        workspace_root: Some(workspace_root.display().to_string()),
-        workspace_meta: Some(workspace_meta),
-        error: None,
-    }
+            workspace_meta: Some(workspace_meta),
+            error: None,
+        }
+    })
 }
 
 #[tauri::command]
@@ -1736,107 +2398,213 @@ fn workspace_update_commands_settings(
     app: AppHandle,
     payload: WorkspaceCommandSettingsPayload,
 ) -> WorkspaceTerminalSettingsResponse {
-    let request_id = request_id();
-
-    let play_groove_command = match normalize_play_groove_command(&payload.play_groove_command) {
-        Ok(value) => value,
-        Err(error) => {
-            return WorkspaceTerminalSettingsResponse {
-                request_id,
-                ok: false,
-                workspace_root: None,
-                workspace_meta: None,
-                error: Some(error),
+    handle_command("workspace_update_commands_settings", |context| {
+        let request_id = context.request_id();
+
+        let play_groove_command =
+            match normalize_saved_play_groove_command(&payload.play_groove_command) {
+                Ok(value) => value,
+                Err(error) => {
+                    return WorkspaceTerminalSettingsResponse {
+                        request_id,
+                        ok: false,
+                        workspace_root: None,
+                        workspace_meta: None,
+                        error: Some(error),
+                    }
+                }
+            };
+        let open_terminal_at_worktree_command = match normalize_open_terminal_at_worktree_command(
+            payload.open_terminal_at_worktree_command.as_deref(),
+        ) {
+            Ok(value) => value,
+            Err(error) => {
+                return WorkspaceTerminalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    workspace_meta: None,
+                    error: Some(error),
+                }
             }
-        }
-    };
-    let open_terminal_at_worktree_command = match normalize_open_terminal_at_worktree_command(
-        payload.open_terminal_at_worktree_command.as_deref(),
-    ) {
-        Ok(value) => value,
-        Err(error) => {
-            return WorkspaceTerminalSettingsResponse {
-                request_id,
-                ok: false,
-                workspace_root: None,
-                workspace_meta: None,
-                error: Some(error),
+        };
+        let persisted_root = match read_persisted_active_workspace_root(&app) {
+            Ok(Some(value)) => value,
+            Ok(None) => {
+                return WorkspaceTerminalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    workspace_meta: None,
+                    error: Some("No active workspace selected.".to_string()),
+                }
             }
-        }
-    };
-    let persisted_root = match read_persisted_active_workspace_root(&app) {
-        Ok(Some(value)) => value,
-        Ok(None) => {
-            return WorkspaceTerminalSettingsResponse {
-                request_id,
-                ok: false,
-                workspace_root: None,
-                workspace_meta: None,
-                error: Some("No active workspace selected.".to_string()),
+            Err(error) => {
+                return WorkspaceTerminalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    workspace_meta: None,
+                    error: Some(error),
+                }
             }
-        }
-        Err(error) => {
-            return WorkspaceTerminalSettingsResponse {
-                request_id,
-                ok: false,
-                workspace_root: None,
-                workspace_meta: None,
-                error: Some(error),
+        };
+
+        let workspace_root = match validate_workspace_root_path(&persisted_root) {
+            Ok(root) => root,
+            Err(error) => {
+                return WorkspaceTerminalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: Some(persisted_root),
+                    workspace_meta: None,
+                    error: Some(error),
+                }
             }
-        }
-    };
+        };
 
-    let workspace_root = match validate_workspace_root_path(&persisted_root) {
-        Ok(root) => root,
-        Err(error) => {
-            return WorkspaceTerminalSettingsResponse {
-                request_id,
-                ok: false,
-                workspace_root: Some(persisted_root),
-                workspace_meta: None,
-                error: Some(error),
+        let (mut workspace_meta, _) = match ensure_workspace_meta(&workspace_root) {
+            Ok(result) => result,
+            Err(error) => {
+                return WorkspaceTerminalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: Some(workspace_root.display().to_string()),
+                    workspace_meta: None,
+                    error: Some(error),
+                }
             }
-        }
-    };
+        };
 
-    let (mut workspace_meta, _) = match ensure_workspace_meta(&workspace_root) {
-        Ok(result) => result,
-        Err(error) => {
+        let previous_meta = workspace_meta.clone();
+        workspace_meta.play_groove_command = play_groove_command;
+        workspace_meta.open_terminal_at_worktree_command = open_terminal_at_worktree_command;
+        workspace_meta.onboarding_commands_configured = true;
+        workspace_meta.updated_at = now_iso();
+
+        let workspace_json = workspace_root.join(".groove").join("workspace.json");
+        if let Err(error) = write_workspace_meta_file(&workspace_json, &workspace_meta) {
             return WorkspaceTerminalSettingsResponse {
                 request_id,
                 ok: false,
                 workspace_root: Some(workspace_root.display().to_string()),
                 workspace_meta: None,
                 error: Some(error),
-            }
+            };
         }
-    };
 
-    workspace_meta.play_groove_command = play_groove_command;
-    workspace_meta.open_terminal_at_worktree_command = open_terminal_at_worktree_command;
-    workspace_meta.onboarding_commands_configured = true;
-    workspace_meta.updated_at = now_iso();
+        invalidate_workspace_caches(&app, &workspace_root);
+        push_undo_entry(
+            &app,
+            UndoTarget::Workspace(workspace_root.clone()),
+            "workspace_update_commands_settings",
+            "Revert play and open-terminal commands".to_string(),
+            changed_fields(&previous_meta, &workspace_meta),
+        );
 
-    let workspace_json = workspace_root.join(".groove").join("workspace.json");
-    if let Err(error) = write_workspace_meta_file(&workspace_json, &workspace_meta) {
-        return WorkspaceTerminalSettingsResponse {
+        WorkspaceTerminalSettingsResponse {
             request_id,
-            ok: false,
+            ok: true,
             workspace_root: Some(workspace_root.display().to_string()),
-            workspace_meta: None,
-            error: Some(error),
-        };
+            workspace_meta: Some(workspace_meta),
+            error: None,
+        }
+    })
+}
+
+/// Resolves a Play command template for a worktree of the active workspace
+/// the way Play would, so the argv can be checked before the template is
+/// saved. Nothing is run.
+#[tauri::command]
+fn play_command_preview(
+    app: AppHandle,
+    payload: PlayCommandPreviewPayload,
+) -> PlayCommandPreviewResponse {
+    handle_command("play_command_preview", |context| {
+        let request_id = context.request_id();
+        resolve_play_command_preview(&app, &payload, request_id.clone()).unwrap_or_else(|error| {
+            PlayCommandPreviewResponse {
+                request_id,
+                ok: false,
+                mode: None,
+                argv: Vec::new(),
+                worktree_path: None,
+                branch: None,
+                target_appended: false,
+                error: Some(error),
+            }
+        })
+    })
+}
+
+fn resolve_play_command_preview(
+    app: &AppHandle,
+    payload: &PlayCommandPreviewPayload,
+    request_id: String,
+) -> Result<PlayCommandPreviewResponse, String> {
+    let command = normalize_saved_play_groove_command(&payload.play_groove_command)
+        .and_then(|command| expand_play_groove_command_preset(&command))?;
+    let in_app_mode = if is_groove_terminal_play_command(&command) {
+        Some("groove-terminal")
+    } else if is_groove_tmux_command(&command) {
+        Some("tmux")
+    } else {
+        None
+    };
+    if let Some(mode) = in_app_mode {
+        return Ok(PlayCommandPreviewResponse {
+            request_id,
+            ok: true,
+            mode: Some(mode.to_string()),
+            argv: Vec::new(),
+            worktree_path: None,
+            branch: None,
+            target_appended: false,
+            error: None,
+        });
     }
 
-    invalidate_workspace_context_cache(&app, &workspace_root);
+    let worktree = payload
+        .worktree
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .unwrap_or(PLAY_COMMAND_PREVIEW_SAMPLE_WORKTREE);
+    if !is_safe_path_token(worktree) {
+        return Err("worktree contains unsafe characters or path segments.".to_string());
+    }
+    let workspace_root = active_workspace_root_from_state(app)?;
+    let effective_root = ensure_workspace_meta(&workspace_root)
+        .map(|(meta, _)| effective_workspace_root(&workspace_root, &meta))
+        .unwrap_or(workspace_root);
+    let worktree_path = resolve_worktree_path_for_candidates(
+        &effective_root,
+        ".worktrees",
+        &worktree_path_token_candidates(worktree),
+    )
+    .unwrap_or_else(|| effective_root.join(".worktrees").join(worktree));
+    let branch = resolve_branch_from_worktree(&worktree_path);
+    let target = payload
+        .target
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .or(branch.as_deref())
+        .unwrap_or(worktree)
+        .to_string();
+    let (program, args, target_appended) =
+        resolve_play_groove_command(&command, &target, branch.as_deref(), &worktree_path)?;
 
-    WorkspaceTerminalSettingsResponse {
+    Ok(PlayCommandPreviewResponse {
         request_id,
         ok: true,
-        workspace_root: Some(workspace_root.display().to_string()),
-        workspace_meta: Some(workspace_meta),
+        mode: Some("command".to_string()),
+        argv: std::iter::once(program).chain(args).collect(),
+        worktree_path: Some(worktree_path.display().to_string()),
+        branch,
+        target_appended,
         error: None,
-    }
+    })
 }
 
 #[tauri::command]
@@ -1844,390 +2612,2134 @@ fn workspace_update_max_worktree_count(
     app: AppHandle,
     payload: WorkspaceMaxWorktreeCountPayload,
 ) -> WorkspaceMaxWorktreeCountResponse {
-    let request_id = request_id();
+    handle_command("workspace_update_max_worktree_count", |context| {
+        let request_id = context.request_id();
 
-    let max_error = |workspace_root: Option<String>, error: String| {
-        WorkspaceMaxWorktreeCountResponse {
-            request_id: request_id.clone(),
-            ok: false,
-            workspace_root,
-            workspace_meta: None,
-            evicted_worktrees: Vec::new(),
-            error: Some(error),
-        }
-    };
+        let max_error =
+            |workspace_root: Option<String>, error: String| WorkspaceMaxWorktreeCountResponse {
+                request_id: request_id.clone(),
+                ok: false,
+                workspace_root,
+                workspace_meta: None,
+                evicted_worktrees: Vec::new(),
+                error: Some(error),
+            };
 
-    // Treat 0 the same as "unlimited" so the UI can clear the cap with either.
-    let max_worktree_count = payload.max_worktree_count.filter(|value| *value > 0);
+        // Treat 0 the same as "unlimited" so the UI can clear the cap with either.
+        let max_worktree_count = payload.max_worktree_count.filter(|value| *value > 0);
 
-    let persisted_root = match read_persisted_active_workspace_root(&app) {
-        Ok(Some(value)) => value,
-        Ok(None) => return max_error(None, "No active workspace selected.".to_string()),
-        Err(error) => return max_error(None, error),
-    };
+        let persisted_root = match read_persisted_active_workspace_root(&app) {
+            Ok(Some(value)) => value,
+            Ok(None) => return max_error(None, "No active workspace selected.".to_string()),
+            Err(error) => return max_error(None, error),
+        };
 
-    let workspace_root = match validate_workspace_root_path(&persisted_root) {
-        Ok(root) => root,
-        Err(error) => return max_error(Some(persisted_root), error),
-    };
+        let workspace_root = match validate_workspace_root_path(&persisted_root) {
+            Ok(root) => root,
+            Err(error) => return max_error(Some(persisted_root), error),
+        };
 
-    let (mut workspace_meta, _) = match ensure_workspace_meta(&workspace_root) {
-        Ok(result) => result,
-        Err(error) => return max_error(Some(workspace_root.display().to_string()), error),
-    };
+        let (mut workspace_meta, _) = match ensure_workspace_meta(&workspace_root) {
+            Ok(result) => result,
+            Err(error) => return max_error(Some(workspace_root.display().to_string()), error),
+        };
 
-    workspace_meta.max_worktree_count = max_worktree_count;
-    workspace_meta.updated_at = now_iso();
+        workspace_meta.max_worktree_count = max_worktree_count;
+        workspace_meta.updated_at = now_iso();
 
-    let workspace_json = workspace_root.join(".groove").join("workspace.json");
-    if let Err(error) = write_workspace_meta_file(&workspace_json, &workspace_meta) {
-        return max_error(Some(workspace_root.display().to_string()), error);
-    }
+        let workspace_json = workspace_root.join(".groove").join("workspace.json");
+        if let Err(error) = write_workspace_meta_file(&workspace_json, &workspace_meta) {
+            return max_error(Some(workspace_root.display().to_string()), error);
+        }
 
-    // Enforce the new limit immediately: trim least-recently-used worktrees
-    // (skipping running/dirty ones) down to the cap.
-    let effective_root = effective_workspace_root(&workspace_root, &workspace_meta);
-    let evicted_worktrees =
-        evict_worktrees_over_limit(&app, &workspace_root, &effective_root).unwrap_or_default();
+        // Enforce the new limit immediately: trim least-recently-used worktrees
+        // (skipping running/dirty ones) down to the cap.
+        let effective_root = effective_workspace_root(&workspace_root, &workspace_meta);
+        let evicted_worktrees =
+            evict_worktrees_over_limit(&app, &workspace_root, &effective_root).unwrap_or_default();
 
-    invalidate_workspace_context_cache(&app, &workspace_root);
+        invalidate_workspace_caches(&app, &workspace_root);
 
-    WorkspaceMaxWorktreeCountResponse {
-        request_id,
-        ok: true,
-        workspace_root: Some(workspace_root.display().to_string()),
-        workspace_meta: Some(workspace_meta),
-        evicted_worktrees,
-        error: None,
-    }
+        WorkspaceMaxWorktreeCountResponse {
+            request_id,
+            ok: true,
+            workspace_root: Some(workspace_root.display().to_string()),
+            workspace_meta: Some(workspace_meta),
+            evicted_worktrees,
+            error: None,
+        }
+    })
 }
 
 #[tauri::command]
-fn workspace_mark_onboarding_configured(
+fn workspace_update_staleness_policy(
     app: AppHandle,
-    payload: WorkspaceMarkOnboardingPayload,
+    payload: WorkspaceStalenessPolicyPayload,
 ) -> WorkspaceTerminalSettingsResponse {
-    let request_id = request_id();
+    handle_command("workspace_update_staleness_policy", |context| {
+        let request_id = context.request_id();
 
-    let persisted_root = match read_persisted_active_workspace_root(&app) {
-        Ok(Some(value)) => value,
-        Ok(None) => {
-            return WorkspaceTerminalSettingsResponse {
-                request_id,
-                ok: false,
-                workspace_root: None,
-                workspace_meta: None,
-                error: Some("No active workspace selected.".to_string()),
-            };
-        }
-        Err(error) => {
-            return WorkspaceTerminalSettingsResponse {
-                request_id,
+        let policy_error =
+            |workspace_root: Option<String>, error: String| WorkspaceTerminalSettingsResponse {
+                request_id: request_id.clone(),
                 ok: false,
-                workspace_root: None,
+                workspace_root,
                 workspace_meta: None,
                 error: Some(error),
             };
-        }
-    };
 
-    let workspace_root = match validate_workspace_root_path(&persisted_root) {
-        Ok(root) => root,
-        Err(error) => {
-            return WorkspaceTerminalSettingsResponse {
-                request_id,
-                ok: false,
-                workspace_root: Some(persisted_root),
-                workspace_meta: None,
-                error: Some(error),
-            };
-        }
-    };
+        let staleness_policy = match payload.policy {
+            Some(policy) => match normalize_worktree_staleness_policy(policy) {
+                Ok(policy) => Some(policy),
+                Err(error) => return policy_error(None, error),
+            },
+            None => None,
+        };
 
-    let (mut workspace_meta, _) = match ensure_workspace_meta(&workspace_root) {
-        Ok(result) => result,
-        Err(error) => {
-            return WorkspaceTerminalSettingsResponse {
-                request_id,
-                ok: false,
-                workspace_root: Some(workspace_root.display().to_string()),
-                workspace_meta: None,
-                error: Some(error),
-            };
+        let persisted_root = match read_persisted_active_workspace_root(&app) {
+            Ok(Some(value)) => value,
+            Ok(None) => return policy_error(None, "No active workspace selected.".to_string()),
+            Err(error) => return policy_error(None, error),
+        };
+
+        let workspace_root = match validate_workspace_root_path(&persisted_root) {
+            Ok(root) => root,
+            Err(error) => return policy_error(Some(persisted_root), error),
+        };
+
+        let (mut workspace_meta, _) = match ensure_workspace_meta(&workspace_root) {
+            Ok(result) => result,
+            Err(error) => return policy_error(Some(workspace_root.display().to_string()), error),
+        };
+
+        let previous_meta = workspace_meta.clone();
+        workspace_meta.staleness_policy = staleness_policy;
+        workspace_meta.updated_at = now_iso();
+
+        let workspace_json = workspace_root.join(".groove").join("workspace.json");
+        if let Err(error) = write_workspace_meta_file(&workspace_json, &workspace_meta) {
+            return policy_error(Some(workspace_root.display().to_string()), error);
         }
-    };
 
-    if payload.symlinks_configured {
-        workspace_meta.onboarding_symlinks_configured = true;
-    }
-    if payload.commands_configured {
-        workspace_meta.onboarding_commands_configured = true;
-    }
-    workspace_meta.updated_at = now_iso();
+        invalidate_workspace_caches(&app, &workspace_root);
+        push_undo_entry(
+            &app,
+            UndoTarget::Workspace(workspace_root.clone()),
+            "workspace_update_staleness_policy",
+            "Revert staleness policy".to_string(),
+            changed_fields(&previous_meta, &workspace_meta),
+        );
 
-    let workspace_json = workspace_root.join(".groove").join("workspace.json");
-    if let Err(error) = write_workspace_meta_file(&workspace_json, &workspace_meta) {
-        return WorkspaceTerminalSettingsResponse {
+        WorkspaceTerminalSettingsResponse {
             request_id,
-            ok: false,
+            ok: true,
             workspace_root: Some(workspace_root.display().to_string()),
-            workspace_meta: None,
-            error: Some(error),
-        };
-    }
+            workspace_meta: Some(workspace_meta),
+            error: None,
+        }
+    })
+}
+
+#[tauri::command]
+async fn workspace_freeze(
+    app: AppHandle,
+    payload: WorkspaceFreezePayload,
+) -> WorkspaceFreezeResponse {
+    handle_blocking_command(
+        "workspace_freeze",
+        move |context| {
+            let request_id = context.request_id();
+            let workspace_root = match active_workspace_root_from_state(&app) {
+                Ok(root) => root,
+                Err(error) => return workspace_freeze_error_response(request_id, error),
+            };
+            let compress_artifacts = payload.compress_artifacts.unwrap_or(true);
+            match run_workspace_freeze(&app, &workspace_root, compress_artifacts) {
+                Ok((frozen, failures)) => WorkspaceFreezeResponse {
+                    request_id,
+                    ok: true,
+                    workspace_root: Some(workspace_root.display().to_string()),
+                    frozen: Some(frozen),
+                    restarted: Vec::new(),
+                    failures,
+                    error: None,
+                },
+                Err(error) => WorkspaceFreezeResponse {
+                    workspace_root: Some(workspace_root.display().to_string()),
+                    ..workspace_freeze_error_response(request_id, error)
+                },
+            }
+        },
+        workspace_freeze_error_response,
+    )
+    .await
+}
 
-    invalidate_workspace_context_cache(&app, &workspace_root);
+#[tauri::command]
+async fn workspace_thaw(app: AppHandle, payload: WorkspaceThawPayload) -> WorkspaceFreezeResponse {
+    handle_blocking_command(
+        "workspace_thaw",
+        move |context| {
+            let request_id = context.request_id();
+            let workspace_root = match active_workspace_root_from_state(&app) {
+                Ok(root) => root,
+                Err(error) => return workspace_freeze_error_response(request_id, error),
+            };
+            match run_workspace_thaw(&app, &workspace_root, payload.restart_sessions) {
+                Ok((frozen, restarted, failures)) => WorkspaceFreezeResponse {
+                    request_id,
+                    ok: true,
+                    workspace_root: Some(workspace_root.display().to_string()),
+                    frozen: Some(frozen),
+                    restarted,
+                    failures,
+                    error: None,
+                },
+                Err(error) => WorkspaceFreezeResponse {
+                    workspace_root: Some(workspace_root.display().to_string()),
+                    ..workspace_freeze_error_response(request_id, error)
+                },
+            }
+        },
+        workspace_freeze_error_response,
+    )
+    .await
+}
 
-    WorkspaceTerminalSettingsResponse {
+fn workspace_freeze_error_response(request_id: String, error: String) -> WorkspaceFreezeResponse {
+    WorkspaceFreezeResponse {
         request_id,
-        ok: true,
-        workspace_root: Some(workspace_root.display().to_string()),
-        workspace_meta: Some(workspace_meta),
-        error: None,
+        ok: false,
+        workspace_root: None,
+        frozen: None,
+        restarted: Vec::new(),
+        failures: Vec::new(),
+        error: Some(error),
     }
 }
 
 #[tauri::command]
-fn workspace_set_worktree_state(
+async fn workspace_conflict_matrix(
     app: AppHandle,
-    payload: SetWorktreeStatePayload,
-) -> SetWorktreeStateResponse {
-    let request_id = request_id();
+    payload: WorkspaceConflictMatrixPayload,
+) -> WorkspaceConflictMatrixResponse {
+    handle_blocking_command(
+        "workspace_conflict_matrix",
+        move |context| {
+            let request_id = context.request_id();
+            let workspace_root = match active_workspace_root_from_state(&app) {
+                Ok(root) => root,
+                Err(error) => return workspace_conflict_matrix_error_response(request_id, error),
+            };
+            match run_workspace_conflict_matrix(&app, &workspace_root, &payload.worktrees) {
+                Ok((worktrees, pairs)) => WorkspaceConflictMatrixResponse {
+                    request_id,
+                    ok: true,
+                    workspace_root: Some(workspace_root.display().to_string()),
+                    worktrees,
+                    pairs,
+                    error: None,
+                },
+                Err(error) => WorkspaceConflictMatrixResponse {
+                    workspace_root: Some(workspace_root.display().to_string()),
+                    ..workspace_conflict_matrix_error_response(request_id, error)
+                },
+            }
+        },
+        workspace_conflict_matrix_error_response,
+    )
+    .await
+}
 
-    let worktree = payload.worktree.trim();
-    if worktree.is_empty() {
-        return SetWorktreeStateResponse {
-            request_id,
-            ok: false,
-            workspace_root: None,
-            worktree: None,
-            record: None,
-            error: Some("worktree must be a non-empty string.".to_string()),
-        };
+fn workspace_conflict_matrix_error_response(
+    request_id: String,
+    error: String,
+) -> WorkspaceConflictMatrixResponse {
+    WorkspaceConflictMatrixResponse {
+        request_id,
+        ok: false,
+        workspace_root: None,
+        worktrees: Vec::new(),
+        pairs: Vec::new(),
+        error: Some(error),
     }
+}
 
-    let persisted_root = match read_persisted_active_workspace_root(&app) {
-        Ok(Some(value)) => value,
-        Ok(None) => {
-            return SetWorktreeStateResponse {
-                request_id,
-                ok: false,
-                workspace_root: None,
-                worktree: Some(worktree.to_string()),
-                record: None,
-                error: Some("No active workspace selected.".to_string()),
-            }
-        }
-        Err(error) => {
-            return SetWorktreeStateResponse {
-                request_id,
+#[tauri::command]
+fn workspace_update_git_identity(
+    app: AppHandle,
+    payload: WorkspaceGitIdentityPayload,
+) -> WorkspaceTerminalSettingsResponse {
+    handle_command("workspace_update_git_identity", |context| {
+        let request_id = context.request_id();
+
+        let identity_error =
+            |workspace_root: Option<String>, error: String| WorkspaceTerminalSettingsResponse {
+                request_id: request_id.clone(),
                 ok: false,
-                workspace_root: None,
-                worktree: Some(worktree.to_string()),
-                record: None,
+                workspace_root,
+                workspace_meta: None,
                 error: Some(error),
+            };
+
+        let git_identity = match payload.git_identity.as_ref() {
+            Some(identity) => match normalize_workspace_git_identity(identity) {
+                Ok(identity) => identity,
+                Err(error) => return identity_error(None, error),
+            },
+            None => None,
+        };
+
+        let persisted_root = match read_persisted_active_workspace_root(&app) {
+            Ok(Some(value)) => value,
+            Ok(None) => return identity_error(None, "No active workspace selected.".to_string()),
+            Err(error) => return identity_error(None, error),
+        };
+
+        let workspace_root = match validate_workspace_root_path(&persisted_root) {
+            Ok(root) => root,
+            Err(error) => return identity_error(Some(persisted_root), error),
+        };
+
+        let (mut workspace_meta, _) = match ensure_workspace_meta(&workspace_root) {
+            Ok(result) => result,
+            Err(error) => return identity_error(Some(workspace_root.display().to_string()), error),
+        };
+
+        let previous_meta = workspace_meta.clone();
+        workspace_meta.git_identity = git_identity;
+        workspace_meta.updated_at = now_iso();
+
+        let workspace_json = workspace_root.join(".groove").join("workspace.json");
+        if let Err(error) = write_workspace_meta_file(&workspace_json, &workspace_meta) {
+            return identity_error(Some(workspace_root.display().to_string()), error);
+        }
+
+        invalidate_workspace_caches(&app, &workspace_root);
+        push_undo_entry(
+            &app,
+            UndoTarget::Workspace(workspace_root.clone()),
+            "workspace_update_git_identity",
+            "Revert git identity".to_string(),
+            changed_fields(&previous_meta, &workspace_meta),
+        );
+
+        WorkspaceTerminalSettingsResponse {
+            request_id,
+            ok: true,
+            workspace_root: Some(workspace_root.display().to_string()),
+            workspace_meta: Some(workspace_meta),
+            error: None,
+        }
+    })
+}
+
+#[tauri::command]
+fn workspace_update_commit_template(
+    app: AppHandle,
+    payload: WorkspaceCommitTemplatePayload,
+) -> WorkspaceTerminalSettingsResponse {
+    handle_command("workspace_update_commit_template", |context| {
+        let request_id = context.request_id();
+
+        let template_error =
+            |workspace_root: Option<String>, error: String| WorkspaceTerminalSettingsResponse {
+                request_id: request_id.clone(),
+                ok: false,
+                workspace_root,
+                workspace_meta: None,
+                error: Some(error),
+            };
+
+        let commit_template = match payload.commit_template.as_ref() {
+            Some(template) => match normalize_workspace_commit_template(template) {
+                Ok(template) => template,
+                Err(error) => return template_error(None, error),
+            },
+            None => None,
+        };
+
+        let persisted_root = match read_persisted_active_workspace_root(&app) {
+            Ok(Some(value)) => value,
+            Ok(None) => return template_error(None, "No active workspace selected.".to_string()),
+            Err(error) => return template_error(None, error),
+        };
+
+        let workspace_root = match validate_workspace_root_path(&persisted_root) {
+            Ok(root) => root,
+            Err(error) => return template_error(Some(persisted_root), error),
+        };
+
+        let (mut workspace_meta, _) = match ensure_workspace_meta(&workspace_root) {
+            Ok(result) => result,
+            Err(error) => return template_error(Some(workspace_root.display().to_string()), error),
+        };
+
+        let previous_meta = workspace_meta.clone();
+        workspace_meta.commit_template = commit_template;
+        workspace_meta.updated_at = now_iso();
+
+        let workspace_json = workspace_root.join(".groove").join("workspace.json");
+        if let Err(error) = write_workspace_meta_file(&workspace_json, &workspace_meta) {
+            return template_error(Some(workspace_root.display().to_string()), error);
+        }
+
+        invalidate_workspace_caches(&app, &workspace_root);
+        push_undo_entry(
+            &app,
+            UndoTarget::Workspace(workspace_root.clone()),
+            "workspace_update_commit_template",
+            "Revert commit template".to_string(),
+            changed_fields(&previous_meta, &workspace_meta),
+        );
+
+        WorkspaceTerminalSettingsResponse {
+            request_id,
+            ok: true,
+            workspace_root: Some(workspace_root.display().to_string()),
+            workspace_meta: Some(workspace_meta),
+            error: None,
+        }
+    })
+}
+
+#[tauri::command]
+fn workspace_update_commit_lint(
+    app: AppHandle,
+    payload: WorkspaceCommitLintPayload,
+) -> WorkspaceTerminalSettingsResponse {
+    handle_command("workspace_update_commit_lint", |context| {
+        let request_id = context.request_id();
+
+        let lint_error =
+            |workspace_root: Option<String>, error: String| WorkspaceTerminalSettingsResponse {
+                request_id: request_id.clone(),
+                ok: false,
+                workspace_root,
+                workspace_meta: None,
+                error: Some(error),
+            };
+
+        let commit_lint = match payload.commit_lint.as_ref() {
+            Some(rules) => match normalize_workspace_commit_lint(rules) {
+                Ok(rules) => Some(rules),
+                Err(error) => return lint_error(None, error),
+            },
+            None => None,
+        };
+
+        let persisted_root = match read_persisted_active_workspace_root(&app) {
+            Ok(Some(value)) => value,
+            Ok(None) => return lint_error(None, "No active workspace selected.".to_string()),
+            Err(error) => return lint_error(None, error),
+        };
+
+        let workspace_root = match validate_workspace_root_path(&persisted_root) {
+            Ok(root) => root,
+            Err(error) => return lint_error(Some(persisted_root), error),
+        };
+
+        let (mut workspace_meta, _) = match ensure_workspace_meta(&workspace_root) {
+            Ok(result) => result,
+            Err(error) => return lint_error(Some(workspace_root.display().to_string()), error),
+        };
+
+        let previous_meta = workspace_meta.clone();
+        workspace_meta.commit_lint = commit_lint;
+        workspace_meta.updated_at = now_iso();
+
+        let workspace_json = workspace_root.join(".groove").join("workspace.json");
+        if let Err(error) = write_workspace_meta_file(&workspace_json, &workspace_meta) {
+            return lint_error(Some(workspace_root.display().to_string()), error);
+        }
+
+        invalidate_workspace_caches(&app, &workspace_root);
+        push_undo_entry(
+            &app,
+            UndoTarget::Workspace(workspace_root.clone()),
+            "workspace_update_commit_lint",
+            "Revert commit lint rules".to_string(),
+            changed_fields(&previous_meta, &workspace_meta),
+        );
+
+        WorkspaceTerminalSettingsResponse {
+            request_id,
+            ok: true,
+            workspace_root: Some(workspace_root.display().to_string()),
+            workspace_meta: Some(workspace_meta),
+            error: None,
+        }
+    })
+}
+
+#[tauri::command]
+fn workspace_update_require_sign_off(
+    app: AppHandle,
+    payload: WorkspaceRequireSignOffPayload,
+) -> WorkspaceTerminalSettingsResponse {
+    handle_command("workspace_update_require_sign_off", |context| {
+        let request_id = context.request_id();
+
+        let sign_off_error =
+            |workspace_root: Option<String>, error: String| WorkspaceTerminalSettingsResponse {
+                request_id: request_id.clone(),
+                ok: false,
+                workspace_root,
+                workspace_meta: None,
+                error: Some(error),
+            };
+
+        let persisted_root = match read_persisted_active_workspace_root(&app) {
+            Ok(Some(value)) => value,
+            Ok(None) => return sign_off_error(None, "No active workspace selected.".to_string()),
+            Err(error) => return sign_off_error(None, error),
+        };
+
+        let workspace_root = match validate_workspace_root_path(&persisted_root) {
+            Ok(root) => root,
+            Err(error) => return sign_off_error(Some(persisted_root), error),
+        };
+
+        let (mut workspace_meta, _) = match ensure_workspace_meta(&workspace_root) {
+            Ok(result) => result,
+            Err(error) => return sign_off_error(Some(workspace_root.display().to_string()), error),
+        };
+
+        let previous_meta = workspace_meta.clone();
+        workspace_meta.require_sign_off = payload.require_sign_off;
+        workspace_meta.updated_at = now_iso();
+
+        let workspace_json = workspace_root.join(".groove").join("workspace.json");
+        if let Err(error) = write_workspace_meta_file(&workspace_json, &workspace_meta) {
+            return sign_off_error(Some(workspace_root.display().to_string()), error);
+        }
+
+        invalidate_workspace_caches(&app, &workspace_root);
+        push_undo_entry(
+            &app,
+            UndoTarget::Workspace(workspace_root.clone()),
+            "workspace_update_require_sign_off",
+            "Revert sign-off requirement".to_string(),
+            changed_fields(&previous_meta, &workspace_meta),
+        );
+
+        WorkspaceTerminalSettingsResponse {
+            request_id,
+            ok: true,
+            workspace_root: Some(workspace_root.display().to_string()),
+            workspace_meta: Some(workspace_meta),
+            error: None,
+        }
+    })
+}
+
+#[tauri::command]
+fn workspace_set_trust(app: AppHandle, payload: WorkspaceTrustPayload) -> WorkspaceTrustResponse {
+    handle_command("workspace_set_trust", |context| {
+        let request_id = context.request_id();
+        let workspace_root = match active_workspace_root_from_state(&app) {
+            Ok(root) => root,
+            Err(error) => {
+                return WorkspaceTrustResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    trusted: false,
+                    error: Some(error),
+                }
+            }
+        };
+
+        if let Err(error) = set_workspace_trust(&app, &workspace_root, payload.trusted) {
+            return WorkspaceTrustResponse {
+                request_id,
+                ok: false,
+                workspace_root: Some(workspace_root.display().to_string()),
+                trusted: workspace_trusted(&workspace_root),
+                error: Some(error),
+            };
+        }
+        invalidate_workspace_caches(&app, &workspace_root);
+
+        WorkspaceTrustResponse {
+            request_id,
+            ok: true,
+            workspace_root: Some(workspace_root.display().to_string()),
+            trusted: payload.trusted,
+            error: None,
+        }
+    })
+}
+
+/// Lets a command held back by the custom command policy run in the active
+/// workspace from now on.
+#[tauri::command]
+fn custom_command_approve(
+    app: AppHandle,
+    payload: CustomCommandApprovePayload,
+) -> CustomCommandApproveResponse {
+    handle_command("custom_command_approve", |context| {
+        let request_id = context.request_id();
+        let workspace_root = match active_workspace_root_from_state(&app) {
+            Ok(root) => root,
+            Err(error) => {
+                return CustomCommandApproveResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    error: Some(error),
+                }
+            }
+        };
+
+        let result = approve_custom_command(&app, &workspace_root, &payload.command);
+        CustomCommandApproveResponse {
+            request_id,
+            ok: result.is_ok(),
+            workspace_root: Some(workspace_root.display().to_string()),
+            error: result.err(),
+        }
+    })
+}
+
+#[tauri::command]
+fn workspace_custom_commands_list(app: AppHandle) -> WorkspaceCustomCommandsListResponse {
+    handle_command("workspace_custom_commands_list", |context| {
+        let request_id = context.request_id();
+        let workspace_root = match active_workspace_root_from_state(&app) {
+            Ok(root) => root,
+            Err(error) => {
+                return WorkspaceCustomCommandsListResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    custom_commands: Vec::new(),
+                    error: Some(error),
+                }
+            }
+        };
+
+        match ensure_workspace_meta(&workspace_root) {
+            Ok((workspace_meta, _)) => WorkspaceCustomCommandsListResponse {
+                request_id,
+                ok: true,
+                workspace_root: Some(workspace_root.display().to_string()),
+                custom_commands: workspace_meta.custom_commands,
+                error: None,
+            },
+            Err(error) => WorkspaceCustomCommandsListResponse {
+                request_id,
+                ok: false,
+                workspace_root: Some(workspace_root.display().to_string()),
+                custom_commands: Vec::new(),
+                error: Some(error),
+            },
+        }
+    })
+}
+
+#[tauri::command]
+fn workspace_update_custom_commands(
+    app: AppHandle,
+    payload: WorkspaceCustomCommandsPayload,
+) -> WorkspaceTerminalSettingsResponse {
+    handle_command("workspace_update_custom_commands", |context| {
+        let request_id = context.request_id();
+
+        let custom_commands_error =
+            |workspace_root: Option<String>, error: String| WorkspaceTerminalSettingsResponse {
+                request_id: request_id.clone(),
+                ok: false,
+                workspace_root,
+                workspace_meta: None,
+                error: Some(error),
+            };
+
+        let custom_commands = match normalize_workspace_custom_commands(&payload.custom_commands) {
+            Ok(commands) => commands,
+            Err(error) => return custom_commands_error(None, error),
+        };
+
+        let persisted_root = match read_persisted_active_workspace_root(&app) {
+            Ok(Some(value)) => value,
+            Ok(None) => {
+                return custom_commands_error(None, "No active workspace selected.".to_string())
+            }
+            Err(error) => return custom_commands_error(None, error),
+        };
+
+        let workspace_root = match validate_workspace_root_path(&persisted_root) {
+            Ok(root) => root,
+            Err(error) => return custom_commands_error(Some(persisted_root), error),
+        };
+
+        let (mut workspace_meta, _) = match ensure_workspace_meta(&workspace_root) {
+            Ok(result) => result,
+            Err(error) => {
+                return custom_commands_error(Some(workspace_root.display().to_string()), error)
+            }
+        };
+
+        let previous_meta = workspace_meta.clone();
+        workspace_meta.custom_commands = custom_commands;
+        workspace_meta.updated_at = now_iso();
+
+        let workspace_json = workspace_root.join(".groove").join("workspace.json");
+        if let Err(error) = write_workspace_meta_file(&workspace_json, &workspace_meta) {
+            return custom_commands_error(Some(workspace_root.display().to_string()), error);
+        }
+
+        invalidate_workspace_caches(&app, &workspace_root);
+        push_undo_entry(
+            &app,
+            UndoTarget::Workspace(workspace_root.clone()),
+            "workspace_update_custom_commands",
+            "Revert custom commands".to_string(),
+            changed_fields(&previous_meta, &workspace_meta),
+        );
+
+        WorkspaceTerminalSettingsResponse {
+            request_id,
+            ok: true,
+            workspace_root: Some(workspace_root.display().to_string()),
+            workspace_meta: Some(workspace_meta),
+            error: None,
+        }
+    })
+}
+
+#[tauri::command]
+fn workspace_update_remote(
+    app: AppHandle,
+    payload: WorkspaceRemotePayload,
+) -> WorkspaceTerminalSettingsResponse {
+    handle_command("workspace_update_remote", |context| {
+        let request_id = context.request_id();
+
+        let remote_error =
+            |workspace_root: Option<String>, error: String| WorkspaceTerminalSettingsResponse {
+                request_id: request_id.clone(),
+                ok: false,
+                workspace_root,
+                workspace_meta: None,
+                error: Some(error),
+            };
+
+        let remote = match payload
+            .remote
+            .as_ref()
+            .map(normalize_workspace_remote)
+            .transpose()
+        {
+            Ok(remote) => remote,
+            Err(error) => return remote_error(None, error),
+        };
+
+        let persisted_root = match read_persisted_active_workspace_root(&app) {
+            Ok(Some(value)) => value,
+            Ok(None) => return remote_error(None, "No active workspace selected.".to_string()),
+            Err(error) => return remote_error(None, error),
+        };
+
+        let workspace_root = match validate_workspace_root_path(&persisted_root) {
+            Ok(root) => root,
+            Err(error) => return remote_error(Some(persisted_root), error),
+        };
+
+        let (mut workspace_meta, _) = match ensure_workspace_meta(&workspace_root) {
+            Ok(result) => result,
+            Err(error) => return remote_error(Some(workspace_root.display().to_string()), error),
+        };
+
+        workspace_meta.remote = remote;
+        workspace_meta.updated_at = now_iso();
+
+        let workspace_json = workspace_root.join(".groove").join("workspace.json");
+        if let Err(error) = write_workspace_meta_file(&workspace_json, &workspace_meta) {
+            return remote_error(Some(workspace_root.display().to_string()), error);
+        }
+
+        invalidate_workspace_caches(&app, &workspace_root);
+
+        WorkspaceTerminalSettingsResponse {
+            request_id,
+            ok: true,
+            workspace_root: Some(workspace_root.display().to_string()),
+            workspace_meta: Some(workspace_meta),
+            error: None,
+        }
+    })
+}
+
+#[tauri::command]
+async fn workspace_remote_probe(app: AppHandle) -> WorkspaceRemoteProbeResponse {
+    handle_blocking_command(
+        "workspace_remote_probe",
+        move |context| {
+            let request_id = context.request_id();
+            let remote = match active_workspace_remote(&app) {
+                Ok(remote) => remote,
+                Err(error) => {
+                    return WorkspaceRemoteProbeResponse {
+                        request_id,
+                        ok: false,
+                        destination: None,
+                        git_version: None,
+                        root_is_repository: false,
+                        error: Some(error),
+                    }
+                }
+            };
+
+            let version = run_remote_command(&remote, "/", &["git", "--version"]);
+            if let Some(error) =
+                remote_command_failure(&version, "git --version failed on the remote host")
+            {
+                return WorkspaceRemoteProbeResponse {
+                    request_id,
+                    ok: false,
+                    destination: Some(remote.destination),
+                    git_version: None,
+                    root_is_repository: false,
+                    error: Some(error),
+                };
+            }
+
+            let inside = run_remote_command(
+                &remote,
+                &remote.root,
+                &["git", "rev-parse", "--is-inside-work-tree"],
+            );
+            let root_is_repository =
+                remote_command_failure(&inside, "").is_none() && inside.stdout.trim() == "true";
+            WorkspaceRemoteProbeResponse {
+                request_id,
+                ok: root_is_repository,
+                error: (!root_is_repository).then(|| {
+                    format!(
+                        "\"{}\" on {} is not a git repository.",
+                        remote.root, remote.destination
+                    )
+                }),
+                destination: Some(remote.destination),
+                git_version: first_non_empty_line(&version.stdout),
+                root_is_repository,
+            }
+        },
+        |request_id, error| WorkspaceRemoteProbeResponse {
+            request_id,
+            ok: false,
+            destination: None,
+            git_version: None,
+            root_is_repository: false,
+            error: Some(error),
+        },
+    )
+    .await
+}
+
+#[tauri::command]
+async fn workspace_remote_list_worktrees(app: AppHandle) -> WorkspaceRemoteWorktreesResponse {
+    handle_blocking_command(
+        "workspace_remote_list_worktrees",
+        move |context| {
+            let request_id = context.request_id();
+            let remote = match active_workspace_remote(&app) {
+                Ok(remote) => remote,
+                Err(error) => {
+                    return WorkspaceRemoteWorktreesResponse {
+                        request_id,
+                        ok: false,
+                        destination: None,
+                        worktrees: Vec::new(),
+                        error: Some(error),
+                    }
+                }
+            };
+
+            match list_remote_worktrees(&remote) {
+                Ok(worktrees) => WorkspaceRemoteWorktreesResponse {
+                    request_id,
+                    ok: true,
+                    destination: Some(remote.destination),
+                    worktrees,
+                    error: None,
+                },
+                Err(error) => WorkspaceRemoteWorktreesResponse {
+                    request_id,
+                    ok: false,
+                    destination: Some(remote.destination),
+                    worktrees: Vec::new(),
+                    error: Some(error),
+                },
+            }
+        },
+        |request_id, error| WorkspaceRemoteWorktreesResponse {
+            request_id,
+            ok: false,
+            destination: None,
+            worktrees: Vec::new(),
+            error: Some(error),
+        },
+    )
+    .await
+}
+
+#[tauri::command]
+async fn workspace_remote_git_status(
+    app: AppHandle,
+    payload: WorkspaceRemoteGitStatusPayload,
+) -> GitStatusResponse {
+    handle_blocking_command(
+        "workspace_remote_git_status",
+        move |context| {
+            let request_id = context.request_id();
+            let status_error =
+                |path: Option<String>, output_snippet: Option<String>, error: String| {
+                    GitStatusResponse {
+                        request_id: request_id.clone(),
+                        ok: false,
+                        path,
+                        modified: 0,
+                        added: 0,
+                        deleted: 0,
+                        untracked: 0,
+                        dirty: false,
+                        rebase: None,
+                        output_snippet,
+                        error: Some(error),
+                    }
+                };
+
+            let worktree = payload.worktree.trim();
+            if !is_safe_path_token(worktree) {
+                return status_error(
+                    None,
+                    None,
+                    "worktree contains unsafe characters or path segments.".to_string(),
+                );
+            }
+            let remote = match active_workspace_remote(&app) {
+                Ok(remote) => remote,
+                Err(error) => return status_error(None, None, error),
+            };
+
+            let cwd = remote_worktree_cwd(&remote, worktree);
+            let path = Some(format!("{}:{cwd}", remote.destination));
+            let result = run_remote_command(&remote, &cwd, &["git", "status", "--porcelain=v1"]);
+            if let Some(error) = remote_command_failure(&result, "git status failed") {
+                return status_error(path, command_output_snippet(&result), error);
+            }
+
+            let counts = parse_git_porcelain_counts(&result.stdout);
+            GitStatusResponse {
+                request_id,
+                ok: true,
+                path,
+                modified: counts.modified,
+                added: counts.added,
+                deleted: counts.deleted,
+                untracked: counts.untracked,
+                dirty: counts.dirty(),
+                rebase: None,
+                output_snippet: command_output_snippet(&result),
+                error: None,
+            }
+        },
+        |request_id, error| GitStatusResponse {
+            request_id,
+            ok: false,
+            path: None,
+            modified: 0,
+            added: 0,
+            deleted: 0,
+            untracked: 0,
+            dirty: false,
+            rebase: None,
+            output_snippet: None,
+            error: Some(error),
+        },
+    )
+    .await
+}
+
+#[tauri::command]
+fn workspace_mark_onboarding_configured(
+    app: AppHandle,
+    payload: WorkspaceMarkOnboardingPayload,
+) -> WorkspaceTerminalSettingsResponse {
+    handle_command("workspace_mark_onboarding_configured", |context| {
+        let request_id = context.request_id();
+
+        let persisted_root = match read_persisted_active_workspace_root(&app) {
+            Ok(Some(value)) => value,
+            Ok(None) => {
+                return WorkspaceTerminalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    workspace_meta: None,
+                    error: Some("No active workspace selected.".to_string()),
+                };
+            }
+            Err(error) => {
+                return WorkspaceTerminalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    workspace_meta: None,
+                    error: Some(error),
+                };
+            }
+        };
+
+        let workspace_root = match validate_workspace_root_path(&persisted_root) {
+            Ok(root) => root,
+            Err(error) => {
+                return WorkspaceTerminalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: Some(persisted_root),
+                    workspace_meta: None,
+                    error: Some(error),
+                };
+            }
+        };
+
+        let (mut workspace_meta, _) = match ensure_workspace_meta(&workspace_root) {
+            Ok(result) => result,
+            Err(error) => {
+                return WorkspaceTerminalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: Some(workspace_root.display().to_string()),
+                    workspace_meta: None,
+                    error: Some(error),
+                };
+            }
+        };
+
+        if payload.symlinks_configured {
+            workspace_meta.onboarding_symlinks_configured = true;
+        }
+        if payload.commands_configured {
+            workspace_meta.onboarding_commands_configured = true;
+        }
+        workspace_meta.updated_at = now_iso();
+
+        let workspace_json = workspace_root.join(".groove").join("workspace.json");
+        if let Err(error) = write_workspace_meta_file(&workspace_json, &workspace_meta) {
+            return WorkspaceTerminalSettingsResponse {
+                request_id,
+                ok: false,
+                workspace_root: Some(workspace_root.display().to_string()),
+                workspace_meta: None,
+                error: Some(error),
+            };
+        }
+
+        invalidate_workspace_caches(&app, &workspace_root);
+
+        WorkspaceTerminalSettingsResponse {
+            request_id,
+            ok: true,
+            workspace_root: Some(workspace_root.display().to_string()),
+            workspace_meta: Some(workspace_meta),
+            error: None,
+        }
+    })
+}
+
+#[tauri::command]
+fn workspace_set_worktree_state(
+    app: AppHandle,
+    payload: SetWorktreeStatePayload,
+) -> SetWorktreeStateResponse {
+    handle_command("workspace_set_worktree_state", |context| {
+        let request_id = context.request_id();
+
+        let worktree = payload.worktree.trim();
+        if worktree.is_empty() {
+            return SetWorktreeStateResponse {
+                request_id,
+                ok: false,
+                workspace_root: None,
+                worktree: None,
+                record: None,
+                error: Some("worktree must be a non-empty string.".to_string()),
+            };
+        }
+
+        let persisted_root = match read_persisted_active_workspace_root(&app) {
+            Ok(Some(value)) => value,
+            Ok(None) => {
+                return SetWorktreeStateResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    worktree: Some(worktree.to_string()),
+                    record: None,
+                    error: Some("No active workspace selected.".to_string()),
+                }
+            }
+            Err(error) => {
+                return SetWorktreeStateResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    worktree: Some(worktree.to_string()),
+                    record: None,
+                    error: Some(error),
+                }
+            }
+        };
+
+        let workspace_root = match validate_workspace_root_path(&persisted_root) {
+            Ok(root) => root,
+            Err(error) => {
+                return SetWorktreeStateResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: Some(persisted_root),
+                    worktree: Some(worktree.to_string()),
+                    record: None,
+                    error: Some(error),
+                }
+            }
+        };
+
+        let previous_record = current_worktree_record(&workspace_root, worktree);
+        let record = match set_worktree_state(&workspace_root, worktree, payload.state) {
+            Ok(record) => record,
+            Err(error) => {
+                return SetWorktreeStateResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: Some(workspace_root.display().to_string()),
+                    worktree: Some(worktree.to_string()),
+                    record: None,
+                    error: Some(error),
+                }
+            }
+        };
+
+        push_worktree_undo_entry(
+            &app,
+            &workspace_root,
+            worktree,
+            "workspace_set_worktree_state",
+            format!("Revert state of {worktree}"),
+            previous_record.as_ref(),
+            &record,
+        );
+
+        let worktree_key = worktree.to_string();
+        let patched_record = record.clone();
+        let patched_updated_at = now_iso();
+        patch_workspace_context_cache(&app, &workspace_root, |response| {
+            let Some(meta) = response.workspace_meta.as_mut() else {
+                return;
+            };
+            meta.worktree_records.insert(worktree_key, patched_record);
+            // Frontend hook compares workspace_meta by `updated_at`; bump it so
+            // the patched cache hit isn't deduplicated as "unchanged".
+            meta.updated_at = patched_updated_at;
+        });
+
+        SetWorktreeStateResponse {
+            request_id,
+            ok: true,
+            workspace_root: Some(workspace_root.display().to_string()),
+            worktree: Some(worktree.to_string()),
+            record: Some(record),
+            error: None,
+        }
+    })
+}
+
+/// Checks that `requested` (a canonical path) can be adopted into the
+/// workspace and returns the worktree name it would get and its branch.
+fn plan_worktree_adoption(
+    workspace_root: &Path,
+    workspace_meta: &WorkspaceMeta,
+    git_worktrees: &[(Option<String>, PathBuf)],
+    requested: &Path,
+) -> Result<(String, Option<String>), String> {
+    let same_path = |path: &Path| fs::canonicalize(path).is_ok_and(|path| path == requested);
+
+    // `git worktree list` prints the main worktree first.
+    let branch = match git_worktrees.iter().position(|(_, path)| same_path(path)) {
+        Some(0) => return Err("The main worktree cannot be adopted.".to_string()),
+        Some(position) => git_worktrees[position].0.clone(),
+        None => {
+            return Err(format!(
+                "{} is not a worktree of this repository.",
+                requested.display()
+            ))
+        }
+    };
+
+    let worktrees_dir = effective_workspace_root(workspace_root, workspace_meta).join(".worktrees");
+    if fs::canonicalize(&worktrees_dir).is_ok_and(|dir| requested.starts_with(dir)) {
+        return Err(format!(
+            "{} is already under .worktrees/.",
+            requested.display()
+        ));
+    }
+    let Some(worktree) = requested
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+    else {
+        return Err("path must name a worktree directory.".to_string());
+    };
+    if path_is_directory(&worktrees_dir.join(&worktree)) {
+        return Err(format!(
+            "A worktree named {worktree} already exists in .worktrees/."
+        ));
+    }
+    if workspace_meta
+        .adopted_worktrees
+        .get(&worktree)
+        .is_some_and(|existing| !same_path(Path::new(existing)))
+    {
+        return Err(format!(
+            "Another adopted worktree is already named {worktree}."
+        ));
+    }
+    Ok((worktree, branch))
+}
+
+/// Adopts the git worktree at `requested` into `workspace_meta`, moving it
+/// under `.worktrees/` when `relocate` is set. Writes the worktree's
+/// `.groove/worktree.json`; persisting the meta is left to the caller.
+fn adopt_git_worktree(
+    workspace_root: &Path,
+    workspace_meta: &mut WorkspaceMeta,
+    git_worktrees: &[(Option<String>, PathBuf)],
+    requested: &Path,
+    relocate: bool,
+) -> Result<(String, PathBuf, WorktreeRecord), String> {
+    let (worktree, branch) =
+        plan_worktree_adoption(workspace_root, workspace_meta, git_worktrees, requested)?;
+
+    let path = if relocate {
+        let worktrees_dir =
+            effective_workspace_root(workspace_root, workspace_meta).join(".worktrees");
+        fs::create_dir_all(&worktrees_dir)
+            .map_err(|error| format!("Failed to create {}: {error}", worktrees_dir.display()))?;
+        let target = worktrees_dir.join(&worktree);
+        let git_side_path = |path: &Path| {
+            wsl_location(path)
+                .map(|location| location.path)
+                .unwrap_or_else(|| path.display().to_string())
+        };
+        let result = run_git_command_at_path_with_args(
+            workspace_root,
+            &[
+                "worktree".to_string(),
+                "move".to_string(),
+                git_side_path(requested),
+                git_side_path(&target),
+            ],
+        );
+        if let Some(error) = result.error.clone() {
+            return Err(error);
+        }
+        if result.exit_code != Some(0) {
+            return Err(first_non_empty_line(&result.stderr)
+                .unwrap_or_else(|| "git worktree move failed".to_string()));
+        }
+        workspace_meta.adopted_worktrees.remove(&worktree);
+        target
+    } else {
+        workspace_meta
+            .adopted_worktrees
+            .insert(worktree.clone(), requested.display().to_string());
+        requested.to_path_buf()
+    };
+
+    let record = workspace_meta
+        .worktree_records
+        .entry(worktree.clone())
+        .or_insert_with(|| WorktreeRecord {
+            id: read_worktree_identity(&path)
+                .map(|identity| identity.id)
+                .unwrap_or_else(|| Uuid::new_v4().to_string()),
+            created_at: now_iso(),
+            claude_session_started: false,
+            state: default_worktree_state(),
+            unit: None,
+            summaries: Vec::new(),
+            comments: Vec::new(),
+            pull_requests: Vec::new(),
+            branch,
+            play_groove_command: None,
+            note: None,
+            tags: Vec::new(),
+            issue: None,
+            parent_id: None,
+        })
+        .clone();
+    // Scans report worktrees without `.groove/` as corrupted.
+    write_worktree_identity(&path, &worktree_identity_for_record(&record))?;
+    Ok((worktree, path, record))
+}
+
+/// Brings an existing `git worktree` of the workspace's repository under
+/// Groove management. It gets a worktree record and `.groove/worktree.json`,
+/// and is either remembered where it is or moved under `.worktrees/`.
+#[tauri::command]
+fn workspace_adopt_worktree(
+    app: AppHandle,
+    payload: WorkspaceAdoptWorktreePayload,
+) -> WorkspaceAdoptWorktreeResponse {
+    handle_command("workspace_adopt_worktree", |context| {
+        let request_id = context.request_id();
+
+        let adopt_error =
+            |workspace_root: Option<String>, error: String| WorkspaceAdoptWorktreeResponse {
+                request_id: request_id.clone(),
+                ok: false,
+                workspace_root,
+                worktree: None,
+                path: None,
+                relocated: false,
+                record: None,
+                error: Some(error),
+            };
+
+        let persisted_root = match read_persisted_active_workspace_root(&app) {
+            Ok(Some(value)) => value,
+            Ok(None) => return adopt_error(None, "No active workspace selected.".to_string()),
+            Err(error) => return adopt_error(None, error),
+        };
+
+        let workspace_root = match validate_workspace_root_path(&persisted_root) {
+            Ok(root) => root,
+            Err(error) => return adopt_error(Some(persisted_root), error),
+        };
+        let rendered_root = workspace_root.display().to_string();
+
+        let (mut workspace_meta, _) = match ensure_workspace_meta(&workspace_root) {
+            Ok(result) => result,
+            Err(error) => return adopt_error(Some(rendered_root), error),
+        };
+
+        let requested = match validate_git_worktree_path(&payload.path) {
+            Ok(path) => fs::canonicalize(&path).unwrap_or(path),
+            Err(error) => return adopt_error(Some(rendered_root), error),
+        };
+        let git_worktrees = match list_git_worktrees_by_branch(&workspace_root) {
+            Ok(worktrees) => worktrees,
+            Err(error) => {
+                return adopt_error(
+                    Some(rendered_root),
+                    format!("Failed to list git worktrees: {error}"),
+                )
+            }
+        };
+
+        let (worktree, path, record) = match adopt_git_worktree(
+            &workspace_root,
+            &mut workspace_meta,
+            &git_worktrees,
+            &requested,
+            payload.relocate,
+        ) {
+            Ok(adopted) => adopted,
+            Err(error) => return adopt_error(Some(rendered_root), error),
+        };
+
+        workspace_meta.updated_at = now_iso();
+        let workspace_json = workspace_root.join(".groove").join("workspace.json");
+        if let Err(error) = write_workspace_meta_file(&workspace_json, &workspace_meta) {
+            return adopt_error(Some(rendered_root), error);
+        }
+        invalidate_workspace_caches(&app, &workspace_root);
+
+        WorkspaceAdoptWorktreeResponse {
+            request_id: request_id.clone(),
+            ok: true,
+            workspace_root: Some(rendered_root),
+            worktree: Some(worktree),
+            path: Some(path.display().to_string()),
+            relocated: payload.relocate,
+            record: Some(record),
+            error: None,
+        }
+    })
+}
+
+/// Detects how the repository's worktrees are laid out (a bare repository, a
+/// sibling directory, ...) and adopts every linked worktree Groove does not
+/// manage yet, reporting what happened to each one.
+#[tauri::command]
+fn workspace_migrate(app: AppHandle, payload: WorkspaceMigratePayload) -> WorkspaceMigrateResponse {
+    handle_command("workspace_migrate", |context| {
+        let request_id = context.request_id();
+
+        let migrate_error =
+            |workspace_root: Option<String>, error: String| WorkspaceMigrateResponse {
+                request_id: request_id.clone(),
+                ok: false,
+                workspace_root,
+                layout: None,
+                dry_run: payload.dry_run,
+                entries: Vec::new(),
+                migrated: 0,
+                error: Some(error),
+            };
+
+        let persisted_root = match read_persisted_active_workspace_root(&app) {
+            Ok(Some(value)) => value,
+            Ok(None) => return migrate_error(None, "No active workspace selected.".to_string()),
+            Err(error) => return migrate_error(None, error),
+        };
+
+        let workspace_root = match validate_workspace_root_path(&persisted_root) {
+            Ok(root) => root,
+            Err(error) => return migrate_error(Some(persisted_root), error),
+        };
+        let rendered_root = workspace_root.display().to_string();
+
+        // Creates `.groove/workspace.json` for workspaces opened the first time.
+        let (mut workspace_meta, _) = match ensure_workspace_meta(&workspace_root) {
+            Ok(result) => result,
+            Err(error) => return migrate_error(Some(rendered_root), error),
+        };
+
+        let git_worktrees = match list_git_worktrees_by_branch(&workspace_root) {
+            Ok(worktrees) => worktrees,
+            Err(error) => {
+                return migrate_error(
+                    Some(rendered_root),
+                    format!("Failed to list git worktrees: {error}"),
+                )
+            }
+        };
+        let is_bare =
+            run_git_command_at_path(&workspace_root, &["rev-parse", "--is-bare-repository"])
+                .stdout
+                .trim()
+                == "true";
+
+        let canonical = |path: &Path| fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        let managed_dir = canonical(
+            &effective_workspace_root(&workspace_root, &workspace_meta).join(".worktrees"),
+        );
+        let linked = git_worktrees
+            .iter()
+            .skip(1)
+            .map(|(_, path)| canonical(path))
+            .collect::<Vec<_>>();
+        let layout = workspace::classify_worktree_layout(
+            &canonical(&workspace_root),
+            &managed_dir,
+            is_bare,
+            &linked,
+        );
+
+        let adopted_paths = workspace_meta
+            .adopted_worktrees
+            .values()
+            .map(|path| canonical(Path::new(path)))
+            .collect::<HashSet<_>>();
+        let mut planned_names = HashSet::<String>::new();
+        let mut entries = Vec::with_capacity(linked.len());
+        let mut migrated = 0usize;
+        for ((branch, _), path) in git_worktrees.iter().skip(1).zip(&linked) {
+            let entry = |worktree: Option<String>, action: &str, reason: Option<String>| {
+                WorkspaceMigrationEntry {
+                    path: path.display().to_string(),
+                    branch: branch.clone(),
+                    worktree,
+                    action: action.to_string(),
+                    reason,
+                }
+            };
+
+            if path.starts_with(&managed_dir) || adopted_paths.contains(path) {
+                let worktree = path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string());
+                entries.push(entry(worktree, "alreadyManaged", None));
+                continue;
+            }
+            if !path_is_directory(path) {
+                entries.push(entry(
+                    None,
+                    "skipped",
+                    Some(
+                        "The worktree directory is missing; `git worktree prune` drops it."
+                            .to_string(),
+                    ),
+                ));
+                continue;
+            }
+
+            if payload.dry_run {
+                let planned =
+                    plan_worktree_adoption(&workspace_root, &workspace_meta, &git_worktrees, path)
+                        .and_then(|(worktree, _)| {
+                            if planned_names.insert(worktree.clone()) {
+                                Ok(worktree)
+                            } else {
+                                Err(format!("Another worktree would also be named {worktree}."))
+                            }
+                        });
+                entries.push(match planned {
+                    Ok(worktree) => entry(
+                        Some(worktree),
+                        if payload.relocate {
+                            "relocate"
+                        } else {
+                            "adopt"
+                        },
+                        None,
+                    ),
+                    Err(error) => entry(None, "failed", Some(error)),
+                });
+                continue;
+            }
+
+            match adopt_git_worktree(
+                &workspace_root,
+                &mut workspace_meta,
+                &git_worktrees,
+                path,
+                payload.relocate,
+            ) {
+                Ok((worktree, _, _)) => {
+                    migrated += 1;
+                    entries.push(entry(
+                        Some(worktree),
+                        if payload.relocate {
+                            "relocated"
+                        } else {
+                            "adopted"
+                        },
+                        None,
+                    ));
+                }
+                Err(error) => entries.push(entry(None, "failed", Some(error))),
             }
         }
-    };
 
-    let workspace_root = match validate_workspace_root_path(&persisted_root) {
-        Ok(root) => root,
-        Err(error) => {
+        let mut error = None;
+        if migrated > 0 {
+            workspace_meta.updated_at = now_iso();
+            let workspace_json = workspace_root.join(".groove").join("workspace.json");
+            error = write_workspace_meta_file(&workspace_json, &workspace_meta).err();
+            invalidate_workspace_caches(&app, &workspace_root);
+        }
+
+        WorkspaceMigrateResponse {
+            request_id: request_id.clone(),
+            ok: error.is_none(),
+            workspace_root: Some(rendered_root),
+            layout: Some(layout.to_string()),
+            dry_run: payload.dry_run,
+            entries,
+            migrated,
+            error,
+        }
+    })
+}
+
+#[tauri::command]
+fn workspace_set_worktree_play_command(
+    app: AppHandle,
+    payload: SetWorktreePlayCommandPayload,
+) -> SetWorktreeStateResponse {
+    handle_command("workspace_set_worktree_play_command", |context| {
+        let request_id = context.request_id();
+
+        let worktree = payload.worktree.trim();
+        if worktree.is_empty() {
             return SetWorktreeStateResponse {
                 request_id,
                 ok: false,
-                workspace_root: Some(persisted_root),
-                worktree: Some(worktree.to_string()),
+                workspace_root: None,
+                worktree: None,
                 record: None,
-                error: Some(error),
+                error: Some("worktree must be a non-empty string.".to_string()),
+            };
+        }
+
+        let persisted_root = match read_persisted_active_workspace_root(&app) {
+            Ok(Some(value)) => value,
+            Ok(None) => {
+                return SetWorktreeStateResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    worktree: Some(worktree.to_string()),
+                    record: None,
+                    error: Some("No active workspace selected.".to_string()),
+                }
+            }
+            Err(error) => {
+                return SetWorktreeStateResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    worktree: Some(worktree.to_string()),
+                    record: None,
+                    error: Some(error),
+                }
+            }
+        };
+
+        let workspace_root = match validate_workspace_root_path(&persisted_root) {
+            Ok(root) => root,
+            Err(error) => {
+                return SetWorktreeStateResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: Some(persisted_root),
+                    worktree: Some(worktree.to_string()),
+                    record: None,
+                    error: Some(error),
+                }
+            }
+        };
+
+        let previous_record = current_worktree_record(&workspace_root, worktree);
+        let record = match set_worktree_play_command(
+            &workspace_root,
+            worktree,
+            payload.play_groove_command.as_deref(),
+        ) {
+            Ok(record) => record,
+            Err(error) => {
+                return SetWorktreeStateResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: Some(workspace_root.display().to_string()),
+                    worktree: Some(worktree.to_string()),
+                    record: None,
+                    error: Some(error),
+                }
             }
+        };
+
+        push_worktree_undo_entry(
+            &app,
+            &workspace_root,
+            worktree,
+            "workspace_set_worktree_play_command",
+            format!("Revert play command of {worktree}"),
+            previous_record.as_ref(),
+            &record,
+        );
+
+        let worktree_key = worktree.to_string();
+        let patched_record = record.clone();
+        let patched_updated_at = now_iso();
+        patch_workspace_context_cache(&app, &workspace_root, |response| {
+            let Some(meta) = response.workspace_meta.as_mut() else {
+                return;
+            };
+            meta.worktree_records.insert(worktree_key, patched_record);
+            // Frontend hook compares workspace_meta by `updated_at`; bump it so
+            // the patched cache hit isn't deduplicated as "unchanged".
+            meta.updated_at = patched_updated_at;
+        });
+
+        SetWorktreeStateResponse {
+            request_id,
+            ok: true,
+            workspace_root: Some(workspace_root.display().to_string()),
+            worktree: Some(worktree.to_string()),
+            record: Some(record),
+            error: None,
         }
-    };
+    })
+}
 
-    let record = match set_worktree_state(&workspace_root, worktree, payload.state) {
-        Ok(record) => record,
-        Err(error) => {
+#[tauri::command]
+fn workspace_set_worktree_labels(
+    app: AppHandle,
+    payload: SetWorktreeLabelsPayload,
+) -> SetWorktreeStateResponse {
+    handle_command("workspace_set_worktree_labels", |context| {
+        let request_id = context.request_id();
+
+        let worktree = payload.worktree.trim();
+        if worktree.is_empty() {
             return SetWorktreeStateResponse {
                 request_id,
                 ok: false,
-                workspace_root: Some(workspace_root.display().to_string()),
-                worktree: Some(worktree.to_string()),
+                workspace_root: None,
+                worktree: None,
                 record: None,
-                error: Some(error),
-            }
+                error: Some("worktree must be a non-empty string.".to_string()),
+            };
         }
-    };
 
-    let worktree_key = worktree.to_string();
-    let patched_record = record.clone();
-    let patched_updated_at = now_iso();
-    patch_workspace_context_cache(&app, &workspace_root, |response| {
-        let Some(meta) = response.workspace_meta.as_mut() else {
-            return;
+        let workspace_root = match active_workspace_root_from_state(&app) {
+            Ok(root) => root,
+            Err(error) => {
+                return SetWorktreeStateResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    worktree: Some(worktree.to_string()),
+                    record: None,
+                    error: Some(error),
+                }
+            }
         };
-        meta.worktree_records.insert(worktree_key, patched_record);
-        // Frontend hook compares workspace_meta by `updated_at`; bump it so
-        // the patched cache hit isn't deduplicated as "unchanged".
-        meta.updated_at = patched_updated_at;
-    });
 
-    SetWorktreeStateResponse {
-        request_id,
-        ok: true,
-        workspace_root: Some(workspace_root.display().to_string()),
-        worktree: Some(worktree.to_string()),
-        record: Some(record),
-        error: None,
-    }
+        let previous_record = current_worktree_record(&workspace_root, worktree);
+        let record = match set_worktree_labels(
+            &workspace_root,
+            worktree,
+            payload.note.as_deref(),
+            &payload.tags,
+        ) {
+            Ok(record) => record,
+            Err(error) => {
+                return SetWorktreeStateResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: Some(workspace_root.display().to_string()),
+                    worktree: Some(worktree.to_string()),
+                    record: None,
+                    error: Some(error),
+                }
+            }
+        };
+
+        push_worktree_undo_entry(
+            &app,
+            &workspace_root,
+            worktree,
+            "workspace_set_worktree_labels",
+            format!("Revert note and tags of {worktree}"),
+            previous_record.as_ref(),
+            &record,
+        );
+
+        let worktree_key = worktree.to_string();
+        let patched_record = record.clone();
+        let patched_updated_at = now_iso();
+        patch_workspace_context_cache(&app, &workspace_root, |response| {
+            let Some(meta) = response.workspace_meta.as_mut() else {
+                return;
+            };
+            meta.worktree_records.insert(worktree_key, patched_record);
+            meta.updated_at = patched_updated_at;
+        });
+
+        SetWorktreeStateResponse {
+            request_id,
+            ok: true,
+            workspace_root: Some(workspace_root.display().to_string()),
+            worktree: Some(worktree.to_string()),
+            record: Some(record),
+            error: None,
+        }
+    })
 }
 
 #[tauri::command]
-fn workspace_claim_worktree_reward(
+fn workspace_search_worktrees(
     app: AppHandle,
-    payload: ClaimWorktreeRewardPayload,
-) -> ClaimWorktreeRewardResponse {
-    let request_id = request_id();
-    let worktree = payload.worktree.trim();
-    if worktree.is_empty() {
-        return ClaimWorktreeRewardResponse {
-            request_id,
-            ok: false,
-            unit: None,
-            gold: None,
-            error: Some("worktree must be a non-empty string.".to_string()),
+    payload: WorkspaceSearchWorktreesPayload,
+) -> WorkspaceSearchWorktreesResponse {
+    handle_command("workspace_search_worktrees", |context| {
+        let request_id = context.request_id();
+        let workspace_root = match active_workspace_root_from_state(&app) {
+            Ok(root) => root,
+            Err(error) => {
+                return WorkspaceSearchWorktreesResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    results: Vec::new(),
+                    error: Some(error),
+                }
+            }
         };
-    }
 
-    let persisted_root = match read_persisted_active_workspace_root(&app) {
-        Ok(Some(value)) => value,
-        Ok(None) => {
-            return ClaimWorktreeRewardResponse {
+        let workspace_context =
+            build_workspace_context(&app, &workspace_root, request_id.clone(), false);
+        if !workspace_context.ok {
+            return WorkspaceSearchWorktreesResponse {
                 request_id,
                 ok: false,
-                unit: None,
-                gold: None,
-                error: Some("No active workspace selected.".to_string()),
-            }
+                workspace_root: Some(workspace_root.display().to_string()),
+                results: Vec::new(),
+                error: workspace_context.error,
+            };
         }
-        Err(error) => {
-            return ClaimWorktreeRewardResponse {
-                request_id,
-                ok: false,
-                unit: None,
-                gold: None,
-                error: Some(error),
-            }
+
+        let records = workspace_context
+            .workspace_meta
+            .map(|workspace_meta| workspace_meta.worktree_records)
+            .unwrap_or_default();
+        let limit = payload
+            .limit
+            .unwrap_or(WORKTREE_SEARCH_DEFAULT_LIMIT)
+            .clamp(1, WORKTREE_SEARCH_MAX_LIMIT);
+        WorkspaceSearchWorktreesResponse {
+            request_id,
+            ok: true,
+            workspace_root: Some(workspace_root.display().to_string()),
+            results: rank_worktree_search(
+                &workspace_context.rows,
+                &records,
+                &payload.query,
+                payload.include_deleted,
+                limit,
+            ),
+            error: None,
         }
-    };
+    })
+}
 
-    let workspace_root = match validate_workspace_root_path(&persisted_root) {
-        Ok(root) => root,
-        Err(error) => {
-            return ClaimWorktreeRewardResponse {
-                request_id,
-                ok: false,
-                unit: None,
-                gold: None,
-                error: Some(error),
+/// One page of the workspace rows, for workspaces with too many worktrees to
+/// render at once. Pages come from the cached workspace context.
+#[tauri::command]
+fn workspace_list_worktrees(
+    app: AppHandle,
+    payload: WorkspaceListWorktreesPayload,
+) -> WorkspaceListWorktreesResponse {
+    handle_command("workspace_list_worktrees", |context| {
+        let request_id = context.request_id();
+        let workspace_root = match active_workspace_root_from_state(&app) {
+            Ok(root) => root,
+            Err(error) => {
+                return WorkspaceListWorktreesResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    rows: Vec::new(),
+                    total: 0,
+                    offset: payload.offset,
+                    has_more: false,
+                    error: Some(error),
+                }
             }
-        }
-    };
+        };
 
-    let (record, gold) = match claim_worktree_reward(&workspace_root, worktree) {
-        Ok(result) => result,
-        Err(error) => {
-            return ClaimWorktreeRewardResponse {
+        let workspace_context =
+            build_workspace_context(&app, &workspace_root, request_id.clone(), false);
+        if !workspace_context.ok {
+            return WorkspaceListWorktreesResponse {
                 request_id,
                 ok: false,
-                unit: None,
-                gold: None,
-                error: Some(error),
-            }
+                workspace_root: Some(workspace_root.display().to_string()),
+                rows: Vec::new(),
+                total: 0,
+                offset: payload.offset,
+                has_more: false,
+                error: workspace_context.error,
+            };
         }
-    };
 
-    let patched_record = record.clone();
-    let patched_worktree = worktree.to_string();
-    let patched_gold = gold;
-    patch_workspace_context_cache(&app, &workspace_root, |response| {
-        let Some(meta) = response.workspace_meta.as_mut() else {
-            return;
-        };
-        if let Some(record) = meta.worktree_records.get_mut(&patched_worktree) {
-            *record = patched_record;
+        let limit = payload
+            .limit
+            .unwrap_or(WORKSPACE_WORKTREES_PAGE_DEFAULT_LIMIT)
+            .clamp(1, WORKSPACE_WORKTREES_PAGE_MAX_LIMIT);
+        let total = workspace_context.rows.len();
+        let rows = workspace_context
+            .rows
+            .into_iter()
+            .skip(payload.offset)
+            .take(limit)
+            .collect::<Vec<_>>();
+        WorkspaceListWorktreesResponse {
+            request_id,
+            ok: true,
+            workspace_root: Some(workspace_root.display().to_string()),
+            has_more: payload.offset.saturating_add(rows.len()) < total,
+            rows,
+            total,
+            offset: payload.offset,
+            error: None,
         }
-        meta.gold = patched_gold;
-        meta.updated_at = now_iso();
-    });
+    })
+}
+
+#[tauri::command]
+async fn workspace_summary(app: AppHandle) -> WorkspaceSummaryResponse {
+    handle_blocking_command(
+        "workspace_summary",
+        move |context| {
+            let request_id = context.request_id();
+            match active_workspace_root_from_state(&app) {
+                Ok(workspace_root) => build_workspace_summary(&app, &workspace_root, request_id),
+                Err(error) => workspace_summary_failure(request_id, None, error),
+            }
+        },
+        |request_id, error| workspace_summary_failure(request_id, None, error),
+    )
+    .await
+}
+
+fn workspace_summary_failure(
+    request_id: String,
+    workspace_root: Option<String>,
+    error: String,
+) -> WorkspaceSummaryResponse {
+    WorkspaceSummaryResponse {
+        request_id,
+        ok: false,
+        workspace_root,
+        total_worktrees: 0,
+        running_agents: WorkspaceSummaryCategory::default(),
+        dirty_worktrees: WorkspaceSummaryCategory::default(),
+        open_prs: WorkspaceSummaryCategory::default(),
+        running_testing_envs: WorkspaceSummaryCategory::default(),
+        error: Some(error),
+    }
+}
 
-    ClaimWorktreeRewardResponse {
+/// Counts for the dashboard header. Everything comes from state the backend
+/// already holds: the cached workspace context, open terminal sessions,
+/// running grooves and `workspace.json`. Dirty worktrees are only counted in
+/// builds with `native-git`, where the status is read in-process.
+fn build_workspace_summary(
+    app: &AppHandle,
+    workspace_root: &Path,
+    request_id: String,
+) -> WorkspaceSummaryResponse {
+    let workspace_root_rendered = workspace_root.display().to_string();
+    let workspace_context = build_workspace_context(app, workspace_root, request_id.clone(), false);
+    if !workspace_context.ok {
+        return workspace_summary_failure(
+            request_id,
+            Some(workspace_root_rendered),
+            workspace_context
+                .error
+                .unwrap_or_else(|| "Failed to read the workspace.".to_string()),
+        );
+    }
+    let worktrees = workspace_context
+        .rows
+        .iter()
+        .map(|row| row.worktree.as_str())
+        .collect::<HashSet<_>>();
+
+    let running_agents = app
+        .try_state::<GrooveTerminalState>()
+        .map(|state| {
+            state
+                .lock_sessions()
+                .sessions_by_id
+                .values()
+                .filter(|session| session.workspace_root == workspace_root_rendered)
+                .filter(|session| !matches!(session.open_mode, GrooveTerminalOpenMode::Plain))
+                .map(|session| WorkspaceSummaryItem {
+                    worktree: session.worktree.clone(),
+                    detail: Some(groove_terminal_open_mode_key(session.open_mode).to_string()),
+                    url: None,
+                    since: Some(session.started_at.clone()),
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    let mut dirty_worktrees = workspace_context
+        .rows
+        .iter()
+        .filter_map(|row| {
+            let output = native_git::porcelain_status(Path::new(&row.path))?;
+            let changed = output
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .count();
+            (changed > 0).then(|| (changed, row.worktree.clone()))
+        })
+        .collect::<Vec<_>>();
+    dirty_worktrees.sort_by_key(|entry| std::cmp::Reverse(entry.0));
+    let dirty_worktrees = dirty_worktrees
+        .into_iter()
+        .map(|(changed, worktree)| WorkspaceSummaryItem {
+            worktree,
+            detail: Some(if changed == 1 {
+                "1 changed file".to_string()
+            } else {
+                format!("{changed} changed files")
+            }),
+            url: None,
+            since: None,
+        })
+        .collect::<Vec<_>>();
+
+    let open_prs = workspace_context
+        .workspace_meta
+        .as_ref()
+        .map(|meta| {
+            meta.worktree_records
+                .iter()
+                .filter(|(worktree, _)| worktrees.contains(worktree.as_str()))
+                .filter_map(|(worktree, record)| {
+                    let pull_request = record.pull_requests.last()?;
+                    Some(WorkspaceSummaryItem {
+                        worktree: worktree.clone(),
+                        detail: Some(format!("PR #{}", pull_request.number)),
+                        url: Some(pull_request.url.clone()),
+                        since: Some(pull_request.added_at.clone()),
+                    })
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    let running_testing_envs = read_running_grooves(app, workspace_root)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|record| WorkspaceSummaryItem {
+            worktree: record.worktree,
+            detail: record.target.or(Some(record.command)),
+            url: None,
+            since: Some(record.started_at),
+        })
+        .collect::<Vec<_>>();
+
+    WorkspaceSummaryResponse {
         request_id,
         ok: true,
-        unit: record.unit,
-        gold: Some(gold),
+        workspace_root: Some(workspace_root_rendered),
+        total_worktrees: workspace_context.rows.len(),
+        running_agents: workspace_summary_category(running_agents),
+        dirty_worktrees: workspace_summary_category(dirty_worktrees),
+        open_prs: workspace_summary_category(open_prs),
+        running_testing_envs: workspace_summary_category(running_testing_envs),
         error: None,
     }
 }
 
+/// Keeps the newest `WORKSPACE_SUMMARY_TOP_ITEMS` items; items without a
+/// time keep their order, after the others.
+fn workspace_summary_category(mut items: Vec<WorkspaceSummaryItem>) -> WorkspaceSummaryCategory {
+    let count = items.len();
+    items.sort_by(|left, right| right.since.cmp(&left.since));
+    items.truncate(WORKSPACE_SUMMARY_TOP_ITEMS);
+    WorkspaceSummaryCategory { count, top: items }
+}
+
 #[tauri::command]
-fn workspace_loot_worktree(
+fn workspace_claim_worktree_reward(
     app: AppHandle,
-    payload: LootWorktreePayload,
-) -> LootWorktreeResponse {
-    let request_id = request_id();
-    let worktree = payload.worktree.trim();
-    if worktree.is_empty() {
-        return LootWorktreeResponse {
-            request_id,
-            ok: false,
-            unit: None,
-            loot: Vec::new(),
-            inventory: None,
-            error: Some("worktree must be a non-empty string.".to_string()),
-        };
-    }
-
-    let persisted_root = match read_persisted_active_workspace_root(&app) {
-        Ok(Some(value)) => value,
-        Ok(None) => {
-            return LootWorktreeResponse {
+    payload: ClaimWorktreeRewardPayload,
+) -> ClaimWorktreeRewardResponse {
+    handle_command("workspace_claim_worktree_reward", |context| {
+        let request_id = context.request_id();
+        let worktree = payload.worktree.trim();
+        if worktree.is_empty() {
+            return ClaimWorktreeRewardResponse {
                 request_id,
                 ok: false,
                 unit: None,
-                loot: Vec::new(),
-                inventory: None,
-                error: Some("No active workspace selected.".to_string()),
+                gold: None,
+                error: Some("worktree must be a non-empty string.".to_string()),
+            };
+        }
+
+        let persisted_root = match read_persisted_active_workspace_root(&app) {
+            Ok(Some(value)) => value,
+            Ok(None) => {
+                return ClaimWorktreeRewardResponse {
+                    request_id,
+                    ok: false,
+                    unit: None,
+                    gold: None,
+                    error: Some("No active workspace selected.".to_string()),
+                }
+            }
+            Err(error) => {
+                return ClaimWorktreeRewardResponse {
+                    request_id,
+                    ok: false,
+                    unit: None,
+                    gold: None,
+                    error: Some(error),
+                }
+            }
+        };
+
+        let workspace_root = match validate_workspace_root_path(&persisted_root) {
+            Ok(root) => root,
+            Err(error) => {
+                return ClaimWorktreeRewardResponse {
+                    request_id,
+                    ok: false,
+                    unit: None,
+                    gold: None,
+                    error: Some(error),
+                }
+            }
+        };
+
+        let (record, gold) = match claim_worktree_reward(&workspace_root, worktree) {
+            Ok(result) => result,
+            Err(error) => {
+                return ClaimWorktreeRewardResponse {
+                    request_id,
+                    ok: false,
+                    unit: None,
+                    gold: None,
+                    error: Some(error),
+                }
             }
-        }
-        Err(error) => {
-            return LootWorktreeResponse {
-                request_id,
-                ok: false,
-                unit: None,
-                loot: Vec::new(),
-                inventory: None,
-                error: Some(error),
+        };
+
+        let patched_record = record.clone();
+        let patched_worktree = worktree.to_string();
+        let patched_gold = gold;
+        patch_workspace_context_cache(&app, &workspace_root, |response| {
+            let Some(meta) = response.workspace_meta.as_mut() else {
+                return;
+            };
+            if let Some(record) = meta.worktree_records.get_mut(&patched_worktree) {
+                *record = patched_record;
             }
+            meta.gold = patched_gold;
+            meta.updated_at = now_iso();
+        });
+
+        ClaimWorktreeRewardResponse {
+            request_id,
+            ok: true,
+            unit: record.unit,
+            gold: Some(gold),
+            error: None,
         }
-    };
+    })
+}
 
-    let workspace_root = match validate_workspace_root_path(&persisted_root) {
-        Ok(root) => root,
-        Err(error) => {
+#[tauri::command]
+fn workspace_loot_worktree(app: AppHandle, payload: LootWorktreePayload) -> LootWorktreeResponse {
+    handle_command("workspace_loot_worktree", |context| {
+        let request_id = context.request_id();
+        let worktree = payload.worktree.trim();
+        if worktree.is_empty() {
             return LootWorktreeResponse {
                 request_id,
                 ok: false,
                 unit: None,
                 loot: Vec::new(),
                 inventory: None,
-                error: Some(error),
-            }
+                error: Some("worktree must be a non-empty string.".to_string()),
+            };
         }
-    };
 
-    let (record, rolled_loot, inventory_snapshot) =
-        match loot_worktree(&workspace_root, worktree) {
-            Ok(result) => result,
+        let persisted_root = match read_persisted_active_workspace_root(&app) {
+            Ok(Some(value)) => value,
+            Ok(None) => {
+                return LootWorktreeResponse {
+                    request_id,
+                    ok: false,
+                    unit: None,
+                    loot: Vec::new(),
+                    inventory: None,
+                    error: Some("No active workspace selected.".to_string()),
+                }
+            }
             Err(error) => {
                 return LootWorktreeResponse {
                     request_id,
@@ -2240,28 +4752,58 @@ fn workspace_loot_worktree(
             }
         };
 
-    let patched_record = record.clone();
-    let patched_worktree = worktree.to_string();
-    let patched_inventory = inventory_snapshot.clone();
-    patch_workspace_context_cache(&app, &workspace_root, |response| {
-        let Some(meta) = response.workspace_meta.as_mut() else {
-            return;
+        let workspace_root = match validate_workspace_root_path(&persisted_root) {
+            Ok(root) => root,
+            Err(error) => {
+                return LootWorktreeResponse {
+                    request_id,
+                    ok: false,
+                    unit: None,
+                    loot: Vec::new(),
+                    inventory: None,
+                    error: Some(error),
+                }
+            }
         };
-        if let Some(record) = meta.worktree_records.get_mut(&patched_worktree) {
-            *record = patched_record;
-        }
-        meta.inventory = patched_inventory;
-        meta.updated_at = now_iso();
-    });
 
-    LootWorktreeResponse {
-        request_id,
-        ok: true,
-        unit: record.unit,
-        loot: rolled_loot,
-        inventory: Some(inventory_snapshot),
-        error: None,
-    }
+        let (record, rolled_loot, inventory_snapshot) =
+            match loot_worktree(&workspace_root, worktree) {
+                Ok(result) => result,
+                Err(error) => {
+                    return LootWorktreeResponse {
+                        request_id,
+                        ok: false,
+                        unit: None,
+                        loot: Vec::new(),
+                        inventory: None,
+                        error: Some(error),
+                    }
+                }
+            };
+
+        let patched_record = record.clone();
+        let patched_worktree = worktree.to_string();
+        let patched_inventory = inventory_snapshot.clone();
+        patch_workspace_context_cache(&app, &workspace_root, |response| {
+            let Some(meta) = response.workspace_meta.as_mut() else {
+                return;
+            };
+            if let Some(record) = meta.worktree_records.get_mut(&patched_worktree) {
+                *record = patched_record;
+            }
+            meta.inventory = patched_inventory;
+            meta.updated_at = now_iso();
+        });
+
+        LootWorktreeResponse {
+            request_id,
+            ok: true,
+            unit: record.unit,
+            loot: rolled_loot,
+            inventory: Some(inventory_snapshot),
+            error: None,
+        }
+    })
 }
 
 #[tauri::command]
@@ -2269,10 +4811,33 @@ fn workspace_update_worktree_symlink_paths(
     app: AppHandle,
     payload: WorkspaceWorktreeSymlinkPathsPayload,
 ) -> WorkspaceTerminalSettingsResponse {
-    let request_id = request_id();
-    let worktree_symlink_paths =
-        match validate_worktree_symlink_paths(&payload.worktree_symlink_paths) {
-            Ok(value) => value,
+    handle_command("workspace_update_worktree_symlink_paths", |context| {
+        let request_id = context.request_id();
+        let worktree_symlink_paths =
+            match validate_worktree_symlink_paths(&payload.worktree_symlink_paths) {
+                Ok(value) => value,
+                Err(error) => {
+                    return WorkspaceTerminalSettingsResponse {
+                        request_id,
+                        ok: false,
+                        workspace_root: None,
+                        workspace_meta: None,
+                        error: Some(error),
+                    }
+                }
+            };
+
+        let persisted_root = match read_persisted_active_workspace_root(&app) {
+            Ok(Some(value)) => value,
+            Ok(None) => {
+                return WorkspaceTerminalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    workspace_meta: None,
+                    error: Some("No active workspace selected.".to_string()),
+                }
+            }
             Err(error) => {
                 return WorkspaceTerminalSettingsResponse {
                     request_id,
@@ -2284,78 +4849,57 @@ fn workspace_update_worktree_symlink_paths(
             }
         };
 
-    let persisted_root = match read_persisted_active_workspace_root(&app) {
-        Ok(Some(value)) => value,
-        Ok(None) => {
-            return WorkspaceTerminalSettingsResponse {
-                request_id,
-                ok: false,
-                workspace_root: None,
-                workspace_meta: None,
-                error: Some("No active workspace selected.".to_string()),
-            }
-        }
-        Err(error) => {
-            return WorkspaceTerminalSettingsResponse {
-                request_id,
-                ok: false,
-                workspace_root: None,
-                workspace_meta: None,
-                error: Some(error),
+        let workspace_root = match validate_workspace_root_path(&persisted_root) {
+            Ok(root) => root,
+            Err(error) => {
+                return WorkspaceTerminalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: Some(persisted_root),
+                    workspace_meta: None,
+                    error: Some(error),
+                }
             }
-        }
-    };
+        };
 
-    let workspace_root = match validate_workspace_root_path(&persisted_root) {
-        Ok(root) => root,
-        Err(error) => {
-            return WorkspaceTerminalSettingsResponse {
-                request_id,
-                ok: false,
-                workspace_root: Some(persisted_root),
-                workspace_meta: None,
-                error: Some(error),
+        let (mut workspace_meta, _) = match ensure_workspace_meta(&workspace_root) {
+            Ok(result) => result,
+            Err(error) => {
+                return WorkspaceTerminalSettingsResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: Some(workspace_root.display().to_string()),
+                    workspace_meta: None,
+                    error: Some(error),
+                }
             }
-        }
-    };
+        };
 
-    let (mut workspace_meta, _) = match ensure_workspace_meta(&workspace_root) {
-        Ok(result) => result,
-        Err(error) => {
+        workspace_meta.worktree_symlink_paths = worktree_symlink_paths;
+        workspace_meta.onboarding_symlinks_configured = true;
+        workspace_meta.updated_at = now_iso();
+
+        let workspace_json = workspace_root.join(".groove").join("workspace.json");
+        if let Err(error) = write_workspace_meta_file(&workspace_json, &workspace_meta) {
             return WorkspaceTerminalSettingsResponse {
                 request_id,
                 ok: false,
                 workspace_root: Some(workspace_root.display().to_string()),
                 workspace_meta: None,
                 error: Some(error),
-            }
+            };
         }
-    };
 
-    workspace_meta.worktree_symlink_paths = worktree_symlink_paths;
-    workspace_meta.onboarding_symlinks_configured = true;
-    workspace_meta.updated_at = now_iso();
+        invalidate_workspace_caches(&app, &workspace_root);
 
-    let workspace_json = workspace_root.join(".groove").join("workspace.json");
-    if let Err(error) = write_workspace_meta_file(&workspace_json, &workspace_meta) {
-        return WorkspaceTerminalSettingsResponse {
+        WorkspaceTerminalSettingsResponse {
             request_id,
-            ok: false,
+            ok: true,
             workspace_root: Some(workspace_root.display().to_string()),
-            workspace_meta: None,
-            error: Some(error),
-        };
-    }
-
-    invalidate_workspace_context_cache(&app, &workspace_root);
-
-    WorkspaceTerminalSettingsResponse {
-        request_id,
-        ok: true,
-        workspace_root: Some(workspace_root.display().to_string()),
-        workspace_meta: Some(workspace_meta),
-        error: None,
-    }
+            workspace_meta: Some(workspace_meta),
+            error: None,
+        }
+    })
 }
 
 #[tauri::command]
@@ -2363,139 +4907,141 @@ fn workspace_list_symlink_entries(
     app: AppHandle,
     payload: WorkspaceBrowseEntriesPayload,
 ) -> WorkspaceBrowseEntriesResponse {
-    let request_id = request_id();
-    let relative_path = match normalize_browse_relative_path(payload.relative_path.as_deref()) {
-        Ok(value) => value,
-        Err(error) => {
-            return WorkspaceBrowseEntriesResponse {
-                request_id,
-                ok: false,
-                workspace_root: None,
-                relative_path: String::new(),
-                entries: Vec::new(),
-                error: Some(error),
+    handle_command("workspace_list_symlink_entries", |context| {
+        let request_id = context.request_id();
+        let relative_path = match normalize_browse_relative_path(payload.relative_path.as_deref()) {
+            Ok(value) => value,
+            Err(error) => {
+                return WorkspaceBrowseEntriesResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    relative_path: String::new(),
+                    entries: Vec::new(),
+                    error: Some(error),
+                }
             }
-        }
-    };
+        };
 
-    let persisted_root = match read_persisted_active_workspace_root(&app) {
-        Ok(Some(value)) => value,
-        Ok(None) => {
-            return WorkspaceBrowseEntriesResponse {
-                request_id,
-                ok: false,
-                workspace_root: None,
-                relative_path,
-                entries: Vec::new(),
-                error: Some("No active workspace selected.".to_string()),
+        let persisted_root = match read_persisted_active_workspace_root(&app) {
+            Ok(Some(value)) => value,
+            Ok(None) => {
+                return WorkspaceBrowseEntriesResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    relative_path,
+                    entries: Vec::new(),
+                    error: Some("No active workspace selected.".to_string()),
+                }
             }
-        }
-        Err(error) => {
-            return WorkspaceBrowseEntriesResponse {
-                request_id,
-                ok: false,
-                workspace_root: None,
-                relative_path,
-                entries: Vec::new(),
-                error: Some(error),
+            Err(error) => {
+                return WorkspaceBrowseEntriesResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: None,
+                    relative_path,
+                    entries: Vec::new(),
+                    error: Some(error),
+                }
             }
-        }
-    };
+        };
 
-    let workspace_root = match validate_workspace_root_path(&persisted_root) {
-        Ok(root) => root,
-        Err(error) => {
-            return WorkspaceBrowseEntriesResponse {
-                request_id,
-                ok: false,
-                workspace_root: Some(persisted_root),
-                relative_path,
-                entries: Vec::new(),
-                error: Some(error),
+        let workspace_root = match validate_workspace_root_path(&persisted_root) {
+            Ok(root) => root,
+            Err(error) => {
+                return WorkspaceBrowseEntriesResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: Some(persisted_root),
+                    relative_path,
+                    entries: Vec::new(),
+                    error: Some(error),
+                }
             }
-        }
-    };
-
-    let browse_root = if relative_path.is_empty() {
-        workspace_root.clone()
-    } else {
-        workspace_root.join(&relative_path)
-    };
-    if !path_is_directory(&browse_root) {
-        return WorkspaceBrowseEntriesResponse {
-            request_id,
-            ok: false,
-            workspace_root: Some(workspace_root.display().to_string()),
-            relative_path,
-            entries: Vec::new(),
-            error: Some("Requested path is not a directory in this workspace.".to_string()),
         };
-    }
 
-    let mut entries = Vec::new();
-    let read_dir = match fs::read_dir(&browse_root) {
-        Ok(value) => value,
-        Err(error) => {
+        let browse_root = if relative_path.is_empty() {
+            workspace_root.clone()
+        } else {
+            workspace_root.join(&relative_path)
+        };
+        if !path_is_directory(&browse_root) {
             return WorkspaceBrowseEntriesResponse {
                 request_id,
                 ok: false,
                 workspace_root: Some(workspace_root.display().to_string()),
                 relative_path,
                 entries: Vec::new(),
-                error: Some(format!(
-                    "Failed to read directory \"{}\": {error}",
-                    browse_root.display()
-                )),
-            }
+                error: Some("Requested path is not a directory in this workspace.".to_string()),
+            };
         }
-    };
 
-    for entry in read_dir {
-        let entry = match entry {
+        let mut entries = Vec::new();
+        let read_dir = match fs::read_dir(&browse_root) {
             Ok(value) => value,
-            Err(_) => continue,
+            Err(error) => {
+                return WorkspaceBrowseEntriesResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: Some(workspace_root.display().to_string()),
+                    relative_path,
+                    entries: Vec::new(),
+                    error: Some(format!(
+                        "Failed to read directory \"{}\": {error}",
+                        browse_root.display()
+                    )),
+                }
+            }
         };
-        let name = entry.file_name().to_string_lossy().to_string();
-        if name.trim().is_empty() {
-            continue;
-        }
 
-        if relative_path.is_empty() && is_restricted_worktree_symlink_path(&name) {
-            continue;
-        }
+        for entry in read_dir {
+            let entry = match entry {
+                Ok(value) => value,
+                Err(_) => continue,
+            };
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.trim().is_empty() {
+                continue;
+            }
 
-        let file_type = match entry.file_type() {
-            Ok(value) => value,
-            Err(_) => continue,
-        };
-        let child_relative_path = if relative_path.is_empty() {
-            name.clone()
-        } else {
-            format!("{}/{}", relative_path, name)
-        };
+            if relative_path.is_empty() && is_restricted_worktree_symlink_path(&name) {
+                continue;
+            }
 
-        entries.push(WorkspaceBrowseEntry {
-            name,
-            path: child_relative_path,
-            is_dir: file_type.is_dir(),
-        });
-    }
+            let file_type = match entry.file_type() {
+                Ok(value) => value,
+                Err(_) => continue,
+            };
+            let child_relative_path = if relative_path.is_empty() {
+                name.clone()
+            } else {
+                format!("{}/{}", relative_path, name)
+            };
 
-    entries.sort_by(|left, right| {
-        right
-            .is_dir
-            .cmp(&left.is_dir)
-            .then_with(|| left.name.to_lowercase().cmp(&right.name.to_lowercase()))
-    });
+            entries.push(WorkspaceBrowseEntry {
+                name,
+                path: child_relative_path,
+                is_dir: file_type.is_dir(),
+            });
+        }
 
-    WorkspaceBrowseEntriesResponse {
-        request_id,
-        ok: true,
-        workspace_root: Some(workspace_root.display().to_string()),
-        relative_path,
-        entries,
-        error: None,
-    }
+        entries.sort_by(|left, right| {
+            right
+                .is_dir
+                .cmp(&left.is_dir)
+                .then_with(|| left.name.to_lowercase().cmp(&right.name.to_lowercase()))
+        });
+
+        WorkspaceBrowseEntriesResponse {
+            request_id,
+            ok: true,
+            workspace_root: Some(workspace_root.display().to_string()),
+            relative_path,
+            entries,
+            error: None,
+        }
+    })
 }
 
 fn active_workspace_meta(app: &AppHandle) -> Result<(PathBuf, WorkspaceMeta), String> {
@@ -2511,7 +5057,7 @@ fn persist_workspace_meta_update(
 ) -> Result<(), String> {
     let workspace_json = workspace_root.join(".groove").join("workspace.json");
     write_workspace_meta_file(&workspace_json, workspace_meta)?;
-    invalidate_workspace_context_cache(app, workspace_root);
+    invalidate_workspace_caches(app, workspace_root);
     Ok(())
 }
 
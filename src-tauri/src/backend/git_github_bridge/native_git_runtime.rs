@@ -0,0 +1,166 @@
+// Optional libgit2 backend for the read-only git commands: `git_status`,
+// `git_current_branch`, `git_list_branches`, `git_ahead_behind` and
+// `git_list_file_states`. It is only built with the `native-git` feature.
+// Every function returns `None` when libgit2 cannot open or read the
+// repository (or the feature is off), and the command then runs the `git`
+// CLI as before. Paths inside WSL always go through the CLI.
+
+#[cfg(feature = "native-git")]
+mod native_git {
+    use super::*;
+    use git2::{BranchType, Repository, Status, StatusOptions};
+
+    fn open(path: &Path) -> Option<Repository> {
+        if wsl_location(path).is_some() {
+            return None;
+        }
+        Repository::open(path).ok()
+    }
+
+    /// The `XY` column of `git status --porcelain=v1` for one entry.
+    fn porcelain_code(status: Status) -> Option<[char; 2]> {
+        if status.is_ignored() {
+            return None;
+        }
+        if status.is_conflicted() {
+            return Some(['U', 'U']);
+        }
+        if status.is_wt_new() && !status.is_index_new() {
+            return Some(['?', '?']);
+        }
+
+        let index = if status.is_index_new() {
+            'A'
+        } else if status.is_index_renamed() {
+            'R'
+        } else if status.is_index_deleted() {
+            'D'
+        } else if status.is_index_typechange() {
+            'T'
+        } else if status.is_index_modified() {
+            'M'
+        } else {
+            ' '
+        };
+        let worktree = if status.is_wt_deleted() {
+            'D'
+        } else if status.is_wt_renamed() {
+            'R'
+        } else if status.is_wt_typechange() {
+            'T'
+        } else if status.is_wt_modified() {
+            'M'
+        } else {
+            ' '
+        };
+        (index != ' ' || worktree != ' ').then_some([index, worktree])
+    }
+
+    /// Status rendered as `git status --porcelain=v1` output, so the CLI
+    /// parsers apply to both backends.
+    pub(super) fn porcelain_status(path: &Path) -> Option<String> {
+        let repository = open(path)?;
+        let _permit = acquire_git_operation(path, &["status"]);
+        let mut options = StatusOptions::new();
+        options
+            .include_untracked(true)
+            .recurse_untracked_dirs(false)
+            .renames_head_to_index(true);
+        let statuses = repository.statuses(Some(&mut options)).ok()?;
+
+        let mut output = String::new();
+        for entry in statuses.iter() {
+            let Some([x, y]) = porcelain_code(entry.status()) else {
+                continue;
+            };
+            let path = entry.path()?;
+            let renamed_from = entry
+                .head_to_index()
+                .filter(|_| x == 'R')
+                .and_then(|delta| delta.old_file().path())
+                .map(|old| old.to_string_lossy().to_string());
+            match renamed_from {
+                Some(old) => output.push_str(&format!("{x}{y} {old} -> {path}\n")),
+                None => output.push_str(&format!("{x}{y} {path}\n")),
+            }
+        }
+        Some(output)
+    }
+
+    /// Like `git branch --show-current`: `Some(None)` for a detached HEAD.
+    /// An unborn branch is left to the CLI.
+    pub(super) fn current_branch(path: &Path) -> Option<Option<String>> {
+        let repository = open(path)?;
+        let head = repository.head().ok()?;
+        if !head.is_branch() {
+            return Some(None);
+        }
+        Some(head.shorthand().map(str::to_string))
+    }
+
+    /// Local branch names, sorted like `git branch`.
+    pub(super) fn list_branches(path: &Path) -> Option<Vec<String>> {
+        let repository = open(path)?;
+        let mut branches = Vec::new();
+        for branch in repository.branches(Some(BranchType::Local)).ok()? {
+            let (branch, _) = branch.ok()?;
+            if let Some(name) = branch.name().ok()? {
+                branches.push(name.to_string());
+            }
+        }
+        branches.sort();
+        Some(branches)
+    }
+
+    /// Commits ahead of and behind the upstream of the current branch;
+    /// `(0, 0)` without an upstream, like `git status -sb`.
+    pub(super) fn ahead_behind(path: &Path) -> Option<(u32, u32)> {
+        let repository = open(path)?;
+        let head = repository.head().ok()?;
+        if !head.is_branch() {
+            return Some((0, 0));
+        }
+        let local = head.target()?;
+        let Ok(upstream) = git2::Branch::wrap(head).upstream() else {
+            return Some((0, 0));
+        };
+        let upstream = upstream.get().target()?;
+        let (ahead, behind) = repository.graph_ahead_behind(local, upstream).ok()?;
+        Some((
+            u32::try_from(ahead).unwrap_or(u32::MAX),
+            u32::try_from(behind).unwrap_or(u32::MAX),
+        ))
+    }
+}
+
+#[cfg(not(feature = "native-git"))]
+mod native_git {
+    use std::path::Path;
+
+    pub(super) fn porcelain_status(_path: &Path) -> Option<String> {
+        None
+    }
+
+    pub(super) fn current_branch(_path: &Path) -> Option<Option<String>> {
+        None
+    }
+
+    pub(super) fn list_branches(_path: &Path) -> Option<Vec<String>> {
+        None
+    }
+
+    pub(super) fn ahead_behind(_path: &Path) -> Option<(u32, u32)> {
+        None
+    }
+}
+
+/// Wraps output produced by the native backend as a successful CLI run, so
+/// the commands handle it like `git` output.
+fn native_git_result(stdout: String) -> CommandResult {
+    CommandResult {
+        exit_code: Some(0),
+        stdout,
+        stderr: String::new(),
+        error: None,
+    }
+}
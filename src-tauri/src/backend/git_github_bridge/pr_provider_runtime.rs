@@ -0,0 +1,593 @@
+// Pull requests on hosts other than GitHub. `gh_pr_list`, `gh_pr_create` and
+// `gh_pr_checks` ask `pr_provider_for` first: a GitLab remote goes through
+// `glab`, a Gitea or Forgejo remote through the host's REST API (with `curl`,
+// authenticated by `GITEA_TOKEN` or `FORGEJO_TOKEN`). Results come back in
+// the same shapes `gh` produces, so the frontend does not tell them apart.
+// The provider is picked from the remote host, or from `git config
+// groove.prProvider` (`github`, `gitlab` or `gitea`) for self-hosted
+// instances whose host name says nothing. GitHub and unknown hosts get no
+// provider and keep using `gh`.
+
+trait PrProvider {
+    /// PRs (merge requests on GitLab) whose head is `branch`.
+    fn list(&self, cwd: &Path, branch: &str) -> Result<Vec<GhPrSummary>, String>;
+    /// Opens a PR from `branch` and returns its number and URL.
+    fn create(
+        &self,
+        cwd: &Path,
+        branch: &str,
+        payload: &GhPrCreatePayload,
+    ) -> Result<(Option<i64>, Option<String>), String>;
+    /// The PR's number, its checks and whether it can be merged.
+    fn checks(&self, cwd: &Path, selector: &str) -> Result<PrProviderChecks, String>;
+}
+
+struct PrProviderChecks {
+    number: i64,
+    checks: Vec<GhPrCheck>,
+    mergeable: Option<String>,
+    merge_state_status: Option<String>,
+}
+
+/// `gitlab`, `gitea` or `github` for `host`; a configured value wins.
+fn pr_provider_kind(host: &str, configured: Option<&str>) -> Option<&'static str> {
+    match configured
+        .map(|value| value.trim().to_ascii_lowercase())
+        .as_deref()
+    {
+        Some("github") => return Some("github"),
+        Some("gitlab") => return Some("gitlab"),
+        Some("gitea" | "forgejo") => return Some("gitea"),
+        _ => {}
+    }
+    let host = host.to_ascii_lowercase();
+    if host == "github.com" || host.ends_with(".github.com") {
+        Some("github")
+    } else if host.contains("gitlab") {
+        Some("gitlab")
+    } else if host.contains("gitea") || host.contains("forgejo") || host == "codeberg.org" {
+        Some("gitea")
+    } else {
+        None
+    }
+}
+
+fn pr_provider_for(worktree_path: &Path) -> Option<Box<dyn PrProvider>> {
+    let (_, url) = resolve_remote_url_with_fallback(worktree_path)?;
+    let (host, path) = split_remote_url(&url)?;
+    let configured = run_capture_command(
+        worktree_path,
+        "git",
+        &["config", "--get", "groove.prProvider"],
+    );
+    match pr_provider_kind(&host, first_non_empty_line(&configured.stdout).as_deref())? {
+        "gitlab" => Some(Box::new(GitLabPrProvider)),
+        "gitea" => {
+            let (Some(owner), Some(repo)) = owner_repo_from_path(&path) else {
+                return None;
+            };
+            Some(Box::new(GiteaPrProvider { host, owner, repo }))
+        }
+        _ => None,
+    }
+}
+
+/// The number in a bare `12`, or in a URL ending in `<marker>12`.
+fn provider_pr_number(selector: &str, marker: &str) -> Option<i64> {
+    let digits = match selector.split_once(marker) {
+        Some((_, after)) => after,
+        None => selector,
+    };
+    let digits: String = digits
+        .chars()
+        .take_while(|character| character.is_ascii_digit())
+        .collect();
+    if digits.is_empty() || (!selector.contains(marker) && digits.len() != selector.len()) {
+        return None;
+    }
+    digits.parse::<i64>().ok()
+}
+
+fn provider_command_error(result: CommandResult, binary: &str, missing: &str) -> String {
+    if let Some(error) = result.error {
+        return if error.contains(&format!("Failed to execute {binary}")) {
+            missing.to_string()
+        } else {
+            error
+        };
+    }
+    first_non_empty_line(&result.stderr)
+        .or_else(|| first_non_empty_line(&result.stdout))
+        .unwrap_or_else(|| format!("{binary} exited with {:?}.", result.exit_code))
+}
+
+// ---- GitLab ----------------------------------------------------------------
+
+struct GitLabPrProvider;
+
+#[derive(serde::Deserialize)]
+struct GitLabPipelineRaw {
+    id: i64,
+    #[serde(default)]
+    status: String,
+}
+
+#[derive(serde::Deserialize)]
+struct GitLabMergeRequestRaw {
+    iid: i64,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    state: String,
+    #[serde(default)]
+    web_url: String,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    head_pipeline: Option<GitLabPipelineRaw>,
+    #[serde(default)]
+    detailed_merge_status: Option<String>,
+}
+
+#[derive(serde::Deserialize)]
+struct GitLabJobRaw {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    status: String,
+    #[serde(default)]
+    stage: Option<String>,
+    #[serde(default)]
+    web_url: Option<String>,
+    #[serde(default)]
+    allow_failure: bool,
+}
+
+fn run_glab_in(cwd: &Path, args: &[&str]) -> Result<String, String> {
+    let result = run_capture_command(cwd, "glab", args);
+    if result.error.is_some() || result.exit_code != Some(0) {
+        return Err(provider_command_error(
+            result,
+            "glab",
+            "GitLab CLI (glab) is not installed or not on PATH.",
+        ));
+    }
+    Ok(result.stdout)
+}
+
+/// GitLab's `opened`/`merged`/`closed` as `gh` spells them.
+fn gitlab_state(state: &str) -> String {
+    match state {
+        "opened" => "OPEN".to_string(),
+        other => other.to_ascii_uppercase(),
+    }
+}
+
+/// A GitLab pipeline or job status as a check `status` and `conclusion`.
+fn gitlab_check_state(status: &str, allow_failure: bool) -> (String, Option<String>) {
+    let conclusion = match status {
+        "success" => "SUCCESS",
+        "failed" if allow_failure => "NEUTRAL",
+        "failed" => "FAILURE",
+        "canceled" => "CANCELLED",
+        // Manual jobs never start on their own; they must not keep the PR
+        // pending.
+        "skipped" | "manual" => "SKIPPED",
+        "running" => return ("IN_PROGRESS".to_string(), None),
+        _ => return ("QUEUED".to_string(), None),
+    };
+    ("COMPLETED".to_string(), Some(conclusion.to_string()))
+}
+
+impl PrProvider for GitLabPrProvider {
+    fn list(&self, cwd: &Path, branch: &str) -> Result<Vec<GhPrSummary>, String> {
+        let stdout = run_glab_in(
+            cwd,
+            &[
+                "mr",
+                "list",
+                "--all",
+                "--source-branch",
+                branch,
+                "--output",
+                "json",
+            ],
+        )?;
+        let items = serde_json::from_str::<Vec<GitLabMergeRequestRaw>>(&stdout)
+            .map_err(|error| format!("Could not parse glab mr list output: {error}"))?;
+        Ok(items
+            .into_iter()
+            .map(|item| GhPrSummary {
+                number: item.iid,
+                title: item.title,
+                state: gitlab_state(&item.state),
+                url: item.web_url,
+                is_draft: item.draft,
+                checks_status: item.head_pipeline.map(|pipeline| {
+                    let (status, conclusion) = gitlab_check_state(&pipeline.status, false);
+                    let check = GhPrCheck {
+                        name: String::new(),
+                        status,
+                        conclusion,
+                        details_url: None,
+                        workflow: None,
+                    };
+                    checks_status(&[check]).unwrap_or("pending").to_string()
+                }),
+            })
+            .collect())
+    }
+
+    fn create(
+        &self,
+        cwd: &Path,
+        branch: &str,
+        payload: &GhPrCreatePayload,
+    ) -> Result<(Option<i64>, Option<String>), String> {
+        let title = normalize_optional(payload.title.clone());
+        let body = payload.body.clone().unwrap_or_default();
+        let base = payload.base.trim();
+        let mut args = vec![
+            "mr",
+            "create",
+            "--source-branch",
+            branch,
+            "--target-branch",
+            base,
+            "--yes",
+        ];
+        match title.as_deref() {
+            Some(title) => args.extend(["--title", title, "--description", body.as_str()]),
+            None => args.push("--fill"),
+        }
+        if payload.draft {
+            args.push("--draft");
+        }
+        let stdout = run_glab_in(cwd, &args)?;
+        let url = stdout
+            .lines()
+            .rev()
+            .map(str::trim)
+            .find(|line| line.contains("/merge_requests/"))
+            .map(str::to_string);
+        let number = url
+            .as_deref()
+            .and_then(|url| provider_pr_number(url, "/merge_requests/"));
+        Ok((number, url))
+    }
+
+    fn checks(&self, cwd: &Path, selector: &str) -> Result<PrProviderChecks, String> {
+        let iid = provider_pr_number(selector, "/merge_requests/")
+            .ok_or_else(|| "Selector must be a merge request number or URL.".to_string())?;
+        let stdout = run_glab_in(cwd, &["api", &format!("projects/:id/merge_requests/{iid}")])?;
+        let merge_request = serde_json::from_str::<GitLabMergeRequestRaw>(&stdout)
+            .map_err(|error| format!("Could not parse the merge request: {error}"))?;
+
+        let mut checks = Vec::new();
+        if let Some(pipeline) = merge_request.head_pipeline {
+            let stdout = run_glab_in(
+                cwd,
+                &[
+                    "api",
+                    &format!("projects/:id/pipelines/{}/jobs", pipeline.id),
+                ],
+            )?;
+            let jobs = serde_json::from_str::<Vec<GitLabJobRaw>>(&stdout)
+                .map_err(|error| format!("Could not parse the pipeline jobs: {error}"))?;
+            checks = jobs
+                .into_iter()
+                .map(|job| {
+                    let (status, conclusion) = gitlab_check_state(&job.status, job.allow_failure);
+                    GhPrCheck {
+                        name: job.name,
+                        status,
+                        conclusion,
+                        details_url: normalize_optional(job.web_url),
+                        workflow: normalize_optional(job.stage),
+                    }
+                })
+                .collect();
+        }
+
+        let merge_status = normalize_optional(merge_request.detailed_merge_status);
+        Ok(PrProviderChecks {
+            number: merge_request.iid,
+            checks,
+            mergeable: merge_status.as_deref().map(|status| {
+                match status {
+                    "mergeable" => "MERGEABLE",
+                    "conflict" | "broken_status" => "CONFLICTING",
+                    _ => "UNKNOWN",
+                }
+                .to_string()
+            }),
+            merge_state_status: merge_status.map(|status| status.to_ascii_uppercase()),
+        })
+    }
+}
+
+// ---- Gitea / Forgejo -------------------------------------------------------
+
+struct GiteaPrProvider {
+    host: String,
+    owner: String,
+    repo: String,
+}
+
+#[derive(serde::Deserialize)]
+struct GiteaRefRaw {
+    #[serde(default, rename = "ref")]
+    name: String,
+    #[serde(default)]
+    sha: String,
+}
+
+#[derive(serde::Deserialize)]
+struct GiteaPullRaw {
+    number: i64,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    state: String,
+    #[serde(default)]
+    merged: bool,
+    #[serde(default)]
+    html_url: String,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    mergeable: Option<bool>,
+    head: GiteaRefRaw,
+}
+
+#[derive(serde::Deserialize)]
+struct GiteaStatusRaw {
+    #[serde(default)]
+    context: String,
+    #[serde(default)]
+    status: String,
+    #[serde(default)]
+    target_url: Option<String>,
+}
+
+#[derive(serde::Deserialize)]
+struct GiteaCombinedStatusRaw {
+    #[serde(default)]
+    statuses: Vec<GiteaStatusRaw>,
+}
+
+/// A quoted value for a curl config file.
+fn curl_config_value(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Gitea also marks drafts with a `WIP:` title prefix.
+fn gitea_is_draft(pull: &GiteaPullRaw) -> bool {
+    let title = pull.title.trim_start().to_ascii_lowercase();
+    pull.draft || title.starts_with("wip:") || title.starts_with("[wip]")
+}
+
+fn gitea_check(status: GiteaStatusRaw) -> GhPrCheck {
+    let pending = matches!(status.status.as_str(), "pending" | "");
+    GhPrCheck {
+        name: status.context,
+        status: if pending { "PENDING" } else { "COMPLETED" }.to_string(),
+        conclusion: (!pending).then(|| match status.status.as_str() {
+            "warning" => "NEUTRAL".to_string(),
+            other => other.to_ascii_uppercase(),
+        }),
+        details_url: normalize_optional(status.target_url),
+        workflow: None,
+    }
+}
+
+impl GiteaPrProvider {
+    /// Calls `https://<host>/api/v1/repos/<owner>/<repo><endpoint>`. The
+    /// request goes to curl as a config file on stdin so the token stays out
+    /// of the process list.
+    fn request(
+        &self,
+        cwd: &Path,
+        method: &str,
+        endpoint: &str,
+        body: Option<&serde_json::Value>,
+    ) -> Result<String, String> {
+        let url = format!(
+            "https://{}/api/v1/repos/{}/{}{endpoint}",
+            self.host, self.owner, self.repo
+        );
+        let mut config = vec![
+            format!("url = {}", curl_config_value(&url)),
+            format!("request = {}", curl_config_value(method)),
+            "header = \"Accept: application/json\"".to_string(),
+        ];
+        let token = ["GITEA_TOKEN", "FORGEJO_TOKEN"]
+            .iter()
+            .find_map(|name| std::env::var(name).ok().filter(|value| !value.is_empty()));
+        if let Some(token) = token {
+            config.push(format!(
+                "header = {}",
+                curl_config_value(&format!("Authorization: token {token}"))
+            ));
+        }
+        if let Some(body) = body {
+            config.push("header = \"Content-Type: application/json\"".to_string());
+            config.push(format!("data = {}", curl_config_value(&body.to_string())));
+        }
+
+        let result = run_capture_command_with_stdin(
+            cwd,
+            "curl",
+            &[
+                "--silent",
+                "--show-error",
+                "-K",
+                "-",
+                "-w",
+                "\n%{http_code}",
+            ],
+            &(config.join("\n") + "\n"),
+        );
+        if result.error.is_some() || result.exit_code != Some(0) {
+            return Err(provider_command_error(
+                result,
+                "curl",
+                "curl is not installed or not on PATH.",
+            ));
+        }
+        let (response, status) = result
+            .stdout
+            .trim_end()
+            .rsplit_once('\n')
+            .unwrap_or(("", result.stdout.trim()));
+        let status = status.trim().parse::<u16>().unwrap_or(0);
+        if !(200..300).contains(&status) {
+            let message = serde_json::from_str::<serde_json::Value>(response)
+                .ok()
+                .and_then(|value| value.get("message")?.as_str().map(str::to_string))
+                .unwrap_or_else(|| format!("{} answered HTTP {status}.", self.host));
+            return Err(message);
+        }
+        Ok(response.to_string())
+    }
+
+    fn pull(&self, cwd: &Path, number: i64) -> Result<GiteaPullRaw, String> {
+        let response = self.request(cwd, "GET", &format!("/pulls/{number}"), None)?;
+        serde_json::from_str::<GiteaPullRaw>(&response)
+            .map_err(|error| format!("Could not parse the pull request: {error}"))
+    }
+}
+
+impl PrProvider for GiteaPrProvider {
+    fn list(&self, cwd: &Path, branch: &str) -> Result<Vec<GhPrSummary>, String> {
+        let response = self.request(cwd, "GET", "/pulls?state=all&limit=50", None)?;
+        let pulls = serde_json::from_str::<Vec<GiteaPullRaw>>(&response)
+            .map_err(|error| format!("Could not parse the pull requests: {error}"))?;
+        Ok(pulls
+            .into_iter()
+            .filter(|pull| pull.head.name == branch)
+            .map(|pull| GhPrSummary {
+                is_draft: gitea_is_draft(&pull),
+                number: pull.number,
+                state: if pull.merged {
+                    "MERGED".to_string()
+                } else {
+                    pull.state.to_ascii_uppercase()
+                },
+                title: pull.title,
+                url: pull.html_url,
+                checks_status: None,
+            })
+            .collect())
+    }
+
+    fn create(
+        &self,
+        cwd: &Path,
+        branch: &str,
+        payload: &GhPrCreatePayload,
+    ) -> Result<(Option<i64>, Option<String>), String> {
+        // Like `gh pr create --fill`: the last commit's subject.
+        let title = match normalize_optional(payload.title.clone()) {
+            Some(title) => title,
+            None => first_non_empty_line(
+                &run_git_command_at_path(cwd, &["log", "-1", "--format=%s"]).stdout,
+            )
+            .ok_or_else(|| "A title is required.".to_string())?,
+        };
+        let title = if payload.draft && !title.to_ascii_lowercase().starts_with("wip:") {
+            format!("WIP: {title}")
+        } else {
+            title
+        };
+        let body = serde_json::json!({
+            "head": branch,
+            "base": payload.base.trim(),
+            "title": title,
+            "body": payload.body.clone().unwrap_or_default(),
+        });
+        let response = self.request(cwd, "POST", "/pulls", Some(&body))?;
+        let pull = serde_json::from_str::<GiteaPullRaw>(&response)
+            .map_err(|error| format!("Could not parse the pull request: {error}"))?;
+        Ok((Some(pull.number), normalize_optional(Some(pull.html_url))))
+    }
+
+    fn checks(&self, cwd: &Path, selector: &str) -> Result<PrProviderChecks, String> {
+        let number = provider_pr_number(selector, "/pulls/")
+            .ok_or_else(|| "Selector must be a pull request number or URL.".to_string())?;
+        let pull = self.pull(cwd, number)?;
+        let response = self.request(
+            cwd,
+            "GET",
+            &format!("/commits/{}/status", pull.head.sha),
+            None,
+        )?;
+        let combined = serde_json::from_str::<GiteaCombinedStatusRaw>(&response)
+            .map_err(|error| format!("Could not parse the commit status: {error}"))?;
+        Ok(PrProviderChecks {
+            number: pull.number,
+            checks: combined.statuses.into_iter().map(gitea_check).collect(),
+            mergeable: pull.mergeable.map(|mergeable| {
+                if mergeable {
+                    "MERGEABLE"
+                } else {
+                    "CONFLICTING"
+                }
+                .to_string()
+            }),
+            merge_state_status: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod pr_provider_runtime_tests {
+    use super::*;
+
+    #[test]
+    fn picks_the_provider_from_the_host_or_the_configured_value() {
+        assert_eq!(pr_provider_kind("github.com", None), Some("github"));
+        assert_eq!(pr_provider_kind("gitlab.com", None), Some("gitlab"));
+        assert_eq!(pr_provider_kind("gitlab.example.org", None), Some("gitlab"));
+        assert_eq!(pr_provider_kind("codeberg.org", None), Some("gitea"));
+        assert_eq!(pr_provider_kind("forgejo.example.org", None), Some("gitea"));
+        assert_eq!(pr_provider_kind("git.example.org", None), None);
+        assert_eq!(
+            pr_provider_kind("git.example.org", Some(" GitLab ")),
+            Some("gitlab")
+        );
+        assert_eq!(
+            pr_provider_kind("gitlab.com", Some("forgejo")),
+            Some("gitea")
+        );
+    }
+
+    #[test]
+    fn reads_pr_numbers_from_numbers_and_urls() {
+        assert_eq!(provider_pr_number("12", "/pulls/"), Some(12));
+        assert_eq!(
+            provider_pr_number("https://codeberg.org/o/r/pulls/7", "/pulls/"),
+            Some(7)
+        );
+        assert_eq!(
+            provider_pr_number(
+                "https://gitlab.com/o/r/-/merge_requests/31",
+                "/merge_requests/"
+            ),
+            Some(31)
+        );
+        assert_eq!(provider_pr_number("12a", "/pulls/"), None);
+        assert_eq!(provider_pr_number("--web", "/pulls/"), None);
+    }
+
+    #[test]
+    fn maps_gitlab_job_states_to_checks() {
+        assert_eq!(
+            gitlab_check_state("failed", true),
+            ("COMPLETED".to_string(), Some("NEUTRAL".to_string()))
+        );
+        assert_eq!(gitlab_check_state("running", false).1, None);
+        assert_eq!(gitlab_state("opened"), "OPEN");
+        assert_eq!(curl_config_value("a\"b\\c"), "\"a\\\"b\\\\c\"");
+    }
+}
@@ -0,0 +1,261 @@
+// Worktree verbs behind `worktree_action`, the one entry point the command
+// palette and keyboard shortcuts dispatch through. An action first checks
+// what the worktree can do right now (running or not, remote, upstream),
+// then runs the command it maps to with a payload built from the worktree
+// context and the caller's `options`.
+
+/// What a worktree can do when an action is dispatched. Only the fields the
+/// action looks at are gathered.
+#[derive(Debug, Clone, Copy, Default)]
+struct WorktreeActionCapabilities {
+    running: bool,
+    has_remote: bool,
+    has_upstream: bool,
+}
+
+fn normalize_worktree_action(action: &str) -> Result<&'static str, String> {
+    let action = action.trim();
+    WORKTREE_ACTIONS
+        .iter()
+        .find(|known| **known == action)
+        .copied()
+        .ok_or_else(|| format!("action must be one of: {}.", WORKTREE_ACTIONS.join(", ")))
+}
+
+/// Options each action accepts; they are passed on as fields of the payload
+/// of the command it runs.
+fn worktree_action_option_keys(action: &str) -> &'static [&'static str] {
+    match action {
+        "play" => &["target", "opencodeLogFile"],
+        "openEditor" => &["command"],
+        "push" => &["setUpstream", "forceWithLease", "branch"],
+        "pull" => &["rebase"],
+        "stop" => &["instanceId"],
+        "remove" => &["force", "acknowledge"],
+        "setTestingTarget" => &["playGrooveCommand"],
+        _ => &[],
+    }
+}
+
+fn validate_worktree_action_options(
+    action: &str,
+    options: &serde_json::Map<String, serde_json::Value>,
+) -> Result<(), String> {
+    let allowed = worktree_action_option_keys(action);
+    match options.keys().find(|key| !allowed.contains(&key.as_str())) {
+        Some(key) if allowed.is_empty() => {
+            Err(format!("{action} does not take options; got {key}."))
+        }
+        Some(key) => Err(format!(
+            "{action} does not take the {key} option; expected one of: {}.",
+            allowed.join(", ")
+        )),
+        None => Ok(()),
+    }
+}
+
+fn gather_worktree_action_capabilities(
+    app: &AppHandle,
+    workspace_root: &Path,
+    worktree: &str,
+    worktree_path: &Path,
+    action: &str,
+) -> WorktreeActionCapabilities {
+    let mut capabilities = WorktreeActionCapabilities::default();
+    match action {
+        "play" | "stop" => {
+            capabilities.running = read_running_grooves(app, workspace_root)
+                .unwrap_or_default()
+                .iter()
+                .any(|record| record.worktree == worktree);
+        }
+        "push" => {
+            let remotes = run_git_command_at_path(worktree_path, &["remote"]);
+            capabilities.has_remote =
+                remotes.exit_code == Some(0) && !remotes.stdout.trim().is_empty();
+        }
+        "pull" => {
+            let upstream = run_git_command_at_path(
+                worktree_path,
+                &[
+                    "rev-parse",
+                    "--abbrev-ref",
+                    "--symbolic-full-name",
+                    "@{upstream}",
+                ],
+            );
+            capabilities.has_upstream = upstream.exit_code == Some(0);
+        }
+        _ => {}
+    }
+    capabilities
+}
+
+fn check_worktree_action_capabilities(
+    action: &str,
+    capabilities: WorktreeActionCapabilities,
+) -> Result<(), String> {
+    match action {
+        "play" if capabilities.running => {
+            Err("Worktree is already running; stop it first.".to_string())
+        }
+        "stop" if !capabilities.running => Err("Worktree is not running.".to_string()),
+        "push" if !capabilities.has_remote => Err("Worktree has no remote to push to.".to_string()),
+        "pull" if !capabilities.has_upstream => {
+            Err("Worktree branch has no upstream to pull from.".to_string())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The payload of the command `action` runs: defaults, then the caller's
+/// options, then the worktree context, which options cannot override.
+fn worktree_action_command_payload(
+    action: &str,
+    payload: &WorktreeActionPayload,
+    worktree: &str,
+    worktree_path: &Path,
+) -> serde_json::Value {
+    let mut command_payload = serde_json::Map::new();
+    if action == "play" {
+        command_payload.insert("target".to_string(), serde_json::json!(worktree));
+    }
+    command_payload.extend(payload.options.clone());
+
+    let context = match action {
+        "push" | "pull" => serde_json::json!({ "path": worktree_path.display().to_string() }),
+        "setTestingTarget" => serde_json::json!({ "worktree": worktree }),
+        _ => serde_json::json!({
+            "rootName": payload.root_name,
+            "knownWorktrees": payload.known_worktrees,
+            "workspaceMeta": payload.workspace_meta,
+            "worktree": worktree,
+            "dir": payload.dir,
+        }),
+    };
+    if let serde_json::Value::Object(context) = context {
+        command_payload.extend(context);
+    }
+    match action {
+        "play" => {
+            command_payload.insert("action".to_string(), serde_json::json!("go"));
+        }
+        "remove" => {
+            command_payload.insert("target".to_string(), serde_json::json!(worktree));
+        }
+        _ => {}
+    }
+    serde_json::Value::Object(command_payload)
+}
+
+/// Runs the command an action maps to with the payload built for it.
+fn run_worktree_action<P, R>(
+    payload: serde_json::Value,
+    run: impl FnOnce(P) -> R,
+) -> Result<ActionReplay, String>
+where
+    P: serde::de::DeserializeOwned,
+    R: CommandResponse + Serialize,
+{
+    let payload = serde_json::from_value::<P>(payload)
+        .map_err(|error| format!("Invalid options: {error}"))?;
+    let response = run(payload);
+    Ok(ActionReplay {
+        ok: response.command_ok(),
+        error: response.command_error().map(str::to_string),
+        result: serde_json::to_value(&response)
+            .map_err(|error| format!("Failed to serialize the action response: {error}"))?,
+    })
+}
+
+/// Starts the editor on the worktree and returns the program it ran.
+fn open_worktree_in_editor(worktree_path: &Path, command: Option<&str>) -> Result<String, String> {
+    let command = command
+        .map(str::trim)
+        .filter(|command| !command.is_empty())
+        .unwrap_or(DEFAULT_WORKTREE_EDITOR_COMMAND);
+    let (program, args) = parse_custom_terminal_command(command, worktree_path)?;
+    ensure_command_program_resolves(&program)?;
+    Command::new(&program)
+        .args(&args)
+        .current_dir(worktree_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| program.clone())
+        .map_err(|error| format!("Failed to start {program}: {error}"))
+}
+
+#[cfg(test)]
+mod action_runtime_tests {
+    use super::*;
+
+    fn payload(action: &str, options: serde_json::Value) -> WorktreeActionPayload {
+        WorktreeActionPayload {
+            root_name: Some("repo".to_string()),
+            known_worktrees: vec!["feature".to_string()],
+            workspace_meta: None,
+            worktree: "feature".to_string(),
+            action: action.to_string(),
+            options: options.as_object().cloned().unwrap_or_default(),
+            dir: None,
+        }
+    }
+
+    #[test]
+    fn actions_and_their_options_are_validated() {
+        assert_eq!(normalize_worktree_action(" pull "), Ok("pull"));
+        assert!(normalize_worktree_action("rebase").is_err());
+
+        let options = payload("pull", serde_json::json!({ "rebase": true })).options;
+        assert!(validate_worktree_action_options("pull", &options).is_ok());
+        assert!(validate_worktree_action_options("push", &options).is_err());
+        assert!(validate_worktree_action_options("openTerminal", &options).is_err());
+    }
+
+    #[test]
+    fn capability_checks_refuse_actions_the_worktree_cannot_take() {
+        let running = WorktreeActionCapabilities {
+            running: true,
+            ..WorktreeActionCapabilities::default()
+        };
+        let idle = WorktreeActionCapabilities::default();
+
+        assert!(check_worktree_action_capabilities("play", running).is_err());
+        assert!(check_worktree_action_capabilities("play", idle).is_ok());
+        assert!(check_worktree_action_capabilities("stop", idle).is_err());
+        assert!(check_worktree_action_capabilities("push", idle).is_err());
+        assert!(check_worktree_action_capabilities("pull", idle).is_err());
+        assert!(check_worktree_action_capabilities("remove", running).is_ok());
+    }
+
+    #[test]
+    fn options_cannot_override_the_worktree_context() {
+        let play = payload(
+            "play",
+            serde_json::json!({ "target": "web", "worktree": "other" }),
+        );
+        let command_payload = worktree_action_command_payload(
+            "play",
+            &play,
+            "feature",
+            Path::new("/repo/.worktrees/feature"),
+        );
+        assert_eq!(command_payload["worktree"], "feature");
+        assert_eq!(command_payload["target"], "web");
+        assert_eq!(command_payload["action"], "go");
+
+        let pull = payload("pull", serde_json::json!({ "rebase": true }));
+        let command_payload = worktree_action_command_payload(
+            "pull",
+            &pull,
+            "feature",
+            Path::new("/repo/.worktrees/feature"),
+        );
+        assert_eq!(
+            command_payload,
+            serde_json::json!({ "path": "/repo/.worktrees/feature", "rebase": true })
+        );
+    }
+}
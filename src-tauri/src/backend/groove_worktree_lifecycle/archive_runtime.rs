@@ -0,0 +1,339 @@
+// Worktree archives behind `groove_archive`, `groove_archive_list` and
+// `groove_unarchive`. An archive is `.groove/archives/<id>.tar.gz` holding
+// the diff of the worktree against the merge base with its base branch
+// (`changes.patch`, committed and uncommitted work alike), its untracked
+// files (`untracked.tar`), its `.groove/logs` (`logs.tar`) and
+// `archive.json`. Restoring creates a worktree at the merge base and applies
+// all of it there.
+
+fn groove_archives_dir(workspace_root: &Path) -> PathBuf {
+    workspace_root.join(".groove").join(GROOVE_ARCHIVES_DIR)
+}
+
+fn groove_archive_id(worktree: &str, now: OffsetDateTime) -> String {
+    format!("{worktree}-{}", now.unix_timestamp())
+}
+
+/// Ids name files directly under the archives directory.
+fn validate_groove_archive_id(id: &str) -> Result<String, String> {
+    let id = id.trim();
+    if id.is_empty() {
+        return Err("id is required and must be a non-empty string.".to_string());
+    }
+    let valid = !id.starts_with('.')
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if !valid {
+        return Err("id is not a valid archive id.".to_string());
+    }
+    Ok(id.to_string())
+}
+
+/// Archives of the workspace, newest first. Unreadable metadata files are
+/// skipped.
+fn read_groove_archives(workspace_root: &Path) -> Vec<GrooveArchiveMetadata> {
+    let Ok(entries) = fs::read_dir(groove_archives_dir(workspace_root)) else {
+        return Vec::new();
+    };
+    let mut archives = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "json")
+        })
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .filter_map(|raw| serde_json::from_str::<GrooveArchiveMetadata>(&raw).ok())
+        .collect::<Vec<_>>();
+    archives.sort_by(|left, right| {
+        right
+            .archived_at
+            .cmp(&left.archived_at)
+            .then_with(|| left.id.cmp(&right.id))
+    });
+    archives
+}
+
+fn read_groove_archive(workspace_root: &Path, id: &str) -> Result<GrooveArchiveMetadata, String> {
+    let path = groove_archives_dir(workspace_root).join(format!("{id}.json"));
+    let raw = fs::read_to_string(&path).map_err(|_| format!("No archive with id {id}."))?;
+    serde_json::from_str(&raw)
+        .map_err(|error| format!("Failed to parse {}: {error}", path.display()))
+}
+
+fn remove_groove_archive(workspace_root: &Path, id: &str) -> Result<(), String> {
+    let archives_dir = groove_archives_dir(workspace_root);
+    for path in [
+        archives_dir.join(format!("{id}.tar.gz")),
+        archives_dir.join(format!("{id}.json")),
+    ] {
+        if path_is_file(&path) {
+            fs::remove_file(&path)
+                .map_err(|error| format!("Failed to remove {}: {error}", path.display()))?;
+        }
+    }
+    Ok(())
+}
+
+fn run_groove_archive_tar(cwd: &Path, args: &[&str]) -> Result<(), String> {
+    let result = run_capture_command_timeout(cwd, "tar", args, GROOVE_ARCHIVE_TAR_TIMEOUT);
+    if result.exit_code == Some(0) {
+        return Ok(());
+    }
+    Err(format!(
+        "tar failed: {}",
+        result
+            .error
+            .or_else(|| first_non_empty_line(&result.stderr))
+            .unwrap_or_else(|| "unknown error.".to_string())
+    ))
+}
+
+fn git_stdout_at(worktree_path: &Path, args: &[&str]) -> Result<String, String> {
+    let result = run_git_command_at_path(worktree_path, args);
+    if result.exit_code == Some(0) {
+        return Ok(result.stdout.trim().to_string());
+    }
+    Err(result
+        .error
+        .or_else(|| first_non_empty_line(&result.stderr))
+        .unwrap_or_else(|| format!("git {} failed.", args.join(" "))))
+}
+
+/// Archives the worktree at `worktree_path`. The worktree itself is left
+/// untouched.
+fn create_groove_archive(
+    workspace_root: &Path,
+    worktree: &str,
+    worktree_path: &Path,
+    base: Option<&str>,
+) -> Result<GrooveArchiveMetadata, String> {
+    let base_ref = match base.map(str::trim).filter(|base| !base.is_empty()) {
+        Some(base) => base.to_string(),
+        None => worktree_staleness_base_ref(worktree_path)
+            .ok_or_else(|| "Could not find a base branch; pass base.".to_string())?,
+    };
+    let base_commit = git_stdout_at(worktree_path, &["merge-base", "HEAD", &base_ref])
+        .map_err(|error| format!("Could not find the merge base with {base_ref}: {error}"))?;
+    let head_commit = git_stdout_at(worktree_path, &["rev-parse", "HEAD"]).ok();
+    let branch = git_stdout_at(worktree_path, &["rev-parse", "--abbrev-ref", "HEAD"])
+        .ok()
+        .filter(|branch| branch != "HEAD");
+
+    let archives_dir = groove_archives_dir(workspace_root);
+    let id = groove_archive_id(worktree, OffsetDateTime::now_utc());
+    let staging_dir = archives_dir.join(format!(".staging-{id}"));
+    fs::create_dir_all(&staging_dir)
+        .map_err(|error| format!("Failed to create {}: {error}", staging_dir.display()))?;
+
+    let mut archive = GrooveArchiveMetadata {
+        version: GROOVE_ARCHIVE_VERSION,
+        id: id.clone(),
+        worktree: worktree.to_string(),
+        branch,
+        base_ref,
+        base_commit,
+        head_commit,
+        archived_at: now_iso(),
+        untracked_files: 0,
+        has_logs: false,
+        bytes: 0,
+    };
+    let packed = pack_groove_archive(&archives_dir, &staging_dir, worktree_path, &mut archive);
+    let _ = fs::remove_dir_all(&staging_dir);
+    packed?;
+
+    archive.bytes = fs::metadata(archives_dir.join(format!("{id}.tar.gz")))
+        .map(|metadata| metadata.len())
+        .unwrap_or(0);
+    let body = serde_json::to_string_pretty(&archive)
+        .map_err(|error| format!("Failed to serialize archive metadata: {error}"))?;
+    let metadata_path = archives_dir.join(format!("{id}.json"));
+    fs::write(&metadata_path, format!("{body}\n"))
+        .map_err(|error| format!("Failed to write {}: {error}", metadata_path.display()))?;
+    Ok(archive)
+}
+
+fn pack_groove_archive(
+    archives_dir: &Path,
+    staging_dir: &Path,
+    worktree_path: &Path,
+    archive: &mut GrooveArchiveMetadata,
+) -> Result<(), String> {
+    // Written by git itself so file contents that are not UTF-8 survive.
+    let patch_output = format!("--output={}", staging_dir.join("changes.patch").display());
+    git_stdout_at(
+        worktree_path,
+        &[
+            "diff",
+            "--binary",
+            patch_output.as_str(),
+            archive.base_commit.as_str(),
+        ],
+    )
+    .map_err(|error| format!("Failed to diff against {}: {error}", archive.base_ref))?;
+
+    let untracked = git_stdout_at(
+        worktree_path,
+        &["ls-files", "--others", "--exclude-standard", "-z"],
+    )?;
+    let untracked = untracked
+        .split('\0')
+        .filter(|path| !path.is_empty())
+        .collect::<Vec<_>>();
+    if !untracked.is_empty() {
+        let list_path = staging_dir.join("untracked.list");
+        fs::write(&list_path, untracked.join("\0"))
+            .map_err(|error| format!("Failed to write {}: {error}", list_path.display()))?;
+        let untracked_tar = staging_dir.join("untracked.tar").display().to_string();
+        let list = list_path.display().to_string();
+        run_groove_archive_tar(
+            worktree_path,
+            &["-cf", untracked_tar.as_str(), "--null", "-T", list.as_str()],
+        )?;
+        let _ = fs::remove_file(&list_path);
+        archive.untracked_files = untracked.len();
+    }
+
+    let groove_dir = worktree_path.join(".groove");
+    let has_logs = fs::read_dir(groove_dir.join("logs"))
+        .map(|mut entries| entries.next().is_some())
+        .unwrap_or(false);
+    if has_logs {
+        let logs_tar = staging_dir.join("logs.tar").display().to_string();
+        run_groove_archive_tar(&groove_dir, &["-cf", logs_tar.as_str(), "logs"])?;
+        archive.has_logs = true;
+    }
+
+    let body = serde_json::to_string_pretty(&*archive)
+        .map_err(|error| format!("Failed to serialize archive metadata: {error}"))?;
+    fs::write(staging_dir.join("archive.json"), format!("{body}\n"))
+        .map_err(|error| format!("Failed to write archive.json: {error}"))?;
+
+    let tarball = format!("{}.tar.gz", archive.id);
+    let staging = staging_dir.display().to_string();
+    run_groove_archive_tar(
+        archives_dir,
+        &["-czf", tarball.as_str(), "-C", staging.as_str(), "."],
+    )
+}
+
+/// Applies an archive to a worktree created at its merge base.
+fn restore_groove_archive(
+    workspace_root: &Path,
+    archive: &GrooveArchiveMetadata,
+    worktree_path: &Path,
+) -> Result<(), String> {
+    let archives_dir = groove_archives_dir(workspace_root);
+    let staging_dir = archives_dir.join(format!(".staging-restore-{}", archive.id));
+    fs::create_dir_all(&staging_dir)
+        .map_err(|error| format!("Failed to create {}: {error}", staging_dir.display()))?;
+    let unpacked = unpack_groove_archive(&archives_dir, &staging_dir, archive, worktree_path);
+    let _ = fs::remove_dir_all(&staging_dir);
+    unpacked
+}
+
+fn unpack_groove_archive(
+    archives_dir: &Path,
+    staging_dir: &Path,
+    archive: &GrooveArchiveMetadata,
+    worktree_path: &Path,
+) -> Result<(), String> {
+    let tarball = format!("{}.tar.gz", archive.id);
+    if !path_is_file(&archives_dir.join(&tarball)) {
+        return Err(format!("The tarball of archive {} is missing.", archive.id));
+    }
+    let staging = staging_dir.display().to_string();
+    run_groove_archive_tar(
+        archives_dir,
+        &["-xzf", tarball.as_str(), "-C", staging.as_str()],
+    )?;
+
+    let patch_path = staging_dir.join("changes.patch");
+    let has_changes = fs::metadata(&patch_path)
+        .map(|metadata| metadata.len() > 0)
+        .unwrap_or(false);
+    if has_changes {
+        let patch = patch_path.display().to_string();
+        git_stdout_at(worktree_path, &["apply", "--binary", patch.as_str()])
+            .map_err(|error| format!("Failed to apply the archived changes: {error}"))?;
+    }
+
+    let untracked_tar = staging_dir.join("untracked.tar");
+    if path_is_file(&untracked_tar) {
+        let untracked_tar = untracked_tar.display().to_string();
+        run_groove_archive_tar(worktree_path, &["-xf", untracked_tar.as_str()])?;
+    }
+
+    let logs_tar = staging_dir.join("logs.tar");
+    if path_is_file(&logs_tar) {
+        let groove_dir = worktree_path.join(".groove");
+        fs::create_dir_all(&groove_dir)
+            .map_err(|error| format!("Failed to create {}: {error}", groove_dir.display()))?;
+        let logs_tar = logs_tar.display().to_string();
+        run_groove_archive_tar(&groove_dir, &["-xf", logs_tar.as_str()])?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod archive_runtime_tests {
+    use super::*;
+
+    fn archive(id: &str, archived_at: &str) -> GrooveArchiveMetadata {
+        GrooveArchiveMetadata {
+            version: GROOVE_ARCHIVE_VERSION,
+            id: id.to_string(),
+            worktree: "feature".to_string(),
+            branch: Some("feature".to_string()),
+            base_ref: "main".to_string(),
+            base_commit: "abc123".to_string(),
+            head_commit: None,
+            archived_at: archived_at.to_string(),
+            untracked_files: 0,
+            has_logs: false,
+            bytes: 0,
+        }
+    }
+
+    #[test]
+    fn archive_ids_cannot_leave_the_archives_directory() {
+        let id = groove_archive_id("feature", OffsetDateTime::UNIX_EPOCH);
+        assert_eq!(validate_groove_archive_id(&id), Ok("feature-0".to_string()));
+        assert!(validate_groove_archive_id("../feature-0").is_err());
+        assert!(validate_groove_archive_id(".staging-feature-0").is_err());
+        assert!(validate_groove_archive_id(" ").is_err());
+    }
+
+    #[test]
+    fn archives_are_listed_newest_first() {
+        let workspace_root =
+            std::env::temp_dir().join(format!("groove-archive-test-{}", Uuid::new_v4()));
+        let archives_dir = groove_archives_dir(&workspace_root);
+        fs::create_dir_all(&archives_dir).unwrap();
+        for archive in [
+            archive("feature-1", "2026-01-01T00:00:00Z"),
+            archive("feature-2", "2026-02-01T00:00:00Z"),
+        ] {
+            fs::write(
+                archives_dir.join(format!("{}.json", archive.id)),
+                serde_json::to_string(&archive).unwrap(),
+            )
+            .unwrap();
+        }
+        fs::write(archives_dir.join("broken.json"), "{").unwrap();
+
+        let ids = read_groove_archives(&workspace_root)
+            .into_iter()
+            .map(|archive| archive.id)
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["feature-2", "feature-1"]);
+        assert!(read_groove_archive(&workspace_root, "feature-3").is_err());
+
+        remove_groove_archive(&workspace_root, "feature-1").unwrap();
+        assert_eq!(read_groove_archives(&workspace_root).len(), 1);
+
+        let _ = fs::remove_dir_all(&workspace_root);
+    }
+}
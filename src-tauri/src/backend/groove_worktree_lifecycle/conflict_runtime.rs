@@ -0,0 +1,279 @@
+// Merge-time collision forecast behind `workspace_conflict_matrix`. Each
+// pair of worktrees is diffed against its merge base, uncommitted edits to
+// tracked files included, and the files both sides changed are compared
+// hunk by hunk on the base side. Changes that overlap or touch are what git
+// stops on when the branches are merged; the same file changed in separate
+// places usually merges cleanly.
+
+/// Base-side line ranges a worktree changed in one file, as half-open
+/// `(start, end)` pairs; an insertion is an empty range at the line it goes
+/// before. `None` is the whole file, for changes without hunks such as
+/// binary files.
+type ChangedLineRanges = Option<Vec<(u32, u32)>>;
+
+/// What a worktree changed since a merge base, by file path.
+type ChangedFiles = BTreeMap<String, ChangedLineRanges>;
+
+fn changed_line_ranges(files: Vec<GitDiffFile>) -> ChangedFiles {
+    files
+        .into_iter()
+        .map(|file| {
+            let ranges = (!file.binary && !file.hunks.is_empty()).then(|| {
+                file.hunks
+                    .iter()
+                    .map(|hunk| {
+                        if hunk.old_lines == 0 {
+                            (hunk.old_start + 1, hunk.old_start + 1)
+                        } else {
+                            (hunk.old_start, hunk.old_start + hunk.old_lines)
+                        }
+                    })
+                    .collect()
+            });
+            (file.file_path, ranges)
+        })
+        .collect()
+}
+
+/// Whether two changes to a file overlap or are adjacent.
+fn changed_line_ranges_collide(left: &ChangedLineRanges, right: &ChangedLineRanges) -> bool {
+    let (Some(left), Some(right)) = (left, right) else {
+        return true;
+    };
+    left.iter().any(|(left_start, left_end)| {
+        right
+            .iter()
+            .any(|(right_start, right_end)| left_start <= right_end && right_start <= left_end)
+    })
+}
+
+/// Files changed on both sides, sorted by path.
+fn compare_changed_files(left: &ChangedFiles, right: &ChangedFiles) -> Vec<WorktreeConflictFile> {
+    left.iter()
+        .filter_map(|(path, left_ranges)| {
+            let right_ranges = right.get(path)?;
+            Some(WorktreeConflictFile {
+                path: path.clone(),
+                overlapping: changed_line_ranges_collide(left_ranges, right_ranges),
+            })
+        })
+        .collect()
+}
+
+fn worktree_conflict_risk(files: &[WorktreeConflictFile]) -> &'static str {
+    if files.iter().any(|file| file.overlapping) {
+        "conflicting"
+    } else if files.is_empty() {
+        "clear"
+    } else {
+        "sameFiles"
+    }
+}
+
+fn git_stdout_line(worktree_path: &Path, args: &[&str]) -> Result<String, String> {
+    let result = run_git_command_at_path(worktree_path, args);
+    if let Some(error) = result.error {
+        return Err(error);
+    }
+    if result.exit_code != Some(0) {
+        return Err(first_non_empty_line(&result.stderr)
+            .unwrap_or_else(|| format!("git {} failed", args[0])));
+    }
+    first_non_empty_line(&result.stdout).ok_or_else(|| format!("git {} printed nothing", args[0]))
+}
+
+/// What the worktree at `worktree_path` changed since `merge_base`, its
+/// working tree included. Untracked files are not seen.
+fn worktree_changes_since(worktree_path: &Path, merge_base: &str) -> Result<ChangedFiles, String> {
+    let diff = run_git_command_at_path(
+        worktree_path,
+        &[
+            "diff",
+            "--no-color",
+            "--no-ext-diff",
+            "--no-renames",
+            "--unified=0",
+            merge_base,
+        ],
+    );
+    if let Some(error) = diff.error {
+        return Err(error);
+    }
+    if diff.exit_code != Some(0) {
+        return Err(
+            first_non_empty_line(&diff.stderr).unwrap_or_else(|| "git diff failed".to_string())
+        );
+    }
+    Ok(changed_line_ranges(parse_unified_diff(&diff.stdout)))
+}
+
+/// The merge base of two worktrees and the files both changed since it.
+/// `changes_since` reads what one side changed.
+fn compare_worktree_pair(
+    (left_path, left_head): (&Path, &Result<String, String>),
+    (right_path, right_head): (&Path, &Result<String, String>),
+    mut changes_since: impl FnMut(&Path, &str) -> Result<ChangedFiles, String>,
+) -> Result<(String, Vec<WorktreeConflictFile>), String> {
+    let left_head = left_head.as_ref().map_err(String::clone)?;
+    let right_head = right_head.as_ref().map_err(String::clone)?;
+    let merge_base = git_stdout_line(left_path, &["merge-base", left_head, right_head])?;
+    let left_changes = changes_since(left_path, &merge_base)?;
+    let right_changes = changes_since(right_path, &merge_base)?;
+    let files = compare_changed_files(&left_changes, &right_changes);
+    Ok((merge_base, files))
+}
+
+/// Compares every pair of worktrees on disk, or of `requested` when given,
+/// and returns the compared worktrees with one entry per pair.
+fn run_workspace_conflict_matrix(
+    app: &AppHandle,
+    workspace_root: &Path,
+    requested: &[String],
+) -> Result<(Vec<String>, Vec<WorktreeConflictPair>), String> {
+    let (workspace_meta, _) = ensure_workspace_meta(workspace_root)?;
+    let effective_root = effective_workspace_root(workspace_root, &workspace_meta);
+    let mut execution_state = read_persisted_worktree_execution_state(app)?;
+    let (_, rows) = scan_workspace_worktrees_with_state(
+        app,
+        workspace_root,
+        &effective_root,
+        &workspace_meta,
+        &mut execution_state,
+    )?;
+    let mut worktrees = rows
+        .into_iter()
+        .filter(|row| row.status != "deleted")
+        .filter(|row| {
+            workspace_meta
+                .worktree_records
+                .get(&row.worktree)
+                .is_none_or(|record| record.state != WorktreeState::Forgotten)
+        })
+        .map(|row| (row.worktree, PathBuf::from(row.path)))
+        .collect::<Vec<_>>();
+    if !requested.is_empty() {
+        if let Some(unknown) = requested
+            .iter()
+            .find(|name| !worktrees.iter().any(|(worktree, _)| worktree == *name))
+        {
+            return Err(format!("Worktree \"{unknown}\" is not in this workspace."));
+        }
+        worktrees.retain(|(worktree, _)| requested.contains(worktree));
+    }
+    worktrees.sort_by(|(left, _), (right, _)| left.cmp(right));
+
+    let heads = worktrees
+        .iter()
+        .map(|(_, path)| git_stdout_line(path, &["rev-parse", "HEAD"]))
+        .collect::<Vec<_>>();
+    // Pairs usually share a merge base, so each diff is read once.
+    let mut changes: HashMap<(PathBuf, String), Result<ChangedFiles, String>> = HashMap::new();
+    let mut pairs = Vec::new();
+    for left in 0..worktrees.len() {
+        for right in left + 1..worktrees.len() {
+            if command_cancelled() {
+                return Err("workspace_conflict_matrix was cancelled.".to_string());
+            }
+            let mut pair = WorktreeConflictPair {
+                left: worktrees[left].0.clone(),
+                right: worktrees[right].0.clone(),
+                risk: "unknown".to_string(),
+                merge_base: None,
+                files: Vec::new(),
+                error: None,
+            };
+            let compared = compare_worktree_pair(
+                (&worktrees[left].1, &heads[left]),
+                (&worktrees[right].1, &heads[right]),
+                |path, merge_base| {
+                    changes
+                        .entry((path.to_path_buf(), merge_base.to_string()))
+                        .or_insert_with(|| worktree_changes_since(path, merge_base))
+                        .clone()
+                },
+            );
+            match compared {
+                Ok((merge_base, files)) => {
+                    pair.risk = worktree_conflict_risk(&files).to_string();
+                    pair.merge_base = Some(merge_base);
+                    pair.files = files;
+                }
+                Err(error) => pair.error = Some(error),
+            }
+            pairs.push(pair);
+        }
+    }
+
+    log_line(&format!(
+        "[conflicts] workspace={} worktrees={} conflicting={}",
+        workspace_root.display(),
+        worktrees.len(),
+        pairs
+            .iter()
+            .filter(|pair| pair.risk == "conflicting")
+            .count()
+    ));
+    Ok((
+        worktrees
+            .into_iter()
+            .map(|(worktree, _)| worktree)
+            .collect(),
+        pairs,
+    ))
+}
+
+#[cfg(test)]
+mod conflict_runtime_tests {
+    use super::*;
+
+    fn changes(diff: &str) -> ChangedFiles {
+        changed_line_ranges(parse_unified_diff(diff))
+    }
+
+    #[test]
+    fn changed_ranges_are_read_from_the_base_side() {
+        let changed = changes(
+            "diff --git a/src/lib.rs b/src/lib.rs\n\
+             --- a/src/lib.rs\n\
+             +++ b/src/lib.rs\n\
+             @@ -3,2 +3 @@ fn main() {\n\
+             -    one();\n\
+             -    two();\n\
+             +    both();\n\
+             @@ -10,0 +10 @@\n\
+             +// trailing\n\
+             diff --git a/logo.png b/logo.png\n\
+             Binary files a/logo.png and b/logo.png differ\n",
+        );
+
+        assert_eq!(changed["src/lib.rs"], Some(vec![(3, 5), (11, 11)]));
+        assert_eq!(changed["logo.png"], None);
+    }
+
+    #[test]
+    fn only_overlapping_or_adjacent_changes_collide() {
+        let left = BTreeMap::from([
+            ("a.rs".to_string(), Some(vec![(3, 5)])),
+            ("b.rs".to_string(), Some(vec![(10, 12)])),
+            ("c.rs".to_string(), Some(vec![(1, 2)])),
+            ("logo.png".to_string(), None),
+        ]);
+        let right = BTreeMap::from([
+            ("a.rs".to_string(), Some(vec![(5, 5)])),
+            ("b.rs".to_string(), Some(vec![(20, 21)])),
+            ("logo.png".to_string(), Some(vec![(1, 1)])),
+        ]);
+
+        let files = compare_changed_files(&left, &right);
+        assert_eq!(
+            files
+                .iter()
+                .map(|file| (file.path.as_str(), file.overlapping))
+                .collect::<Vec<_>>(),
+            vec![("a.rs", true), ("b.rs", false), ("logo.png", true)]
+        );
+        assert_eq!(worktree_conflict_risk(&files), "conflicting");
+        assert_eq!(worktree_conflict_risk(&files[1..2]), "sameFiles");
+        assert_eq!(worktree_conflict_risk(&[]), "clear");
+    }
+}
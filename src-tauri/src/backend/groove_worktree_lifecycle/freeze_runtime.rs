@@ -0,0 +1,465 @@
+// Freezing a workspace behind `workspace_freeze` and `workspace_thaw`. A
+// freeze records the running grooves and session layouts, stops the grooves,
+// closes every terminal session of the workspace, stops the node and opencode
+// processes still pointing at it and packs terminal history into tarballs.
+// The record lives in `.groove/frozen.json`; a thaw unpacks the tarballs and
+// either reopens what was recorded or puts the layouts back.
+
+fn workspace_frozen_state_file(workspace_root: &Path) -> PathBuf {
+    workspace_root
+        .join(".groove")
+        .join(WORKSPACE_FROZEN_STATE_FILE)
+}
+
+fn read_frozen_workspace_state(
+    workspace_root: &Path,
+) -> Result<Option<FrozenWorkspaceState>, String> {
+    let path = workspace_frozen_state_file(workspace_root);
+    if !path_is_file(&path) {
+        return Ok(None);
+    }
+    let raw = fs::read_to_string(&path)
+        .map_err(|error| format!("Failed to read {}: {error}", path.display()))?;
+    serde_json::from_str(&raw)
+        .map(Some)
+        .map_err(|error| format!("Failed to parse {}: {error}", path.display()))
+}
+
+fn write_frozen_workspace_state(
+    workspace_root: &Path,
+    frozen: &FrozenWorkspaceState,
+) -> Result<(), String> {
+    let path = workspace_frozen_state_file(workspace_root);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|error| format!("Failed to create {}: {error}", parent.display()))?;
+    }
+    let body = serde_json::to_string_pretty(frozen)
+        .map_err(|error| format!("Failed to serialize frozen workspace state: {error}"))?;
+    fs::write(&path, format!("{body}\n"))
+        .map_err(|error| format!("Failed to write {}: {error}", path.display()))
+}
+
+/// Removes the workspace's session layouts from the execution state and
+/// returns them, sorted by worktree.
+fn take_workspace_session_layouts(
+    app: &AppHandle,
+    workspace_root: &Path,
+) -> Result<Vec<WorktreeSessionLayout>, String> {
+    let _guard = lock_worktree_execution_state();
+    let mut state = read_persisted_worktree_execution_state(app)?;
+    let workspace_key = workspace_root_storage_key(workspace_root);
+    let Some(workspace_layouts) = state.session_layouts_by_workspace.remove(&workspace_key) else {
+        return Ok(Vec::new());
+    };
+    write_persisted_worktree_execution_state(app, &state)?;
+    let mut layouts = workspace_layouts
+        .into_values()
+        .filter(|layout| !layout.entries.is_empty())
+        .collect::<Vec<_>>();
+    layouts.sort_by(|left, right| left.worktree.cmp(&right.worktree));
+    Ok(layouts)
+}
+
+/// Running grooves with no session layout entry, i.e. Plays that were not
+/// opened in an in-app terminal and are restarted through `groove_restore`.
+fn frozen_grooves_without_sessions(frozen: &FrozenWorkspaceState) -> Vec<&RunningGrooveRecord> {
+    let session_ids = frozen
+        .session_layouts
+        .iter()
+        .flat_map(|layout| layout.entries.iter())
+        .map(|entry| entry.session_id.as_str())
+        .collect::<HashSet<_>>();
+    let mut seen = HashSet::new();
+    frozen
+        .running_grooves
+        .iter()
+        .filter(|record| !session_ids.contains(record.session_id.as_str()))
+        .filter(|record| seen.insert(record.worktree.as_str()))
+        .collect()
+}
+
+/// Packs `.groove/<source>` into `.groove/frozen/<source>.tar.gz` and removes
+/// the directory. Missing or empty directories are skipped.
+fn archive_frozen_workspace_dir(
+    workspace_root: &Path,
+    source: &str,
+) -> Result<Option<FrozenWorkspaceArchive>, String> {
+    let groove_dir = workspace_root.join(".groove");
+    let source_dir = groove_dir.join(source);
+    let is_empty = fs::read_dir(&source_dir)
+        .map(|mut entries| entries.next().is_none())
+        .unwrap_or(true);
+    if is_empty {
+        return Ok(None);
+    }
+
+    let archive_dir = groove_dir.join(WORKSPACE_FROZEN_ARCHIVE_DIR);
+    fs::create_dir_all(&archive_dir)
+        .map_err(|error| format!("Failed to create {}: {error}", archive_dir.display()))?;
+    let archive = format!("{WORKSPACE_FROZEN_ARCHIVE_DIR}/{source}.tar.gz");
+    let result = run_capture_command_timeout(
+        &groove_dir,
+        "tar",
+        &["-czf", archive.as_str(), source],
+        WORKSPACE_FREEZE_TAR_TIMEOUT,
+    );
+    if result.exit_code != Some(0) {
+        let _ = fs::remove_file(groove_dir.join(&archive));
+        return Err(format!(
+            "Failed to archive {source}: {}",
+            result
+                .error
+                .or_else(|| first_non_empty_line(&result.stderr))
+                .unwrap_or_else(|| "tar failed.".to_string())
+        ));
+    }
+    fs::remove_dir_all(&source_dir)
+        .map_err(|error| format!("Failed to remove {}: {error}", source_dir.display()))?;
+
+    let bytes = fs::metadata(groove_dir.join(&archive))
+        .map(|metadata| metadata.len())
+        .unwrap_or(0);
+    Ok(Some(FrozenWorkspaceArchive {
+        source: source.to_string(),
+        archive,
+        bytes,
+    }))
+}
+
+/// Unpacks an archive made by `archive_frozen_workspace_dir` and removes it.
+fn extract_frozen_workspace_archive(
+    workspace_root: &Path,
+    archive: &FrozenWorkspaceArchive,
+) -> Result<(), String> {
+    let groove_dir = workspace_root.join(".groove");
+    let archive_path = groove_dir.join(&archive.archive);
+    if !path_is_file(&archive_path) {
+        return Err(format!("{} is missing.", archive_path.display()));
+    }
+    let result = run_capture_command_timeout(
+        &groove_dir,
+        "tar",
+        &["-xzf", archive.archive.as_str()],
+        WORKSPACE_FREEZE_TAR_TIMEOUT,
+    );
+    if result.exit_code != Some(0) {
+        return Err(format!(
+            "Failed to extract {}: {}",
+            archive.archive,
+            result
+                .error
+                .or_else(|| first_non_empty_line(&result.stderr))
+                .unwrap_or_else(|| "tar failed.".to_string())
+        ));
+    }
+    fs::remove_file(&archive_path)
+        .map_err(|error| format!("Failed to remove {}: {error}", archive_path.display()))
+}
+
+/// Freezes the workspace at `workspace_root`. Failures of single steps are
+/// collected and the freeze goes on; only being frozen already, or failing to
+/// record the state, fails it.
+fn run_workspace_freeze(
+    app: &AppHandle,
+    workspace_root: &Path,
+    compress_artifacts: bool,
+) -> Result<(FrozenWorkspaceState, Vec<String>), String> {
+    if read_frozen_workspace_state(workspace_root)?.is_some() {
+        return Err("Workspace is already frozen.".to_string());
+    }
+
+    let mut failures = Vec::new();
+    let execution_state = read_persisted_worktree_execution_state(app)?;
+    let running_grooves = running_grooves_in_state(&execution_state, workspace_root);
+
+    // Layouts are taken before the grooves are stopped, which drops the
+    // entries of their Play sessions.
+    let session_layouts = take_workspace_session_layouts(app, workspace_root)?;
+
+    let mut stopped_worktrees = HashSet::new();
+    for record in &running_grooves {
+        if !stopped_worktrees.insert(record.worktree.clone()) {
+            continue;
+        }
+        let response = stop_groove_worktree(
+            app,
+            &GrooveStopPayload {
+                root_name: None,
+                known_worktrees: Vec::new(),
+                workspace_meta: None,
+                worktree: record.worktree.clone(),
+                instance_id: None,
+                dir: None,
+            },
+        );
+        if !response.ok {
+            failures.push(format!(
+                "{}: {}",
+                record.worktree,
+                response
+                    .error
+                    .unwrap_or_else(|| "Failed to stop groove.".to_string())
+            ));
+        }
+    }
+
+    let workspace_key = workspace_root_storage_key(workspace_root);
+    let terminal_state = app.state::<GrooveTerminalState>();
+    let sessions_to_close = drain_groove_terminal_sessions(
+        &mut terminal_state.lock_sessions(),
+        Some(workspace_key.as_str()),
+    );
+    let closed_sessions = sessions_to_close.len();
+    close_groove_terminal_sessions_best_effort(sessions_to_close);
+    // Closing a session drops nothing from the layouts, so clear what the
+    // open paths recorded in the meantime.
+    let _ = take_workspace_session_layouts(app, workspace_root);
+
+    let mut stopped_pids = Vec::new();
+    match list_stop_category_targets(
+        diagnostics::DiagnosticsStopCategory::Workspace,
+        Some(workspace_root),
+    ) {
+        Ok(targets) => {
+            for target in targets {
+                match stop_process_by_pid(target.pid) {
+                    Ok((false, _)) => stopped_pids.push(target.pid),
+                    Ok((true, _)) => {}
+                    Err(error) => failures.push(format!("PID {}: {error}", target.pid)),
+                }
+            }
+        }
+        Err(error) => failures.push(error),
+    }
+
+    let mut archives = Vec::new();
+    if compress_artifacts {
+        for source in WORKSPACE_FREEZE_ARCHIVED_DIRS {
+            match archive_frozen_workspace_dir(workspace_root, source) {
+                Ok(Some(archive)) => archives.push(archive),
+                Ok(None) => {}
+                Err(error) => failures.push(error),
+            }
+        }
+    }
+
+    let frozen = FrozenWorkspaceState {
+        frozen_at: now_iso(),
+        running_grooves,
+        session_layouts,
+        closed_sessions,
+        stopped_pids,
+        archives,
+    };
+    write_frozen_workspace_state(workspace_root, &frozen)?;
+    invalidate_workspace_caches(app, workspace_root);
+    Ok((frozen, failures))
+}
+
+/// Thaws the workspace at `workspace_root`, returning the recorded state, the
+/// sessions and targets reopened and the steps that failed. Layout entries
+/// that could not be reopened are put back so a later restore can retry.
+fn run_workspace_thaw(
+    app: &AppHandle,
+    workspace_root: &Path,
+    restart_sessions: bool,
+) -> Result<(FrozenWorkspaceState, Vec<String>, Vec<String>), String> {
+    let Some(frozen) = read_frozen_workspace_state(workspace_root)? else {
+        return Err("Workspace is not frozen.".to_string());
+    };
+
+    let mut restarted = Vec::new();
+    let mut failures = Vec::new();
+    for archive in &frozen.archives {
+        if let Err(error) = extract_frozen_workspace_archive(workspace_root, archive) {
+            failures.push(error);
+        }
+    }
+    let archive_dir = workspace_root
+        .join(".groove")
+        .join(WORKSPACE_FROZEN_ARCHIVE_DIR);
+    let _ = fs::remove_dir(&archive_dir);
+
+    let terminal_state = app.state::<GrooveTerminalState>();
+    for layout in &frozen.session_layouts {
+        let worktree_path = if restart_sessions {
+            match resolve_terminal_worktree_context(app, &None, &[], &None, &layout.worktree) {
+                Ok((_, worktree_path)) => Some(worktree_path),
+                Err(error) => {
+                    failures.push(format!("{}: {error}", layout.worktree));
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        for entry in &layout.entries {
+            let reopened = worktree_path.as_deref().and_then(|worktree_path| {
+                let result = validate_groove_terminal_open_mode(Some(entry.open_mode.as_str()))
+                    .and_then(|open_mode| {
+                        open_groove_terminal_session(
+                            app,
+                            &terminal_state,
+                            workspace_root,
+                            &layout.worktree,
+                            worktree_path,
+                            open_mode,
+                            entry.target.as_deref(),
+                            None,
+                            None,
+                            false,
+                            true,
+                            entry.run_local,
+                        )
+                        .map(|_| open_mode)
+                    });
+                match result {
+                    Ok(open_mode) => {
+                        if entry.run_local
+                            && matches!(open_mode, GrooveTerminalOpenMode::ClaudeCode)
+                        {
+                            mark_claude_session_started(workspace_root, &layout.worktree);
+                        }
+                        Some(())
+                    }
+                    Err(error) => {
+                        failures.push(format!("{}: {}: {error}", layout.worktree, entry.open_mode));
+                        None
+                    }
+                }
+            });
+
+            if reopened.is_some() {
+                restarted.push(format!("{}: {}", layout.worktree, entry.open_mode));
+            } else if let Err(error) = record_worktree_session_layout_entry(
+                app,
+                workspace_root,
+                &layout.worktree,
+                entry.clone(),
+            ) {
+                failures.push(format!("{}: {error}", layout.worktree));
+            }
+        }
+    }
+
+    if restart_sessions {
+        for record in frozen_grooves_without_sessions(&frozen) {
+            let response = groove_restore(
+                app.clone(),
+                app.state::<GrooveTerminalState>(),
+                GrooveRestorePayload {
+                    workspace_root: Some(workspace_root.display().to_string()),
+                    root_name: None,
+                    known_worktrees: vec![record.worktree.clone()],
+                    workspace_meta: None,
+                    worktree: record.worktree.clone(),
+                    action: Some("go".to_string()),
+                    target: Some(
+                        record
+                            .target
+                            .clone()
+                            .unwrap_or_else(|| record.worktree.clone()),
+                    ),
+                    dir: None,
+                    opencode_log_file: None,
+                    stream: false,
+                    debug: None,
+                },
+            );
+            if response.ok {
+                restarted.push(format!("{}: play", record.worktree));
+            } else {
+                failures.push(format!(
+                    "{}: {}",
+                    record.worktree,
+                    response
+                        .error
+                        .unwrap_or_else(|| "Failed to restart Play.".to_string())
+                ));
+            }
+        }
+    }
+
+    let state_file = workspace_frozen_state_file(workspace_root);
+    fs::remove_file(&state_file)
+        .map_err(|error| format!("Failed to remove {}: {error}", state_file.display()))?;
+    invalidate_workspace_caches(app, workspace_root);
+    Ok((frozen, restarted, failures))
+}
+
+#[cfg(test)]
+mod freeze_runtime_tests {
+    use super::*;
+
+    fn running_groove(worktree: &str, session_id: &str) -> RunningGrooveRecord {
+        RunningGrooveRecord {
+            workspace_root: "/repo".to_string(),
+            worktree: worktree.to_string(),
+            worktree_path: format!("/repo/.worktrees/{worktree}"),
+            command: "npm run dev".to_string(),
+            target: None,
+            session_id: session_id.to_string(),
+            pid: None,
+            started_at: "2026-01-01T00:00:00Z".to_string(),
+            process_started_at: None,
+            still_running: None,
+        }
+    }
+
+    #[test]
+    fn grooves_with_a_terminal_session_are_reopened_from_the_layout() {
+        let frozen = FrozenWorkspaceState {
+            running_grooves: vec![
+                running_groove("feature", "play-1"),
+                running_groove("bugfix", "external-1"),
+                running_groove("bugfix", "external-2"),
+            ],
+            session_layouts: vec![WorktreeSessionLayout {
+                worktree: "feature".to_string(),
+                entries: vec![WorktreeSessionLayoutEntry {
+                    session_id: "play-1".to_string(),
+                    open_mode: "plain".to_string(),
+                    target: None,
+                    run_local: true,
+                }],
+                updated_at: "2026-01-01T00:00:00Z".to_string(),
+            }],
+            ..FrozenWorkspaceState::default()
+        };
+
+        let worktrees = frozen_grooves_without_sessions(&frozen)
+            .into_iter()
+            .map(|record| record.worktree.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(worktrees, vec!["bugfix"]);
+    }
+
+    #[test]
+    fn frozen_state_round_trips_through_the_state_file() {
+        let workspace_root =
+            std::env::temp_dir().join(format!("groove-freeze-test-{}", Uuid::new_v4()));
+        assert!(read_frozen_workspace_state(&workspace_root)
+            .unwrap()
+            .is_none());
+
+        let frozen = FrozenWorkspaceState {
+            frozen_at: "2026-01-01T00:00:00Z".to_string(),
+            running_grooves: vec![running_groove("feature", "play-1")],
+            closed_sessions: 2,
+            stopped_pids: vec![42],
+            ..FrozenWorkspaceState::default()
+        };
+        write_frozen_workspace_state(&workspace_root, &frozen).unwrap();
+
+        let read = read_frozen_workspace_state(&workspace_root)
+            .unwrap()
+            .unwrap();
+        assert_eq!(read.running_grooves.len(), 1);
+        assert_eq!(read.closed_sessions, 2);
+        assert_eq!(read.stopped_pids, vec![42]);
+
+        let _ = fs::remove_dir_all(&workspace_root);
+    }
+}
@@ -1,11 +1,51 @@
 fn run_command(binary: &Path, args: &[String], cwd: &Path) -> CommandResult {
-    let output = Command::new(binary)
+    run_command_with_env(binary, args, cwd, &[])
+}
+
+fn run_command_with_env(
+    binary: &Path,
+    args: &[String],
+    cwd: &Path,
+    envs: &[(&str, &str)],
+) -> CommandResult {
+    run_command_with_output_stream(binary, args, cwd, envs, None)
+}
+
+/// The command and request whose sidecar output is streamed as
+/// `groove-command-output` events.
+struct CommandOutputStream<'a> {
+    command: &'a str,
+    request_id: &'a str,
+}
+
+/// Runs the sidecar in `cwd`. With `stream`, what it writes is emitted as
+/// `groove-command-output` events while it runs and a
+/// `groove-command-complete` event follows once it exits; the full output
+/// is returned either way.
+fn run_command_with_output_stream(
+    binary: &Path,
+    args: &[String],
+    cwd: &Path,
+    envs: &[(&str, &str)],
+    stream: Option<&CommandOutputStream>,
+) -> CommandResult {
+    // The sidecar runs git itself, so the workspace identity travels as
+    // `GIT_CONFIG_*` variables instead of `-c` arguments.
+    let git_config_env = git::config_override_env(&workspace_git_config_for_path(cwd));
+    let mut command = Command::new(binary);
+    command
         .args(args)
         .current_dir(cwd)
         .env("GROOVE_REPO_ROOT", cwd)
-        .output();
+        .envs(git_config_env)
+        .envs(envs.iter().copied());
+    let started_at = Instant::now();
+    let output = match stream {
+        Some(stream) => streamed_command_output(&mut command, stream),
+        None => process_output(&command, None),
+    };
 
-    match output {
+    let result = match output {
         Ok(output) => CommandResult {
             exit_code: output.status.code(),
             stdout: String::from_utf8_lossy(&output.stdout).to_string(),
@@ -18,7 +58,97 @@ fn run_command(binary: &Path, args: &[String], cwd: &Path) -> CommandResult {
             stderr: String::new(),
             error: Some(format!("Failed to execute {}: {}", binary.display(), error)),
         },
+    };
+    record_subprocess_transcript(&command, started_at, &result);
+    if let Some(stream) = stream {
+        emit_background_groove_event(&GrooveCommandCompleteEvent {
+            request_id: stream.request_id.to_string(),
+            command: stream.command.to_string(),
+            ok: result.error.is_none() && result.exit_code == Some(0),
+            exit_code: result.exit_code,
+            error: result.error.clone(),
+        });
     }
+    result
+}
+
+fn streamed_command_output(
+    command: &mut Command,
+    stream: &CommandOutputStream,
+) -> std::io::Result<std::process::Output> {
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let stdout = child
+        .stdout
+        .take()
+        .map(|pipe| stream_command_pipe(pipe, stream, "stdout"));
+    let stderr = child
+        .stderr
+        .take()
+        .map(|pipe| stream_command_pipe(pipe, stream, "stderr"));
+    let status = child.wait()?;
+    let collect = |reader: Option<JoinHandle<Vec<u8>>>| {
+        reader
+            .and_then(|reader| reader.join().ok())
+            .unwrap_or_default()
+    };
+    Ok(std::process::Output {
+        status,
+        stdout: collect(stdout),
+        stderr: collect(stderr),
+    })
+}
+
+fn stream_command_pipe(
+    pipe: impl Read + Send + 'static,
+    stream: &CommandOutputStream,
+    name: &'static str,
+) -> JoinHandle<Vec<u8>> {
+    let request_id = stream.request_id.to_string();
+    let command = stream.command.to_string();
+    thread::spawn(move || {
+        forward_command_output(pipe, |chunk| {
+            emit_background_groove_event(&GrooveCommandOutputEvent {
+                request_id: request_id.clone(),
+                command: command.clone(),
+                stream: name.to_string(),
+                chunk,
+            });
+        })
+    })
+}
+
+/// Reads `pipe` to the end, passing on each piece as text as it arrives. A
+/// character split across reads is held back until it is complete. Returns
+/// everything read.
+fn forward_command_output(mut pipe: impl Read, mut emit: impl FnMut(String)) -> Vec<u8> {
+    let mut output = Vec::new();
+    let mut pending = Vec::new();
+    let mut buffer = [0_u8; 4096];
+    loop {
+        let read = match pipe.read(&mut buffer) {
+            Ok(0) | Err(_) => break,
+            Ok(read) => read,
+        };
+        output.extend_from_slice(&buffer[..read]);
+        pending.extend_from_slice(&buffer[..read]);
+        let complete = match std::str::from_utf8(&pending) {
+            Ok(_) => pending.len(),
+            Err(error) if error.error_len().is_none() => error.valid_up_to(),
+            Err(_) => pending.len(),
+        };
+        if complete > 0 {
+            emit(String::from_utf8_lossy(&pending[..complete]).to_string());
+            pending.drain(..complete);
+        }
+    }
+    if !pending.is_empty() {
+        emit(String::from_utf8_lossy(&pending).to_string());
+    }
+    output
 }
 
 fn parse_log_segment(value: &str) -> (String, Option<String>) {
@@ -137,6 +267,10 @@ fn parse_groove_list_output(
                 worktree,
                 log_state,
                 log_target,
+                resources: None,
+                badges: None,
+                parent: None,
+                children: Vec::new(),
             },
         );
     }
@@ -180,38 +314,47 @@ fn resolve_groove_list_worktrees(
     dir: &Option<String>,
 ) -> Result<Vec<(String, PathBuf)>, String> {
     let worktrees_dir = workspace_root.join(dir.as_deref().unwrap_or(".worktrees"));
-    if !path_is_directory(&worktrees_dir) {
-        return Ok(Vec::new());
-    }
-
-    if !known_worktrees.is_empty() {
-        let mut rows = known_worktrees
-            .iter()
-            .map(|worktree| (worktree.clone(), worktrees_dir.join(worktree)))
-            .filter(|(_, path)| path_is_directory(path))
-            .collect::<Vec<_>>();
-        rows.sort_by(|left, right| left.0.cmp(&right.0));
-        return Ok(rows);
+    let mut rows = Vec::<(String, PathBuf)>::new();
+    if path_is_directory(&worktrees_dir) && !known_worktrees.is_empty() {
+        rows.extend(
+            known_worktrees
+                .iter()
+                .map(|worktree| (worktree.clone(), worktrees_dir.join(worktree)))
+                .filter(|(_, path)| path_is_directory(path)),
+        );
+    } else if path_is_directory(&worktrees_dir) {
+        let entries = fs::read_dir(&worktrees_dir)
+            .map_err(|error| format!("Failed to read {}: {error}", worktrees_dir.display()))?;
+        for entry in entries {
+            let entry = entry.map_err(|error| {
+                format!(
+                    "Failed to enumerate {} entries: {error}",
+                    worktrees_dir.display()
+                )
+            })?;
+            let path = entry.path();
+            if !path_is_directory(&path) {
+                continue;
+            }
+            let Some(name) = path.file_name().map(|value| value.to_string_lossy().to_string()) else {
+                continue;
+            };
+            rows.push((name, path));
+        }
     }
 
-    let entries = fs::read_dir(&worktrees_dir)
-        .map_err(|error| format!("Failed to read {}: {error}", worktrees_dir.display()))?;
-    let mut rows = Vec::<(String, PathBuf)>::new();
-    for entry in entries {
-        let entry = entry.map_err(|error| {
-            format!(
-                "Failed to enumerate {} entries: {error}",
-                worktrees_dir.display()
-            )
-        })?;
-        let path = entry.path();
-        if !path_is_directory(&path) {
-            continue;
+    // Adopted worktrees live outside `.worktrees/`, so a custom `dir` never
+    // lists them.
+    if dir.is_none() {
+        let existing = rows.iter().map(|(_, path)| path.clone()).collect::<Vec<_>>();
+        for path in adopted_worktree_directories(&read_adopted_worktrees(workspace_root), &existing) {
+            let Some(name) = path.file_name().map(|value| value.to_string_lossy().to_string()) else {
+                continue;
+            };
+            if known_worktrees.is_empty() || known_worktrees.contains(&name) {
+                rows.push((name, path));
+            }
         }
-        let Some(name) = path.file_name().map(|value| value.to_string_lossy().to_string()) else {
-            continue;
-        };
-        rows.push((name, path));
     }
 
     rows.sort_by(|left, right| left.0.cmp(&right.0));
@@ -361,6 +504,7 @@ fn collect_groove_list_rows_native(
     previous_cache: Option<&GrooveListNativeCache>,
 ) -> Result<NativeGrooveListCollection, String> {
     let worktrees = resolve_groove_list_worktrees(workspace_root, known_worktrees, dir)?;
+    let worktree_records = read_worktree_records(workspace_root);
 
     let mut rows = HashMap::new();
     let mut cache_rows = HashMap::new();
@@ -385,10 +529,14 @@ fn collect_groove_list_rows_native(
 
         let row = RuntimeStateRow {
             branch: resolve_branch_from_worktree(&worktree_path)
-                .unwrap_or_else(|| branch_guess_from_worktree_name(&worktree)),
+                .unwrap_or_else(|| worktree_branch_from_records(&worktree_records, &worktree)),
             worktree: worktree.clone(),
             log_state: log_signals.log_state,
             log_target: log_signals.log_target,
+            resources: None,
+            badges: None,
+            parent: None,
+            children: Vec::new(),
         };
 
         rows.insert(worktree.clone(), row.clone());
@@ -415,17 +563,8 @@ fn inject_groove_terminal_sessions_into_runtime_rows(
         return GrooveListTerminalIntegration::default();
     };
 
-    let sessions_state = match terminal_state.inner.lock() {
-        Ok(state) => state,
-        Err(error) => {
-            return GrooveListTerminalIntegration {
-                integration_error: Some(format!(
-                    "Failed to acquire Groove terminal session lock: {error}"
-                )),
-                ..GrooveListTerminalIntegration::default()
-            };
-        }
-    };
+    let sessions_state = terminal_state.lock_sessions();
+
 
     let mut integration = GrooveListTerminalIntegration {
         session_count: sessions_state.sessions_by_id.len(),
@@ -435,6 +574,7 @@ fn inject_groove_terminal_sessions_into_runtime_rows(
     };
     let workspace_root_key = workspace_root_storage_key(workspace_root);
     let mut injected_worktrees = HashSet::new();
+    let mut worktree_records = None;
 
     for session in sessions_state.sessions_by_id.values() {
         let session_workspace_root_key = workspace_root_storage_key(Path::new(&session.workspace_root));
@@ -453,11 +593,17 @@ fn inject_groove_terminal_sessions_into_runtime_rows(
             .entry(worktree.to_string())
             .or_insert_with(|| {
                 injected_worktrees.insert(worktree.to_string());
+                let worktree_records =
+                    worktree_records.get_or_insert_with(|| read_worktree_records(workspace_root));
                 RuntimeStateRow {
-                    branch: branch_guess_from_worktree_name(worktree),
+                    branch: worktree_branch_from_records(worktree_records, worktree),
                     worktree: worktree.to_string(),
                     log_state: "unknown".to_string(),
                     log_target: None,
+                    resources: None,
+                    badges: None,
+                    parent: None,
+                    children: Vec::new(),
                 }
             });
     }
@@ -468,6 +614,246 @@ fn inject_groove_terminal_sessions_into_runtime_rows(
     integration
 }
 
+/// Badges of one groove list row, most pressing first. `failed_commands`
+/// holds the labels of custom commands whose last run failed.
+fn worktree_badges(
+    status: Option<&git::GitPorcelainCounts>,
+    ahead_behind: Option<(u32, u32)>,
+    pull_request: Option<&PullRequestRecord>,
+    failed_commands: &[&str],
+    running: bool,
+) -> Vec<WorktreeBadge> {
+    let badge = |kind: &str, label: String| WorktreeBadge {
+        kind: kind.to_string(),
+        label,
+    };
+    let mut badges = Vec::new();
+    if running {
+        badges.push(badge("running", "running".to_string()));
+    }
+    for label in failed_commands {
+        badges.push(badge("failing", format!("{label} failing")));
+    }
+    if status.is_some_and(|counts| counts.dirty()) {
+        badges.push(badge("dirty", "dirty".to_string()));
+    }
+    if let Some((ahead, behind)) = ahead_behind {
+        if ahead > 0 {
+            badges.push(badge("ahead", format!("ahead {ahead}")));
+        }
+        if behind > 0 {
+            badges.push(badge("behind", format!("behind {behind}")));
+        }
+    }
+    if let Some(pull_request) = pull_request {
+        badges.push(badge("pr", format!("PR #{}", pull_request.number)));
+    }
+    badges
+}
+
+/// Fills in `RuntimeStateRow.badges` for a successful groove list, from what
+/// the backend already keeps: running grooves, captured command runs, the
+/// pull requests in `workspace.json` and, in builds with `native-git`, the
+/// git state read in-process.
+fn attach_worktree_badges(app: &AppHandle, response: &mut GrooveListResponse, dir: Option<&str>) {
+    let Some(workspace_root) = response.workspace_root.clone().filter(|_| response.ok) else {
+        return;
+    };
+    let workspace_root_path = PathBuf::from(&workspace_root);
+    let workspace_meta = ensure_workspace_meta(&workspace_root_path)
+        .ok()
+        .map(|(meta, _)| meta);
+    let worktrees_dir = workspace_meta
+        .as_ref()
+        .map(|meta| effective_workspace_root(&workspace_root_path, meta))
+        .unwrap_or_else(|| workspace_root_path.clone())
+        .join(dir.unwrap_or(".worktrees"));
+    let running = read_running_grooves(app, &workspace_root_path)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|record| record.worktree)
+        .collect::<HashSet<_>>();
+    let runs = app
+        .try_state::<CommandRunsState>()
+        .map(|state| {
+            state
+                .lock_runs()
+                .iter()
+                .filter(|run| run.workspace_root == workspace_root)
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    for (worktree, row) in response.rows.iter_mut() {
+        let worktree_path = worktrees_dir.join(worktree);
+        let status = native_git::porcelain_status(&worktree_path)
+            .map(|output| parse_git_porcelain_counts(&output));
+        let pull_request = workspace_meta
+            .as_ref()
+            .and_then(|meta| meta.worktree_records.get(worktree))
+            .and_then(|record| record.pull_requests.last());
+        // Runs are newest first, so the first run of a command is its last.
+        let mut seen_commands = HashSet::new();
+        let failed_commands = runs
+            .iter()
+            .filter(|run| run.worktree == *worktree)
+            .filter(|run| seen_commands.insert(run.command_id.as_str()))
+            .filter(|run| run.status == CommandRunStatus::Failed)
+            .map(|run| run.label.as_str())
+            .collect::<Vec<_>>();
+        row.badges = Some(worktree_badges(
+            status.as_ref(),
+            native_git::ahead_behind(&worktree_path),
+            pull_request,
+            &failed_commands,
+            running.contains(worktree),
+        ));
+    }
+}
+
+/// Drops the rows that fail `filter` and returns the remaining worktrees in
+/// `filter.sort` order, if it is set. `last_executed_at` and `diff_sizes` are
+/// by worktree; ties and missing values fall back to name order.
+fn filter_groove_list_rows(
+    rows: &mut HashMap<String, RuntimeStateRow>,
+    filter: &GrooveListFilter,
+    records: &HashMap<String, WorktreeRecord>,
+    last_executed_at: &HashMap<String, String>,
+    diff_sizes: &HashMap<String, usize>,
+) -> Option<Vec<String>> {
+    let tag = filter
+        .tag
+        .as_deref()
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty());
+    rows.retain(|worktree, row| {
+        let record = records.get(worktree);
+        let state = record.map_or(WorktreeState::Pending, |record| record.state);
+        let tagged = |tag: &str| {
+            record.is_some_and(|record| {
+                record
+                    .tags
+                    .iter()
+                    .any(|candidate| candidate.eq_ignore_ascii_case(tag))
+            })
+        };
+        (filter.status.is_empty() || filter.status.contains(&state))
+            && tag.is_none_or(tagged)
+            && (filter.activity.is_empty() || filter.activity.contains(&row.log_state))
+    });
+
+    let sort = filter.sort?;
+    let mut order = rows.keys().cloned().collect::<Vec<_>>();
+    order.sort();
+    match sort {
+        GrooveListSort::Name => {}
+        GrooveListSort::LastActivity => order
+            .sort_by(|left, right| last_executed_at.get(right).cmp(&last_executed_at.get(left))),
+        GrooveListSort::DiffSize => order.sort_by_key(|worktree| {
+            std::cmp::Reverse(diff_sizes.get(worktree).copied().unwrap_or(0))
+        }),
+    }
+    Some(order)
+}
+
+/// Applies the filter a groove list was requested with to a successful
+/// response, and echoes it back.
+fn apply_groove_list_filter(
+    app: &AppHandle,
+    response: &mut GrooveListResponse,
+    filter: GrooveListFilter,
+    dir: Option<&str>,
+) {
+    let Some(workspace_root) = response.workspace_root.clone().filter(|_| response.ok) else {
+        return;
+    };
+    let workspace_root = PathBuf::from(workspace_root);
+    let records = read_worktree_records(&workspace_root);
+
+    let last_executed_at = if filter.sort == Some(GrooveListSort::LastActivity) {
+        let workspace_key = workspace_root_storage_key(&workspace_root);
+        let entries = read_persisted_worktree_execution_state(app)
+            .ok()
+            .and_then(|mut state| state.last_executed_at_by_workspace.remove(&workspace_key))
+            .unwrap_or_default();
+        response
+            .rows
+            .keys()
+            .filter_map(|worktree| {
+                let id = records.get(worktree).map(|record| record.id.as_str());
+                let executed_at = id
+                    .and_then(|id| entries.get(id))
+                    .or_else(|| entries.get(worktree))?;
+                Some((worktree.clone(), executed_at.clone()))
+            })
+            .collect()
+    } else {
+        HashMap::new()
+    };
+
+    let diff_sizes = if filter.sort == Some(GrooveListSort::DiffSize) {
+        let worktrees_dir = ensure_workspace_meta(&workspace_root)
+            .map(|(meta, _)| effective_workspace_root(&workspace_root, &meta))
+            .unwrap_or_else(|_| workspace_root.clone())
+            .join(dir.unwrap_or(".worktrees"));
+        response
+            .rows
+            .keys()
+            .filter_map(|worktree| {
+                let output = native_git::porcelain_status(&worktrees_dir.join(worktree))?;
+                let changed = output
+                    .lines()
+                    .filter(|line| !line.trim().is_empty())
+                    .count();
+                Some((worktree.clone(), changed))
+            })
+            .collect()
+    } else {
+        HashMap::new()
+    };
+
+    response.order = filter_groove_list_rows(
+        &mut response.rows,
+        &filter,
+        &records,
+        &last_executed_at,
+        &diff_sizes,
+    );
+    response.filter = Some(filter);
+}
+
+/// Keeps one page of a successful response's rows, in `order` when the list
+/// was sorted and by name otherwise. `order` then lists the page's worktrees.
+fn paginate_groove_list(response: &mut GrooveListResponse, page: &RowsPagePayload) {
+    if !response.ok {
+        return;
+    }
+    let by_name = response
+        .filter
+        .as_ref()
+        .and_then(|filter| filter.sort)
+        .is_none_or(|sort| sort == GrooveListSort::Name);
+    let ordered = response.order.take().unwrap_or_else(|| {
+        let mut names = response.rows.keys().cloned().collect::<Vec<_>>();
+        names.sort();
+        names
+    });
+    match worktree_rows_page(&ordered, page, by_name) {
+        Ok((range, info)) => {
+            let order = ordered[range].to_vec();
+            response.rows.retain(|worktree, _| order.contains(worktree));
+            response.order = Some(order);
+            response.page = Some(info);
+        }
+        Err(error) => {
+            response.ok = false;
+            response.rows.clear();
+            response.error = Some(error);
+        }
+    }
+}
+
 fn collect_groove_list_via_shell(
     app: &AppHandle,
     workspace_root: &Path,
@@ -527,10 +913,12 @@ fn ensure_worktree_in_dir(
         let expected_worktrees_dir = workspace_root.join(dir);
         (expected_worktrees_dir.clone(), expected_worktrees_dir.join(worktree))
     };
-    let expected_resolved = expected_worktrees_dir
-        .canonicalize()
+    // A worktree that is a junction is checked by where it sits, not by
+    // where it leads.
+    let canonicalize_entry_path = crate::backend::common::platform_env::canonicalize_entry_path;
+    let expected_resolved = canonicalize_entry_path(&expected_worktrees_dir)
         .unwrap_or_else(|_| expected_worktrees_dir.clone());
-    let target_resolved = target.canonicalize().unwrap_or_else(|_| target.clone());
+    let target_resolved = canonicalize_entry_path(&target).unwrap_or_else(|_| target.clone());
 
     if !target_resolved.starts_with(&expected_resolved) {
         return Err(format!(
@@ -562,15 +950,13 @@ fn clear_stale_worktree_state(
 ) -> Result<(), String> {
     clear_worktree_tombstone(app, workspace_root, worktree)?;
     clear_worktree_last_executed_at(app, workspace_root, worktree)?;
-    invalidate_workspace_context_cache(app, workspace_root);
-    invalidate_groove_list_cache_for_workspace(app, workspace_root);
+    invalidate_workspace_caches(app, workspace_root);
     Ok(())
 }
 
 fn resolve_branch_from_worktree(worktree_path: &Path) -> Option<String> {
-    let output = Command::new("git")
+    let output = git_command_at(worktree_path)
         .args(["rev-parse", "--abbrev-ref", "HEAD"])
-        .current_dir(worktree_path)
         .output()
         .ok()?;
 
@@ -699,6 +1085,37 @@ fn stop_process_by_pid(pid: i32) -> Result<(bool, i32), String> {
     }
 }
 
+fn verify_recorded_groove_process(record: &RunningGrooveRecord, pid: i32) -> Result<(), String> {
+    let identity = crate::backend::common::platform_env::read_process_identity(pid);
+    diagnostics::verify_recorded_process(
+        pid,
+        &record.command,
+        record.process_started_at.as_deref(),
+        identity
+            .as_ref()
+            .map(|identity| (identity.started_at.as_str(), identity.command.as_str())),
+    )
+}
+
+/// Whether the process of a running-groove record is still alive, counting a
+/// PID now held by another process as gone.
+fn recorded_groove_process_running(record: &RunningGrooveRecord) -> Option<bool> {
+    let pid = record.pid.and_then(|pid| i32::try_from(pid).ok())?;
+    Some(is_process_running(pid) && verify_recorded_groove_process(record, pid).is_ok())
+}
+
+/// Stops `pid` for a running-groove record, refusing when the PID no longer
+/// belongs to the recorded process.
+fn stop_recorded_groove_process(
+    record: &RunningGrooveRecord,
+    pid: i32,
+) -> Result<(bool, i32), String> {
+    if is_process_running(pid) {
+        verify_recorded_groove_process(record, pid)?;
+    }
+    stop_process_by_pid(pid)
+}
+
 fn command_mentions_worktrees(command: &str) -> bool {
     let normalized = command.to_lowercase();
     normalized.contains("/.worktree/")
@@ -835,3 +1252,249 @@ fn is_process_running(pid: i32) -> bool {
     }
 }
 
+
+/// How `groove_new` names the directory it creates under the worktrees dir.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WorktreeDirNameStrategy {
+    /// The sidecar's `branch` → `branch_with_underscores` name.
+    Branch,
+    /// Always use the short `wt-<hash>` alias.
+    Hashed,
+    /// Use the branch name unless it fails preflight, then fall back to the
+    /// hashed alias.
+    Auto,
+}
+
+fn parse_worktree_dir_name_strategy(
+    value: Option<&str>,
+) -> Result<WorktreeDirNameStrategy, String> {
+    match value.map(str::trim).filter(|value| !value.is_empty()) {
+        None | Some("branch") => Ok(WorktreeDirNameStrategy::Branch),
+        Some("hashed") => Ok(WorktreeDirNameStrategy::Hashed),
+        Some("auto") => Ok(WorktreeDirNameStrategy::Auto),
+        Some(other) => Err(format!(
+            "dirNameStrategy must be one of: branch, hashed, auto (got \"{other}\")."
+        )),
+    }
+}
+
+#[derive(Debug)]
+struct WorktreeDirPreflight {
+    dir_name: String,
+    issues: Vec<WorktreePathIssue>,
+}
+
+fn collect_worktree_dir_issues(
+    worktrees_dir: &Path,
+    dir_name: &str,
+    existing: &[String],
+) -> Vec<WorktreePathIssue> {
+    let mut issues = Vec::new();
+
+    if let Some(reason) = workspace::invalid_worktree_dir_name_reason(dir_name) {
+        issues.push(WorktreePathIssue {
+            kind: "invalid-characters".to_string(),
+            message: format!("\"{dir_name}\": {reason}."),
+        });
+    }
+
+    if let Some(collision) =
+        workspace::find_case_insensitive_collision(dir_name, existing.iter().map(String::as_str))
+    {
+        issues.push(WorktreePathIssue {
+            kind: "case-collision".to_string(),
+            message: format!(
+                "\"{dir_name}\" differs only by case from existing worktree \"{collision}\"."
+            ),
+        });
+    }
+
+    let path_length = worktrees_dir.join(dir_name).as_os_str().len();
+    let max_length = workspace::max_worktree_path_length();
+    if path_length > max_length {
+        issues.push(WorktreePathIssue {
+            kind: "path-too-long".to_string(),
+            message: format!(
+                "Worktree path would be {path_length} characters long (limit {max_length})."
+            ),
+        });
+    }
+
+    issues
+}
+
+/// Picks the directory name for a new worktree and checks it for path length,
+/// case collisions with existing worktrees and non-portable characters before
+/// anything touches git.
+fn preflight_new_worktree_dir(
+    worktrees_dir: &Path,
+    branch: &str,
+    strategy: WorktreeDirNameStrategy,
+) -> WorktreeDirPreflight {
+    let existing = fs::read_dir(worktrees_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter(|entry| path_is_directory(&entry.path()))
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    let branch_dir_name = workspace::worktree_dir_name_for_branch(branch);
+    let hashed_dir_name = workspace::hashed_worktree_dir_name(branch);
+
+    match strategy {
+        WorktreeDirNameStrategy::Branch => WorktreeDirPreflight {
+            issues: collect_worktree_dir_issues(worktrees_dir, &branch_dir_name, &existing),
+            dir_name: branch_dir_name,
+        },
+        WorktreeDirNameStrategy::Hashed => WorktreeDirPreflight {
+            issues: collect_worktree_dir_issues(worktrees_dir, &hashed_dir_name, &existing),
+            dir_name: hashed_dir_name,
+        },
+        WorktreeDirNameStrategy::Auto => {
+            let issues = collect_worktree_dir_issues(worktrees_dir, &branch_dir_name, &existing);
+            if issues.is_empty() {
+                return WorktreeDirPreflight {
+                    dir_name: branch_dir_name,
+                    issues,
+                };
+            }
+            WorktreeDirPreflight {
+                issues: collect_worktree_dir_issues(worktrees_dir, &hashed_dir_name, &existing),
+                dir_name: hashed_dir_name,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod groove_runtime_tests {
+    use super::*;
+
+    #[test]
+    fn orders_worktree_badges_by_urgency() {
+        let counts = git::GitPorcelainCounts {
+            modified: 1,
+            ..Default::default()
+        };
+        let pull_request = PullRequestRecord {
+            number: 42,
+            url: "https://github.com/o/r/pull/42".to_string(),
+            title: None,
+            base: None,
+            head: None,
+            added_at: "2026-01-01T00:00:00Z".to_string(),
+        };
+        let labels = worktree_badges(
+            Some(&counts),
+            Some((3, 0)),
+            Some(&pull_request),
+            &["Tests"],
+            true,
+        )
+        .into_iter()
+        .map(|badge| badge.label)
+        .collect::<Vec<_>>();
+        assert_eq!(
+            labels,
+            vec!["running", "Tests failing", "dirty", "ahead 3", "PR #42"]
+        );
+
+        assert!(worktree_badges(None, Some((0, 0)), None, &[], false).is_empty());
+    }
+
+    #[test]
+    fn filters_and_sorts_groove_list_rows() {
+        let row = |worktree: &str, log_state: &str| {
+            (
+                worktree.to_string(),
+                RuntimeStateRow {
+                    branch: worktree.to_string(),
+                    worktree: worktree.to_string(),
+                    log_state: log_state.to_string(),
+                    log_target: None,
+                    resources: None,
+                    badges: None,
+                    parent: None,
+                    children: Vec::new(),
+                },
+            )
+        };
+        let rows = HashMap::from([
+            row("alpha", "latest"),
+            row("beta", "latest"),
+            row("gamma", "none"),
+            row("delta", "latest"),
+        ]);
+        let mut record: WorktreeRecord = serde_json::from_value(serde_json::json!({
+            "id": "id-beta",
+            "createdAt": "2026-01-01T00:00:00Z",
+            "state": "fighting",
+            "tags": ["Backend"],
+        }))
+        .unwrap();
+        let mut records = HashMap::from([("beta".to_string(), record.clone())]);
+        record.state = WorktreeState::Pending;
+        records.insert("delta".to_string(), record);
+        let last_executed_at = HashMap::from([
+            ("alpha".to_string(), "2026-01-02T00:00:00Z".to_string()),
+            ("delta".to_string(), "2026-01-03T00:00:00Z".to_string()),
+        ]);
+
+        let mut filtered = rows.clone();
+        let filter = GrooveListFilter {
+            status: Vec::new(),
+            tag: Some("backend".to_string()),
+            activity: vec!["latest".to_string()],
+            sort: Some(GrooveListSort::LastActivity),
+        };
+        let order = filter_groove_list_rows(
+            &mut filtered,
+            &filter,
+            &records,
+            &last_executed_at,
+            &HashMap::new(),
+        );
+        assert_eq!(order, Some(vec!["delta".to_string(), "beta".to_string()]));
+
+        let mut filtered = rows;
+        let filter = GrooveListFilter {
+            status: vec![WorktreeState::Pending],
+            ..Default::default()
+        };
+        let order = filter_groove_list_rows(
+            &mut filtered,
+            &filter,
+            &records,
+            &HashMap::new(),
+            &HashMap::new(),
+        );
+        assert_eq!(order, None);
+        let mut remaining = filtered.into_keys().collect::<Vec<_>>();
+        remaining.sort();
+        assert_eq!(remaining, vec!["alpha", "delta", "gamma"]);
+    }
+
+    #[test]
+    fn streamed_output_holds_back_split_characters() {
+        struct Pieces(Vec<&'static [u8]>);
+        impl Read for Pieces {
+            fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+                if self.0.is_empty() {
+                    return Ok(0);
+                }
+                let piece = self.0.remove(0);
+                buffer[..piece.len()].copy_from_slice(piece);
+                Ok(piece.len())
+            }
+        }
+
+        let pieces = Pieces(vec![b"caf", &[0xc3], &[0xa9, b'\n']]);
+        let mut chunks = Vec::new();
+        let output = forward_command_output(pieces, |chunk| chunks.push(chunk));
+        assert_eq!(chunks, vec!["caf", "\u{e9}\n"]);
+        assert_eq!(output, "caf\u{e9}\n".as_bytes());
+    }
+}
@@ -0,0 +1,555 @@
+// Merge trains behind the `merge_train_*` commands: an ordered list of
+// worktrees merged into the base branch one at a time. Each worktree is
+// rebased onto the freshly fetched base and pushed, its pull request's checks
+// are read, the pull request is merged and the worktree removed. The train
+// pauses on conflicts, failing or unfinished checks and failed steps, with its
+// state in `.groove/merge-train.json`; `merge_train_resume` runs the step it
+// paused on again, or skips it, once the problem is fixed by hand.
+
+/// Workspaces whose merge train is being driven, by storage key. A second
+/// start, resume or abort is refused rather than queued behind it.
+static MERGE_TRAINS_RUNNING: std::sync::OnceLock<Mutex<HashSet<String>>> =
+    std::sync::OnceLock::new();
+
+struct MergeTrainClaim {
+    key: String,
+}
+
+impl MergeTrainClaim {
+    fn acquire(workspace_root: &Path) -> Result<Self, String> {
+        let key = workspace_root_storage_key(workspace_root);
+        let inserted = MERGE_TRAINS_RUNNING
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+            .insert(key.clone());
+        if !inserted {
+            return Err("The merge train of this workspace is already running.".to_string());
+        }
+        Ok(Self { key })
+    }
+}
+
+impl Drop for MergeTrainClaim {
+    fn drop(&mut self) {
+        MERGE_TRAINS_RUNNING
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+            .remove(&self.key);
+    }
+}
+
+fn merge_train_state_file(workspace_root: &Path) -> PathBuf {
+    workspace_root.join(".groove").join(MERGE_TRAIN_STATE_FILE)
+}
+
+fn read_merge_train_state(workspace_root: &Path) -> Result<Option<MergeTrainState>, String> {
+    let path = merge_train_state_file(workspace_root);
+    if !path_is_file(&path) {
+        return Ok(None);
+    }
+    let raw = fs::read_to_string(&path)
+        .map_err(|error| format!("Failed to read {}: {error}", path.display()))?;
+    serde_json::from_str(&raw)
+        .map(Some)
+        .map_err(|error| format!("Failed to parse {}: {error}", path.display()))
+}
+
+fn write_merge_train_state(workspace_root: &Path, train: &MergeTrainState) -> Result<(), String> {
+    let path = merge_train_state_file(workspace_root);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|error| format!("Failed to create {}: {error}", parent.display()))?;
+    }
+    let body = serde_json::to_string_pretty(train)
+        .map_err(|error| format!("Failed to serialize the merge train: {error}"))?;
+    fs::write(&path, format!("{body}\n"))
+        .map_err(|error| format!("Failed to write {}: {error}", path.display()))
+}
+
+/// A train that has worktrees left and was not aborted. "running" counts:
+/// it is what a train interrupted by a restart is left at.
+fn merge_train_is_active(train: &MergeTrainState) -> bool {
+    train.status == "running" || train.status == "paused"
+}
+
+fn next_merge_train_step(step: &str, keep_worktrees: bool) -> &'static str {
+    match step {
+        "rebase" => "checks",
+        "checks" => "merge",
+        "merge" if !keep_worktrees => "cleanup",
+        _ => "done",
+    }
+}
+
+/// The open pull request of a worktree's branch.
+fn merge_train_open_pr(worktree: &str, listed: &GhPrListResponse) -> Result<i64, String> {
+    if !listed.ok {
+        return Err(format!(
+            "Could not list the pull requests of {worktree}: {}",
+            listed.error.as_deref().unwrap_or("gh pr list failed.")
+        ));
+    }
+    listed
+        .prs
+        .iter()
+        .find(|pr| pr.state.eq_ignore_ascii_case("open"))
+        .map(|pr| pr.number)
+        .ok_or_else(|| format!("{worktree} has no open pull request."))
+}
+
+fn merge_train_pause(entry: &MergeTrainEntry, reason: &str, message: String) -> MergeTrainPause {
+    MergeTrainPause {
+        worktree: entry.worktree.clone(),
+        step: entry.step.clone(),
+        reason: reason.to_string(),
+        message,
+        conflicted_files: Vec::new(),
+        failed_checks: Vec::new(),
+    }
+}
+
+/// Lets the train merge when the checks passed or there are none. GitHub
+/// still refuses the merge while required checks have not reported.
+fn merge_train_checks_verdict(
+    entry: &MergeTrainEntry,
+    checks: &GhPrChecksResponse,
+) -> Result<(), Box<MergeTrainPause>> {
+    if !checks.ok {
+        let error = checks.error.clone();
+        return Err(Box::new(merge_train_pause(
+            entry,
+            "failed",
+            error.unwrap_or_else(|| "gh pr view failed.".to_string()),
+        )));
+    }
+    match checks.checks_status.as_deref() {
+        Some("failure") => {
+            let failed_checks = checks
+                .checks
+                .iter()
+                .filter(|check| gh_check_failed(check))
+                .map(|check| check.name.clone())
+                .collect::<Vec<_>>();
+            Err(Box::new(MergeTrainPause {
+                failed_checks,
+                ..merge_train_pause(
+                    entry,
+                    "checksFailed",
+                    format!("Checks failed on #{}.", entry.pr_number),
+                )
+            }))
+        }
+        Some("pending") => Err(Box::new(merge_train_pause(
+            entry,
+            "checksPending",
+            format!(
+                "Checks are still running on #{}; resume once they finish.",
+                entry.pr_number
+            ),
+        ))),
+        _ => Ok(()),
+    }
+}
+
+fn run_merge_train_step(
+    app: &AppHandle,
+    request_id: &str,
+    train: &MergeTrainState,
+    entry: &MergeTrainEntry,
+    worktree_path: &Path,
+) -> Result<(), Box<MergeTrainPause>> {
+    let path = worktree_path.display().to_string();
+    let failed = |error: Option<String>, fallback: &str| {
+        Box::new(merge_train_pause(
+            entry,
+            "failed",
+            error.unwrap_or_else(|| fallback.to_string()),
+        ))
+    };
+    match entry.step.as_str() {
+        "rebase" => {
+            if rebase_state_at(worktree_path).is_some() {
+                return Err(Box::new(MergeTrainPause {
+                    conflicted_files: list_unmerged_files(worktree_path)
+                        .map(|files| files.into_iter().map(|file| file.path).collect())
+                        .unwrap_or_default(),
+                    ..merge_train_pause(
+                        entry,
+                        "conflicts",
+                        format!(
+                            "A rebase is in progress in {}; finish or abort it first.",
+                            entry.worktree
+                        ),
+                    )
+                }));
+            }
+            let fetched = run_git_command_at_path(worktree_path, &["fetch", "origin", &train.base]);
+            if fetched.error.is_some() || fetched.exit_code != Some(0) {
+                return Err(failed(
+                    fetched
+                        .error
+                        .or_else(|| first_non_empty_line(&fetched.stderr)),
+                    "git fetch failed.",
+                ));
+            }
+            let onto = format!("origin/{}", train.base);
+            let rebase = run_rebase_step(request_id.to_string(), &path, false, &["rebase", &onto]);
+            if !rebase.conflicted_files.is_empty() {
+                return Err(Box::new(MergeTrainPause {
+                    conflicted_files: rebase.conflicted_files,
+                    ..merge_train_pause(
+                        entry,
+                        "conflicts",
+                        format!(
+                            "Rebasing {} onto {onto} stopped on conflicts; resolve them, continue the rebase and resume.",
+                            entry.worktree
+                        ),
+                    )
+                }));
+            }
+            if !rebase.ok {
+                return Err(failed(rebase.error, "git rebase failed."));
+            }
+            let pushed = git_push(
+                app.clone(),
+                GitPushPayload {
+                    path,
+                    set_upstream: false,
+                    force_with_lease: true,
+                    branch: None,
+                    debug: None,
+                },
+            );
+            if !pushed.ok {
+                return Err(failed(pushed.error, "git push failed."));
+            }
+            Ok(())
+        }
+        "checks" => {
+            let checks = gh_pr_checks_blocking(
+                request_id.to_string(),
+                GhPrViewPayload {
+                    worktree_path: path,
+                    selector: entry.pr_number.to_string(),
+                },
+            );
+            merge_train_checks_verdict(entry, &checks)
+        }
+        "merge" => {
+            let merged = gh_pr_merge_blocking(
+                request_id.to_string(),
+                GhPrMergePayload {
+                    worktree_path: path,
+                    selector: entry.pr_number.to_string(),
+                    strategy: train.strategy.clone(),
+                    delete_branch: !train.keep_worktrees,
+                    subject: None,
+                    body: None,
+                },
+            );
+            if !merged.ok {
+                return Err(failed(merged.error, "gh pr merge failed."));
+            }
+            Ok(())
+        }
+        "cleanup" => {
+            // The commits are merged, so losing them locally is expected.
+            let removed = groove_rm(
+                app.clone(),
+                GrooveRmPayload {
+                    root_name: None,
+                    known_worktrees: Vec::new(),
+                    workspace_meta: None,
+                    target: entry.worktree.clone(),
+                    worktree: entry.worktree.clone(),
+                    dir: Some(train.worktree_dir.clone()),
+                    force: None,
+                    acknowledge: vec!["unpushedCommits".to_string()],
+                    stream: false,
+                    debug: None,
+                },
+            );
+            if !removed.ok {
+                return Err(failed(removed.error, "groove rm failed."));
+            }
+            Ok(())
+        }
+        step => Err(failed(
+            None,
+            &format!("Unknown merge train step \"{step}\"."),
+        )),
+    }
+}
+
+/// Runs the train from where it stands until it pauses or every worktree is
+/// done. `skip` skips the first step instead of running it.
+fn drive_merge_train(
+    app: &AppHandle,
+    request_id: &str,
+    workspace_root: &Path,
+    train: &mut MergeTrainState,
+    mut skip: bool,
+) -> Result<(), String> {
+    let (workspace_meta, _) = ensure_workspace_meta(workspace_root)?;
+    let effective_root = effective_workspace_root(workspace_root, &workspace_meta);
+    train.status = "running".to_string();
+    train.paused = None;
+    train.updated_at = now_iso();
+    write_merge_train_state(workspace_root, train)?;
+
+    while let Some(index) = train.entries.iter().position(|entry| entry.step != "done") {
+        let entry = &train.entries[index];
+        let outcome = if skip {
+            skip = false;
+            Ok(())
+        } else if command_cancelled() {
+            Err(Box::new(merge_train_pause(
+                entry,
+                "cancelled",
+                "The merge train was cancelled.".to_string(),
+            )))
+        } else {
+            let worktree_path =
+                ensure_worktree_in_dir(&effective_root, &entry.worktree, &train.worktree_dir);
+            match worktree_path {
+                Ok(worktree_path) => {
+                    run_merge_train_step(app, request_id, train, entry, &worktree_path)
+                }
+                Err(error) => Err(Box::new(merge_train_pause(entry, "failed", error))),
+            }
+        };
+        log_line(&format!(
+            "[merge-train] workspace={} worktree={} step={} outcome={}",
+            workspace_root.display(),
+            entry.worktree,
+            entry.step,
+            outcome
+                .as_ref()
+                .map_or_else(|pause| pause.reason.as_str(), |_| "ok")
+        ));
+
+        train.updated_at = now_iso();
+        match outcome {
+            Ok(()) => {
+                let entry = &mut train.entries[index];
+                entry.step = next_merge_train_step(&entry.step, train.keep_worktrees).to_string();
+            }
+            Err(pause) => {
+                train.status = "paused".to_string();
+                train.paused = Some(*pause);
+                return write_merge_train_state(workspace_root, train);
+            }
+        }
+        write_merge_train_state(workspace_root, train)?;
+    }
+
+    train.status = "completed".to_string();
+    write_merge_train_state(workspace_root, train)
+}
+
+fn start_merge_train(
+    app: &AppHandle,
+    request_id: &str,
+    workspace_root: &Path,
+    payload: MergeTrainStartPayload,
+) -> Result<MergeTrainState, String> {
+    let _claim = MergeTrainClaim::acquire(workspace_root)?;
+    if read_merge_train_state(workspace_root)?.is_some_and(|train| merge_train_is_active(&train)) {
+        return Err("A merge train is already in progress; resume or abort it first.".to_string());
+    }
+
+    let mut worktrees: Vec<String> = Vec::new();
+    for worktree in payload.worktrees.iter().map(|worktree| worktree.trim()) {
+        if !is_safe_path_token(worktree) {
+            return Err(format!("\"{worktree}\" is not a valid worktree name."));
+        }
+        if worktrees.iter().any(|known| known == worktree) {
+            return Err(format!("{worktree} is listed twice."));
+        }
+        worktrees.push(worktree.to_string());
+    }
+    if worktrees.is_empty() {
+        return Err("worktrees must list at least one worktree.".to_string());
+    }
+    let strategy = normalize_optional(payload.strategy).map(|strategy| strategy.trim().to_string());
+    if let Some(strategy) = strategy.as_deref() {
+        if !MERGE_TRAIN_STRATEGIES.contains(&strategy) {
+            return Err(format!(
+                "strategy must be one of: {}.",
+                MERGE_TRAIN_STRATEGIES.join(", ")
+            ));
+        }
+    }
+    let worktree_dir = validate_optional_relative_path(&payload.dir, "dir")?
+        .unwrap_or_else(|| ".worktrees".to_string());
+
+    let (workspace_meta, _) = ensure_workspace_meta(workspace_root)?;
+    let effective_root = effective_workspace_root(workspace_root, &workspace_meta);
+    let mut entries = Vec::new();
+    let mut first_path = None;
+    for worktree in worktrees {
+        let worktree_path = ensure_worktree_in_dir(&effective_root, &worktree, &worktree_dir)?;
+        let listed = gh_pr_list_blocking(
+            request_id.to_string(),
+            GhWorktreePayload {
+                worktree_path: worktree_path.display().to_string(),
+            },
+        );
+        let pr_number = merge_train_open_pr(&worktree, &listed)?;
+        first_path.get_or_insert(worktree_path);
+        entries.push(MergeTrainEntry {
+            worktree,
+            pr_number,
+            step: "rebase".to_string(),
+        });
+    }
+
+    let base = match normalize_optional(payload.base) {
+        Some(base) => base.trim().to_string(),
+        None => first_path
+            .and_then(|path| {
+                gh_repo_default_branch_blocking(
+                    request_id.to_string(),
+                    GhWorktreePayload {
+                        worktree_path: path.display().to_string(),
+                    },
+                )
+                .default_branch
+            })
+            .ok_or_else(|| "Could not determine the default branch; pass base.".to_string())?,
+    };
+    if !is_valid_branch_token(&base) {
+        return Err("base contains unsafe characters.".to_string());
+    }
+
+    let now = now_iso();
+    let mut train = MergeTrainState {
+        base,
+        strategy,
+        keep_worktrees: payload.keep_worktrees,
+        worktree_dir,
+        status: "running".to_string(),
+        entries,
+        paused: None,
+        started_at: now.clone(),
+        updated_at: now,
+    };
+    drive_merge_train(app, request_id, workspace_root, &mut train, false)?;
+    Ok(train)
+}
+
+fn resume_merge_train(
+    app: &AppHandle,
+    request_id: &str,
+    workspace_root: &Path,
+    skip: bool,
+) -> Result<MergeTrainState, String> {
+    let _claim = MergeTrainClaim::acquire(workspace_root)?;
+    let mut train = read_merge_train_state(workspace_root)?
+        .filter(merge_train_is_active)
+        .ok_or_else(|| "There is no merge train to resume.".to_string())?;
+    drive_merge_train(app, request_id, workspace_root, &mut train, skip)?;
+    Ok(train)
+}
+
+/// Stops the train where it is. A rebase it left in progress stays as is.
+fn abort_merge_train(workspace_root: &Path) -> Result<MergeTrainState, String> {
+    let _claim = MergeTrainClaim::acquire(workspace_root)?;
+    let mut train = read_merge_train_state(workspace_root)?
+        .filter(merge_train_is_active)
+        .ok_or_else(|| "There is no merge train to abort.".to_string())?;
+    train.status = "aborted".to_string();
+    train.updated_at = now_iso();
+    write_merge_train_state(workspace_root, &train)?;
+    Ok(train)
+}
+
+#[cfg(test)]
+mod merge_train_runtime_tests {
+    use super::*;
+
+    fn entry() -> MergeTrainEntry {
+        MergeTrainEntry {
+            worktree: "feature".to_string(),
+            pr_number: 42,
+            step: "checks".to_string(),
+        }
+    }
+
+    fn checks(statuses: &[(&str, &str, Option<&str>)]) -> GhPrChecksResponse {
+        let checks = statuses
+            .iter()
+            .map(|(name, status, conclusion)| GhPrCheck {
+                name: name.to_string(),
+                status: status.to_string(),
+                conclusion: conclusion.map(str::to_string),
+                details_url: None,
+                workflow: None,
+            })
+            .collect::<Vec<_>>();
+        GhPrChecksResponse {
+            request_id: String::new(),
+            ok: true,
+            number: Some(42),
+            checks_status: checks_status(&checks).map(str::to_string),
+            checks,
+            mergeable: None,
+            merge_state_status: None,
+            cancelled: None,
+            error: None,
+            retries: None,
+        }
+    }
+
+    #[test]
+    fn steps_run_in_order_and_cleanup_is_optional() {
+        let steps = |keep_worktrees: bool| {
+            let mut steps = vec!["rebase"];
+            while let Some(&step) = steps.last().filter(|step| **step != "done") {
+                steps.push(next_merge_train_step(step, keep_worktrees));
+            }
+            steps
+        };
+        assert_eq!(
+            steps(false),
+            vec!["rebase", "checks", "merge", "cleanup", "done"]
+        );
+        assert_eq!(steps(true), vec!["rebase", "checks", "merge", "done"]);
+    }
+
+    #[test]
+    fn failing_and_unfinished_checks_pause_the_train() {
+        let entry = entry();
+        assert!(merge_train_checks_verdict(&entry, &checks(&[])).is_ok());
+        assert!(merge_train_checks_verdict(
+            &entry,
+            &checks(&[("build", "COMPLETED", Some("SUCCESS"))])
+        )
+        .is_ok());
+
+        let pending = merge_train_checks_verdict(
+            &entry,
+            &checks(&[
+                ("build", "COMPLETED", Some("SUCCESS")),
+                ("e2e", "IN_PROGRESS", None),
+            ]),
+        )
+        .unwrap_err();
+        assert_eq!(pending.reason, "checksPending");
+
+        let failed = merge_train_checks_verdict(
+            &entry,
+            &checks(&[
+                ("build", "COMPLETED", Some("FAILURE")),
+                ("lint", "COMPLETED", Some("SUCCESS")),
+                ("e2e", "IN_PROGRESS", None),
+            ]),
+        )
+        .unwrap_err();
+        assert_eq!(failed.reason, "checksFailed");
+        assert_eq!(failed.failed_checks, vec!["build".to_string()]);
+        assert_eq!(failed.step, "checks");
+    }
+}
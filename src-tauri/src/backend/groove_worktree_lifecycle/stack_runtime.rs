@@ -0,0 +1,270 @@
+// Stacked grooves. A worktree created with a `parent` branches off the
+// parent's branch and keeps the parent's record id. Groove list rows name
+// their parent and children, `worktree_restack` rebases the children after
+// the parent moved, and `gh_pr_create` targets the parent's branch.
+
+fn worktree_record_name_by_id<'a>(
+    records: &'a HashMap<String, WorktreeRecord>,
+    id: &str,
+) -> Option<&'a str> {
+    records
+        .iter()
+        .find(|(_, record)| record.id == id)
+        .map(|(name, _)| name.as_str())
+}
+
+fn worktree_stack_parent<'a>(
+    records: &'a HashMap<String, WorktreeRecord>,
+    worktree: &str,
+) -> Option<&'a str> {
+    let parent_id = records.get(worktree)?.parent_id.as_deref()?;
+    worktree_record_name_by_id(records, parent_id)
+}
+
+/// Worktrees stacked directly on `worktree`, by name.
+fn worktree_stack_children(
+    records: &HashMap<String, WorktreeRecord>,
+    worktree: &str,
+) -> Vec<String> {
+    let Some(id) = records.get(worktree).map(|record| record.id.as_str()) else {
+        return Vec::new();
+    };
+    let mut children = records
+        .iter()
+        .filter(|(_, record)| record.parent_id.as_deref() == Some(id))
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+    children.sort();
+    children
+}
+
+/// `(child, parent)` pairs to rebase after `worktree` moved, parents before
+/// their children. Without `recursive` only the direct children are listed.
+fn worktree_restack_order(
+    records: &HashMap<String, WorktreeRecord>,
+    worktree: &str,
+    recursive: bool,
+) -> Vec<(String, String)> {
+    let mut order = Vec::new();
+    let mut seen = HashSet::from([worktree.to_string()]);
+    let mut parents = vec![worktree.to_string()];
+    let mut next = 0;
+    while let Some(parent) = parents.get(next).cloned() {
+        next += 1;
+        for child in worktree_stack_children(records, &parent) {
+            if !seen.insert(child.clone()) {
+                continue;
+            }
+            if recursive {
+                parents.push(child.clone());
+            }
+            order.push((child, parent.clone()));
+        }
+    }
+    order
+}
+
+/// Records `parent` as the worktree `worktree` is stacked on, refusing links
+/// that would make the stack a cycle.
+fn link_worktree_parent(workspace_root: &Path, worktree: &str, parent: &str) -> Result<(), String> {
+    let (mut workspace_meta, _) = ensure_workspace_meta(workspace_root)?;
+    let records = &workspace_meta.worktree_records;
+    let parent_id = records
+        .get(parent)
+        .map(|record| record.id.clone())
+        .ok_or_else(|| format!("No worktree record for parent \"{parent}\"."))?;
+    let mut ancestor = Some(parent);
+    let mut depth = 0;
+    while let Some(name) = ancestor {
+        if name == worktree || depth > records.len() {
+            return Err(format!("{worktree} cannot be stacked on {parent}."));
+        }
+        ancestor = worktree_stack_parent(records, name);
+        depth += 1;
+    }
+
+    let record = workspace_meta
+        .worktree_records
+        .get_mut(worktree)
+        .ok_or_else(|| format!("No worktree record for \"{worktree}\"."))?;
+    record.parent_id = Some(parent_id);
+    workspace_meta.updated_at = now_iso();
+    let workspace_json = workspace_root.join(".groove").join("workspace.json");
+    write_workspace_meta_file(&workspace_json, &workspace_meta)
+}
+
+/// The branch of `worktree`: the one Groove recorded, else the one checked
+/// out under `worktrees_dir`.
+fn worktree_stack_branch(
+    records: &HashMap<String, WorktreeRecord>,
+    worktrees_dir: &Path,
+    worktree: &str,
+) -> Option<String> {
+    records
+        .get(worktree)
+        .and_then(|record| record.branch.clone())
+        .or_else(|| resolve_branch_from_worktree(&worktrees_dir.join(worktree)))
+}
+
+/// The branch a worktree stacked on `parent` starts from.
+fn stacked_worktree_base(
+    workspace_root: &Path,
+    effective_root: &Path,
+    worktree_dir: &str,
+    parent: &str,
+) -> Result<String, String> {
+    if !is_safe_path_token(parent) {
+        return Err("parent contains unsafe characters or path segments.".to_string());
+    }
+    let parent_path = ensure_worktree_in_dir(effective_root, parent, worktree_dir)?;
+    let (workspace_meta, _) = ensure_workspace_meta(workspace_root)?;
+    workspace_meta
+        .worktree_records
+        .get(parent)
+        .and_then(|record| record.branch.clone())
+        .or_else(|| resolve_branch_from_worktree(&parent_path))
+        .ok_or_else(|| format!("Could not determine the branch of {parent}."))
+}
+
+/// Branch of the worktree the one at `worktree_path` is stacked on, in the
+/// active workspace.
+fn stacked_parent_branch(app: &AppHandle, worktree_path: &Path) -> Option<String> {
+    let (_, workspace_meta) = active_workspace_meta(app).ok()?;
+    let worktree = worktree_record_name_for_path(&workspace_meta, worktree_path)?;
+    let parent = worktree_stack_parent(&workspace_meta.worktree_records, &worktree)?;
+    let worktrees_dir = worktree_path.parent()?;
+    worktree_stack_branch(&workspace_meta.worktree_records, worktrees_dir, parent)
+}
+
+/// Fills in `RuntimeStateRow.parent` and `children` for a successful groove
+/// list.
+fn attach_worktree_stack(response: &mut GrooveListResponse) {
+    let Some(workspace_root) = response.workspace_root.clone().filter(|_| response.ok) else {
+        return;
+    };
+    let Ok((workspace_meta, _)) = ensure_workspace_meta(Path::new(&workspace_root)) else {
+        return;
+    };
+    let records = &workspace_meta.worktree_records;
+    for (worktree, row) in response.rows.iter_mut() {
+        row.parent = worktree_stack_parent(records, worktree).map(str::to_string);
+        row.children = worktree_stack_children(records, worktree);
+    }
+}
+
+/// Rebases the worktrees stacked on `worktree` onto their parents' branches.
+/// A rebase that stops on conflicts is left in progress, and the children
+/// of a worktree that failed are skipped.
+fn restack_worktree_children(
+    request_id: &str,
+    workspace_root: &Path,
+    worktrees_dir: &Path,
+    worktree: &str,
+    recursive: bool,
+    autostash: bool,
+) -> Result<Vec<WorktreeRestackResult>, String> {
+    let (workspace_meta, _) = ensure_workspace_meta(workspace_root)?;
+    let records = &workspace_meta.worktree_records;
+    if !records.contains_key(worktree) {
+        return Err(format!("No worktree record for \"{worktree}\"."));
+    }
+
+    let mut failed = HashSet::new();
+    let mut results = Vec::new();
+    for (child, parent) in worktree_restack_order(records, worktree, recursive) {
+        if failed.contains(&parent) {
+            failed.insert(child);
+            continue;
+        }
+        let Some(onto) = worktree_stack_branch(records, worktrees_dir, &parent) else {
+            results.push(WorktreeRestackResult {
+                worktree: child.clone(),
+                onto: parent.clone(),
+                ok: false,
+                conflicted_files: Vec::new(),
+                error: Some(format!("Could not determine the branch of {parent}.")),
+            });
+            failed.insert(child);
+            continue;
+        };
+
+        let mut args = vec!["rebase"];
+        if autostash {
+            args.push("--autostash");
+        }
+        args.push(&onto);
+        let child_path = worktrees_dir.join(&child).display().to_string();
+        let rebase = run_rebase_step(request_id.to_string(), &child_path, false, &args);
+        if !rebase.ok {
+            failed.insert(child.clone());
+        }
+        results.push(WorktreeRestackResult {
+            worktree: child,
+            onto,
+            ok: rebase.ok,
+            conflicted_files: rebase.conflicted_files,
+            error: rebase.error,
+        });
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod stack_runtime_tests {
+    use super::*;
+
+    fn records(entries: &[(&str, Option<&str>)]) -> HashMap<String, WorktreeRecord> {
+        entries
+            .iter()
+            .map(|(name, parent)| {
+                let record = serde_json::from_value::<WorktreeRecord>(serde_json::json!({
+                    "id": format!("id-{name}"),
+                    "createdAt": "2026-01-01T00:00:00Z",
+                    "parentId": parent.map(|parent| format!("id-{parent}")),
+                }))
+                .expect("record");
+                (name.to_string(), record)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parents_and_children_follow_record_ids() {
+        let records = records(&[
+            ("base", None),
+            ("api", Some("base")),
+            ("ui", Some("base")),
+            ("ui-tests", Some("ui")),
+        ]);
+
+        assert_eq!(worktree_stack_parent(&records, "ui-tests"), Some("ui"));
+        assert_eq!(worktree_stack_parent(&records, "base"), None);
+        assert_eq!(worktree_stack_children(&records, "base"), vec!["api", "ui"]);
+        assert!(worktree_stack_children(&records, "api").is_empty());
+    }
+
+    #[test]
+    fn restack_order_lists_parents_before_their_children() {
+        let records = records(&[
+            ("base", None),
+            ("api", Some("base")),
+            ("ui", Some("base")),
+            ("ui-tests", Some("ui")),
+        ]);
+
+        let pairs = |order: Vec<(String, String)>| {
+            order
+                .into_iter()
+                .map(|(child, parent)| format!("{parent}>{child}"))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            pairs(worktree_restack_order(&records, "base", false)),
+            vec!["base>api", "base>ui"]
+        );
+        assert_eq!(
+            pairs(worktree_restack_order(&records, "base", true)),
+            vec!["base>api", "base>ui", "ui>ui-tests"]
+        );
+    }
+}
@@ -0,0 +1,360 @@
+// Stale worktree detection behind `worktree_staleness_report`. The rules live
+// in the workspace's `stalenessPolicy`; a worktree matching any enabled rule
+// is stale. Signals are only gathered for enabled rules, so a policy without
+// `prClosed` never runs `gh`. An "archive" policy forgets stale worktrees
+// (state `forgotten`, undoable) unless they are running or dirty; a "prompt"
+// policy marks them for the frontend to offer deleting.
+
+/// What the rules look at for one worktree. `None` means the signal was not
+/// gathered or could not be read, and never matches a rule.
+#[derive(Debug, Clone, Default)]
+struct WorktreeStalenessSignals {
+    last_commit_at: Option<OffsetDateTime>,
+    merged: Option<bool>,
+    /// States of the branch's pull requests, e.g. "OPEN" or "MERGED".
+    pr_states: Option<Vec<String>>,
+    /// Newest opencode log, or the worktree's creation when it has none.
+    last_opencode_activity_at: Option<OffsetDateTime>,
+}
+
+fn normalize_worktree_staleness_policy(
+    policy: WorktreeStalenessPolicy,
+) -> Result<WorktreeStalenessPolicy, String> {
+    let days = |value: Option<u32>, field: &str| match value {
+        Some(0) | None => Ok(None),
+        Some(days) if days > MAX_WORKTREE_STALENESS_DAYS => Err(format!(
+            "{field} must be at most {MAX_WORKTREE_STALENESS_DAYS}."
+        )),
+        Some(days) => Ok(Some(days)),
+    };
+    let action = policy.action.trim().to_lowercase();
+    let action = if action.is_empty() {
+        "report".to_string()
+    } else if WORKTREE_STALENESS_ACTIONS.contains(&action.as_str()) {
+        action
+    } else {
+        return Err(format!(
+            "action must be one of: {}.",
+            WORKTREE_STALENESS_ACTIONS.join(", ")
+        ));
+    };
+    Ok(WorktreeStalenessPolicy {
+        no_commits_days: days(policy.no_commits_days, "noCommitsDays")?,
+        branch_merged: policy.branch_merged,
+        pr_closed: policy.pr_closed,
+        no_opencode_activity_days: days(
+            policy.no_opencode_activity_days,
+            "noOpencodeActivityDays",
+        )?,
+        action,
+    })
+}
+
+/// The rules `signals` match, in policy order.
+fn classify_worktree_staleness(
+    policy: &WorktreeStalenessPolicy,
+    signals: &WorktreeStalenessSignals,
+    now: OffsetDateTime,
+) -> Vec<String> {
+    let older_than = |at: Option<OffsetDateTime>, days: Option<u32>| match (at, days) {
+        (Some(at), Some(days)) => now - at > time::Duration::days(i64::from(days)),
+        _ => false,
+    };
+    let mut reasons = Vec::new();
+    if older_than(signals.last_commit_at, policy.no_commits_days) {
+        reasons.push("noCommits".to_string());
+    }
+    if policy.branch_merged && signals.merged == Some(true) {
+        reasons.push("branchMerged".to_string());
+    }
+    if policy.pr_closed
+        && signals.pr_states.as_ref().is_some_and(|states| {
+            !states.is_empty()
+                && !states
+                    .iter()
+                    .any(|state| state.eq_ignore_ascii_case("open"))
+        })
+    {
+        reasons.push("prClosed".to_string());
+    }
+    if older_than(
+        signals.last_opencode_activity_at,
+        policy.no_opencode_activity_days,
+    ) {
+        reasons.push("noOpencodeActivity".to_string());
+    }
+    reasons
+}
+
+/// The ref a branch counts as merged into: `origin/HEAD`, else a local
+/// `main` or `master`.
+fn worktree_staleness_base_ref(repository_path: &Path) -> Option<String> {
+    ["origin/HEAD", "main", "master"]
+        .into_iter()
+        .find(|reference| {
+            run_git_command_at_path(
+                repository_path,
+                &["rev-parse", "--verify", "--quiet", reference],
+            )
+            .exit_code
+                == Some(0)
+        })
+        .map(str::to_string)
+}
+
+/// Whether HEAD is contained in `base_ref`. A branch with no commits of its
+/// own (HEAD at the tip of `base_ref`) does not count as merged.
+fn worktree_branch_merged(worktree_path: &Path, base_ref: &str) -> Option<bool> {
+    let head = run_git_command_at_path(worktree_path, &["rev-parse", "HEAD"]);
+    let base = run_git_command_at_path(worktree_path, &["rev-parse", base_ref]);
+    if head.exit_code != Some(0) || base.exit_code != Some(0) {
+        return None;
+    }
+    if head.stdout.trim() == base.stdout.trim() {
+        return Some(false);
+    }
+    match run_git_command_at_path(
+        worktree_path,
+        &["merge-base", "--is-ancestor", "HEAD", base_ref],
+    )
+    .exit_code
+    {
+        Some(0) => Some(true),
+        Some(1) => Some(false),
+        _ => None,
+    }
+}
+
+fn read_worktree_staleness_signals(
+    policy: &WorktreeStalenessPolicy,
+    worktree_path: &Path,
+    base_ref: Option<&str>,
+    created_at: Option<&str>,
+) -> WorktreeStalenessSignals {
+    let mut signals = WorktreeStalenessSignals::default();
+    if policy.no_commits_days.is_some() {
+        let result = run_git_command_at_path(worktree_path, &["log", "-1", "--format=%cI"]);
+        signals.last_commit_at = first_non_empty_line(&result.stdout)
+            .as_deref()
+            .and_then(parse_rfc3339_opt);
+    }
+    if policy.branch_merged {
+        signals.merged =
+            base_ref.and_then(|base_ref| worktree_branch_merged(worktree_path, base_ref));
+    }
+    if policy.pr_closed {
+        let listed = gh_pr_list_blocking(
+            String::new(),
+            GhWorktreePayload {
+                worktree_path: worktree_path.display().to_string(),
+            },
+        );
+        signals.pr_states = listed
+            .ok
+            .then(|| listed.prs.into_iter().map(|pr| pr.state).collect());
+    }
+    if policy.no_opencode_activity_days.is_some() {
+        let latest_log_mtime_ms = collect_native_log_signals(worktree_path).latest_log_mtime_ms;
+        signals.last_opencode_activity_at = if latest_log_mtime_ms > 0 {
+            OffsetDateTime::from_unix_timestamp_nanos(latest_log_mtime_ms as i128 * 1_000_000).ok()
+        } else {
+            created_at.and_then(parse_rfc3339_opt)
+        };
+    }
+    signals
+}
+
+fn format_staleness_time(at: Option<OffsetDateTime>) -> Option<String> {
+    at.and_then(|at| at.format(&Rfc3339).ok())
+}
+
+/// Classifies every worktree on disk that is not already forgotten and
+/// applies the policy's action unless `dry_run`.
+fn run_worktree_staleness_report(
+    app: &AppHandle,
+    workspace_root: &Path,
+    dry_run: bool,
+) -> Result<(Option<WorktreeStalenessPolicy>, Vec<WorktreeStalenessEntry>), String> {
+    let (workspace_meta, _) = ensure_workspace_meta(workspace_root)?;
+    let Some(policy) = workspace_meta.staleness_policy.clone() else {
+        return Ok((None, Vec::new()));
+    };
+    let effective_root = effective_workspace_root(workspace_root, &workspace_meta);
+    let mut execution_state = read_persisted_worktree_execution_state(app)?;
+    let (_, rows) = scan_workspace_worktrees_with_state(
+        app,
+        workspace_root,
+        &effective_root,
+        &workspace_meta,
+        &mut execution_state,
+    )?;
+
+    let mut running: HashSet<String> = running_grooves_in_state(&execution_state, workspace_root)
+        .into_iter()
+        .map(|record| record.worktree)
+        .collect();
+    {
+        let state = app.state::<GrooveTerminalState>();
+        running.extend(active_worktrees_for_workspace(
+            &state.lock_sessions(),
+            workspace_root,
+        ));
+    }
+
+    let base_ref = if policy.branch_merged {
+        worktree_staleness_base_ref(&effective_root)
+    } else {
+        None
+    };
+    let now = OffsetDateTime::now_utc();
+    let mut entries = Vec::new();
+    let mut archived_any = false;
+    for row in rows.into_iter().filter(|row| row.status != "deleted") {
+        let record = workspace_meta.worktree_records.get(&row.worktree);
+        if record.is_some_and(|record| record.state == WorktreeState::Forgotten) {
+            continue;
+        }
+        let worktree_path = PathBuf::from(&row.path);
+        let signals = read_worktree_staleness_signals(
+            &policy,
+            &worktree_path,
+            base_ref.as_deref(),
+            record.map(|record| record.created_at.as_str()),
+        );
+        let reasons = classify_worktree_staleness(&policy, &signals, now);
+        let mut entry = WorktreeStalenessEntry {
+            worktree: row.worktree.clone(),
+            branch: Some(row.branch_guess.clone()).filter(|branch| !branch.is_empty()),
+            stale: !reasons.is_empty(),
+            reasons,
+            last_commit_at: format_staleness_time(signals.last_commit_at),
+            last_opencode_activity_at: format_staleness_time(signals.last_opencode_activity_at),
+            action: None,
+            skipped: None,
+        };
+        if entry.stale && policy.action != "report" {
+            if running.contains(&row.worktree) {
+                entry.skipped = Some("running".to_string());
+            } else if worktree_is_dirty(&worktree_path) {
+                entry.skipped = Some("dirty".to_string());
+            } else if policy.action == "prompt" {
+                entry.action = Some("delete".to_string());
+            } else if !dry_run {
+                let previous_record = current_worktree_record(workspace_root, &row.worktree);
+                let record =
+                    set_worktree_state(workspace_root, &row.worktree, WorktreeState::Forgotten)?;
+                push_worktree_undo_entry(
+                    app,
+                    workspace_root,
+                    &row.worktree,
+                    "worktree_staleness_report",
+                    format!("Restore stale worktree {}", row.worktree),
+                    previous_record.as_ref(),
+                    &record,
+                );
+                entry.action = Some("archived".to_string());
+                archived_any = true;
+            }
+        }
+        entries.push(entry);
+    }
+
+    if archived_any {
+        invalidate_workspace_caches(app, workspace_root);
+    }
+    log_line(&format!(
+        "[staleness] workspace={} worktrees={} stale={} action={}",
+        workspace_root.display(),
+        entries.len(),
+        entries.iter().filter(|entry| entry.stale).count(),
+        policy.action
+    ));
+    Ok((Some(policy), entries))
+}
+
+#[cfg(test)]
+mod staleness_runtime_tests {
+    use super::*;
+
+    fn days_ago(now: OffsetDateTime, days: i64) -> Option<OffsetDateTime> {
+        Some(now - time::Duration::days(days))
+    }
+
+    #[test]
+    fn a_worktree_is_stale_for_each_enabled_rule_it_matches() {
+        let now = OffsetDateTime::now_utc();
+        let policy = WorktreeStalenessPolicy {
+            no_commits_days: Some(30),
+            branch_merged: true,
+            pr_closed: true,
+            no_opencode_activity_days: Some(7),
+            ..WorktreeStalenessPolicy::default()
+        };
+        let signals = WorktreeStalenessSignals {
+            last_commit_at: days_ago(now, 45),
+            merged: Some(false),
+            pr_states: Some(vec!["MERGED".to_string(), "CLOSED".to_string()]),
+            last_opencode_activity_at: days_ago(now, 2),
+        };
+
+        assert_eq!(
+            classify_worktree_staleness(&policy, &signals, now),
+            vec!["noCommits".to_string(), "prClosed".to_string()]
+        );
+    }
+
+    #[test]
+    fn disabled_rules_and_missing_signals_never_match() {
+        let now = OffsetDateTime::now_utc();
+        let signals = WorktreeStalenessSignals {
+            last_commit_at: days_ago(now, 400),
+            merged: Some(true),
+            pr_states: Some(Vec::new()),
+            last_opencode_activity_at: None,
+        };
+        assert!(
+            classify_worktree_staleness(&WorktreeStalenessPolicy::default(), &signals, now)
+                .is_empty()
+        );
+
+        let policy = WorktreeStalenessPolicy {
+            pr_closed: true,
+            no_opencode_activity_days: Some(1),
+            ..WorktreeStalenessPolicy::default()
+        };
+        assert!(classify_worktree_staleness(&policy, &signals, now).is_empty());
+
+        let open = WorktreeStalenessSignals {
+            pr_states: Some(vec!["CLOSED".to_string(), "OPEN".to_string()]),
+            ..WorktreeStalenessSignals::default()
+        };
+        assert!(classify_worktree_staleness(&policy, &open, now).is_empty());
+    }
+
+    #[test]
+    fn policies_are_normalized() {
+        let policy = normalize_worktree_staleness_policy(WorktreeStalenessPolicy {
+            no_commits_days: Some(0),
+            action: " Archive ".to_string(),
+            ..WorktreeStalenessPolicy::default()
+        })
+        .unwrap();
+        assert_eq!(policy.no_commits_days, None);
+        assert_eq!(policy.action, "archive");
+
+        assert!(
+            normalize_worktree_staleness_policy(WorktreeStalenessPolicy {
+                action: "delete".to_string(),
+                ..WorktreeStalenessPolicy::default()
+            })
+            .is_err()
+        );
+        assert!(
+            normalize_worktree_staleness_policy(WorktreeStalenessPolicy {
+                no_opencode_activity_days: Some(MAX_WORKTREE_STALENESS_DAYS + 1),
+                ..WorktreeStalenessPolicy::default()
+            })
+            .is_err()
+        );
+    }
+}
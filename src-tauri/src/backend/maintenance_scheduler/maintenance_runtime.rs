@@ -0,0 +1,429 @@
+// Maintenance tasks run at set times while the app is open: fetching and
+// pruning the active workspace's remotes ("fetchPrune"), a dry run of its
+// staleness policy ("staleBranchReport"), trimming terminal storage and
+// dropping cached workspace state ("cleanup"), and recomputing the worktree
+// conflict matrix ("conflictMatrix"). Schedules are local times of day kept
+// with the outcome of each task's last run in `maintenance.json` in the app
+// data directory. A scheduler thread started with the app runs a task once
+// its time has passed since the thread started and since the task last ran,
+// so a time missed while the app was closed is not caught up on.
+// `maintenance_run_now` runs tasks straight away.
+
+/// Serializes reads and writes of `maintenance.json`; tasks run outside it.
+static MAINTENANCE_STATE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Tasks being run, by name. A task that is due or asked for while it runs
+/// is skipped.
+static MAINTENANCE_TASKS_RUNNING: std::sync::OnceLock<Mutex<HashSet<String>>> =
+    std::sync::OnceLock::new();
+
+fn maintenance_tasks_running() -> MutexGuard<'static, HashSet<String>> {
+    MAINTENANCE_TASKS_RUNNING
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+}
+
+struct MaintenanceTaskClaim {
+    task: String,
+}
+
+impl MaintenanceTaskClaim {
+    fn acquire(task: &str) -> Option<Self> {
+        maintenance_tasks_running()
+            .insert(task.to_string())
+            .then(|| Self {
+                task: task.to_string(),
+            })
+    }
+}
+
+impl Drop for MaintenanceTaskClaim {
+    fn drop(&mut self) {
+        maintenance_tasks_running().remove(&self.task);
+    }
+}
+
+fn lock_maintenance_state() -> MutexGuard<'static, ()> {
+    MAINTENANCE_STATE_LOCK
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+}
+
+fn maintenance_state_file(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|error| format!("Failed to resolve app data directory: {error}"))?;
+    fs::create_dir_all(&app_data_dir)
+        .map_err(|error| format!("Failed to create app data directory: {error}"))?;
+    Ok(app_data_dir.join(MAINTENANCE_STATE_FILE))
+}
+
+fn read_maintenance_state(app: &AppHandle) -> Result<PersistedMaintenance, String> {
+    let path = maintenance_state_file(app)?;
+    if !path_is_file(&path) {
+        return Ok(PersistedMaintenance::default());
+    }
+    let raw = fs::read_to_string(&path)
+        .map_err(|error| format!("Failed to read {}: {error}", path.display()))?;
+    serde_json::from_str(&raw)
+        .map_err(|error| format!("Failed to parse {}: {error}", path.display()))
+}
+
+fn write_maintenance_state(app: &AppHandle, state: &PersistedMaintenance) -> Result<(), String> {
+    let path = maintenance_state_file(app)?;
+    let body = serde_json::to_string_pretty(state)
+        .map_err(|error| format!("Failed to serialize maintenance state: {error}"))?;
+    fs::write(&path, format!("{body}\n"))
+        .map_err(|error| format!("Failed to write {}: {error}", path.display()))
+}
+
+fn normalize_maintenance_task(task: &str) -> Result<&'static str, String> {
+    let task = task.trim();
+    MAINTENANCE_TASKS
+        .iter()
+        .find(|known| **known == task)
+        .copied()
+        .ok_or_else(|| format!("task must be one of: {}.", MAINTENANCE_TASKS.join(", ")))
+}
+
+/// Hour and minute of an "HH:MM" time of day.
+fn parse_maintenance_time(value: &str) -> Result<(u8, u8), String> {
+    let invalid = || format!("at must be a time of day as \"HH:MM\"; got \"{value}\".");
+    let (hour, minute) = value.trim().split_once(':').ok_or_else(invalid)?;
+    let hour = hour.parse::<u8>().map_err(|_| invalid())?;
+    let minute = minute.parse::<u8>().map_err(|_| invalid())?;
+    if hour > 23 || minute > 59 {
+        return Err(invalid());
+    }
+    Ok((hour, minute))
+}
+
+fn apply_maintenance_configuration(
+    state: &mut PersistedMaintenance,
+    payload: &MaintenanceConfigurePayload,
+) -> Result<(), String> {
+    if let Some(offset) = payload.utc_offset_minutes {
+        if !(MIN_MAINTENANCE_UTC_OFFSET_MINUTES..=MAX_MAINTENANCE_UTC_OFFSET_MINUTES)
+            .contains(&offset)
+        {
+            return Err(format!(
+                "utcOffsetMinutes must be between {MIN_MAINTENANCE_UTC_OFFSET_MINUTES} and {MAX_MAINTENANCE_UTC_OFFSET_MINUTES}."
+            ));
+        }
+        state.utc_offset_minutes = offset;
+    }
+    for schedule in &payload.tasks {
+        let task = normalize_maintenance_task(&schedule.task)?;
+        let at = match schedule.at.as_deref().map(str::trim) {
+            Some("") => Some(None),
+            Some(at) => {
+                let (hour, minute) = parse_maintenance_time(at)?;
+                Some(Some(format!("{hour:02}:{minute:02}")))
+            }
+            None => None,
+        };
+        let entry = state.tasks.entry(task.to_string()).or_default();
+        if let Some(enabled) = schedule.enabled {
+            entry.enabled = enabled;
+        }
+        if let Some(at) = at {
+            entry.at = at;
+        }
+    }
+    Ok(())
+}
+
+/// When a task set for `at` in a zone `utc_offset_minutes` ahead of UTC was
+/// last due, at or before `now`.
+fn last_maintenance_occurrence(
+    (hour, minute): (u8, u8),
+    utc_offset_minutes: i32,
+    now: OffsetDateTime,
+) -> Option<OffsetDateTime> {
+    let offset = time::UtcOffset::from_whole_seconds(utc_offset_minutes * 60).ok()?;
+    let local_now = now.to_offset(offset);
+    let due = local_now.replace_time(time::Time::from_hms(hour, minute, 0).ok()?);
+    Some(if due > local_now {
+        due - time::Duration::days(1)
+    } else {
+        due
+    })
+}
+
+/// Whether a task set for `at` came due after `since`, at or before `now`.
+fn maintenance_task_due(
+    at: (u8, u8),
+    utc_offset_minutes: i32,
+    since: OffsetDateTime,
+    now: OffsetDateTime,
+) -> bool {
+    last_maintenance_occurrence(at, utc_offset_minutes, now).is_some_and(|due| due > since)
+}
+
+/// Runs one task against the active workspace, if any, and returns a line
+/// describing what it did.
+fn run_maintenance_task(
+    app: &AppHandle,
+    task: &str,
+    workspace_root: Option<&Path>,
+) -> Result<String, String> {
+    if task == "cleanup" {
+        let (removed, freed) = enforce_terminal_storage_quotas(app);
+        if let Some(workspace_root) = workspace_root {
+            invalidate_workspace_caches(app, workspace_root);
+        }
+        return Ok(format!(
+            "Removed {removed} terminal storage file{} ({freed} bytes) and dropped cached workspace state.",
+            if removed == 1 { "" } else { "s" }
+        ));
+    }
+
+    let workspace_root = workspace_root.ok_or_else(|| "No workspace is active.".to_string())?;
+    match task {
+        "fetchPrune" => {
+            let (workspace_meta, _) = ensure_workspace_meta(workspace_root)?;
+            let effective_root = effective_workspace_root(workspace_root, &workspace_meta);
+            let fetched = run_git_command_at_path(&effective_root, &["fetch", "--all", "--prune"]);
+            if let Some(error) = fetched.error {
+                return Err(error);
+            }
+            if fetched.exit_code != Some(0) {
+                return Err(first_non_empty_line(&fetched.stderr)
+                    .unwrap_or_else(|| "git fetch failed.".to_string()));
+            }
+            invalidate_workspace_caches(app, workspace_root);
+            Ok("Fetched all remotes and pruned deleted remote branches.".to_string())
+        }
+        "staleBranchReport" => {
+            let (policy, worktrees) = run_worktree_staleness_report(app, workspace_root, true)?;
+            if policy.is_none() {
+                return Ok("The workspace has no staleness policy.".to_string());
+            }
+            let stale = worktrees
+                .iter()
+                .filter(|entry| entry.stale)
+                .map(|entry| entry.worktree.as_str())
+                .collect::<Vec<_>>();
+            Ok(if stale.is_empty() {
+                format!("None of {} worktrees are stale.", worktrees.len())
+            } else {
+                format!(
+                    "{} of {} worktrees are stale: {}.",
+                    stale.len(),
+                    worktrees.len(),
+                    stale.join(", ")
+                )
+            })
+        }
+        "conflictMatrix" => {
+            let (worktrees, pairs) = run_workspace_conflict_matrix(app, workspace_root, &[])?;
+            let conflicting = pairs
+                .iter()
+                .filter(|pair| pair.risk == "conflicting")
+                .count();
+            Ok(format!(
+                "{conflicting} of {} pairs of {} worktrees conflict.",
+                pairs.len(),
+                worktrees.len()
+            ))
+        }
+        _ => Err(format!("Unknown maintenance task \"{task}\".")),
+    }
+}
+
+/// Runs `tasks` in order and records each one's outcome. Tasks that are
+/// already running are skipped.
+fn run_maintenance_tasks(app: &AppHandle, tasks: &[&str], trigger: &str) {
+    for task in tasks {
+        if command_cancelled() {
+            return;
+        }
+        let Some(_claim) = MaintenanceTaskClaim::acquire(task) else {
+            continue;
+        };
+        let started_at = now_iso();
+        let started = Instant::now();
+        let workspace_root = active_workspace_root_from_state(app).ok();
+        let result = run_maintenance_task(app, task, workspace_root.as_deref());
+        let run = MaintenanceRun {
+            started_at,
+            duration_ms: started.elapsed().as_millis() as u64,
+            trigger: trigger.to_string(),
+            ok: result.is_ok(),
+            workspace_root: workspace_root.map(|root| root.display().to_string()),
+            summary: result.as_ref().ok().cloned(),
+            error: result.err(),
+        };
+        log_line(&format!(
+            "[maintenance] task={task} trigger={trigger} ok={} duration_ms={}",
+            run.ok, run.duration_ms
+        ));
+
+        let _guard = lock_maintenance_state();
+        let recorded = read_maintenance_state(app).and_then(|mut state| {
+            state.tasks.entry(task.to_string()).or_default().last_run = Some(run);
+            write_maintenance_state(app, &state)
+        });
+        if let Err(error) = recorded {
+            log_line(&format!("[maintenance] {error}"));
+        }
+    }
+}
+
+/// Tasks whose scheduled time came after `started_at` and their last run.
+fn due_maintenance_tasks(
+    state: &PersistedMaintenance,
+    started_at: OffsetDateTime,
+    now: OffsetDateTime,
+) -> Vec<&'static str> {
+    MAINTENANCE_TASKS
+        .into_iter()
+        .filter(|task| {
+            let Some(entry) = state.tasks.get(*task).filter(|entry| entry.enabled) else {
+                return false;
+            };
+            let Some(at) = entry
+                .at
+                .as_deref()
+                .and_then(|at| parse_maintenance_time(at).ok())
+            else {
+                return false;
+            };
+            let last_run_at = entry
+                .last_run
+                .as_ref()
+                .and_then(|run| parse_rfc3339_opt(&run.started_at));
+            let since = last_run_at.map_or(started_at, |last| last.max(started_at));
+            maintenance_task_due(at, state.utc_offset_minutes, since, now)
+        })
+        .collect()
+}
+
+fn maintenance_task_infos(state: &PersistedMaintenance) -> Vec<MaintenanceTaskInfo> {
+    let running = maintenance_tasks_running().clone();
+    let now = OffsetDateTime::now_utc();
+    MAINTENANCE_TASKS
+        .iter()
+        .map(|task| {
+            let entry = state.tasks.get(*task).cloned().unwrap_or_default();
+            let next_run_at = entry
+                .at
+                .as_deref()
+                .filter(|_| entry.enabled)
+                .and_then(|at| parse_maintenance_time(at).ok())
+                .and_then(|at| last_maintenance_occurrence(at, state.utc_offset_minutes, now))
+                .and_then(|due| {
+                    (due + time::Duration::days(1))
+                        .to_offset(time::UtcOffset::UTC)
+                        .format(&Rfc3339)
+                        .ok()
+                });
+            MaintenanceTaskInfo {
+                task: task.to_string(),
+                enabled: entry.enabled,
+                at: entry.at,
+                next_run_at,
+                running: running.contains(*task),
+                last_run: entry.last_run,
+            }
+        })
+        .collect()
+}
+
+fn start_maintenance_scheduler(app: AppHandle) {
+    thread::spawn(move || {
+        let started_at = OffsetDateTime::now_utc();
+        loop {
+            thread::sleep(MAINTENANCE_SCHEDULER_INTERVAL);
+            if background_activity_paused() {
+                continue;
+            }
+            let state = {
+                let _guard = lock_maintenance_state();
+                read_maintenance_state(&app)
+            };
+            match state {
+                Ok(state) => {
+                    let due = due_maintenance_tasks(&state, started_at, OffsetDateTime::now_utc());
+                    run_maintenance_tasks(&app, &due, "schedule");
+                }
+                Err(error) => log_line(&format!("[maintenance] {error}")),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod maintenance_runtime_tests {
+    use super::*;
+
+    fn utc(value: &str) -> OffsetDateTime {
+        OffsetDateTime::parse(value, &Rfc3339).expect("timestamp")
+    }
+
+    #[test]
+    fn schedules_are_validated_and_normalized() {
+        let mut state = PersistedMaintenance::default();
+        let payload = MaintenanceConfigurePayload {
+            utc_offset_minutes: Some(120),
+            tasks: vec![MaintenanceTaskSchedulePayload {
+                task: " fetchPrune ".to_string(),
+                enabled: Some(true),
+                at: Some("3:05".to_string()),
+            }],
+        };
+        apply_maintenance_configuration(&mut state, &payload).expect("configured");
+        assert_eq!(state.utc_offset_minutes, 120);
+        assert_eq!(state.tasks["fetchPrune"].at.as_deref(), Some("03:05"));
+        assert!(state.tasks["fetchPrune"].enabled);
+
+        for (task, time) in [
+            ("fetchPrune", "24:00"),
+            ("fetchPrune", "noon"),
+            ("vacuum", "03:00"),
+        ] {
+            let payload = MaintenanceConfigurePayload {
+                utc_offset_minutes: None,
+                tasks: vec![MaintenanceTaskSchedulePayload {
+                    task: task.to_string(),
+                    enabled: None,
+                    at: Some(time.to_string()),
+                }],
+            };
+            assert!(apply_maintenance_configuration(&mut state, &payload).is_err());
+        }
+    }
+
+    #[test]
+    fn tasks_are_due_once_their_local_time_passes() {
+        // 03:00 at UTC+2 is 01:00 UTC.
+        let started_at = utc("2026-03-01T20:00:00Z");
+        assert!(!maintenance_task_due(
+            (3, 0),
+            120,
+            started_at,
+            utc("2026-03-02T00:59:00Z")
+        ));
+        assert!(maintenance_task_due(
+            (3, 0),
+            120,
+            started_at,
+            utc("2026-03-02T01:00:30Z")
+        ));
+        assert!(!maintenance_task_due(
+            (3, 0),
+            120,
+            utc("2026-03-02T01:00:30Z"),
+            utc("2026-03-02T12:00:00Z")
+        ));
+        // A time that passed before the app started is not caught up on.
+        assert!(!maintenance_task_due(
+            (3, 0),
+            120,
+            utc("2026-03-02T09:00:00Z"),
+            utc("2026-03-02T09:01:00Z")
+        ));
+    }
+}
@@ -24,7 +24,7 @@ fn groove_mcp_port() -> u16 {
 
 fn start_groove_mcp_server(app: AppHandle) {
     if groove_mcp_disabled() {
-        eprintln!("[groove-mcp] disabled via GROOVE_MCP_DISABLED.");
+        log_line("[groove-mcp] disabled via GROOVE_MCP_DISABLED.");
         return;
     }
 
@@ -33,13 +33,15 @@ fn start_groove_mcp_server(app: AppHandle) {
         let server = match tiny_http::Server::http(("127.0.0.1", port)) {
             Ok(server) => server,
             Err(error) => {
-                eprintln!("[groove-mcp] failed to bind 127.0.0.1:{port}: {error}");
+                log_line(&format!(
+                    "[groove-mcp] failed to bind 127.0.0.1:{port}: {error}"
+                ));
                 return;
             }
         };
-        eprintln!(
+        log_line(&format!(
             "[groove-mcp] listening on http://127.0.0.1:{port}/mcp — connect with: claude mcp add --transport http groove http://127.0.0.1:{port}/mcp"
-        );
+        ));
 
         loop {
             match server.recv() {
@@ -48,7 +50,7 @@ fn start_groove_mcp_server(app: AppHandle) {
                     thread::spawn(move || handle_groove_mcp_http_request(app, request));
                 }
                 Err(error) => {
-                    eprintln!("[groove-mcp] accept error: {error}");
+                    log_line(&format!("[groove-mcp] accept error: {error}"));
                 }
             }
         }
@@ -86,6 +88,21 @@ fn groove_mcp_origin_allowed(request: &tiny_http::Request) -> bool {
         || origin.starts_with("https://localhost")
 }
 
+fn groove_mcp_bearer_token(request: &tiny_http::Request) -> Option<String> {
+    request
+        .headers()
+        .iter()
+        .find(|header| header.field.equiv("Authorization"))
+        .and_then(|header| {
+            let value = header.value.as_str().trim();
+            value
+                .get(..7)
+                .filter(|scheme| scheme.eq_ignore_ascii_case("bearer "))
+                .map(|_| value[7..].trim().to_string())
+        })
+        .filter(|token| !token.is_empty())
+}
+
 fn handle_groove_mcp_http_request(app: AppHandle, mut request: tiny_http::Request) {
     if !groove_mcp_origin_allowed(&request) {
         let _ = request.respond(groove_mcp_http_response(
@@ -110,6 +127,18 @@ fn handle_groove_mcp_http_request(app: AppHandle, mut request: tiny_http::Reques
         }
     }
 
+    let bearer = groove_mcp_bearer_token(&request);
+    let granted = match api_token_capabilities(&app, bearer.as_deref(), false) {
+        Ok(granted) => granted,
+        Err(error) => {
+            let _ = request.respond(groove_mcp_http_response(
+                401,
+                Some(serde_json::json!({"error": error})),
+            ));
+            return;
+        }
+    };
+
     let mut body = String::new();
     if request
         .as_reader()
@@ -145,11 +174,15 @@ fn handle_groove_mcp_http_request(app: AppHandle, mut request: tiny_http::Reques
         return;
     }
 
-    let response = groove_mcp_dispatch(&app, &message);
+    let response = groove_mcp_dispatch(&app, &granted, &message);
     let _ = request.respond(groove_mcp_http_response(200, Some(response)));
 }
 
-fn groove_mcp_dispatch(app: &AppHandle, message: &serde_json::Value) -> serde_json::Value {
+fn groove_mcp_dispatch(
+    app: &AppHandle,
+    granted: &[Capability],
+    message: &serde_json::Value,
+) -> serde_json::Value {
     let id = message.get("id").cloned().unwrap_or(serde_json::Value::Null);
     let method = message
         .get("method")
@@ -163,10 +196,10 @@ fn groove_mcp_dispatch(app: &AppHandle, message: &serde_json::Value) -> serde_js
     let result: Result<serde_json::Value, (i64, String)> = match method {
         "initialize" => Ok(groove_mcp_initialize_result(app, &params)),
         "ping" => Ok(serde_json::json!({})),
-        "tools/list" => Ok(serde_json::json!({"tools": groove_mcp_tool_definitions()})),
+        "tools/list" => Ok(serde_json::json!({"tools": groove_mcp_granted_tools(granted)})),
         "resources/list" => Ok(serde_json::json!({"resources": []})),
         "prompts/list" => Ok(serde_json::json!({"prompts": []})),
-        "tools/call" => Ok(groove_mcp_handle_tool_call(app, &params)),
+        "tools/call" => Ok(groove_mcp_handle_tool_call(app, granted, &params)),
         _ => Err((-32601, format!("Method not found: {method}"))),
     };
 
@@ -428,11 +461,39 @@ fn groove_mcp_tool_definitions() -> serde_json::Value {
     ])
 }
 
-fn groove_mcp_handle_tool_call(app: &AppHandle, params: &serde_json::Value) -> serde_json::Value {
+/// The tool definitions the caller holds the capability for.
+fn groove_mcp_granted_tools(granted: &[Capability]) -> Vec<serde_json::Value> {
+    let serde_json::Value::Array(tools) = groove_mcp_tool_definitions() else {
+        return Vec::new();
+    };
+    tools
+        .into_iter()
+        .filter(|tool| {
+            tool.get("name")
+                .and_then(|name| name.as_str())
+                .and_then(capabilities::mcp_tool_capability)
+                .is_some_and(|required| granted.contains(&required))
+        })
+        .collect()
+}
+
+fn groove_mcp_handle_tool_call(
+    app: &AppHandle,
+    granted: &[Capability],
+    params: &serde_json::Value,
+) -> serde_json::Value {
     let name = params
         .get("name")
         .and_then(|value| value.as_str())
         .unwrap_or_default();
+    if let Some(required) = capabilities::mcp_tool_capability(name) {
+        if let Err(error) = authorize_remote_command(granted, name, required) {
+            return serde_json::json!({
+                "content": [{"type": "text", "text": error}],
+                "isError": true,
+            });
+        }
+    }
     let arguments = params
         .get("arguments")
         .cloned()
@@ -625,11 +686,31 @@ fn groove_mcp_create_worktree(
         .map(|(meta, _)| effective_workspace_root(&workspace_root, &meta))
         .unwrap_or_else(|_| workspace_root.clone());
 
+    let preflight = preflight_new_worktree_dir(
+        &effective_root.join(".worktrees"),
+        branch,
+        WorktreeDirNameStrategy::Auto,
+    );
+    if !preflight.issues.is_empty() {
+        let details = preflight
+            .issues
+            .iter()
+            .map(|issue| issue.message.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+        return Err(format!("Worktree preflight failed for \"{branch}\": {details}"));
+    }
+    let stamped = preflight.dir_name;
+
     let mut args = vec!["create".to_string(), branch.to_string()];
     if let Some(base) = base {
         args.push("--base".to_string());
         args.push(base.to_string());
     }
+    if stamped != workspace::worktree_dir_name_for_branch(branch) {
+        args.push("--name".to_string());
+        args.push(stamped.clone());
+    }
 
     let result = run_command(&groove_binary_path(app), &args, &effective_root);
     if result.exit_code != Some(0) || result.error.is_some() {
@@ -645,8 +726,7 @@ fn groove_mcp_create_worktree(
     }
 
     // Post-create registration, mirroring groove_new's success path.
-    let stamped = branch.replace('/', "_");
-    register_worktree_record(&workspace_root, &stamped)?;
+    register_worktree_record(&workspace_root, &stamped, Some(branch))?;
     let _ = sync_worktree_records_with_disk(&workspace_root, &effective_root);
     record_worktree_last_executed_at(app, &workspace_root, &stamped)?;
 
@@ -662,8 +742,7 @@ fn groove_mcp_create_worktree(
     // running/dirty worktrees); emits a "worktree-evicted" event on removal.
     run_post_create_eviction(app, &workspace_root, &effective_root);
 
-    invalidate_workspace_context_cache(app, &workspace_root);
-    invalidate_groove_list_cache_for_workspace(app, &workspace_root);
+    invalidate_workspace_caches(app, &workspace_root);
 
     let mut response = serde_json::json!({
         "name": stamped,
@@ -757,9 +836,7 @@ fn groove_mcp_sessions_by_worktree(
     workspace_root: &Path,
 ) -> HashMap<String, Vec<serde_json::Value>> {
     let state = app.state::<GrooveTerminalState>();
-    let Ok(sessions_state) = state.inner.lock() else {
-        return HashMap::new();
-    };
+    let sessions_state = state.lock_sessions();
 
     let root_key = workspace_root_storage_key(workspace_root);
     let mut sessions_by_worktree: HashMap<String, Vec<serde_json::Value>> = HashMap::new();
@@ -976,9 +1053,7 @@ fn groove_mcp_pause_worktree(app: &AppHandle, worktree: &str) -> Result<serde_js
     let state = app.state::<GrooveTerminalState>();
     let sessions_to_close = {
         let mut sessions_state = state
-            .inner
-            .lock()
-            .map_err(|error| format!("Failed to acquire Groove terminal state lock: {error}"))?;
+            .lock_sessions();
         let session_ids = sessions_state
             .session_ids_by_worktree
             .get(&worktree_key)
@@ -993,8 +1068,10 @@ fn groove_mcp_pause_worktree(app: &AppHandle, worktree: &str) -> Result<serde_js
     let mut closed_session_ids = Vec::new();
     for mut session in sessions_to_close {
         let session_id = session.session_id.clone();
-        let _ = session.child.kill();
-        let _ = collect_groove_terminal_exit_status(session.child.as_mut());
+        if let Some(pty) = session.pty.as_mut() {
+            let _ = pty.child.kill();
+            let _ = collect_groove_terminal_exit_status(pty.child.as_mut());
+        }
         drop(session);
         let _ = clear_running_groove_if_session_matches(app, &workspace_root, worktree, &session_id);
         emit_groove_terminal_lifecycle_event(
@@ -1007,11 +1084,11 @@ fn groove_mcp_pause_worktree(app: &AppHandle, worktree: &str) -> Result<serde_js
         );
         closed_session_ids.push(session_id);
     }
-    invalidate_groove_list_cache_for_workspace(app, &workspace_root);
+    invalidate_worktree_caches(app, &workspace_root, worktree);
 
-    let stop = groove_stop(
-        app.clone(),
-        GrooveStopPayload {
+    let stop = stop_groove_worktree(
+        app,
+        &GrooveStopPayload {
             root_name: None,
             known_worktrees: Vec::new(),
             workspace_meta: None,
@@ -1272,16 +1349,16 @@ fn groove_mcp_terminal_write(
     bytes: &[u8],
 ) -> Result<(), String> {
     let mut sessions_state = state
-        .inner
-        .lock()
-        .map_err(|error| format!("Failed to acquire Groove terminal state lock: {error}"))?;
+        .lock_sessions();
     let Some(session) = sessions_state.sessions_by_id.get_mut(session_id) else {
         return Err("The Claude Code terminal session closed before the prompt was sent.".to_string());
     };
-    session
-        .writer
+    let Some(pty) = session.pty.as_mut() else {
+        return Err("The Claude Code terminal session is not running.".to_string());
+    };
+    pty.writer
         .write_all(bytes)
-        .and_then(|_| session.writer.flush())
+        .and_then(|_| pty.writer.flush())
         .map_err(|error| format!("Failed to write to Groove terminal session: {error}"))
 }
 
@@ -1298,9 +1375,7 @@ fn groove_mcp_send_worktree_prompt(
     let state = app.state::<GrooveTerminalState>();
     let session_id = {
         let sessions_state = state
-            .inner
-            .lock()
-            .map_err(|error| format!("Failed to acquire Groove terminal state lock: {error}"))?;
+            .lock_sessions();
         match requested_session_id {
             Some(requested) => {
                 resolve_terminal_session_id(&sessions_state, &worktree_key, Some(requested))?
@@ -1461,9 +1536,7 @@ fn groove_mcp_read_worktree_terminal(
 
     let state = app.state::<GrooveTerminalState>();
     let sessions_state = state
-        .inner
-        .lock()
-        .map_err(|error| format!("Failed to acquire Groove terminal state lock: {error}"))?;
+        .lock_sessions();
     let session_id =
         resolve_terminal_session_id(&sessions_state, &worktree_key, requested_session_id)?;
     let Some(session) = sessions_state.sessions_by_id.get(&session_id) else {
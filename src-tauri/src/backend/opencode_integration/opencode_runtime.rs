@@ -854,9 +854,7 @@ fn check_opencode_status_runtime(worktree_path: &Path) -> OpenCodeStatus {
     }
 
     let git_repo = if worktree_exists {
-        let result = Command::new("git")
-            .arg("-C")
-            .arg(worktree_path)
+        let result = git_command_at(worktree_path)
             .args(["rev-parse", "--is-inside-work-tree"])
             .output();
         match result {
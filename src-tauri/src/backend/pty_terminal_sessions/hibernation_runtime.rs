@@ -0,0 +1,159 @@
+// Hibernation of idle plain shells. With
+// `TerminalMemorySettings.hibernate_idle_shells` on, the reaper stops the PTY
+// of a local plain-shell session once nothing has been written to or read from
+// it for the idle threshold and the shell has no child processes. The session
+// itself stays, with its snapshot and scrollback, and the next write starts the
+// shell again in the directory it was last in. The new shell gets the
+// environment the session was opened with; variables exported inside the old
+// one are lost.
+
+fn groove_terminal_pty_replaced(
+    state: &GrooveTerminalState,
+    session_id: &str,
+    pty_generation: u64,
+) -> bool {
+    state
+        .lock_sessions()
+        .sessions_by_id
+        .get(session_id)
+        .is_some_and(|session| session.pty_generation != pty_generation)
+}
+
+fn groove_terminal_session_idle_for(session: &GrooveTerminalSessionState) -> Duration {
+    let last_output_at = session
+        .snapshot
+        .lock()
+        .ok()
+        .and_then(|buffer| buffer.last_output_at);
+    last_output_at
+        .map_or(session.last_input_at, |at| at.max(session.last_input_at))
+        .elapsed()
+}
+
+/// Sessions that have been idle long enough, with their PTY generation and
+/// the pid of their shell.
+fn idle_groove_terminal_shells(
+    sessions_state: &GrooveTerminalSessionsState,
+    idle_after: Duration,
+) -> Vec<(String, u64, u32)> {
+    sessions_state
+        .sessions_by_id
+        .values()
+        .filter(|session| session.respawn.is_some())
+        .filter(|session| groove_terminal_session_idle_for(session) >= idle_after)
+        .filter_map(|session| {
+            let pid = session.pty.as_ref()?.child.process_id()?;
+            Some((session.session_id.clone(), session.pty_generation, pid))
+        })
+        .collect()
+}
+
+fn hibernate_idle_groove_terminal_sessions(app: &AppHandle, state: &GrooveTerminalState) {
+    let idle_after =
+        Duration::from_secs(TERMINAL_HIBERNATE_IDLE_MINUTES.load(Ordering::Relaxed) * 60);
+    let candidates = idle_groove_terminal_shells(&state.lock_sessions(), idle_after);
+    // Checked without the lock held: both may spawn a helper process.
+    let candidates = candidates
+        .into_iter()
+        .filter(|(_, _, pid)| !has_child_processes(*pid))
+        .map(|(session_id, pty_generation, pid)| (session_id, pty_generation, process_cwd(pid)))
+        .collect::<Vec<_>>();
+    if candidates.is_empty() {
+        return;
+    }
+
+    let mut hibernated = Vec::new();
+    {
+        let mut sessions_state = state.lock_sessions();
+        for (session_id, pty_generation, cwd) in candidates {
+            let Some(session) = sessions_state.sessions_by_id.get_mut(&session_id) else {
+                continue;
+            };
+            // Used again since the check above.
+            if session.pty_generation != pty_generation
+                || groove_terminal_session_idle_for(session) < idle_after
+            {
+                continue;
+            }
+            let Some(pty) = session.pty.take() else {
+                continue;
+            };
+            session.pty_generation += 1;
+            if let (Some(cwd), Some(respawn)) = (cwd, session.respawn.as_mut()) {
+                respawn.cwd = cwd;
+            }
+            hibernated.push((
+                session.session_id.clone(),
+                session.workspace_root.clone(),
+                session.worktree.clone(),
+                pty,
+            ));
+        }
+    }
+
+    let telemetry_enabled = telemetry_enabled_for_app(app);
+    for (session_id, workspace_root, worktree, mut pty) in hibernated {
+        let _ = pty.child.kill();
+        let exit_detail = collect_groove_terminal_exit_status(pty.child.as_mut());
+        drop(pty);
+        log_play_telemetry(
+            telemetry_enabled,
+            "terminal.session.hibernated",
+            format!(
+                "workspace_root={} worktree={} session_id={} {}",
+                workspace_root, worktree, session_id, exit_detail
+            )
+            .as_str(),
+        );
+        emit_groove_terminal_lifecycle_event(
+            app,
+            &session_id,
+            &workspace_root,
+            &worktree,
+            "hibernated",
+            Some("Idle shell stopped; it starts again on the next input.".to_string()),
+        );
+    }
+}
+
+/// Starts the shell of a hibernated session again at the session's size.
+fn resume_groove_terminal_session<'a>(
+    app: &AppHandle,
+    session: &'a mut GrooveTerminalSessionState,
+) -> Result<&'a mut GrooveTerminalPty, String> {
+    let spec = session
+        .respawn
+        .as_ref()
+        .ok_or_else(|| "The Groove terminal session is no longer running.".to_string())?;
+    let telemetry_enabled = telemetry_enabled_for_app(app);
+    let cwd_rendered = spec.cwd.display().to_string();
+    let (pty, reader) =
+        spawn_groove_terminal_pty(spec, session.cols, session.rows).map_err(|(stage, error)| {
+            log_play_telemetry(
+                telemetry_enabled,
+                format!("terminal.resume.{stage}").as_str(),
+                format!("session_id={} error={error}", session.session_id).as_str(),
+            );
+            error
+        })?;
+    start_groove_terminal_output(app, session, reader, telemetry_enabled);
+
+    log_play_telemetry(
+        telemetry_enabled,
+        "terminal.session.resumed",
+        format!(
+            "workspace_root={} worktree={} session_id={} cwd={}",
+            session.workspace_root, session.worktree, session.session_id, cwd_rendered
+        )
+        .as_str(),
+    );
+    emit_groove_terminal_lifecycle_event(
+        app,
+        &session.session_id,
+        &session.workspace_root,
+        &session.worktree,
+        "resumed",
+        Some("Shell started again after hibernation.".to_string()),
+    );
+    Ok(session.pty.insert(pty))
+}
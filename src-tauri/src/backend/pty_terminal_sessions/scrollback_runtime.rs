@@ -0,0 +1,459 @@
+// Terminal scrollback beyond the in-memory snapshot. With
+// `TerminalMemorySettings.scrollback_persistence_enabled` on, output trimmed
+// from a session snapshot is appended to `terminal-scrollback/<session>.log`
+// in the app data directory, and the terminal view fetches it lazily as the
+// user scrolls up. Each file is capped; past the cap its older half is
+// dropped. A file is deleted when its session closes unless the session is
+// being recorded, in which case it stays behind as `<session>.recorded.log`.
+//
+// Terminal history is separate: with
+// `TerminalMemorySettings.history_persistence_enabled` on, all output of a
+// session is appended to `.groove/terminal-history/<worktree>/<session>.log`
+// in its workspace and kept after the session closes, so the terminal view
+// can show it again when it reattaches after a restart.
+
+fn terminal_scrollback_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|app_data_dir| app_data_dir.join(GROOVE_TERMINAL_SCROLLBACK_DIR))
+        .map_err(|error| format!("Failed to resolve app data directory: {error}"))
+}
+
+fn terminal_scrollback_file_name(session_id: &str, recording: bool) -> String {
+    if recording {
+        format!("{session_id}.recorded.log")
+    } else {
+        format!("{session_id}.log")
+    }
+}
+
+/// Scrollback files of sessions that ended without closing cleanly. Recorded
+/// files are kept.
+fn remove_stale_terminal_scrollback_files(app: &AppHandle) {
+    let Ok(entries) = terminal_scrollback_dir(app).and_then(|dir| {
+        fs::read_dir(&dir).map_err(|error| format!("Failed to read {}: {error}", dir.display()))
+    }) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if file_name.ends_with(".log") && !file_name.ends_with(".recorded.log") {
+            let _ = fs::remove_file(entry.path());
+        }
+    }
+}
+
+impl TerminalScrollbackFile {
+    fn open(path: &Path, start_offset: u64, len: u64, truncate: bool) -> Result<Self, String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|error| format!("Failed to create {}: {error}", parent.display()))?;
+        }
+        let file = fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(truncate)
+            .open(path)
+            .map_err(|error| format!("Failed to open {}: {error}", path.display()))?;
+        Ok(Self {
+            file,
+            start_offset,
+            len,
+        })
+    }
+
+    fn end_offset(&self) -> u64 {
+        self.start_offset + self.len
+    }
+
+    fn write(&mut self, bytes: &[u8], max_bytes: u64) -> std::io::Result<()> {
+        self.file.seek(SeekFrom::Start(self.len))?;
+        self.file.write_all(bytes)?;
+        self.len += bytes.len() as u64;
+        if self.len > max_bytes {
+            self.keep_tail(max_bytes / 2)?;
+        }
+        Ok(())
+    }
+
+    /// Rewrites the file with only its last `keep` bytes.
+    fn keep_tail(&mut self, keep: u64) -> std::io::Result<()> {
+        let keep = keep.min(self.len);
+        let mut tail = vec![0u8; keep as usize];
+        self.file.seek(SeekFrom::Start(self.len - keep))?;
+        self.file.read_exact(&mut tail)?;
+        self.file.set_len(0)?;
+        self.file.seek(SeekFrom::Start(0))?;
+        self.file.write_all(&tail)?;
+        self.start_offset += self.len - keep;
+        self.len = keep;
+        Ok(())
+    }
+
+    /// Up to `limit` bytes from output offset `offset`, clamped to the file.
+    fn read(&mut self, offset: u64, limit: usize) -> std::io::Result<(u64, Vec<u8>)> {
+        let from = offset.clamp(self.start_offset, self.end_offset());
+        let to = from.saturating_add(limit as u64).min(self.end_offset());
+        let mut bytes = vec![0u8; (to - from) as usize];
+        self.file.seek(SeekFrom::Start(from - self.start_offset))?;
+        self.file.read_exact(&mut bytes)?;
+        Ok((from, bytes))
+    }
+}
+
+impl TerminalScrollback {
+    fn path(&self) -> Option<PathBuf> {
+        self.dir.as_ref().map(|dir| {
+            dir.join(terminal_scrollback_file_name(
+                &self.session_id,
+                self.recording,
+            ))
+        })
+    }
+
+    /// Appends output that starts at `offset`. A gap since the last spill
+    /// (persistence was off for a while) starts the file over.
+    fn spill(&mut self, offset: u64, bytes: &[u8]) {
+        if bytes.is_empty() || !TERMINAL_SCROLLBACK_PERSISTENCE_ENABLED.load(Ordering::Relaxed) {
+            return;
+        }
+        let Some(path) = self.path() else {
+            return;
+        };
+        if self
+            .file
+            .as_ref()
+            .is_some_and(|file| file.end_offset() != offset)
+        {
+            self.file = None;
+        }
+        if self.file.is_none() {
+            match TerminalScrollbackFile::open(&path, offset, 0, true) {
+                Ok(file) => self.file = Some(file),
+                Err(error) => {
+                    log_line(&format!("[terminal-scrollback] {error}"));
+                    self.dir = None;
+                    return;
+                }
+            }
+        }
+
+        let max_bytes = TERMINAL_SCROLLBACK_FILE_MAX_BYTES.load(Ordering::Relaxed) as u64;
+        if let Some(Err(error)) = self.file.as_mut().map(|file| file.write(bytes, max_bytes)) {
+            log_line(&format!(
+                "[terminal-scrollback] failed to write {}: {error}",
+                path.display()
+            ));
+            self.file = None;
+        }
+    }
+
+    /// Renames the file between its plain and recorded names. The handle is
+    /// closed first so the rename also works on Windows.
+    fn set_recording(&mut self, recording: bool) -> Result<(), String> {
+        if self.recording == recording {
+            return Ok(());
+        }
+        let (Some(from), Some(file)) = (self.path(), self.file.take()) else {
+            self.recording = recording;
+            return Ok(());
+        };
+        let (start_offset, len) = (file.start_offset, file.len);
+        drop(file);
+
+        self.recording = recording;
+        let to = self.path().unwrap_or_else(|| from.clone());
+        let renamed = fs::rename(&from, &to)
+            .map_err(|error| format!("Failed to rename {}: {error}", from.display()));
+        if renamed.is_err() {
+            self.recording = !recording;
+        }
+        let path = if renamed.is_ok() { to } else { from };
+        self.file = TerminalScrollbackFile::open(&path, start_offset, len, false).ok();
+        renamed
+    }
+}
+
+impl Drop for TerminalScrollback {
+    fn drop(&mut self) {
+        if self.recording || self.file.take().is_none() {
+            return;
+        }
+        if let Some(path) = self.path() {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+/// `<workspace>/.groove/terminal-history/<worktree>`, or `None` when the
+/// worktree name cannot be used as a directory name.
+fn terminal_history_dir(workspace_root: &Path, worktree: &str) -> Option<PathBuf> {
+    let worktree = worktree.trim();
+    let usable = !worktree.is_empty()
+        && !worktree.contains(['/', '\\'])
+        && worktree != "."
+        && worktree != "..";
+    usable.then(|| {
+        workspace_root
+            .join(".groove")
+            .join(GROOVE_TERMINAL_HISTORY_DIR)
+            .join(worktree)
+    })
+}
+
+/// History files in `dir`, newest first.
+fn terminal_history_files(dir: &Path) -> Vec<(PathBuf, std::time::SystemTime)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files = entries
+        .flatten()
+        .filter(|entry| entry.file_name().to_string_lossy().ends_with(".log"))
+        .filter_map(|entry| {
+            let modified = entry.metadata().ok()?.modified().ok()?;
+            Some((entry.path(), modified))
+        })
+        .collect::<Vec<_>>();
+    files.sort_by_key(|(_, modified)| std::cmp::Reverse(*modified));
+    files
+}
+
+/// Deletes all but the newest `keep` history files in `dir`.
+fn prune_terminal_history_files(dir: &Path, keep: usize) {
+    for (path, _) in terminal_history_files(dir).into_iter().skip(keep) {
+        let _ = fs::remove_file(path);
+    }
+}
+
+impl TerminalHistory {
+    fn append(&mut self, bytes: &[u8]) {
+        if bytes.is_empty() || !TERMINAL_HISTORY_PERSISTENCE_ENABLED.load(Ordering::Relaxed) {
+            return;
+        }
+        let Some(path) = self.path.clone() else {
+            return;
+        };
+        if self.file.is_none() {
+            if let Some(dir) = path.parent() {
+                prune_terminal_history_files(dir, MAX_GROOVE_TERMINAL_HISTORY_FILES - 1);
+            }
+            match TerminalScrollbackFile::open(&path, 0, 0, true) {
+                Ok(file) => self.file = Some(file),
+                Err(error) => {
+                    log_line(&format!("[terminal-history] {error}"));
+                    self.path = None;
+                    return;
+                }
+            }
+        }
+
+        let max_bytes = TERMINAL_SCROLLBACK_FILE_MAX_BYTES.load(Ordering::Relaxed) as u64;
+        if let Some(Err(error)) = self.file.as_mut().map(|file| file.write(bytes, max_bytes)) {
+            log_line(&format!(
+                "[terminal-history] failed to write {}: {error}",
+                path.display()
+            ));
+            self.file = None;
+            self.path = None;
+        }
+    }
+}
+
+/// The last `max_bytes` of a history file in `dir`: the one of `session_id`,
+/// or the most recently written one. Returns the session id, the bytes and
+/// whether older output was left out, or `None` when there is no history.
+fn read_terminal_history(
+    dir: &Path,
+    session_id: Option<&str>,
+    max_bytes: Option<usize>,
+) -> Result<Option<(String, Vec<u8>, bool)>, String> {
+    let max_bytes = max_bytes
+        .unwrap_or(DEFAULT_GROOVE_TERMINAL_HISTORY_FETCH_BYTES)
+        .clamp(1, MAX_GROOVE_TERMINAL_HISTORY_FETCH_BYTES) as u64;
+    let path = match session_id.map(str::trim).filter(|value| !value.is_empty()) {
+        Some(session_id) if session_id.contains(['/', '\\']) || session_id.starts_with('.') => {
+            return Err(format!("Invalid terminal session id {session_id}."));
+        }
+        Some(session_id) => dir.join(format!("{session_id}.log")),
+        None => match terminal_history_files(dir).into_iter().next() {
+            Some((path, _)) => path,
+            None => return Ok(None),
+        },
+    };
+    let mut file = match fs::File::open(&path) {
+        Ok(file) => file,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(format!("Failed to open {}: {error}", path.display())),
+    };
+
+    let read = |file: &mut fs::File| -> std::io::Result<(Vec<u8>, bool)> {
+        let len = file.metadata()?.len();
+        let from = len.saturating_sub(max_bytes);
+        let mut bytes = Vec::with_capacity((len - from) as usize);
+        file.seek(SeekFrom::Start(from))?;
+        file.read_to_end(&mut bytes)?;
+        Ok((bytes, from > 0))
+    };
+    let (bytes, truncated) =
+        read(&mut file).map_err(|error| format!("Failed to read {}: {error}", path.display()))?;
+    let session_id = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    Ok(Some((session_id, bytes, truncated)))
+}
+
+fn terminal_session_snapshot(
+    state: &GrooveTerminalState,
+    session_id: &str,
+) -> Result<Arc<Mutex<TerminalSnapshotBuffer>>, String> {
+    state
+        .lock_sessions()
+        .sessions_by_id
+        .get(session_id.trim())
+        .map(|session| session.snapshot.clone())
+        .ok_or_else(|| format!("No terminal session with id {session_id}."))
+}
+
+/// Reads scrollback ending at the in-memory snapshot at the latest. Without
+/// an offset, the chunk right before the snapshot is returned.
+fn fetch_terminal_scrollback(
+    buffer: &mut TerminalSnapshotBuffer,
+    offset: Option<u64>,
+    limit: Option<usize>,
+) -> Result<(u64, Vec<u8>, u64), String> {
+    let limit = limit
+        .unwrap_or(DEFAULT_GROOVE_TERMINAL_SCROLLBACK_FETCH_BYTES)
+        .clamp(1, MAX_GROOVE_TERMINAL_SCROLLBACK_FETCH_BYTES);
+    let snapshot_offset = buffer.trimmed_bytes;
+    let Some(file) = buffer.scrollback.file.as_mut() else {
+        return Ok((snapshot_offset, Vec::new(), snapshot_offset));
+    };
+    let offset = offset.unwrap_or_else(|| snapshot_offset.saturating_sub(limit as u64));
+    let end = offset.saturating_add(limit as u64).min(snapshot_offset);
+    let (offset, bytes) = file
+        .read(offset, end.saturating_sub(offset) as usize)
+        .map_err(|error| format!("Failed to read terminal scrollback: {error}"))?;
+    Ok((offset, bytes, file.start_offset))
+}
+
+/// Output from offset `from` to the end of the snapshot, for a terminal view
+/// attaching again after a reload. Without `from` only the snapshot is
+/// returned; output before it comes from the scrollback file, up to the
+/// fetch limit, and starts later than `from` once it has been dropped.
+fn terminal_attach_replay(
+    buffer: &mut TerminalSnapshotBuffer,
+    from: Option<u64>,
+) -> Result<(u64, Vec<u8>), String> {
+    let snapshot_offset = buffer.trimmed_bytes;
+    let from = from.unwrap_or(snapshot_offset).min(buffer.end_offset());
+    if from >= snapshot_offset {
+        let skip = (from - snapshot_offset) as usize;
+        return Ok((from, buffer.bytes[skip..].to_vec()));
+    }
+
+    let from = from.max(
+        snapshot_offset.saturating_sub(MAX_GROOVE_TERMINAL_SCROLLBACK_FETCH_BYTES as u64),
+    );
+    let (offset, mut bytes) = match buffer
+        .scrollback
+        .file
+        .as_mut()
+        .filter(|file| file.end_offset() == snapshot_offset)
+    {
+        Some(file) => file
+            .read(from, (snapshot_offset - from) as usize)
+            .map_err(|error| format!("Failed to read terminal scrollback: {error}"))?,
+        None => (snapshot_offset, Vec::new()),
+    };
+    bytes.extend_from_slice(&buffer.bytes);
+    Ok((offset, bytes))
+}
+
+#[cfg(test)]
+mod scrollback_runtime_tests {
+    use super::*;
+
+    #[test]
+    fn scrollback_file_keeps_offsets_across_compaction() {
+        let path = std::env::temp_dir().join(format!("groove-scrollback-{}.log", Uuid::new_v4()));
+        let mut file = TerminalScrollbackFile::open(&path, 100, 0, true).unwrap();
+        file.write(b"0123456789", 16).unwrap();
+        assert_eq!(file.read(104, 3).unwrap(), (104, b"456".to_vec()));
+
+        file.write(b"abcdefghij", 16).unwrap();
+        assert_eq!((file.start_offset, file.len), (112, 8));
+        assert_eq!(file.read(0, 4).unwrap(), (112, b"cdef".to_vec()));
+        assert_eq!(file.read(118, 100).unwrap(), (118, b"ij".to_vec()));
+
+        drop(file);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn attach_replays_missed_output_from_scrollback_and_snapshot() {
+        let dir = std::env::temp_dir().join(format!("groove-attach-{}", Uuid::new_v4()));
+        let mut buffer = TerminalSnapshotBuffer::for_session(Some(dir.clone()), None, "s1");
+        TERMINAL_SCROLLBACK_PERSISTENCE_ENABLED.store(true, Ordering::Relaxed);
+        buffer.append(b"0123456789", 4);
+        buffer.append(b"abcd", 4);
+        TERMINAL_SCROLLBACK_PERSISTENCE_ENABLED.store(false, Ordering::Relaxed);
+        assert_eq!((buffer.trimmed_bytes, buffer.end_offset()), (10, 14));
+
+        assert_eq!(
+            terminal_attach_replay(&mut buffer, None),
+            Ok((10, b"abcd".to_vec()))
+        );
+        assert_eq!(
+            terminal_attach_replay(&mut buffer, Some(12)),
+            Ok((12, b"cd".to_vec()))
+        );
+        assert_eq!(
+            terminal_attach_replay(&mut buffer, Some(7)),
+            Ok((7, b"789abcd".to_vec()))
+        );
+        assert_eq!(
+            terminal_attach_replay(&mut buffer, Some(40)),
+            Ok((14, Vec::new()))
+        );
+
+        drop(buffer);
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn terminal_history_reads_the_tail_of_the_newest_file() {
+        let dir = std::env::temp_dir().join(format!("groove-history-{}", Uuid::new_v4()));
+        assert_eq!(read_terminal_history(&dir, None, None), Ok(None));
+
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("older.log"), b"old output").unwrap();
+        std::thread::sleep(Duration::from_millis(20));
+        let mut history = TerminalHistory {
+            path: Some(dir.join("newer.log")),
+            file: None,
+        };
+        TERMINAL_HISTORY_PERSISTENCE_ENABLED.store(true, Ordering::Relaxed);
+        history.append(b"$ cargo test\n");
+        history.append(b"ok\n");
+        TERMINAL_HISTORY_PERSISTENCE_ENABLED.store(false, Ordering::Relaxed);
+        history.append(b"not persisted");
+        drop(history);
+
+        assert_eq!(
+            read_terminal_history(&dir, None, Some(3)),
+            Ok(Some(("newer".to_string(), b"ok\n".to_vec(), true)))
+        );
+        assert_eq!(
+            read_terminal_history(&dir, Some("older"), None),
+            Ok(Some(("older".to_string(), b"old output".to_vec(), false)))
+        );
+        assert!(read_terminal_history(&dir, Some("../secrets"), None).is_err());
+
+        prune_terminal_history_files(&dir, 1);
+        assert!(!dir.join("older.log").exists());
+        assert!(dir.join("newer.log").exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
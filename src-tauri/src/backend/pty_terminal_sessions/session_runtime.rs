@@ -36,6 +36,33 @@ fn has_child_processes(pid: u32) -> bool {
     }
 }
 
+/// Working directory of a running process, where the platform exposes it.
+fn process_cwd(pid: u32) -> Option<PathBuf> {
+    #[cfg(target_os = "linux")]
+    {
+        fs::read_link(format!("/proc/{pid}/cwd")).ok()
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        let output = Command::new("lsof")
+            .args(["-a", "-p", &pid.to_string(), "-d", "cwd", "-Fn"])
+            .stderr(Stdio::null())
+            .output()
+            .ok()?;
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .find_map(|line| line.strip_prefix('n'))
+            .map(PathBuf::from)
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        let _ = pid;
+        None
+    }
+}
+
 fn request_id() -> String {
     Uuid::new_v4().to_string()
 }
@@ -86,6 +113,10 @@ fn is_groove_terminal_open_command(command: &str) -> bool {
     command.trim() == GROOVE_OPEN_TERMINAL_COMMAND_SENTINEL
 }
 
+fn is_groove_tmux_command(command: &str) -> bool {
+    command.trim() == GROOVE_TMUX_COMMAND_SENTINEL
+}
+
 fn groove_terminal_session_key(workspace_root: &Path, worktree: &str) -> String {
     format!("{}::{worktree}", workspace_root_storage_key(workspace_root))
 }
@@ -186,11 +217,124 @@ fn drain_groove_terminal_sessions(
 
 fn close_groove_terminal_sessions_best_effort(sessions: Vec<GrooveTerminalSessionState>) {
     for mut session in sessions {
-        let _ = session.child.kill();
-        let _ = collect_groove_terminal_exit_status(session.child.as_mut());
+        if let Some(pty) = session.pty.as_mut() {
+            let _ = pty.child.kill();
+            let _ = collect_groove_terminal_exit_status(pty.child.as_mut());
+        }
     }
 }
 
+/// Periodically reaps PTY sessions whose child has exited but which are still
+/// tracked, e.g. because the reader thread panicked or never saw EOF. A session
+/// must be seen dead on two consecutive ticks before it is removed so the
+/// reader thread gets the first chance to close it normally.
+fn start_groove_terminal_reaper(app: AppHandle) {
+    thread::spawn(move || {
+        let mut dead_since_previous_tick = HashSet::<String>::new();
+        let mut reaped_total = 0usize;
+        loop {
+            thread::sleep(GROOVE_TERMINAL_REAPER_INTERVAL);
+            // Sessions that exit meanwhile are still closed by their reader
+            // thread; the reaper only catches the ones it missed.
+            if background_activity_paused() {
+                continue;
+            }
+
+            let state = app.state::<GrooveTerminalState>();
+            let mut reaped = Vec::new();
+            let tracked_sessions;
+            {
+                let mut sessions_state = state.lock_sessions();
+                let mut dead_now = HashSet::new();
+                for (session_id, session) in sessions_state.sessions_by_id.iter_mut() {
+                    let Some(pty) = session.pty.as_mut() else {
+                        continue;
+                    };
+                    if let Ok(Some(status)) = pty.child.try_wait() {
+                        dead_now.insert((
+                            session_id.clone(),
+                            format!("exit_status={status:?}"),
+                            status.success(),
+                        ));
+                    }
+                }
+
+                for (session_id, exit_detail, exited_cleanly) in dead_now.iter() {
+                    if !dead_since_previous_tick.contains(session_id) {
+                        continue;
+                    }
+                    if let Some(session) = remove_session_by_id(&mut sessions_state, session_id) {
+                        reaped.push((session, exit_detail.clone(), *exited_cleanly));
+                    }
+                }
+                dead_since_previous_tick = dead_now
+                    .into_iter()
+                    .map(|(session_id, _, _)| session_id)
+                    .filter(|session_id| sessions_state.sessions_by_id.contains_key(session_id))
+                    .collect();
+                tracked_sessions = sessions_state.sessions_by_id.len();
+            }
+
+            if TERMINAL_HIBERNATE_IDLE_SHELLS.load(Ordering::Relaxed) {
+                hibernate_idle_groove_terminal_sessions(&app, &state);
+            }
+
+            if reaped.is_empty() {
+                continue;
+            }
+
+            let telemetry_enabled = telemetry_enabled_for_app(&app);
+            reaped_total += reaped.len();
+            for (session, exit_detail, exited_cleanly) in &reaped {
+                let workspace_root = Path::new(&session.workspace_root);
+                let cleared_record = clear_running_groove_if_session_matches(
+                    &app,
+                    workspace_root,
+                    &session.worktree,
+                    &session.session_id,
+                );
+                log_play_telemetry(
+                    telemetry_enabled,
+                    "terminal.session.reaped",
+                    format!(
+                        "workspace_root={} worktree={} session_id={} {}",
+                        session.workspace_root, session.worktree, session.session_id, exit_detail
+                    )
+                    .as_str(),
+                );
+                invalidate_worktree_caches(&app, workspace_root, &session.worktree);
+                emit_groove_terminal_lifecycle_event(
+                    &app,
+                    &session.session_id,
+                    &session.workspace_root,
+                    &session.worktree,
+                    "closed",
+                    Some(format!("Terminal session reaped after exit ({exit_detail}).")),
+                );
+                if let Ok(Some(record)) = cleared_record {
+                    report_testing_environment_exit(
+                        &app,
+                        record,
+                        session.open_mode,
+                        (!exited_cleanly).then(|| exit_detail.clone()),
+                    );
+                }
+            }
+            log_play_telemetry(
+                telemetry_enabled,
+                "terminal.reaper.stats",
+                format!(
+                    "reaped={} reaped_total={} tracked_sessions={}",
+                    reaped.len(),
+                    reaped_total,
+                    tracked_sessions
+                )
+                .as_str(),
+            );
+        }
+    });
+}
+
 fn groove_terminal_session_from_state(
     session: &GrooveTerminalSessionState,
 ) -> GrooveTerminalSession {
@@ -204,6 +348,8 @@ fn groove_terminal_session_from_state(
         cols: session.cols,
         rows: session.rows,
         snapshot: None,
+        hibernated: session.pty.is_none(),
+        pid: session.pty.as_ref().and_then(|pty| pty.child.process_id()),
     }
 }
 
@@ -211,7 +357,7 @@ fn groove_terminal_session_with_snapshot_from_state(
     session: &GrooveTerminalSessionState,
 ) -> GrooveTerminalSession {
     let snapshot = match session.snapshot.lock() {
-        Ok(buffer) => String::from_utf8_lossy(buffer.as_slice()).to_string(),
+        Ok(buffer) => String::from_utf8_lossy(buffer.bytes.as_slice()).to_string(),
         Err(_) => String::new(),
     };
 
@@ -225,28 +371,202 @@ fn groove_terminal_session_with_snapshot_from_state(
         cols: session.cols,
         rows: session.rows,
         snapshot: Some(snapshot),
+        hibernated: session.pty.is_none(),
+        pid: session.pty.as_ref().and_then(|pty| pty.child.process_id()),
     }
 }
 
-fn append_terminal_snapshot(snapshot: &Arc<Mutex<Vec<u8>>>, chunk: &[u8]) {
-    let Ok(mut buffer) = snapshot.lock() else {
-        return;
-    };
+/// Mirror `GlobalSettings.terminal_memory_settings` so reader threads do not
+/// re-read `global-settings.json` per chunk. Refreshed whenever that file is
+/// written.
+static TERMINAL_SNAPSHOT_MAX_BYTES: AtomicUsize =
+    AtomicUsize::new(DEFAULT_GROOVE_TERMINAL_SNAPSHOT_BYTES);
+static TERMINAL_SNAPSHOT_BUDGET_BYTES: AtomicUsize =
+    AtomicUsize::new(DEFAULT_GROOVE_TERMINAL_SNAPSHOT_BUDGET_BYTES);
+static TERMINAL_SCROLLBACK_PERSISTENCE_ENABLED: AtomicBool = AtomicBool::new(false);
+static TERMINAL_SCROLLBACK_FILE_MAX_BYTES: AtomicUsize =
+    AtomicUsize::new(DEFAULT_GROOVE_TERMINAL_SCROLLBACK_FILE_BYTES);
+static TERMINAL_HISTORY_PERSISTENCE_ENABLED: AtomicBool = AtomicBool::new(false);
+static TERMINAL_HIBERNATE_IDLE_SHELLS: AtomicBool = AtomicBool::new(false);
+static TERMINAL_HIBERNATE_IDLE_MINUTES: AtomicU64 =
+    AtomicU64::new(DEFAULT_GROOVE_TERMINAL_HIBERNATE_IDLE_MINUTES);
+static TERMINAL_STORAGE_QUOTA_BYTES: AtomicU64 =
+    AtomicU64::new(DEFAULT_GROOVE_TERMINAL_STORAGE_QUOTA_BYTES);
+static TERMINAL_STORAGE_MAX_AGE_DAYS: AtomicU64 =
+    AtomicU64::new(DEFAULT_GROOVE_TERMINAL_STORAGE_MAX_AGE_DAYS);
+/// Bytes held by every live `TerminalSnapshotBuffer`.
+static TERMINAL_SNAPSHOT_USED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+fn set_terminal_memory_limits(settings: &TerminalMemorySettings) {
+    TERMINAL_SNAPSHOT_MAX_BYTES.store(settings.snapshot_max_bytes, Ordering::Relaxed);
+    TERMINAL_SNAPSHOT_BUDGET_BYTES.store(settings.snapshot_budget_bytes, Ordering::Relaxed);
+    TERMINAL_SCROLLBACK_PERSISTENCE_ENABLED
+        .store(settings.scrollback_persistence_enabled, Ordering::Relaxed);
+    TERMINAL_SCROLLBACK_FILE_MAX_BYTES.store(settings.scrollback_file_max_bytes, Ordering::Relaxed);
+    TERMINAL_HISTORY_PERSISTENCE_ENABLED
+        .store(settings.history_persistence_enabled, Ordering::Relaxed);
+    TERMINAL_HIBERNATE_IDLE_SHELLS.store(settings.hibernate_idle_shells, Ordering::Relaxed);
+    TERMINAL_HIBERNATE_IDLE_MINUTES.store(settings.hibernate_idle_minutes, Ordering::Relaxed);
+    TERMINAL_STORAGE_QUOTA_BYTES.store(settings.storage_quota_bytes, Ordering::Relaxed);
+    TERMINAL_STORAGE_MAX_AGE_DAYS.store(settings.storage_max_age_days, Ordering::Relaxed);
+}
+
+fn terminal_memory_limits() -> TerminalMemorySettings {
+    TerminalMemorySettings {
+        snapshot_max_bytes: TERMINAL_SNAPSHOT_MAX_BYTES.load(Ordering::Relaxed),
+        snapshot_budget_bytes: TERMINAL_SNAPSHOT_BUDGET_BYTES.load(Ordering::Relaxed),
+        scrollback_persistence_enabled: TERMINAL_SCROLLBACK_PERSISTENCE_ENABLED
+            .load(Ordering::Relaxed),
+        scrollback_file_max_bytes: TERMINAL_SCROLLBACK_FILE_MAX_BYTES.load(Ordering::Relaxed),
+        history_persistence_enabled: TERMINAL_HISTORY_PERSISTENCE_ENABLED.load(Ordering::Relaxed),
+        hibernate_idle_shells: TERMINAL_HIBERNATE_IDLE_SHELLS.load(Ordering::Relaxed),
+        hibernate_idle_minutes: TERMINAL_HIBERNATE_IDLE_MINUTES.load(Ordering::Relaxed),
+        storage_quota_bytes: TERMINAL_STORAGE_QUOTA_BYTES.load(Ordering::Relaxed),
+        storage_max_age_days: TERMINAL_STORAGE_MAX_AGE_DAYS.load(Ordering::Relaxed),
+    }
+}
+
+fn account_terminal_snapshot_bytes(before: usize, after: usize) {
+    if after >= before {
+        TERMINAL_SNAPSHOT_USED_BYTES.fetch_add(after - before, Ordering::Relaxed);
+    } else {
+        let _ = TERMINAL_SNAPSHOT_USED_BYTES.fetch_update(
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+            |used| Some(used.saturating_sub(before - after)),
+        );
+    }
+}
+
+impl TerminalSnapshotBuffer {
+    fn for_session(
+        scrollback_dir: Option<PathBuf>,
+        history_dir: Option<PathBuf>,
+        session_id: &str,
+    ) -> Self {
+        Self {
+            bytes: Vec::new(),
+            trimmed_bytes: 0,
+            scrollback: TerminalScrollback {
+                dir: scrollback_dir,
+                session_id: session_id.to_string(),
+                file: None,
+                recording: false,
+            },
+            history: TerminalHistory {
+                path: history_dir.map(|dir| dir.join(format!("{session_id}.log"))),
+                file: None,
+            },
+            last_output_at: None,
+        }
+    }
+
+    /// Appends `chunk`, keeping at most the last `max_bytes`. Whatever falls
+    /// out goes to the scrollback file.
+    fn append(&mut self, chunk: &[u8], max_bytes: usize) {
+        let before = self.bytes.len();
+        self.history.append(chunk);
+        let skipped = chunk.len().saturating_sub(max_bytes);
+        let overflow = (before + chunk.len() - skipped)
+            .saturating_sub(max_bytes)
+            .min(before);
+        self.trim_front(overflow);
+        self.scrollback.spill(self.trimmed_bytes, &chunk[..skipped]);
+        self.trimmed_bytes += skipped as u64;
+        self.bytes.extend_from_slice(&chunk[skipped..]);
+        account_terminal_snapshot_bytes(before - overflow, self.bytes.len());
+    }
+
+    /// Output offset right after the last byte of `bytes`.
+    fn end_offset(&self) -> u64 {
+        self.trimmed_bytes + self.bytes.len() as u64
+    }
 
-    if chunk.len() >= MAX_GROOVE_TERMINAL_SNAPSHOT_BYTES {
-        buffer.clear();
-        let start = chunk.len() - MAX_GROOVE_TERMINAL_SNAPSHOT_BYTES;
-        buffer.extend_from_slice(&chunk[start..]);
-        return;
+    /// Drops up to `count` of the oldest bytes and returns how many went.
+    fn trim_front(&mut self, count: usize) -> usize {
+        let before = self.bytes.len();
+        let count = count.min(before);
+        self.scrollback
+            .spill(self.trimmed_bytes, &self.bytes[..count]);
+        self.bytes.drain(..count);
+        self.trimmed_bytes += count as u64;
+        account_terminal_snapshot_bytes(before, self.bytes.len());
+        count
     }
+}
+
+/// Returns the output offset right after `chunk`, and whether the snapshots
+/// of all sessions now exceed the budget.
+fn append_terminal_snapshot(
+    snapshot: &Arc<Mutex<TerminalSnapshotBuffer>>,
+    chunk: &[u8],
+) -> Option<(u64, bool)> {
+    let mut buffer = snapshot.lock().ok()?;
+    buffer.append(chunk, TERMINAL_SNAPSHOT_MAX_BYTES.load(Ordering::Relaxed));
+    buffer.last_output_at = Some(Instant::now());
+    let over_budget = TERMINAL_SNAPSHOT_USED_BYTES.load(Ordering::Relaxed)
+        > TERMINAL_SNAPSHOT_BUDGET_BYTES.load(Ordering::Relaxed);
+    Some((buffer.end_offset(), over_budget))
+}
 
-    let total_after_append = buffer.len() + chunk.len();
-    if total_after_append > MAX_GROOVE_TERMINAL_SNAPSHOT_BYTES {
-        let overflow = total_after_append - MAX_GROOVE_TERMINAL_SNAPSHOT_BYTES;
-        buffer.drain(..overflow);
+/// Trims the snapshots of the oldest sessions until all of them fit the
+/// budget again. The session lock is released before trimming so terminal
+/// IPC is not held up by it.
+fn enforce_terminal_snapshot_budget(state: &GrooveTerminalState) {
+    let mut snapshots = state
+        .lock_sessions()
+        .sessions_by_id
+        .values()
+        .map(|session| (session.started_at.clone(), session.snapshot.clone()))
+        .collect::<Vec<_>>();
+    snapshots.sort_by(|left, right| left.0.cmp(&right.0));
+
+    let budget = TERMINAL_SNAPSHOT_BUDGET_BYTES.load(Ordering::Relaxed);
+    for (_, snapshot) in snapshots {
+        let excess = TERMINAL_SNAPSHOT_USED_BYTES
+            .load(Ordering::Relaxed)
+            .saturating_sub(budget);
+        if excess == 0 {
+            break;
+        }
+        if let Ok(mut buffer) = snapshot.lock() {
+            buffer.trim_front(excess);
+        }
     }
+}
 
-    buffer.extend_from_slice(chunk);
+fn terminal_session_memory_rows(
+    sessions_state: &GrooveTerminalSessionsState,
+) -> Vec<DiagnosticsTerminalSessionMemory> {
+    let mut rows = sessions_state
+        .sessions_by_id
+        .values()
+        .map(|session| {
+            let (snapshot_bytes, trimmed_bytes, scrollback_file_bytes) = session
+                .snapshot
+                .lock()
+                .map(|buffer| {
+                    (
+                        buffer.bytes.len(),
+                        buffer.trimmed_bytes,
+                        buffer.scrollback.file.as_ref().map_or(0, |file| file.len),
+                    )
+                })
+                .unwrap_or_default();
+            DiagnosticsTerminalSessionMemory {
+                session_id: session.session_id.clone(),
+                workspace_root: session.workspace_root.clone(),
+                worktree: session.worktree.clone(),
+                command: session.command.clone(),
+                started_at: session.started_at.clone(),
+                snapshot_bytes,
+                trimmed_bytes,
+                scrollback_file_bytes,
+            }
+        })
+        .collect::<Vec<_>>();
+    rows.sort_by_key(|row| std::cmp::Reverse(row.snapshot_bytes));
+    rows
 }
 
 fn emit_groove_terminal_lifecycle_event(
@@ -257,9 +577,9 @@ fn emit_groove_terminal_lifecycle_event(
     kind: &str,
     message: Option<String>,
 ) {
-    let _ = app.emit(
-        GROOVE_TERMINAL_LIFECYCLE_EVENT,
-        GrooveTerminalLifecycleEvent {
+    emit_groove_event(
+        app,
+        &GrooveTerminalLifecycleEvent {
             session_id: session_id.to_string(),
             workspace_root: workspace_root.to_string(),
             worktree: worktree.to_string(),
@@ -269,6 +589,23 @@ fn emit_groove_terminal_lifecycle_event(
     );
 }
 
+fn groove_terminal_session_exit_status(session: &mut GrooveTerminalSessionState) -> String {
+    match session.pty.as_mut() {
+        Some(pty) => collect_groove_terminal_exit_status(pty.child.as_mut()),
+        None => "hibernated=true".to_string(),
+    }
+}
+
+/// Whether the session's process exited with success, once its exit status
+/// has been collected.
+fn groove_terminal_session_exited_cleanly(session: &mut GrooveTerminalSessionState) -> bool {
+    session
+        .pty
+        .as_mut()
+        .and_then(|pty| pty.child.try_wait().ok().flatten())
+        .is_some_and(|status| status.success())
+}
+
 fn collect_groove_terminal_exit_status(child: &mut (dyn PtyChild + Send)) -> String {
     match child.try_wait() {
         Ok(Some(status)) => format!("exit_status={status:?}"),
@@ -290,10 +627,34 @@ fn validate_groove_terminal_open_mode(
     terminal::validate_groove_terminal_open_mode(value)
 }
 
+fn groove_terminal_open_mode_key(mode: GrooveTerminalOpenMode) -> &'static str {
+    terminal::groove_terminal_open_mode_key(mode)
+}
+
 fn resolve_plain_terminal_command() -> (String, Vec<String>) {
     crate::backend::common::platform_env::resolve_shell_command()
 }
 
+/// Runs a terminal for a worktree on a WSL share inside its distro, through
+/// the distro user's default shell so their `PATH` applies.
+fn wsl_terminal_command(
+    location: &wsl::WslPath,
+    open_mode: GrooveTerminalOpenMode,
+    args: &[String],
+) -> (String, Vec<String>) {
+    let argv = match open_mode {
+        GrooveTerminalOpenMode::Plain => Vec::new(),
+        GrooveTerminalOpenMode::Opencode => vec!["opencode".to_string()],
+        GrooveTerminalOpenMode::ClaudeCode => std::iter::once("claude".to_string())
+            .chain(args.iter().cloned())
+            .collect(),
+    };
+    (
+        "wsl.exe".to_string(),
+        wsl::wsl_shell_args(&location.distro, &location.path, &argv),
+    )
+}
+
 fn augmented_child_path() -> Option<String> {
     // In an AppImage, PATH is contaminated with FUSE mount paths.
     // Use the original PATH (saved as PATH_ORIG by AppImage) when available.
@@ -424,10 +785,18 @@ fn resolve_terminal_worktree_context(
         &known_worktrees,
         workspace_meta,
     )?;
-    let effective_root = ensure_workspace_meta(&workspace_root)
-        .map(|(meta, _)| effective_workspace_root(&workspace_root, &meta))
-        .unwrap_or_else(|_| workspace_root.clone());
-    let worktree_path = ensure_worktree_in_dir(&effective_root, worktree, ".worktrees")?;
+    let workspace_meta = ensure_workspace_meta(&workspace_root).map(|(meta, _)| meta).ok();
+    let effective_root = workspace_meta
+        .as_ref()
+        .map(|meta| effective_workspace_root(&workspace_root, meta))
+        .unwrap_or_else(|| workspace_root.clone());
+    // Remote worktrees only exist on the SSH host; the local path is just the
+    // session key and is never used as a working directory.
+    let worktree_path = if workspace_meta.is_some_and(|meta| meta.remote.is_some()) {
+        effective_root.join(".worktrees").join(worktree)
+    } else {
+        ensure_worktree_in_dir(&effective_root, worktree, ".worktrees")?
+    };
 
     store_terminal_resolution(app, root_name, worktree, &workspace_root, &worktree_path);
     Ok((workspace_root, worktree_path))
@@ -459,351 +828,146 @@ fn resolve_terminal_session_id(
         .ok_or_else(|| "No active Groove terminal session found for this worktree.".to_string())
 }
 
-fn open_groove_terminal_session(
+fn write_groove_terminal_input(
     app: &AppHandle,
     state: &State<GrooveTerminalState>,
-    workspace_root: &Path,
-    worktree: &str,
-    worktree_path: &Path,
-    open_mode: GrooveTerminalOpenMode,
-    target: Option<&str>,
-    cols: Option<u16>,
-    rows: Option<u16>,
-    force_restart: bool,
-    open_new: bool,
-    record_as_running: bool,
-) -> Result<GrooveTerminalSession, String> {
-    let telemetry_enabled = telemetry_enabled_for_app(app);
-    let worktree_key = groove_terminal_session_key(workspace_root, worktree);
-    let workspace_root_rendered = workspace_root.display().to_string();
-    let cols = normalize_terminal_dimension(cols, DEFAULT_GROOVE_TERMINAL_COLS);
-    let rows = normalize_terminal_dimension(rows, DEFAULT_GROOVE_TERMINAL_ROWS);
-    let target_rendered = target
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-        .unwrap_or("<none>");
+    session_id: &str,
+    input: &str,
+) -> Result<(), String> {
+    let mut sessions_state = state.lock_sessions();
+    let session = sessions_state
+        .sessions_by_id
+        .get_mut(session_id)
+        .ok_or_else(|| {
+            format!("No active Groove terminal session found for sessionId={session_id}.")
+        })?;
+    write_groove_terminal_session(app, session, input.as_bytes())
+}
 
-    let (program, args) = match open_mode {
-        GrooveTerminalOpenMode::Opencode => (resolve_opencode_bin(), Vec::new()),
-        GrooveTerminalOpenMode::ClaudeCode => {
-            let args = if worktree == GROOVE_WORKSPACE_TERMINAL_WORKTREE {
-                // No worktree record for the workspace root; resume its most
-                // recent Claude session when one exists.
-                match resolve_existing_claude_session_id(
-                    worktree_path,
-                    GROOVE_WORKSPACE_TERMINAL_WORKTREE,
-                ) {
-                    Some(session_id) => vec!["--resume".to_string(), session_id],
-                    None => vec!["--session-id".to_string(), Uuid::new_v4().to_string()],
-                }
-            } else {
-                let (worktree_id, has_started) =
-                    register_worktree_record(workspace_root, worktree)?;
-                if has_started {
-                    match resolve_existing_claude_session_id(worktree_path, &worktree_id) {
-                        Some(session_id) => vec!["--resume".to_string(), session_id],
-                        None => vec!["--session-id".to_string(), Uuid::new_v4().to_string()],
-                    }
-                } else {
-                    vec!["--session-id".to_string(), worktree_id]
-                }
-            };
-            (resolve_claude_code_bin(), args)
-        }
-        GrooveTerminalOpenMode::Plain => resolve_plain_terminal_command(),
+/// Writes to a session, starting its shell again first if it hibernated.
+fn write_groove_terminal_session(
+    app: &AppHandle,
+    session: &mut GrooveTerminalSessionState,
+    bytes: &[u8],
+) -> Result<(), String> {
+    session.last_input_at = Instant::now();
+    let pty = match session.pty.as_mut() {
+        Some(pty) => pty,
+        None => resume_groove_terminal_session(app, session)?,
     };
-    let command_rendered = std::iter::once(program.as_str())
-        .chain(args.iter().map(|value| value.as_str()))
-        .collect::<Vec<_>>()
-        .join(" ");
-    let worktree_cwd_rendered = worktree_path.display().to_string();
+    pty.writer
+        .write_all(bytes)
+        .map_err(|error| format!("Failed to write to Groove terminal session: {error}"))
+}
 
-    log_play_telemetry(
-        telemetry_enabled,
-        "terminal.open.start",
-        format!(
-            "workspace_root={} worktree={} target={} command={} cwd={} cols={} rows={} force_restart={} open_new={}",
-            workspace_root_rendered,
-            worktree,
-            target_rendered,
-            command_rendered,
-            worktree_cwd_rendered,
-            cols,
+/// Opens a PTY running `spec`. An error names the step that failed so callers
+/// can report it.
+fn spawn_groove_terminal_pty(
+    spec: &GrooveTerminalSpawnSpec,
+    cols: u16,
+    rows: u16,
+) -> Result<(GrooveTerminalPty, GrooveTerminalReader), (&'static str, String)> {
+    let pty_system = native_pty_system();
+    let pair = pty_system
+        .openpty(PtySize {
             rows,
-            force_restart,
-            open_new
-        )
-        .as_str(),
-    );
-
-    let mut sessions_to_close = Vec::new();
-    {
-        let mut sessions_state = state.inner.lock().map_err(|error| {
-            log_play_telemetry(
-                telemetry_enabled,
-                "terminal.open.lock_error",
-                format!("worktree={} error={error}", worktree).as_str(),
-            );
-            format!("Failed to acquire Groove terminal state lock: {error}")
+            cols,
+            pixel_width: 0,
+            pixel_height: 0,
+        })
+        .map_err(|error| {
+            (
+                "pty_error",
+                format!("Failed to create PTY for Groove terminal: {error}"),
+            )
         })?;
 
-        if force_restart {
-            let existing_ids = sessions_state
-                .session_ids_by_worktree
-                .get(&worktree_key)
-                .cloned()
-                .unwrap_or_default();
-            for existing_id in existing_ids {
-                if let Some(previous_session) =
-                    remove_session_by_id(&mut sessions_state, &existing_id)
-                {
-                    sessions_to_close.push(previous_session);
-                }
-            }
+    // Keep sentinel mode aligned with external Play defaults by targeting via cwd,
+    // not by passing the branch target as an opencode positional argument.
+    let mut spawn_command = CommandBuilder::new(&spec.program);
+    for arg in &spec.args {
+        spawn_command.arg(arg);
+    }
+    spawn_command.cwd(&spec.cwd);
+    spawn_command.env("PWD", spec.cwd.display().to_string());
+    spawn_command.env("GROOVE_WORKTREE", spec.worktree_path.display().to_string());
+    if let Some(path) = augmented_child_path() {
+        spawn_command.env("PATH", path);
+    }
 
-            if !sessions_to_close.is_empty() {
-                log_play_telemetry(
-                    telemetry_enabled,
-                    "terminal.open.force_restart",
-                    format!(
-                        "workspace_root={} worktree={} previous_session_count={}",
-                        workspace_root_rendered,
-                        worktree,
-                        sessions_to_close.len()
-                    )
-                    .as_str(),
-                );
-            }
-        } else if !open_new {
-            if let Some(existing_id) =
-                latest_session_id_for_worktree(&sessions_state, &worktree_key)
-            {
-                if let Some(existing) = sessions_state.sessions_by_id.get(&existing_id) {
-                    log_play_telemetry(
-                        telemetry_enabled,
-                        "terminal.open.reused",
-                        format!(
-                            "workspace_root={} worktree={} session_id={}",
-                            workspace_root_rendered, worktree, existing.session_id
-                        )
-                        .as_str(),
-                    );
-                    return Ok(groove_terminal_session_from_state(existing));
-                }
-            }
-        }
-    }
-
-    for mut previous_session in sessions_to_close {
-        let previous_session_id = previous_session.session_id.clone();
-        let kill_detail = match previous_session.child.kill() {
-            Ok(()) => "kill=ok".to_string(),
-            Err(error) => format!("kill_error={error}"),
-        };
-        let exit_detail = collect_groove_terminal_exit_status(previous_session.child.as_mut());
-        let close_detail = format!("reason=restart {kill_detail} {exit_detail}");
-        drop(previous_session);
-
-        log_play_telemetry(
-            telemetry_enabled,
-            "terminal.session.closed",
-            format!(
-                "workspace_root={} worktree={} session_id={} {}",
-                workspace_root_rendered, worktree, previous_session_id, close_detail
-            )
-            .as_str(),
-        );
-        emit_groove_terminal_lifecycle_event(
-            app,
-            &previous_session_id,
-            &workspace_root_rendered,
-            worktree,
-            "closed",
-            Some("Session restarted.".to_string()),
-        );
-    }
-
-    let pty_system = native_pty_system();
-    let pair = pty_system
-        .openpty(PtySize {
-            rows,
-            cols,
-            pixel_width: 0,
-            pixel_height: 0,
-        })
-        .map_err(|error| {
-            log_play_telemetry(
-                telemetry_enabled,
-                "terminal.open.pty_error",
-                format!(
-                    "workspace_root={} worktree={} error={error}",
-                    workspace_root_rendered, worktree
-                )
-                .as_str(),
-            );
-            format!("Failed to create PTY for Groove terminal: {error}")
-        })?;
-
-    // Keep sentinel mode aligned with external Play defaults by targeting via cwd,
-    // not by passing the branch target as an opencode positional argument.
-    let mut spawn_command = CommandBuilder::new(&program);
-    for arg in args {
-        spawn_command.arg(arg);
-    }
-    spawn_command.cwd(worktree_path);
-    spawn_command.env("PWD", worktree_path.display().to_string());
-    spawn_command.env("GROOVE_WORKTREE", worktree_path.display().to_string());
-    if let Some(path) = augmented_child_path() {
-        spawn_command.env("PATH", path);
-    }
-
-    // Clean AppImage-injected environment variables so the child shell uses
-    // system libraries and paths instead of the FUSE-mounted AppImage ones.
-    // Skip PATH — already handled by augmented_child_path() using PATH_ORIG.
-    for (key, value) in crate::backend::common::platform_env::appimage_cleaned_env() {
-        if key == "PATH" { continue; }
-        match value {
-            Some(restored) => { spawn_command.env(&key, restored); }
-            None => { spawn_command.env_remove(&key); }
+    // Clean AppImage-injected environment variables so the child shell uses
+    // system libraries and paths instead of the FUSE-mounted AppImage ones.
+    // Skip PATH — already handled by augmented_child_path() using PATH_ORIG.
+    for (key, value) in crate::backend::common::platform_env::appimage_cleaned_env() {
+        if key == "PATH" { continue; }
+        match value {
+            Some(restored) => { spawn_command.env(&key, restored); }
+            None => { spawn_command.env_remove(&key); }
         }
     }
 
     let child = pair.slave.spawn_command(spawn_command).map_err(|error| {
-        log_play_telemetry(
-            telemetry_enabled,
-            "terminal.open.spawn_error",
-            format!(
-                "workspace_root={} worktree={} error={error}",
-                workspace_root_rendered, worktree
-            )
-            .as_str(),
-        );
-        format!("Failed to spawn in-app terminal command in Groove terminal: {error}")
+        (
+            "spawn_error",
+            format!("Failed to spawn in-app terminal command in Groove terminal: {error}"),
+        )
     })?;
-    let child_pid = child.process_id();
     drop(pair.slave);
 
-    let mut reader = pair.master.try_clone_reader().map_err(|error| {
-        log_play_telemetry(
-            telemetry_enabled,
-            "terminal.open.reader_attach_error",
-            format!(
-                "workspace_root={} worktree={} error={error}",
-                workspace_root_rendered, worktree
-            )
-            .as_str(),
-        );
-        format!("Failed to attach Groove terminal reader: {error}")
+    let reader = pair.master.try_clone_reader().map_err(|error| {
+        (
+            "reader_attach_error",
+            format!("Failed to attach Groove terminal reader: {error}"),
+        )
     })?;
     let writer = pair.master.take_writer().map_err(|error| {
-        log_play_telemetry(
-            telemetry_enabled,
-            "terminal.open.writer_attach_error",
-            format!(
-                "workspace_root={} worktree={} error={error}",
-                workspace_root_rendered, worktree
-            )
-            .as_str(),
-        );
-        format!("Failed to attach Groove terminal writer: {error}")
+        (
+            "writer_attach_error",
+            format!("Failed to attach Groove terminal writer: {error}"),
+        )
     })?;
 
-    let session_id = Uuid::new_v4().to_string();
-    let snapshot = Arc::new(Mutex::new(Vec::new()));
-    let session = GrooveTerminalSessionState {
-        session_id: session_id.clone(),
-        worktree_key: worktree_key.clone(),
-        workspace_root: workspace_root_rendered.clone(),
-        worktree: worktree.to_string(),
-        worktree_path: worktree_cwd_rendered.clone(),
-        command: command_rendered.clone(),
-        started_at: now_iso(),
-        cols,
-        rows,
-        child,
-        master: pair.master,
-        writer,
-        snapshot: snapshot.clone(),
-    };
-
-    {
-        let mut sessions_state = state.inner.lock().map_err(|error| {
-            log_play_telemetry(
-                telemetry_enabled,
-                "terminal.open.store_lock_error",
-                format!("worktree={} error={error}", worktree).as_str(),
-            );
-            format!("Failed to acquire Groove terminal state lock: {error}")
-        })?;
-        sessions_state
-            .session_ids_by_worktree
-            .entry(worktree_key.clone())
-            .or_default()
-            .push(session_id.clone());
-        sessions_state
-            .sessions_by_id
-            .insert(session_id.clone(), session);
-    }
-
-    if record_as_running {
-        let record = RunningGrooveRecord {
-            workspace_root: workspace_root_rendered.clone(),
-            worktree: worktree.to_string(),
-            worktree_path: worktree_cwd_rendered.clone(),
-            command: command_rendered.clone(),
-            target: target
-                .map(str::trim)
-                .filter(|value| !value.is_empty())
-                .map(|value| value.to_string()),
-            session_id: session_id.clone(),
-            pid: child_pid,
-            started_at: now_iso(),
-            still_running: None,
-        };
-        if let Err(error) = record_running_groove(app, &record) {
-            log_play_telemetry(
-                telemetry_enabled,
-                "terminal.open.record_running_failed",
-                format!(
-                    "worktree={} session_id={} error={error}",
-                    worktree, session_id
-                )
-                .as_str(),
-            );
-        }
-    }
-
-    log_play_telemetry(
-        telemetry_enabled,
-        "terminal.open.created",
-        format!(
-            "workspace_root={} worktree={} session_id={} target={} command={} cwd={}",
-            workspace_root_rendered,
-            worktree,
-            session_id,
-            target_rendered,
-            command_rendered,
-            worktree_cwd_rendered
-        )
-        .as_str(),
-    );
+    Ok((
+        GrooveTerminalPty {
+            child,
+            master: pair.master,
+            writer,
+        },
+        reader,
+    ))
+}
 
+/// Streams the output of a session's PTY into its snapshot and the terminal
+/// view, and closes the session once the PTY ends. The reader of a PTY that
+/// hibernation took away stops without closing anything.
+fn start_groove_terminal_output(
+    app: &AppHandle,
+    session: &GrooveTerminalSessionState,
+    mut reader: GrooveTerminalReader,
+    telemetry_enabled: bool,
+) {
     let app_handle = app.clone();
-    let session_id_clone = session_id.clone();
-    let workspace_root_clone = workspace_root_rendered.clone();
-    let worktree_clone = worktree.to_string();
+    let session_id_clone = session.session_id.clone();
+    let workspace_root_clone = session.workspace_root.clone();
+    let worktree_clone = session.worktree.clone();
     let telemetry_enabled_clone = telemetry_enabled;
-    let snapshot_clone = snapshot.clone();
+    let snapshot_clone = session.snapshot.clone();
+    let pty_generation = session.pty_generation;
 
     // The reader below produces output in small, high-frequency chunks (one per
     // `read()`). Emitting a Tauri event per chunk floods the webview main thread
     // (each event is deserialized + dispatched there), which can collapse the UI
     // frame rate while a chatty session streams output. Instead, the reader feeds
     // chunks into this channel and a dedicated flusher coalesces them into at most
-    // one `GROOVE_TERMINAL_OUTPUT_EVENT` per frame interval (or per size budget).
+    // one `GrooveTerminalOutputEvent` per frame interval (or per size budget).
     const TERMINAL_OUTPUT_FLUSH_INTERVAL: Duration = Duration::from_millis(16);
     const TERMINAL_OUTPUT_FLUSH_MAX_BYTES: usize = 64 * 1024;
-    let (output_tx, output_rx) = std::sync::mpsc::channel::<String>();
+    // Bounded so a flusher that falls behind makes the reader wait instead of
+    // queueing output without limit.
+    // Each chunk carries the output offset right after it, so a terminal view
+    // attaching with `groove_terminal_attach` can drop events it already got.
+    let (output_tx, output_rx) =
+        std::sync::mpsc::sync_channel::<(u64, String)>(GROOVE_TERMINAL_OUTPUT_CHANNEL_CAPACITY);
     {
         let app_handle = app_handle.clone();
         let session_id = session_id_clone.clone();
@@ -811,33 +975,36 @@ fn open_groove_terminal_session(
         let worktree = worktree_clone.clone();
         thread::spawn(move || {
             let mut pending = String::new();
-            let flush = |buffer: &mut String| {
+            let mut pending_offset = 0u64;
+            let flush = |buffer: &mut String, offset: u64| {
                 if buffer.is_empty() {
                     return;
                 }
-                let _ = app_handle.emit(
-                    GROOVE_TERMINAL_OUTPUT_EVENT,
-                    GrooveTerminalOutputEvent {
+                emit_groove_event(
+                    &app_handle,
+                    &GrooveTerminalOutputEvent {
                         session_id: session_id.clone(),
                         workspace_root: workspace_root.clone(),
                         worktree: worktree.clone(),
                         chunk: std::mem::take(buffer),
+                        offset,
                     },
                 );
             };
             loop {
                 match output_rx.recv_timeout(TERMINAL_OUTPUT_FLUSH_INTERVAL) {
-                    Ok(chunk) => {
+                    Ok((offset, chunk)) => {
                         pending.push_str(&chunk);
+                        pending_offset = offset;
                         if pending.len() >= TERMINAL_OUTPUT_FLUSH_MAX_BYTES {
-                            flush(&mut pending);
+                            flush(&mut pending, pending_offset);
                         }
                     }
                     Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {
-                        flush(&mut pending);
+                        flush(&mut pending, pending_offset);
                     }
                     Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => {
-                        flush(&mut pending);
+                        flush(&mut pending, pending_offset);
                         break;
                     }
                 }
@@ -847,28 +1014,41 @@ fn open_groove_terminal_session(
 
     thread::spawn(move || {
         let mut buffer = [0u8; 4096];
+        let mut output_offset = snapshot_clone
+            .lock()
+            .map(|snapshot| snapshot.end_offset())
+            .unwrap_or_default();
         loop {
             match reader.read(&mut buffer) {
                 Ok(0) => {
                     let state = app_handle.state::<GrooveTerminalState>();
-                    let mut close_detail = "reason=eof".to_string();
+                    if groove_terminal_pty_replaced(&state, &session_id_clone, pty_generation) {
+                        break;
+                    }
+                    let close_detail;
                     let mut closed_command: Option<String> = None;
                     let mut closed_cwd: Option<String> = None;
-                    if let Ok(mut sessions_state) = state.inner.lock() {
+                    let mut closed_open_mode = None;
+                    let mut exited_cleanly = false;
+                    {
+                        let mut sessions_state = state.lock_sessions();
                         if let Some(mut closed_session) =
                             remove_session_by_id(&mut sessions_state, &session_id_clone)
                         {
                             closed_command = Some(closed_session.command.clone());
                             closed_cwd = Some(closed_session.worktree_path.clone());
+                            closed_open_mode = Some(closed_session.open_mode);
                             close_detail = format!(
                                 "reason=eof {}",
-                                collect_groove_terminal_exit_status(closed_session.child.as_mut())
+                                groove_terminal_session_exit_status(&mut closed_session)
                             );
+                            exited_cleanly =
+                                groove_terminal_session_exited_cleanly(&mut closed_session);
                         } else {
                             close_detail = "reason=eof already_closed=true".to_string();
                         }
                     }
-                    let _ = clear_running_groove_if_session_matches(
+                    let cleared_record = clear_running_groove_if_session_matches(
                         &app_handle,
                         Path::new(&workspace_root_clone),
                         &worktree_clone,
@@ -876,9 +1056,12 @@ fn open_groove_terminal_session(
                     );
                     if let Some(command) = closed_command {
                         let cwd = closed_cwd.unwrap_or_else(|| workspace_root_clone.clone());
-                        let _ = output_tx.send(format!(
-                            "\r\n[groove] session ended: command=\"{}\" cwd=\"{}\" {}\r\n",
-                            command, cwd, close_detail
+                        let _ = output_tx.send((
+                            output_offset,
+                            format!(
+                                "\r\n[groove] session ended: command=\"{}\" cwd=\"{}\" {}\r\n",
+                                command, cwd, close_detail
+                            ),
                         ));
                     }
                     log_play_telemetry(
@@ -890,9 +1073,10 @@ fn open_groove_terminal_session(
                         )
                         .as_str(),
                     );
-                    invalidate_groove_list_cache_for_workspace(
+                    invalidate_worktree_caches(
                         &app_handle,
                         Path::new(&workspace_root_clone),
+                        &worktree_clone,
                     );
                     emit_groove_terminal_lifecycle_event(
                         &app_handle,
@@ -902,28 +1086,52 @@ fn open_groove_terminal_session(
                         "closed",
                         Some(format!("Terminal session ended ({close_detail}).")),
                     );
+                    if let (Ok(Some(record)), Some(open_mode)) = (cleared_record, closed_open_mode)
+                    {
+                        report_testing_environment_exit(
+                            &app_handle,
+                            record,
+                            open_mode,
+                            (!exited_cleanly).then_some(close_detail),
+                        );
+                    }
                     break;
                 }
                 Ok(count) => {
-                    append_terminal_snapshot(&snapshot_clone, &buffer[..count]);
+                    if let Some((offset, over_budget)) =
+                        append_terminal_snapshot(&snapshot_clone, &buffer[..count])
+                    {
+                        if over_budget {
+                            enforce_terminal_snapshot_budget(
+                                &app_handle.state::<GrooveTerminalState>(),
+                            );
+                        }
+                        output_offset = offset;
+                    }
                     let chunk = String::from_utf8_lossy(&buffer[..count]).to_string();
-                    let _ = output_tx.send(chunk);
+                    let _ = output_tx.send((output_offset, chunk));
                 }
                 Err(error) => {
                     let state = app_handle.state::<GrooveTerminalState>();
-                    let mut close_detail = format!("reason=read_error read_error={error}");
+                    if groove_terminal_pty_replaced(&state, &session_id_clone, pty_generation) {
+                        break;
+                    }
+                    let close_detail;
                     let mut closed_command: Option<String> = None;
                     let mut closed_cwd: Option<String> = None;
-                    if let Ok(mut sessions_state) = state.inner.lock() {
+                    let mut closed_open_mode = None;
+                    {
+                        let mut sessions_state = state.lock_sessions();
                         if let Some(mut closed_session) =
                             remove_session_by_id(&mut sessions_state, &session_id_clone)
                         {
                             closed_command = Some(closed_session.command.clone());
                             closed_cwd = Some(closed_session.worktree_path.clone());
+                            closed_open_mode = Some(closed_session.open_mode);
                             close_detail = format!(
                                 "reason=read_error read_error={} {}",
                                 error,
-                                collect_groove_terminal_exit_status(closed_session.child.as_mut())
+                                groove_terminal_session_exit_status(&mut closed_session)
                             );
                         } else {
                             close_detail = format!(
@@ -932,7 +1140,7 @@ fn open_groove_terminal_session(
                             );
                         }
                     }
-                    let _ = clear_running_groove_if_session_matches(
+                    let cleared_record = clear_running_groove_if_session_matches(
                         &app_handle,
                         Path::new(&workspace_root_clone),
                         &worktree_clone,
@@ -940,9 +1148,12 @@ fn open_groove_terminal_session(
                     );
                     if let Some(command) = closed_command {
                         let cwd = closed_cwd.unwrap_or_else(|| workspace_root_clone.clone());
-                        let _ = output_tx.send(format!(
-                            "\r\n[groove] session error: command=\"{}\" cwd=\"{}\" {}\r\n",
-                            command, cwd, close_detail
+                        let _ = output_tx.send((
+                            output_offset,
+                            format!(
+                                "\r\n[groove] session error: command=\"{}\" cwd=\"{}\" {}\r\n",
+                                command, cwd, close_detail
+                            ),
                         ));
                     }
                     log_play_telemetry(
@@ -954,9 +1165,10 @@ fn open_groove_terminal_session(
                         )
                         .as_str(),
                     );
-                    invalidate_groove_list_cache_for_workspace(
+                    invalidate_worktree_caches(
                         &app_handle,
                         Path::new(&workspace_root_clone),
+                        &worktree_clone,
                     );
                     emit_groove_terminal_lifecycle_event(
                         &app_handle,
@@ -966,11 +1178,344 @@ fn open_groove_terminal_session(
                         "error",
                         Some(format!("Terminal read failed ({close_detail}).")),
                     );
+                    if let (Ok(Some(record)), Some(open_mode)) = (cleared_record, closed_open_mode)
+                    {
+                        report_testing_environment_exit(
+                            &app_handle,
+                            record,
+                            open_mode,
+                            Some(close_detail),
+                        );
+                    }
                     break;
                 }
             }
         }
     });
+}
+
+fn open_groove_terminal_session(
+    app: &AppHandle,
+    state: &State<GrooveTerminalState>,
+    workspace_root: &Path,
+    worktree: &str,
+    worktree_path: &Path,
+    open_mode: GrooveTerminalOpenMode,
+    target: Option<&str>,
+    cols: Option<u16>,
+    rows: Option<u16>,
+    force_restart: bool,
+    open_new: bool,
+    record_as_running: bool,
+) -> Result<GrooveTerminalSession, String> {
+    let telemetry_enabled = telemetry_enabled_for_app(app);
+    let worktree_key = groove_terminal_session_key(workspace_root, worktree);
+    let workspace_root_rendered = workspace_root.display().to_string();
+    let cols = normalize_terminal_dimension(cols, DEFAULT_GROOVE_TERMINAL_COLS);
+    let rows = normalize_terminal_dimension(rows, DEFAULT_GROOVE_TERMINAL_ROWS);
+    let target_rendered = target
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .unwrap_or("<none>");
+
+    let (program, args) = match open_mode {
+        GrooveTerminalOpenMode::Opencode => (resolve_opencode_bin(), Vec::new()),
+        GrooveTerminalOpenMode::ClaudeCode => {
+            let args = if worktree == GROOVE_WORKSPACE_TERMINAL_WORKTREE {
+                // No worktree record for the workspace root; resume its most
+                // recent Claude session when one exists.
+                match resolve_existing_claude_session_id(
+                    worktree_path,
+                    GROOVE_WORKSPACE_TERMINAL_WORKTREE,
+                ) {
+                    Some(session_id) => vec!["--resume".to_string(), session_id],
+                    None => vec!["--session-id".to_string(), Uuid::new_v4().to_string()],
+                }
+            } else {
+                let (worktree_id, has_started) =
+                    register_worktree_record(
+                    workspace_root,
+                    worktree,
+                    resolve_branch_from_worktree(worktree_path).as_deref(),
+                )?;
+                if has_started {
+                    match resolve_existing_claude_session_id(worktree_path, &worktree_id) {
+                        Some(session_id) => vec!["--resume".to_string(), session_id],
+                        None => vec!["--session-id".to_string(), Uuid::new_v4().to_string()],
+                    }
+                } else {
+                    vec!["--session-id".to_string(), worktree_id]
+                }
+            };
+            (resolve_claude_code_bin(), args)
+        }
+        GrooveTerminalOpenMode::Plain => resolve_plain_terminal_command(),
+    };
+    let remote = workspace_remote(workspace_root);
+    let wsl = wsl_location(worktree_path);
+    let runs_locally = remote.is_none() && wsl.is_none();
+    let (program, args) = match (remote.as_ref(), wsl) {
+        (Some(remote), _) => remote_terminal_command(remote, worktree, open_mode, &args),
+        (None, Some(location)) => wsl_terminal_command(&location, open_mode, &args),
+        (None, None) => (program, args),
+    };
+    let spawn_cwd = if remote.is_some() {
+        workspace_root
+    } else {
+        worktree_path
+    };
+    let command_rendered = std::iter::once(program.as_str())
+        .chain(args.iter().map(|value| value.as_str()))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let worktree_cwd_rendered = worktree_path.display().to_string();
+
+    log_play_telemetry(
+        telemetry_enabled,
+        "terminal.open.start",
+        format!(
+            "workspace_root={} worktree={} target={} command={} cwd={} cols={} rows={} force_restart={} open_new={}",
+            workspace_root_rendered,
+            worktree,
+            target_rendered,
+            command_rendered,
+            worktree_cwd_rendered,
+            cols,
+            rows,
+            force_restart,
+            open_new
+        )
+        .as_str(),
+    );
+
+    let mut sessions_to_close = Vec::new();
+    {
+        let mut sessions_state = state.lock_sessions();
+
+        if force_restart {
+            let existing_ids = sessions_state
+                .session_ids_by_worktree
+                .get(&worktree_key)
+                .cloned()
+                .unwrap_or_default();
+            for existing_id in existing_ids {
+                if let Some(previous_session) =
+                    remove_session_by_id(&mut sessions_state, &existing_id)
+                {
+                    sessions_to_close.push(previous_session);
+                }
+            }
+
+            if !sessions_to_close.is_empty() {
+                log_play_telemetry(
+                    telemetry_enabled,
+                    "terminal.open.force_restart",
+                    format!(
+                        "workspace_root={} worktree={} previous_session_count={}",
+                        workspace_root_rendered,
+                        worktree,
+                        sessions_to_close.len()
+                    )
+                    .as_str(),
+                );
+            }
+        } else if !open_new {
+            if let Some(existing_id) =
+                latest_session_id_for_worktree(&sessions_state, &worktree_key)
+            {
+                if let Some(existing) = sessions_state.sessions_by_id.get(&existing_id) {
+                    log_play_telemetry(
+                        telemetry_enabled,
+                        "terminal.open.reused",
+                        format!(
+                            "workspace_root={} worktree={} session_id={}",
+                            workspace_root_rendered, worktree, existing.session_id
+                        )
+                        .as_str(),
+                    );
+                    return Ok(groove_terminal_session_from_state(existing));
+                }
+            }
+        }
+    }
+
+    if !sessions_to_close.is_empty() {
+        let closed_session_ids = sessions_to_close
+            .iter()
+            .map(|session| session.session_id.clone())
+            .collect::<Vec<_>>();
+        let _ = remove_worktree_session_layout_entries(
+            app,
+            workspace_root,
+            worktree,
+            &closed_session_ids,
+        );
+    }
+
+    for mut previous_session in sessions_to_close {
+        let previous_session_id = previous_session.session_id.clone();
+        let kill_detail = match previous_session.pty.as_mut().map(|pty| pty.child.kill()) {
+            Some(Ok(())) => "kill=ok".to_string(),
+            Some(Err(error)) => format!("kill_error={error}"),
+            None => "kill=hibernated".to_string(),
+        };
+        let exit_detail = groove_terminal_session_exit_status(&mut previous_session);
+        let close_detail = format!("reason=restart {kill_detail} {exit_detail}");
+        drop(previous_session);
+
+        log_play_telemetry(
+            telemetry_enabled,
+            "terminal.session.closed",
+            format!(
+                "workspace_root={} worktree={} session_id={} {}",
+                workspace_root_rendered, worktree, previous_session_id, close_detail
+            )
+            .as_str(),
+        );
+        emit_groove_terminal_lifecycle_event(
+            app,
+            &previous_session_id,
+            &workspace_root_rendered,
+            worktree,
+            "closed",
+            Some("Session restarted.".to_string()),
+        );
+    }
+
+    let spawn_spec = GrooveTerminalSpawnSpec {
+        program,
+        args,
+        cwd: spawn_cwd.to_path_buf(),
+        worktree_path: worktree_path.to_path_buf(),
+    };
+    let (pty, reader) =
+        spawn_groove_terminal_pty(&spawn_spec, cols, rows).map_err(|(stage, error)| {
+            log_play_telemetry(
+                telemetry_enabled,
+                format!("terminal.open.{stage}").as_str(),
+                format!(
+                    "workspace_root={} worktree={} error={error}",
+                    workspace_root_rendered, worktree
+                )
+                .as_str(),
+            );
+            error
+        })?;
+    let child_pid = pty.child.process_id();
+
+    let session_id = Uuid::new_v4().to_string();
+    let snapshot = Arc::new(Mutex::new(TerminalSnapshotBuffer::for_session(
+        terminal_scrollback_dir(app).ok(),
+        workspace_read_only_reason(workspace_root)
+            .is_none()
+            .then(|| terminal_history_dir(workspace_root, worktree))
+            .flatten(),
+        &session_id,
+    )));
+    let session = GrooveTerminalSessionState {
+        session_id: session_id.clone(),
+        worktree_key: worktree_key.clone(),
+        workspace_root: workspace_root_rendered.clone(),
+        worktree: worktree.to_string(),
+        worktree_path: worktree_cwd_rendered.clone(),
+        command: command_rendered.clone(),
+        open_mode,
+        started_at: now_iso(),
+        cols,
+        rows,
+        pty: Some(pty),
+        pty_generation: 0,
+        respawn: (matches!(open_mode, GrooveTerminalOpenMode::Plain)
+            && runs_locally
+            && !record_as_running)
+            .then_some(spawn_spec),
+        last_input_at: Instant::now(),
+        snapshot,
+    };
+
+    {
+        let mut sessions_state = state.lock_sessions();
+        // Started under the lock so an early EOF finds the session in place.
+        start_groove_terminal_output(app, &session, reader, telemetry_enabled);
+        sessions_state
+            .session_ids_by_worktree
+            .entry(worktree_key.clone())
+            .or_default()
+            .push(session_id.clone());
+        sessions_state
+            .sessions_by_id
+            .insert(session_id.clone(), session);
+    }
+
+    if record_as_running {
+        let record = RunningGrooveRecord {
+            workspace_root: workspace_root_rendered.clone(),
+            worktree: worktree.to_string(),
+            worktree_path: worktree_cwd_rendered.clone(),
+            command: command_rendered.clone(),
+            target: target
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(|value| value.to_string()),
+            session_id: session_id.clone(),
+            pid: child_pid,
+            started_at: now_iso(),
+            process_started_at: child_pid
+                .and_then(|pid| i32::try_from(pid).ok())
+                .and_then(crate::backend::common::platform_env::read_process_identity)
+                .map(|identity| identity.started_at),
+            still_running: None,
+        };
+        if let Err(error) = record_running_groove(app, &record) {
+            log_play_telemetry(
+                telemetry_enabled,
+                "terminal.open.record_running_failed",
+                format!(
+                    "worktree={} session_id={} error={error}",
+                    worktree, session_id
+                )
+                .as_str(),
+            );
+        }
+    }
+
+    let layout_entry = WorktreeSessionLayoutEntry {
+        session_id: session_id.clone(),
+        open_mode: groove_terminal_open_mode_key(open_mode).to_string(),
+        target: target
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(|value| value.to_string()),
+        run_local: record_as_running,
+    };
+    if let Err(error) =
+        record_worktree_session_layout_entry(app, workspace_root, worktree, layout_entry)
+    {
+        log_play_telemetry(
+            telemetry_enabled,
+            "terminal.open.record_layout_failed",
+            format!(
+                "worktree={} session_id={} error={error}",
+                worktree, session_id
+            )
+            .as_str(),
+        );
+    }
+
+    log_play_telemetry(
+        telemetry_enabled,
+        "terminal.open.created",
+        format!(
+            "workspace_root={} worktree={} session_id={} target={} command={} cwd={}",
+            workspace_root_rendered,
+            worktree,
+            session_id,
+            target_rendered,
+            command_rendered,
+            worktree_cwd_rendered
+        )
+        .as_str(),
+    );
 
     emit_groove_terminal_lifecycle_event(
         app,
@@ -990,16 +1535,9 @@ fn open_groove_terminal_session(
         )
         .as_str(),
     );
-    invalidate_groove_list_cache_for_workspace(app, workspace_root);
+    invalidate_worktree_caches(app, workspace_root, worktree);
 
-    let sessions_state = state.inner.lock().map_err(|error| {
-        log_play_telemetry(
-            telemetry_enabled,
-            "terminal.open.final_lock_error",
-            format!("worktree={} error={error}", worktree).as_str(),
-        );
-        format!("Failed to acquire Groove terminal state lock: {error}")
-    })?;
+    let sessions_state = state.lock_sessions();
     let Some(stored) = sessions_state.sessions_by_id.get(&session_id) else {
         log_play_telemetry(
             telemetry_enabled,
@@ -1014,3 +1552,27 @@ fn open_groove_terminal_session(
     };
     Ok(groove_terminal_session_from_state(stored))
 }
+
+#[cfg(test)]
+mod session_runtime_tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_buffer_keeps_the_newest_bytes() {
+        let mut buffer = TerminalSnapshotBuffer::default();
+        buffer.append(b"hello ", 8);
+        buffer.append(b"world", 8);
+        assert_eq!(buffer.bytes, b"lo world");
+        assert_eq!(buffer.trimmed_bytes, 3);
+
+        buffer.append(b"0123456789", 8);
+        assert_eq!(buffer.bytes, b"23456789");
+        assert_eq!(buffer.trimmed_bytes, 13);
+
+        assert_eq!(buffer.trim_front(5), 5);
+        assert_eq!(buffer.bytes, b"789");
+        assert_eq!(buffer.trim_front(10), 3);
+        assert!(buffer.bytes.is_empty());
+        assert_eq!(buffer.trimmed_bytes, 21);
+    }
+}
@@ -0,0 +1,251 @@
+// Disk quotas for terminal output kept on disk: the terminal history under
+// `.groove/terminal-history` of each workspace, and the scrollback files
+// (recordings included) in the app data directory. Every
+// `GROOVE_TERMINAL_STORAGE_JANITOR_INTERVAL` the janitor deletes files older
+// than `TerminalMemorySettings.storage_max_age_days`, then the oldest ones
+// until the history of each workspace, and the scrollback directory, fit in
+// `storage_quota_bytes`. It looks at the active workspace and the workspaces
+// of open sessions. Files that open sessions write to count towards the
+// quota but are never deleted.
+
+struct TerminalStorageFile {
+    path: PathBuf,
+    session_id: String,
+    /// `history`, `scrollback` or `recording`.
+    kind: &'static str,
+    bytes: u64,
+    modified: std::time::SystemTime,
+}
+
+/// The `.log` files directly in `dir`.
+fn read_terminal_storage_dir(dir: &Path, history: bool) -> Vec<TerminalStorageFile> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            let (session_id, kind) = if history {
+                (file_name.strip_suffix(".log")?, "history")
+            } else if let Some(session_id) = file_name.strip_suffix(".recorded.log") {
+                (session_id, "recording")
+            } else {
+                (file_name.strip_suffix(".log")?, "scrollback")
+            };
+            let metadata = entry
+                .metadata()
+                .ok()
+                .filter(|metadata| metadata.is_file())?;
+            Some(TerminalStorageFile {
+                path: entry.path(),
+                session_id: session_id.to_string(),
+                kind,
+                bytes: metadata.len(),
+                modified: metadata.modified().ok()?,
+            })
+        })
+        .collect()
+}
+
+/// History files of a workspace, by worktree.
+fn terminal_history_storage(workspace_root: &Path) -> Vec<(String, Vec<TerminalStorageFile>)> {
+    let history_root = workspace_root
+        .join(".groove")
+        .join(GROOVE_TERMINAL_HISTORY_DIR);
+    let Ok(entries) = fs::read_dir(&history_root) else {
+        return Vec::new();
+    };
+    let mut worktrees = entries
+        .flatten()
+        .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_dir()))
+        .map(|entry| {
+            (
+                entry.file_name().to_string_lossy().to_string(),
+                read_terminal_storage_dir(&entry.path(), true),
+            )
+        })
+        .collect::<Vec<_>>();
+    worktrees.sort_by(|left, right| left.0.cmp(&right.0));
+    worktrees
+}
+
+/// Files that open sessions write to, with the worktree of their session.
+fn live_terminal_storage_paths(state: &GrooveTerminalState) -> HashMap<PathBuf, String> {
+    let snapshots = state
+        .lock_sessions()
+        .sessions_by_id
+        .values()
+        .map(|session| (session.worktree.clone(), session.snapshot.clone()))
+        .collect::<Vec<_>>();
+    let mut paths = HashMap::new();
+    for (worktree, snapshot) in snapshots {
+        let Ok(buffer) = snapshot.lock() else {
+            continue;
+        };
+        if let Some(path) = buffer.scrollback.path() {
+            paths.insert(path, worktree.clone());
+        }
+        if let Some(path) = buffer.history.path.clone() {
+            paths.insert(path, worktree);
+        }
+    }
+    paths
+}
+
+/// Files to delete so that none is older than `max_age` and all of them fit
+/// in `quota_bytes`, oldest first. Live files are kept either way.
+fn terminal_storage_files_to_remove(
+    files: &[TerminalStorageFile],
+    live: &HashMap<PathBuf, String>,
+    quota_bytes: u64,
+    max_age: Duration,
+    now: std::time::SystemTime,
+) -> Vec<PathBuf> {
+    let mut total = files.iter().map(|file| file.bytes).sum::<u64>();
+    let mut candidates = files
+        .iter()
+        .filter(|file| !live.contains_key(&file.path))
+        .collect::<Vec<_>>();
+    candidates.sort_by_key(|file| file.modified);
+
+    let mut removed = Vec::new();
+    for file in candidates {
+        let expired = now
+            .duration_since(file.modified)
+            .is_ok_and(|age| age > max_age);
+        if expired || total > quota_bytes {
+            total -= file.bytes;
+            removed.push(file.path.clone());
+        }
+    }
+    removed
+}
+
+/// Returns how many files were removed and the bytes they freed.
+fn enforce_terminal_storage_quotas(app: &AppHandle) -> (usize, u64) {
+    let quota_bytes = TERMINAL_STORAGE_QUOTA_BYTES.load(Ordering::Relaxed);
+    let max_age =
+        Duration::from_secs(TERMINAL_STORAGE_MAX_AGE_DAYS.load(Ordering::Relaxed) * 24 * 60 * 60);
+    let state = app.state::<GrooveTerminalState>();
+    let live = live_terminal_storage_paths(&state);
+
+    let mut workspace_roots = state
+        .lock_sessions()
+        .sessions_by_id
+        .values()
+        .map(|session| PathBuf::from(&session.workspace_root))
+        .collect::<HashSet<_>>();
+    if let Ok(active_root) = active_workspace_root_from_state(app) {
+        workspace_roots.insert(active_root);
+    }
+
+    let mut groups = workspace_roots
+        .iter()
+        .filter(|root| workspace_read_only_reason(root).is_none())
+        .map(|root| {
+            terminal_history_storage(root)
+                .into_iter()
+                .flat_map(|(_, files)| files)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    if let Ok(dir) = terminal_scrollback_dir(app) {
+        groups.push(read_terminal_storage_dir(&dir, false));
+    }
+
+    let now = std::time::SystemTime::now();
+    let (mut removed, mut freed) = (0usize, 0u64);
+    for files in groups {
+        for path in terminal_storage_files_to_remove(&files, &live, quota_bytes, max_age, now) {
+            let bytes = fs::metadata(&path)
+                .map(|metadata| metadata.len())
+                .unwrap_or(0);
+            if fs::remove_file(&path).is_ok() {
+                removed += 1;
+                freed += bytes;
+            }
+        }
+    }
+    if removed > 0 {
+        log_line(&format!(
+            "[terminal-storage] removed {removed} files, {freed} bytes"
+        ));
+    }
+    (removed, freed)
+}
+
+fn start_terminal_storage_janitor(app: AppHandle) {
+    thread::spawn(move || loop {
+        thread::sleep(GROOVE_TERMINAL_STORAGE_JANITOR_INTERVAL);
+        if background_activity_paused() {
+            continue;
+        }
+        enforce_terminal_storage_quotas(&app);
+    });
+}
+
+fn terminal_storage_usage(
+    file: &TerminalStorageFile,
+    live: &HashMap<PathBuf, String>,
+) -> TerminalStorageSessionUsage {
+    TerminalStorageSessionUsage {
+        session_id: file.session_id.clone(),
+        kind: file.kind.to_string(),
+        bytes: file.bytes,
+        modified_at: OffsetDateTime::from(file.modified).format(&Rfc3339).ok(),
+        live: live.contains_key(&file.path),
+        worktree: live.get(&file.path).cloned(),
+    }
+}
+
+#[cfg(test)]
+mod storage_runtime_tests {
+    use super::*;
+
+    fn storage_file(
+        name: &str,
+        bytes: u64,
+        age_days: u64,
+        now: std::time::SystemTime,
+    ) -> TerminalStorageFile {
+        TerminalStorageFile {
+            path: PathBuf::from(format!("/tmp/{name}.log")),
+            session_id: name.to_string(),
+            kind: "history",
+            bytes,
+            modified: now - Duration::from_secs(age_days * 24 * 60 * 60),
+        }
+    }
+
+    #[test]
+    fn removes_expired_files_then_oldest_until_under_quota() {
+        let now = std::time::SystemTime::now();
+        let max_age = Duration::from_secs(30 * 24 * 60 * 60);
+        let files = vec![
+            storage_file("live-old", 10, 40, now),
+            storage_file("expired", 10, 31, now),
+            storage_file("older", 30, 5, now),
+            storage_file("newer", 30, 1, now),
+        ];
+        let live = HashMap::from([(PathBuf::from("/tmp/live-old.log"), "wt".to_string())]);
+
+        assert_eq!(
+            terminal_storage_files_to_remove(&files, &live, 100, max_age, now),
+            vec![PathBuf::from("/tmp/expired.log")]
+        );
+        assert_eq!(
+            terminal_storage_files_to_remove(&files, &live, 45, max_age, now),
+            vec![
+                PathBuf::from("/tmp/expired.log"),
+                PathBuf::from("/tmp/older.log")
+            ]
+        );
+        // Live files still count: the quota cannot be met, so everything
+        // else goes.
+        assert_eq!(
+            terminal_storage_files_to_remove(&files, &live, 5, max_age, now).len(),
+            3
+        );
+    }
+}
@@ -0,0 +1,149 @@
+// Experimental remote workspaces. The workspace folder (and its
+// `.groove/workspace.json`) stays local, but `workspace_meta.remote` points at
+// a repository on an SSH host: worktree listing, git status and terminal
+// sessions run there through the local `ssh` binary, reusing the user's ssh
+// config, keys and agent.
+
+fn workspace_remote(workspace_root: &Path) -> Option<WorkspaceRemote> {
+    ensure_workspace_meta(workspace_root)
+        .ok()
+        .and_then(|(workspace_meta, _)| workspace_meta.remote)
+}
+
+fn normalize_workspace_remote(remote: &WorkspaceRemote) -> Result<WorkspaceRemote, String> {
+    let destination = remote.destination.trim();
+    remote::validate_ssh_destination(destination)?;
+    let root = remote.root.trim();
+    remote::validate_remote_root(root)?;
+    if remote.port == Some(0) {
+        return Err("remote port must be between 1 and 65535.".to_string());
+    }
+
+    Ok(WorkspaceRemote {
+        destination: destination.to_string(),
+        port: remote.port,
+        root: if root == "/" {
+            root.to_string()
+        } else {
+            root.trim_end_matches('/').to_string()
+        },
+        forward_agent: remote.forward_agent,
+    })
+}
+
+/// The directory a worktree's commands and terminals run in on the host.
+fn remote_worktree_cwd(remote: &WorkspaceRemote, worktree: &str) -> String {
+    if worktree == GROOVE_WORKSPACE_TERMINAL_WORKTREE {
+        remote.root.clone()
+    } else {
+        remote::remote_worktree_path(&remote.root, worktree)
+    }
+}
+
+/// Runs `argv` in `cwd` on the remote host without a TTY.
+fn run_remote_command(remote: &WorkspaceRemote, cwd: &str, argv: &[&str]) -> CommandResult {
+    let argv = argv.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+    let mut command = Command::new("ssh");
+    command
+        .args(remote::ssh_args(
+            &remote.destination,
+            remote.port,
+            remote.forward_agent,
+            false,
+            &remote::remote_shell_command(cwd, &argv),
+        ))
+        .stdin(Stdio::null());
+    run_command_with_timeout(
+        command,
+        REMOTE_WORKSPACE_COMMAND_TIMEOUT,
+        "Failed to execute ssh".to_string(),
+        format!("ssh {}", remote.destination),
+    )
+}
+
+fn remote_command_failure(result: &CommandResult, fallback: &str) -> Option<String> {
+    if let Some(error) = result.error.clone() {
+        return Some(error);
+    }
+    if result.exit_code == Some(0) {
+        return None;
+    }
+    Some(
+        first_non_empty_line(&result.stderr)
+            .or_else(|| first_non_empty_line(&result.stdout))
+            .unwrap_or_else(|| fallback.to_string()),
+    )
+}
+
+/// Wraps a terminal launch in `ssh -tt` so the PTY is attached to the remote
+/// process. Plain terminals get the remote login shell; agent modes expect
+/// the agent CLI on the remote `PATH`.
+fn remote_terminal_command(
+    remote: &WorkspaceRemote,
+    worktree: &str,
+    open_mode: GrooveTerminalOpenMode,
+    args: &[String],
+) -> (String, Vec<String>) {
+    let cwd = remote_worktree_cwd(remote, worktree);
+    let remote_command = match open_mode {
+        GrooveTerminalOpenMode::Plain => remote::remote_login_shell_command(&cwd),
+        GrooveTerminalOpenMode::Opencode => {
+            remote::remote_shell_command(&cwd, &["opencode".to_string()])
+        }
+        GrooveTerminalOpenMode::ClaudeCode => {
+            let argv = std::iter::once("claude".to_string())
+                .chain(args.iter().cloned())
+                .collect::<Vec<_>>();
+            remote::remote_shell_command(&cwd, &argv)
+        }
+    };
+    (
+        "ssh".to_string(),
+        remote::ssh_args(
+            &remote.destination,
+            remote.port,
+            remote.forward_agent,
+            true,
+            &remote_command,
+        ),
+    )
+}
+
+/// Worktrees the remote repository knows about, keyed by their directory name
+/// under `<root>/.worktrees`. The main checkout is left out, matching the
+/// local scan.
+fn list_remote_worktrees(remote: &WorkspaceRemote) -> Result<Vec<RemoteWorktreeRow>, String> {
+    let result = run_remote_command(
+        remote,
+        &remote.root,
+        &["git", "worktree", "list", "--porcelain"],
+    );
+    if let Some(error) = remote_command_failure(&result, "git worktree list failed") {
+        return Err(error);
+    }
+
+    let worktrees_dir = format!("{}/.worktrees/", remote.root.trim_end_matches('/'));
+    Ok(git::parse_worktree_list_porcelain(&result.stdout)
+        .into_iter()
+        .filter_map(|(branch, path)| {
+            let worktree = path.strip_prefix(&worktrees_dir)?;
+            if worktree.is_empty() || worktree.contains('/') {
+                return None;
+            }
+            Some(RemoteWorktreeRow {
+                worktree: worktree.to_string(),
+                branch,
+                path: path.clone(),
+            })
+        })
+        .collect())
+}
+
+/// The active workspace's remote settings, or why there are none.
+fn active_workspace_remote(app: &AppHandle) -> Result<WorkspaceRemote, String> {
+    let persisted_root = read_persisted_active_workspace_root(app)?
+        .ok_or_else(|| "No active workspace selected.".to_string())?;
+    let workspace_root = validate_workspace_root_path(&persisted_root)?;
+    workspace_remote(&workspace_root)
+        .ok_or_else(|| "The active workspace has no remote host configured.".to_string())
+}
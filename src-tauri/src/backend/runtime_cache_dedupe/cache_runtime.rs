@@ -29,39 +29,50 @@ fn snapshot_runtime_pids_by_worktree(
         .collect()
 }
 
+/// Writes a backend log line to stderr. Every log goes through here so paths
+/// are redacted the same way everywhere.
+fn log_line(line: &str) {
+    eprintln!(
+        "{}",
+        redaction::redact_paths(line, telemetry_path_redaction())
+    );
+}
+
 fn log_backend_timing(telemetry_enabled: bool, event: &str, elapsed: Duration, details: &str) {
     if !telemetry_enabled {
         return;
     }
-    eprintln!(
+    log_line(&format!(
         "[startup-telemetry] event={event} elapsed_ms={} {details}",
         elapsed.as_millis()
-    );
+    ));
 }
 
 fn log_play_telemetry(telemetry_enabled: bool, event: &str, details: &str) {
     if !telemetry_enabled {
         return;
     }
-    eprintln!("[play-telemetry] event={event} {details}");
+    log_line(&format!("[play-telemetry] event={event} {details}"));
 }
 
 fn log_build_workspace_context_timing(
     telemetry_enabled: bool,
-    meta_elapsed: Duration,
-    scan_elapsed: Duration,
+    timings: WorkspaceContextTimings,
     total_elapsed: Duration,
     cache_hit: bool,
 ) {
     if !telemetry_enabled {
         return;
     }
-    eprintln!(
-        "[startup-telemetry] event=build_workspace_context meta_ms={} scan_ms={} total_ms={} cache_hit={cache_hit}",
-        meta_elapsed.as_millis(),
-        scan_elapsed.as_millis(),
+    // The remote lookup overlaps meta and scan, so the stages can add up to
+    // more than the total.
+    log_line(&format!(
+        "[startup-telemetry] event=build_workspace_context remote_ms={} meta_ms={} scan_ms={} total_ms={} cache_hit={cache_hit}",
+        timings.remote.as_millis(),
+        timings.meta.as_millis(),
+        timings.scan.as_millis(),
         total_elapsed.as_millis(),
-    );
+    ));
 }
 
 fn sorted_worktrees_key(known_worktrees: &[String]) -> String {
@@ -74,6 +85,18 @@ fn workspace_context_cache_key(workspace_root: &Path) -> String {
     workspace_root_storage_key(workspace_root)
 }
 
+/// `.git/config` of the workspace repository, or the `.git` file when the
+/// workspace is itself a linked worktree. The remote URL is only looked up
+/// with git again once it changes.
+fn workspace_git_marker(workspace_root: &Path) -> PathBuf {
+    let git_path = workspace_root.join(".git");
+    if path_is_directory(&git_path) {
+        git_path.join("config")
+    } else {
+        git_path
+    }
+}
+
 fn workspace_context_signature(
     app: &AppHandle,
     workspace_root: &Path,
@@ -86,9 +109,24 @@ fn workspace_context_signature(
         workspace_manifest: snapshot_entry(&workspace_root.join(".groove").join("workspace.json")),
         worktrees_dir: snapshot_entry(&effective_root.join(".worktrees")),
         worktree_execution_state_file: snapshot_entry(&execution_state_file),
+        git_marker: snapshot_entry(&workspace_git_marker(workspace_root)),
     })
 }
 
+/// The remote URL of the last context built for the workspace, while its git
+/// marker is unchanged. `None` means git has to be asked.
+fn cached_repository_remote_url(app: &AppHandle, workspace_root: &Path) -> Option<Option<String>> {
+    let cache_state = app.try_state::<WorkspaceContextCacheState>()?;
+    let git_marker = snapshot_entry(&workspace_git_marker(workspace_root));
+    if !git_marker.exists {
+        return None;
+    }
+    let entries = cache_state.lock_entries();
+    let cached = entries.get(&workspace_context_cache_key(workspace_root))?;
+    (cached.signature.git_marker == git_marker)
+        .then(|| cached.response.repository_remote_url.clone())
+}
+
 fn try_cached_workspace_context(
     app: &AppHandle,
     workspace_root: &Path,
@@ -98,7 +136,7 @@ fn try_cached_workspace_context(
     let signature = workspace_context_signature(app, workspace_root).ok()?;
     let key = workspace_context_cache_key(workspace_root);
     let mut response = {
-        let entries = cache_state.entries.lock().ok()?;
+        let entries = cache_state.lock_entries();
         let cached = entries.get(&key)?;
         if cached.signature != signature {
             return None;
@@ -127,9 +165,7 @@ fn store_workspace_context_cache(
     let Ok(signature) = workspace_context_signature(app, workspace_root) else {
         return;
     };
-    let Ok(mut entries) = cache_state.entries.lock() else {
-        return;
-    };
+    let mut entries = cache_state.lock_entries();
     entries.insert(
         workspace_context_cache_key(workspace_root),
         WorkspaceContextCacheEntry {
@@ -172,7 +208,7 @@ fn try_cached_terminal_resolution(
     let cache_state = app.try_state::<TerminalResolutionCacheState>()?;
     let key = terminal_resolution_cache_key(root_name, worktree);
     let (workspace_root, worktree_path, cached_signature) = {
-        let entries = cache_state.entries.lock().ok()?;
+        let entries = cache_state.lock_entries();
         let cached = entries.get(&key)?;
         (
             cached.workspace_root.clone(),
@@ -198,9 +234,7 @@ fn store_terminal_resolution(
         return;
     };
     let signature = terminal_resolution_signature(app, workspace_root, worktree_path);
-    let Ok(mut entries) = cache_state.entries.lock() else {
-        return;
-    };
+    let mut entries = cache_state.lock_entries();
     entries.insert(
         terminal_resolution_cache_key(root_name, worktree),
         TerminalResolutionCacheEntry {
@@ -211,13 +245,69 @@ fn store_terminal_resolution(
     );
 }
 
-fn invalidate_workspace_context_cache(app: &AppHandle, workspace_root: &Path) {
+/// A change to what the caches show for a workspace, published by whichever
+/// subsystem made it. `worktree` narrows the change to one worktree; `None`
+/// covers the whole workspace.
+#[derive(Debug, Clone, Copy)]
+struct CacheInvalidation<'a> {
+    workspace_root: &'a Path,
+    worktree: Option<&'a str>,
+}
+
+/// Every cache that holds per-workspace data. Each one drops what an
+/// invalidation affects and keeps the rest.
+const CACHE_INVALIDATION_SUBSCRIBERS: &[fn(&AppHandle, CacheInvalidation)] = &[
+    invalidate_workspace_context_cache,
+    invalidate_groove_list_cache,
+];
+
+fn publish_cache_invalidation(app: &AppHandle, invalidation: CacheInvalidation) {
+    for subscriber in CACHE_INVALIDATION_SUBSCRIBERS {
+        subscriber(app, invalidation);
+    }
+}
+
+fn invalidate_workspace_caches(app: &AppHandle, workspace_root: &Path) {
+    publish_cache_invalidation(
+        app,
+        CacheInvalidation {
+            workspace_root,
+            worktree: None,
+        },
+    );
+}
+
+fn invalidate_worktree_caches(app: &AppHandle, workspace_root: &Path, worktree: &str) {
+    publish_cache_invalidation(
+        app,
+        CacheInvalidation {
+            workspace_root,
+            worktree: Some(worktree),
+        },
+    );
+}
+
+/// A worktree change only drops the cached context when it lists that
+/// worktree.
+fn invalidate_workspace_context_cache(app: &AppHandle, invalidation: CacheInvalidation) {
     let Some(cache_state) = app.try_state::<WorkspaceContextCacheState>() else {
         return;
     };
-    if let Ok(mut entries) = cache_state.entries.lock() {
-        entries.remove(&workspace_context_cache_key(workspace_root));
+    let key = workspace_context_cache_key(invalidation.workspace_root);
+    let mut entries = cache_state.lock_entries();
+    let affected = match invalidation.worktree {
+        None => true,
+        Some(worktree) => entries.get(&key).is_some_and(|entry| {
+            entry
+                .response
+                .rows
+                .iter()
+                .any(|row| row.worktree == worktree)
+        }),
     };
+    if affected {
+        entries.remove(&key);
+    }
 }
 
 /// Mutates the cached `WorkspaceContextResponse` in place (if any) and
@@ -239,9 +329,7 @@ fn patch_workspace_context_cache(
     let Ok(signature) = workspace_context_signature(app, workspace_root) else {
         return;
     };
-    let Ok(mut entries) = cache_state.entries.lock() else {
-        return;
-    };
+    let mut entries = cache_state.lock_entries();
     let key = workspace_context_cache_key(workspace_root);
     let Some(entry) = entries.get_mut(&key) else {
         return;
@@ -250,15 +338,6 @@ fn patch_workspace_context_cache(
     entry.signature = signature;
 }
 
-fn clear_workspace_context_cache(app: &AppHandle) {
-    let Some(cache_state) = app.try_state::<WorkspaceContextCacheState>() else {
-        return;
-    };
-    if let Ok(mut entries) = cache_state.entries.lock() {
-        entries.clear();
-    };
-}
-
 fn groove_list_cache_key(
     workspace_root: &Path,
     known_worktrees: &[String],
@@ -291,24 +370,351 @@ fn groove_list_cache_key(
     )
 }
 
-fn invalidate_groove_list_cache_for_workspace(app: &AppHandle, workspace_root: &Path) {
+fn invalidate_groove_list_cache(app: &AppHandle, invalidation: CacheInvalidation) {
     let Some(cache_state) = app.try_state::<GrooveListCacheState>() else {
         return;
     };
 
-    let root_prefix = format!("root={}\n", workspace_root_storage_key(workspace_root));
+    let root_prefix = format!(
+        "root={}\n",
+        workspace_root_storage_key(invalidation.workspace_root)
+    );
+    apply_groove_list_invalidation(
+        &mut cache_state.lock_entries(),
+        &root_prefix,
+        invalidation.worktree,
+    );
+}
 
-    if let Ok(mut entries) = cache_state.entries.lock() {
-        entries.retain(|key, _| !key.starts_with(&root_prefix));
+/// A workspace change drops its entries outright. A worktree change only
+/// marks them invalidated and forgets that worktree's native row, so the
+/// next list recomputes it and reuses the rows of the other worktrees.
+fn apply_groove_list_invalidation(
+    entries: &mut HashMap<String, GrooveListCacheEntry>,
+    root_prefix: &str,
+    worktree: Option<&str>,
+) {
+    let Some(worktree) = worktree else {
+        entries.retain(|key, _| !key.starts_with(root_prefix));
+        return;
     };
+    for (_, entry) in entries
+        .iter_mut()
+        .filter(|(key, _)| key.starts_with(root_prefix))
+    {
+        entry.invalidated = true;
+        if let Some(native_cache) = entry.native_cache.as_mut() {
+            native_cache.rows_by_worktree.remove(worktree);
+        }
+    }
+}
+
+
+// The native groove list rows are also mirrored to `groove-list-cache.json`
+// in the app data directory, so the first list after a launch only
+// recomputes worktrees whose signature changed. A workspace is restored from
+// the file at most once per run; after that the in-memory cache (and its
+// invalidation) is authoritative.
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PersistedGrooveListCache {
+    version: u32,
+    #[serde(default)]
+    workspaces: HashMap<String, PersistedGrooveListWorkspace>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PersistedGrooveListWorkspace {
+    saved_at_ms: u64,
+    rows: HashMap<String, PersistedGrooveListRow>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PersistedGrooveListRow {
+    signature: String,
+    branch: String,
+    log_state: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    log_target: Option<String>,
+}
+
+fn groove_list_cache_file(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|error| format!("Failed to resolve app data directory: {error}"))?;
+    fs::create_dir_all(&app_data_dir)
+        .map_err(|error| format!("Failed to create app data directory: {error}"))?;
+    Ok(app_data_dir.join(GROOVE_LIST_CACHE_FILE))
 }
 
-fn clear_groove_list_cache(app: &AppHandle) {
+fn groove_list_persisted_cache_key(workspace_root: &Path, dir: &Option<String>) -> String {
+    format!(
+        "root={}\ndir={}",
+        workspace_root_storage_key(workspace_root),
+        dir.as_deref().unwrap_or_default()
+    )
+}
+
+/// A missing, unreadable or outdated file restores nothing.
+fn read_groove_list_cache_file(path: &Path) -> PersistedGrooveListCache {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|raw| serde_json::from_str::<PersistedGrooveListCache>(&raw).ok())
+        .filter(|cache| cache.version == GROOVE_LIST_CACHE_FILE_VERSION)
+        .unwrap_or_default()
+}
+
+/// Rows of one workspace as a native cache. Rows without a signature are
+/// dropped; the others are only reused by `collect_groove_list_rows_native`
+/// while their signature still matches the worktree on disk.
+fn restore_groove_list_native_cache(
+    cache: &PersistedGrooveListCache,
+    key: &str,
+) -> Option<GrooveListNativeCache> {
+    let workspace = cache.workspaces.get(key)?;
+    let rows_by_worktree = workspace
+        .rows
+        .iter()
+        .filter(|(worktree, row)| !worktree.is_empty() && !row.signature.is_empty())
+        .map(|(worktree, row)| {
+            (
+                worktree.clone(),
+                GrooveListNativeCacheRow {
+                    signature: row.signature.clone(),
+                    row: RuntimeStateRow {
+                        branch: row.branch.clone(),
+                        worktree: worktree.clone(),
+                        log_state: row.log_state.clone(),
+                        log_target: row.log_target.clone(),
+                        resources: None,
+                        badges: None,
+                        parent: None,
+                        children: Vec::new(),
+                    },
+                },
+            )
+        })
+        .collect::<HashMap<_, _>>();
+    (!rows_by_worktree.is_empty()).then_some(GrooveListNativeCache { rows_by_worktree })
+}
+
+/// Replaces the rows of one workspace, keeping the most recently saved
+/// `GROOVE_LIST_CACHE_FILE_MAX_WORKSPACES`.
+fn store_groove_list_native_cache(
+    cache: &mut PersistedGrooveListCache,
+    key: &str,
+    native_cache: &GrooveListNativeCache,
+    saved_at_ms: u64,
+) {
+    if cache.version != GROOVE_LIST_CACHE_FILE_VERSION {
+        *cache = PersistedGrooveListCache {
+            version: GROOVE_LIST_CACHE_FILE_VERSION,
+            workspaces: HashMap::new(),
+        };
+    }
+    let rows = native_cache
+        .rows_by_worktree
+        .iter()
+        .map(|(worktree, cached)| {
+            (
+                worktree.clone(),
+                PersistedGrooveListRow {
+                    signature: cached.signature.clone(),
+                    branch: cached.row.branch.clone(),
+                    log_state: cached.row.log_state.clone(),
+                    log_target: cached.row.log_target.clone(),
+                },
+            )
+        })
+        .collect();
+    cache.workspaces.insert(
+        key.to_string(),
+        PersistedGrooveListWorkspace { saved_at_ms, rows },
+    );
+
+    while cache.workspaces.len() > GROOVE_LIST_CACHE_FILE_MAX_WORKSPACES {
+        let Some(oldest) = cache
+            .workspaces
+            .iter()
+            .min_by_key(|(_, workspace)| workspace.saved_at_ms)
+            .map(|(key, _)| key.clone())
+        else {
+            break;
+        };
+        cache.workspaces.remove(&oldest);
+    }
+}
+
+/// The persisted rows for `key`, the first time it is listed this run.
+fn load_persisted_groove_list_cache(app: &AppHandle, key: &str) -> Option<GrooveListNativeCache> {
+    let cache_state = app.try_state::<GrooveListCacheState>()?;
+    let mut restored = cache_state.lock_restored_from_disk();
+    if !restored.insert(key.to_string()) {
+        return None;
+    }
+    let path = groove_list_cache_file(app).ok()?;
+    restore_groove_list_native_cache(&read_groove_list_cache_file(&path), key)
+}
+
+/// Failures are logged; the in-memory cache is unaffected.
+fn persist_groove_list_cache(app: &AppHandle, key: &str, native_cache: &GrooveListNativeCache) {
     let Some(cache_state) = app.try_state::<GrooveListCacheState>() else {
         return;
     };
-    if let Ok(mut entries) = cache_state.entries.lock() {
-        entries.clear();
-    };
+    let _restored = cache_state.lock_restored_from_disk();
+    let saved_at_ms = std::time::SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or_default();
+    let result = groove_list_cache_file(app).and_then(|path| {
+        let mut cache = read_groove_list_cache_file(&path);
+        store_groove_list_native_cache(&mut cache, key, native_cache, saved_at_ms);
+        let body = serde_json::to_string(&cache)
+            .map_err(|error| format!("Failed to serialize groove list cache: {error}"))?;
+        fs::write(&path, body)
+            .map_err(|error| format!("Failed to write {}: {error}", path.display()))
+    });
+    if let Err(error) = result {
+        log_line(&format!("[groove-list-cache] {error}"));
+    }
 }
 
+#[cfg(test)]
+mod cache_runtime_tests {
+    use super::*;
+
+    fn native_cache(rows: &[(&str, &str)]) -> GrooveListNativeCache {
+        GrooveListNativeCache {
+            rows_by_worktree: rows
+                .iter()
+                .map(|(worktree, signature)| {
+                    (
+                        worktree.to_string(),
+                        GrooveListNativeCacheRow {
+                            signature: signature.to_string(),
+                            row: RuntimeStateRow {
+                                branch: format!("feature/{worktree}"),
+                                worktree: worktree.to_string(),
+                                log_state: "none".to_string(),
+                                log_target: None,
+                                resources: None,
+                                badges: None,
+                                parent: None,
+                                children: Vec::new(),
+                            },
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    fn list_entry(rows: &[(&str, &str)]) -> GrooveListCacheEntry {
+        GrooveListCacheEntry {
+            created_at: Instant::now(),
+            response: GrooveListResponse {
+                request_id: String::new(),
+                ok: true,
+                workspace_root: None,
+                rows: HashMap::new(),
+                order: None,
+                filter: None,
+                page: None,
+                stdout: String::new(),
+                stderr: String::new(),
+                cancelled: None,
+                error: None,
+            },
+            native_cache: Some(native_cache(rows)),
+            invalidated: false,
+        }
+    }
+
+    #[test]
+    fn worktree_invalidation_keeps_the_other_native_rows() {
+        let mut entries = HashMap::from([
+            (
+                "root=/repo\nknown=\ndir=\nmeta=".to_string(),
+                list_entry(&[("alpha", "a"), ("beta", "b")]),
+            ),
+            (
+                "root=/other\nknown=\ndir=\nmeta=".to_string(),
+                list_entry(&[("alpha", "a")]),
+            ),
+        ]);
+
+        apply_groove_list_invalidation(&mut entries, "root=/repo\n", Some("alpha"));
+        let repo = &entries["root=/repo\nknown=\ndir=\nmeta="];
+        assert!(repo.invalidated);
+        let rows = &repo.native_cache.as_ref().unwrap().rows_by_worktree;
+        assert!(!rows.contains_key("alpha") && rows.contains_key("beta"));
+        assert!(!entries["root=/other\nknown=\ndir=\nmeta="].invalidated);
+
+        apply_groove_list_invalidation(&mut entries, "root=/repo\n", None);
+        assert_eq!(
+            entries.keys().collect::<Vec<_>>(),
+            ["root=/other\nknown=\ndir=\nmeta="]
+        );
+    }
+
+    #[test]
+    fn persisted_groove_list_rows_round_trip_per_workspace() {
+        let mut cache = PersistedGrooveListCache::default();
+        store_groove_list_native_cache(
+            &mut cache,
+            "root=/repo\ndir=",
+            &native_cache(&[("alpha", "worktree=true:1"), ("beta", "")]),
+            10,
+        );
+        let raw = serde_json::to_string(&cache).unwrap();
+        let cache = serde_json::from_str::<PersistedGrooveListCache>(&raw).unwrap();
+
+        let restored = restore_groove_list_native_cache(&cache, "root=/repo\ndir=").unwrap();
+        assert_eq!(restored.rows_by_worktree.len(), 1);
+        let alpha = &restored.rows_by_worktree["alpha"];
+        assert_eq!(alpha.signature, "worktree=true:1");
+        assert_eq!(alpha.row.branch, "feature/alpha");
+        assert_eq!(alpha.row.worktree, "alpha");
+        assert!(restore_groove_list_native_cache(&cache, "root=/other\ndir=").is_none());
+    }
+
+    #[test]
+    fn persisted_groove_list_cache_keeps_the_newest_workspaces() {
+        let mut cache = PersistedGrooveListCache {
+            version: GROOVE_LIST_CACHE_FILE_VERSION + 1,
+            workspaces: HashMap::new(),
+        };
+        for index in 0..GROOVE_LIST_CACHE_FILE_MAX_WORKSPACES + 1 {
+            store_groove_list_native_cache(
+                &mut cache,
+                &format!("root=/repo-{index}\ndir="),
+                &native_cache(&[("alpha", "sig")]),
+                index as u64,
+            );
+        }
+        assert_eq!(cache.version, GROOVE_LIST_CACHE_FILE_VERSION);
+        assert_eq!(cache.workspaces.len(), GROOVE_LIST_CACHE_FILE_MAX_WORKSPACES);
+        assert!(!cache.workspaces.contains_key("root=/repo-0\ndir="));
+    }
+
+    #[test]
+    fn git_marker_is_the_repository_config_or_the_worktree_git_file() {
+        let root = std::env::temp_dir().join(format!("groove-git-marker-{}", Uuid::new_v4()));
+        fs::create_dir_all(root.join(".git")).expect("create .git");
+        assert_eq!(
+            workspace_git_marker(&root),
+            root.join(".git").join("config")
+        );
+
+        let linked = root.join("linked");
+        fs::create_dir_all(&linked).expect("create linked worktree");
+        fs::write(linked.join(".git"), "gitdir: ../.git/worktrees/linked\n").expect("write .git");
+        assert_eq!(workspace_git_marker(&linked), linked.join(".git"));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -0,0 +1,344 @@
+// Backend health summary for `app_health`: one row per subsystem with an
+// "ok", "warning" or "error" status and a line a person can read off a
+// screenshot. Every check reads in-memory state except the state file check,
+// which writes and removes a probe file in each directory Groove persists to.
+
+fn app_health_subsystem(name: &str, status: &str, summary: String) -> AppHealthSubsystem {
+    AppHealthSubsystem {
+        name: name.to_string(),
+        status: status.to_string(),
+        summary,
+    }
+}
+
+fn app_health_status_rank(status: &str) -> u8 {
+    match status {
+        "error" => 2,
+        "warning" => 1,
+        _ => 0,
+    }
+}
+
+fn workspace_worker_health(app: &AppHandle) -> AppHealthSubsystem {
+    let active_root = read_persisted_active_workspace_root(app).ok().flatten();
+    let Some(state) = app.try_state::<WorkspaceEventState>() else {
+        return app_health_subsystem(
+            "workspaceWorker",
+            "error",
+            "Workspace event state is not available.".to_string(),
+        );
+    };
+    let workers = match state.workers.lock() {
+        Ok(workers) => workers,
+        Err(error) => {
+            return app_health_subsystem(
+                "workspaceWorker",
+                "error",
+                format!("Workspace event lock is poisoned: {error}"),
+            )
+        }
+    };
+    // The active workspace's worker; any other open workspace's otherwise.
+    let worker = active_root
+        .as_deref()
+        .and_then(|active_root| workers.get(&workspace_root_storage_key(Path::new(active_root))))
+        .or_else(|| workers.values().next());
+
+    match (worker, active_root) {
+        (None, None) => {
+            app_health_subsystem("workspaceWorker", "ok", "No workspace is open.".to_string())
+        }
+        (None, Some(active_root)) => app_health_subsystem(
+            "workspaceWorker",
+            "warning",
+            format!("Not watching the active workspace {active_root}."),
+        ),
+        (Some(worker), _) if worker.handle.is_finished() => app_health_subsystem(
+            "workspaceWorker",
+            "error",
+            format!("Stopped while watching {}.", worker.workspace_root),
+        ),
+        (Some(worker), _) if background_activity_paused() => app_health_subsystem(
+            "workspaceWorker",
+            "ok",
+            format!(
+                "Watching {}, paused since {}.",
+                worker.workspace_root,
+                background_paused_since().unwrap_or_default()
+            ),
+        ),
+        (Some(worker), active_root) => {
+            let since_poll = worker
+                .last_poll_at
+                .lock()
+                .map(|last_poll_at| last_poll_at.elapsed())
+                .unwrap_or_default();
+            if since_poll >= APP_HEALTH_WORKER_STALL_AFTER {
+                return app_health_subsystem(
+                    "workspaceWorker",
+                    "warning",
+                    format!(
+                        "Watching {} but the last poll started {}s ago.",
+                        worker.workspace_root,
+                        since_poll.as_secs()
+                    ),
+                );
+            }
+            let watches_active = active_root.is_none_or(|active_root| {
+                workspace_root_storage_key(Path::new(&active_root))
+                    == workspace_root_storage_key(Path::new(&worker.workspace_root))
+            });
+            if !watches_active {
+                return app_health_subsystem(
+                    "workspaceWorker",
+                    "warning",
+                    format!(
+                        "Watching {} instead of the active workspace.",
+                        worker.workspace_root
+                    ),
+                );
+            }
+            app_health_subsystem(
+                "workspaceWorker",
+                "ok",
+                format!(
+                    "Watching {}, last poll {}s ago.",
+                    worker.workspace_root,
+                    since_poll.as_secs()
+                ),
+            )
+        }
+    }
+}
+
+fn terminal_sessions_health(app: &AppHandle) -> AppHealthSubsystem {
+    let Some(state) = app.try_state::<GrooveTerminalState>() else {
+        return app_health_subsystem(
+            "terminalSessions",
+            "error",
+            "Terminal state is not available.".to_string(),
+        );
+    };
+    let (sessions, hibernated) = {
+        let sessions_state = state.lock_sessions();
+        let hibernated = sessions_state
+            .sessions_by_id
+            .values()
+            .filter(|session| session.pty.is_none())
+            .count();
+        (sessions_state.sessions_by_id.len(), hibernated)
+    };
+    let limits = terminal_memory_limits();
+    let used_bytes = TERMINAL_SNAPSHOT_USED_BYTES.load(Ordering::Relaxed);
+    app_health_subsystem(
+        "terminalSessions",
+        "ok",
+        format!(
+            "{sessions} open ({hibernated} hibernated), {:.1} of {:.1} MiB snapshot budget used.",
+            used_bytes as f64 / (1024.0 * 1024.0),
+            limits.snapshot_budget_bytes as f64 / (1024.0 * 1024.0)
+        ),
+    )
+}
+
+fn caches_health(app: &AppHandle) -> AppHealthSubsystem {
+    let workspace_contexts = app
+        .try_state::<WorkspaceContextCacheState>()
+        .map_or(0, |state| state.lock_entries().len());
+    let terminal_resolutions = app
+        .try_state::<TerminalResolutionCacheState>()
+        .map_or(0, |state| state.lock_entries().len());
+    let (groove_lists, groove_lists_in_flight) = app
+        .try_state::<GrooveListCacheState>()
+        .map_or((0, 0), |state| {
+            (state.lock_entries().len(), state.lock_in_flight().len())
+        });
+    app_health_subsystem(
+        "caches",
+        "ok",
+        format!(
+            "Workspace contexts {workspace_contexts}, terminal resolutions {terminal_resolutions}, groove lists {groove_lists} ({groove_lists_in_flight} in flight)."
+        ),
+    )
+}
+
+fn probe_directory_writable(dir: &Path) -> Result<(), String> {
+    let probe = dir.join(format!(".health-probe-{}", Uuid::new_v4()));
+    fs::write(&probe, b"ok")
+        .map_err(|error| format!("{} is not writable: {error}", dir.display()))?;
+    let _ = fs::remove_file(&probe);
+    Ok(())
+}
+
+fn state_files_health(app: &AppHandle) -> AppHealthSubsystem {
+    let mut problems = Vec::new();
+    let mut checked = Vec::new();
+    match workspace_state_file(app) {
+        Ok(path) => {
+            let app_data_dir = path.parent().unwrap_or(&path);
+            match probe_directory_writable(app_data_dir) {
+                Ok(()) => checked.push("app data"),
+                Err(error) => problems.push(error),
+            }
+        }
+        Err(error) => problems.push(error),
+    }
+    match ensure_global_settings(app) {
+        Ok(_) => checked.push("global settings"),
+        Err(error) => problems.push(error),
+    }
+    match read_persisted_active_workspace_root(app) {
+        Ok(Some(workspace_root)) => {
+            let groove_dir = Path::new(&workspace_root).join(".groove");
+            if path_is_directory(&groove_dir) {
+                match probe_directory_writable(&groove_dir) {
+                    Ok(()) => checked.push("workspace .groove"),
+                    Err(error) => problems.push(error),
+                }
+            }
+        }
+        Ok(None) => {}
+        Err(error) => problems.push(error),
+    }
+
+    if problems.is_empty() {
+        app_health_subsystem(
+            "stateFiles",
+            "ok",
+            format!("Writable: {}.", checked.join(", ")),
+        )
+    } else {
+        app_health_subsystem("stateFiles", "error", problems.join(" "))
+    }
+}
+
+fn groove_bin_health(app: &AppHandle) -> AppHealthSubsystem {
+    let status = app
+        .try_state::<GrooveBinStatusState>()
+        .and_then(|state| state.lock_status().clone())
+        .unwrap_or_else(|| evaluate_groove_bin_check_status(app));
+    if status.has_issue {
+        app_health_subsystem(
+            "grooveBin",
+            "warning",
+            status
+                .issue
+                .unwrap_or_else(|| "The groove binary has an issue.".to_string()),
+        )
+    } else {
+        app_health_subsystem(
+            "grooveBin",
+            "ok",
+            format!(
+                "{} ({}).",
+                status.effective_binary_path, status.effective_binary_source
+            ),
+        )
+    }
+}
+
+fn state_locks_health() -> AppHealthSubsystem {
+    let recoveries = STATE_LOCK_RECOVERIES.load(Ordering::Relaxed);
+    if recoveries == 0 {
+        app_health_subsystem("stateLocks", "ok", "No poisoned locks.".to_string())
+    } else {
+        app_health_subsystem(
+            "stateLocks",
+            "warning",
+            format!("Recovered {recoveries} poisoned lock(s) since startup."),
+        )
+    }
+}
+
+fn background_activity_health() -> AppHealthSubsystem {
+    match background_paused_since() {
+        Some(paused_since) => app_health_subsystem(
+            "backgroundActivity",
+            "ok",
+            format!("Paused since {paused_since}."),
+        ),
+        None => app_health_subsystem("backgroundActivity", "ok", "Running.".to_string()),
+    }
+}
+
+fn collect_app_health(app: &AppHandle) -> Vec<AppHealthSubsystem> {
+    vec![
+        workspace_worker_health(app),
+        terminal_sessions_health(app),
+        caches_health(app),
+        state_files_health(app),
+        groove_bin_health(app),
+        state_locks_health(),
+        background_activity_health(),
+    ]
+}
+
+fn backend_heartbeat(
+    app: &AppHandle,
+    sequence: u64,
+    started_at: Instant,
+) -> BackendHeartbeatEvent {
+    let (workspace_worker_generation, workspace_worker_running, workspace_worker_idle_ms) = app
+        .try_state::<WorkspaceEventState>()
+        .map_or((0, false, None), |state| {
+            let generation = state.worker_generation.load(Ordering::Relaxed);
+            // A poisoned worker lock reads as no worker; `app_health` has
+            // the details. With several workers, the longest idle counts.
+            let guard = state.workers.lock().ok();
+            let workers = guard.iter().flat_map(|workers| workers.values());
+            let running = workers.clone().any(|worker| !worker.handle.is_finished());
+            let idle_ms = workers
+                .map(|worker| {
+                    worker
+                        .last_poll_at
+                        .lock()
+                        .map(|last_poll_at| last_poll_at.elapsed().as_millis() as u64)
+                        .unwrap_or_default()
+                })
+                .max();
+            (generation, running, idle_ms)
+        });
+    let groove_lists_in_flight = app
+        .try_state::<GrooveListCacheState>()
+        .map_or(0, |state| state.lock_in_flight().len());
+    let command_runs_running = app.try_state::<CommandRunsState>().map_or(0, |state| {
+        state
+            .lock_runs()
+            .iter()
+            .filter(|run| matches!(run.status, CommandRunStatus::Running))
+            .count()
+    });
+    let terminal_sessions = app
+        .try_state::<GrooveTerminalState>()
+        .map_or(0, |state| state.lock_sessions().sessions_by_id.len());
+
+    BackendHeartbeatEvent {
+        sequence,
+        uptime_ms: started_at.elapsed().as_millis() as u64,
+        sent_at: now_iso(),
+        workspace_worker_generation,
+        workspace_worker_running,
+        workspace_worker_idle_ms,
+        commands_in_flight: COMMANDS_IN_FLIGHT.load(Ordering::Relaxed),
+        git_operations_pending: pending_git_operations(),
+        groove_lists_in_flight,
+        command_runs_running,
+        terminal_sessions,
+    }
+}
+
+/// Emits `backend-heartbeat` every `BACKEND_HEARTBEAT_INTERVAL` from startup.
+fn start_backend_heartbeat(app: AppHandle) {
+    let started_at = Instant::now();
+    thread::spawn(move || {
+        let mut sequence = 0;
+        loop {
+            thread::sleep(BACKEND_HEARTBEAT_INTERVAL);
+            if background_activity_paused() {
+                continue;
+            }
+            sequence += 1;
+            emit_groove_event(&app, &backend_heartbeat(&app, sequence, started_at));
+        }
+    });
+}
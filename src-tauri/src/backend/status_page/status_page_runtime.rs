@@ -0,0 +1,384 @@
+// Optional HTTP status page for checking on Groove without the window, e.g.
+// from a phone or a status bar widget. `/status.json` serves a
+// `StatusPageSnapshot` (workspaces, running grooves, testing environments and
+// health) and `/` the same as a minimal HTML page. It only answers GET, and
+// follows `GlobalSettings.status_page_settings`: every write of the global
+// settings starts, moves or stops the listener. Requests need an API token
+// with `read` once any token exists, and always when they come from another
+// machine; browsers can pass it as `?token=`.
+
+struct RunningStatusPage {
+    settings: StatusPageSettings,
+    server: Arc<tiny_http::Server>,
+    accept_thread: JoinHandle<()>,
+}
+
+#[derive(Default)]
+struct StatusPageState {
+    /// Mirrored from the global settings; applied once the app handle is
+    /// installed by `start_status_page_server`.
+    settings: StatusPageSettings,
+    app: Option<AppHandle>,
+    running: Option<RunningStatusPage>,
+    /// Why the page is not listening although enabled.
+    error: Option<String>,
+}
+
+static STATUS_PAGE_STATE: std::sync::OnceLock<Mutex<StatusPageState>> = std::sync::OnceLock::new();
+
+fn status_page_state() -> MutexGuard<'static, StatusPageState> {
+    STATUS_PAGE_STATE
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+}
+
+fn set_status_page_settings(settings: &StatusPageSettings) {
+    let mut state = status_page_state();
+    state.settings = *settings;
+    apply_status_page_settings(&mut state);
+}
+
+fn start_status_page_server(app: AppHandle) {
+    let mut state = status_page_state();
+    state.app = Some(app);
+    apply_status_page_settings(&mut state);
+}
+
+fn status_page_host(settings: &StatusPageSettings) -> &'static str {
+    if settings.allow_remote {
+        "0.0.0.0"
+    } else {
+        "127.0.0.1"
+    }
+}
+
+/// Brings the listener in line with `state.settings`, restarting it when
+/// the port or interface changed.
+fn apply_status_page_settings(state: &mut StatusPageState) {
+    let Some(app) = state.app.clone() else {
+        return;
+    };
+    let wanted = Some(state.settings).filter(|settings| settings.enabled);
+    if state.running.as_ref().map(|running| running.settings) == wanted {
+        return;
+    }
+    if let Some(running) = state.running.take() {
+        running.server.unblock();
+        // The listener closes once the accept thread drops the server.
+        let _ = running.accept_thread.join();
+        log_line("[status-page] stopped.");
+    }
+    state.error = None;
+    let Some(settings) = wanted else {
+        return;
+    };
+
+    let host = status_page_host(&settings);
+    let server = match tiny_http::Server::http((host, settings.port)) {
+        Ok(server) => Arc::new(server),
+        Err(error) => {
+            let error = format!("Failed to listen on {host}:{}: {error}", settings.port);
+            log_line(&format!("[status-page] {error}"));
+            state.error = Some(error);
+            return;
+        }
+    };
+    log_line(&format!(
+        "[status-page] listening on http://{host}:{}/",
+        settings.port
+    ));
+    let accept_server = server.clone();
+    let accept_thread = thread::spawn(move || {
+        // `recv` fails once the server is unblocked.
+        while let Ok(request) = accept_server.recv() {
+            let app = app.clone();
+            thread::spawn(move || handle_status_page_request(&app, request));
+        }
+    });
+    state.running = Some(RunningStatusPage {
+        settings,
+        server,
+        accept_thread,
+    });
+}
+
+/// Settings, whether the page listens, its URL and the last bind error.
+fn status_page_listener_status() -> (StatusPageSettings, bool, Option<String>, Option<String>) {
+    let state = status_page_state();
+    let url = state
+        .running
+        .as_ref()
+        .map(|running| format!("http://127.0.0.1:{}/", running.settings.port));
+    (
+        state.settings,
+        state.running.is_some(),
+        url,
+        state.error.clone(),
+    )
+}
+
+fn status_page_snapshot(app: &AppHandle) -> StatusPageSnapshot {
+    let health = collect_app_health(app);
+    let status = health
+        .iter()
+        .map(|subsystem| subsystem.status.as_str())
+        .max_by_key(|status| app_health_status_rank(status))
+        .unwrap_or("ok")
+        .to_string();
+    let workspaces = read_persisted_workspace_state(app).unwrap_or_default();
+    let mut running_grooves = read_persisted_worktree_execution_state(app)
+        .map(|state| {
+            state
+                .running_by_workspace
+                .into_values()
+                .flat_map(HashMap::into_values)
+                .map(|record| StatusPageRunningGroove {
+                    workspace_root: record.workspace_root,
+                    worktree: record.worktree,
+                    command: record.command,
+                    started_at: record.started_at,
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    running_grooves.sort_by(|left, right| {
+        (&left.workspace_root, &left.worktree).cmp(&(&right.workspace_root, &right.worktree))
+    });
+
+    StatusPageSnapshot {
+        app_version: app.package_info().version.to_string(),
+        generated_at: now_iso(),
+        status,
+        active_workspace_root: workspaces.workspace_root,
+        open_workspaces: workspaces
+            .open_workspaces
+            .into_iter()
+            .map(|record| record.workspace_root)
+            .collect(),
+        running_grooves,
+        testing_environments: testing_environment_infos(),
+        health,
+    }
+}
+
+fn escape_status_page_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn render_status_page_html(snapshot: &StatusPageSnapshot) -> String {
+    let list = |items: Vec<String>| {
+        if items.is_empty() {
+            "<p>None.</p>".to_string()
+        } else {
+            format!("<ul>{}</ul>", items.concat())
+        }
+    };
+    let workspaces = list(
+        snapshot
+            .open_workspaces
+            .iter()
+            .map(|root| {
+                let active = snapshot.active_workspace_root.as_deref() == Some(root.as_str());
+                format!(
+                    "<li>{}{}</li>",
+                    escape_status_page_html(root),
+                    if active { " (active)" } else { "" }
+                )
+            })
+            .collect(),
+    );
+    let grooves = list(
+        snapshot
+            .running_grooves
+            .iter()
+            .map(|groove| {
+                format!(
+                    "<li>{} in {}: <code>{}</code> since {}</li>",
+                    escape_status_page_html(&groove.worktree),
+                    escape_status_page_html(&groove.workspace_root),
+                    escape_status_page_html(&groove.command),
+                    escape_status_page_html(&groove.started_at)
+                )
+            })
+            .collect(),
+    );
+    let environments = list(
+        snapshot
+            .testing_environments
+            .iter()
+            .map(|environment| {
+                format!(
+                    "<li>{}: {} ({} restarts)</li>",
+                    escape_status_page_html(&environment.worktree),
+                    escape_status_page_html(&environment.status),
+                    environment.restarts
+                )
+            })
+            .collect(),
+    );
+    let health = list(
+        snapshot
+            .health
+            .iter()
+            .map(|subsystem| {
+                format!(
+                    "<li>{}: {} — {}</li>",
+                    escape_status_page_html(&subsystem.name),
+                    escape_status_page_html(&subsystem.status),
+                    escape_status_page_html(&subsystem.summary)
+                )
+            })
+            .collect(),
+    );
+
+    format!(
+        "<!doctype html>\n<html><head><meta charset=\"utf-8\">\
+         <meta http-equiv=\"refresh\" content=\"{STATUS_PAGE_REFRESH_SECONDS}\">\
+         <title>Groove: {status}</title></head><body>\
+         <h1>Groove {version}: {status}</h1><p>As of {generated_at}</p>\
+         <h2>Workspaces</h2>{workspaces}<h2>Running grooves</h2>{grooves}\
+         <h2>Testing environments</h2>{environments}<h2>Health</h2>{health}\
+         </body></html>\n",
+        status = escape_status_page_html(&snapshot.status),
+        version = escape_status_page_html(&snapshot.app_version),
+        generated_at = escape_status_page_html(&snapshot.generated_at),
+    )
+}
+
+/// The `token` query parameter, for browsers that cannot send headers.
+fn status_page_query_token(url: &str) -> Option<String> {
+    let (_, query) = url.split_once('?')?;
+    query
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("token="))
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .map(str::to_string)
+}
+
+fn status_page_response(
+    status: u16,
+    content_type: &str,
+    body: String,
+) -> tiny_http::Response<std::io::Cursor<Vec<u8>>> {
+    let mut response = tiny_http::Response::from_string(body).with_status_code(status);
+    if let Ok(header) = tiny_http::Header::from_bytes(&b"Content-Type"[..], content_type.as_bytes())
+    {
+        response = response.with_header(header);
+    }
+    response
+}
+
+fn status_page_error_response(
+    status: u16,
+    error: &str,
+) -> tiny_http::Response<std::io::Cursor<Vec<u8>>> {
+    status_page_response(
+        status,
+        "application/json",
+        serde_json::json!({ "error": error }).to_string(),
+    )
+}
+
+fn handle_status_page_request(app: &AppHandle, request: tiny_http::Request) {
+    if *request.method() != tiny_http::Method::Get {
+        let _ = request.respond(status_page_error_response(405, "Only GET is supported."));
+        return;
+    }
+
+    let remote = request
+        .remote_addr()
+        .is_some_and(|address| !address.ip().is_loopback());
+    let token =
+        groove_mcp_bearer_token(&request).or_else(|| status_page_query_token(request.url()));
+    if remote && token.is_none() {
+        let _ = request.respond(status_page_error_response(
+            401,
+            "An API token is required from other machines.",
+        ));
+        return;
+    }
+    let authorized = api_token_capabilities(app, token.as_deref(), remote).and_then(|granted| {
+        capabilities::require_capability(&granted, "status_page", Capability::Read)
+    });
+    if let Err(error) = authorized {
+        let _ = request.respond(status_page_error_response(401, &error));
+        return;
+    }
+
+    let path = request
+        .url()
+        .split('?')
+        .next()
+        .unwrap_or_default()
+        .to_string();
+    let response = match path.as_str() {
+        "/status.json" => match serde_json::to_string_pretty(&status_page_snapshot(app)) {
+            Ok(body) => status_page_response(200, "application/json", body),
+            Err(error) => status_page_error_response(500, &error.to_string()),
+        },
+        "/" => status_page_response(
+            200,
+            "text/html; charset=utf-8",
+            render_status_page_html(&status_page_snapshot(app)),
+        ),
+        _ => status_page_error_response(404, "Not found. Try / or /status.json."),
+    };
+    let _ = request.respond(response);
+}
+
+#[cfg(test)]
+mod status_page_runtime_tests {
+    use super::*;
+
+    #[test]
+    fn query_token_is_read_from_the_url() {
+        assert_eq!(
+            status_page_query_token("/status.json?a=1&token=gv_abc"),
+            Some("gv_abc".to_string())
+        );
+        assert_eq!(status_page_query_token("/?a=1"), None);
+        assert_eq!(status_page_query_token("/status.json?token="), None);
+        assert_eq!(status_page_query_token("/?token=&a=1"), None);
+        assert_eq!(status_page_query_token("/"), None);
+    }
+
+    #[test]
+    fn remote_requests_need_a_stored_token_even_when_none_exist() {
+        assert!(stored_api_token_capabilities(&[], Some(""), true).is_err());
+        assert!(stored_api_token_capabilities(&[], None, true).is_err());
+        assert!(stored_api_token_capabilities(&[], Some("gv_guess"), true).is_err());
+        assert_eq!(
+            stored_api_token_capabilities(&[], Some(" "), false),
+            Ok(Capability::DEFAULT.to_vec())
+        );
+    }
+
+    #[test]
+    fn html_escapes_values_from_state_files() {
+        let snapshot = StatusPageSnapshot {
+            app_version: "1.0.0".to_string(),
+            generated_at: "2026-01-01T00:00:00Z".to_string(),
+            status: "ok".to_string(),
+            active_workspace_root: None,
+            open_workspaces: Vec::new(),
+            running_grooves: vec![StatusPageRunningGroove {
+                workspace_root: "/repo".to_string(),
+                worktree: "<script>".to_string(),
+                command: "npm run dev".to_string(),
+                started_at: "2026-01-01T00:00:00Z".to_string(),
+            }],
+            testing_environments: Vec::new(),
+            health: Vec::new(),
+        };
+
+        let html = render_status_page_html(&snapshot);
+        assert!(html.contains("&lt;script&gt;"));
+        assert!(!html.contains("<script>"));
+    }
+}
@@ -0,0 +1,365 @@
+// Output of custom Play commands, which usually start dev servers. Their
+// stdout and stderr are piped into `.groove/testing-logs/<worktree>/play.log`
+// instead of being dropped; once the file reaches `TESTING_LOG_ROTATE_BYTES`
+// it moves to `play.log.1` (and so on, up to `TESTING_LOG_ROTATIONS`). While
+// a worktree's log is followed, each line is also sent as a
+// `testing-environment-log` event. Play sessions opened in a Groove terminal
+// keep their output in the terminal scrollback instead.
+
+struct TestingLogWriter {
+    path: PathBuf,
+    file: Option<fs::File>,
+    bytes: u64,
+}
+
+impl TestingLogWriter {
+    fn open(path: PathBuf) -> Result<Self, String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|error| format!("Failed to create {}: {error}", parent.display()))?;
+        }
+        let file = open_testing_log_file(&path)?;
+        let bytes = file.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+        Ok(Self {
+            path,
+            file: Some(file),
+            bytes,
+        })
+    }
+
+    fn write_line(&mut self, line: &str) {
+        let line_bytes = line.len() as u64 + 1;
+        if self.bytes > 0 && self.bytes + line_bytes > TESTING_LOG_ROTATE_BYTES {
+            self.file = None;
+            rotate_testing_log_files(&self.path);
+            self.file = open_testing_log_file(&self.path).ok();
+            self.bytes = 0;
+        }
+        if let Some(file) = self.file.as_mut() {
+            if writeln!(file, "{line}").is_ok() {
+                self.bytes += line_bytes;
+            }
+        }
+    }
+
+    fn clear(&mut self) -> Result<(), String> {
+        if let Some(file) = self.file.as_mut() {
+            file.set_len(0)
+                .map_err(|error| format!("Failed to clear {}: {error}", self.path.display()))?;
+        }
+        self.bytes = 0;
+        Ok(())
+    }
+}
+
+/// Writers of Play commands still running, by workspace root and worktree.
+static TESTING_LOG_WRITERS: std::sync::OnceLock<
+    Mutex<HashMap<String, Arc<Mutex<TestingLogWriter>>>>,
+> = std::sync::OnceLock::new();
+
+/// Testing logs whose lines are sent as events, by workspace root and
+/// worktree.
+static FOLLOWED_TESTING_LOGS: std::sync::OnceLock<Mutex<HashSet<String>>> =
+    std::sync::OnceLock::new();
+
+fn testing_log_writers() -> MutexGuard<'static, HashMap<String, Arc<Mutex<TestingLogWriter>>>> {
+    TESTING_LOG_WRITERS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+}
+
+fn followed_testing_logs() -> MutexGuard<'static, HashSet<String>> {
+    FOLLOWED_TESTING_LOGS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+}
+
+fn testing_logs_dir(workspace_root: &Path, worktree: &str) -> PathBuf {
+    workspace_root
+        .join(".groove")
+        .join(TESTING_LOGS_DIR)
+        .join(worktree)
+}
+
+fn open_testing_log_file(path: &Path) -> Result<fs::File, String> {
+    fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|error| format!("Failed to open {}: {error}", path.display()))
+}
+
+/// `play.log.<index>`; index 0 is `play.log` itself.
+fn rotated_testing_log_path(path: &Path, index: usize) -> PathBuf {
+    if index == 0 {
+        return path.to_path_buf();
+    }
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    path.with_file_name(format!("{file_name}.{index}"))
+}
+
+fn rotate_testing_log_files(path: &Path) {
+    for index in (0..TESTING_LOG_ROTATIONS).rev() {
+        let from = rotated_testing_log_path(path, index);
+        if path_is_file(&from) {
+            let _ = fs::rename(&from, rotated_testing_log_path(path, index + 1));
+        }
+    }
+}
+
+/// The last `lines` lines of the log, reading rotated files as far back as
+/// needed. Oldest first.
+fn tail_testing_log(dir: &Path, lines: usize) -> Vec<String> {
+    let path = dir.join(TESTING_LOG_FILE);
+    let mut tail = Vec::new();
+    for index in 0..=TESTING_LOG_ROTATIONS {
+        if tail.len() >= lines {
+            break;
+        }
+        let Ok(raw) = fs::read(rotated_testing_log_path(&path, index)) else {
+            continue;
+        };
+        let contents = String::from_utf8_lossy(&raw);
+        let missing = lines - tail.len();
+        let mut older = contents
+            .lines()
+            .rev()
+            .take(missing)
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        older.reverse();
+        older.append(&mut tail);
+        tail = older;
+    }
+    tail
+}
+
+fn append_testing_log_line(
+    key: &str,
+    workspace_root: &Path,
+    worktree: &str,
+    writer: &Mutex<TestingLogWriter>,
+    stream: &str,
+    line: &str,
+) {
+    writer
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+        .write_line(line);
+    if followed_testing_logs().contains(key) {
+        emit_background_groove_event(&TestingEnvironmentLogEvent {
+            workspace_root: workspace_root.display().to_string(),
+            worktree: worktree.to_string(),
+            stream: stream.to_string(),
+            line: line.to_string(),
+        });
+    }
+}
+
+/// Drops the worktree's writer from `TESTING_LOG_WRITERS` unless another
+/// Play command of the worktree still holds it.
+fn release_testing_log_writer(key: &str, writer: &Arc<Mutex<TestingLogWriter>>) {
+    let mut writers = testing_log_writers();
+    // The map's reference and the caller's are the only ones left.
+    if writers
+        .get(key)
+        .is_some_and(|current| Arc::ptr_eq(current, writer) && Arc::strong_count(writer) == 2)
+    {
+        writers.remove(key);
+    }
+}
+
+/// Starts a custom Play command with its output going to the worktree's
+/// testing log. The child is waited on in the background, and its exit is
+/// written to the log too.
+fn spawn_testing_environment_process(
+    workspace_root: &Path,
+    worktree: &str,
+    program: &str,
+    args: &[String],
+    worktree_path: &Path,
+) -> Result<(), String> {
+    let key = testing_environment_key(&workspace_root.display().to_string(), worktree);
+    let writer = {
+        let mut writers = testing_log_writers();
+        match writers.get(&key) {
+            Some(writer) => writer.clone(),
+            None => {
+                let log_path = testing_logs_dir(workspace_root, worktree).join(TESTING_LOG_FILE);
+                let writer = Arc::new(Mutex::new(TestingLogWriter::open(log_path)?));
+                writers.insert(key.clone(), writer.clone());
+                writer
+            }
+        }
+    };
+
+    let child = terminal_process_command(program, args, worktree_path, worktree_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(error) => {
+            release_testing_log_writer(&key, &writer);
+            return Err(error.to_string());
+        }
+    };
+
+    let readers = [
+        (
+            "stdout",
+            child
+                .stdout
+                .take()
+                .map(|pipe| Box::new(pipe) as Box<dyn Read + Send>),
+        ),
+        (
+            "stderr",
+            child
+                .stderr
+                .take()
+                .map(|pipe| Box::new(pipe) as Box<dyn Read + Send>),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(stream, pipe)| pipe.map(|pipe| (stream, pipe)))
+    .map(|(stream, pipe)| {
+        let key = key.clone();
+        let workspace_root = workspace_root.to_path_buf();
+        let worktree = worktree.to_string();
+        let writer = writer.clone();
+        thread::spawn(move || {
+            let mut reader = std::io::BufReader::new(pipe);
+            let mut buffer = Vec::new();
+            loop {
+                buffer.clear();
+                match std::io::BufRead::read_until(&mut reader, b'\n', &mut buffer) {
+                    Ok(0) | Err(_) => break,
+                    Ok(_) => {
+                        let line = String::from_utf8_lossy(&buffer);
+                        let line = line.trim_end_matches(['\r', '\n']);
+                        append_testing_log_line(
+                            &key,
+                            &workspace_root,
+                            &worktree,
+                            &writer,
+                            stream,
+                            line,
+                        );
+                    }
+                }
+            }
+        })
+    })
+    .collect::<Vec<_>>();
+
+    let workspace_root = workspace_root.to_path_buf();
+    let worktree = worktree.to_string();
+    thread::spawn(move || {
+        for reader in readers {
+            let _ = reader.join();
+        }
+        let status = match child.wait() {
+            Ok(status) => status.to_string(),
+            Err(error) => format!("unknown status ({error})"),
+        };
+        append_testing_log_line(
+            &key,
+            &workspace_root,
+            &worktree,
+            &writer,
+            "stderr",
+            &format!("[groove] Play command exited: {status}"),
+        );
+        release_testing_log_writer(&key, &writer);
+    });
+    Ok(())
+}
+
+/// Starts or stops sending the worktree's log lines as events.
+fn set_testing_log_follow(workspace_root: &Path, worktree: &str, follow: bool) {
+    let key = testing_environment_key(&workspace_root.display().to_string(), worktree);
+    let mut followed = followed_testing_logs();
+    if follow {
+        followed.insert(key);
+    } else {
+        followed.remove(&key);
+    }
+}
+
+fn clear_testing_log(workspace_root: &Path, worktree: &str) -> Result<(), String> {
+    let key = testing_environment_key(&workspace_root.display().to_string(), worktree);
+    let dir = testing_logs_dir(workspace_root, worktree);
+    let path = dir.join(TESTING_LOG_FILE);
+    for index in 1..=TESTING_LOG_ROTATIONS {
+        let rotated = rotated_testing_log_path(&path, index);
+        if path_is_file(&rotated) {
+            fs::remove_file(&rotated)
+                .map_err(|error| format!("Failed to remove {}: {error}", rotated.display()))?;
+        }
+    }
+    let writer = testing_log_writers().get(&key).cloned();
+    match writer {
+        Some(writer) => writer
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+            .clear(),
+        None if path_is_file(&path) => fs::remove_file(&path)
+            .map_err(|error| format!("Failed to remove {}: {error}", path.display())),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod logs_runtime_tests {
+    use super::*;
+
+    fn test_log_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("groove-testing-logs-test-{}", Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn rotation_shifts_files_and_drops_the_oldest() {
+        let dir = test_log_dir();
+        let path = dir.join(TESTING_LOG_FILE);
+        for index in 0..=TESTING_LOG_ROTATIONS {
+            fs::write(rotated_testing_log_path(&path, index), format!("{index}\n")).unwrap();
+        }
+
+        rotate_testing_log_files(&path);
+
+        assert!(!path_is_file(&path));
+        for index in 1..=TESTING_LOG_ROTATIONS {
+            assert_eq!(
+                fs::read_to_string(rotated_testing_log_path(&path, index)).unwrap(),
+                format!("{}\n", index - 1)
+            );
+        }
+        assert!(!path_is_file(&rotated_testing_log_path(
+            &path,
+            TESTING_LOG_ROTATIONS + 1
+        )));
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn tail_reads_into_rotated_files_when_the_current_one_is_short() {
+        let dir = test_log_dir();
+        let path = dir.join(TESTING_LOG_FILE);
+        fs::write(rotated_testing_log_path(&path, 1), "a\nb\nc\n").unwrap();
+        fs::write(&path, "d\ne\n").unwrap();
+
+        assert_eq!(tail_testing_log(&dir, 3), vec!["c", "d", "e"]);
+        assert_eq!(tail_testing_log(&dir, 1), vec!["e"]);
+        assert_eq!(tail_testing_log(&dir, 10).len(), 5);
+        let _ = fs::remove_dir_all(dir);
+    }
+}
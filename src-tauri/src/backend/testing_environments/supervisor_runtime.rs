@@ -0,0 +1,426 @@
+// Testing environments are the Play sessions recorded as running grooves.
+// When one ends on its own, the terminal reader (or the reaper) reports it
+// here; an exit that is not a clean one counts as a crash and is kept with
+// its reason. With the restart policy of the global settings enabled, a
+// supervisor thread started with the app opens the session again after a
+// backoff that doubles with each restart in a row, and gives up after
+// `max_restarts`. Every change is sent as a `testing-environment-status`
+// event. Nothing here is persisted: a crash seen before the app restarted is
+// forgotten.
+
+/// Testing environments seen crashing, by workspace root and worktree.
+static SUPERVISED_TESTING_ENVIRONMENTS: std::sync::OnceLock<
+    Mutex<HashMap<String, SupervisedTestingEnvironment>>,
+> = std::sync::OnceLock::new();
+
+fn supervised_testing_environments(
+) -> MutexGuard<'static, HashMap<String, SupervisedTestingEnvironment>> {
+    SUPERVISED_TESTING_ENVIRONMENTS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+}
+
+struct SupervisedTestingEnvironment {
+    /// The running record of the session that crashed last, reused to open
+    /// it again.
+    record: RunningGrooveRecord,
+    open_mode: GrooveTerminalOpenMode,
+    status: &'static str,
+    restarts: u32,
+    restart_at: Option<Instant>,
+    next_restart_at: Option<String>,
+    /// When the last restart opened its session.
+    restarted_at: Option<Instant>,
+    /// Set when the restarted session was not found on the previous tick.
+    missing_since_previous_tick: bool,
+    crashes: Vec<TestingEnvironmentCrash>,
+}
+
+impl SupervisedTestingEnvironment {
+    fn info(&self) -> TestingEnvironmentInfo {
+        TestingEnvironmentInfo {
+            workspace_root: self.record.workspace_root.clone(),
+            worktree: self.record.worktree.clone(),
+            status: self.status.to_string(),
+            restarts: self.restarts,
+            session_id: (self.status == "running").then(|| self.record.session_id.clone()),
+            next_restart_at: self.next_restart_at.clone(),
+            crashes: self.crashes.clone(),
+        }
+    }
+
+    fn status_event(
+        &self,
+        status: &str,
+        crash_reason: Option<String>,
+    ) -> TestingEnvironmentStatusEvent {
+        TestingEnvironmentStatusEvent {
+            workspace_root: self.record.workspace_root.clone(),
+            worktree: self.record.worktree.clone(),
+            status: status.to_string(),
+            restarts: self.restarts,
+            session_id: (status == "running").then(|| self.record.session_id.clone()),
+            crash_reason,
+            next_restart_at: self.next_restart_at.clone(),
+        }
+    }
+}
+
+fn testing_environment_key(workspace_root: &str, worktree: &str) -> String {
+    format!(
+        "{}\n{}",
+        workspace_root_storage_key(Path::new(workspace_root)),
+        worktree
+    )
+}
+
+/// Wait before restart number `restarts + 1`.
+fn testing_environment_restart_delay(
+    policy: &TestingEnvironmentRestartPolicy,
+    restarts: u32,
+) -> Duration {
+    let factor = 1u64.checked_shl(restarts).unwrap_or(u64::MAX);
+    Duration::from_millis(policy.backoff_ms.saturating_mul(factor))
+        .min(MAX_TESTING_ENVIRONMENT_RESTART_DELAY)
+}
+
+/// Records a crash and schedules a restart when the policy allows one,
+/// returning the status the environment is left in.
+fn record_testing_environment_crash(
+    environment: &mut SupervisedTestingEnvironment,
+    policy: &TestingEnvironmentRestartPolicy,
+    reason: &str,
+) -> &'static str {
+    if environment
+        .restarted_at
+        .is_some_and(|restarted_at| restarted_at.elapsed() >= TESTING_ENVIRONMENT_STABLE_AFTER)
+    {
+        environment.restarts = 0;
+    }
+    environment.restarted_at = None;
+    environment.missing_since_previous_tick = false;
+    environment.crashes.push(TestingEnvironmentCrash {
+        at: now_iso(),
+        session_id: environment.record.session_id.clone(),
+        reason: reason.to_string(),
+    });
+    let excess = environment
+        .crashes
+        .len()
+        .saturating_sub(TESTING_ENVIRONMENT_CRASH_HISTORY);
+    environment.crashes.drain(..excess);
+
+    if !policy.enabled {
+        environment.restart_at = None;
+        environment.next_restart_at = None;
+        environment.status = "crashed";
+    } else if environment.restarts >= policy.max_restarts {
+        environment.restart_at = None;
+        environment.next_restart_at = None;
+        environment.status = "failed";
+    } else {
+        let delay = testing_environment_restart_delay(policy, environment.restarts);
+        environment.restart_at = Some(Instant::now() + delay);
+        environment.next_restart_at = (OffsetDateTime::now_utc() + delay).format(&Rfc3339).ok();
+        environment.status = "restarting";
+    }
+    environment.status
+}
+
+fn testing_environment_restart_policy(app: &AppHandle) -> TestingEnvironmentRestartPolicy {
+    ensure_global_settings(app)
+        .map(|settings| settings.testing_environment_restart_policy)
+        .unwrap_or_default()
+}
+
+/// Called once a session recorded as a running groove has ended without
+/// being closed by the user. `crash_reason` is `None` for a clean exit,
+/// which only matters to an environment the supervisor restarted.
+fn report_testing_environment_exit(
+    app: &AppHandle,
+    record: RunningGrooveRecord,
+    open_mode: GrooveTerminalOpenMode,
+    crash_reason: Option<String>,
+) {
+    let key = testing_environment_key(&record.workspace_root, &record.worktree);
+    let Some(reason) = crash_reason else {
+        let stopped = supervised_testing_environments().remove(&key);
+        if let Some(mut environment) = stopped {
+            environment.next_restart_at = None;
+            emit_groove_event(app, &environment.status_event("stopped", None));
+        }
+        return;
+    };
+
+    let policy = testing_environment_restart_policy(app);
+    let event = {
+        let mut environments = supervised_testing_environments();
+        let environment = environments
+            .entry(key)
+            .or_insert_with(|| SupervisedTestingEnvironment {
+                record: record.clone(),
+                open_mode,
+                status: "crashed",
+                restarts: 0,
+                restart_at: None,
+                next_restart_at: None,
+                restarted_at: None,
+                missing_since_previous_tick: false,
+                crashes: Vec::new(),
+            });
+        environment.record = record;
+        environment.open_mode = open_mode;
+        let status = record_testing_environment_crash(environment, &policy, &reason);
+        environment.status_event(status, Some(reason))
+    };
+    log_play_telemetry(
+        telemetry_enabled_for_app(app),
+        "testing_environment.crashed",
+        format!(
+            "workspace_root={} worktree={} status={} restarts={} reason={}",
+            event.workspace_root,
+            event.worktree,
+            event.status,
+            event.restarts,
+            event.crash_reason.as_deref().unwrap_or_default()
+        )
+        .as_str(),
+    );
+    emit_groove_event(app, &event);
+}
+
+fn testing_environment_infos() -> Vec<TestingEnvironmentInfo> {
+    let mut environments = supervised_testing_environments()
+        .values()
+        .map(SupervisedTestingEnvironment::info)
+        .collect::<Vec<_>>();
+    environments.sort_by(|left, right| {
+        (&left.workspace_root, &left.worktree).cmp(&(&right.workspace_root, &right.worktree))
+    });
+    environments
+}
+
+/// Opens the session of a testing environment again. Gives up without
+/// opening anything when the worktree is gone or something else started it.
+fn restart_testing_environment(
+    app: &AppHandle,
+    record: &RunningGrooveRecord,
+    open_mode: GrooveTerminalOpenMode,
+) -> Result<Option<String>, String> {
+    let workspace_root = Path::new(&record.workspace_root);
+    let worktree_path = Path::new(&record.worktree_path);
+    if !path_is_directory(worktree_path) {
+        return Ok(None);
+    }
+    if read_running_grooves(app, workspace_root)?
+        .iter()
+        .any(|running| running.worktree == record.worktree)
+    {
+        return Ok(None);
+    }
+
+    let terminal_state = app.state::<GrooveTerminalState>();
+    let session = open_groove_terminal_session(
+        app,
+        &terminal_state,
+        workspace_root,
+        &record.worktree,
+        worktree_path,
+        open_mode,
+        record.target.as_deref(),
+        None,
+        None,
+        false,
+        true,
+        true,
+    )?;
+    if matches!(open_mode, GrooveTerminalOpenMode::ClaudeCode) {
+        mark_claude_session_started(workspace_root, &record.worktree);
+    }
+    Ok(Some(session.session_id))
+}
+
+/// Restarts the environments whose backoff has passed and drops restarted
+/// ones whose session was closed by the user, once it has been missing on
+/// two ticks in a row so the terminal reader gets to report it first.
+fn supervise_testing_environments(app: &AppHandle) {
+    let live_sessions = app
+        .state::<GrooveTerminalState>()
+        .lock_sessions()
+        .sessions_by_id
+        .keys()
+        .cloned()
+        .collect::<HashSet<_>>();
+    let now = Instant::now();
+    let mut due = Vec::new();
+    let mut stopped = Vec::new();
+    {
+        let mut environments = supervised_testing_environments();
+        environments.retain(|_, environment| {
+            if environment.status != "running"
+                || live_sessions.contains(&environment.record.session_id)
+            {
+                environment.missing_since_previous_tick = false;
+                return true;
+            }
+            if environment.missing_since_previous_tick {
+                stopped.push(environment.status_event("stopped", None));
+                return false;
+            }
+            environment.missing_since_previous_tick = true;
+            true
+        });
+        for (key, environment) in environments.iter_mut() {
+            if environment
+                .restart_at
+                .is_some_and(|restart_at| restart_at <= now)
+            {
+                environment.restart_at = None;
+                environment.restarts += 1;
+                due.push((
+                    key.clone(),
+                    environment.record.clone(),
+                    environment.open_mode,
+                ));
+            }
+        }
+    }
+    for event in stopped {
+        emit_groove_event(app, &event);
+    }
+
+    for (key, record, open_mode) in due {
+        let restarted = restart_testing_environment(app, &record, open_mode);
+        let policy = testing_environment_restart_policy(app);
+        let event = {
+            let mut environments = supervised_testing_environments();
+            let Some(environment) = environments.get_mut(&key) else {
+                continue;
+            };
+            match restarted {
+                // The new session already crashed and was reported.
+                Ok(Some(session_id)) if environment.record.session_id == session_id => continue,
+                Ok(Some(session_id)) => {
+                    environment.record.session_id = session_id;
+                    environment.status = "running";
+                    environment.next_restart_at = None;
+                    environment.restarted_at = Some(Instant::now());
+                    environment.status_event("running", None)
+                }
+                Ok(None) => {
+                    environment.next_restart_at = None;
+                    let event = environment.status_event("stopped", None);
+                    environments.remove(&key);
+                    event
+                }
+                Err(error) => {
+                    let reason = format!("Restart failed: {error}");
+                    let status = record_testing_environment_crash(environment, &policy, &reason);
+                    environment.status_event(status, Some(reason))
+                }
+            }
+        };
+        log_play_telemetry(
+            telemetry_enabled_for_app(app),
+            "testing_environment.restart",
+            format!(
+                "workspace_root={} worktree={} status={} restarts={}",
+                event.workspace_root, event.worktree, event.status, event.restarts
+            )
+            .as_str(),
+        );
+        emit_groove_event(app, &event);
+    }
+}
+
+fn start_testing_environment_supervisor(app: AppHandle) {
+    thread::spawn(move || loop {
+        thread::sleep(TESTING_ENVIRONMENT_SUPERVISOR_INTERVAL);
+        // Restarts wait for background work to be resumed.
+        if background_activity_paused() {
+            continue;
+        }
+        supervise_testing_environments(&app);
+    });
+}
+
+#[cfg(test)]
+mod supervisor_runtime_tests {
+    use super::*;
+
+    fn supervised(restarts: u32) -> SupervisedTestingEnvironment {
+        SupervisedTestingEnvironment {
+            record: RunningGrooveRecord {
+                workspace_root: "/repo".to_string(),
+                worktree: "feature".to_string(),
+                worktree_path: "/repo/.worktrees/feature".to_string(),
+                command: "opencode".to_string(),
+                target: None,
+                session_id: "session-1".to_string(),
+                pid: None,
+                started_at: now_iso(),
+                process_started_at: None,
+                still_running: None,
+            },
+            open_mode: GrooveTerminalOpenMode::Opencode,
+            status: "running",
+            restarts,
+            restart_at: None,
+            next_restart_at: None,
+            restarted_at: Some(Instant::now()),
+            missing_since_previous_tick: false,
+            crashes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn restart_delay_doubles_up_to_the_cap() {
+        let policy = TestingEnvironmentRestartPolicy {
+            enabled: true,
+            max_restarts: 20,
+            backoff_ms: 1_000,
+        };
+        let delays =
+            [0, 1, 2, 40].map(|restarts| testing_environment_restart_delay(&policy, restarts));
+        assert_eq!(
+            delays,
+            [
+                Duration::from_secs(1),
+                Duration::from_secs(2),
+                Duration::from_secs(4),
+                MAX_TESTING_ENVIRONMENT_RESTART_DELAY,
+            ]
+        );
+    }
+
+    #[test]
+    fn crashes_restart_until_the_policy_gives_up() {
+        let policy = TestingEnvironmentRestartPolicy {
+            enabled: true,
+            max_restarts: 2,
+            backoff_ms: 1_000,
+        };
+        let mut environment = supervised(1);
+        assert_eq!(
+            record_testing_environment_crash(&mut environment, &policy, "exit 1"),
+            "restarting"
+        );
+        assert!(environment.restart_at.is_some());
+
+        environment.restarts = 2;
+        assert_eq!(
+            record_testing_environment_crash(&mut environment, &policy, "exit 1"),
+            "failed"
+        );
+        assert!(environment.restart_at.is_none());
+        assert_eq!(environment.crashes.len(), 2);
+
+        let disabled = TestingEnvironmentRestartPolicy::default();
+        let mut environment = supervised(0);
+        assert_eq!(
+            record_testing_environment_crash(&mut environment, &disabled, "exit 1"),
+            "crashed"
+        );
+    }
+}
@@ -0,0 +1,143 @@
+// tmux integration mode. A play or open-terminal command set to
+// `GROOVE_TMUX_COMMAND_SENTINEL` opens the worktree in a window of the
+// workspace's tmux session instead of an external terminal or the in-app
+// PTY, so agents keep running after Groove quits and can be re-attached from
+// any terminal with `tmux attach`.
+
+fn tmux_session_for_workspace(workspace_meta: &WorkspaceMeta) -> String {
+    tmux::session_name(&workspace_meta.root_name)
+}
+
+/// Workspace metadata for commands addressed by root name and known
+/// worktrees rather than an explicit path; shared with the zellij commands.
+fn resolve_payload_workspace_meta(
+    app: &AppHandle,
+    root_name: &Option<String>,
+    known_worktrees: &[String],
+    workspace_meta: &Option<WorkspaceMetaContext>,
+) -> Result<WorkspaceMeta, String> {
+    let known_worktrees = validate_known_worktrees(known_worktrees)?;
+    let workspace_root =
+        resolve_workspace_root(app, root_name, None, &known_worktrees, workspace_meta)?;
+    ensure_workspace_meta(&workspace_root).map(|(meta, _)| meta)
+}
+
+fn run_tmux(args: &[String]) -> CommandResult {
+    let mut command = Command::new("tmux");
+    command.args(args).stdin(Stdio::null());
+    run_command_with_timeout(
+        command,
+        TMUX_COMMAND_TIMEOUT,
+        "Failed to execute tmux".to_string(),
+        format!(
+            "tmux {}",
+            args.first().map(String::as_str).unwrap_or_default()
+        ),
+    )
+}
+
+fn tmux_failure(result: &CommandResult, fallback: &str) -> Option<String> {
+    if let Some(error) = result.error.clone() {
+        return Some(error);
+    }
+    if result.exit_code == Some(0) {
+        return None;
+    }
+    Some(command_output_snippet(result).unwrap_or_else(|| fallback.to_string()))
+}
+
+/// Windows of `session`; empty when the session or the tmux server does not
+/// exist yet.
+fn list_tmux_session_windows(session: &str) -> Result<Vec<tmux::TmuxWindow>, String> {
+    let result = run_tmux(&[
+        "list-windows".to_string(),
+        "-a".to_string(),
+        "-F".to_string(),
+        tmux::LIST_WINDOWS_FORMAT.to_string(),
+    ]);
+    if result.error.is_none()
+        && result.exit_code != Some(0)
+        && tmux::is_no_server_error(&result.stderr)
+    {
+        return Ok(Vec::new());
+    }
+    if let Some(error) = tmux_failure(&result, "tmux list-windows failed") {
+        return Err(error);
+    }
+
+    Ok(tmux::parse_list_windows(&result.stdout)
+        .into_iter()
+        .filter(|window| window.session == session)
+        .collect())
+}
+
+/// Opens `argv` (or tmux's default shell when empty) in the worktree's window,
+/// reusing the window when it is already there. When a client is attached to
+/// the session the window is selected so it comes to the front; otherwise the
+/// returned message says how to attach.
+fn open_tmux_worktree_window(
+    workspace_meta: &WorkspaceMeta,
+    worktree_path: &Path,
+    argv: &[String],
+) -> Result<String, String> {
+    let session = tmux_session_for_workspace(workspace_meta);
+    let worktree = worktree_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| workspace_meta.root_name.clone());
+    let window = tmux::window_name(&worktree);
+    let windows = list_tmux_session_windows(&session)?;
+
+    if !windows.iter().any(|existing| existing.name == window) {
+        let cwd = command_template_path(worktree_path)?;
+        let result = run_tmux(&tmux::open_window_args(
+            !windows.is_empty(),
+            &session,
+            &window,
+            cwd,
+            argv,
+        ));
+        if let Some(error) = tmux_failure(&result, "tmux could not open a window") {
+            return Err(error);
+        }
+    }
+
+    if windows.iter().any(|existing| existing.session_attached) {
+        let result = run_tmux(&[
+            "select-window".to_string(),
+            "-t".to_string(),
+            tmux::window_target(&session, &window),
+        ]);
+        if let Some(error) = tmux_failure(&result, "tmux select-window failed") {
+            return Err(error);
+        }
+        return Ok(format!("tmux window {session}:{window} (attached)"));
+    }
+
+    Ok(format!(
+        "tmux window {session}:{window}; attach with: tmux attach-session -t {session}"
+    ))
+}
+
+/// Kills the worktree's window. Returns `false` when there was none.
+fn kill_tmux_worktree_window(
+    workspace_meta: &WorkspaceMeta,
+    worktree: &str,
+) -> Result<bool, String> {
+    let session = tmux_session_for_workspace(workspace_meta);
+    let window = tmux::window_name(worktree);
+    let windows = list_tmux_session_windows(&session)?;
+    if !windows.iter().any(|existing| existing.name == window) {
+        return Ok(false);
+    }
+
+    let result = run_tmux(&[
+        "kill-window".to_string(),
+        "-t".to_string(),
+        tmux::window_target(&session, &window),
+    ]);
+    match tmux_failure(&result, "tmux kill-window failed") {
+        Some(error) => Err(error),
+        None => Ok(true),
+    }
+}
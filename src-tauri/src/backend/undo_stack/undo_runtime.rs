@@ -0,0 +1,199 @@
+// Undo stack for changes with a natural inverse: worktree state, play command
+// and labels, workspace settings and global settings. An entry keeps the
+// previous JSON values of only the fields its change touched, so undoing it
+// leaves later edits to other fields alone. The stack lives in memory and is
+// not meant to survive a restart.
+
+/// Previous values of the top-level JSON fields that differ between `before`
+/// and `after`. `updatedAt` is bookkeeping and never restored.
+fn changed_fields<T: Serialize>(
+    before: &T,
+    after: &T,
+) -> serde_json::Map<String, serde_json::Value> {
+    let (Ok(serde_json::Value::Object(before)), Ok(serde_json::Value::Object(after))) =
+        (serde_json::to_value(before), serde_json::to_value(after))
+    else {
+        return serde_json::Map::new();
+    };
+    before
+        .keys()
+        .chain(after.keys())
+        .filter(|key| key.as_str() != "updatedAt")
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .filter(|key| before.get(*key) != after.get(*key))
+        .map(|key| {
+            (
+                key.clone(),
+                before.get(key).cloned().unwrap_or(serde_json::Value::Null),
+            )
+        })
+        .collect()
+}
+
+/// Writes `previous` back over `current`. A null removes the field so it
+/// falls back to its serde default, as it was before the change.
+fn restore_fields<T>(
+    current: &T,
+    previous: &serde_json::Map<String, serde_json::Value>,
+) -> Result<T, String>
+where
+    T: Serialize + serde::de::DeserializeOwned,
+{
+    let serde_json::Value::Object(mut object) = serde_json::to_value(current)
+        .map_err(|error| format!("Failed to serialize current settings: {error}"))?
+    else {
+        return Err("Current settings are not a JSON object.".to_string());
+    };
+    for (key, value) in previous {
+        if value.is_null() {
+            object.remove(key);
+        } else {
+            object.insert(key.clone(), value.clone());
+        }
+    }
+    serde_json::from_value(serde_json::Value::Object(object))
+        .map_err(|error| format!("Failed to restore previous settings: {error}"))
+}
+
+fn push_undo_entry(
+    app: &AppHandle,
+    target: UndoTarget,
+    command: &str,
+    description: String,
+    previous: serde_json::Map<String, serde_json::Value>,
+) {
+    if previous.is_empty() {
+        return;
+    }
+    let Some(state) = app.try_state::<UndoState>() else {
+        return;
+    };
+    let (scope, workspace_root, worktree) = match &target {
+        UndoTarget::Workspace(workspace_root) => (
+            "workspace",
+            Some(workspace_root.display().to_string()),
+            None,
+        ),
+        UndoTarget::Worktree(workspace_root, worktree) => (
+            "worktree",
+            Some(workspace_root.display().to_string()),
+            Some(worktree.clone()),
+        ),
+        UndoTarget::GlobalSettings => ("globalSettings", None, None),
+    };
+    let record = UndoRecord {
+        entry: UndoEntry {
+            id: Uuid::new_v4().to_string(),
+            command: command.to_string(),
+            description,
+            scope: scope.to_string(),
+            workspace_root,
+            worktree,
+            fields: previous.keys().cloned().collect(),
+            created_at: now_iso(),
+        },
+        target,
+        previous,
+    };
+
+    let mut entries = state.lock_entries();
+    entries.push(record);
+    let excess = entries.len().saturating_sub(UNDO_STACK_MAX_ENTRIES);
+    entries.drain(..excess);
+}
+
+fn current_worktree_record(workspace_root: &Path, worktree: &str) -> Option<WorktreeRecord> {
+    ensure_workspace_meta(workspace_root)
+        .ok()
+        .and_then(|(workspace_meta, _)| workspace_meta.worktree_records.get(worktree).cloned())
+}
+
+/// Nothing is pushed when the record did not exist before the change.
+fn push_worktree_undo_entry(
+    app: &AppHandle,
+    workspace_root: &Path,
+    worktree: &str,
+    command: &str,
+    description: String,
+    before: Option<&WorktreeRecord>,
+    after: &WorktreeRecord,
+) {
+    let Some(before) = before else {
+        return;
+    };
+    push_undo_entry(
+        app,
+        UndoTarget::Worktree(workspace_root.to_path_buf(), worktree.to_string()),
+        command,
+        description,
+        changed_fields(before, after),
+    );
+}
+
+/// Writes the previous values back and returns whichever of the workspace
+/// meta or the global settings changed.
+fn apply_undo(
+    app: &AppHandle,
+    record: &UndoRecord,
+) -> Result<(Option<WorkspaceMeta>, Option<GlobalSettings>), String> {
+    let workspace_root = match &record.target {
+        UndoTarget::GlobalSettings => {
+            let global_settings = restore_fields(&ensure_global_settings(app)?, &record.previous)?;
+            write_global_settings_file(&global_settings_file(app)?, &global_settings)?;
+            return Ok((None, Some(global_settings)));
+        }
+        UndoTarget::Workspace(workspace_root) | UndoTarget::Worktree(workspace_root, _) => {
+            workspace_root
+        }
+    };
+
+    let (workspace_meta, _) = ensure_workspace_meta(workspace_root)?;
+    let mut workspace_meta = match &record.target {
+        UndoTarget::Worktree(_, worktree) => {
+            let mut workspace_meta = workspace_meta;
+            let current = workspace_meta
+                .worktree_records
+                .get(worktree)
+                .ok_or_else(|| format!("Worktree {worktree} no longer has a record."))?;
+            let restored = restore_fields(current, &record.previous)?;
+            workspace_meta
+                .worktree_records
+                .insert(worktree.clone(), restored);
+            workspace_meta
+        }
+        _ => restore_fields(&workspace_meta, &record.previous)?,
+    };
+    workspace_meta.updated_at = now_iso();
+    let workspace_json = workspace_root.join(".groove").join("workspace.json");
+    write_workspace_meta_file(&workspace_json, &workspace_meta)?;
+    invalidate_workspace_caches(app, workspace_root);
+    Ok((Some(workspace_meta), None))
+}
+
+#[cfg(test)]
+mod undo_runtime_tests {
+    use super::*;
+
+    #[test]
+    fn restores_only_the_changed_fields() {
+        let before: WorktreeRecord = serde_json::from_value(serde_json::json!({
+            "id": "a",
+            "createdAt": "2026-01-01T00:00:00Z",
+        }))
+        .unwrap();
+        let mut after = before.clone();
+        after.note = Some("wip".to_string());
+        after.tags = vec!["urgent".to_string()];
+
+        let previous = changed_fields(&before, &after);
+        assert_eq!(previous.keys().collect::<Vec<_>>(), vec!["note", "tags"]);
+
+        after.play_groove_command = Some("npm run dev".to_string());
+        let restored = restore_fields(&after, &previous).unwrap();
+        assert_eq!(restored.note, None);
+        assert!(restored.tags.is_empty());
+        assert_eq!(restored.play_groove_command.as_deref(), Some("npm run dev"));
+        assert!(changed_fields(&before, &before).is_empty());
+    }
+}
@@ -2,54 +2,73 @@ fn scan_workspace_worktrees(
     app: &AppHandle,
     workspace_root: &Path,
     scan_root: &Path,
-    worktree_records: &HashMap<String, WorktreeRecord>,
+    workspace_meta: &WorkspaceMeta,
 ) -> Result<(bool, Vec<WorkspaceScanRow>), String> {
+    let mut execution_state = read_persisted_worktree_execution_state(app)?;
+    scan_workspace_worktrees_with_state(
+        app,
+        workspace_root,
+        scan_root,
+        workspace_meta,
+        &mut execution_state,
+    )
+}
+
+/// `scan_workspace_worktrees` for callers that need the execution state
+/// afterwards too, so it is read once. Cleared tombstones are removed from
+/// `execution_state` as well as persisted.
+fn scan_workspace_worktrees_with_state(
+    app: &AppHandle,
+    workspace_root: &Path,
+    scan_root: &Path,
+    workspace_meta: &WorkspaceMeta,
+    execution_state: &mut PersistedWorktreeExecutionState,
+) -> Result<(bool, Vec<WorkspaceScanRow>), String> {
+    let worktree_records = &workspace_meta.worktree_records;
     let worktrees_dir = scan_root.join(".worktrees");
-    if !path_is_directory(&worktrees_dir) {
+    let has_worktrees_directory = path_is_directory(&worktrees_dir);
+    if !has_worktrees_directory && workspace_meta.adopted_worktrees.is_empty() {
         return Ok((false, Vec::new()));
     }
 
-    let mut rows = Vec::new();
-    let mut seen_worktrees = HashSet::<String>::new();
+    let mut worktree_paths = if has_worktrees_directory {
+        list_worktree_directories(&worktrees_dir)?
+    } else {
+        Vec::new()
+    };
+    worktree_paths.extend(adopted_worktree_directories(
+        &workspace_meta.adopted_worktrees,
+        &worktree_paths,
+    ));
+    let statuses = worktree_directory_statuses(&worktree_paths);
+    let mut rows = Vec::with_capacity(worktree_paths.len());
+    let mut seen_worktrees = HashSet::<String>::with_capacity(worktree_paths.len());
     let workspace_key = workspace_root_storage_key(workspace_root);
-    let mut execution_state = read_persisted_worktree_execution_state(app)?;
     let last_executed_by_worktree = execution_state
         .last_executed_at_by_workspace
         .get(&workspace_key);
-    let entries = fs::read_dir(&worktrees_dir)
-        .map_err(|error| format!("Failed to read {}: {error}", worktrees_dir.display()))?;
-
-    for entry in entries {
-        let entry = entry.map_err(|error| {
-            format!(
-                "Failed to enumerate {} entries: {error}",
-                worktrees_dir.display()
-            )
-        })?;
-        let path = entry.path();
-        if !path_is_directory(&path) {
-            continue;
-        }
 
+    for (path, status) in worktree_paths.into_iter().zip(statuses) {
         let Some(worktree_os_name) = path.file_name() else {
             continue;
         };
         let worktree = worktree_os_name.to_string_lossy().to_string();
         seen_worktrees.insert(worktree.clone());
-        let status = if path_is_directory(&path.join(".groove")) {
-            "paused"
-        } else {
-            "corrupted"
-        };
 
+        let worktree_id = worktree_records.get(&worktree).map(|record| record.id.clone());
         rows.push(WorkspaceScanRow {
-            worktree_id: worktree_records.get(&worktree).map(|record| record.id.clone()),
-            branch_guess: branch_guess_from_worktree_name(&worktree),
+            branch_guess: worktree_branch_from_records(worktree_records, &worktree),
             path: path.display().to_string(),
             status: status.to_string(),
             last_executed_at: last_executed_by_worktree
-                .and_then(|entries| entries.get(&worktree))
+                .and_then(|entries| {
+                    worktree_id
+                        .as_ref()
+                        .and_then(|id| entries.get(id))
+                        .or_else(|| entries.get(&worktree))
+                })
                 .cloned(),
+            worktree_id,
             worktree,
         });
     }
@@ -62,11 +81,12 @@ fn scan_workspace_worktrees(
     {
         let mut tombstones_to_drop = Vec::<String>::new();
 
-        for (worktree, tombstone) in workspace_tombstones.iter() {
+        for (key, tombstone) in workspace_tombstones.iter() {
+            let worktree = &tombstone.worktree;
             if seen_worktrees.contains(worktree)
                 || path_is_directory(Path::new(&tombstone.worktree_path))
             {
-                tombstones_to_drop.push(worktree.clone());
+                tombstones_to_drop.push(key.clone());
                 continue;
             }
 
@@ -76,11 +96,14 @@ fn scan_workspace_worktrees(
                 .map(str::trim)
                 .filter(|value| !value.is_empty())
                 .map(|value| value.to_string())
-                .unwrap_or_else(|| branch_guess_from_worktree_name(worktree));
+                .unwrap_or_else(|| worktree_branch_from_records(worktree_records, worktree));
 
             rows.push(WorkspaceScanRow {
                 worktree: worktree.clone(),
-                worktree_id: worktree_records.get(worktree).map(|record| record.id.clone()),
+                worktree_id: tombstone
+                    .worktree_id
+                    .clone()
+                    .or_else(|| worktree_records.get(worktree).map(|record| record.id.clone())),
                 branch_guess,
                 path: tombstone.worktree_path.clone(),
                 status: "deleted".to_string(),
@@ -88,8 +111,8 @@ fn scan_workspace_worktrees(
             });
         }
 
-        for worktree in tombstones_to_drop {
-            if workspace_tombstones.remove(&worktree).is_some() {
+        for key in tombstones_to_drop {
+            if workspace_tombstones.remove(&key).is_some() {
                 cleared_tombstones = true;
             }
         }
@@ -105,13 +128,125 @@ fn scan_workspace_worktrees(
     }
 
     if cleared_tombstones {
-        write_persisted_worktree_execution_state(app, &execution_state)?;
+        write_persisted_worktree_execution_state(app, execution_state)?;
     }
 
     rows.sort_by(|left, right| left.worktree.cmp(&right.worktree));
     Ok((true, rows))
 }
 
+/// The part of `ordered` on the page after `page.cursor`. The cursor is the
+/// last worktree of the previous page rather than an offset, so worktrees
+/// added or removed meanwhile do not shift the pages that follow. When that
+/// worktree is gone, a list ordered by name resumes at the next name; other
+/// orders cannot tell where to resume and refuse the cursor.
+fn worktree_rows_page(
+    ordered: &[String],
+    page: &RowsPagePayload,
+    by_name: bool,
+) -> Result<(std::ops::Range<usize>, RowsPageInfo), String> {
+    let limit = page
+        .limit
+        .unwrap_or(WORKSPACE_WORKTREES_PAGE_DEFAULT_LIMIT)
+        .clamp(1, WORKSPACE_WORKTREES_PAGE_MAX_LIMIT);
+    let start = match page.cursor.as_deref() {
+        None => 0,
+        Some(cursor) => match ordered.iter().position(|worktree| worktree == cursor) {
+            Some(index) => index + 1,
+            None if by_name => ordered.partition_point(|worktree| worktree.as_str() <= cursor),
+            None => {
+                return Err(
+                    "The page cursor is no longer valid; start again from the first page."
+                        .to_string(),
+                )
+            }
+        },
+    };
+    let end = start.saturating_add(limit).min(ordered.len());
+    Ok((
+        start..end,
+        RowsPageInfo {
+            total: ordered.len(),
+            next_cursor: (end < ordered.len()).then(|| ordered[end - 1].clone()),
+        },
+    ))
+}
+
+/// Keeps one page of a complete, successful context's rows.
+fn paginate_workspace_context(
+    response: &mut WorkspaceContextResponse,
+    page: Option<RowsPagePayload>,
+) {
+    let Some(page) = page.filter(|_| response.ok && response.partial != Some(true)) else {
+        return;
+    };
+    let worktrees = response
+        .rows
+        .iter()
+        .map(|row| row.worktree.clone())
+        .collect::<Vec<_>>();
+    match worktree_rows_page(&worktrees, &page, true) {
+        Ok((range, info)) => {
+            response.rows = response.rows.drain(range).collect();
+            response.page = Some(info);
+        }
+        Err(error) => {
+            response.ok = false;
+            response.rows.clear();
+            response.error = Some(error);
+        }
+    }
+}
+
+fn worktree_directory_status(worktree_path: &Path) -> &'static str {
+    if path_is_directory(&worktree_path.join(".groove")) {
+        "paused"
+    } else {
+        "corrupted"
+    }
+}
+
+/// Status of each worktree directory, in order. Workspaces with
+/// `WORKSPACE_SCAN_PARALLEL_MIN_WORKTREES` or more worktrees split the `.groove`
+/// checks across threads: on large workspaces the per-directory stat dominates
+/// the scan (see `worktree_directory_statuses_benchmark`).
+fn worktree_directory_statuses(worktree_paths: &[PathBuf]) -> Vec<&'static str> {
+    let parallelism = thread::available_parallelism().map_or(1, |value| value.get());
+    if worktree_paths.len() < WORKSPACE_SCAN_PARALLEL_MIN_WORKTREES || parallelism < 2 {
+        return worktree_paths
+            .iter()
+            .map(|path| worktree_directory_status(path))
+            .collect();
+    }
+
+    let chunk_size = worktree_paths.len().div_ceil(parallelism);
+    thread::scope(|scope| {
+        let handles = worktree_paths
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|path| worktree_directory_status(path))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .zip(worktree_paths.chunks(chunk_size))
+            .flat_map(|(handle, chunk)| {
+                handle.join().unwrap_or_else(|_| {
+                    chunk
+                        .iter()
+                        .map(|path| worktree_directory_status(path))
+                        .collect()
+                })
+            })
+            .collect()
+    })
+}
+
 fn build_workspace_context(
     app: &AppHandle,
     workspace_root: &Path,
@@ -123,124 +258,215 @@ fn build_workspace_context(
     if let Some(cached) = try_cached_workspace_context(app, workspace_root, &request_id) {
         log_build_workspace_context_timing(
             telemetry_enabled,
-            Duration::ZERO,
-            Duration::ZERO,
+            WorkspaceContextTimings::default(),
             total_started_at.elapsed(),
             true,
         );
         return cached;
     }
 
+    // The remote URL only needs git, so it is looked up while the meta is
+    // ensured and the worktrees are scanned, and only when the repository
+    // config changed since the last build.
+    let cached_remote_url = cached_repository_remote_url(app, workspace_root);
+    let (remote_lookup, (mut response, mut timings)) = thread::scope(|scope| {
+        let remote_lookup = cached_remote_url.is_none().then(|| {
+            scope.spawn(|| {
+                let started_at = Instant::now();
+                (repository_remote_url(workspace_root), started_at.elapsed())
+            })
+        });
+        let built = load_workspace_context(app, workspace_root, request_id, persist_as_active);
+        (remote_lookup.map(|lookup| lookup.join()), built)
+    });
+    if let Some(repository_remote_url) = cached_remote_url {
+        response.repository_remote_url = repository_remote_url;
+    } else if let Some(Ok((repository_remote_url, remote_elapsed))) = remote_lookup {
+        response.repository_remote_url = repository_remote_url;
+        timings.remote = remote_elapsed;
+    }
+
+    store_workspace_context_cache(app, workspace_root, &response);
+    log_build_workspace_context_timing(
+        telemetry_enabled,
+        timings,
+        total_started_at.elapsed(),
+        false,
+    );
+
+    response
+}
+
+/// Ensures the meta, reconciles and scans the worktrees and, when asked,
+/// persists the workspace as active. `repository_remote_url` is left for the
+/// caller to fill in. A workspace that cannot be written is only read: the
+/// meta is not created or migrated and records are not reconciled.
+fn load_workspace_context(
+    app: &AppHandle,
+    workspace_root: &Path,
+    request_id: String,
+    persist_as_active: bool,
+) -> (WorkspaceContextResponse, WorkspaceContextTimings) {
+    let mut timings = WorkspaceContextTimings::default();
     let meta_started_at = Instant::now();
-    let repository_remote_url = repository_remote_url(workspace_root);
-    let (workspace_meta, workspace_message) = match ensure_workspace_meta(workspace_root) {
+    let read_only_reason = probe_workspace_write_access(workspace_root);
+    record_workspace_read_only(workspace_root, read_only_reason.as_deref());
+    let read_only = read_only_reason.is_some().then_some(true);
+    let trust = workspace_trust_status(workspace_root);
+    let meta = if read_only_reason.is_some() {
+        read_only_workspace_meta(workspace_root)
+    } else {
+        ensure_workspace_meta(workspace_root)
+    };
+    let (workspace_meta, workspace_message) = match meta {
         Ok(result) => result,
         Err(error) => {
-            let meta_elapsed = meta_started_at.elapsed();
-            log_build_workspace_context_timing(
-                telemetry_enabled,
-                meta_elapsed,
-                Duration::ZERO,
-                total_started_at.elapsed(),
-                false,
+            timings.meta = meta_started_at.elapsed();
+            return (
+                WorkspaceContextResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: Some(workspace_root.display().to_string()),
+                    repository_remote_url: None,
+                    workspace_meta: None,
+                    workspace_message: None,
+                    has_worktrees_directory: None,
+                    rows: Vec::new(),
+                    cancelled: None,
+                    partial: None,
+                    read_only,
+                    read_only_reason,
+                    trust,
+                    page: None,
+                    error: Some(error),
+                },
+                timings,
             );
-            return WorkspaceContextResponse {
-                request_id,
-                ok: false,
-                workspace_root: Some(workspace_root.display().to_string()),
-                repository_remote_url,
-                workspace_meta: None,
-                workspace_message: None,
-                has_worktrees_directory: None,
-                rows: Vec::new(),
-                cancelled: None,
-                error: Some(error),
-            };
         }
     };
-    let meta_elapsed = meta_started_at.elapsed();
+    timings.meta = meta_started_at.elapsed();
 
     let scan_started_at = Instant::now();
     let scan_root = effective_workspace_root(workspace_root, &workspace_meta);
-    let (has_worktrees_directory, rows) = match scan_workspace_worktrees(
-        app,
-        workspace_root,
-        &scan_root,
-        &workspace_meta.worktree_records,
-    ) {
+    // Reconcile records with disk first so renamed/moved worktrees are matched
+    // to their existing record (via `.groove/worktree.json`) before rows are
+    // built from it.
+    let workspace_meta = if read_only.is_some() {
+        workspace_meta
+    } else {
+        match sync_worktree_records_with_disk(workspace_root, &scan_root) {
+            Ok(changed) if changed > 0 => ensure_workspace_meta(workspace_root)
+                .map(|(refreshed, _)| refreshed)
+                .unwrap_or(workspace_meta),
+            _ => workspace_meta,
+        }
+    };
+    let scanned = scan_workspace_worktrees(app, workspace_root, &scan_root, &workspace_meta);
+    timings.scan = scan_started_at.elapsed();
+    let (has_worktrees_directory, rows) = match scanned {
         Ok(result) => result,
         Err(error) => {
-            let scan_elapsed = scan_started_at.elapsed();
-            log_build_workspace_context_timing(
-                telemetry_enabled,
-                meta_elapsed,
-                scan_elapsed,
-                total_started_at.elapsed(),
-                false,
+            return (
+                WorkspaceContextResponse {
+                    request_id,
+                    ok: false,
+                    workspace_root: Some(workspace_root.display().to_string()),
+                    repository_remote_url: None,
+                    workspace_meta: Some(workspace_meta),
+                    workspace_message: Some(workspace_message),
+                    has_worktrees_directory: None,
+                    rows: Vec::new(),
+                    cancelled: None,
+                    partial: None,
+                    read_only,
+                    read_only_reason,
+                    trust,
+                    page: None,
+                    error: Some(error),
+                },
+                timings,
             );
-            return WorkspaceContextResponse {
-                request_id,
-                ok: false,
-                workspace_root: Some(workspace_root.display().to_string()),
-                repository_remote_url,
-                workspace_meta: Some(workspace_meta),
-                workspace_message: Some(workspace_message),
-                has_worktrees_directory: None,
-                rows: Vec::new(),
-                cancelled: None,
-                error: Some(error),
-            };
         }
     };
-    let scan_elapsed = scan_started_at.elapsed();
-
-    if persist_as_active {
-        if let Err(error) = persist_active_workspace_root(app, workspace_root) {
-            log_build_workspace_context_timing(
-                telemetry_enabled,
-                meta_elapsed,
-                scan_elapsed,
-                total_started_at.elapsed(),
-                false,
-            );
-            return WorkspaceContextResponse {
-                request_id,
-                ok: false,
-                workspace_root: Some(workspace_root.display().to_string()),
-                repository_remote_url,
-                workspace_meta: Some(workspace_meta),
-                workspace_message: Some(workspace_message),
-                has_worktrees_directory: Some(has_worktrees_directory),
-                rows,
-                cancelled: None,
-                error: Some(error),
-            };
-        }
-    }
 
-    let response = WorkspaceContextResponse {
+    let error = if persist_as_active {
+        persist_active_workspace_root(app, workspace_root).err()
+    } else {
+        None
+    };
+
+    (
+        WorkspaceContextResponse {
+            request_id,
+            ok: error.is_none(),
+            workspace_root: Some(workspace_root.display().to_string()),
+            repository_remote_url: None,
+            workspace_meta: Some(workspace_meta),
+            workspace_message: Some(workspace_message),
+            has_worktrees_directory: Some(has_worktrees_directory),
+            rows,
+            cancelled: None,
+            partial: None,
+            read_only,
+            read_only_reason,
+            trust,
+            page: None,
+            error,
+        },
+        timings,
+    )
+}
+
+/// Cold-start answer for `workspace_get_active`: the workspace meta with no
+/// rows, flagged `partial`. The full context is built on a background thread,
+/// cached, and delivered with a `workspace-context-complete` event carrying
+/// the same request id.
+fn partial_workspace_context(
+    app: &AppHandle,
+    workspace_root: &Path,
+    request_id: String,
+) -> WorkspaceContextResponse {
+    let (workspace_meta, workspace_message) = match ensure_workspace_meta(workspace_root) {
+        Ok(result) => result,
+        // The full build reports the same error.
+        Err(_) => return build_workspace_context(app, workspace_root, request_id, false),
+    };
+
+    let background_app = app.clone();
+    let background_root = workspace_root.to_path_buf();
+    let background_request_id = request_id.clone();
+    thread::spawn(move || {
+        let context = build_workspace_context(
+            &background_app,
+            &background_root,
+            background_request_id.clone(),
+            false,
+        );
+        emit_workspace_context_complete_event(
+            &background_app,
+            &background_request_id,
+            &background_root,
+            &context,
+        );
+    });
+
+    WorkspaceContextResponse {
         request_id,
         ok: true,
         workspace_root: Some(workspace_root.display().to_string()),
-        repository_remote_url,
+        repository_remote_url: None,
         workspace_meta: Some(workspace_meta),
         workspace_message: Some(workspace_message),
-        has_worktrees_directory: Some(has_worktrees_directory),
-        rows,
+        has_worktrees_directory: None,
+        rows: Vec::new(),
         cancelled: None,
+        partial: Some(true),
+        read_only: None,
+        read_only_reason: None,
+        trust: workspace_trust_status(workspace_root),
+        page: None,
         error: None,
-    };
-
-    store_workspace_context_cache(app, workspace_root, &response);
-    log_build_workspace_context_timing(
-        telemetry_enabled,
-        meta_elapsed,
-        scan_elapsed,
-        total_started_at.elapsed(),
-        false,
-    );
-
-    response
+    }
 }
 
 fn read_workspace_meta(workspace_root: &Path) -> Option<WorkspaceMetaContext> {
@@ -467,7 +693,7 @@ fn discover_workspace_root_candidates(
     let mut scanned = 0usize;
 
     for base in build_likely_search_bases() {
-        if scanned >= MAX_DISCOVERY_DIRECTORIES {
+        if scanned >= MAX_DISCOVERY_DIRECTORIES || command_cancelled() {
             break;
         }
 
@@ -485,7 +711,7 @@ fn discover_workspace_root_candidates(
             })
             .filter_map(Result::ok)
         {
-            if scanned >= MAX_DISCOVERY_DIRECTORIES {
+            if scanned >= MAX_DISCOVERY_DIRECTORIES || command_cancelled() {
                 break;
             }
 
@@ -619,20 +845,58 @@ fn emit_workspace_ready_event(
     workspace_root: Option<&str>,
     kind: &str,
 ) {
-    let _ = app.emit(
-        "workspace-ready",
-        serde_json::json!({
-            "requestId": request_id,
-            "workspaceRoot": workspace_root,
-            "kind": kind,
-        }),
+    emit_groove_event(
+        app,
+        &WorkspaceReadyEvent {
+            request_id: request_id.to_string(),
+            workspace_root: workspace_root.map(str::to_string),
+            kind: kind.to_string(),
+        },
+    );
+}
+
+fn emit_workspace_context_complete_event(
+    app: &AppHandle,
+    request_id: &str,
+    workspace_root: &Path,
+    context: &WorkspaceContextResponse,
+) {
+    let context = match serde_json::to_value(context) {
+        Ok(context) => context,
+        Err(error) => {
+            log_line(&format!(
+                "[workspace-context] failed to serialize the full context: {error}"
+            ));
+            return;
+        }
+    };
+    emit_groove_event(
+        app,
+        &WorkspaceContextCompleteEvent {
+            request_id: request_id.to_string(),
+            workspace_root: workspace_root.display().to_string(),
+            context,
+        },
     );
 }
 
 fn run_capture_command(cwd: &Path, binary: &str, args: &[&str]) -> CommandResult {
-    let output = Command::new(binary).args(args).current_dir(cwd).output();
+    let mut command = if binary == "git" {
+        git_command_at(cwd)
+    } else {
+        let mut command = Command::new(binary);
+        command.current_dir(cwd);
+        command
+    };
+    command.args(args);
+    let started_at = Instant::now();
+    let output = if binary == "git" {
+        git_output(&mut command, args)
+    } else {
+        output_unless_cancelled(&mut command)
+    };
 
-    match output {
+    let result = match output {
         Ok(output) => CommandResult {
             exit_code: output.status.code(),
             stdout: String::from_utf8_lossy(&output.stdout).to_string(),
@@ -645,7 +909,9 @@ fn run_capture_command(cwd: &Path, binary: &str, args: &[&str]) -> CommandResult
             stderr: String::new(),
             error: Some(format!("Failed to execute {binary}: {error}")),
         },
-    }
+    };
+    record_subprocess_transcript(&command, started_at, &result);
+    result
 }
 
 fn run_capture_command_timeout(
@@ -664,6 +930,35 @@ fn run_capture_command_timeout(
     )
 }
 
+/// Like `run_capture_command`, with `stdin_data` piped to the process.
+fn run_capture_command_with_stdin(
+    cwd: &Path,
+    binary: &str,
+    args: &[&str],
+    stdin_data: &str,
+) -> CommandResult {
+    let mut command = Command::new(binary);
+    command.args(args).current_dir(cwd);
+    let started_at = Instant::now();
+    let output = process_output_with_stdin(&command, stdin_data.as_bytes());
+    let result = match output {
+        Ok(output) => CommandResult {
+            exit_code: output.status.code(),
+            stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+            stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+            error: None,
+        },
+        Err(error) => CommandResult {
+            exit_code: None,
+            stdout: String::new(),
+            stderr: String::new(),
+            error: Some(format!("Failed to execute {binary}: {error}")),
+        },
+    };
+    record_subprocess_transcript(&command, started_at, &result);
+    result
+}
+
 fn first_non_empty_line(value: &str) -> Option<String> {
     value
         .lines()
@@ -676,9 +971,114 @@ fn repository_remote_url(workspace_root: &Path) -> Option<String> {
     resolve_remote_url_with_fallback(workspace_root).map(|(_, remote_url)| remote_url)
 }
 
+/// Ranks worktrees for the quick switcher. Every whitespace-separated query
+/// term has to match the worktree name, its branch, a tag or the note; each
+/// term counts its best match, weighted so names and branches outrank notes.
+/// An empty query lists the most recently used worktrees.
+fn rank_worktree_search(
+    rows: &[WorkspaceScanRow],
+    records: &HashMap<String, WorktreeRecord>,
+    query: &str,
+    include_deleted: bool,
+    limit: usize,
+) -> Vec<WorktreeSearchResult> {
+    let terms = query.split_whitespace().collect::<Vec<_>>();
+    let mut results = rows
+        .iter()
+        .filter(|row| include_deleted || row.status != "deleted")
+        .filter_map(|row| {
+            let mut fields = vec![
+                ("worktree", 4, row.worktree.as_str()),
+                ("branch", 4, row.branch_guess.as_str()),
+            ];
+            if let Some(record) = records.get(&row.worktree) {
+                fields.extend(record.tags.iter().map(|tag| ("tag", 3, tag.as_str())));
+                fields.extend(record.note.as_deref().map(|note| ("note", 2, note)));
+            }
+
+            let mut score = 0;
+            let mut matches: Vec<WorktreeSearchMatch> = Vec::new();
+            for term in &terms {
+                let (field, text, found) = fields
+                    .iter()
+                    .filter_map(|&(field, weight, text)| {
+                        let found = fuzzy::fuzzy_match(term, text)?;
+                        Some((field, text, found.score * weight, found.indices))
+                    })
+                    .min_by_key(|(_, _, score, _)| std::cmp::Reverse(*score))
+                    .map(|(field, text, term_score, indices)| {
+                        score += term_score;
+                        (field, text, indices)
+                    })?;
+                match matches
+                    .iter_mut()
+                    .find(|existing| existing.field == field && existing.text == text)
+                {
+                    Some(existing) => {
+                        existing.indices.extend(found);
+                        existing.indices.sort_unstable();
+                        existing.indices.dedup();
+                    }
+                    None => matches.push(WorktreeSearchMatch {
+                        field: field.to_string(),
+                        text: text.to_string(),
+                        indices: found,
+                    }),
+                }
+            }
+
+            Some(WorktreeSearchResult {
+                worktree: row.worktree.clone(),
+                worktree_id: row.worktree_id.clone(),
+                branch: row.branch_guess.clone(),
+                path: row.path.clone(),
+                status: row.status.clone(),
+                last_executed_at: row.last_executed_at.clone(),
+                score,
+                matches,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    results.sort_by(|left, right| {
+        right
+            .score
+            .cmp(&left.score)
+            .then_with(|| right.last_executed_at.cmp(&left.last_executed_at))
+            .then_with(|| left.worktree.cmp(&right.worktree))
+    });
+    results.truncate(limit);
+    results
+}
+
 #[cfg(test)]
 mod tests {
-    use super::collect_gitignore_sanity;
+    use super::{
+        collect_gitignore_sanity, list_worktree_directories, rank_worktree_search,
+        worktree_directory_status, worktree_directory_statuses, WorkspaceScanRow, WorktreeRecord,
+        WORKSPACE_SCAN_PARALLEL_MIN_WORKTREES,
+    };
+    use std::collections::HashMap;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::time::Instant;
+    use uuid::Uuid;
+
+    /// A `.worktrees` directory with `count` worktrees, every other one
+    /// missing its `.groove` directory, plus a stray file.
+    fn fake_worktrees_dir(count: usize) -> PathBuf {
+        let worktrees_dir =
+            std::env::temp_dir().join(format!("groove-scan-test-{}", Uuid::new_v4()));
+        for index in 0..count {
+            let worktree = worktrees_dir.join(format!("worktree-{index:04}"));
+            fs::create_dir_all(&worktree).unwrap();
+            if index % 2 == 0 {
+                fs::create_dir_all(worktree.join(".groove")).unwrap();
+            }
+        }
+        fs::write(worktrees_dir.join("notes.txt"), "not a worktree").unwrap();
+        worktrees_dir
+    }
 
     #[test]
     fn gitignore_sanity_accepts_worktrees_equivalent_forms() {
@@ -712,4 +1112,124 @@ mod tests {
         assert!(!has_workspace_entry);
         assert_eq!(missing_entries, vec![".worktrees/".to_string()]);
     }
+
+    fn scan_row(worktree: &str, branch: &str, last_executed_at: Option<&str>) -> WorkspaceScanRow {
+        WorkspaceScanRow {
+            worktree: worktree.to_string(),
+            worktree_id: None,
+            branch_guess: branch.to_string(),
+            path: format!("/repo/.worktrees/{worktree}"),
+            status: "ready".to_string(),
+            last_executed_at: last_executed_at.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn pages_worktrees_after_the_cursor() {
+        let ordered = ["a", "b", "c", "d", "e"].map(str::to_string);
+        let page = |cursor: Option<&str>| RowsPagePayload {
+            cursor: cursor.map(str::to_string),
+            limit: Some(2),
+        };
+
+        let (range, info) = worktree_rows_page(&ordered, &page(None), true).unwrap();
+        assert_eq!(range, 0..2);
+        assert_eq!(info.total, 5);
+        assert_eq!(info.next_cursor.as_deref(), Some("b"));
+
+        let (range, info) = worktree_rows_page(&ordered, &page(Some("d")), true).unwrap();
+        assert_eq!(range, 4..5);
+        assert_eq!(info.next_cursor, None);
+
+        // "bb" was removed since the last page: resume after it by name.
+        let (range, _) = worktree_rows_page(&ordered, &page(Some("bb")), true).unwrap();
+        assert_eq!(range, 2..4);
+        assert!(worktree_rows_page(&ordered, &page(Some("bb")), false).is_err());
+    }
+
+    #[test]
+    fn worktree_search_ranks_names_over_notes_and_requires_every_term() {
+        let rows = vec![
+            scan_row("payments-fix", "fix/payments", None),
+            scan_row(
+                "login-form",
+                "feature/login-form",
+                Some("2026-01-02T00:00:00Z"),
+            ),
+            scan_row("docs", "docs/readme", Some("2026-01-03T00:00:00Z")),
+        ];
+        let mut records = HashMap::new();
+        let mut docs: WorktreeRecord = serde_json::from_value(serde_json::json!({
+            "id": "docs",
+            "createdAt": "2026-01-01T00:00:00Z",
+            "note": "login copy tweaks",
+            "tags": ["urgent"],
+        }))
+        .unwrap();
+        docs.tags.push("Review".to_string());
+        records.insert("docs".to_string(), docs);
+
+        let names = |query: &str| {
+            rank_worktree_search(&rows, &records, query, false, 10)
+                .into_iter()
+                .map(|result| result.worktree)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names("login"), vec!["login-form", "docs"]);
+        assert_eq!(names("login urgent"), vec!["docs"]);
+        assert_eq!(names("pay"), vec!["payments-fix"]);
+        assert_eq!(names(""), vec!["docs", "login-form", "payments-fix"]);
+        assert!(names("zzz").is_empty());
+
+        let results = rank_worktree_search(&rows, &records, "review", false, 10);
+        assert_eq!(results[0].matches[0].field, "tag");
+        assert_eq!(results[0].matches[0].indices, vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(rank_worktree_search(&rows, &records, "", false, 2).len(), 2);
+    }
+
+    #[test]
+    fn parallel_worktree_statuses_keep_directory_order() {
+        let count = WORKSPACE_SCAN_PARALLEL_MIN_WORKTREES + 7;
+        let worktrees_dir = fake_worktrees_dir(count);
+        let mut paths = list_worktree_directories(&worktrees_dir).unwrap();
+        paths.sort();
+
+        assert_eq!(paths.len(), count);
+        let statuses = worktree_directory_statuses(&paths);
+        for (index, status) in statuses.iter().enumerate() {
+            let expected = if index % 2 == 0 {
+                "paused"
+            } else {
+                "corrupted"
+            };
+            assert_eq!(*status, expected);
+        }
+        assert_eq!(worktree_directory_statuses(&paths[..3]), statuses[..3]);
+        fs::remove_dir_all(worktrees_dir).unwrap();
+    }
+
+    /// `cargo test worktree_directory_statuses_benchmark -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn worktree_directory_statuses_benchmark() {
+        let worktrees_dir = fake_worktrees_dir(2_000);
+        let paths = list_worktree_directories(&worktrees_dir).unwrap();
+
+        let started_at = Instant::now();
+        let sequential = paths
+            .iter()
+            .map(|path| worktree_directory_status(path))
+            .collect::<Vec<_>>();
+        let sequential_elapsed = started_at.elapsed();
+        let started_at = Instant::now();
+        let parallel = worktree_directory_statuses(&paths);
+        let parallel_elapsed = started_at.elapsed();
+
+        println!(
+            "{} worktrees: sequential {sequential_elapsed:?}, parallel {parallel_elapsed:?}",
+            paths.len()
+        );
+        assert_eq!(sequential, parallel);
+        fs::remove_dir_all(worktrees_dir).unwrap();
+    }
 }
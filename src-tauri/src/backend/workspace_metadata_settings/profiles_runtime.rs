@@ -0,0 +1,265 @@
+// Named settings profiles (e.g. "work" and "home"). A profile holds
+// overrides for a few global settings; switching to it writes them into
+// `global-settings.json` like `global_settings_update` would, undo entry
+// included. Profiles are kept in `settings-profiles.json` in the app data
+// directory and can be exported to a standalone document to carry to
+// another machine.
+
+fn settings_profiles_file(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|error| format!("Failed to resolve app data directory: {error}"))?;
+    fs::create_dir_all(&app_data_dir)
+        .map_err(|error| format!("Failed to create app data directory: {error}"))?;
+    Ok(app_data_dir.join(SETTINGS_PROFILES_FILE))
+}
+
+/// A missing file has no profiles; an unreadable one is reported so saving
+/// does not overwrite it.
+fn read_settings_profiles(app: &AppHandle) -> Result<PersistedSettingsProfiles, String> {
+    let path = settings_profiles_file(app)?;
+    if !path_is_file(&path) {
+        return Ok(PersistedSettingsProfiles::default());
+    }
+    let raw = fs::read_to_string(&path)
+        .map_err(|error| format!("Failed to read {}: {error}", path.display()))?;
+    serde_json::from_str(&raw)
+        .map_err(|error| format!("Failed to parse {}: {error}", path.display()))
+}
+
+fn write_settings_profiles(
+    app: &AppHandle,
+    profiles: &PersistedSettingsProfiles,
+) -> Result<(), String> {
+    let path = settings_profiles_file(app)?;
+    let body = serde_json::to_string_pretty(profiles)
+        .map_err(|error| format!("Failed to serialize settings profiles: {error}"))?;
+    fs::write(&path, format!("{body}\n"))
+        .map_err(|error| format!("Failed to write {}: {error}", path.display()))
+}
+
+fn normalize_settings_profile_name(name: &str) -> Result<String, String> {
+    let trimmed = name.trim();
+    if trimmed.is_empty() {
+        return Err("name must be a non-empty string.".to_string());
+    }
+    if trimmed.chars().count() > MAX_SETTINGS_PROFILE_NAME_CHARS {
+        return Err(format!(
+            "name must be at most {MAX_SETTINGS_PROFILE_NAME_CHARS} characters."
+        ));
+    }
+    if trimmed.chars().any(char::is_control) {
+        return Err("name must not contain control characters.".to_string());
+    }
+    Ok(trimmed.to_string())
+}
+
+/// Validates the theme and terminal and clamps the poll intervals, as
+/// `global_settings_update` does.
+fn normalize_settings_profile_overrides(
+    overrides: SettingsProfileOverrides,
+) -> Result<SettingsProfileOverrides, String> {
+    let theme_mode = overrides
+        .theme_mode
+        .as_deref()
+        .map(normalize_theme_mode)
+        .transpose()?;
+    let default_terminal = match overrides.default_terminal.as_deref() {
+        Some(value) => {
+            Some(normalize_default_terminal_override(value)?.unwrap_or_else(|| "auto".to_string()))
+        }
+        None => None,
+    };
+    Ok(SettingsProfileOverrides {
+        telemetry_enabled: overrides.telemetry_enabled,
+        theme_mode,
+        default_terminal,
+        polling_settings: overrides.polling_settings.map(normalize_polling_settings),
+    })
+}
+
+/// Every overridable setting as currently set, for saving a profile from
+/// the settings in use.
+fn capture_settings_profile_overrides(settings: &GlobalSettings) -> SettingsProfileOverrides {
+    SettingsProfileOverrides {
+        telemetry_enabled: Some(settings.telemetry_enabled),
+        theme_mode: Some(settings.theme_mode.clone()),
+        default_terminal: Some(
+            settings
+                .default_terminal
+                .clone()
+                .unwrap_or_else(|| "auto".to_string()),
+        ),
+        polling_settings: Some(settings.polling_settings),
+    }
+}
+
+/// `overrides` must have gone through `normalize_settings_profile_overrides`.
+fn apply_settings_profile_overrides(
+    settings: &mut GlobalSettings,
+    overrides: &SettingsProfileOverrides,
+) {
+    if let Some(telemetry_enabled) = overrides.telemetry_enabled {
+        settings.telemetry_enabled = telemetry_enabled;
+    }
+    if let Some(theme_mode) = overrides.theme_mode.as_ref() {
+        settings.theme_mode = theme_mode.clone();
+    }
+    if let Some(default_terminal) = overrides.default_terminal.as_deref() {
+        settings.default_terminal =
+            (default_terminal != "auto").then(|| default_terminal.to_string());
+    }
+    if let Some(polling_settings) = overrides.polling_settings {
+        settings.polling_settings = polling_settings;
+    }
+}
+
+/// Replaces the profile with the same name (case-insensitively) or adds it,
+/// keeping profiles sorted by name.
+fn upsert_settings_profile(profiles: &mut PersistedSettingsProfiles, profile: SettingsProfile) {
+    if let Some(existing) = profiles
+        .profiles
+        .iter_mut()
+        .find(|existing| existing.name.eq_ignore_ascii_case(&profile.name))
+    {
+        if profiles
+            .active_profile
+            .as_deref()
+            .is_some_and(|active| active == existing.name)
+        {
+            profiles.active_profile = Some(profile.name.clone());
+        }
+        *existing = profile;
+    } else {
+        profiles.profiles.push(profile);
+    }
+    profiles
+        .profiles
+        .sort_by_key(|profile| profile.name.to_lowercase());
+}
+
+fn find_settings_profile<'a>(
+    profiles: &'a PersistedSettingsProfiles,
+    name: &str,
+) -> Option<&'a SettingsProfile> {
+    profiles
+        .profiles
+        .iter()
+        .find(|profile| profile.name.eq_ignore_ascii_case(name))
+}
+
+fn export_settings_profile(profile: &SettingsProfile) -> Result<String, String> {
+    let document = ExportedSettingsProfile {
+        groove_settings_profile_version: SETTINGS_PROFILE_EXPORT_VERSION,
+        name: profile.name.clone(),
+        overrides: profile.overrides.clone(),
+    };
+    serde_json::to_string_pretty(&document)
+        .map(|body| format!("{body}\n"))
+        .map_err(|error| format!("Failed to serialize settings profile: {error}"))
+}
+
+/// The profile in an exported document, validated like a saved one.
+fn parse_exported_settings_profile(
+    contents: &str,
+    name: Option<&str>,
+) -> Result<SettingsProfile, String> {
+    let document = serde_json::from_str::<ExportedSettingsProfile>(contents)
+        .map_err(|error| format!("Not a Groove settings profile: {error}"))?;
+    if document.groove_settings_profile_version != SETTINGS_PROFILE_EXPORT_VERSION {
+        return Err(format!(
+            "Unsupported settings profile version {}; expected {SETTINGS_PROFILE_EXPORT_VERSION}.",
+            document.groove_settings_profile_version
+        ));
+    }
+    Ok(SettingsProfile {
+        name: normalize_settings_profile_name(name.unwrap_or(&document.name))?,
+        overrides: normalize_settings_profile_overrides(document.overrides)?,
+        updated_at: now_iso(),
+    })
+}
+
+#[cfg(test)]
+mod profiles_runtime_tests {
+    use super::*;
+
+    fn profile(name: &str, theme_mode: &str) -> SettingsProfile {
+        SettingsProfile {
+            name: name.to_string(),
+            overrides: SettingsProfileOverrides {
+                theme_mode: Some(theme_mode.to_string()),
+                ..SettingsProfileOverrides::default()
+            },
+            updated_at: "2026-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn applying_a_profile_only_touches_its_overrides() {
+        let mut settings = default_global_settings();
+        settings.hide_mascot = true;
+        settings.default_terminal = Some("kitty".to_string());
+        let overrides = normalize_settings_profile_overrides(SettingsProfileOverrides {
+            telemetry_enabled: Some(false),
+            default_terminal: Some("auto".to_string()),
+            polling_settings: Some(PollingSettings {
+                workspace_events_interval_ms: 1,
+                worktree_watch_interval_ms: DEFAULT_WORKTREE_WATCH_POLL_INTERVAL_MS,
+            }),
+            ..SettingsProfileOverrides::default()
+        })
+        .unwrap();
+
+        apply_settings_profile_overrides(&mut settings, &overrides);
+
+        assert!(!settings.telemetry_enabled);
+        assert!(settings.hide_mascot);
+        assert_eq!(settings.theme_mode, default_theme_mode());
+        assert_eq!(settings.default_terminal, None);
+        assert_eq!(
+            settings.polling_settings.workspace_events_interval_ms,
+            MIN_WORKSPACE_EVENTS_POLL_INTERVAL_MS
+        );
+    }
+
+    #[test]
+    fn upserting_replaces_profiles_by_name_and_keeps_them_sorted() {
+        let mut profiles = PersistedSettingsProfiles {
+            active_profile: Some("work".to_string()),
+            profiles: vec![profile("work", "light")],
+        };
+
+        upsert_settings_profile(&mut profiles, profile("home", "dark"));
+        upsert_settings_profile(&mut profiles, profile("Work", "ice"));
+
+        let names = profiles
+            .profiles
+            .iter()
+            .map(|profile| profile.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["home", "Work"]);
+        assert_eq!(profiles.active_profile.as_deref(), Some("Work"));
+        assert_eq!(
+            find_settings_profile(&profiles, "WORK")
+                .and_then(|profile| profile.overrides.theme_mode.as_deref()),
+            Some("ice")
+        );
+    }
+
+    #[test]
+    fn exported_profiles_round_trip_and_other_versions_are_refused() {
+        let exported = export_settings_profile(&profile("work", "light")).unwrap();
+
+        let imported = parse_exported_settings_profile(&exported, Some("laptop")).unwrap();
+        assert_eq!(imported.name, "laptop");
+        assert_eq!(imported.overrides.theme_mode.as_deref(), Some("light"));
+
+        let newer = exported.replace(
+            "\"grooveSettingsProfileVersion\": 1",
+            "\"grooveSettingsProfileVersion\": 2",
+        );
+        assert!(parse_exported_settings_profile(&newer, None).is_err());
+        assert!(parse_exported_settings_profile("{}", None).is_err());
+    }
+}
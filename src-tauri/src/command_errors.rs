@@ -0,0 +1,158 @@
+/// Coarse category of a failed command, derived from its error message so the
+/// existing `Result<_, String>` helpers keep working unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CommandErrorCode {
+    Cancelled,
+    Timeout,
+    ReadOnly,
+    Untrusted,
+    ConfirmationRequired,
+    ProcessMismatch,
+    PermissionDenied,
+    NotFound,
+    InvalidPayload,
+    ExternalCommand,
+    Io,
+    Internal,
+}
+
+impl CommandErrorCode {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            CommandErrorCode::Cancelled => "cancelled",
+            CommandErrorCode::Timeout => "timeout",
+            CommandErrorCode::ReadOnly => "read_only",
+            CommandErrorCode::Untrusted => "untrusted",
+            CommandErrorCode::ConfirmationRequired => "confirmation_required",
+            CommandErrorCode::ProcessMismatch => "process_mismatch",
+            CommandErrorCode::PermissionDenied => "permission_denied",
+            CommandErrorCode::NotFound => "not_found",
+            CommandErrorCode::InvalidPayload => "invalid_payload",
+            CommandErrorCode::ExternalCommand => "external_command",
+            CommandErrorCode::Io => "io",
+            CommandErrorCode::Internal => "internal",
+        }
+    }
+}
+
+pub(crate) fn classify_command_error(error: &str) -> CommandErrorCode {
+    let error = error.to_ascii_lowercase();
+    let mentions = |needles: &[&str]| needles.iter().any(|needle| error.contains(needle));
+
+    if mentions(&["was cancelled"]) {
+        CommandErrorCode::Cancelled
+    } else if mentions(&["timed out", "timeout"]) {
+        CommandErrorCode::Timeout
+    } else if mentions(&["is read-only", "read-only file system"]) {
+        CommandErrorCode::ReadOnly
+    } else if mentions(&["is not trusted"]) {
+        CommandErrorCode::Untrusted
+    } else if mentions(&["needs confirmation"]) {
+        CommandErrorCode::ConfirmationRequired
+    } else if mentions(&["as the recorded process", "no longer the recorded process"]) {
+        CommandErrorCode::ProcessMismatch
+    } else if mentions(&[
+        "permission denied",
+        "access is denied",
+        "operation not permitted",
+    ]) {
+        CommandErrorCode::PermissionDenied
+    } else if mentions(&["not found", "does not exist", "no such file"]) {
+        CommandErrorCode::NotFound
+    } else if mentions(&["is required", "must ", "invalid", "is empty"]) {
+        CommandErrorCode::InvalidPayload
+    } else if mentions(&[
+        "failed to execute",
+        "failed to launch",
+        "failed to run",
+        "exited with",
+        "exit code",
+    ]) {
+        CommandErrorCode::ExternalCommand
+    } else if mentions(&[
+        "failed to read",
+        "failed to write",
+        "failed to create",
+        "failed to parse",
+        "failed to remove",
+    ]) {
+        CommandErrorCode::Io
+    } else {
+        CommandErrorCode::Internal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_validation_and_lookup_errors() {
+        assert_eq!(
+            classify_command_error("worktree is required."),
+            CommandErrorCode::InvalidPayload
+        );
+        assert_eq!(
+            classify_command_error("URL must not be empty."),
+            CommandErrorCode::InvalidPayload
+        );
+        assert_eq!(
+            classify_command_error("Worktree path does not exist."),
+            CommandErrorCode::NotFound
+        );
+        assert_eq!(
+            classify_command_error("Phase timed out after 30 seconds."),
+            CommandErrorCode::Timeout
+        );
+    }
+
+    #[test]
+    fn classifies_process_and_io_failures() {
+        assert_eq!(
+            classify_command_error("Failed to execute git: No such file or directory"),
+            CommandErrorCode::NotFound
+        );
+        assert_eq!(
+            classify_command_error("Failed to execute gh: broken pipe"),
+            CommandErrorCode::ExternalCommand
+        );
+        assert_eq!(
+            classify_command_error("Failed to execute git: the command was cancelled"),
+            CommandErrorCode::Cancelled
+        );
+        assert_eq!(
+            classify_command_error("Failed to write /tmp/x: Permission denied (os error 13)"),
+            CommandErrorCode::PermissionDenied
+        );
+        assert_eq!(
+            classify_command_error("Workspace /mnt/repo is read-only: permission denied"),
+            CommandErrorCode::ReadOnly
+        );
+        assert_eq!(
+            classify_command_error(
+                "Workspace /src/repo is not trusted; trust it to run its play command."
+            ),
+            CommandErrorCode::Untrusted
+        );
+        assert_eq!(
+            classify_command_error(
+                "\"npm run dev\" has not run in workspace /src/repo before and needs confirmation."
+            ),
+            CommandErrorCode::ConfirmationRequired
+        );
+        assert_eq!(
+            classify_command_error(
+                "PID 41 is no longer the recorded process: its command \"postgres\" does not run npm. Refusing to stop it."
+            ),
+            CommandErrorCode::ProcessMismatch
+        );
+        assert_eq!(
+            classify_command_error("Failed to read workspace.json: unexpected eof"),
+            CommandErrorCode::Io
+        );
+        assert_eq!(
+            classify_command_error("Groove terminal session lock poisoned"),
+            CommandErrorCode::Internal
+        );
+    }
+}
@@ -0,0 +1,163 @@
+//! Capabilities granted to API tokens used by Groove's external surfaces (the
+//! MCP server today). Every remotely callable command requires exactly one
+//! capability; a token can call it only if it was granted that capability.
+//! The desktop UI itself is not subject to these checks.
+
+use sha2::{Digest, Sha256};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Capability {
+    /// Inspect worktrees, sessions and rules without changing anything.
+    Read,
+    /// Create worktrees and change metadata or rules.
+    Write,
+    /// Stop processes and delete state that cannot be recreated.
+    Destructive,
+    /// Start terminal sessions, type into them and read their output.
+    TerminalIo,
+}
+
+impl Capability {
+    pub(crate) const ALL: [Capability; 4] = [
+        Capability::Read,
+        Capability::Write,
+        Capability::Destructive,
+        Capability::TerminalIo,
+    ];
+
+    /// Granted to a new token when none are chosen, and to requests without a
+    /// token while no tokens exist. Everything except `Destructive`.
+    pub(crate) const DEFAULT: [Capability; 3] =
+        [Capability::Read, Capability::Write, Capability::TerminalIo];
+
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Capability::Read => "read",
+            Capability::Write => "write",
+            Capability::Destructive => "destructive",
+            Capability::TerminalIo => "terminal-io",
+        }
+    }
+
+    pub(crate) fn parse(value: &str) -> Result<Self, String> {
+        let normalized = value.trim().to_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|capability| capability.as_str() == normalized)
+            .ok_or_else(|| {
+                format!(
+                    "Unknown capability \"{}\"; expected one of: {}.",
+                    value.trim(),
+                    Self::ALL.map(Capability::as_str).join(", ")
+                )
+            })
+    }
+}
+
+/// Parses and de-duplicates a capability list, keeping the order of `ALL`.
+pub(crate) fn parse_capabilities(values: &[String]) -> Result<Vec<Capability>, String> {
+    let parsed = values
+        .iter()
+        .map(|value| Capability::parse(value))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Capability::ALL
+        .into_iter()
+        .filter(|capability| parsed.contains(capability))
+        .collect())
+}
+
+/// Capability an MCP tool requires, or `None` for a tool Groove does not
+/// expose.
+pub(crate) fn mcp_tool_capability(tool: &str) -> Option<Capability> {
+    let capability = match tool {
+        "list_worktrees"
+        | "search_worktrees"
+        | "get_worktree"
+        | "list_assistant_rules"
+        | "get_worktree_claude_session"
+        | "read_worktree_session" => Capability::Read,
+        "create_worktree" | "add_assistant_rule" => Capability::Write,
+        "pause_worktree" | "remove_assistant_rule" => Capability::Destructive,
+        "play_worktree"
+        | "send_worktree_prompt"
+        | "wait_for_worktree_response"
+        | "read_worktree_terminal" => Capability::TerminalIo,
+        _ => return None,
+    };
+    Some(capability)
+}
+
+/// Checks that `granted` includes what `command` requires.
+pub(crate) fn require_capability(
+    granted: &[Capability],
+    command: &str,
+    required: Capability,
+) -> Result<(), String> {
+    if granted.contains(&required) {
+        return Ok(());
+    }
+    Err(format!(
+        "Permission denied: {command} requires the \"{}\" capability, which this API token does not grant.",
+        required.as_str()
+    ))
+}
+
+/// Hex SHA-256 of a token secret. Only digests are persisted, so the secret
+/// is shown once when the token is created.
+pub(crate) fn api_token_digest(secret: &str) -> String {
+    Sha256::digest(secret.trim().as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_orders_capabilities() {
+        assert_eq!(
+            parse_capabilities(&[
+                "terminal-io".to_string(),
+                " READ ".to_string(),
+                "read".to_string(),
+            ]),
+            Ok(vec![Capability::Read, Capability::TerminalIo])
+        );
+        assert_eq!(
+            Capability::parse("admin"),
+            Err(
+                "Unknown capability \"admin\"; expected one of: read, write, destructive, terminal-io."
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn default_grant_excludes_destructive_tools() {
+        assert!(require_capability(
+            &Capability::DEFAULT,
+            "send_worktree_prompt",
+            mcp_tool_capability("send_worktree_prompt").unwrap()
+        )
+        .is_ok());
+        assert_eq!(
+            require_capability(
+                &Capability::DEFAULT,
+                "pause_worktree",
+                mcp_tool_capability("pause_worktree").unwrap()
+            ),
+            Err("Permission denied: pause_worktree requires the \"destructive\" capability, which this API token does not grant.".to_string())
+        );
+        assert_eq!(mcp_tool_capability("drop_database"), None);
+    }
+
+    #[test]
+    fn digests_are_stable_and_ignore_surrounding_whitespace() {
+        let digest = api_token_digest("groove_abc");
+        assert_eq!(digest.len(), 64);
+        assert_eq!(digest, api_token_digest(" groove_abc\n"));
+        assert_ne!(digest, api_token_digest("groove_abd"));
+    }
+}
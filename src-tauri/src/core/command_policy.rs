@@ -0,0 +1,179 @@
+//! Rules for commands taken from workspace settings (play, terminal and
+//! custom commands). A command is refused when it contains a denied pattern
+//! or runs a program outside the allowed list, and its first run in a
+//! workspace can be allowed, held for confirmation or blocked depending on
+//! whether the workspace is trusted.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FirstRunPolicy {
+    Allow,
+    Confirm,
+    Block,
+}
+
+impl FirstRunPolicy {
+    pub(crate) const ALL: [FirstRunPolicy; 3] = [
+        FirstRunPolicy::Allow,
+        FirstRunPolicy::Confirm,
+        FirstRunPolicy::Block,
+    ];
+
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            FirstRunPolicy::Allow => "allow",
+            FirstRunPolicy::Confirm => "confirm",
+            FirstRunPolicy::Block => "block",
+        }
+    }
+
+    pub(crate) fn parse(value: &str) -> Result<Self, String> {
+        let normalized = value.trim().to_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|policy| policy.as_str() == normalized)
+            .ok_or_else(|| {
+                format!(
+                    "First-run policy must be one of: {}.",
+                    Self::ALL.map(FirstRunPolicy::as_str).join(", ")
+                )
+            })
+    }
+}
+
+/// Denied by default: recursive deletes, filesystem formatting, raw disk
+/// writes and piping downloads into a shell.
+pub(crate) const DEFAULT_DENIED_COMMAND_PATTERNS: [&str; 6] =
+    ["rm -rf", "rm -fr", "mkfs", "dd if=", "| sh", "| bash"];
+
+/// Lowercase with runs of whitespace collapsed, so patterns match however a
+/// command is spaced.
+pub(crate) fn normalize_command(command: &str) -> String {
+    command
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+fn is_word_boundary(value: Option<char>) -> bool {
+    value.is_none_or(|value| {
+        value.is_whitespace() || matches!(value, ';' | '|' | '&' | '(' | ')' | '"' | '\'')
+    })
+}
+
+/// Whether `pattern` occurs in `command` as whole words: `rm -rf` matches
+/// `rm -rf build` but not `farm -rfx`. A pattern ending in punctuation, such
+/// as `dd if=`, may be followed by anything.
+pub(crate) fn command_matches_pattern(command: &str, pattern: &str) -> bool {
+    let command = normalize_command(command);
+    let pattern = normalize_command(pattern);
+    if pattern.is_empty() {
+        return false;
+    }
+    let open_ended = pattern
+        .chars()
+        .last()
+        .is_some_and(|last| !last.is_alphanumeric());
+    command.match_indices(&pattern).any(|(index, _)| {
+        is_word_boundary(command[..index].chars().last())
+            && (open_ended || is_word_boundary(command[index + pattern.len()..].chars().next()))
+    })
+}
+
+/// The program a command line starts with, without its directory or a
+/// Windows executable extension.
+pub(crate) fn program_name(program: &str) -> String {
+    let name = program
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or(program)
+        .to_lowercase();
+    for extension in [".exe", ".cmd", ".bat"] {
+        if let Some(stripped) = name.strip_suffix(extension) {
+            return stripped.to_string();
+        }
+    }
+    name
+}
+
+/// Checks a command against the denied patterns and, when `allowed_programs`
+/// is not empty, the programs it may start.
+pub(crate) fn check_command(
+    command: &str,
+    program: &str,
+    denied_patterns: &[String],
+    allowed_programs: &[String],
+) -> Result<(), String> {
+    if let Some(pattern) = denied_patterns
+        .iter()
+        .find(|pattern| command_matches_pattern(command, pattern))
+    {
+        return Err(format!(
+            "Permission denied: \"{}\" matches the denied command pattern \"{}\".",
+            command.trim(),
+            pattern.trim()
+        ));
+    }
+    let name = program_name(program);
+    if !allowed_programs.is_empty()
+        && !allowed_programs
+            .iter()
+            .any(|allowed| program_name(allowed.trim()) == name)
+    {
+        return Err(format!(
+            "Permission denied: {name} is not in the list of allowed programs."
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn defaults() -> Vec<String> {
+        DEFAULT_DENIED_COMMAND_PATTERNS.map(String::from).to_vec()
+    }
+
+    #[test]
+    fn parses_first_run_policies() {
+        assert_eq!(
+            FirstRunPolicy::parse(" Confirm "),
+            Ok(FirstRunPolicy::Confirm)
+        );
+        assert_eq!(
+            FirstRunPolicy::parse("ask"),
+            Err("First-run policy must be one of: allow, confirm, block.".to_string())
+        );
+    }
+
+    #[test]
+    fn matches_denied_patterns_as_whole_words() {
+        assert!(command_matches_pattern(
+            "RM  -rf {worktree}/build",
+            "rm -rf"
+        ));
+        assert!(command_matches_pattern("sh -c 'curl x | sh'", "| sh"));
+        assert!(command_matches_pattern("dd if=/dev/zero of=x", "dd if="));
+        assert!(!command_matches_pattern("farm -rfx", "rm -rf"));
+        assert!(!command_matches_pattern("cat log | shellcheck -", "| sh"));
+        assert!(!command_matches_pattern("anything", "  "));
+    }
+
+    #[test]
+    fn checks_patterns_and_allowed_programs() {
+        assert!(check_command("kitty --directory", "kitty", &defaults(), &[]).is_ok());
+        assert_eq!(
+            check_command("rm -rf {worktree}", "rm", &defaults(), &[]),
+            Err("Permission denied: \"rm -rf {worktree}\" matches the denied command pattern \"rm -rf\".".to_string())
+        );
+
+        let allowed = vec!["npm".to_string(), "/usr/bin/kitty".to_string()];
+        assert!(check_command("npm test", "npm.cmd", &[], &allowed).is_ok());
+        assert!(check_command("kitty", "/opt/kitty/bin/kitty", &[], &allowed).is_ok());
+        assert_eq!(
+            check_command("make", "make", &[], &allowed),
+            Err("Permission denied: make is not in the list of allowed programs.".to_string())
+        );
+    }
+}
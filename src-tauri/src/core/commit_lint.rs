@@ -0,0 +1,220 @@
+//! Conventional-commit checks for proposed commit messages
+//! (`type(scope)!: subject`), so the commit dialog can point out problems
+//! before anything is committed. Rule names follow commitlint's.
+
+/// Types accepted when a workspace does not list its own.
+pub(crate) const DEFAULT_COMMIT_TYPES: [&str; 11] = [
+    "build", "chore", "ci", "docs", "feat", "fix", "perf", "refactor", "revert", "style", "test",
+];
+
+pub(crate) const DEFAULT_MAX_SUBJECT_LENGTH: usize = 72;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CommitLintRules<'a> {
+    /// Empty means `DEFAULT_COMMIT_TYPES`.
+    pub(crate) types: &'a [String],
+    /// Empty means any scope.
+    pub(crate) scopes: &'a [String],
+    pub(crate) require_scope: bool,
+    /// Applies to the whole header line, in characters.
+    pub(crate) max_subject_length: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CommitLintIssue {
+    pub(crate) rule: &'static str,
+    pub(crate) message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CommitHeader<'a> {
+    kind: &'a str,
+    scope: Option<&'a str>,
+    subject: &'a str,
+}
+
+fn parse_header(header: &str) -> Option<CommitHeader<'_>> {
+    let (prefix, subject) = header.split_once(':')?;
+    let prefix = prefix.strip_suffix('!').unwrap_or(prefix);
+    let (kind, scope) = match prefix.split_once('(') {
+        Some((kind, rest)) => (kind, Some(rest.strip_suffix(')')?)),
+        None => (prefix, None),
+    };
+    let is_word = |value: &str| {
+        !value.is_empty()
+            && value
+                .chars()
+                .all(|character| character.is_ascii_alphanumeric() || character == '-')
+    };
+    if !is_word(kind) || scope.is_some_and(|scope| scope.is_empty() || scope.contains(['(', ')'])) {
+        return None;
+    }
+    Some(CommitHeader {
+        kind,
+        scope,
+        subject: subject.trim(),
+    })
+}
+
+/// Messages git writes itself, which conventional-commit tooling skips.
+fn is_generated_message(header: &str) -> bool {
+    ["Merge ", "Revert \"", "fixup! ", "squash! ", "amend! "]
+        .iter()
+        .any(|prefix| header.starts_with(prefix))
+}
+
+pub(crate) fn lint_commit_message(message: &str, rules: &CommitLintRules) -> Vec<CommitLintIssue> {
+    let mut lines = message.trim().lines();
+    let header = lines.next().unwrap_or_default().trim_end();
+    let issue = |rule: &'static str, message: String| CommitLintIssue { rule, message };
+
+    if header.is_empty() {
+        return vec![issue(
+            "header-empty",
+            "The commit message is empty.".to_string(),
+        )];
+    }
+    if is_generated_message(header) {
+        return Vec::new();
+    }
+
+    let mut issues = Vec::new();
+    let header_length = header.chars().count();
+    if header_length > rules.max_subject_length {
+        issues.push(issue(
+            "header-max-length",
+            format!(
+                "The first line is {header_length} characters; keep it within {}.",
+                rules.max_subject_length
+            ),
+        ));
+    }
+    if lines.next().is_some_and(|line| !line.trim().is_empty()) {
+        issues.push(issue(
+            "body-leading-blank",
+            "Leave a blank line between the first line and the body.".to_string(),
+        ));
+    }
+
+    let Some(parsed) = parse_header(header) else {
+        issues.push(issue(
+            "header-format",
+            "Start with \"type: subject\" or \"type(scope): subject\".".to_string(),
+        ));
+        return issues;
+    };
+
+    let known_type = if rules.types.is_empty() {
+        DEFAULT_COMMIT_TYPES.contains(&parsed.kind)
+    } else {
+        rules.types.iter().any(|kind| kind == parsed.kind)
+    };
+    if !known_type {
+        let allowed = if rules.types.is_empty() {
+            DEFAULT_COMMIT_TYPES.join(", ")
+        } else {
+            rules.types.join(", ")
+        };
+        issues.push(issue(
+            "type-enum",
+            format!("Type \"{}\" is not one of: {allowed}.", parsed.kind),
+        ));
+    }
+    match parsed.scope {
+        None if rules.require_scope => issues.push(issue(
+            "scope-empty",
+            "A scope is required, e.g. \"fix(api): ...\".".to_string(),
+        )),
+        Some(scope)
+            if !rules.scopes.is_empty() && !rules.scopes.iter().any(|known| known == scope) =>
+        {
+            issues.push(issue(
+                "scope-enum",
+                format!(
+                    "Scope \"{scope}\" is not one of: {}.",
+                    rules.scopes.join(", ")
+                ),
+            ))
+        }
+        _ => {}
+    }
+    if parsed.subject.is_empty() {
+        issues.push(issue(
+            "subject-empty",
+            "Describe the change after the colon.".to_string(),
+        ));
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules<'a>(types: &'a [String], scopes: &'a [String]) -> CommitLintRules<'a> {
+        CommitLintRules {
+            types,
+            scopes,
+            require_scope: false,
+            max_subject_length: DEFAULT_MAX_SUBJECT_LENGTH,
+        }
+    }
+
+    fn rule_names(issues: &[CommitLintIssue]) -> Vec<&'static str> {
+        issues.iter().map(|issue| issue.rule).collect()
+    }
+
+    #[test]
+    fn accepts_conventional_messages() {
+        let defaults = rules(&[], &[]);
+        assert!(lint_commit_message("feat: add tmux mode", &defaults).is_empty());
+        assert!(
+            lint_commit_message("fix(api)!: drop v1\n\nBREAKING CHANGE: gone", &defaults)
+                .is_empty()
+        );
+        assert!(lint_commit_message("Merge branch 'main' into feature", &defaults).is_empty());
+        assert!(lint_commit_message("fixup! feat: add tmux mode", &defaults).is_empty());
+    }
+
+    #[test]
+    fn reports_rule_violations() {
+        let defaults = rules(&[], &[]);
+        assert_eq!(
+            rule_names(&lint_commit_message("update stuff", &defaults)),
+            vec!["header-format"]
+        );
+        assert_eq!(
+            rule_names(&lint_commit_message("feature: x\nbody", &defaults)),
+            vec!["body-leading-blank", "type-enum"]
+        );
+        assert_eq!(
+            rule_names(&lint_commit_message("fix:  ", &defaults)),
+            vec!["subject-empty"]
+        );
+        assert_eq!(
+            rule_names(&lint_commit_message(
+                &format!("fix: {}", "x".repeat(80)),
+                &defaults
+            )),
+            vec!["header-max-length"]
+        );
+        assert_eq!(
+            rule_names(&lint_commit_message("  \n", &defaults)),
+            vec!["header-empty"]
+        );
+
+        let types = vec!["feat".to_string()];
+        let scopes = vec!["ui".to_string()];
+        let mut strict = rules(&types, &scopes);
+        strict.require_scope = true;
+        assert!(lint_commit_message("feat(ui): tweak", &strict).is_empty());
+        assert_eq!(
+            rule_names(&lint_commit_message("fix(api): tweak", &strict)),
+            vec!["type-enum", "scope-enum"]
+        );
+        assert_eq!(
+            rule_names(&lint_commit_message("feat: tweak", &strict)),
+            vec!["scope-empty"]
+        );
+    }
+}
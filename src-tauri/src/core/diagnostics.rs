@@ -0,0 +1,480 @@
+use std::collections::{HashMap, HashSet};
+
+pub(crate) fn collect_descendant_pids(
+    snapshot_rows: &[(i32, Option<i32>)],
+    root_pid: i32,
+) -> Vec<i32> {
+    if root_pid <= 0 {
+        return Vec::new();
+    }
+
+    let mut children_by_parent: HashMap<i32, Vec<i32>> = HashMap::new();
+    for (pid, ppid) in snapshot_rows {
+        let Some(parent_pid) = *ppid else {
+            continue;
+        };
+        children_by_parent.entry(parent_pid).or_default().push(*pid);
+    }
+
+    let mut stack = vec![root_pid];
+    let mut descendants = Vec::new();
+    let mut seen = HashSet::new();
+
+    while let Some(parent_pid) = stack.pop() {
+        let Some(children) = children_by_parent.get(&parent_pid) else {
+            continue;
+        };
+
+        for child_pid in children {
+            if *child_pid <= 0 || !seen.insert(*child_pid) {
+                continue;
+            }
+
+            descendants.push(*child_pid);
+            stack.push(*child_pid);
+        }
+    }
+
+    descendants
+}
+
+pub(crate) fn parse_basic_csv_line(raw: &str) -> Vec<String> {
+    let mut values = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let chars = raw.chars().collect::<Vec<_>>();
+    let mut index = 0usize;
+
+    while index < chars.len() {
+        let ch = chars[index];
+        if ch == '"' {
+            if in_quotes && index + 1 < chars.len() && chars[index + 1] == '"' {
+                current.push('"');
+                index += 2;
+                continue;
+            }
+            in_quotes = !in_quotes;
+            index += 1;
+            continue;
+        }
+
+        if ch == ',' && !in_quotes {
+            values.push(current.trim().to_string());
+            current.clear();
+            index += 1;
+            continue;
+        }
+
+        current.push(ch);
+        index += 1;
+    }
+
+    values.push(current.trim().to_string());
+    values
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DiagnosticsStopCategory {
+    Opencode,
+    NodeDevServers,
+    Workspace,
+}
+
+impl DiagnosticsStopCategory {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            DiagnosticsStopCategory::Opencode => "opencode",
+            DiagnosticsStopCategory::NodeDevServers => "nodeDevServers",
+            DiagnosticsStopCategory::Workspace => "workspace",
+        }
+    }
+}
+
+pub(crate) fn parse_diagnostics_stop_category(
+    value: &str,
+) -> Result<DiagnosticsStopCategory, String> {
+    match value.trim() {
+        "opencode" => Ok(DiagnosticsStopCategory::Opencode),
+        "nodeDevServers" => Ok(DiagnosticsStopCategory::NodeDevServers),
+        "workspace" => Ok(DiagnosticsStopCategory::Workspace),
+        _ => Err(
+            "category must be \"opencode\", \"nodeDevServers\", or \"workspace\".".to_string(),
+        ),
+    }
+}
+
+pub(crate) fn is_opencode_command(process_name: Option<&str>, command: &str) -> bool {
+    let is_opencode_token = |token: &str| {
+        let file_name = token
+            .rsplit(['/', '\\'])
+            .next()
+            .unwrap_or(token)
+            .to_lowercase();
+        file_name == "opencode" || file_name == "opencode.exe"
+    };
+
+    if process_name.map(is_opencode_token).unwrap_or(false) {
+        return true;
+    }
+
+    command
+        .split_whitespace()
+        .take(2)
+        .any(is_opencode_token)
+}
+
+/// Whether a command line references a path inside `workspace_root`. Paths
+/// are compared with forward slashes and case-insensitively so Windows
+/// command lines match too. A `\\?\` prefix on the root, as on canonical
+/// Windows paths, is ignored.
+pub(crate) fn command_mentions_workspace_root(command: &str, workspace_root: &str) -> bool {
+    let root = workspace_root.replace('\\', "/").to_lowercase();
+    let root = match root.strip_prefix("//?/") {
+        Some(verbatim) => match verbatim.strip_prefix("unc/") {
+            Some(share) => format!("//{share}"),
+            None => verbatim.to_string(),
+        },
+        None => root,
+    };
+    let root = root.trim_end_matches('/');
+    if root.is_empty() {
+        return false;
+    }
+
+    let normalized = command.replace('\\', "/").to_lowercase();
+    normalized.match_indices(&root).any(|(index, _)| {
+        matches!(
+            normalized[index + root.len()..].chars().next(),
+            None | Some('/') | Some(' ') | Some('"') | Some('\'')
+        )
+    })
+}
+
+/// Maps pids to the worktree they work in. A process belongs to the worktree
+/// whose path its command line mentions, the deepest one when paths nest.
+/// Otherwise it belongs to the worktree of its nearest assigned ancestor,
+/// where `seeds` assign processes that name no path, such as terminal shells.
+pub(crate) fn attribute_processes_to_worktrees(
+    processes: &[(i32, Option<i32>, &str)],
+    worktree_paths: &[(String, String)],
+    seeds: &[(String, i32)],
+) -> HashMap<i32, String> {
+    let mut owners = HashMap::new();
+    for (pid, _, command) in processes {
+        let owner = worktree_paths
+            .iter()
+            .filter(|(_, path)| command_mentions_workspace_root(command, path))
+            .max_by_key(|(_, path)| path.len());
+        if let Some((worktree, _)) = owner {
+            owners.insert(*pid, worktree.clone());
+        }
+    }
+    for (worktree, pid) in seeds {
+        owners.entry(*pid).or_insert_with(|| worktree.clone());
+    }
+
+    let mut children_by_parent: HashMap<i32, Vec<i32>> = HashMap::new();
+    for (pid, ppid, _) in processes {
+        if let Some(parent_pid) = ppid {
+            children_by_parent
+                .entry(*parent_pid)
+                .or_default()
+                .push(*pid);
+        }
+    }
+    let mut stack = owners.keys().copied().collect::<Vec<_>>();
+    while let Some(parent_pid) = stack.pop() {
+        let Some(owner) = owners.get(&parent_pid).cloned() else {
+            continue;
+        };
+        for child_pid in children_by_parent.get(&parent_pid).into_iter().flatten() {
+            if !owners.contains_key(child_pid) {
+                owners.insert(*child_pid, owner.clone());
+                stack.push(*child_pid);
+            }
+        }
+    }
+    owners
+}
+
+/// The Groove subsystem a descriptor of the app belongs to, from its
+/// `/proc/<pid>/fd` link target.
+pub(crate) fn classify_fd_target(target: &str, scrollback_dir: &str) -> &'static str {
+    if target == "/dev/ptmx" || target.starts_with("/dev/pts/") {
+        "terminals"
+    } else if !scrollback_dir.is_empty() && target.starts_with(scrollback_dir) {
+        "terminalScrollback"
+    } else if target == "anon_inode:inotify" {
+        "watchers"
+    } else if target.starts_with("socket:") {
+        "sockets"
+    } else if target.starts_with("pipe:") {
+        "pipes"
+    } else {
+        "files"
+    }
+}
+
+/// Soft and hard `Max open files` from `/proc/<pid>/limits`, `None` for
+/// unlimited.
+pub(crate) fn parse_open_files_limit(limits: &str) -> Option<(Option<u64>, Option<u64>)> {
+    let line = limits
+        .lines()
+        .find(|line| line.starts_with("Max open files"))?;
+    let mut values = line["Max open files".len()..].split_whitespace();
+    let soft = values.next()?.parse().ok();
+    let hard = values.next()?.parse().ok();
+    Some((soft, hard))
+}
+
+/// Watches held by an inotify instance, from its `/proc/<pid>/fdinfo/<fd>`.
+pub(crate) fn count_inotify_watches(fdinfo: &str) -> usize {
+    fdinfo
+        .lines()
+        .filter(|line| line.starts_with("inotify wd:"))
+        .count()
+}
+
+/// Idle worktrees to stop, largest first, until their memory covers
+/// `reclaim_bytes`; all of them when even that falls short. Rows are
+/// `(worktree, rss_bytes, cpu_percent)`.
+pub(crate) fn idle_worktrees_to_stop(
+    worktrees: &[(String, u64, f64)],
+    reclaim_bytes: u64,
+    idle_cpu_percent: f64,
+) -> Vec<String> {
+    let mut idle = worktrees
+        .iter()
+        .filter(|(_, rss_bytes, cpu_percent)| *rss_bytes > 0 && *cpu_percent < idle_cpu_percent)
+        .collect::<Vec<_>>();
+    idle.sort_by(|left, right| right.1.cmp(&left.1).then_with(|| left.0.cmp(&right.0)));
+
+    let mut reclaimed = 0u64;
+    idle.into_iter()
+        .take_while(|(_, rss_bytes, _)| {
+            let covered = reclaimed >= reclaim_bytes;
+            reclaimed = reclaimed.saturating_add(*rss_bytes);
+            !covered
+        })
+        .map(|(worktree, _, _)| worktree.clone())
+        .collect()
+}
+
+/// Checks that `pid` is still the process Groove recorded before it is
+/// signalled, so a PID reused by an unrelated process is left alone.
+/// `actual` is the `(start time, command line)` the OS reports now. A
+/// recorded start time must match exactly; records without one fall back to
+/// the program of the recorded command appearing in the command line.
+pub(crate) fn verify_recorded_process(
+    pid: i32,
+    recorded_command: &str,
+    recorded_started_at: Option<&str>,
+    actual: Option<(&str, &str)>,
+) -> Result<(), String> {
+    let Some((actual_started_at, actual_command)) = actual else {
+        return Err(format!(
+            "PID {pid} could not be verified as the recorded process because its start time and command could not be read. Refusing to stop it."
+        ));
+    };
+
+    if let Some(recorded_started_at) = recorded_started_at
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        if recorded_started_at == actual_started_at.trim() {
+            return Ok(());
+        }
+        return Err(format!(
+            "PID {pid} is no longer the recorded process: it started at {}, not {recorded_started_at}. Refusing to stop it.",
+            actual_started_at.trim()
+        ));
+    }
+
+    let Some(program) = recorded_command
+        .split_whitespace()
+        .next()
+        .map(crate::core::command_policy::program_name)
+    else {
+        return Ok(());
+    };
+    if actual_command.to_lowercase().contains(&program) {
+        return Ok(());
+    }
+    Err(format!(
+        "PID {pid} is no longer the recorded process: its command \"{}\" does not run {program}. Refusing to stop it.",
+        actual_command.trim()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collects_descendants_for_nested_tree() {
+        let rows = vec![(10, None), (11, Some(10)), (12, Some(11)), (13, Some(10))];
+        let mut descendants = collect_descendant_pids(&rows, 10);
+        descendants.sort();
+        assert_eq!(descendants, vec![11, 12, 13]);
+    }
+
+    #[test]
+    fn parses_stop_categories_and_rejects_unknown_values() {
+        assert_eq!(
+            parse_diagnostics_stop_category(" opencode "),
+            Ok(DiagnosticsStopCategory::Opencode)
+        );
+        assert_eq!(
+            parse_diagnostics_stop_category("nodeDevServers").map(DiagnosticsStopCategory::as_str),
+            Ok("nodeDevServers")
+        );
+        assert!(parse_diagnostics_stop_category("testing").is_err());
+    }
+
+    #[test]
+    fn matches_opencode_binary_but_not_arguments_mentioning_it() {
+        assert!(is_opencode_command(Some("opencode"), "opencode"));
+        assert!(is_opencode_command(None, "/usr/local/bin/opencode --port 4096"));
+        assert!(is_opencode_command(None, "node /home/me/.bun/bin/opencode"));
+        assert!(!is_opencode_command(Some("vim"), "vim notes/opencode.md"));
+    }
+
+    #[test]
+    fn workspace_root_match_requires_a_path_boundary() {
+        assert!(command_mentions_workspace_root(
+            "node /repo/app/.worktrees/feat/server.js",
+            "/repo/app"
+        ));
+        assert!(command_mentions_workspace_root(
+            "node C:\\Repo\\App\\index.js",
+            "c:\\repo\\app\\"
+        ));
+        assert!(command_mentions_workspace_root(
+            "node C:\\Repo\\App\\index.js",
+            "\\\\?\\C:\\Repo\\App"
+        ));
+        assert!(command_mentions_workspace_root(
+            "node \\\\server\\share\\app\\index.js",
+            "\\\\?\\UNC\\server\\share\\app"
+        ));
+        assert!(!command_mentions_workspace_root("node /repo/app-two/index.js", "/repo/app"));
+        assert!(!command_mentions_workspace_root("node index.js", ""));
+    }
+
+    #[test]
+    fn attributes_processes_to_the_deepest_worktree_and_their_descendants() {
+        let worktrees = vec![
+            ("feat".to_string(), "/repo/.worktrees/feat".to_string()),
+            (
+                "feat-two".to_string(),
+                "/repo/.worktrees/feat-two".to_string(),
+            ),
+        ];
+        let processes = vec![
+            (10, Some(1), "node /repo/.worktrees/feat/server.js"),
+            (11, Some(10), "esbuild --service"),
+            (20, Some(1), "opencode /repo/.worktrees/feat-two"),
+            (30, Some(1), "/bin/zsh"),
+            (31, Some(30), "cargo build"),
+            (40, Some(1), "/bin/zsh"),
+        ];
+        let seeds = vec![("feat-two".to_string(), 30)];
+
+        let owners = attribute_processes_to_worktrees(&processes, &worktrees, &seeds);
+        assert_eq!(owners.get(&10).map(String::as_str), Some("feat"));
+        assert_eq!(owners.get(&11).map(String::as_str), Some("feat"));
+        assert_eq!(owners.get(&20).map(String::as_str), Some("feat-two"));
+        assert_eq!(owners.get(&31).map(String::as_str), Some("feat-two"));
+        assert!(!owners.contains_key(&40));
+    }
+
+    #[test]
+    fn picks_the_largest_idle_worktrees_until_the_excess_is_covered() {
+        let worktrees = vec![
+            ("small".to_string(), 100, 0.5),
+            ("busy".to_string(), 900, 40.0),
+            ("large".to_string(), 500, 1.0),
+            ("medium".to_string(), 300, 0.0),
+            ("stopped".to_string(), 0, 0.0),
+        ];
+
+        assert_eq!(
+            idle_worktrees_to_stop(&worktrees, 600, 5.0),
+            vec!["large", "medium"]
+        );
+        assert_eq!(idle_worktrees_to_stop(&worktrees, 500, 5.0), vec!["large"]);
+        assert_eq!(
+            idle_worktrees_to_stop(&worktrees, 5_000, 5.0),
+            vec!["large", "medium", "small"]
+        );
+        assert!(idle_worktrees_to_stop(&worktrees, 0, 5.0).is_empty());
+    }
+
+    #[test]
+    fn reads_descriptor_limits_and_inotify_watches() {
+        let limits = "Limit                     Soft Limit           Hard Limit           Units\n\
+                      Max cpu time              unlimited            unlimited            seconds\n\
+                      Max open files            1024                 524288               files\n";
+        assert_eq!(
+            parse_open_files_limit(limits),
+            Some((Some(1024), Some(524288)))
+        );
+        assert_eq!(
+            parse_open_files_limit(
+                "Max open files            unlimited            unlimited            files"
+            ),
+            Some((None, None))
+        );
+        assert_eq!(parse_open_files_limit("Max processes 10 10"), None);
+
+        let fdinfo = "pos:\t0\nflags:\t02004000\nmnt_id:\t15\ninotify wd:2 ino:1a sdev:fd00 mask:fc6 ignored_mask:0\ninotify wd:1 ino:2 sdev:fd00 mask:fc6 ignored_mask:0\n";
+        assert_eq!(count_inotify_watches(fdinfo), 2);
+
+        let scrollback = "/data/groove/terminal-scrollback";
+        assert_eq!(classify_fd_target("/dev/pts/3", scrollback), "terminals");
+        assert_eq!(
+            classify_fd_target("/data/groove/terminal-scrollback/a.log", scrollback),
+            "terminalScrollback"
+        );
+        assert_eq!(
+            classify_fd_target("anon_inode:inotify", scrollback),
+            "watchers"
+        );
+        assert_eq!(classify_fd_target("socket:[123]", scrollback), "sockets");
+        assert_eq!(classify_fd_target("/usr/lib/libc.so.6", ""), "files");
+    }
+
+    #[test]
+    fn recorded_processes_must_keep_their_start_time_or_command() {
+        let started = "Fri Oct 16 09:12:03 2026";
+        assert!(verify_recorded_process(
+            41,
+            "npm run dev",
+            Some(started),
+            Some((started, "node /usr/bin/npm run dev"))
+        )
+        .is_ok());
+        assert_eq!(
+            verify_recorded_process(
+                41,
+                "npm run dev",
+                Some(started),
+                Some(("Fri Oct 16 11:40:55 2026", "node /usr/bin/npm run dev"))
+            ),
+            Err("PID 41 is no longer the recorded process: it started at Fri Oct 16 11:40:55 2026, not Fri Oct 16 09:12:03 2026. Refusing to stop it.".to_string())
+        );
+
+        assert!(verify_recorded_process(
+            41,
+            "/usr/local/bin/claude --resume",
+            None,
+            Some(("", "claude --resume"))
+        )
+        .is_ok());
+        assert_eq!(
+            verify_recorded_process(41, "npm run dev", None, Some(("", "/usr/bin/postgres -D data"))),
+            Err("PID 41 is no longer the recorded process: its command \"/usr/bin/postgres -D data\" does not run npm. Refusing to stop it.".to_string())
+        );
+        assert!(verify_recorded_process(41, "npm run dev", None, None).is_err());
+    }
+}
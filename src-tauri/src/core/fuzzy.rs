@@ -0,0 +1,126 @@
+//! Fuzzy matching for the worktree switcher. Query characters must appear in
+//! order in the candidate; matches score higher when they are contiguous,
+//! start words (`feature/login-form` → `lf`) or cover a prefix.
+
+const SCORE_MATCH: i64 = 16;
+const BONUS_CONSECUTIVE: i64 = 8;
+const BONUS_WORD_START: i64 = 10;
+const BONUS_PREFIX: i64 = 50;
+const BONUS_EXACT: i64 = 100;
+const PENALTY_GAP_START: i64 = 3;
+const PENALTY_GAP_EXTENSION: i64 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FuzzyMatch {
+    pub(crate) score: i64,
+    /// Character (not byte) positions of the matched characters.
+    pub(crate) indices: Vec<usize>,
+}
+
+fn fold(character: char) -> char {
+    character.to_lowercase().next().unwrap_or(character)
+}
+
+fn is_word_start(characters: &[char], index: usize) -> bool {
+    let Some(previous) = index.checked_sub(1).map(|previous| characters[previous]) else {
+        return true;
+    };
+    let current = characters[index];
+    !previous.is_alphanumeric() || (previous.is_lowercase() && current.is_uppercase())
+}
+
+/// Matches `query` against `candidate`, ignoring case. The match is the
+/// shortest window ending at the first complete occurrence, found by a
+/// forward scan and then shrunk by a backward one.
+pub(crate) fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    let query = query.chars().map(fold).collect::<Vec<_>>();
+    if query.is_empty() {
+        return None;
+    }
+    let original = candidate.chars().collect::<Vec<_>>();
+    let folded = original.iter().copied().map(fold).collect::<Vec<_>>();
+
+    let mut next = 0;
+    let mut end = None;
+    for (index, character) in folded.iter().enumerate() {
+        if *character == query[next] {
+            next += 1;
+            if next == query.len() {
+                end = Some(index);
+                break;
+            }
+        }
+    }
+    let end = end?;
+
+    let mut indices = Vec::with_capacity(query.len());
+    let mut remaining = query.len();
+    for index in (0..=end).rev() {
+        if folded[index] == query[remaining - 1] {
+            indices.push(index);
+            remaining -= 1;
+            if remaining == 0 {
+                break;
+            }
+        }
+    }
+    indices.reverse();
+
+    let mut score = 0;
+    for (position, &index) in indices.iter().enumerate() {
+        score += SCORE_MATCH;
+        if is_word_start(&original, index) {
+            score += BONUS_WORD_START;
+        }
+        if let Some(&previous) = position
+            .checked_sub(1)
+            .and_then(|previous| indices.get(previous))
+        {
+            let gap = index - previous - 1;
+            if gap == 0 {
+                score += BONUS_CONSECUTIVE;
+            } else {
+                score -= PENALTY_GAP_START + PENALTY_GAP_EXTENSION * (gap as i64 - 1);
+            }
+        }
+    }
+    if folded.len() == query.len() {
+        score += BONUS_EXACT;
+    } else if folded.starts_with(&query) {
+        score += BONUS_PREFIX;
+    }
+
+    Some(FuzzyMatch { score, indices })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_subsequences_case_insensitively() {
+        let found = fuzzy_match("LGN", "feature/login").unwrap();
+        assert_eq!(found.indices, vec![8, 10, 12]);
+        assert!(fuzzy_match("xyz", "feature/login").is_none());
+        assert!(fuzzy_match("", "feature/login").is_none());
+        assert_eq!(
+            fuzzy_match("機能", "feat/機能-ブランチ").unwrap().indices,
+            vec![5, 6]
+        );
+    }
+
+    #[test]
+    fn ranks_tighter_and_word_start_matches_higher() {
+        let score = |query: &str, candidate: &str| fuzzy_match(query, candidate).unwrap().score;
+        assert!(score("login", "login") > score("login", "login-form"));
+        assert!(score("login", "login-form") > score("login", "feature/login-form"));
+        assert!(score("login", "feature/login") > score("login", "feature/l-o-g-i-n"));
+        assert!(score("lf", "login-form") > score("lf", "halfway"));
+        assert!(score("fl", "featureLogin") > score("fl", "fooflag"));
+    }
+
+    #[test]
+    fn shrinks_the_match_window() {
+        assert_eq!(fuzzy_match("ab", "a-x-ab").unwrap().indices, vec![4, 5]);
+    }
+}
@@ -0,0 +1,1743 @@
+use std::collections::{HashSet, VecDeque};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Default, Clone)]
+pub(crate) struct GitPorcelainCounts {
+    pub(crate) modified: u32,
+    pub(crate) added: u32,
+    pub(crate) deleted: u32,
+    pub(crate) untracked: u32,
+}
+
+impl GitPorcelainCounts {
+    pub(crate) fn dirty(&self) -> bool {
+        self.modified > 0 || self.added > 0 || self.deleted > 0 || self.untracked > 0
+    }
+}
+
+pub(crate) fn parse_git_porcelain_counts(output: &str) -> GitPorcelainCounts {
+    let mut counts = GitPorcelainCounts::default();
+
+    // Only trim the end: the leading column is the index state.
+    for line in output
+        .lines()
+        .map(str::trim_end)
+        .filter(|line| !line.is_empty())
+    {
+        if line.starts_with("??") {
+            counts.untracked += 1;
+            continue;
+        }
+
+        let bytes = line.as_bytes();
+        if bytes.len() < 2 {
+            continue;
+        }
+
+        let x = bytes[0] as char;
+        let y = bytes[1] as char;
+
+        if x == 'A' || y == 'A' {
+            counts.added += 1;
+        }
+        if x == 'D' || y == 'D' {
+            counts.deleted += 1;
+        }
+        if matches!(x, 'M' | 'R' | 'C' | 'T') || matches!(y, 'M' | 'R' | 'C' | 'T') {
+            counts.modified += 1;
+        }
+    }
+
+    counts
+}
+
+pub(crate) fn parse_git_ahead_behind(status_sb_output: &str) -> (u32, u32) {
+    let Some(first_line) = status_sb_output
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+    else {
+        return (0, 0);
+    };
+
+    let Some(bracket_start) = first_line.find('[') else {
+        return (0, 0);
+    };
+    let Some(bracket_end_rel) = first_line[bracket_start + 1..].find(']') else {
+        return (0, 0);
+    };
+
+    let mut ahead = 0u32;
+    let mut behind = 0u32;
+    let details = &first_line[bracket_start + 1..bracket_start + 1 + bracket_end_rel];
+    for part in details.split(',') {
+        let token = part.trim();
+        if let Some(value) = token.strip_prefix("ahead ") {
+            ahead = value.trim().parse::<u32>().unwrap_or(0);
+            continue;
+        }
+        if let Some(value) = token.strip_prefix("behind ") {
+            behind = value.trim().parse::<u32>().unwrap_or(0);
+        }
+    }
+
+    (ahead, behind)
+}
+
+pub(crate) fn parse_git_file_states(output: &str) -> (Vec<String>, Vec<String>, Vec<String>) {
+    let mut staged = HashSet::new();
+    let mut unstaged = HashSet::new();
+    let mut untracked = HashSet::new();
+
+    // Only trim the end: the leading column is the index state.
+    for line in output
+        .lines()
+        .map(str::trim_end)
+        .filter(|line| !line.is_empty())
+    {
+        if line.starts_with("??") {
+            if let Some(path) = normalize_git_status_path(line.get(2..).unwrap_or_default()) {
+                untracked.insert(path);
+            }
+            continue;
+        }
+
+        if line.len() < 3 {
+            continue;
+        }
+
+        let Some(path) = normalize_git_status_path(line.get(3..).unwrap_or_default()) else {
+            continue;
+        };
+        let bytes = line.as_bytes();
+        let index_state = bytes[0] as char;
+        let worktree_state = bytes[1] as char;
+
+        if index_state != ' ' && index_state != '?' {
+            staged.insert(path.clone());
+        }
+        if worktree_state != ' ' && worktree_state != '?' {
+            unstaged.insert(path);
+        }
+    }
+
+    let mut staged = staged.into_iter().collect::<Vec<_>>();
+    let mut unstaged = unstaged.into_iter().collect::<Vec<_>>();
+    let mut untracked = untracked.into_iter().collect::<Vec<_>>();
+    staged.sort();
+    unstaged.sort();
+    untracked.sort();
+
+    (staged, unstaged, untracked)
+}
+
+pub(crate) fn normalize_git_file_list(files: &[String]) -> Result<Vec<String>, String> {
+    let mut normalized = Vec::new();
+    let mut seen = HashSet::new();
+
+    for file in files {
+        let trimmed = file.trim();
+        if trimmed.is_empty() {
+            return Err("files entries must be non-empty strings.".to_string());
+        }
+        if trimmed.contains('\0') {
+            return Err("files entries cannot contain null bytes.".to_string());
+        }
+        if seen.insert(trimmed.to_string()) {
+            normalized.push(trimmed.to_string());
+        }
+    }
+
+    if normalized.is_empty() {
+        return Err("files must include at least one path.".to_string());
+    }
+
+    Ok(normalized)
+}
+
+fn normalize_git_status_path(value: &str) -> Option<String> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+
+    if let Some((_, right)) = trimmed.rsplit_once(" -> ") {
+        return Some(right.trim().to_string());
+    }
+
+    Some(trimmed.to_string())
+}
+
+/// Reads `git worktree list --porcelain` into `(branch, path)` pairs; the
+/// branch is `None` for detached or bare entries.
+pub(crate) fn parse_worktree_list_porcelain(output: &str) -> Vec<(Option<String>, String)> {
+    let mut entries = Vec::new();
+    let mut current_branch = None;
+    let mut current_path = None;
+
+    for raw_line in output.lines().chain(std::iter::once("")) {
+        let line = raw_line.trim();
+        if line.is_empty() {
+            if let Some(path) = current_path.take() {
+                entries.push((current_branch.take(), path));
+            }
+            continue;
+        }
+
+        if let Some(value) = line.strip_prefix("worktree ") {
+            current_path = Some(value.trim().to_string());
+            continue;
+        }
+
+        if let Some(value) = line.strip_prefix("branch ") {
+            let normalized = value
+                .trim()
+                .strip_prefix("refs/heads/")
+                .unwrap_or(value.trim())
+                .to_string();
+            current_branch = Some(normalized);
+        }
+    }
+
+    entries
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum GitOperationKind {
+    Read,
+    Write,
+    /// Talks to a remote and leaves the index and checked-out refs alone.
+    Network,
+}
+
+/// Decides whether a git invocation may touch `.git` locks. Anything not
+/// known to be read-only or network-only is treated as a write; `pull`
+/// merges into the worktree, so it is a write.
+pub(crate) fn classify_git_operation(args: &[&str]) -> GitOperationKind {
+    let Some(subcommand) = args.first() else {
+        return GitOperationKind::Read;
+    };
+    if matches!(*subcommand, "fetch" | "push" | "ls-remote") {
+        return GitOperationKind::Network;
+    }
+    let rest = &args[1..];
+    let read_only = match *subcommand {
+        "status" | "diff" | "show" | "log" | "rev-parse" | "rev-list" | "ls-files"
+        | "merge-base" | "cat-file" | "for-each-ref" | "describe" => true,
+        "branch" => rest.iter().all(|arg| {
+            matches!(
+                *arg,
+                "--show-current" | "--list" | "-a" | "--all" | "-r" | "-v"
+            ) || arg.starts_with("--format")
+        }),
+        "config" => rest.first().is_some_and(|arg| arg.starts_with("--get")),
+        "worktree" => rest.first() == Some(&"list"),
+        "remote" => rest.first() == Some(&"get-url") || rest.is_empty(),
+        "symbolic-ref" => rest.iter().filter(|arg| !arg.starts_with('-')).count() <= 1,
+        _ => false,
+    };
+    if read_only {
+        GitOperationKind::Read
+    } else {
+        GitOperationKind::Write
+    }
+}
+
+/// The git dir of the worktree containing `path`: `.git` itself, or the
+/// `.git/worktrees/<name>` directory a linked worktree's `.git` file names.
+pub(crate) fn resolve_git_dir(path: &Path) -> Option<PathBuf> {
+    for dir in path.ancestors() {
+        let dot_git = dir.join(".git");
+        if dot_git.is_dir() {
+            return Some(dot_git);
+        }
+        if !dot_git.is_file() {
+            continue;
+        }
+        let contents = fs::read_to_string(&dot_git).ok()?;
+        let git_dir = contents.trim().strip_prefix("gitdir:")?.trim();
+        return Some(dir.join(git_dir));
+    }
+    None
+}
+
+/// Finds the directory holding the shared index/refs locks for `path`. Linked
+/// worktrees point at `<common>/.git/worktrees/<name>` through a `.git` file,
+/// and that directory names the common dir in its `commondir` file.
+pub(crate) fn resolve_git_common_dir(path: &Path) -> Option<PathBuf> {
+    let git_dir = resolve_git_dir(path)?;
+    match fs::read_to_string(git_dir.join("commondir")) {
+        Ok(common) => {
+            let common_dir = git_dir.join(common.trim());
+            Some(common_dir.canonicalize().unwrap_or(common_dir))
+        }
+        Err(_) => Some(git_dir),
+    }
+}
+
+/// A remote a partial clone lazily fetches missing objects from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PartialClone {
+    pub(crate) remote: String,
+    pub(crate) filter: String,
+}
+
+/// Reads `git config --get-regexp '^remote\..*\.partialclonefilter$'` output.
+/// `origin` wins when several promisor remotes are configured.
+pub(crate) fn parse_partial_clone_config(output: &str) -> Option<PartialClone> {
+    let mut remotes = output.lines().filter_map(|line| {
+        let (key, filter) = line.trim().split_once(char::is_whitespace)?;
+        let remote = key
+            .strip_prefix("remote.")?
+            .strip_suffix(".partialclonefilter")?;
+        let filter = filter.trim();
+        (!remote.is_empty() && !filter.is_empty()).then(|| PartialClone {
+            remote: remote.to_string(),
+            filter: filter.to_string(),
+        })
+    });
+    let first = remotes.next()?;
+    if first.remote == "origin" {
+        return Some(first);
+    }
+    Some(
+        remotes
+            .find(|candidate| candidate.remote == "origin")
+            .unwrap_or(first),
+    )
+}
+
+/// Object ids `git rev-list --objects --missing=print` reported as absent
+/// (`?<oid>` lines), deduplicated in first-seen order.
+pub(crate) fn parse_missing_object_ids(rev_list_output: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    rev_list_output
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix('?'))
+        .filter(|oid| !oid.is_empty() && seen.insert(oid.to_string()))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Whether a failed git command died while demand-fetching from a promisor
+/// remote (offline, expired credentials) rather than for its own reasons.
+pub(crate) fn is_promisor_fetch_failure(stderr: &str) -> bool {
+    stderr.to_ascii_lowercase().contains("promisor remote")
+}
+
+/// Checks per-workspace git identity overrides before they are stored. Values
+/// end up in `-c key=value` arguments and environment variables, so line
+/// breaks and other control characters are rejected outright.
+pub(crate) fn validate_git_identity(
+    user_name: Option<&str>,
+    user_email: Option<&str>,
+    ssh_command: Option<&str>,
+) -> Result<(), String> {
+    for (field, value) in [
+        ("userName", user_name),
+        ("userEmail", user_email),
+        ("sshCommand", ssh_command),
+    ] {
+        if value.is_some_and(|value| value.chars().any(char::is_control)) {
+            return Err(format!("gitIdentity.{field} must be a single line."));
+        }
+    }
+    if user_name.is_some_and(|name| name.contains(['<', '>'])) {
+        return Err("gitIdentity.userName must not contain \"<\" or \">\".".to_string());
+    }
+    if let Some(email) = user_email {
+        let valid = email
+            .split_once('@')
+            .is_some_and(|(local, domain)| !local.is_empty() && !domain.is_empty())
+            && !email
+                .contains(|character: char| character.is_whitespace() || "<>".contains(character));
+        if !valid {
+            return Err(format!(
+                "gitIdentity.userEmail \"{email}\" is not an email address."
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Config overrides for a workspace identity, in the order git applies them.
+pub(crate) fn identity_config_pairs(
+    user_name: Option<&str>,
+    user_email: Option<&str>,
+    ssh_command: Option<&str>,
+) -> Vec<(&'static str, String)> {
+    [
+        ("user.name", user_name),
+        ("user.email", user_email),
+        ("core.sshCommand", ssh_command),
+    ]
+    .into_iter()
+    .filter_map(|(key, value)| Some((key, value?.to_string())))
+    .collect()
+}
+
+/// `-c key=value` arguments for a git invocation.
+pub(crate) fn config_override_args(pairs: &[(&str, String)]) -> Vec<String> {
+    pairs
+        .iter()
+        .flat_map(|(key, value)| ["-c".to_string(), format!("{key}={value}")])
+        .collect()
+}
+
+/// The same overrides as `GIT_CONFIG_COUNT`/`GIT_CONFIG_KEY_<n>`/
+/// `GIT_CONFIG_VALUE_<n>`, for processes that run git themselves.
+pub(crate) fn config_override_env(pairs: &[(&str, String)]) -> Vec<(String, String)> {
+    if pairs.is_empty() {
+        return Vec::new();
+    }
+    let mut env = vec![("GIT_CONFIG_COUNT".to_string(), pairs.len().to_string())];
+    for (index, (key, value)) in pairs.iter().enumerate() {
+        env.push((format!("GIT_CONFIG_KEY_{index}"), key.to_string()));
+        env.push((format!("GIT_CONFIG_VALUE_{index}"), value.clone()));
+    }
+    env
+}
+
+/// Hooks `git commit --no-verify` skips, in the order git runs them.
+pub(crate) const VERIFY_COMMIT_HOOKS: [&str; 2] = ["pre-commit", "commit-msg"];
+
+/// Which tool installed the repository's hooks: husky points
+/// `core.hooksPath` into `.husky`, pre-commit and lefthook keep their config
+/// at the repository root.
+pub(crate) fn detect_hook_manager(worktree_root: &Path, hooks_dir: &Path) -> Option<&'static str> {
+    if hooks_dir
+        .components()
+        .any(|component| component.as_os_str() == ".husky")
+    {
+        return Some("husky");
+    }
+    [
+        (".pre-commit-config.yaml", "pre-commit"),
+        ("lefthook.yml", "lefthook"),
+        (".lefthook.yml", "lefthook"),
+    ]
+    .into_iter()
+    .find(|(config, _)| worktree_root.join(config).is_file())
+    .map(|(_, manager)| manager)
+}
+
+/// `git hook run` only exists since git 2.36; older versions reject it.
+pub(crate) fn is_hook_run_unsupported(stderr: &str) -> bool {
+    stderr.contains("'hook' is not a git command")
+}
+
+/// `git log --format` for DCO checks: hash, `name <email>` author and
+/// subject split by unit separators, then the raw message; every commit ends
+/// with a record separator.
+pub(crate) const DCO_LOG_FORMAT: &str = "--format=%H%x1f%an <%ae>%x1f%s%x1f%B%x1e";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DcoCommit {
+    pub(crate) sha: String,
+    pub(crate) author: String,
+    pub(crate) subject: String,
+    /// Carries a `Signed-off-by` with the author's email, which is what DCO
+    /// checks compare.
+    pub(crate) signed_off: bool,
+}
+
+fn identity_email(identity: &str) -> Option<&str> {
+    let (_, rest) = identity.rsplit_once('<')?;
+    rest.split_once('>').map(|(email, _)| email.trim())
+}
+
+/// Values of the `trailer` lines in a commit message.
+fn trailer_values<'a>(message: &'a str, trailer: &str) -> Vec<&'a str> {
+    message
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.trim().split_once(':')?;
+            key.eq_ignore_ascii_case(trailer).then(|| value.trim())
+        })
+        .collect()
+}
+
+/// `Signed-off-by` identities in a commit message.
+pub(crate) fn sign_off_identities(message: &str) -> Vec<&str> {
+    trailer_values(message, "Signed-off-by")
+}
+
+pub(crate) fn parse_dco_log(output: &str) -> Vec<DcoCommit> {
+    output
+        .split('\u{1e}')
+        .filter_map(|record| {
+            let mut fields = record.trim_start_matches('\n').splitn(4, '\u{1f}');
+            let sha = fields.next().filter(|sha| !sha.is_empty())?;
+            let author = fields.next()?;
+            let subject = fields.next()?;
+            let message = fields.next().unwrap_or_default();
+            let author_email = identity_email(author);
+            let signed_off = sign_off_identities(message).into_iter().any(|identity| {
+                identity_email(identity)
+                    .zip(author_email)
+                    .is_some_and(|(signed, author)| signed.eq_ignore_ascii_case(author))
+            });
+            Some(DcoCommit {
+                sha: sha.to_string(),
+                author: author.to_string(),
+                subject: subject.to_string(),
+                signed_off,
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PrCommit {
+    pub(crate) sha: String,
+    /// `name <email>`.
+    pub(crate) author: String,
+    pub(crate) subject: String,
+    /// `Co-authored-by` identities of the commit.
+    pub(crate) co_authors: Vec<String>,
+}
+
+/// Parses `git log` output in `DCO_LOG_FORMAT` for a pull request
+/// description.
+pub(crate) fn parse_pr_commits(output: &str) -> Vec<PrCommit> {
+    output
+        .split('\u{1e}')
+        .filter_map(|record| {
+            let mut fields = record.trim_start_matches('\n').splitn(4, '\u{1f}');
+            let sha = fields.next().filter(|sha| !sha.is_empty())?;
+            let author = fields.next()?;
+            let subject = fields.next()?;
+            let message = fields.next().unwrap_or_default();
+            Some(PrCommit {
+                sha: sha.to_string(),
+                author: author.to_string(),
+                subject: subject.to_string(),
+                co_authors: trailer_values(message, "Co-authored-by")
+                    .into_iter()
+                    .map(str::to_string)
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+/// Commit authors and `Co-authored-by` identities other than `own_email`,
+/// once per email, oldest first. `commits` are newest first, like `git log`.
+pub(crate) fn pr_co_authors(commits: &[PrCommit], own_email: Option<&str>) -> Vec<String> {
+    let mut seen = own_email
+        .map(|email| email.trim().to_ascii_lowercase())
+        .into_iter()
+        .collect::<HashSet<_>>();
+    let mut co_authors = Vec::new();
+    for commit in commits.iter().rev() {
+        for identity in std::iter::once(&commit.author).chain(&commit.co_authors) {
+            let Some(email) = identity_email(identity) else {
+                continue;
+            };
+            if seen.insert(email.to_ascii_lowercase()) {
+                co_authors.push(identity.clone());
+            }
+        }
+    }
+    co_authors
+}
+
+/// Suggested pull request title: the subject of a lone commit, else the
+/// linked issue's title, else the branch name in words (`42-fix-login` reads
+/// `Fix login`).
+pub(crate) fn pr_title_from_commits(
+    commits: &[PrCommit],
+    branch: &str,
+    issue_title: Option<&str>,
+) -> String {
+    if let [commit] = commits {
+        return commit.subject.clone();
+    }
+    if let Some(title) = issue_title.map(str::trim).filter(|title| !title.is_empty()) {
+        return title.to_string();
+    }
+    let name = branch.rsplit('/').next().unwrap_or(branch);
+    let name = name
+        .split_once('-')
+        .filter(|(number, _)| {
+            !number.is_empty() && number.chars().all(|character| character.is_ascii_digit())
+        })
+        .map_or(name, |(_, rest)| rest);
+    let words = name
+        .split(['-', '_'])
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut characters = words.chars();
+    match characters.next() {
+        Some(first) => first.to_uppercase().chain(characters).collect(),
+        None => commits
+            .last()
+            .map(|commit| commit.subject.clone())
+            .unwrap_or_default(),
+    }
+}
+
+/// Markdown pull request body: the commits oldest first, `Closes #<issue>`
+/// and a `Co-authored-by` trailer per co-author.
+pub(crate) fn pr_body_from_commits(
+    commits: &[PrCommit],
+    co_authors: &[String],
+    issue: Option<i64>,
+) -> String {
+    let mut body = String::from("## Commits\n\n");
+    for commit in commits.iter().rev() {
+        let short_sha = commit.sha.chars().take(7).collect::<String>();
+        body.push_str(&format!("- {} ({short_sha})\n", commit.subject));
+    }
+    if let Some(issue) = issue {
+        body.push_str(&format!("\nCloses #{issue}\n"));
+    }
+    if !co_authors.is_empty() {
+        body.push('\n');
+        for co_author in co_authors {
+            body.push_str(&format!("Co-authored-by: {co_author}\n"));
+        }
+    }
+    body
+}
+
+/// `git log --format` for the history browser: hash, parent hashes, author
+/// name and email, strict ISO author date, ref decorations and subject split
+/// by unit separators; every commit ends with a record separator.
+pub(crate) const HISTORY_LOG_FORMAT: &str =
+    "--format=%H%x1f%P%x1f%an%x1f%ae%x1f%aI%x1f%D%x1f%s%x1e";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct HistoryCommit {
+    pub(crate) hash: String,
+    pub(crate) parents: Vec<String>,
+    pub(crate) author_name: String,
+    pub(crate) author_email: String,
+    pub(crate) authored_at: String,
+    /// `%D` split on commas, e.g. `HEAD -> main`, `origin/main`, `tag: v1.0`.
+    pub(crate) refs: Vec<String>,
+    pub(crate) subject: String,
+}
+
+pub(crate) fn parse_history_log(output: &str) -> Vec<HistoryCommit> {
+    output
+        .split('\u{1e}')
+        .filter_map(|record| {
+            let mut fields = record.trim_start_matches('\n').splitn(7, '\u{1f}');
+            let hash = fields.next().filter(|hash| !hash.is_empty())?;
+            let parents = fields.next()?;
+            let author_name = fields.next()?;
+            let author_email = fields.next()?;
+            let authored_at = fields.next()?;
+            let refs = fields.next()?;
+            let subject = fields.next().unwrap_or_default();
+            Some(HistoryCommit {
+                hash: hash.to_string(),
+                parents: parents.split_whitespace().map(str::to_string).collect(),
+                author_name: author_name.to_string(),
+                author_email: author_email.to_string(),
+                authored_at: authored_at.to_string(),
+                refs: refs
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|name| !name.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+                subject: subject.trim_end().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Ticket ID in a branch name, e.g. `PROJ-123` from `feature/proj-123-login`:
+/// 2 to 10 ASCII letters, a dash and digits, upper-cased.
+pub(crate) fn ticket_id_from_branch(branch: &str) -> Option<String> {
+    branch.split(['/', '_', '.']).find_map(|segment| {
+        let parts = segment.split('-').collect::<Vec<_>>();
+        parts.windows(2).find_map(|pair| {
+            let (letters, digits) = (pair[0], pair[1]);
+            let is_ticket = (2..=10).contains(&letters.len())
+                && letters
+                    .chars()
+                    .all(|character| character.is_ascii_alphabetic())
+                && !digits.is_empty()
+                && digits.chars().all(|character| character.is_ascii_digit());
+            is_ticket.then(|| format!("{}-{digits}", letters.to_ascii_uppercase()))
+        })
+    })
+}
+
+/// Branch name for work on a GitHub issue: its number and the title as a
+/// lower-case slug cut at a word within 40 characters, e.g.
+/// `42-fix-login-redirect-loop`.
+pub(crate) fn branch_name_for_issue(number: u64, title: &str) -> String {
+    const MAX_SLUG_LEN: usize = 40;
+    let mut slug = String::new();
+    for word in title
+        .split(|character: char| !character.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+    {
+        let extra = word.len() + usize::from(!slug.is_empty());
+        if slug.len() + extra > MAX_SLUG_LEN {
+            if slug.is_empty() {
+                slug.push_str(&word[..MAX_SLUG_LEN]);
+            }
+            break;
+        }
+        if !slug.is_empty() {
+            slug.push('-');
+        }
+        slug.push_str(&word.to_ascii_lowercase());
+    }
+    if slug.is_empty() {
+        format!("issue-{number}")
+    } else {
+        format!("{number}-{slug}")
+    }
+}
+
+/// `Key: value` with a key git's trailer parser accepts.
+pub(crate) fn is_commit_trailer(line: &str) -> bool {
+    line.split_once(':').is_some_and(|(key, value)| {
+        !key.is_empty()
+            && key
+                .chars()
+                .all(|character| character.is_ascii_alphanumeric() || character == '-')
+            && !value.trim().is_empty()
+    })
+}
+
+/// Appends `trailers` that `message` does not already carry. They join an
+/// existing trailer block at the end of the message, or start a new
+/// paragraph after the body.
+pub(crate) fn append_commit_trailers(message: &str, trailers: &[String]) -> String {
+    let message = message.trim_end();
+    let existing = message
+        .lines()
+        .map(|line| line.trim().to_ascii_lowercase())
+        .collect::<HashSet<_>>();
+    let mut seen = HashSet::new();
+    let missing = trailers
+        .iter()
+        .filter(|trailer| {
+            let key = trailer.trim().to_ascii_lowercase();
+            !existing.contains(&key) && seen.insert(key)
+        })
+        .map(|trailer| trailer.trim())
+        .collect::<Vec<_>>();
+    if missing.is_empty() {
+        return message.to_string();
+    }
+
+    let ends_with_trailer_block = message
+        .rsplit_once("\n\n")
+        .is_some_and(|(_, last)| last.lines().all(|line| is_commit_trailer(line.trim())));
+    let separator = if ends_with_trailer_block {
+        "\n"
+    } else {
+        "\n\n"
+    };
+    format!("{message}{separator}{}", missing.join("\n"))
+}
+
+/// Finds the rename `file` takes part in, as its old or new path, in
+/// `git diff --name-status -z` output. Returns `(old_path, new_path)`.
+pub(crate) fn find_rename_in_name_status(output: &str, file: &str) -> Option<(String, String)> {
+    let mut fields = output.split('\0').filter(|field| !field.is_empty());
+    while let Some(status) = fields.next() {
+        if status.starts_with('R') || status.starts_with('C') {
+            let old_path = fields.next()?;
+            let new_path = fields.next()?;
+            if status.starts_with('R') && (old_path == file || new_path == file) {
+                return Some((old_path.to_string(), new_path.to_string()));
+            }
+        } else {
+            fields.next()?;
+        }
+    }
+    None
+}
+
+/// A conflicted path from `git ls-files --unmerged -z`, with the blob id of
+/// each stage it has: 1 is the merge base, 2 ours, 3 theirs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct UnmergedFile {
+    pub(crate) path: String,
+    pub(crate) base: Option<String>,
+    pub(crate) ours: Option<String>,
+    pub(crate) theirs: Option<String>,
+}
+
+impl UnmergedFile {
+    /// How the two sides disagree, named after the `git status` descriptions.
+    pub(crate) fn conflict_kind(&self) -> &'static str {
+        match (self.base.is_some(), self.ours.is_some(), self.theirs.is_some()) {
+            (true, true, true) => "bothModified",
+            (false, true, true) => "bothAdded",
+            (true, false, true) => "deletedByUs",
+            (true, true, false) => "deletedByThem",
+            (false, true, false) => "addedByUs",
+            (false, false, true) => "addedByThem",
+            _ => "bothDeleted",
+        }
+    }
+}
+
+/// Groups `<mode> <object> <stage>\t<path>` records by path, sorted by path.
+pub(crate) fn parse_unmerged_files(output: &str) -> Vec<UnmergedFile> {
+    let mut files = std::collections::BTreeMap::<String, UnmergedFile>::new();
+    for record in output.split('\0').filter(|record| !record.is_empty()) {
+        let Some((info, path)) = record.split_once('\t') else {
+            continue;
+        };
+        let mut fields = info.split_whitespace();
+        let (Some(_mode), Some(object), Some(stage)) = (fields.next(), fields.next(), fields.next())
+        else {
+            continue;
+        };
+        let file = files.entry(path.to_string()).or_insert_with(|| UnmergedFile {
+            path: path.to_string(),
+            ..UnmergedFile::default()
+        });
+        let slot = match stage {
+            "1" => &mut file.base,
+            "2" => &mut file.ours,
+            "3" => &mut file.theirs,
+            _ => continue,
+        };
+        *slot = Some(object.to_string());
+    }
+    files.into_values().collect()
+}
+
+/// The operation that left the worktree with conflicts, read from the state
+/// files git keeps in the worktree's git dir.
+pub(crate) fn conflict_operation(git_dir: &Path) -> Option<&'static str> {
+    if git_dir.join("rebase-merge").is_dir() || git_dir.join("rebase-apply").is_dir() {
+        Some("rebase")
+    } else if git_dir.join("MERGE_HEAD").is_file() {
+        Some("merge")
+    } else if git_dir.join("CHERRY_PICK_HEAD").is_file() {
+        Some("cherry-pick")
+    } else if git_dir.join("REVERT_HEAD").is_file() {
+        Some("revert")
+    } else {
+        None
+    }
+}
+
+/// Where a rebase stopped, read from the `rebase-merge/` (or `rebase-apply/`)
+/// state directory in the worktree's git dir.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct RebaseProgress {
+    /// Branch being rebased; `None` when the rebase started on a detached HEAD.
+    pub(crate) branch: Option<String>,
+    /// Commit the branch is being replayed onto.
+    pub(crate) onto: Option<String>,
+    /// 1-based number of the commit being applied, and how many there are.
+    pub(crate) step: Option<u32>,
+    pub(crate) total: Option<u32>,
+}
+
+pub(crate) fn read_rebase_progress(git_dir: &Path) -> Option<RebaseProgress> {
+    let (state_dir, step_file, total_file) = if git_dir.join("rebase-merge").is_dir() {
+        (git_dir.join("rebase-merge"), "msgnum", "end")
+    } else if git_dir.join("rebase-apply").is_dir() {
+        (git_dir.join("rebase-apply"), "next", "last")
+    } else {
+        return None;
+    };
+    let read = |name: &str| {
+        fs::read_to_string(state_dir.join(name))
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+    Some(RebaseProgress {
+        branch: read("head-name")
+            .and_then(|name| name.strip_prefix("refs/heads/").map(str::to_string)),
+        onto: read("onto"),
+        step: read(step_file).and_then(|value| value.parse().ok()),
+        total: read(total_file).and_then(|value| value.parse().ok()),
+    })
+}
+
+/// A hunk of `git diff --unified=0` output, identified by the file it
+/// belongs to (the new path for renames) and its `@@` ranges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DiffHunkRef {
+    pub(crate) file_path: String,
+    pub(crate) old_start: u32,
+    pub(crate) old_lines: u32,
+    pub(crate) new_start: u32,
+    pub(crate) new_lines: u32,
+}
+
+/// `(old_start, old_lines, new_start, new_lines)` of a `@@ -a,b +c,d @@`
+/// line; a range without a count covers one line.
+fn parse_hunk_header_ranges(line: &str) -> Option<(u32, u32, u32, u32)> {
+    let inner = line.strip_prefix("@@ ")?;
+    let inner = &inner[..inner.find(" @@")?];
+    let (old, new) = inner.split_once(' ')?;
+    let range = |value: &str| -> Option<(u32, u32)> {
+        match value.split_once(',') {
+            Some((start, lines)) => Some((start.parse().ok()?, lines.parse().ok()?)),
+            None => Some((value.parse().ok()?, 1)),
+        }
+    };
+    let (old_start, old_lines) = range(old.strip_prefix('-')?)?;
+    let (new_start, new_lines) = range(new.strip_prefix('+')?)?;
+    Some((old_start, old_lines, new_start, new_lines))
+}
+
+/// Builds a patch from `diff_text` holding only the `selected` hunks, each
+/// file keeping its header so `git apply` accepts it. Hunk lines are copied
+/// verbatim, `\ No newline at end of file` markers included. Fails when a
+/// selection is not in the diff anymore, i.e. the file changed after the
+/// hunks were listed.
+pub(crate) fn select_diff_hunks(
+    diff_text: &str,
+    selected: &[DiffHunkRef],
+) -> Result<String, String> {
+    if selected.is_empty() {
+        return Err("No hunks selected.".to_string());
+    }
+
+    let mut patch = String::new();
+    let mut matched = vec![false; selected.len()];
+    let mut file_header = String::new();
+    let mut file_path: Option<String> = None;
+    let mut header_written = false;
+    let mut in_hunks = false;
+    let mut keep_hunk = false;
+
+    for line in diff_text.split_inclusive('\n') {
+        let content = line.trim_end_matches('\n');
+        if let Some(rest) = content.strip_prefix("diff --git ") {
+            file_header.clear();
+            file_header.push_str(line);
+            file_path = Some(
+                rest.split_once(" b/")
+                    .map_or(rest, |(_, new_path)| new_path)
+                    .to_string(),
+            );
+            header_written = false;
+            in_hunks = false;
+            keep_hunk = false;
+            continue;
+        }
+        if !in_hunks && !content.starts_with("@@") {
+            if let Some(new_path) = content.strip_prefix("rename to ") {
+                file_path = Some(new_path.to_string());
+            }
+            file_header.push_str(line);
+            continue;
+        }
+        if content.starts_with("@@") {
+            in_hunks = true;
+            keep_hunk = false;
+            if let (Some(path), Some((old_start, old_lines, new_start, new_lines))) =
+                (file_path.as_deref(), parse_hunk_header_ranges(content))
+            {
+                for (index, hunk) in selected.iter().enumerate() {
+                    if hunk.file_path == path
+                        && (hunk.old_start, hunk.old_lines, hunk.new_start, hunk.new_lines)
+                            == (old_start, old_lines, new_start, new_lines)
+                    {
+                        matched[index] = true;
+                        keep_hunk = true;
+                    }
+                }
+            }
+            if keep_hunk && !header_written {
+                patch.push_str(&file_header);
+                header_written = true;
+            }
+        }
+        if keep_hunk {
+            patch.push_str(line);
+            if !line.ends_with('\n') {
+                patch.push('\n');
+            }
+        }
+    }
+
+    if let Some(missing) = matched
+        .iter()
+        .position(|found| !found)
+        .map(|index| &selected[index])
+    {
+        return Err(format!(
+            "{}: hunk @@ -{},{} +{},{} @@ no longer matches the diff; refresh and try again.",
+            missing.file_path,
+            missing.old_start,
+            missing.old_lines,
+            missing.new_start,
+            missing.new_lines
+        ));
+    }
+    Ok(patch)
+}
+
+/// One step of git's `--progress` output, e.g. `Receiving objects:  45% (9/20)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct GitProgress {
+    pub(crate) phase: String,
+    pub(crate) percent: u8,
+    pub(crate) completed: Option<u64>,
+    pub(crate) total: Option<u64>,
+}
+
+/// Parses a single progress segment. Lines relayed from the remote keep
+/// their phase name without the `remote:` prefix; anything without a
+/// percentage (hints, ref updates, errors) yields `None`.
+pub(crate) fn parse_git_progress_line(line: &str) -> Option<GitProgress> {
+    let line = line.trim();
+    let line = line.strip_prefix("remote:").map(str::trim).unwrap_or(line);
+    let (phase, rest) = line.split_once(':')?;
+    let phase = phase.trim();
+    if phase.is_empty() {
+        return None;
+    }
+
+    let rest = rest.trim_start();
+    let (percent, rest) = rest.split_once('%')?;
+    let percent = percent.trim().parse::<u8>().ok().filter(|value| *value <= 100)?;
+    let counts = rest
+        .trim_start()
+        .strip_prefix('(')
+        .and_then(|value| value.split_once(')'))
+        .and_then(|(counts, _)| counts.split_once('/'))
+        .and_then(|(completed, total)| {
+            Some((completed.trim().parse().ok()?, total.trim().parse().ok()?))
+        });
+
+    Some(GitProgress {
+        phase: phase.to_string(),
+        percent,
+        completed: counts.map(|(completed, _)| completed),
+        total: counts.map(|(_, total)| total),
+    })
+}
+
+/// Splits streamed stderr into progress segments. Git redraws a phase with
+/// `\r` and finishes it with `\n`, and reads may end mid-segment, so the
+/// unterminated tail is held until the next chunk.
+#[derive(Debug, Default)]
+pub(crate) struct GitProgressReader {
+    pending: String,
+}
+
+impl GitProgressReader {
+    pub(crate) fn push(&mut self, chunk: &str) -> Vec<GitProgress> {
+        self.pending.push_str(chunk);
+        let Some(end) = self.pending.rfind(['\r', '\n']) else {
+            return Vec::new();
+        };
+        let complete = self.pending[..end].to_string();
+        self.pending.drain(..=end);
+        complete
+            .split(['\r', '\n'])
+            .filter_map(parse_git_progress_line)
+            .collect()
+    }
+}
+
+#[derive(Default)]
+struct GitOperationQueueState {
+    active_readers: usize,
+    writer_active: bool,
+    network_active: bool,
+    waiting_writers: VecDeque<u64>,
+    next_ticket: u64,
+}
+
+impl GitOperationQueueState {
+    /// Operations that have to finish before one of `kind` (holding `ticket`
+    /// when it is a queued write) can start.
+    fn ahead_of(&self, kind: GitOperationKind, ticket: u64) -> usize {
+        if kind == GitOperationKind::Network {
+            return usize::from(self.network_active);
+        }
+        let writers_ahead = match kind {
+            GitOperationKind::Read | GitOperationKind::Network => self.waiting_writers.len(),
+            GitOperationKind::Write => self
+                .waiting_writers
+                .iter()
+                .take_while(|queued| **queued != ticket)
+                .count(),
+        };
+        let readers_ahead = match kind {
+            GitOperationKind::Read | GitOperationKind::Network => 0,
+            GitOperationKind::Write => self.active_readers,
+        };
+        writers_ahead + readers_ahead + usize::from(self.writer_active)
+    }
+
+    fn can_start(&self, kind: GitOperationKind, ticket: u64) -> bool {
+        match kind {
+            // Queued writers go first so a steady stream of reads cannot
+            // starve a commit.
+            GitOperationKind::Read => !self.writer_active && self.waiting_writers.is_empty(),
+            GitOperationKind::Write => {
+                !self.writer_active
+                    && self.active_readers == 0
+                    && self.waiting_writers.front() == Some(&ticket)
+            }
+            GitOperationKind::Network => !self.network_active,
+        }
+    }
+}
+
+/// Per-repository reader/writer queue: reads run concurrently, writes run
+/// one at a time in arrival order and exclude reads. Network operations run
+/// one at a time in a lane of their own, so a slow transfer holds up
+/// neither reads nor writes.
+#[derive(Default)]
+pub(crate) struct GitOperationQueue {
+    state: Mutex<GitOperationQueueState>,
+    changed: Condvar,
+}
+
+pub(crate) struct GitOperationPermit {
+    queue: Arc<GitOperationQueue>,
+    kind: GitOperationKind,
+}
+
+impl GitOperationQueue {
+    fn lock_state(&self) -> MutexGuard<'_, GitOperationQueueState> {
+        // The state is a handful of counters updated without panicking
+        // calls, so a poisoned guard is still consistent.
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Operations running plus writes waiting for their turn. Waiting reads
+    /// are not tracked.
+    pub(crate) fn pending_operations(&self) -> usize {
+        let state = self.lock_state();
+        state.active_readers
+            + usize::from(state.writer_active)
+            + usize::from(state.network_active)
+            + state.waiting_writers.len()
+    }
+
+    /// Blocks until an operation of `kind` may run. While waiting,
+    /// `on_long_wait` is called every `report_interval` with the number of
+    /// operations still ahead and the time waited so far.
+    pub(crate) fn acquire(
+        self: &Arc<Self>,
+        kind: GitOperationKind,
+        report_interval: Duration,
+        mut on_long_wait: impl FnMut(usize, Duration),
+    ) -> GitOperationPermit {
+        let started_at = Instant::now();
+        let mut state = self.lock_state();
+        let ticket = state.next_ticket;
+        state.next_ticket += 1;
+        if kind == GitOperationKind::Write {
+            state.waiting_writers.push_back(ticket);
+        }
+
+        let mut next_report = report_interval;
+        while !state.can_start(kind, ticket) {
+            let remaining = next_report.saturating_sub(started_at.elapsed());
+            state = self
+                .changed
+                .wait_timeout(state, remaining)
+                .map(|(guard, _)| guard)
+                .unwrap_or_else(|poisoned| poisoned.into_inner().0);
+            if started_at.elapsed() >= next_report && !state.can_start(kind, ticket) {
+                on_long_wait(state.ahead_of(kind, ticket), started_at.elapsed());
+                next_report += report_interval;
+            }
+        }
+
+        match kind {
+            GitOperationKind::Read => state.active_readers += 1,
+            GitOperationKind::Write => {
+                state.waiting_writers.pop_front();
+                state.writer_active = true;
+            }
+            GitOperationKind::Network => state.network_active = true,
+        }
+        GitOperationPermit {
+            queue: Arc::clone(self),
+            kind,
+        }
+    }
+}
+
+impl Drop for GitOperationPermit {
+    fn drop(&mut self) {
+        let mut state = self.queue.lock_state();
+        match self.kind {
+            GitOperationKind::Read => state.active_readers -= 1,
+            GitOperationKind::Write => state.writer_active = false,
+            GitOperationKind::Network => state.network_active = false,
+        }
+        drop(state);
+        self.queue.changed.notify_all();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_ahead_behind_tokens() {
+        let (ahead, behind) = parse_git_ahead_behind("## main...origin/main [ahead 2, behind 1]");
+        assert_eq!((ahead, behind), (2, 1));
+    }
+
+    #[test]
+    fn parses_file_states() {
+        let output = "M  src/a.ts\n M src/b.ts\n?? src/c.ts\n";
+        let (staged, unstaged, untracked) = parse_git_file_states(output);
+        assert_eq!(staged, vec!["src/a.ts".to_string()]);
+        assert_eq!(unstaged, vec!["src/b.ts".to_string()]);
+        assert_eq!(untracked, vec!["src/c.ts".to_string()]);
+    }
+
+    #[test]
+    fn parses_git_progress_lines() {
+        assert_eq!(
+            parse_git_progress_line("Receiving objects:  45% (9/20), 1.20 MiB | 2.00 MiB/s"),
+            Some(GitProgress {
+                phase: "Receiving objects".to_string(),
+                percent: 45,
+                completed: Some(9),
+                total: Some(20),
+            })
+        );
+        assert_eq!(
+            parse_git_progress_line("remote: Counting objects: 100% (3/3), done."),
+            Some(GitProgress {
+                phase: "Counting objects".to_string(),
+                percent: 100,
+                completed: Some(3),
+                total: Some(3),
+            })
+        );
+        assert_eq!(parse_git_progress_line("From github.com:org/repo"), None);
+        assert_eq!(parse_git_progress_line("hint: use --rebase"), None);
+    }
+
+    #[test]
+    fn progress_reader_holds_partial_segments() {
+        let mut reader = GitProgressReader::default();
+        assert!(reader.push("Receiving objects:  1").is_empty());
+        let progress = reader.push("0% (1/10)\rReceiving objects:  20% (2/10)\rResol");
+        assert_eq!(
+            progress.iter().map(|step| step.percent).collect::<Vec<_>>(),
+            vec![10, 20]
+        );
+        let progress = reader.push("ving deltas: 100% (4/4), done.\n");
+        assert_eq!(progress.len(), 1);
+        assert_eq!(progress[0].phase, "Resolving deltas");
+    }
+
+    #[test]
+    fn builds_identity_config_overrides() {
+        let pairs = identity_config_pairs(
+            Some("Ada Lovelace"),
+            Some("ada@work.example"),
+            Some("ssh -i ~/.ssh/id_work -o IdentitiesOnly=yes"),
+        );
+        assert_eq!(
+            config_override_args(&pairs[..2]),
+            vec![
+                "-c",
+                "user.name=Ada Lovelace",
+                "-c",
+                "user.email=ada@work.example"
+            ]
+        );
+        let env = config_override_env(&pairs);
+        assert_eq!(env[0], ("GIT_CONFIG_COUNT".to_string(), "3".to_string()));
+        assert_eq!(
+            env[5],
+            (
+                "GIT_CONFIG_KEY_2".to_string(),
+                "core.sshCommand".to_string()
+            )
+        );
+        assert!(config_override_env(&identity_config_pairs(None, None, None)).is_empty());
+    }
+
+    #[test]
+    fn validates_git_identity() {
+        assert!(validate_git_identity(Some("Ada"), Some("ada@example.com"), None).is_ok());
+        assert!(validate_git_identity(Some("Ada\nEvil"), None, None).is_err());
+        assert!(validate_git_identity(Some("Ada <x>"), None, None).is_err());
+        assert!(validate_git_identity(None, Some("not-an-email"), None).is_err());
+        assert!(validate_git_identity(None, Some("a b@example.com"), None).is_err());
+    }
+
+    #[test]
+    fn detects_hook_managers() {
+        let root = std::env::temp_dir().join(format!("groove-git-hooks-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        let git_hooks = root.join(".git").join("hooks");
+        assert_eq!(detect_hook_manager(&root, &git_hooks), None);
+        assert_eq!(
+            detect_hook_manager(&root, &root.join(".husky").join("_")),
+            Some("husky")
+        );
+        fs::write(root.join(".pre-commit-config.yaml"), "repos: []\n").unwrap();
+        assert_eq!(detect_hook_manager(&root, &git_hooks), Some("pre-commit"));
+        let _ = fs::remove_dir_all(&root);
+
+        assert!(is_hook_run_unsupported(
+            "git: 'hook' is not a git command. See 'git --help'."
+        ));
+    }
+
+    #[test]
+    fn checks_commits_for_dco_sign_off() {
+        let output = "aaa\u{1f}Ada <ada@example.com>\u{1f}feat: x\u{1f}feat: x\n\nSigned-off-by: Ada L <ADA@example.com>\n\u{1e}\n\
+                      bbb\u{1f}Bob <bob@example.com>\u{1f}fix: y\u{1f}fix: y\n\nSigned-off-by: Ada <ada@example.com>\n\u{1e}\n\
+                      ccc\u{1f}Cy <cy@example.com>\u{1f}chore: z\u{1f}chore: z\n\u{1e}\n";
+        let commits = parse_dco_log(output);
+        assert_eq!(commits.len(), 3);
+        assert_eq!(commits[0].sha, "aaa");
+        assert_eq!(commits[0].subject, "feat: x");
+        assert!(commits[0].signed_off);
+        assert!(!commits[1].signed_off);
+        assert!(!commits[2].signed_off);
+        assert_eq!(
+            sign_off_identities("x\n\nsigned-off-by: Ada <a@b.c>"),
+            vec!["Ada <a@b.c>"]
+        );
+    }
+
+    #[test]
+    fn extracts_ticket_ids_from_branches() {
+        assert_eq!(
+            ticket_id_from_branch("feature/proj-123-login"),
+            Some("PROJ-123".to_string())
+        );
+        assert_eq!(ticket_id_from_branch("ABC-9"), Some("ABC-9".to_string()));
+        assert_eq!(
+            ticket_id_from_branch("fix/login-v2-JIRA-77"),
+            Some("JIRA-77".to_string())
+        );
+        assert_eq!(ticket_id_from_branch("feature/login"), None);
+        assert_eq!(ticket_id_from_branch("release/1.2-3"), None);
+    }
+
+    #[test]
+    fn prepares_pull_request_descriptions() {
+        let log = "b2\u{1f}Ada <ada@example.com>\u{1f}Add tests\u{1f}Add tests\n\nCo-authored-by: Lin <lin@example.com>\n\u{1e}\n\
+                   a1\u{1f}Me <me@example.com>\u{1f}Add login\u{1f}Add login\n\u{1e}\n";
+        let commits = parse_pr_commits(log);
+        assert_eq!(commits.len(), 2);
+        assert_eq!(commits[0].co_authors, vec!["Lin <lin@example.com>"]);
+
+        let co_authors = pr_co_authors(&commits, Some("ME@example.com"));
+        assert_eq!(
+            co_authors,
+            vec!["Ada <ada@example.com>", "Lin <lin@example.com>"]
+        );
+        assert_eq!(
+            pr_body_from_commits(&commits, &co_authors, Some(42)),
+            "## Commits\n\n- Add login (a1)\n- Add tests (b2)\n\nCloses #42\n\n\
+             Co-authored-by: Ada <ada@example.com>\nCo-authored-by: Lin <lin@example.com>\n"
+        );
+
+        assert_eq!(pr_title_from_commits(&commits[..1], "x", None), "Add tests");
+        assert_eq!(
+            pr_title_from_commits(&commits, "feature/42-fix_login-page", None),
+            "Fix login page"
+        );
+        assert_eq!(
+            pr_title_from_commits(&commits, "42-fix", Some("Login is broken")),
+            "Login is broken"
+        );
+    }
+
+    #[test]
+    fn names_branches_after_issues() {
+        assert_eq!(
+            branch_name_for_issue(42, "Fix login: redirect loop (Safari)"),
+            "42-fix-login-redirect-loop-safari"
+        );
+        assert_eq!(
+            branch_name_for_issue(
+                7,
+                "Crash when the workspace path contains emoji or other characters"
+            ),
+            "7-crash-when-the-workspace-path-contains"
+        );
+        assert_eq!(branch_name_for_issue(9, "🚀 ✨"), "issue-9");
+    }
+
+    #[test]
+    fn appends_commit_trailers() {
+        let trailers = vec![
+            "Co-authored-by: opencode <opencode@users.noreply.github.com>".to_string(),
+            "Refs: PROJ-123".to_string(),
+        ];
+        assert_eq!(
+            append_commit_trailers("fix: login\n", &trailers),
+            "fix: login\n\nCo-authored-by: opencode <opencode@users.noreply.github.com>\nRefs: PROJ-123"
+        );
+        assert_eq!(
+            append_commit_trailers("fix: login\n\nBody.\n\nRefs: PROJ-123", &trailers),
+            "fix: login\n\nBody.\n\nRefs: PROJ-123\nCo-authored-by: opencode <opencode@users.noreply.github.com>"
+        );
+        assert_eq!(
+            append_commit_trailers("fix: login\n\nrefs: proj-123", &trailers[1..]),
+            "fix: login\n\nrefs: proj-123"
+        );
+        assert!(is_commit_trailer("Signed-off-by: Ada <ada@example.com>"));
+        assert!(!is_commit_trailer("Not a trailer: here"));
+        assert!(!is_commit_trailer("Refs:"));
+    }
+
+    #[test]
+    fn parses_partial_clone_config() {
+        assert_eq!(parse_partial_clone_config(""), None);
+        assert_eq!(
+            parse_partial_clone_config(
+                "remote.fork.partialclonefilter tree:0\nremote.origin.partialclonefilter blob:none\n"
+            ),
+            Some(PartialClone {
+                remote: "origin".to_string(),
+                filter: "blob:none".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_partial_clone_config("remote.upstream.partialclonefilter blob:limit=1m"),
+            Some(PartialClone {
+                remote: "upstream".to_string(),
+                filter: "blob:limit=1m".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn parses_missing_object_ids() {
+        let output = "f4003d6\n07946d2 \n?5ea2ed4\n5ea2ed4 f1\n?8c1384d\n?5ea2ed4\n";
+        assert_eq!(
+            parse_missing_object_ids(output),
+            vec!["5ea2ed4".to_string(), "8c1384d".to_string()]
+        );
+        assert!(is_promisor_fetch_failure(
+            "fatal: could not fetch 5ea2ed4 from promisor remote"
+        ));
+        assert!(!is_promisor_fetch_failure("fatal: invalid reference: main"));
+    }
+
+    #[test]
+    fn parses_worktree_list_porcelain() {
+        let output = "worktree /srv/repo\nHEAD abc\nbranch refs/heads/main\n\nworktree /srv/repo/.worktrees/fix\nHEAD def\ndetached\n";
+        assert_eq!(
+            parse_worktree_list_porcelain(output),
+            vec![
+                (Some("main".to_string()), "/srv/repo".to_string()),
+                (None, "/srv/repo/.worktrees/fix".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn classifies_git_operations() {
+        assert_eq!(
+            classify_git_operation(&["status", "--porcelain=v1"]),
+            GitOperationKind::Read
+        );
+        assert_eq!(
+            classify_git_operation(&["branch", "--show-current"]),
+            GitOperationKind::Read
+        );
+        assert_eq!(
+            classify_git_operation(&["branch", "--format=%(refname:short)"]),
+            GitOperationKind::Read
+        );
+        assert_eq!(
+            classify_git_operation(&["worktree", "list", "--porcelain"]),
+            GitOperationKind::Read
+        );
+        assert_eq!(
+            classify_git_operation(&["branch", "-D", "feature"]),
+            GitOperationKind::Write
+        );
+        assert_eq!(
+            classify_git_operation(&["commit", "-m", "x"]),
+            GitOperationKind::Write
+        );
+        assert_eq!(
+            classify_git_operation(&["merge", "--abort"]),
+            GitOperationKind::Write
+        );
+        assert_eq!(
+            classify_git_operation(&["worktree", "add", "x"]),
+            GitOperationKind::Write
+        );
+        assert_eq!(
+            classify_git_operation(&["fetch", "--prune", "origin"]),
+            GitOperationKind::Network
+        );
+        assert_eq!(
+            classify_git_operation(&["push", "origin", "feature"]),
+            GitOperationKind::Network
+        );
+        assert_eq!(
+            classify_git_operation(&["pull", "--ff-only"]),
+            GitOperationKind::Write
+        );
+    }
+
+    #[test]
+    fn resolves_common_dir_of_linked_worktree() {
+        let root = std::env::temp_dir().join(format!("groove-git-common-{}", std::process::id()));
+        let common = root.join("repo").join(".git");
+        let linked_git_dir = common.join("worktrees").join("feature");
+        let worktree = root.join("feature");
+        fs::create_dir_all(&linked_git_dir).unwrap();
+        fs::create_dir_all(worktree.join("src")).unwrap();
+        fs::write(linked_git_dir.join("commondir"), "../..\n").unwrap();
+        fs::write(
+            worktree.join(".git"),
+            format!("gitdir: {}\n", linked_git_dir.display()),
+        )
+        .unwrap();
+
+        let expected = common.canonicalize().unwrap();
+        assert_eq!(
+            resolve_git_common_dir(&worktree.join("src")),
+            Some(expected.clone())
+        );
+        assert_eq!(resolve_git_common_dir(&root.join("repo")), Some(common));
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn queued_write_blocks_new_reads_until_it_runs() {
+        let queue = Arc::new(GitOperationQueue::default());
+        let read = queue.acquire(GitOperationKind::Read, Duration::from_secs(60), |_, _| {});
+
+        let writer_queue = Arc::clone(&queue);
+        let writer = std::thread::spawn(move || {
+            let mut positions = Vec::new();
+            let _permit = writer_queue.acquire(
+                GitOperationKind::Write,
+                Duration::from_millis(20),
+                |ahead, _| positions.push(ahead),
+            );
+            positions
+        });
+
+        while queue.lock_state().waiting_writers.is_empty() {
+            std::thread::yield_now();
+        }
+        {
+            let state = queue.lock_state();
+            assert!(!state.can_start(GitOperationKind::Read, u64::MAX));
+        }
+
+        std::thread::sleep(Duration::from_millis(50));
+        drop(read);
+        let positions = writer.join().unwrap();
+        assert!(!positions.is_empty());
+        assert!(positions.iter().all(|ahead| *ahead == 1));
+
+        let state = queue.lock_state();
+        assert_eq!(state.active_readers, 0);
+        assert!(!state.writer_active);
+    }
+
+    #[test]
+    fn network_operations_run_beside_reads_and_writes_but_not_each_other() {
+        let queue = Arc::new(GitOperationQueue::default());
+        let report = Duration::from_secs(60);
+        let fetch = queue.acquire(GitOperationKind::Network, report, |_, _| {});
+        let write = queue.acquire(GitOperationKind::Write, report, |_, _| {});
+        {
+            let state = queue.lock_state();
+            assert!(!state.can_start(GitOperationKind::Network, u64::MAX));
+            assert_eq!(state.ahead_of(GitOperationKind::Network, u64::MAX), 1);
+        }
+        drop(write);
+        let read = queue.acquire(GitOperationKind::Read, report, |_, _| {});
+        assert_eq!(queue.pending_operations(), 2);
+
+        drop(fetch);
+        drop(read);
+        let state = queue.lock_state();
+        assert!(state.can_start(GitOperationKind::Network, u64::MAX));
+        assert_eq!(state.active_readers, 0);
+    }
+
+    const HUNK_DIFF: &str = "diff --git a/src/lib.rs b/src/lib.rs
+index 1111111..2222222 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -2 +2 @@ fn main() {
+-    old();
++    new();
+@@ -10,0 +11,2 @@ fn tail() {
++    one();
++    two();
+diff --git a/old.txt b/new.txt
+similarity index 90%
+rename from old.txt
+rename to new.txt
+index 3333333..4444444 100644
+--- a/old.txt
++++ b/new.txt
+@@ -5 +4,0 @@
+-gone
+\\ No newline at end of file
+";
+
+    fn hunk_ref(file_path: &str, ranges: (u32, u32, u32, u32)) -> DiffHunkRef {
+        DiffHunkRef {
+            file_path: file_path.to_string(),
+            old_start: ranges.0,
+            old_lines: ranges.1,
+            new_start: ranges.2,
+            new_lines: ranges.3,
+        }
+    }
+
+    #[test]
+    fn selects_hunks_with_their_file_headers() {
+        let patch = select_diff_hunks(
+            HUNK_DIFF,
+            &[hunk_ref("src/lib.rs", (10, 0, 11, 2)), hunk_ref("new.txt", (5, 1, 4, 0))],
+        )
+        .unwrap();
+
+        assert_eq!(
+            patch,
+            "diff --git a/src/lib.rs b/src/lib.rs
+index 1111111..2222222 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -10,0 +11,2 @@ fn tail() {
++    one();
++    two();
+diff --git a/old.txt b/new.txt
+similarity index 90%
+rename from old.txt
+rename to new.txt
+index 3333333..4444444 100644
+--- a/old.txt
++++ b/new.txt
+@@ -5 +4,0 @@
+-gone
+\\ No newline at end of file
+"
+        );
+    }
+
+    #[test]
+    fn rejects_hunks_missing_from_the_diff() {
+        let error = select_diff_hunks(HUNK_DIFF, &[hunk_ref("src/lib.rs", (3, 1, 3, 1))])
+            .unwrap_err();
+        assert!(error.starts_with("src/lib.rs: hunk @@ -3,1 +3,1 @@"));
+        assert!(select_diff_hunks(HUNK_DIFF, &[]).is_err());
+    }
+
+    #[test]
+    fn parses_history_log_records() {
+        let output = "aaa\u{1f}\u{1f}Ada\u{1f}ada@example.com\u{1f}2024-05-01T10:00:00+02:00\u{1f}tag: v1.0\u{1f}Initial commit\u{1e}\n\
+bbb\u{1f}aaa ccc\u{1f}Grace\u{1f}grace@example.com\u{1f}2024-05-02T09:30:00+00:00\u{1f}HEAD -> main, origin/main\u{1f}Merge branch 'topic'\u{1e}\n";
+
+        let commits = parse_history_log(output);
+        assert_eq!(commits.len(), 2);
+        assert!(commits[0].parents.is_empty());
+        assert_eq!(commits[0].refs, ["tag: v1.0"]);
+        assert_eq!(commits[1].hash, "bbb");
+        assert_eq!(commits[1].parents, ["aaa", "ccc"]);
+        assert_eq!(commits[1].author_email, "grace@example.com");
+        assert_eq!(commits[1].refs, ["HEAD -> main", "origin/main"]);
+        assert_eq!(commits[1].subject, "Merge branch 'topic'");
+    }
+
+    #[test]
+    fn finds_renames_by_either_path() {
+        let output = "M\0src/a.rs\0R087\0old/name.rs\0new/name.rs\0C100\0b.rs\0c.rs\0";
+        let rename = Some(("old/name.rs".to_string(), "new/name.rs".to_string()));
+        assert_eq!(find_rename_in_name_status(output, "old/name.rs"), rename);
+        assert_eq!(find_rename_in_name_status(output, "new/name.rs"), rename);
+        assert_eq!(find_rename_in_name_status(output, "src/a.rs"), None);
+        assert_eq!(find_rename_in_name_status(output, "c.rs"), None);
+    }
+
+    #[test]
+    fn groups_unmerged_stages_by_path() {
+        let output = "100644 aaa 1\tsrc/both.rs\0100644 bbb 2\tsrc/both.rs\0100644 ccc 3\tsrc/both.rs\0\
+100644 ddd 1\tgone.rs\0100644 eee 3\tgone.rs\0100644 fff 2\tnew.rs\0100644 ggg 3\tnew.rs\0";
+
+        let files = parse_unmerged_files(output);
+        let summary = files
+            .iter()
+            .map(|file| (file.path.as_str(), file.conflict_kind()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            [
+                ("gone.rs", "deletedByUs"),
+                ("new.rs", "bothAdded"),
+                ("src/both.rs", "bothModified"),
+            ]
+        );
+        assert_eq!(files[2].base.as_deref(), Some("aaa"));
+        assert_eq!(files[2].ours.as_deref(), Some("bbb"));
+        assert_eq!(files[2].theirs.as_deref(), Some("ccc"));
+    }
+
+    #[test]
+    fn reads_rebase_progress_from_the_state_directory() {
+        let git_dir =
+            std::env::temp_dir().join(format!("groove-git-rebase-{}", std::process::id()));
+        let state_dir = git_dir.join("rebase-merge");
+        fs::create_dir_all(&git_dir).unwrap();
+        assert_eq!(read_rebase_progress(&git_dir), None);
+
+        fs::create_dir_all(&state_dir).unwrap();
+        fs::write(state_dir.join("head-name"), "refs/heads/feature/login\n").unwrap();
+        fs::write(state_dir.join("onto"), "abc123\n").unwrap();
+        fs::write(state_dir.join("msgnum"), "2\n").unwrap();
+        fs::write(state_dir.join("end"), "5\n").unwrap();
+        assert_eq!(
+            read_rebase_progress(&git_dir),
+            Some(RebaseProgress {
+                branch: Some("feature/login".to_string()),
+                onto: Some("abc123".to_string()),
+                step: Some(2),
+                total: Some(5),
+            })
+        );
+
+        fs::write(state_dir.join("head-name"), "detached HEAD\n").unwrap();
+        assert_eq!(read_rebase_progress(&git_dir).unwrap().branch, None);
+
+        let _ = fs::remove_dir_all(&git_dir);
+    }
+
+    #[test]
+    fn detects_the_operation_behind_conflicts() {
+        let git_dir =
+            std::env::temp_dir().join(format!("groove-git-conflicts-{}", std::process::id()));
+        fs::create_dir_all(&git_dir).unwrap();
+        assert_eq!(conflict_operation(&git_dir), None);
+
+        fs::write(git_dir.join("MERGE_HEAD"), "abc\n").unwrap();
+        assert_eq!(conflict_operation(&git_dir), Some("merge"));
+
+        fs::create_dir_all(git_dir.join("rebase-merge")).unwrap();
+        assert_eq!(conflict_operation(&git_dir), Some("rebase"));
+
+        let _ = fs::remove_dir_all(&git_dir);
+    }
+}
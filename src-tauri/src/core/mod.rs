@@ -0,0 +1,18 @@
+//! Domain logic behind the Tauri commands, kept free of `AppHandle` and
+//! managed state so each module can be unit-tested on its own. The command
+//! handlers in `backend` reach these through the prelude.
+
+pub(crate) mod capabilities;
+pub(crate) mod command_policy;
+pub(crate) mod commit_lint;
+pub(crate) mod diagnostics;
+pub(crate) mod fuzzy;
+pub(crate) mod git;
+pub(crate) mod redaction;
+pub(crate) mod remote;
+pub(crate) mod retry;
+pub(crate) mod terminal;
+pub(crate) mod tmux;
+pub(crate) mod workspace;
+pub(crate) mod wsl;
+pub(crate) mod zellij;
@@ -0,0 +1,185 @@
+//! Redaction of filesystem paths in backend log lines. Workspace and
+//! worktree paths often carry a user or client name, so logs can keep only
+//! the last path component or replace each path with a short stable hash.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PathRedaction {
+    Off,
+    Basename,
+    Hash,
+}
+
+impl PathRedaction {
+    pub(crate) const ALL: [PathRedaction; 3] = [
+        PathRedaction::Off,
+        PathRedaction::Basename,
+        PathRedaction::Hash,
+    ];
+
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            PathRedaction::Off => "off",
+            PathRedaction::Basename => "basename",
+            PathRedaction::Hash => "hash",
+        }
+    }
+
+    pub(crate) fn parse(value: &str) -> Result<Self, String> {
+        let normalized = value.trim().to_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|mode| mode.as_str() == normalized)
+            .ok_or_else(|| {
+                format!(
+                    "telemetryPathRedaction must be one of: {}.",
+                    Self::ALL.map(PathRedaction::as_str).join(", ")
+                )
+            })
+    }
+}
+
+/// Characters a path in a log line can follow: the start of the line,
+/// whitespace, `key=`, quotes and opening brackets.
+fn is_path_boundary(previous: Option<char>) -> bool {
+    previous.is_none_or(|previous| {
+        previous.is_whitespace() || matches!(previous, '=' | '"' | '\'' | '(' | '[' | ',')
+    })
+}
+
+fn is_path_end(value: char) -> bool {
+    value.is_whitespace() || matches!(value, '"' | '\'' | ')' | ']' | ',' | ';')
+}
+
+/// Length in bytes of the absolute path starting `rest`, if one does: a Unix
+/// path with at least two components, a `~/` path, a Windows drive path or a
+/// UNC path.
+fn absolute_path_len(rest: &str) -> Option<usize> {
+    let bytes = rest.as_bytes();
+    let starts_path = match bytes {
+        [b'/', next, ..] => *next != b'/' && !is_path_end(char::from(*next)),
+        [b'~', b'/' | b'\\', ..] | [b'\\', b'\\', ..] => true,
+        [drive, b':', b'\\' | b'/', ..] => drive.is_ascii_alphabetic(),
+        _ => false,
+    };
+    if !starts_path {
+        return None;
+    }
+    let len = rest.find(is_path_end).unwrap_or(rest.len());
+    let path = &rest[..len];
+    let separators = path.matches(['/', '\\']).count();
+    (separators >= 2 || !path.starts_with('/')).then_some(len)
+}
+
+/// 64-bit FNV-1a, stable across runs and builds so hashed paths can be
+/// matched between log files.
+fn fnv1a(value: &str) -> u64 {
+    value.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+fn redact_path(path: &str, mode: PathRedaction) -> String {
+    match mode {
+        PathRedaction::Off => path.to_string(),
+        PathRedaction::Basename => {
+            let basename = path
+                .split(['/', '\\'])
+                .rev()
+                .find(|component| !component.is_empty())
+                .unwrap_or_default();
+            format!(".../{basename}")
+        }
+        PathRedaction::Hash => {
+            let normalized = path.replace('\\', "/");
+            format!(
+                "<path:{:08x}>",
+                fnv1a(normalized.trim_end_matches('/')) >> 32
+            )
+        }
+    }
+}
+
+/// `line` with every absolute path redacted per `mode`. Paths containing
+/// spaces are only redacted up to the first space.
+pub(crate) fn redact_paths(line: &str, mode: PathRedaction) -> String {
+    if mode == PathRedaction::Off {
+        return line.to_string();
+    }
+
+    let mut redacted = String::with_capacity(line.len());
+    let mut previous = None;
+    let mut index = 0;
+    while let Some(current) = line[index..].chars().next() {
+        if is_path_boundary(previous) {
+            if let Some(len) = absolute_path_len(&line[index..]) {
+                let path = &line[index..index + len];
+                redacted.push_str(&redact_path(path, mode));
+                previous = path.chars().last();
+                index += len;
+                continue;
+            }
+        }
+        redacted.push(current);
+        previous = Some(current);
+        index += current.len_utf8();
+    }
+    redacted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_redaction_modes() {
+        assert_eq!(PathRedaction::parse(" Hash "), Ok(PathRedaction::Hash));
+        assert_eq!(
+            PathRedaction::parse("full"),
+            Err("telemetryPathRedaction must be one of: off, basename, hash.".to_string())
+        );
+    }
+
+    #[test]
+    fn redacts_unix_windows_and_home_paths_to_their_basename() {
+        assert_eq!(
+            redact_paths(
+                "event=play workspace_root=/home/alice/acme-app worktree=feat cwd=\"C:\\Users\\Bob\\acme\"",
+                PathRedaction::Basename
+            ),
+            "event=play workspace_root=.../acme-app worktree=feat cwd=\".../acme\""
+        );
+        assert_eq!(
+            redact_paths("opened ~/clients/acme/ (ok)", PathRedaction::Basename),
+            "opened .../acme (ok)"
+        );
+        assert_eq!(
+            redact_paths("share=\\\\server\\clients\\acme", PathRedaction::Basename),
+            "share=.../acme"
+        );
+    }
+
+    #[test]
+    fn leaves_urls_ratios_and_short_paths_alone() {
+        let line = "remote=https://github.com/acme/app ratio=3/4 root=/ tmp=/tmp";
+        assert_eq!(redact_paths(line, PathRedaction::Basename), line);
+        assert_eq!(redact_paths(line, PathRedaction::Off), line);
+    }
+
+    #[test]
+    fn hashes_paths_stably_regardless_of_separators() {
+        let unix = redact_paths("root=/home/alice/acme", PathRedaction::Hash);
+        assert_eq!(
+            unix,
+            redact_paths("root=/home/alice/acme/", PathRedaction::Hash)
+        );
+        assert_ne!(
+            unix,
+            redact_paths("root=/home/bob/acme", PathRedaction::Hash)
+        );
+        assert!(unix.starts_with("root=<path:") && !unix.contains("alice"));
+        assert_eq!(
+            redact_paths("C:\\Users\\alice", PathRedaction::Hash),
+            redact_paths("C:/Users/alice", PathRedaction::Hash)
+        );
+    }
+}
@@ -0,0 +1,164 @@
+//! Builds the `ssh` invocations used by remote workspaces. Everything the
+//! remote host runs goes through a POSIX shell, so commands are rendered as a
+//! single quoted string rather than an argv.
+
+/// Options every non-interactive call passes: never prompt for a password
+/// (the UI has nowhere to type it) and give up quickly on a dead host.
+const SSH_BATCH_OPTIONS: [&str; 4] = ["-o", "BatchMode=yes", "-o", "ConnectTimeout=10"];
+
+/// Accepts `host`, `user@host`, `host.domain` and ssh config aliases. Leading
+/// dashes and whitespace are rejected so the value can never be read as an
+/// ssh option.
+pub(crate) fn validate_ssh_destination(destination: &str) -> Result<(), String> {
+    if destination.is_empty() {
+        return Err("remote destination is required and must be a non-empty string.".to_string());
+    }
+    if destination.starts_with('-') {
+        return Err("remote destination must not start with \"-\".".to_string());
+    }
+    let allowed = |character: char| {
+        character.is_ascii_alphanumeric()
+            || matches!(character, '@' | '.' | '_' | '-' | ':' | '[' | ']')
+    };
+    if !destination.chars().all(allowed) || destination.matches('@').count() > 1 {
+        return Err(format!(
+            "remote destination \"{destination}\" is invalid; use host or user@host."
+        ));
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_remote_root(root: &str) -> Result<(), String> {
+    if !root.starts_with('/') {
+        return Err("remote root must be an absolute path.".to_string());
+    }
+    if root.chars().any(char::is_control) || root.split('/').any(|segment| segment == "..") {
+        return Err("remote root contains unsafe characters or path segments.".to_string());
+    }
+    Ok(())
+}
+
+/// Quotes `value` for a POSIX shell.
+pub(crate) fn shell_quote(value: &str) -> String {
+    if !value.is_empty()
+        && value
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric() || "-_./=:@%+,".contains(character))
+    {
+        return value.to_string();
+    }
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+/// `cd <cwd> && exec <argv...>`, ready to hand to the remote shell.
+pub(crate) fn remote_shell_command(cwd: &str, argv: &[String]) -> String {
+    let argv = argv
+        .iter()
+        .map(|arg| shell_quote(arg))
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!("cd {} && exec {argv}", shell_quote(cwd))
+}
+
+/// Starts the user's login shell in `cwd` on the remote host.
+pub(crate) fn remote_login_shell_command(cwd: &str) -> String {
+    format!("cd {} && exec \"${{SHELL:-/bin/sh}}\" -l", shell_quote(cwd))
+}
+
+/// Arguments for the local `ssh` binary. Interactive sessions get a TTY and
+/// may prompt (they run inside a PTY); anything else runs in batch mode.
+/// `forward_agent` adds `-A` so git on the host can use the local agent.
+pub(crate) fn ssh_args(
+    destination: &str,
+    port: Option<u16>,
+    forward_agent: bool,
+    interactive: bool,
+    remote_command: &str,
+) -> Vec<String> {
+    let mut args = Vec::new();
+    if interactive {
+        args.push("-tt".to_string());
+    } else {
+        args.extend(SSH_BATCH_OPTIONS.iter().map(|option| option.to_string()));
+    }
+    if forward_agent {
+        args.push("-A".to_string());
+    }
+    if let Some(port) = port {
+        args.push("-p".to_string());
+        args.push(port.to_string());
+    }
+    args.push("--".to_string());
+    args.push(destination.to_string());
+    args.push(remote_command.to_string());
+    args
+}
+
+/// Where the remote sidecar keeps `worktree` under `root`.
+pub(crate) fn remote_worktree_path(root: &str, worktree: &str) -> String {
+    format!("{}/.worktrees/{worktree}", root.trim_end_matches('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_destinations_and_roots() {
+        assert!(validate_ssh_destination("dev@build-box.internal").is_ok());
+        assert!(validate_ssh_destination("devbox").is_ok());
+        assert!(validate_ssh_destination("-oProxyCommand=x").is_err());
+        assert!(validate_ssh_destination("dev box").is_err());
+        assert!(validate_ssh_destination("a@b@c").is_err());
+        assert!(validate_remote_root("/home/dev/repo").is_ok());
+        assert!(validate_remote_root("repo").is_err());
+        assert!(validate_remote_root("/home/../etc").is_err());
+    }
+
+    #[test]
+    fn quotes_remote_commands() {
+        assert_eq!(shell_quote("status"), "status");
+        assert_eq!(shell_quote("it's here"), r"'it'\''s here'");
+        assert_eq!(
+            remote_shell_command(
+                "/srv/my repo",
+                &[
+                    "git".to_string(),
+                    "status".to_string(),
+                    "--porcelain=v1".to_string()
+                ]
+            ),
+            "cd '/srv/my repo' && exec git status --porcelain=v1"
+        );
+        assert_eq!(
+            remote_login_shell_command("/srv/repo/.worktrees/feature"),
+            "cd /srv/repo/.worktrees/feature && exec \"${SHELL:-/bin/sh}\" -l"
+        );
+    }
+
+    #[test]
+    fn builds_ssh_args() {
+        assert_eq!(
+            ssh_args("dev@box", Some(2222), false, false, "true"),
+            vec![
+                "-o",
+                "BatchMode=yes",
+                "-o",
+                "ConnectTimeout=10",
+                "-p",
+                "2222",
+                "--",
+                "dev@box",
+                "true",
+            ]
+        );
+        assert_eq!(
+            ssh_args("box", None, true, true, "true"),
+            vec!["-tt", "-A", "--", "box", "true"]
+        );
+        assert_eq!(
+            remote_worktree_path("/srv/repo/", "feature"),
+            "/srv/repo/.worktrees/feature"
+        );
+    }
+}
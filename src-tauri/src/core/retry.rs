@@ -0,0 +1,248 @@
+//! Decides which failed git and gh runs are worth trying again and how long to
+//! wait before doing so. Only runs that are safe to repeat qualify: fetches,
+//! pushes and read-only gh calls, never something that could open a second
+//! pull request.
+
+use std::time::Duration;
+
+/// Why a run failed, as far as retrying is concerned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RetryCategory {
+    /// DNS, connection and HTTP 5xx errors.
+    Network,
+    /// The run or its connection timed out.
+    Timeout,
+    /// Another git process held a lock file.
+    Lock,
+}
+
+impl RetryCategory {
+    pub(crate) const ALL: [RetryCategory; 3] = [Self::Network, Self::Timeout, Self::Lock];
+
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Network => "network",
+            Self::Timeout => "timeout",
+            Self::Lock => "lock",
+        }
+    }
+
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|category| category.as_str() == value.trim().to_ascii_lowercase())
+    }
+}
+
+const NETWORK_FAILURE_MARKERS: [&str; 14] = [
+    "could not resolve host",
+    "temporary failure in name resolution",
+    "name or service not known",
+    "connection reset",
+    "connection refused",
+    "connection closed by remote host",
+    "network is unreachable",
+    "the remote end hung up unexpectedly",
+    "early eof",
+    "rpc failed",
+    "error connecting to api.github.com",
+    "http 502",
+    "http 503",
+    "http 504",
+];
+
+/// Whole phrases only: a bare "timeout" also matches file names, e.g. a
+/// conflict in `request_timeout.rs`.
+const TIMEOUT_FAILURE_MARKERS: [&str; 6] = [
+    "connection timed out",
+    "operation timed out",
+    "timed out after",
+    "operation too slow",
+    "i/o timeout",
+    "tls handshake timeout",
+];
+
+const LOCK_FAILURE_MARKERS: [&str; 3] = ["index.lock", "cannot lock ref", "unable to create"];
+
+/// The category of a failed run from its stderr and spawn or timeout error,
+/// or `None` when the failure would just happen again.
+pub(crate) fn classify_failure(stderr: &str, error: Option<&str>) -> Option<RetryCategory> {
+    let output = format!("{}\n{}", error.unwrap_or_default(), stderr).to_ascii_lowercase();
+    let matches = |markers: &[&str]| markers.iter().any(|marker| output.contains(marker));
+    if matches(&TIMEOUT_FAILURE_MARKERS) {
+        Some(RetryCategory::Timeout)
+    } else if matches(&NETWORK_FAILURE_MARKERS) {
+        Some(RetryCategory::Network)
+    } else if output.contains(".lock") && matches(&LOCK_FAILURE_MARKERS) {
+        Some(RetryCategory::Lock)
+    } else {
+        None
+    }
+}
+
+/// Wait before retry number `retry` (1 for the first retry): `initial`,
+/// doubled for each retry after it, up to `max`.
+pub(crate) fn backoff(initial: Duration, max: Duration, retry: u32) -> Duration {
+    let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+    initial.saturating_mul(factor).min(max)
+}
+
+/// Timeout for try number `attempt` (1 for the first try) of a run whose
+/// first try gets `base`: doubled for each try after it, up to four times
+/// `base`, so a slow link gets more room instead of failing the same way.
+pub(crate) fn progressive_timeout(base: Duration, attempt: u32) -> Duration {
+    let factor = 2u32.saturating_pow(attempt.saturating_sub(1)).min(4);
+    base.saturating_mul(factor)
+}
+
+/// The git subcommand of `args`, skipping `-C <path>` and `-c <key=value>`.
+pub(crate) fn git_subcommand<'a>(args: &[&'a str]) -> Option<&'a str> {
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match *arg {
+            "-C" | "-c" => {
+                args.next();
+            }
+            arg if arg.starts_with('-') => {}
+            arg => return Some(arg),
+        }
+    }
+    None
+}
+
+/// Git runs that only talk to a remote and can be repeated as-is.
+pub(crate) fn git_command_is_retryable(args: &[&str]) -> bool {
+    matches!(
+        git_subcommand(args),
+        Some("fetch" | "pull" | "push" | "ls-remote")
+    )
+}
+
+/// Whether the `gh api` arguments send a method other than GET or a body,
+/// which makes gh default to POST.
+fn gh_api_call_writes(args: &[&str]) -> bool {
+    let writing_method = |method: &str| !method.eq_ignore_ascii_case("GET");
+    args.iter().enumerate().any(|(index, arg)| match *arg {
+        "-X" | "--method" => args
+            .get(index + 1)
+            .is_some_and(|method| writing_method(method)),
+        "-f" | "-F" | "--field" | "--raw-field" | "--input" => true,
+        arg => arg.strip_prefix("--method=").is_some_and(writing_method),
+    })
+}
+
+/// Read-only gh runs. Anything that creates, edits or merges is left alone:
+/// a request that timed out may still have gone through.
+pub(crate) fn gh_command_is_retryable(args: &[&str]) -> bool {
+    match args {
+        ["pr", "list" | "view" | "checks" | "status" | "diff", ..]
+        | ["issue", "list" | "view" | "status", ..]
+        | ["repo", "view", ..]
+        | ["auth", "status", ..] => true,
+        ["api", rest @ ..] => !gh_api_call_writes(rest),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_network_timeout_and_lock_failures() {
+        assert_eq!(
+            classify_failure(
+                "fatal: unable to access 'https://github.com/a/b/': Could not resolve host: github.com",
+                None
+            ),
+            Some(RetryCategory::Network)
+        );
+        assert_eq!(
+            classify_failure("error: RPC failed; curl 56 GnuTLS recv error", None),
+            Some(RetryCategory::Network)
+        );
+        assert_eq!(
+            classify_failure("", Some("Command timed out after 30s: ssh box")),
+            Some(RetryCategory::Timeout)
+        );
+        assert_eq!(
+            classify_failure(
+                "fatal: Unable to create '/repo/.git/index.lock': File exists.",
+                None
+            ),
+            Some(RetryCategory::Lock)
+        );
+        assert_eq!(
+            classify_failure(
+                "ssh: connect to host github.com port 22: Connection timed out",
+                None
+            ),
+            Some(RetryCategory::Timeout)
+        );
+        assert_eq!(
+            classify_failure("error: failed to push some refs (non-fast-forward)", None),
+            None
+        );
+        assert_eq!(
+            classify_failure(
+                "CONFLICT (content): Merge conflict in src/request_timeout.rs",
+                None
+            ),
+            None
+        );
+        assert_eq!(
+            RetryCategory::parse(" Network"),
+            Some(RetryCategory::Network)
+        );
+        assert_eq!(RetryCategory::parse("disk"), None);
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap_and_timeouts_grow_fourfold_at_most() {
+        let initial = Duration::from_millis(500);
+        let max = Duration::from_millis(3_000);
+        assert_eq!(backoff(initial, max, 1), Duration::from_millis(500));
+        assert_eq!(backoff(initial, max, 2), Duration::from_millis(1_000));
+        assert_eq!(backoff(initial, max, 3), Duration::from_millis(2_000));
+        assert_eq!(backoff(initial, max, 4), max);
+        assert_eq!(backoff(initial, max, 40), max);
+
+        let base = Duration::from_secs(8);
+        assert_eq!(progressive_timeout(base, 1), base);
+        assert_eq!(progressive_timeout(base, 2), Duration::from_secs(16));
+        assert_eq!(progressive_timeout(base, 5), Duration::from_secs(32));
+    }
+
+    #[test]
+    fn only_repeatable_commands_are_retried() {
+        assert!(git_command_is_retryable(&["fetch", "--prune"]));
+        assert!(git_command_is_retryable(&["-C", "/repo", "push", "origin"]));
+        assert!(!git_command_is_retryable(&["commit", "-m", "fetch"]));
+        assert!(!git_command_is_retryable(&["-C", "fetch", "status"]));
+
+        assert!(gh_command_is_retryable(&[
+            "pr", "view", "12", "--json", "title"
+        ]));
+        assert!(gh_command_is_retryable(&["api", "repos/a/b/pulls"]));
+        assert!(gh_command_is_retryable(&["api", "-X", "GET", "user"]));
+        assert!(!gh_command_is_retryable(&[
+            "api",
+            "-X",
+            "POST",
+            "repos/a/b/issues"
+        ]));
+        assert!(!gh_command_is_retryable(&[
+            "api",
+            "--method=PATCH",
+            "repos/a/b"
+        ]));
+        assert!(!gh_command_is_retryable(&[
+            "api",
+            "graphql",
+            "-f",
+            "query=..."
+        ]));
+        assert!(!gh_command_is_retryable(&["pr", "create", "--fill"]));
+        assert!(!gh_command_is_retryable(&["pr", "merge", "12"]));
+    }
+}
@@ -0,0 +1,370 @@
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum GrooveTerminalOpenMode {
+    Opencode,
+    ClaudeCode,
+    Plain,
+}
+
+pub(crate) fn normalize_terminal_dimension(
+    value: Option<u16>,
+    default: u16,
+    min: u16,
+    max: u16,
+) -> u16 {
+    value
+        .map(|candidate| candidate.max(min).min(max))
+        .unwrap_or(default)
+}
+
+pub(crate) fn validate_groove_terminal_target(
+    value: Option<&str>,
+) -> Result<Option<String>, String> {
+    let Some(target) = value
+        .map(str::trim)
+        .filter(|candidate| !candidate.is_empty())
+    else {
+        return Ok(None);
+    };
+    if !super::workspace::is_safe_path_token(target) {
+        return Err("target contains unsafe characters or path segments.".to_string());
+    }
+    Ok(Some(target.to_string()))
+}
+
+pub(crate) fn validate_groove_terminal_open_mode(
+    value: Option<&str>,
+) -> Result<GrooveTerminalOpenMode, String> {
+    let Some(mode) = value
+        .map(str::trim)
+        .filter(|candidate| !candidate.is_empty())
+    else {
+        return Ok(GrooveTerminalOpenMode::Plain);
+    };
+
+    match mode {
+        "opencode" => Ok(GrooveTerminalOpenMode::Opencode),
+        "claudeCode" => Ok(GrooveTerminalOpenMode::ClaudeCode),
+        "plain" => Ok(GrooveTerminalOpenMode::Plain),
+        _ => Err("openMode must be \"opencode\", \"claudeCode\", or \"plain\".".to_string()),
+    }
+}
+
+/// Inverse of `validate_groove_terminal_open_mode`, used when persisting which
+/// kind of session was open so it can be reopened later.
+pub(crate) fn groove_terminal_open_mode_key(mode: GrooveTerminalOpenMode) -> &'static str {
+    match mode {
+        GrooveTerminalOpenMode::Opencode => "opencode",
+        GrooveTerminalOpenMode::ClaudeCode => "claudeCode",
+        GrooveTerminalOpenMode::Plain => "plain",
+    }
+}
+
+pub(crate) fn parse_terminal_command_tokens(command: &str) -> Result<Vec<String>, String> {
+    parse_command_tokens(command, "terminalCustomCommand")
+}
+
+pub(crate) fn parse_play_groove_command_tokens(command: &str) -> Result<Vec<String>, String> {
+    parse_command_tokens(command, "playGrooveCommand")
+}
+
+fn parse_command_tokens(command: &str, field_name: &str) -> Result<Vec<String>, String> {
+    let trimmed = command.trim();
+    if trimmed.is_empty() {
+        return Err(format!("{field_name} must be a non-empty command string."));
+    }
+
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_single_quote = false;
+    let mut in_double_quote = false;
+    let mut escaping = false;
+
+    let mut chars = trimmed.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if escaping {
+            current.push(ch);
+            escaping = false;
+            continue;
+        }
+
+        if ch == '\\' && !in_single_quote && starts_escape(chars.peek(), in_double_quote) {
+            escaping = true;
+            continue;
+        }
+
+        if ch == '\'' && !in_double_quote {
+            in_single_quote = !in_single_quote;
+            continue;
+        }
+
+        if ch == '"' && !in_single_quote {
+            in_double_quote = !in_double_quote;
+            continue;
+        }
+
+        if ch.is_whitespace() && !in_single_quote && !in_double_quote {
+            if !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+
+        current.push(ch);
+    }
+
+    if escaping {
+        return Err(format!("{field_name} ends with an unfinished escape (\\)."));
+    }
+    if in_single_quote || in_double_quote {
+        return Err(format!("{field_name} has an unmatched quote."));
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    if tokens.is_empty() {
+        return Err(format!("{field_name} must include an executable command."));
+    }
+
+    Ok(tokens)
+}
+
+/// Backslash only escapes what the tokenizer would otherwise act on (quotes,
+/// whitespace outside quotes, another backslash), so Windows paths such as
+/// `C:\Program Files\Ghostty\ghostty.exe` survive unquoted or in double
+/// quotes.
+fn starts_escape(next: Option<&char>, in_double_quote: bool) -> bool {
+    match next {
+        Some('"' | '\\') => true,
+        Some('\'') => !in_double_quote,
+        Some(next) => !in_double_quote && next.is_whitespace(),
+        None => true,
+    }
+}
+
+/// Fills `{name}` placeholders in already-tokenized template arguments. The
+/// template is split before substitution, so values with spaces or non-ASCII
+/// characters always stay a single argument, and substitution is single-pass
+/// so a value that itself contains `{target}` is never expanded again. `{{`
+/// and `}}` produce literal braces; unknown placeholders are kept verbatim.
+/// Returns the expanded tokens and whether any placeholder was used.
+pub(crate) fn expand_command_template(
+    tokens: &[String],
+    values: &[(&str, &str)],
+) -> (Vec<String>, bool) {
+    let mut used_placeholder = false;
+    let expanded = tokens
+        .iter()
+        .map(|token| {
+            let mut output = String::with_capacity(token.len());
+            let mut rest = token.as_str();
+            while let Some(index) = rest.find(['{', '}']) {
+                output.push_str(&rest[..index]);
+                rest = &rest[index..];
+                if rest.starts_with("{{") || rest.starts_with("}}") {
+                    output.push_str(&rest[..1]);
+                    rest = &rest[2..];
+                    continue;
+                }
+                let placeholder = rest
+                    .strip_prefix('{')
+                    .and_then(|inner| inner.split_once('}'))
+                    .and_then(|(name, after)| {
+                        values
+                            .iter()
+                            .find(|(key, _)| *key == name)
+                            .map(|(_, value)| (*value, after))
+                    });
+                match placeholder {
+                    Some((value, after)) => {
+                        output.push_str(value);
+                        used_placeholder = true;
+                        rest = after;
+                    }
+                    None => {
+                        output.push_str(&rest[..1]);
+                        rest = &rest[1..];
+                    }
+                }
+            }
+            output.push_str(rest);
+            output
+        })
+        .collect();
+    (expanded, used_placeholder)
+}
+
+/// Names of the `{name}` placeholders in `tokens`, in order. Only names made
+/// of letters, digits and `_` count, so `awk '{print $1}'` has none; `{{` and
+/// `}}` are escaped braces.
+pub(crate) fn command_template_placeholders(tokens: &[String]) -> Vec<String> {
+    let mut names = Vec::new();
+    for token in tokens {
+        let mut rest = token.as_str();
+        while let Some(index) = rest.find(['{', '}']) {
+            rest = &rest[index..];
+            if rest.starts_with("{{") || rest.starts_with("}}") {
+                rest = &rest[2..];
+                continue;
+            }
+            let name = rest
+                .strip_prefix('{')
+                .and_then(|inner| inner.split_once('}'))
+                .map(|(name, _)| name)
+                .filter(|name| {
+                    !name.is_empty()
+                        && name
+                            .chars()
+                            .all(|character| character.is_ascii_alphanumeric() || character == '_')
+                });
+            match name {
+                Some(name) => {
+                    names.push(name.to_string());
+                    rest = &rest[name.len() + 2..];
+                }
+                None => rest = &rest[1..],
+            }
+        }
+    }
+    names
+}
+
+/// Single-quotes `value` for a POSIX shell, for templates that hand a path to
+/// `sh -c` and the like.
+pub(crate) fn shell_single_quote_escape(value: &str) -> String {
+    if value.is_empty() {
+        return "''".to_string();
+    }
+
+    format!("'{}'", value.replace('\'', "'\"'\"'"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_terminal_command_tokens_with_quotes() {
+        let tokens = parse_terminal_command_tokens("ghostty -e \"npm run dev\"").unwrap();
+        assert_eq!(tokens, vec!["ghostty", "-e", "npm run dev"]);
+    }
+
+    #[test]
+    fn keeps_windows_paths_and_non_ascii_in_tokens() {
+        let tokens =
+            parse_terminal_command_tokens(r#""C:\Program Files\Ghostty\ghostty.exe" --title 作業"#)
+                .unwrap();
+        assert_eq!(
+            tokens,
+            vec![r"C:\Program Files\Ghostty\ghostty.exe", "--title", "作業"]
+        );
+        let tokens =
+            parse_terminal_command_tokens(r#"C:\tools\wt.exe -d my\ dir "say \"hi\"""#).unwrap();
+        assert_eq!(
+            tokens,
+            vec![r"C:\tools\wt.exe", "-d", "my dir", r#"say "hi""#]
+        );
+        assert!(parse_terminal_command_tokens(r"open \").is_err());
+    }
+
+    #[test]
+    fn expands_placeholders_without_splitting_values() {
+        let tokens = parse_terminal_command_tokens(
+            "wezterm start --cwd {worktree} -- sh -c \"cd {worktree_escaped} && make {target}\"",
+        )
+        .unwrap();
+        let worktree = "/Users/me/My Projects/リポジトリ/.worktrees/機能-🚀";
+        let escaped = shell_single_quote_escape(worktree);
+        let (expanded, used) = expand_command_template(
+            &tokens,
+            &[
+                ("worktree_escaped", escaped.as_str()),
+                ("worktree", worktree),
+                ("target", "機能-🚀"),
+            ],
+        );
+        assert!(used);
+        assert_eq!(
+            expanded,
+            vec![
+                "wezterm",
+                "start",
+                "--cwd",
+                worktree,
+                "--",
+                "sh",
+                "-c",
+                "cd '/Users/me/My Projects/リポジトリ/.worktrees/機能-🚀' && make 機能-🚀",
+            ]
+        );
+    }
+
+    #[test]
+    fn expands_placeholders_in_a_single_pass() {
+        let tokens = vec![
+            "{worktree}".to_string(),
+            "{{target}}".to_string(),
+            "{unknown}".to_string(),
+            "{".to_string(),
+        ];
+        let (expanded, used) =
+            expand_command_template(&tokens, &[("worktree", "/tmp/{target}"), ("target", "x")]);
+        assert!(used);
+        assert_eq!(
+            expanded,
+            vec!["/tmp/{target}", "{target}", "{unknown}", "{"]
+        );
+
+        let (_, used) = expand_command_template(&["code".to_string()], &[("worktree", "/tmp")]);
+        assert!(!used);
+    }
+
+    #[test]
+    fn lists_placeholders_but_not_escaped_or_code_braces() {
+        let tokens = parse_terminal_command_tokens(
+            "sh -c \"cd {worktree_escaped} && awk '{print $1}' {{target}} {branch}-{taget}\"",
+        )
+        .unwrap();
+        assert_eq!(
+            command_template_placeholders(&tokens),
+            vec!["worktree_escaped", "branch", "taget"]
+        );
+        assert!(command_template_placeholders(&["code".to_string(), "{".to_string()]).is_empty());
+    }
+
+    #[test]
+    fn rejects_invalid_open_mode() {
+        let result = validate_groove_terminal_open_mode(Some("bad"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn defaults_open_mode_to_plain_shell() {
+        assert!(matches!(
+            validate_groove_terminal_open_mode(None),
+            Ok(GrooveTerminalOpenMode::Plain)
+        ));
+        assert!(matches!(
+            validate_groove_terminal_open_mode(Some("   ")),
+            Ok(GrooveTerminalOpenMode::Plain)
+        ));
+    }
+
+    #[test]
+    fn open_mode_key_round_trips_through_validation() {
+        for mode in [
+            GrooveTerminalOpenMode::Opencode,
+            GrooveTerminalOpenMode::ClaudeCode,
+            GrooveTerminalOpenMode::Plain,
+        ] {
+            let key = groove_terminal_open_mode_key(mode);
+            let parsed = validate_groove_terminal_open_mode(Some(key)).unwrap();
+            assert_eq!(groove_terminal_open_mode_key(parsed), key);
+        }
+    }
+
+    #[test]
+    fn normalizes_dimension_in_range() {
+        assert_eq!(normalize_terminal_dimension(Some(2), 40, 10, 80), 10);
+        assert_eq!(normalize_terminal_dimension(Some(100), 40, 10, 80), 80);
+    }
+}
@@ -0,0 +1,187 @@
+//! tmux adapter. Each workspace gets one tmux session (`groove-<root name>`)
+//! and each worktree a window named after it, so agents and shells opened
+//! through tmux outlive the app and can be re-attached from any terminal.
+
+const SESSION_PREFIX: &str = "groove-";
+
+/// `-F` format for `list-windows`; fields are tab-separated in this order.
+pub(crate) const LIST_WINDOWS_FORMAT: &str = "#{session_name}\t#{window_index}\t#{window_name}\t#{pane_current_path}\t#{window_active}\t#{session_attached}";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TmuxWindow {
+    pub(crate) session: String,
+    pub(crate) index: u32,
+    pub(crate) name: String,
+    pub(crate) path: String,
+    pub(crate) active: bool,
+    pub(crate) session_attached: bool,
+}
+
+/// tmux treats `.` and `:` in names as target separators; everything else,
+/// including non-ASCII, is kept.
+fn sanitize_name(value: &str) -> String {
+    let sanitized = value
+        .trim()
+        .chars()
+        .map(|character| {
+            if matches!(character, '.' | ':') || character.is_whitespace() {
+                '-'
+            } else {
+                character
+            }
+        })
+        .collect::<String>();
+    if sanitized.is_empty() {
+        "groove".to_string()
+    } else {
+        sanitized
+    }
+}
+
+pub(crate) fn session_name(workspace_root_name: &str) -> String {
+    format!("{SESSION_PREFIX}{}", sanitize_name(workspace_root_name))
+}
+
+pub(crate) fn window_name(worktree: &str) -> String {
+    sanitize_name(worktree)
+}
+
+/// Exact-match target for `window` in `session`, immune to tmux's prefix and
+/// pattern matching.
+pub(crate) fn window_target(session: &str, window: &str) -> String {
+    format!("={session}:={window}")
+}
+
+pub(crate) fn parse_list_windows(output: &str) -> Vec<TmuxWindow> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\t');
+            let session = fields.next()?.to_string();
+            let index = fields.next()?.parse().ok()?;
+            let name = fields.next()?.to_string();
+            let path = fields.next()?.to_string();
+            let active = fields.next()? == "1";
+            let session_attached = fields.next()?.parse::<u32>().ok()? > 0;
+            Some(TmuxWindow {
+                session,
+                index,
+                name,
+                path,
+                active,
+                session_attached,
+            })
+        })
+        .collect()
+}
+
+/// Arguments that create `window` running `argv` in `cwd`, starting a
+/// detached session first when it does not exist yet. An empty `argv` runs
+/// tmux's default shell.
+pub(crate) fn open_window_args(
+    session_exists: bool,
+    session: &str,
+    window: &str,
+    cwd: &str,
+    argv: &[String],
+) -> Vec<String> {
+    let mut args = if session_exists {
+        vec![
+            "new-window".to_string(),
+            "-d".to_string(),
+            "-t".to_string(),
+            format!("={session}:"),
+        ]
+    } else {
+        vec![
+            "new-session".to_string(),
+            "-d".to_string(),
+            "-s".to_string(),
+            session.to_string(),
+        ]
+    };
+    args.extend([
+        "-n".to_string(),
+        window.to_string(),
+        "-c".to_string(),
+        cwd.to_string(),
+    ]);
+    args.extend(argv.iter().cloned());
+    args
+}
+
+/// True for the messages tmux prints when no server is running, which means
+/// there are simply no windows yet.
+pub(crate) fn is_no_server_error(stderr: &str) -> bool {
+    let stderr = stderr.to_ascii_lowercase();
+    stderr.contains("no server running") || stderr.contains("error connecting to")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_sessions_and_windows() {
+        assert_eq!(session_name("my.repo"), "groove-my-repo");
+        assert_eq!(window_name("feature: 機能"), "feature--機能");
+        assert_eq!(window_name("  "), "groove");
+        assert_eq!(
+            window_target("groove-repo", "feature"),
+            "=groove-repo:=feature"
+        );
+    }
+
+    #[test]
+    fn parses_list_windows_output() {
+        let output = "groove-repo\t0\tfeature\t/srv/repo/.worktrees/feature\t1\t1\n\
+                      groove-repo\t1\tfix\t/srv/repo/.worktrees/fix\t0\t1\n\
+                      garbage line\n";
+        let windows = parse_list_windows(output);
+        assert_eq!(windows.len(), 2);
+        assert_eq!(windows[1].name, "fix");
+        assert_eq!(windows[1].index, 1);
+        assert!(!windows[1].active);
+        assert!(windows[1].session_attached);
+    }
+
+    #[test]
+    fn builds_open_window_args() {
+        assert_eq!(
+            open_window_args(
+                false,
+                "groove-repo",
+                "feature",
+                "/srv/repo",
+                &["opencode".to_string()]
+            ),
+            vec![
+                "new-session",
+                "-d",
+                "-s",
+                "groove-repo",
+                "-n",
+                "feature",
+                "-c",
+                "/srv/repo",
+                "opencode"
+            ]
+        );
+        assert_eq!(
+            open_window_args(true, "groove-repo", "feature", "/srv/repo", &[]),
+            vec![
+                "new-window",
+                "-d",
+                "-t",
+                "=groove-repo:",
+                "-n",
+                "feature",
+                "-c",
+                "/srv/repo"
+            ]
+        );
+        assert!(is_no_server_error(
+            "no server running on /tmp/tmux-1000/default"
+        ));
+    }
+}
@@ -0,0 +1,473 @@
+use std::collections::HashSet;
+use std::path::{Component, Path, PathBuf};
+
+pub(crate) fn normalize_default_terminal(
+    value: &str,
+    supported_default_terminals: &[&str],
+) -> Result<String, String> {
+    let normalized = value.trim().to_lowercase();
+    if supported_default_terminals.contains(&normalized.as_str()) {
+        Ok(normalized)
+    } else {
+        Err(format!(
+            "defaultTerminal must be one of: {}.",
+            supported_default_terminals.join(", ")
+        ))
+    }
+}
+
+pub(crate) fn normalize_theme_mode(
+    value: &str,
+    supported_theme_modes: &[&str],
+) -> Result<String, String> {
+    let normalized = value.trim().to_lowercase();
+    if supported_theme_modes.contains(&normalized.as_str()) {
+        Ok(normalized)
+    } else {
+        Err(format!(
+            "themeMode must be one of: {}.",
+            supported_theme_modes.join(", ")
+        ))
+    }
+}
+
+pub(crate) fn is_restricted_worktree_symlink_path(path: &str) -> bool {
+    path.split('/')
+        .next()
+        .map(|part| part.eq_ignore_ascii_case(".worktrees"))
+        .unwrap_or(false)
+}
+
+pub(crate) fn is_safe_path_token(value: &str) -> bool {
+    if value.is_empty() {
+        return false;
+    }
+
+    for segment in value.split('/') {
+        if segment.is_empty() || segment == "." || segment == ".." {
+            return false;
+        }
+
+        if !segment
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '_' || c == '-')
+        {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Directory name the `groove` sidecar derives from a branch name: `/` and
+/// spaces become `_` (mirrors `safe_branch_name` in `scripts/groove`).
+pub(crate) fn worktree_dir_name_for_branch(branch: &str) -> String {
+    branch.replace(['/', ' '], "_")
+}
+
+/// Short directory name for a branch: `wt-` followed by 10 hex digits of a
+/// 64-bit FNV-1a hash. FNV is used instead of `DefaultHasher` because the
+/// latter is not guaranteed stable across Rust releases, and the same branch
+/// must always map to the same directory.
+pub(crate) fn hashed_worktree_dir_name(branch: &str) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in branch.trim().bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    format!("wt-{:010x}", hash >> 24)
+}
+
+const WINDOWS_RESERVED_DEVICE_NAMES: [&str; 22] = [
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// Returns why `name` cannot be used as a worktree directory on every
+/// supported filesystem, or `None` when it is portable. Checks are the union
+/// of Windows/NTFS rules so a workspace stays usable when cloned elsewhere.
+pub(crate) fn invalid_worktree_dir_name_reason(name: &str) -> Option<String> {
+    if name.is_empty() {
+        return Some("directory name is empty".to_string());
+    }
+
+    if let Some(invalid) = name
+        .chars()
+        .find(|c| c.is_control() || matches!(c, '<' | '>' | ':' | '"' | '|' | '?' | '*' | '\\'))
+    {
+        return Some(format!("directory name contains invalid character {invalid:?}"));
+    }
+
+    if name.ends_with('.') || name.ends_with(' ') {
+        return Some("directory name cannot end with a dot or a space".to_string());
+    }
+
+    let stem = name.split('.').next().unwrap_or(name);
+    if WINDOWS_RESERVED_DEVICE_NAMES
+        .iter()
+        .any(|reserved| reserved.eq_ignore_ascii_case(stem))
+    {
+        return Some(format!("\"{stem}\" is a reserved device name on Windows"));
+    }
+
+    None
+}
+
+/// Finds an existing worktree directory that differs from `name` only by
+/// letter case. Such a pair cannot coexist on case-insensitive filesystems
+/// (default macOS/Windows). An exact match is not a collision: `groove create`
+/// reuses it.
+pub(crate) fn find_case_insensitive_collision<'a, I>(name: &str, existing: I) -> Option<String>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    existing
+        .into_iter()
+        .find(|candidate| *candidate != name && candidate.eq_ignore_ascii_case(name))
+        .map(str::to_string)
+}
+
+/// Whether the platform's default filesystems compare paths ignoring case
+/// (NTFS, and APFS/HFS+ in their default mode).
+pub(crate) const CASE_INSENSITIVE_PATHS: bool = cfg!(any(windows, target_os = "macos"));
+
+/// Reduces a path string to the form used as a map key, so one directory
+/// always yields one key: the `\\?\` prefix `canonicalize` adds on Windows
+/// is dropped, Windows separators are unified, trailing separators are
+/// trimmed, and case is folded when `case_insensitive`.
+pub(crate) fn normalize_path_key(path: &str, case_insensitive: bool) -> String {
+    let path = if let Some(share) = path.strip_prefix(r"\\?\UNC\") {
+        format!(r"\\{share}")
+    } else {
+        path.strip_prefix(r"\\?\").unwrap_or(path).to_string()
+    };
+
+    let bytes = path.as_bytes();
+    let windows_style = path.starts_with(r"\\")
+        || path.starts_with("//")
+        || (bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':');
+    let normalized = if windows_style {
+        let path = path.replace('/', "\\");
+        let trimmed = path.trim_end_matches('\\');
+        if trimmed.len() == 2 && trimmed.ends_with(':') {
+            format!("{trimmed}\\")
+        } else {
+            trimmed.to_string()
+        }
+    } else {
+        let trimmed = path.trim_end_matches('/');
+        if trimmed.is_empty() && path.starts_with('/') {
+            "/".to_string()
+        } else {
+            trimmed.to_string()
+        }
+    };
+
+    if case_insensitive {
+        normalized.to_lowercase()
+    } else {
+        normalized
+    }
+}
+
+/// Longest worktree path (in bytes) Groove accepts before warning. On Windows
+/// this leaves headroom under `MAX_PATH` (260) for nested files such as
+/// `node_modules`; elsewhere it guards deep CI mounts.
+pub(crate) fn max_worktree_path_length() -> usize {
+    if cfg!(windows) {
+        200
+    } else {
+        1024
+    }
+}
+
+pub(crate) fn validate_known_worktrees(known_worktrees: &[String]) -> Result<Vec<String>, String> {
+    if known_worktrees.len() > 4096 {
+        return Err("knownWorktrees is too large (max 4096 entries).".to_string());
+    }
+
+    let mut set = HashSet::new();
+    for entry in known_worktrees {
+        let trimmed = entry.trim();
+        if trimmed.is_empty() {
+            return Err("knownWorktrees entries must be non-empty strings.".to_string());
+        }
+
+        if !is_safe_path_token(trimmed) {
+            return Err("knownWorktrees contains unsafe characters or path segments.".to_string());
+        }
+
+        set.insert(trimmed.to_string());
+    }
+
+    let mut values = set.into_iter().collect::<Vec<_>>();
+    values.sort();
+    Ok(values)
+}
+
+pub(crate) fn normalize_worktree_symlink_paths(paths: &[String]) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let mut normalized = Vec::new();
+
+    for path in paths {
+        let trimmed = path.trim();
+        if trimmed.is_empty() || trimmed.contains('\\') {
+            continue;
+        }
+
+        let candidate = Path::new(trimmed);
+        if candidate.is_absolute() {
+            continue;
+        }
+
+        let mut parts = Vec::new();
+        let mut valid = true;
+        for component in candidate.components() {
+            match component {
+                Component::Normal(value) => {
+                    let part = value.to_string_lossy().trim().to_string();
+                    if part.is_empty() {
+                        valid = false;
+                        break;
+                    }
+                    parts.push(part);
+                }
+                Component::ParentDir
+                | Component::CurDir
+                | Component::RootDir
+                | Component::Prefix(_) => {
+                    valid = false;
+                    break;
+                }
+            }
+        }
+
+        if !valid || parts.is_empty() {
+            continue;
+        }
+
+        let rendered = parts.join("/");
+        if is_restricted_worktree_symlink_path(&rendered) {
+            continue;
+        }
+        if seen.insert(rendered.clone()) {
+            normalized.push(rendered);
+        }
+    }
+
+    normalized
+}
+
+pub(crate) fn validate_worktree_symlink_paths(paths: &[String]) -> Result<Vec<String>, String> {
+    let mut seen = HashSet::new();
+    let mut normalized = Vec::new();
+
+    for path in paths {
+        let candidate = normalize_worktree_symlink_paths(std::slice::from_ref(path));
+        let Some(value) = candidate.into_iter().next() else {
+            return Err(format!(
+                "worktreeSymlinkPaths contains an invalid or restricted path: \"{}\".",
+                path.trim()
+            ));
+        };
+
+        if seen.insert(value.clone()) {
+            normalized.push(value);
+        }
+    }
+
+    Ok(normalized)
+}
+
+pub(crate) fn normalize_browse_relative_path(value: Option<&str>) -> Result<String, String> {
+    let Some(trimmed) = value.map(str::trim).filter(|entry| !entry.is_empty()) else {
+        return Ok(String::new());
+    };
+
+    if trimmed.contains('\\') {
+        return Err("relativePath must use forward slashes only.".to_string());
+    }
+
+    let path = Path::new(trimmed);
+    if path.is_absolute() {
+        return Err("relativePath must be a relative path.".to_string());
+    }
+
+    let mut parts = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(value) => {
+                let part = value.to_string_lossy().trim().to_string();
+                if part.is_empty() {
+                    return Err("relativePath contains invalid path segments.".to_string());
+                }
+                parts.push(part);
+            }
+            Component::ParentDir
+            | Component::CurDir
+            | Component::RootDir
+            | Component::Prefix(_) => {
+                return Err("relativePath contains unsafe path segments.".to_string());
+            }
+        }
+    }
+
+    if parts.is_empty() {
+        return Ok(String::new());
+    }
+
+    let normalized = parts.join("/");
+    if is_restricted_worktree_symlink_path(&normalized) {
+        return Err("relativePath cannot browse restricted workspace directories.".to_string());
+    }
+
+    Ok(normalized)
+}
+
+/// Names the layout of a repository's linked worktrees for
+/// `workspace_migrate`: `groove` when all of them already live under
+/// `managed_dir`, `bareRepository` for a bare repository with worktrees
+/// checked out around it, `siblingDirectory` when they share one directory
+/// outside the workspace (e.g. `../repo-worktrees/`), `nested` when they sit
+/// elsewhere inside the workspace, and `mixed` otherwise. `none` means the
+/// repository has no linked worktrees.
+pub(crate) fn classify_worktree_layout(
+    workspace_root: &Path,
+    managed_dir: &Path,
+    is_bare: bool,
+    linked_worktrees: &[PathBuf],
+) -> &'static str {
+    if linked_worktrees.is_empty() {
+        return "none";
+    }
+    let unmanaged = linked_worktrees
+        .iter()
+        .filter(|path| !path.starts_with(managed_dir))
+        .collect::<Vec<_>>();
+    if unmanaged.is_empty() {
+        return "groove";
+    }
+    if is_bare {
+        return "bareRepository";
+    }
+    if unmanaged.iter().all(|path| path.starts_with(workspace_root)) {
+        return "nested";
+    }
+    let first_parent = unmanaged[0].parent();
+    if unmanaged
+        .iter()
+        .all(|path| !path.starts_with(workspace_root) && path.parent() == first_parent)
+    {
+        return "siblingDirectory";
+    }
+    "mixed"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_default_terminal_case_and_spacing() {
+        let terminals = ["auto", "ghostty", "none"];
+        let result = normalize_default_terminal("  GhostTy  ", &terminals);
+        assert_eq!(result, Ok("ghostty".to_string()));
+    }
+
+    #[test]
+    fn normalizes_path_keys() {
+        assert_eq!(normalize_path_key("/home/me/repo/", false), "/home/me/repo");
+        assert_eq!(normalize_path_key("/", false), "/");
+        assert_eq!(normalize_path_key("/Users/Me/Repo", true), "/users/me/repo");
+        assert_eq!(normalize_path_key("/Users/Me/Repo", false), "/Users/Me/Repo");
+        assert_eq!(
+            normalize_path_key(r"\\?\C:\Users\Me\Repo\", true),
+            normalize_path_key("c:/users/me/repo", true)
+        );
+        assert_eq!(normalize_path_key(r"\\?\D:\", true), r"d:\");
+        assert_eq!(
+            normalize_path_key(r"\\?\UNC\server\share\Repo", true),
+            r"\\server\share\repo"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_known_worktree_entries() {
+        let values = vec!["good".to_string(), "../bad".to_string()];
+        assert!(validate_known_worktrees(&values).is_err());
+    }
+
+    #[test]
+    fn maps_branch_to_sidecar_dir_name() {
+        assert_eq!(worktree_dir_name_for_branch("feature/a b"), "feature_a_b");
+    }
+
+    #[test]
+    fn hashed_dir_name_is_short_and_stable() {
+        let first = hashed_worktree_dir_name("feature/very/long/branch-name");
+        assert_eq!(first, hashed_worktree_dir_name("feature/very/long/branch-name"));
+        assert_ne!(first, hashed_worktree_dir_name("feature/other"));
+        assert_eq!(first.len(), 13);
+        assert!(invalid_worktree_dir_name_reason(&first).is_none());
+    }
+
+    #[test]
+    fn flags_non_portable_dir_names() {
+        assert!(invalid_worktree_dir_name_reason("feature_ok").is_none());
+        assert!(invalid_worktree_dir_name_reason("trailing.").is_some());
+        assert!(invalid_worktree_dir_name_reason("con").is_some());
+        assert!(invalid_worktree_dir_name_reason("Nul.txt").is_some());
+        assert!(invalid_worktree_dir_name_reason("a:b").is_some());
+    }
+
+    #[test]
+    fn detects_case_only_collisions() {
+        let existing = ["Feature_X", "other"];
+        assert_eq!(
+            find_case_insensitive_collision("feature_x", existing.iter().copied()),
+            Some("Feature_X".to_string())
+        );
+        assert_eq!(
+            find_case_insensitive_collision("other", existing.iter().copied()),
+            None
+        );
+    }
+
+    #[test]
+    fn normalizes_and_filters_symlink_paths() {
+        let values = vec![
+            " node_modules ".to_string(),
+            "./bad".to_string(),
+            ".worktrees/state".to_string(),
+            "node_modules".to_string(),
+        ];
+        let normalized = normalize_worktree_symlink_paths(&values);
+        assert_eq!(normalized, vec!["node_modules".to_string()]);
+    }
+
+    #[test]
+    fn classifies_worktree_layouts() {
+        let root = Path::new("/src/app");
+        let managed = root.join(".worktrees");
+        let layout = |is_bare: bool, paths: &[&str]| {
+            let paths = paths.iter().map(PathBuf::from).collect::<Vec<_>>();
+            classify_worktree_layout(root, &managed, is_bare, &paths)
+        };
+
+        assert_eq!(layout(false, &[]), "none");
+        assert_eq!(layout(false, &["/src/app/.worktrees/feature"]), "groove");
+        assert_eq!(layout(true, &["/src/app/main", "/src/app/feature"]), "bareRepository");
+        assert_eq!(layout(false, &["/src/app/wt/feature"]), "nested");
+        assert_eq!(
+            layout(false, &["/src/app-worktrees/a", "/src/app-worktrees/b"]),
+            "siblingDirectory"
+        );
+        assert_eq!(layout(false, &["/src/app/wt/a", "/tmp/b"]), "mixed");
+    }
+
+    #[test]
+    fn rejects_restricted_browse_path() {
+        let result = normalize_browse_relative_path(Some(".worktrees/state"));
+        assert!(result.is_err());
+    }
+}
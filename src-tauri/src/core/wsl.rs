@@ -0,0 +1,139 @@
+//! Windows ↔ WSL path translation. Windows exposes each distro's filesystem
+//! as `\\wsl.localhost\<distro>\...` (or the older `\\wsl$\<distro>\...`);
+//! tools running on the Windows side are slow or wrong on those shares, so
+//! commands for such paths are run inside the distro through `wsl.exe`.
+
+/// A path inside a WSL distro, as seen from Linux.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct WslPath {
+    pub(crate) distro: String,
+    pub(crate) path: String,
+}
+
+const WSL_SHARE_HOSTS: [&str; 2] = ["wsl.localhost", "wsl$"];
+
+/// Recognizes `\\wsl.localhost\Ubuntu\home\me`, `\\wsl$\Ubuntu\...` and their
+/// `\\?\UNC\...` verbatim forms (what `canonicalize` returns), with either
+/// slash style.
+pub(crate) fn parse_wsl_unc_path(path: &str) -> Option<WslPath> {
+    let normalized = path.replace('/', "\\");
+    let share = normalized
+        .strip_prefix(r"\\?\UNC\")
+        .or_else(|| normalized.strip_prefix(r"\\"))?;
+    let mut segments = share.split('\\');
+    let host = segments.next()?;
+    if !WSL_SHARE_HOSTS
+        .iter()
+        .any(|candidate| host.eq_ignore_ascii_case(candidate))
+    {
+        return None;
+    }
+    let distro = segments.next().filter(|distro| !distro.is_empty())?;
+    let rest = segments
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>();
+
+    Some(WslPath {
+        distro: distro.to_string(),
+        path: format!("/{}", rest.join("/")),
+    })
+}
+
+/// The Windows UNC path of `path` inside `distro`.
+pub(crate) fn wsl_to_windows_unc(distro: &str, path: &str) -> String {
+    let rest = path.trim_start_matches('/').replace('/', "\\");
+    format!(r"\\wsl.localhost\{distro}\{rest}")
+}
+
+/// `wsl.exe` arguments that run `argv` directly (no shell) in `cwd`.
+pub(crate) fn wsl_exec_args(distro: &str, cwd: &str, argv: &[&str]) -> Vec<String> {
+    ["-d", distro, "--cd", cwd, "--exec"]
+        .into_iter()
+        .chain(argv.iter().copied())
+        .map(str::to_string)
+        .collect()
+}
+
+/// `wsl.exe` arguments that hand `argv` to the distro user's default shell in
+/// `cwd`, so profile-provided `PATH` entries apply; with no `argv` the shell
+/// itself is started interactively.
+pub(crate) fn wsl_shell_args(distro: &str, cwd: &str, argv: &[String]) -> Vec<String> {
+    let mut args = ["-d", distro, "--cd", cwd]
+        .into_iter()
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    if !argv.is_empty() {
+        args.push("--".to_string());
+        args.extend(argv.iter().cloned());
+    }
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_wsl_share_paths() {
+        let expected = Some(WslPath {
+            distro: "Ubuntu".to_string(),
+            path: "/home/me/repo".to_string(),
+        });
+        assert_eq!(
+            parse_wsl_unc_path(r"\\wsl.localhost\Ubuntu\home\me\repo"),
+            expected
+        );
+        assert_eq!(parse_wsl_unc_path(r"\\wsl$\Ubuntu\home\me\repo\"), expected);
+        assert_eq!(
+            parse_wsl_unc_path(r"\\?\UNC\wsl.localhost\Ubuntu\home\me\repo"),
+            expected
+        );
+        assert_eq!(
+            parse_wsl_unc_path("//wsl.localhost/Ubuntu/home/me/repo"),
+            expected
+        );
+        assert_eq!(
+            parse_wsl_unc_path(r"\\wsl$\Debian").map(|path| path.path),
+            Some("/".to_string())
+        );
+        assert_eq!(parse_wsl_unc_path(r"\\server\share\repo"), None);
+        assert_eq!(parse_wsl_unc_path(r"C:\Users\me"), None);
+        assert_eq!(parse_wsl_unc_path("/home/me"), None);
+    }
+
+    #[test]
+    fn maps_linux_paths_back_to_the_share() {
+        assert_eq!(
+            wsl_to_windows_unc("Ubuntu", "/home/me/repo"),
+            r"\\wsl.localhost\Ubuntu\home\me\repo"
+        );
+        assert_eq!(
+            wsl_to_windows_unc("Ubuntu", "/"),
+            r"\\wsl.localhost\Ubuntu\"
+        );
+    }
+
+    #[test]
+    fn builds_wsl_exe_arguments() {
+        assert_eq!(
+            wsl_exec_args("Ubuntu", "/srv/repo", &["git", "status"]),
+            vec![
+                "-d",
+                "Ubuntu",
+                "--cd",
+                "/srv/repo",
+                "--exec",
+                "git",
+                "status"
+            ]
+        );
+        assert_eq!(
+            wsl_shell_args("Ubuntu", "/srv/repo", &[]),
+            vec!["-d", "Ubuntu", "--cd", "/srv/repo"]
+        );
+        assert_eq!(
+            wsl_shell_args("Ubuntu", "/srv/repo", &["opencode".to_string()]),
+            vec!["-d", "Ubuntu", "--cd", "/srv/repo", "--", "opencode"]
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! zellij adapter. Unlike tmux, zellij has no addressable windows from the
+//! CLI, so every worktree gets its own session named
+//! `groove-<root name>-<worktree>`; sessions are created in the background and
+//! attached from any terminal with `zellij attach <name>`.
+
+const SESSION_PREFIX: &str = "groove-";
+
+/// zellij puts session sockets under a per-version runtime directory, and
+/// Unix socket paths are capped at ~100 bytes, so long names are shortened.
+const MAX_SESSION_NAME_BYTES: usize = 36;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ZellijSession {
+    pub(crate) name: String,
+    /// Exited sessions are kept on disk for resurrection until deleted.
+    pub(crate) exited: bool,
+}
+
+fn sanitize_segment(value: &str) -> String {
+    value
+        .trim()
+        .chars()
+        .map(|character| {
+            if character.is_alphanumeric() || matches!(character, '-' | '_') {
+                character
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn session_name(workspace_root_name: &str, worktree: &str) -> String {
+    let name = format!(
+        "{SESSION_PREFIX}{}-{}",
+        sanitize_segment(workspace_root_name),
+        sanitize_segment(worktree)
+    );
+    if name.len() <= MAX_SESSION_NAME_BYTES {
+        return name;
+    }
+
+    let hash = super::workspace::hashed_worktree_dir_name(&name);
+    let mut keep = MAX_SESSION_NAME_BYTES - hash.len() - 1;
+    while !name.is_char_boundary(keep) {
+        keep -= 1;
+    }
+    format!("{}-{hash}", &name[..keep])
+}
+
+fn strip_ansi(value: &str) -> String {
+    let mut output = String::with_capacity(value.len());
+    let mut characters = value.chars();
+    while let Some(character) = characters.next() {
+        if character == '\u{1b}' {
+            for next in characters.by_ref() {
+                if next.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+            continue;
+        }
+        output.push(character);
+    }
+    output
+}
+
+/// Parses `zellij list-sessions` output, with or without `--no-formatting`:
+/// `name [Created 2m ago] (current)` or `name [...] (EXITED - attach to
+/// resurrect)`.
+pub(crate) fn parse_list_sessions(output: &str) -> Vec<ZellijSession> {
+    strip_ansi(output)
+        .lines()
+        .filter(|line| !is_no_sessions_message(line))
+        .filter_map(|line| {
+            let name = line.split_whitespace().next()?;
+            Some(ZellijSession {
+                name: name.to_string(),
+                exited: line.contains("EXITED"),
+            })
+        })
+        .collect()
+}
+
+/// zellij exits non-zero with this message when there is nothing to list.
+pub(crate) fn is_no_sessions_message(output: &str) -> bool {
+    output.contains("No active zellij sessions found")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_sessions_per_worktree() {
+        assert_eq!(session_name("repo", "feature_x"), "groove-repo-feature_x");
+        assert_eq!(session_name("my repo", "機能.v2"), "groove-my-repo-機能-v2");
+
+        let long = session_name("a-very-long-workspace-name", "another-very-long-worktree");
+        assert_eq!(long.len(), MAX_SESSION_NAME_BYTES);
+        assert!(session_name("リポジトリ", "機能ブランチの名前").len() <= MAX_SESSION_NAME_BYTES);
+        assert!(long.starts_with("groove-a-very-long"));
+        assert_ne!(
+            long,
+            session_name("a-very-long-workspace-name", "another-very-long-worktree2")
+        );
+    }
+
+    #[test]
+    fn parses_list_sessions_output() {
+        let output = "\u{1b}[32;1mgroove-repo-feature\u{1b}[m [Created \u{1b}[35;1m2m\u{1b}[m ago] (\u{1b}[31;1mcurrent\u{1b}[m)\n\
+                      groove-repo-fix [Created 1h ago] (EXITED - attach to resurrect)\n";
+        assert_eq!(
+            parse_list_sessions(output),
+            vec![
+                ZellijSession {
+                    name: "groove-repo-feature".to_string(),
+                    exited: false,
+                },
+                ZellijSession {
+                    name: "groove-repo-fix".to_string(),
+                    exited: true,
+                },
+            ]
+        );
+        assert!(parse_list_sessions("No active zellij sessions found.\n").is_empty());
+    }
+}
@@ -0,0 +1,601 @@
+//! Payloads of every event the backend emits to the frontend.
+//!
+//! Each event is declared once in `groove_events!`, which produces the Rust
+//! struct and the schema used to render `src/lib/ipc/types-events.ts`. Every
+//! payload is sent through `versioned` so the frontend sees a `version` field
+//! and can ignore shapes it does not understand. The schema and renderer are
+//! only exercised by the bindings test, which fails when the TS file is stale.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+/// Bump when an event payload changes in a way older frontends cannot read.
+pub(crate) const GROOVE_EVENT_SCHEMA_VERSION: u32 = 1;
+
+/// Types referenced by event fields that live in hand-written frontend files.
+#[cfg_attr(not(test), allow(dead_code))]
+const TYPESCRIPT_IMPORTS: &str = "import type { WorkspaceContextResponse } from \"./types-core\";\nimport type { GrooveNotification } from \"./types-terminal\";";
+
+pub(crate) trait GrooveEvent: Serialize + Clone {
+    const NAME: &'static str;
+}
+
+#[cfg_attr(not(test), allow(dead_code))]
+pub(crate) struct EventFieldSchema {
+    pub(crate) name: &'static str,
+    pub(crate) rust_type: &'static str,
+    pub(crate) ts_type: &'static str,
+}
+
+#[cfg_attr(not(test), allow(dead_code))]
+pub(crate) struct EventSchema {
+    pub(crate) name: &'static str,
+    pub(crate) type_name: &'static str,
+    pub(crate) doc: &'static [&'static str],
+    pub(crate) fields: &'static [EventFieldSchema],
+}
+
+macro_rules! groove_events {
+    ($(
+        $(#[doc = $doc:literal])*
+        $name:literal => struct $ident:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field:ident: $field_type:ty => $ts_type:literal,
+            )*
+        }
+    )*) => {
+        $(
+            $(#[doc = $doc])*
+            #[derive(Debug, Clone, Serialize)]
+            #[serde(rename_all = "camelCase")]
+            pub(crate) struct $ident {
+                $(
+                    $(#[$field_meta])*
+                    pub(crate) $field: $field_type,
+                )*
+            }
+
+            impl GrooveEvent for $ident {
+                const NAME: &'static str = $name;
+            }
+        )*
+
+        #[cfg_attr(not(test), allow(dead_code))]
+        pub(crate) const GROOVE_EVENT_SCHEMAS: &[EventSchema] = &[
+            $(
+                EventSchema {
+                    name: $name,
+                    type_name: stringify!($ident),
+                    doc: &[$($doc),*],
+                    fields: &[
+                        $(
+                            EventFieldSchema {
+                                name: stringify!($field),
+                                rust_type: stringify!($field_type),
+                                ts_type: $ts_type,
+                            },
+                        )*
+                    ],
+                },
+            )*
+        ];
+    };
+}
+
+groove_events! {
+    /// A workspace finished loading (`kind` is "connection" or "filesystem").
+    "workspace-ready" => struct WorkspaceReadyEvent {
+        request_id: String => "string",
+        #[serde(skip_serializing_if = "Option::is_none")]
+        workspace_root: Option<String> => "string",
+        kind: String => "string",
+    }
+
+    /// The full context after `workspace_get_active` answered a cold start
+    /// with a partial one; `request_id` matches that answer.
+    "workspace-context-complete" => struct WorkspaceContextCompleteEvent {
+        request_id: String => "string",
+        workspace_root: String => "string",
+        context: serde_json::Value => "WorkspaceContextResponse",
+    }
+
+    /// Coalesced filesystem or runtime changes under the active workspace.
+    /// `changes` maps each affected worktree to why it was flagged; changes
+    /// to workspace-level paths only show up in `sources`.
+    "workspace-change" => struct WorkspaceChangeEvent {
+        index: u64 => "number",
+        source: String => "string",
+        sources: Vec<String> => "string[]",
+        source_count: usize => "number",
+        workspace_root: String => "string",
+        kind: String => "string",
+        changes: BTreeMap<String, Vec<WorktreeChangeReason>> => "Record<string, WorktreeChangeReason[]>",
+    }
+
+    /// A notification file dropped by the `groove` CLI or a hook.
+    "groove-notification" => struct GrooveNotificationEvent {
+        workspace_root: String => "string",
+        notification: serde_json::Value => "GrooveNotification",
+    }
+
+    /// Worktrees removed to stay under the workspace worktree limit.
+    "worktree-evicted" => struct WorktreeEvictedEvent {
+        workspace_root: String => "string",
+        worktrees: Vec<String> => "string[]",
+    }
+
+    /// `offset` is the output offset right after `chunk`; pass the last one
+    /// seen to `groove_terminal_attach` to resume after a reload.
+    "groove-terminal-output" => struct GrooveTerminalOutputEvent {
+        session_id: String => "string",
+        workspace_root: String => "string",
+        worktree: String => "string",
+        chunk: String => "string",
+        offset: u64 => "number",
+    }
+
+    "groove-terminal-lifecycle" => struct GrooveTerminalLifecycleEvent {
+        session_id: String => "string",
+        workspace_root: String => "string",
+        worktree: String => "string",
+        kind: String => "\"started\" | \"closed\" | \"error\" | \"hibernated\" | \"resumed\"",
+        #[serde(skip_serializing_if = "Option::is_none")]
+        message: Option<String> => "string",
+    }
+
+    /// State of the worktree followed with `worktree_watch`. The first event
+    /// after a watch starts carries every field; later ones only the fields
+    /// that changed since the previous event.
+    "worktree-watch" => struct WorktreeWatchEvent {
+        worktree_path: String => "string",
+        /// Starts at zero for every watch; a gap means events were lost.
+        sequence: u64 => "number",
+        #[serde(skip_serializing_if = "Option::is_none")]
+        modified: Option<u32> => "number",
+        #[serde(skip_serializing_if = "Option::is_none")]
+        added: Option<u32> => "number",
+        #[serde(skip_serializing_if = "Option::is_none")]
+        deleted: Option<u32> => "number",
+        #[serde(skip_serializing_if = "Option::is_none")]
+        untracked: Option<u32> => "number",
+        #[serde(skip_serializing_if = "Option::is_none")]
+        dirty: Option<bool> => "boolean",
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ahead: Option<u32> => "number",
+        #[serde(skip_serializing_if = "Option::is_none")]
+        behind: Option<u32> => "number",
+        /// `log_state` of the groove row: "latest", "broken-latest" or "none".
+        #[serde(skip_serializing_if = "Option::is_none")]
+        log_state: Option<String> => "string",
+        #[serde(skip_serializing_if = "Option::is_none")]
+        latest_log_mtime_ms: Option<u64> => "number",
+        #[serde(skip_serializing_if = "Option::is_none")]
+        terminal_sessions: Option<usize> => "number",
+        #[serde(skip_serializing_if = "Option::is_none")]
+        command_runs_running: Option<usize> => "number",
+        /// Set on every event while git cannot be read; the git fields keep
+        /// their last reported values.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String> => "string",
+        /// Set on the last event of a watch whose worktree was removed.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        removed: Option<bool> => "boolean",
+    }
+
+    /// A git command has been waiting on its repository's operation queue
+    /// for a while; `ahead` is how many operations still run before it.
+    "git-queue-wait" => struct GitQueueWaitEvent {
+        repository: String => "string",
+        operation: String => "string",
+        ahead: usize => "number",
+        waited_ms: u64 => "number",
+    }
+
+    /// Progress of a long-running git command (pull, push, fetch) run with
+    /// `--progress`; `request_id` is the id of the command that started it.
+    "git-progress" => struct GitProgressEvent {
+        request_id: String => "string",
+        path: String => "string",
+        operation: String => "string",
+        phase: String => "string",
+        percent: u8 => "number",
+        #[serde(skip_serializing_if = "Option::is_none")]
+        completed: Option<u64> => "number",
+        #[serde(skip_serializing_if = "Option::is_none")]
+        total: Option<u64> => "number",
+    }
+
+    /// Output of a `groove_new`, `groove_restore` or `groove_rm` run with
+    /// `stream`, as the sidecar writes it; `request_id` is the id of that
+    /// command.
+    "groove-command-output" => struct GrooveCommandOutputEvent {
+        request_id: String => "string",
+        command: String => "string",
+        stream: String => "\"stdout\" | \"stderr\"",
+        chunk: String => "string",
+    }
+
+    /// The sidecar behind a streamed groove command exited. The command still
+    /// answers its request with the full output.
+    "groove-command-complete" => struct GrooveCommandCompleteEvent {
+        request_id: String => "string",
+        command: String => "string",
+        ok: bool => "boolean",
+        #[serde(skip_serializing_if = "Option::is_none")]
+        exit_code: Option<i32> => "number",
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String> => "string",
+    }
+
+    /// The supervisor saw a testing environment (Play session) crash,
+    /// scheduled or made a restart of it, gave up on it, or saw a restarted
+    /// one stop.
+    "testing-environment-status" => struct TestingEnvironmentStatusEvent {
+        workspace_root: String => "string",
+        worktree: String => "string",
+        status: String => "\"running\" | \"restarting\" | \"crashed\" | \"failed\" | \"stopped\"",
+        restarts: u32 => "number",
+        #[serde(skip_serializing_if = "Option::is_none")]
+        session_id: Option<String> => "string",
+        #[serde(skip_serializing_if = "Option::is_none")]
+        crash_reason: Option<String> => "string",
+        #[serde(skip_serializing_if = "Option::is_none")]
+        next_restart_at: Option<String> => "string",
+    }
+
+    /// A line a custom Play command wrote, sent while the worktree's testing
+    /// log is followed through `testing_environment_logs_tail`.
+    "testing-environment-log" => struct TestingEnvironmentLogEvent {
+        workspace_root: String => "string",
+        worktree: String => "string",
+        stream: String => "\"stdout\" | \"stderr\"",
+        line: String => "string",
+    }
+
+    /// A system resource went over its alert threshold (`active`) or came back
+    /// under it. `stop_worktrees` are idle grooves of the active workspace
+    /// whose memory would cover the excess, largest first.
+    "system-alert" => struct SystemAlertEvent {
+        kind: String => "\"ram\" | \"disk\" | \"swap\"",
+        active: bool => "boolean",
+        value_percent: f64 => "number",
+        threshold_percent: f64 => "number",
+        message: String => "string",
+        stop_worktrees: Vec<String> => "string[]",
+        #[serde(skip_serializing_if = "Option::is_none")]
+        suggestion: Option<String> => "string",
+    }
+
+    /// A command from the workspace settings was held back because it has not
+    /// run in the workspace before. `custom_command_approve` lets it run.
+    "custom-command-confirmation" => struct CustomCommandConfirmationEvent {
+        workspace_root: String => "string",
+        command: String => "string",
+        /// What the command is for, e.g. "its play command".
+        what: String => "string",
+    }
+
+    /// Sent every few seconds while the backend is responsive. The counts are
+    /// read under the same state locks commands take, so a deadlocked
+    /// backend stops sending it, while an idle one keeps reporting zeros.
+    /// Not sent while background activity is paused.
+    "backend-heartbeat" => struct BackendHeartbeatEvent {
+        /// Increases by one per heartbeat; a gap means heartbeats were lost.
+        sequence: u64 => "number",
+        uptime_ms: u64 => "number",
+        sent_at: String => "string",
+        /// Bumped every time `workspace_events` starts a worker.
+        workspace_worker_generation: u64 => "number",
+        /// Whether the worker of any open workspace runs.
+        workspace_worker_running: bool => "boolean",
+        /// Time since a workspace worker last started a poll pass; the
+        /// longest across workers.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        workspace_worker_idle_ms: Option<u64> => "number",
+        commands_in_flight: u64 => "number",
+        /// Git operations running or waiting to write, across repositories.
+        git_operations_pending: usize => "number",
+        groove_lists_in_flight: usize => "number",
+        command_runs_running: usize => "number",
+        terminal_sessions: usize => "number",
+    }
+
+    /// Background activity was paused or resumed with `background_pause` /
+    /// `background_resume`.
+    "background-activity" => struct BackgroundActivityEvent {
+        paused: bool => "boolean",
+        changed_at: String => "string",
+    }
+
+    /// A poisoned lock on shared backend state was recovered.
+    "groove-state-recovered" => struct StateRecoveredEvent {
+        state: String => "string",
+        detail: String => "string",
+        recovered_at: String => "string",
+    }
+}
+
+/// Why the workspace worker flagged a worktree, derived from the difference
+/// between two consecutive snapshots of its poll targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum WorktreeChangeReason {
+    DirectoryAdded,
+    DirectoryRemoved,
+    /// The worktree's `.groove` directory changed, e.g. a log was written.
+    LogModified,
+    MetadataModified,
+    ProcessStarted,
+    ProcessExited,
+    ProcessReplaced,
+}
+
+impl WorktreeChangeReason {
+    #[cfg_attr(not(test), allow(dead_code))]
+    const ALL: [WorktreeChangeReason; 7] = [
+        WorktreeChangeReason::DirectoryAdded,
+        WorktreeChangeReason::DirectoryRemoved,
+        WorktreeChangeReason::LogModified,
+        WorktreeChangeReason::MetadataModified,
+        WorktreeChangeReason::ProcessStarted,
+        WorktreeChangeReason::ProcessExited,
+        WorktreeChangeReason::ProcessReplaced,
+    ];
+
+    #[cfg_attr(not(test), allow(dead_code))]
+    fn as_str(self) -> &'static str {
+        match self {
+            WorktreeChangeReason::DirectoryAdded => "directoryAdded",
+            WorktreeChangeReason::DirectoryRemoved => "directoryRemoved",
+            WorktreeChangeReason::LogModified => "logModified",
+            WorktreeChangeReason::MetadataModified => "metadataModified",
+            WorktreeChangeReason::ProcessStarted => "processStarted",
+            WorktreeChangeReason::ProcessExited => "processExited",
+            WorktreeChangeReason::ProcessReplaced => "processReplaced",
+        }
+    }
+
+    /// Compares two `(exists, mtime_ms)` snapshots of a worktree's `.groove`
+    /// directory or its `workspace.json`.
+    pub(crate) fn from_snapshots(
+        is_metadata: bool,
+        previous: (bool, u128),
+        next: (bool, u128),
+    ) -> Option<Self> {
+        if previous == next {
+            return None;
+        }
+        if is_metadata {
+            return Some(WorktreeChangeReason::MetadataModified);
+        }
+        Some(match (previous.0, next.0) {
+            (false, true) => WorktreeChangeReason::DirectoryAdded,
+            (true, false) => WorktreeChangeReason::DirectoryRemoved,
+            _ => WorktreeChangeReason::LogModified,
+        })
+    }
+
+    pub(crate) fn from_runtime_pids(previous: Option<i32>, next: Option<i32>) -> Option<Self> {
+        match (previous, next) {
+            (None, Some(_)) => Some(WorktreeChangeReason::ProcessStarted),
+            (Some(_), None) => Some(WorktreeChangeReason::ProcessExited),
+            (Some(previous), Some(next)) if previous != next => {
+                Some(WorktreeChangeReason::ProcessReplaced)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Serialize)]
+pub(crate) struct VersionedEvent<'a, E: GrooveEvent> {
+    version: u32,
+    #[serde(flatten)]
+    payload: &'a E,
+}
+
+pub(crate) fn versioned<E: GrooveEvent>(payload: &E) -> VersionedEvent<'_, E> {
+    VersionedEvent {
+        version: GROOVE_EVENT_SCHEMA_VERSION,
+        payload,
+    }
+}
+
+#[cfg_attr(not(test), allow(dead_code))]
+fn camel_case(name: &str) -> String {
+    let mut rendered = String::with_capacity(name.len());
+    let mut upper_next = false;
+    for ch in name.chars() {
+        if ch == '_' {
+            upper_next = true;
+        } else if upper_next {
+            rendered.extend(ch.to_uppercase());
+            upper_next = false;
+        } else {
+            rendered.push(ch);
+        }
+    }
+    rendered
+}
+
+#[cfg_attr(not(test), allow(dead_code))]
+pub(crate) fn render_typescript_bindings() -> String {
+    let mut out = String::new();
+    out.push_str("// Generated from src-tauri/src/events.rs; do not edit by hand.\n");
+    out.push_str("// Regenerate with: GROOVE_WRITE_EVENT_BINDINGS=1 cargo test events::\n\n");
+    out.push_str(TYPESCRIPT_IMPORTS);
+    out.push_str("\n\n");
+    out.push_str(&format!(
+        "export const GROOVE_EVENT_SCHEMA_VERSION = {GROOVE_EVENT_SCHEMA_VERSION};\n\n"
+    ));
+
+    out.push_str("export const GROOVE_EVENT_NAMES = {\n");
+    for schema in GROOVE_EVENT_SCHEMAS {
+        out.push_str(&format!("  {}: \"{}\",\n", schema.type_name, schema.name));
+    }
+    out.push_str("} as const;\n\n");
+
+    let reasons = WorktreeChangeReason::ALL
+        .iter()
+        .map(|reason| format!("\"{}\"", reason.as_str()))
+        .collect::<Vec<_>>();
+    out.push_str(&format!(
+        "export type WorktreeChangeReason = {};\n",
+        reasons.join(" | ")
+    ));
+
+    for schema in GROOVE_EVENT_SCHEMAS {
+        out.push('\n');
+        if schema.doc.is_empty() {
+            out.push_str(&format!(
+                "/** Payload of the \"{}\" event. */\n",
+                schema.name
+            ));
+        } else {
+            out.push_str(&format!(
+                "/**\n * Payload of the \"{}\" event.\n",
+                schema.name
+            ));
+            for line in schema.doc {
+                out.push_str(&format!(" *{}\n", line.trim_end()));
+            }
+            out.push_str(" */\n");
+        }
+        out.push_str(&format!("export type {} = {{\n", schema.type_name));
+        out.push_str("  version: number;\n");
+        for field in schema.fields {
+            let optional = if field.rust_type.starts_with("Option") {
+                "?"
+            } else {
+                ""
+            };
+            out.push_str(&format!(
+                "  {}{}: {};\n",
+                camel_case(field.name),
+                optional,
+                field.ts_type
+            ));
+        }
+        out.push_str("};\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_field_names_to_camel_case() {
+        assert_eq!(camel_case("workspace_root"), "workspaceRoot");
+        assert_eq!(camel_case("kind"), "kind");
+    }
+
+    #[test]
+    fn event_names_are_unique() {
+        let mut names = GROOVE_EVENT_SCHEMAS
+            .iter()
+            .map(|schema| schema.name)
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), GROOVE_EVENT_SCHEMAS.len());
+    }
+
+    #[test]
+    fn versioned_payload_serializes_flat_with_version() {
+        let event = GrooveTerminalLifecycleEvent {
+            session_id: "s1".to_string(),
+            workspace_root: "/repo".to_string(),
+            worktree: "feature".to_string(),
+            kind: "closed".to_string(),
+            message: None,
+        };
+        let value = serde_json::to_value(versioned(&event)).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "version": GROOVE_EVENT_SCHEMA_VERSION,
+                "sessionId": "s1",
+                "workspaceRoot": "/repo",
+                "worktree": "feature",
+                "kind": "closed",
+            })
+        );
+    }
+
+    #[test]
+    fn classifies_worktree_changes_from_snapshots() {
+        use WorktreeChangeReason::*;
+        assert_eq!(
+            WorktreeChangeReason::from_snapshots(false, (false, 0), (true, 5)),
+            Some(DirectoryAdded)
+        );
+        assert_eq!(
+            WorktreeChangeReason::from_snapshots(false, (true, 5), (false, 0)),
+            Some(DirectoryRemoved)
+        );
+        assert_eq!(
+            WorktreeChangeReason::from_snapshots(false, (true, 5), (true, 9)),
+            Some(LogModified)
+        );
+        assert_eq!(
+            WorktreeChangeReason::from_snapshots(true, (true, 5), (false, 0)),
+            Some(MetadataModified)
+        );
+        assert_eq!(
+            WorktreeChangeReason::from_snapshots(true, (true, 5), (true, 5)),
+            None
+        );
+    }
+
+    #[test]
+    fn classifies_worktree_changes_from_runtime_pids() {
+        use WorktreeChangeReason::*;
+        assert_eq!(
+            WorktreeChangeReason::from_runtime_pids(None, Some(4)),
+            Some(ProcessStarted)
+        );
+        assert_eq!(
+            WorktreeChangeReason::from_runtime_pids(Some(4), None),
+            Some(ProcessExited)
+        );
+        assert_eq!(
+            WorktreeChangeReason::from_runtime_pids(Some(4), Some(7)),
+            Some(ProcessReplaced)
+        );
+        assert_eq!(
+            WorktreeChangeReason::from_runtime_pids(Some(4), Some(4)),
+            None
+        );
+    }
+
+    #[test]
+    fn change_reason_names_match_serde() {
+        for reason in WorktreeChangeReason::ALL {
+            assert_eq!(
+                serde_json::to_value(reason).unwrap(),
+                serde_json::json!(reason.as_str())
+            );
+        }
+    }
+
+    #[test]
+    fn typescript_bindings_are_up_to_date() {
+        let path =
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../src/lib/ipc/types-events.ts");
+        let rendered = render_typescript_bindings();
+        if std::env::var_os("GROOVE_WRITE_EVENT_BINDINGS").is_some() {
+            std::fs::write(&path, &rendered).unwrap();
+            return;
+        }
+        let on_disk = std::fs::read_to_string(&path).unwrap_or_default();
+        assert!(
+            on_disk == rendered,
+            "{} is stale; rerun with GROOVE_WRITE_EVENT_BINDINGS=1",
+            path.display()
+        );
+    }
+}
@@ -1,7 +1,8 @@
 mod backend;
-mod diagnostics;
-mod git_gh;
-mod terminal;
-mod workspace;
+#[cfg(test)]
+mod bindings;
+mod command_errors;
+mod core;
+mod events;
 
 pub use backend::tauri_backend_entry::run;
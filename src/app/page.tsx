@@ -87,6 +87,7 @@ export default function Home() {
     isCloseWorkspaceConfirmOpen,
     cutConfirmRow,
     forceCutConfirmRow,
+    forceCutSafety,
     activeTerminalWorktrees,
     isCreateModalOpen,
     createBranch,
@@ -609,6 +610,7 @@ export default function Home() {
         cutConfirmRow={cutConfirmRow}
         setCutConfirmRow={setCutConfirmRow}
         forceCutConfirmRow={forceCutConfirmRow}
+        forceCutSafety={forceCutSafety}
         setForceCutConfirmRow={setForceCutConfirmRow}
         forceCutConfirmLoading={forceCutConfirmLoading}
         isCloseWorkspaceConfirmOpen={isCloseWorkspaceConfirmOpen}
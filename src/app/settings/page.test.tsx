@@ -34,6 +34,32 @@ const defaultGlobalSettings: GlobalSettings = {
     emergency: { enabled: false, soundId: null },
     remove: { enabled: false, soundId: null },
   },
+  terminalMemorySettings: {
+    snapshotMaxBytes: 262144,
+    snapshotBudgetBytes: 8388608,
+    scrollbackPersistenceEnabled: false,
+    scrollbackFileMaxBytes: 16777216,
+    historyPersistenceEnabled: false,
+    hibernateIdleShells: false,
+    hibernateIdleMinutes: 30,
+    storageQuotaBytes: 536870912,
+    storageMaxAgeDays: 30,
+  },
+  systemAlertSettings: {
+    enabled: true,
+    ramPercent: 90,
+    diskPercent: 95,
+    swapInUse: true,
+    desktopNotifications: false,
+  },
+  telemetryPathRedaction: "off",
+  customCommandPolicy: {
+    deniedPatterns: ["rm -rf", "rm -fr", "mkfs", "dd if=", "| sh", "| bash"],
+    allowedPrograms: [],
+    requireResolvedPrograms: true,
+    trustedFirstRun: "confirm",
+    untrustedFirstRun: "block",
+  },
 };
 
 const {
@@ -76,6 +102,32 @@ const globalSettingsSnapshotRef = vi.hoisted(() => ({
       emergency: { enabled: false, soundId: null as string | null },
       remove: { enabled: false, soundId: null as string | null },
     },
+    terminalMemorySettings: {
+      snapshotMaxBytes: 262144,
+      snapshotBudgetBytes: 8388608,
+      scrollbackPersistenceEnabled: false,
+      scrollbackFileMaxBytes: 16777216,
+      historyPersistenceEnabled: false,
+      hibernateIdleShells: false,
+      hibernateIdleMinutes: 30,
+      storageQuotaBytes: 536870912,
+      storageMaxAgeDays: 30,
+    },
+    systemAlertSettings: {
+      enabled: true,
+      ramPercent: 90,
+      diskPercent: 95,
+      swapInUse: true,
+      desktopNotifications: false,
+    },
+    telemetryPathRedaction: "off",
+    customCommandPolicy: {
+      deniedPatterns: ["rm -rf", "rm -fr", "mkfs", "dd if=", "| sh", "| bash"],
+      allowedPrograms: [],
+      requireResolvedPrograms: true,
+      trustedFirstRun: "confirm",
+      untrustedFirstRun: "block",
+    },
   },
 }));
 
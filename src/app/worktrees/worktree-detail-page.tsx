@@ -66,6 +66,7 @@ export default function WorktreeDetailPage() {
     isCloseWorkspaceConfirmOpen,
     cutConfirmRow,
     forceCutConfirmRow,
+    forceCutSafety,
     activeTerminalWorktrees,
     isCreateModalOpen,
     createBranch,
@@ -937,6 +938,7 @@ export default function WorktreeDetailPage() {
             cutConfirmRow={cutConfirmRow}
             setCutConfirmRow={setCutConfirmRow}
             forceCutConfirmRow={forceCutConfirmRow}
+            forceCutSafety={forceCutSafety}
             setForceCutConfirmRow={setForceCutConfirmRow}
             forceCutConfirmLoading={forceCutConfirmLoading}
             isCloseWorkspaceConfirmOpen={isCloseWorkspaceConfirmOpen}
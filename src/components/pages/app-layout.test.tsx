@@ -5,6 +5,7 @@ import { describe, expect, it, vi } from "vitest";
 vi.mock("@/src/lib/ipc", () => ({
   DEFAULT_WORKTREE_STATE: "pending",
   WORKTREE_STATES: ["pending", "fighting", "wounded", "defeated", "blocked", "forgotten"],
+  customCommandApprove: vi.fn(async () => ({ ok: true })),
   diagnosticsGetSystemOverview: vi.fn().mockResolvedValue({ ok: false }),
   grooveBinRepair: vi
     .fn()
@@ -17,6 +18,7 @@ vi.mock("@/src/lib/ipc", () => ({
   isGamificationLabelsHidden: vi.fn(() => false),
   isShowFpsEnabled: vi.fn(() => false),
   isTelemetryEnabled: vi.fn(() => false),
+  listenCustomCommandConfirmation: vi.fn(async () => () => {}),
   listenGrooveTerminalLifecycle: vi.fn(async () => () => {}),
   listenWorkspaceChange: vi.fn(async () => () => {}),
   listenWorkspaceReady: vi.fn(async () => () => {}),
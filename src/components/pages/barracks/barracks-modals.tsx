@@ -1,12 +1,16 @@
 import { CreateWorktreeModal } from "@/src/components/create-worktree-modal";
 import type { WorktreeRow } from "@/src/components/pages/barracks/types";
 import { ConfirmModal } from "@/src/components/ui/confirm-modal";
+import type { GrooveRmSafetyScan } from "@/src/lib/ipc";
+import { describeGrooveRmSafety } from "@/src/lib/utils/worktree/status";
 
 type BarracksModalsProps = {
   workspaceRoot: string | null;
   cutConfirmRow: WorktreeRow | null;
   setCutConfirmRow: (row: WorktreeRow | null) => void;
   forceCutConfirmRow: WorktreeRow | null;
+  /** Why the cut was refused, when the safety scan refused it. */
+  forceCutSafety?: GrooveRmSafetyScan | null;
   setForceCutConfirmRow: (row: WorktreeRow | null) => void;
   forceCutConfirmLoading: boolean;
   isCloseWorkspaceConfirmOpen: boolean;
@@ -34,6 +38,7 @@ export function BarracksModals({
   cutConfirmRow,
   setCutConfirmRow,
   forceCutConfirmRow,
+  forceCutSafety,
   setForceCutConfirmRow,
   forceCutConfirmLoading,
   isCloseWorkspaceConfirmOpen,
@@ -100,9 +105,11 @@ export function BarracksModals({
         }}
         title="Force cut this groove?"
         description={
-          forceCutConfirmRow
-            ? `Worktree "${forceCutConfirmRow.worktree}" contains modified or untracked files. Force deletion is irreversible and there is no turn back.`
-            : "This worktree contains modified or untracked files. Force deletion is irreversible and there is no turn back."
+          forceCutConfirmRow && forceCutSafety
+            ? `Worktree "${forceCutConfirmRow.worktree}" has ${describeGrooveRmSafety(forceCutSafety)}. Force deletion is irreversible and there is no turn back.`
+            : forceCutConfirmRow
+              ? `Worktree "${forceCutConfirmRow.worktree}" contains modified or untracked files. Force deletion is irreversible and there is no turn back.`
+              : "This worktree contains modified or untracked files. Force deletion is irreversible and there is no turn back."
         }
         confirmLabel="Force delete worktree"
         cancelLabel="Keep worktree"
@@ -45,6 +45,7 @@ import {
   workspacePickAndOpen,
   GROOVE_PLAY_COMMAND_SENTINEL,
   isTelemetryEnabled,
+  type GrooveRmSafetyScan,
   type WorkspaceContextResponse,
   type WorkspaceGitignoreSanityResponse,
 } from "@/src/lib/ipc";
@@ -236,6 +237,8 @@ export function useBarracksState(viewingWorktree?: string) {
   const [cutConfirmRow, setCutConfirmRow] = useState<WorktreeRow | null>(null);
   const [forceCutConfirmRow, setForceCutConfirmRow] =
     useState<WorktreeRow | null>(null);
+  const [forceCutSafety, setForceCutSafety] =
+    useState<GrooveRmSafetyScan | null>(null);
   const [activeTerminalWorktrees, setActiveTerminalWorktrees] = useState<
     Set<string>
   >(new Set());
@@ -1078,7 +1081,9 @@ export function useBarracksState(viewingWorktree?: string) {
           workspaceMeta,
           target: row.worktree,
           worktree: row.worktree,
-          ...(force ? { force: true } : {}),
+          ...(force
+            ? { force: true, acknowledge: forceCutSafety?.blocking ?? [] }
+            : {}),
         });
 
         if (result.ok) {
@@ -1095,6 +1100,7 @@ export function useBarracksState(viewingWorktree?: string) {
         }
 
         if (!force && shouldPromptForceCutRetry(result)) {
+          setForceCutSafety(result.safety ?? null);
           setForceCutConfirmRow(row);
           return;
         }
@@ -1107,7 +1113,13 @@ export function useBarracksState(viewingWorktree?: string) {
         );
       }
     },
-    [knownWorktrees, rescanWorktrees, scheduleRuntimeStateFetch, workspaceMeta],
+    [
+      forceCutSafety,
+      knownWorktrees,
+      rescanWorktrees,
+      scheduleRuntimeStateFetch,
+      workspaceMeta,
+    ],
   );
 
   const runForgetAllDeletedWorktreesAction =
@@ -1388,6 +1400,7 @@ export function useBarracksState(viewingWorktree?: string) {
     isCloseWorkspaceConfirmOpen,
     cutConfirmRow,
     forceCutConfirmRow,
+    forceCutSafety,
     activeTerminalWorktrees,
     isCreateModalOpen,
     createBranch,
@@ -0,0 +1,89 @@
+"use client";
+
+import { useEffect, useState } from "react";
+
+import { ConfirmModal } from "@/src/components/ui/confirm-modal";
+import {
+  customCommandApprove,
+  listenCustomCommandConfirmation,
+  type CustomCommandConfirmationEvent,
+} from "@/src/lib/ipc";
+import { toast } from "@/src/lib/toast";
+
+/**
+ * Asks before a command from the workspace settings runs for the first time,
+ * as held back by the custom command policy. Allowing it records the approval
+ * for the workspace; the action that was refused has to be started again.
+ */
+function CustomCommandConfirmationModal() {
+  const [pending, setPending] = useState<CustomCommandConfirmationEvent | null>(
+    null,
+  );
+  const [loading, setLoading] = useState(false);
+
+  useEffect(() => {
+    let isClosed = false;
+    let unlisten: (() => void) | null = null;
+
+    void listenCustomCommandConfirmation((event) => {
+      setPending(event);
+    })
+      .then((nextUnlisten) => {
+        if (isClosed) {
+          nextUnlisten();
+          return;
+        }
+        unlisten = nextUnlisten;
+      })
+      .catch(() => {
+        unlisten = null;
+      });
+
+    return () => {
+      isClosed = true;
+      unlisten?.();
+    };
+  }, []);
+
+  const approve = () => {
+    if (!pending) {
+      return;
+    }
+    setLoading(true);
+    void customCommandApprove(pending.command)
+      .then((result) => {
+        if (!result.ok) {
+          toast.error(result.error ?? "Failed to allow the command.");
+          return;
+        }
+        toast.success("Command allowed. Run it again to start it.");
+        setPending(null);
+      })
+      .catch(() => {
+        toast.error("Failed to allow the command.");
+      })
+      .finally(() => {
+        setLoading(false);
+      });
+  };
+
+  return (
+    <ConfirmModal
+      open={pending !== null}
+      title="Allow this command?"
+      description={`${pending?.workspaceRoot ?? "This workspace"} wants to run ${pending?.what ?? "a command"} for the first time: ${pending?.command ?? ""}`}
+      confirmLabel="Allow command"
+      cancelLabel="Not now"
+      loading={loading}
+      onOpenChange={(nextOpen) => {
+        if (!nextOpen && !loading) {
+          setPending(null);
+        }
+      }}
+      onConfirm={approve}
+      onCancel={() => setPending(null)}
+    />
+  );
+}
+
+export { CustomCommandConfirmationModal };
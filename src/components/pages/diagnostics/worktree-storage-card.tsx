@@ -21,7 +21,10 @@ import { formatBytes } from "@/src/lib/format-bytes";
 import { playGrooveHookSound } from "@/src/lib/groove-sound-system";
 import { toast } from "@/src/lib/toast";
 import { cn } from "@/src/lib/utils";
-import { shouldPromptForceCutRetry } from "@/src/lib/utils/worktree/status";
+import {
+  describeGrooveRmSafety,
+  shouldPromptForceCutRetry,
+} from "@/src/lib/utils/worktree/status";
 
 type WorktreeStorageCardProps = {
   workspaceMeta: WorkspaceMeta | null;
@@ -150,12 +153,19 @@ export function WorktreeStorageCard({
       try {
         let result = await grooveRm(base);
         if (!result.ok && shouldPromptForceCutRetry(result)) {
+          const blockedBy = result.safety
+            ? describeGrooveRmSafety(result.safety)
+            : "uncommitted changes or is locked";
           if (
             window.confirm(
-              `"${row.worktree}" has uncommitted changes or is locked. Force-delete it anyway?`,
+              `"${row.worktree}" has ${blockedBy}. Force-delete it anyway?`,
             )
           ) {
-            result = await grooveRm({ ...base, force: true });
+            result = await grooveRm({
+              ...base,
+              force: true,
+              acknowledge: result.safety?.blocking ?? [],
+            });
           } else {
             return;
           }
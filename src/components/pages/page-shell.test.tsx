@@ -29,6 +29,8 @@ vi.mock("@/src/lib/ipc", () => ({
   grooveBinStatus: grooveBinStatusMock,
   grooveBinRepair: grooveBinRepairMock,
   diagnosticsGetSystemOverview: diagnosticsGetSystemOverviewMock,
+  customCommandApprove: vi.fn(async () => ({ ok: true })),
+  listenCustomCommandConfirmation: vi.fn(async () => () => {}),
   listenWorkspaceChange: listenWorkspaceChangeMock,
   listenWorkspaceReady: listenWorkspaceReadyMock,
   workspaceGitignoreSanityCheck: workspaceGitignoreSanityCheckMock,
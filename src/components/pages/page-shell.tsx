@@ -14,6 +14,8 @@ import { AppNavigation } from "@/src/components/app-navigation";
 import { DiagnosticsSystemSidebar } from "@/src/components/pages/diagnostics/diagnostics-system-sidebar";
 import { useGrooveRecovery } from "@/src/components/pages/barracks/hooks/use-groove-recovery";
 import { RecoverPreviousSessionModal } from "@/src/components/pages/barracks/recover-previous-session-modal";
+import { WorkspaceTrustModal } from "@/src/components/pages/workspace-trust-modal";
+import { CustomCommandConfirmationModal } from "@/src/components/pages/custom-command-confirmation-modal";
 import { Button } from "@/src/components/ui/button";
 import { toast } from "@/src/lib/toast";
 import {
@@ -819,6 +821,8 @@ export function PageShell({
           onRecover={grooveRecovery.recover}
         />
       )}
+      {hasOpenWorkspace && <WorkspaceTrustModal />}
+      {hasOpenWorkspace && <CustomCommandConfirmationModal />}
     </main>
   );
 }
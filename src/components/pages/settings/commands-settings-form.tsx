@@ -21,6 +21,8 @@ import {
   GROOVE_OPEN_TERMINAL_COMMAND_SENTINEL,
   GROOVE_PLAY_CLAUDE_CODE_COMMAND_SENTINEL,
   GROOVE_PLAY_COMMAND_SENTINEL,
+  GROOVE_TMUX_COMMAND_SENTINEL,
+  PLAY_GROOVE_COMMAND_PRESET_PREFIX,
 } from "@/src/lib/ipc";
 
 type CommandsSettingsPayload = {
@@ -47,6 +49,9 @@ const PLAY_GROOVE_TEMPLATE_COMMANDS = {
   grooveOpencode: GROOVE_PLAY_COMMAND_SENTINEL,
   grooveClaudeCode: GROOVE_PLAY_CLAUDE_CODE_COMMAND_SENTINEL,
   system: DEFAULT_PLAY_GROOVE_COMMAND,
+  ghosttyOpencode: `${PLAY_GROOVE_COMMAND_PRESET_PREFIX}ghostty-opencode`,
+  kittyOpencode: `${PLAY_GROOVE_COMMAND_PRESET_PREFIX}kitty-opencode`,
+  tmuxWindow: `${PLAY_GROOVE_COMMAND_PRESET_PREFIX}tmux-window`,
 } as const;
 
 const PLAY_GROOVE_COMMAND_TEMPLATES: Array<{
@@ -56,10 +61,14 @@ const PLAY_GROOVE_COMMAND_TEMPLATES: Array<{
   { value: "grooveOpencode", label: "Groove: Opencode" },
   { value: "grooveClaudeCode", label: "Groove: Claude Code" },
   { value: "system", label: "System default" },
+  { value: "ghosttyOpencode", label: "Ghostty: Opencode" },
+  { value: "kittyOpencode", label: "Kitty: Opencode" },
+  { value: "tmuxWindow", label: "tmux: new window" },
 ];
 
 const OPEN_TERMINAL_TEMPLATE_COMMANDS = {
   groove: GROOVE_OPEN_TERMINAL_COMMAND_SENTINEL,
+  tmux: GROOVE_TMUX_COMMAND_SENTINEL,
   ghostty: "ghostty --working-directory={worktree}",
   warp: "warp --working-directory {worktree}",
   kitty: "kitty --directory {worktree}",
@@ -71,6 +80,7 @@ const OPEN_TERMINAL_COMMAND_TEMPLATES: Array<{
   label: string;
 }> = [
   { value: "groove", label: "Groove" },
+  { value: "tmux", label: "tmux window" },
   { value: "ghostty", label: "Ghostty" },
   { value: "warp", label: "Warp" },
   { value: "kitty", label: "Kitty" },
@@ -31,6 +31,7 @@ describe("SUPPORTED_TERMINAL_OPTIONS", () => {
     expect(values).toContain("alacritty");
     expect(values).toContain("gnome");
     expect(values).toContain("xterm");
+    expect(values).toContain("zellij");
     expect(values).toContain("none");
     expect(values).toContain("custom");
   });
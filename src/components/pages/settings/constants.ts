@@ -11,6 +11,7 @@ export const SUPPORTED_TERMINAL_OPTIONS: Array<{
   { value: "alacritty", label: "Alacritty" },
   { value: "gnome", label: "GNOME Terminal" },
   { value: "xterm", label: "xterm" },
+  { value: "zellij", label: "Zellij session" },
   { value: "none", label: "None" },
   { value: "custom", label: "Custom command" },
 ];
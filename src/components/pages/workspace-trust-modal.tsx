@@ -0,0 +1,83 @@
+"use client";
+
+import { useState, useSyncExternalStore } from "react";
+
+import { ConfirmModal } from "@/src/components/ui/confirm-modal";
+import { workspaceSetTrust } from "@/src/lib/ipc";
+import { toast } from "@/src/lib/toast";
+import {
+  getWorkspaceContextStoreSnapshot,
+  refreshWorkspaceContext,
+  subscribeToWorkspaceContextStore,
+} from "@/src/lib/workspace-store";
+
+/**
+ * Asks whether to trust a workspace opened for the first time. Until it is
+ * trusted, play, custom and terminal commands from its `.groove` settings are
+ * refused and git runs without hooks. "Decide later" asks again on the next
+ * launch.
+ */
+function WorkspaceTrustModal() {
+  const { context } = useSyncExternalStore(
+    subscribeToWorkspaceContextStore,
+    getWorkspaceContextStoreSnapshot,
+    getWorkspaceContextStoreSnapshot,
+  );
+  const [dismissedRoots, setDismissedRoots] = useState<ReadonlySet<string>>(
+    new Set(),
+  );
+  const [loading, setLoading] = useState(false);
+
+  const workspaceRoot = context?.workspaceRoot ?? null;
+  const open =
+    context?.trust === "undecided" &&
+    workspaceRoot !== null &&
+    !dismissedRoots.has(workspaceRoot);
+
+  const dismiss = () => {
+    if (workspaceRoot) {
+      setDismissedRoots((prev) => new Set(prev).add(workspaceRoot));
+    }
+  };
+
+  const decide = (trusted: boolean) => {
+    setLoading(true);
+    void workspaceSetTrust(trusted)
+      .then((result) => {
+        if (!result.ok) {
+          toast.error(result.error ?? "Failed to save the trust decision.");
+          return;
+        }
+        dismiss();
+        void refreshWorkspaceContext();
+      })
+      .catch(() => {
+        toast.error("Failed to save the trust decision.");
+      })
+      .finally(() => {
+        setLoading(false);
+      });
+  };
+
+  return (
+    <ConfirmModal
+      open={open}
+      title="Trust this workspace?"
+      description={`${workspaceRoot ?? "This workspace"} has not been opened in Groove before. Trusting it lets Groove run the play, custom and terminal commands from its .groove settings and the git hooks of its repository. Only trust folders whose contents you know.`}
+      confirmLabel="Trust workspace"
+      secondaryActionLabel="Don't trust"
+      cancelLabel="Decide later"
+      loading={loading}
+      onOpenChange={(nextOpen) => {
+        if (!nextOpen && !loading) {
+          dismiss();
+        }
+      }}
+      onConfirm={() => decide(true)}
+      onSecondaryAction={() => decide(false)}
+      onCancel={dismiss}
+    />
+  );
+}
+
+export { WorkspaceTrustModal };
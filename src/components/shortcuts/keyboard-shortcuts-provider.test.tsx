@@ -54,6 +54,32 @@ const {
       emergency: { enabled: false, soundId: null },
       remove: { enabled: false, soundId: null },
     },
+    terminalMemorySettings: {
+      snapshotMaxBytes: 262144,
+      snapshotBudgetBytes: 8388608,
+      scrollbackPersistenceEnabled: false,
+      scrollbackFileMaxBytes: 16777216,
+      historyPersistenceEnabled: false,
+      hibernateIdleShells: false,
+      hibernateIdleMinutes: 30,
+      storageQuotaBytes: 536870912,
+      storageMaxAgeDays: 30,
+    },
+    systemAlertSettings: {
+      enabled: true,
+      ramPercent: 90,
+      diskPercent: 95,
+      swapInUse: true,
+      desktopNotifications: false,
+    },
+    telemetryPathRedaction: "off",
+    customCommandPolicy: {
+      deniedPatterns: ["rm -rf", "rm -fr", "mkfs", "dd if=", "| sh", "| bash"],
+      allowedPrograms: [],
+      requireResolvedPrograms: true,
+      trustedFirstRun: "confirm",
+      untrustedFirstRun: "block",
+    },
   } as GlobalSettings,
   subscribeToGlobalSettingsMock: vi.fn((onStoreChange: () => void) => {
     void onStoreChange;
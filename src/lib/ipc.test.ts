@@ -14,9 +14,13 @@ vi.mock("@/src/lib/command-history", () => ({
 }));
 
 import {
+  appBackupCreate,
+  appBackupRestore,
   cancelOpencodeFlow,
   checkOpencodeStatus,
   clearIpcTelemetrySummary,
+  cancelRequest,
+  commandsInFlight,
   diagnosticsCleanAllDevServers,
   diagnosticsGetMsotConsumingPrograms,
   diagnosticsGetSystemOverview,
@@ -33,12 +37,17 @@ import {
   ghAuthLogout,
   ghAuthStatus,
   ghAuthSwitch,
+  ghIssueView,
+  ghPrCreate,
   ghPrCreateWeb,
   ghPrList,
+  ghPrPrepare,
+  ghPrUpdate,
   ghPrView,
   ghRepoDefaultBranch,
   ghSshOverview,
   ghSshSetIdentity,
+  gitAdd,
   gitAheadBehind,
   gitCurrentBranch,
   gitHasUpstream,
@@ -48,13 +57,16 @@ import {
   groovePrDetach,
   globalSettingsGet,
   globalSettingsUpdate,
+  grooveArchive,
   grooveBinRepair,
   grooveBinStatus,
+  grooveBulkRm,
   grooveComment,
   grooveTerminalActiveWorktrees,
   grooveNew,
   grooveRestore,
   grooveRm,
+  grooveRmSafetyCheck,
   grooveStop,
   grooveSummary,
   grooveTerminalClose,
@@ -63,6 +75,7 @@ import {
   grooveTerminalOpen,
   grooveTerminalResize,
   grooveTerminalWrite,
+  grooveUnarchive,
   hasBlockingInvokeInFlight,
   invalidateWorkspaceGetActiveCache,
   isAlwaysShowDiagnosticsSidebarEnabled,
@@ -70,11 +83,17 @@ import {
   isPeriodicRerenderEnabled,
   isShowFpsEnabled,
   isTelemetryEnabled,
+  listenGrooveCommandOutput,
   listenGrooveNotification,
   listenGrooveTerminalLifecycle,
   listenGrooveTerminalOutput,
+  listenTestingEnvironmentStatus,
   listenWorkspaceChange,
   listenWorkspaceReady,
+  maintenanceConfigure,
+  maintenanceRunNow,
+  mergeTrainResume,
+  mergeTrainStart,
   opencodeCopySkills,
   opencodeIntegrationStatus,
   opencodeListSkills,
@@ -82,22 +101,31 @@ import {
   opencodeUpdateWorkspaceSettings,
   openExternalUrl,
   workspaceOpenDirectory,
+  playCommandPreview,
   printIpcTelemetrySummary,
   repairOpencodeIntegration,
   runOpencodeFlow,
   setOpencodeProfile,
+  settingsProfileSwitch,
+  settingsSyncMerge,
   soundLibraryGetPath,
   soundLibraryImport,
   soundLibraryOpenDirectory,
   soundLibraryRemove,
   soundLibraryRename,
+  statusPageStatus,
   subscribeToBlockingInvokes,
   subscribeToGlobalSettings,
   subscribeToWorkspaceSettings,
   syncOpencodeConfig,
+  testingEnvironmentLogsClear,
+  testingEnvironmentLogsTail,
   validateOpencodeSettingsDirectory,
   workspaceClearActive,
+  workspaceClose,
+  workspaceConflictMatrix,
   workspaceEvents,
+  workspaceFreeze,
   workspaceGetActive,
   workspaceGitignoreSanityApply,
   workspaceGitignoreSanityCheck,
@@ -108,9 +136,15 @@ import {
   workspacePickAndOpen,
   workspaceTermSanityApply,
   workspaceTermSanityCheck,
+  workspaceThaw,
   workspaceUpdateCommandsSettings,
   workspaceUpdateTerminalSettings,
   workspaceUpdateWorktreeSymlinkPaths,
+  worktreeAction,
+  worktreeRestack,
+  worktreeStalenessReport,
+  worktreeUnwatch,
+  worktreeWatch,
 } from "@/src/lib/ipc";
 
 beforeEach(() => {
@@ -653,6 +687,50 @@ describe("global settings sync", () => {
     await globalSettingsUpdate({ showFps: false });
   });
 
+  it("updates global settings when settings_profile_switch returns", async () => {
+    mockInvoke.mockResolvedValueOnce({
+      ok: true,
+      activeProfile: "home",
+      profiles: [],
+      globalSettings: {
+        showFps: true,
+        pollingSettings: {
+          workspaceEventsIntervalMs: 5000,
+          worktreeWatchIntervalMs: 1000,
+        },
+      },
+    });
+    await settingsProfileSwitch({ name: "home" });
+    expect(mockInvoke).toHaveBeenCalledWith("settings_profile_switch", {
+      payload: { name: "home" },
+    });
+    expect(isShowFpsEnabled()).toBe(true);
+    expect(
+      getGlobalSettingsSnapshot().pollingSettings.workspaceEventsIntervalMs,
+    ).toBe(5000);
+    // Reset
+    mockInvoke.mockResolvedValueOnce({
+      ok: true,
+      globalSettings: { showFps: false },
+    });
+    await globalSettingsUpdate({ showFps: false });
+  });
+
+  it("reports merge conflicts without syncing global settings", async () => {
+    mockInvoke.mockResolvedValueOnce({
+      ok: false,
+      target: { kind: "file", path: "/home/me/dotfiles/groove.json" },
+      conflicts: ["themeMode"],
+      error: "1 setting(s) changed on both sides since the last sync",
+    });
+    const response = await settingsSyncMerge();
+    expect(mockInvoke).toHaveBeenCalledWith("settings_sync_merge", {
+      payload: {},
+    });
+    expect(response.conflicts).toEqual(["themeMode"]);
+    expect(getThemeMode()).toBe("groove");
+  });
+
   it("does not update settings when ok is false", async () => {
     mockInvoke.mockResolvedValueOnce({
       ok: false,
@@ -694,6 +772,27 @@ describe("global settings sync", () => {
     expect(snap.opencodeSettings.settingsDirectory).toBe("~/.config/opencode");
   });
 
+  it("normalizeCommandRetryPolicy drops unknown retry categories", async () => {
+    mockInvoke.mockResolvedValueOnce({
+      ok: true,
+      globalSettings: {
+        commandRetryPolicy: {
+          maxAttempts: 0,
+          backoffMs: 500,
+          maxBackoffMs: 4000,
+          retryOn: ["lock", "disk", "network"],
+        },
+      },
+    });
+    await globalSettingsGet();
+    expect(getGlobalSettingsSnapshot().commandRetryPolicy).toEqual({
+      maxAttempts: 3,
+      backoffMs: 500,
+      maxBackoffMs: 4000,
+      retryOn: ["network", "lock"],
+    });
+  });
+
   it("normalizeGlobalSettings uses defaults for invalid themeMode", async () => {
     mockInvoke.mockResolvedValueOnce({
       ok: true,
@@ -770,6 +869,231 @@ describe("IPC wrapper functions", () => {
     });
   });
 
+  it("grooveBulkRm sends every worktree in one call", async () => {
+    await grooveBulkRm({
+      rootName: "r",
+      knownWorktrees: [],
+      worktrees: ["a", "b"],
+      force: true,
+    });
+    expect(mockInvoke).toHaveBeenCalledTimes(1);
+    expect(mockInvoke).toHaveBeenCalledWith("groove_bulk_rm", {
+      payload: {
+        rootName: "r",
+        knownWorktrees: [],
+        worktrees: ["a", "b"],
+        force: true,
+      },
+    });
+  });
+
+  it("grooveRmSafetyCheck calls groove_rm_safety_check", async () => {
+    await grooveRmSafetyCheck({
+      rootName: "r",
+      knownWorktrees: [],
+      target: "w",
+      worktree: "w",
+    });
+    expect(mockInvoke).toHaveBeenCalledWith("groove_rm_safety_check", {
+      payload: { rootName: "r", knownWorktrees: [], target: "w", worktree: "w" },
+    });
+  });
+
+  it("worktreeAction forwards the action and its options", async () => {
+    await worktreeAction({
+      rootName: "r",
+      knownWorktrees: [],
+      worktree: "w",
+      action: "pull",
+      options: { rebase: true },
+    });
+    expect(mockInvoke).toHaveBeenCalledWith("worktree_action", {
+      payload: {
+        rootName: "r",
+        knownWorktrees: [],
+        worktree: "w",
+        action: "pull",
+        options: { rebase: true },
+      },
+    });
+  });
+
+  it("grooveArchive and grooveUnarchive forward their options", async () => {
+    await grooveArchive({
+      rootName: "r",
+      knownWorktrees: [],
+      worktree: "w",
+      remove: true,
+    });
+    expect(mockInvoke).toHaveBeenCalledWith("groove_archive", {
+      payload: {
+        rootName: "r",
+        knownWorktrees: [],
+        worktree: "w",
+        remove: true,
+      },
+    });
+
+    await grooveUnarchive({
+      rootName: "r",
+      knownWorktrees: [],
+      id: "w-1700000000",
+      keepArchive: true,
+    });
+    expect(mockInvoke).toHaveBeenCalledWith("groove_unarchive", {
+      payload: {
+        rootName: "r",
+        knownWorktrees: [],
+        id: "w-1700000000",
+        keepArchive: true,
+      },
+    });
+  });
+
+  it("worktreeRestack forwards the parent worktree", async () => {
+    await worktreeRestack({
+      rootName: "r",
+      knownWorktrees: [],
+      worktree: "w",
+      recursive: true,
+    });
+    expect(mockInvoke).toHaveBeenCalledWith("worktree_restack", {
+      payload: {
+        rootName: "r",
+        knownWorktrees: [],
+        worktree: "w",
+        recursive: true,
+      },
+    });
+  });
+
+  it("cancelRequest targets a request id", async () => {
+    await cancelRequest("req-1");
+    expect(mockInvoke).toHaveBeenCalledWith("cancel_request", {
+      payload: { requestId: "req-1" },
+    });
+
+    await commandsInFlight();
+    expect(mockInvoke).toHaveBeenCalledWith("commands_in_flight", undefined);
+  });
+
+  it("maintenance commands forward payloads", async () => {
+    await maintenanceConfigure({
+      utcOffsetMinutes: 120,
+      tasks: [{ task: "fetchPrune", enabled: true, at: "03:00" }],
+    });
+    expect(mockInvoke).toHaveBeenCalledWith("maintenance_configure", {
+      payload: {
+        utcOffsetMinutes: 120,
+        tasks: [{ task: "fetchPrune", enabled: true, at: "03:00" }],
+      },
+    });
+
+    await maintenanceRunNow();
+    expect(mockInvoke).toHaveBeenCalledWith("maintenance_run_now", {
+      payload: { tasks: [] },
+    });
+  });
+
+  it("testing environment log commands forward the worktree", async () => {
+    await testingEnvironmentLogsTail({ worktree: "w", follow: true });
+    expect(mockInvoke).toHaveBeenCalledWith("testing_environment_logs_tail", {
+      payload: { worktree: "w", follow: true },
+    });
+
+    await testingEnvironmentLogsClear("w");
+    expect(mockInvoke).toHaveBeenCalledWith("testing_environment_logs_clear", {
+      payload: { worktree: "w" },
+    });
+  });
+
+  it("statusPageStatus reads the listener state", async () => {
+    mockInvoke.mockResolvedValueOnce({
+      ok: true,
+      settings: { enabled: true, port: 4924, allowRemote: false },
+      listening: true,
+      url: "http://127.0.0.1:4924/",
+    });
+    const response = await statusPageStatus();
+    expect(mockInvoke).toHaveBeenCalledWith("status_page_status", undefined);
+    expect(response.url).toBe("http://127.0.0.1:4924/");
+  });
+
+  it("gitAdd forwards debug and returns transcripts", async () => {
+    mockInvoke.mockResolvedValueOnce({
+      ok: true,
+      debugTranscripts: [
+        {
+          argv: ["git", "-C", "/repo", "add", "-A"],
+          env: {},
+          durationMs: 12,
+          exitCode: 0,
+          stdout: "",
+          stderr: "",
+          outputTruncated: false,
+        },
+      ],
+    });
+    const response = await gitAdd({ path: "/repo", debug: true });
+    expect(mockInvoke).toHaveBeenCalledWith("git_add", {
+      payload: { path: "/repo", debug: true },
+    });
+    expect(response.debugTranscripts?.[0]?.argv).toContain("add");
+  });
+
+  it("appBackupCreate and appBackupRestore forward the backup path", async () => {
+    await appBackupCreate("~/groove-backup.json");
+    expect(mockInvoke).toHaveBeenCalledWith("app_backup_create", {
+      payload: { path: "~/groove-backup.json" },
+    });
+
+    await appBackupRestore({
+      path: "~/groove-backup.json",
+      includeWorkspaces: false,
+    });
+    expect(mockInvoke).toHaveBeenCalledWith("app_backup_restore", {
+      payload: { path: "~/groove-backup.json", includeWorkspaces: false },
+    });
+  });
+
+  it("mergeTrainStart and mergeTrainResume forward payloads", async () => {
+    await mergeTrainStart({ worktrees: ["a", "b"], strategy: "squash" });
+    expect(mockInvoke).toHaveBeenCalledWith("merge_train_start", {
+      payload: { worktrees: ["a", "b"], strategy: "squash" },
+    });
+
+    await mergeTrainResume({ skip: true });
+    expect(mockInvoke).toHaveBeenCalledWith("merge_train_resume", {
+      payload: { skip: true },
+    });
+  });
+
+  it("worktreeStalenessReport forwards dryRun", async () => {
+    await worktreeStalenessReport({ dryRun: true });
+    expect(mockInvoke).toHaveBeenCalledWith("worktree_staleness_report", {
+      payload: { dryRun: true },
+    });
+  });
+
+  it("workspaceConflictMatrix forwards the worktrees to compare", async () => {
+    await workspaceConflictMatrix({ worktrees: ["a", "b"] });
+    expect(mockInvoke).toHaveBeenCalledWith("workspace_conflict_matrix", {
+      payload: { worktrees: ["a", "b"] },
+    });
+  });
+
+  it("workspaceFreeze and workspaceThaw forward their options", async () => {
+    await workspaceFreeze({ compressArtifacts: false });
+    expect(mockInvoke).toHaveBeenCalledWith("workspace_freeze", {
+      payload: { compressArtifacts: false },
+    });
+
+    await workspaceThaw({ restartSessions: true });
+    expect(mockInvoke).toHaveBeenCalledWith("workspace_thaw", {
+      payload: { restartSessions: true },
+    });
+  });
+
   it("grooveComment forwards includeSession flag", async () => {
     await grooveComment({
       rootName: "r",
@@ -920,6 +1244,14 @@ describe("IPC wrapper functions", () => {
     });
   });
 
+  it("workspaceOpen passes the requested page", async () => {
+    await workspaceOpen("/path", { cursor: "feature-a", limit: 50 });
+    expect(mockInvoke).toHaveBeenCalledWith("workspace_open", {
+      workspaceRoot: "/path",
+      page: { cursor: "feature-a", limit: 50 },
+    });
+  });
+
   it("workspaceClearActive invalidates cache and calls command", async () => {
     await workspaceClearActive();
     expect(mockInvoke).toHaveBeenCalledWith(
@@ -928,6 +1260,22 @@ describe("IPC wrapper functions", () => {
     );
   });
 
+  it("workspaceClose invalidates cache and calls command", async () => {
+    await workspaceClose("/path");
+    expect(mockInvoke).toHaveBeenCalledWith("workspace_close", {
+      workspaceRoot: "/path",
+    });
+  });
+
+  it("worktreeWatch and worktreeUnwatch call their commands", async () => {
+    await worktreeWatch("/repo/.worktrees/feature");
+    expect(mockInvoke).toHaveBeenCalledWith("worktree_watch", {
+      payload: { worktreePath: "/repo/.worktrees/feature" },
+    });
+    await worktreeUnwatch();
+    expect(mockInvoke).toHaveBeenCalledWith("worktree_unwatch", undefined);
+  });
+
   it("workspaceTermSanityCheck calls correct command", async () => {
     await workspaceTermSanityCheck();
     expect(mockInvoke).toHaveBeenCalledWith(
@@ -1033,6 +1381,34 @@ describe("IPC wrapper functions", () => {
     });
   });
 
+  it("ghPrCreate calls gh_pr_create with the draft flag", async () => {
+    await ghPrCreate({ worktreePath: "/p", base: "main", draft: true });
+    expect(mockInvoke).toHaveBeenCalledWith("gh_pr_create", {
+      payload: { worktreePath: "/p", base: "main", draft: true },
+    });
+  });
+
+  it("ghPrPrepare calls gh_pr_prepare with base", async () => {
+    await ghPrPrepare({ worktreePath: "/p", base: "main" });
+    expect(mockInvoke).toHaveBeenCalledWith("gh_pr_prepare", {
+      payload: { worktreePath: "/p", base: "main" },
+    });
+  });
+
+  it("ghPrUpdate calls gh_pr_update", async () => {
+    await ghPrUpdate({ worktreePath: "/p", selector: "42", ready: true });
+    expect(mockInvoke).toHaveBeenCalledWith("gh_pr_update", {
+      payload: { worktreePath: "/p", selector: "42", ready: true },
+    });
+  });
+
+  it("ghIssueView calls gh_issue_view", async () => {
+    await ghIssueView({ worktreePath: "/p", number: 7 });
+    expect(mockInvoke).toHaveBeenCalledWith("gh_issue_view", {
+      payload: { worktreePath: "/p", number: 7 },
+    });
+  });
+
   it("groovePrAttach calls groove_pr_attach", async () => {
     await groovePrAttach({
       rootName: "r",
@@ -1097,6 +1473,24 @@ describe("IPC wrapper functions", () => {
     );
   });
 
+  it("playCommandPreview forwards the template and worktree", async () => {
+    const payload = {
+      playGrooveCommand: "npm run dev -- {branch}",
+      worktree: "feature-a",
+    };
+    mockInvoke.mockResolvedValueOnce({
+      ok: true,
+      mode: "command",
+      argv: ["npm", "run", "dev", "--", "feature/a"],
+      targetAppended: false,
+    });
+    const result = await playCommandPreview(payload);
+    expect(mockInvoke).toHaveBeenCalledWith("play_command_preview", {
+      payload,
+    });
+    expect(result.argv).toEqual(["npm", "run", "dev", "--", "feature/a"]);
+  });
+
   it("workspaceUpdateWorktreeSymlinkPaths calls correct command", async () => {
     const payload = { worktreeSymlinkPaths: ["/a"] };
     await workspaceUpdateWorktreeSymlinkPaths(payload);
@@ -1394,6 +1788,51 @@ describe("listen functions", () => {
     expect(callback).toHaveBeenCalledWith(payload);
   });
 
+  it("listenGrooveCommandOutput registers listener on groove-command-output", async () => {
+    const unlisten = vi.fn();
+    mockListen.mockResolvedValueOnce(unlisten);
+    const callback = vi.fn();
+    await listenGrooveCommandOutput(callback);
+    expect(mockListen).toHaveBeenCalledWith(
+      "groove-command-output",
+      expect.any(Function),
+    );
+
+    const eventHandler = mockListen.mock.calls[0][1];
+    const payload = {
+      version: 1,
+      requestId: "r1",
+      command: "groove_new",
+      stream: "stdout",
+      chunk: "data",
+    };
+    eventHandler({ payload });
+    expect(callback).toHaveBeenCalledWith(payload);
+  });
+
+  it("listenTestingEnvironmentStatus registers listener on testing-environment-status", async () => {
+    const unlisten = vi.fn();
+    mockListen.mockResolvedValueOnce(unlisten);
+    const callback = vi.fn();
+    await listenTestingEnvironmentStatus(callback);
+    expect(mockListen).toHaveBeenCalledWith(
+      "testing-environment-status",
+      expect.any(Function),
+    );
+
+    const eventHandler = mockListen.mock.calls[0][1];
+    const payload = {
+      version: 1,
+      workspaceRoot: "/r",
+      worktree: "w",
+      status: "restarting",
+      restarts: 1,
+      crashReason: "reason=eof exit_status=1",
+    };
+    eventHandler({ payload });
+    expect(callback).toHaveBeenCalledWith(payload);
+  });
+
   it("listenGrooveTerminalLifecycle registers listener on groove-terminal-lifecycle", async () => {
     const unlisten = vi.fn();
     mockListen.mockResolvedValueOnce(unlisten);
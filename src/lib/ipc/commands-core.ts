@@ -4,30 +4,65 @@ import type {
   WorkspaceContextResponse,
   WorkspaceEventsPayload,
   WorkspaceEventsResponse,
+  WorktreeWatchResponse,
   WorkspaceTermSanityResponse,
   WorkspaceGitignoreSanityResponse,
+  WorkspaceSearchWorktreesPayload,
+  WorkspaceSearchWorktreesResponse,
+  WorkspaceListWorktreesPayload,
+  WorkspaceListWorktreesResponse,
+  WorkspaceSummaryResponse,
+  WorkspaceListOpenResponse,
+  RowsPagePayload,
+  AppHealthResponse,
+  BackgroundActivityResponse,
   GrooveBinStatusResponse,
   GrooveBinRepairResponse,
   ExternalUrlOpenResponse,
   WorkspaceTerminalSettingsPayload,
   WorkspaceTerminalSettingsResponse,
+  WorkspaceTrustResponse,
+  CustomCommandApproveResponse,
   WorkspaceCommandSettingsPayload,
   WorkspaceCommandSettingsResponse,
+  PlayCommandPreviewPayload,
+  PlayCommandPreviewResponse,
   WorkspaceMaxWorktreeCountPayload,
   WorkspaceMaxWorktreeCountResponse,
+  WorkspaceStalenessPolicyPayload,
+  WorkspaceRemoteGitStatusPayload,
+  WorkspaceRemotePayload,
+  WorkspaceGitIdentityPayload,
+  WorkspaceCommitTemplatePayload,
+  WorkspaceCommitLintPayload,
+  WorkspaceRequireSignOffPayload,
+  WorkspaceCustomCommandsPayload,
+  WorkspaceCustomCommandsListResponse,
+  WorkspaceRemoteProbeResponse,
+  WorkspaceRemoteWorktreesResponse,
   WorktreeStorageStatsPayload,
   WorktreeStorageStatsResponse,
+  WorktreeStalenessReportPayload,
+  WorktreeStalenessReportResponse,
   WorktreeEvictedEvent,
   WorkspaceWorktreeSymlinkPathsPayload,
   WorkspaceBrowseEntriesPayload,
   WorkspaceBrowseEntriesResponse,
   WorkspaceOpenDirectoryResponse,
+  SetWorktreePlayCommandPayload,
+  SetWorktreeLabelsPayload,
   SetWorktreeStatePayload,
   SetWorktreeStateResponse,
+  WorkspaceAdoptWorktreePayload,
+  WorkspaceAdoptWorktreeResponse,
+  WorkspaceMigratePayload,
+  WorkspaceMigrateResponse,
   ClaimWorktreeRewardPayload,
   ClaimWorktreeRewardResponse,
   LootWorktreePayload,
   LootWorktreeResponse,
+  UndoListResponse,
+  UndoLastResponse,
 } from "./types-core";
 import type {
   GrooveRestorePayload,
@@ -36,12 +71,32 @@ import type {
   GrooveNewResponse,
   GrooveRmPayload,
   GrooveRmResponse,
+  GrooveRmSafetyResponse,
   GrooveStopPayload,
   GrooveStopResponse,
+  GrooveBulkPayload,
+  GrooveBulkResponse,
+  GrooveArchiveListPayload,
+  GrooveArchiveListResponse,
+  GrooveArchivePayload,
+  GrooveArchiveResponse,
+  GrooveUnarchivePayload,
+  WorktreeActionPayload,
+  WorktreeActionResponse,
+  WorktreeRestackPayload,
+  WorktreeRestackResponse,
+  MergeTrainStartPayload,
+  MergeTrainResumePayload,
+  MergeTrainResponse,
   GrooveRecoverableListPayload,
   GrooveRecoverableListResponse,
   GrooveRecoverableClearPayload,
   GrooveRecoverableClearResponse,
+  WorkspaceFreezePayload,
+  WorkspaceThawPayload,
+  WorkspaceFreezeResponse,
+  WorkspaceConflictMatrixPayload,
+  WorkspaceConflictMatrixResponse,
   GrooveSummaryPayload,
   GrooveSummaryResponse,
   GrooveCommentPayload,
@@ -55,21 +110,42 @@ import type {
   DiscoverWorktreeUnitResponse,
   WorkspaceOpenTerminalPayload,
   WorkspaceOpenWorkspaceTerminalPayload,
+  GrooveTmuxKillWindowPayload,
+  GrooveTmuxWindowsResponse,
+  GrooveZellijSessionsResponse,
   DiagnosticsStopResponse,
   DiagnosticsStopAllResponse,
+  DiagnosticsStopByCategoryPayload,
+  DiagnosticsStopByCategoryResponse,
   DiagnosticsNodeAppsResponse,
   DiagnosticsMostConsumingProgramsResponse,
+  DiagnosticsFdUsageResponse,
   DiagnosticsSystemOverviewResponse,
+  DiagnosticsTerminalMemoryResponse,
+  DiagnosticsWorktreeResourcesResponse,
+  TerminalStorageStatsResponse,
 } from "./types-commands";
+import type { GitStatusResponse } from "./types-git";
 import type { GrooveNotificationEvent } from "./types-terminal";
+import type {
+  BackendHeartbeatEvent,
+  BackgroundActivityEvent,
+  CustomCommandConfirmationEvent,
+  GitProgressEvent,
+  GitQueueWaitEvent,
+  GrooveCommandCompleteEvent,
+  GrooveCommandOutputEvent,
+  StateRecoveredEvent,
+  SystemAlertEvent,
+  TestingEnvironmentLogEvent,
+  TestingEnvironmentStatusEvent,
+  WorkspaceChangeEvent,
+  WorkspaceContextCompleteEvent,
+  WorkspaceReadyEvent,
+  WorktreeWatchEvent,
+} from "./types-events";
 import { invokeCommand } from "./invoke";
 
-type WorkspaceEvent = {
-  index?: number;
-  source?: string;
-  kind?: string;
-};
-
 export function grooveRestore(
   payload: GrooveRestorePayload,
 ): Promise<GrooveRestoreResponse> {
@@ -86,12 +162,104 @@ export function grooveRm(payload: GrooveRmPayload): Promise<GrooveRmResponse> {
   return invokeCommand<GrooveRmResponse>("groove_rm", { payload });
 }
 
+export function grooveRmSafetyCheck(
+  payload: GrooveRmPayload,
+): Promise<GrooveRmSafetyResponse> {
+  return invokeCommand<GrooveRmSafetyResponse>("groove_rm_safety_check", {
+    payload,
+  });
+}
+
 export function grooveStop(
   payload: GrooveStopPayload,
 ): Promise<GrooveStopResponse> {
   return invokeCommand<GrooveStopResponse>("groove_stop", { payload });
 }
 
+export function grooveBulkRm(
+  payload: GrooveBulkPayload,
+): Promise<GrooveBulkResponse> {
+  return invokeCommand<GrooveBulkResponse>("groove_bulk_rm", { payload });
+}
+
+export function grooveBulkStop(
+  payload: GrooveBulkPayload,
+): Promise<GrooveBulkResponse> {
+  return invokeCommand<GrooveBulkResponse>("groove_bulk_stop", { payload });
+}
+
+export function grooveBulkRestore(
+  payload: GrooveBulkPayload,
+): Promise<GrooveBulkResponse> {
+  return invokeCommand<GrooveBulkResponse>("groove_bulk_restore", {
+    payload,
+  });
+}
+
+export function worktreeAction(
+  payload: WorktreeActionPayload,
+): Promise<WorktreeActionResponse> {
+  invalidateWorkspaceGetActiveCache();
+  return invokeCommand<WorktreeActionResponse>("worktree_action", { payload });
+}
+
+export function grooveArchive(
+  payload: GrooveArchivePayload,
+): Promise<GrooveArchiveResponse> {
+  invalidateWorkspaceGetActiveCache();
+  return invokeCommand<GrooveArchiveResponse>("groove_archive", { payload });
+}
+
+export function grooveArchiveList(
+  payload: GrooveArchiveListPayload,
+): Promise<GrooveArchiveListResponse> {
+  return invokeCommand<GrooveArchiveListResponse>("groove_archive_list", {
+    payload,
+  });
+}
+
+export function grooveUnarchive(
+  payload: GrooveUnarchivePayload,
+): Promise<GrooveArchiveResponse> {
+  invalidateWorkspaceGetActiveCache();
+  return invokeCommand<GrooveArchiveResponse>("groove_unarchive", { payload });
+}
+
+export function worktreeRestack(
+  payload: WorktreeRestackPayload,
+): Promise<WorktreeRestackResponse> {
+  invalidateWorkspaceGetActiveCache();
+  return invokeCommand<WorktreeRestackResponse>("worktree_restack", {
+    payload,
+  });
+}
+
+/**
+ * Merges the pull requests of `payload.worktrees` one by one. The response
+ * comes back once the train completed or paused; see `train.paused`.
+ */
+export function mergeTrainStart(
+  payload: MergeTrainStartPayload,
+): Promise<MergeTrainResponse> {
+  invalidateWorkspaceGetActiveCache();
+  return invokeCommand<MergeTrainResponse>("merge_train_start", { payload });
+}
+
+export function mergeTrainResume(
+  payload: MergeTrainResumePayload = {},
+): Promise<MergeTrainResponse> {
+  invalidateWorkspaceGetActiveCache();
+  return invokeCommand<MergeTrainResponse>("merge_train_resume", { payload });
+}
+
+export function mergeTrainStatus(): Promise<MergeTrainResponse> {
+  return invokeCommand<MergeTrainResponse>("merge_train_status");
+}
+
+export function mergeTrainAbort(): Promise<MergeTrainResponse> {
+  return invokeCommand<MergeTrainResponse>("merge_train_abort");
+}
+
 export function grooveRecoverableList(
   payload: GrooveRecoverableListPayload,
 ): Promise<GrooveRecoverableListResponse> {
@@ -161,6 +329,22 @@ export function workspaceEvents(
   });
 }
 
+/**
+ * Streams `worktree-watch` deltas for one worktree, replacing the watch of
+ * any other worktree.
+ */
+export function worktreeWatch(
+  worktreePath: string,
+): Promise<WorktreeWatchResponse> {
+  return invokeCommand<WorktreeWatchResponse>("worktree_watch", {
+    payload: { worktreePath },
+  });
+}
+
+export function worktreeUnwatch(): Promise<WorktreeWatchResponse> {
+  return invokeCommand<WorktreeWatchResponse>("worktree_unwatch");
+}
+
 export function openExternalUrl(url: string): Promise<ExternalUrlOpenResponse> {
   return invokeCommand<ExternalUrlOpenResponse>("open_external_url", { url });
 }
@@ -200,6 +384,115 @@ export function diagnosticsCleanAllDevServers(): Promise<DiagnosticsStopAllRespo
   );
 }
 
+export function diagnosticsStopByCategory(
+  payload: DiagnosticsStopByCategoryPayload,
+): Promise<DiagnosticsStopByCategoryResponse> {
+  return invokeCommand<DiagnosticsStopByCategoryResponse>(
+    "diagnostics_stop_by_category",
+    { payload },
+  );
+}
+
+export function listenStateRecovered(
+  callback: (event: StateRecoveredEvent) => void,
+): Promise<UnlistenFn> {
+  return listen<StateRecoveredEvent>("groove-state-recovered", (event) => {
+    callback(event.payload);
+  });
+}
+
+export function listenGitProgress(
+  callback: (event: GitProgressEvent) => void,
+): Promise<UnlistenFn> {
+  return listen<GitProgressEvent>("git-progress", (event) => {
+    callback(event.payload);
+  });
+}
+
+export function listenGrooveCommandOutput(
+  callback: (event: GrooveCommandOutputEvent) => void,
+): Promise<UnlistenFn> {
+  return listen<GrooveCommandOutputEvent>("groove-command-output", (event) => {
+    callback(event.payload);
+  });
+}
+
+export function listenGrooveCommandComplete(
+  callback: (event: GrooveCommandCompleteEvent) => void,
+): Promise<UnlistenFn> {
+  return listen<GrooveCommandCompleteEvent>(
+    "groove-command-complete",
+    (event) => {
+      callback(event.payload);
+    },
+  );
+}
+
+export function listenTestingEnvironmentStatus(
+  callback: (event: TestingEnvironmentStatusEvent) => void,
+): Promise<UnlistenFn> {
+  return listen<TestingEnvironmentStatusEvent>(
+    "testing-environment-status",
+    (event) => {
+      callback(event.payload);
+    },
+  );
+}
+
+export function listenTestingEnvironmentLog(
+  callback: (event: TestingEnvironmentLogEvent) => void,
+): Promise<UnlistenFn> {
+  return listen<TestingEnvironmentLogEvent>(
+    "testing-environment-log",
+    (event) => {
+      callback(event.payload);
+    },
+  );
+}
+
+export function listenGitQueueWait(
+  callback: (event: GitQueueWaitEvent) => void,
+): Promise<UnlistenFn> {
+  return listen<GitQueueWaitEvent>("git-queue-wait", (event) => {
+    callback(event.payload);
+  });
+}
+
+export function listenSystemAlert(
+  callback: (event: SystemAlertEvent) => void,
+): Promise<UnlistenFn> {
+  return listen<SystemAlertEvent>("system-alert", (event) => {
+    callback(event.payload);
+  });
+}
+
+export function listenBackendHeartbeat(
+  callback: (event: BackendHeartbeatEvent) => void,
+): Promise<UnlistenFn> {
+  return listen<BackendHeartbeatEvent>("backend-heartbeat", (event) => {
+    callback(event.payload);
+  });
+}
+
+export function listenBackgroundActivity(
+  callback: (event: BackgroundActivityEvent) => void,
+): Promise<UnlistenFn> {
+  return listen<BackgroundActivityEvent>("background-activity", (event) => {
+    callback(event.payload);
+  });
+}
+
+export function listenCustomCommandConfirmation(
+  callback: (event: CustomCommandConfirmationEvent) => void,
+): Promise<UnlistenFn> {
+  return listen<CustomCommandConfirmationEvent>(
+    "custom-command-confirmation",
+    (event) => {
+      callback(event.payload);
+    },
+  );
+}
+
 export function diagnosticsGetMsotConsumingPrograms(): Promise<DiagnosticsMostConsumingProgramsResponse> {
   return invokeCommand<DiagnosticsMostConsumingProgramsResponse>(
     "diagnostics_get_msot_consuming_programs",
@@ -216,18 +509,66 @@ export function diagnosticsGetSystemOverview(): Promise<DiagnosticsSystemOvervie
   );
 }
 
+export function diagnosticsTerminalMemory(): Promise<DiagnosticsTerminalMemoryResponse> {
+  return invokeCommand<DiagnosticsTerminalMemoryResponse>(
+    "diagnostics_terminal_memory",
+    undefined,
+    {
+      intent: "background",
+    },
+  );
+}
+
+export function terminalStorageStats(): Promise<TerminalStorageStatsResponse> {
+  return invokeCommand<TerminalStorageStatsResponse>(
+    "terminal_storage_stats",
+    undefined,
+    {
+      intent: "background",
+    },
+  );
+}
+
+export function diagnosticsWorktreeResources(): Promise<DiagnosticsWorktreeResourcesResponse> {
+  return invokeCommand<DiagnosticsWorktreeResourcesResponse>(
+    "diagnostics_worktree_resources",
+    undefined,
+    {
+      intent: "background",
+    },
+  );
+}
+
+export function diagnosticsFdUsage(): Promise<DiagnosticsFdUsageResponse> {
+  return invokeCommand<DiagnosticsFdUsageResponse>(
+    "diagnostics_fd_usage",
+    undefined,
+    {
+      intent: "background",
+    },
+  );
+}
+
 export function listenWorkspaceChange(
-  callback: (event: WorkspaceEvent) => void,
+  callback: (event: WorkspaceChangeEvent) => void,
 ): Promise<UnlistenFn> {
-  return listen<WorkspaceEvent>("workspace-change", (event) => {
+  return listen<WorkspaceChangeEvent>("workspace-change", (event) => {
     callback(event.payload);
   });
 }
 
 export function listenWorkspaceReady(
-  callback: (event: Record<string, unknown>) => void,
+  callback: (event: WorkspaceReadyEvent) => void,
 ): Promise<UnlistenFn> {
-  return listen<Record<string, unknown>>("workspace-ready", (event) => {
+  return listen<WorkspaceReadyEvent>("workspace-ready", (event) => {
+    callback(event.payload);
+  });
+}
+
+export function listenWorktreeWatch(
+  callback: (event: WorktreeWatchEvent) => void,
+): Promise<UnlistenFn> {
+  return listen<WorktreeWatchEvent>("worktree-watch", (event) => {
     callback(event.payload);
   });
 }
@@ -247,10 +588,12 @@ export function workspacePickAndOpen(): Promise<WorkspaceContextResponse> {
 
 export function workspaceOpen(
   workspaceRoot: string,
+  page?: RowsPagePayload,
 ): Promise<WorkspaceContextResponse> {
   invalidateWorkspaceGetActiveCache();
   return invokeCommand<WorkspaceContextResponse>("workspace_open", {
     workspaceRoot,
+    page,
   });
 }
 
@@ -258,7 +601,16 @@ let workspaceGetActiveCachedResult: WorkspaceContextResponse | null = null;
 let workspaceGetActiveCachedAt = 0;
 const WORKSPACE_GET_ACTIVE_CACHE_TTL_MS = 400;
 
-export function workspaceGetActive(): Promise<WorkspaceContextResponse> {
+export function workspaceGetActive(
+  page?: RowsPagePayload,
+): Promise<WorkspaceContextResponse> {
+  if (page) {
+    return invokeCommand<WorkspaceContextResponse>(
+      "workspace_get_active",
+      { page },
+      { intent: "background" },
+    );
+  }
   const now = Date.now();
   if (
     workspaceGetActiveCachedResult &&
@@ -273,12 +625,25 @@ export function workspaceGetActive(): Promise<WorkspaceContextResponse> {
       intent: "background",
     },
   ).then((result) => {
-    workspaceGetActiveCachedResult = result;
-    workspaceGetActiveCachedAt = Date.now();
+    if (!result.partial) {
+      workspaceGetActiveCachedResult = result;
+      workspaceGetActiveCachedAt = Date.now();
+    }
     return result;
   });
 }
 
+export function listenWorkspaceContextComplete(
+  callback: (event: WorkspaceContextCompleteEvent) => void,
+): Promise<UnlistenFn> {
+  return listen<WorkspaceContextCompleteEvent>(
+    "workspace-context-complete",
+    (event) => {
+      callback(event.payload);
+    },
+  );
+}
+
 export function invalidateWorkspaceGetActiveCache(): void {
   workspaceGetActiveCachedResult = null;
   workspaceGetActiveCachedAt = 0;
@@ -316,6 +681,21 @@ export function workspaceGitignoreSanityApply(): Promise<WorkspaceGitignoreSanit
   );
 }
 
+export function appHealth(): Promise<AppHealthResponse> {
+  return invokeCommand<AppHealthResponse>("app_health", undefined, {
+    intent: "background",
+  });
+}
+
+/** Quiets workers, watchers and samplers until `backgroundResume`. */
+export function backgroundPause(): Promise<BackgroundActivityResponse> {
+  return invokeCommand<BackgroundActivityResponse>("background_pause");
+}
+
+export function backgroundResume(): Promise<BackgroundActivityResponse> {
+  return invokeCommand<BackgroundActivityResponse>("background_resume");
+}
+
 export function grooveBinStatus(): Promise<GrooveBinStatusResponse> {
   return invokeCommand<GrooveBinStatusResponse>(
     "groove_bin_status",
@@ -335,6 +715,34 @@ export function workspaceClearActive(): Promise<WorkspaceContextResponse> {
   return invokeCommand<WorkspaceContextResponse>("workspace_clear_active");
 }
 
+export function workspaceListOpen(): Promise<WorkspaceListOpenResponse> {
+  return invokeCommand<WorkspaceListOpenResponse>(
+    "workspace_list_open",
+    undefined,
+    { intent: "background" },
+  );
+}
+
+export function workspaceSwitch(
+  workspaceRoot: string,
+  page?: RowsPagePayload,
+): Promise<WorkspaceContextResponse> {
+  invalidateWorkspaceGetActiveCache();
+  return invokeCommand<WorkspaceContextResponse>("workspace_switch", {
+    workspaceRoot,
+    page,
+  });
+}
+
+export function workspaceClose(
+  workspaceRoot: string,
+): Promise<WorkspaceListOpenResponse> {
+  invalidateWorkspaceGetActiveCache();
+  return invokeCommand<WorkspaceListOpenResponse>("workspace_close", {
+    workspaceRoot,
+  });
+}
+
 export function workspaceUpdateTerminalSettings(
   payload: WorkspaceTerminalSettingsPayload,
 ): Promise<WorkspaceTerminalSettingsResponse> {
@@ -353,6 +761,14 @@ export function workspaceUpdateCommandsSettings(
   );
 }
 
+export function playCommandPreview(
+  payload: PlayCommandPreviewPayload,
+): Promise<PlayCommandPreviewResponse> {
+  return invokeCommand<PlayCommandPreviewResponse>("play_command_preview", {
+    payload,
+  });
+}
+
 export function workspaceUpdateMaxWorktreeCount(
   payload: WorkspaceMaxWorktreeCountPayload,
 ): Promise<WorkspaceMaxWorktreeCountResponse> {
@@ -363,6 +779,120 @@ export function workspaceUpdateMaxWorktreeCount(
   );
 }
 
+export function workspaceUpdateStalenessPolicy(
+  payload: WorkspaceStalenessPolicyPayload,
+): Promise<WorkspaceTerminalSettingsResponse> {
+  invalidateWorkspaceGetActiveCache();
+  return invokeCommand<WorkspaceTerminalSettingsResponse>(
+    "workspace_update_staleness_policy",
+    { payload },
+  );
+}
+
+export function workspaceUpdateRemote(
+  payload: WorkspaceRemotePayload,
+): Promise<WorkspaceTerminalSettingsResponse> {
+  invalidateWorkspaceGetActiveCache();
+  return invokeCommand<WorkspaceTerminalSettingsResponse>(
+    "workspace_update_remote",
+    { payload },
+  );
+}
+
+export function workspaceUpdateGitIdentity(
+  payload: WorkspaceGitIdentityPayload,
+): Promise<WorkspaceTerminalSettingsResponse> {
+  invalidateWorkspaceGetActiveCache();
+  return invokeCommand<WorkspaceTerminalSettingsResponse>(
+    "workspace_update_git_identity",
+    { payload },
+  );
+}
+
+export function workspaceUpdateCommitTemplate(
+  payload: WorkspaceCommitTemplatePayload,
+): Promise<WorkspaceTerminalSettingsResponse> {
+  invalidateWorkspaceGetActiveCache();
+  return invokeCommand<WorkspaceTerminalSettingsResponse>(
+    "workspace_update_commit_template",
+    { payload },
+  );
+}
+
+export function workspaceUpdateCommitLint(
+  payload: WorkspaceCommitLintPayload,
+): Promise<WorkspaceTerminalSettingsResponse> {
+  invalidateWorkspaceGetActiveCache();
+  return invokeCommand<WorkspaceTerminalSettingsResponse>(
+    "workspace_update_commit_lint",
+    { payload },
+  );
+}
+
+export function workspaceUpdateRequireSignOff(
+  payload: WorkspaceRequireSignOffPayload,
+): Promise<WorkspaceTerminalSettingsResponse> {
+  invalidateWorkspaceGetActiveCache();
+  return invokeCommand<WorkspaceTerminalSettingsResponse>(
+    "workspace_update_require_sign_off",
+    { payload },
+  );
+}
+
+export function workspaceSetTrust(
+  trusted: boolean,
+): Promise<WorkspaceTrustResponse> {
+  invalidateWorkspaceGetActiveCache();
+  return invokeCommand<WorkspaceTrustResponse>("workspace_set_trust", {
+    payload: { trusted },
+  });
+}
+
+export function customCommandApprove(
+  command: string,
+): Promise<CustomCommandApproveResponse> {
+  return invokeCommand<CustomCommandApproveResponse>(
+    "custom_command_approve",
+    { payload: { command } },
+  );
+}
+
+export function workspaceCustomCommandsList(): Promise<WorkspaceCustomCommandsListResponse> {
+  return invokeCommand<WorkspaceCustomCommandsListResponse>(
+    "workspace_custom_commands_list",
+    undefined,
+    { intent: "background" },
+  );
+}
+
+export function workspaceUpdateCustomCommands(
+  payload: WorkspaceCustomCommandsPayload,
+): Promise<WorkspaceTerminalSettingsResponse> {
+  invalidateWorkspaceGetActiveCache();
+  return invokeCommand<WorkspaceTerminalSettingsResponse>(
+    "workspace_update_custom_commands",
+    { payload },
+  );
+}
+
+export function workspaceRemoteProbe(): Promise<WorkspaceRemoteProbeResponse> {
+  return invokeCommand<WorkspaceRemoteProbeResponse>("workspace_remote_probe");
+}
+
+export function workspaceRemoteListWorktrees(): Promise<WorkspaceRemoteWorktreesResponse> {
+  return invokeCommand<WorkspaceRemoteWorktreesResponse>(
+    "workspace_remote_list_worktrees",
+  );
+}
+
+export function workspaceRemoteGitStatus(
+  payload: WorkspaceRemoteGitStatusPayload,
+): Promise<GitStatusResponse> {
+  return invokeCommand<GitStatusResponse>("workspace_remote_git_status", {
+    payload,
+  });
+}
+
 export function worktreeStorageStats(
   payload: WorktreeStorageStatsPayload = {},
 ): Promise<WorktreeStorageStatsResponse> {
@@ -373,6 +903,48 @@ export function worktreeStorageStats(
   );
 }
 
+export function worktreeStalenessReport(
+  payload: WorktreeStalenessReportPayload = {},
+): Promise<WorktreeStalenessReportResponse> {
+  if (!payload.dryRun) {
+    invalidateWorkspaceGetActiveCache();
+  }
+  return invokeCommand<WorktreeStalenessReportResponse>(
+    "worktree_staleness_report",
+    { payload },
+  );
+}
+
+export function workspaceFreeze(
+  payload: WorkspaceFreezePayload = {},
+): Promise<WorkspaceFreezeResponse> {
+  invalidateWorkspaceGetActiveCache();
+  return invokeCommand<WorkspaceFreezeResponse>("workspace_freeze", {
+    payload,
+  });
+}
+
+export function workspaceThaw(
+  payload: WorkspaceThawPayload = {},
+): Promise<WorkspaceFreezeResponse> {
+  invalidateWorkspaceGetActiveCache();
+  return invokeCommand<WorkspaceFreezeResponse>("workspace_thaw", { payload });
+}
+
+/**
+ * Which pairs of worktrees change the same lines since their merge base and
+ * would conflict when merged. Cheap enough to poll while agents work.
+ */
+export function workspaceConflictMatrix(
+  payload: WorkspaceConflictMatrixPayload = {},
+): Promise<WorkspaceConflictMatrixResponse> {
+  return invokeCommand<WorkspaceConflictMatrixResponse>(
+    "workspace_conflict_matrix",
+    { payload },
+    { intent: "background" },
+  );
+}
+
 export function listenWorktreeEvicted(
   callback: (event: WorktreeEvictedEvent) => void,
 ): Promise<UnlistenFn> {
@@ -418,6 +990,68 @@ export function workspaceSetWorktreeState(
   );
 }
 
+export function workspaceAdoptWorktree(
+  payload: WorkspaceAdoptWorktreePayload,
+): Promise<WorkspaceAdoptWorktreeResponse> {
+  return invokeCommand<WorkspaceAdoptWorktreeResponse>(
+    "workspace_adopt_worktree",
+    { payload },
+  );
+}
+
+export function workspaceMigrate(
+  payload: WorkspaceMigratePayload,
+): Promise<WorkspaceMigrateResponse> {
+  return invokeCommand<WorkspaceMigrateResponse>("workspace_migrate", {
+    payload,
+  });
+}
+
+export function workspaceSetWorktreePlayCommand(
+  payload: SetWorktreePlayCommandPayload,
+): Promise<SetWorktreeStateResponse> {
+  return invokeCommand<SetWorktreeStateResponse>(
+    "workspace_set_worktree_play_command",
+    { payload },
+  );
+}
+
+export function workspaceSetWorktreeLabels(
+  payload: SetWorktreeLabelsPayload,
+): Promise<SetWorktreeStateResponse> {
+  return invokeCommand<SetWorktreeStateResponse>(
+    "workspace_set_worktree_labels",
+    { payload },
+  );
+}
+
+export function workspaceSearchWorktrees(
+  payload: WorkspaceSearchWorktreesPayload,
+): Promise<WorkspaceSearchWorktreesResponse> {
+  return invokeCommand<WorkspaceSearchWorktreesResponse>(
+    "workspace_search_worktrees",
+    { payload },
+  );
+}
+
+export function workspaceListWorktrees(
+  payload: WorkspaceListWorktreesPayload,
+): Promise<WorkspaceListWorktreesResponse> {
+  return invokeCommand<WorkspaceListWorktreesResponse>(
+    "workspace_list_worktrees",
+    { payload },
+    { intent: "background" },
+  );
+}
+
+export function workspaceSummary(): Promise<WorkspaceSummaryResponse> {
+  return invokeCommand<WorkspaceSummaryResponse>(
+    "workspace_summary",
+    undefined,
+    { intent: "background" },
+  );
+}
+
 export function workspaceClaimWorktreeReward(
   payload: ClaimWorktreeRewardPayload,
 ): Promise<ClaimWorktreeRewardResponse> {
@@ -463,3 +1097,55 @@ export function workspaceOpenWorkspaceTerminal(
     { payload },
   );
 }
+
+export function grooveTmuxListWindows(
+  payload: WorkspaceOpenWorkspaceTerminalPayload,
+): Promise<GrooveTmuxWindowsResponse> {
+  return invokeCommand<GrooveTmuxWindowsResponse>("groove_tmux_list_windows", {
+    payload,
+  });
+}
+
+export function grooveZellijListSessions(
+  payload: WorkspaceOpenWorkspaceTerminalPayload,
+): Promise<GrooveZellijSessionsResponse> {
+  return invokeCommand<GrooveZellijSessionsResponse>(
+    "groove_zellij_list_sessions",
+    { payload },
+  );
+}
+
+export function grooveZellijAttachSession(
+  payload: WorkspaceOpenTerminalPayload,
+): Promise<GrooveRestoreResponse> {
+  return invokeCommand<GrooveRestoreResponse>("groove_zellij_attach_session", {
+    payload,
+  });
+}
+
+export function grooveZellijKillSession(
+  payload: WorkspaceOpenTerminalPayload,
+): Promise<GrooveRestoreResponse> {
+  return invokeCommand<GrooveRestoreResponse>("groove_zellij_kill_session", {
+    payload,
+  });
+}
+
+export function grooveTmuxKillWindow(
+  payload: GrooveTmuxKillWindowPayload,
+): Promise<GrooveRestoreResponse> {
+  return invokeCommand<GrooveRestoreResponse>("groove_tmux_kill_window", {
+    payload,
+  });
+}
+
+export function undoList(): Promise<UndoListResponse> {
+  return invokeCommand<UndoListResponse>("undo_list", undefined, {
+    intent: "background",
+  });
+}
+
+export function undoLast(): Promise<UndoLastResponse> {
+  invalidateWorkspaceGetActiveCache();
+  return invokeCommand<UndoLastResponse>("undo_last");
+}
@@ -3,6 +3,13 @@ import { listen, type UnlistenFn } from "@tauri-apps/api/event";
 import type {
   GlobalSettingsUpdatePayload,
   GlobalSettingsResponse,
+  SettingsProfileImportPayload,
+  SettingsProfileNamePayload,
+  SettingsProfileSavePayload,
+  SettingsProfilesResponse,
+  SettingsSyncConfigurePayload,
+  SettingsSyncResponse,
+  SettingsSyncRunPayload,
   WorkspaceEventsPayload,
   OpencodeUpdateWorkspaceSettingsPayload,
   OpencodeUpdateGlobalSettingsPayload,
@@ -38,21 +45,55 @@ import type {
   GitAheadBehindResponse,
   GitBooleanResponse,
   GitCommandResponse,
+  GitCommitHooksResponse,
   GitCommitPayload,
+  GitCommitResponse,
+  GitConflictResolvePayload,
+  GitConflictResolveResponse,
+  GitConflictsResponse,
+  GitLintCommitMessagePayload,
+  GitLintCommitMessageResponse,
+  GitPushPreflightResponse,
   GitCurrentBranchPayload,
   GitCurrentBranchResponse,
+  GitDiffFilePayload,
+  GitDiffFileResponse,
+  GitDiffHunksPayload,
+  GitDiffHunksResponse,
   GitDiffResponse,
+  GitFetchBlobsPayload,
+  GitFetchBlobsResponse,
   GitListBranchesPayload,
+  GitHunksPayload,
   GitListBranchesResponse,
+  GitLogPayload,
+  GitLogResponse,
   GitPushPayload,
+  GitRebaseResponse,
+  GitRebaseStartPayload,
+  GitRebaseStatusResponse,
 } from "./types-git";
 import type {
   GhAuthStatusResponse,
   GhCommandResponse,
+  GhIssueListPayload,
+  GhIssueListResponse,
+  GhIssueViewPayload,
+  GhIssueViewResponse,
   GhLoginPayload,
   GhLogoutPayload,
+  GhPrChecksResponse,
+  GhPrCommentsResponse,
+  GhPrCreatePayload,
+  GhPrCreateResponse,
   GhPrCreateWebPayload,
   GhPrListResponse,
+  GhPrMergePayload,
+  GhPrMergeResponse,
+  GhPrPreparePayload,
+  GhPrPrepareResponse,
+  GhPrReviewPayload,
+  GhPrUpdatePayload,
   GhPrViewPayload,
   GhPrViewResponse,
   GhRepoDefaultBranchResponse,
@@ -63,7 +104,7 @@ import type {
   GhWorktreePayload,
 } from "./types-github";
 
-type GitPathPayload = { path: string };
+type GitPathPayload = { path: string; debug?: boolean };
 import type {
   GrooveTerminalOpenPayload,
   GrooveTerminalWritePayload,
@@ -73,16 +114,45 @@ import type {
   GrooveTerminalCommandResponse,
   GrooveTerminalSessionResponse,
   GrooveTerminalSessionsResponse,
+  GrooveTerminalAttachPayload,
+  GrooveTerminalAttachResponse,
+  GrooveTerminalHistoryPayload,
+  GrooveTerminalHistoryResponse,
+  GrooveTerminalScrollbackPayload,
+  GrooveTerminalScrollbackRecordingPayload,
+  GrooveTerminalScrollbackResponse,
   GrooveTerminalOutputEvent,
   GrooveTerminalLifecycleEvent,
   GrooveTerminalActivityResponse,
   GrooveTerminalActiveWorktreesResponse,
+  WorktreeSessionLayoutRestorePayload,
+  WorktreeSessionLayoutRestoreResponse,
+  WorkspaceCustomCommandRunPayload,
+  WorkspaceCustomCommandRunResponse,
 } from "./types-terminal";
 import type {
   AssistantConnectResponse,
   AssistantValidateResponse,
   AssistantRuleScope,
   AssistantRulesListResponse,
+  HistoryListPayload,
+  HistoryListResponse,
+  HistoryRepeatResponse,
+  CommandRunsListPayload,
+  CommandRunsListResponse,
+  CancelRequestResponse,
+  CommandsInFlightResponse,
+  MaintenanceConfigurePayload,
+  MaintenanceResponse,
+  MaintenanceTask,
+  TestingEnvironmentStatusResponse,
+  TestingEnvironmentLogsResponse,
+  TestingEnvironmentLogsTailPayload,
+  AppBackupRestorePayload,
+  AppBackupResponse,
+  StatusPageStatusResponse,
+  ApiTokenCapability,
+  ApiTokensResponse,
 } from "./types-commands";
 import { invokeCommand } from "./invoke";
 
@@ -116,6 +186,14 @@ export function gitPush(payload: GitPushPayload): Promise<GitCommandResponse> {
   return invokeCommand<GitCommandResponse>("git_push", { payload });
 }
 
+export function gitFetchBlobsForWorktree(
+  payload: GitFetchBlobsPayload,
+): Promise<GitFetchBlobsResponse> {
+  return invokeCommand<GitFetchBlobsResponse>("git_fetch_blobs_for_worktree", {
+    payload,
+  });
+}
+
 export function gitHasUpstream(
   payload: GitPathPayload,
 ): Promise<GitBooleanResponse> {
@@ -128,8 +206,31 @@ export function gitHasUpstream(
 
 export function gitCommit(
   payload: GitCommitPayload,
-): Promise<GitCommandResponse> {
-  return invokeCommand<GitCommandResponse>("git_commit", { payload });
+): Promise<GitCommitResponse> {
+  return invokeCommand<GitCommitResponse>("git_commit", { payload });
+}
+
+export function gitLintCommitMessage(
+  payload: GitLintCommitMessagePayload,
+): Promise<GitLintCommitMessageResponse> {
+  return invokeCommand<GitLintCommitMessageResponse>(
+    "git_lint_commit_message",
+    { payload },
+  );
+}
+
+export function gitPushPreflight(
+  payload: GitPathPayload,
+): Promise<GitPushPreflightResponse> {
+  return invokeCommand<GitPushPreflightResponse>("git_push_preflight", {
+    payload,
+  });
+}
+
+export function gitCommitHooks(
+  payload: GitPathPayload,
+): Promise<GitCommitHooksResponse> {
+  return invokeCommand<GitCommitHooksResponse>("git_commit_hooks", { payload });
 }
 
 export function gitAdd(payload: GitPathPayload): Promise<GitCommandResponse> {
@@ -206,6 +307,80 @@ export function ghPrView(
   );
 }
 
+export function ghPrChecks(
+  payload: GhPrViewPayload,
+): Promise<GhPrChecksResponse> {
+  return invokeCommand<GhPrChecksResponse>(
+    "gh_pr_checks",
+    { payload },
+    { intent: "background" },
+  );
+}
+
+export function ghPrMerge(
+  payload: GhPrMergePayload,
+): Promise<GhPrMergeResponse> {
+  return invokeCommand<GhPrMergeResponse>("gh_pr_merge", { payload });
+}
+
+export function ghPrComments(
+  payload: GhPrViewPayload,
+): Promise<GhPrCommentsResponse> {
+  return invokeCommand<GhPrCommentsResponse>(
+    "gh_pr_comments",
+    { payload },
+    { intent: "background" },
+  );
+}
+
+export function ghPrReview(
+  payload: GhPrReviewPayload,
+): Promise<GhCommandResponse> {
+  return invokeCommand<GhCommandResponse>("gh_pr_review", { payload });
+}
+
+export function ghPrPrepare(
+  payload: GhPrPreparePayload,
+): Promise<GhPrPrepareResponse> {
+  return invokeCommand<GhPrPrepareResponse>(
+    "gh_pr_prepare",
+    { payload },
+    { intent: "background" },
+  );
+}
+
+export function ghPrCreate(
+  payload: GhPrCreatePayload,
+): Promise<GhPrCreateResponse> {
+  return invokeCommand<GhPrCreateResponse>("gh_pr_create", { payload });
+}
+
+export function ghPrUpdate(
+  payload: GhPrUpdatePayload,
+): Promise<GhCommandResponse> {
+  return invokeCommand<GhCommandResponse>("gh_pr_update", { payload });
+}
+
+export function ghIssueList(
+  payload: GhIssueListPayload,
+): Promise<GhIssueListResponse> {
+  return invokeCommand<GhIssueListResponse>(
+    "gh_issue_list",
+    { payload },
+    { intent: "background" },
+  );
+}
+
+export function ghIssueView(
+  payload: GhIssueViewPayload,
+): Promise<GhIssueViewResponse> {
+  return invokeCommand<GhIssueViewResponse>(
+    "gh_issue_view",
+    { payload },
+    { intent: "background" },
+  );
+}
+
 export function ghPrCreateWeb(
   payload: GhPrCreateWebPayload,
 ): Promise<GhCommandResponse> {
@@ -220,6 +395,98 @@ export function gitDiff(payload: GitPathPayload): Promise<GitDiffResponse> {
   );
 }
 
+export function gitDiffFile(
+  payload: GitDiffFilePayload,
+): Promise<GitDiffFileResponse> {
+  return invokeCommand<GitDiffFileResponse>(
+    "git_diff_file",
+    { payload },
+    { intent: "background" },
+  );
+}
+
+export function gitDiffHunks(
+  payload: GitDiffHunksPayload,
+): Promise<GitDiffHunksResponse> {
+  return invokeCommand<GitDiffHunksResponse>(
+    "git_diff_hunks",
+    { payload },
+    { intent: "background" },
+  );
+}
+
+export function gitStageHunk(
+  payload: GitHunksPayload,
+): Promise<GitCommandResponse> {
+  return invokeCommand<GitCommandResponse>("git_stage_hunk", { payload });
+}
+
+export function gitUnstageHunk(
+  payload: GitHunksPayload,
+): Promise<GitCommandResponse> {
+  return invokeCommand<GitCommandResponse>("git_unstage_hunk", { payload });
+}
+
+export function gitConflictsList(
+  payload: GitPathPayload,
+): Promise<GitConflictsResponse> {
+  return invokeCommand<GitConflictsResponse>(
+    "git_conflicts_list",
+    { payload },
+    { intent: "background" },
+  );
+}
+
+export function gitConflictResolve(
+  payload: GitConflictResolvePayload,
+): Promise<GitConflictResolveResponse> {
+  return invokeCommand<GitConflictResolveResponse>("git_conflict_resolve", {
+    payload,
+  });
+}
+
+export function gitRebaseStatus(
+  payload: GitPathPayload,
+): Promise<GitRebaseStatusResponse> {
+  return invokeCommand<GitRebaseStatusResponse>(
+    "git_rebase_status",
+    { payload },
+    { intent: "background" },
+  );
+}
+
+export function gitRebaseStart(
+  payload: GitRebaseStartPayload,
+): Promise<GitRebaseResponse> {
+  return invokeCommand<GitRebaseResponse>("git_rebase_start", { payload });
+}
+
+export function gitRebaseContinue(
+  payload: GitPathPayload,
+): Promise<GitRebaseResponse> {
+  return invokeCommand<GitRebaseResponse>("git_rebase_continue", { payload });
+}
+
+export function gitRebaseSkip(
+  payload: GitPathPayload,
+): Promise<GitRebaseResponse> {
+  return invokeCommand<GitRebaseResponse>("git_rebase_skip", { payload });
+}
+
+export function gitRebaseAbort(
+  payload: GitPathPayload,
+): Promise<GitRebaseResponse> {
+  return invokeCommand<GitRebaseResponse>("git_rebase_abort", { payload });
+}
+
+export function gitLog(payload: GitLogPayload): Promise<GitLogResponse> {
+  return invokeCommand<GitLogResponse>(
+    "git_log",
+    { payload },
+    { intent: "background" },
+  );
+}
+
 export function globalSettingsGet(): Promise<GlobalSettingsResponse> {
   return invokeCommand<GlobalSettingsResponse>(
     "global_settings_get",
@@ -238,6 +505,100 @@ export function globalSettingsUpdate(
   });
 }
 
+export function settingsProfileList(): Promise<SettingsProfilesResponse> {
+  return invokeCommand<SettingsProfilesResponse>(
+    "settings_profile_list",
+    undefined,
+    {
+      intent: "background",
+    },
+  );
+}
+
+export function settingsProfileSave(
+  payload: SettingsProfileSavePayload,
+): Promise<SettingsProfilesResponse> {
+  return invokeCommand<SettingsProfilesResponse>("settings_profile_save", {
+    payload,
+  });
+}
+
+export function settingsProfileDelete(
+  payload: SettingsProfileNamePayload,
+): Promise<SettingsProfilesResponse> {
+  return invokeCommand<SettingsProfilesResponse>("settings_profile_delete", {
+    payload,
+  });
+}
+
+/** Applies the profile's overrides to the global settings (undoable). */
+export function settingsProfileSwitch(
+  payload: SettingsProfileNamePayload,
+): Promise<SettingsProfilesResponse> {
+  return invokeCommand<SettingsProfilesResponse>("settings_profile_switch", {
+    payload,
+  });
+}
+
+export function settingsProfileExport(
+  payload: SettingsProfileNamePayload,
+): Promise<SettingsProfilesResponse> {
+  return invokeCommand<SettingsProfilesResponse>("settings_profile_export", {
+    payload,
+  });
+}
+
+export function settingsProfileImport(
+  payload: SettingsProfileImportPayload,
+): Promise<SettingsProfilesResponse> {
+  return invokeCommand<SettingsProfilesResponse>("settings_profile_import", {
+    payload,
+  });
+}
+
+export function settingsSyncStatus(): Promise<SettingsSyncResponse> {
+  return invokeCommand<SettingsSyncResponse>("settings_sync_status", undefined, {
+    intent: "background",
+  });
+}
+
+export function settingsSyncConfigure(
+  payload: SettingsSyncConfigurePayload,
+): Promise<SettingsSyncResponse> {
+  return invokeCommand<SettingsSyncResponse>("settings_sync_configure", {
+    payload,
+  });
+}
+
+export function settingsSyncExport(
+  payload: SettingsSyncRunPayload = {},
+): Promise<SettingsSyncResponse> {
+  return invokeCommand<SettingsSyncResponse>("settings_sync_export", {
+    payload,
+  });
+}
+
+export function settingsSyncImport(
+  payload: SettingsSyncRunPayload = {},
+): Promise<SettingsSyncResponse> {
+  return invokeCommand<SettingsSyncResponse>("settings_sync_import", {
+    payload,
+  });
+}
+
+/**
+ * Takes each setting from the side that changed it since the last sync.
+ * Settings changed on both sides come back in `conflicts`; nothing is
+ * written unless `prefer` settles them.
+ */
+export function settingsSyncMerge(
+  payload: SettingsSyncRunPayload = {},
+): Promise<SettingsSyncResponse> {
+  return invokeCommand<SettingsSyncResponse>("settings_sync_merge", {
+    payload,
+  });
+}
+
 export function soundLibraryRead(
   fileName: string,
 ): Promise<{ requestId?: string; ok: boolean; data?: string; error?: string }> {
@@ -499,6 +860,24 @@ export function grooveTerminalOpen(
   });
 }
 
+export function workspaceCustomCommandsRun(
+  payload: WorkspaceCustomCommandRunPayload,
+): Promise<WorkspaceCustomCommandRunResponse> {
+  return invokeCommand<WorkspaceCustomCommandRunResponse>(
+    "workspace_custom_commands_run",
+    { payload },
+  );
+}
+
+export function worktreeSessionLayoutRestore(
+  payload: WorktreeSessionLayoutRestorePayload,
+): Promise<WorktreeSessionLayoutRestoreResponse> {
+  return invokeCommand<WorktreeSessionLayoutRestoreResponse>(
+    "worktree_session_layout_restore",
+    { payload },
+  );
+}
+
 export function grooveTerminalWrite(
   payload: GrooveTerminalWritePayload,
 ): Promise<GrooveTerminalCommandResponse> {
@@ -537,6 +916,45 @@ export function grooveTerminalGetSession(
   );
 }
 
+export function grooveTerminalFetchScrollback(
+  payload: GrooveTerminalScrollbackPayload,
+): Promise<GrooveTerminalScrollbackResponse> {
+  return invokeCommand<GrooveTerminalScrollbackResponse>(
+    "groove_terminal_fetch_scrollback",
+    { payload },
+    { intent: "background" },
+  );
+}
+
+export function grooveTerminalAttach(
+  payload: GrooveTerminalAttachPayload,
+): Promise<GrooveTerminalAttachResponse> {
+  return invokeCommand<GrooveTerminalAttachResponse>(
+    "groove_terminal_attach",
+    { payload },
+    { intent: "background" },
+  );
+}
+
+export function grooveTerminalHistory(
+  payload: GrooveTerminalHistoryPayload,
+): Promise<GrooveTerminalHistoryResponse> {
+  return invokeCommand<GrooveTerminalHistoryResponse>(
+    "groove_terminal_history",
+    { payload },
+    { intent: "background" },
+  );
+}
+
+export function grooveTerminalSetScrollbackRecording(
+  payload: GrooveTerminalScrollbackRecordingPayload,
+): Promise<GrooveTerminalScrollbackResponse> {
+  return invokeCommand<GrooveTerminalScrollbackResponse>(
+    "groove_terminal_set_scrollback_recording",
+    { payload },
+  );
+}
+
 export function grooveTerminalListSessions(
   payload: GrooveTerminalSessionPayload,
 ): Promise<GrooveTerminalSessionsResponse> {
@@ -610,3 +1028,150 @@ export function assistantRuleRemove(
     id,
   });
 }
+
+export function historyList(
+  payload: HistoryListPayload = {},
+): Promise<HistoryListResponse> {
+  return invokeCommand<HistoryListResponse>(
+    "history_list",
+    { payload },
+    { intent: "background" },
+  );
+}
+
+export function historyRepeat(id: string): Promise<HistoryRepeatResponse> {
+  return invokeCommand<HistoryRepeatResponse>("history_repeat", {
+    payload: { id },
+  });
+}
+
+export function commandRunsList(
+  payload: CommandRunsListPayload = {},
+): Promise<CommandRunsListResponse> {
+  return invokeCommand<CommandRunsListResponse>(
+    "command_runs_list",
+    { payload },
+    { intent: "background" },
+  );
+}
+
+/**
+ * Asks the command with `requestId` to stop. Git progress events carry the
+ * request id of the push, pull or fetch they report on.
+ */
+export function cancelRequest(
+  requestId: string,
+): Promise<CancelRequestResponse> {
+  return invokeCommand<CancelRequestResponse>("cancel_request", {
+    payload: { requestId },
+  });
+}
+
+export function commandsInFlight(): Promise<CommandsInFlightResponse> {
+  return invokeCommand<CommandsInFlightResponse>(
+    "commands_in_flight",
+    undefined,
+    { intent: "background" },
+  );
+}
+
+export function maintenanceStatus(): Promise<MaintenanceResponse> {
+  return invokeCommand<MaintenanceResponse>("maintenance_status", undefined, {
+    intent: "background",
+  });
+}
+
+export function maintenanceConfigure(
+  payload: MaintenanceConfigurePayload,
+): Promise<MaintenanceResponse> {
+  return invokeCommand<MaintenanceResponse>("maintenance_configure", {
+    payload,
+  });
+}
+
+/** Runs `tasks` in order, or every task when none are given. */
+export function maintenanceRunNow(
+  tasks: MaintenanceTask[] = [],
+): Promise<MaintenanceResponse> {
+  return invokeCommand<MaintenanceResponse>("maintenance_run_now", {
+    payload: { tasks },
+  });
+}
+
+export function testingEnvironmentStatus(): Promise<TestingEnvironmentStatusResponse> {
+  return invokeCommand<TestingEnvironmentStatusResponse>(
+    "testing_environment_status",
+    undefined,
+    { intent: "background" },
+  );
+}
+
+/** Output of the worktree's custom Play command, from its testing log. */
+export function testingEnvironmentLogsTail(
+  payload: TestingEnvironmentLogsTailPayload,
+): Promise<TestingEnvironmentLogsResponse> {
+  return invokeCommand<TestingEnvironmentLogsResponse>(
+    "testing_environment_logs_tail",
+    { payload },
+    { intent: "background" },
+  );
+}
+
+export function testingEnvironmentLogsClear(
+  worktree: string,
+): Promise<TestingEnvironmentLogsResponse> {
+  return invokeCommand<TestingEnvironmentLogsResponse>(
+    "testing_environment_logs_clear",
+    { payload: { worktree } },
+  );
+}
+
+/**
+ * Whether the HTTP status page is listening. It is turned on and configured
+ * through `statusPageSettings` of the global settings.
+ */
+export function statusPageStatus(): Promise<StatusPageStatusResponse> {
+  return invokeCommand<StatusPageStatusResponse>(
+    "status_page_status",
+    undefined,
+    { intent: "background" },
+  );
+}
+
+/**
+ * Writes global settings, worktree history and the `workspace.json` of each
+ * open workspace to one file, for `appBackupRestore` on another machine.
+ */
+export function appBackupCreate(path: string): Promise<AppBackupResponse> {
+  return invokeCommand<AppBackupResponse>("app_backup_create", {
+    payload: { path },
+  });
+}
+
+export function appBackupRestore(
+  payload: AppBackupRestorePayload,
+): Promise<AppBackupResponse> {
+  return invokeCommand<AppBackupResponse>("app_backup_restore", { payload });
+}
+
+export function apiTokensList(): Promise<ApiTokensResponse> {
+  return invokeCommand<ApiTokensResponse>("api_tokens_list", undefined, {
+    intent: "background",
+  });
+}
+
+/** Without `capabilities` the token gets everything except `destructive`. */
+export function apiTokenCreate(
+  name: string,
+  capabilities?: ApiTokenCapability[],
+): Promise<ApiTokensResponse> {
+  return invokeCommand<ApiTokensResponse>("api_token_create", {
+    payload: { name, capabilities },
+  });
+}
+
+export function apiTokenRevoke(id: string): Promise<ApiTokensResponse> {
+  return invokeCommand<ApiTokensResponse>("api_token_revoke", {
+    payload: { id },
+  });
+}
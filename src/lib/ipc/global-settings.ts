@@ -8,10 +8,20 @@ import { DEFAULT_THEME_MODE, type ThemeMode } from "@/src/lib/theme-constants";
 
 import type {
   ClaudeCodeSoundSettings,
+  CommandRetryCategory,
+  CommandRetryPolicy,
+  CustomCommandFirstRun,
+  CustomCommandPolicy,
   GlobalSettings,
   GrooveSoundSettings,
   OpencodeSettings,
+  PollingSettings,
+  TestingEnvironmentRestartPolicy,
   SoundLibraryEntry,
+  StatusPageSettings,
+  SystemAlertSettings,
+  TelemetryPathRedaction,
+  TerminalMemorySettings,
 } from "./types-core";
 import { DEFAULT_OPENCODE_SETTINGS_DIRECTORY } from "./types-core";
 
@@ -39,6 +49,64 @@ const DEFAULT_GROOVE_SOUND_SETTINGS: GrooveSoundSettings = {
   remove: { ...DEFAULT_GROOVE_SOUND_HOOK_ENTRY },
 };
 
+const DEFAULT_TERMINAL_MEMORY_SETTINGS: TerminalMemorySettings = {
+  snapshotMaxBytes: 256 * 1024,
+  snapshotBudgetBytes: 8 * 1024 * 1024,
+  scrollbackPersistenceEnabled: false,
+  scrollbackFileMaxBytes: 16 * 1024 * 1024,
+  historyPersistenceEnabled: false,
+  hibernateIdleShells: false,
+  hibernateIdleMinutes: 30,
+  storageQuotaBytes: 512 * 1024 * 1024,
+  storageMaxAgeDays: 30,
+};
+
+const DEFAULT_SYSTEM_ALERT_SETTINGS: SystemAlertSettings = {
+  enabled: true,
+  ramPercent: 90,
+  diskPercent: 95,
+  swapInUse: true,
+  desktopNotifications: false,
+};
+
+const DEFAULT_CUSTOM_COMMAND_POLICY: CustomCommandPolicy = {
+  deniedPatterns: ["rm -rf", "rm -fr", "mkfs", "dd if=", "| sh", "| bash"],
+  allowedPrograms: [],
+  requireResolvedPrograms: true,
+  trustedFirstRun: "confirm",
+  untrustedFirstRun: "block",
+};
+
+const DEFAULT_POLLING_SETTINGS: PollingSettings = {
+  workspaceEventsIntervalMs: 1800,
+  worktreeWatchIntervalMs: 400,
+};
+
+const DEFAULT_RESTART_POLICY: TestingEnvironmentRestartPolicy = {
+  enabled: false,
+  maxRestarts: 3,
+  backoffMs: 2000,
+};
+
+const DEFAULT_STATUS_PAGE_SETTINGS: StatusPageSettings = {
+  enabled: false,
+  port: 4924,
+  allowRemote: false,
+};
+
+const DEFAULT_COMMAND_RETRY_POLICY: CommandRetryPolicy = {
+  maxAttempts: 3,
+  backoffMs: 1000,
+  maxBackoffMs: 8000,
+  retryOn: ["network", "timeout"],
+};
+
+const COMMAND_RETRY_CATEGORIES: CommandRetryCategory[] = [
+  "network",
+  "timeout",
+  "lock",
+];
+
 let latestGlobalSettings: GlobalSettings = {
   telemetryEnabled: true,
   disableGrooveBusiness: false,
@@ -54,6 +122,17 @@ let latestGlobalSettings: GlobalSettings = {
   soundLibrary: [],
   claudeCodeSoundSettings: { ...DEFAULT_CLAUDE_CODE_SOUND_SETTINGS },
   grooveSoundSettings: { ...DEFAULT_GROOVE_SOUND_SETTINGS },
+  terminalMemorySettings: { ...DEFAULT_TERMINAL_MEMORY_SETTINGS },
+  systemAlertSettings: { ...DEFAULT_SYSTEM_ALERT_SETTINGS },
+  telemetryPathRedaction: "off",
+  customCommandPolicy: { ...DEFAULT_CUSTOM_COMMAND_POLICY },
+  pollingSettings: { ...DEFAULT_POLLING_SETTINGS },
+  testingEnvironmentRestartPolicy: { ...DEFAULT_RESTART_POLICY },
+  statusPageSettings: { ...DEFAULT_STATUS_PAGE_SETTINGS },
+  commandRetryPolicy: {
+    ...DEFAULT_COMMAND_RETRY_POLICY,
+    retryOn: [...DEFAULT_COMMAND_RETRY_POLICY.retryOn],
+  },
 };
 
 const globalSettingsListeners = new Set<() => void>();
@@ -135,6 +214,184 @@ function normalizeGrooveSoundSettings(
   return result;
 }
 
+function normalizeTerminalMemorySettings(
+  value: Partial<TerminalMemorySettings> | null | undefined,
+): TerminalMemorySettings {
+  const snapshotMaxBytes = value?.snapshotMaxBytes;
+  const snapshotBudgetBytes = value?.snapshotBudgetBytes;
+  const scrollbackFileMaxBytes = value?.scrollbackFileMaxBytes;
+  const hibernateIdleMinutes = value?.hibernateIdleMinutes;
+  const storageQuotaBytes = value?.storageQuotaBytes;
+  const storageMaxAgeDays = value?.storageMaxAgeDays;
+  return {
+    snapshotMaxBytes:
+      typeof snapshotMaxBytes === "number" && snapshotMaxBytes > 0
+        ? snapshotMaxBytes
+        : DEFAULT_TERMINAL_MEMORY_SETTINGS.snapshotMaxBytes,
+    snapshotBudgetBytes:
+      typeof snapshotBudgetBytes === "number" && snapshotBudgetBytes > 0
+        ? snapshotBudgetBytes
+        : DEFAULT_TERMINAL_MEMORY_SETTINGS.snapshotBudgetBytes,
+    scrollbackPersistenceEnabled: value?.scrollbackPersistenceEnabled === true,
+    scrollbackFileMaxBytes:
+      typeof scrollbackFileMaxBytes === "number" && scrollbackFileMaxBytes > 0
+        ? scrollbackFileMaxBytes
+        : DEFAULT_TERMINAL_MEMORY_SETTINGS.scrollbackFileMaxBytes,
+    historyPersistenceEnabled: value?.historyPersistenceEnabled === true,
+    hibernateIdleShells: value?.hibernateIdleShells === true,
+    hibernateIdleMinutes:
+      typeof hibernateIdleMinutes === "number" && hibernateIdleMinutes > 0
+        ? hibernateIdleMinutes
+        : DEFAULT_TERMINAL_MEMORY_SETTINGS.hibernateIdleMinutes,
+    storageQuotaBytes:
+      typeof storageQuotaBytes === "number" && storageQuotaBytes > 0
+        ? storageQuotaBytes
+        : DEFAULT_TERMINAL_MEMORY_SETTINGS.storageQuotaBytes,
+    storageMaxAgeDays:
+      typeof storageMaxAgeDays === "number" && storageMaxAgeDays > 0
+        ? storageMaxAgeDays
+        : DEFAULT_TERMINAL_MEMORY_SETTINGS.storageMaxAgeDays,
+  };
+}
+
+function normalizeSystemAlertSettings(
+  value: Partial<SystemAlertSettings> | null | undefined,
+): SystemAlertSettings {
+  const ramPercent = value?.ramPercent;
+  const diskPercent = value?.diskPercent;
+  return {
+    enabled: value?.enabled !== false,
+    ramPercent:
+      typeof ramPercent === "number" && ramPercent > 0
+        ? ramPercent
+        : DEFAULT_SYSTEM_ALERT_SETTINGS.ramPercent,
+    diskPercent:
+      typeof diskPercent === "number" && diskPercent > 0
+        ? diskPercent
+        : DEFAULT_SYSTEM_ALERT_SETTINGS.diskPercent,
+    swapInUse: value?.swapInUse !== false,
+    desktopNotifications: value?.desktopNotifications === true,
+  };
+}
+
+function normalizeTelemetryPathRedaction(
+  value: TelemetryPathRedaction | null | undefined,
+): TelemetryPathRedaction {
+  return value === "basename" || value === "hash" ? value : "off";
+}
+
+function normalizePollingSettings(
+  value: Partial<PollingSettings> | null | undefined,
+): PollingSettings {
+  const workspaceEventsIntervalMs = value?.workspaceEventsIntervalMs;
+  const worktreeWatchIntervalMs = value?.worktreeWatchIntervalMs;
+  return {
+    workspaceEventsIntervalMs:
+      typeof workspaceEventsIntervalMs === "number" &&
+      workspaceEventsIntervalMs > 0
+        ? workspaceEventsIntervalMs
+        : DEFAULT_POLLING_SETTINGS.workspaceEventsIntervalMs,
+    worktreeWatchIntervalMs:
+      typeof worktreeWatchIntervalMs === "number" && worktreeWatchIntervalMs > 0
+        ? worktreeWatchIntervalMs
+        : DEFAULT_POLLING_SETTINGS.worktreeWatchIntervalMs,
+  };
+}
+
+function normalizeTestingEnvironmentRestartPolicy(
+  value: Partial<TestingEnvironmentRestartPolicy> | null | undefined,
+): TestingEnvironmentRestartPolicy {
+  const maxRestarts = value?.maxRestarts;
+  const backoffMs = value?.backoffMs;
+  return {
+    enabled: value?.enabled === true,
+    maxRestarts:
+      typeof maxRestarts === "number" && maxRestarts >= 0
+        ? maxRestarts
+        : DEFAULT_RESTART_POLICY.maxRestarts,
+    backoffMs:
+      typeof backoffMs === "number" && backoffMs > 0
+        ? backoffMs
+        : DEFAULT_RESTART_POLICY.backoffMs,
+  };
+}
+
+function normalizeStatusPageSettings(
+  value: Partial<StatusPageSettings> | null | undefined,
+): StatusPageSettings {
+  const port = value?.port;
+  return {
+    enabled: value?.enabled === true,
+    port:
+      typeof port === "number" && port >= 1024
+        ? port
+        : DEFAULT_STATUS_PAGE_SETTINGS.port,
+    allowRemote: value?.allowRemote === true,
+  };
+}
+
+function normalizeCommandRetryPolicy(
+  value: Partial<CommandRetryPolicy> | null | undefined,
+): CommandRetryPolicy {
+  const maxAttempts = value?.maxAttempts;
+  const backoffMs = value?.backoffMs;
+  const maxBackoffMs = value?.maxBackoffMs;
+  const retryOn = value?.retryOn;
+  return {
+    maxAttempts:
+      typeof maxAttempts === "number" && maxAttempts >= 1
+        ? maxAttempts
+        : DEFAULT_COMMAND_RETRY_POLICY.maxAttempts,
+    backoffMs:
+      typeof backoffMs === "number" && backoffMs > 0
+        ? backoffMs
+        : DEFAULT_COMMAND_RETRY_POLICY.backoffMs,
+    maxBackoffMs:
+      typeof maxBackoffMs === "number" && maxBackoffMs > 0
+        ? maxBackoffMs
+        : DEFAULT_COMMAND_RETRY_POLICY.maxBackoffMs,
+    retryOn: Array.isArray(retryOn)
+      ? COMMAND_RETRY_CATEGORIES.filter((category) =>
+          retryOn.includes(category),
+        )
+      : [...DEFAULT_COMMAND_RETRY_POLICY.retryOn],
+  };
+}
+
+function normalizeCustomCommandFirstRun(
+  value: CustomCommandFirstRun | null | undefined,
+  fallback: CustomCommandFirstRun,
+): CustomCommandFirstRun {
+  return value === "allow" || value === "confirm" || value === "block"
+    ? value
+    : fallback;
+}
+
+function normalizeCustomCommandPolicy(
+  value: Partial<CustomCommandPolicy> | null | undefined,
+): CustomCommandPolicy {
+  const strings = (list: unknown, fallback: string[]) =>
+    Array.isArray(list)
+      ? list.filter((entry): entry is string => typeof entry === "string")
+      : [...fallback];
+  return {
+    deniedPatterns: strings(
+      value?.deniedPatterns,
+      DEFAULT_CUSTOM_COMMAND_POLICY.deniedPatterns,
+    ),
+    allowedPrograms: strings(value?.allowedPrograms, []),
+    requireResolvedPrograms: value?.requireResolvedPrograms !== false,
+    trustedFirstRun: normalizeCustomCommandFirstRun(
+      value?.trustedFirstRun,
+      DEFAULT_CUSTOM_COMMAND_POLICY.trustedFirstRun,
+    ),
+    untrustedFirstRun: normalizeCustomCommandFirstRun(
+      value?.untrustedFirstRun,
+      DEFAULT_CUSTOM_COMMAND_POLICY.untrustedFirstRun,
+    ),
+  };
+}
+
 function normalizeSoundLibrary(
   value: SoundLibraryEntry[] | null | undefined,
 ): SoundLibraryEntry[] {
@@ -181,6 +438,30 @@ function normalizeGlobalSettings(
     grooveSoundSettings: normalizeGrooveSoundSettings(
       value?.grooveSoundSettings,
     ),
+    terminalMemorySettings: normalizeTerminalMemorySettings(
+      value?.terminalMemorySettings,
+    ),
+    systemAlertSettings: normalizeSystemAlertSettings(
+      value?.systemAlertSettings,
+    ),
+    telemetryPathRedaction: normalizeTelemetryPathRedaction(
+      value?.telemetryPathRedaction,
+    ),
+    customCommandPolicy: normalizeCustomCommandPolicy(
+      value?.customCommandPolicy,
+    ),
+    ...(typeof value?.defaultTerminal === "string"
+      ? { defaultTerminal: value.defaultTerminal }
+      : {}),
+    pollingSettings: normalizePollingSettings(value?.pollingSettings),
+    testingEnvironmentRestartPolicy: normalizeTestingEnvironmentRestartPolicy(
+      value?.testingEnvironmentRestartPolicy,
+    ),
+    statusPageSettings: normalizeStatusPageSettings(value?.statusPageSettings),
+    commandRetryPolicy: normalizeCommandRetryPolicy(value?.commandRetryPolicy),
+    ...(typeof value?.updatedAt === "string"
+      ? { updatedAt: value.updatedAt }
+      : {}),
   };
 }
 
@@ -208,8 +489,12 @@ export function updateBlockingInvokeCount(delta: number): void {
 const GLOBAL_SETTINGS_SYNC_COMMANDS = new Set<string>([
   "global_settings_get",
   "global_settings_update",
+  "settings_profile_switch",
+  "settings_sync_import",
+  "settings_sync_merge",
   "sound_library_import",
   "sound_library_remove",
+  "undo_last",
 ]);
 
 export function syncGlobalSettingsFromResult(
@@ -273,7 +558,17 @@ export function syncGlobalSettingsFromResult(
     JSON.stringify(nextGlobalSettings.claudeCodeSoundSettings) !==
       JSON.stringify(latestGlobalSettings.claudeCodeSoundSettings) ||
     JSON.stringify(nextGlobalSettings.grooveSoundSettings) !==
-      JSON.stringify(latestGlobalSettings.grooveSoundSettings);
+      JSON.stringify(latestGlobalSettings.grooveSoundSettings) ||
+    nextGlobalSettings.defaultTerminal !==
+      latestGlobalSettings.defaultTerminal ||
+    JSON.stringify(nextGlobalSettings.pollingSettings) !==
+      JSON.stringify(latestGlobalSettings.pollingSettings) ||
+    JSON.stringify(nextGlobalSettings.testingEnvironmentRestartPolicy) !==
+      JSON.stringify(latestGlobalSettings.testingEnvironmentRestartPolicy) ||
+    JSON.stringify(nextGlobalSettings.statusPageSettings) !==
+      JSON.stringify(latestGlobalSettings.statusPageSettings) ||
+    JSON.stringify(nextGlobalSettings.commandRetryPolicy) !==
+      JSON.stringify(latestGlobalSettings.commandRetryPolicy);
 
   latestGlobalSettings = nextGlobalSettings;
 
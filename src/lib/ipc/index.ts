@@ -5,6 +5,26 @@ export * from "./types-commands";
 export * from "./types-terminal";
 export * from "./types-git";
 export * from "./types-github";
+export {
+  GROOVE_EVENT_NAMES,
+  GROOVE_EVENT_SCHEMA_VERSION,
+  type BackendHeartbeatEvent,
+  type BackgroundActivityEvent,
+  type CustomCommandConfirmationEvent,
+  type GitProgressEvent,
+  type GitQueueWaitEvent,
+  type GrooveCommandCompleteEvent,
+  type GrooveCommandOutputEvent,
+  type StateRecoveredEvent,
+  type SystemAlertEvent,
+  type TestingEnvironmentLogEvent,
+  type TestingEnvironmentStatusEvent,
+  type WorkspaceChangeEvent,
+  type WorkspaceReadyEvent,
+  type WorktreeChangeReason,
+  type WorktreeWatchEvent,
+} from "./types-events";
+export type * as IpcBindings from "./types-generated";
 export {
   getIpcTelemetrySummary,
   printIpcTelemetrySummary,
@@ -25,6 +25,8 @@ const UNTRACKED_COMMANDS = new Set<string>([
   "sound_library_read",
   "workspace_events",
   "workspace_get_active",
+  "workspace_list_open",
+  "workspace_summary",
   "workspace_term_sanity_check",
   "workspace_term_sanity_apply",
   "workspace_gitignore_sanity_check",
@@ -37,6 +39,11 @@ const UNTRACKED_COMMANDS = new Set<string>([
   "git_ahead_behind",
   "git_list_file_states",
   "git_diff",
+  "git_diff_file",
+  "git_diff_hunks",
+  "git_conflicts_list",
+  "git_rebase_status",
+  "git_log",
   "gh_detect_repo",
   "gh_auth_status",
   "gh_auth_login",
@@ -47,11 +54,21 @@ const UNTRACKED_COMMANDS = new Set<string>([
   "gh_repo_default_branch",
   "gh_pr_list",
   "gh_pr_view",
+  "gh_pr_checks",
+  "gh_pr_comments",
+  "gh_pr_prepare",
+  "gh_issue_list",
+  "gh_issue_view",
   "git_has_upstream",
   "gh_check_branch_pr",
   "global_settings_get",
   "global_settings_update",
   "diagnostics_get_system_overview",
+  "diagnostics_terminal_memory",
+  "terminal_storage_stats",
+  "diagnostics_worktree_resources",
+  "diagnostics_fd_usage",
+  "app_health",
   "workspace_list_symlink_entries",
   "groove_terminal_open",
   "groove_terminal_write",
@@ -1,11 +1,74 @@
 import type {
   CommentRecord,
   PullRequestRecord,
+  StatusPageSettings,
+  SubprocessTranscript,
+  TerminalMemorySettings,
   WorkspaceMeta,
   WorktreeUnit,
 } from "./types-core";
+import type {
+  AppBackupRestorePayload,
+  AssistantRule,
+  CommandRunsListPayload,
+  DiagnosticsFdCounts,
+  DiagnosticsFdLimits,
+  DiagnosticsNodeAppRow,
+  DiagnosticsSystemOverview,
+  DiagnosticsTerminalSessionMemory,
+  DiagnosticsWorktreeResourceRow,
+  FrozenWorkspaceArchive,
+  GrooveArchiveMetadata,
+  GrooveBulkResult,
+  GrooveSummaryEntry,
+  GrooveTmuxWindow,
+  GrooveZellijSession,
+  HistoryListPayload,
+  InFlightCommandInfo,
+  MergeTrainResumePayload,
+  RunningGrooveRecord,
+  TestingEnvironmentCrash,
+  TestingEnvironmentLogsTailPayload,
+  WorkspaceConflictMatrixPayload,
+  WorkspaceFreezePayload,
+  WorkspaceThawPayload,
+  WorktreeConflictFile,
+  WorktreeResourceUsage,
+  WorktreeRestackResult,
+} from "./types-generated";
+
+export type {
+  AppBackupRestorePayload,
+  AssistantRule,
+  CommandRunsListPayload,
+  DiagnosticsFdCounts,
+  DiagnosticsFdLimits,
+  DiagnosticsNodeAppRow,
+  DiagnosticsSystemOverview,
+  DiagnosticsTerminalSessionMemory,
+  DiagnosticsWorktreeResourceRow,
+  FrozenWorkspaceArchive,
+  GrooveArchiveMetadata,
+  GrooveBulkResult,
+  GrooveSummaryEntry,
+  GrooveTmuxWindow,
+  GrooveZellijSession,
+  HistoryListPayload,
+  InFlightCommandInfo,
+  MergeTrainResumePayload,
+  RunningGrooveRecord,
+  TestingEnvironmentCrash,
+  TestingEnvironmentLogsTailPayload,
+  WorkspaceConflictMatrixPayload,
+  WorkspaceFreezePayload,
+  WorkspaceThawPayload,
+  WorktreeConflictFile,
+  WorktreeResourceUsage,
+  WorktreeRestackResult,
+};
 
 export type GrooveRestorePayload = {
+  workspaceRoot?: string | null;
   rootName: string;
   knownWorktrees: string[];
   workspaceMeta?: WorkspaceMeta;
@@ -14,15 +77,33 @@ export type GrooveRestorePayload = {
   target?: string;
   dir?: string;
   opencodeLogFile?: string;
+  /** Emit the sidecar's output as `groove-command-output` events. */
+  stream?: boolean;
+  debug?: boolean;
 };
 
 export type GrooveNewPayload = {
   rootName: string;
   knownWorktrees: string[];
   workspaceMeta?: WorkspaceMeta;
+  /** May be empty when `issue` is set: the branch is named after it. */
   branch: string;
   base?: string;
   dir?: string;
+  dirNameStrategy?: WorktreeDirNameStrategy;
+  issue?: number;
+  /** Worktree to stack the new one on; its branch is the default `base`. */
+  parent?: string;
+  /** Emit the sidecar's output as `groove-command-output` events. */
+  stream?: boolean;
+  debug?: boolean;
+};
+
+export type WorktreeDirNameStrategy = "branch" | "hashed" | "auto";
+
+export type WorktreePathIssue = {
+  kind: "invalid-characters" | "case-collision" | "path-too-long";
+  message: string;
 };
 
 export type GrooveNewResponse = {
@@ -31,7 +112,11 @@ export type GrooveNewResponse = {
   exitCode: number | null;
   stdout: string;
   stderr: string;
+  worktree?: string;
+  preflightIssues?: WorktreePathIssue[];
   error?: string;
+  /** Set when the command was called with `debug: true`. */
+  debugTranscripts?: SubprocessTranscript[];
 };
 
 export type GrooveRestoreResponse = {
@@ -41,6 +126,8 @@ export type GrooveRestoreResponse = {
   stdout: string;
   stderr: string;
   error?: string;
+  /** Set when the command was called with `debug: true`. */
+  debugTranscripts?: SubprocessTranscript[];
 };
 
 export type GrooveRmPayload = {
@@ -50,7 +137,35 @@ export type GrooveRmPayload = {
   target: string;
   worktree: string;
   dir?: string;
+  /** Runs `git worktree remove --force` instead of the sidecar's `rm`. */
   force?: boolean;
+  /**
+   * Safety categories the caller accepts losing; removal is refused while
+   * the worktree has findings in any other.
+   */
+  acknowledge?: GrooveRmSafetyCategory[];
+  /** Emit the sidecar's output as `groove-command-output` events. */
+  stream?: boolean;
+  debug?: boolean;
+};
+
+export type GrooveRmSafetyCategory =
+  | "uncommittedChanges"
+  | "unpushedCommits"
+  | "runningProcesses"
+  | "terminalSessions";
+
+/** What removing a worktree would lose or interrupt. */
+export type GrooveRmSafetyScan = {
+  /** Null when git status failed, which blocks like uncommitted changes. */
+  uncommittedChanges: number | null;
+  /** Null when the repository has no remotes. */
+  unpushedCommits: number | null;
+  /** Processes working in the worktree, terminal shells excluded. */
+  runningPids: number[];
+  terminalSessions: number;
+  /** Categories with findings; each must be acknowledged to remove. */
+  blocking: GrooveRmSafetyCategory[];
 };
 
 export type GrooveRmResponse = {
@@ -59,6 +174,17 @@ export type GrooveRmResponse = {
   exitCode: number | null;
   stdout: string;
   stderr: string;
+  /** Set when removal was refused over unacknowledged categories. */
+  safety?: GrooveRmSafetyScan;
+  error?: string;
+  /** Set when the command was called with `debug: true`. */
+  debugTranscripts?: SubprocessTranscript[];
+};
+
+export type GrooveRmSafetyResponse = {
+  requestId?: string;
+  ok: boolean;
+  safety?: GrooveRmSafetyScan;
   error?: string;
 };
 
@@ -80,16 +206,191 @@ export type GrooveStopResponse = {
   error?: string;
 };
 
-export type RunningGrooveRecord = {
-  workspaceRoot: string;
+/** Payload of `grooveBulkRm`, `grooveBulkStop` and `grooveBulkRestore`. */
+export type GrooveBulkPayload = {
+  rootName: string;
+  knownWorktrees: string[];
+  workspaceMeta?: WorkspaceMeta;
+  worktrees: string[];
+  dir?: string;
+  /** Only read by `grooveBulkRm`. */
+  force?: boolean;
+  /** Only read by `grooveBulkRm`; applies to every worktree. */
+  acknowledge?: GrooveRmSafetyCategory[];
+  /** Only read by `grooveBulkRestore`. */
+  action?: string;
+};
+
+/** `ok` is false when any worktree failed. */
+export type GrooveBulkResponse = {
+  requestId?: string;
+  ok: boolean;
+  results: GrooveBulkResult[];
+  succeeded: number;
+  failed: number;
+  error?: string;
+};
+
+export type GrooveArchivePayload = {
+  rootName: string;
+  knownWorktrees: string[];
+  workspaceMeta?: WorkspaceMeta;
   worktree: string;
-  worktreePath: string;
-  command: string;
-  target?: string;
-  sessionId: string;
-  pid?: number;
+  dir?: string;
+  /**
+   * Branch the diff is taken against; defaults to origin/HEAD, main or
+   * master, whichever exists.
+   */
+  base?: string;
+  /** Remove the worktree once archived. */
+  remove?: boolean;
+};
+
+export type GrooveArchiveListPayload = {
+  rootName: string;
+  knownWorktrees: string[];
+  workspaceMeta?: WorkspaceMeta;
+};
+
+export type GrooveUnarchivePayload = {
+  rootName: string;
+  knownWorktrees: string[];
+  workspaceMeta?: WorkspaceMeta;
+  id: string;
+  /** Branch of the new worktree; defaults to the archived branch. */
+  branch?: string;
+  dir?: string;
+  keepArchive?: boolean;
+};
+
+export type GrooveArchiveResponse = {
+  requestId?: string;
+  ok: boolean;
+  archive?: GrooveArchiveMetadata;
+  /** The worktree archived, or the one created by an unarchive. */
+  worktree?: string;
+  error?: string;
+};
+
+export type GrooveArchiveListResponse = {
+  requestId?: string;
+  ok: boolean;
+  /** Newest first. */
+  archives: GrooveArchiveMetadata[];
+  error?: string;
+};
+
+export type WorktreeRestackPayload = {
+  rootName: string;
+  knownWorktrees: string[];
+  workspaceMeta?: WorkspaceMeta;
+  /** The parent whose children are rebased. */
+  worktree: string;
+  dir?: string;
+  /** Also restack the children's own children, parents first. */
+  recursive?: boolean;
+  autostash?: boolean;
+};
+
+export type WorktreeRestackResponse = {
+  requestId?: string;
+  ok: boolean;
+  worktree?: string;
+  /** In rebase order. Children of a worktree that failed are skipped. */
+  results: WorktreeRestackResult[];
+  error?: string;
+};
+
+export type MergeTrainStartPayload = {
+  /** Worktrees to merge, in merge order. */
+  worktrees: string[];
+  /** Defaults to the repository's default branch. */
+  base?: string;
+  strategy?: "merge" | "squash" | "rebase";
+  /** Leave the worktrees and their remote branches once merged. */
+  keepWorktrees?: boolean;
+  dir?: string;
+};
+
+export type MergeTrainStep = "rebase" | "checks" | "merge" | "cleanup" | "done";
+
+export type MergeTrainEntry = {
+  worktree: string;
+  prNumber: number;
+  /** Next step to run. */
+  step: MergeTrainStep;
+};
+
+export type MergeTrainPause = {
+  worktree: string;
+  step: MergeTrainStep;
+  reason:
+    | "conflicts"
+    | "checksFailed"
+    | "checksPending"
+    | "failed"
+    | "cancelled";
+  message: string;
+  conflictedFiles?: string[];
+  failedChecks?: string[];
+};
+
+export type MergeTrainState = {
+  base: string;
+  strategy?: string;
+  keepWorktrees: boolean;
+  worktreeDir: string;
+  status: "running" | "paused" | "completed" | "aborted";
+  entries: MergeTrainEntry[];
+  paused?: MergeTrainPause;
   startedAt: string;
-  stillRunning?: boolean;
+  updatedAt: string;
+};
+
+export type MergeTrainResponse = {
+  requestId?: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  /** Missing from a status response when no train was ever started. */
+  train?: MergeTrainState;
+  error?: string;
+};
+
+export type WorktreeAction =
+  | "play"
+  | "openTerminal"
+  | "openEditor"
+  | "push"
+  | "pull"
+  | "stop"
+  | "remove"
+  | "setTestingTarget";
+
+export type WorktreeActionPayload = {
+  rootName: string;
+  knownWorktrees: string[];
+  workspaceMeta?: WorkspaceMeta;
+  worktree: string;
+  action: WorktreeAction;
+  /**
+   * Fields passed on to the command the action runs: `target` and
+   * `opencodeLogFile` (play), `command` (openEditor), `setUpstream`,
+   * `forceWithLease` and `branch` (push), `rebase` (pull), `instanceId`
+   * (stop), `force` and `acknowledge` (remove), `playGrooveCommand`
+   * (setTestingTarget).
+   */
+  options?: Record<string, unknown>;
+  dir?: string;
+};
+
+export type WorktreeActionResponse = {
+  requestId?: string;
+  ok: boolean;
+  action?: WorktreeAction;
+  worktree?: string;
+  /** The response of the command the action ran. */
+  result?: unknown;
+  error?: string;
 };
 
 export type GrooveRecoverableListPayload = {
@@ -118,6 +419,63 @@ export type GrooveRecoverableClearResponse = {
   error?: string;
 };
 
+export type FrozenWorkspaceState = {
+  frozenAt: string;
+  runningGrooves: RunningGrooveRecord[];
+  sessionLayouts: Array<{
+    worktree: string;
+    entries: Array<{
+      sessionId: string;
+      openMode: string;
+      target?: string;
+      runLocal: boolean;
+    }>;
+    updatedAt: string;
+  }>;
+  closedSessions: number;
+  stoppedPids: number[];
+  archives: FrozenWorkspaceArchive[];
+};
+
+/** Response of `workspaceFreeze` and `workspaceThaw`. */
+export type WorkspaceFreezeResponse = {
+  requestId?: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  frozen?: FrozenWorkspaceState;
+  /** Sessions and Play targets reopened by a thaw, as `<worktree>: <mode>`. */
+  restarted: string[];
+  /** Steps that failed without stopping the freeze or thaw. */
+  failures: string[];
+  error?: string;
+};
+
+export type WorktreeConflictRisk =
+  | "conflicting"
+  | "sameFiles"
+  | "clear"
+  | "unknown";
+
+export type WorktreeConflictPair = {
+  left: string;
+  right: string;
+  risk: WorktreeConflictRisk;
+  mergeBase?: string;
+  files: WorktreeConflictFile[];
+  /** Why the pair could not be compared; `risk` is then "unknown". */
+  error?: string;
+};
+
+export type WorkspaceConflictMatrixResponse = {
+  requestId?: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  worktrees: string[];
+  /** One entry per pair of `worktrees`, in order. */
+  pairs: WorktreeConflictPair[];
+  error?: string;
+};
+
 export type GrooveSummaryPayload = {
   rootName: string;
   knownWorktrees: string[];
@@ -125,14 +483,6 @@ export type GrooveSummaryPayload = {
   sessionIds: string[];
 };
 
-export type GrooveSummaryEntry = {
-  sessionId: string;
-  worktree?: string;
-  ok: boolean;
-  summary?: string;
-  error?: string;
-};
-
 export type GrooveSummaryResponse = {
   requestId?: string;
   ok: boolean;
@@ -229,6 +579,29 @@ export type WorkspaceOpenWorkspaceTerminalPayload = {
   workspaceMeta?: WorkspaceMeta;
 };
 
+export type GrooveTmuxKillWindowPayload = {
+  rootName: string;
+  knownWorktrees: string[];
+  workspaceMeta?: WorkspaceMeta;
+  worktree: string;
+};
+
+export type GrooveZellijSessionsResponse = {
+  requestId?: string;
+  ok: boolean;
+  sessions: GrooveZellijSession[];
+  error?: string;
+};
+
+export type GrooveTmuxWindowsResponse = {
+  requestId?: string;
+  ok: boolean;
+  session?: string;
+  attached: boolean;
+  windows: GrooveTmuxWindow[];
+  error?: string;
+};
+
 export type DiagnosticsStopResponse = {
   requestId?: string;
   ok: boolean;
@@ -248,10 +621,37 @@ export type DiagnosticsStopAllResponse = {
   error?: string;
 };
 
-export type DiagnosticsNodeAppRow = {
+export type DiagnosticsStopCategory = "opencode" | "nodeDevServers" | "workspace";
+
+export type DiagnosticsStopByCategoryPayload = {
+  category: DiagnosticsStopCategory;
+  preview?: boolean;
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMeta;
+};
+
+export type DiagnosticsStopTarget = {
   pid: number;
-  ppid: number;
-  cmd: string;
+  ppid?: number;
+  command: string;
+  outcome?: "stopped" | "alreadyStopped" | "failed";
+};
+
+export type DiagnosticsStopByCategoryResponse = {
+  requestId?: string;
+  ok: boolean;
+  category: string;
+  preview: boolean;
+  workspaceRoot?: string;
+  targets: DiagnosticsStopTarget[];
+  remaining: DiagnosticsStopTarget[];
+  attempted: number;
+  stopped: number;
+  alreadyStopped: number;
+  failed: number;
+  errors: string[];
+  error?: string;
 };
 
 export type DiagnosticsNodeAppsResponse = {
@@ -269,23 +669,6 @@ export type DiagnosticsMostConsumingProgramsResponse = {
   error?: string;
 };
 
-export type DiagnosticsSystemOverview = {
-  cpuUsagePercent?: number;
-  cpuCores?: number;
-  ramTotalBytes?: number;
-  ramUsedBytes?: number;
-  ramUsagePercent?: number;
-  swapTotalBytes?: number;
-  swapUsedBytes?: number;
-  swapUsagePercent?: number;
-  diskTotalBytes?: number;
-  diskUsedBytes?: number;
-  diskUsagePercent?: number;
-  platform: string;
-  hostname?: string;
-  warnings?: string[];
-};
-
 export type DiagnosticsSystemOverviewResponse = {
   requestId?: string;
   ok: boolean;
@@ -293,6 +676,92 @@ export type DiagnosticsSystemOverviewResponse = {
   error?: string;
 };
 
+export type DiagnosticsWorktreeResourcesResponse = {
+  requestId?: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  /** Heaviest first, by CPU and then memory. */
+  rows: DiagnosticsWorktreeResourceRow[];
+  warning?: string;
+  error?: string;
+};
+
+export type DiagnosticsFdHolderRow = {
+  /**
+   * "groove" for the app's own descriptors, named by subsystem, "worktree"
+   * for processes running in a worktree and "other" for the rest.
+   */
+  kind: "groove" | "worktree" | "other";
+  name: string;
+  counts: DiagnosticsFdCounts;
+  pids: number[];
+};
+
+export type DiagnosticsFdUsage = {
+  /** Descriptors held by the app process itself. */
+  groove: DiagnosticsFdCounts;
+  /**
+   * Every process the app can inspect, which is those of the same user;
+   * inotify limits apply to this total.
+   */
+  user: DiagnosticsFdCounts;
+  limits: DiagnosticsFdLimits;
+  rows: DiagnosticsFdHolderRow[];
+  hints: string[];
+  warnings: string[];
+};
+
+export type DiagnosticsFdUsageResponse = {
+  requestId?: string;
+  ok: boolean;
+  usage?: DiagnosticsFdUsage;
+  error?: string;
+};
+
+export type DiagnosticsTerminalMemoryResponse = {
+  requestId?: string;
+  ok: boolean;
+  /** Largest snapshot first. */
+  sessions: DiagnosticsTerminalSessionMemory[];
+  totalSnapshotBytes: number;
+  limits: TerminalMemorySettings;
+  error?: string;
+};
+
+export type TerminalStorageSessionUsage = {
+  sessionId: string;
+  kind: "history" | "scrollback" | "recording";
+  bytes: number;
+  modifiedAt?: string;
+  /** An open session still writes to it, so it is never cleaned up. */
+  live: boolean;
+  /** Set for scrollback files of open sessions. */
+  worktree?: string;
+};
+
+export type TerminalStorageWorktreeUsage = {
+  worktree: string;
+  bytes: number;
+  /** Largest file first. */
+  sessions: TerminalStorageSessionUsage[];
+};
+
+export type TerminalStorageStatsResponse = {
+  requestId?: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  quotaBytes: number;
+  maxAgeDays: number;
+  /** Terminal history of the active workspace, counted against the quota. */
+  historyBytes: number;
+  /** Largest worktree first. */
+  worktrees: TerminalStorageWorktreeUsage[];
+  /** Scrollback files of all workspaces, with a quota of their own. */
+  scrollbackBytes: number;
+  scrollback: TerminalStorageSessionUsage[];
+  error?: string;
+};
+
 export type AssistantConnectResponse = {
   requestId?: string;
   ok: boolean;
@@ -316,12 +785,6 @@ export type AssistantValidateResponse = {
 
 export type AssistantRuleScope = "project" | "global";
 
-export type AssistantRule = {
-  id: string;
-  text: string;
-  createdAt: string;
-};
-
 export type AssistantRulesListResponse = {
   requestId?: string;
   ok: boolean;
@@ -330,3 +793,221 @@ export type AssistantRulesListResponse = {
   projectWorkspace?: string;
   error?: string;
 };
+
+export type ActionHistoryCommand =
+  | "git_pull"
+  | "git_push"
+  | "git_commit"
+  | "git_merge"
+  | "groove_restore"
+  | "groove_stop";
+
+export type ActionHistoryEntry = {
+  id: string;
+  command: ActionHistoryCommand;
+  /** Directory name of the worktree the action targeted. */
+  worktree: string;
+  ok: boolean;
+  error?: string;
+  recordedAt: string;
+  /** Payload the command ran with; `historyRepeat` replays it. */
+  payload: unknown;
+};
+
+export type HistoryListResponse = {
+  requestId?: string;
+  ok: boolean;
+  entries: ActionHistoryEntry[];
+  error?: string;
+};
+
+export type HistoryRepeatResponse = {
+  requestId?: string;
+  ok: boolean;
+  command?: ActionHistoryCommand;
+  worktree?: string;
+  /** The replayed command's own response. */
+  result?: unknown;
+  error?: string;
+};
+
+/** One captured run of a custom command with `runMode: "capture"`. */
+export type CommandRun = {
+  id: string;
+  commandId: string;
+  label: string;
+  workspaceRoot: string;
+  worktree: string;
+  /** The expanded command line. */
+  command: string;
+  status: "running" | "succeeded" | "failed";
+  exitCode?: number;
+  /** Tail of the output; see `outputTruncated`. */
+  stdout: string;
+  stderr: string;
+  outputTruncated: boolean;
+  error?: string;
+  startedAt: string;
+  finishedAt?: string;
+  durationMs?: number;
+};
+
+export type CommandRunsListResponse = {
+  requestId?: string;
+  ok: boolean;
+  /** Newest first, for the active workspace; kept in memory only. */
+  runs: CommandRun[];
+  error?: string;
+};
+
+export type CancelRequestResponse = {
+  requestId?: string;
+  ok: boolean;
+  /**
+   * The command that was asked to stop. It still answers its own request,
+   * with a "was cancelled" error and `cancelled: true` on the responses that
+   * carry the flag once it noticed.
+   */
+  command?: string;
+  error?: string;
+};
+
+export type CommandsInFlightResponse = {
+  requestId?: string;
+  ok: boolean;
+  /** Oldest first. */
+  commands: InFlightCommandInfo[];
+  error?: string;
+};
+
+export type MaintenanceTask =
+  | "fetchPrune"
+  | "staleBranchReport"
+  | "cleanup"
+  | "conflictMatrix";
+
+export type MaintenanceTaskSchedule = {
+  task: MaintenanceTask;
+  enabled?: boolean;
+  /** Local time of day as "HH:MM"; an empty string unschedules the task. */
+  at?: string;
+};
+
+export type MaintenanceConfigurePayload = {
+  /** Minutes local time is ahead of UTC: `-new Date().getTimezoneOffset()`. */
+  utcOffsetMinutes?: number;
+  tasks?: MaintenanceTaskSchedule[];
+};
+
+export type MaintenanceRun = {
+  startedAt: string;
+  durationMs: number;
+  trigger: "schedule" | "manual";
+  ok: boolean;
+  workspaceRoot?: string;
+  summary?: string;
+  error?: string;
+};
+
+export type MaintenanceTaskInfo = {
+  task: MaintenanceTask;
+  enabled: boolean;
+  at?: string;
+  /** Next scheduled start, in UTC. */
+  nextRunAt?: string;
+  running: boolean;
+  lastRun?: MaintenanceRun;
+};
+
+export type MaintenanceResponse = {
+  requestId?: string;
+  ok: boolean;
+  utcOffsetMinutes: number;
+  tasks: MaintenanceTaskInfo[];
+  error?: string;
+};
+
+/**
+ * A testing environment the supervisor has seen crash since the app
+ * started.
+ */
+export type TestingEnvironmentInfo = {
+  workspaceRoot: string;
+  worktree: string;
+  status: "running" | "restarting" | "crashed" | "failed";
+  /** Restarts since the environment last stayed up. */
+  restarts: number;
+  /** Session of the last restart while it is running. */
+  sessionId?: string;
+  nextRestartAt?: string;
+  /** Most recent last. */
+  crashes: TestingEnvironmentCrash[];
+};
+
+export type TestingEnvironmentStatusResponse = {
+  requestId?: string;
+  ok: boolean;
+  environments: TestingEnvironmentInfo[];
+  error?: string;
+};
+
+export type TestingEnvironmentLogsResponse = {
+  requestId?: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  worktree?: string;
+  /** Oldest first; empty after a clear. */
+  lines: string[];
+  /** Whether new lines are sent as events. */
+  following: boolean;
+  error?: string;
+};
+
+export type StatusPageStatusResponse = {
+  requestId?: string;
+  ok: boolean;
+  settings: StatusPageSettings;
+  listening: boolean;
+  /** Where this machine reaches the page, while it listens. */
+  url?: string;
+  /** Why the page is not listening although enabled, e.g. a port in use. */
+  error?: string;
+};
+
+export type AppBackupResponse = {
+  requestId?: string;
+  ok: boolean;
+  path?: string;
+  /** App data files written to or restored from the backup. */
+  files: string[];
+  /** Workspace roots whose `workspace.json` was written or restored. */
+  workspaces: string[];
+  /**
+   * Bundled workspace roots that do not exist on this machine, or that
+   * were neither open nor already a Groove workspace.
+   */
+  skippedWorkspaces: string[];
+  error?: string;
+};
+
+export type ApiTokenCapability =
+  | "read"
+  | "write"
+  | "destructive"
+  | "terminal-io";
+
+export type ApiTokenSummary = {
+  id: string;
+  name: string;
+  capabilities: ApiTokenCapability[];
+  createdAt: string;
+};
+
+export type ApiTokensResponse = {
+  requestId?: string;
+  ok: boolean;
+  tokens: ApiTokenSummary[];
+  /** Secret of the token just created; it is not stored and never shown again. */
+  secret?: string;
+  error?: string;
+};
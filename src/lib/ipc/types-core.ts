@@ -1,5 +1,78 @@
 import type { ItemRarity } from "@/src/lib/items/definitions";
 import type { ThemeMode } from "@/src/lib/theme-constants";
+import type {
+  ClaimWorktreeRewardPayload,
+  CommentRecord,
+  CommentState,
+  IssueRecord,
+  LootWorktreePayload,
+  OpenWorkspaceSummary,
+  OpencodeSettings,
+  PlayCommandPreviewPayload,
+  PollingSettings,
+  PullRequestRecord,
+  RemoteWorktreeRow,
+  RowsPageInfo,
+  RowsPagePayload,
+  SettingsProfileImportPayload,
+  SettingsProfileNamePayload,
+  SoundLibraryEntry,
+  StatusPageSettings,
+  SubprocessTranscript,
+  SummaryRecord,
+  SystemAlertSettings,
+  TerminalMemorySettings,
+  TestingEnvironmentRestartPolicy,
+  WorkspaceAdoptWorktreePayload,
+  WorkspaceBrowseEntry,
+  WorkspaceMigratePayload,
+  WorkspaceRemoteGitStatusPayload,
+  WorkspaceRequireSignOffPayload,
+  WorkspaceSearchWorktreesPayload,
+  WorkspaceSummaryCategory,
+  WorkspaceSummaryItem,
+  WorktreeStalenessReportPayload,
+  WorktreeStorageRow,
+  WorktreeStorageStatsPayload,
+  WorktreeUnitKind,
+} from "./types-generated";
+
+export type {
+  ClaimWorktreeRewardPayload,
+  CommentRecord,
+  CommentState,
+  IssueRecord,
+  LootWorktreePayload,
+  OpenWorkspaceSummary,
+  OpencodeSettings,
+  PlayCommandPreviewPayload,
+  PollingSettings,
+  PullRequestRecord,
+  RemoteWorktreeRow,
+  RowsPageInfo,
+  RowsPagePayload,
+  SettingsProfileImportPayload,
+  SettingsProfileNamePayload,
+  SoundLibraryEntry,
+  StatusPageSettings,
+  SubprocessTranscript,
+  SummaryRecord,
+  SystemAlertSettings,
+  TerminalMemorySettings,
+  TestingEnvironmentRestartPolicy,
+  WorkspaceAdoptWorktreePayload,
+  WorkspaceBrowseEntry,
+  WorkspaceMigratePayload,
+  WorkspaceRemoteGitStatusPayload,
+  WorkspaceRequireSignOffPayload,
+  WorkspaceSearchWorktreesPayload,
+  WorkspaceSummaryCategory,
+  WorkspaceSummaryItem,
+  WorktreeStalenessReportPayload,
+  WorktreeStorageRow,
+  WorktreeStorageStatsPayload,
+  WorktreeUnitKind,
+};
 
 export type DefaultTerminal =
   | "auto"
@@ -9,6 +82,7 @@ export type DefaultTerminal =
   | "alacritty"
   | "gnome"
   | "xterm"
+  | "zellij"
   | "none"
   | "custom";
 
@@ -20,14 +94,19 @@ export const GROOVE_PLAY_COMMAND_SENTINEL = "__groove_terminal__";
 export const GROOVE_PLAY_CLAUDE_CODE_COMMAND_SENTINEL =
   "__groove_terminal_claude__";
 export const GROOVE_OPEN_TERMINAL_COMMAND_SENTINEL = "__groove_terminal_open__";
+export const GROOVE_TMUX_COMMAND_SENTINEL = "__groove_tmux__";
+export const PLAY_GROOVE_COMMAND_PRESET_PREFIX = "preset:";
+export const PLAY_GROOVE_COMMAND_PRESET_NAMES = [
+  "groove-opencode",
+  "groove-claude-code",
+  "ghostty-opencode",
+  "kitty-opencode",
+  "tmux-window",
+] as const;
+export type PlayGrooveCommandPresetName =
+  (typeof PLAY_GROOVE_COMMAND_PRESET_NAMES)[number];
 export const DEFAULT_OPENCODE_SETTINGS_DIRECTORY = "~/.config/opencode";
 
-export type OpencodeSettings = {
-  enabled: boolean;
-  defaultModel?: string | null;
-  settingsDirectory: string;
-};
-
 export type OpencodeUpdateWorkspaceSettingsPayload = {
   enabled: boolean;
   defaultModel?: string | null;
@@ -40,31 +119,6 @@ export type OpencodeUpdateGlobalSettingsPayload = {
   settingsDirectory?: string | null;
 };
 
-export type SummaryRecord = {
-  worktreeIds: string[];
-  createdAt: string;
-  summary: string;
-  oneLiner?: string;
-};
-
-export type CommentState = "uncommitted" | "committed";
-
-export type CommentRecord = {
-  worktreeId: string;
-  createdAt: string;
-  message: string;
-  state: CommentState;
-};
-
-export type PullRequestRecord = {
-  number: number;
-  url: string;
-  title?: string;
-  base?: string;
-  head?: string;
-  addedAt: string;
-};
-
 export const WORKTREE_STATES = [
   "pending",
   "hunting",
@@ -79,8 +133,6 @@ export type WorktreeState = (typeof WORKTREE_STATES)[number];
 
 export const DEFAULT_WORKTREE_STATE: WorktreeState = "pending";
 
-export type WorktreeUnitKind = "bug" | "goldmine" | "gems";
-
 export type WorktreeLootEntry = {
   itemId: string;
   rarity: ItemRarity;
@@ -113,6 +165,13 @@ export type WorktreeRecord = {
   summaries?: SummaryRecord[];
   comments?: CommentRecord[];
   pullRequests?: PullRequestRecord[];
+  issue?: IssueRecord;
+  branch?: string;
+  playGrooveCommand?: string;
+  note?: string;
+  tags?: string[];
+  /** Record id of the worktree this one is stacked on. */
+  parentId?: string;
 };
 
 export type WorkspaceMeta = {
@@ -155,6 +214,45 @@ export type WorkspaceMeta = {
    * neither running nor dirty is auto-removed. Absent/0 means unlimited.
    */
   maxWorktreeCount?: number | null;
+  /** Rules `worktreeStalenessReport` classifies worktrees by. */
+  stalenessPolicy?: WorktreeStalenessPolicy | null;
+  /**
+   * Experimental: when set, worktrees, git and terminal sessions of this
+   * workspace live on this SSH host instead of the local disk.
+   */
+  remote?: WorkspaceRemote | null;
+  /** Git identity applied to every git command run for this workspace. */
+  gitIdentity?: WorkspaceGitIdentity | null;
+  /** Message and trailers used by `git_commit` in this workspace. */
+  commitTemplate?: WorkspaceCommitTemplate | null;
+  /** Conventional-commit rules for `gitLintCommitMessage`; null disables it. */
+  commitLint?: WorkspaceCommitLintRules | null;
+  /**
+   * Commits made through the app get `Signed-off-by` (DCO), and
+   * `gitPushPreflight` checks unpushed commits for it.
+   */
+  requireSignOff?: boolean;
+  /** Named commands the worktree view offers as buttons. */
+  customCommands?: WorkspaceCustomCommand[];
+  /** Worktree name → path of git worktrees adopted from outside `.worktrees/`. */
+  adoptedWorktrees?: Record<string, string>;
+};
+
+export type WorkspaceRemote = {
+  /** `host`, `user@host` or an ssh config alias. */
+  destination: string;
+  port?: number | null;
+  /** Absolute path of the repository root on the remote host. */
+  root: string;
+  /** Forwards the local ssh agent (`ssh -A`) so git on the host can use it. */
+  forwardAgent?: boolean;
+};
+
+export type WorkspaceGitIdentity = {
+  userName?: string | null;
+  userEmail?: string | null;
+  /** Overrides `core.sshCommand`, e.g. `ssh -i ~/.ssh/work_ed25519`. */
+  sshCommand?: string | null;
 };
 
 export type WorkspaceRow = {
@@ -176,6 +274,24 @@ export type WorkspaceContextResponse = {
   hasWorktreesDirectory?: boolean;
   rows: WorkspaceRow[];
   cancelled?: boolean;
+  /**
+   * Cold-start `workspaceGetActive` answer with the meta but no rows yet; the
+   * full context follows in a "workspace-context-complete" event.
+   */
+  partial?: boolean;
+  /**
+   * The workspace root or `.groove` cannot be written; settings changes and new
+   * worktrees are refused. `readOnlyReason` says why.
+   */
+  readOnly?: boolean;
+  readOnlyReason?: string;
+  /**
+   * Unset once the user trusts the workspace. Until then commands from its
+   * `.groove` settings and git hooks do not run; see `workspaceSetTrust`.
+   */
+  trust?: WorkspaceTrustStatus;
+  /** Set when only one page of `rows` was asked for. */
+  page?: RowsPageInfo;
   error?: string;
 };
 
@@ -189,6 +305,71 @@ export type WorkspaceTermSanityResponse = {
   error?: string;
 };
 
+export type WorktreeSearchMatch = {
+  field: "worktree" | "branch" | "tag" | "note";
+  text: string;
+  /** Character positions in `text` to highlight. */
+  indices: number[];
+};
+
+export type WorktreeSearchResult = {
+  worktree: string;
+  worktreeId?: string;
+  branch: string;
+  path: string;
+  status: WorkspaceRow["status"];
+  lastExecutedAt?: string;
+  score: number;
+  matches: WorktreeSearchMatch[];
+};
+
+export type WorkspaceSearchWorktreesResponse = {
+  requestId?: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  results: WorktreeSearchResult[];
+  error?: string;
+};
+
+export type WorkspaceListWorktreesPayload = {
+  offset?: number;
+  /** Defaults to 100, at most 500. */
+  limit?: number;
+};
+
+export type WorkspaceListWorktreesResponse = {
+  requestId?: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  rows: WorkspaceRow[];
+  /** Rows in the whole workspace, not just this page. */
+  total: number;
+  offset: number;
+  hasMore: boolean;
+  error?: string;
+};
+
+export type WorkspaceSummaryResponse = {
+  requestId?: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  totalWorktrees: number;
+  runningAgents: WorkspaceSummaryCategory;
+  dirtyWorktrees: WorkspaceSummaryCategory;
+  openPrs: WorkspaceSummaryCategory;
+  runningTestingEnvs: WorkspaceSummaryCategory;
+  error?: string;
+};
+
+export type WorkspaceListOpenResponse = {
+  requestId?: string;
+  ok: boolean;
+  activeWorkspaceRoot?: string;
+  /** Most recently active first. */
+  workspaces: OpenWorkspaceSummary[];
+  error?: string;
+};
+
 export type WorkspaceGitignoreSanityResponse = {
   requestId?: string;
   ok: boolean;
@@ -203,12 +384,6 @@ export type WorkspaceGitignoreSanityResponse = {
   error?: string;
 };
 
-export type SoundLibraryEntry = {
-  id: string;
-  name: string;
-  fileName: string;
-};
-
 export type ClaudeCodeHookSoundEntry = {
   enabled: boolean;
   soundId: string | null;
@@ -256,6 +431,55 @@ export type GlobalSettings = {
   soundLibrary: SoundLibraryEntry[];
   claudeCodeSoundSettings: ClaudeCodeSoundSettings;
   grooveSoundSettings: GrooveSoundSettings;
+  terminalMemorySettings: TerminalMemorySettings;
+  systemAlertSettings: SystemAlertSettings;
+  /** How paths are written in backend logs. */
+  telemetryPathRedaction: TelemetryPathRedaction;
+  customCommandPolicy: CustomCommandPolicy;
+  /**
+   * Terminal opened by workspaces whose own default is `auto`; unset keeps
+   * auto-detection.
+   */
+  defaultTerminal?: DefaultTerminal;
+  pollingSettings: PollingSettings;
+  testingEnvironmentRestartPolicy: TestingEnvironmentRestartPolicy;
+  statusPageSettings: StatusPageSettings;
+  commandRetryPolicy: CommandRetryPolicy;
+  /** When `global-settings.json` was last written. */
+  updatedAt?: string;
+};
+
+export type CommandRetryCategory = "network" | "timeout" | "lock";
+
+/**
+ * How git and gh runs that failed for a passing reason are tried again.
+ * Only fetches, pulls, pushes and read-only gh calls qualify.
+ */
+export type CommandRetryPolicy = {
+  /** Tries in total, 1 to 6; 1 turns retries off. */
+  maxAttempts: number;
+  /** Wait before the first retry, doubled for each one after it. */
+  backoffMs: number;
+  maxBackoffMs: number;
+  retryOn: CommandRetryCategory[];
+};
+
+export type TelemetryPathRedaction = "off" | "basename" | "hash";
+
+export type CustomCommandFirstRun = "allow" | "confirm" | "block";
+
+/** Limits on the play, terminal and custom commands workspaces configure. */
+export type CustomCommandPolicy = {
+  /** Commands containing any of these as whole words are refused. */
+  deniedPatterns: string[];
+  /** When not empty, the only programs commands may start. */
+  allowedPrograms: string[];
+  /** Refuse programs given as a relative path or not found on `PATH`. */
+  requireResolvedPrograms: boolean;
+  /** What the first run of a command in a trusted workspace needs. */
+  trustedFirstRun: CustomCommandFirstRun;
+  /** The same for workspaces that are not trusted. */
+  untrustedFirstRun: CustomCommandFirstRun;
 };
 
 export type GlobalSettingsUpdatePayload = {
@@ -273,6 +497,16 @@ export type GlobalSettingsUpdatePayload = {
   soundLibrary?: SoundLibraryEntry[];
   claudeCodeSoundSettings?: ClaudeCodeSoundSettings;
   grooveSoundSettings?: GrooveSoundSettings;
+  terminalMemorySettings?: TerminalMemorySettings;
+  systemAlertSettings?: SystemAlertSettings;
+  telemetryPathRedaction?: TelemetryPathRedaction;
+  customCommandPolicy?: CustomCommandPolicy;
+  /** `auto` clears the override. */
+  defaultTerminal?: DefaultTerminal;
+  pollingSettings?: PollingSettings;
+  testingEnvironmentRestartPolicy?: TestingEnvironmentRestartPolicy;
+  statusPageSettings?: StatusPageSettings;
+  commandRetryPolicy?: CommandRetryPolicy;
 };
 
 export type GlobalSettingsResponse = {
@@ -282,6 +516,111 @@ export type GlobalSettingsResponse = {
   error?: string;
 };
 
+/** Settings a profile sets when switched to; unset fields are left alone. */
+export type SettingsProfileOverrides = {
+  telemetryEnabled?: boolean;
+  themeMode?: ThemeMode;
+  /** `auto` clears the global default terminal. */
+  defaultTerminal?: DefaultTerminal;
+  pollingSettings?: PollingSettings;
+};
+
+export type SettingsProfile = {
+  name: string;
+  overrides: SettingsProfileOverrides;
+  updatedAt: string;
+};
+
+export type SettingsProfileSavePayload = {
+  name: string;
+  /** Omit to save the settings currently in use. */
+  overrides?: SettingsProfileOverrides;
+};
+
+export type SettingsProfilesResponse = {
+  requestId?: string;
+  ok: boolean;
+  activeProfile?: string;
+  profiles: SettingsProfile[];
+  /** Set by `settingsProfileSwitch`. */
+  globalSettings?: GlobalSettings;
+  /** Set by `settingsProfileExport`. */
+  exported?: string;
+  error?: string;
+};
+
+export type SettingsSyncKind = "off" | "file" | "gist";
+
+export type SettingsSyncTarget = {
+  kind: SettingsSyncKind;
+  path?: string;
+  /** Filled in by the first export when no gist was given. */
+  gistId?: string;
+  /** Also sync the active workspace's preferences. */
+  includeWorkspacePrefs: boolean;
+};
+
+export type SettingsSyncConfigurePayload = {
+  kind: SettingsSyncKind;
+  /** Absolute or starting with `~/`; required for `file`. */
+  path?: string;
+  /** Gist id or URL; a private gist is created on export when omitted. */
+  gistId?: string;
+  includeWorkspacePrefs?: boolean;
+};
+
+export type SettingsSyncRunPayload = {
+  /** Overwrite the other side even though it changed since the last sync. */
+  force?: boolean;
+  /** How a merge settles fields changed on both sides. */
+  prefer?: "local" | "remote";
+};
+
+export type SettingsSyncResponse = {
+  requestId?: string;
+  ok: boolean;
+  target: SettingsSyncTarget;
+  lastSyncedAt?: string;
+  /**
+   * Fields changed on both sides since the last sync, as `themeMode` or
+   * `workspace:<root name>.defaultTerminal`.
+   */
+  conflicts: string[];
+  /** Set when the global settings were written. */
+  globalSettings?: GlobalSettings;
+  error?: string;
+};
+
+export type UndoEntry = {
+  id: string;
+  command: string;
+  description: string;
+  scope: "workspace" | "worktree" | "globalSettings";
+  workspaceRoot?: string;
+  worktree?: string;
+  /** Settings fields that will be restored. */
+  fields: string[];
+  createdAt: string;
+};
+
+export type UndoListResponse = {
+  requestId?: string;
+  ok: boolean;
+  /** Newest first; `undoLast` reverts the first one. */
+  entries: UndoEntry[];
+  error?: string;
+};
+
+export type UndoLastResponse = {
+  requestId?: string;
+  ok: boolean;
+  undone?: UndoEntry;
+  remaining: number;
+  workspaceMeta?: WorkspaceMeta;
+  globalSettings?: GlobalSettings;
+  error?: string;
+};
+
 export type WorkspaceTerminalSettingsPayload = {
   defaultTerminal: DefaultTerminal;
   terminalCustomCommand?: string | null;
@@ -300,6 +639,23 @@ export type WorkspaceTerminalSettingsResponse = {
   error?: string;
 };
 
+export type WorkspaceTrustStatus = "undecided" | "denied";
+
+export type WorkspaceTrustResponse = {
+  requestId?: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  trusted: boolean;
+  error?: string;
+};
+
+export type CustomCommandApproveResponse = {
+  requestId?: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  error?: string;
+};
+
 export type WorkspaceCommandSettingsResponse =
   WorkspaceTerminalSettingsResponse;
 
@@ -308,6 +664,18 @@ export type WorkspaceCommandSettingsPayload = {
   openTerminalAtWorktreeCommand?: string | null;
 };
 
+export type PlayCommandPreviewResponse = {
+  requestId?: string;
+  ok: boolean;
+  mode?: "command" | "groove-terminal" | "tmux";
+  argv: string[];
+  worktreePath?: string;
+  branch?: string;
+  /** True when the template had no placeholder and the target was appended. */
+  targetAppended: boolean;
+  error?: string;
+};
+
 export type WorkspaceMaxWorktreeCountPayload = {
   /** Absent, null, or 0 clears the cap (unlimited). */
   maxWorktreeCount?: number | null;
@@ -323,20 +691,136 @@ export type WorkspaceMaxWorktreeCountResponse = {
   error?: string;
 };
 
-export type WorktreeStorageStatsPayload = {
+/** A worktree matching any enabled rule is stale. */
+export type WorktreeStalenessPolicy = {
+  /** Stale once the newest commit is older than this many days. */
+  noCommitsDays?: number | null;
+  /** Stale once the branch is merged into the default branch. */
+  branchMerged?: boolean;
+  /** Stale once the branch has pull requests and none is open. */
+  prClosed?: boolean;
+  /** Stale once no opencode log was written for this many days. */
+  noOpencodeActivityDays?: number | null;
   /**
-   * Compute on-disk sizes for each worktree. Expensive (walks every file), so
-   * the panel loads counts first and only opts in on demand.
+   * "archive" forgets stale worktrees when the report runs; "prompt" has the
+   * report suggest deleting them.
    */
-  includeSizes?: boolean;
+  action?: "report" | "archive" | "prompt";
 };
 
-export type WorktreeStorageRow = {
+export type WorkspaceStalenessPolicyPayload = {
+  /** Absent or null removes the policy. */
+  policy?: WorktreeStalenessPolicy | null;
+};
+
+export type WorktreeStalenessEntry = {
   worktree: string;
-  path: string;
-  /** Only meaningful when the response's `sizesIncluded` is true; otherwise 0. */
-  bytes: number;
-  lastExecutedAt?: string;
+  branch?: string;
+  stale: boolean;
+  reasons: Array<
+    "noCommits" | "branchMerged" | "prClosed" | "noOpencodeActivity"
+  >;
+  lastCommitAt?: string;
+  lastOpencodeActivityAt?: string;
+  /** Set when the policy acted on (or asks to delete) the worktree. */
+  action?: "archived" | "delete";
+  /** Why a stale worktree was left alone. */
+  skipped?: "running" | "dirty";
+};
+
+export type WorktreeStalenessReportResponse = {
+  requestId?: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  /** Absent when the workspace has no policy; nothing is then stale. */
+  policy?: WorktreeStalenessPolicy;
+  worktrees: WorktreeStalenessEntry[];
+  error?: string;
+};
+
+export type WorkspaceRemotePayload = {
+  /** Absent or null turns the workspace back into a local one. */
+  remote?: WorkspaceRemote | null;
+};
+
+/**
+ * `{branch}`, `{ticket}` (e.g. `PROJ-123` from the branch name) and
+ * `{worktree}` are expanded in the message and the trailers.
+ */
+export type WorkspaceCommitTemplate = {
+  /** Used when a commit is made without an explicit message. */
+  message?: string | null;
+  /** `Key: value` lines appended to every commit message. */
+  trailers?: string[];
+};
+
+export type WorkspaceCommitLintRules = {
+  /** Allowed types; empty means the conventional-commit defaults. */
+  types?: string[];
+  /** Allowed scopes; empty means any. */
+  scopes?: string[];
+  requireScope?: boolean;
+  /** Longest allowed first line, in characters; 72 when unset. */
+  maxSubjectLength?: number | null;
+};
+
+/** `{worktree}` (the worktree path) and `{branch}` are expanded in the template. */
+export type WorkspaceCustomCommand = {
+  /** Empty for a new command; the backend assigns one when it is saved. */
+  id: string;
+  label: string;
+  template: string;
+  /**
+   * `terminal` types the command into a new in-app terminal tab; `detached`
+   * runs it in the background; `capture` also runs it in the background and
+   * keeps its output for `commandRunsList`. Defaults to `terminal`.
+   */
+  runMode?: "terminal" | "detached" | "capture";
+};
+
+export type WorkspaceCustomCommandsPayload = {
+  /** Replaces the whole list. */
+  customCommands: WorkspaceCustomCommand[];
+};
+
+export type WorkspaceCustomCommandsListResponse = {
+  requestId?: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  customCommands: WorkspaceCustomCommand[];
+  error?: string;
+};
+
+export type WorkspaceCommitLintPayload = {
+  /** Absent or null turns commit message linting off. */
+  commitLint?: WorkspaceCommitLintRules | null;
+};
+
+export type WorkspaceCommitTemplatePayload = {
+  /** Absent or null removes the template. */
+  commitTemplate?: WorkspaceCommitTemplate | null;
+};
+
+export type WorkspaceGitIdentityPayload = {
+  /** Absent or null clears the override and falls back to git's own config. */
+  gitIdentity?: WorkspaceGitIdentity | null;
+};
+
+export type WorkspaceRemoteProbeResponse = {
+  requestId?: string;
+  ok: boolean;
+  destination?: string;
+  gitVersion?: string;
+  rootIsRepository: boolean;
+  error?: string;
+};
+
+export type WorkspaceRemoteWorktreesResponse = {
+  requestId?: string;
+  ok: boolean;
+  destination?: string;
+  worktrees: RemoteWorktreeRow[];
+  error?: string;
 };
 
 export type WorktreeStorageStatsResponse = {
@@ -351,11 +835,7 @@ export type WorktreeStorageStatsResponse = {
   error?: string;
 };
 
-/** Payload of the backend "worktree-evicted" event. */
-export type WorktreeEvictedEvent = {
-  workspaceRoot: string;
-  worktrees: string[];
-};
+export type { WorktreeEvictedEvent } from "./types-events";
 
 export type WorkspaceWorktreeSymlinkPathsPayload = {
   worktreeSymlinkPaths: string[];
@@ -366,8 +846,15 @@ export type SetWorktreeStatePayload = {
   state: WorktreeState;
 };
 
-export type ClaimWorktreeRewardPayload = {
+export type SetWorktreePlayCommandPayload = {
   worktree: string;
+  playGrooveCommand?: string | null;
+};
+
+export type SetWorktreeLabelsPayload = {
+  worktree: string;
+  note?: string | null;
+  tags?: string[];
 };
 
 export type ClaimWorktreeRewardResponse = {
@@ -378,10 +865,6 @@ export type ClaimWorktreeRewardResponse = {
   error?: string;
 };
 
-export type LootWorktreePayload = {
-  worktree: string;
-};
-
 export type LootWorktreeResponse = {
   requestId?: string;
   ok: boolean;
@@ -400,14 +883,55 @@ export type SetWorktreeStateResponse = {
   error?: string;
 };
 
-export type WorkspaceBrowseEntriesPayload = {
-  relativePath?: string | null;
+export type WorkspaceAdoptWorktreeResponse = {
+  requestId?: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  worktree?: string;
+  /** Where the worktree lives now. */
+  path?: string;
+  relocated: boolean;
+  record?: WorktreeRecord;
+  error?: string;
 };
 
-export type WorkspaceBrowseEntry = {
-  name: string;
+export type WorkspaceMigrationEntry = {
+  /** Where the worktree was found. */
   path: string;
-  isDir: boolean;
+  branch?: string;
+  /** Name the worktree has (or would have) in Groove. */
+  worktree?: string;
+  action:
+    | "adopt"
+    | "relocate"
+    | "adopted"
+    | "relocated"
+    | "alreadyManaged"
+    | "skipped"
+    | "failed";
+  reason?: string;
+};
+
+export type WorkspaceMigrateResponse = {
+  requestId?: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  layout?:
+    | "none"
+    | "groove"
+    | "bareRepository"
+    | "siblingDirectory"
+    | "nested"
+    | "mixed";
+  dryRun: boolean;
+  entries: WorkspaceMigrationEntry[];
+  /** Worktrees adopted by this run. */
+  migrated: number;
+  error?: string;
+};
+
+export type WorkspaceBrowseEntriesPayload = {
+  relativePath?: string | null;
 };
 
 export type WorkspaceBrowseEntriesResponse = {
@@ -432,6 +956,14 @@ export type WorkspaceEventsResponse = {
   error?: string;
 };
 
+/** `worktreePath` is the worktree watched after the call, if any. */
+export type WorktreeWatchResponse = {
+  requestId?: string;
+  ok: boolean;
+  worktreePath?: string;
+  error?: string;
+};
+
 export type GrooveBinCheckStatus = {
   configuredPath?: string;
   configuredPathValid?: boolean;
@@ -441,6 +973,43 @@ export type GrooveBinCheckStatus = {
   effectiveBinarySource: "env" | "bundled" | "path" | string;
 };
 
+export type AppHealthStatus = "ok" | "warning" | "error";
+
+export type AppHealthSubsystem = {
+  name:
+    | "workspaceWorker"
+    | "terminalSessions"
+    | "caches"
+    | "stateFiles"
+    | "grooveBin"
+    | "stateLocks"
+    | "backgroundActivity"
+    | string;
+  status: AppHealthStatus;
+  summary: string;
+};
+
+export type AppHealthResponse = {
+  requestId?: string;
+  ok: boolean;
+  /** The worst status of any subsystem. */
+  status: AppHealthStatus;
+  appVersion: string;
+  checkedAt: string;
+  subsystems: AppHealthSubsystem[];
+  error?: string;
+};
+
+export type BackgroundActivityResponse = {
+  requestId?: string;
+  ok: boolean;
+  paused: boolean;
+  pausedSince?: string;
+  /** False when background activity already was in the requested state. */
+  changed: boolean;
+  error?: string;
+};
+
 export type GrooveBinStatusResponse = {
   requestId?: string;
   ok: boolean;
@@ -478,3 +1047,15 @@ export type IpcTelemetrySummaryRow = {
   p95_ms: number;
   max_ms: number;
 };
+
+/** A failed try of a git or gh run that was tried again. */
+export type CommandRetry = {
+  /** The program and its subcommand, e.g. `git fetch`. */
+  command: string;
+  /** 1 for the first try. */
+  attempt: number;
+  category: CommandRetryCategory;
+  error: string;
+  /** Wait before the next try. */
+  backoffMs: number;
+};
@@ -1,12 +1,17 @@
-export type DoctrineWorktreeCase = {
-  branch: string;
-  prompts: string[];
-  date: string;
-  summary?: string;
-};
-
-export type DoctrineReportRequest = {
-  maxCases?: number;
+import type {
+  DoctrineRecord,
+  DoctrineReportRequest,
+  DoctrineSetActiveRequest,
+  DoctrineState,
+  DoctrineWorktreeCase,
+} from "./types-generated";
+
+export type {
+  DoctrineRecord,
+  DoctrineReportRequest,
+  DoctrineSetActiveRequest,
+  DoctrineState,
+  DoctrineWorktreeCase,
 };
 
 export type DoctrineReportResponse = {
@@ -20,19 +25,6 @@ export type DoctrineReportResponse = {
   error?: string;
 };
 
-export type DoctrineState = "ready" | "inactive";
-
-export type DoctrineRecord = {
-  id: string;
-  createdAt: string;
-  inputTokens: number;
-  outputTokens: number;
-  result: string;
-  state: DoctrineState;
-  instructions?: string;
-  directives?: string;
-};
-
 export type DoctrineResultRequest = {
   reportText: string;
   instructions?: string | null;
@@ -53,10 +45,6 @@ export type DoctrineListResponse = {
   error?: string;
 };
 
-export type DoctrineSetActiveRequest = {
-  id: string;
-};
-
 export type DoctrineSetActiveResponse = {
   requestId?: string;
   ok: boolean;
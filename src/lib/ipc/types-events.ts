@@ -0,0 +1,300 @@
+// Generated from src-tauri/src/events.rs; do not edit by hand.
+// Regenerate with: GROOVE_WRITE_EVENT_BINDINGS=1 cargo test events::
+
+import type { WorkspaceContextResponse } from "./types-core";
+import type { GrooveNotification } from "./types-terminal";
+
+export const GROOVE_EVENT_SCHEMA_VERSION = 1;
+
+export const GROOVE_EVENT_NAMES = {
+  WorkspaceReadyEvent: "workspace-ready",
+  WorkspaceContextCompleteEvent: "workspace-context-complete",
+  WorkspaceChangeEvent: "workspace-change",
+  GrooveNotificationEvent: "groove-notification",
+  WorktreeEvictedEvent: "worktree-evicted",
+  GrooveTerminalOutputEvent: "groove-terminal-output",
+  GrooveTerminalLifecycleEvent: "groove-terminal-lifecycle",
+  WorktreeWatchEvent: "worktree-watch",
+  GitQueueWaitEvent: "git-queue-wait",
+  GitProgressEvent: "git-progress",
+  GrooveCommandOutputEvent: "groove-command-output",
+  GrooveCommandCompleteEvent: "groove-command-complete",
+  TestingEnvironmentStatusEvent: "testing-environment-status",
+  TestingEnvironmentLogEvent: "testing-environment-log",
+  SystemAlertEvent: "system-alert",
+  CustomCommandConfirmationEvent: "custom-command-confirmation",
+  BackendHeartbeatEvent: "backend-heartbeat",
+  BackgroundActivityEvent: "background-activity",
+  StateRecoveredEvent: "groove-state-recovered",
+} as const;
+
+export type WorktreeChangeReason = "directoryAdded" | "directoryRemoved" | "logModified" | "metadataModified" | "processStarted" | "processExited" | "processReplaced";
+
+/**
+ * Payload of the "workspace-ready" event.
+ * A workspace finished loading (`kind` is "connection" or "filesystem").
+ */
+export type WorkspaceReadyEvent = {
+  version: number;
+  requestId: string;
+  workspaceRoot?: string;
+  kind: string;
+};
+
+/**
+ * Payload of the "workspace-context-complete" event.
+ * The full context after `workspace_get_active` answered a cold start
+ * with a partial one; `request_id` matches that answer.
+ */
+export type WorkspaceContextCompleteEvent = {
+  version: number;
+  requestId: string;
+  workspaceRoot: string;
+  context: WorkspaceContextResponse;
+};
+
+/**
+ * Payload of the "workspace-change" event.
+ * Coalesced filesystem or runtime changes under the active workspace.
+ * `changes` maps each affected worktree to why it was flagged; changes
+ * to workspace-level paths only show up in `sources`.
+ */
+export type WorkspaceChangeEvent = {
+  version: number;
+  index: number;
+  source: string;
+  sources: string[];
+  sourceCount: number;
+  workspaceRoot: string;
+  kind: string;
+  changes: Record<string, WorktreeChangeReason[]>;
+};
+
+/**
+ * Payload of the "groove-notification" event.
+ * A notification file dropped by the `groove` CLI or a hook.
+ */
+export type GrooveNotificationEvent = {
+  version: number;
+  workspaceRoot: string;
+  notification: GrooveNotification;
+};
+
+/**
+ * Payload of the "worktree-evicted" event.
+ * Worktrees removed to stay under the workspace worktree limit.
+ */
+export type WorktreeEvictedEvent = {
+  version: number;
+  workspaceRoot: string;
+  worktrees: string[];
+};
+
+/**
+ * Payload of the "groove-terminal-output" event.
+ * `offset` is the output offset right after `chunk`; pass the last one
+ * seen to `groove_terminal_attach` to resume after a reload.
+ */
+export type GrooveTerminalOutputEvent = {
+  version: number;
+  sessionId: string;
+  workspaceRoot: string;
+  worktree: string;
+  chunk: string;
+  offset: number;
+};
+
+/** Payload of the "groove-terminal-lifecycle" event. */
+export type GrooveTerminalLifecycleEvent = {
+  version: number;
+  sessionId: string;
+  workspaceRoot: string;
+  worktree: string;
+  kind: "started" | "closed" | "error" | "hibernated" | "resumed";
+  message?: string;
+};
+
+/**
+ * Payload of the "worktree-watch" event.
+ * State of the worktree followed with `worktree_watch`. The first event
+ * after a watch starts carries every field; later ones only the fields
+ * that changed since the previous event.
+ */
+export type WorktreeWatchEvent = {
+  version: number;
+  worktreePath: string;
+  sequence: number;
+  modified?: number;
+  added?: number;
+  deleted?: number;
+  untracked?: number;
+  dirty?: boolean;
+  ahead?: number;
+  behind?: number;
+  logState?: string;
+  latestLogMtimeMs?: number;
+  terminalSessions?: number;
+  commandRunsRunning?: number;
+  error?: string;
+  removed?: boolean;
+};
+
+/**
+ * Payload of the "git-queue-wait" event.
+ * A git command has been waiting on its repository's operation queue
+ * for a while; `ahead` is how many operations still run before it.
+ */
+export type GitQueueWaitEvent = {
+  version: number;
+  repository: string;
+  operation: string;
+  ahead: number;
+  waitedMs: number;
+};
+
+/**
+ * Payload of the "git-progress" event.
+ * Progress of a long-running git command (pull, push, fetch) run with
+ * `--progress`; `request_id` is the id of the command that started it.
+ */
+export type GitProgressEvent = {
+  version: number;
+  requestId: string;
+  path: string;
+  operation: string;
+  phase: string;
+  percent: number;
+  completed?: number;
+  total?: number;
+};
+
+/**
+ * Payload of the "groove-command-output" event.
+ * Output of a `groove_new`, `groove_restore` or `groove_rm` run with
+ * `stream`, as the sidecar writes it; `request_id` is the id of that
+ * command.
+ */
+export type GrooveCommandOutputEvent = {
+  version: number;
+  requestId: string;
+  command: string;
+  stream: "stdout" | "stderr";
+  chunk: string;
+};
+
+/**
+ * Payload of the "groove-command-complete" event.
+ * The sidecar behind a streamed groove command exited. The command still
+ * answers its request with the full output.
+ */
+export type GrooveCommandCompleteEvent = {
+  version: number;
+  requestId: string;
+  command: string;
+  ok: boolean;
+  exitCode?: number;
+  error?: string;
+};
+
+/**
+ * Payload of the "testing-environment-status" event.
+ * The supervisor saw a testing environment (Play session) crash,
+ * scheduled or made a restart of it, gave up on it, or saw a restarted
+ * one stop.
+ */
+export type TestingEnvironmentStatusEvent = {
+  version: number;
+  workspaceRoot: string;
+  worktree: string;
+  status: "running" | "restarting" | "crashed" | "failed" | "stopped";
+  restarts: number;
+  sessionId?: string;
+  crashReason?: string;
+  nextRestartAt?: string;
+};
+
+/**
+ * Payload of the "testing-environment-log" event.
+ * A line a custom Play command wrote, sent while the worktree's testing
+ * log is followed through `testing_environment_logs_tail`.
+ */
+export type TestingEnvironmentLogEvent = {
+  version: number;
+  workspaceRoot: string;
+  worktree: string;
+  stream: "stdout" | "stderr";
+  line: string;
+};
+
+/**
+ * Payload of the "system-alert" event.
+ * A system resource went over its alert threshold (`active`) or came back
+ * under it. `stop_worktrees` are idle grooves of the active workspace
+ * whose memory would cover the excess, largest first.
+ */
+export type SystemAlertEvent = {
+  version: number;
+  kind: "ram" | "disk" | "swap";
+  active: boolean;
+  valuePercent: number;
+  thresholdPercent: number;
+  message: string;
+  stopWorktrees: string[];
+  suggestion?: string;
+};
+
+/**
+ * Payload of the "custom-command-confirmation" event.
+ * A command from the workspace settings was held back because it has not
+ * run in the workspace before. `custom_command_approve` lets it run.
+ */
+export type CustomCommandConfirmationEvent = {
+  version: number;
+  workspaceRoot: string;
+  command: string;
+  what: string;
+};
+
+/**
+ * Payload of the "backend-heartbeat" event.
+ * Sent every few seconds while the backend is responsive. The counts are
+ * read under the same state locks commands take, so a deadlocked
+ * backend stops sending it, while an idle one keeps reporting zeros.
+ * Not sent while background activity is paused.
+ */
+export type BackendHeartbeatEvent = {
+  version: number;
+  sequence: number;
+  uptimeMs: number;
+  sentAt: string;
+  workspaceWorkerGeneration: number;
+  workspaceWorkerRunning: boolean;
+  workspaceWorkerIdleMs?: number;
+  commandsInFlight: number;
+  gitOperationsPending: number;
+  grooveListsInFlight: number;
+  commandRunsRunning: number;
+  terminalSessions: number;
+};
+
+/**
+ * Payload of the "background-activity" event.
+ * Background activity was paused or resumed with `background_pause` /
+ * `background_resume`.
+ */
+export type BackgroundActivityEvent = {
+  version: number;
+  paused: boolean;
+  changedAt: string;
+};
+
+/**
+ * Payload of the "groove-state-recovered" event.
+ * A poisoned lock on shared backend state was recovered.
+ */
+export type StateRecoveredEvent = {
+  version: number;
+  state: string;
+  detail: string;
+  recoveredAt: string;
+};
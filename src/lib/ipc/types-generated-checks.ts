@@ -0,0 +1,576 @@
+// Compile-time checks that the hand-written IPC types agree with the ones
+// generated from the Rust DTOs. Types the frontend would declare exactly as
+// generated are re-exported from ./types-generated instead; the ones below
+// narrow field types (literal unions, fields the backend always sends) and
+// so stay hand-written, but must name the same fields as their Rust struct.
+// A field renamed or added on either side fails `npm run typecheck`, naming
+// the type. New IPC types should re-export the generated ones.
+
+import type * as Commands from "./types-commands";
+import type * as Core from "./types-core";
+import type * as Doctrine from "./types-doctrine";
+import type * as Git from "./types-git";
+import type * as GitHub from "./types-github";
+import type * as Opencode from "./types-opencode";
+import type * as Terminal from "./types-terminal";
+import type * as Generated from "./types-generated";
+
+type SameFields<Hand, Rust> = [
+  Exclude<keyof Hand, keyof Rust>,
+  Exclude<keyof Rust, keyof Hand>,
+] extends [never, never]
+  ? true
+  : false;
+
+type SameUnion<Hand, Rust> = [Hand] extends [Rust]
+  ? [Rust] extends [Hand]
+    ? true
+    : false
+  : false;
+
+type Expect<T extends true> = T;
+
+interface HandWritten {
+  ActionHistoryEntry: Commands.ActionHistoryEntry;
+  ApiTokenSummary: Commands.ApiTokenSummary;
+  ApiTokensResponse: Commands.ApiTokensResponse;
+  AppBackupResponse: Commands.AppBackupResponse;
+  AppHealthResponse: Core.AppHealthResponse;
+  AppHealthSubsystem: Core.AppHealthSubsystem;
+  AssistantConnectResponse: Commands.AssistantConnectResponse;
+  AssistantRulesListResponse: Commands.AssistantRulesListResponse;
+  AssistantValidateResponse: Commands.AssistantValidateResponse;
+  BackgroundActivityResponse: Core.BackgroundActivityResponse;
+  CancelRequestResponse: Commands.CancelRequestResponse;
+  ClaimWorktreeRewardResponse: Core.ClaimWorktreeRewardResponse;
+  ClaudeCodeHookSoundEntry: Core.ClaudeCodeHookSoundEntry;
+  ClaudeCodeSoundSettings: Core.ClaudeCodeSoundSettings;
+  CommandRetry: Core.CommandRetry;
+  CommandRetryPolicy: Core.CommandRetryPolicy;
+  CommandRun: Commands.CommandRun;
+  CommandRunsListResponse: Commands.CommandRunsListResponse;
+  CommandsInFlightResponse: Commands.CommandsInFlightResponse;
+  CustomCommandApproveResponse: Core.CustomCommandApproveResponse;
+  CustomCommandPolicy: Core.CustomCommandPolicy;
+  DiagnosticsFdHolderRow: Commands.DiagnosticsFdHolderRow;
+  DiagnosticsFdUsage: Commands.DiagnosticsFdUsage;
+  DiagnosticsFdUsageResponse: Commands.DiagnosticsFdUsageResponse;
+  DiagnosticsMostConsumingProgramsResponse: Commands.DiagnosticsMostConsumingProgramsResponse;
+  DiagnosticsNodeAppsResponse: Commands.DiagnosticsNodeAppsResponse;
+  DiagnosticsStopAllResponse: Commands.DiagnosticsStopAllResponse;
+  DiagnosticsStopByCategoryPayload: Commands.DiagnosticsStopByCategoryPayload;
+  DiagnosticsStopByCategoryResponse: Commands.DiagnosticsStopByCategoryResponse;
+  DiagnosticsStopResponse: Commands.DiagnosticsStopResponse;
+  DiagnosticsStopTarget: Commands.DiagnosticsStopTarget;
+  DiagnosticsSystemOverviewResponse: Commands.DiagnosticsSystemOverviewResponse;
+  DiagnosticsTerminalMemoryResponse: Commands.DiagnosticsTerminalMemoryResponse;
+  DiagnosticsWorktreeResourcesResponse: Commands.DiagnosticsWorktreeResourcesResponse;
+  DiscoverWorktreeUnitPayload: Commands.DiscoverWorktreeUnitPayload;
+  DiscoverWorktreeUnitResponse: Commands.DiscoverWorktreeUnitResponse;
+  DoctrineListResponse: Doctrine.DoctrineListResponse;
+  DoctrineReportResponse: Doctrine.DoctrineReportResponse;
+  DoctrineResultRequest: Doctrine.DoctrineResultRequest;
+  DoctrineResultResponse: Doctrine.DoctrineResultResponse;
+  DoctrineSetActiveResponse: Doctrine.DoctrineSetActiveResponse;
+  ExternalUrlOpenResponse: Core.ExternalUrlOpenResponse;
+  FrozenWorkspaceState: Commands.FrozenWorkspaceState;
+  GhAuthStatusResponse: GitHub.GhAuthStatusResponse;
+  GhCommandResponse: GitHub.GhCommandResponse;
+  GhIssueListPayload: GitHub.GhIssueListPayload;
+  GhIssueListResponse: GitHub.GhIssueListResponse;
+  GhIssueViewResponse: GitHub.GhIssueViewResponse;
+  GhPrChecksResponse: GitHub.GhPrChecksResponse;
+  GhPrCommentsResponse: GitHub.GhPrCommentsResponse;
+  GhPrCreatePayload: GitHub.GhPrCreatePayload;
+  GhPrCreateResponse: GitHub.GhPrCreateResponse;
+  GhPrListResponse: GitHub.GhPrListResponse;
+  GhPrMergePayload: GitHub.GhPrMergePayload;
+  GhPrMergeResponse: GitHub.GhPrMergeResponse;
+  GhPrPrepareResponse: GitHub.GhPrPrepareResponse;
+  GhPrReview: GitHub.GhPrReview;
+  GhPrReviewPayload: GitHub.GhPrReviewPayload;
+  GhPrSummary: GitHub.GhPrSummary;
+  GhPrViewResponse: GitHub.GhPrViewResponse;
+  GhRepoDefaultBranchResponse: GitHub.GhRepoDefaultBranchResponse;
+  GhSshIdentity: GitHub.GhSshIdentity;
+  GhSshOverviewResponse: GitHub.GhSshOverviewResponse;
+  GitAheadBehindResponse: Git.GitAheadBehindResponse;
+  GitAuthStatusResponse: Git.GitAuthStatusResponse;
+  GitBooleanResponse: Git.GitBooleanResponse;
+  GitCommandResponse: Git.GitCommandResponse;
+  GitCommitHooksResponse: Git.GitCommitHooksResponse;
+  GitCommitPayload: Git.GitCommitPayload;
+  GitCommitResponse: Git.GitCommitResponse;
+  GitConflictFile: Git.GitConflictFile;
+  GitConflictResolvePayload: Git.GitConflictResolvePayload;
+  GitConflictResolveResponse: Git.GitConflictResolveResponse;
+  GitConflictSide: Git.GitConflictSide;
+  GitConflictsResponse: Git.GitConflictsResponse;
+  GitCurrentBranchResponse: Git.GitCurrentBranchResponse;
+  GitDiffFile: Git.GitDiffFile;
+  GitDiffFileResponse: Git.GitDiffFileResponse;
+  GitDiffHunk: Git.GitDiffHunk;
+  GitDiffHunksResponse: Git.GitDiffHunksResponse;
+  GitDiffLine: Git.GitDiffLine;
+  GitDiffResponse: Git.GitDiffResponse;
+  GitFetchBlobsResponse: Git.GitFetchBlobsResponse;
+  GitFileStatesResponse: Git.GitFileStatesResponse;
+  GitHookRun: Git.GitHookRun;
+  GitLintCommitMessageResponse: Git.GitLintCommitMessageResponse;
+  GitListBranchesResponse: Git.GitListBranchesResponse;
+  GitLogResponse: Git.GitLogResponse;
+  GitMergePayload: Git.GitMergePayload;
+  GitPullPayload: Git.GitPullPayload;
+  GitPushPayload: Git.GitPushPayload;
+  GitPushPreflightResponse: Git.GitPushPreflightResponse;
+  GitRebaseResponse: Git.GitRebaseResponse;
+  GitRebaseStartPayload: Git.GitRebaseStartPayload;
+  GitRebaseStatusResponse: Git.GitRebaseStatusResponse;
+  GitStatusResponse: Git.GitStatusResponse;
+  GlobalSettings: Core.GlobalSettings;
+  GlobalSettingsResponse: Core.GlobalSettingsResponse;
+  GlobalSettingsUpdatePayload: Core.GlobalSettingsUpdatePayload;
+  GrooveArchiveListPayload: Commands.GrooveArchiveListPayload;
+  GrooveArchiveListResponse: Commands.GrooveArchiveListResponse;
+  GrooveArchivePayload: Commands.GrooveArchivePayload;
+  GrooveArchiveResponse: Commands.GrooveArchiveResponse;
+  GrooveBinCheckStatus: Core.GrooveBinCheckStatus;
+  GrooveBinRepairResponse: Core.GrooveBinRepairResponse;
+  GrooveBinStatusResponse: Core.GrooveBinStatusResponse;
+  GrooveBulkPayload: Commands.GrooveBulkPayload;
+  GrooveBulkResponse: Commands.GrooveBulkResponse;
+  GrooveCommentMarkCommittedPayload: Commands.GrooveCommentMarkCommittedPayload;
+  GrooveCommentMarkCommittedResponse: Commands.GrooveCommentMarkCommittedResponse;
+  GrooveCommentPayload: Commands.GrooveCommentPayload;
+  GrooveCommentResponse: Commands.GrooveCommentResponse;
+  GrooveNewPayload: Commands.GrooveNewPayload;
+  GrooveNewResponse: Commands.GrooveNewResponse;
+  GroovePrAttachPayload: Commands.GroovePrAttachPayload;
+  GroovePrDetachPayload: Commands.GroovePrDetachPayload;
+  GroovePrResponse: Commands.GroovePrResponse;
+  GrooveRecoverableClearPayload: Commands.GrooveRecoverableClearPayload;
+  GrooveRecoverableClearResponse: Commands.GrooveRecoverableClearResponse;
+  GrooveRecoverableListResponse: Commands.GrooveRecoverableListResponse;
+  GrooveRestorePayload: Commands.GrooveRestorePayload;
+  GrooveRmPayload: Commands.GrooveRmPayload;
+  GrooveRmResponse: Commands.GrooveRmResponse;
+  GrooveRmSafetyResponse: Commands.GrooveRmSafetyResponse;
+  GrooveRmSafetyScan: Commands.GrooveRmSafetyScan;
+  GrooveSoundHookEntry: Core.GrooveSoundHookEntry;
+  GrooveSoundSettings: Core.GrooveSoundSettings;
+  GrooveStopPayload: Commands.GrooveStopPayload;
+  GrooveStopResponse: Commands.GrooveStopResponse;
+  GrooveSummaryPayload: Commands.GrooveSummaryPayload;
+  GrooveSummaryResponse: Commands.GrooveSummaryResponse;
+  GrooveTerminalActiveWorktreesResponse: Terminal.GrooveTerminalActiveWorktreesResponse;
+  GrooveTerminalActivityResponse: Terminal.GrooveTerminalActivityResponse;
+  GrooveTerminalAttachResponse: Terminal.GrooveTerminalAttachResponse;
+  GrooveTerminalClosePayload: Terminal.GrooveTerminalClosePayload;
+  GrooveTerminalHistoryPayload: Terminal.GrooveTerminalHistoryPayload;
+  GrooveTerminalHistoryResponse: Terminal.GrooveTerminalHistoryResponse;
+  GrooveTerminalOpenPayload: Terminal.GrooveTerminalOpenPayload;
+  GrooveTerminalResizePayload: Terminal.GrooveTerminalResizePayload;
+  GrooveTerminalScrollbackResponse: Terminal.GrooveTerminalScrollbackResponse;
+  GrooveTerminalSession: Terminal.GrooveTerminalSession;
+  GrooveTerminalSessionPayload: Terminal.GrooveTerminalSessionPayload;
+  GrooveTerminalSessionsResponse: Terminal.GrooveTerminalSessionsResponse;
+  GrooveTerminalWritePayload: Terminal.GrooveTerminalWritePayload;
+  GrooveTmuxKillWindowPayload: Commands.GrooveTmuxKillWindowPayload;
+  GrooveTmuxWindowsResponse: Commands.GrooveTmuxWindowsResponse;
+  GrooveUnarchivePayload: Commands.GrooveUnarchivePayload;
+  GrooveZellijSessionsResponse: Commands.GrooveZellijSessionsResponse;
+  HistoryListResponse: Commands.HistoryListResponse;
+  HistoryRepeatResponse: Commands.HistoryRepeatResponse;
+  LootWorktreeResponse: Core.LootWorktreeResponse;
+  MaintenanceConfigurePayload: Commands.MaintenanceConfigurePayload;
+  MaintenanceResponse: Commands.MaintenanceResponse;
+  MaintenanceRun: Commands.MaintenanceRun;
+  MaintenanceTaskInfo: Commands.MaintenanceTaskInfo;
+  MergeTrainEntry: Commands.MergeTrainEntry;
+  MergeTrainPause: Commands.MergeTrainPause;
+  MergeTrainResponse: Commands.MergeTrainResponse;
+  MergeTrainStartPayload: Commands.MergeTrainStartPayload;
+  MergeTrainState: Commands.MergeTrainState;
+  OpenCodeCancelResponse: Opencode.OpenCodeCancelResponse;
+  OpenCodeProfile: Opencode.OpenCodeProfile;
+  OpenCodeProfilePatch: Opencode.OpenCodeProfilePatch;
+  OpenCodeProfileResponse: Opencode.OpenCodeProfileResponse;
+  OpenCodeRepairResponse: Opencode.OpenCodeRepairResponse;
+  OpenCodeRunResponse: Opencode.OpenCodeRunResponse;
+  OpenCodeRunResult: Opencode.OpenCodeRunResult;
+  OpenCodeStatusResponse: Opencode.OpenCodeStatusResponse;
+  OpenCodeSyncResponse: Opencode.OpenCodeSyncResponse;
+  OpencodeCopySkillsPayload: Opencode.OpencodeCopySkillsPayload;
+  OpencodeCopySkillsResponse: Opencode.OpencodeCopySkillsResponse;
+  OpencodeGlobalSettingsResponse: Opencode.OpencodeGlobalSettingsResponse;
+  OpencodeIntegrationStatusResponse: Opencode.OpencodeIntegrationStatusResponse;
+  OpencodeSettingsDirectoryValidationResponse: Opencode.OpencodeSettingsDirectoryValidationResponse;
+  OpencodeSkillScope: Opencode.OpencodeSkillScope;
+  OpencodeSkillsListResponse: Opencode.OpencodeSkillsListResponse;
+  OpencodeWorkspaceSettingsResponse: Opencode.OpencodeWorkspaceSettingsResponse;
+  PlayCommandPreviewResponse: Core.PlayCommandPreviewResponse;
+  SetWorktreeLabelsPayload: Core.SetWorktreeLabelsPayload;
+  SetWorktreePlayCommandPayload: Core.SetWorktreePlayCommandPayload;
+  SetWorktreeStatePayload: Core.SetWorktreeStatePayload;
+  SetWorktreeStateResponse: Core.SetWorktreeStateResponse;
+  SettingsProfile: Core.SettingsProfile;
+  SettingsProfileOverrides: Core.SettingsProfileOverrides;
+  SettingsProfileSavePayload: Core.SettingsProfileSavePayload;
+  SettingsProfilesResponse: Core.SettingsProfilesResponse;
+  SettingsSyncConfigurePayload: Core.SettingsSyncConfigurePayload;
+  SettingsSyncResponse: Core.SettingsSyncResponse;
+  SettingsSyncRunPayload: Core.SettingsSyncRunPayload;
+  SettingsSyncTarget: Core.SettingsSyncTarget;
+  StatusPageStatusResponse: Commands.StatusPageStatusResponse;
+  TerminalStorageSessionUsage: Commands.TerminalStorageSessionUsage;
+  TerminalStorageStatsResponse: Commands.TerminalStorageStatsResponse;
+  TerminalStorageWorktreeUsage: Commands.TerminalStorageWorktreeUsage;
+  TestingEnvironmentInfo: Commands.TestingEnvironmentInfo;
+  TestingEnvironmentLogsResponse: Commands.TestingEnvironmentLogsResponse;
+  TestingEnvironmentStatusResponse: Commands.TestingEnvironmentStatusResponse;
+  UndoEntry: Core.UndoEntry;
+  UndoLastResponse: Core.UndoLastResponse;
+  UndoListResponse: Core.UndoListResponse;
+  WorkspaceAdoptWorktreeResponse: Core.WorkspaceAdoptWorktreeResponse;
+  WorkspaceBrowseEntriesPayload: Core.WorkspaceBrowseEntriesPayload;
+  WorkspaceBrowseEntriesResponse: Core.WorkspaceBrowseEntriesResponse;
+  WorkspaceCommandSettingsPayload: Core.WorkspaceCommandSettingsPayload;
+  WorkspaceCommitLintPayload: Core.WorkspaceCommitLintPayload;
+  WorkspaceCommitLintRules: Core.WorkspaceCommitLintRules;
+  WorkspaceCommitTemplate: Core.WorkspaceCommitTemplate;
+  WorkspaceCommitTemplatePayload: Core.WorkspaceCommitTemplatePayload;
+  WorkspaceConflictMatrixResponse: Commands.WorkspaceConflictMatrixResponse;
+  WorkspaceContextResponse: Core.WorkspaceContextResponse;
+  WorkspaceCustomCommand: Core.WorkspaceCustomCommand;
+  WorkspaceCustomCommandRunPayload: Terminal.WorkspaceCustomCommandRunPayload;
+  WorkspaceCustomCommandRunResponse: Terminal.WorkspaceCustomCommandRunResponse;
+  WorkspaceCustomCommandsListResponse: Core.WorkspaceCustomCommandsListResponse;
+  WorkspaceCustomCommandsPayload: Core.WorkspaceCustomCommandsPayload;
+  WorkspaceEventsPayload: Core.WorkspaceEventsPayload;
+  WorkspaceEventsResponse: Core.WorkspaceEventsResponse;
+  WorkspaceFreezeResponse: Commands.WorkspaceFreezeResponse;
+  WorkspaceGitIdentity: Core.WorkspaceGitIdentity;
+  WorkspaceGitIdentityPayload: Core.WorkspaceGitIdentityPayload;
+  WorkspaceGitignoreSanityResponse: Core.WorkspaceGitignoreSanityResponse;
+  WorkspaceListOpenResponse: Core.WorkspaceListOpenResponse;
+  WorkspaceListWorktreesPayload: Core.WorkspaceListWorktreesPayload;
+  WorkspaceListWorktreesResponse: Core.WorkspaceListWorktreesResponse;
+  WorkspaceMaxWorktreeCountPayload: Core.WorkspaceMaxWorktreeCountPayload;
+  WorkspaceMaxWorktreeCountResponse: Core.WorkspaceMaxWorktreeCountResponse;
+  WorkspaceMeta: Core.WorkspaceMeta;
+  WorkspaceMigrateResponse: Core.WorkspaceMigrateResponse;
+  WorkspaceMigrationEntry: Core.WorkspaceMigrationEntry;
+  WorkspaceOpenDirectoryResponse: Core.WorkspaceOpenDirectoryResponse;
+  WorkspaceOpenTerminalPayload: Commands.WorkspaceOpenTerminalPayload;
+  WorkspaceRemote: Core.WorkspaceRemote;
+  WorkspaceRemotePayload: Core.WorkspaceRemotePayload;
+  WorkspaceRemoteProbeResponse: Core.WorkspaceRemoteProbeResponse;
+  WorkspaceRemoteWorktreesResponse: Core.WorkspaceRemoteWorktreesResponse;
+  WorkspaceSearchWorktreesResponse: Core.WorkspaceSearchWorktreesResponse;
+  WorkspaceStalenessPolicyPayload: Core.WorkspaceStalenessPolicyPayload;
+  WorkspaceSummaryResponse: Core.WorkspaceSummaryResponse;
+  WorkspaceTermSanityResponse: Core.WorkspaceTermSanityResponse;
+  WorkspaceTerminalSettingsPayload: Core.WorkspaceTerminalSettingsPayload;
+  WorkspaceTerminalSettingsResponse: Core.WorkspaceTerminalSettingsResponse;
+  WorkspaceTrustResponse: Core.WorkspaceTrustResponse;
+  WorkspaceWorktreeSymlinkPathsPayload: Core.WorkspaceWorktreeSymlinkPathsPayload;
+  WorktreeActionPayload: Commands.WorktreeActionPayload;
+  WorktreeActionResponse: Commands.WorktreeActionResponse;
+  WorktreeConflictPair: Commands.WorktreeConflictPair;
+  WorktreeLootEntry: Core.WorktreeLootEntry;
+  WorktreePathIssue: Commands.WorktreePathIssue;
+  WorktreeRecord: Core.WorktreeRecord;
+  WorktreeRestackPayload: Commands.WorktreeRestackPayload;
+  WorktreeRestackResponse: Commands.WorktreeRestackResponse;
+  WorktreeSearchMatch: Core.WorktreeSearchMatch;
+  WorktreeSearchResult: Core.WorktreeSearchResult;
+  WorktreeSessionLayoutRestorePayload: Terminal.WorktreeSessionLayoutRestorePayload;
+  WorktreeSessionLayoutRestoreResponse: Terminal.WorktreeSessionLayoutRestoreResponse;
+  WorktreeStalenessEntry: Core.WorktreeStalenessEntry;
+  WorktreeStalenessPolicy: Core.WorktreeStalenessPolicy;
+  WorktreeStalenessReportResponse: Core.WorktreeStalenessReportResponse;
+  WorktreeStorageStatsResponse: Core.WorktreeStorageStatsResponse;
+  WorktreeUnit: Core.WorktreeUnit;
+  WorktreeWatchResponse: Core.WorktreeWatchResponse;
+}
+
+interface GeneratedFromRust {
+  ActionHistoryEntry: Generated.ActionHistoryEntry;
+  ApiTokenSummary: Generated.ApiTokenSummary;
+  ApiTokensResponse: Generated.ApiTokensResponse;
+  AppBackupResponse: Generated.AppBackupResponse;
+  AppHealthResponse: Generated.AppHealthResponse;
+  AppHealthSubsystem: Generated.AppHealthSubsystem;
+  AssistantConnectResponse: Generated.AssistantConnectResponse;
+  AssistantRulesListResponse: Generated.AssistantRulesListResponse;
+  AssistantValidateResponse: Generated.AssistantValidateResponse;
+  BackgroundActivityResponse: Generated.BackgroundActivityResponse;
+  CancelRequestResponse: Generated.CancelRequestResponse;
+  ClaimWorktreeRewardResponse: Generated.ClaimWorktreeRewardResponse;
+  ClaudeCodeHookSoundEntry: Generated.ClaudeCodeHookSoundEntry;
+  ClaudeCodeSoundSettings: Generated.ClaudeCodeSoundSettings;
+  CommandRetry: Generated.CommandRetry;
+  CommandRetryPolicy: Generated.CommandRetryPolicy;
+  CommandRun: Generated.CommandRun;
+  CommandRunsListResponse: Generated.CommandRunsListResponse;
+  CommandsInFlightResponse: Generated.CommandsInFlightResponse;
+  CustomCommandApproveResponse: Generated.CustomCommandApproveResponse;
+  CustomCommandPolicy: Generated.CustomCommandPolicy;
+  DiagnosticsFdHolderRow: Generated.DiagnosticsFdHolderRow;
+  DiagnosticsFdUsage: Generated.DiagnosticsFdUsage;
+  DiagnosticsFdUsageResponse: Generated.DiagnosticsFdUsageResponse;
+  DiagnosticsMostConsumingProgramsResponse: Generated.DiagnosticsMostConsumingProgramsResponse;
+  DiagnosticsNodeAppsResponse: Generated.DiagnosticsNodeAppsResponse;
+  DiagnosticsStopAllResponse: Generated.DiagnosticsStopAllResponse;
+  DiagnosticsStopByCategoryPayload: Generated.DiagnosticsStopByCategoryPayload;
+  DiagnosticsStopByCategoryResponse: Generated.DiagnosticsStopByCategoryResponse;
+  DiagnosticsStopResponse: Generated.DiagnosticsStopResponse;
+  DiagnosticsStopTarget: Generated.DiagnosticsStopTarget;
+  DiagnosticsSystemOverviewResponse: Generated.DiagnosticsSystemOverviewResponse;
+  DiagnosticsTerminalMemoryResponse: Generated.DiagnosticsTerminalMemoryResponse;
+  DiagnosticsWorktreeResourcesResponse: Generated.DiagnosticsWorktreeResourcesResponse;
+  DiscoverWorktreeUnitPayload: Generated.DiscoverWorktreeUnitPayload;
+  DiscoverWorktreeUnitResponse: Generated.DiscoverWorktreeUnitResponse;
+  DoctrineListResponse: Generated.DoctrineListResponse;
+  DoctrineReportResponse: Generated.DoctrineReportResponse;
+  DoctrineResultRequest: Generated.DoctrineResultRequest;
+  DoctrineResultResponse: Generated.DoctrineResultResponse;
+  DoctrineSetActiveResponse: Generated.DoctrineSetActiveResponse;
+  ExternalUrlOpenResponse: Generated.ExternalUrlOpenResponse;
+  FrozenWorkspaceState: Generated.FrozenWorkspaceState;
+  GhAuthStatusResponse: Generated.GhAuthStatusResponse;
+  GhCommandResponse: Generated.GhCommandResponse;
+  GhIssueListPayload: Generated.GhIssueListPayload;
+  GhIssueListResponse: Generated.GhIssueListResponse;
+  GhIssueViewResponse: Generated.GhIssueViewResponse;
+  GhPrChecksResponse: Generated.GhPrChecksResponse;
+  GhPrCommentsResponse: Generated.GhPrCommentsResponse;
+  GhPrCreatePayload: Generated.GhPrCreatePayload;
+  GhPrCreateResponse: Generated.GhPrCreateResponse;
+  GhPrListResponse: Generated.GhPrListResponse;
+  GhPrMergePayload: Generated.GhPrMergePayload;
+  GhPrMergeResponse: Generated.GhPrMergeResponse;
+  GhPrPrepareResponse: Generated.GhPrPrepareResponse;
+  GhPrReview: Generated.GhPrReview;
+  GhPrReviewPayload: Generated.GhPrReviewPayload;
+  GhPrSummary: Generated.GhPrSummary;
+  GhPrViewResponse: Generated.GhPrViewResponse;
+  GhRepoDefaultBranchResponse: Generated.GhRepoDefaultBranchResponse;
+  GhSshIdentity: Generated.GhSshIdentity;
+  GhSshOverviewResponse: Generated.GhSshOverviewResponse;
+  GitAheadBehindResponse: Generated.GitAheadBehindResponse;
+  GitAuthStatusResponse: Generated.GitAuthStatusResponse;
+  GitBooleanResponse: Generated.GitBooleanResponse;
+  GitCommandResponse: Generated.GitCommandResponse;
+  GitCommitHooksResponse: Generated.GitCommitHooksResponse;
+  GitCommitPayload: Generated.GitCommitPayload;
+  GitCommitResponse: Generated.GitCommitResponse;
+  GitConflictFile: Generated.GitConflictFile;
+  GitConflictResolvePayload: Generated.GitConflictResolvePayload;
+  GitConflictResolveResponse: Generated.GitConflictResolveResponse;
+  GitConflictSide: Generated.GitConflictSide;
+  GitConflictsResponse: Generated.GitConflictsResponse;
+  GitCurrentBranchResponse: Generated.GitCurrentBranchResponse;
+  GitDiffFile: Generated.GitDiffFile;
+  GitDiffFileResponse: Generated.GitDiffFileResponse;
+  GitDiffHunk: Generated.GitDiffHunk;
+  GitDiffHunksResponse: Generated.GitDiffHunksResponse;
+  GitDiffLine: Generated.GitDiffLine;
+  GitDiffResponse: Generated.GitDiffResponse;
+  GitFetchBlobsResponse: Generated.GitFetchBlobsResponse;
+  GitFileStatesResponse: Generated.GitFileStatesResponse;
+  GitHookRun: Generated.GitHookRun;
+  GitLintCommitMessageResponse: Generated.GitLintCommitMessageResponse;
+  GitListBranchesResponse: Generated.GitListBranchesResponse;
+  GitLogResponse: Generated.GitLogResponse;
+  GitMergePayload: Generated.GitMergePayload;
+  GitPullPayload: Generated.GitPullPayload;
+  GitPushPayload: Generated.GitPushPayload;
+  GitPushPreflightResponse: Generated.GitPushPreflightResponse;
+  GitRebaseResponse: Generated.GitRebaseResponse;
+  GitRebaseStartPayload: Generated.GitRebaseStartPayload;
+  GitRebaseStatusResponse: Generated.GitRebaseStatusResponse;
+  GitStatusResponse: Generated.GitStatusResponse;
+  GlobalSettings: Generated.GlobalSettings;
+  GlobalSettingsResponse: Generated.GlobalSettingsResponse;
+  GlobalSettingsUpdatePayload: Generated.GlobalSettingsUpdatePayload;
+  GrooveArchiveListPayload: Generated.GrooveArchiveListPayload;
+  GrooveArchiveListResponse: Generated.GrooveArchiveListResponse;
+  GrooveArchivePayload: Generated.GrooveArchivePayload;
+  GrooveArchiveResponse: Generated.GrooveArchiveResponse;
+  GrooveBinCheckStatus: Generated.GrooveBinCheckStatus;
+  GrooveBinRepairResponse: Generated.GrooveBinRepairResponse;
+  GrooveBinStatusResponse: Generated.GrooveBinStatusResponse;
+  GrooveBulkPayload: Generated.GrooveBulkPayload;
+  GrooveBulkResponse: Generated.GrooveBulkResponse;
+  GrooveCommentMarkCommittedPayload: Generated.GrooveCommentMarkCommittedPayload;
+  GrooveCommentMarkCommittedResponse: Generated.GrooveCommentMarkCommittedResponse;
+  GrooveCommentPayload: Generated.GrooveCommentPayload;
+  GrooveCommentResponse: Generated.GrooveCommentResponse;
+  GrooveNewPayload: Generated.GrooveNewPayload;
+  GrooveNewResponse: Generated.GrooveNewResponse;
+  GroovePrAttachPayload: Generated.GroovePrAttachPayload;
+  GroovePrDetachPayload: Generated.GroovePrDetachPayload;
+  GroovePrResponse: Generated.GroovePrResponse;
+  GrooveRecoverableClearPayload: Generated.GrooveRecoverableClearPayload;
+  GrooveRecoverableClearResponse: Generated.GrooveRecoverableClearResponse;
+  GrooveRecoverableListResponse: Generated.GrooveRecoverableListResponse;
+  GrooveRestorePayload: Generated.GrooveRestorePayload;
+  GrooveRmPayload: Generated.GrooveRmPayload;
+  GrooveRmResponse: Generated.GrooveRmResponse;
+  GrooveRmSafetyResponse: Generated.GrooveRmSafetyResponse;
+  GrooveRmSafetyScan: Generated.GrooveRmSafetyScan;
+  GrooveSoundHookEntry: Generated.GrooveSoundHookEntry;
+  GrooveSoundSettings: Generated.GrooveSoundSettings;
+  GrooveStopPayload: Generated.GrooveStopPayload;
+  GrooveStopResponse: Generated.GrooveStopResponse;
+  GrooveSummaryPayload: Generated.GrooveSummaryPayload;
+  GrooveSummaryResponse: Generated.GrooveSummaryResponse;
+  GrooveTerminalActiveWorktreesResponse: Generated.GrooveTerminalActiveWorktreesResponse;
+  GrooveTerminalActivityResponse: Generated.GrooveTerminalActivityResponse;
+  GrooveTerminalAttachResponse: Generated.GrooveTerminalAttachResponse;
+  GrooveTerminalClosePayload: Generated.GrooveTerminalClosePayload;
+  GrooveTerminalHistoryPayload: Generated.GrooveTerminalHistoryPayload;
+  GrooveTerminalHistoryResponse: Generated.GrooveTerminalHistoryResponse;
+  GrooveTerminalOpenPayload: Generated.GrooveTerminalOpenPayload;
+  GrooveTerminalResizePayload: Generated.GrooveTerminalResizePayload;
+  GrooveTerminalScrollbackResponse: Generated.GrooveTerminalScrollbackResponse;
+  GrooveTerminalSession: Generated.GrooveTerminalSession;
+  GrooveTerminalSessionPayload: Generated.GrooveTerminalSessionPayload;
+  GrooveTerminalSessionsResponse: Generated.GrooveTerminalSessionsResponse;
+  GrooveTerminalWritePayload: Generated.GrooveTerminalWritePayload;
+  GrooveTmuxKillWindowPayload: Generated.GrooveTmuxKillWindowPayload;
+  GrooveTmuxWindowsResponse: Generated.GrooveTmuxWindowsResponse;
+  GrooveUnarchivePayload: Generated.GrooveUnarchivePayload;
+  GrooveZellijSessionsResponse: Generated.GrooveZellijSessionsResponse;
+  HistoryListResponse: Generated.HistoryListResponse;
+  HistoryRepeatResponse: Generated.HistoryRepeatResponse;
+  LootWorktreeResponse: Generated.LootWorktreeResponse;
+  MaintenanceConfigurePayload: Generated.MaintenanceConfigurePayload;
+  MaintenanceResponse: Generated.MaintenanceResponse;
+  MaintenanceRun: Generated.MaintenanceRun;
+  MaintenanceTaskInfo: Generated.MaintenanceTaskInfo;
+  MergeTrainEntry: Generated.MergeTrainEntry;
+  MergeTrainPause: Generated.MergeTrainPause;
+  MergeTrainResponse: Generated.MergeTrainResponse;
+  MergeTrainStartPayload: Generated.MergeTrainStartPayload;
+  MergeTrainState: Generated.MergeTrainState;
+  OpenCodeCancelResponse: Generated.OpenCodeCancelResponse;
+  OpenCodeProfile: Generated.OpenCodeProfile;
+  OpenCodeProfilePatch: Generated.OpenCodeProfilePatch;
+  OpenCodeProfileResponse: Generated.OpenCodeProfileResponse;
+  OpenCodeRepairResponse: Generated.OpenCodeRepairResponse;
+  OpenCodeRunResponse: Generated.OpenCodeRunResponse;
+  OpenCodeRunResult: Generated.OpenCodeRunResult;
+  OpenCodeStatusResponse: Generated.OpenCodeStatusResponse;
+  OpenCodeSyncResponse: Generated.OpenCodeSyncResponse;
+  OpencodeCopySkillsPayload: Generated.OpencodeCopySkillsPayload;
+  OpencodeCopySkillsResponse: Generated.OpencodeCopySkillsResponse;
+  OpencodeGlobalSettingsResponse: Generated.OpencodeGlobalSettingsResponse;
+  OpencodeIntegrationStatusResponse: Generated.OpencodeIntegrationStatusResponse;
+  OpencodeSettingsDirectoryValidationResponse: Generated.OpencodeSettingsDirectoryValidationResponse;
+  OpencodeSkillScope: Generated.OpencodeSkillScope;
+  OpencodeSkillsListResponse: Generated.OpencodeSkillsListResponse;
+  OpencodeWorkspaceSettingsResponse: Generated.OpencodeWorkspaceSettingsResponse;
+  PlayCommandPreviewResponse: Generated.PlayCommandPreviewResponse;
+  SetWorktreeLabelsPayload: Generated.SetWorktreeLabelsPayload;
+  SetWorktreePlayCommandPayload: Generated.SetWorktreePlayCommandPayload;
+  SetWorktreeStatePayload: Generated.SetWorktreeStatePayload;
+  SetWorktreeStateResponse: Generated.SetWorktreeStateResponse;
+  SettingsProfile: Generated.SettingsProfile;
+  SettingsProfileOverrides: Generated.SettingsProfileOverrides;
+  SettingsProfileSavePayload: Generated.SettingsProfileSavePayload;
+  SettingsProfilesResponse: Generated.SettingsProfilesResponse;
+  SettingsSyncConfigurePayload: Generated.SettingsSyncConfigurePayload;
+  SettingsSyncResponse: Generated.SettingsSyncResponse;
+  SettingsSyncRunPayload: Generated.SettingsSyncRunPayload;
+  SettingsSyncTarget: Generated.SettingsSyncTarget;
+  StatusPageStatusResponse: Generated.StatusPageStatusResponse;
+  TerminalStorageSessionUsage: Generated.TerminalStorageSessionUsage;
+  TerminalStorageStatsResponse: Generated.TerminalStorageStatsResponse;
+  TerminalStorageWorktreeUsage: Generated.TerminalStorageWorktreeUsage;
+  TestingEnvironmentInfo: Generated.TestingEnvironmentInfo;
+  TestingEnvironmentLogsResponse: Generated.TestingEnvironmentLogsResponse;
+  TestingEnvironmentStatusResponse: Generated.TestingEnvironmentStatusResponse;
+  UndoEntry: Generated.UndoEntry;
+  UndoLastResponse: Generated.UndoLastResponse;
+  UndoListResponse: Generated.UndoListResponse;
+  WorkspaceAdoptWorktreeResponse: Generated.WorkspaceAdoptWorktreeResponse;
+  WorkspaceBrowseEntriesPayload: Generated.WorkspaceBrowseEntriesPayload;
+  WorkspaceBrowseEntriesResponse: Generated.WorkspaceBrowseEntriesResponse;
+  WorkspaceCommandSettingsPayload: Generated.WorkspaceCommandSettingsPayload;
+  WorkspaceCommitLintPayload: Generated.WorkspaceCommitLintPayload;
+  WorkspaceCommitLintRules: Generated.WorkspaceCommitLintRules;
+  WorkspaceCommitTemplate: Generated.WorkspaceCommitTemplate;
+  WorkspaceCommitTemplatePayload: Generated.WorkspaceCommitTemplatePayload;
+  WorkspaceConflictMatrixResponse: Generated.WorkspaceConflictMatrixResponse;
+  WorkspaceContextResponse: Generated.WorkspaceContextResponse;
+  WorkspaceCustomCommand: Generated.WorkspaceCustomCommand;
+  WorkspaceCustomCommandRunPayload: Generated.WorkspaceCustomCommandRunPayload;
+  WorkspaceCustomCommandRunResponse: Generated.WorkspaceCustomCommandRunResponse;
+  WorkspaceCustomCommandsListResponse: Generated.WorkspaceCustomCommandsListResponse;
+  WorkspaceCustomCommandsPayload: Generated.WorkspaceCustomCommandsPayload;
+  WorkspaceEventsPayload: Generated.WorkspaceEventsPayload;
+  WorkspaceEventsResponse: Generated.WorkspaceEventsResponse;
+  WorkspaceFreezeResponse: Generated.WorkspaceFreezeResponse;
+  WorkspaceGitIdentity: Generated.WorkspaceGitIdentity;
+  WorkspaceGitIdentityPayload: Generated.WorkspaceGitIdentityPayload;
+  WorkspaceGitignoreSanityResponse: Generated.WorkspaceGitignoreSanityResponse;
+  WorkspaceListOpenResponse: Generated.WorkspaceListOpenResponse;
+  WorkspaceListWorktreesPayload: Generated.WorkspaceListWorktreesPayload;
+  WorkspaceListWorktreesResponse: Generated.WorkspaceListWorktreesResponse;
+  WorkspaceMaxWorktreeCountPayload: Generated.WorkspaceMaxWorktreeCountPayload;
+  WorkspaceMaxWorktreeCountResponse: Generated.WorkspaceMaxWorktreeCountResponse;
+  WorkspaceMeta: Generated.WorkspaceMeta;
+  WorkspaceMigrateResponse: Generated.WorkspaceMigrateResponse;
+  WorkspaceMigrationEntry: Generated.WorkspaceMigrationEntry;
+  WorkspaceOpenDirectoryResponse: Generated.WorkspaceOpenDirectoryResponse;
+  WorkspaceOpenTerminalPayload: Generated.WorkspaceOpenTerminalPayload;
+  WorkspaceRemote: Generated.WorkspaceRemote;
+  WorkspaceRemotePayload: Generated.WorkspaceRemotePayload;
+  WorkspaceRemoteProbeResponse: Generated.WorkspaceRemoteProbeResponse;
+  WorkspaceRemoteWorktreesResponse: Generated.WorkspaceRemoteWorktreesResponse;
+  WorkspaceSearchWorktreesResponse: Generated.WorkspaceSearchWorktreesResponse;
+  WorkspaceStalenessPolicyPayload: Generated.WorkspaceStalenessPolicyPayload;
+  WorkspaceSummaryResponse: Generated.WorkspaceSummaryResponse;
+  WorkspaceTermSanityResponse: Generated.WorkspaceTermSanityResponse;
+  WorkspaceTerminalSettingsPayload: Generated.WorkspaceTerminalSettingsPayload;
+  WorkspaceTerminalSettingsResponse: Generated.WorkspaceTerminalSettingsResponse;
+  WorkspaceTrustResponse: Generated.WorkspaceTrustResponse;
+  WorkspaceWorktreeSymlinkPathsPayload: Generated.WorkspaceWorktreeSymlinkPathsPayload;
+  WorktreeActionPayload: Generated.WorktreeActionPayload;
+  WorktreeActionResponse: Generated.WorktreeActionResponse;
+  WorktreeConflictPair: Generated.WorktreeConflictPair;
+  WorktreeLootEntry: Generated.WorktreeLootEntry;
+  WorktreePathIssue: Generated.WorktreePathIssue;
+  WorktreeRecord: Generated.WorktreeRecord;
+  WorktreeRestackPayload: Generated.WorktreeRestackPayload;
+  WorktreeRestackResponse: Generated.WorktreeRestackResponse;
+  WorktreeSearchMatch: Generated.WorktreeSearchMatch;
+  WorktreeSearchResult: Generated.WorktreeSearchResult;
+  WorktreeSessionLayoutRestorePayload: Generated.WorktreeSessionLayoutRestorePayload;
+  WorktreeSessionLayoutRestoreResponse: Generated.WorktreeSessionLayoutRestoreResponse;
+  WorktreeStalenessEntry: Generated.WorktreeStalenessEntry;
+  WorktreeStalenessPolicy: Generated.WorktreeStalenessPolicy;
+  WorktreeStalenessReportResponse: Generated.WorktreeStalenessReportResponse;
+  WorktreeStorageStatsResponse: Generated.WorktreeStorageStatsResponse;
+  WorktreeUnit: Generated.WorktreeUnit;
+  WorktreeWatchResponse: Generated.WorktreeWatchResponse;
+}
+
+type DriftedTypes = {
+  [Name in keyof HandWritten]: SameFields<
+    HandWritten[Name],
+    GeneratedFromRust[Name]
+  > extends true
+    ? never
+    : Name;
+}[keyof HandWritten];
+
+export type IpcTypesMatchGenerated = Expect<
+  [DriftedTypes] extends [never] ? true : DriftedTypes
+>;
+
+export type WorktreeStatesMatchGenerated = Expect<
+  SameUnion<Core.WorktreeState, Generated.WorktreeState>
+>;
@@ -0,0 +1,4286 @@
+// Generated from the IPC types in src-tauri/src/backend; do not edit by hand.
+// Regenerate with: GROOVE_WRITE_DTO_BINDINGS=1 cargo test bindings::
+
+export type PersistedWorktreeExecutionState = {
+  /**
+   * Inner maps are keyed by worktree id (see `worktree_state_key`); entries
+   * written before ids were used are keyed by directory name.
+   */
+  lastExecutedAtByWorkspace: Record<string, Record<string, string>>;
+  tombstonesByWorkspace: Record<string, Record<string, WorktreeTombstone>>;
+  runningByWorkspace: Record<string, Record<string, RunningGrooveRecord>>;
+  sessionLayoutsByWorkspace: Record<string, Record<string, WorktreeSessionLayout>>;
+};
+
+/**
+ * Terminal sessions that were open for a worktree, in the order they were
+ * opened. Entries are added on open and dropped on an explicit close or
+ * restart, so an app shutdown leaves the layout in place for
+ * `worktree_session_layout_restore`.
+ */
+export type WorktreeSessionLayout = {
+  worktree: string;
+  entries: WorktreeSessionLayoutEntry[];
+  updatedAt: string;
+};
+
+export type WorktreeSessionLayoutEntry = {
+  sessionId: string;
+  /** One of the `openMode` values accepted by `groove_terminal_open`. */
+  openMode: string;
+  target?: string;
+  /**
+   * True for Play sessions (run locally in the app), which are also
+   * tracked as running grooves.
+   */
+  runLocal: boolean;
+};
+
+export type WorktreeTombstone = {
+  workspaceRoot: string;
+  worktree: string;
+  worktreeId?: string;
+  worktreePath: string;
+  branchName?: string;
+  deletedAt: string;
+};
+
+/**
+ * A groove that was actively playing an in-app PTY session. Persisted on play
+ * and removed when the session ends cleanly, so survivors after an unexpected
+ * shutdown can be surfaced for recovery on the next launch.
+ */
+export type RunningGrooveRecord = {
+  workspaceRoot: string;
+  worktree: string;
+  worktreePath: string;
+  command: string;
+  target?: string;
+  sessionId: string;
+  pid?: number;
+  startedAt: string;
+  /**
+   * Start time of `pid` as reported by the OS, checked before the process
+   * is stopped so a reused PID is not signalled.
+   */
+  processStartedAt?: string;
+  /**
+   * Best-effort liveness annotation set only when returned to the frontend;
+   * never persisted.
+   */
+  stillRunning?: boolean;
+};
+
+export type WorkspaceMetaContext = {
+  version?: number | null;
+  rootName?: string | null;
+  createdAt?: string | null;
+  updatedAt?: string | null;
+  defaultTerminal?: string | null;
+  terminalCustomCommand?: string | null;
+  telemetryEnabled?: boolean | null;
+  disableGrooveBusiness?: boolean | null;
+  hideMascot?: boolean | null;
+  hideLabels?: boolean | null;
+  showFps?: boolean | null;
+  playGrooveCommand?: string | null;
+  openTerminalAtWorktreeCommand?: string | null;
+  worktreeSymlinkPaths?: string[] | null;
+  opencodeSettings?: OpencodeSettings | null;
+  worktreeRecords?: Record<string, WorktreeRecord> | null;
+  rootDirectory?: string | null;
+};
+
+export type OpencodeSettings = {
+  enabled: boolean;
+  defaultModel?: string | null;
+  settingsDirectory: string;
+};
+
+export type OpencodeSkillEntry = {
+  name: string;
+  path: string;
+  isDirectory: boolean;
+  hasSkillMarkdown: boolean;
+};
+
+export type OpencodeSkillScope = {
+  scope: string;
+  rootPath: string;
+  skillsPath: string;
+  skillsDirectoryExists: boolean;
+  skills: OpencodeSkillEntry[];
+};
+
+export type OpenCodeProfileCommands = {
+  init: string;
+  newChange: string;
+  continue: string;
+  apply: string;
+  verify: string;
+  archive: string;
+};
+
+export type OpenCodeProfileTimeouts = {
+  phaseSeconds: number;
+};
+
+export type OpenCodeProfileSafety = {
+  requireUserApprovalBetweenPhases: boolean;
+  allowParallelSpecDesign: boolean;
+};
+
+export type OpenCodeProfile = {
+  version: string;
+  enabled: boolean;
+  artifactStore: string;
+  defaultFlow: string;
+  commands: OpenCodeProfileCommands;
+  timeouts: OpenCodeProfileTimeouts;
+  safety: OpenCodeProfileSafety;
+};
+
+export type OpenCodeProfileCommandsPatch = {
+  init?: string;
+  newChange?: string;
+  continue?: string;
+  apply?: string;
+  verify?: string;
+  archive?: string;
+};
+
+export type OpenCodeProfileTimeoutsPatch = {
+  phaseSeconds?: number;
+};
+
+export type OpenCodeProfileSafetyPatch = {
+  requireUserApprovalBetweenPhases?: boolean;
+  allowParallelSpecDesign?: boolean;
+};
+
+export type OpenCodeProfilePatch = {
+  version?: string;
+  enabled?: boolean;
+  artifactStore?: string;
+  defaultFlow?: string;
+  commands?: OpenCodeProfileCommandsPatch;
+  timeouts?: OpenCodeProfileTimeoutsPatch;
+  safety?: OpenCodeProfileSafetyPatch;
+};
+
+export type SetOpenCodeProfilePayload = {
+  patch: OpenCodeProfilePatch;
+};
+
+export type RunOpenCodeFlowPayload = {
+  phase: string;
+  args?: string[];
+};
+
+export type OpenCodeErrorDetail = {
+  code: string;
+  message: string;
+  hint: string;
+  paths: string[];
+};
+
+export type OpenCodeSanityChecks = {
+  agentTeamsLiteAvailable: boolean;
+  requiredRefsPresent: boolean;
+  profileExistsAndValid: boolean;
+  syncArtifactApplied: boolean;
+  artifactStoreReady: boolean;
+};
+
+export type OpenCodeSanityStatus = {
+  applied: boolean;
+  checks: OpenCodeSanityChecks;
+  hardBlockers: string[];
+  recommendations: string[];
+  diagnostics: string[];
+};
+
+export type OpenCodeStatus = {
+  worktreePath: string;
+  worktreeExists: boolean;
+  gitRepo: boolean;
+  opencodeAvailable: boolean;
+  opencodeBinaryPath?: string;
+  agentTeamsLiteAvailable: boolean;
+  agentTeamsLiteDir?: string;
+  requiredCommandsAvailable: boolean;
+  missingCommands: string[];
+  profilePresent: boolean;
+  profilePath: string;
+  syncTargetExists: boolean;
+  syncTargetPath: string;
+  artifactStore?: string;
+  artifactStoreReady: boolean;
+  engramBinaryAvailable?: boolean;
+  engramOpencodeMcpConfigPresent?: boolean;
+  engramOpencodePluginPresent?: boolean;
+  engramOpencodeConfigPath?: string;
+  engramOpencodePluginPath?: string;
+  profileValid: boolean;
+  warnings: string[];
+  sanity: OpenCodeSanityStatus;
+};
+
+export type SyncResult = {
+  ok: boolean;
+  changed: boolean;
+  profilePath: string;
+  syncArtifactPath: string;
+  warnings: string[];
+  message: string;
+};
+
+export type OpenCodeRepairResult = {
+  repaired: boolean;
+  backupPath?: string;
+  actions: string[];
+  postRepairStatus: OpenCodeStatus;
+};
+
+export type OpenCodeRunResult = {
+  runId: string;
+  phase: string;
+  status: string;
+  exitCode?: number;
+  durationMs: number;
+  summary?: string;
+  stdout: string;
+  stderr: string;
+  error?: OpenCodeErrorDetail;
+};
+
+export type CancelResult = {
+  runId: string;
+  supported: boolean;
+  cancelled: boolean;
+  status: string;
+  message: string;
+  error?: OpenCodeErrorDetail;
+};
+
+export type SummaryRecord = {
+  worktreeIds: string[];
+  createdAt: string;
+  summary: string;
+  oneLiner?: string;
+};
+
+export type CommentState = "uncommitted" | "committed";
+
+export type CommentRecord = {
+  worktreeId: string;
+  createdAt: string;
+  message: string;
+  state: CommentState;
+};
+
+export type PullRequestRecord = {
+  number: number;
+  url: string;
+  title?: string;
+  base?: string;
+  head?: string;
+  addedAt: string;
+};
+
+/** The GitHub issue a worktree was created for. */
+export type IssueRecord = {
+  number: number;
+  url: string;
+  title?: string;
+  linkedAt: string;
+};
+
+export type WorktreeState = "pending" | "hunting" | "fighting" | "wounded" | "defeated" | "blocked" | "forgotten";
+
+export type WorktreeUnitKind = "bug" | "goldmine" | "gems";
+
+export type WorktreeLootEntry = {
+  itemId: string;
+  rarity: string;
+};
+
+export type WorktreeUnit = {
+  kind: WorktreeUnitKind;
+  level: number;
+  reward: number;
+  name: string;
+  rewarded: boolean;
+  /**
+   * `false` (or absent) means "not looted yet" — the player still has to
+   * open the looting interface to roll and collect items. Decoupled from
+   * `rewarded` so gold and loot are two independent steps.
+   */
+  looted: boolean;
+  /**
+   * Empty until the player triggers the loot step. Loot is rolled lazily
+   * in `loot_worktree` (0..=3 items), not at unit creation time.
+   */
+  loot?: WorktreeLootEntry[];
+};
+
+export type WorktreeRecord = {
+  id: string;
+  createdAt: string;
+  claudeSessionStarted: boolean;
+  state: WorktreeState;
+  unit?: WorktreeUnit;
+  summaries: SummaryRecord[];
+  comments: CommentRecord[];
+  pullRequests: PullRequestRecord[];
+  /**
+   * Branch checked out in this worktree when Groove created it. Needed when
+   * the directory name is a hashed alias that cannot be reversed.
+   */
+  branch?: string;
+  /** Overrides the workspace `play_groove_command` for this worktree only. */
+  playGrooveCommand?: string;
+  /**
+   * Free-form reminder of what the worktree is for; searchable from the
+   * quick switcher.
+   */
+  note?: string;
+  tags?: string[];
+  /** Set when the worktree was created from an issue; PR bodies refer to it. */
+  issue?: IssueRecord;
+  /**
+   * Record id of the worktree this one is stacked on. An id rather than a
+   * name, so renaming the parent keeps the link.
+   */
+  parentId?: string;
+};
+
+/**
+ * Contents of `<worktree>/.groove/worktree.json`. The id travels with the
+ * directory, so a renamed or moved worktree can be matched back to its
+ * `WorktreeRecord` (and the state keyed by that id).
+ */
+export type WorktreeIdentity = {
+  id: string;
+  branch?: string;
+  createdAt: string;
+};
+
+export type WorkspaceMeta = {
+  version: number;
+  rootName: string;
+  createdAt: string;
+  updatedAt: string;
+  defaultTerminal: string;
+  terminalCustomCommand?: string | null;
+  telemetryEnabled: boolean;
+  disableGrooveBusiness: boolean;
+  hideMascot: boolean;
+  hideLabels: boolean;
+  showFps: boolean;
+  playGrooveCommand: string;
+  openTerminalAtWorktreeCommand?: string | null;
+  worktreeSymlinkPaths: string[];
+  opencodeSettings: OpencodeSettings;
+  onboardingSymlinksConfigured: boolean;
+  onboardingCommandsConfigured: boolean;
+  worktreeRecords: Record<string, WorktreeRecord>;
+  summaries: SummaryRecord[];
+  rootDirectory?: string | null;
+  gold: number;
+  defeatedCount: number;
+  /**
+   * Bug names that have ever been rolled in this workspace. Populated
+   * whenever Discover produces a `Bug` unit; used by the UI as a
+   * "bestiary" of encountered creatures.
+   */
+  knownBugs: string[];
+  /**
+   * Item-id → count of items collected over the workspace's lifetime.
+   * Bumped on reward claim alongside `gold`.
+   */
+  inventory: Record<string, number>;
+  /**
+   * Optional cap on how many worktrees are kept on disk. When a new
+   * worktree is created past this limit, the least-recently-used worktree
+   * that is neither running nor dirty is auto-removed. `None`/`Some(0)`
+   * means unlimited.
+   */
+  maxWorktreeCount?: number | null;
+  /**
+   * Experimental: when set, worktrees, git and terminal sessions of this
+   * workspace live on this SSH host instead of the local disk.
+   */
+  remote?: WorkspaceRemote | null;
+  /**
+   * Overrides applied with `git -c` to every git invocation Groove makes
+   * for this workspace.
+   */
+  gitIdentity?: WorkspaceGitIdentity | null;
+  /** Message and trailers `git_commit` uses for this workspace. */
+  commitTemplate?: WorkspaceCommitTemplate | null;
+  /**
+   * Conventional-commit rules `git_lint_commit_message` checks against;
+   * `None` leaves messages unchecked.
+   */
+  commitLint?: WorkspaceCommitLintRules | null;
+  /**
+   * Commits made through the app get `Signed-off-by` (DCO), and
+   * `git_push_preflight` checks unpushed commits for it.
+   */
+  requireSignOff: boolean;
+  /** Named commands the worktree view offers as buttons. */
+  customCommands: WorkspaceCustomCommand[];
+  /**
+   * Worktree name → absolute path of git worktrees adopted from outside
+   * `.worktrees/`. Scans and `groove_list` include them like the others.
+   */
+  adoptedWorktrees: Record<string, string>;
+  /**
+   * Rules `worktree_staleness_report` classifies worktrees by; `None`
+   * reports nothing as stale.
+   */
+  stalenessPolicy?: WorktreeStalenessPolicy;
+};
+
+/** A worktree matching any enabled rule is stale. */
+export type WorktreeStalenessPolicy = {
+  /** Stale once the newest commit is older than this many days. */
+  noCommitsDays?: number;
+  /** Stale once the branch is merged into the default branch. */
+  branchMerged: boolean;
+  /** Stale once the branch has pull requests and none is open. */
+  prClosed: boolean;
+  /**
+   * Stale once no opencode log was written in the worktree for this many
+   * days.
+   */
+  noOpencodeActivityDays?: number;
+  /**
+   * "report"; "archive" to forget stale worktrees when the report runs;
+   * "prompt" to have the report suggest deleting them.
+   */
+  action: string;
+};
+
+export type WorkspaceRemote = {
+  /** `host`, `user@host` or an ssh config alias. */
+  destination: string;
+  port?: number | null;
+  /** Absolute path of the repository root on the remote host. */
+  root: string;
+  /**
+   * Forward the local ssh agent (`ssh -A`) so git on the host can use the
+   * user's keys.
+   */
+  forwardAgent: boolean;
+};
+
+export type WorkspaceGitIdentity = {
+  userName?: string;
+  userEmail?: string;
+  /** `core.sshCommand`, e.g. `ssh -i ~/.ssh/id_work -o IdentitiesOnly=yes`. */
+  sshCommand?: string;
+};
+
+/**
+ * `{branch}`, `{ticket}` (e.g. `PROJ-123` taken from the branch name) and
+ * `{worktree}` are expanded in both the message and the trailers.
+ */
+export type WorkspaceCommitTemplate = {
+  /** Used when a commit is made without an explicit message. */
+  message?: string;
+  /**
+   * `Key: value` lines appended to every commit message; ones that need
+   * `{ticket}` are skipped on branches without a ticket ID.
+   */
+  trailers?: string[];
+};
+
+/**
+ * `{worktree}` (the worktree path) and `{branch}` are expanded in the
+ * template.
+ */
+export type WorkspaceCustomCommand = {
+  /** Assigned when the command is first saved. */
+  id: string;
+  label: string;
+  template: string;
+  runMode: CustomCommandRunMode;
+};
+
+export type CustomCommandRunMode = "terminal" | "detached" | "capture";
+
+export type WorkspaceCommitLintRules = {
+  /** Allowed types; empty means the conventional-commit defaults. */
+  types?: string[];
+  /** Allowed scopes; empty means any. */
+  scopes?: string[];
+  requireScope: boolean;
+  /** Longest allowed first line, in characters; 72 when unset. */
+  maxSubjectLength?: number;
+};
+
+export type WorkspaceScanRow = {
+  worktree: string;
+  worktreeId?: string;
+  branchGuess: string;
+  path: string;
+  status: string;
+  lastExecutedAt?: string;
+};
+
+export type WorkspaceContextResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  repositoryRemoteUrl?: string;
+  workspaceMeta?: WorkspaceMeta;
+  workspaceMessage?: string;
+  hasWorktreesDirectory?: boolean;
+  rows: WorkspaceScanRow[];
+  cancelled?: boolean;
+  /**
+   * Set on a cold-start `workspace_get_active` answer that has the meta but
+   * no rows yet; the full context follows in a `workspace-context-complete`
+   * event.
+   */
+  partial?: boolean;
+  /**
+   * Set when the workspace root or its `.groove` directory cannot be
+   * written. The context is read without writing, and settings writes and
+   * new worktrees are refused up front.
+   */
+  readOnly?: boolean;
+  readOnlyReason?: string;
+  /**
+   * "undecided" for a workspace the user has not trusted or distrusted
+   * yet, "denied" once distrusted; unset when trusted. Commands from its
+   * settings and git hooks only run when trusted; see `workspace_set_trust`.
+   */
+  trust?: string;
+  /** Set when only one page of `rows` was asked for. */
+  page?: RowsPageInfo;
+  error?: string;
+};
+
+/**
+ * `active-workspace.json`: the active workspace and every open one. Files
+ * written before several workspaces could be open only have
+ * `workspaceRoot`.
+ */
+export type PersistedWorkspaceState = {
+  workspaceRoot?: string;
+  updatedAt?: string;
+  openWorkspaces: OpenWorkspaceRecord[];
+};
+
+export type OpenWorkspaceRecord = {
+  workspaceRoot: string;
+  openedAt: string;
+  lastActiveAt: string;
+};
+
+export type OpenWorkspaceSummary = {
+  workspaceRoot: string;
+  /** The root directory's name. */
+  rootName: string;
+  openedAt: string;
+  lastActiveAt: string;
+  active: boolean;
+  /** Whether a `workspace_events` worker polls this workspace. */
+  watching: boolean;
+  /** False once the root is no longer an accessible directory. */
+  available: boolean;
+};
+
+/** Open workspaces, most recently active first. */
+export type WorkspaceListOpenResponse = {
+  requestId: string;
+  ok: boolean;
+  activeWorkspaceRoot?: string;
+  workspaces: OpenWorkspaceSummary[];
+  error?: string;
+};
+
+export type WorkspaceSearchWorktreesPayload = {
+  query?: string;
+  limit?: number;
+  includeDeleted?: boolean;
+};
+
+export type WorktreeSearchMatch = {
+  /** `worktree`, `branch`, `tag` or `note`. */
+  field: string;
+  text: string;
+  /** Character positions in `text` to highlight. */
+  indices: number[];
+};
+
+export type WorktreeSearchResult = {
+  worktree: string;
+  worktreeId?: string;
+  branch: string;
+  path: string;
+  status: string;
+  lastExecutedAt?: string;
+  score: number;
+  matches: WorktreeSearchMatch[];
+};
+
+export type WorkspaceSearchWorktreesResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  results: WorktreeSearchResult[];
+  error?: string;
+};
+
+export type WorkspaceListWorktreesPayload = {
+  offset: number;
+  limit?: number | null;
+};
+
+/** Asks for one page of worktree rows instead of all of them. */
+export type RowsPagePayload = {
+  /** `nextCursor` of the previous page; unset for the first page. */
+  cursor?: string;
+  /** Defaults to 100, at most 500. */
+  limit?: number;
+};
+
+export type RowsPageInfo = {
+  /** Rows on every page together, e.g. after a groove list filter. */
+  total: number;
+  /** Unset on the last page. */
+  nextCursor?: string;
+};
+
+export type WorkspaceListWorktreesResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  rows: WorkspaceScanRow[];
+  /** Rows in the whole workspace, not just this page. */
+  total: number;
+  offset: number;
+  hasMore: boolean;
+  error?: string;
+};
+
+/** A worktree listed under a `workspace_summary` category. */
+export type WorkspaceSummaryItem = {
+  worktree: string;
+  /**
+   * What the worktree is listed for, e.g. `claude-code`, `3 changed files`
+   * or `PR #42`.
+   */
+  detail?: string;
+  url?: string;
+  /**
+   * When the agent or testing environment started, or the pull request
+   * was attached.
+   */
+  since?: string;
+};
+
+export type WorkspaceSummaryCategory = {
+  count: number;
+  /** At most `WORKSPACE_SUMMARY_TOP_ITEMS`, newest first. */
+  top: WorkspaceSummaryItem[];
+};
+
+export type WorkspaceSummaryResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  totalWorktrees: number;
+  runningAgents: WorkspaceSummaryCategory;
+  dirtyWorktrees: WorkspaceSummaryCategory;
+  openPrs: WorkspaceSummaryCategory;
+  runningTestingEnvs: WorkspaceSummaryCategory;
+  error?: string;
+};
+
+export type WorkspaceCustomCommandsListResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  customCommands: WorkspaceCustomCommand[];
+  error?: string;
+};
+
+export type WorkspaceCustomCommandRunResponse = {
+  requestId: string;
+  ok: boolean;
+  /** The expanded command line. */
+  command?: string;
+  runMode?: CustomCommandRunMode;
+  /** The terminal the command was started in, for `terminal` commands. */
+  session?: GrooveTerminalSession;
+  /**
+   * The run as started, for `capture` commands; poll `command_runs_list`
+   * for its outcome.
+   */
+  run?: CommandRun;
+  error?: string;
+};
+
+export type CommandRunStatus = "running" | "succeeded" | "failed";
+
+/** One captured run of a custom command. */
+export type CommandRun = {
+  id: string;
+  commandId: string;
+  label: string;
+  workspaceRoot: string;
+  worktree: string;
+  /** The expanded command line. */
+  command: string;
+  status: CommandRunStatus;
+  exitCode?: number;
+  stdout: string;
+  stderr: string;
+  /** Set when `stdout` or `stderr` were cut to their tail. */
+  outputTruncated: boolean;
+  error?: string;
+  startedAt: string;
+  finishedAt?: string;
+  durationMs?: number;
+};
+
+export type CommandRunsListPayload = {
+  /** Only runs in this worktree of the active workspace. */
+  worktree?: string;
+  /** Only runs of this custom command. */
+  commandId?: string;
+};
+
+export type CommandRunsListResponse = {
+  requestId: string;
+  ok: boolean;
+  /** Newest first. */
+  runs: CommandRun[];
+  error?: string;
+};
+
+export type CancelRequestPayload = {
+  /**
+   * Request id of the command to cancel, e.g. from `GitProgressEvent` or
+   * `commands_in_flight`.
+   */
+  requestId: string;
+};
+
+export type CancelRequestResponse = {
+  requestId: string;
+  ok: boolean;
+  /**
+   * The command that was asked to stop. It still answers its own
+   * request, with a "was cancelled" error and `cancelled: true` on the
+   * responses that carry the flag once it noticed.
+   */
+  command?: string;
+  error?: string;
+};
+
+export type InFlightCommandInfo = {
+  requestId: string;
+  command: string;
+  elapsedMs: number;
+  cancelled: boolean;
+};
+
+export type CommandsInFlightResponse = {
+  requestId: string;
+  ok: boolean;
+  /** Oldest first; this request is not listed. */
+  commands: InFlightCommandInfo[];
+  error?: string;
+};
+
+/**
+ * An API token for the MCP server. Only the SHA-256 digest of the secret is
+ * stored; `capabilities` holds `Capability::as_str` values.
+ */
+export type ApiTokenRecord = {
+  id: string;
+  name: string;
+  secretDigest: string;
+  capabilities: string[];
+  createdAt: string;
+};
+
+export type ApiTokenSummary = {
+  id: string;
+  name: string;
+  capabilities: string[];
+  createdAt: string;
+};
+
+export type ApiTokenCreatePayload = {
+  name: string;
+  /** Defaults to every capability except `destructive`. */
+  capabilities?: string[];
+};
+
+export type ApiTokenRevokePayload = {
+  id: string;
+};
+
+export type ApiTokensResponse = {
+  requestId: string;
+  ok: boolean;
+  tokens: ApiTokenSummary[];
+  /** The secret of a token `api_token_create` just made; never shown again. */
+  secret?: string;
+  error?: string;
+};
+
+/**
+ * One recent action. `payload` is what the command was invoked with, so
+ * `history_repeat` can run it again.
+ */
+export type ActionHistoryEntry = {
+  id: string;
+  command: string;
+  /** Directory name of the worktree the action targeted. */
+  worktree: string;
+  ok: boolean;
+  error?: string;
+  recordedAt: string;
+  payload: unknown;
+};
+
+export type HistoryListPayload = {
+  limit?: number;
+  /** Only actions on this worktree. */
+  worktree?: string;
+};
+
+export type HistoryListResponse = {
+  requestId: string;
+  ok: boolean;
+  entries: ActionHistoryEntry[];
+  error?: string;
+};
+
+export type HistoryRepeatPayload = {
+  id: string;
+};
+
+export type HistoryRepeatResponse = {
+  requestId: string;
+  ok: boolean;
+  command?: string;
+  worktree?: string;
+  /** The replayed command's own response. */
+  result?: unknown;
+  error?: string;
+};
+
+/** A change `undo_last` can revert. */
+export type UndoEntry = {
+  id: string;
+  /** Command that made the change. */
+  command: string;
+  description: string;
+  /** `workspace`, `worktree` or `globalSettings`. */
+  scope: string;
+  workspaceRoot?: string;
+  worktree?: string;
+  /** JSON names of the fields that will be restored. */
+  fields: string[];
+  createdAt: string;
+};
+
+export type UndoListResponse = {
+  requestId: string;
+  ok: boolean;
+  /** Newest first; `undo_last` reverts the first one. */
+  entries: UndoEntry[];
+  error?: string;
+};
+
+export type UndoLastResponse = {
+  requestId: string;
+  ok: boolean;
+  undone?: UndoEntry;
+  remaining: number;
+  /** Set when a workspace or worktree change was reverted. */
+  workspaceMeta?: WorkspaceMeta;
+  /** Set when a global settings change was reverted. */
+  globalSettings?: GlobalSettings;
+  error?: string;
+};
+
+export type WorkspaceGitignoreSanityResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  isApplicable: boolean;
+  hasGrooveEntry: boolean;
+  hasWorkspaceEntry: boolean;
+  missingEntries: string[];
+  patched?: boolean;
+  patchedWorktree?: string;
+  playStarted?: boolean;
+  error?: string;
+};
+
+export type WorkspaceTermSanityResponse = {
+  requestId: string;
+  ok: boolean;
+  termValue?: string;
+  isUsable: boolean;
+  applied?: boolean;
+  fixedValue?: string;
+  error?: string;
+};
+
+export type GrooveListPayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  dir?: string;
+  /** Attach per-worktree CPU and memory to every row. */
+  includeResources?: boolean;
+  /** Attach status badges to every row. */
+  includeBadges?: boolean;
+  /** Trim and sort the rows before they are sent. */
+  filter?: GrooveListFilter;
+  /** Send one page of the (filtered) rows. */
+  page?: RowsPagePayload;
+};
+
+/** Narrows a groove list down; rows must pass every criterion that is set. */
+export type GrooveListFilter = {
+  /** Worktree states to keep; worktrees without a record are `pending`. */
+  status?: WorktreeState[];
+  /** Keep worktrees carrying this tag, in any case. */
+  tag?: string;
+  /** `logState` values to keep, e.g. `latest`. */
+  activity?: string[];
+  sort?: GrooveListSort;
+};
+
+export type GrooveListSort = "lastActivity" | "diffSize" | "name";
+
+export type GrooveRestorePayload = {
+  workspaceRoot?: string | null;
+  rootName?: string | null;
+  knownWorktrees: string[];
+  workspaceMeta?: WorkspaceMetaContext | null;
+  worktree: string;
+  action?: string | null;
+  target?: string | null;
+  dir?: string | null;
+  opencodeLogFile?: string | null;
+  /**
+   * Emit the sidecar's output as `groove-command-output` events while it
+   * runs.
+   */
+  stream: boolean;
+  debug?: boolean | null;
+};
+
+export type GrooveNewPayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  branch: string;
+  base?: string;
+  dir?: string;
+  /**
+   * `"branch"` (default), `"hashed"` or `"auto"` — see
+   * `WorktreeDirNameStrategy`.
+   */
+  dirNameStrategy?: string;
+  /**
+   * GitHub issue number to start work on. `branch` may then be empty to
+   * name the branch after the issue, and the issue is linked in the
+   * worktree record.
+   */
+  issue?: number;
+  /**
+   * Worktree to stack the new one on. Its branch is the default `base`,
+   * and `worktree_restack` and `gh_pr_create` follow the link.
+   */
+  parent?: string;
+  /**
+   * Emit the sidecar's output as `groove-command-output` events while it
+   * runs.
+   */
+  stream?: boolean;
+  debug?: boolean;
+};
+
+export type GrooveRmPayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  target: string;
+  worktree: string;
+  dir?: string;
+  /** Runs `git worktree remove --force` instead of the sidecar's `rm`. */
+  force?: boolean;
+  /**
+   * Safety categories (see `GROOVE_RM_SAFETY_CATEGORIES`) the caller
+   * accepts losing; removal is refused while the worktree has findings in
+   * any other.
+   */
+  acknowledge?: string[];
+  /**
+   * Emit the sidecar's output as `groove-command-output` events while it
+   * runs.
+   */
+  stream?: boolean;
+  debug?: boolean;
+};
+
+export type GrooveStopPayload = {
+  rootName?: string | null;
+  knownWorktrees: string[];
+  workspaceMeta?: WorkspaceMetaContext | null;
+  worktree: string;
+  instanceId?: string | null;
+  dir?: string | null;
+};
+
+/** Payload of `groove_bulk_rm`, `groove_bulk_stop` and `groove_bulk_restore`. */
+export type GrooveBulkPayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  worktrees: string[];
+  dir?: string;
+  /** Only read by `groove_bulk_rm`. */
+  force?: boolean;
+  /** Only read by `groove_bulk_rm`; applies to every worktree. */
+  acknowledge?: string[];
+  /** Only read by `groove_bulk_restore`. */
+  action?: string;
+};
+
+export type GrooveArchivePayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  worktree: string;
+  dir?: string;
+  /**
+   * Branch the diff is taken against; defaults to `origin/HEAD`, `main`
+   * or `master`, whichever exists.
+   */
+  base?: string;
+  /**
+   * Remove the worktree once archived. Its uncommitted changes and
+   * unpushed commits are in the archive, so only running processes and
+   * terminal sessions can still block the removal.
+   */
+  remove?: boolean;
+};
+
+export type GrooveArchiveListPayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+};
+
+export type GrooveUnarchivePayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  id: string;
+  /** Branch of the new worktree; defaults to the archived branch. */
+  branch?: string;
+  dir?: string;
+  /** Keep the archive after restoring it. */
+  keepArchive?: boolean;
+};
+
+export type WorktreeRestackPayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  /** The parent whose children are rebased. */
+  worktree: string;
+  dir?: string;
+  /** Also restack the children's own children, parents first. */
+  recursive?: boolean;
+  autostash?: boolean;
+};
+
+export type WorktreeActionPayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  worktree: string;
+  /** One of `WORKTREE_ACTIONS`. */
+  action: string;
+  /**
+   * Extra fields of the payload of the command the action runs, e.g.
+   * `rebase` for "pull" or `force` for "remove".
+   */
+  options?: Record<string, unknown>;
+  dir?: string;
+};
+
+export type WorkspaceOpenTerminalPayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  worktree?: string;
+};
+
+export type WorkspaceEventsPayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+};
+
+export type WorktreeWatchPayload = {
+  worktreePath: string;
+};
+
+export type WorkspaceTerminalSettingsPayload = {
+  defaultTerminal: string;
+  terminalCustomCommand?: string;
+  telemetryEnabled?: boolean;
+  disableGrooveBusiness?: boolean;
+  hideMascot?: boolean;
+  hideLabels?: boolean;
+  showFps?: boolean;
+};
+
+export type WorkspaceCommandSettingsPayload = {
+  playGrooveCommand: string;
+  openTerminalAtWorktreeCommand?: string;
+};
+
+export type PlayCommandPreviewPayload = {
+  /** The template to preview, validated as it would be when saved. */
+  playGrooveCommand: string;
+  /**
+   * Worktree of the active workspace to resolve it for; a sample one
+   * when omitted.
+   */
+  worktree?: string;
+  /** What Play passes as the target; the worktree's branch when omitted. */
+  target?: string;
+};
+
+export type WorkspaceMaxWorktreeCountPayload = {
+  maxWorktreeCount?: number;
+};
+
+export type WorkspaceStalenessPolicyPayload = {
+  /** `None` removes the policy. */
+  policy?: WorktreeStalenessPolicy;
+};
+
+export type WorktreeStalenessReportPayload = {
+  /** Classify only; an "archive" policy forgets nothing. */
+  dryRun?: boolean;
+};
+
+export type WorkspaceFreezePayload = {
+  /** Defaults to true: terminal history is packed into tarballs until thaw. */
+  compressArtifacts?: boolean;
+};
+
+export type WorkspaceThawPayload = {
+  /**
+   * Reopen the recorded terminal sessions and Play targets. Otherwise the
+   * session layouts are put back for `worktree_session_layout_restore`.
+   */
+  restartSessions?: boolean;
+};
+
+export type WorkspaceConflictMatrixPayload = {
+  /** Worktrees to compare; empty compares every worktree on disk. */
+  worktrees?: string[];
+};
+
+export type MergeTrainStartPayload = {
+  /** Worktrees to merge, in merge order. */
+  worktrees: string[];
+  /**
+   * Branch the pull requests are merged into; defaults to the repository's
+   * default branch.
+   */
+  base?: string;
+  /** `merge` (default), `squash` or `rebase`, as for `gh_pr_merge`. */
+  strategy?: string;
+  /** Leave the worktrees and their remote branches in place once merged. */
+  keepWorktrees?: boolean;
+  dir?: string;
+};
+
+export type MergeTrainResumePayload = {
+  /** Skip the step the train paused on instead of running it again. */
+  skip?: boolean;
+};
+
+/** A schedule change for one maintenance task; unset fields are kept. */
+export type MaintenanceTaskSchedulePayload = {
+  task: string;
+  enabled?: boolean;
+  /** Local time of day as "HH:MM". */
+  at?: string;
+};
+
+export type MaintenanceConfigurePayload = {
+  /**
+   * Minutes the local time the schedules are given in is ahead of UTC,
+   * e.g. the negated `Date.getTimezoneOffset()`.
+   */
+  utcOffsetMinutes?: number;
+  tasks?: MaintenanceTaskSchedulePayload[];
+};
+
+export type MaintenanceRunNowPayload = {
+  /** Tasks to run, in order; every task when empty. */
+  tasks?: string[];
+};
+
+export type WorkspaceGitIdentityPayload = {
+  /** `None` (or all fields blank) removes the overrides. */
+  gitIdentity?: WorkspaceGitIdentity;
+};
+
+export type WorkspaceCommitTemplatePayload = {
+  /** `None` (or an empty template) removes it. */
+  commitTemplate?: WorkspaceCommitTemplate;
+};
+
+export type WorkspaceCommitLintPayload = {
+  /** `None` turns commit message linting off. */
+  commitLint?: WorkspaceCommitLintRules;
+};
+
+export type WorkspaceRequireSignOffPayload = {
+  requireSignOff: boolean;
+};
+
+export type WorkspaceAdoptWorktreePayload = {
+  /** An existing `git worktree` of the active workspace's repository. */
+  path: string;
+  /**
+   * Move it under `.worktrees/` with `git worktree move` instead of
+   * leaving it where it is.
+   */
+  relocate?: boolean;
+};
+
+export type WorkspaceMigratePayload = {
+  /** Report what would be adopted without changing anything. */
+  dryRun?: boolean;
+  /**
+   * Move adopted worktrees under `.worktrees/` instead of leaving them
+   * where they are.
+   */
+  relocate?: boolean;
+};
+
+export type WorkspaceTrustRecord = {
+  workspaceRoot: string;
+  trusted: boolean;
+  decidedAt: string;
+};
+
+export type WorkspaceTrustPayload = {
+  trusted: boolean;
+};
+
+export type WorkspaceTrustResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  trusted: boolean;
+  error?: string;
+};
+
+export type CustomCommandApprovePayload = {
+  /** The command as sent in `custom-command-confirmation`. */
+  command: string;
+};
+
+export type CustomCommandApproveResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  error?: string;
+};
+
+export type WorkspaceCustomCommandsPayload = {
+  /** Replaces the whole list; commands without an id are new. */
+  customCommands?: WorkspaceCustomCommand[];
+};
+
+export type WorkspaceCustomCommandRunPayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  worktree: string;
+  id: string;
+  cols?: number;
+  rows?: number;
+};
+
+export type WorkspaceRemotePayload = {
+  /** `None` turns the workspace back into a local one. */
+  remote?: WorkspaceRemote;
+};
+
+export type WorkspaceRemoteGitStatusPayload = {
+  worktree: string;
+};
+
+export type WorkspaceRemoteProbeResponse = {
+  requestId: string;
+  ok: boolean;
+  destination?: string;
+  gitVersion?: string;
+  rootIsRepository: boolean;
+  error?: string;
+};
+
+export type RemoteWorktreeRow = {
+  worktree: string;
+  branch?: string;
+  path: string;
+};
+
+export type WorkspaceRemoteWorktreesResponse = {
+  requestId: string;
+  ok: boolean;
+  destination?: string;
+  worktrees: RemoteWorktreeRow[];
+  error?: string;
+};
+
+export type GrooveTmuxKillWindowPayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  worktree: string;
+};
+
+export type GrooveTmuxWindow = {
+  index: number;
+  name: string;
+  path: string;
+  active: boolean;
+};
+
+export type GrooveTmuxWindowsResponse = {
+  requestId: string;
+  ok: boolean;
+  session?: string;
+  /** Whether a tmux client is currently attached to the session. */
+  attached: boolean;
+  windows: GrooveTmuxWindow[];
+  error?: string;
+};
+
+export type GrooveZellijSession = {
+  worktree: string;
+  session: string;
+  /** Exited sessions can be resurrected by attaching to them. */
+  exited: boolean;
+};
+
+export type GrooveZellijSessionsResponse = {
+  requestId: string;
+  ok: boolean;
+  sessions: GrooveZellijSession[];
+  error?: string;
+};
+
+export type WorkspaceMaxWorktreeCountResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  workspaceMeta?: WorkspaceMeta;
+  /** Worktrees auto-removed to bring the count down to the new limit. */
+  evictedWorktrees?: string[];
+  error?: string;
+};
+
+export type WorktreeStorageStatsPayload = {
+  /**
+   * Disk-size calculation walks every file in each worktree (`du`), which is
+   * expensive for worktrees with large `node_modules`. Off by default so the
+   * panel can show counts instantly; opted into on demand.
+   */
+  includeSizes?: boolean;
+};
+
+export type WorktreeStorageRow = {
+  worktree: string;
+  path: string;
+  /** Only meaningful when the response's `sizesIncluded` is true; otherwise 0. */
+  bytes: number;
+  lastExecutedAt?: string;
+};
+
+export type WorktreeStorageStatsResponse = {
+  requestId: string;
+  ok: boolean;
+  totalCount: number;
+  totalBytes: number;
+  /** Whether disk sizes were computed for this response. */
+  sizesIncluded: boolean;
+  maxWorktreeCount?: number;
+  worktrees: WorktreeStorageRow[];
+  workspaceRoot?: string;
+  error?: string;
+};
+
+export type WorkspaceMarkOnboardingPayload = {
+  symlinksConfigured?: boolean;
+  commandsConfigured?: boolean;
+};
+
+export type WorkspaceRootDirectoryPayload = {
+  rootDirectory?: string;
+};
+
+export type WorkspaceWorktreeSymlinkPathsPayload = {
+  worktreeSymlinkPaths?: string[];
+};
+
+export type SetWorktreeStatePayload = {
+  worktree: string;
+  state: WorktreeState;
+};
+
+export type SetWorktreePlayCommandPayload = {
+  worktree: string;
+  /** `None` (or blank) clears the override. */
+  playGrooveCommand?: string;
+};
+
+export type SetWorktreeLabelsPayload = {
+  worktree: string;
+  /** `None` (or blank) clears the note. */
+  note?: string;
+  /** Replaces the worktree's tags. */
+  tags?: string[];
+};
+
+export type OpencodeSettingsUpdatePayload = {
+  enabled: boolean;
+  defaultModel?: string;
+  settingsDirectory?: string;
+};
+
+export type OpencodeCopySkillsPayload = {
+  globalSkillsPath: string;
+  workspaceSkillsPath: string;
+  globalToWorkspace?: string[];
+  workspaceToGlobal?: string[];
+};
+
+export type WorkspaceBrowseEntriesPayload = {
+  relativePath?: string;
+};
+
+export type GlobalSettingsUpdatePayload = {
+  telemetryEnabled?: boolean;
+  disableGrooveBusiness?: boolean;
+  hideMascot?: boolean;
+  hideLabels?: boolean;
+  showFps?: boolean;
+  alwaysShowDiagnosticsSidebar?: boolean;
+  periodicRerenderEnabled?: boolean;
+  themeMode?: string;
+  keyboardShortcutLeader?: string;
+  keyboardLeaderBindings?: Record<string, string>;
+  opencodeSettings?: OpencodeSettingsUpdatePayload;
+  soundLibrary?: SoundLibraryEntry[];
+  claudeCodeSoundSettings?: ClaudeCodeSoundSettings;
+  grooveSoundSettings?: GrooveSoundSettings;
+  terminalMemorySettings?: TerminalMemorySettings;
+  systemAlertSettings?: SystemAlertSettings;
+  telemetryPathRedaction?: string;
+  customCommandPolicy?: CustomCommandPolicy;
+  /** "auto" clears it. */
+  defaultTerminal?: string;
+  pollingSettings?: PollingSettings;
+  testingEnvironmentRestartPolicy?: TestingEnvironmentRestartPolicy;
+  statusPageSettings?: StatusPageSettings;
+  commandRetryPolicy?: CommandRetryPolicy;
+};
+
+export type SettingsProfileSavePayload = {
+  name: string;
+  /** Captured from the current global settings when absent. */
+  overrides?: SettingsProfileOverrides;
+};
+
+/**
+ * Payload of `settings_profile_switch`, `settings_profile_delete` and
+ * `settings_profile_export`.
+ */
+export type SettingsProfileNamePayload = {
+  name: string;
+};
+
+export type SettingsProfileImportPayload = {
+  /** A document written by `settings_profile_export`. */
+  contents: string;
+  /** Saves the profile under this name instead of the exported one. */
+  name?: string;
+};
+
+export type SettingsSyncConfigurePayload = {
+  /** "off", "file" or "gist". */
+  kind: string;
+  path?: string;
+  /**
+   * An existing gist to sync with; a private one is created on the first
+   * export when absent.
+   */
+  gistId?: string;
+  includeWorkspacePrefs?: boolean;
+};
+
+/**
+ * Payload of `settings_sync_export`, `settings_sync_import` and
+ * `settings_sync_merge`.
+ */
+export type SettingsSyncRunPayload = {
+  /**
+   * Export or import even though the other side changed since the last
+   * sync.
+   */
+  force?: boolean;
+  /**
+   * How a merge settles fields changed on both sides: "local" or
+   * "remote". Conflicts are reported and nothing is written when absent.
+   */
+  prefer?: string;
+};
+
+export type AppBackupCreatePayload = {
+  /** Absolute, or starting with `~/`. */
+  path: string;
+};
+
+export type AppBackupRestorePayload = {
+  path: string;
+  /**
+   * Also write each bundled `.groove/workspace.json` back into its
+   * workspace. Defaults to true.
+   */
+  includeWorkspaces?: boolean;
+};
+
+export type SoundLibraryRemovePayload = {
+  soundId: string;
+};
+
+export type SoundLibraryReadPayload = {
+  fileName: string;
+};
+
+export type SoundLibraryRenamePayload = {
+  soundId: string;
+  newName: string;
+};
+
+export type SoundLibraryGetPathPayload = {
+  soundId: string;
+};
+
+export type SoundLibraryPathResponse = {
+  requestId: string;
+  ok: boolean;
+  folderPath?: string;
+  filePath?: string;
+  error?: string;
+};
+
+export type SoundLibraryReadResponse = {
+  requestId: string;
+  ok: boolean;
+  data?: string;
+  error?: string;
+};
+
+export type GitAuthStatusPayload = {
+  workspaceRoot: string;
+};
+
+export type GhSwitchPayload = {
+  user: string;
+  debug?: boolean;
+};
+
+export type GhLogoutPayload = {
+  user: string;
+  debug?: boolean;
+};
+
+export type GhLoginPayload = {
+  token: string;
+  debug?: boolean;
+};
+
+export type GitPathPayload = {
+  path: string;
+  /** Honored by the commands answering with a `GitCommandResponse`. */
+  debug?: boolean;
+};
+
+export type GitPullPayload = {
+  path: string;
+  rebase: boolean;
+  debug?: boolean | null;
+};
+
+export type GitFetchBlobsPayload = {
+  path: string;
+  /** Ref the worktree is compared against; defaults to its upstream. */
+  base?: string;
+};
+
+export type GitPushPayload = {
+  path: string;
+  setUpstream: boolean;
+  forceWithLease: boolean;
+  branch?: string | null;
+  debug?: boolean | null;
+};
+
+export type GitMergePayload = {
+  path: string;
+  targetBranch: string;
+  ffOnly: boolean;
+  debug?: boolean | null;
+};
+
+export type GitCommitPayload = {
+  path: string;
+  message?: string | null;
+  /** Skip `pre-commit` and `commit-msg` hooks (`git commit --no-verify`). */
+  noVerify: boolean;
+};
+
+export type GitLintCommitMessagePayload = {
+  path: string;
+  message: string;
+};
+
+export type GitFilesPayload = {
+  path: string;
+  files: string[];
+  debug?: boolean;
+};
+
+export type GitDiffFilePayload = {
+  path: string;
+  /**
+   * The file to diff, relative to the worktree. For a rename either the
+   * old or the new path finds it.
+   */
+  filePath: string;
+  /**
+   * Diff the index against `HEAD` instead of the working tree against
+   * the index.
+   */
+  staged?: boolean;
+  /** Unchanged lines around each change; 3 when omitted. */
+  contextLines?: number;
+};
+
+export type GitDiffHunksPayload = {
+  path: string;
+  /**
+   * List the staged hunks (index against `HEAD`) instead of the unstaged
+   * ones (working tree against the index).
+   */
+  staged?: boolean;
+};
+
+/** A hunk returned by `git_diff_hunks`, echoed back to stage or unstage it. */
+export type GitHunkSelection = {
+  filePath: string;
+  oldStart: number;
+  oldLines: number;
+  newStart: number;
+  newLines: number;
+};
+
+export type GitHunksPayload = {
+  path: string;
+  hunks: GitHunkSelection[];
+  debug?: boolean;
+};
+
+export type GitRebaseStartPayload = {
+  path: string;
+  /** Branch or commit to replay the worktree's branch onto. */
+  onto: string;
+  /** Stash local changes before the rebase and reapply them after. */
+  autostash: boolean;
+};
+
+export type GitConflictResolvePayload = {
+  path: string;
+  filePath: string;
+  /**
+   * `ours`, `theirs` or `base` to take that side as is, `content` to
+   * write `content`, or `delete` to resolve by removing the file.
+   */
+  resolution: string;
+  /** Kept out of the action history. */
+  content?: string | null;
+};
+
+export type GitLogPayload = {
+  path: string;
+  offset?: number;
+  limit?: number;
+  /** Branch or other ref to list; the worktree's `HEAD` when omitted. */
+  branch?: string;
+  /** Matched like `git log --author`, against name and email. */
+  author?: string;
+  /** Only commits touching this path, relative to the worktree. */
+  filePath?: string;
+  /**
+   * Any date `git log --since`/`--until` accepts, e.g. `2024-05-01` or
+   * `2 weeks ago`.
+   */
+  since?: string;
+  until?: string;
+};
+
+export type GrooveTerminalOpenPayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  worktree: string;
+  target?: string;
+  openMode?: string;
+  cols?: number;
+  rows?: number;
+  forceRestart?: boolean;
+  openNew?: boolean;
+};
+
+export type WorktreeSessionLayoutRestorePayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  worktree: string;
+  cols?: number;
+  rows?: number;
+};
+
+export type GrooveTerminalWritePayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  worktree: string;
+  sessionId?: string;
+  input: string;
+};
+
+export type GrooveTerminalResizePayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  worktree: string;
+  sessionId?: string;
+  cols: number;
+  rows: number;
+};
+
+export type GrooveTerminalClosePayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  worktree: string;
+  sessionId?: string;
+};
+
+export type GrooveTerminalSessionPayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  worktree: string;
+  sessionId?: string;
+};
+
+export type GrooveTerminalScrollbackPayload = {
+  sessionId: string;
+  /**
+   * Output offset to read from; defaults to the chunk just before the
+   * in-memory snapshot.
+   */
+  offset?: number;
+  limit?: number;
+};
+
+export type GrooveTerminalHistoryPayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  worktree: string;
+  /** Defaults to the most recently written history of the worktree. */
+  sessionId?: string;
+  maxBytes?: number;
+};
+
+export type GrooveTerminalAttachPayload = {
+  sessionId: string;
+  /**
+   * `offset` of the last output event the terminal view saw; without it
+   * only the in-memory snapshot is replayed.
+   */
+  offset?: number;
+};
+
+export type GrooveTerminalScrollbackRecordingPayload = {
+  sessionId: string;
+  recording: boolean;
+};
+
+export type RuntimeStateRow = {
+  branch: string;
+  worktree: string;
+  logState: string;
+  logTarget?: string;
+  /** Only filled in when the list was requested with `includeResources`. */
+  resources?: WorktreeResourceUsage;
+  /** Only filled in when the list was requested with `includeBadges`. */
+  badges?: WorktreeBadge[];
+  /** Worktree this one is stacked on. */
+  parent?: string;
+  /** Worktrees stacked on this one, by name. */
+  children?: string[];
+};
+
+/** A status badge of a groove list row, such as `ahead 3` or `PR #42`. */
+export type WorktreeBadge = {
+  /** `running`, `failing`, `dirty`, `ahead`, `behind` or `pr`. */
+  kind: string;
+  label: string;
+};
+
+export type WorktreeResourceUsage = {
+  /** Summed over processes, so it can pass 100 on multi-core machines. */
+  cpuPercent: number;
+  rssBytes: number;
+  processCount: number;
+};
+
+export type GrooveListResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  rows: Record<string, RuntimeStateRow>;
+  /**
+   * Worktrees in `filter.sort` order, when the list was sorted; the
+   * page's worktrees in order, when it was paged.
+   */
+  order?: string[];
+  /** The filter applied to `rows`, as requested. */
+  filter?: GrooveListFilter;
+  /** Set when only one page of `rows` was asked for. */
+  page?: RowsPageInfo;
+  stdout: string;
+  stderr: string;
+  cancelled?: boolean;
+  error?: string;
+};
+
+export type GrooveCommandResponse = {
+  requestId: string;
+  ok: boolean;
+  exitCode?: number | null;
+  stdout: string;
+  stderr: string;
+  error?: string;
+  /** Subprocesses the command ran, when it was called with `debug: true`. */
+  debugTranscripts?: SubprocessTranscript[];
+};
+
+/** What removing a worktree would throw away or interrupt. */
+export type GrooveRmSafetyScan = {
+  /**
+   * Paths `git status` lists. `None` when it failed, which blocks removal
+   * like uncommitted changes do.
+   */
+  uncommittedChanges?: number | null;
+  /**
+   * Commits on `HEAD` that no remote branch contains. `None` when the
+   * repository has no remotes, where nothing can be pushed.
+   */
+  unpushedCommits?: number | null;
+  /** Processes working in the worktree, terminal shells excluded. */
+  runningPids: number[];
+  terminalSessions: number;
+  /** Categories with findings, in `GROOVE_RM_SAFETY_CATEGORIES` order. */
+  blocking: string[];
+};
+
+export type GrooveRmResponse = {
+  requestId: string;
+  ok: boolean;
+  exitCode?: number | null;
+  stdout: string;
+  stderr: string;
+  /** Set when removal was refused over unacknowledged categories. */
+  safety?: GrooveRmSafetyScan;
+  error?: string;
+  /** Subprocesses the command ran, when it was called with `debug: true`. */
+  debugTranscripts?: SubprocessTranscript[];
+};
+
+export type GrooveRmSafetyResponse = {
+  requestId: string;
+  ok: boolean;
+  safety?: GrooveRmSafetyScan;
+  error?: string;
+};
+
+/**
+ * What an archive under `.groove/archives` holds, written next to the
+ * tarball as `<id>.json` and inside it as `archive.json`.
+ */
+export type GrooveArchiveMetadata = {
+  version: number;
+  id: string;
+  worktree: string;
+  branch?: string;
+  baseRef: string;
+  /** Merge base of the worktree and `base_ref`; the diff applies on it. */
+  baseCommit: string;
+  headCommit?: string;
+  archivedAt: string;
+  untrackedFiles: number;
+  hasLogs: boolean;
+  /** Size of the tarball; 0 inside it. */
+  bytes: number;
+};
+
+/** Response of `groove_archive` and `groove_unarchive`. */
+export type GrooveArchiveResponse = {
+  requestId: string;
+  ok: boolean;
+  archive?: GrooveArchiveMetadata;
+  /** The worktree archived, or the one created by an unarchive. */
+  worktree?: string;
+  error?: string;
+};
+
+export type GrooveArchiveListResponse = {
+  requestId: string;
+  ok: boolean;
+  /** Newest first. */
+  archives: GrooveArchiveMetadata[];
+  error?: string;
+};
+
+/** How rebasing one stacked worktree onto its parent went. */
+export type WorktreeRestackResult = {
+  worktree: string;
+  /** Branch of the parent it was rebased onto. */
+  onto: string;
+  ok: boolean;
+  /**
+   * Set when the rebase stopped on conflicts; it is left in progress for
+   * `git_rebase_continue` or `git_rebase_abort`.
+   */
+  conflictedFiles?: string[];
+  error?: string;
+};
+
+export type WorktreeRestackResponse = {
+  requestId: string;
+  ok: boolean;
+  worktree?: string;
+  /** In rebase order. Children of a worktree that failed are skipped. */
+  results: WorktreeRestackResult[];
+  error?: string;
+};
+
+export type WorktreeActionResponse = {
+  requestId: string;
+  ok: boolean;
+  action?: string;
+  worktree?: string;
+  /** The response of the command the action ran. */
+  result?: unknown;
+  error?: string;
+};
+
+export type WorktreePathIssue = {
+  kind: string;
+  message: string;
+};
+
+export type GrooveNewResponse = {
+  requestId: string;
+  ok: boolean;
+  exitCode?: number | null;
+  stdout: string;
+  stderr: string;
+  worktree?: string;
+  preflightIssues?: WorktreePathIssue[];
+  error?: string;
+  /** Subprocesses the command ran, when it was called with `debug: true`. */
+  debugTranscripts?: SubprocessTranscript[];
+};
+
+export type ExternalUrlOpenResponse = {
+  requestId: string;
+  ok: boolean;
+  error?: string;
+};
+
+export type WorkspaceOpenDirectoryResponse = {
+  requestId: string;
+  ok: boolean;
+  error?: string;
+};
+
+export type GrooveStopResponse = {
+  requestId: string;
+  ok: boolean;
+  alreadyStopped?: boolean;
+  pid?: number;
+  source?: string;
+  error?: string;
+};
+
+export type GrooveBulkResult = {
+  worktree: string;
+  ok: boolean;
+  error?: string;
+};
+
+/**
+ * `ok` is false when any worktree failed; `results` has one entry per
+ * requested worktree, in order.
+ */
+export type GrooveBulkResponse = {
+  requestId: string;
+  ok: boolean;
+  results: GrooveBulkResult[];
+  succeeded: number;
+  failed: number;
+  error?: string;
+};
+
+export type GrooveRecoverableListResponse = {
+  requestId: string;
+  ok: boolean;
+  grooves: RunningGrooveRecord[];
+  error?: string;
+};
+
+export type GrooveRecoverableClearPayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  /** Worktrees to clear; an empty list clears every running record for the workspace. */
+  worktrees?: string[];
+};
+
+export type GrooveRecoverableClearResponse = {
+  requestId: string;
+  ok: boolean;
+  error?: string;
+};
+
+export type GrooveSummaryPayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  sessionIds: string[];
+};
+
+export type DiscoverWorktreeUnitPayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  worktree: string;
+  sessionId: string;
+};
+
+export type ClaimWorktreeRewardPayload = {
+  worktree: string;
+};
+
+export type ClaimWorktreeRewardResponse = {
+  requestId: string;
+  ok: boolean;
+  unit?: WorktreeUnit;
+  gold?: number;
+  error?: string;
+};
+
+export type LootWorktreePayload = {
+  worktree: string;
+};
+
+export type LootWorktreeResponse = {
+  requestId: string;
+  ok: boolean;
+  unit?: WorktreeUnit;
+  loot?: WorktreeLootEntry[];
+  inventory?: Record<string, number>;
+  error?: string;
+};
+
+export type DiscoverWorktreeUnitResponse = {
+  requestId: string;
+  ok: boolean;
+  unit?: WorktreeUnit;
+  level?: number;
+  rawClaudeOutput?: string;
+  wasNewDiscovery?: boolean;
+  error?: string;
+};
+
+export type GrooveSummaryResponse = {
+  requestId: string;
+  ok: boolean;
+  summaries?: GrooveSummaryEntry[];
+  compiledSummary?: string;
+  error?: string;
+};
+
+export type GrooveCommentPayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  worktree: string;
+  /**
+   * When true, also feed the Claude conversation since the last commit into
+   * the drafting prompt (the Changes-panel "Draft commit comment" button).
+   */
+  includeSession?: boolean;
+};
+
+export type GrooveCommentResponse = {
+  requestId: string;
+  ok: boolean;
+  comment?: CommentRecord;
+  error?: string;
+};
+
+export type GrooveCommentMarkCommittedPayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  worktree: string;
+  createdAt: string;
+};
+
+export type GrooveCommentMarkCommittedResponse = {
+  requestId: string;
+  ok: boolean;
+  comment?: CommentRecord;
+  error?: string;
+};
+
+export type GroovePrAttachPayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  worktree: string;
+  url: string;
+  title?: string;
+  base?: string;
+  head?: string;
+};
+
+export type GroovePrDetachPayload = {
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+  worktree: string;
+  url: string;
+};
+
+export type GroovePrResponse = {
+  requestId: string;
+  ok: boolean;
+  pullRequest?: PullRequestRecord;
+  error?: string;
+};
+
+export type GrooveSummaryEntry = {
+  sessionId: string;
+  worktree?: string;
+  ok: boolean;
+  summary?: string;
+  error?: string;
+};
+
+export type BackgroundActivityResponse = {
+  requestId: string;
+  ok: boolean;
+  paused: boolean;
+  pausedSince?: string;
+  /** False when background activity already was in the requested state. */
+  changed: boolean;
+  error?: string;
+};
+
+export type WorkspaceEventsResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  error?: string;
+};
+
+/**
+ * Shared by `worktree_watch` and `worktree_unwatch`; `worktree_path` is the
+ * worktree watched after the call, if any.
+ */
+export type WorktreeWatchResponse = {
+  requestId: string;
+  ok: boolean;
+  worktreePath?: string;
+  error?: string;
+};
+
+export type GrooveTerminalSession = {
+  sessionId: string;
+  workspaceRoot: string;
+  worktree: string;
+  worktreePath: string;
+  command: string;
+  startedAt: string;
+  cols: number;
+  rows: number;
+  snapshot?: string;
+  /** The shell was stopped while idle and starts again on the next write. */
+  hibernated?: boolean;
+  /** Process id of the shell; absent while hibernated. */
+  pid?: number;
+};
+
+export type GrooveTerminalResponse = {
+  requestId: string;
+  ok: boolean;
+  session?: GrooveTerminalSession;
+  error?: string;
+};
+
+/** Offsets count bytes of session output since it started. */
+export type GrooveTerminalScrollbackResponse = {
+  requestId: string;
+  ok: boolean;
+  sessionId: string;
+  data: string;
+  /** Offset of the first byte of `data`. */
+  offset: number;
+  /** Oldest offset still available on disk. */
+  startOffset: number;
+  /** Offset where the in-memory snapshot begins. */
+  snapshotOffset: number;
+  /** Whether older scrollback than `data` is available. */
+  hasMore: boolean;
+  recording: boolean;
+  error?: string;
+};
+
+export type GrooveTerminalHistoryResponse = {
+  requestId: string;
+  ok: boolean;
+  /** Session the history belongs to; `None` when the worktree has none. */
+  sessionId?: string;
+  /** The last `max_bytes` of its output. */
+  data: string;
+  /** Whether older output than `data` was left out. */
+  truncated: boolean;
+  error?: string;
+};
+
+/** One terminal output file on disk. */
+export type TerminalStorageSessionUsage = {
+  sessionId: string;
+  /** `history`, `scrollback` or `recording`. */
+  kind: string;
+  bytes: number;
+  modifiedAt?: string;
+  /** An open session still writes to it, so it is never cleaned up. */
+  live: boolean;
+  /**
+   * Set for scrollback files of open sessions; closed sessions' scrollback
+   * is not tied to a worktree anymore.
+   */
+  worktree?: string;
+};
+
+export type TerminalStorageWorktreeUsage = {
+  worktree: string;
+  bytes: number;
+  sessions: TerminalStorageSessionUsage[];
+};
+
+export type TerminalStorageStatsResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  quotaBytes: number;
+  maxAgeDays: number;
+  /** Terminal history of the workspace, counted against `quota_bytes`. */
+  historyBytes: number;
+  worktrees: TerminalStorageWorktreeUsage[];
+  /**
+   * Scrollback files of all workspaces, counted against `quota_bytes` on
+   * their own.
+   */
+  scrollbackBytes: number;
+  scrollback: TerminalStorageSessionUsage[];
+  error?: string;
+};
+
+/**
+ * Output missed since the payload offset, followed by live
+ * `groove-terminal-output` events; events whose `offset` is at most
+ * `end_offset` are already part of `data`.
+ */
+export type GrooveTerminalAttachResponse = {
+  requestId: string;
+  ok: boolean;
+  session?: GrooveTerminalSession;
+  data: string;
+  /** Offset of the first byte of `data`. */
+  offset: number;
+  /** Offset right after the last byte of `data`. */
+  endOffset: number;
+  /**
+   * Whether part of the missed output is no longer available, so `data`
+   * starts later than requested.
+   */
+  truncated: boolean;
+  error?: string;
+};
+
+export type GrooveTerminalSessionsResponse = {
+  requestId: string;
+  ok: boolean;
+  sessions: GrooveTerminalSession[];
+  error?: string;
+};
+
+export type WorktreeSessionLayoutRestoreResponse = {
+  requestId: string;
+  ok: boolean;
+  sessions: GrooveTerminalSession[];
+  failures?: string[];
+  error?: string;
+};
+
+export type GrooveTerminalActiveWorktreesResponse = {
+  requestId: string;
+  ok: boolean;
+  worktrees: string[];
+  error?: string;
+};
+
+export type GrooveTerminalActivityEntry = {
+  sessionId: string;
+  hasActivity: boolean;
+};
+
+export type GrooveTerminalActivityResponse = {
+  requestId: string;
+  ok: boolean;
+  entries: GrooveTerminalActivityEntry[];
+  error?: string;
+};
+
+export type WorkspaceTerminalSettingsResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  workspaceMeta?: WorkspaceMeta;
+  error?: string;
+};
+
+/** What Play would run for a worktree, without running it. */
+export type PlayCommandPreviewResponse = {
+  requestId: string;
+  ok: boolean;
+  /**
+   * `command`, or `groove-terminal` and `tmux` for the templates that
+   * open inside Groove and have no argv.
+   */
+  mode?: string;
+  /** Program first, one entry per argument as the process gets it. */
+  argv: string[];
+  worktreePath?: string;
+  branch?: string;
+  /**
+   * Whether the target was appended because the template has no
+   * placeholder.
+   */
+  targetAppended: boolean;
+  error?: string;
+};
+
+export type SetWorktreeStateResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  worktree?: string;
+  record?: WorktreeRecord;
+  error?: string;
+};
+
+export type WorkspaceAdoptWorktreeResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  worktree?: string;
+  /** Where the worktree lives now; under `.worktrees/` when relocated. */
+  path?: string;
+  relocated: boolean;
+  record?: WorktreeRecord;
+  error?: string;
+};
+
+export type WorkspaceMigrationEntry = {
+  /** Where the worktree was found. */
+  path: string;
+  branch?: string;
+  /** Name the worktree has (or would have) in Groove. */
+  worktree?: string;
+  /**
+   * `adopted` or `relocated` (`adopt` or `relocate` in a dry run),
+   * `alreadyManaged`, `skipped` when the directory is gone, or `failed`.
+   */
+  action: string;
+  reason?: string;
+};
+
+export type WorkspaceMigrateResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  /**
+   * `none`, `groove`, `bareRepository`, `siblingDirectory`, `nested` or
+   * `mixed`.
+   */
+  layout?: string;
+  dryRun: boolean;
+  /** One entry per linked worktree of the repository. */
+  entries: WorkspaceMigrationEntry[];
+  /** Worktrees adopted by this run. */
+  migrated: number;
+  error?: string;
+};
+
+export type WorkspaceBrowseEntry = {
+  name: string;
+  path: string;
+  isDir: boolean;
+};
+
+export type WorkspaceBrowseEntriesResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  relativePath: string;
+  entries: WorkspaceBrowseEntry[];
+  error?: string;
+};
+
+export type SoundLibraryEntry = {
+  id: string;
+  name: string;
+  fileName: string;
+};
+
+export type ClaudeCodeHookSoundEntry = {
+  enabled: boolean;
+  soundId?: string;
+};
+
+export type ClaudeCodeSoundSettings = {
+  notification: ClaudeCodeHookSoundEntry;
+  stop: ClaudeCodeHookSoundEntry;
+};
+
+export type GrooveSoundHookEntry = {
+  enabled: boolean;
+  soundId?: string;
+};
+
+export type GrooveSoundSettings = {
+  play: GrooveSoundHookEntry;
+  pause: GrooveSoundHookEntry;
+  summaryStart: GrooveSoundHookEntry;
+  summaryEnd: GrooveSoundHookEntry;
+  emergency: GrooveSoundHookEntry;
+  remove: GrooveSoundHookEntry;
+};
+
+export type GlobalSettings = {
+  telemetryEnabled: boolean;
+  disableGrooveBusiness: boolean;
+  hideMascot: boolean;
+  hideLabels: boolean;
+  showFps: boolean;
+  alwaysShowDiagnosticsSidebar: boolean;
+  periodicRerenderEnabled: boolean;
+  themeMode: string;
+  keyboardShortcutLeader: string;
+  keyboardLeaderBindings: Record<string, string>;
+  opencodeSettings: OpencodeSettings;
+  soundLibrary: SoundLibraryEntry[];
+  claudeCodeSoundSettings: ClaudeCodeSoundSettings;
+  grooveSoundSettings: GrooveSoundSettings;
+  terminalMemorySettings: TerminalMemorySettings;
+  systemAlertSettings: SystemAlertSettings;
+  /** How paths are written in logs: "off", "basename" or "hash". */
+  telemetryPathRedaction: string;
+  customCommandPolicy: CustomCommandPolicy;
+  /** Terminal opened in workspaces whose default terminal is "auto". */
+  defaultTerminal?: string;
+  pollingSettings: PollingSettings;
+  testingEnvironmentRestartPolicy: TestingEnvironmentRestartPolicy;
+  statusPageSettings: StatusPageSettings;
+  commandRetryPolicy: CommandRetryPolicy;
+  /**
+   * Stamped by `write_global_settings_file`; settings sync compares it to
+   * tell whether the settings changed since the last sync.
+   */
+  updatedAt?: string;
+};
+
+/** Pauses between passes of the background poll loops. */
+export type PollingSettings = {
+  /** The events worker of each open workspace. */
+  workspaceEventsIntervalMs: number;
+  /** The `worktree_watch` worker. */
+  worktreeWatchIntervalMs: number;
+};
+
+/**
+ * What a settings profile puts over the global settings when switched to;
+ * unset fields are left as they are.
+ */
+export type SettingsProfileOverrides = {
+  telemetryEnabled?: boolean;
+  themeMode?: string;
+  /** "auto" clears the global default terminal. */
+  defaultTerminal?: string;
+  pollingSettings?: PollingSettings;
+};
+
+export type SettingsProfile = {
+  name: string;
+  overrides: SettingsProfileOverrides;
+  updatedAt: string;
+};
+
+/** `settings-profiles.json` in the app data directory. */
+export type PersistedSettingsProfiles = {
+  /** The profile last switched to. */
+  activeProfile?: string;
+  profiles: SettingsProfile[];
+};
+
+/**
+ * The document `settings_profile_export` produces and
+ * `settings_profile_import` reads, meant to be kept with dotfiles.
+ */
+export type ExportedSettingsProfile = {
+  grooveSettingsProfileVersion: number;
+  name: string;
+  overrides: SettingsProfileOverrides;
+};
+
+/** Where settings sync reads and writes its document. */
+export type SettingsSyncTarget = {
+  /** "off", "file" or "gist". */
+  kind: string;
+  path?: string;
+  /** Set by the first export when not given. */
+  gistId?: string;
+  /** Also sync the active workspace's preferences. */
+  includeWorkspacePrefs: boolean;
+};
+
+/** `settings-sync.json` in the app data directory. */
+export type PersistedSettingsSync = {
+  target: SettingsSyncTarget;
+  /** `updatedAt` of the document as of the last sync. */
+  remoteUpdatedAt?: string;
+  /** `updatedAt` of the global settings right after the last sync. */
+  localUpdatedAt?: string;
+  /** The document as of the last sync, the common base of a merge. */
+  base?: SettingsSyncDocument;
+};
+
+/** What settings sync writes to the file or gist. */
+export type SettingsSyncDocument = {
+  grooveSettingsSyncVersion: number;
+  updatedAt: string;
+  /** Global settings without `SETTINGS_SYNC_LOCAL_ONLY_FIELDS`. */
+  settings: Record<string, unknown>;
+  /**
+   * `SETTINGS_SYNC_WORKSPACE_FIELDS` of each synced workspace, by root
+   * name.
+   */
+  workspaces?: Record<string, Record<string, unknown>>;
+};
+
+/** What `app_backup_create` writes. */
+export type AppBackupDocument = {
+  grooveBackupVersion: number;
+  createdAt: string;
+  /** Contents of each `APP_BACKUP_FILES` entry that existed, by file name. */
+  files: Record<string, unknown>;
+  /** `.groove/workspace.json` of each open workspace, by workspace root. */
+  workspaces: Record<string, unknown>;
+};
+
+/** Limits on the play, terminal and custom commands workspaces configure. */
+export type CustomCommandPolicy = {
+  /** Commands containing any of these as whole words are refused. */
+  deniedPatterns: string[];
+  /** When not empty, the only programs commands may start. */
+  allowedPrograms: string[];
+  /** Refuse programs given as a relative path or not found on `PATH`. */
+  requireResolvedPrograms: boolean;
+  /**
+   * What the first run of a command in a trusted workspace needs:
+   * "allow", "confirm" or "block".
+   */
+  trustedFirstRun: string;
+  /** The same for workspaces that are not trusted. */
+  untrustedFirstRun: string;
+};
+
+export type TerminalMemorySettings = {
+  /** Scrollback kept per terminal session. */
+  snapshotMaxBytes: number;
+  /**
+   * Scrollback kept across all sessions; the oldest sessions are trimmed
+   * first once it is exceeded.
+   */
+  snapshotBudgetBytes: number;
+  /** Spill trimmed scrollback to a file per session instead of dropping it. */
+  scrollbackPersistenceEnabled: boolean;
+  scrollbackFileMaxBytes: number;
+  /**
+   * Append all output of each session to
+   * `.groove/terminal-history/<worktree>/<session>.log` in its workspace,
+   * so it can be restored after a restart. Capped like scrollback files.
+   */
+  historyPersistenceEnabled: boolean;
+  /**
+   * Stop plain shells that sit idle with nothing running in them; they are
+   * started again in the same directory on the next keystroke.
+   */
+  hibernateIdleShells: boolean;
+  hibernateIdleMinutes: number;
+  /**
+   * Disk that terminal history may take per workspace, and scrollback
+   * files in total; the oldest closed sessions' files go first.
+   */
+  storageQuotaBytes: number;
+  /** History and recorded scrollback older than this are deleted. */
+  storageMaxAgeDays: number;
+};
+
+export type SystemAlertSettings = {
+  enabled: boolean;
+  /** Alert once RAM usage goes above this percentage. */
+  ramPercent: number;
+  /** Alert once the disk holding the active workspace is fuller than this. */
+  diskPercent: number;
+  /** Alert whenever any swap is in use. */
+  swapInUse: boolean;
+  /** Also show alerts as native desktop notifications. */
+  desktopNotifications: boolean;
+};
+
+/**
+ * How the supervisor restarts testing environments (Play sessions) that
+ * crash. Restarts are off unless `enabled`.
+ */
+export type TestingEnvironmentRestartPolicy = {
+  enabled: boolean;
+  /** Restarts in a row before the supervisor gives up. */
+  maxRestarts: number;
+  /** Wait before the first restart, doubled for each one after it. */
+  backoffMs: number;
+};
+
+/**
+ * The HTTP status page for monitoring Groove from a browser, script or
+ * status bar widget. Off unless `enabled`.
+ */
+export type StatusPageSettings = {
+  enabled: boolean;
+  port: number;
+  /**
+   * Listen on every interface instead of only 127.0.0.1. Requests from
+   * other machines must then present an API token with `read`.
+   */
+  allowRemote: boolean;
+};
+
+/**
+ * How git and gh runs that failed for a passing reason, like a dropped
+ * connection, are tried again. Only runs that are safe to repeat qualify:
+ * fetches, pulls, pushes and read-only gh calls.
+ */
+export type CommandRetryPolicy = {
+  /** Tries in total, the first included; 1 turns retries off. */
+  maxAttempts: number;
+  /** Wait before the first retry, doubled for each one after it. */
+  backoffMs: number;
+  maxBackoffMs: number;
+  /** Failure categories to retry: `network`, `timeout` and `lock`. */
+  retryOn: string[];
+};
+
+export type OpencodeIntegrationStatusResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  workspaceScopeAvailable: boolean;
+  globalScopeAvailable: boolean;
+  effectiveScope: string;
+  workspaceSettings?: OpencodeSettings;
+  globalSettings?: OpencodeSettings;
+  error?: string;
+};
+
+export type OpencodeWorkspaceSettingsResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  settings?: OpencodeSettings;
+  error?: string;
+};
+
+export type OpencodeGlobalSettingsResponse = {
+  requestId: string;
+  ok: boolean;
+  settings?: OpencodeSettings;
+  error?: string;
+};
+
+export type OpenCodeStatusResponse = {
+  requestId: string;
+  ok: boolean;
+  status?: OpenCodeStatus;
+  error?: string;
+};
+
+export type OpencodeSettingsDirectoryValidationResponse = {
+  requestId: string;
+  ok: boolean;
+  resolvedPath?: string;
+  directoryExists: boolean;
+  opencodeConfigExists: boolean;
+  error?: string;
+};
+
+export type OpencodeSkillsListResponse = {
+  requestId: string;
+  ok: boolean;
+  globalScope?: OpencodeSkillScope;
+  workspaceScope?: OpencodeSkillScope;
+  error?: string;
+};
+
+export type OpencodeCopySkillsResponse = {
+  requestId: string;
+  ok: boolean;
+  copiedToWorkspace: number;
+  copiedToGlobal: number;
+  error?: string;
+};
+
+export type OpenCodeProfileResponse = {
+  requestId: string;
+  ok: boolean;
+  profile?: OpenCodeProfile;
+  error?: string;
+};
+
+export type OpenCodeSyncResponse = {
+  requestId: string;
+  ok: boolean;
+  result?: SyncResult;
+  error?: string;
+};
+
+export type OpenCodeRepairResponse = {
+  requestId: string;
+  ok: boolean;
+  result?: OpenCodeRepairResult;
+  error?: string;
+};
+
+export type OpenCodeRunResponse = {
+  requestId: string;
+  ok: boolean;
+  result: OpenCodeRunResult;
+};
+
+export type OpenCodeCancelResponse = {
+  requestId: string;
+  ok: boolean;
+  result: CancelResult;
+};
+
+export type GlobalSettingsResponse = {
+  requestId: string;
+  ok: boolean;
+  globalSettings?: GlobalSettings;
+  error?: string;
+};
+
+/**
+ * Shared by the `settings_profile_*` commands. `global_settings` is set
+ * after a switch; `exported` by `settings_profile_export`.
+ */
+export type SettingsProfilesResponse = {
+  requestId: string;
+  ok: boolean;
+  activeProfile?: string;
+  profiles: SettingsProfile[];
+  globalSettings?: GlobalSettings;
+  exported?: string;
+  error?: string;
+};
+
+export type SettingsSyncResponse = {
+  requestId: string;
+  ok: boolean;
+  target: SettingsSyncTarget;
+  lastSyncedAt?: string;
+  /**
+   * Fields changed on both sides since the last sync, as `themeMode` or
+   * `workspace:<root name>.defaultTerminal`.
+   */
+  conflicts: string[];
+  /** Set when the global settings were written. */
+  globalSettings?: GlobalSettings;
+  error?: string;
+};
+
+export type AppBackupResponse = {
+  requestId: string;
+  ok: boolean;
+  path?: string;
+  /** App data files written to or restored from the backup. */
+  files: string[];
+  /** Workspace roots whose `workspace.json` was written or restored. */
+  workspaces: string[];
+  /**
+   * Bundled workspace roots that do not exist on this machine, or that
+   * were neither open nor already a Groove workspace.
+   */
+  skippedWorkspaces: string[];
+  error?: string;
+};
+
+export type GitProfileStatus = {
+  userName?: string;
+  userEmail?: string;
+};
+
+export type GitSshStatus = {
+  state: string;
+  message: string;
+};
+
+export type GitAuthStatusResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  profile: GitProfileStatus;
+  sshStatus: GitSshStatus;
+  error?: string;
+};
+
+export type GhAccount = {
+  login: string;
+  active: boolean;
+  scopes: string[];
+  protocol?: string;
+};
+
+export type GhAuthStatusResponse = {
+  requestId: string;
+  ok: boolean;
+  installed: boolean;
+  loggedIn: boolean;
+  activeAccount?: string;
+  accounts: GhAccount[];
+  cancelled?: boolean;
+  error?: string;
+  /** Runs that failed for a passing reason and were tried again. */
+  retries?: CommandRetry[];
+};
+
+export type GhCommandResponse = {
+  requestId: string;
+  ok: boolean;
+  cancelled?: boolean;
+  error?: string;
+  /** Subprocesses the command ran, when it was called with `debug: true`. */
+  debugTranscripts?: SubprocessTranscript[];
+};
+
+export type GhSshOverviewPayload = {
+  workspaceRoot?: string;
+};
+
+export type GhSshSetIdentityPayload = {
+  workspaceRoot: string;
+  alias: string;
+  debug?: boolean;
+};
+
+export type GhSshIdentity = {
+  alias: string;
+  hostname: string;
+  identityFile?: string;
+  username?: string;
+  authState: string;
+};
+
+export type GhRemoteOrigin = {
+  url: string;
+  host?: string;
+  owner?: string;
+  repo?: string;
+  matchedAlias?: string;
+};
+
+export type GhSshOverviewResponse = {
+  requestId: string;
+  ok: boolean;
+  configFound: boolean;
+  identities: GhSshIdentity[];
+  origin?: GhRemoteOrigin;
+  cancelled?: boolean;
+  error?: string;
+};
+
+export type GhWorktreePayload = {
+  worktreePath: string;
+};
+
+export type GhIssueListPayload = {
+  worktreePath: string;
+  /** `open` (default), `closed` or `all`. */
+  state?: string;
+  /** GitHub search query, e.g. `label:bug no:assignee`. */
+  search?: string;
+  limit?: number;
+};
+
+export type GhIssueSummary = {
+  number: number;
+  title: string;
+  state: string;
+  url: string;
+  labels: string[];
+  assignees: string[];
+  updatedAt?: string;
+};
+
+export type GhIssueListResponse = {
+  requestId: string;
+  ok: boolean;
+  issues: GhIssueSummary[];
+  cancelled?: boolean;
+  error?: string;
+  /** Runs that failed for a passing reason and were tried again. */
+  retries?: CommandRetry[];
+};
+
+export type GhIssueViewPayload = {
+  worktreePath: string;
+  number: number;
+};
+
+export type GhIssueDetail = {
+  number: number;
+  title: string;
+  state: string;
+  url: string;
+  body?: string;
+  author?: string;
+  labels: string[];
+  assignees: string[];
+  createdAt?: string;
+  updatedAt?: string;
+  comments: GhPrComment[];
+  /** Branch name `groove_new` uses for this issue when none is given. */
+  suggestedBranch: string;
+};
+
+export type GhIssueViewResponse = {
+  requestId: string;
+  ok: boolean;
+  issue?: GhIssueDetail;
+  cancelled?: boolean;
+  error?: string;
+  /** Runs that failed for a passing reason and were tried again. */
+  retries?: CommandRetry[];
+};
+
+export type GhPrViewPayload = {
+  worktreePath: string;
+  selector: string;
+};
+
+export type GhPrPreparePayload = {
+  worktreePath: string;
+  base: string;
+};
+
+export type GhPrPrepareCommit = {
+  sha: string;
+  author: string;
+  subject: string;
+};
+
+export type GhPrPrepareResponse = {
+  requestId: string;
+  ok: boolean;
+  title?: string;
+  /**
+   * The pull request template, when the repository has one, followed by
+   * the commit list, the linked issue and the co-authors.
+   */
+  body?: string;
+  /** Commits between the base and `HEAD`, newest first. */
+  commits: GhPrPrepareCommit[];
+  coAuthors: string[];
+  /** Relative to the worktree, e.g. `.github/pull_request_template.md`. */
+  templatePath?: string;
+  /** Issue the worktree was created from. */
+  issue?: number;
+  cancelled?: boolean;
+  error?: string;
+};
+
+export type GhPrCreatePayload = {
+  worktreePath: string;
+  /** Empty targets the branch of the worktree this one is stacked on. */
+  base: string;
+  /** Without a title, gh fills title and body from the branch's commits. */
+  title?: string;
+  body?: string;
+  draft: boolean;
+};
+
+export type GhPrCreateResponse = {
+  requestId: string;
+  ok: boolean;
+  number?: number;
+  url?: string;
+  cancelled?: boolean;
+  error?: string;
+};
+
+/** Edits an existing PR; only the fields that are set change. */
+export type GhPrUpdatePayload = {
+  worktreePath: string;
+  selector: string;
+  title?: string;
+  /** An empty string clears the description. */
+  body?: string;
+  base?: string;
+  addLabels?: string[];
+  removeLabels?: string[];
+  /** `true` marks a draft ready for review, `false` converts it back to a draft. */
+  ready?: boolean;
+  debug?: boolean;
+};
+
+export type GhPrMergePayload = {
+  worktreePath: string;
+  selector: string;
+  /** `merge` (default), `squash` or `rebase`. */
+  strategy?: string;
+  /**
+   * Deletes the PR's head branch on GitHub once merged. The local branch is
+   * left alone: it is usually checked out in a worktree.
+   */
+  deleteBranch: boolean;
+  /** Overrides the merge or squash commit title. */
+  subject?: string;
+  /** Overrides the merge or squash commit body. */
+  body?: string;
+};
+
+export type GhPrMergeResponse = {
+  requestId: string;
+  ok: boolean;
+  number?: number;
+  mergeCommitSha?: string;
+  branchDeleted: boolean;
+  cancelled?: boolean;
+  error?: string;
+};
+
+export type GhPrCreateWebPayload = {
+  worktreePath: string;
+  base: string;
+  debug?: boolean;
+};
+
+export type GhRepoDefaultBranchResponse = {
+  requestId: string;
+  ok: boolean;
+  defaultBranch?: string;
+  cancelled?: boolean;
+  error?: string;
+  /** Runs that failed for a passing reason and were tried again. */
+  retries?: CommandRetry[];
+};
+
+/** One PR as returned by `gh pr list --json ...`. */
+export type GhPrSummary = {
+  number: number;
+  title: string;
+  state: string;
+  url: string;
+  isDraft: boolean;
+  /**
+   * Rolled-up CI status: `success`, `failure` or `pending`; `None` when the
+   * PR has no checks.
+   */
+  checksStatus?: string;
+};
+
+export type WorktreeStalenessEntry = {
+  worktree: string;
+  branch?: string;
+  stale: boolean;
+  /**
+   * Rules the worktree matched: "noCommits", "branchMerged", "prClosed"
+   * or "noOpencodeActivity".
+   */
+  reasons: string[];
+  lastCommitAt?: string;
+  lastOpencodeActivityAt?: string;
+  /**
+   * "archived" when the policy forgot the worktree, "delete" when it
+   * asks for the worktree to be removed.
+   */
+  action?: string;
+  /** Why a stale worktree was left alone: "running" or "dirty". */
+  skipped?: string;
+};
+
+export type WorktreeStalenessReportResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  policy?: WorktreeStalenessPolicy;
+  worktrees: WorktreeStalenessEntry[];
+  error?: string;
+};
+
+/**
+ * A directory under `<workspace>/.groove` packed while the workspace is
+ * frozen.
+ */
+export type FrozenWorkspaceArchive = {
+  /** Directory name relative to `.groove`, e.g. "terminal-history". */
+  source: string;
+  /** Tarball path relative to `.groove`. */
+  archive: string;
+  bytes: number;
+};
+
+/**
+ * What `workspace_freeze` stopped, persisted in `.groove/frozen.json` until
+ * `workspace_thaw`.
+ */
+export type FrozenWorkspaceState = {
+  frozenAt: string;
+  runningGrooves: RunningGrooveRecord[];
+  sessionLayouts: WorktreeSessionLayout[];
+  /** Terminal sessions that were open, hibernated ones included. */
+  closedSessions: number;
+  /** Node and opencode processes stopped after the sessions were closed. */
+  stoppedPids: number[];
+  archives: FrozenWorkspaceArchive[];
+};
+
+/** Response of `workspace_freeze` and `workspace_thaw`. */
+export type WorkspaceFreezeResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  frozen?: FrozenWorkspaceState;
+  /** Sessions and Play targets reopened by a thaw, as `<worktree>: <mode>`. */
+  restarted: string[];
+  /** Steps that failed without stopping the freeze or thaw. */
+  failures: string[];
+  error?: string;
+};
+
+/** A file two worktrees both changed since their merge base. */
+export type WorktreeConflictFile = {
+  path: string;
+  /** The changes overlap or touch, which git reports as a conflict. */
+  overlapping: boolean;
+};
+
+export type WorktreeConflictPair = {
+  left: string;
+  right: string;
+  /**
+   * "conflicting" when a file has overlapping changes, "sameFiles" when
+   * the worktrees only change the same files, else "clear".
+   */
+  risk: string;
+  mergeBase?: string;
+  files: WorktreeConflictFile[];
+  /** Why the pair could not be compared; `risk` is then "unknown". */
+  error?: string;
+};
+
+export type WorkspaceConflictMatrixResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  worktrees: string[];
+  /** One entry per pair of `worktrees`, in order. */
+  pairs: WorktreeConflictPair[];
+  error?: string;
+};
+
+/** A worktree in a merge train and the step it is at. */
+export type MergeTrainEntry = {
+  worktree: string;
+  prNumber: number;
+  /** Next step to run: "rebase", "checks", "merge", "cleanup" or "done". */
+  step: string;
+};
+
+/** Why a merge train stopped before its last worktree. */
+export type MergeTrainPause = {
+  worktree: string;
+  step: string;
+  /** "conflicts", "checksFailed", "checksPending" or "failed". */
+  reason: string;
+  message: string;
+  conflictedFiles?: string[];
+  failedChecks?: string[];
+};
+
+/**
+ * A merge train, persisted in `.groove/merge-train.json` so it can be
+ * resumed after a pause or a restart.
+ */
+export type MergeTrainState = {
+  base: string;
+  strategy?: string;
+  keepWorktrees: boolean;
+  worktreeDir: string;
+  /** "running", "paused", "completed" or "aborted". */
+  status: string;
+  entries: MergeTrainEntry[];
+  paused?: MergeTrainPause;
+  startedAt: string;
+  updatedAt: string;
+};
+
+/** Response of the `merge_train_*` commands. */
+export type MergeTrainResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  train?: MergeTrainState;
+  error?: string;
+};
+
+/** The outcome of one run of a maintenance task. */
+export type MaintenanceRun = {
+  startedAt: string;
+  durationMs: number;
+  /** "schedule" or "manual". */
+  trigger: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  summary?: string;
+  error?: string;
+};
+
+/**
+ * Schedule and last run of a maintenance task. A task without `at` only
+ * runs through `maintenance_run_now`.
+ */
+export type MaintenanceTaskState = {
+  enabled: boolean;
+  at?: string;
+  lastRun?: MaintenanceRun;
+};
+
+/** `maintenance.json` in the app data directory. */
+export type PersistedMaintenance = {
+  utcOffsetMinutes: number;
+  tasks: Record<string, MaintenanceTaskState>;
+};
+
+export type MaintenanceTaskInfo = {
+  task: string;
+  enabled: boolean;
+  at?: string;
+  /** Next scheduled start, in UTC; unset when the task is not scheduled. */
+  nextRunAt?: string;
+  running: boolean;
+  lastRun?: MaintenanceRun;
+};
+
+/**
+ * Response of `maintenance_status`, `maintenance_configure` and
+ * `maintenance_run_now`.
+ */
+export type MaintenanceResponse = {
+  requestId: string;
+  ok: boolean;
+  utcOffsetMinutes: number;
+  tasks: MaintenanceTaskInfo[];
+  error?: string;
+};
+
+export type TestingEnvironmentCrash = {
+  at: string;
+  sessionId: string;
+  reason: string;
+};
+
+/**
+ * A testing environment the supervisor has seen crash since the app
+ * started.
+ */
+export type TestingEnvironmentInfo = {
+  workspaceRoot: string;
+  worktree: string;
+  /** "running", "restarting", "crashed" or "failed". */
+  status: string;
+  /** Restarts since the environment last stayed up. */
+  restarts: number;
+  /** Session of the last restart while it is running. */
+  sessionId?: string;
+  nextRestartAt?: string;
+  /** Most recent last. */
+  crashes: TestingEnvironmentCrash[];
+};
+
+export type TestingEnvironmentStatusResponse = {
+  requestId: string;
+  ok: boolean;
+  environments: TestingEnvironmentInfo[];
+  error?: string;
+};
+
+/** A groove recorded as running, as shown on the status page. */
+export type StatusPageRunningGroove = {
+  workspaceRoot: string;
+  worktree: string;
+  command: string;
+  startedAt: string;
+};
+
+/** What the status page serves at `/status.json`. */
+export type StatusPageSnapshot = {
+  appVersion: string;
+  generatedAt: string;
+  /** The worst status of any health subsystem. */
+  status: string;
+  activeWorkspaceRoot?: string;
+  openWorkspaces: string[];
+  runningGrooves: StatusPageRunningGroove[];
+  testingEnvironments: TestingEnvironmentInfo[];
+  health: AppHealthSubsystem[];
+};
+
+export type StatusPageStatusResponse = {
+  requestId: string;
+  ok: boolean;
+  settings: StatusPageSettings;
+  listening: boolean;
+  url?: string;
+  /** Why the page is not listening although enabled, e.g. a port in use. */
+  error?: string;
+};
+
+export type TestingEnvironmentLogsTailPayload = {
+  worktree: string;
+  /** Defaults to `DEFAULT_TESTING_LOG_TAIL_LINES`. */
+  lines?: number;
+  /**
+   * Start (true) or stop (false) sending new lines as
+   * `testing-environment-log` events. Left as is when absent.
+   */
+  follow?: boolean;
+};
+
+export type TestingEnvironmentLogsClearPayload = {
+  worktree: string;
+};
+
+export type TestingEnvironmentLogsResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  worktree?: string;
+  /** Oldest first; empty after a clear. */
+  lines: string[];
+  /** Whether new lines are sent as events. */
+  following: boolean;
+  error?: string;
+};
+
+export type GhPrListResponse = {
+  requestId: string;
+  ok: boolean;
+  branch?: string;
+  prs: GhPrSummary[];
+  cancelled?: boolean;
+  error?: string;
+  /** Runs that failed for a passing reason and were tried again. */
+  retries?: CommandRetry[];
+};
+
+export type GhPrComment = {
+  author?: string;
+  body: string;
+  createdAt?: string;
+};
+
+/** An inline review conversation on one file of a PR. */
+export type GhPrReviewThread = {
+  id: string;
+  path: string;
+  line?: number;
+  isResolved: boolean;
+  /** The diff the thread was left on has since changed. */
+  isOutdated: boolean;
+  comments: GhPrComment[];
+};
+
+/**
+ * A submitted review; `state` is `APPROVED`, `CHANGES_REQUESTED`,
+ * `COMMENTED` or `DISMISSED`.
+ */
+export type GhPrReview = {
+  author?: string;
+  state: string;
+  body: string;
+  submittedAt?: string;
+};
+
+export type GhPrCommentsResponse = {
+  requestId: string;
+  ok: boolean;
+  number?: number;
+  reviews: GhPrReview[];
+  threads: GhPrReviewThread[];
+  cancelled?: boolean;
+  error?: string;
+  /** Runs that failed for a passing reason and were tried again. */
+  retries?: CommandRetry[];
+};
+
+export type GhPrReviewPayload = {
+  worktreePath: string;
+  selector: string;
+  /** `approve`, `requestChanges` or `comment`. */
+  event: string;
+  /** Required for `requestChanges` and `comment`. */
+  body?: string;
+  debug?: boolean;
+};
+
+export type GhPrDetail = {
+  number: number;
+  title: string;
+  state: string;
+  url: string;
+  isDraft: boolean;
+  baseRefName?: string;
+  headRefName?: string;
+  reviewDecision?: string;
+  body?: string;
+  author?: string;
+  labels: string[];
+  additions?: number;
+  deletions?: number;
+  createdAt?: string;
+  updatedAt?: string;
+  comments: GhPrComment[];
+};
+
+/** A check run, or a commit status from an external CI, on a PR's head. */
+export type GhPrCheck = {
+  name: string;
+  /** As GitHub reports it: `QUEUED`, `IN_PROGRESS`, `COMPLETED`, `PENDING`... */
+  status: string;
+  /** `SUCCESS`, `FAILURE`, `SKIPPED`...; set once the check completed. */
+  conclusion?: string;
+  detailsUrl?: string;
+  workflow?: string;
+};
+
+export type GhPrChecksResponse = {
+  requestId: string;
+  ok: boolean;
+  number?: number;
+  checks: GhPrCheck[];
+  /** `success`, `failure` or `pending`; `None` without checks. */
+  checksStatus?: string;
+  /** `MERGEABLE`, `CONFLICTING` or `UNKNOWN`. */
+  mergeable?: string;
+  /** GitHub's merge state, e.g. `CLEAN`, `BLOCKED`, `BEHIND` or `DIRTY`. */
+  mergeStateStatus?: string;
+  cancelled?: boolean;
+  error?: string;
+  /** Runs that failed for a passing reason and were tried again. */
+  retries?: CommandRetry[];
+};
+
+export type GhPrViewResponse = {
+  requestId: string;
+  ok: boolean;
+  pr?: GhPrDetail;
+  cancelled?: boolean;
+  error?: string;
+  /** Runs that failed for a passing reason and were tried again. */
+  retries?: CommandRetry[];
+};
+
+export type GitStatusResponse = {
+  requestId: string;
+  ok: boolean;
+  path?: string;
+  modified: number;
+  added: number;
+  deleted: number;
+  untracked: number;
+  dirty: boolean;
+  /** Set while a rebase is stopped in the worktree. */
+  rebase?: GitRebaseState;
+  outputSnippet?: string;
+  error?: string;
+};
+
+export type GitRebaseState = {
+  /** Branch being rebased; `None` for a rebase of a detached HEAD. */
+  branch?: string;
+  /** Commit the branch is replayed onto. */
+  onto?: string;
+  /** 1-based number of the commit being applied. */
+  step?: number;
+  total?: number;
+};
+
+export type GitRebaseStatusResponse = {
+  requestId: string;
+  ok: boolean;
+  path?: string;
+  rebase?: GitRebaseState;
+  /** Files left unmerged by the step the rebase stopped at. */
+  conflictedFiles: string[];
+  error?: string;
+};
+
+/**
+ * Result of `git_rebase_start`, `_continue`, `_skip` and `_abort`, with the
+ * rebase state left behind.
+ */
+export type GitRebaseResponse = {
+  requestId: string;
+  ok: boolean;
+  path?: string;
+  exitCode?: number;
+  outputSnippet?: string;
+  /** Still set when the rebase stopped again, e.g. on the next conflict. */
+  rebase?: GitRebaseState;
+  conflictedFiles: string[];
+  error?: string;
+};
+
+export type GitCurrentBranchResponse = {
+  requestId: string;
+  ok: boolean;
+  path?: string;
+  branch?: string;
+  outputSnippet?: string;
+  error?: string;
+};
+
+export type GitListBranchesResponse = {
+  requestId: string;
+  ok: boolean;
+  path?: string;
+  branches: string[];
+  outputSnippet?: string;
+  error?: string;
+};
+
+export type GitAheadBehindResponse = {
+  requestId: string;
+  ok: boolean;
+  path?: string;
+  ahead: number;
+  behind: number;
+  outputSnippet?: string;
+  error?: string;
+};
+
+export type GitFetchBlobsResponse = {
+  requestId: string;
+  ok: boolean;
+  path?: string;
+  /** `None` when the repository is a full clone and nothing had to be fetched. */
+  partialCloneFilter?: string;
+  changedPaths: number;
+  fetchedBlobs: number;
+  error?: string;
+};
+
+export type GitCommandResponse = {
+  requestId: string;
+  ok: boolean;
+  path?: string;
+  exitCode?: number;
+  outputSnippet?: string;
+  error?: string;
+  /** Subprocesses the command ran, when it was called with `debug: true`. */
+  debugTranscripts?: SubprocessTranscript[];
+  /** Runs that failed for a passing reason and were tried again. */
+  retries?: CommandRetry[];
+};
+
+export type GitHookRun = {
+  hook: string;
+  ok: boolean;
+  exitCode?: number;
+  durationMs: number;
+  /** The hook's stdout and stderr, limited to the last lines. */
+  output: string;
+};
+
+export type GitCommitResponse = {
+  requestId: string;
+  ok: boolean;
+  path?: string;
+  exitCode?: number;
+  outputSnippet?: string;
+  error?: string;
+  /** Hooks and commit together. */
+  durationMs: number;
+  /** `husky`, `pre-commit` or `lefthook` when one of them manages the hooks. */
+  hookManager?: string;
+  /** Hooks that ran before the commit, in order. */
+  hooks?: GitHookRun[];
+  /** The hook that rejected the commit, if any; its output is in `hooks`. */
+  failedHook?: string;
+};
+
+export type GitCommitHooksResponse = {
+  requestId: string;
+  ok: boolean;
+  /** Installed hooks that `noVerify` would skip. */
+  hooks: string[];
+  hookManager?: string;
+  error?: string;
+};
+
+export type GitCommitLintIssue = {
+  /** commitlint-style rule name, e.g. `type-enum`. */
+  rule: string;
+  message: string;
+};
+
+export type GitLintCommitMessageResponse = {
+  requestId: string;
+  ok: boolean;
+  /**
+   * False when the workspace has no `commitLint` rules; `issues` is then
+   * always empty.
+   */
+  enabled: boolean;
+  issues: GitCommitLintIssue[];
+  error?: string;
+};
+
+export type GitUnsignedCommit = {
+  sha: string;
+  subject: string;
+  /** `name <email>`. */
+  author: string;
+};
+
+export type GitPushPreflightResponse = {
+  requestId: string;
+  ok: boolean;
+  path?: string;
+  /** Whether every check passed and the push can go ahead. */
+  passed: boolean;
+  dcoRequired: boolean;
+  /**
+   * `workspace` (the `requireSignOff` option) or `repository` (a DCO app
+   * config in the repository).
+   */
+  dcoSource?: string;
+  checkedCommits: number;
+  unsignedCommits: GitUnsignedCommit[];
+  error?: string;
+};
+
+export type GitBooleanResponse = {
+  requestId: string;
+  ok: boolean;
+  path?: string;
+  value: boolean;
+  outputSnippet?: string;
+  error?: string;
+};
+
+export type GitFileStatesResponse = {
+  requestId: string;
+  ok: boolean;
+  path?: string;
+  staged: string[];
+  unstaged: string[];
+  untracked: string[];
+  outputSnippet?: string;
+  error?: string;
+};
+
+export type GitDiffLine = {
+  kind: string;
+  content: string;
+  /** Line number in the old file; `None` for added lines. */
+  oldLineNumber?: number;
+  /** Line number in the new file; `None` for removed lines. */
+  newLineNumber?: number;
+};
+
+export type GitDiffHunk = {
+  header: string;
+  oldStart: number;
+  oldLines: number;
+  newStart: number;
+  newLines: number;
+  lines: GitDiffLine[];
+};
+
+export type GitDiffFile = {
+  filePath: string;
+  oldPath?: string;
+  status: string;
+  additions: number;
+  deletions: number;
+  binary: boolean;
+  hunks: GitDiffHunk[];
+};
+
+export type GitDiffResponse = {
+  requestId: string;
+  ok: boolean;
+  path?: string;
+  files: GitDiffFile[];
+  error?: string;
+};
+
+export type GitDiffFileResponse = {
+  requestId: string;
+  ok: boolean;
+  path?: string;
+  staged: boolean;
+  /** `None` when the file has no changes on the requested side. */
+  file?: GitDiffFile;
+  error?: string;
+};
+
+export type GitDiffHunksResponse = {
+  requestId: string;
+  ok: boolean;
+  path?: string;
+  staged: boolean;
+  files: GitDiffFile[];
+  error?: string;
+};
+
+/**
+ * One side of a conflicted file. `content` is left out for binary and
+ * oversized files, with the reason in `omitted`.
+ */
+export type GitConflictSide = {
+  content?: string;
+  /** `binary` or `tooLarge`. */
+  omitted?: string;
+};
+
+export type GitConflictFile = {
+  filePath: string;
+  /**
+   * `bothModified`, `bothAdded`, `deletedByUs`, `deletedByThem`,
+   * `addedByUs`, `addedByThem` or `bothDeleted`.
+   */
+  conflict: string;
+  /** Each side is `None` when the file does not exist there. */
+  base?: GitConflictSide;
+  ours?: GitConflictSide;
+  theirs?: GitConflictSide;
+  /** The working tree file, conflict markers included. */
+  working?: GitConflictSide;
+};
+
+export type GitConflictsResponse = {
+  requestId: string;
+  ok: boolean;
+  path?: string;
+  /**
+   * `merge`, `rebase`, `cherry-pick` or `revert`; `None` when nothing is
+   * in progress.
+   */
+  operation?: string;
+  files: GitConflictFile[];
+  error?: string;
+};
+
+export type GitConflictResolveResponse = {
+  requestId: string;
+  ok: boolean;
+  path?: string;
+  /** Conflicted files left after this one was resolved. */
+  remainingConflicts: number;
+  outputSnippet?: string;
+  error?: string;
+};
+
+export type GitLogCommit = {
+  hash: string;
+  shortHash: string;
+  parentHashes: string[];
+  authorName: string;
+  authorEmail: string;
+  /** Author date in strict ISO 8601, with the author's offset. */
+  authoredAt: string;
+  subject: string;
+  /** Ref decorations, e.g. `HEAD -> main`, `origin/main`, `tag: v1.0`. */
+  refs: string[];
+};
+
+export type GitLogResponse = {
+  requestId: string;
+  ok: boolean;
+  path?: string;
+  commits: GitLogCommit[];
+  offset: number;
+  hasMore: boolean;
+  error?: string;
+};
+
+export type DiagnosticsStopResponse = {
+  requestId: string;
+  ok: boolean;
+  pid?: number;
+  alreadyStopped?: boolean;
+  error?: string;
+};
+
+export type DiagnosticsStopAllResponse = {
+  requestId: string;
+  ok: boolean;
+  attempted: number;
+  stopped: number;
+  alreadyStopped: number;
+  failed: number;
+  errors: string[];
+  error?: string;
+};
+
+export type DiagnosticsStopByCategoryPayload = {
+  category: string;
+  /** When true, only report the processes that would be stopped. */
+  preview?: boolean;
+  rootName?: string;
+  knownWorktrees?: string[];
+  workspaceMeta?: WorkspaceMetaContext;
+};
+
+export type DiagnosticsStopTarget = {
+  pid: number;
+  ppid?: number;
+  command: string;
+  /** "stopped", "alreadyStopped" or "failed"; absent in preview mode. */
+  outcome?: string;
+};
+
+export type DiagnosticsStopByCategoryResponse = {
+  requestId: string;
+  ok: boolean;
+  category: string;
+  preview: boolean;
+  workspaceRoot?: string;
+  /** Processes matched before anything was stopped. */
+  targets: DiagnosticsStopTarget[];
+  /** Targeted processes still running after execution. */
+  remaining: DiagnosticsStopTarget[];
+  attempted: number;
+  stopped: number;
+  alreadyStopped: number;
+  failed: number;
+  errors: string[];
+  error?: string;
+};
+
+export type DiagnosticsNodeAppRow = {
+  pid: number;
+  ppid: number;
+  cmd: string;
+};
+
+export type DiagnosticsNodeAppsResponse = {
+  requestId: string;
+  ok: boolean;
+  rows: DiagnosticsNodeAppRow[];
+  warning?: string;
+  error?: string;
+};
+
+export type DiagnosticsMostConsumingProgramsResponse = {
+  requestId: string;
+  ok: boolean;
+  output: string;
+  error?: string;
+};
+
+export type DiagnosticsSystemOverview = {
+  cpuUsagePercent?: number;
+  cpuCores?: number;
+  ramTotalBytes?: number;
+  ramUsedBytes?: number;
+  ramUsagePercent?: number;
+  swapTotalBytes?: number;
+  swapUsedBytes?: number;
+  swapUsagePercent?: number;
+  diskTotalBytes?: number;
+  diskUsedBytes?: number;
+  diskUsagePercent?: number;
+  platform: string;
+  hostname?: string;
+  warnings?: string[];
+};
+
+export type DiagnosticsSystemOverviewResponse = {
+  requestId: string;
+  ok: boolean;
+  overview?: DiagnosticsSystemOverview;
+  error?: string;
+};
+
+export type DiagnosticsTerminalSessionMemory = {
+  sessionId: string;
+  workspaceRoot: string;
+  worktree: string;
+  command: string;
+  startedAt: string;
+  snapshotBytes: number;
+  trimmedBytes: number;
+  scrollbackFileBytes: number;
+};
+
+export type DiagnosticsWorktreeResourceRow = {
+  worktree: string;
+  path: string;
+  usage: WorktreeResourceUsage;
+  pids: number[];
+};
+
+export type DiagnosticsWorktreeResourcesResponse = {
+  requestId: string;
+  ok: boolean;
+  workspaceRoot?: string;
+  /** Heaviest first, by CPU and then memory. */
+  rows: DiagnosticsWorktreeResourceRow[];
+  warning?: string;
+  error?: string;
+};
+
+export type DiagnosticsFdCounts = {
+  fds: number;
+  inotifyInstances: number;
+  inotifyWatches: number;
+};
+
+export type DiagnosticsFdHolderRow = {
+  /**
+   * "groove" for the app's own descriptors, named by subsystem, "worktree"
+   * for processes running in a worktree and "other" for the rest.
+   */
+  kind: string;
+  name: string;
+  counts: DiagnosticsFdCounts;
+  pids: number[];
+};
+
+export type DiagnosticsFdLimits = {
+  openFilesSoft?: number;
+  openFilesHard?: number;
+  inotifyMaxUserWatches?: number;
+  inotifyMaxUserInstances?: number;
+  systemOpenFiles?: number;
+  systemMaxFiles?: number;
+};
+
+export type DiagnosticsFdUsage = {
+  /** Descriptors held by the app process itself. */
+  groove: DiagnosticsFdCounts;
+  /**
+   * Every process the app can inspect, which is those of the same user;
+   * inotify limits apply to this total.
+   */
+  user: DiagnosticsFdCounts;
+  limits: DiagnosticsFdLimits;
+  rows: DiagnosticsFdHolderRow[];
+  hints: string[];
+  warnings: string[];
+};
+
+export type DiagnosticsFdUsageResponse = {
+  requestId: string;
+  ok: boolean;
+  usage?: DiagnosticsFdUsage;
+  error?: string;
+};
+
+export type DiagnosticsTerminalMemoryResponse = {
+  requestId: string;
+  ok: boolean;
+  /** Largest snapshot first. */
+  sessions: DiagnosticsTerminalSessionMemory[];
+  totalSnapshotBytes: number;
+  limits: TerminalMemorySettings;
+  error?: string;
+};
+
+export type GrooveBinCheckStatus = {
+  configuredPath?: string;
+  configuredPathValid?: boolean;
+  hasIssue: boolean;
+  issue?: string;
+  effectiveBinaryPath: string;
+  effectiveBinarySource: string;
+};
+
+export type AppHealthSubsystem = {
+  name: string;
+  /** "ok", "warning" or "error". */
+  status: string;
+  summary: string;
+};
+
+export type AppHealthResponse = {
+  requestId: string;
+  ok: boolean;
+  /** The worst status of any subsystem. */
+  status: string;
+  appVersion: string;
+  checkedAt: string;
+  subsystems: AppHealthSubsystem[];
+  error?: string;
+};
+
+export type GrooveBinStatusResponse = {
+  requestId: string;
+  ok: boolean;
+  status: GrooveBinCheckStatus;
+  error?: string;
+};
+
+export type GrooveBinRepairResponse = {
+  requestId: string;
+  ok: boolean;
+  changed: boolean;
+  action: string;
+  clearedPath?: string;
+  status: GrooveBinCheckStatus;
+  error?: string;
+};
+
+/**
+ * One subprocess a command called with `debug: true` ran.
+ * A failed try of a git or gh run that was tried again, so a slow answer
+ * can be explained.
+ */
+export type CommandRetry = {
+  /** The program and its subcommand, e.g. `git fetch`. */
+  command: string;
+  /** 1 for the first try. */
+  attempt: number;
+  /** `network`, `timeout` or `lock`. */
+  category: string;
+  error: string;
+  /** Wait before the next try. */
+  backoffMs: number;
+};
+
+export type SubprocessTranscript = {
+  /** The program followed by its arguments. */
+  argv: string[];
+  cwd?: string;
+  /**
+   * Variables set (`Some`) or removed (`None`) on top of the app's own
+   * environment. Secret-looking values read `"<redacted>"`.
+   */
+  env: Record<string, string | null>;
+  durationMs: number;
+  exitCode?: number;
+  stdout: string;
+  stderr: string;
+  /** Set when `stdout` or `stderr` were cut to their tail. */
+  outputTruncated: boolean;
+  error?: string;
+};
+
+/**
+ * Result of registering Groove's embedded MCP server as an HTTP transport in
+ * Claude Code (`claude mcp add`). `already_connected` distinguishes a no-op
+ * re-add from a fresh registration so the UI can phrase it accurately.
+ */
+export type AssistantConnectResponse = {
+  requestId: string;
+  ok: boolean;
+  alreadyConnected: boolean;
+  endpoint: string;
+  scope: string;
+  message?: string;
+  error?: string;
+};
+
+/**
+ * Health snapshot for the Assistant settings page. `server_running` is a
+ * direct probe of Groove's local MCP endpoint; `registered_in_claude` and
+ * `claude_connection_ok` come from `claude mcp list` (which live-pings each
+ * configured server). `ok` is the AND of all three.
+ */
+export type AssistantValidateResponse = {
+  requestId: string;
+  ok: boolean;
+  serverRunning: boolean;
+  registeredInClaude: boolean;
+  claudeConnectionOk: boolean;
+  endpoint: string;
+  details?: string;
+  error?: string;
+};
+
+/**
+ * Project + global assistant rules for the Settings → Assistant UI.
+ * `project_workspace` is null when no workspace is open (project rules N/A).
+ */
+export type AssistantRulesListResponse = {
+  requestId: string;
+  ok: boolean;
+  global: AssistantRule[];
+  project: AssistantRule[];
+  projectWorkspace?: string;
+  error?: string;
+};
+
+export type AssistantRule = {
+  id: string;
+  text: string;
+  createdAt: string;
+};
+
+export type AssistantRuleStore = {
+  rules: AssistantRule[];
+};
+
+export type DoctrineReportRequest = {
+  maxCases?: number;
+};
+
+export type DoctrineWorktreeCase = {
+  branch: string;
+  prompts: string[];
+  date: string;
+  summary?: string;
+};
+
+export type DoctrineReportResponse = {
+  requestId: string;
+  ok: boolean;
+  cases: DoctrineWorktreeCase[];
+  reportText: string;
+  inputTokens: number;
+  worktreesScanned: number;
+  worktreesQualified: number;
+  error?: string;
+};
+
+export type DoctrineState = "ready" | "inactive";
+
+export type DoctrineRecord = {
+  id: string;
+  createdAt: string;
+  inputTokens: number;
+  outputTokens: number;
+  result: string;
+  state: DoctrineState;
+  instructions?: string;
+  directives?: string;
+};
+
+export type DoctrineStore = {
+  version: number;
+  doctrines: DoctrineRecord[];
+};
+
+export type DoctrineResultRequest = {
+  reportText: string;
+  instructions?: string;
+};
+
+export type DoctrineResultResponse = {
+  requestId: string;
+  ok: boolean;
+  newDoctrineId?: string;
+  doctrines: DoctrineRecord[];
+  error?: string;
+};
+
+export type DoctrineListResponse = {
+  requestId: string;
+  ok: boolean;
+  doctrines: DoctrineRecord[];
+  error?: string;
+};
+
+export type DoctrineSetActiveRequest = {
+  id: string;
+};
+
+export type DoctrineSetActiveResponse = {
+  requestId: string;
+  ok: boolean;
+  doctrines: DoctrineRecord[];
+  error?: string;
+};
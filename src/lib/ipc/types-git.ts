@@ -1,5 +1,34 @@
-export type GitAuthStatusPayload = {
-  workspaceRoot: string;
+import type { CommandRetry, SubprocessTranscript } from "./types-core";
+import type {
+  GitAuthStatusPayload,
+  GitCommitLintIssue,
+  GitDiffFilePayload,
+  GitDiffHunksPayload,
+  GitFetchBlobsPayload,
+  GitFilesPayload,
+  GitHunkSelection,
+  GitHunksPayload,
+  GitLintCommitMessagePayload,
+  GitLogCommit,
+  GitLogPayload,
+  GitRebaseState,
+  GitUnsignedCommit,
+} from "./types-generated";
+
+export type {
+  GitAuthStatusPayload,
+  GitCommitLintIssue,
+  GitDiffFilePayload,
+  GitDiffHunksPayload,
+  GitFetchBlobsPayload,
+  GitFilesPayload,
+  GitHunkSelection,
+  GitHunksPayload,
+  GitLintCommitMessagePayload,
+  GitLogCommit,
+  GitLogPayload,
+  GitRebaseState,
+  GitUnsignedCommit,
 };
 
 export type GitAuthStatusResponse = {
@@ -30,10 +59,40 @@ export type GitStatusResponse = {
   deleted: number;
   untracked: number;
   dirty: boolean;
+  /** Set while a rebase is stopped in the worktree. */
+  rebase?: GitRebaseState;
   outputSnippet?: string;
   error?: string;
 };
 
+export type GitRebaseStartPayload = {
+  path: string;
+  /** Branch or commit to replay the worktree's branch onto. */
+  onto: string;
+  autostash?: boolean;
+};
+
+export type GitRebaseStatusResponse = {
+  requestId?: string;
+  ok: boolean;
+  path?: string;
+  rebase?: GitRebaseState;
+  conflictedFiles: string[];
+  error?: string;
+};
+
+export type GitRebaseResponse = {
+  requestId?: string;
+  ok: boolean;
+  path?: string;
+  exitCode?: number;
+  outputSnippet?: string;
+  /** Still set when the rebase stopped again, e.g. on the next conflict. */
+  rebase?: GitRebaseState;
+  conflictedFiles: string[];
+  error?: string;
+};
+
 export type GitCurrentBranchPayload = {
   path: string;
 };
@@ -77,6 +136,7 @@ export type GitAheadBehindResponse = {
 export type GitPullPayload = {
   path: string;
   rebase?: boolean;
+  debug?: boolean;
 };
 
 export type GitPushPayload = {
@@ -84,26 +144,26 @@ export type GitPushPayload = {
   setUpstream?: boolean;
   forceWithLease?: boolean;
   branch?: string;
+  debug?: boolean;
 };
 
 export type GitMergePayload = {
   path: string;
   targetBranch: string;
   ffOnly?: boolean;
+  debug?: boolean;
 };
 
 export type GitMergeAbortPayload = {
   path: string;
+  debug?: boolean;
 };
 
 export type GitCommitPayload = {
   path: string;
   message?: string;
-};
-
-export type GitFilesPayload = {
-  path: string;
-  files: string[];
+  /** Skip `pre-commit` and `commit-msg` hooks (`git commit --no-verify`). */
+  noVerify?: boolean;
 };
 
 export type GitFileStatesResponse = {
@@ -124,6 +184,75 @@ export type GitCommandResponse = {
   exitCode?: number | null;
   outputSnippet?: string;
   error?: string;
+  /** Set when the command was called with `debug: true`. */
+  debugTranscripts?: SubprocessTranscript[];
+  /** Runs that failed for a passing reason and were tried again. */
+  retries?: CommandRetry[];
+};
+
+export type GitLintCommitMessageResponse = {
+  requestId?: string;
+  ok: boolean;
+  /** False when the workspace has no commit lint rules. */
+  enabled: boolean;
+  issues: GitCommitLintIssue[];
+  error?: string;
+};
+
+export type GitPushPreflightResponse = {
+  requestId?: string;
+  ok: boolean;
+  path?: string;
+  /** Whether every check passed and the push can go ahead. */
+  passed: boolean;
+  dcoRequired: boolean;
+  /** `workspace` (requireSignOff) or `repository` (`.github/dco.yml`). */
+  dcoSource?: "workspace" | "repository";
+  checkedCommits: number;
+  unsignedCommits: GitUnsignedCommit[];
+  error?: string;
+};
+
+export type GitHookRun = {
+  hook: string;
+  ok: boolean;
+  exitCode?: number | null;
+  durationMs: number;
+  /** The hook's stdout and stderr, limited to the last lines. */
+  output: string;
+};
+
+/** Commits are never retried and carry no debug transcripts. */
+export type GitCommitResponse = Omit<
+  GitCommandResponse,
+  "debugTranscripts" | "retries"
+> & {
+  /** Hooks and commit together. */
+  durationMs: number;
+  hookManager?: "husky" | "pre-commit" | "lefthook";
+  /** Hooks that ran before the commit, in order. */
+  hooks?: GitHookRun[];
+  /** The hook that rejected the commit; its output is in `hooks`. */
+  failedHook?: string;
+};
+
+export type GitCommitHooksResponse = {
+  requestId?: string;
+  ok: boolean;
+  /** Installed hooks that `noVerify` would skip. */
+  hooks: string[];
+  hookManager?: "husky" | "pre-commit" | "lefthook";
+  error?: string;
+};
+
+export type GitFetchBlobsResponse = {
+  requestId?: string;
+  ok: boolean;
+  path?: string;
+  partialCloneFilter?: string;
+  changedPaths: number;
+  fetchedBlobs: number;
+  error?: string;
 };
 
 export type GitBooleanResponse = {
@@ -138,6 +267,10 @@ export type GitBooleanResponse = {
 export type GitDiffLine = {
   kind: "context" | "add" | "remove";
   content: string;
+  /** Line number in the old file; absent for added lines. */
+  oldLineNumber?: number;
+  /** Line number in the new file; absent for removed lines. */
+  newLineNumber?: number;
 };
 
 export type GitDiffHunk = {
@@ -166,6 +299,84 @@ export type GitDiffFile = {
   hunks: GitDiffHunk[];
 };
 
+export type GitDiffFileResponse = {
+  requestId?: string;
+  ok: boolean;
+  path?: string;
+  staged: boolean;
+  /** Absent when the file has no changes on the requested side. */
+  file?: GitDiffFile;
+  error?: string;
+};
+
+export type GitDiffHunksResponse = {
+  requestId?: string;
+  ok: boolean;
+  path?: string;
+  staged: boolean;
+  files: GitDiffFile[];
+  error?: string;
+};
+
+/** One side of a conflicted file; `content` is omitted for binary or oversized files. */
+export type GitConflictSide = {
+  content?: string;
+  omitted?: "binary" | "tooLarge";
+};
+
+export type GitConflictFile = {
+  filePath: string;
+  conflict:
+    | "bothModified"
+    | "bothAdded"
+    | "deletedByUs"
+    | "deletedByThem"
+    | "addedByUs"
+    | "addedByThem"
+    | "bothDeleted";
+  base?: GitConflictSide;
+  ours?: GitConflictSide;
+  theirs?: GitConflictSide;
+  /** The working tree file, conflict markers included. */
+  working?: GitConflictSide;
+};
+
+export type GitConflictsResponse = {
+  requestId?: string;
+  ok: boolean;
+  path?: string;
+  operation?: "merge" | "rebase" | "cherry-pick" | "revert";
+  files: GitConflictFile[];
+  error?: string;
+};
+
+export type GitConflictResolvePayload = {
+  path: string;
+  filePath: string;
+  resolution: "ours" | "theirs" | "base" | "content" | "delete";
+  /** Required when `resolution` is `content`. */
+  content?: string;
+};
+
+export type GitConflictResolveResponse = {
+  requestId?: string;
+  ok: boolean;
+  path?: string;
+  remainingConflicts: number;
+  outputSnippet?: string;
+  error?: string;
+};
+
+export type GitLogResponse = {
+  requestId?: string;
+  ok: boolean;
+  path?: string;
+  commits: GitLogCommit[];
+  offset: number;
+  hasMore: boolean;
+  error?: string;
+};
+
 export type GitDiffResponse = {
   requestId?: string;
   ok: boolean;
@@ -1,8 +1,48 @@
-export type GhAccount = {
-  login: string;
-  active: boolean;
-  scopes: string[];
-  protocol?: string;
+import type { CommandRetry, SubprocessTranscript } from "./types-core";
+import type {
+  GhAccount,
+  GhIssueDetail,
+  GhIssueSummary,
+  GhIssueViewPayload,
+  GhLoginPayload,
+  GhLogoutPayload,
+  GhPrCheck,
+  GhPrComment,
+  GhPrCreateWebPayload,
+  GhPrDetail,
+  GhPrPrepareCommit,
+  GhPrPreparePayload,
+  GhPrReviewThread,
+  GhPrUpdatePayload,
+  GhPrViewPayload,
+  GhRemoteOrigin,
+  GhSshOverviewPayload,
+  GhSshSetIdentityPayload,
+  GhSwitchPayload,
+  GhWorktreePayload,
+} from "./types-generated";
+
+export type {
+  GhAccount,
+  GhIssueDetail,
+  GhIssueSummary,
+  GhIssueViewPayload,
+  GhLoginPayload,
+  GhLogoutPayload,
+  GhPrCheck,
+  GhPrComment,
+  GhPrCreateWebPayload,
+  GhPrDetail,
+  GhPrPrepareCommit,
+  GhPrPreparePayload,
+  GhPrReviewThread,
+  GhPrUpdatePayload,
+  GhPrViewPayload,
+  GhRemoteOrigin,
+  GhSshOverviewPayload,
+  GhSshSetIdentityPayload,
+  GhSwitchPayload,
+  GhWorktreePayload,
 };
 
 export type GhAuthStatusResponse = {
@@ -12,25 +52,19 @@ export type GhAuthStatusResponse = {
   loggedIn: boolean;
   activeAccount?: string;
   accounts: GhAccount[];
+  cancelled?: boolean;
   error?: string;
-};
-
-export type GhLoginPayload = {
-  token: string;
-};
-
-export type GhSwitchPayload = {
-  user: string;
-};
-
-export type GhLogoutPayload = {
-  user: string;
+  /** Runs that failed for a passing reason and were tried again. */
+  retries?: CommandRetry[];
 };
 
 export type GhCommandResponse = {
   requestId?: string;
   ok: boolean;
+  cancelled?: boolean;
   error?: string;
+  /** Set when the command was called with `debug: true`. */
+  debugTranscripts?: SubprocessTranscript[];
 };
 
 export type GhSshAuthState =
@@ -47,41 +81,24 @@ export type GhSshIdentity = {
   authState: GhSshAuthState;
 };
 
-export type GhRemoteOrigin = {
-  url: string;
-  host?: string;
-  owner?: string;
-  repo?: string;
-  matchedAlias?: string;
-};
-
 export type GhSshOverviewResponse = {
   requestId?: string;
   ok: boolean;
   configFound: boolean;
   identities: GhSshIdentity[];
   origin?: GhRemoteOrigin;
+  cancelled?: boolean;
   error?: string;
 };
 
-export type GhSshOverviewPayload = {
-  workspaceRoot?: string;
-};
-
-export type GhSshSetIdentityPayload = {
-  workspaceRoot: string;
-  alias: string;
-};
-
-export type GhWorktreePayload = {
-  worktreePath: string;
-};
-
 export type GhRepoDefaultBranchResponse = {
   requestId?: string;
   ok: boolean;
   defaultBranch?: string;
+  cancelled?: boolean;
   error?: string;
+  /** Runs that failed for a passing reason and were tried again. */
+  retries?: CommandRetry[];
 };
 
 export type GhPrSummary = {
@@ -90,6 +107,7 @@ export type GhPrSummary = {
   state: string;
   url: string;
   isDraft: boolean;
+  checksStatus?: "success" | "failure" | "pending";
 };
 
 export type GhPrListResponse = {
@@ -97,47 +115,140 @@ export type GhPrListResponse = {
   ok: boolean;
   branch?: string;
   prs: GhPrSummary[];
+  cancelled?: boolean;
+  error?: string;
+  /** Runs that failed for a passing reason and were tried again. */
+  retries?: CommandRetry[];
+};
+
+export type GhPrViewResponse = {
+  requestId?: string;
+  ok: boolean;
+  pr?: GhPrDetail;
+  cancelled?: boolean;
+  error?: string;
+  /** Runs that failed for a passing reason and were tried again. */
+  retries?: CommandRetry[];
+};
+
+export type GhPrChecksResponse = {
+  requestId?: string;
+  ok: boolean;
+  number?: number;
+  checks: GhPrCheck[];
+  checksStatus?: "success" | "failure" | "pending";
+  mergeable?: string;
+  mergeStateStatus?: string;
+  cancelled?: boolean;
   error?: string;
+  /** Runs that failed for a passing reason and were tried again. */
+  retries?: CommandRetry[];
 };
 
-export type GhPrComment = {
+export type GhPrReview = {
   author?: string;
+  state: "APPROVED" | "CHANGES_REQUESTED" | "COMMENTED" | "DISMISSED" | string;
   body: string;
-  createdAt?: string;
+  submittedAt?: string;
 };
 
-export type GhPrDetail = {
-  number: number;
-  title: string;
-  state: string;
-  url: string;
-  isDraft: boolean;
-  baseRefName?: string;
-  headRefName?: string;
-  reviewDecision?: string;
+export type GhPrCommentsResponse = {
+  requestId?: string;
+  ok: boolean;
+  number?: number;
+  reviews: GhPrReview[];
+  threads: GhPrReviewThread[];
+  cancelled?: boolean;
+  error?: string;
+  /** Runs that failed for a passing reason and were tried again. */
+  retries?: CommandRetry[];
+};
+
+export type GhPrReviewPayload = {
+  worktreePath: string;
+  selector: string;
+  event: "approve" | "requestChanges" | "comment";
   body?: string;
-  author?: string;
-  labels: string[];
-  additions?: number;
-  deletions?: number;
-  createdAt?: string;
-  updatedAt?: string;
-  comments: GhPrComment[];
+  debug?: boolean;
 };
 
-export type GhPrViewPayload = {
+export type GhPrMergeStrategy = "merge" | "squash" | "rebase";
+
+export type GhPrMergePayload = {
   worktreePath: string;
   selector: string;
+  strategy?: GhPrMergeStrategy;
+  deleteBranch?: boolean;
+  subject?: string;
+  body?: string;
 };
 
-export type GhPrViewResponse = {
+export type GhPrMergeResponse = {
   requestId?: string;
   ok: boolean;
-  pr?: GhPrDetail;
+  number?: number;
+  mergeCommitSha?: string;
+  branchDeleted: boolean;
+  cancelled?: boolean;
   error?: string;
 };
 
-export type GhPrCreateWebPayload = {
+export type GhPrPrepareResponse = {
+  requestId?: string;
+  ok: boolean;
+  title?: string;
+  /** The PR template, if any, followed by the commits, issue and co-authors. */
+  body?: string;
+  /** Newest first. */
+  commits: GhPrPrepareCommit[];
+  coAuthors: string[];
+  templatePath?: string;
+  issue?: number;
+  cancelled?: boolean;
+  error?: string;
+};
+
+export type GhPrCreatePayload = {
   worktreePath: string;
-  base: string;
+  /** Omitted targets the branch of the worktree this one is stacked on. */
+  base?: string;
+  title?: string;
+  body?: string;
+  draft?: boolean;
+};
+
+export type GhPrCreateResponse = {
+  requestId?: string;
+  ok: boolean;
+  number?: number;
+  url?: string;
+  cancelled?: boolean;
+  error?: string;
+};
+
+export type GhIssueListPayload = {
+  worktreePath: string;
+  state?: "open" | "closed" | "all";
+  search?: string;
+  limit?: number;
+};
+
+export type GhIssueListResponse = {
+  requestId?: string;
+  ok: boolean;
+  issues: GhIssueSummary[];
+  cancelled?: boolean;
+  error?: string;
+  /** Runs that failed for a passing reason and were tried again. */
+  retries?: CommandRetry[];
+};
+
+export type GhIssueViewResponse = {
+  requestId?: string;
+  ok: boolean;
+  issue?: GhIssueDetail;
+  cancelled?: boolean;
+  error?: string;
+  /** Runs that failed for a passing reason and were tried again. */
+  retries?: CommandRetry[];
 };
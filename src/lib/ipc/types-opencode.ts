@@ -1,4 +1,27 @@
 import type { OpencodeSettings } from "./types-core";
+import type {
+  OpenCodeErrorDetail,
+  OpenCodeProfileCommands,
+  OpenCodeProfileSafety,
+  OpenCodeProfileTimeouts,
+  OpenCodeRepairResult,
+  OpenCodeSanityChecks,
+  OpenCodeSanityStatus,
+  OpenCodeStatus,
+  OpencodeSkillEntry,
+} from "./types-generated";
+
+export type {
+  OpenCodeErrorDetail,
+  OpenCodeProfileCommands,
+  OpenCodeProfileSafety,
+  OpenCodeProfileTimeouts,
+  OpenCodeRepairResult,
+  OpenCodeSanityChecks,
+  OpenCodeSanityStatus,
+  OpenCodeStatus,
+  OpencodeSkillEntry,
+};
 
 export type OpencodeEffectiveScope = "workspace" | "global" | "none";
 
@@ -23,13 +46,6 @@ export type OpencodeSettingsDirectoryValidationResponse = {
   error?: string;
 };
 
-export type OpencodeSkillEntry = {
-  name: string;
-  path: string;
-  isDirectory: boolean;
-  hasSkillMarkdown: boolean;
-};
-
 export type OpencodeSkillScope = {
   scope: "global" | "workspace" | string;
   rootPath: string;
@@ -76,24 +92,6 @@ export type OpencodeGlobalSettingsResponse = {
   error?: string;
 };
 
-export type OpenCodeProfileCommands = {
-  init: string;
-  newChange: string;
-  continue: string;
-  apply: string;
-  verify: string;
-  archive: string;
-};
-
-export type OpenCodeProfileTimeouts = {
-  phaseSeconds: number;
-};
-
-export type OpenCodeProfileSafety = {
-  requireUserApprovalBetweenPhases: boolean;
-  allowParallelSpecDesign: boolean;
-};
-
 export type OpenCodeProfile = {
   version: string;
   enabled: boolean;
@@ -114,55 +112,6 @@ export type OpenCodeProfilePatch = {
   safety?: Partial<OpenCodeProfileSafety>;
 };
 
-export type OpenCodeErrorDetail = {
-  code: string;
-  message: string;
-  hint: string;
-  paths: string[];
-};
-
-export type OpenCodeStatus = {
-  worktreePath: string;
-  worktreeExists: boolean;
-  gitRepo: boolean;
-  opencodeAvailable: boolean;
-  opencodeBinaryPath?: string;
-  agentTeamsLiteAvailable: boolean;
-  agentTeamsLiteDir?: string;
-  requiredCommandsAvailable: boolean;
-  missingCommands: string[];
-  profilePresent: boolean;
-  profilePath: string;
-  syncTargetExists: boolean;
-  syncTargetPath: string;
-  artifactStore?: string;
-  artifactStoreReady: boolean;
-  engramBinaryAvailable?: boolean;
-  engramOpencodeMcpConfigPresent?: boolean;
-  engramOpencodePluginPresent?: boolean;
-  engramOpencodeConfigPath?: string;
-  engramOpencodePluginPath?: string;
-  profileValid: boolean;
-  warnings: string[];
-  sanity: OpenCodeSanityStatus;
-};
-
-export type OpenCodeSanityChecks = {
-  agentTeamsLiteAvailable: boolean;
-  requiredRefsPresent: boolean;
-  profileExistsAndValid: boolean;
-  syncArtifactApplied: boolean;
-  artifactStoreReady: boolean;
-};
-
-export type OpenCodeSanityStatus = {
-  applied: boolean;
-  checks: OpenCodeSanityChecks;
-  hardBlockers: string[];
-  recommendations: string[];
-  diagnostics: string[];
-};
-
 export type OpenCodeStatusResponse = {
   requestId?: string;
   ok: boolean;
@@ -193,13 +142,6 @@ export type OpenCodeSyncResponse = {
   error?: string;
 };
 
-export type OpenCodeRepairResult = {
-  repaired: boolean;
-  backupPath?: string;
-  actions: string[];
-  postRepairStatus: OpenCodeStatus;
-};
-
 export type OpenCodeRepairResponse = {
   requestId?: string;
   ok: boolean;
@@ -1,4 +1,18 @@
+import type { CommandRun } from "./types-commands";
 import type { WorkspaceMeta } from "./types-core";
+import type {
+  GrooveTerminalActivityEntry,
+  GrooveTerminalAttachPayload,
+  GrooveTerminalScrollbackPayload,
+  GrooveTerminalScrollbackRecordingPayload,
+} from "./types-generated";
+
+export type {
+  GrooveTerminalActivityEntry,
+  GrooveTerminalAttachPayload,
+  GrooveTerminalScrollbackPayload,
+  GrooveTerminalScrollbackRecordingPayload,
+};
 
 export type GrooveTerminalSession = {
   sessionId: string;
@@ -11,6 +25,8 @@ export type GrooveTerminalSession = {
   cols: number;
   rows: number;
   snapshot?: string;
+  /** The shell was stopped while idle and starts again on the next write. */
+  hibernated?: boolean;
 };
 
 export type GrooveTerminalOpenPayload = {
@@ -68,54 +84,138 @@ export type GrooveTerminalCommandResponse = {
   error?: string;
 };
 
-export type GrooveTerminalSessionResponse = {
+export type WorkspaceCustomCommandRunPayload = {
+  rootName: string;
+  knownWorktrees: string[];
+  workspaceMeta?: WorkspaceMeta;
+  worktree: string;
+  /** Id of one of the workspace's `customCommands`. */
+  id: string;
+  cols?: number;
+  rows?: number;
+};
+
+export type WorkspaceCustomCommandRunResponse = {
   requestId?: string;
   ok: boolean;
+  /** The expanded command line. */
+  command?: string;
+  runMode?: "terminal" | "detached" | "capture";
+  /** The terminal the command was started in, for `terminal` commands. */
   session?: GrooveTerminalSession;
+  /** The run as started, for `capture` commands. */
+  run?: CommandRun;
   error?: string;
 };
 
-export type GrooveTerminalSessionsResponse = {
+export type GrooveTerminalSessionResponse = {
   requestId?: string;
   ok: boolean;
-  sessions: GrooveTerminalSession[];
+  session?: GrooveTerminalSession;
   error?: string;
 };
 
-export type GrooveTerminalActiveWorktreesResponse = {
+/**
+ * Output missed since `offset`. Output events with an `offset` at most
+ * `endOffset` are already part of `data`.
+ */
+export type GrooveTerminalAttachResponse = {
   requestId?: string;
   ok: boolean;
-  worktrees: string[];
+  session?: GrooveTerminalSession;
+  data: string;
+  offset: number;
+  endOffset: number;
+  /** Whether part of the missed output is no longer available. */
+  truncated: boolean;
   error?: string;
 };
 
-export type GrooveTerminalActivityEntry = {
-  sessionId: string;
-  hasActivity: boolean;
+export type GrooveTerminalHistoryPayload = {
+  rootName: string;
+  knownWorktrees: string[];
+  workspaceMeta?: WorkspaceMeta;
+  worktree: string;
+  /** Defaults to the most recently written history of the worktree. */
+  sessionId?: string;
+  /** Defaults to 256 KiB, at most 4 MiB. */
+  maxBytes?: number;
 };
 
-export type GrooveTerminalActivityResponse = {
+/** Output persisted under `.groove/terminal-history`. */
+export type GrooveTerminalHistoryResponse = {
   requestId?: string;
   ok: boolean;
-  entries: GrooveTerminalActivityEntry[];
+  /** Absent when the worktree has no history. */
+  sessionId?: string;
+  data: string;
+  /** Whether older output than `data` was left out. */
+  truncated: boolean;
   error?: string;
 };
 
-export type GrooveTerminalOutputEvent = {
+/** Offsets count bytes of session output since it started. */
+export type GrooveTerminalScrollbackResponse = {
+  requestId?: string;
+  ok: boolean;
   sessionId: string;
-  workspaceRoot: string;
-  worktree: string;
-  chunk: string;
+  data: string;
+  /** Offset of the first byte of `data`. */
+  offset: number;
+  /** Oldest offset still available on disk. */
+  startOffset: number;
+  /** Offset where the in-memory snapshot begins. */
+  snapshotOffset: number;
+  hasMore: boolean;
+  /** Recorded scrollback is kept after the session closes. */
+  recording: boolean;
+  error?: string;
 };
 
-export type GrooveTerminalLifecycleEvent = {
-  sessionId: string;
-  workspaceRoot: string;
+export type WorktreeSessionLayoutRestorePayload = {
+  rootName: string;
+  knownWorktrees: string[];
+  workspaceMeta?: WorkspaceMeta;
   worktree: string;
-  kind: "started" | "closed" | "error";
-  message?: string;
+  cols?: number;
+  rows?: number;
+};
+
+export type WorktreeSessionLayoutRestoreResponse = {
+  requestId?: string;
+  ok: boolean;
+  sessions: GrooveTerminalSession[];
+  failures?: string[];
+  error?: string;
 };
 
+export type GrooveTerminalSessionsResponse = {
+  requestId?: string;
+  ok: boolean;
+  sessions: GrooveTerminalSession[];
+  error?: string;
+};
+
+export type GrooveTerminalActiveWorktreesResponse = {
+  requestId?: string;
+  ok: boolean;
+  worktrees: string[];
+  error?: string;
+};
+
+export type GrooveTerminalActivityResponse = {
+  requestId?: string;
+  ok: boolean;
+  entries: GrooveTerminalActivityEntry[];
+  error?: string;
+};
+
+export type {
+  GrooveTerminalOutputEvent,
+  GrooveTerminalLifecycleEvent,
+  GrooveNotificationEvent,
+} from "./types-events";
+
 export type GrooveNotification = {
   id: string;
   action?: string;
@@ -125,8 +225,3 @@ export type GrooveNotification = {
   timestamp: string;
   source: string;
 };
-
-export type GrooveNotificationEvent = {
-  workspaceRoot: string;
-  notification: GrooveNotification;
-};
@@ -3,6 +3,7 @@ import { describe, expect, it } from "vitest";
 import type { WorkspaceRow } from "@/src/lib/ipc";
 import {
   deriveWorktreeStatus,
+  describeGrooveRmSafety,
   getActiveWorktreeRows,
   shouldPromptForceCutRetry,
 } from "@/src/lib/utils/worktree/status";
@@ -118,4 +119,25 @@ describe("shouldPromptForceCutRetry", () => {
 
     expect(shouldPromptForceCutRetry(incompleteResult)).toBe(false);
   });
+
+  it("prompts when the safety scan refused the removal", () => {
+    const blockedResult = {
+      ok: false,
+      exitCode: null,
+      stdout: "",
+      stderr: "",
+      safety: {
+        uncommittedChanges: 0,
+        unpushedCommits: 1,
+        runningPids: [41, 42],
+        terminalSessions: 0,
+        blocking: ["unpushedCommits" as const, "runningProcesses" as const],
+      },
+    };
+
+    expect(shouldPromptForceCutRetry(blockedResult)).toBe(true);
+    expect(describeGrooveRmSafety(blockedResult.safety)).toBe(
+      "1 unpushed commit, 2 running processes",
+    );
+  });
 });
@@ -1,4 +1,8 @@
-import type { GrooveRmResponse, WorkspaceRow } from "@/src/lib/ipc";
+import type {
+  GrooveRmResponse,
+  GrooveRmSafetyScan,
+  WorkspaceRow,
+} from "@/src/lib/ipc";
 import { parseLastExecutedAt } from "@/src/lib/utils/time/grouping";
 
 export type WorktreeStatus = WorkspaceRow["status"];
@@ -52,6 +56,9 @@ export function getActiveWorktreeRows(
 }
 
 export function shouldPromptForceCutRetry(result: GrooveRmResponse): boolean {
+  if (result.safety && result.safety.blocking.length > 0) {
+    return true;
+  }
   const combinedOutput =
     `${result.stdout}\n${result.stderr}\n${result.error ?? ""}`.toLowerCase();
   return (
@@ -59,3 +66,29 @@ export function shouldPromptForceCutRetry(result: GrooveRmResponse): boolean {
     /use --force to delete it/.test(combinedOutput)
   );
 }
+
+/** What blocks removal, e.g. "2 unpushed commits, 1 open terminal". */
+export function describeGrooveRmSafety(scan: GrooveRmSafetyScan): string {
+  const plural = (count: number, noun: string, nouns = `${noun}s`) =>
+    `${count} ${count === 1 ? noun : nouns}`;
+  return scan.blocking
+    .map((category) => {
+      switch (category) {
+        case "uncommittedChanges":
+          return scan.uncommittedChanges === null
+            ? "changes git status could not read"
+            : plural(scan.uncommittedChanges, "uncommitted change");
+        case "unpushedCommits":
+          return plural(scan.unpushedCommits ?? 0, "unpushed commit");
+        case "runningProcesses":
+          return plural(
+            scan.runningPids.length,
+            "running process",
+            "running processes",
+          );
+        case "terminalSessions":
+          return plural(scan.terminalSessions, "open terminal");
+      }
+    })
+    .join(", ");
+}
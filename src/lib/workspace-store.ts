@@ -1,5 +1,6 @@
 import {
   grooveTerminalActiveWorktrees,
+  listenWorkspaceContextComplete,
   workspaceGetActive,
   type WorkspaceContextResponse,
   type WorkspaceMeta,
@@ -26,6 +27,9 @@ let snapshot: WorkspaceContextStoreSnapshot = {
 const listeners = new Set<Listener>();
 let inFlightContextFetch: Promise<WorkspaceContextResponse> | null = null;
 let inFlightRuntimeFetch: Promise<ReadonlySet<string>> | null = null;
+let contextCompleteListener: Promise<unknown> | null = null;
+/** A full context whose event beat the partial answer it completes. */
+let earlyCompletedContext: WorkspaceContextResponse | null = null;
 
 function emitChange(): void {
   for (const listener of listeners) {
@@ -64,7 +68,25 @@ export function publishWorkspaceContext(
   setSnapshot({
     ...snapshot,
     context,
-    isContextLoading: false,
+    isContextLoading: context.partial === true,
+  });
+}
+
+/**
+ * Replaces a partial cold-start context with the full one once the backend
+ * has scanned the worktrees.
+ */
+function ensureContextCompleteListener(): void {
+  if (contextCompleteListener) {
+    return;
+  }
+  contextCompleteListener = listenWorkspaceContextComplete((event) => {
+    const current = snapshot.context;
+    if (current?.partial && current.requestId === event.requestId) {
+      publishWorkspaceContext(event.context);
+    } else if (current?.requestId !== event.requestId) {
+      earlyCompletedContext = event.context;
+    }
   });
 }
 
@@ -138,15 +160,21 @@ export function refreshWorkspaceContext(): Promise<WorkspaceContextResponse> {
   }
 
   setSnapshot({ ...snapshot, isContextLoading: true });
+  ensureContextCompleteListener();
 
   inFlightContextFetch = workspaceGetActive()
     .then((result) => {
-      publishWorkspaceContext(result);
-      return result;
+      const completed =
+        result.partial && earlyCompletedContext?.requestId === result.requestId
+          ? earlyCompletedContext
+          : result;
+      earlyCompletedContext = null;
+      publishWorkspaceContext(completed);
+      return completed;
     })
     .finally(() => {
       inFlightContextFetch = null;
-      if (snapshot.isContextLoading) {
+      if (snapshot.isContextLoading && !snapshot.context?.partial) {
         setSnapshot({ ...snapshot, isContextLoading: false });
       }
     });